@@ -2,16 +2,20 @@
 
 use crate::asset_registry::Junction::GeneralIndex;
 use crate::polkadot_test_net::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use hydradx_runtime::AssetRegistry as Registry;
+use orml_traits::MultiCurrency;
+use pallet_asset_registry_rpc_runtime_api::runtime_decl_for_asset_registry_api::AssetRegistryApi;
 use polkadot_xcm::v3::{
 	Junction::{self, Parachain},
 	Junctions::X2,
 	MultiLocation,
 };
 use pretty_assertions::{assert_eq, assert_ne};
+use primitives::AccountId;
 use xcm_emulator::TestExt;
+use xcm_executor::traits::TransactAsset;
 
 #[test]
 fn root_should_update_decimals_when_it_was_already_set() {
@@ -85,3 +89,120 @@ fn root_should_update_location_when_asset_exists() {
 		assert!(Registry::location_assets(loc_1).is_none());
 	});
 }
+
+#[test]
+fn unknown_foreign_asset_should_be_registered_as_quarantined_when_deposited() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		let location = MultiLocation::new(1, X2(Parachain(ACALA_PARA_ID), GeneralIndex(1_234)));
+		let asset = xcm_asset(location, 10 * UNITS);
+		let bob =
+			polkadot_xcm::v4::Location::new(0, polkadot_xcm::v4::Junction::AccountId32 { id: BOB, network: None });
+		assert_eq!(
+			Registry::location_to_asset(hydradx_runtime::AssetLocation(location)),
+			None
+		);
+
+		//Act
+		assert_ok!(hydradx_runtime::LocalAssetTransactor::deposit_asset(&asset, &bob, None));
+
+		//Assert
+		let asset_id = Registry::location_to_asset(hydradx_runtime::AssetLocation(location)).unwrap();
+		assert!(Registry::quarantined_assets(asset_id).is_some());
+		assert!(!Registry::assets(asset_id).unwrap().is_sufficient);
+		assert_eq!(
+			hydradx_runtime::Currencies::free_balance(asset_id, &AccountId::from(BOB)),
+			10 * UNITS
+		);
+
+		// following deposits are handled as a known asset
+		assert_ok!(hydradx_runtime::LocalAssetTransactor::deposit_asset(&asset, &bob, None));
+		assert_eq!(
+			Registry::location_to_asset(hydradx_runtime::AssetLocation(location)),
+			Some(asset_id)
+		);
+		assert_eq!(
+			hydradx_runtime::Currencies::free_balance(asset_id, &AccountId::from(BOB)),
+			20 * UNITS
+		);
+	});
+}
+
+#[test]
+fn quarantined_asset_should_not_be_tradable_until_promoted() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		let location = MultiLocation::new(1, X2(Parachain(ACALA_PARA_ID), GeneralIndex(1_234)));
+		let asset_id = Registry::register_quarantined_asset(hydradx_runtime::AssetLocation(location)).unwrap();
+
+		assert_noop!(
+			hydradx_runtime::OTC::place_order(
+				hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+				HDX,
+				asset_id,
+				10 * UNITS,
+				10 * UNITS,
+				true
+			),
+			pallet_otc::Error::<hydradx_runtime::Runtime>::AssetQuarantined
+		);
+
+		//Act
+		assert_ok!(Registry::promote_asset(RawOrigin::Root.into(), asset_id));
+
+		//Assert
+		assert_eq!(Registry::quarantined_assets(asset_id), None);
+	});
+}
+
+#[test]
+fn asset_should_be_found_by_alias_when_alias_is_added_by_governance() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		assert_ok!(Registry::update(
+			RawOrigin::Root.into(),
+			DAI,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(b"DAI".to_vec().try_into().unwrap()),
+			None,
+			None
+		));
+
+		assert_ok!(Registry::add_alias(
+			RawOrigin::Root.into(),
+			DAI,
+			b"DAI.e".to_vec().try_into().unwrap()
+		));
+
+		assert_eq!(
+			<hydradx_runtime::Runtime as AssetRegistryApi<hydradx_runtime::Block, AssetId>>::asset_by_ticker(
+				b"dai.E".to_vec()
+			),
+			Some(DAI)
+		);
+		assert_eq!(
+			<hydradx_runtime::Runtime as AssetRegistryApi<hydradx_runtime::Block, AssetId>>::asset_tickers(DAI),
+			Some(pallet_asset_registry_rpc_runtime_api::AssetTickers {
+				symbol: Some(b"DAI".to_vec()),
+				aliases: vec![b"DAI.e".to_vec()],
+			})
+		);
+		assert_noop!(
+			Registry::add_alias(RawOrigin::Root.into(), DOT, b"dai".to_vec().try_into().unwrap()),
+			pallet_asset_registry::Error::<hydradx_runtime::Runtime>::TickerAlreadyUsed
+		);
+	});
+}
+
+fn xcm_asset(location: MultiLocation, amount: Balance) -> polkadot_xcm::v4::Asset {
+	polkadot_xcm::v4::Asset {
+		id: polkadot_xcm::v4::AssetId(location.try_into().unwrap()),
+		fun: polkadot_xcm::v4::Fungibility::Fungible(amount),
+	}
+}
@@ -302,7 +302,8 @@ fn referenda_can_not_be_filtered() {
 		assert_ok!(hydradx_runtime::TransactionPause::pause_transaction(
 			hydradx_runtime::RuntimeOrigin::root(),
 			b"Referenda".to_vec(),
-			b"submit".to_vec()
+			b"submit".to_vec(),
+			None
 		));
 
 		// Prepare a Referenda/submit call
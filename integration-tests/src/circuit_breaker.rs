@@ -3,7 +3,7 @@
 use crate::polkadot_test_net::*;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
-use hydradx_runtime::{Balances, CircuitBreaker, Omnipool, OmnipoolCollectionId, Tokens, Uniques};
+use hydradx_runtime::{Balances, CircuitBreaker, Omnipool, OmnipoolCollectionId, Tokens, Uniques, XYK};
 use orml_traits::MultiCurrency;
 use primitives::constants::chain::CORE_ASSET_ID;
 use primitives::Balance;
@@ -478,7 +478,7 @@ fn remove_liquidity_to_omnipool_should_work_when_liquidity_limit_per_block_not_e
 }
 
 #[test]
-fn remove_liquidity_from_omnipool_should_fail_when_large_legacy_position_removed() {
+fn remove_liquidity_from_omnipool_should_queue_withdrawal_when_large_legacy_position_removed() {
 	Hydra::execute_with(|| {
 		//Arrange
 		init_omnipool();
@@ -507,11 +507,16 @@ fn remove_liquidity_from_omnipool_should_fail_when_large_legacy_position_removed
 			ALICE.into(),
 		));
 
-		//Act and Assert
-		assert_noop!(
-			Omnipool::remove_liquidity(hydradx_runtime::RuntimeOrigin::signed(ALICE.into()), position, bag,),
-			pallet_circuit_breaker::Error::<hydradx_runtime::Runtime>::MaxLiquidityLimitPerBlockReached
-		);
+		//Act
+		assert_ok!(Omnipool::remove_liquidity(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			position,
+			bag,
+		));
+
+		//Assert
+		assert!(Omnipool::queued_withdrawal(position).is_some());
+		assert_eq!(Omnipool::positions(position).unwrap().shares, bag);
 	});
 }
 
@@ -697,3 +702,47 @@ fn do_trading_activity_to_populate_oracle() {
 		Balance::zero()
 	));
 }
+
+#[test]
+fn trade_volume_should_be_tracked_only_for_omnipool_trades() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		assert_ok!(XYK::create_pool(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			CORE_ASSET_ID,
+			100 * UNITS,
+			DAI,
+			200 * UNITS,
+		));
+
+		//Act
+		assert_ok!(XYK::sell(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			CORE_ASSET_ID,
+			UNITS,
+			0,
+			false,
+		));
+
+		//Assert
+		assert!(CircuitBreaker::allowed_trade_volume_limit_per_asset(DAI).is_none());
+		assert!(CircuitBreaker::allowed_trade_volume_limit_per_asset(CORE_ASSET_ID).is_none());
+
+		//Act
+		assert_ok!(Omnipool::sell(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			CORE_ASSET_ID,
+			UNITS,
+			0
+		));
+
+		//Assert
+		assert!(CircuitBreaker::allowed_trade_volume_limit_per_asset(DAI).is_some());
+		assert!(CircuitBreaker::allowed_trade_volume_limit_per_asset(CORE_ASSET_ID).is_some());
+	});
+}
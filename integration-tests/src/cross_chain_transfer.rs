@@ -3,6 +3,7 @@ use crate::erc20::bind_erc20;
 use crate::erc20::deploy_token_contract;
 use crate::polkadot_test_net::Rococo;
 use crate::polkadot_test_net::*;
+use crate::utils::xcm_scenario::{advance_blocks_on_all, send_xcm_transfer, BalanceSnapshot, Chain, ALL_PARACHAINS};
 use xcm_emulator::ConvertLocation;
 use xcm_executor::traits::TransferType;
 
@@ -187,6 +188,59 @@ fn hydra_should_receive_asset_when_transferred_from_acala() {
 	});
 }
 
+#[test]
+fn hydra_should_receive_asset_when_transferred_from_acala_by_scenario_driver() {
+	// Arrange
+	TestNet::reset();
+
+	Chain::Hydra.execute_with(|| {
+		assert_ok!(hydradx_runtime::AssetRegistry::set_location(
+			ACA,
+			hydradx_runtime::AssetLocation(MultiLocation::new(
+				1,
+				X2(Junction::Parachain(ACALA_PARA_ID), Junction::GeneralIndex(0))
+			))
+		));
+	});
+
+	let alice = Chain::Acala.account(ALICE);
+	let bob = Chain::Hydra.account(BOB);
+	let balances = BalanceSnapshot::take(&[(alice, 0), (bob, ACA)]);
+
+	// Act
+	send_xcm_transfer(alice, bob, 0, 30 * UNITS);
+	advance_blocks_on_all(1);
+
+	// Assert
+	balances.assert_diff(alice, 0, -(30 * UNITS as i128));
+	let received = balances.diff(bob, ACA);
+	assert!(received > 0 && received < 30 * UNITS as i128, "fee is not paid");
+}
+
+#[test]
+fn advance_blocks_on_all_should_produce_blocks_on_all_parachains_without_changing_balances() {
+	// Arrange
+	TestNet::reset();
+	advance_blocks_on_all(1);
+
+	let block_numbers: Vec<_> = ALL_PARACHAINS.iter().map(|chain| chain.block_number()).collect();
+	let balances = BalanceSnapshot::take(&[(Chain::Hydra.account(ALICE), HDX), (Chain::Acala.account(ALICE), HDX)]);
+
+	// Act
+	advance_blocks_on_all(3);
+
+	// Assert
+	for (chain, block_number) in ALL_PARACHAINS.iter().zip(block_numbers) {
+		assert_eq!(
+			chain.block_number(),
+			block_number + 3,
+			"unexpected block number of {:?}",
+			chain
+		);
+	}
+	balances.assert_unchanged();
+}
+
 #[test]
 fn hydra_should_receive_asset_when_transferred_from_acala_to_eth_address() {
 	// Arrange
@@ -472,10 +526,10 @@ fn assets_should_be_trapped_when_assets_are_unknown() {
 		};
 		let hash = determine_hash(&origin, vec![asset.clone()]);
 
-		assert_eq!(hydradx_runtime::PolkadotXcm::asset_trap(hash), 1);
+		assert_eq!(hydradx_runtime::XcmAssetTrap::trapped_assets(hash), Some(1));
 
-		expect_hydra_events(vec![hydradx_runtime::RuntimeEvent::PolkadotXcm(
-			pallet_xcm::Event::AssetsTrapped {
+		expect_hydra_events(vec![hydradx_runtime::RuntimeEvent::XcmAssetTrap(
+			pallet_xcm_asset_trap::Event::AssetsTrapped {
 				hash,
 				origin: origin.try_into().unwrap(),
 				assets: vec![asset].into(),
@@ -524,7 +578,43 @@ fn claim_trapped_asset_should_work() {
 
 		let origin = MultiLocation::new(1, X1(Junction::Parachain(ACALA_PARA_ID)));
 		let hash = determine_hash(&origin, vec![asset]);
-		assert_eq!(hydradx_runtime::PolkadotXcm::asset_trap(hash), 0);
+		assert_eq!(hydradx_runtime::XcmAssetTrap::trapped_assets(hash), None);
+	});
+}
+
+#[test]
+fn force_claim_trapped_asset_should_deposit_asset_to_beneficiary() {
+	TestNet::reset();
+
+	// traps asset when asset is not registered yet
+	let asset = trap_asset();
+
+	Hydra::execute_with(|| {
+		// register the asset
+		assert_ok!(hydradx_runtime::AssetRegistry::set_location(
+			1,
+			hydradx_runtime::AssetLocation(MultiLocation::new(
+				1,
+				X2(Junction::Parachain(ACALA_PARA_ID), Junction::GeneralIndex(0))
+			))
+		));
+		let origin = MultiLocation::new(1, X1(Junction::Parachain(ACALA_PARA_ID)));
+		let hash = determine_hash(&origin, vec![asset.clone()]);
+
+		// Act
+		assert_ok!(hydradx_runtime::XcmAssetTrap::force_claim_trapped_assets(
+			hydradx_runtime::RuntimeOrigin::root(),
+			Box::new(Location::try_from(origin).unwrap().into_versioned()),
+			Box::new(VersionedAssets::from(Assets::from(vec![asset]))),
+			BOB.into(),
+		));
+
+		// Assert
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(LRNA, &AccountId::from(BOB)),
+			BOB_INITIAL_LRNA_BALANCE + 30 * UNITS
+		);
+		assert_eq!(hydradx_runtime::XcmAssetTrap::trapped_assets(hash), None);
 	});
 }
 
@@ -1077,7 +1167,7 @@ fn trap_asset() -> Asset {
 		let origin = MultiLocation::new(1, X1(Junction::Parachain(ACALA_PARA_ID)));
 		let hash = determine_hash(&origin, vec![asset.clone()]);
 
-		assert_eq!(hydradx_runtime::PolkadotXcm::asset_trap(hash), 1);
+		assert_eq!(hydradx_runtime::XcmAssetTrap::trapped_assets(hash), Some(1));
 	});
 
 	asset
@@ -20,7 +20,7 @@ use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
 use orml_traits::MultiCurrency;
 use orml_traits::MultiReservableCurrency;
-use pallet_dca::types::{Order, Schedule};
+use pallet_dca::types::{ExecutionMode, Order, Schedule, TerminationMode};
 use pallet_omnipool::types::Tradability;
 use pallet_stableswap::types::AssetAmount;
 use pallet_stableswap::MAX_ASSETS_IN_POOL;
@@ -266,6 +266,9 @@ mod omnipool {
 						asset_out: DAI,
 					}]),
 				},
+				termination: TerminationMode::Standard,
+				time_period: None,
+				execution: ExecutionMode::Automatic,
 			};
 			create_schedule(ALICE, schedule1);
 
@@ -1230,6 +1233,9 @@ mod omnipool {
 						asset_out: DAI,
 					}]),
 				},
+				termination: TerminationMode::Standard,
+				time_period: None,
+				execution: ExecutionMode::Automatic,
 			};
 			create_schedule(ALICE, schedule1);
 
@@ -2143,6 +2149,9 @@ mod stableswap {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2423,6 +2432,9 @@ mod stableswap {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2731,6 +2743,9 @@ mod stableswap {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(trades),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2912,6 +2927,9 @@ mod stableswap {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(trades),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3165,6 +3183,9 @@ mod all_pools {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3313,6 +3334,9 @@ mod with_onchain_route {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(vec![]),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3427,6 +3451,9 @@ mod with_onchain_route {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(vec![]),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3511,6 +3538,9 @@ mod with_onchain_route {
 					min_amount_out: Balance::MIN,
 					route: create_bounded_vec(vec![]),
 				},
+				termination: TerminationMode::Standard,
+				time_period: None,
+				execution: ExecutionMode::Automatic,
 			};
 
 			//We verify the price diff between hdx and stable asset.
@@ -3658,6 +3688,9 @@ mod with_onchain_route {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(vec![]),
 					},
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
 				};
 
 				//We verify the price diff between hdx and stable asset.
@@ -3778,6 +3811,9 @@ mod with_onchain_route {
 					min_amount_out: Balance::MIN,
 					route: create_bounded_vec(vec![]),
 				},
+				termination: TerminationMode::Standard,
+				time_period: None,
+				execution: ExecutionMode::Automatic,
 			};
 
 			//Just to verify the price difference between HDX and DOT
@@ -3815,6 +3851,53 @@ mod with_onchain_route {
 	}
 }
 
+mod remote {
+	use super::*;
+	use hydradx_runtime::AssetLocation;
+	use polkadot_xcm::opaque::v3::{Junction, Junctions::X1, MultiLocation};
+
+	#[test]
+	fn terminate_should_transfer_leftover_budget_to_destination_when_schedule_is_remote() {
+		TestNet::reset();
+		Rococo::execute_with(|| {
+			assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+		});
+
+		Hydra::execute_with(|| {
+			//Arrange
+			hydradx_runtime::AssetRegistry::set_location(DOT, DOT_ASSET_LOCATION).unwrap();
+			init_omnipool_with_oracle_for_block_10();
+			add_dot_as_payment_currency();
+
+			let dca_budget = 100 * UNITS;
+			let schedule = schedule_fake_with_sell_order(ALICE, PoolType::Omnipool, dca_budget, DOT, HDX, 10 * UNITS);
+			let destination = AssetLocation(MultiLocation::new(
+				1,
+				X1(Junction::AccountId32 { id: BOB, network: None }),
+			));
+			assert_ok!(DCA::schedule_remote(
+				RuntimeOrigin::signed(ALICE.into()),
+				schedule,
+				None,
+				Box::new(destination.clone())
+			));
+			assert_eq!(DCA::remote_destinations(0), Some(destination));
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE.into()), 0, Some(12)));
+
+			//Assert
+			assert_balance!(ALICE.into(), DOT, ALICE_INITIAL_DOT_BALANCE - dca_budget);
+			assert_reserved_balance!(&ALICE.into(), DOT, 0);
+			assert_eq!(count_dca_event!(pallet_dca::Event::RemoteTransferred { .. }), 1);
+		});
+
+		Rococo::execute_with(|| {
+			assert!(Balances::free_balance(AccountId::from(BOB)) > 0);
+		});
+	}
+}
+
 fn create_xyk_pool_with_amounts(asset_a: u32, amount_a: u128, asset_b: u32, amount_b: u128) {
 	assert_ok!(Currencies::update_balance(
 		hydradx_runtime::RuntimeOrigin::root(),
@@ -3883,6 +3966,9 @@ fn schedule_fake_with_buy_order_with_route(
 			max_amount_in: Balance::MAX,
 			route: create_bounded_vec(route),
 		},
+		termination: TerminationMode::Standard,
+		time_period: None,
+		execution: ExecutionMode::Automatic,
 	}
 }
 
@@ -3930,6 +4016,9 @@ fn schedule_fake_with_sell_order_with_route(
 			min_amount_out: Balance::MIN,
 			route: create_bounded_vec(route),
 		},
+		termination: TerminationMode::Standard,
+		time_period: None,
+		execution: ExecutionMode::Automatic,
 	}
 }
 
@@ -4110,7 +4199,7 @@ pub fn init_stableswap() -> Result<(AssetId, AssetId, AssetId), DispatchError> {
 			Some(name.try_into().unwrap()),
 			AssetKind::Token,
 			1u128,
-			Some(b"xDUM".to_vec().try_into().unwrap()),
+			Some(format!("xDUM{idx}").into_bytes().try_into().unwrap()),
 			Some(18u8),
 			None,
 			None,
@@ -4171,7 +4260,7 @@ pub fn init_stableswap_with_three_assets_having_different_decimals(
 			Some(name.try_into().unwrap()),
 			AssetKind::Token,
 			1u128,
-			Some(b"xDUM".to_vec().try_into().unwrap()),
+			Some(format!("xDUM{idx}").into_bytes().try_into().unwrap()),
 			Some(decimals_for_each_asset[idx as usize]),
 			None,
 			None,
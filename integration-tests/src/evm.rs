@@ -605,6 +605,7 @@ mod standard_precompiles {
 
 mod currency_precompile {
 	use super::*;
+	use crate::utils::accounts::{alith_evm_address, alith_secret_key};
 	use fp_evm::ExitRevert::Reverted;
 	use fp_evm::PrecompileFailure;
 	use frame_support::assert_noop;
@@ -986,18 +987,101 @@ mod currency_precompile {
 	}
 
 	#[test]
-	fn precompile_for_currency_approve_allowance_should_fail_as_not_supported() {
+	fn precompile_for_currency_approve_should_set_allowance() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
-			assert_ok!(hydradx_runtime::Currencies::update_balance(
-				hydradx_runtime::RuntimeOrigin::root(),
-				evm_account(),
-				HDX,
-				100 * UNITS as i128,
+			let data = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000001"].to_vec(),
+				})
+			);
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), evm_address(), evm_address2()),
+				50u128 * UNITS
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_approve_should_set_unlimited_allowance_when_amount_is_max_uint256() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::MAX)
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000001"].to_vec(),
+				})
+			);
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), evm_address(), evm_address2()),
+				u128::MAX
+			);
+			assert_ok!(EVMAccounts::spend_allowance(
+				native_asset_ethereum_address(),
+				evm_address(),
+				evm_address2(),
+				10u128 * UNITS
 			));
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), evm_address(), evm_address2()),
+				u128::MAX
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_approve_should_fail_in_static_context() {
+		TestNet::reset();
 
+		Hydra::execute_with(|| {
+			//Arrange
 			let data = EvmDataWriter::new_with_selector(Function::Approve)
 				.write(Address::from(evm_address2()))
 				.write(U256::from(50u128 * UNITS))
@@ -1006,8 +1090,8 @@ mod currency_precompile {
 			let mut handle = MockHandle {
 				input: data,
 				context: Context {
-					address: evm_address(),
-					caller: native_asset_ethereum_address(),
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
 					apparent_value: U256::from(0),
 				},
 				code_address: native_asset_ethereum_address(),
@@ -1020,8 +1104,47 @@ mod currency_precompile {
 			//Assert
 			assert_eq!(
 				result,
-				Err(PrecompileFailure::Error {
-					exit_status: pallet_evm::ExitError::Other("not supported".into())
+				Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "can't call non-static function in static context".as_bytes().to_vec()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_allowance_should_return_approved_amount() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			EVMAccounts::set_allowance(native_asset_ethereum_address(), evm_address2(), evm_address(), 1_000);
+
+			let data = EvmDataWriter::new_with_selector(Function::Allowance)
+				.write(Address::from(evm_address2()))
+				.write(Address::from(evm_address()))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: true,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["00000000000000000000000000000000000000000000000000000000000003e8"].to_vec()
 				})
 			);
 		});
@@ -1149,7 +1272,7 @@ mod currency_precompile {
 	}
 
 	#[test]
-	fn precompile_for_transfer_from_should_fail_for_not_approved_contract() {
+	fn precompile_for_transfer_from_should_fail_without_allowance() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
@@ -1183,7 +1306,7 @@ mod currency_precompile {
 				CurrencyPrecompile::execute(&mut handle),
 				PrecompileFailure::Revert {
 					exit_status: Reverted,
-					output: "Not approved contract".as_bytes().to_vec()
+					output: "Insufficient allowance".as_bytes().to_vec()
 				}
 			);
 			assert_balance!(evm_account2(), HDX, 0);
@@ -1240,6 +1363,243 @@ mod currency_precompile {
 		});
 	}
 
+	#[test]
+	fn precompile_for_transfer_from_should_spend_allowance() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+			EVMAccounts::set_allowance(
+				native_asset_ethereum_address(),
+				evm_address(),
+				evm_address2(),
+				80u128 * UNITS,
+			);
+
+			let data = EvmDataWriter::new_with_selector(Function::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address2(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_balance!(evm_account2(), HDX, 50u128 * UNITS);
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), evm_address(), evm_address2()),
+				30u128 * UNITS
+			);
+		});
+	}
+
+	fn permit_data(owner: H160, spender: H160, value: U256, deadline: U256, secret_key: [u8; 32]) -> Vec<u8> {
+		let nonce = EVMAccounts::permit_nonce(native_asset_ethereum_address(), owner);
+		let digest = CurrencyPrecompile::permit_digest(
+			HDX,
+			native_asset_ethereum_address(),
+			owner,
+			spender,
+			value,
+			nonce,
+			deadline,
+		);
+		let secret_key = libsecp256k1::SecretKey::parse(&secret_key).unwrap();
+		let (rs, v) = libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), &secret_key);
+
+		EvmDataWriter::new_with_selector(Function::Permit)
+			.write(Address::from(owner))
+			.write(Address::from(spender))
+			.write(value)
+			.write(deadline)
+			.write(v.serialize())
+			.write(H256::from(rs.r.b32()))
+			.write(H256::from(rs.s.b32()))
+			.build()
+	}
+
+	fn permit_handle(data: Vec<u8>, caller: H160) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: native_asset_ethereum_address(),
+				caller,
+				apparent_value: U256::from(0),
+			},
+			code_address: native_asset_ethereum_address(),
+			is_static: false,
+		}
+	}
+
+	#[test]
+	fn precompile_for_currency_permit_should_set_allowance_and_increase_nonce() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let owner = alith_evm_address();
+			let data = permit_data(
+				owner,
+				evm_address2(),
+				U256::from(50u128 * UNITS),
+				U256::from(1_000_000_000_000u128),
+				alith_secret_key(),
+			);
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut permit_handle(data, evm_address2()));
+
+			//Assert
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), owner, evm_address2()),
+				50u128 * UNITS
+			);
+			assert_eq!(
+				EVMAccounts::permit_nonce(native_asset_ethereum_address(), owner),
+				U256::one()
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_permit_should_set_unlimited_allowance_when_value_is_max_uint256() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let owner = alith_evm_address();
+			let data = permit_data(
+				owner,
+				evm_address2(),
+				U256::MAX,
+				U256::from(1_000_000_000_000u128),
+				alith_secret_key(),
+			);
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut permit_handle(data, evm_address2()));
+
+			//Assert
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), owner, evm_address2()),
+				u128::MAX
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_permit_should_fail_when_signature_is_reused() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = permit_data(
+				alith_evm_address(),
+				evm_address2(),
+				U256::from(50u128 * UNITS),
+				U256::from(1_000_000_000_000u128),
+				alith_secret_key(),
+			);
+			assert_ok!(CurrencyPrecompile::execute(&mut permit_handle(
+				data.clone(),
+				evm_address2()
+			)));
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut permit_handle(data, evm_address2()));
+
+			//Assert
+			assert_eq!(
+				result,
+				Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "Invalid permit signature".into(),
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_permit_should_fail_when_signed_by_other_account() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = permit_data(
+				evm_address2(),
+				alith_evm_address(),
+				U256::from(50u128 * UNITS),
+				U256::from(1_000_000_000_000u128),
+				alith_secret_key(),
+			);
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut permit_handle(data, alith_evm_address()));
+
+			//Assert
+			assert_eq!(
+				result,
+				Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "Invalid permit signature".into(),
+				})
+			);
+			assert_eq!(
+				EVMAccounts::allowance(native_asset_ethereum_address(), evm_address2(), alith_evm_address()),
+				0
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_permit_should_fail_when_deadline_passed() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = permit_data(
+				alith_evm_address(),
+				evm_address2(),
+				U256::from(50u128 * UNITS),
+				U256::from(1_000u128),
+				alith_secret_key(),
+			);
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut permit_handle(data, evm_address2()));
+
+			//Assert
+			assert_eq!(
+				result,
+				Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "Permit expired".into(),
+				})
+			);
+		});
+	}
+
 	fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
 		let payload = (b"evm:", account_id);
 		EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
@@ -1307,6 +1667,428 @@ mod contract_deployment {
 	}
 }
 
+mod dca_precompile {
+	use super::*;
+	use hydradx_runtime::evm::precompiles::{dca, erc20_mapping::HydraErc20Mapping, DCA as DCA_ADDR};
+	use hydradx_runtime::DCA;
+	use hydradx_traits::evm::Erc20Mapping;
+	use pretty_assertions::assert_eq;
+
+	fn dca_handle(data: Vec<u8>) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: DCA_ADDR,
+				caller: evm_address(),
+				apparent_value: U256::from(0),
+			},
+			code_address: DCA_ADDR,
+			is_static: false,
+		}
+	}
+
+	fn schedule_sell_data(total_amount: Balance) -> Vec<u8> {
+		EvmDataWriter::new_with_selector(dca::Function::ScheduleSell)
+			.write(Address::from(HydraErc20Mapping::encode_evm_address(HDX)))
+			.write(Address::from(HydraErc20Mapping::encode_evm_address(DAI)))
+			.write(100 * UNITS)
+			.write(Balance::MIN)
+			.write(total_amount)
+			.write(5u32)
+			.build()
+	}
+
+	#[test]
+	fn schedule_sell_should_create_schedule_owned_by_caller() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				10_000 * UNITS as i128,
+			));
+			let budget = 1_000 * UNITS;
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new()
+				.execute(&mut dca_handle(schedule_sell_data(budget)));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(0u32).build(),
+				}))
+			);
+			let schedule = DCA::schedules(0).unwrap();
+			assert_eq!(schedule.owner, evm_account());
+			assert_eq!(schedule.total_amount, budget);
+			assert_eq!(DCA::remaining_amounts(0), Some(budget));
+		});
+	}
+
+	#[test]
+	fn schedule_sell_should_fail_when_asset_address_is_invalid() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(dca::Function::ScheduleSell)
+				.write(Address::from(evm_address()))
+				.write(Address::from(HydraErc20Mapping::encode_evm_address(DAI)))
+				.write(100 * UNITS)
+				.write(Balance::MIN)
+				.write(1_000 * UNITS)
+				.write(5u32)
+				.build();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new().execute(&mut dca_handle(data));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid asset address".into(),
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn terminate_should_remove_schedule_of_caller() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				10_000 * UNITS as i128,
+			));
+			let initial_balance = Currencies::free_balance(HDX, &evm_account());
+			let precompiles = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new();
+			let result = precompiles.execute(&mut dca_handle(schedule_sell_data(1_000 * UNITS)));
+			assert!(matches!(result, Some(Ok(_))));
+			let (next_execution_block, _) = pallet_dca::ScheduleIdsPerBlock::<hydradx_runtime::Runtime>::iter()
+				.find(|(_, ids)| ids.contains(&0))
+				.unwrap();
+
+			//Act
+			let data = EvmDataWriter::new_with_selector(dca::Function::Terminate)
+				.write(0u32)
+				.write(next_execution_block)
+				.build();
+			let result = precompiles.execute(&mut dca_handle(data));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(true).build(),
+				}))
+			);
+			assert!(DCA::schedules(0).is_none());
+			assert_balance!(evm_account(), HDX, initial_balance);
+		});
+	}
+}
+
+mod router_precompile {
+	use super::*;
+	use hydradx_runtime::evm::precompiles::{erc20_mapping::HydraErc20Mapping, router, ROUTER};
+	use hydradx_traits::evm::Erc20Mapping;
+	use pretty_assertions::assert_eq;
+
+	fn router_handle(data: Vec<u8>) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: ROUTER,
+				caller: evm_address(),
+				apparent_value: U256::from(0),
+			},
+			code_address: ROUTER,
+			is_static: false,
+		}
+	}
+
+	fn asset_address(asset_id: AssetId) -> Address {
+		Address::from(HydraErc20Mapping::encode_evm_address(asset_id))
+	}
+
+	fn omnipool_hop(asset_in: AssetId, asset_out: AssetId) -> router::Hop {
+		router::Hop {
+			pool: router::POOL_OMNIPOOL,
+			pool_id: 0,
+			asset_in: asset_address(asset_in),
+			asset_out: asset_address(asset_out),
+		}
+	}
+
+	#[test]
+	fn sell_should_trade_on_behalf_of_caller_and_return_amount_out() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				1_000 * UNITS as i128,
+			));
+			let initial_hdx_balance = Currencies::free_balance(HDX, &evm_account());
+			let initial_dai_balance = Currencies::free_balance(DAI, &evm_account());
+			let data = EvmDataWriter::new_with_selector(router::Function::Sell)
+				.write(asset_address(HDX))
+				.write(asset_address(DAI))
+				.write(100 * UNITS)
+				.write(Balance::MIN)
+				.write(vec![omnipool_hop(HDX, DAI)])
+				.build();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new().execute(&mut router_handle(data));
+
+			//Assert
+			let received = Currencies::free_balance(DAI, &evm_account()) - initial_dai_balance;
+			assert!(received > 0);
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(received).build(),
+				}))
+			);
+			assert_balance!(evm_account(), HDX, initial_hdx_balance - 100 * UNITS);
+		});
+	}
+
+	#[test]
+	fn buy_should_trade_on_behalf_of_caller_and_return_amount_in() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				DAI,
+				1_000 * UNITS as i128 * 1_000_000,
+			));
+			let initial_dai_balance = Currencies::free_balance(DAI, &evm_account());
+			let initial_hdx_balance = Currencies::free_balance(HDX, &evm_account());
+			let data = EvmDataWriter::new_with_selector(router::Function::Buy)
+				.write(asset_address(DAI))
+				.write(asset_address(HDX))
+				.write(10 * UNITS)
+				.write(initial_dai_balance)
+				.write(vec![omnipool_hop(DAI, HDX)])
+				.build();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new().execute(&mut router_handle(data));
+
+			//Assert
+			let spent = initial_dai_balance - Currencies::free_balance(DAI, &evm_account());
+			assert!(spent > 0);
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(spent).build(),
+				}))
+			);
+			assert_balance!(evm_account(), HDX, initial_hdx_balance + 10 * UNITS);
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_when_pool_type_is_invalid() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let mut hop = omnipool_hop(HDX, DAI);
+			hop.pool = 10;
+			let data = EvmDataWriter::new_with_selector(router::Function::Sell)
+				.write(asset_address(HDX))
+				.write(asset_address(DAI))
+				.write(100 * UNITS)
+				.write(Balance::MIN)
+				.write(vec![hop])
+				.build();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new().execute(&mut router_handle(data));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid pool type".into(),
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_when_min_amount_out_is_not_reached() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				1_000 * UNITS as i128,
+			));
+			let initial_hdx_balance = Currencies::free_balance(HDX, &evm_account());
+			let data = EvmDataWriter::new_with_selector(router::Function::Sell)
+				.write(asset_address(HDX))
+				.write(asset_address(DAI))
+				.write(100 * UNITS)
+				.write(Balance::MAX)
+				.write(vec![omnipool_hop(HDX, DAI)])
+				.build();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new().execute(&mut router_handle(data));
+
+			//Assert
+			assert!(matches!(result, Some(Err(PrecompileFailure::Revert { .. }))));
+			assert_balance!(evm_account(), HDX, initial_hdx_balance);
+		});
+	}
+}
+
+mod ema_oracle_precompile {
+	use super::*;
+	use hydradx_runtime::evm::precompiles::{ema_oracle, erc20_mapping::HydraErc20Mapping, EMA_ORACLE};
+	use hydradx_runtime::EmaOracle;
+	use hydradx_traits::{evm::Erc20Mapping, AggregatedEntry, AggregatedOracle, OraclePeriod, Source};
+	use pallet_ema_oracle::ordered_pair;
+	use pretty_assertions::assert_eq;
+	use primitives::constants::chain::OMNIPOOL_SOURCE;
+
+	fn oracle_handle(data: Vec<u8>) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: EMA_ORACLE,
+				caller: evm_address(),
+				apparent_value: U256::from(0),
+			},
+			code_address: EMA_ORACLE,
+			is_static: true,
+		}
+	}
+
+	fn get_price_data(source: Source, asset_a: AssetId, asset_b: AssetId, period: u8) -> Vec<u8> {
+		let mut encoded_source = [0u8; 32];
+		encoded_source[..8].copy_from_slice(&source);
+		EvmDataWriter::new_with_selector(ema_oracle::Function::GetPrice)
+			.write(H256(encoded_source))
+			.write(Address::from(HydraErc20Mapping::encode_evm_address(asset_a)))
+			.write(Address::from(HydraErc20Mapping::encode_evm_address(asset_b)))
+			.write(period)
+			.build()
+	}
+
+	#[test]
+	fn get_price_should_return_price_and_liquidity_of_oracle() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			hydradx_run_to_next_block();
+			let AggregatedEntry { price, liquidity, .. } =
+				EmaOracle::get_entry(DOT, LRNA, OraclePeriod::Short, OMNIPOOL_SOURCE).unwrap();
+			let (entry, _) =
+				EmaOracle::oracle((OMNIPOOL_SOURCE, ordered_pair(DOT, LRNA), OraclePeriod::Short)).unwrap();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new()
+				.execute(&mut oracle_handle(get_price_data(OMNIPOOL_SOURCE, DOT, LRNA, 1)));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new()
+						.write(price.n)
+						.write(price.d)
+						.write(entry.updated_at)
+						.write(liquidity.a)
+						.write(liquidity.b)
+						.build(),
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn get_price_should_fail_when_oracle_does_not_exist() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			hydradx_run_to_next_block();
+
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new()
+				.execute(&mut oracle_handle(get_price_data(OMNIPOOL_SOURCE, DOT, DAI, 1)));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "oracle not available".into(),
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn get_price_should_fail_when_period_is_invalid() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Act
+			let result = HydraDXPrecompiles::<hydradx_runtime::Runtime>::new()
+				.execute(&mut oracle_handle(get_price_data(OMNIPOOL_SOURCE, DOT, LRNA, 6)));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid period".into(),
+				}))
+			);
+		});
+	}
+}
+
 #[test]
 fn dispatch_should_work_with_remark() {
 	TestNet::reset();
@@ -1525,7 +2307,8 @@ fn dispatch_should_respect_call_filter() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::root(),
 			b"Tokens".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert!(!CallFilter::contains(&transfer_call));
 
@@ -1950,11 +2733,11 @@ impl PrecompileHandle for MockHandle {
 	fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
 
 	fn remaining_gas(&self) -> u64 {
-		unimplemented!()
+		u64::MAX
 	}
 
 	fn log(&mut self, _: H160, _: Vec<H256>, _: Vec<u8>) -> Result<(), ExitError> {
-		unimplemented!()
+		Ok(())
 	}
 
 	fn code_address(&self) -> H160 {
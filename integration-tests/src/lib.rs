@@ -25,13 +25,22 @@ mod omnipool_init;
 mod omnipool_liquidity_mining;
 mod oracle;
 mod otc;
+mod parameters;
 mod polkadot_test_net;
+mod portfolio;
+mod price_monitor;
+mod proxy;
 mod referrals;
+mod remote_intents;
+mod revenue_stats;
 mod router;
+mod scenario;
 mod staking;
 mod transact_call_filter;
 pub mod utils;
 mod vesting;
+mod xcm_fee_sponsor;
+mod xcm_rate_limiter;
 mod xyk;
 mod xyk_liquidity_mining;
 
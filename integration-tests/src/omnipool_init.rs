@@ -318,3 +318,99 @@ fn removing_token_should_work_when_no_shares_remaining() {
 		assert_eq!(lrna_balance, 0);
 	});
 }
+
+#[test]
+fn add_liquidity_from_should_add_liquidity_of_asset_received_from_router() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+
+		//NOTE: necessary to get oracle price.
+		hydradx_run_to_block(10);
+
+		let charlie: AccountId = CHARLIE.into();
+		let dai_amount = 100 * UNITS * 1_000_000;
+		let charlie_dai = hydradx_runtime::Tokens::free_balance(DAI, &charlie);
+		let charlie_hdx = hydradx_runtime::Balances::free_balance(&charlie);
+		let position_id = hydradx_runtime::Omnipool::next_position_id();
+
+		assert_ok!(hydradx_runtime::Omnipool::add_liquidity_from(
+			hydradx_runtime::RuntimeOrigin::signed(charlie.clone()),
+			DAI,
+			dai_amount,
+			HDX,
+			0,
+		));
+
+		let position = hydradx_runtime::Omnipool::positions(position_id).unwrap();
+		assert_eq!(position.asset_id, HDX);
+		assert!(position.amount > 0);
+
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(DAI, &charlie),
+			charlie_dai - dai_amount
+		);
+		assert_eq!(hydradx_runtime::Balances::free_balance(&charlie), charlie_hdx);
+	});
+}
+
+#[test]
+fn omnipool_assets_should_be_added_at_genesis_when_built_by_genesis_builder() {
+	let native_amount = 936_329_588_000_000_000;
+	let stable_amount = 50_000 * UNITS * 1_000_000;
+	let native_price = FixedU128::from_inner(1201500000000000);
+	let stable_price = FixedU128::from_inner(45_000_000_000);
+
+	let storage = hydradx_runtime::genesis::GenesisBuilder::new(HYDRA_PARA_ID)
+		.with_collators(collators::invulnerables(), 2 * UNITS)
+		.with_registered_asset(Some(LRNA), Some(b"LRNA"), 1_000u128, true)
+		.with_registered_asset(Some(DAI), Some(b"DAI"), 1_000u128, true)
+		.with_omnipool_asset(
+			HDX,
+			native_amount,
+			native_price,
+			Permill::from_percent(10),
+			AccountId::from(ALICE),
+		)
+		.with_omnipool_asset(
+			DAI,
+			stable_amount,
+			stable_price,
+			Permill::from_percent(100),
+			AccountId::from(ALICE),
+		)
+		.build_storage()
+		.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		let omnipool_account = hydradx_runtime::Omnipool::protocol_account();
+
+		assert_eq!(
+			hydradx_runtime::Balances::free_balance(&omnipool_account),
+			native_amount
+		);
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(DAI, &omnipool_account),
+			stable_amount
+		);
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(LRNA, &omnipool_account),
+			3374999999982000
+		);
+
+		let hdx_state = hydradx_runtime::Omnipool::load_asset_state(HDX).unwrap();
+		assert_eq!(hdx_state.reserve, native_amount);
+		assert_eq!(
+			hdx_state.hub_reserve,
+			native_price.checked_mul_int(native_amount).unwrap()
+		);
+
+		let dai_state = hydradx_runtime::Omnipool::load_asset_state(DAI).unwrap();
+		assert_eq!(dai_state.reserve, stable_amount);
+		assert_eq!(
+			dai_state.hub_reserve,
+			stable_price.checked_mul_int(stable_amount).unwrap()
+		);
+	});
+}
@@ -18,7 +18,7 @@
 #![cfg(test)]
 use crate::polkadot_test_net::*;
 
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use hydradx_traits::liquidity_mining::PriceAdjustment;
 use warehouse_liquidity_mining::{
 	DefaultPriceAdjustment, DepositData, GlobalFarmData, GlobalFarmId, Instance1, LoyaltyCurve, YieldFarmData,
@@ -202,9 +202,71 @@ fn deposit_shares_should_work_when_yield_farm_exists() {
 
 		//assert LM deposit
 		assert_nft_owner!(hydradx_runtime::OmnipoolLMCollectionId::get(), 1, CHARLIE.into());
-		//original position owner should be palelt account
-		let lm_account = hydradx_runtime::OmnipoolLiquidityMining::account_id();
-		assert_nft_owner!(hydradx_runtime::OmnipoolCollectionId::get(), position_id, lm_account);
+		//original position should stay owned by the user and be frozen
+		assert_nft_owner!(
+			hydradx_runtime::OmnipoolCollectionId::get(),
+			position_id,
+			CHARLIE.into()
+		);
+		assert!(hydradx_runtime::Omnipool::is_position_frozen(position_id));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_work_when_position_is_deposited() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		//Arrange
+		init_omnipool();
+
+		//NOTE: necessary to get oracle price.
+		hydradx_run_to_block(100);
+		set_relaychain_block_number(100);
+		create_global_farm(None, None);
+
+		set_relaychain_block_number(200);
+		create_yield_farm(global_farm_id, ETH);
+
+		set_relaychain_block_number(300);
+
+		assert_ok!(hydradx_runtime::Currencies::update_balance(
+			hydradx_runtime::RuntimeOrigin::root(),
+			CHARLIE.into(),
+			ETH,
+			10_000 * UNITS as i128,
+		));
+
+		let position_id = omnipool_add_liquidity(CHARLIE.into(), ETH, 1_000 * UNITS);
+
+		set_relaychain_block_number(400);
+		assert_ok!(hydradx_runtime::OmnipoolLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			global_farm_id,
+			yield_farm_id,
+			position_id
+		));
+
+		//Act & assert
+		assert_noop!(
+			hydradx_runtime::Omnipool::remove_liquidity(RuntimeOrigin::signed(CHARLIE.into()), position_id, UNITS),
+			pallet_omnipool::Error::<hydradx_runtime::Runtime>::PositionFrozen
+		);
+
+		assert_ok!(hydradx_runtime::OmnipoolLiquidityMining::withdraw_shares(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			1,
+			yield_farm_id
+		));
+		assert!(!hydradx_runtime::Omnipool::is_position_frozen(position_id));
+		assert_ok!(hydradx_runtime::Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			position_id,
+			UNITS
+		));
 	});
 }
 
@@ -354,9 +416,13 @@ fn join_farms_should_work_for_multiple_farms() {
 			position_id
 		));
 
-		//Assert that the ownership of the nft should be transferred to omnipool account
-		let lm_account = hydradx_runtime::OmnipoolLiquidityMining::account_id();
-		assert_nft_owner!(hydradx_runtime::OmnipoolCollectionId::get(), position_id, lm_account);
+		//original position should stay owned by the user and be frozen
+		assert_nft_owner!(
+			hydradx_runtime::OmnipoolCollectionId::get(),
+			position_id,
+			CHARLIE.into()
+		);
+		assert!(hydradx_runtime::Omnipool::is_position_frozen(position_id));
 
 		set_relaychain_block_number(500);
 
@@ -464,9 +530,13 @@ fn add_liquidity_and_join_farms_should_work_for_multiple_farms() {
 			1_000 * UNITS,
 		));
 
-		//Assert that the ownership of the nft should be transferred to omnipool account
-		let lm_account = hydradx_runtime::OmnipoolLiquidityMining::account_id();
-		assert_nft_owner!(hydradx_runtime::OmnipoolCollectionId::get(), position_id, lm_account);
+		//original position should stay owned by the user and be frozen
+		assert_nft_owner!(
+			hydradx_runtime::OmnipoolCollectionId::get(),
+			position_id,
+			CHARLIE.into()
+		);
+		assert!(hydradx_runtime::Omnipool::is_position_frozen(position_id));
 
 		set_relaychain_block_number(500);
 
@@ -602,9 +672,13 @@ fn withdraw_shares_should_work_when_deposit_exists() {
 		//yield-farm-entry left)
 		//assert LM deposit
 		assert_nft_owner!(hydradx_runtime::OmnipoolLMCollectionId::get(), 1, CHARLIE.into());
-		//original position owner should be palelt account
-		let lm_account = hydradx_runtime::OmnipoolLiquidityMining::account_id();
-		assert_nft_owner!(hydradx_runtime::OmnipoolCollectionId::get(), position_id, lm_account);
+		//original position should stay owned by the user and be frozen
+		assert_nft_owner!(
+			hydradx_runtime::OmnipoolCollectionId::get(),
+			position_id,
+			CHARLIE.into()
+		);
+		assert!(hydradx_runtime::Omnipool::is_position_frozen(position_id));
 
 		//Check if yield-farm-entry was removed from the deposit.
 		assert!(
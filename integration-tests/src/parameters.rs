@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use hydradx_runtime::{
+	dynamic_params::{circuit_breaker, dca, otc},
+	CircuitBreaker, Parameters, Runtime, RuntimeOrigin, RuntimeParameters,
+};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+use xcm_emulator::TestExt;
+
+fn otc_fee_parameter(fee: Option<Permill>) -> RuntimeParameters {
+	RuntimeParameters::Otc(otc::Parameters::Fee(otc::Fee, fee))
+}
+
+#[test]
+fn parameters_should_have_previous_constant_values_when_not_set() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		assert_eq!(
+			<Runtime as pallet_dca::Config>::FeeMultiplierForMinTradeLimit::get(),
+			20
+		);
+		assert_eq!(
+			<Runtime as pallet_otc::Config>::Fee::get(),
+			Permill::from_rational(1u32, 1_000u32)
+		);
+		assert_eq!(
+			<Runtime as pallet_circuit_breaker::Config>::DefaultMaxNetTradeVolumeLimitPerBlock::get(),
+			(5_000, 10_000)
+		);
+		assert_eq!(
+			<Runtime as pallet_circuit_breaker::Config>::DefaultMaxAddLiquidityLimitPerBlock::get(),
+			Some((500, 10_000))
+		);
+		assert_eq!(
+			<Runtime as pallet_circuit_breaker::Config>::DefaultMaxRemoveLiquidityLimitPerBlock::get(),
+			Some((500, 10_000))
+		);
+		assert_eq!(
+			<Runtime as pallet_circuit_breaker::Config>::DefaultMaxAccountTradeVolumeLimitPerBlock::get(),
+			None
+		);
+	});
+}
+
+#[test]
+fn set_parameter_should_update_otc_fee() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Act
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			otc_fee_parameter(Some(Permill::from_percent(1)))
+		));
+
+		//Assert
+		assert_eq!(<Runtime as pallet_otc::Config>::Fee::get(), Permill::from_percent(1));
+
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			otc_fee_parameter(None)
+		));
+		assert_eq!(
+			<Runtime as pallet_otc::Config>::Fee::get(),
+			Permill::from_rational(1u32, 1_000u32)
+		);
+	});
+}
+
+#[test]
+fn set_parameter_should_update_default_trade_volume_limit() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Act
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::CircuitBreaker(circuit_breaker::Parameters::DefaultMaxNetTradeVolumeLimitPerBlock(
+				circuit_breaker::DefaultMaxNetTradeVolumeLimitPerBlock,
+				Some((2_000, 10_000)),
+			))
+		));
+
+		//Assert
+		assert_eq!(CircuitBreaker::trade_volume_limit_per_asset(DAI), (2_000, 10_000));
+	});
+}
+
+#[test]
+fn set_parameter_should_update_dca_parameters() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Act
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::Dca(dca::Parameters::FeeMultiplierForMinTradeLimit(
+				dca::FeeMultiplierForMinTradeLimit,
+				Some(10),
+			))
+		));
+
+		//Assert
+		assert_eq!(
+			<Runtime as pallet_dca::Config>::FeeMultiplierForMinTradeLimit::get(),
+			10
+		);
+	});
+}
+
+#[test]
+fn set_parameter_should_fail_when_origin_is_not_authorized() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		assert_noop!(
+			Parameters::set_parameter(
+				RuntimeOrigin::signed(ALICE.into()),
+				otc_fee_parameter(Some(Permill::from_percent(1)))
+			),
+			BadOrigin
+		);
+	});
+}
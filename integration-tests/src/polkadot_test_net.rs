@@ -15,7 +15,7 @@ use cumulus_primitives_core::ParaId;
 use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
 pub use frame_system::RawOrigin;
 use hex_literal::hex;
-use hydradx_runtime::{evm::WETH_ASSET_LOCATION, Referrals, RuntimeOrigin};
+use hydradx_runtime::{evm::WETH_ASSET_LOCATION, genesis::GenesisBuilder, Referrals, RuntimeOrigin};
 pub use hydradx_traits::{evm::InspectEvmAccounts, registry::Mutate};
 use pallet_referrals::{FeeDistribution, Level};
 pub use polkadot_primitives::v7::{BlockNumber, MAX_CODE_SIZE, MAX_POV_SIZE};
@@ -438,173 +438,51 @@ pub mod hydra {
 		let omnipool_account = hydradx_runtime::Omnipool::protocol_account();
 		let staking_account = pallet_staking::Pallet::<hydradx_runtime::Runtime>::pot_account_id();
 
-		let existential_deposit = NativeExistentialDeposit::get();
-
-		let genesis_config = hydradx_runtime::RuntimeGenesisConfig {
-			balances: hydradx_runtime::BalancesConfig {
-				balances: vec![
-					(AccountId::from(ALICE), ALICE_INITIAL_NATIVE_BALANCE),
-					(AccountId::from(BOB), BOB_INITIAL_NATIVE_BALANCE),
-					(AccountId::from(CHARLIE), CHARLIE_INITIAL_NATIVE_BALANCE),
-					(AccountId::from(DAVE), 1_000 * UNITS),
-					(omnipool_account.clone(), native_amount),
-					(vesting_account(), 10_000 * UNITS),
-					(staking_account, UNITS),
-				],
-			},
-			collator_selection: hydradx_runtime::CollatorSelectionConfig {
-				invulnerables: collators::invulnerables().iter().cloned().map(|(acc, _)| acc).collect(),
-				candidacy_bond: 2 * UNITS,
-				..Default::default()
-			},
-			session: hydradx_runtime::SessionConfig {
-				keys: collators::invulnerables()
-					.into_iter()
-					.map(|(acc, aura)| {
-						(
-							acc.clone(),                                   // account id
-							acc,                                           // validator id
-							hydradx_runtime::opaque::SessionKeys { aura }, // session keys
-						)
-					})
-					.collect(),
-			},
-			asset_registry: hydradx_runtime::AssetRegistryConfig {
-				registered_assets: vec![
-					(
-						Some(LRNA),
-						Some(b"LRNA".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(DAI),
-						Some(b"DAI".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(DOT),
-						Some(b"DOT".to_vec().try_into().unwrap()),
-						1_000_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(ETH),
-						Some(b"ETH".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(BTC),
-						Some(b"BTC".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(ACA),
-						Some(b"ACA".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(WETH),
-						Some(b"WETH".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(
-						Some(PEPE),
-						Some(b"PEPE".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						true,
-					),
-					(Some(INSUFFICIENT_ASSET), None, 1_000u128, None, None, None, false),
-					// workaround for next_asset_id() to return correct values
-					(
-						None,
-						Some(b"DUMMY".to_vec().try_into().unwrap()),
-						1_000u128,
-						None,
-						None,
-						None,
-						false,
-					),
-				],
-				native_asset_name: b"HDX".to_vec().try_into().unwrap(),
-				native_existential_deposit: existential_deposit,
-				native_symbol: b"HDX".to_vec().try_into().unwrap(),
-				native_decimals: 12,
-			},
-			parachain_info: hydradx_runtime::ParachainInfoConfig {
-				parachain_id: HYDRA_PARA_ID.into(),
-				..Default::default()
-			},
-			tokens: hydradx_runtime::TokensConfig {
-				balances: vec![
-					(AccountId::from(ALICE), LRNA, ALICE_INITIAL_LRNA_BALANCE),
-					(AccountId::from(ALICE), DAI, ALICE_INITIAL_DAI_BALANCE),
-					(AccountId::from(ALICE), DOT, ALICE_INITIAL_DOT_BALANCE),
-					(AccountId::from(BOB), LRNA, BOB_INITIAL_LRNA_BALANCE),
-					(AccountId::from(BOB), DAI, BOB_INITIAL_DAI_BALANCE),
-					(AccountId::from(BOB), BTC, 1_000_000),
-					(AccountId::from(BOB), PEPE, 1_000 * UNITS * 1_000_000),
-					(AccountId::from(CHARLIE), LRNA, CHARLIE_INITIAL_LRNA_BALANCE),
-					(AccountId::from(CHARLIE), DAI, 80_000_000_000 * UNITS),
-					(AccountId::from(DAVE), LRNA, 1_000 * UNITS),
-					(AccountId::from(DAVE), DAI, 1_000_000_000 * UNITS),
-					(omnipool_account.clone(), DAI, stable_amount),
-					(omnipool_account.clone(), ETH, eth_amount),
-					(omnipool_account.clone(), BTC, btc_amount),
-					(omnipool_account, DOT, dot_amount),
-				],
-			},
-			polkadot_xcm: hydradx_runtime::PolkadotXcmConfig {
-				safe_xcm_version: Some(3),
-				..Default::default()
-			},
-			multi_transaction_payment: hydradx_runtime::MultiTransactionPaymentConfig {
-				currencies: vec![
-					(LRNA, Price::from(1)),
-					(DAI, Price::from(1)),
-					(ACA, Price::from(1)),
-					(BTC, Price::from_inner(134_000_000)),
-					(WETH, pallet_dynamic_evm_fee::ETH_HDX_REFERENCE_PRICE),
-				],
-				account_currencies: vec![],
-			},
-			duster: hydradx_runtime::DusterConfig {
-				account_blacklist: vec![Treasury::account_id()],
-				reward_account: Some(Treasury::account_id()),
-				dust_account: Some(Treasury::account_id()),
-			},
-			..Default::default()
-		};
-		genesis_config.build_storage().unwrap()
+		GenesisBuilder::new(HYDRA_PARA_ID)
+			.with_collators(collators::invulnerables(), 2 * UNITS)
+			.with_registered_asset(Some(LRNA), Some(b"LRNA"), 1_000u128, true)
+			.with_registered_asset(Some(DAI), Some(b"DAI"), 1_000u128, true)
+			.with_registered_asset(Some(DOT), Some(b"DOT"), 1_000_000u128, true)
+			.with_registered_asset(Some(ETH), Some(b"ETH"), 1_000u128, true)
+			.with_registered_asset(Some(BTC), Some(b"BTC"), 1_000u128, true)
+			.with_registered_asset(Some(ACA), Some(b"ACA"), 1_000u128, true)
+			.with_registered_asset(Some(WETH), Some(b"WETH"), 1_000u128, true)
+			.with_registered_asset(Some(PEPE), Some(b"PEPE"), 1_000u128, true)
+			.with_registered_asset(Some(INSUFFICIENT_ASSET), None, 1_000u128, false)
+			// workaround for next_asset_id() to return correct values
+			.with_registered_asset(None, Some(b"DUMMY"), 1_000u128, false)
+			.with_balances(vec![
+				(AccountId::from(ALICE), HDX, ALICE_INITIAL_NATIVE_BALANCE),
+				(AccountId::from(BOB), HDX, BOB_INITIAL_NATIVE_BALANCE),
+				(AccountId::from(CHARLIE), HDX, CHARLIE_INITIAL_NATIVE_BALANCE),
+				(AccountId::from(DAVE), HDX, 1_000 * UNITS),
+				(omnipool_account.clone(), HDX, native_amount),
+				(vesting_account(), HDX, 10_000 * UNITS),
+				(staking_account, HDX, UNITS),
+				(hydradx_runtime::Omnipool::fee_account(), HDX, UNITS),
+				(AccountId::from(ALICE), LRNA, ALICE_INITIAL_LRNA_BALANCE),
+				(AccountId::from(ALICE), DAI, ALICE_INITIAL_DAI_BALANCE),
+				(AccountId::from(ALICE), DOT, ALICE_INITIAL_DOT_BALANCE),
+				(AccountId::from(BOB), LRNA, BOB_INITIAL_LRNA_BALANCE),
+				(AccountId::from(BOB), DAI, BOB_INITIAL_DAI_BALANCE),
+				(AccountId::from(BOB), BTC, 1_000_000),
+				(AccountId::from(BOB), PEPE, 1_000 * UNITS * 1_000_000),
+				(AccountId::from(CHARLIE), LRNA, CHARLIE_INITIAL_LRNA_BALANCE),
+				(AccountId::from(CHARLIE), DAI, 80_000_000_000 * UNITS),
+				(AccountId::from(DAVE), LRNA, 1_000 * UNITS),
+				(AccountId::from(DAVE), DAI, 1_000_000_000 * UNITS),
+				(omnipool_account.clone(), DAI, stable_amount),
+				(omnipool_account.clone(), ETH, eth_amount),
+				(omnipool_account.clone(), BTC, btc_amount),
+				(omnipool_account, DOT, dot_amount),
+			])
+			.with_fee_currency(LRNA, Price::from(1))
+			.with_fee_currency(DAI, Price::from(1))
+			.with_fee_currency(ACA, Price::from(1))
+			.with_fee_currency(BTC, Price::from_inner(134_000_000))
+			.with_fee_currency(WETH, pallet_dynamic_evm_fee::ETH_HDX_REFERENCE_PRICE)
+			.build_storage()
+			.unwrap()
 	}
 }
 
@@ -612,44 +490,11 @@ pub mod para {
 	use super::*;
 
 	pub fn genesis(para_id: u32) -> Storage {
-		let genesis_config = hydradx_runtime::RuntimeGenesisConfig {
-			balances: hydradx_runtime::BalancesConfig {
-				balances: vec![(AccountId::from(ALICE), ALICE_INITIAL_NATIVE_BALANCE)],
-			},
-			collator_selection: hydradx_runtime::CollatorSelectionConfig {
-				invulnerables: collators::invulnerables().iter().cloned().map(|(acc, _)| acc).collect(),
-				candidacy_bond: UNITS * 16,
-				..Default::default()
-			},
-			session: hydradx_runtime::SessionConfig {
-				keys: collators::invulnerables()
-					.into_iter()
-					.map(|(acc, aura)| {
-						(
-							acc.clone(),                                   // account id
-							acc,                                           // validator id
-							hydradx_runtime::opaque::SessionKeys { aura }, // session keys
-						)
-					})
-					.collect(),
-			},
-			parachain_info: hydradx_runtime::ParachainInfoConfig {
-				parachain_id: para_id.into(),
-				..Default::default()
-			},
-			polkadot_xcm: hydradx_runtime::PolkadotXcmConfig {
-				safe_xcm_version: Some(3),
-				..Default::default()
-			},
-			duster: hydradx_runtime::DusterConfig {
-				account_blacklist: vec![Treasury::account_id()],
-				reward_account: Some(Treasury::account_id()),
-				dust_account: Some(Treasury::account_id()),
-			},
-			..Default::default()
-		};
-
-		genesis_config.build_storage().unwrap()
+		GenesisBuilder::new(para_id)
+			.with_collators(collators::invulnerables(), UNITS * 16)
+			.with_balance(AccountId::from(ALICE), HDX, ALICE_INITIAL_NATIVE_BALANCE)
+			.build_storage()
+			.unwrap()
 	}
 }
 
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::assert_ok;
+use hydradx_runtime::{Balances, Currencies, Omnipool, RuntimeOrigin, Staking};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use primitives::constants::currency::UNITS;
+use xcm_emulator::TestExt;
+
+#[test]
+fn account_portfolio_should_contain_token_balances() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let portfolio = hydradx_runtime::portfolio::account_portfolio(ALICE.into());
+
+		let (_, hdx) = portfolio
+			.balances
+			.iter()
+			.find(|(asset_id, _)| *asset_id == HDX)
+			.unwrap();
+		assert_eq!(hdx.free, Balances::free_balance(AccountId::from(ALICE)));
+		let (_, dai) = portfolio
+			.balances
+			.iter()
+			.find(|(asset_id, _)| *asset_id == DAI)
+			.unwrap();
+		assert_eq!(dai.free, Currencies::free_balance(DAI, &AccountId::from(ALICE)));
+		assert!(portfolio.omnipool_positions.is_empty());
+		assert!(portfolio.staking.is_none());
+	});
+}
+
+#[test]
+fn account_portfolio_should_contain_omnipool_positions_with_value() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipool();
+		//NOTE: necessary to get oracle price.
+		hydradx_run_to_block(100);
+
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			CHARLIE.into(),
+			ETH,
+			10_000 * UNITS as i128,
+		));
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			ETH,
+			1_000 * UNITS
+		));
+
+		// Act
+		let portfolio = hydradx_runtime::portfolio::account_portfolio(CHARLIE.into());
+
+		// Assert
+		assert_eq!(portfolio.omnipool_positions.len(), 1);
+		let position = &portfolio.omnipool_positions[0];
+		assert_eq!(position.position_id, position_id);
+		assert_eq!(position.asset_id, ETH);
+		assert_eq!(position.amount, 1_000 * UNITS);
+		assert_eq!(
+			(position.value, position.hub_value),
+			Omnipool::position_value(position_id).unwrap()
+		);
+	});
+}
+
+#[test]
+fn account_portfolio_should_contain_staking_position() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		assert_ok!(Staking::initialize_staking(RuntimeOrigin::root()));
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			1_000_000 * UNITS,
+		));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(ALICE.into()), 1_000 * UNITS));
+
+		// Act
+		let portfolio = hydradx_runtime::portfolio::account_portfolio(ALICE.into());
+
+		// Assert
+		let staking = portfolio.staking.unwrap();
+		assert_eq!(staking.stake, 1_000 * UNITS);
+		assert_eq!(staking.locked, 1_000 * UNITS);
+	});
+}
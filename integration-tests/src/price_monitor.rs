@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::pallet_prelude::{
+	InvalidTransaction, TransactionSource, TransactionValidityError, ValidateUnsigned,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_runtime::{Omnipool, PriceMonitor, Runtime, RuntimeOrigin};
+use hydradx_traits::price::PriceDivergenceProvider;
+use sp_runtime::Permill;
+use xcm_emulator::TestExt;
+
+fn init_omnipool_with_oracle() {
+	init_omnipool();
+	assert_ok!(Omnipool::sell(RuntimeOrigin::signed(BOB.into()), DAI, HDX, UNITS, 0));
+	hydradx_run_to_block(10);
+	assert_ok!(PriceMonitor::set_threshold(
+		RuntimeOrigin::root(),
+		DAI,
+		Some(Permill::from_percent(1))
+	));
+}
+
+fn move_dai_spot_price() {
+	assert_ok!(Omnipool::sell(
+		RuntimeOrigin::signed(BOB.into()),
+		DAI,
+		HDX,
+		500_000_000 * UNITS,
+		0
+	));
+}
+
+#[test]
+fn report_divergence_should_store_report_when_spot_price_diverges_from_oracle_price() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool_with_oracle();
+		move_dai_spot_price();
+
+		//Act
+		assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DAI));
+
+		//Assert
+		let report = PriceMonitor::divergence_report(DAI).unwrap();
+		assert!(report.spot_price < report.oracle_price);
+		assert!(report.divergence > Permill::from_percent(1));
+		assert_eq!(report.reported_at, 10);
+		assert_eq!(
+			<PriceMonitor as PriceDivergenceProvider<_>>::divergence(DAI),
+			Some(report.divergence)
+		);
+	});
+}
+
+#[test]
+fn report_divergence_should_fail_when_spot_price_follows_oracle_price() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		init_omnipool_with_oracle();
+
+		assert_noop!(
+			PriceMonitor::report_divergence(RuntimeOrigin::none(), DAI),
+			pallet_price_monitor::Error::<Runtime>::NothingToReport
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_should_accept_local_report_only_when_spot_price_diverges() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool_with_oracle();
+		let call = pallet_price_monitor::Call::report_divergence { asset_id: DAI };
+		assert_eq!(
+			PriceMonitor::validate_unsigned(TransactionSource::Local, &call),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+		);
+
+		//Act
+		move_dai_spot_price();
+
+		//Assert
+		assert_ok!(PriceMonitor::validate_unsigned(TransactionSource::Local, &call));
+		assert_eq!(
+			PriceMonitor::validate_unsigned(TransactionSource::External, &call),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+	});
+}
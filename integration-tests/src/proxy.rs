@@ -0,0 +1,355 @@
+#![cfg(test)]
+
+use crate::assert_balance;
+use crate::polkadot_test_net::*;
+use frame_support::{assert_ok, traits::InstanceFilter};
+use hydradx_runtime::{Balances, Currencies, Proxy, ProxyType, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, DCA};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::MultiCurrency;
+use pallet_dca::types::{ExecutionMode, Order, Schedule, TerminationMode};
+use primitives::constants::currency::UNITS;
+use sp_runtime::Permill;
+use xcm_emulator::TestExt;
+
+fn dca_schedule(execution: ExecutionMode) -> Schedule<AccountId, AssetId, BlockNumber> {
+	Schedule {
+		owner: ALICE.into(),
+		period: 5u32,
+		total_amount: 1_000 * UNITS,
+		max_retries: None,
+		stability_threshold: None,
+		slippage: Some(Permill::from_percent(5)),
+		order: Order::Sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 100 * UNITS,
+			min_amount_out: 0,
+			route: vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}]
+			.try_into()
+			.unwrap(),
+		},
+		termination: TerminationMode::Standard,
+		time_period: None,
+		execution,
+	}
+}
+
+fn trading_calls() -> Vec<RuntimeCall> {
+	let route = vec![Trade {
+		pool: PoolType::Omnipool,
+		asset_in: HDX,
+		asset_out: DAI,
+	}];
+
+	vec![
+		RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount: UNITS,
+			min_buy_amount: 0,
+		}),
+		RuntimeCall::Omnipool(pallet_omnipool::Call::buy {
+			asset_out: DAI,
+			asset_in: HDX,
+			amount: UNITS,
+			max_sell_amount: u128::MAX,
+		}),
+		RuntimeCall::Stableswap(pallet_stableswap::Call::sell {
+			pool_id: 100,
+			asset_in: DAI,
+			asset_out: DOT,
+			amount_in: UNITS,
+			min_buy_amount: 0,
+		}),
+		RuntimeCall::Stableswap(pallet_stableswap::Call::buy {
+			pool_id: 100,
+			asset_out: DOT,
+			asset_in: DAI,
+			amount_out: UNITS,
+			max_sell_amount: u128::MAX,
+		}),
+		RuntimeCall::Router(pallet_route_executor::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: UNITS,
+			min_amount_out: 0,
+			route: route.clone(),
+		}),
+		RuntimeCall::Router(pallet_route_executor::Call::buy {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_out: UNITS,
+			max_amount_in: u128::MAX,
+			route,
+		}),
+		RuntimeCall::DCA(pallet_dca::Call::schedule {
+			schedule: dca_schedule(ExecutionMode::Automatic),
+			start_execution_block: None,
+		}),
+		RuntimeCall::DCA(pallet_dca::Call::terminate {
+			schedule_id: 0,
+			next_execution_block: None,
+		}),
+	]
+}
+
+fn forbidden_calls() -> Vec<RuntimeCall> {
+	let route = vec![Trade {
+		pool: PoolType::Omnipool,
+		asset_in: HDX,
+		asset_out: DAI,
+	}];
+
+	vec![
+		RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: BOB.into(),
+			value: UNITS,
+		}),
+		RuntimeCall::Currencies(pallet_currencies::Call::transfer {
+			dest: BOB.into(),
+			currency_id: DAI,
+			amount: UNITS,
+		}),
+		RuntimeCall::Tokens(orml_tokens::Call::transfer {
+			dest: BOB.into(),
+			currency_id: DAI,
+			amount: UNITS,
+		}),
+		RuntimeCall::Staking(pallet_staking::Call::stake { amount: UNITS }),
+		RuntimeCall::Staking(pallet_staking::Call::unstake { position_id: 0 }),
+		RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity {
+			asset: DAI,
+			amount: UNITS,
+		}),
+		RuntimeCall::Router(pallet_route_executor::Call::sell_with_surplus_capture {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: UNITS,
+			min_amount_out: 0,
+			route: route.clone(),
+			beneficiary: BOB.into(),
+		}),
+		RuntimeCall::Router(pallet_route_executor::Call::buy_with_surplus_capture {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_out: UNITS,
+			max_amount_in: u128::MAX,
+			route,
+			beneficiary: BOB.into(),
+		}),
+		RuntimeCall::DCA(pallet_dca::Call::schedule {
+			schedule: dca_schedule(ExecutionMode::Keeper),
+			start_execution_block: None,
+		}),
+	]
+}
+
+#[test]
+fn trading_proxy_should_allow_trading_and_dca_calls() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		for call in trading_calls() {
+			assert!(ProxyType::Trading.filter(&call), "{call:?} should be allowed");
+		}
+	});
+}
+
+#[test]
+fn trading_proxy_should_not_allow_transfers_and_staking_calls() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		for call in forbidden_calls() {
+			assert!(!ProxyType::Trading.filter(&call), "{call:?} should be forbidden");
+		}
+	});
+}
+
+#[test]
+fn trading_proxy_should_trade_on_behalf_of_delegator() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipool();
+		let alice_init_hdx_balance = 5_000 * UNITS;
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			alice_init_hdx_balance,
+		));
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(ALICE.into()),
+			BOB.into(),
+			ProxyType::Trading,
+			0
+		));
+		let alice_hdx_balance = Currencies::free_balance(HDX, &AccountId::from(ALICE));
+		let amount_to_sell = 10 * UNITS;
+
+		// Act
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(BOB.into()),
+			ALICE.into(),
+			None,
+			Box::new(RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+				asset_in: HDX,
+				asset_out: DAI,
+				amount: amount_to_sell,
+				min_buy_amount: 0,
+			})),
+		));
+
+		// Assert
+		frame_system::Pallet::<Runtime>::assert_has_event(RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted {
+			result: Ok(()),
+		}));
+		assert_balance!(AccountId::from(ALICE), HDX, alice_hdx_balance - amount_to_sell);
+		assert!(Currencies::free_balance(DAI, &AccountId::from(ALICE)) > ALICE_INITIAL_DAI_BALANCE);
+	});
+}
+
+#[test]
+fn trading_proxy_should_not_transfer_on_behalf_of_delegator() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(ALICE.into()),
+			BOB.into(),
+			ProxyType::Trading,
+			0
+		));
+		let alice_dai_balance = Currencies::free_balance(DAI, &AccountId::from(ALICE));
+
+		// Act
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(BOB.into()),
+			ALICE.into(),
+			None,
+			Box::new(RuntimeCall::Currencies(pallet_currencies::Call::transfer {
+				dest: BOB.into(),
+				currency_id: DAI,
+				amount: UNITS,
+			})),
+		));
+
+		// Assert
+		frame_system::Pallet::<Runtime>::assert_has_event(RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted {
+			result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+		}));
+		assert_balance!(AccountId::from(ALICE), DAI, alice_dai_balance);
+	});
+}
+
+#[test]
+fn trading_proxy_should_not_capture_surplus_of_delegator() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipool();
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			5_000 * UNITS,
+		));
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(ALICE.into()),
+			BOB.into(),
+			ProxyType::Trading,
+			0
+		));
+		let route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DAI,
+		}];
+		let alice_hdx_balance = Currencies::free_balance(HDX, &AccountId::from(ALICE));
+		let alice_dai_balance = Currencies::free_balance(DAI, &AccountId::from(ALICE));
+		let bob_dai_balance = Currencies::free_balance(DAI, &AccountId::from(BOB));
+
+		for call in [
+			RuntimeCall::Router(pallet_route_executor::Call::sell_with_surplus_capture {
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: 10 * UNITS,
+				min_amount_out: 0,
+				route: route.clone(),
+				beneficiary: BOB.into(),
+			}),
+			RuntimeCall::Router(pallet_route_executor::Call::buy_with_surplus_capture {
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_out: UNITS,
+				max_amount_in: 1_000 * UNITS,
+				route: route.clone(),
+				beneficiary: BOB.into(),
+			}),
+		] {
+			// Act
+			assert_ok!(Proxy::proxy(
+				RuntimeOrigin::signed(BOB.into()),
+				ALICE.into(),
+				None,
+				Box::new(call),
+			));
+
+			// Assert
+			frame_system::Pallet::<Runtime>::assert_has_event(RuntimeEvent::Proxy(
+				pallet_proxy::Event::ProxyExecuted {
+					result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+				},
+			));
+		}
+		assert_balance!(AccountId::from(ALICE), HDX, alice_hdx_balance);
+		assert_balance!(AccountId::from(ALICE), DAI, alice_dai_balance);
+		assert_balance!(AccountId::from(BOB), DAI, bob_dai_balance);
+	});
+}
+
+#[test]
+fn trading_proxy_should_not_schedule_keeper_dca_on_behalf_of_delegator() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipool();
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			5_000 * UNITS,
+		));
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(ALICE.into()),
+			BOB.into(),
+			ProxyType::Trading,
+			0
+		));
+		let alice_hdx_balance = Currencies::free_balance(HDX, &AccountId::from(ALICE));
+
+		// Act
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(BOB.into()),
+			ALICE.into(),
+			None,
+			Box::new(RuntimeCall::DCA(pallet_dca::Call::schedule {
+				schedule: dca_schedule(ExecutionMode::Keeper),
+				start_execution_block: None,
+			})),
+		));
+
+		// Assert
+		frame_system::Pallet::<Runtime>::assert_has_event(RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted {
+			result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+		}));
+		assert!(DCA::schedules(0).is_none());
+		assert_balance!(AccountId::from(ALICE), HDX, alice_hdx_balance);
+	});
+}
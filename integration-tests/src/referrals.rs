@@ -1,8 +1,15 @@
 #![cfg(test)]
 use crate::polkadot_test_net::*;
 use frame_support::assert_ok;
+use frame_support::dispatch::DispatchInfo;
+use frame_support::sp_runtime::traits::SignedExtension;
+use frame_support::weights::Weight;
 use frame_system::RawOrigin;
-use hydradx_runtime::{Currencies, Omnipool, Referrals, Runtime, RuntimeOrigin, Staking, Tokens};
+use hydradx_runtime::{
+	Currencies, Omnipool, Referrals, Runtime, RuntimeOrigin, Staking, Tokens, Treasury, TreasuryAccount,
+	TreasuryBeneficiary,
+};
+use hydradx_traits::correlation::CorrelationId;
 use orml_traits::MultiCurrency;
 use pallet_referrals::{FeeDistribution, ReferralCode};
 use primitives::AccountId;
@@ -186,7 +193,7 @@ fn trading_in_omnipool_should_increase_total_shares_correctly() {
 }
 
 #[test]
-fn claiming_rewards_should_convert_all_assets_to_reward_asset() {
+fn claiming_rewards_should_pay_rewards_of_converted_assets() {
 	Hydra::execute_with(|| {
 		init_omnipool_with_oracle_for_block_12();
 		let code =
@@ -205,10 +212,20 @@ fn claiming_rewards_should_convert_all_assets_to_reward_asset() {
 		));
 		let pot_balance = Currencies::free_balance(DAI, &Referrals::pot_account_id());
 		assert!(pot_balance > 0);
+		assert_eq!(Referrals::pending_rewards(&ALICE.into()), 0);
 
-		assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE.into())));
+		assert_ok!(Referrals::convert(RuntimeOrigin::signed(BOB.into()), DAI));
 		let pot_balance = Currencies::free_balance(DAI, &Referrals::pot_account_id());
 		assert_eq!(pot_balance, 0);
+
+		let pending_rewards = Referrals::pending_rewards(&ALICE.into());
+		assert!(pending_rewards > 0);
+		let alice_balance = Currencies::free_balance(HDX, &ALICE.into());
+		assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE.into())));
+		assert_eq!(
+			Currencies::free_balance(HDX, &ALICE.into()),
+			alice_balance + pending_rewards
+		);
 	});
 }
 
@@ -295,6 +312,7 @@ fn trading_in_omnipool_should_use_asset_rewards_when_set() {
 				referrer: Permill::from_percent(2),
 				trader: Permill::from_percent(1),
 				external: Permill::from_percent(10),
+				lp: Permill::zero(),
 			}
 		));
 		let code =
@@ -349,6 +367,7 @@ fn buying_hdx_in_omnipool_should_transfer_correct_fee() {
 			hub_amount_out: 1_209_141_304,
 			asset_fee_amount: 2_794_789_078,
 			protocol_fee_amount: 604_873,
+			correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 		}
 		.into()]);
 
@@ -386,6 +405,7 @@ fn buying_with_hdx_in_omnipool_should_transfer_correct_fee() {
 			hub_amount_out: 45_200_101_724,
 			asset_fee_amount: 2_644_977_450_514_458,
 			protocol_fee_amount: 22_611_356,
+			correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 		}
 		.into()]);
 
@@ -443,6 +463,84 @@ fn transfer_using_mutate_should_emit_event() {
 	});
 }
 
+#[test]
+fn treasury_spend_to_referral_rewards_should_fund_reward_pot() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		let amount = 1_000 * UNITS;
+		assert_ok!(Currencies::update_balance(
+			RawOrigin::Root.into(),
+			TreasuryAccount::get(),
+			HDX,
+			amount as i128,
+		));
+		let treasury_balance = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		let pot_balance = Currencies::free_balance(HDX, &Referrals::pot_account_id());
+		let total_shares = Referrals::total_shares();
+
+		//Act
+		assert_ok!(Treasury::spend(
+			RuntimeOrigin::root(),
+			Box::new(()),
+			amount,
+			Box::new(TreasuryBeneficiary::ReferralRewards(CHARLIE.into())),
+			None,
+		));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(BOB.into()), 0));
+
+		//Assert
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()),
+			treasury_balance - amount
+		);
+		assert_eq!(
+			Currencies::free_balance(HDX, &Referrals::pot_account_id()),
+			pot_balance + amount
+		);
+		assert_eq!(Referrals::trader_shares(AccountId::from(CHARLIE)), amount);
+		assert_eq!(Referrals::total_shares(), total_shares + amount);
+	});
+}
+
+#[test]
+fn paying_transaction_fee_in_non_native_currency_should_increase_referrer_shares() {
+	Hydra::execute_with(|| {
+		init_omnipool_with_oracle_for_block_12();
+		let code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"BALLS69".to_vec());
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE.into()),
+			code.clone()
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB.into()), code));
+
+		let call = hydradx_runtime::RuntimeCall::MultiTransactionPayment(
+			pallet_transaction_multi_payment::Call::set_currency { currency: DAI },
+		);
+		let info = DispatchInfo {
+			weight: Weight::from_parts(106_957_000, 0),
+			..Default::default()
+		};
+		let len: usize = 10;
+		let pre = pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&AccountId::from(BOB), &call, &info, len)
+			.unwrap();
+		assert_ok!(
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::post_dispatch(
+				Some(pre),
+				&info,
+				&Default::default(),
+				len,
+				&Ok(())
+			)
+		);
+
+		assert!(Referrals::referrer_shares::<AccountId>(ALICE.into()) > 0);
+		assert!(Currencies::free_balance(DAI, &Referrals::pot_account_id()) > 0);
+	});
+}
+
 fn init_omnipool() {
 	let native_price = FixedU128::from_inner(1201500000000000);
 	let stable_price = FixedU128::from_inner(45_000_000_000);
@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use hydradx_runtime::{Currencies, RemoteIntents, RuntimeCall, RuntimeOrigin};
+use orml_traits::MultiCurrency;
+use pallet_remote_intents::{RemoteIntent, VersionedRemoteIntent};
+use polkadot_xcm::v4::prelude::*;
+use sp_runtime::codec::Encode;
+use sp_std::sync::Arc;
+use xcm_emulator::TestExt;
+
+fn swap_intent(amount_in: Balance) -> VersionedRemoteIntent<AssetId, Balance, BlockNumber> {
+	RemoteIntent::Swap {
+		asset_in: HDX,
+		asset_out: DAI,
+		amount_in,
+		min_amount_out: 0,
+	}
+	.into()
+}
+
+#[test]
+fn dispatch_intent_call_should_be_encoded_with_fixed_indices() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let call = RuntimeCall::RemoteIntents(pallet_remote_intents::Call::dispatch_intent {
+			intent: Box::new(swap_intent(UNITS)),
+		});
+
+		let mut expected = vec![116u8, 0u8];
+		expected.extend(swap_intent(UNITS).encode());
+
+		assert_eq!(call.encode(), expected);
+	});
+}
+
+#[test]
+fn swap_intent_should_sell_by_on_chain_route() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipool();
+		let alice_hdx_balance = Currencies::free_balance(HDX, &AccountId::from(ALICE));
+		let alice_dai_balance = Currencies::free_balance(DAI, &AccountId::from(ALICE));
+
+		// Act
+		assert_ok!(RemoteIntents::dispatch_intent(
+			RuntimeOrigin::signed(ALICE.into()),
+			Box::new(swap_intent(10 * UNITS))
+		));
+
+		// Assert
+		assert_eq!(
+			Currencies::free_balance(HDX, &AccountId::from(ALICE)),
+			alice_hdx_balance - 10 * UNITS
+		);
+		assert!(Currencies::free_balance(DAI, &AccountId::from(ALICE)) > alice_dai_balance);
+	});
+}
+
+#[test]
+fn add_liquidity_intent_should_add_liquidity_to_omnipool() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipool();
+		let position_id = hydradx_runtime::Omnipool::next_position_id();
+		let alice_dai_balance = Currencies::free_balance(DAI, &AccountId::from(ALICE));
+		let intent = RemoteIntent::AddLiquidity {
+			asset: DAI,
+			amount: 100 * UNITS,
+			min_shares: 0,
+		};
+
+		// Act
+		assert_ok!(RemoteIntents::dispatch_intent(
+			RuntimeOrigin::signed(ALICE.into()),
+			Box::new(intent.into())
+		));
+
+		// Assert
+		assert_eq!(
+			Currencies::free_balance(DAI, &AccountId::from(ALICE)),
+			alice_dai_balance - 100 * UNITS
+		);
+		assert!(hydradx_runtime::Omnipool::load_position(position_id, ALICE.into()).is_ok());
+	});
+}
+
+#[test]
+fn swap_intent_should_be_executed_when_sent_by_transact_from_sibling_chain() {
+	// Arrange
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+		assert_ok!(hydradx_runtime::Balances::transfer_allow_death(
+			RuntimeOrigin::signed(ALICE.into()),
+			parachain_reserve_account(),
+			1_000 * UNITS,
+		));
+	});
+
+	Acala::execute_with(|| {
+		let call = RuntimeCall::RemoteIntents(pallet_remote_intents::Call::dispatch_intent {
+			intent: Box::new(swap_intent(100 * UNITS)),
+		});
+
+		let hdx_loc = Location::new(
+			1,
+			cumulus_primitives_core::Junctions::X2(Arc::new([
+				cumulus_primitives_core::Junction::Parachain(HYDRA_PARA_ID),
+				cumulus_primitives_core::Junction::GeneralIndex(0),
+			])),
+		);
+		let asset_to_withdraw: Asset = Asset {
+			id: cumulus_primitives_core::AssetId(hdx_loc.clone()),
+			fun: Fungible(500 * UNITS),
+		};
+		let asset_for_buy_execution: Asset = Asset {
+			id: cumulus_primitives_core::AssetId(hdx_loc),
+			fun: Fungible(400 * UNITS),
+		};
+
+		let message = Xcm(vec![
+			WithdrawAsset(asset_to_withdraw.into()),
+			BuyExecution {
+				fees: asset_for_buy_execution,
+				weight_limit: Unlimited,
+			},
+			Transact {
+				require_weight_at_most: call.get_dispatch_info().weight,
+				origin_kind: OriginKind::SovereignAccount,
+				call: call.encode().into(),
+			},
+			ExpectTransactStatus(MaybeErrorCode::Success),
+			RefundSurplus,
+			DepositAsset {
+				assets: All.into(),
+				beneficiary: cumulus_primitives_core::Junction::AccountId32 {
+					id: parachain_reserve_account().into(),
+					network: None,
+				}
+				.into(),
+			},
+		]);
+
+		// Act
+		assert_ok!(hydradx_runtime::PolkadotXcm::send_xcm(
+			Here,
+			Location::new(
+				1,
+				cumulus_primitives_core::Junctions::X1(Arc::new([cumulus_primitives_core::Junction::Parachain(
+					HYDRA_PARA_ID
+				)])),
+			),
+			message
+		));
+	});
+
+	Hydra::execute_with(|| {
+		// Assert
+		assert_xcm_message_processing_passed();
+
+		assert!(Currencies::free_balance(DAI, &parachain_reserve_account()) > 0);
+	});
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::{assert_ok, traits::Get};
+use hydradx_runtime::{Currencies, Omnipool, Referrals, RevenueStats, RuntimeEvent, RuntimeOrigin, TreasuryAccount};
+use orml_traits::MultiCurrency;
+use pallet_transaction_multi_payment::DepositFee;
+use xcm_emulator::TestExt;
+
+#[test]
+fn omnipool_sell_should_record_fees_in_revenue_stats() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		let era = RevenueStats::current_era();
+		let pot_balance = Currencies::free_balance(DAI, &Referrals::pot_account_id());
+
+		//Act
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			DAI,
+			1_000 * UNITS,
+			0
+		));
+
+		//Assert
+		let (asset_fee, protocol_fee) = hydradx_runtime::System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::Omnipool(pallet_omnipool::Event::SellExecuted {
+					asset_fee_amount,
+					protocol_fee_amount,
+					..
+				}) => Some((asset_fee_amount, protocol_fee_amount)),
+				_ => None,
+			})
+			.unwrap();
+		let referral_fee = Currencies::free_balance(DAI, &Referrals::pot_account_id()) - pot_balance;
+
+		let dai_revenue = RevenueStats::revenue(era, DAI);
+		// omnipool keeps one unit of the fee for rounding
+		assert_eq!(dai_revenue.asset_fees, asset_fee - 1);
+		assert_eq!(dai_revenue.referral_fees, referral_fee);
+		assert_eq!(RevenueStats::revenue(era, LRNA).protocol_fees, protocol_fee);
+	});
+}
+
+#[test]
+fn fee_deposited_to_treasury_should_be_recorded_in_revenue_stats() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		let era = RevenueStats::current_era();
+		let treasury_balance = Currencies::free_balance(DAI, &TreasuryAccount::get());
+
+		//Act
+		assert_ok!(hydradx_runtime::TreasuryFeeDeposit::deposit_fee(
+			&TreasuryAccount::get(),
+			DAI,
+			10 * UNITS
+		));
+
+		//Assert
+		assert_eq!(
+			Currencies::free_balance(DAI, &TreasuryAccount::get()),
+			treasury_balance + 10 * UNITS
+		);
+		assert_eq!(RevenueStats::revenue(era, DAI).treasury_inflows, 10 * UNITS);
+	});
+}
@@ -7,6 +7,7 @@ use hydradx_runtime::{
 	AssetRegistry, BlockNumber, Currencies, Omnipool, Router, RouterWeightInfo, Runtime, RuntimeOrigin, Stableswap,
 	LBP, XYK,
 };
+use hydradx_traits::correlation::CorrelationId;
 use hydradx_traits::router::RouteSpotPriceProvider;
 use hydradx_traits::{
 	registry::Create,
@@ -157,6 +158,7 @@ mod router_different_pools_tests {
 				asset_out: DOT,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -223,6 +225,7 @@ mod router_different_pools_tests {
 					asset_out: stable_asset_2,
 					amount_in: amount_to_sell,
 					amount_out,
+					correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 				}
 				.into()]);
 
@@ -286,6 +289,7 @@ mod router_different_pools_tests {
 				asset_out: DOT,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -352,6 +356,7 @@ mod router_different_pools_tests {
 					asset_out: stable_asset_2,
 					amount_in,
 					amount_out: amount_to_buy,
+					correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 				}
 				.into()]);
 				TransactionOutcome::Commit(DispatchResult::Ok(()))
@@ -802,6 +807,7 @@ mod omnipool_router_tests {
 				asset_out: DAI,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -1976,6 +1982,7 @@ mod omnipool_router_tests {
 				asset_out: DAI,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2019,6 +2026,7 @@ mod omnipool_router_tests {
 				asset_out: DAI,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2049,6 +2057,7 @@ mod omnipool_router_tests {
 				hub_amount_out: 12008864246,
 				asset_fee_amount: 667_155_563_986_401,
 				protocol_fee_amount: 6_007_435,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 
@@ -2094,6 +2103,7 @@ mod omnipool_router_tests {
 				asset_out: DAI,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2199,6 +2209,7 @@ mod omnipool_router_tests {
 				asset_out: DAI,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2229,6 +2240,7 @@ mod omnipool_router_tests {
 				hub_amount_out: 45113,
 				asset_fee_amount: 2_506_265_665,
 				protocol_fee_amount: 22,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 
@@ -2311,6 +2323,7 @@ mod lbp_router_tests {
 				asset_out: DAI,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2354,6 +2367,7 @@ mod lbp_router_tests {
 				asset_out: HDX,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2406,6 +2420,7 @@ mod lbp_router_tests {
 				asset_out: DOT,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2458,6 +2473,7 @@ mod lbp_router_tests {
 				asset_out: DOT,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2501,6 +2517,7 @@ mod lbp_router_tests {
 				asset_out: DAI,
 				amount_in: amount_to_sell,
 				amount_out: received_amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2576,6 +2593,7 @@ mod lbp_router_tests {
 				asset_out: DAI,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2619,6 +2637,7 @@ mod lbp_router_tests {
 				asset_out: HDX,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2671,6 +2690,7 @@ mod lbp_router_tests {
 				asset_out: DOT,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2723,6 +2743,7 @@ mod lbp_router_tests {
 				asset_out: DOT,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2766,6 +2787,7 @@ mod lbp_router_tests {
 				asset_out: DAI,
 				amount_in: spent_amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2868,6 +2890,7 @@ mod xyk_router_tests {
 				asset_out: DOT,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -2931,6 +2954,7 @@ mod xyk_router_tests {
 				asset_out: DOT,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -3096,6 +3120,7 @@ mod xyk_router_tests {
 				asset_out: DOT,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -3151,6 +3176,7 @@ mod xyk_router_tests {
 				asset_out: DAI,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -3214,6 +3240,7 @@ mod xyk_router_tests {
 				asset_out: DAI,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -4935,6 +4962,7 @@ mod with_on_chain_and_default_route {
 				asset_out: DAI,
 				amount_in: amount_to_sell,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -4972,6 +5000,7 @@ mod with_on_chain_and_default_route {
 				asset_out: DAI,
 				amount_in,
 				amount_out: amount_to_buy,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -5333,6 +5362,7 @@ mod sell_all {
 				asset_out: DAI,
 				amount_in: bob_hdx_balance,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -5374,6 +5404,7 @@ mod sell_all {
 				asset_out: HDX,
 				amount_in: bob_nonnative_balance,
 				amount_out,
+				correlation_id: CorrelationId::current::<hydradx_runtime::Runtime>(),
 			}
 			.into()]);
 		});
@@ -5453,6 +5484,7 @@ fn create_lbp_pool(accumulated_asset: u32, distributed_asset: u32) {
 		(2, 1_000),
 		CHARLIE.into(),
 		0,
+		None,
 	));
 
 	let account_id = get_lbp_pair_account_id(accumulated_asset, distributed_asset);
@@ -5540,7 +5572,7 @@ pub fn init_stableswap_with_details(
 			Some(name.try_into().unwrap()),
 			AssetKind::Token,
 			1000u128,
-			Some(b"xDUM".to_vec().try_into().unwrap()),
+			Some(format!("xDUM{idx}").into_bytes().try_into().unwrap()),
 			Some(decimals),
 			None,
 			None,
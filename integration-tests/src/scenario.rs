@@ -0,0 +1,44 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use crate::utils::scenario::{Scenario, Step};
+
+use orml_traits::MultiCurrencyExtended;
+
+const PATH_TO_SNAPSHOT: &str = "omnipool-snapshot/SNAPSHOT";
+
+const ETH_UNIT: u128 = 1_000_000_000_000_000_000;
+
+fn fund_alice() {
+	orml_tokens::Pallet::<hydradx_runtime::Runtime>::update_balance(ETH, &ALICE.into(), 1000 * ETH_UNIT as i128)
+		.unwrap();
+}
+
+fn sell_eth_for_dai() -> hydradx_runtime::RuntimeCall {
+	hydradx_runtime::RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+		asset_in: ETH,
+		asset_out: DAI,
+		amount: ETH_UNIT,
+		min_buy_amount: 0,
+	})
+}
+
+#[ignore]
+#[test]
+fn omnipool_trades_and_migrations_should_fit_into_block_on_omnipool_snapshot() {
+	let reports = Scenario::new(PATH_TO_SNAPSHOT)
+		.step(Step::Run(fund_alice))
+		.step(Step::Dispatch(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			sell_eth_for_dai(),
+		))
+		.step(Step::RunBlocks(1))
+		.step(Step::Dispatch(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			sell_eth_for_dai(),
+		))
+		.step(Step::Migrate)
+		.run();
+
+	assert_eq!(reports.len(), 3);
+}
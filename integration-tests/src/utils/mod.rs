@@ -1,2 +1,4 @@
 pub(crate) mod accounts;
 pub(crate) mod contracts;
+pub(crate) mod scenario;
+pub(crate) mod xcm_scenario;
@@ -0,0 +1,105 @@
+//! Scenario runner for chain state snapshots.
+//!
+//! Loads a storage snapshot (see `hydra_live_ext`) and executes a list of steps against it. Every step is checked
+//! against the block limits, so weight and migration changes can be validated against realistic state sizes.
+//!
+//! Example:
+//! ```ignore
+//! Scenario::new("omnipool-snapshot/SNAPSHOT")
+//! 	.step(Step::Run(fund_alice))
+//! 	.step(Step::Dispatch(RuntimeOrigin::signed(ALICE.into()), sell_call))
+//! 	.step(Step::RunBlocks(1))
+//! 	.step(Step::Migrate)
+//! 	.run();
+//! ```
+
+use crate::polkadot_test_net::{hydra_live_ext, hydradx_run_to_next_block};
+use frame_support::dispatch::{DispatchClass, GetDispatchInfo};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use hydradx_runtime::{Executive, RuntimeCall, RuntimeOrigin};
+use sp_runtime::traits::Dispatchable;
+
+pub(crate) enum Step {
+	/// Dispatch a call with given origin. The call must succeed and fit into a block as a normal extrinsic.
+	Dispatch(RuntimeOrigin, RuntimeCall),
+	/// Produce given number of blocks.
+	RunBlocks(u32),
+	/// Execute runtime upgrade migrations. The migrations must fit into a block.
+	Migrate,
+	/// Run arbitrary code, e.g. to set up balances or to check the state.
+	Run(fn()),
+}
+
+/// Weight consumed by a step of a scenario.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct StepReport {
+	pub index: usize,
+	pub weight: Weight,
+}
+
+pub(crate) struct Scenario {
+	snapshot: &'static str,
+	steps: Vec<Step>,
+}
+
+impl Scenario {
+	pub fn new(snapshot: &'static str) -> Self {
+		Self {
+			snapshot,
+			steps: Vec::new(),
+		}
+	}
+
+	pub fn step(mut self, step: Step) -> Self {
+		self.steps.push(step);
+		self
+	}
+
+	/// Executes all steps on top of the snapshot and returns weights consumed by dispatches and migrations.
+	pub fn run(self) -> Vec<StepReport> {
+		let mut reports = Vec::new();
+
+		hydra_live_ext(self.snapshot).execute_with(|| {
+			let block_weights = <hydradx_runtime::Runtime as frame_system::Config>::BlockWeights::get();
+			let max_extrinsic = block_weights
+				.get(DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or(block_weights.max_block);
+
+			for (index, step) in self.steps.into_iter().enumerate() {
+				match step {
+					Step::Dispatch(origin, call) => {
+						let info = call.get_dispatch_info();
+						let post_info = call
+							.dispatch(origin)
+							.unwrap_or_else(|e| panic!("step {index}: dispatch failed: {:?}", e.error));
+						let weight = post_info.calc_actual_weight(&info);
+						assert!(
+							weight.all_lte(max_extrinsic),
+							"step {index}: weight {weight:?} exceeds max extrinsic weight {max_extrinsic:?}"
+						);
+						reports.push(StepReport { index, weight });
+					}
+					Step::RunBlocks(n) => {
+						for _ in 0..n {
+							hydradx_run_to_next_block();
+						}
+					}
+					Step::Migrate => {
+						let weight = Executive::execute_on_runtime_upgrade();
+						assert!(
+							weight.all_lte(block_weights.max_block),
+							"step {index}: migration weight {weight:?} exceeds max block weight {:?}",
+							block_weights.max_block
+						);
+						reports.push(StepReport { index, weight });
+					}
+					Step::Run(f) => f(),
+				}
+			}
+		});
+
+		reports
+	}
+}
@@ -0,0 +1,196 @@
+//! Driver for cross-chain scenarios on the emulated test network.
+//!
+//! Wraps the xcm-emulator boilerplate of cross-chain tests - selecting the chain to execute on, building
+//! transfer destinations, producing blocks and comparing balances before and after a transfer.
+//!
+//! Every `execute_with` of the emulator produces a new block and delivers pending messages. Balances are read
+//! without producing blocks, so checking a balance doesn't move the scenario forward.
+//!
+//! Example:
+//! ```ignore
+//! let alice = Chain::Acala.account(ALICE);
+//! let bob = Chain::Hydra.account(BOB);
+//! let balances = BalanceSnapshot::take(&[(alice, 0), (bob, ACA)]);
+//!
+//! send_xcm_transfer(alice, bob, 0, 30 * UNITS);
+//! advance_blocks_on_all(1);
+//!
+//! balances.assert_diff(alice, 0, -(30 * UNITS as i128));
+//! assert!(balances.diff(bob, ACA) > 0);
+//! ```
+
+use crate::polkadot_test_net::*;
+use frame_support::assert_ok;
+use frame_support::weights::Weight;
+use hydradx_runtime::Currencies;
+use orml_traits::MultiCurrency;
+use polkadot_xcm::opaque::v3::{Junction, Junctions::X2, MultiLocation};
+use polkadot_xcm::v4::prelude::WeightLimit;
+use xcm_emulator::TestExt;
+
+/// Parachains of the test network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Chain {
+	Hydra,
+	Acala,
+	Moonbeam,
+	Interlay,
+	AssetHub,
+	Zeitgeist,
+}
+
+pub(crate) const ALL_PARACHAINS: [Chain; 6] = [
+	Chain::Hydra,
+	Chain::Acala,
+	Chain::Moonbeam,
+	Chain::Interlay,
+	Chain::AssetHub,
+	Chain::Zeitgeist,
+];
+
+impl Chain {
+	pub fn para_id(self) -> u32 {
+		match self {
+			Chain::Hydra => HYDRA_PARA_ID,
+			Chain::Acala => ACALA_PARA_ID,
+			Chain::Moonbeam => MOONBEAM_PARA_ID,
+			Chain::Interlay => INTERLAY_PARA_ID,
+			Chain::AssetHub => ASSET_HUB_PARA_ID,
+			Chain::Zeitgeist => ZEITGEIST_PARA_ID,
+		}
+	}
+
+	pub fn account(self, id: [u8; 32]) -> ChainAccount {
+		ChainAccount { chain: self, id }
+	}
+
+	/// Execute in a new block of the chain. Pending messages are delivered afterwards.
+	pub fn execute_with<R>(self, f: impl FnOnce() -> R) -> R {
+		match self {
+			Chain::Hydra => Hydra::execute_with(f),
+			Chain::Acala => Acala::execute_with(f),
+			Chain::Moonbeam => Moonbeam::execute_with(f),
+			Chain::Interlay => Interlay::execute_with(f),
+			Chain::AssetHub => AssetHub::execute_with(f),
+			Chain::Zeitgeist => Zeitgeist::execute_with(f),
+		}
+	}
+
+	/// Read state of the chain without producing a block.
+	pub fn read<R>(self, f: impl FnOnce() -> R) -> R {
+		match self {
+			Chain::Hydra => Hydra::ext_wrapper(f),
+			Chain::Acala => Acala::ext_wrapper(f),
+			Chain::Moonbeam => Moonbeam::ext_wrapper(f),
+			Chain::Interlay => Interlay::ext_wrapper(f),
+			Chain::AssetHub => AssetHub::ext_wrapper(f),
+			Chain::Zeitgeist => Zeitgeist::ext_wrapper(f),
+		}
+	}
+
+	pub fn block_number(self) -> BlockNumber {
+		self.read(hydradx_runtime::System::block_number)
+	}
+}
+
+/// Account on a parachain of the test network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ChainAccount {
+	pub chain: Chain,
+	pub id: [u8; 32],
+}
+
+impl ChainAccount {
+	pub fn free_balance(self, asset: AssetId) -> Balance {
+		self.chain
+			.read(|| Currencies::free_balance(asset, &AccountId::from(self.id)))
+	}
+}
+
+/// Transfer `amount` of `asset` from `from` to `to` on another parachain by `XTokens`.
+///
+/// `asset` is the id of the asset on the sending chain. The message is delivered when the sending block is
+/// finalized, but its execution on the destination chain can be deferred.
+pub(crate) fn send_xcm_transfer(from: ChainAccount, to: ChainAccount, asset: AssetId, amount: Balance) {
+	assert_ne!(
+		from.chain, to.chain,
+		"transfer within the same chain is not a cross-chain transfer"
+	);
+
+	let dest = MultiLocation::new(
+		1,
+		X2(
+			Junction::Parachain(to.chain.para_id()),
+			Junction::AccountId32 {
+				id: to.id,
+				network: None,
+			},
+		),
+	);
+
+	from.chain.execute_with(|| {
+		assert_ok!(hydradx_runtime::XTokens::transfer(
+			hydradx_runtime::RuntimeOrigin::signed(from.id.into()),
+			asset,
+			amount,
+			Box::new(dest.into_versioned()),
+			WeightLimit::Limited(Weight::from_parts(399_600_000_000, 0)),
+		));
+	});
+}
+
+/// Produce `n` blocks on the relay chain and on every parachain, delivering pending messages.
+///
+/// Useful to wait until deferred messages are executed.
+pub(crate) fn advance_blocks_on_all(n: u32) {
+	for _ in 0..n {
+		Rococo::execute_with(|| {});
+		for chain in ALL_PARACHAINS {
+			chain.execute_with(|| {});
+		}
+	}
+}
+
+/// Free balances of accounts taken at one point of a scenario, to assert balance changes against later.
+pub(crate) struct BalanceSnapshot {
+	balances: Vec<(ChainAccount, AssetId, Balance)>,
+}
+
+impl BalanceSnapshot {
+	pub fn take(accounts: &[(ChainAccount, AssetId)]) -> Self {
+		Self {
+			balances: accounts
+				.iter()
+				.map(|&(account, asset)| (account, asset, account.free_balance(asset)))
+				.collect(),
+		}
+	}
+
+	/// Change of the free balance since the snapshot was taken.
+	pub fn diff(&self, account: ChainAccount, asset: AssetId) -> i128 {
+		let (_, _, initial) = self
+			.balances
+			.iter()
+			.find(|(a, id, _)| *a == account && *id == asset)
+			.expect("balance is not in the snapshot");
+
+		account.free_balance(asset) as i128 - *initial as i128
+	}
+
+	pub fn assert_diff(&self, account: ChainAccount, asset: AssetId, expected: i128) {
+		assert_eq!(
+			self.diff(account, asset),
+			expected,
+			"unexpected balance change of asset {} of {:?}",
+			asset,
+			account
+		);
+	}
+
+	/// Assert that no balance in the snapshot has changed.
+	pub fn assert_unchanged(&self) {
+		for &(account, asset, _) in self.balances.iter() {
+			self.assert_diff(account, asset, 0);
+		}
+	}
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+use crate::polkadot_test_net::*;
+
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use sp_runtime::codec::Encode;
+
+use polkadot_xcm::v4::prelude::*;
+use polkadot_xcm::VersionedLocation;
+use xcm_emulator::TestExt;
+
+fn unpaid_transfer_message() -> Xcm<()> {
+	let call = pallet_balances::Call::<hydradx_runtime::Runtime>::transfer_allow_death {
+		dest: BOB.into(),
+		value: UNITS,
+	};
+
+	Xcm(vec![
+		Transact {
+			require_weight_at_most: call.get_dispatch_info().weight,
+			origin_kind: OriginKind::SovereignAccount,
+			call: hydradx_runtime::RuntimeCall::Balances(call).encode().into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+	])
+}
+
+fn send_unpaid_transfer_from_acala() {
+	Acala::execute_with(|| {
+		assert_ok!(hydradx_runtime::PolkadotXcm::send_xcm(
+			Here,
+			Location::new(1, [Parachain(HYDRA_PARA_ID)]),
+			unpaid_transfer_message()
+		));
+	});
+}
+
+fn fund_acala_sovereign_account() {
+	assert_ok!(hydradx_runtime::Balances::transfer_allow_death(
+		hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+		parachain_reserve_account(),
+		10 * UNITS,
+	));
+}
+
+#[test]
+fn unpaid_message_should_be_executed_when_origin_is_sponsored() {
+	// Arrange
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		fund_acala_sovereign_account();
+		assert_ok!(hydradx_runtime::XcmFeeSponsor::register_sponsorship(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			Box::new(VersionedLocation::from(Location::new(1, [Parachain(ACALA_PARA_ID)]))),
+			100 * UNITS,
+			100,
+			1_000,
+		));
+	});
+
+	// Act
+	send_unpaid_transfer_from_acala();
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processing_passed();
+
+		assert_eq!(
+			hydradx_runtime::Balances::free_balance(AccountId::from(BOB)),
+			BOB_INITIAL_NATIVE_BALANCE + UNITS
+		);
+
+		let spent = hydradx_runtime::XcmFeeSponsor::sponsorships(0).unwrap().spent;
+		assert!(spent > 0);
+		assert_eq!(
+			hydradx_runtime::Balances::free_balance(AccountId::from(ALICE)),
+			ALICE_INITIAL_NATIVE_BALANCE - 10 * UNITS - spent
+		);
+	});
+}
+
+#[test]
+fn unpaid_message_should_fail_when_origin_is_not_sponsored() {
+	// Arrange
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		fund_acala_sovereign_account();
+		assert_ok!(hydradx_runtime::XcmFeeSponsor::register_sponsorship(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			Box::new(VersionedLocation::from(Location::new(1, [Parachain(MOONBEAM_PARA_ID)]))),
+			100 * UNITS,
+			100,
+			1_000,
+		));
+	});
+
+	// Act
+	send_unpaid_transfer_from_acala();
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processing_failed();
+
+		assert_eq!(
+			hydradx_runtime::Balances::free_balance(AccountId::from(BOB)),
+			BOB_INITIAL_NATIVE_BALANCE
+		);
+	});
+}
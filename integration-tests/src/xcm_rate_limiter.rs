@@ -0,0 +1,136 @@
+#![cfg(test)]
+use crate::polkadot_test_net::*;
+use crate::utils::xcm_scenario::{advance_blocks_on_all, send_xcm_transfer, BalanceSnapshot, Chain};
+
+use cumulus_primitives_core::ParaId;
+use frame_support::assert_ok;
+use hydradx_runtime::{AssetRegistry, RuntimeOrigin, XcmRateLimiter};
+use polkadot_xcm::opaque::v3::{Junction, Junctions::X2, MultiLocation};
+use pretty_assertions::assert_eq;
+
+const RATE_LIMIT: Balance = 10 * UNITS;
+
+fn limit_aca_transfers_from_acala() {
+	Chain::Hydra.execute_with(|| {
+		assert_ok!(AssetRegistry::set_location(
+			ACA,
+			hydradx_runtime::AssetLocation(MultiLocation::new(
+				1,
+				X2(Junction::Parachain(ACALA_PARA_ID), Junction::GeneralIndex(0))
+			))
+		));
+		assert_ok!(AssetRegistry::update(
+			RuntimeOrigin::root(),
+			ACA,
+			None,
+			None,
+			None,
+			Some(RATE_LIMIT),
+			None,
+			None,
+			None,
+			None,
+		));
+	});
+}
+
+fn deferred_indices() -> Vec<u32> {
+	Chain::Hydra.execute_with(|| {
+		XcmRateLimiter::deferred_messages(ParaId::from(ACALA_PARA_ID))
+			.into_iter()
+			.map(|(index, _)| index)
+			.collect()
+	})
+}
+
+#[test]
+fn transfer_within_rate_limit_should_not_be_deferred() {
+	// Arrange
+	TestNet::reset();
+	limit_aca_transfers_from_acala();
+	let alice = Chain::Acala.account(ALICE);
+	let bob = Chain::Hydra.account(BOB);
+	let balances = BalanceSnapshot::take(&[(bob, ACA)]);
+
+	// Act
+	send_xcm_transfer(alice, bob, 0, RATE_LIMIT / 2);
+	advance_blocks_on_all(1);
+
+	// Assert
+	assert!(balances.diff(bob, ACA) > 0, "transfer is not received");
+	assert_eq!(deferred_indices(), Vec::<u32>::new());
+}
+
+#[test]
+fn transfer_exceeding_rate_limit_should_be_deferred() {
+	// Arrange
+	TestNet::reset();
+	limit_aca_transfers_from_acala();
+	let alice = Chain::Acala.account(ALICE);
+	let bob = Chain::Hydra.account(BOB);
+	let balances = BalanceSnapshot::take(&[(bob, ACA)]);
+
+	// Act
+	send_xcm_transfer(alice, bob, 0, 3 * RATE_LIMIT);
+	advance_blocks_on_all(1);
+
+	// Assert
+	balances.assert_diff(bob, ACA, 0);
+	assert_eq!(deferred_indices(), vec![0]);
+}
+
+#[test]
+fn release_deferred_should_process_deferred_transfer() {
+	// Arrange
+	TestNet::reset();
+	limit_aca_transfers_from_acala();
+	let alice = Chain::Acala.account(ALICE);
+	let bob = Chain::Hydra.account(BOB);
+	let balances = BalanceSnapshot::take(&[(bob, ACA)]);
+	send_xcm_transfer(alice, bob, 0, 3 * RATE_LIMIT);
+	advance_blocks_on_all(1);
+
+	// Act
+	Chain::Hydra.execute_with(|| {
+		assert_ok!(XcmRateLimiter::release_deferred(
+			RuntimeOrigin::root(),
+			ACALA_PARA_ID.into(),
+			0
+		));
+	});
+	advance_blocks_on_all(1);
+
+	// Assert
+	let received = balances.diff(bob, ACA);
+	assert!(
+		received > 0 && received < 3 * RATE_LIMIT as i128,
+		"transfer is not received"
+	);
+	assert_eq!(deferred_indices(), Vec::<u32>::new());
+}
+
+#[test]
+fn discard_deferred_should_drop_deferred_transfer() {
+	// Arrange
+	TestNet::reset();
+	limit_aca_transfers_from_acala();
+	let alice = Chain::Acala.account(ALICE);
+	let bob = Chain::Hydra.account(BOB);
+	let balances = BalanceSnapshot::take(&[(bob, ACA)]);
+	send_xcm_transfer(alice, bob, 0, 3 * RATE_LIMIT);
+	advance_blocks_on_all(1);
+
+	// Act
+	Chain::Hydra.execute_with(|| {
+		assert_ok!(XcmRateLimiter::discard_deferred(
+			RuntimeOrigin::root(),
+			ACALA_PARA_ID.into(),
+			0
+		));
+	});
+	advance_blocks_on_all(1);
+
+	// Assert
+	balances.assert_diff(bob, ACA, 0);
+	assert_eq!(deferred_indices(), Vec::<u32>::new());
+}
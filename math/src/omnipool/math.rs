@@ -187,6 +187,89 @@ pub fn calculate_buy_for_hub_asset_state_changes(
 	})
 }
 
+/// Calculate delta changes of a sell trade where asset_out is Hub Asset.
+///
+/// Hub asset amount received by the seller is `delta_hub_reserve` less the protocol fee.
+pub fn calculate_sell_for_hub_asset_state_changes(
+	asset_in_state: &AssetReserveState<Balance>,
+	amount: Balance,
+	protocol_fee: Permill,
+	imbalance: Balance,
+) -> Option<HubTradeStateChange<Balance>> {
+	let (in_hub_reserve, in_reserve, in_amount) = to_u256!(asset_in_state.hub_reserve, asset_in_state.reserve, amount);
+
+	let delta_hub_reserve = in_amount
+		.checked_mul(in_hub_reserve)
+		.and_then(|v| v.checked_div(in_reserve.checked_add(in_amount)?))?;
+
+	let delta_hub_reserve = to_balance!(delta_hub_reserve).ok()?;
+
+	let protocol_fee_amount = protocol_fee.mul_floor(delta_hub_reserve);
+
+	let delta_imbalance = min(protocol_fee_amount, imbalance);
+
+	Some(HubTradeStateChange {
+		asset: AssetStateChange {
+			delta_reserve: Increase(amount),
+			delta_hub_reserve: Decrease(delta_hub_reserve),
+			..Default::default()
+		},
+		delta_imbalance: Increase(delta_imbalance),
+		fee: TradeFee {
+			protocol_fee: protocol_fee_amount,
+			..Default::default()
+		},
+	})
+}
+
+/// Calculate delta changes of a buy trade where asset_out is Hub Asset.
+///
+/// `hub_asset_amount` is the amount received by the buyer, protocol fee is added on top of it.
+pub fn calculate_buy_hub_asset_state_changes(
+	asset_in_state: &AssetReserveState<Balance>,
+	hub_asset_amount: Balance,
+	protocol_fee: Permill,
+	imbalance: Balance,
+) -> Option<HubTradeStateChange<Balance>> {
+	if protocol_fee == Permill::one() {
+		return None;
+	}
+
+	let protocol_fee_amount = calculate_fee_amount_for_buy(protocol_fee, hub_asset_amount);
+	let delta_hub_reserve = hub_asset_amount.checked_add(protocol_fee_amount)?;
+
+	let hub_denominator = asset_in_state.hub_reserve.checked_sub(delta_hub_reserve)?;
+
+	if hub_denominator.is_zero() {
+		return None;
+	}
+
+	let (in_reserve_hp, delta_hub_reserve_hp, hub_denominator_hp) =
+		to_u256!(asset_in_state.reserve, delta_hub_reserve, hub_denominator);
+
+	let delta_reserve_hp = in_reserve_hp.checked_mul(delta_hub_reserve_hp).and_then(|v| {
+		v.checked_div(hub_denominator_hp)
+			.and_then(|v| v.checked_add(U256::one()))
+	})?;
+
+	let delta_reserve = to_balance!(delta_reserve_hp).ok()?;
+
+	let delta_imbalance = min(protocol_fee_amount, imbalance);
+
+	Some(HubTradeStateChange {
+		asset: AssetStateChange {
+			delta_reserve: Increase(delta_reserve),
+			delta_hub_reserve: Decrease(delta_hub_reserve),
+			..Default::default()
+		},
+		delta_imbalance: Increase(delta_imbalance),
+		fee: TradeFee {
+			protocol_fee: protocol_fee_amount,
+			..Default::default()
+		},
+	})
+}
+
 /// Calculate delta changes of a buy trade given current state of asset in and out
 pub fn calculate_buy_state_changes(
 	asset_in_state: &AssetReserveState<Balance>,
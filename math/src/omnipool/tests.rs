@@ -1,8 +1,9 @@
 use crate::omnipool::types::{AssetReserveState, BalanceUpdate, Position, TradeFee, I129};
 use crate::omnipool::{
-	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes, calculate_buy_state_changes,
-	calculate_cap_difference, calculate_delta_imbalance, calculate_fee_amount_for_buy,
-	calculate_remove_liquidity_state_changes, calculate_sell_hub_state_changes, calculate_sell_state_changes,
+	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes,
+	calculate_buy_hub_asset_state_changes, calculate_buy_state_changes, calculate_cap_difference,
+	calculate_delta_imbalance, calculate_fee_amount_for_buy, calculate_remove_liquidity_state_changes,
+	calculate_sell_for_hub_asset_state_changes, calculate_sell_hub_state_changes, calculate_sell_state_changes,
 	calculate_tvl_cap_difference, calculate_withdrawal_fee, verify_asset_cap,
 };
 use crate::types::Balance;
@@ -1171,3 +1172,87 @@ fn calculate_buy_should_charge_more_when_fee_is_not_zero() {
 		}
 	);
 }
+
+#[test]
+fn calculate_sell_for_hub_asset_should_work_when_correct_input_provided() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let amount_to_sell = 10 * UNIT;
+	let protocol_fee = Permill::from_percent(1);
+	let imbalance = 2 * UNIT;
+
+	let state_changes =
+		calculate_sell_for_hub_asset_state_changes(&asset_state, amount_to_sell, protocol_fee, imbalance);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(amount_to_sell)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(10 * UNIT)
+	);
+	assert_eq!(
+		state_changes.delta_imbalance,
+		BalanceUpdate::Increase(100_000_000_000u128)
+	);
+	assert_eq!(
+		state_changes.fee,
+		TradeFee {
+			asset_fee: 0,
+			protocol_fee: 100_000_000_000u128,
+		}
+	);
+}
+
+#[test]
+fn calculate_buy_hub_asset_should_work_when_correct_input_provided() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let amount_to_buy = 9_900_000_000_000u128;
+	let protocol_fee = Permill::from_percent(1);
+	let imbalance = 0u128;
+
+	let state_changes = calculate_buy_hub_asset_state_changes(&asset_state, amount_to_buy, protocol_fee, imbalance);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(10_000_000_000_003u128)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(10_000_000_000_001u128)
+	);
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(0u128));
+	assert_eq!(state_changes.fee.protocol_fee, 100_000_000_001u128);
+}
+
+#[test]
+fn calculate_buy_hub_asset_should_fail_when_whole_hub_reserve_is_bought() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	assert!(calculate_buy_hub_asset_state_changes(&asset_state, 20 * UNIT, Permill::zero(), 0u128).is_none());
+}
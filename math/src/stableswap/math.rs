@@ -1,4 +1,4 @@
-use crate::stableswap::types::AssetReserve;
+use crate::stableswap::types::{AssetReserve, PegType};
 
 use crate::support::rational::round_to_rational;
 use crate::to_u256;
@@ -631,7 +631,8 @@ fn abs_diff(d0: U256, d1: U256) -> U256 {
 	}
 }
 
-pub(crate) enum Rounding {
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Rounding {
 	Down,
 	Up,
 }
@@ -667,6 +668,49 @@ pub(crate) fn normalize_value(amount: Balance, decimals: u8, target_decimals: u8
 	}
 }
 
+/// Convert amount of an asset to units of pool's peg base using given peg.
+pub fn peg_amount(amount: Balance, peg: PegType, rounding: Rounding) -> Option<Balance> {
+	mul_div(amount, peg.0, peg.1, rounding)
+}
+
+/// Convert amount expressed in units of pool's peg base back to units of an asset using given peg.
+pub fn unpeg_amount(amount: Balance, peg: PegType, rounding: Rounding) -> Option<Balance> {
+	mul_div(amount, peg.1, peg.0, rounding)
+}
+
+/// Apply pegs to pool reserves. Resulting reserves are rounded down.
+///
+/// Number of pegs must match number of reserves.
+pub fn peg_reserves(reserves: &[AssetReserve], pegs: &[PegType]) -> Option<Vec<AssetReserve>> {
+	if reserves.len() != pegs.len() {
+		return None;
+	}
+	reserves
+		.iter()
+		.zip(pegs.iter())
+		.map(|(reserve, peg)| {
+			Some(AssetReserve::new(
+				peg_amount(reserve.amount, *peg, Rounding::Down)?,
+				reserve.decimals,
+			))
+		})
+		.collect()
+}
+
+fn mul_div(amount: Balance, n: Balance, d: Balance, rounding: Rounding) -> Option<Balance> {
+	if d.is_zero() {
+		return None;
+	}
+	let (amount, n, d) = to_u256!(amount, n, d);
+	let (result, remainder) = amount.checked_mul(n)?.div_mod(d);
+	let result = if rounding == Rounding::Up && !remainder.is_zero() {
+		result.checked_add(U256::one())?
+	} else {
+		result
+	};
+	Balance::try_from(result).ok()
+}
+
 pub fn calculate_share_prices<const D: u8>(
 	reserves: &[AssetReserve],
 	amplification: Balance,
@@ -985,4 +1029,38 @@ mod tests {
 
 		assert!(calculate_share_price::<MAX_D_ITERATIONS>(&reserves, amp, 1000000000000000, 4, None).is_none());
 	}
+
+	#[test]
+	fn peg_amount_should_round_according_to_rounding() {
+		let peg = (3, 2);
+
+		assert_eq!(peg_amount(5, peg, Rounding::Down), Some(7));
+		assert_eq!(peg_amount(5, peg, Rounding::Up), Some(8));
+		assert_eq!(unpeg_amount(7, peg, Rounding::Down), Some(4));
+		assert_eq!(unpeg_amount(7, peg, Rounding::Up), Some(5));
+		assert_eq!(peg_amount(4, peg, Rounding::Up), Some(6));
+	}
+
+	#[test]
+	fn peg_amount_should_fail_when_peg_is_invalid() {
+		assert!(peg_amount(5, (1, 0), Rounding::Down).is_none());
+		assert!(unpeg_amount(5, (0, 1), Rounding::Down).is_none());
+		assert!(peg_amount(u128::MAX, (2, 1), Rounding::Down).is_none());
+	}
+
+	#[test]
+	fn peg_reserves_should_scale_reserves_by_pegs() {
+		let reserves = vec![
+			AssetReserve::new(1_000_000_000_000_000, 12),
+			AssetReserve::new(1_000_000_000_000_000_000_000, 18),
+		];
+
+		let pegged = peg_reserves(&reserves, &[(1, 1), (105, 100)]).unwrap();
+
+		assert_eq!(pegged[0].amount, 1_000_000_000_000_000);
+		assert_eq!(pegged[0].decimals, 12);
+		assert_eq!(pegged[1].amount, 1_050_000_000_000_000_000_000);
+		assert_eq!(pegged[1].decimals, 18);
+		assert!(peg_reserves(&reserves, &[(1, 1)]).is_none());
+	}
 }
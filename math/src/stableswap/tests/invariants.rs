@@ -415,3 +415,31 @@ proptest! {
 		assert!(diff <= 1000)
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn calculate_shares_for_amount_should_not_decrease_when_fee_is_applied(
+		pool in some_pool(3),
+		amount in trade_amount(),
+		amp in amplification(),
+		fee in 0..100_000u32,
+	) {
+		let balances = pool
+			.iter()
+			.map(|v| normalize_value(v.amount, v.decimals, 18u8, Rounding::Down))
+			.collect::<Vec<Balance>>();
+
+		let issuance = balances.iter().sum();
+		let amount = to_precision(amount, pool[1].decimals);
+
+		let shares_without_fee =
+			calculate_shares_for_amount::<D_ITERATIONS>(&pool, 1, amount, amp, issuance, Permill::zero()).unwrap();
+		let shares_with_fee =
+			calculate_shares_for_amount::<D_ITERATIONS>(&pool, 1, amount, amp, issuance, Permill::from_parts(fee))
+				.unwrap();
+
+		// Fee is paid by LP - more shares are needed for the same amount.
+		assert!(shares_with_fee >= shares_without_fee);
+	}
+}
@@ -1,6 +1,11 @@
 use crate::types::Balance;
 use num_traits::Zero;
 
+/// Peg of an asset in a pool - (numerator, denominator).
+///
+/// Value of one unit of the asset expressed in units of pool's peg base.
+pub type PegType = (Balance, Balance);
+
 #[derive(Debug, Clone, Copy)]
 pub struct AssetReserve {
 	pub amount: Balance,
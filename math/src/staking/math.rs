@@ -1,6 +1,10 @@
 use crate::types::Balance;
 use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
-use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128, Perbill, Permill};
+use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_arithmetic::{
+	traits::{Saturating, Zero},
+	FixedPointNumber, FixedU128, Perbill, Permill, Rounding,
+};
 use sp_std::num::NonZeroU128;
 use sp_std::ops::Div;
 
@@ -88,6 +92,27 @@ pub fn calculate_points(
 		.checked_sub(slashed_points)
 }
 
+/// Function calculates amount of action points left after `inactive_periods` without any new
+/// action. Points decay linearly and are gone completely after `decay_periods`.
+/// Calculated points are rounded down.
+///
+/// Parameters:
+/// - `action_points`: amount of action points accumulated by user
+/// - `inactive_periods`: number of periods since user received action points for the last time
+/// - `decay_periods`: number of periods after which all action points are lost. `0` disables decay
+pub fn calculate_decayed_action_points(
+	action_points: Point,
+	inactive_periods: Period,
+	decay_periods: Period,
+) -> Option<Point> {
+	if decay_periods.is_zero() {
+		return Some(action_points);
+	}
+
+	let remaining_periods = decay_periods.saturating_sub(inactive_periods);
+	multiply_by_rational_with_rounding(action_points, remaining_periods, decay_periods, Rounding::Down)
+}
+
 /// Implementation of sigmoid function returning values from range [0,1)
 ///
 /// f(x) = (ax)^4/(b + (ax)^4)
@@ -155,6 +155,24 @@ fn calculate_points_should_work() {
 	);
 }
 
+#[test]
+fn calculate_decayed_action_points_should_work() {
+	//no decay configured
+	assert_eq!(calculate_decayed_action_points(10_000, 1_000, 0), Some(10_000));
+
+	//no inactive periods
+	assert_eq!(calculate_decayed_action_points(10_000, 0, 90), Some(10_000));
+
+	assert_eq!(calculate_decayed_action_points(10_000, 30, 90), Some(6_666));
+	assert_eq!(calculate_decayed_action_points(10_000, 45, 90), Some(5_000));
+	assert_eq!(calculate_decayed_action_points(10_000, 89, 90), Some(111));
+
+	//all points decayed
+	assert_eq!(calculate_decayed_action_points(10_000, 90, 90), Some(0));
+	assert_eq!(calculate_decayed_action_points(10_000, 1_000, 90), Some(0));
+	assert_eq!(calculate_decayed_action_points(u128::MAX, 1, 2), Some(u128::MAX / 2));
+}
+
 #[test]
 fn sigmoid_should_work() {
 	let a = FixedU128::from_inner(8_000_000_000_000_000);
@@ -157,6 +157,71 @@ pub fn calculate_liquidity_in(
 	to_balance!(b_required_hp)
 }
 
+/// Calculating amount of asset to sell when providing liquidity in single asset.
+///
+/// Selling the returned amount leaves the rest of `amount` and the amount received from the trade in ratio of
+/// pool reserves after the trade. Trade fee is taken from the amount out and stays in the pool.
+///
+/// Formula : (sqrt(B^2 + 4 * AMOUNT * IN_RESERVE) - B) / 2
+/// where B = (2 - FEE) * IN_RESERVE - FEE * AMOUNT
+///
+/// - `in_reserve` - reserve amount of provided asset
+/// - `amount` - amount of provided asset
+/// - `fee` - trade fee rate of the pool
+///
+/// Returns MathError in case of error
+pub fn calculate_single_asset_sell_amount(
+	in_reserve: Balance,
+	amount: Balance,
+	fee: (u32, u32),
+) -> Result<Balance, MathError> {
+	ensure!(in_reserve != 0, ZeroReserve);
+
+	if amount.is_zero() {
+		return Ok(Balance::zero());
+	}
+
+	let (fee_n, fee_d) = if fee.1 == 0 { (0, 1) } else { fee };
+	ensure!(fee_n <= fee_d, Overflow);
+
+	let (in_reserve_hp, amount_hp, fee_n_hp, fee_d_hp) = to_u256!(in_reserve, amount, fee_n, fee_d);
+
+	// All terms are multiplied by fee denominator to keep the fee rate exact.
+	let b_pos = fee_d_hp
+		.checked_mul(U256::from(2))
+		.and_then(|v| v.checked_sub(fee_n_hp))
+		.and_then(|v| v.checked_mul(in_reserve_hp))
+		.ok_or(Overflow)?;
+	let b_neg = fee_n_hp.checked_mul(amount_hp).ok_or(Overflow)?;
+	let b_abs = if b_pos >= b_neg { b_pos - b_neg } else { b_neg - b_pos };
+
+	let discriminant = b_abs
+		.checked_mul(b_abs)
+		.and_then(|v| {
+			amount_hp
+				.checked_mul(in_reserve_hp)
+				.and_then(|v| v.checked_mul(fee_d_hp))
+				.and_then(|v| v.checked_mul(fee_d_hp))
+				.and_then(|v| v.checked_mul(U256::from(4)))
+				.and_then(|r| v.checked_add(r))
+		})
+		.ok_or(Overflow)?;
+	let root = discriminant.integer_sqrt();
+
+	let numerator = if b_pos >= b_neg {
+		root.checked_sub(b_abs)
+	} else {
+		root.checked_add(b_abs)
+	}
+	.ok_or(Overflow)?;
+
+	let sell_amount_hp = numerator
+		.checked_div(fee_d_hp.checked_mul(U256::from(2)).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+
+	Ok(to_balance!(sell_amount_hp)?.min(amount))
+}
+
 /// Calculating amount of assets returned when removing liquidity.
 /// Formula A: AMOUNT * ASSET_A_RESERVE / TOTAL_LIQUIDITY
 /// Formula B: AMOUNT * ASSET_B_RESERVE / TOTAL_LIQUIDITY
@@ -77,6 +77,41 @@ fn in_given_out_should_work() {
 	}
 }
 
+#[test]
+fn single_asset_sell_amount_should_work() {
+	let cases = vec![
+		(1000, 1000, (0, 1), Ok(414), "Easy case"),
+		(1000, 0, (3, 1000), Ok(0), "amount is zero"),
+		(
+			1_000_000_000_000_000,
+			100_000_000_000_000,
+			(3, 1000),
+			Ok(48_885_753_066_137),
+			"with fee",
+		),
+		(
+			2_000_000_000_000_000_000,
+			1_000_000_000_000_000_000,
+			(3, 1000),
+			Ok(450_316_888_076_456_143),
+			"large amount",
+		),
+		(1000, 1000, (0, 0), Ok(414), "fee denominator is zero"),
+		(0, 1000, (3, 1000), Err(ZeroReserve), "reserve is zero"),
+		(1000, 1000, (2, 1), Err(Overflow), "fee is greater than one"),
+		(u128::MAX, u128::MAX, (3, 1000), Err(Overflow), "overflow"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			crate::xyk::calculate_single_asset_sell_amount(case.0, case.1, case.2),
+			case.3,
+			"{}",
+			case.4
+		);
+	}
+}
+
 #[test]
 fn add_liquidity_should_work() {
 	let cases = vec![
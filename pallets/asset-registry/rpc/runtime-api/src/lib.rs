@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct AssetTickers {
+	/// Symbol of the asset.
+	pub symbol: Option<Vec<u8>>,
+	/// Aliases of the asset set by governance.
+	pub aliases: Vec<Vec<u8>>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait AssetRegistryApi<AssetId> where
+		AssetId: Codec,
+	{
+		/// Asset registered under given symbol or alias. Lookup is case-insensitive.
+		fn asset_by_ticker(ticker: Vec<u8>) -> Option<AssetId>;
+		/// Symbol and aliases of an asset. `None` if the asset is not registered.
+		fn asset_tickers(asset_id: AssetId) -> Option<AssetTickers>;
+	}
+}
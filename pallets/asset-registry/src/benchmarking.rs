@@ -25,6 +25,50 @@ use frame_support::traits::tokens::fungibles::Mutate as FungiblesMutate;
 use frame_system::RawOrigin;
 use sp_std::vec;
 
+fn create_holders<T: Config>(
+	asset_id: T::AssetId,
+	n: u32,
+	amount: Balance,
+) -> Result<BoundedVec<T::AccountId, T::MaxHoldersForParameterChange>, DispatchError>
+where
+	T::Currency: FungiblesMutate<T::AccountId>,
+{
+	let mut holders = vec![];
+	for i in 0..n {
+		let who: T::AccountId = account("holder", i, 1);
+		T::Currency::mint_into(asset_id, &who, amount)?;
+		holders.push(who);
+	}
+
+	Ok(holders.try_into().unwrap())
+}
+
+fn alias<T: Config>(i: u32) -> Symbol<T::StringLimit> {
+	vec![98u8 + (i % 24) as u8, 98u8 + (i / 24 % 24) as u8, 97u8, 97u8]
+		.try_into()
+		.unwrap()
+}
+
+fn register_asset_with_aliases<T: Config>(asset_id: T::AssetId, n: u32) -> DispatchResult {
+	let symbol = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+	Pallet::<T>::register(
+		RawOrigin::Root.into(),
+		Some(asset_id),
+		None,
+		AssetType::Token,
+		None,
+		Some(symbol),
+		None,
+		None,
+		None,
+		true,
+	)?;
+	for i in 0..n {
+		Pallet::<T>::add_alias(RawOrigin::Root.into(), asset_id, alias::<T>(i))?;
+	}
+	Ok(())
+}
+
 benchmarks! {
 	 where_clause { where
 		T::Currency: FungiblesMutate<T::AccountId>,
@@ -135,6 +179,59 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::banned_assets(asset_id), None);
 	}
 
+	set_sufficiency {
+		let n in 0 .. T::MaxHoldersForParameterChange::get();
+
+		let asset_id = T::AssetId::from(3);
+		let ed = 1_000_000_u128;
+		let _ = Pallet::<T>::register(RawOrigin::Root.into(), Some(asset_id), None, AssetType::Token, Some(ed), None, None, None, None, true);
+
+		let holders = create_holders::<T>(asset_id, n, ed)?;
+	}: _(RawOrigin::Root, asset_id, false, holders)
+	verify {
+		assert!(!Pallet::<T>::assets(asset_id).unwrap().is_sufficient);
+	}
+
+	set_existential_deposit {
+		let n in 0 .. T::MaxHoldersForParameterChange::get();
+
+		let asset_id = T::AssetId::from(3);
+		let ed = 1_000_000_u128;
+		let _ = Pallet::<T>::register(RawOrigin::Root.into(), Some(asset_id), None, AssetType::Token, Some(ed), None, None, None, None, true);
+
+		let holders = create_holders::<T>(asset_id, n, 2 * ed)?;
+	}: _(RawOrigin::Root, asset_id, 2 * ed, holders)
+	verify {
+		assert_eq!(Pallet::<T>::assets(asset_id).unwrap().existential_deposit, 2 * ed);
+	}
+
+	promote_asset {
+		let location: T::AssetNativeLocation = Default::default();
+		let asset_id = Pallet::<T>::register_quarantined_asset(location)?;
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert_eq!(Pallet::<T>::quarantined_assets(asset_id), None);
+	}
+
+	add_alias {
+		let asset_id = T::AssetId::from(3);
+		let max_aliases = T::MaxAliases::get();
+		register_asset_with_aliases::<T>(asset_id, max_aliases.saturating_sub(1))?;
+		let new_alias = alias::<T>(max_aliases);
+	}: _(RawOrigin::Root, asset_id, new_alias.clone())
+	verify {
+		assert_eq!(Pallet::<T>::ticker_assets(Pallet::<T>::normalize_ticker(&new_alias)), Some(asset_id));
+	}
+
+	remove_alias {
+		let asset_id = T::AssetId::from(3);
+		let max_aliases = T::MaxAliases::get();
+		register_asset_with_aliases::<T>(asset_id, max_aliases)?;
+		let removed_alias = alias::<T>(max_aliases.saturating_sub(1));
+	}: _(RawOrigin::Root, asset_id, removed_alias.clone())
+	verify {
+		assert_eq!(Pallet::<T>::ticker_assets(Pallet::<T>::normalize_ticker(&removed_alias)), None);
+	}
 
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
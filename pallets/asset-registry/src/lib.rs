@@ -45,12 +45,12 @@ pub use types::AssetType;
 pub use pallet::*;
 
 pub use crate::types::{AssetDetails, Balance, Name, Symbol};
-use frame_support::storage::with_transaction;
+use frame_support::storage::{with_storage_layer, with_transaction};
 use frame_support::BoundedVec;
 use hydradx_traits::evm::EvmAddress;
 use hydradx_traits::{
 	registry::{Create, Inspect, Mutate},
-	AssetKind, BoundErc20, RegisterAssetHook,
+	AssetKind, BoundErc20, RegisterAssetHook, SufficiencyChangeHook,
 };
 use orml_traits::GetByKey;
 use polkadot_xcm::v3::Junction::AccountKey20;
@@ -69,7 +69,7 @@ pub mod pallet {
 
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	pub type AssetDetailsT<T> = AssetDetails<<T as Config>::StringLimit>;
 
@@ -118,6 +118,17 @@ pub mod pallet {
 		/// Hook executed after new asset is registered
 		type RegisterAssetHook: RegisterAssetHook<Self::AssetId>;
 
+		/// Hook executed for every holder of an asset whose sufficiency was changed
+		type SufficiencyChangeHook: SufficiencyChangeHook<Self::AccountId, Self::AssetId>;
+
+		/// Max number of holders of an asset whose sufficiency or existential deposit can be changed
+		#[pallet::constant]
+		type MaxHoldersForParameterChange: Get<u32>;
+
+		/// Max number of aliases an asset can have.
+		#[pallet::constant]
+		type MaxAliases: Get<u32>;
+
 		/// Weight information for the extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -182,6 +193,27 @@ pub mod pallet {
 
 		/// Asset is not banned.
 		AssetNotBanned,
+
+		/// Provided holders are duplicated or don't hold total issuance of the asset.
+		InvalidHolders,
+
+		/// Balance of a holder is below the new existential deposit.
+		HolderBelowExistentialDeposit,
+
+		/// Asset already has the requested sufficiency.
+		SufficiencyNotChanged,
+
+		/// Asset is not quarantined.
+		AssetNotQuarantined,
+
+		/// Symbol or alias is already used by another asset.
+		TickerAlreadyUsed,
+
+		/// Asset already has the maximum number of aliases.
+		TooManyAliases,
+
+		/// Alias is not registered for the asset.
+		AliasNotFound,
 	}
 
 	#[pallet::type_value]
@@ -223,11 +255,27 @@ pub mod pallet {
 	pub type LocationAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetNativeLocation, T::AssetId, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn quarantined_assets)]
+	/// Assets registered automatically from an incoming XCM transfer, waiting to be promoted by governance.
+	pub type QuarantinedAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, (), OptionQuery>;
+
 	#[pallet::storage]
 	/// Number of accounts that paid existential deposits for insufficient assets.
 	/// This storage is used by `SufficiencyCheck`.
 	pub type ExistentialDepositCounter<T: Config> = StorageValue<_, u128, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn ticker_assets)]
+	/// Mapping between normalized ticker and asset id. Contains symbols and aliases of all assets.
+	pub type TickerAssets<T: Config> = StorageMap<_, Blake2_128Concat, Symbol<T::StringLimit>, T::AssetId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_aliases)]
+	/// Aliases of an asset set by governance.
+	pub type AssetAliases<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BoundedVec<Symbol<T::StringLimit>, T::MaxAliases>, ValueQuery>;
+
 	#[allow(clippy::type_complexity)]
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -277,6 +325,7 @@ pub mod pallet {
 				};
 
 				Assets::<T>::insert(native_asset_id, details);
+				TickerAssets::<T>::insert(Pallet::<T>::normalize_ticker(&self.native_symbol), native_asset_id);
 
 				self.registered_assets.iter().for_each(
 					|(id, name, ed, symbol, decimals, xcm_rate_limit, is_sufficient)| {
@@ -345,6 +394,36 @@ pub mod pallet {
 
 		/// Asset's ban was removed.
 		AssetUnbanned { asset_id: T::AssetId },
+
+		/// Sufficiency or existential deposit of an asset was changed.
+		/// `migrated_holders` is the number of holders whose references were migrated.
+		AssetParametersChanged {
+			asset_id: T::AssetId,
+			existential_deposit: Balance,
+			is_sufficient: bool,
+			migrated_holders: u32,
+		},
+
+		/// Asset of unknown location was registered in quarantine.
+		AssetQuarantined {
+			asset_id: T::AssetId,
+			location: T::AssetNativeLocation,
+		},
+
+		/// Quarantine of an asset was lifted.
+		AssetPromoted { asset_id: T::AssetId },
+
+		/// Alias was added to an asset.
+		AliasAdded {
+			asset_id: T::AssetId,
+			alias: Symbol<T::StringLimit>,
+		},
+
+		/// Alias of an asset was removed.
+		AliasRemoved {
+			asset_id: T::AssetId,
+			alias: Symbol<T::StringLimit>,
+		},
 	}
 
 	#[pallet::call]
@@ -446,6 +525,20 @@ pub mod pallet {
 				detail.asset_type = asset_type.unwrap_or(detail.asset_type);
 				detail.existential_deposit = existential_deposit.unwrap_or(detail.existential_deposit);
 				detail.xcm_rate_limit = xcm_rate_limit.or(detail.xcm_rate_limit);
+
+				if let Some(new_symbol) = symbol.as_ref() {
+					Self::do_set_ticker(asset_id, new_symbol)?;
+
+					if let Some(old_symbol) = &detail.symbol {
+						let old_ticker = Self::normalize_ticker(old_symbol);
+						if old_ticker != Self::normalize_ticker(new_symbol)
+							&& Self::ticker_assets(&old_ticker) == Some(asset_id)
+						{
+							TickerAssets::<T>::remove(old_ticker);
+						}
+					}
+				}
+
 				detail.symbol = symbol.or_else(|| detail.symbol.clone());
 
 				let suff = is_sufficient.unwrap_or(detail.is_sufficient);
@@ -536,6 +629,187 @@ pub mod pallet {
 			Self::deposit_event(Event::AssetUnbanned { asset_id });
 			Ok(())
 		}
+
+		/// Change sufficiency of a registered asset.
+		///
+		/// `holders` must be all accounts holding the asset - their total balance has to be equal to total issuance
+		/// of the asset. Changing sufficiency of assets with more than `MaxHoldersForParameterChange` holders is
+		/// not possible.
+		///
+		/// References of all holders with non-zero balance are migrated by `SufficiencyChangeHook`.
+		///
+		/// Emits `AssetParametersChanged` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sufficiency(holders.len() as u32)
+			.saturating_add(
+				T::SufficiencyChangeHook::on_sufficiency_changed_weight().saturating_mul(holders.len() as u64)
+			)
+		)]
+		pub fn set_sufficiency(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			is_sufficient: bool,
+			holders: BoundedVec<T::AccountId, T::MaxHoldersForParameterChange>,
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let mut details = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+			ensure!(
+				details.is_sufficient != is_sufficient,
+				Error::<T>::SufficiencyNotChanged
+			);
+
+			let holders = Self::ensure_all_holders(asset_id, holders)?;
+
+			details.is_sufficient = is_sufficient;
+			Assets::<T>::insert(asset_id, &details);
+
+			for (who, _) in holders.iter() {
+				T::SufficiencyChangeHook::on_sufficiency_changed(asset_id, who, is_sufficient);
+			}
+
+			Self::deposit_event(Event::AssetParametersChanged {
+				asset_id,
+				existential_deposit: details.existential_deposit,
+				is_sufficient,
+				migrated_holders: holders.len() as u32,
+			});
+
+			Ok(())
+		}
+
+		/// Change existential deposit of a registered asset.
+		///
+		/// `holders` must be all accounts holding the asset - their total balance has to be equal to total issuance
+		/// of the asset. Balance of every holder has to be at least the new existential deposit, so no account is
+		/// dusted by the change.
+		///
+		/// Emits `AssetParametersChanged` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_existential_deposit(holders.len() as u32))]
+		pub fn set_existential_deposit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			existential_deposit: Balance,
+			holders: BoundedVec<T::AccountId, T::MaxHoldersForParameterChange>,
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let mut details = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+
+			let holders = Self::ensure_all_holders(asset_id, holders)?;
+			ensure!(
+				holders.iter().all(|(_, balance)| *balance >= existential_deposit),
+				Error::<T>::HolderBelowExistentialDeposit
+			);
+
+			details.existential_deposit = existential_deposit;
+			Assets::<T>::insert(asset_id, &details);
+
+			Self::deposit_event(Event::AssetParametersChanged {
+				asset_id,
+				existential_deposit,
+				is_sufficient: details.is_sufficient,
+				migrated_holders: 0,
+			});
+
+			Ok(())
+		}
+
+		/// Lift quarantine of an asset registered automatically from an incoming XCM transfer.
+		///
+		/// Metadata and sufficiency of the asset are not changed - they should be set by `update` and
+		/// `set_sufficiency`.
+		///
+		/// Emits `AssetPromoted` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::promote_asset())]
+		pub fn promote_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				QuarantinedAssets::<T>::take(asset_id).is_some(),
+				Error::<T>::AssetNotQuarantined
+			);
+
+			Self::deposit_event(Event::AssetPromoted { asset_id });
+			Ok(())
+		}
+
+		/// Add an alias to a registered asset.
+		///
+		/// Alias shares the ticker namespace with symbols - it can't be used by any other asset as a symbol
+		/// or an alias. Tickers are compared case-insensitively.
+		///
+		/// Emits `AliasAdded` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_alias())]
+		pub fn add_alias(origin: OriginFor<T>, asset_id: T::AssetId, alias: Symbol<T::StringLimit>) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+			Self::validate_symbol(&Some(alias.clone()))?;
+
+			let ticker = Self::normalize_ticker(&alias);
+			ensure!(!TickerAssets::<T>::contains_key(&ticker), Error::<T>::TickerAlreadyUsed);
+
+			AssetAliases::<T>::try_mutate(asset_id, |aliases| -> DispatchResult {
+				aliases
+					.try_push(alias.clone())
+					.map_err(|_| Error::<T>::TooManyAliases)?;
+				Ok(())
+			})?;
+			TickerAssets::<T>::insert(ticker, asset_id);
+
+			Self::deposit_event(Event::AliasAdded { asset_id, alias });
+			Ok(())
+		}
+
+		/// Remove an alias of a registered asset.
+		///
+		/// Alias is matched case-insensitively.
+		///
+		/// Emits `AliasRemoved` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_alias())]
+		pub fn remove_alias(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			alias: Symbol<T::StringLimit>,
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let ticker = Self::normalize_ticker(&alias);
+			let removed = AssetAliases::<T>::try_mutate_exists(
+				asset_id,
+				|maybe_aliases| -> Result<Symbol<T::StringLimit>, DispatchError> {
+					let aliases = maybe_aliases.as_mut().ok_or(Error::<T>::AliasNotFound)?;
+					let idx = aliases
+						.iter()
+						.position(|a| Self::normalize_ticker(a) == ticker)
+						.ok_or(Error::<T>::AliasNotFound)?;
+					let removed = aliases.remove(idx);
+					if aliases.is_empty() {
+						*maybe_aliases = None;
+					}
+					Ok(removed)
+				},
+			)?;
+
+			// Symbol of the asset can be the same as its alias if it was updated after the alias was added.
+			let is_symbol = Self::assets(asset_id)
+				.and_then(|d| d.symbol)
+				.is_some_and(|s| Self::normalize_ticker(&s) == ticker);
+			if !is_symbol {
+				TickerAssets::<T>::remove(ticker);
+			}
+
+			Self::deposit_event(Event::AliasRemoved {
+				asset_id,
+				alias: removed,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -552,6 +826,73 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Return upper-cased `symbol` which is used as a key of the ticker registry.
+	pub fn normalize_ticker(symbol: &Symbol<T::StringLimit>) -> Symbol<T::StringLimit> {
+		BoundedVec::truncate_from(symbol.iter().map(u8::to_ascii_uppercase).collect())
+	}
+
+	/// Register `symbol` as a ticker of `asset_id`.
+	///
+	/// Fails if the ticker is already used by a different asset.
+	fn do_set_ticker(asset_id: T::AssetId, symbol: &Symbol<T::StringLimit>) -> DispatchResult {
+		let ticker = Self::normalize_ticker(symbol);
+		if let Some(owner) = Self::ticker_assets(&ticker) {
+			ensure!(owner == asset_id, Error::<T>::TickerAlreadyUsed);
+		}
+		TickerAssets::<T>::insert(ticker, asset_id);
+		Ok(())
+	}
+
+	/// Return asset registered under `ticker` as a symbol or an alias.
+	pub fn asset_by_ticker(ticker: Vec<u8>) -> Option<T::AssetId> {
+		let ticker: Symbol<T::StringLimit> = ticker.try_into().ok()?;
+		Self::ticker_assets(Self::normalize_ticker(&ticker))
+	}
+
+	/// Return symbol and aliases of an asset.
+	pub fn asset_tickers(asset_id: T::AssetId) -> Option<(Option<Vec<u8>>, Vec<Vec<u8>>)> {
+		let details = Self::assets(asset_id)?;
+		let aliases = Self::asset_aliases(asset_id)
+			.into_iter()
+			.map(|a| a.into_inner())
+			.collect();
+		Some((details.symbol.map(|s| s.into_inner()), aliases))
+	}
+
+	/// Ensure `holders` are all accounts holding `asset_id`.
+	///
+	/// Returns holders with non-zero balance and their balances.
+	fn ensure_all_holders(
+		asset_id: T::AssetId,
+		holders: BoundedVec<T::AccountId, T::MaxHoldersForParameterChange>,
+	) -> Result<Vec<(T::AccountId, Balance)>, DispatchError> {
+		let mut holders = holders.into_inner();
+		let count = holders.len();
+		holders.sort();
+		holders.dedup();
+		ensure!(holders.len() == count, Error::<T>::InvalidHolders);
+
+		let holders: Vec<(T::AccountId, Balance)> = holders
+			.into_iter()
+			.map(|who| {
+				let balance = T::Currency::total_balance(asset_id, &who);
+				(who, balance)
+			})
+			.filter(|(_, balance)| *balance > 0)
+			.collect();
+
+		let total = holders
+			.iter()
+			.try_fold(Balance::default(), |acc, (_, balance)| acc.checked_add(*balance))
+			.ok_or(Error::<T>::InvalidHolders)?;
+		ensure!(
+			total == T::Currency::total_issuance(asset_id),
+			Error::<T>::InvalidHolders
+		);
+
+		Ok(holders)
+	}
+
 	pub fn next_asset_id() -> Option<T::AssetId> {
 		NextAssetId::<T>::get().checked_add(&T::SequentialIdStartAt::get())
 	}
@@ -604,6 +945,10 @@ impl<T: Config> Pallet<T> {
 			AssetIds::<T>::insert(name, asset_id);
 		}
 
+		if let Some(symbol) = details.symbol.as_ref() {
+			Self::do_set_ticker(asset_id, symbol)?;
+		}
+
 		if let Some(loc) = location {
 			Self::do_set_location(asset_id, loc)?;
 		}
@@ -624,6 +969,26 @@ impl<T: Config> Pallet<T> {
 		Ok(asset_id)
 	}
 
+	/// Register an external asset of unknown `location` in quarantine.
+	///
+	/// Quarantined assets are insufficient and can't be traded until the quarantine is lifted by `promote_asset`.
+	/// Used to register assets received by XCM from their reserve.
+	pub fn register_quarantined_asset(location: T::AssetNativeLocation) -> Result<T::AssetId, DispatchError> {
+		with_storage_layer(|| {
+			let asset_id = Self::do_register_asset(
+				None,
+				&AssetDetails::new(None, AssetType::External, DEFAULT_ED, None, None, None, false),
+				Some(location.clone()),
+			)?;
+
+			QuarantinedAssets::<T>::insert(asset_id, ());
+
+			Self::deposit_event(Event::AssetQuarantined { asset_id, location });
+
+			Ok(asset_id)
+		})
+	}
+
 	/// Return location for given asset.
 	pub fn asset_to_location(asset_id: T::AssetId) -> Option<T::AssetNativeLocation> {
 		Self::locations(asset_id)
@@ -695,6 +1060,10 @@ impl<T: Config> Inspect for Pallet<T> {
 	fn existential_deposit(id: Self::AssetId) -> Option<u128> {
 		Self::assets(id).map(|a| a.existential_deposit)
 	}
+
+	fn is_quarantined(id: Self::AssetId) -> bool {
+		QuarantinedAssets::<T>::contains_key(id)
+	}
 }
 
 impl<T: Config> Mutate<Balance> for Pallet<T> {
@@ -216,3 +216,57 @@ pub mod v2 {
 		);
 	}
 }
+
+pub mod versioned {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+
+	pub type V2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		v3::VersionUncheckedMigrateToV3<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// This migration fills the ticker registry with symbols of already registered assets. If more assets
+// use the same ticker, the ticker is assigned to the asset with the lowest id.
+pub mod v3 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use sp_std::marker::PhantomData;
+
+	pub struct VersionUncheckedMigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+
+			let mut tickers = Vec::new();
+			for (asset_id, details) in Assets::<T>::iter() {
+				reads += 1;
+				if let Some(symbol) = details.symbol {
+					tickers.push((asset_id, Pallet::<T>::normalize_ticker(&symbol)));
+				}
+			}
+			tickers.sort_by(|a, b| a.0.cmp(&b.0));
+
+			for (asset_id, ticker) in tickers {
+				reads += 1;
+				if TickerAssets::<T>::contains_key(&ticker) {
+					log::info!(
+						target: "runtime::asset-registry",
+						"Ticker of asset {:?} is already used", asset_id
+					);
+					continue;
+				}
+				writes += 1;
+				TickerAssets::<T>::insert(ticker, asset_id);
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
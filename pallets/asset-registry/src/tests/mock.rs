@@ -119,6 +119,9 @@ impl pallet_asset_registry::Config for Test {
 	type SequentialIdStartAt = SequentialIdStart;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type SufficiencyChangeHook = ();
+	type MaxHoldersForParameterChange = frame_support::traits::ConstU32<5>;
+	type MaxAliases = frame_support::traits::ConstU32<3>;
 	type WeightInfo = ();
 }
 
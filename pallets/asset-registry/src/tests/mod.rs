@@ -8,9 +8,12 @@ mod evm;
 mod inspect_trait;
 pub(crate) mod mock;
 mod mutate_trait;
+mod parameters;
+mod quarantine;
 mod register;
 #[allow(clippy::module_inception)]
 mod tests;
+mod tickers;
 mod update;
 
 #[macro_export]
@@ -0,0 +1,138 @@
+use super::*;
+
+use mock::Registry;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+const ASSET: AssetId = 1;
+const BOB: u64 = 2_000;
+const CHARLIE: u64 = 3_000;
+
+fn holders(accounts: Vec<u64>) -> BoundedVec<u64, <Test as Config>::MaxHoldersForParameterChange> {
+	accounts.try_into().unwrap()
+}
+
+fn parameters_ext(is_sufficient: bool) -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_assets(vec![(
+			Some(ASSET),
+			Some(b"Tkn1".to_vec().try_into().unwrap()),
+			UNIT,
+			None,
+			None,
+			None,
+			is_sufficient,
+		)])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(Tokens::deposit(ASSET, &ALICE, 10 * UNIT));
+		assert_ok!(Tokens::deposit(ASSET, &BOB, 5 * UNIT));
+	});
+	ext
+}
+
+#[test]
+fn set_sufficiency_should_work_when_all_holders_are_provided() {
+	parameters_ext(false).execute_with(|| {
+		//Act
+		assert_ok!(Registry::set_sufficiency(
+			RuntimeOrigin::root(),
+			ASSET,
+			true,
+			holders(vec![BOB, ALICE, CHARLIE])
+		));
+
+		//Assert
+		assert!(Registry::assets(ASSET).unwrap().is_sufficient);
+		assert_last_event!(Event::<Test>::AssetParametersChanged {
+			asset_id: ASSET,
+			existential_deposit: UNIT,
+			is_sufficient: true,
+			migrated_holders: 2,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn set_sufficiency_should_fail_when_holder_is_missing() {
+	parameters_ext(false).execute_with(|| {
+		assert_noop!(
+			Registry::set_sufficiency(RuntimeOrigin::root(), ASSET, true, holders(vec![ALICE])),
+			Error::<Test>::InvalidHolders
+		);
+	});
+}
+
+#[test]
+fn set_sufficiency_should_fail_when_holder_is_duplicated() {
+	parameters_ext(false).execute_with(|| {
+		assert_noop!(
+			Registry::set_sufficiency(RuntimeOrigin::root(), ASSET, true, holders(vec![ALICE, BOB, ALICE])),
+			Error::<Test>::InvalidHolders
+		);
+	});
+}
+
+#[test]
+fn set_sufficiency_should_fail_when_sufficiency_is_not_changed() {
+	parameters_ext(true).execute_with(|| {
+		assert_noop!(
+			Registry::set_sufficiency(RuntimeOrigin::root(), ASSET, true, holders(vec![ALICE, BOB])),
+			Error::<Test>::SufficiencyNotChanged
+		);
+	});
+}
+
+#[test]
+fn set_sufficiency_should_fail_when_origin_is_not_registry_origin() {
+	parameters_ext(false).execute_with(|| {
+		assert_noop!(
+			Registry::set_sufficiency(RuntimeOrigin::signed(ALICE), ASSET, true, holders(vec![ALICE, BOB])),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_work_when_all_holders_are_above_new_existential_deposit() {
+	parameters_ext(true).execute_with(|| {
+		//Act
+		assert_ok!(Registry::set_existential_deposit(
+			RuntimeOrigin::root(),
+			ASSET,
+			5 * UNIT,
+			holders(vec![ALICE, BOB])
+		));
+
+		//Assert
+		assert_eq!(Registry::assets(ASSET).unwrap().existential_deposit, 5 * UNIT);
+		assert_last_event!(Event::<Test>::AssetParametersChanged {
+			asset_id: ASSET,
+			existential_deposit: 5 * UNIT,
+			is_sufficient: true,
+			migrated_holders: 0,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_fail_when_holder_is_below_new_existential_deposit() {
+	parameters_ext(true).execute_with(|| {
+		assert_noop!(
+			Registry::set_existential_deposit(RuntimeOrigin::root(), ASSET, 6 * UNIT, holders(vec![ALICE, BOB])),
+			Error::<Test>::HolderBelowExistentialDeposit
+		);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_fail_when_holder_is_missing() {
+	parameters_ext(true).execute_with(|| {
+		assert_noop!(
+			Registry::set_existential_deposit(RuntimeOrigin::root(), ASSET, 2 * UNIT, holders(vec![BOB])),
+			Error::<Test>::InvalidHolders
+		);
+	});
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+use frame_support::error::BadOrigin;
+use hydradx_traits::registry::Inspect;
+use mock::Registry;
+use polkadot_xcm::v3::{
+	Junction::{self, Parachain},
+	Junctions::X2,
+	MultiLocation,
+};
+use pretty_assertions::assert_eq;
+
+fn foreign_location(key: u32) -> AssetLocation {
+	let key = Junction::from(BoundedVec::try_from(key.encode()).unwrap());
+	AssetLocation(MultiLocation::new(1, X2(Parachain(300), key)))
+}
+
+#[test]
+fn register_quarantined_asset_should_register_insufficient_external_asset_with_location() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let expected_id = Pallet::<Test>::next_asset_id().unwrap();
+		let location = foreign_location(1);
+
+		//Act
+		assert_eq!(Registry::register_quarantined_asset(location.clone()), Ok(expected_id));
+
+		//Assert
+		assert_eq!(
+			Registry::assets(expected_id),
+			Some(AssetDetails {
+				name: None,
+				asset_type: AssetType::External,
+				existential_deposit: crate::DEFAULT_ED,
+				xcm_rate_limit: None,
+				symbol: None,
+				decimals: None,
+				is_sufficient: false
+			})
+		);
+		assert_eq!(Registry::location_assets(location.clone()), Some(expected_id));
+		assert_eq!(Registry::quarantined_assets(expected_id), Some(()));
+		assert!(Registry::is_quarantined(expected_id));
+
+		assert_last_event!(Event::<Test>::AssetQuarantined {
+			asset_id: expected_id,
+			location
+		}
+		.into());
+	});
+}
+
+#[test]
+fn register_quarantined_asset_should_not_work_when_location_is_already_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let location = foreign_location(1);
+		let asset_id = Registry::register_quarantined_asset(location.clone()).unwrap();
+
+		//Act & assert
+		assert_noop!(
+			Registry::register_quarantined_asset(location),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+		assert_eq!(Registry::next_asset_id(), Some(asset_id + 1));
+	});
+}
+
+#[test]
+fn promote_asset_should_lift_quarantine() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let asset_id = Registry::register_quarantined_asset(foreign_location(1)).unwrap();
+
+		//Act
+		assert_ok!(Registry::promote_asset(RuntimeOrigin::root(), asset_id));
+
+		//Assert
+		assert_eq!(Registry::quarantined_assets(asset_id), None);
+		assert!(!Registry::is_quarantined(asset_id));
+		assert!(Registry::exists(asset_id));
+
+		assert_last_event!(Event::<Test>::AssetPromoted { asset_id }.into());
+	});
+}
+
+#[test]
+fn promote_asset_should_not_work_when_origin_is_not_registry_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let asset_id = Registry::register_quarantined_asset(foreign_location(1)).unwrap();
+
+		//Act & assert
+		assert_noop!(
+			Registry::promote_asset(RuntimeOrigin::signed(ALICE), asset_id),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn promote_asset_should_not_work_when_asset_is_not_quarantined() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let asset_id = Registry::register_quarantined_asset(foreign_location(1)).unwrap();
+		assert_ok!(Registry::promote_asset(RuntimeOrigin::root(), asset_id));
+
+		//Act & assert
+		assert_noop!(
+			Registry::promote_asset(RuntimeOrigin::root(), asset_id),
+			Error::<Test>::AssetNotQuarantined
+		);
+	});
+}
@@ -0,0 +1,284 @@
+use super::*;
+
+use frame_support::error::BadOrigin;
+use mock::{Registry, RegistryStringLimit};
+use pretty_assertions::assert_eq;
+
+fn ticker(t: &[u8]) -> Symbol<RegistryStringLimit> {
+	t.to_vec().try_into().unwrap()
+}
+
+fn with_tokens() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_assets(vec![
+			(
+				Some(1),
+				Some(b"Tkn1".to_vec().try_into().unwrap()),
+				UNIT,
+				Some(ticker(b"TKN1")),
+				None,
+				None,
+				true,
+			),
+			(
+				Some(2),
+				Some(b"Tkn2".to_vec().try_into().unwrap()),
+				UNIT,
+				Some(ticker(b"TKN2")),
+				None,
+				None,
+				true,
+			),
+		])
+		.build()
+}
+
+#[test]
+fn genesis_should_register_tickers_of_assets() {
+	with_tokens().execute_with(|| {
+		assert_eq!(Registry::asset_by_ticker(b"HDX".to_vec()), Some(0));
+		assert_eq!(Registry::asset_by_ticker(b"TKN1".to_vec()), Some(1));
+		assert_eq!(Registry::asset_by_ticker(b"tkn2".to_vec()), Some(2));
+		assert_eq!(Registry::asset_by_ticker(b"TKN3".to_vec()), None);
+	});
+}
+
+#[test]
+fn register_should_fail_when_symbol_differs_only_in_case_from_existing_symbol() {
+	with_tokens().execute_with(|| {
+		assert_noop!(
+			Registry::register(
+				RuntimeOrigin::root(),
+				Some(3),
+				Some(b"Tkn3".to_vec().try_into().unwrap()),
+				AssetType::Token,
+				None,
+				Some(ticker(b"tKn1")),
+				None,
+				None,
+				None,
+				true
+			),
+			Error::<Test>::TickerAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn update_should_fail_when_symbol_is_used_by_another_asset() {
+	with_tokens().execute_with(|| {
+		assert_noop!(
+			Registry::update(
+				RuntimeOrigin::root(),
+				2,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(ticker(b"TKN1")),
+				None,
+				None
+			),
+			Error::<Test>::TickerAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn update_should_release_old_ticker_when_symbol_is_changed() {
+	with_tokens().execute_with(|| {
+		//Act
+		assert_ok!(Registry::update(
+			RuntimeOrigin::root(),
+			1,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(ticker(b"NTKN")),
+			None,
+			None
+		));
+
+		//Assert
+		assert_eq!(Registry::asset_by_ticker(b"NTKN".to_vec()), Some(1));
+		assert_eq!(Registry::asset_by_ticker(b"TKN1".to_vec()), None);
+	});
+}
+
+#[test]
+fn update_should_work_when_symbol_changes_only_case() {
+	with_tokens().execute_with(|| {
+		assert_ok!(Registry::update(
+			RuntimeOrigin::root(),
+			1,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(ticker(b"Tkn1")),
+			None,
+			None
+		));
+
+		assert_eq!(Registry::asset_by_ticker(b"TKN1".to_vec()), Some(1));
+	});
+}
+
+#[test]
+fn add_alias_should_work_when_ticker_is_not_used() {
+	with_tokens().execute_with(|| {
+		//Act
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"wTKN")));
+
+		//Assert
+		assert_eq!(Registry::asset_by_ticker(b"WTKN".to_vec()), Some(1));
+		assert_eq!(
+			Registry::asset_tickers(1),
+			Some((Some(b"TKN1".to_vec()), vec![b"wTKN".to_vec()]))
+		);
+		assert_last_event!(Event::<Test>::AliasAdded {
+			asset_id: 1,
+			alias: ticker(b"wTKN"),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn add_alias_should_fail_when_ticker_is_used() {
+	with_tokens().execute_with(|| {
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"wTKN")));
+
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 2, ticker(b"tkn1")),
+			Error::<Test>::TickerAlreadyUsed
+		);
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 2, ticker(b"WTKN")),
+			Error::<Test>::TickerAlreadyUsed
+		);
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"TKN1")),
+			Error::<Test>::TickerAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn add_alias_should_fail_when_asset_has_max_aliases() {
+	with_tokens().execute_with(|| {
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"AL1")));
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"AL2")));
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"AL3")));
+
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"AL4")),
+			Error::<Test>::TooManyAliases
+		);
+	});
+}
+
+#[test]
+fn add_alias_should_fail_when_alias_is_not_valid() {
+	with_tokens().execute_with(|| {
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"w TKN")),
+			Error::<Test>::InvalidSymbol
+		);
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"w")),
+			Error::<Test>::TooShort
+		);
+	});
+}
+
+#[test]
+fn add_alias_should_fail_when_asset_does_not_exist() {
+	with_tokens().execute_with(|| {
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::root(), 3, ticker(b"wTKN")),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn add_alias_should_fail_when_origin_is_not_registry_origin() {
+	with_tokens().execute_with(|| {
+		assert_noop!(
+			Registry::add_alias(RuntimeOrigin::signed(ALICE), 1, ticker(b"wTKN")),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_alias_should_release_ticker() {
+	with_tokens().execute_with(|| {
+		//Arrange
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"wTKN")));
+
+		//Act
+		assert_ok!(Registry::remove_alias(RuntimeOrigin::root(), 1, ticker(b"WTKN")));
+
+		//Assert
+		assert_eq!(Registry::asset_by_ticker(b"WTKN".to_vec()), None);
+		assert_eq!(
+			Registry::asset_aliases(1).into_inner(),
+			Vec::<Symbol<RegistryStringLimit>>::new()
+		);
+		assert_last_event!(Event::<Test>::AliasRemoved {
+			asset_id: 1,
+			alias: ticker(b"wTKN"),
+		}
+		.into());
+
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 2, ticker(b"wTKN")));
+	});
+}
+
+#[test]
+fn remove_alias_should_fail_when_alias_is_not_registered_for_asset() {
+	with_tokens().execute_with(|| {
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"wTKN")));
+
+		assert_noop!(
+			Registry::remove_alias(RuntimeOrigin::root(), 2, ticker(b"wTKN")),
+			Error::<Test>::AliasNotFound
+		);
+		assert_noop!(
+			Registry::remove_alias(RuntimeOrigin::root(), 1, ticker(b"TKN1")),
+			Error::<Test>::AliasNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_alias_should_keep_ticker_when_alias_became_symbol() {
+	with_tokens().execute_with(|| {
+		//Arrange
+		assert_ok!(Registry::add_alias(RuntimeOrigin::root(), 1, ticker(b"wTKN")));
+		assert_ok!(Registry::update(
+			RuntimeOrigin::root(),
+			1,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(ticker(b"WTKN")),
+			None,
+			None
+		));
+
+		//Act
+		assert_ok!(Registry::remove_alias(RuntimeOrigin::root(), 1, ticker(b"wTKN")));
+
+		//Assert
+		assert_eq!(Registry::asset_by_ticker(b"WTKN".to_vec()), Some(1));
+	});
+}
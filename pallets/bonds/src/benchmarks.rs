@@ -19,7 +19,7 @@
 
 use super::*;
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
 use frame_support::{assert_ok, traits::EnsureOrigin};
 use frame_system::RawOrigin;
 
@@ -30,6 +30,48 @@ pub const NOW: Moment = 1689844300000; // unix time in milliseconds
 pub const ONE: Balance = 1_000_000_000_000;
 pub const HDX: AssetId = 0;
 
+fn issue_bonds_to_treasury<T: Config>() -> Result<AssetId, DispatchError>
+where
+	T::Balance: From<u128>,
+{
+	let origin = T::IssueOrigin::try_successful_origin().unwrap();
+	let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+	let amount: T::Balance = (200 * ONE).into();
+	//NOTE: bonds are insufficient so issuer must ED for it
+	T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+	T::Currency::deposit(HDX, &T::TreasuryAccount::get(), (100 * ONE).into())?;
+
+	crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, NOW + 2 * MONTH)?;
+
+	let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+	T::Currency::transfer(
+		bond_id,
+		&issuer,
+		&T::TreasuryAccount::get(),
+		T::Currency::free_balance(bond_id, &issuer),
+	)?;
+
+	Ok(bond_id)
+}
+
+fn register_redemption_asset<T: Config>() -> Result<AssetId, DispatchError> {
+	T::AssetRegistry::register_insufficient_asset(
+		None,
+		Some(
+			b"RDMP"
+				.to_vec()
+				.try_into()
+				.map_err(|_| DispatchError::Other("InvalidName"))?,
+		),
+		AssetKind::Token,
+		Some(1_000u128.into()),
+		None,
+		None,
+		None,
+		None,
+	)
+}
+
 benchmarks! {
 	 where_clause {
 		where
@@ -79,5 +121,89 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
 	}
 
+	open_sale {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let bond_id = issue_bonds_to_treasury::<T>()?;
+		let origin = T::SaleOrigin::try_successful_origin().unwrap();
+		let amount: T::Balance = (100 * ONE).into();
+
+	}: _<T::RuntimeOrigin>(origin, bond_id, HDX, FixedU128::from_rational(9, 10), amount, amount, NOW, NOW + MONTH)
+	verify {
+		assert!(Sales::<T>::get(0).is_some());
+	}
+
+	buy {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let bond_id = issue_bonds_to_treasury::<T>()?;
+		let amount: T::Balance = (100 * ONE).into();
+		assert_ok!(crate::Pallet::<T>::open_sale(T::SaleOrigin::try_successful_origin().unwrap(), bond_id, HDX, FixedU128::from_rational(9, 10), amount, amount, NOW, NOW + MONTH));
+
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		T::Currency::deposit(HDX, &buyer, (200 * ONE).into())?;
+
+	}: _(RawOrigin::Signed(buyer.clone()), 0, amount)
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &buyer), amount);
+	}
+
+	close_sale {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let bond_id = issue_bonds_to_treasury::<T>()?;
+		let amount: T::Balance = (100 * ONE).into();
+		assert_ok!(crate::Pallet::<T>::open_sale(T::SaleOrigin::try_successful_origin().unwrap(), bond_id, HDX, FixedU128::from_rational(9, 10), amount, amount, NOW, NOW + MONTH));
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + MONTH).into());
+
+		let caller: T::AccountId = account("caller", 0, 0);
+
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(Sales::<T>::get(0).is_none());
+	}
+
+	set_class_metadata {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let bond_id = issue_bonds_to_treasury::<T>()?;
+		let redemption_asset = register_redemption_asset::<T>()?;
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let metadata = BondClassMetadata {
+			redemption_asset,
+			max_price_deviation: Permill::from_percent(5),
+		};
+
+	}: _<T::RuntimeOrigin>(origin, bond_id, Some(metadata.clone()))
+	verify {
+		assert_eq!(ClassMetadata::<T>::get(bond_id), Some(metadata));
+	}
+
+	claim {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let bond_id = issue_bonds_to_treasury::<T>()?;
+		let redemption_asset = register_redemption_asset::<T>()?;
+		assert_ok!(crate::Pallet::<T>::set_class_metadata(
+			T::AuthorityOrigin::try_successful_origin().unwrap(),
+			bond_id,
+			Some(BondClassMetadata {
+				redemption_asset,
+				max_price_deviation: Permill::from_percent(100),
+			})
+		));
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 2 * MONTH).into());
+
+		let holder = T::TreasuryAccount::get();
+		let amount = T::Currency::free_balance(bond_id, &holder);
+
+	}: _(RawOrigin::Signed(holder.clone()), bond_id, amount)
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &holder), 0u32.into());
+		assert!(Conversions::<T>::get(bond_id).is_some());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
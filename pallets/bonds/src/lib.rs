@@ -38,26 +38,49 @@
 //! * Bonds can be both partially or fully redeemed.
 //! * The amount of the underlying asset an account receives is 1:1 to the `amount` of the bonds redeemed.
 //! * Anyone who holds the bonds is able to redeem them.
+//!
+//! ## Sales of bonds
+//! * `SaleOrigin` can open a sale window in which bonds held by the treasury are sold at a fixed price.
+//! * The price is set in a configured payment asset, normally at a discount to the face value of the bonds.
+//! * Bonds offered in the sale are transferred from the treasury to the pallet account when the sale is opened.
+//! * Anyone can buy bonds during the sale window, up to the remaining amount and the per-account limit.
+//! * Payment is transferred directly to the treasury.
+//! * `SaleOrigin` can close the sale at any time. Anyone can close it once the window has ended.
+//!   Unsold bonds are returned to the treasury.
+//!
+//! ## Redemption asset
+//! * `AuthorityOrigin` can set metadata of a bond class before its maturity, specifying a redemption asset
+//!   different from the underlying asset.
+//! * Bonds with a redemption asset can't be redeemed for the underlying asset, they are claimed instead.
+//! * First claim after maturity converts all underlying assets of the bond class to the redemption asset via router.
+//!   Minimum amount received is bounded by the oracle price minus the maximum price deviation of the class.
+//! * Claimed bonds receive pro-rata share of the converted amount.
+//! * New bonds can't be issued for bond classes which were already converted.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use frame_support::{
 	ensure,
-	pallet_prelude::{DispatchResult, Get},
+	pallet_prelude::{DispatchResult, Get, Weight},
 	sp_runtime::{
-		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub},
-		DispatchError, Permill, Saturating,
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, SaturatedConversion, Zero},
+		ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding, RuntimeDebug, Saturating,
 	},
 	traits::{Contains, Time},
 	PalletId,
 };
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
 use sp_std::{mem, vec::Vec};
 
+use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::{
 	registry::{Create, Inspect},
-	AssetKind,
+	router::{inverse_route, AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade},
+	AssetKind, OraclePeriod, PriceOracle,
 };
 use orml_traits::{GetByKey, MultiCurrency};
 use primitives::{AssetId, Moment};
@@ -73,6 +96,45 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+pub type SaleId = u32;
+
+/// Sale of bonds at a fixed price.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Sale<Balance> {
+	/// Bonds offered in the sale.
+	pub bond_id: AssetId,
+	/// Asset the bonds are paid in.
+	pub payment_asset: AssetId,
+	/// Amount of the payment asset paid for one unit of the bonds.
+	pub price: FixedU128,
+	/// Amount of the bonds which are still available.
+	pub remaining: Balance,
+	/// Maximum amount of the bonds a single account can buy in the sale.
+	pub max_per_account: Balance,
+	/// Unix time in milliseconds when the sale starts.
+	pub start: Moment,
+	/// Unix time in milliseconds when the sale ends.
+	pub end: Moment,
+}
+
+/// Metadata of a bond class.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BondClassMetadata {
+	/// Asset the bonds are redeemed in at maturity.
+	pub redemption_asset: AssetId,
+	/// Maximum deviation of the conversion price from the oracle price.
+	pub max_price_deviation: Permill,
+}
+
+/// Underlying asset converted to the redemption asset at maturity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Conversion<Balance> {
+	/// Amount of the bonds which are not claimed yet.
+	pub bonds: Balance,
+	/// Amount of the redemption asset which is not claimed yet.
+	pub amount: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -129,6 +191,32 @@ pub mod pallet {
 		#[pallet::constant]
 		type FeeReceiver: Get<Self::AccountId>;
 
+		/// The origin which can open and close sales of bonds.
+		type SaleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Treasury account which provides the bonds for sales and receives the payments.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The origin which can set metadata of bond classes.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Router implementation used to convert underlying assets to redemption assets.
+		type Router: RouterT<Self::RuntimeOrigin, AssetId, Self::Balance, Trade<AssetId>, AmountInAndOut<Self::Balance>>;
+
+		/// Provider of the routes used to convert underlying assets to redemption assets.
+		type RouteProvider: RouteProvider<AssetId>;
+
+		/// Oracle price provider used to bound the conversion price.
+		type OraclePriceProvider: PriceOracle<AssetId, Price = EmaPrice>;
+
+		/// Oracle period used to bound the conversion price.
+		#[pallet::constant]
+		type OraclePeriod: Get<OraclePeriod>;
+
+		/// Weight information for the router trades.
+		type AmmTradeWeights: AmmTradeWeights<Trade<AssetId>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -145,6 +233,36 @@ pub mod pallet {
 	#[pallet::getter(fn bond)]
 	pub(super) type Bonds<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (AssetId, Moment)>;
 
+	#[pallet::storage]
+	/// Next available sale id.
+	#[pallet::getter(fn next_sale_id)]
+	pub(super) type NextSaleId<T: Config> = StorageValue<_, SaleId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Open sales of bonds.
+	/// Maps sale ID -> sale details
+	#[pallet::getter(fn sale)]
+	pub(super) type Sales<T: Config> = StorageMap<_, Blake2_128Concat, SaleId, Sale<T::Balance>>;
+
+	#[pallet::storage]
+	/// Amounts of bonds bought by accounts in sales.
+	/// Maps (sale ID, account ID) -> amount of bonds
+	#[pallet::getter(fn purchased)]
+	pub(super) type Purchases<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, SaleId, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Metadata of bond classes.
+	/// Maps bond ID -> bond class metadata
+	#[pallet::getter(fn class_metadata)]
+	pub(super) type ClassMetadata<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, BondClassMetadata>;
+
+	#[pallet::storage]
+	/// Bond classes converted to the redemption asset.
+	/// Maps bond ID -> amounts left to claim
+	#[pallet::getter(fn conversion)]
+	pub(super) type Conversions<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Conversion<T::Balance>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -168,6 +286,48 @@ pub mod pallet {
 			bond_id: AssetId,
 			amount: T::Balance,
 		},
+		/// Sale of bonds was opened
+		SaleOpened {
+			sale_id: SaleId,
+			bond_id: AssetId,
+			payment_asset: AssetId,
+			price: FixedU128,
+			amount: T::Balance,
+			max_per_account: T::Balance,
+			start: Moment,
+			end: Moment,
+		},
+		/// Bonds were bought in a sale
+		Purchased {
+			sale_id: SaleId,
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			payment: T::Balance,
+		},
+		/// Sale of bonds was closed and unsold bonds were returned to the treasury
+		SaleClosed { sale_id: SaleId, unsold: T::Balance },
+		/// Metadata of a bond class was set or removed
+		ClassMetadataSet {
+			bond_id: AssetId,
+			metadata: Option<BondClassMetadata>,
+		},
+		/// Underlying asset of a bond class was converted to the redemption asset
+		Converted {
+			bond_id: AssetId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
+		/// Bonds were claimed for the redemption asset
+		Claimed {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			asset_id: AssetId,
+			received: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -187,6 +347,32 @@ pub mod pallet {
 		InvalidBondName,
 		/// Bond's name parsing was now successful
 		FailToParseName,
+		/// Sale does not exist
+		SaleNotFound,
+		/// Sale window is invalid or has already ended
+		InvalidSaleWindow,
+		/// Price, amount or purchase limit of the sale is zero
+		InvalidSaleParameters,
+		/// Sale window is not open
+		SaleNotActive,
+		/// Sale window has not ended yet
+		SaleNotEnded,
+		/// Not enough bonds left in the sale
+		InsufficientSaleAmount,
+		/// Purchase exceeds the limit per account
+		PurchaseLimitExceeded,
+		/// Bond is already mature
+		AlreadyMature,
+		/// Redemption asset is the same as the underlying asset
+		InvalidRedemptionAsset,
+		/// Bonds have a redemption asset and must be claimed
+		RedemptionAssetSet,
+		/// Bonds have no redemption asset and must be redeemed
+		RedemptionAssetNotSet,
+		/// Bond class was already converted to the redemption asset
+		AlreadyConverted,
+		/// Oracle price of the conversion is not available
+		PriceNotAvailable,
 	}
 
 	#[pallet::call]
@@ -229,7 +415,10 @@ pub mod pallet {
 			let pallet_account = Self::pallet_account_id();
 
 			let bond_id = match BondIds::<T>::get((asset_id, maturity)) {
-				Some(bond_id) => bond_id,
+				Some(bond_id) => {
+					ensure!(!Conversions::<T>::contains_key(bond_id), Error::<T>::AlreadyConverted);
+					bond_id
+				}
 				None => {
 					// register new bonds
 					ensure!(maturity >= T::TimestampProvider::now(), Error::<T>::InvalidMaturity);
@@ -293,6 +482,10 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(
+				!ClassMetadata::<T>::contains_key(bond_id),
+				Error::<T>::RedemptionAssetSet
+			);
 
 			let now = T::TimestampProvider::now();
 			ensure!(now >= maturity, Error::<T>::NotMature);
@@ -306,6 +499,278 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Open a sale of bonds at a fixed price.
+		/// The `amount` of the bonds is transferred from `T::TreasuryAccount` to the pallet account
+		/// and sold in the sale window.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::SaleOrigin`
+		/// - `bond_id`: bond asset id
+		/// - `payment_asset`: asset the bonds are paid in
+		/// - `price`: amount of the payment asset paid for one unit of the bonds
+		/// - `amount`: the amount of the bonds offered in the sale
+		/// - `max_per_account`: maximum amount of the bonds a single account can buy
+		/// - `start`: Unix time in milliseconds, when the sale starts
+		/// - `end`: Unix time in milliseconds, when the sale ends
+		///
+		/// Emits `SaleOpened` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::open_sale())]
+		#[allow(clippy::too_many_arguments)]
+		pub fn open_sale(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			payment_asset: AssetId,
+			price: FixedU128,
+			amount: T::Balance,
+			max_per_account: T::Balance,
+			start: Moment,
+			end: Moment,
+		) -> DispatchResult {
+			T::SaleOrigin::ensure_origin(origin)?;
+
+			ensure!(Bonds::<T>::contains_key(bond_id), Error::<T>::NotRegistered);
+			ensure!(
+				!price.is_zero() && !amount.is_zero() && !max_per_account.is_zero(),
+				Error::<T>::InvalidSaleParameters
+			);
+			ensure!(
+				start < end && end > T::TimestampProvider::now(),
+				Error::<T>::InvalidSaleWindow
+			);
+
+			let sale_id = NextSaleId::<T>::try_mutate(|next_id| -> Result<SaleId, DispatchError> {
+				let sale_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(sale_id)
+			})?;
+
+			T::Currency::transfer(bond_id, &T::TreasuryAccount::get(), &Self::pallet_account_id(), amount)?;
+
+			Sales::<T>::insert(
+				sale_id,
+				Sale {
+					bond_id,
+					payment_asset,
+					price,
+					remaining: amount,
+					max_per_account,
+					start,
+					end,
+				},
+			);
+
+			Self::deposit_event(Event::SaleOpened {
+				sale_id,
+				bond_id,
+				payment_asset,
+				price,
+				amount,
+				max_per_account,
+				start,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Buy bonds in an open sale.
+		/// The payment is calculated from the fixed price of the sale, rounded up,
+		/// and transferred to `T::TreasuryAccount`.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `sale_id`: sale id
+		/// - `amount`: the amount of the bonds to buy
+		///
+		/// Emits `Purchased` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		pub fn buy(origin: OriginFor<T>, sale_id: SaleId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut sale = Self::sale(sale_id).ok_or(Error::<T>::SaleNotFound)?;
+
+			let now = T::TimestampProvider::now();
+			ensure!(now >= sale.start && now < sale.end, Error::<T>::SaleNotActive);
+
+			sale.remaining = sale
+				.remaining
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::InsufficientSaleAmount)?;
+
+			let purchased = Purchases::<T>::get(sale_id, &who)
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(purchased <= sale.max_per_account, Error::<T>::PurchaseLimitExceeded);
+
+			let payment: T::Balance = multiply_by_rational_with_rounding(
+				amount.saturated_into(),
+				sale.price.into_inner(),
+				FixedU128::DIV,
+				Rounding::Up,
+			)
+			.ok_or(ArithmeticError::Overflow)?
+			.into();
+
+			T::Currency::transfer(sale.payment_asset, &who, &T::TreasuryAccount::get(), payment)?;
+			T::Currency::transfer(sale.bond_id, &Self::pallet_account_id(), &who, amount)?;
+
+			Purchases::<T>::insert(sale_id, &who, purchased);
+			Sales::<T>::insert(sale_id, &sale);
+
+			Self::deposit_event(Event::Purchased {
+				sale_id,
+				who,
+				bond_id: sale.bond_id,
+				amount,
+				payment,
+			});
+
+			Ok(())
+		}
+
+		/// Close a sale of bonds and return unsold bonds to `T::TreasuryAccount`.
+		/// `T::SaleOrigin` can close the sale at any time, anyone else only after the sale window has ended.
+		///
+		/// Parameters:
+		/// - `origin`: `T::SaleOrigin` or account id
+		/// - `sale_id`: sale id
+		///
+		/// Emits `SaleClosed` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::close_sale())]
+		pub fn close_sale(origin: OriginFor<T>, sale_id: SaleId) -> DispatchResult {
+			let sale = Self::sale(sale_id).ok_or(Error::<T>::SaleNotFound)?;
+
+			if T::SaleOrigin::ensure_origin(origin.clone()).is_err() {
+				ensure_signed(origin)?;
+				ensure!(T::TimestampProvider::now() >= sale.end, Error::<T>::SaleNotEnded);
+			}
+
+			if !sale.remaining.is_zero() {
+				T::Currency::transfer(
+					sale.bond_id,
+					&Self::pallet_account_id(),
+					&T::TreasuryAccount::get(),
+					sale.remaining,
+				)?;
+			}
+
+			Sales::<T>::remove(sale_id);
+
+			Self::deposit_event(Event::SaleClosed {
+				sale_id,
+				unsold: sale.remaining,
+			});
+
+			Ok(())
+		}
+
+		/// Set or remove metadata of a bond class.
+		/// Metadata can be changed only before the bonds are mature.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `bond_id`: bond asset id
+		/// - `metadata`: bond class metadata, `None` removes the metadata
+		///
+		/// Emits `ClassMetadataSet` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_class_metadata())]
+		pub fn set_class_metadata(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			metadata: Option<BondClassMetadata>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(T::TimestampProvider::now() < maturity, Error::<T>::AlreadyMature);
+
+			if let Some(metadata) = &metadata {
+				ensure!(
+					metadata.redemption_asset != underlying_asset_id && metadata.redemption_asset != bond_id,
+					Error::<T>::InvalidRedemptionAsset
+				);
+				ensure!(
+					T::AssetRegistry::exists(metadata.redemption_asset),
+					Error::<T>::AssetNotFound
+				);
+			}
+
+			ClassMetadata::<T>::set(bond_id, metadata.clone());
+
+			Self::deposit_event(Event::ClassMetadataSet { bond_id, metadata });
+
+			Ok(())
+		}
+
+		/// Claim mature bonds for the redemption asset of the bond class.
+		/// First claim converts all underlying assets of the bond class to the redemption asset.
+		/// The amount of the redemption asset the `origin` receives is pro-rata to the `amount` of the bonds.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `amount`: the amount of the bonds to claim
+		///
+		/// Emits `Converted` event when the bond class was converted.
+		/// Emits `Claimed` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::claim_weight(*bond_id))]
+		pub fn claim(origin: OriginFor<T>, bond_id: AssetId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			let metadata = Self::class_metadata(bond_id).ok_or(Error::<T>::RedemptionAssetNotSet)?;
+
+			ensure!(T::TimestampProvider::now() >= maturity, Error::<T>::NotMature);
+
+			let mut conversion = match Self::conversion(bond_id) {
+				Some(conversion) => conversion,
+				None => Self::convert(bond_id, underlying_asset_id, &metadata)?,
+			};
+
+			let received: T::Balance = multiply_by_rational_with_rounding(
+				amount.saturated_into(),
+				conversion.amount.saturated_into(),
+				conversion.bonds.saturated_into(),
+				Rounding::Down,
+			)
+			.ok_or(ArithmeticError::Overflow)?
+			.into();
+
+			conversion.bonds = conversion
+				.bonds
+				.checked_sub(&amount)
+				.ok_or(ArithmeticError::Underflow)?;
+			conversion.amount = conversion
+				.amount
+				.checked_sub(&received)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			T::Currency::withdraw(bond_id, &who, amount)?;
+			T::Currency::transfer(metadata.redemption_asset, &Self::pallet_account_id(), &who, received)?;
+
+			Conversions::<T>::insert(bond_id, conversion);
+
+			Self::deposit_event(Event::Claimed {
+				who,
+				bond_id,
+				amount,
+				asset_id: metadata.redemption_asset,
+				received,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -329,6 +794,67 @@ impl<T: Config> Pallet<T> {
 		buf
 	}
 
+	/// Convert all underlying assets of the bond class to the redemption asset.
+	/// Amount of the underlying assets is equal to the total issuance of the bonds.
+	fn convert(
+		bond_id: AssetId,
+		underlying_asset_id: AssetId,
+		metadata: &BondClassMetadata,
+	) -> Result<Conversion<T::Balance>, DispatchError> {
+		let bonds = T::Currency::total_issuance(bond_id);
+		let route = T::RouteProvider::get_route(AssetPair::new(underlying_asset_id, metadata.redemption_asset));
+
+		let price = T::OraclePriceProvider::price(&inverse_route(route.clone()), T::OraclePeriod::get())
+			.ok_or(Error::<T>::PriceNotAvailable)?;
+		let estimated_amount_out: T::Balance =
+			multiply_by_rational_with_rounding(bonds.saturated_into(), price.n, price.d, Rounding::Down)
+				.ok_or(ArithmeticError::Overflow)?
+				.into();
+		let min_amount_out =
+			estimated_amount_out.saturating_sub(metadata.max_price_deviation.mul_ceil(estimated_amount_out));
+
+		let pallet_account = Self::pallet_account_id();
+		let initial_balance = T::Currency::free_balance(metadata.redemption_asset, &pallet_account);
+
+		if !bonds.is_zero() {
+			T::Router::sell(
+				RawOrigin::Signed(pallet_account.clone()).into(),
+				underlying_asset_id,
+				metadata.redemption_asset,
+				bonds,
+				min_amount_out,
+				route,
+			)?;
+		}
+
+		let amount = T::Currency::free_balance(metadata.redemption_asset, &pallet_account)
+			.checked_sub(&initial_balance)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		Self::deposit_event(Event::Converted {
+			bond_id,
+			asset_in: underlying_asset_id,
+			asset_out: metadata.redemption_asset,
+			amount_in: bonds,
+			amount_out: amount,
+		});
+
+		Ok(Conversion { bonds, amount })
+	}
+
+	/// Weight of the `claim` call, including the conversion trade if the bond class was not converted yet.
+	pub fn claim_weight(bond_id: AssetId) -> Weight {
+		let conversion_weight = match (Self::bond(bond_id), Self::class_metadata(bond_id)) {
+			(Some((underlying_asset_id, _)), Some(metadata)) if !Conversions::<T>::contains_key(bond_id) => {
+				let route = T::RouteProvider::get_route(AssetPair::new(underlying_asset_id, metadata.redemption_asset));
+				T::AmmTradeWeights::sell_weight(&route).saturating_add(T::AmmTradeWeights::get_route_weight())
+			}
+			_ => Weight::zero(),
+		};
+
+		<T as Config>::WeightInfo::claim().saturating_add(conversion_weight)
+	}
+
 	pub fn parse_bond_name(name: Vec<u8>) -> Result<AssetId, Error<T>> {
 		Ok(AssetId::from_le_bytes(
 			name[..mem::size_of::<AssetId>()]
@@ -0,0 +1,317 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::ema::EmaPrice;
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const AMOUNT: Balance = 100 * ONE;
+const MATURITY: Moment = NOW + MONTH;
+
+fn metadata() -> BondClassMetadata {
+	BondClassMetadata {
+		redemption_asset: DAI,
+		max_price_deviation: Permill::from_percent(5),
+	}
+}
+
+fn claim_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.with_router_price(FixedU128::from(2))
+		.with_oracle_price(Some(EmaPrice::new(2, 1)))
+		.build()
+}
+
+fn issue_bonds_with_redemption_asset() -> AssetId {
+	let bond_id = next_asset_id();
+	assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY));
+	assert_ok!(Bonds::set_class_metadata(
+		RuntimeOrigin::root(),
+		bond_id,
+		Some(metadata())
+	));
+	bond_id
+}
+
+#[test]
+fn set_class_metadata_should_work() {
+	claim_ext().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY));
+
+		// Act
+		assert_ok!(Bonds::set_class_metadata(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(metadata())
+		));
+
+		// Assert
+		assert_eq!(Bonds::class_metadata(bond_id), Some(metadata()));
+		expect_events(vec![Event::ClassMetadataSet {
+			bond_id,
+			metadata: Some(metadata()),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_class_metadata_should_remove_metadata_when_none() {
+	claim_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_with_redemption_asset();
+
+		// Act
+		assert_ok!(Bonds::set_class_metadata(RuntimeOrigin::root(), bond_id, None));
+
+		// Assert
+		assert_eq!(Bonds::class_metadata(bond_id), None);
+	});
+}
+
+#[test]
+fn set_class_metadata_should_fail_when_origin_is_not_authority() {
+	claim_ext().execute_with(|| {
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY));
+
+		assert_noop!(
+			Bonds::set_class_metadata(RuntimeOrigin::signed(ALICE), bond_id, Some(metadata())),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_class_metadata_should_fail_when_bond_is_mature() {
+	claim_ext().execute_with(|| {
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY));
+
+		Timestamp::set_timestamp(MATURITY);
+
+		assert_noop!(
+			Bonds::set_class_metadata(RuntimeOrigin::root(), bond_id, Some(metadata())),
+			Error::<Test>::AlreadyMature
+		);
+	});
+}
+
+#[test]
+fn set_class_metadata_should_fail_when_redemption_asset_is_invalid() {
+	claim_ext().execute_with(|| {
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY));
+
+		for redemption_asset in [HDX, bond_id] {
+			assert_noop!(
+				Bonds::set_class_metadata(
+					RuntimeOrigin::root(),
+					bond_id,
+					Some(BondClassMetadata {
+						redemption_asset,
+						..metadata()
+					})
+				),
+				Error::<Test>::InvalidRedemptionAsset
+			);
+		}
+		assert_noop!(
+			Bonds::set_class_metadata(
+				RuntimeOrigin::root(),
+				bond_id,
+				Some(BondClassMetadata {
+					redemption_asset: 1_000,
+					..metadata()
+				})
+			),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn redeem_should_fail_when_redemption_asset_is_set() {
+	claim_ext().execute_with(|| {
+		let bond_id = issue_bonds_with_redemption_asset();
+
+		Timestamp::set_timestamp(MATURITY);
+
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT),
+			Error::<Test>::RedemptionAssetSet
+		);
+	});
+}
+
+#[test]
+fn claim_should_convert_underlying_asset_when_called_first_time() {
+	claim_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_with_redemption_asset();
+		let claim_amount = AMOUNT / 4;
+
+		Timestamp::set_timestamp(MATURITY);
+
+		// Act
+		assert_ok!(Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, claim_amount));
+
+		// Assert
+		expect_events(vec![
+			Event::Converted {
+				bond_id,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: AMOUNT,
+				amount_out: 2 * AMOUNT,
+			}
+			.into(),
+			Event::Claimed {
+				who: ALICE,
+				bond_id,
+				amount: claim_amount,
+				asset_id: DAI,
+				received: 2 * claim_amount,
+			}
+			.into(),
+		]);
+
+		assert_eq!(
+			Bonds::conversion(bond_id),
+			Some(Conversion {
+				bonds: AMOUNT - claim_amount,
+				amount: 2 * (AMOUNT - claim_amount),
+			})
+		);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 2 * claim_amount);
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), AMOUNT - claim_amount);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(
+			Tokens::free_balance(DAI, &Bonds::pallet_account_id()),
+			2 * (AMOUNT - claim_amount)
+		);
+	});
+}
+
+#[test]
+fn claim_should_pay_pro_rata_share_when_class_was_converted() {
+	claim_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_with_redemption_asset();
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, AMOUNT / 2));
+
+		Timestamp::set_timestamp(MATURITY);
+		assert_ok!(Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT / 2));
+
+		// Act
+		assert_ok!(Bonds::claim(RuntimeOrigin::signed(BOB), bond_id, AMOUNT / 2));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), AMOUNT);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), AMOUNT);
+		assert_eq!(Tokens::free_balance(DAI, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::conversion(bond_id), Some(Conversion { bonds: 0, amount: 0 }));
+	});
+}
+
+#[test]
+fn claim_should_fail_when_conversion_price_deviates_from_oracle_price() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.with_router_price(FixedU128::from_rational(3, 2))
+		.with_oracle_price(Some(EmaPrice::new(2, 1)))
+		.build()
+		.execute_with(|| {
+			let bond_id = issue_bonds_with_redemption_asset();
+
+			Timestamp::set_timestamp(MATURITY);
+
+			assert_noop!(
+				Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT),
+				DispatchError::Other("TradingLimitReached")
+			);
+		});
+}
+
+#[test]
+fn claim_should_fail_when_oracle_price_is_not_available() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.with_oracle_price(None)
+		.build()
+		.execute_with(|| {
+			let bond_id = issue_bonds_with_redemption_asset();
+
+			Timestamp::set_timestamp(MATURITY);
+
+			assert_noop!(
+				Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT),
+				Error::<Test>::PriceNotAvailable
+			);
+		});
+}
+
+#[test]
+fn claim_should_fail_when_bonds_are_not_mature() {
+	claim_ext().execute_with(|| {
+		let bond_id = issue_bonds_with_redemption_asset();
+
+		assert_noop!(
+			Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT),
+			Error::<Test>::NotMature
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_redemption_asset_is_not_set() {
+	claim_ext().execute_with(|| {
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY));
+
+		Timestamp::set_timestamp(MATURITY);
+
+		assert_noop!(
+			Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT),
+			Error::<Test>::RedemptionAssetNotSet
+		);
+	});
+}
+
+#[test]
+fn issue_should_fail_when_class_was_converted() {
+	claim_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_with_redemption_asset();
+
+		Timestamp::set_timestamp(MATURITY);
+		assert_ok!(Bonds::claim(RuntimeOrigin::signed(ALICE), bond_id, AMOUNT / 2));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, AMOUNT, MATURITY),
+			Error::<Test>::AlreadyConverted
+		);
+	});
+}
@@ -26,9 +26,9 @@ use frame_support::{
 	},
 	traits::{ConstU32, ConstU64, Everything, SortedMembers},
 };
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
-use sp_runtime::BoundedVec;
+use sp_runtime::{traits::One, BoundedVec, DispatchResultWithPostInfo};
 use std::{cell::RefCell, collections::HashMap};
 
 use hydradx_traits::registry::{Create, Inspect};
@@ -64,6 +64,8 @@ thread_local! {
 	// maps AssetId -> existential deposit
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, (Balance, AssetKind)>> = RefCell::new(HashMap::default());
 	pub static PROTOCOL_FEE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
+	pub static ROUTER_PRICE: RefCell<FixedU128> = RefCell::new(FixedU128::one());
+	pub static ORACLE_PRICE: RefCell<Option<EmaPrice>> = const { RefCell::new(None) };
 }
 
 construct_runtime!(
@@ -80,6 +82,7 @@ parameter_types! {
 	pub ProtocolFee: Permill = PROTOCOL_FEE.with(|v| *v.borrow());
 	pub TreasuryAccount: AccountId = TREASURY;
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const BondsOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 parameter_type_with_key! {
@@ -114,6 +117,14 @@ impl pallet_bonds::Config for Test {
 	type AssetTypeWhitelist = AssetTypeWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type SaleOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type Router = MockRouter;
+	type RouteProvider = ();
+	type OraclePriceProvider = MockOracle;
+	type OraclePeriod = BondsOraclePeriod;
+	type AmmTradeWeights = ();
 	type WeightInfo = ();
 }
 
@@ -174,6 +185,90 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+pub struct MockRouter;
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let amount_out = ROUTER_PRICE.with(|v| v.borrow().saturating_mul_int(amount_in));
+		ensure!(
+			amount_out >= min_amount_out,
+			DispatchError::Other("TradingLimitReached")
+		);
+
+		Tokens::withdraw(asset_in, &who, amount_in)?;
+		Tokens::deposit(asset_out, &who, amount_out)
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+pub struct MockOracle;
+
+impl PriceOracle<AssetId> for MockOracle {
+	type Price = EmaPrice;
+
+	fn price(_route: &[Trade<AssetId>], _period: OraclePeriod) -> Option<Self::Price> {
+		ORACLE_PRICE.with(|v| *v.borrow())
+	}
+}
+
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> Create<Balance> for DummyRegistry<T> {
@@ -243,8 +338,8 @@ impl<T: Config> Inspect for DummyRegistry<T> {
 		REGISTERED_ASSETS.with(|v| v.borrow().get(&id).cloned()).map(|v| v.1)
 	}
 
-	fn exists(_name: AssetId) -> bool {
-		unimplemented!()
+	fn exists(id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&id))
 	}
 
 	fn is_banned(_id: Self::AssetId) -> bool {
@@ -268,6 +363,8 @@ pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
 	registered_assets: Vec<(AssetId, (Balance, AssetKind))>,
 	protocol_fee: Permill,
+	router_price: FixedU128,
+	oracle_price: Option<EmaPrice>,
 }
 
 impl Default for ExtBuilder {
@@ -280,6 +377,8 @@ impl Default for ExtBuilder {
 			endowed_accounts: vec![(ALICE, HDX, 1_000 * ONE)],
 			registered_assets: vec![(HDX, (NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token))],
 			protocol_fee: Permill::from_percent(0),
+			router_price: FixedU128::one(),
+			oracle_price: Some(EmaPrice::one()),
 		}
 	}
 }
@@ -299,6 +398,14 @@ impl ExtBuilder {
 		self.protocol_fee = fee;
 		self
 	}
+	pub fn with_router_price(mut self, price: FixedU128) -> Self {
+		self.router_price = price;
+		self
+	}
+	pub fn with_oracle_price(mut self, price: Option<EmaPrice>) -> Self {
+		self.oracle_price = price;
+		self
+	}
 
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -313,6 +420,14 @@ impl ExtBuilder {
 			*v.borrow_mut() = self.protocol_fee;
 		});
 
+		ROUTER_PRICE.with(|v| {
+			*v.borrow_mut() = self.router_price;
+		});
+
+		ORACLE_PRICE.with(|v| {
+			*v.borrow_mut() = self.oracle_price;
+		});
+
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
 				.endowed_accounts
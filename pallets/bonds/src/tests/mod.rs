@@ -1,5 +1,7 @@
+mod claim;
 mod issue;
 pub mod mock;
 mod redeem;
+mod sale;
 #[allow(clippy::module_inception)]
 mod tests;
@@ -0,0 +1,389 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const SALE_AMOUNT: Balance = 100 * ONE;
+const START: Moment = NOW + DAY;
+const END: Moment = NOW + WEEK;
+
+fn price() -> FixedU128 {
+	FixedU128::from_rational(9, 10)
+}
+
+fn issue_bonds_to_treasury() -> AssetId {
+	let bond_id = next_asset_id();
+	assert_ok!(Bonds::issue(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		SALE_AMOUNT,
+		NOW + MONTH
+	));
+	assert_ok!(Tokens::transfer(
+		RuntimeOrigin::signed(ALICE),
+		TREASURY,
+		bond_id,
+		SALE_AMOUNT
+	));
+	bond_id
+}
+
+fn open_default_sale(bond_id: AssetId) {
+	assert_ok!(Bonds::open_sale(
+		RuntimeOrigin::root(),
+		bond_id,
+		DAI,
+		price(),
+		SALE_AMOUNT,
+		20 * ONE,
+		START,
+		END
+	));
+}
+
+fn sale_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(BOB, DAI, INITIAL_BALANCE)])
+		.build()
+}
+
+#[test]
+fn open_sale_should_work() {
+	sale_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_to_treasury();
+
+		// Act
+		open_default_sale(bond_id);
+
+		// Assert
+		expect_events(vec![Event::SaleOpened {
+			sale_id: 0,
+			bond_id,
+			payment_asset: DAI,
+			price: price(),
+			amount: SALE_AMOUNT,
+			max_per_account: 20 * ONE,
+			start: START,
+			end: END,
+		}
+		.into()]);
+
+		assert_eq!(
+			Bonds::sale(0),
+			Some(Sale {
+				bond_id,
+				payment_asset: DAI,
+				price: price(),
+				remaining: SALE_AMOUNT,
+				max_per_account: 20 * ONE,
+				start: START,
+				end: END,
+			})
+		);
+		assert_eq!(Bonds::next_sale_id(), 1);
+
+		assert_eq!(Tokens::free_balance(bond_id, &TREASURY), 0);
+		assert_eq!(Tokens::free_balance(bond_id, &Bonds::pallet_account_id()), SALE_AMOUNT);
+	});
+}
+
+#[test]
+fn open_sale_should_fail_when_called_by_non_sale_origin() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+
+		assert_noop!(
+			Bonds::open_sale(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				DAI,
+				price(),
+				SALE_AMOUNT,
+				20 * ONE,
+				START,
+				END
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn open_sale_should_fail_when_bond_is_not_registered() {
+	sale_ext().execute_with(|| {
+		assert_noop!(
+			Bonds::open_sale(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				price(),
+				SALE_AMOUNT,
+				20 * ONE,
+				START,
+				END
+			),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn open_sale_should_fail_when_sale_window_is_invalid() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+
+		assert_noop!(
+			Bonds::open_sale(
+				RuntimeOrigin::root(),
+				bond_id,
+				DAI,
+				price(),
+				SALE_AMOUNT,
+				20 * ONE,
+				END,
+				START
+			),
+			Error::<Test>::InvalidSaleWindow
+		);
+
+		assert_noop!(
+			Bonds::open_sale(
+				RuntimeOrigin::root(),
+				bond_id,
+				DAI,
+				price(),
+				SALE_AMOUNT,
+				20 * ONE,
+				NOW - WEEK,
+				NOW
+			),
+			Error::<Test>::InvalidSaleWindow
+		);
+	});
+}
+
+#[test]
+fn open_sale_should_fail_when_price_or_amounts_are_zero() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+
+		assert_noop!(
+			Bonds::open_sale(
+				RuntimeOrigin::root(),
+				bond_id,
+				DAI,
+				FixedU128::zero(),
+				SALE_AMOUNT,
+				20 * ONE,
+				START,
+				END
+			),
+			Error::<Test>::InvalidSaleParameters
+		);
+
+		assert_noop!(
+			Bonds::open_sale(RuntimeOrigin::root(), bond_id, DAI, price(), 0, 20 * ONE, START, END),
+			Error::<Test>::InvalidSaleParameters
+		);
+
+		assert_noop!(
+			Bonds::open_sale(RuntimeOrigin::root(), bond_id, DAI, price(), SALE_AMOUNT, 0, START, END),
+			Error::<Test>::InvalidSaleParameters
+		);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	sale_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_to_treasury();
+		open_default_sale(bond_id);
+		Timestamp::set_timestamp(START);
+
+		let amount = 10 * ONE;
+
+		// Act
+		assert_ok!(Bonds::buy(RuntimeOrigin::signed(BOB), 0, amount));
+
+		// Assert
+		let payment = 9 * ONE;
+		expect_events(vec![Event::Purchased {
+			sale_id: 0,
+			who: BOB,
+			bond_id,
+			amount,
+			payment,
+		}
+		.into()]);
+
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), amount);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), INITIAL_BALANCE - payment);
+		assert_eq!(Tokens::free_balance(DAI, &TREASURY), payment);
+		assert_eq!(
+			Tokens::free_balance(bond_id, &Bonds::pallet_account_id()),
+			SALE_AMOUNT - amount
+		);
+
+		assert_eq!(Bonds::sale(0).unwrap().remaining, SALE_AMOUNT - amount);
+		assert_eq!(Bonds::purchased(0, BOB), amount);
+	});
+}
+
+#[test]
+fn buy_should_round_payment_up() {
+	sale_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_to_treasury();
+		assert_ok!(Bonds::open_sale(
+			RuntimeOrigin::root(),
+			bond_id,
+			DAI,
+			FixedU128::from_rational(1, 3),
+			SALE_AMOUNT,
+			SALE_AMOUNT,
+			NOW,
+			END
+		));
+
+		// Act
+		assert_ok!(Bonds::buy(RuntimeOrigin::signed(BOB), 0, 10 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &TREASURY), 3_333_333_333_334);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sale_is_not_active() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+		open_default_sale(bond_id);
+
+		assert_noop!(
+			Bonds::buy(RuntimeOrigin::signed(BOB), 0, ONE),
+			Error::<Test>::SaleNotActive
+		);
+
+		Timestamp::set_timestamp(END);
+
+		assert_noop!(
+			Bonds::buy(RuntimeOrigin::signed(BOB), 0, ONE),
+			Error::<Test>::SaleNotActive
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sale_does_not_exist() {
+	sale_ext().execute_with(|| {
+		assert_noop!(
+			Bonds::buy(RuntimeOrigin::signed(BOB), 0, ONE),
+			Error::<Test>::SaleNotFound
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_amount_exceeds_remaining_bonds() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+		assert_ok!(Bonds::open_sale(
+			RuntimeOrigin::root(),
+			bond_id,
+			DAI,
+			price(),
+			SALE_AMOUNT,
+			2 * SALE_AMOUNT,
+			NOW,
+			END
+		));
+
+		assert_noop!(
+			Bonds::buy(RuntimeOrigin::signed(BOB), 0, SALE_AMOUNT + 1),
+			Error::<Test>::InsufficientSaleAmount
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_purchase_limit_is_exceeded() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+		open_default_sale(bond_id);
+		Timestamp::set_timestamp(START);
+
+		assert_ok!(Bonds::buy(RuntimeOrigin::signed(BOB), 0, 15 * ONE));
+
+		assert_noop!(
+			Bonds::buy(RuntimeOrigin::signed(BOB), 0, 5 * ONE + 1),
+			Error::<Test>::PurchaseLimitExceeded
+		);
+		assert_ok!(Bonds::buy(RuntimeOrigin::signed(BOB), 0, 5 * ONE));
+	});
+}
+
+#[test]
+fn close_sale_should_return_unsold_bonds_to_treasury() {
+	sale_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds_to_treasury();
+		open_default_sale(bond_id);
+		Timestamp::set_timestamp(START);
+		assert_ok!(Bonds::buy(RuntimeOrigin::signed(BOB), 0, 10 * ONE));
+
+		// Act
+		assert_ok!(Bonds::close_sale(RuntimeOrigin::root(), 0));
+
+		// Assert
+		expect_events(vec![Event::SaleClosed {
+			sale_id: 0,
+			unsold: SALE_AMOUNT - 10 * ONE,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::sale(0), None);
+		assert_eq!(Tokens::free_balance(bond_id, &TREASURY), SALE_AMOUNT - 10 * ONE);
+		assert_eq!(Tokens::free_balance(bond_id, &Bonds::pallet_account_id()), 0);
+	});
+}
+
+#[test]
+fn close_sale_should_work_for_anyone_when_sale_has_ended() {
+	sale_ext().execute_with(|| {
+		let bond_id = issue_bonds_to_treasury();
+		open_default_sale(bond_id);
+
+		assert_noop!(
+			Bonds::close_sale(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::SaleNotEnded
+		);
+
+		Timestamp::set_timestamp(END);
+
+		assert_ok!(Bonds::close_sale(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Bonds::sale(0), None);
+		assert_eq!(Tokens::free_balance(bond_id, &TREASURY), SALE_AMOUNT);
+	});
+}
@@ -0,0 +1,37 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct TradeVolume<Balance> {
+	/// Amount which can still be sold to the pool.
+	pub amount_in: Balance,
+	/// Amount which can still be bought from the pool.
+	pub amount_out: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RemainingLimits<Balance> {
+	/// Net trade volume which can still be traded. `None` if trades are not limited.
+	pub trade_volume: Option<TradeVolume<Balance>>,
+	/// Liquidity which can still be added. `None` if adding liquidity is not limited.
+	pub add_liquidity: Option<Balance>,
+	/// Liquidity which can still be removed. `None` if removing liquidity is not limited.
+	pub remove_liquidity: Option<Balance>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CircuitBreakerApi<AssetId, AccountId, Balance> where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Limits of an asset remaining in the current block. `None` if the asset is not in the pool.
+		fn remaining_limits(asset_id: AssetId) -> Option<RemainingLimits<Balance>>;
+		/// Trade volume of an asset remaining to an account in the current block. `None` if the trades of the
+		/// account are not limited or the asset is not in the pool.
+		fn remaining_account_trade_volume(asset_id: AssetId, who: AccountId) -> Option<TradeVolume<Balance>>;
+	}
+}
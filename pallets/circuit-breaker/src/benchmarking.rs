@@ -189,5 +189,49 @@ benchmarks! {
 		assert!(before_out != after_out);
 	}
 
+	set_account_trade_volume_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let trade_limit = Some((crate::MAX_LIMIT_VALUE, 1));
+
+	}: _(RawOrigin::Root, asset_id, trade_limit)
+	verify {
+		assert_eq!(AccountTradeVolumeLimitPerAsset::<T>::get(asset_id), trade_limit);
+	}
+
+	add_to_trade_volume_whitelist {
+		let user: T::AccountId = account("user", 0, 1);
+	}: _(RawOrigin::Root, user.clone())
+	verify {
+		assert!(TradeVolumeWhitelist::<T>::contains_key(user));
+	}
+
+	remove_from_trade_volume_whitelist {
+		let user: T::AccountId = account("user", 0, 1);
+		crate::Pallet::<T>::add_to_trade_volume_whitelist(RawOrigin::Root.into(), user.clone())?;
+	}: _(RawOrigin::Root, user.clone())
+	verify {
+		assert!(!TradeVolumeWhitelist::<T>::contains_key(user));
+	}
+
+	ensure_account_trade_volume_limit {
+		let user: T::AccountId = account("user", 0, 1);
+		let asset_in_id = T::AssetId::from(2u32);
+		let asset_in_reserve = 100_000_000_000_000u128;
+		let amount_in= 10_000_000_000_000u128;
+		let asset_out_id = T::AssetId::from(3u32);
+		let asset_out_reserve = 200_000_000_000_000u128;
+		let amount_out = 10_000_000_000_000u128;
+		let trade_limit = Some((crate::MAX_LIMIT_VALUE, 1));
+
+		crate::Pallet::<T>::set_account_trade_volume_limit(RawOrigin::Root.into(), asset_in_id, trade_limit)?;
+		crate::Pallet::<T>::set_account_trade_volume_limit(RawOrigin::Root.into(), asset_out_id, trade_limit)?;
+	}: {
+		crate::Pallet::<T>::ensure_account_trade_volume_limit(&user, asset_in_id, asset_in_reserve.into(), amount_in.into(), asset_out_id, asset_out_reserve.into(), amount_out.into())?
+	}
+	verify {
+		assert!(AllowedAccountTradeVolumeLimit::<T>::get(asset_in_id, &user).is_some());
+		assert!(AllowedAccountTradeVolumeLimit::<T>::get(asset_out_id, &user).is_some());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
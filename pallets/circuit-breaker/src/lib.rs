@@ -25,7 +25,7 @@ use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
-use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
+use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating, Zero};
 use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
 
 pub mod weights;
@@ -90,6 +90,19 @@ where
 		self.check_influx_limit()?;
 		Ok(())
 	}
+
+	/// Amounts which can still be traded in and out before the net volume reaches the limit.
+	pub fn remaining(&self) -> (T::Balance, T::Balance) {
+		let remaining_in = self
+			.limit
+			.saturating_add(self.volume_out)
+			.saturating_sub(self.volume_in);
+		let remaining_out = self
+			.limit
+			.saturating_add(self.volume_in)
+			.saturating_sub(self.volume_out);
+		(remaining_in, remaining_out)
+	}
 }
 
 #[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
@@ -118,6 +131,11 @@ where
 		);
 		Ok(())
 	}
+
+	/// Amount of liquidity which can still be added or removed before the limit is reached.
+	pub fn remaining(&self) -> T::Balance {
+		self.limit.saturating_sub(self.liquidity)
+	}
 }
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
@@ -141,6 +159,7 @@ pub mod pallet {
 			let _ = <AllowedTradeVolumeLimitPerAsset<T>>::clear(u32::MAX, None);
 			let _ = <AllowedAddLiquidityAmountPerAsset<T>>::clear(u32::MAX, None);
 			let _ = <AllowedRemoveLiquidityAmountPerAsset<T>>::clear(u32::MAX, None);
+			let _ = <AllowedAccountTradeVolumeLimit<T>>::clear(u32::MAX, None);
 		}
 
 		fn integrity_test() {
@@ -162,6 +181,13 @@ pub mod pallet {
 					"Circuit Breaker: Max remove liquidity limit per block is set to invalid value."
 				);
 			}
+
+			if let Some(account_limit) = T::DefaultMaxAccountTradeVolumeLimitPerBlock::get() {
+				assert!(
+					Self::validate_limit(account_limit).is_ok(),
+					"Circuit Breaker: Max account trade volume limit per block is set to invalid value."
+				);
+			}
 		}
 	}
 
@@ -217,6 +243,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type DefaultMaxRemoveLiquidityLimitPerBlock: Get<Option<(u32, u32)>>;
 
+		/// The maximum percentage of a pool's liquidity that can be traded by a single account in a block.
+		/// Represented as an optional non-zero fraction (nominator, denominator) with the max value being 10_000.
+		/// If set to None, the limits are not enforced.
+		#[pallet::constant]
+		type DefaultMaxAccountTradeVolumeLimitPerBlock: Get<Option<(u32, u32)>>;
+
 		/// Omnipool's hub asset id. The limits are not tracked for this asset.
 		type OmnipoolHubAsset: Get<Self::AssetId>;
 
@@ -284,6 +316,30 @@ pub mod pallet {
 	pub type AllowedRemoveLiquidityAmountPerAsset<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
 
+	/// Default maximum account trade volume limit per block
+	#[pallet::type_value]
+	pub fn DefaultAccountTradeVolumeLimit<T: Config>() -> Option<(u32, u32)> {
+		T::DefaultMaxAccountTradeVolumeLimitPerBlock::get()
+	}
+
+	#[pallet::storage]
+	/// Trade volume limits of a single account per asset.
+	/// If not set, returns the default limit.
+	#[pallet::getter(fn account_trade_volume_limit_per_asset)]
+	pub type AccountTradeVolumeLimitPerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, Option<(u32, u32)>, ValueQuery, DefaultAccountTradeVolumeLimit<T>>;
+
+	#[pallet::storage]
+	/// Trade volumes of accounts per asset
+	#[pallet::getter(fn allowed_account_trade_volume_limit)]
+	pub type AllowedAccountTradeVolumeLimit<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AccountId, TradeVolumeLimit<T>>;
+
+	#[pallet::storage]
+	/// Accounts which are not subject to the account trade volume limits, e.g. market makers.
+	#[pallet::getter(fn trade_volume_whitelist)]
+	pub type TradeVolumeWhitelist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -302,6 +358,15 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			liquidity_limit: Option<(u32, u32)>,
 		},
+		/// Account trade volume limit of an asset was changed.
+		AccountTradeVolumeLimitChanged {
+			asset_id: T::AssetId,
+			trade_volume_limit: Option<(u32, u32)>,
+		},
+		/// Account was added to the trade volume whitelist.
+		TradeVolumeWhitelistAdded { who: T::AccountId },
+		/// Account was removed from the trade volume whitelist.
+		TradeVolumeWhitelistRemoved { who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -319,6 +384,12 @@ pub mod pallet {
 		MaxLiquidityLimitPerBlockReached,
 		/// Asset is not allowed to have a limit
 		NotAllowed,
+		/// Trade volume of the account per block has been reached
+		AccountTradeVolumeLimitReached,
+		/// Account is already whitelisted
+		AlreadyWhitelisted,
+		/// Account is not whitelisted
+		NotWhitelisted,
 	}
 
 	#[pallet::call]
@@ -422,6 +493,91 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set trade volume limit of a single account for an asset.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `UpdateLimitsOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `trade_volume_limit`: Optional account trade volume limit represented as a percentage
+		///
+		/// Emits `AccountTradeVolumeLimitChanged` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_account_trade_volume_limit())]
+		pub fn set_account_trade_volume_limit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			trade_volume_limit: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::UpdateLimitsOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			if let Some(limit) = trade_volume_limit {
+				Self::validate_limit(limit)?;
+			}
+
+			<AccountTradeVolumeLimitPerAsset<T>>::insert(asset_id, trade_volume_limit);
+
+			Self::deposit_event(Event::AccountTradeVolumeLimitChanged {
+				asset_id,
+				trade_volume_limit,
+			});
+
+			Ok(())
+		}
+
+		/// Add an account to the trade volume whitelist.
+		/// Trades of whitelisted accounts are not subject to the account trade volume limits.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `UpdateLimitsOrigin`
+		/// - `who`: Account to whitelist
+		///
+		/// Emits `TradeVolumeWhitelistAdded` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_to_trade_volume_whitelist())]
+		pub fn add_to_trade_volume_whitelist(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::UpdateLimitsOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!<TradeVolumeWhitelist<T>>::contains_key(&who),
+				Error::<T>::AlreadyWhitelisted
+			);
+
+			<TradeVolumeWhitelist<T>>::insert(&who, ());
+
+			Self::deposit_event(Event::TradeVolumeWhitelistAdded { who });
+
+			Ok(())
+		}
+
+		/// Remove an account from the trade volume whitelist.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `UpdateLimitsOrigin`
+		/// - `who`: Whitelisted account
+		///
+		/// Emits `TradeVolumeWhitelistRemoved` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_from_trade_volume_whitelist())]
+		pub fn remove_from_trade_volume_whitelist(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::UpdateLimitsOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				<TradeVolumeWhitelist<T>>::contains_key(&who),
+				Error::<T>::NotWhitelisted
+			);
+
+			<TradeVolumeWhitelist<T>>::remove(&who);
+
+			Self::deposit_event(Event::TradeVolumeWhitelistRemoved { who });
+
+			Ok(())
+		}
 	}
 }
 
@@ -515,6 +671,41 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_and_update_account_trade_volume_limit(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		// ignore Omnipool's hub asset
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(());
+		}
+
+		let Some(limit) = Pallet::<T>::account_trade_volume_limit_per_asset(asset_id) else {
+			return Ok(());
+		};
+
+		let mut allowed_volume = match Pallet::<T>::allowed_account_trade_volume_limit(asset_id, who) {
+			Some(volume) => volume,
+			None => TradeVolumeLimit::<T> {
+				limit: Self::calculate_limit(asset_reserve, limit)?,
+				volume_in: Zero::zero(),
+				volume_out: Zero::zero(),
+			},
+		};
+
+		allowed_volume.update_amounts(amount_in, amount_out)?;
+		allowed_volume
+			.check_limits()
+			.map_err(|_| Error::<T>::AccountTradeVolumeLimitReached)?;
+
+		<AllowedAccountTradeVolumeLimit<T>>::insert(asset_id, who, allowed_volume);
+
+		Ok(())
+	}
+
 	fn ensure_and_update_add_liquidity_limit(asset_id: T::AssetId, added_liquidity: T::Balance) -> DispatchResult {
 		if asset_id != T::OmnipoolHubAsset::get() && Pallet::<T>::add_liquidity_limit_per_asset(asset_id).is_some() {
 			let mut allowed_liquidity_limit = Pallet::<T>::allowed_add_liquidity_limit_per_asset(asset_id)
@@ -583,6 +774,43 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_pool_state_change_limit())
 	}
 
+	/// Ensures that the net trade volume of `who` in a block stays within the account trade volume limits.
+	/// Accounts in `TradeVolumeWhitelist` or `WhitelistedAccounts` are not limited.
+	pub fn ensure_account_trade_volume_limit(
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		asset_in_reserve: T::Balance,
+		amount_in: T::Balance,
+		asset_out: T::AssetId,
+		asset_out_reserve: T::Balance,
+		amount_out: T::Balance,
+	) -> Result<Weight, DispatchError> {
+		if Self::is_trade_volume_whitelisted(who) {
+			return Ok(Weight::zero());
+		}
+
+		Pallet::<T>::ensure_and_update_account_trade_volume_limit(
+			who,
+			asset_in,
+			asset_in_reserve,
+			amount_in,
+			Zero::zero(),
+		)?;
+		Pallet::<T>::ensure_and_update_account_trade_volume_limit(
+			who,
+			asset_out,
+			asset_out_reserve,
+			Zero::zero(),
+			amount_out,
+		)?;
+
+		Ok(T::WeightInfo::ensure_account_trade_volume_limit())
+	}
+
+	pub fn is_trade_volume_whitelisted(who: &T::AccountId) -> bool {
+		<TradeVolumeWhitelist<T>>::contains_key(who) || T::WhitelistedAccounts::contains(who)
+	}
+
 	pub fn ensure_add_liquidity_limit(
 		origin: OriginFor<T>,
 		asset_id: T::AssetId,
@@ -617,6 +845,111 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
 	}
 
+	/// Amounts of `asset_id` which can still be traded in and out in the current block before the trade volume
+	/// limit is reached.
+	///
+	/// The limit is calculated from `asset_reserve` if the asset has not been traded in the current block yet.
+	/// Returns `None` for Omnipool's hub asset, which is not limited.
+	pub fn remaining_trade_volume(
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+	) -> Result<Option<(T::Balance, T::Balance)>, DispatchError> {
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(None);
+		}
+
+		let remaining = match Pallet::<T>::allowed_trade_volume_limit_per_asset(asset_id) {
+			Some(volume) => volume.remaining(),
+			None => {
+				let limit = Self::calculate_limit(asset_reserve, Pallet::<T>::trade_volume_limit_per_asset(asset_id))?;
+				(limit, limit)
+			}
+		};
+
+		Ok(Some(remaining))
+	}
+
+	/// Amount of `asset_id` which can still be added as liquidity in the current block.
+	///
+	/// The limit is calculated from `asset_reserve` if no liquidity of the asset has been added or removed
+	/// in the current block yet. Returns `None` if adding liquidity of the asset is not limited.
+	pub fn remaining_add_liquidity(
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+	) -> Result<Option<T::Balance>, DispatchError> {
+		Self::remaining_liquidity(
+			asset_id,
+			asset_reserve,
+			Pallet::<T>::add_liquidity_limit_per_asset(asset_id),
+			Pallet::<T>::allowed_add_liquidity_limit_per_asset(asset_id),
+		)
+	}
+
+	/// Amount of `asset_id` which can still be removed as liquidity in the current block.
+	///
+	/// The limit is calculated from `asset_reserve` if no liquidity of the asset has been added or removed
+	/// in the current block yet. Returns `None` if removing liquidity of the asset is not limited.
+	pub fn remaining_remove_liquidity(
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+	) -> Result<Option<T::Balance>, DispatchError> {
+		Self::remaining_liquidity(
+			asset_id,
+			asset_reserve,
+			Pallet::<T>::remove_liquidity_limit_per_asset(asset_id),
+			Pallet::<T>::allowed_remove_liquidity_limit_per_asset(asset_id),
+		)
+	}
+
+	/// Amounts of `asset_id` which `who` can still trade in and out in the current block before the account
+	/// trade volume limit is reached.
+	///
+	/// The limit is calculated from `asset_reserve` if `who` has not traded the asset in the current block yet.
+	/// Returns `None` if the trades of `who` are not limited.
+	pub fn remaining_account_trade_volume(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+	) -> Result<Option<(T::Balance, T::Balance)>, DispatchError> {
+		if asset_id == T::OmnipoolHubAsset::get() || Self::is_trade_volume_whitelisted(who) {
+			return Ok(None);
+		}
+
+		let Some(limit) = Pallet::<T>::account_trade_volume_limit_per_asset(asset_id) else {
+			return Ok(None);
+		};
+
+		let remaining = match Pallet::<T>::allowed_account_trade_volume_limit(asset_id, who) {
+			Some(volume) => volume.remaining(),
+			None => {
+				let limit = Self::calculate_limit(asset_reserve, limit)?;
+				(limit, limit)
+			}
+		};
+
+		Ok(Some(remaining))
+	}
+
+	fn remaining_liquidity(
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		limit: Option<(u32, u32)>,
+		allowed_liquidity: Option<LiquidityLimit<T>>,
+	) -> Result<Option<T::Balance>, DispatchError> {
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(None);
+		}
+
+		let Some(limit) = limit else {
+			return Ok(None);
+		};
+
+		match allowed_liquidity {
+			Some(liquidity) => Ok(Some(liquidity.remaining())),
+			None => Self::calculate_limit(asset_reserve, limit).map(Some),
+		}
+	}
+
 	pub(crate) fn is_origin_whitelisted_or_root(origin: OriginFor<T>) -> Result<bool, DispatchError> {
 		let who = ensure_signed_or_root(origin)?;
 		match who {
@@ -0,0 +1,423 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_storage_noop;
+pub use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn omnipool_ext(account_limit: Option<(u32, u32)>) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1_000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, DOT, 2_000_000 * ONE),
+			(LP1, ACA, 2_000_000 * ONE),
+			(TRADER, DOT, 2_000_000 * ONE),
+			(WHITELISTED_ACCCOUNT, DOT, 2_000_000 * ONE),
+		])
+		.with_registered_asset(DOT)
+		.with_registered_asset(ACA)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(DOT, FixedU128::from_float(0.65), LP1, 10_000 * ONE)
+		.with_token(ACA, FixedU128::from_float(0.65), LP1, 10_000 * ONE)
+		.with_max_trade_volume_limit_per_block(TEN_PERCENT)
+		.with_max_account_trade_volume_limit_per_block(account_limit)
+		.build()
+}
+
+#[test]
+fn account_trade_volume_limit_should_not_be_enforced_when_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_storage_noop!(CircuitBreaker::ensure_account_trade_volume_limit(
+			&ALICE,
+			HDX,
+			INITIAL_LIQUIDITY,
+			INITIAL_LIQUIDITY,
+			DAI,
+			INITIAL_LIQUIDITY,
+			INITIAL_LIQUIDITY,
+		)
+		.unwrap());
+	});
+}
+
+#[test]
+fn account_trade_volume_limit_should_be_stored_when_called_first_time() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(FIVE_PERCENT))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&ALICE,
+				HDX,
+				INITIAL_LIQUIDITY,
+				10_000,
+				DAI,
+				2 * INITIAL_LIQUIDITY,
+				20_000,
+			));
+
+			// Assert
+			assert_eq!(
+				CircuitBreaker::allowed_account_trade_volume_limit(HDX, ALICE).unwrap(),
+				TradeVolumeLimit {
+					volume_in: 10_000,
+					volume_out: 0,
+					limit: 50_000,
+				}
+			);
+			assert_eq!(
+				CircuitBreaker::allowed_account_trade_volume_limit(DAI, ALICE).unwrap(),
+				TradeVolumeLimit {
+					volume_in: 0,
+					volume_out: 20_000,
+					limit: 100_000,
+				}
+			);
+		});
+}
+
+#[test]
+fn account_trade_volume_limit_should_not_be_stored_for_omnipool_hub_asset() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(FIVE_PERCENT))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&ALICE,
+				LRNA,
+				INITIAL_LIQUIDITY,
+				10_000,
+				DAI,
+				INITIAL_LIQUIDITY,
+				10_000,
+			));
+
+			// Assert
+			assert_eq!(CircuitBreaker::allowed_account_trade_volume_limit(LRNA, ALICE), None);
+		});
+}
+
+#[test]
+fn account_trade_volume_limit_should_fail_when_limit_exceeded() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(FIVE_PERCENT))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				CircuitBreaker::ensure_account_trade_volume_limit(
+					&ALICE,
+					HDX,
+					INITIAL_LIQUIDITY,
+					50_001,
+					DAI,
+					INITIAL_LIQUIDITY,
+					10_000,
+				),
+				Error::<Test>::AccountTradeVolumeLimitReached
+			);
+		});
+}
+
+#[test]
+fn account_trade_volume_limit_should_be_tracked_separately_for_each_account() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(FIVE_PERCENT))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&ALICE,
+				HDX,
+				INITIAL_LIQUIDITY,
+				50_000,
+				DAI,
+				INITIAL_LIQUIDITY,
+				10_000,
+			));
+
+			// Act & Assert
+			assert_noop!(
+				CircuitBreaker::ensure_account_trade_volume_limit(
+					&ALICE,
+					HDX,
+					INITIAL_LIQUIDITY,
+					1,
+					DAI,
+					INITIAL_LIQUIDITY,
+					1,
+				),
+				Error::<Test>::AccountTradeVolumeLimitReached
+			);
+			assert_ok!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&TRADER,
+				HDX,
+				INITIAL_LIQUIDITY,
+				50_000,
+				DAI,
+				INITIAL_LIQUIDITY,
+				10_000,
+			));
+		});
+}
+
+#[test]
+fn account_trade_volume_storage_should_be_cleared_at_the_end_of_block() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(FIVE_PERCENT))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&ALICE,
+				HDX,
+				INITIAL_LIQUIDITY,
+				50_000,
+				DAI,
+				INITIAL_LIQUIDITY,
+				10_000,
+			));
+
+			// Act
+			CircuitBreaker::on_finalize(2);
+
+			// Assert
+			assert_eq!(CircuitBreaker::allowed_account_trade_volume_limit(HDX, ALICE), None);
+			assert_eq!(CircuitBreaker::allowed_account_trade_volume_limit(DAI, ALICE), None);
+		});
+}
+
+#[test]
+fn account_trade_volume_limit_should_be_ignored_for_whitelisted_accounts() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(FIVE_PERCENT))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::add_to_trade_volume_whitelist(
+				RuntimeOrigin::root(),
+				ALICE
+			));
+
+			// Act & Assert
+			assert_storage_noop!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&ALICE,
+				HDX,
+				INITIAL_LIQUIDITY,
+				INITIAL_LIQUIDITY,
+				DAI,
+				INITIAL_LIQUIDITY,
+				INITIAL_LIQUIDITY,
+			)
+			.unwrap());
+			assert_storage_noop!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&WHITELISTED_ACCCOUNT,
+				HDX,
+				INITIAL_LIQUIDITY,
+				INITIAL_LIQUIDITY,
+				DAI,
+				INITIAL_LIQUIDITY,
+				INITIAL_LIQUIDITY,
+			)
+			.unwrap());
+		});
+}
+
+#[test]
+fn set_account_trade_volume_limit_should_store_new_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let default_limit = <Test as Config>::DefaultMaxAccountTradeVolumeLimitPerBlock::get();
+		assert_eq!(CircuitBreaker::account_trade_volume_limit_per_asset(HDX), default_limit);
+		let new_limit = Some((7, 100));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_account_trade_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			new_limit
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::account_trade_volume_limit_per_asset(HDX), new_limit);
+
+		expect_events(vec![crate::Event::AccountTradeVolumeLimitChanged {
+			asset_id: HDX,
+			trade_volume_limit: new_limit,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_account_trade_volume_limit_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_account_trade_volume_limit(RuntimeOrigin::signed(ALICE), HDX, Some((7, 100))),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_account_trade_volume_limit_should_fail_when_setting_limit_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_account_trade_volume_limit(RuntimeOrigin::root(), LRNA, Some((7, 100))),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_account_trade_volume_limit_should_fail_when_limit_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_account_trade_volume_limit(
+				RuntimeOrigin::root(),
+				HDX,
+				Some((MAX_LIMIT_VALUE + 1, 100))
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn add_to_trade_volume_whitelist_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CircuitBreaker::add_to_trade_volume_whitelist(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+
+		assert_eq!(CircuitBreaker::trade_volume_whitelist(ALICE), Some(()));
+		assert!(CircuitBreaker::is_trade_volume_whitelisted(&ALICE));
+		expect_events(vec![crate::Event::TradeVolumeWhitelistAdded { who: ALICE }.into()]);
+	});
+}
+
+#[test]
+fn add_to_trade_volume_whitelist_should_fail_when_already_whitelisted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CircuitBreaker::add_to_trade_volume_whitelist(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+
+		assert_noop!(
+			CircuitBreaker::add_to_trade_volume_whitelist(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::AlreadyWhitelisted
+		);
+	});
+}
+
+#[test]
+fn add_to_trade_volume_whitelist_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::add_to_trade_volume_whitelist(RuntimeOrigin::signed(ALICE), ALICE),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_from_trade_volume_whitelist_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CircuitBreaker::add_to_trade_volume_whitelist(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+
+		assert_ok!(CircuitBreaker::remove_from_trade_volume_whitelist(
+			RuntimeOrigin::root(),
+			ALICE
+		));
+
+		assert_eq!(CircuitBreaker::trade_volume_whitelist(ALICE), None);
+		assert!(!CircuitBreaker::is_trade_volume_whitelisted(&ALICE));
+		expect_events(vec![crate::Event::TradeVolumeWhitelistRemoved { who: ALICE }.into()]);
+	});
+}
+
+#[test]
+fn remove_from_trade_volume_whitelist_should_fail_when_not_whitelisted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::remove_from_trade_volume_whitelist(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_account_trade_volume_limit_exceeded() {
+	omnipool_ext(Some(FIVE_PERCENT)).execute_with(|| {
+		let sell_amount = CircuitBreaker::calculate_limit(10_000 * ONE, FIVE_PERCENT).unwrap() + ONE;
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, sell_amount, 10 * ONE),
+			pallet_circuit_breaker::Error::<Test>::AccountTradeVolumeLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_should_work_when_other_account_reached_account_trade_volume_limit() {
+	omnipool_ext(Some(FIVE_PERCENT)).execute_with(|| {
+		let sell_amount = CircuitBreaker::calculate_limit(10_000 * ONE, FIVE_PERCENT).unwrap() - ONE;
+
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(TRADER),
+			DOT,
+			ACA,
+			sell_amount,
+			10 * ONE
+		));
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 2 * ONE, ONE),
+			pallet_circuit_breaker::Error::<Test>::AccountTradeVolumeLimitReached
+		);
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(LP1),
+			DOT,
+			ACA,
+			sell_amount,
+			10 * ONE
+		));
+	});
+}
+
+#[test]
+fn sell_should_work_when_whitelisted_account_exceeds_account_trade_volume_limit() {
+	omnipool_ext(Some(FIVE_PERCENT)).execute_with(|| {
+		let sell_amount = CircuitBreaker::calculate_limit(10_000 * ONE, FIVE_PERCENT).unwrap() + ONE;
+
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(WHITELISTED_ACCCOUNT),
+			DOT,
+			ACA,
+			sell_amount,
+			10 * ONE
+		));
+	});
+}
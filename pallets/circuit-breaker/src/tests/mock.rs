@@ -76,6 +76,7 @@ thread_local! {
 	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<(u32, u32)> = const { RefCell::new((2_000, 10_000)) }; // 20%
 	pub static MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((4_000, 10_000))) }; // 40%
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((2_000, 10_000))) }; // 20%
+	pub static MAX_ACCOUNT_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(None) };
 }
 
 frame_support::construct_runtime!(
@@ -130,6 +131,7 @@ parameter_types! {
 	pub DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
+	pub DefaultMaxAccountTradeVolumeLimitPerBlock: Option<(u32, u32)> = MAX_ACCOUNT_TRADE_VOLUME_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub const OmnipoolHubAsset: AssetId = LRNA;
 }
 
@@ -142,6 +144,7 @@ impl pallet_circuit_breaker::Config for Test {
 	type DefaultMaxNetTradeVolumeLimitPerBlock = DefaultMaxNetTradeVolumeLimitPerBlock;
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxAddLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxRemoveLiquidityLimitPerBlock;
+	type DefaultMaxAccountTradeVolumeLimitPerBlock = DefaultMaxAccountTradeVolumeLimitPerBlock;
 	type OmnipoolHubAsset = OmnipoolHubAsset;
 	type WeightInfo = ();
 }
@@ -225,10 +228,23 @@ impl pallet_omnipool::Config for Test {
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
 	type OmnipoolHooks = CircuitBreakerHooks<Test>;
+	type OnTradeExecuted = CircuitBreakerTradeHandler<Test>;
 	type PriceBarrier = ();
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = ();
+	type WithdrawalStreamBlocks = ();
+	type MaxQueuedWithdrawals = ();
+	type MaxQueuedWithdrawalsPerAccount = ();
+	type MaxHubAssetBuyPerBlock = ();
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
 }
 
 pub struct CircuitBreakerHooks<T>(PhantomData<T>);
@@ -271,28 +287,9 @@ where
 
 	fn on_trade(
 		_: RuntimeOrigin,
-		asset_in: AssetInfo<AssetId, Balance>,
-		asset_out: AssetInfo<AssetId, Balance>,
+		_: AssetInfo<AssetId, Balance>,
+		_: AssetInfo<AssetId, Balance>,
 	) -> Result<Weight, Self::Error> {
-		let amount_in = match asset_in.delta_changes.delta_reserve {
-			BalanceUpdate::Increase(am) => am,
-			BalanceUpdate::Decrease(am) => am,
-		};
-
-		let amount_out = match asset_out.delta_changes.delta_reserve {
-			BalanceUpdate::Increase(am) => am,
-			BalanceUpdate::Decrease(am) => am,
-		};
-
-		pallet_circuit_breaker::Pallet::<T>::ensure_pool_state_change_limit(
-			asset_in.asset_id.into(),
-			asset_in.before.reserve.into(),
-			amount_in.into(),
-			asset_out.asset_id.into(),
-			asset_out.before.reserve.into(),
-			amount_out.into(),
-		)?;
-
 		Ok(Weight::zero())
 	}
 
@@ -307,15 +304,46 @@ where
 	fn on_trade_weight() -> Weight {
 		todo!()
 	}
+}
+
+pub struct CircuitBreakerTradeHandler<T>(PhantomData<T>);
+
+impl<T> OnTradeExecuted<AccountId, AssetId, Balance> for CircuitBreakerTradeHandler<T>
+where
+	T: Config + pallet_circuit_breaker::Config,
+	<T as pallet_circuit_breaker::Config>::Balance: From<u128>,
+	<T as pallet_circuit_breaker::Config>::AssetId: From<u32>,
+	<T as frame_system::Config>::AccountId: From<AccountId>,
+{
+	fn on_trade_executed(trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError> {
+		if trade.asset_in == LRNA {
+			return Ok(Balance::zero());
+		}
+
+		pallet_circuit_breaker::Pallet::<T>::ensure_pool_state_change_limit(
+			trade.asset_in.into(),
+			trade.reserve_in.into(),
+			trade.amount_in.into(),
+			trade.asset_out.into(),
+			trade.reserve_out.into(),
+			trade.amount_out.into(),
+		)?;
+		pallet_circuit_breaker::Pallet::<T>::ensure_account_trade_volume_limit(
+			&trade.trader.into(),
+			trade.asset_in.into(),
+			trade.reserve_in.into(),
+			trade.amount_in.into(),
+			trade.asset_out.into(),
+			trade.reserve_out.into(),
+			trade.amount_out.into(),
+		)?;
 
-	fn on_trade_fee(
-		_fee_account: AccountId,
-		_trader: AccountId,
-		_asset: AssetId,
-		_amount: Balance,
-	) -> Result<Balance, Self::Error> {
 		Ok(Balance::zero())
 	}
+
+	fn on_trade_executed_weight(_pool: PoolType<AssetId>) -> Weight {
+		Weight::zero()
+	}
 }
 
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
@@ -369,7 +397,9 @@ impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
 }
 
 use crate::Config;
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
 use hydradx_traits::registry::{AssetKind, Inspect as InspectRegistry};
+use hydradx_traits::router::PoolType;
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
 
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
@@ -431,6 +461,7 @@ pub struct ExtBuilder {
 	max_net_trade_volume_limit_per_block: (u32, u32),
 	max_add_liquidity_limit_per_block: Option<(u32, u32)>,
 	max_remove_liquidity_limit_per_block: Option<(u32, u32)>,
+	max_account_trade_volume_limit_per_block: Option<(u32, u32)>,
 }
 
 impl Default for ExtBuilder {
@@ -485,6 +516,7 @@ impl Default for ExtBuilder {
 			max_net_trade_volume_limit_per_block: (2_000, 10_000),
 			max_add_liquidity_limit_per_block: Some((4_000, 10_000)),
 			max_remove_liquidity_limit_per_block: Some((2_000, 10_000)),
+			max_account_trade_volume_limit_per_block: None,
 		}
 	}
 }
@@ -523,6 +555,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_max_account_trade_volume_limit_per_block(mut self, value: Option<(u32, u32)>) -> Self {
+		self.max_account_trade_volume_limit_per_block = value;
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
@@ -582,6 +619,9 @@ impl ExtBuilder {
 		MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| {
 			*v.borrow_mut() = self.max_remove_liquidity_limit_per_block;
 		});
+		MAX_ACCOUNT_TRADE_VOLUME_LIMIT_PER_BLOCK.with(|v| {
+			*v.borrow_mut() = self.max_account_trade_volume_limit_per_block;
+		});
 
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
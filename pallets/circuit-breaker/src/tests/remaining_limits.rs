@@ -0,0 +1,159 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn remaining_trade_volume_should_be_calculated_from_reserve_when_asset_was_not_traded() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			CircuitBreaker::remaining_trade_volume(HDX, INITIAL_LIQUIDITY),
+			Ok(Some((200_000, 200_000)))
+		);
+	});
+}
+
+#[test]
+fn remaining_trade_volume_should_account_for_net_volume_traded_in_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			HDX,
+			INITIAL_LIQUIDITY,
+			150_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			50_000,
+		));
+
+		// Act & Assert
+		// reserve is ignored once the limit is stored
+		assert_eq!(
+			CircuitBreaker::remaining_trade_volume(HDX, 2 * INITIAL_LIQUIDITY),
+			Ok(Some((50_000, 350_000)))
+		);
+		assert_eq!(
+			CircuitBreaker::remaining_trade_volume(DOT, INITIAL_LIQUIDITY),
+			Ok(Some((250_000, 150_000)))
+		);
+	});
+}
+
+#[test]
+fn remaining_trade_volume_should_be_none_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			CircuitBreaker::remaining_trade_volume(LRNA, INITIAL_LIQUIDITY),
+			Ok(None)
+		);
+	});
+}
+
+#[test]
+fn remaining_liquidity_should_account_for_liquidity_added_and_removed_in_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::ensure_add_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			100_000,
+		));
+		assert_ok!(CircuitBreaker::ensure_remove_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			50_000,
+		));
+
+		// Act & Assert
+		assert_eq!(
+			CircuitBreaker::remaining_add_liquidity(HDX, INITIAL_LIQUIDITY),
+			Ok(Some(300_000))
+		);
+		assert_eq!(
+			CircuitBreaker::remaining_remove_liquidity(HDX, INITIAL_LIQUIDITY),
+			Ok(Some(150_000))
+		);
+		assert_eq!(
+			CircuitBreaker::remaining_add_liquidity(DOT, INITIAL_LIQUIDITY),
+			Ok(Some(400_000))
+		);
+	});
+}
+
+#[test]
+fn remaining_liquidity_should_be_none_when_limit_is_not_set() {
+	ExtBuilder::default()
+		.with_max_add_liquidity_limit_per_block(None)
+		.with_max_remove_liquidity_limit_per_block(None)
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				CircuitBreaker::remaining_add_liquidity(HDX, INITIAL_LIQUIDITY),
+				Ok(None)
+			);
+			assert_eq!(
+				CircuitBreaker::remaining_remove_liquidity(HDX, INITIAL_LIQUIDITY),
+				Ok(None)
+			);
+		});
+}
+
+#[test]
+fn remaining_account_trade_volume_should_account_for_volume_traded_by_account() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(TEN_PERCENT))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::ensure_account_trade_volume_limit(
+				&ALICE,
+				HDX,
+				INITIAL_LIQUIDITY,
+				30_000,
+				DOT,
+				INITIAL_LIQUIDITY,
+				10_000,
+			));
+
+			// Act & Assert
+			assert_eq!(
+				CircuitBreaker::remaining_account_trade_volume(&ALICE, HDX, INITIAL_LIQUIDITY),
+				Ok(Some((70_000, 130_000)))
+			);
+			assert_eq!(
+				CircuitBreaker::remaining_account_trade_volume(&TRADER, HDX, INITIAL_LIQUIDITY),
+				Ok(Some((100_000, 100_000)))
+			);
+		});
+}
+
+#[test]
+fn remaining_account_trade_volume_should_be_none_when_account_is_whitelisted() {
+	ExtBuilder::default()
+		.with_max_account_trade_volume_limit_per_block(Some(TEN_PERCENT))
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				CircuitBreaker::remaining_account_trade_volume(&WHITELISTED_ACCCOUNT, HDX, INITIAL_LIQUIDITY),
+				Ok(None)
+			);
+		});
+}
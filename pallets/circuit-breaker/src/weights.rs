@@ -60,6 +60,10 @@ pub trait WeightInfo {
 	fn ensure_pool_state_change_limit() -> Weight;
 	fn ensure_add_liquidity_limit() -> Weight;
 	fn ensure_remove_liquidity_limit() -> Weight;
+	fn set_account_trade_volume_limit() -> Weight;
+	fn add_to_trade_volume_whitelist() -> Weight;
+	fn remove_from_trade_volume_whitelist() -> Weight;
+	fn ensure_account_trade_volume_limit() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -175,4 +179,51 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `CircuitBreaker::AccountTradeVolumeLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::AccountTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn set_account_trade_volume_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_941_000 picoseconds.
+		Weight::from_parts(9_203_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::TradeVolumeWhitelist` (r:1 w:1)
+	/// Proof: `CircuitBreaker::TradeVolumeWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn add_to_trade_volume_whitelist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_108_000 picoseconds.
+		Weight::from_parts(12_461_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::TradeVolumeWhitelist` (r:1 w:1)
+	/// Proof: `CircuitBreaker::TradeVolumeWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_from_trade_volume_whitelist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `51`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_673_000 picoseconds.
+		Weight::from_parts(12_955_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::TradeVolumeWhitelist` (r:1 w:0)
+	/// Proof: `CircuitBreaker::TradeVolumeWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AccountTradeVolumeLimitPerAsset` (r:2 w:0)
+	/// Proof: `CircuitBreaker::AccountTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AllowedAccountTradeVolumeLimit` (r:2 w:2)
+	/// Proof: `CircuitBreaker::AllowedAccountTradeVolumeLimit` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn ensure_account_trade_volume_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3`
+		//  Estimated: `6172`
+		// Minimum execution time: 21_874_000 picoseconds.
+		Weight::from_parts(22_305_000, 6172)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -47,7 +47,12 @@
 //! or the default value from the pallet configuration will be used.
 //! 2. Slippage Error: If the minimum amount out (sell) or maximum amount in (buy) slippage limits are not reached.
 //! These limits are calculated based on the last block's oracle price and the user-specified slippage.
-//! If no slippage is specified, the default value from the pallet configuration will be used.
+//! If no slippage is specified, the default tolerance is derived from the oracle volatility of the route,
+//! which is the relative difference between the last block and the short oracle price.
+//! The volatility is multiplied by `SlippageVolatilityMultiplier` and the result is bounded
+//! by `MaxPriceDifferenceBetweenBlocks` from below and by `MaxConfigurablePriceDifferenceBetweenBlocks` from above.
+//! This keeps the tolerance tight in calm markets and widens it when prices move, up to the configured maximum.
+//! A slippage specified by the user is used as is.
 //!
 //! If a trade fails due to these errors, the trade will be retried.
 //! If the number of retries reaches the maximum number of retries, the schedule will be permanently terminated.
@@ -55,6 +60,56 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Termination modes
+//!
+//! By default, a schedule is completed when the remaining budget is not enough for the next trade,
+//! and the leftover is unreserved.
+//!
+//! With `TerminationMode::BudgetExhausted`, the schedule keeps executing until the whole budget is spent.
+//! If a trade would leave less than is needed for another execution, the final execution sells
+//! all of the remaining budget instead, so no dust is left. For buy orders, the final execution is
+//! a sell of the remaining budget limited by the price given by `amount_out` and `max_amount_in`.
+//!
+//! `TerminationMode::TargetAmountOut` works the same way, but the schedule is also completed once the accumulated
+//! `amount_out` reaches the target. Amounts of buy orders are capped so the target is not exceeded.
+//!
+//! ### Time based schedules
+//!
+//! Instead of the `period` in blocks, a schedule can specify `time_period` in milliseconds.
+//! Executions of such schedule are planned by timestamp, so the schedule is not skewed when block times vary.
+//!
+//! Time based executions are planned in time buckets of `TimeBucketSize`. When the timestamp reaches a bucket,
+//! its schedules are planned for execution in the next block. Next execution time is calculated from
+//! the previously planned time and not from the time of the actual execution, so delays don't accumulate.
+//! If an execution is delayed by more than the period, the missed executions are skipped.
+//!
+//! Retries and random bumps of time based schedules are still planned in blocks.
+//!
+//! ### Keeper execution
+//!
+//! With `ExecutionMode::Keeper`, the schedule is not executed automatically during block initialization.
+//! Instead, any signed account (keeper) can execute it by `execute_schedule` from its planned block,
+//! so the executions are spread over blocks and the keeper pays for the execution weight.
+//! No weight based fee is charged from the schedule; the keeper receives a bounty from the schedule budget instead.
+//!
+//! The bounty is `KeeperBountyInNativeCurrency`, converted to the sold currency, when the schedule is executed
+//! in its planned block. It decreases linearly over the `KeeperExecutionWindow`, so prompt executions are rewarded
+//! the most. If no keeper executes the schedule within the window, the missed execution is skipped without bounty
+//! and the schedule is planned for its next period.
+//!
+//! ### Remote schedules
+//!
+//! A schedule can be created by `schedule_remote` with a destination location on a remote chain.
+//! This allows remote chains (e.g. treasuries of sibling parachains) to fund and create a schedule by XCM,
+//! without keeping the proceeds on HydraDX. The schedule is owned by the account the XCM origin is converted to.
+//!
+//! After each successful trade, the received amount is transferred to the destination by `RemoteTransfer`.
+//! Once the schedule is completed or terminated, the unreserved leftover of the budget is transferred back as well.
+//! Fees of the transfers are paid from the transferred amounts, and the execution weight of the transfers
+//! is included in the transaction fee of the schedule.
+//!
+//! If a transfer fails, the assets are kept by the owner of the schedule.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and TerminateOrigin can terminate a DCA schedule. However, users can only terminate schedules that they own.
@@ -67,7 +122,8 @@ use frame_support::traits::DefensiveOption;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Get, Len},
+	storage::with_storage_layer,
+	traits::{Contains, Get, Len, Time},
 	transactional,
 	weights::WeightToFee as FrameSupportWeight,
 };
@@ -81,23 +137,27 @@ use hydradx_traits::fee::{InspectTransactionFeeCurrency, SwappablePaymentAssetTr
 use hydradx_traits::router::{inverse_route, RouteProvider};
 use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, RouterT, Trade};
 use hydradx_traits::NativePriceOracle;
+use hydradx_traits::OnHookWeightUsed;
 use hydradx_traits::OraclePeriod;
 use hydradx_traits::PriceOracle;
 use orml_traits::{arithmetic::CheckedAdd, MultiCurrency, NamedMultiReservableCurrency};
+use primitives::Moment;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::CheckedMul;
 use sp_runtime::{
-	traits::{BlockNumberProvider, Saturating},
+	traits::{BlockNumberProvider, Saturating, Zero},
 	ArithmeticError, BoundedVec, DispatchError, FixedPointNumber, FixedU128, Percent, Permill, Rounding,
+	SaturatedConversion,
 };
 use sp_std::vec::Vec;
-use sp_std::{cmp::min, vec};
+use sp_std::{boxed::Box, cmp::min, vec};
 
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod types;
 pub mod weights;
 
@@ -109,7 +169,7 @@ pub use pallet::*;
 
 pub const SHORT_ORACLE_BLOCK_PERIOD: u32 = 10;
 pub const MAX_NUMBER_OF_RETRY_FOR_RESCHEDULING: u32 = 10;
-pub const FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT: Balance = 20;
+pub const MAX_NUMBER_OF_TIME_BUCKETS_PER_BLOCK: u64 = 10;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -125,7 +185,10 @@ pub mod pallet {
 	use orml_traits::NamedMultiReservableCurrency;
 	use sp_runtime::Percent;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -135,6 +198,11 @@ pub mod pallet {
 
 			let mut randomness_generator = Self::get_randomness_generator(current_blocknumber, None);
 
+			weight.saturating_accrue(Self::plan_due_time_buckets(
+				current_blocknumber,
+				&mut randomness_generator,
+			));
+
 			let mut schedule_ids: Vec<ScheduleId> = ScheduleIdsPerBlock::<T>::take(current_blocknumber).to_vec();
 
 			schedule_ids.sort_by_cached_key(|_| randomness_generator.gen::<u32>());
@@ -149,7 +217,7 @@ pub mod pallet {
 					continue;
 				};
 
-				let weight_for_single_execution = Self::get_trade_weight(&schedule.order);
+				let weight_for_single_execution = Self::get_execution_weight(schedule_id, &schedule.order);
 				weight.saturating_accrue(weight_for_single_execution);
 
 				if let Err(e) = Self::prepare_schedule(
@@ -167,39 +235,12 @@ pub mod pallet {
 					}
 				};
 
-				match Self::execute_trade(schedule_id, &schedule) {
-					Ok(amounts) => {
-						if let Err(err) = Self::replan_or_complete(
-							schedule_id,
-							&schedule,
-							current_blocknumber,
-							amounts,
-							&mut randomness_generator,
-						) {
-							Self::terminate_schedule(schedule_id, &schedule, err);
-						}
-					}
-					Err(error) => {
-						Self::deposit_event(Event::TradeFailed {
-							id: schedule_id,
-							who: schedule.owner.clone(),
-							error,
-						});
-
-						if error != Error::<T>::TradeLimitReached.into()
-							&& error != Error::<T>::SlippageLimitReached.into()
-							&& !T::RetryOnError::contains(&error)
-						{
-							Self::terminate_schedule(schedule_id, &schedule, error);
-						} else if let Err(retry_error) =
-							Self::retry_schedule(schedule_id, &schedule, current_blocknumber, &mut randomness_generator)
-						{
-							Self::terminate_schedule(schedule_id, &schedule, retry_error);
-						}
-					}
-				}
+				Self::execute_and_replan(schedule_id, &schedule, current_blocknumber, &mut randomness_generator);
 			}
 
+			let max_block_weight = T::BlockWeights::get().max_block;
+			weight.saturating_accrue(T::HookWeightRecorder::on_hook_weight_used(weight, max_block_weight));
+
 			weight
 		}
 	}
@@ -261,6 +302,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxConfigurablePriceDifferenceBetweenBlocks: Get<Permill>;
 
+		///Multiplier applied to the oracle volatility to get the default slippage tolerance
+		#[pallet::constant]
+		type SlippageVolatilityMultiplier: Get<u32>;
+
 		///The number of max schedules to be executed per block
 		#[pallet::constant]
 		type MaxSchedulePerBlock: Get<u32>;
@@ -273,6 +318,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimalPeriod: Get<u32>;
 
+		///Timestamp provider to plan time based schedules
+		type TimestampProvider: Time<Moment = Moment>;
+
+		///Minimal time period between executions of time based schedules, in milliseconds
+		#[pallet::constant]
+		type MinimalTimePeriod: Get<Moment>;
+
+		///Size of the time buckets time based schedules are planned in, in milliseconds
+		#[pallet::constant]
+		type TimeBucketSize: Get<Moment>;
+
 		///Chance of the random rescheduling
 		#[pallet::constant]
 		type BumpChance: Get<Percent>;
@@ -307,6 +363,27 @@ pub mod pallet {
 		/// AMMs trade weight information.
 		type AmmTradeWeights: AmmTradeWeights<Trade<Self::AssetId>>;
 
+		///Number of blocks after the planned execution block in which keepers can execute the schedule
+		#[pallet::constant]
+		type KeeperExecutionWindow: Get<u32>;
+
+		///Maximum bounty paid to keepers for executing a schedule, specified in native currency
+		#[pallet::constant]
+		type KeeperBountyInNativeCurrency: Get<Balance>;
+
+		///Multiplier of the transaction fee determining the minimum trade amount of a schedule
+		#[pallet::constant]
+		type FeeMultiplierForMinTradeLimit: Get<Balance>;
+
+		///Location on a remote chain the assets of remote schedules are transferred to
+		type RemoteLocation: Parameter + Member + MaxEncodedLen;
+
+		///Transfers the assets of remote schedules to their destinations
+		type RemoteTransfer: RemoteTransfer<Self::AccountId, Self::AssetId, Self::RemoteLocation>;
+
+		/// Handler of weight used by schedule executions in `on_initialize`.
+		type HookWeightRecorder: OnHookWeightUsed;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 	}
@@ -330,6 +407,12 @@ pub mod pallet {
 			who: T::AccountId,
 			block: BlockNumberFor<T>,
 		},
+		///The time based DCA is planned for timestamp
+		ExecutionTimePlanned {
+			id: ScheduleId,
+			who: T::AccountId,
+			time: Moment,
+		},
 		///The DCA trade is successfully executed
 		TradeExecuted {
 			id: ScheduleId,
@@ -356,6 +439,36 @@ pub mod pallet {
 			block: BlockNumberFor<T>,
 			error: DispatchError,
 		},
+		///The keeper is paid for executing the DCA
+		KeeperBountyPaid {
+			id: ScheduleId,
+			keeper: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
+		///The DCA was not executed by any keeper within the execution window
+		ExecutionWindowMissed { id: ScheduleId, who: T::AccountId },
+		///The remote destination of the DCA is set
+		RemoteDestinationSet {
+			id: ScheduleId,
+			who: T::AccountId,
+			destination: T::RemoteLocation,
+		},
+		///The DCA assets are transferred to the remote destination
+		RemoteTransferred {
+			id: ScheduleId,
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
+		///The transfer to the remote destination failed and the assets are kept by the schedule owner
+		RemoteTransferFailed {
+			id: ScheduleId,
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -380,6 +493,8 @@ pub mod pallet {
 		BudgetTooLow,
 		///There is no free block found to plan DCA execution
 		NoFreeBlockFound,
+		///There is no free time bucket found to plan DCA execution
+		NoFreeTimeBucketFound,
 		///The DCA schedule has been manually terminated
 		ManuallyTerminated,
 		///Max number of retries reached for schedule
@@ -396,6 +511,12 @@ pub mod pallet {
 		PeriodTooShort,
 		///Stability threshold cannot be higher than `MaxConfigurablePriceDifferenceBetweenBlock`
 		StabilityThresholdTooHigh,
+		///Target amount out of the termination mode cannot be zero
+		InvalidTargetAmountOut,
+		///The DCA schedule is executed automatically, not by keepers
+		NotKeeperExecuted,
+		///The planned execution block of the DCA schedule has not been reached yet
+		ExecutionNotDue,
 	}
 
 	/// Id sequencer for schedules
@@ -425,12 +546,45 @@ pub mod pallet {
 	#[pallet::getter(fn retries_on_error)]
 	pub type RetriesOnError<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, u8, ValueQuery>;
 
+	/// Keep tracking the accumulated amounts out of DCA schedules with target amount out termination
+	#[pallet::storage]
+	#[pallet::getter(fn accumulated_amounts_out)]
+	pub type AccumulatedAmountsOut<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, Balance, ValueQuery>;
+
 	/// Keep tracking of the schedule ids to be executed in the block
 	#[pallet::storage]
 	#[pallet::getter(fn schedule_ids_per_block)]
 	pub type ScheduleIdsPerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
 
+	/// Keep tracking of the time based schedule ids to be planned for execution when the time bucket is reached
+	#[pallet::storage]
+	#[pallet::getter(fn schedule_ids_per_time_bucket)]
+	pub type ScheduleIdsPerTimeBucket<T: Config> =
+		StorageMap<_, Blake2_128Concat, u64, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
+
+	/// The last time bucket whose schedules have been planned for execution
+	#[pallet::storage]
+	#[pallet::getter(fn last_processed_time_bucket)]
+	pub type LastProcessedTimeBucket<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// Keep tracking the planned execution time and time bucket of time based schedules
+	#[pallet::storage]
+	#[pallet::getter(fn planned_execution_times)]
+	pub type PlannedExecutionTimes<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (Moment, u64), OptionQuery>;
+
+	/// Keep tracking the block from which keeper executed schedules can be executed
+	#[pallet::storage]
+	#[pallet::getter(fn keeper_execution_blocks)]
+	pub type KeeperExecutionBlocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, ScheduleId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Keep tracking the remote destinations of DCA schedules created by `schedule_remote`
+	#[pallet::storage]
+	#[pallet::getter(fn remote_destinations)]
+	pub type RemoteDestinations<T: Config> =
+		StorageMap<_, Blake2_128Concat, ScheduleId, T::RemoteLocation, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new DCA (Dollar-Cost Averaging) schedule and plans the next execution
@@ -444,6 +598,8 @@ pub mod pallet {
 		///
 		/// Trades are executed as long as there is budget remaining
 		/// from the initial `total_amount` allocation.
+		/// Depending on the `termination` mode of the schedule, the final execution can spend all of the remaining budget,
+		/// and the schedule can also be completed once the target amount out is received.
 		///
 		/// If a trade fails due to slippage limit or price stability errors, it will be retried.
 		/// If the number of retries reaches the maximum allowed,
@@ -466,93 +622,9 @@ pub mod pallet {
 			schedule: Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
 			start_execution_block: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-			ensure!(who == schedule.owner, Error::<T>::Forbidden);
-
-			let min_budget = Self::convert_native_amount_to_currency(
-				schedule.order.get_asset_in(),
-				T::MinBudgetInNativeCurrency::get(),
-			)?;
-			ensure!(
-				schedule.total_amount >= min_budget,
-				Error::<T>::TotalAmountIsSmallerThanMinBudget
-			);
-			ensure!(
-				schedule.period >= BlockNumberFor::<T>::from(T::MinimalPeriod::get()),
-				Error::<T>::PeriodTooShort
-			);
-			ensure!(
-				match schedule.stability_threshold {
-					Some(threshold) => threshold <= T::MaxConfigurablePriceDifferenceBetweenBlocks::get(),
-					None => true,
-				},
-				Error::<T>::StabilityThresholdTooHigh
-			);
-
-			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+			let who = ensure_signed(origin)?;
 
-			let amount_in = match schedule.order {
-				Order::Sell { amount_in, .. } => amount_in,
-				Order::Buy { amount_out, .. } => {
-					let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-					Self::get_amount_in_for_buy(&amount_out, &route)?
-				}
-			};
-			let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
-			ensure!(
-				amount_in >= min_trade_amount_in_from_fee,
-				Error::<T>::MinTradeAmountNotReached
-			);
-			ensure!(
-				amount_in >= T::MinimumTradingLimit::get(),
-				Error::<T>::MinTradeAmountNotReached
-			);
-
-			let amount_in_with_transaction_fee = amount_in.saturating_add(transaction_fee).saturating_mul(2);
-			ensure!(
-				amount_in_with_transaction_fee <= schedule.total_amount,
-				Error::<T>::BudgetTooLow
-			);
-
-			let next_schedule_id =
-				ScheduleIdSequencer::<T>::try_mutate(|current_id| -> Result<ScheduleId, DispatchError> {
-					let schedule_id = *current_id;
-					*current_id = current_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
-					Ok(schedule_id)
-				})?;
-
-			Schedules::<T>::insert(next_schedule_id, &schedule);
-			ScheduleOwnership::<T>::insert(who.clone(), next_schedule_id, ());
-			RemainingAmounts::<T>::insert(next_schedule_id, schedule.total_amount);
-			RetriesOnError::<T>::insert(next_schedule_id, 0);
-
-			T::Currencies::reserve_named(
-				&T::NamedReserveId::get(),
-				schedule.order.get_asset_in(),
-				&who,
-				schedule.total_amount,
-			)?;
-
-			let blocknumber_for_first_schedule_execution = Self::get_first_execution_block(start_execution_block)?;
-
-			let mut randomness_generator = Self::get_randomness_generator(
-				frame_system::Pallet::<T>::current_block_number(),
-				Some(next_schedule_id),
-			);
-			Self::plan_schedule_for_block(
-				&who,
-				blocknumber_for_first_schedule_execution,
-				next_schedule_id,
-				&mut randomness_generator,
-			)?;
-
-			Self::deposit_event(Event::Scheduled {
-				id: next_schedule_id,
-				who,
-				period: schedule.period,
-				total_amount: schedule.total_amount,
-				order: schedule.order,
-			});
+			Self::do_schedule(who, schedule, start_execution_block)?;
 
 			Ok(())
 		}
@@ -565,6 +637,7 @@ pub mod pallet {
 		/// - `origin`: schedule owner
 		/// - `schedule_id`: schedule id
 		/// - `next_execution_block`: block number where the schedule is planned.
+		/// Should be `None` for time based schedule which is planned in a time bucket.
 		///
 		/// Emits `Terminated` event when successful.
 		///
@@ -584,27 +657,54 @@ pub mod pallet {
 			}
 
 			Self::try_unreserve_all(schedule_id, &schedule);
+			Self::try_refund_to_remote(schedule_id, &schedule);
 
-			let next_execution_block = next_execution_block.ok_or(Error::<T>::ScheduleNotFound)?;
-
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+			match (schedule.execution, next_execution_block) {
+				//Keeper executed schedule is removed from its planned block together with other storages
+				(ExecutionMode::Keeper, _) => {}
+				//Remove schedule id from next execution block
+				(_, Some(next_execution_block)) => ScheduleIdsPerBlock::<T>::try_mutate_exists(
+					next_execution_block,
+					|maybe_schedule_ids| -> DispatchResult {
+						let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
 
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
+						let index = schedule_ids
+							.binary_search(&schedule_id)
+							.map_err(|_| Error::<T>::ScheduleNotFound)?;
 
-					schedule_ids.remove(index);
+						schedule_ids.remove(index);
 
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
-			)?;
+						if schedule_ids.is_empty() {
+							*maybe_schedule_ids = None;
+						}
+						Ok(())
+					},
+				)?,
+				//Remove schedule id from the time bucket it is planned in
+				(_, None) => {
+					let (_, time_bucket) =
+						PlannedExecutionTimes::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+
+					ScheduleIdsPerTimeBucket::<T>::try_mutate_exists(
+						time_bucket,
+						|maybe_schedule_ids| -> DispatchResult {
+							let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+							let index = schedule_ids
+								.iter()
+								.position(|id| *id == schedule_id)
+								.ok_or(Error::<T>::ScheduleNotFound)?;
+
+							schedule_ids.remove(index);
+
+							if schedule_ids.is_empty() {
+								*maybe_schedule_ids = None;
+							}
+							Ok(())
+						},
+					)?
+				}
+			}
 
 			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
@@ -616,10 +716,231 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Executes a keeper executed DCA schedule which is due.
+		///
+		/// Can be called by any signed account (keeper) from the planned execution block of the schedule.
+		/// The keeper receives a bounty from the schedule budget, which decreases linearly
+		/// over the `KeeperExecutionWindow` after the planned block.
+		///
+		/// If the window has already passed, the missed execution is skipped without bounty,
+		/// and the schedule is planned for its next period.
+		///
+		/// Parameters:
+		/// - `origin`: keeper
+		/// - `schedule_id`: schedule id
+		///
+		/// Emits `KeeperBountyPaid` and `TradeExecuted` events when successful.
+		/// Emits `ExecutionWindowMissed` event when the execution window has passed.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(Pallet::<T>::keeper_execution_weight(*schedule_id))]
+		#[transactional]
+		pub fn execute_schedule(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(
+				schedule.execution == ExecutionMode::Keeper,
+				Error::<T>::NotKeeperExecuted
+			);
+
+			let current_blocknumber = frame_system::Pallet::<T>::current_block_number();
+			let planned_block = KeeperExecutionBlocks::<T>::get(schedule_id).ok_or(Error::<T>::InvalidState)?;
+			ensure!(current_blocknumber >= planned_block, Error::<T>::ExecutionNotDue);
+
+			KeeperExecutionBlocks::<T>::remove(schedule_id);
+
+			let mut randomness_generator = Self::get_randomness_generator(current_blocknumber, Some(schedule_id));
+
+			let elapsed_blocks = current_blocknumber.saturating_sub(planned_block);
+			if elapsed_blocks > T::KeeperExecutionWindow::get().into() {
+				Self::deposit_event(Event::ExecutionWindowMissed {
+					id: schedule_id,
+					who: schedule.owner.clone(),
+				});
+
+				return Self::plan_next_execution(
+					schedule_id,
+					&schedule,
+					current_blocknumber,
+					&mut randomness_generator,
+				);
+			}
+
+			Self::deposit_event(Event::ExecutionStarted {
+				id: schedule_id,
+				block: current_blocknumber,
+			});
+
+			Self::pay_keeper_bounty(&keeper, schedule_id, &schedule, elapsed_blocks.saturated_into())?;
+
+			if let Err(e) =
+				Self::ensure_price_is_stable(schedule_id, &schedule, current_blocknumber, &mut randomness_generator)
+			{
+				if e != Error::<T>::PriceUnstable.into() {
+					Self::terminate_schedule(schedule_id, &schedule, e);
+				}
+				return Ok(());
+			}
+
+			Self::execute_and_replan(schedule_id, &schedule, current_blocknumber, &mut randomness_generator);
+
+			Ok(())
+		}
+
+		/// Creates a new DCA schedule whose assets are transferred to a destination on a remote chain.
+		///
+		/// The schedule is created the same way as by `schedule`. It is meant to be called by XCM `Transact`
+		/// from a remote chain, after the budget is transferred to the account the XCM origin is converted to.
+		///
+		/// After each successful trade, the received amount is transferred to the destination.
+		/// Once the schedule is completed or terminated, the leftover of the budget is transferred back as well.
+		/// If a transfer fails, the assets are kept by the schedule owner.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule`: schedule details
+		/// - `start_execution_block`: first possible execution block for the schedule
+		/// - `destination`: location on the remote chain the assets are transferred to
+		///
+		/// Emits `Scheduled`, `ExecutionPlanned` and `RemoteDestinationSet` events when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule()
+			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>())
+			+ T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn schedule_remote(
+			origin: OriginFor<T>,
+			schedule: Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+			start_execution_block: Option<BlockNumberFor<T>>,
+			destination: Box<T::RemoteLocation>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedule_id = Self::do_schedule(who.clone(), schedule, start_execution_block)?;
+
+			RemoteDestinations::<T>::insert(schedule_id, destination.as_ref());
+
+			Self::deposit_event(Event::RemoteDestinationSet {
+				id: schedule_id,
+				who,
+				destination: *destination,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_schedule(
+		who: T::AccountId,
+		schedule: Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		start_execution_block: Option<BlockNumberFor<T>>,
+	) -> Result<ScheduleId, DispatchError> {
+		ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+		let min_budget = Self::convert_native_amount_to_currency(
+			schedule.order.get_asset_in(),
+			T::MinBudgetInNativeCurrency::get(),
+		)?;
+		ensure!(
+			schedule.total_amount >= min_budget,
+			Error::<T>::TotalAmountIsSmallerThanMinBudget
+		);
+		ensure!(
+			schedule.period >= BlockNumberFor::<T>::from(T::MinimalPeriod::get()),
+			Error::<T>::PeriodTooShort
+		);
+		ensure!(
+			match schedule.time_period {
+				Some(time_period) => time_period >= T::MinimalTimePeriod::get(),
+				None => true,
+			},
+			Error::<T>::PeriodTooShort
+		);
+		ensure!(
+			match schedule.stability_threshold {
+				Some(threshold) => threshold <= T::MaxConfigurablePriceDifferenceBetweenBlocks::get(),
+				None => true,
+			},
+			Error::<T>::StabilityThresholdTooHigh
+		);
+		ensure!(
+			!matches!(schedule.termination, TerminationMode::TargetAmountOut(0)),
+			Error::<T>::InvalidTargetAmountOut
+		);
+
+		let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+
+		let amount_in = match schedule.order {
+			Order::Sell { amount_in, .. } => amount_in,
+			Order::Buy { amount_out, .. } => {
+				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+				Self::get_amount_in_for_buy(&amount_out, &route)?
+			}
+		};
+		let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(T::FeeMultiplierForMinTradeLimit::get());
+		ensure!(
+			amount_in >= min_trade_amount_in_from_fee,
+			Error::<T>::MinTradeAmountNotReached
+		);
+		ensure!(
+			amount_in >= T::MinimumTradingLimit::get(),
+			Error::<T>::MinTradeAmountNotReached
+		);
+
+		let amount_in_with_transaction_fee = amount_in.saturating_add(transaction_fee).saturating_mul(2);
+		ensure!(
+			amount_in_with_transaction_fee <= schedule.total_amount,
+			Error::<T>::BudgetTooLow
+		);
+
+		let next_schedule_id =
+			ScheduleIdSequencer::<T>::try_mutate(|current_id| -> Result<ScheduleId, DispatchError> {
+				let schedule_id = *current_id;
+				*current_id = current_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(schedule_id)
+			})?;
+
+		Schedules::<T>::insert(next_schedule_id, &schedule);
+		ScheduleOwnership::<T>::insert(who.clone(), next_schedule_id, ());
+		RemainingAmounts::<T>::insert(next_schedule_id, schedule.total_amount);
+		RetriesOnError::<T>::insert(next_schedule_id, 0);
+
+		T::Currencies::reserve_named(
+			&T::NamedReserveId::get(),
+			schedule.order.get_asset_in(),
+			&who,
+			schedule.total_amount,
+		)?;
+
+		let blocknumber_for_first_schedule_execution = Self::get_first_execution_block(start_execution_block)?;
+
+		let mut randomness_generator = Self::get_randomness_generator(
+			frame_system::Pallet::<T>::current_block_number(),
+			Some(next_schedule_id),
+		);
+		Self::plan_schedule_for_block(
+			&schedule,
+			blocknumber_for_first_schedule_execution,
+			next_schedule_id,
+			&mut randomness_generator,
+		)?;
+
+		Self::deposit_event(Event::Scheduled {
+			id: next_schedule_id,
+			who,
+			period: schedule.period,
+			total_amount: schedule.total_amount,
+			order: schedule.order,
+		});
+
+		Ok(next_schedule_id)
+	}
+
 	fn get_randomness_generator(current_blocknumber: BlockNumberFor<T>, salt: Option<u32>) -> StdRng {
 		match T::RandomnessProvider::generator(salt) {
 			Ok(generator) => generator,
@@ -666,12 +987,21 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		if Percent::from_percent(randomness_generator.gen_range(0..100)) <= T::BumpChance::get() {
 			let next_block = current_blocknumber.saturating_add(1u32.into());
-			Self::plan_schedule_for_block(&schedule.owner, next_block, schedule_id, randomness_generator)?;
+			Self::plan_schedule_for_block(schedule, next_block, schedule_id, randomness_generator)?;
 			return Err(Error::<T>::Bumped.into());
 		}
 
 		Self::take_transaction_fee_from_user(schedule_id, schedule, weight_for_dca_execution)?;
 
+		Self::ensure_price_is_stable(schedule_id, schedule, current_blocknumber, randomness_generator)
+	}
+
+	fn ensure_price_is_stable(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		current_blocknumber: BlockNumberFor<T>,
+		randomness_generator: &mut StdRng,
+	) -> DispatchResult {
 		if Self::is_price_unstable(schedule) {
 			Self::deposit_event(Event::TradeFailed {
 				id: schedule_id,
@@ -686,6 +1016,45 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn execute_and_replan(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		current_blocknumber: BlockNumberFor<T>,
+		randomness_generator: &mut StdRng,
+	) {
+		match Self::execute_trade(schedule_id, schedule) {
+			Ok(amounts) => {
+				if let Err(err) = Self::replan_or_complete(
+					schedule_id,
+					schedule,
+					current_blocknumber,
+					amounts,
+					randomness_generator,
+				) {
+					Self::terminate_schedule(schedule_id, schedule, err);
+				}
+			}
+			Err(error) => {
+				Self::deposit_event(Event::TradeFailed {
+					id: schedule_id,
+					who: schedule.owner.clone(),
+					error,
+				});
+
+				if error != Error::<T>::TradeLimitReached.into()
+					&& error != Error::<T>::SlippageLimitReached.into()
+					&& !T::RetryOnError::contains(&error)
+				{
+					Self::terminate_schedule(schedule_id, schedule, error);
+				} else if let Err(retry_error) =
+					Self::retry_schedule(schedule_id, schedule, current_blocknumber, randomness_generator)
+				{
+					Self::terminate_schedule(schedule_id, schedule, retry_error);
+				}
+			}
+		}
+	}
+
 	#[transactional]
 	pub fn execute_trade(
 		schedule_id: ScheduleId,
@@ -695,8 +1064,6 @@ impl<T: Config> Pallet<T> {
 
 		match &schedule.order {
 			Order::Sell {
-				asset_in,
-				asset_out,
 				amount_in,
 				min_amount_out,
 				..
@@ -705,57 +1072,48 @@ impl<T: Config> Pallet<T> {
 				let remaining_amount =
 					RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
 				let amount_to_sell = min(remaining_amount, *amount_in);
-
-				Self::unallocate_amount(schedule_id, schedule, amount_to_sell)?;
-
-				let route_for_slippage = inverse_route(route.to_vec());
-				let (estimated_amount_out, slippage_amount) =
-					Self::calculate_last_block_slippage(&route_for_slippage, amount_to_sell, schedule.slippage)?;
-				let last_block_slippage_min_limit = estimated_amount_out
-					.checked_sub(slippage_amount)
-					.ok_or(ArithmeticError::Overflow)?;
-
-				let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(route, amount_to_sell)?;
-				let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
-				let amount_out = last_trade.amount_out;
-
-				if *min_amount_out > last_block_slippage_min_limit {
-					ensure!(amount_out >= *min_amount_out, Error::<T>::TradeLimitReached);
+				let amount_to_sell = if Self::is_final_execution(schedule, remaining_amount, amount_to_sell)? {
+					remaining_amount
 				} else {
-					ensure!(
-						amount_out >= last_block_slippage_min_limit,
-						Error::<T>::SlippageLimitReached
-					);
+					amount_to_sell
 				};
 
-				T::RouteExecutor::sell(
-					origin,
-					*asset_in,
-					*asset_out,
-					amount_to_sell,
-					amount_out,
-					route.to_vec(),
-				)?;
-
-				Ok(AmountInAndOut {
-					amount_in: amount_to_sell,
-					amount_out,
-				})
+				Self::execute_sell(origin, schedule_id, schedule, route, amount_to_sell, *min_amount_out)
 			}
 			Order::Buy {
 				asset_in,
 				asset_out,
-				amount_out,
+				amount_out: order_amount_out,
 				max_amount_in,
 				..
 			} => {
 				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-				let amount_in = Self::get_amount_in_for_buy(amount_out, &route)?;
+				let amount_out = Self::get_amount_out_to_buy(schedule_id, schedule, *order_amount_out);
+				let amount_in = Self::get_amount_in_for_buy(&amount_out, &route)?;
+
+				let remaining_amount =
+					RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+				// When the amount is capped by the target, the buy completes the schedule,
+				// so the remaining budget is sold only if it is not enough for the buy.
+				let is_capped = amount_out < *order_amount_out;
+				if Self::is_final_execution(schedule, remaining_amount, amount_in)?
+					&& (!is_capped || amount_in > remaining_amount)
+				{
+					let min_amount_out = multiply_by_rational_with_rounding(
+						remaining_amount,
+						*order_amount_out,
+						*max_amount_in,
+						Rounding::Down,
+					)
+					.ok_or(ArithmeticError::Overflow)?;
+
+					return Self::execute_sell(origin, schedule_id, schedule, &route, remaining_amount, min_amount_out);
+				}
 
 				Self::unallocate_amount(schedule_id, schedule, amount_in)?;
 
 				let (estimated_amount_in, slippage_amount) =
-					Self::calculate_last_block_slippage(&route, *amount_out, schedule.slippage)?;
+					Self::calculate_last_block_slippage(&route, amount_out, schedule.slippage)?;
 				let last_block_slippage_max_limit = estimated_amount_in
 					.checked_add(slippage_amount)
 					.ok_or(ArithmeticError::Overflow)?;
@@ -769,13 +1127,89 @@ impl<T: Config> Pallet<T> {
 					);
 				};
 
-				T::RouteExecutor::buy(origin, *asset_in, *asset_out, *amount_out, amount_in, route.to_vec())?;
+				T::RouteExecutor::buy(origin, *asset_in, *asset_out, amount_out, amount_in, route.to_vec())?;
+
+				Ok(AmountInAndOut { amount_in, amount_out })
+			}
+		}
+	}
+
+	fn execute_sell(
+		origin: OriginFor<T>,
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		route: &[Trade<T::AssetId>],
+		amount_to_sell: Balance,
+		min_amount_out: Balance,
+	) -> Result<AmountInAndOut<Balance>, DispatchError> {
+		Self::unallocate_amount(schedule_id, schedule, amount_to_sell)?;
+
+		let route_for_slippage = inverse_route(route.to_vec());
+		let (estimated_amount_out, slippage_amount) =
+			Self::calculate_last_block_slippage(&route_for_slippage, amount_to_sell, schedule.slippage)?;
+		let last_block_slippage_min_limit = estimated_amount_out
+			.checked_sub(slippage_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(route, amount_to_sell)?;
+		let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+		let amount_out = last_trade.amount_out;
+
+		if min_amount_out > last_block_slippage_min_limit {
+			ensure!(amount_out >= min_amount_out, Error::<T>::TradeLimitReached);
+		} else {
+			ensure!(
+				amount_out >= last_block_slippage_min_limit,
+				Error::<T>::SlippageLimitReached
+			);
+		};
+
+		T::RouteExecutor::sell(
+			origin,
+			schedule.order.get_asset_in(),
+			schedule.order.get_asset_out(),
+			amount_to_sell,
+			amount_out,
+			route.to_vec(),
+		)?;
+
+		Ok(AmountInAndOut {
+			amount_in: amount_to_sell,
+			amount_out,
+		})
+	}
+
+	/// Returns true if the trade of `amount_in` would leave less than is needed for another execution,
+	/// in which case the whole remaining amount is traded. Always false for the standard termination mode.
+	fn is_final_execution(
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		remaining_amount: Balance,
+		amount_in: Balance,
+	) -> Result<bool, DispatchError> {
+		if schedule.termination == TerminationMode::Standard {
+			return Ok(false);
+		}
+
+		let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+		let min_amount_for_next_execution = transaction_fee
+			.saturating_mul(T::FeeMultiplierForMinTradeLimit::get())
+			.max(T::MinimumTradingLimit::get())
+			.saturating_add(transaction_fee);
 
-				Ok(AmountInAndOut {
-					amount_in,
-					amount_out: *amount_out,
-				})
+		Ok(remaining_amount.saturating_sub(amount_in) < min_amount_for_next_execution)
+	}
+
+	fn get_amount_out_to_buy(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		amount_out: Balance,
+	) -> Balance {
+		match schedule.termination {
+			TerminationMode::TargetAmountOut(target) => {
+				let left_to_target = target.saturating_sub(AccumulatedAmountsOut::<T>::get(schedule_id));
+				min(amount_out, left_to_target)
 			}
+			_ => amount_out,
 		}
 	}
 
@@ -795,17 +1229,36 @@ impl<T: Config> Pallet<T> {
 
 		RetriesOnError::<T>::remove(schedule_id);
 
+		Self::try_transfer_to_remote(
+			schedule_id,
+			schedule,
+			schedule.order.get_asset_out(),
+			amounts.amount_out,
+		);
+
+		if let TerminationMode::TargetAmountOut(target) = schedule.termination {
+			let accumulated_amount_out = AccumulatedAmountsOut::<T>::mutate(schedule_id, |accumulated| {
+				*accumulated = accumulated.saturating_add(amounts.amount_out);
+				*accumulated
+			});
+			if accumulated_amount_out >= target {
+				Self::complete_schedule(schedule_id, schedule);
+				return Ok(());
+			}
+		}
+
 		let remaining_amount: Balance =
 			RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
 		let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
-		let min_amount_for_replanning = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
+		let min_amount_for_replanning = transaction_fee.saturating_mul(T::FeeMultiplierForMinTradeLimit::get());
 		if remaining_amount < min_amount_for_replanning || remaining_amount < T::MinimumTradingLimit::get() {
 			Self::complete_schedule(schedule_id, schedule);
 			return Ok(());
 		}
 
-		//In buy we complete with returning leftover, in sell we sell the leftover in the next trade
-		if let Order::Buy { amount_out, .. } = &schedule.order {
+		//In standard buy we complete with returning leftover, in sell we sell the leftover in the next trade.
+		//In other termination modes the leftover is sold in the final execution.
+		if let (Order::Buy { amount_out, .. }, TerminationMode::Standard) = (&schedule.order, schedule.termination) {
 			let route = schedule.order.get_route_or_default::<T::RouteProvider>();
 			let amount_to_unreserve: Balance = Self::get_amount_in_for_buy(amount_out, &route)?;
 
@@ -819,13 +1272,24 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
+		Self::plan_next_execution(schedule_id, schedule, current_blocknumber, randomness_generator)
+	}
+
+	fn plan_next_execution(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		current_blocknumber: BlockNumberFor<T>,
+		randomness_generator: &mut StdRng,
+	) -> DispatchResult {
+		if let Some(time_period) = schedule.time_period {
+			return Self::plan_schedule_for_time(&schedule.owner, time_period, schedule_id);
+		}
+
 		let next_execution_block = current_blocknumber
 			.checked_add(&schedule.period)
 			.ok_or(ArithmeticError::Overflow)?;
 
-		Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)?;
-
-		Ok(())
+		Self::plan_schedule_for_block(schedule, next_execution_block, schedule_id, randomness_generator)
 	}
 
 	fn retry_schedule(
@@ -854,7 +1318,7 @@ impl<T: Config> Pallet<T> {
 			.checked_add(&retry_delay.into())
 			.ok_or(ArithmeticError::Overflow)?;
 
-		Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)?;
+		Self::plan_schedule_for_block(schedule, next_execution_block, schedule_id, randomness_generator)?;
 
 		Ok(())
 	}
@@ -984,12 +1448,49 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Pays the bounty for executing the schedule to the keeper from the schedule budget.
+	///
+	/// The full bounty is paid in the planned execution block, then it decreases linearly with the elapsed blocks.
+	#[transactional]
+	fn pay_keeper_bounty(
+		keeper: &T::AccountId,
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		elapsed_blocks: u32,
+	) -> DispatchResult {
+		let asset_in = schedule.order.get_asset_in();
+		let max_bounty = Self::convert_native_amount_to_currency(asset_in, T::KeeperBountyInNativeCurrency::get())?;
+
+		let window_length = T::KeeperExecutionWindow::get().saturating_add(1);
+		let bounty = multiply_by_rational_with_rounding(
+			max_bounty,
+			window_length.saturating_sub(elapsed_blocks).into(),
+			window_length.into(),
+			Rounding::Down,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Self::unallocate_amount(schedule_id, schedule, bounty)?;
+
+		T::Currencies::transfer(asset_in, &schedule.owner, keeper, bounty)?;
+
+		Self::deposit_event(Event::KeeperBountyPaid {
+			id: schedule_id,
+			keeper: keeper.clone(),
+			asset_id: asset_in,
+			amount: bounty,
+		});
+
+		Ok(())
+	}
+
 	fn terminate_schedule(
 		schedule_id: ScheduleId,
 		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
 		error: DispatchError,
 	) {
 		Self::try_unreserve_all(schedule_id, schedule);
+		Self::try_refund_to_remote(schedule_id, schedule);
 
 		Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
@@ -1002,6 +1503,7 @@ impl<T: Config> Pallet<T> {
 
 	fn complete_schedule(schedule_id: ScheduleId, schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) {
 		Self::try_unreserve_all(schedule_id, schedule);
+		Self::try_refund_to_remote(schedule_id, schedule);
 
 		Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
@@ -1027,6 +1529,48 @@ impl<T: Config> Pallet<T> {
 		);
 	}
 
+	/// Transfers the leftover of the budget of a remote schedule to its destination.
+	fn try_refund_to_remote(schedule_id: ScheduleId, schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) {
+		let Some(remaining_amount) = RemainingAmounts::<T>::get(schedule_id) else {
+			return;
+		};
+
+		Self::try_transfer_to_remote(schedule_id, schedule, schedule.order.get_asset_in(), remaining_amount);
+	}
+
+	/// Transfers assets of a remote schedule to its destination. Does nothing if the schedule is not remote.
+	///
+	/// The transfer is executed in its own storage layer, so if it fails, the assets are kept by the owner.
+	fn try_transfer_to_remote(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		asset_id: T::AssetId,
+		amount: Balance,
+	) {
+		if amount.is_zero() {
+			return;
+		}
+		let Some(destination) = RemoteDestinations::<T>::get(schedule_id) else {
+			return;
+		};
+
+		match with_storage_layer(|| T::RemoteTransfer::transfer(&schedule.owner, asset_id, amount, &destination)) {
+			Ok(()) => Self::deposit_event(Event::RemoteTransferred {
+				id: schedule_id,
+				who: schedule.owner.clone(),
+				asset_id,
+				amount,
+			}),
+			Err(error) => Self::deposit_event(Event::RemoteTransferFailed {
+				id: schedule_id,
+				who: schedule.owner.clone(),
+				asset_id,
+				amount,
+				error,
+			}),
+		}
+	}
+
 	fn weight_to_fee(weight: Weight) -> Balance {
 		// cap the weight to the maximum defined in runtime, otherwise it will be the
 		// `Bounded` maximum of its data type, which is not desired.
@@ -1035,7 +1579,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn plan_schedule_for_block(
-		who: &T::AccountId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
 		blocknumber: BlockNumberFor<T>,
 		schedule_id: ScheduleId,
 		randomness_generator: &mut StdRng,
@@ -1043,18 +1587,29 @@ impl<T: Config> Pallet<T> {
 		let current_block_number = frame_system::Pallet::<T>::current_block_number();
 		ensure!(blocknumber > current_block_number, Error::<T>::BlockNumberIsNotInFuture);
 
-		let next_free_block = Self::find_next_free_block(blocknumber, randomness_generator)?;
+		let next_free_block = match schedule.execution {
+			ExecutionMode::Automatic => {
+				let next_free_block = Self::find_next_free_block(blocknumber, randomness_generator)?;
 
-		ScheduleIdsPerBlock::<T>::try_mutate(next_free_block, |schedule_ids| -> DispatchResult {
-			schedule_ids
-				.try_push(schedule_id)
-				.map_err(|_| Error::<T>::InvalidState)?;
-			Ok(())
-		})?;
+				ScheduleIdsPerBlock::<T>::try_mutate(next_free_block, |schedule_ids| -> DispatchResult {
+					schedule_ids
+						.try_push(schedule_id)
+						.map_err(|_| Error::<T>::InvalidState)?;
+					Ok(())
+				})?;
+
+				next_free_block
+			}
+			//Keeper executed schedules are not executed in block initialization, so blocks are not limited for them
+			ExecutionMode::Keeper => {
+				KeeperExecutionBlocks::<T>::insert(schedule_id, blocknumber);
+				blocknumber
+			}
+		};
 
 		Self::deposit_event(Event::ExecutionPlanned {
 			id: schedule_id,
-			who: who.clone(),
+			who: schedule.owner.clone(),
 			block: next_free_block,
 		});
 		Ok(())
@@ -1082,6 +1637,109 @@ impl<T: Config> Pallet<T> {
 		Err(Error::<T>::NoFreeBlockFound.into())
 	}
 
+	/// Plans the next execution of time based schedule in the time bucket of the next execution time.
+	///
+	/// The next execution time is calculated from the previously planned time, so the schedule does not drift
+	/// by the delays of executions. If the next execution time has already passed, the missed periods are skipped.
+	fn plan_schedule_for_time(who: &T::AccountId, time_period: Moment, schedule_id: ScheduleId) -> DispatchResult {
+		let now = T::TimestampProvider::now();
+		let planned_time = PlannedExecutionTimes::<T>::get(schedule_id).map_or(now, |(time, _)| time);
+
+		let mut next_execution_time = planned_time.checked_add(time_period).ok_or(ArithmeticError::Overflow)?;
+		if next_execution_time <= now {
+			let missed_periods = now
+				.saturating_sub(next_execution_time)
+				.checked_div(time_period)
+				.ok_or(ArithmeticError::DivisionByZero)?
+				.saturating_add(1);
+			let missed_time = missed_periods
+				.checked_mul(time_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			next_execution_time = next_execution_time
+				.checked_add(missed_time)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		//Buckets which have already been processed would never be reached again
+		let first_unprocessed_time_bucket = LastProcessedTimeBucket::<T>::get()
+			.unwrap_or_else(|| Self::time_bucket(now))
+			.saturating_add(1);
+		let time_bucket = Self::time_bucket(next_execution_time).max(first_unprocessed_time_bucket);
+		let next_free_time_bucket = Self::find_next_free_time_bucket(time_bucket)?;
+
+		ScheduleIdsPerTimeBucket::<T>::try_mutate(next_free_time_bucket, |schedule_ids| -> DispatchResult {
+			schedule_ids
+				.try_push(schedule_id)
+				.map_err(|_| Error::<T>::InvalidState)?;
+			Ok(())
+		})?;
+		PlannedExecutionTimes::<T>::insert(schedule_id, (next_execution_time, next_free_time_bucket));
+
+		Self::deposit_event(Event::ExecutionTimePlanned {
+			id: schedule_id,
+			who: who.clone(),
+			time: next_execution_time,
+		});
+		Ok(())
+	}
+
+	fn find_next_free_time_bucket(time_bucket: u64) -> Result<u64, DispatchError> {
+		let max_time_bucket = time_bucket.saturating_add(MAX_NUMBER_OF_RETRY_FOR_RESCHEDULING.into());
+		(time_bucket..=max_time_bucket)
+			.find(|bucket| {
+				ScheduleIdsPerTimeBucket::<T>::decode_len(bucket).unwrap_or_default()
+					< T::MaxSchedulePerBlock::get() as usize
+			})
+			.ok_or_else(|| Error::<T>::NoFreeTimeBucketFound.into())
+	}
+
+	fn time_bucket(time: Moment) -> u64 {
+		time.checked_div(T::TimeBucketSize::get()).unwrap_or(time)
+	}
+
+	/// Plans schedules of the time buckets reached by the current timestamp for execution in the next block.
+	///
+	/// At most `MAX_NUMBER_OF_TIME_BUCKETS_PER_BLOCK` buckets are processed in a block,
+	/// the rest of them is processed in the following blocks.
+	fn plan_due_time_buckets(current_blocknumber: BlockNumberFor<T>, randomness_generator: &mut StdRng) -> Weight {
+		let mut weight = Weight::zero();
+
+		let current_time_bucket = Self::time_bucket(T::TimestampProvider::now());
+		let Some(last_processed_time_bucket) = LastProcessedTimeBucket::<T>::get() else {
+			LastProcessedTimeBucket::<T>::put(current_time_bucket);
+			return weight;
+		};
+
+		let last_time_bucket_to_process =
+			current_time_bucket.min(last_processed_time_bucket.saturating_add(MAX_NUMBER_OF_TIME_BUCKETS_PER_BLOCK));
+		if last_time_bucket_to_process <= last_processed_time_bucket {
+			return weight;
+		}
+
+		let next_block = current_blocknumber.saturating_add(1u32.into());
+		for time_bucket in last_processed_time_bucket.saturating_add(1)..=last_time_bucket_to_process {
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+			for schedule_id in ScheduleIdsPerTimeBucket::<T>::take(time_bucket) {
+				// schedule read and planning in the block
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+
+				let Some(schedule) = Schedules::<T>::get(schedule_id) else {
+					continue;
+				};
+
+				if let Err(e) = Self::plan_schedule_for_block(&schedule, next_block, schedule_id, randomness_generator)
+				{
+					Self::terminate_schedule(schedule_id, &schedule, e);
+				}
+			}
+		}
+
+		LastProcessedTimeBucket::<T>::put(last_time_bucket_to_process);
+
+		weight
+	}
+
 	fn calculate_last_block_slippage(
 		route: &[Trade<T::AssetId>],
 		amount: Balance,
@@ -1091,12 +1749,51 @@ impl<T: Config> Pallet<T> {
 
 		let estimated_amount = price.checked_mul_int(amount).ok_or(ArithmeticError::Overflow)?;
 
-		let slippage_limit = slippage.unwrap_or_else(T::MaxPriceDifferenceBetweenBlocks::get);
+		let slippage_limit = match slippage {
+			Some(slippage) => slippage,
+			None => Self::default_slippage_tolerance(route)?,
+		};
 		let slippage_amount = slippage_limit.mul_floor(estimated_amount);
 
 		Ok((estimated_amount, slippage_amount))
 	}
 
+	/// Default slippage tolerance of a route, used when the schedule does not specify one.
+	///
+	/// The oracle volatility multiplied by `SlippageVolatilityMultiplier`,
+	/// bounded by `MaxPriceDifferenceBetweenBlocks` and `MaxConfigurablePriceDifferenceBetweenBlocks`.
+	pub fn default_slippage_tolerance(route: &[Trade<T::AssetId>]) -> Result<Permill, DispatchError> {
+		let volatility = Self::oracle_volatility(route)?;
+		let tolerance = Permill::from_parts(
+			volatility
+				.deconstruct()
+				.saturating_mul(T::SlippageVolatilityMultiplier::get()),
+		);
+
+		let min_tolerance = T::MaxPriceDifferenceBetweenBlocks::get();
+		let max_tolerance = T::MaxConfigurablePriceDifferenceBetweenBlocks::get().max(min_tolerance);
+
+		Ok(tolerance.clamp(min_tolerance, max_tolerance))
+	}
+
+	/// Relative difference between the last block and the short oracle price of a route.
+	pub fn oracle_volatility(route: &[Trade<T::AssetId>]) -> Result<Permill, DispatchError> {
+		let last_block_price = Self::get_price_from_last_block_oracle(route)?;
+		let price_from_short_oracle = Self::get_price_from_short_oracle(route)?;
+		ensure!(!price_from_short_oracle.is_zero(), Error::<T>::CalculatingPriceError);
+
+		let diff = if last_block_price > price_from_short_oracle {
+			last_block_price.saturating_sub(price_from_short_oracle)
+		} else {
+			price_from_short_oracle.saturating_sub(last_block_price)
+		};
+
+		Ok(Permill::from_rational(
+			diff.into_inner(),
+			price_from_short_oracle.into_inner(),
+		))
+	}
+
 	fn convert_weight_to_fee(weight: Weight, fee_currency: T::AssetId) -> Result<Balance, DispatchError> {
 		let fee_amount_in_native = Self::weight_to_fee(weight);
 		let fee_amount_in_sold_asset = Self::convert_native_amount_to_currency(fee_currency, fee_amount_in_native)?;
@@ -1104,6 +1801,24 @@ impl<T: Config> Pallet<T> {
 		Ok(fee_amount_in_sold_asset)
 	}
 
+	/// Weight of the keeper execution of the schedule, which is the same as the weight of its execution in a block.
+	pub fn keeper_execution_weight(schedule_id: ScheduleId) -> Weight {
+		Schedules::<T>::get(schedule_id)
+			.map(|schedule| Self::get_execution_weight(schedule_id, &schedule.order))
+			.unwrap_or_default()
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+
+	// returns trade weight + weight of the transfers of the proceeds and the leftover to the remote destination
+	fn get_execution_weight(schedule_id: ScheduleId, order: &Order<T::AssetId>) -> Weight {
+		let trade_weight = Self::get_trade_weight(order);
+		if RemoteDestinations::<T>::contains_key(schedule_id) {
+			trade_weight.saturating_add(T::RemoteTransfer::transfer_weight().saturating_mul(2))
+		} else {
+			trade_weight
+		}
+	}
+
 	// returns DCA overhead weight + router execution weight
 	fn get_trade_weight(order: &Order<T::AssetId>) -> Weight {
 		let route = &order.get_route_or_default::<T::RouteProvider>();
@@ -1194,6 +1909,29 @@ impl<T: Config> Pallet<T> {
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		AccumulatedAmountsOut::<T>::remove(schedule_id);
+		PlannedExecutionTimes::<T>::remove(schedule_id);
+		KeeperExecutionBlocks::<T>::remove(schedule_id);
+		RemoteDestinations::<T>::remove(schedule_id);
+	}
+}
+
+/// Transfers assets of remote DCA schedules to remote chains.
+pub trait RemoteTransfer<AccountId, AssetId, Location> {
+	/// Transfers `amount` of `asset_id` from `who` to `destination`. Fees of the transfer are paid from the amount.
+	fn transfer(who: &AccountId, asset_id: AssetId, amount: Balance, destination: &Location) -> DispatchResult;
+
+	/// Weight of a single transfer.
+	fn transfer_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Location> RemoteTransfer<AccountId, AssetId, Location> for () {
+	fn transfer(_who: &AccountId, _asset_id: AssetId, _amount: Balance, _destination: &Location) -> DispatchResult {
+		Err(DispatchError::Other("Remote transfers are not supported"))
+	}
+
+	fn transfer_weight() -> Weight {
+		Weight::zero()
 	}
 }
 
@@ -0,0 +1,72 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+pub mod versioned {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+
+	pub type V0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		v1::VersionUncheckedMigrateToV1<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// This migration adds termination mode, time period and execution mode to schedules.
+// Existing schedules keep the standard termination, block based period and automatic execution.
+pub mod v1 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use sp_std::marker::PhantomData;
+
+	#[derive(Decode)]
+	pub struct OldSchedule<AccountId, AssetId, BlockNumber> {
+		pub owner: AccountId,
+		pub period: BlockNumber,
+		pub total_amount: Balance,
+		pub max_retries: Option<u8>,
+		pub stability_threshold: Option<Permill>,
+		pub slippage: Option<Permill>,
+		pub order: Order<AssetId>,
+	}
+
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			Schedules::<T>::translate_values::<OldSchedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, _>(|old| {
+				count.saturating_inc();
+				Some(Schedule {
+					owner: old.owner,
+					period: old.period,
+					total_amount: old.total_amount,
+					max_retries: old.max_retries,
+					stability_threshold: old.stability_threshold,
+					slippage: old.slippage,
+					order: old.order,
+					termination: TerminationMode::Standard,
+					time_period: None,
+					execution: ExecutionMode::Automatic,
+				})
+			});
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
@@ -0,0 +1,225 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_number_of_executed_buy_trades, assert_that_schedule_has_been_removed_from_storages, Error,
+	Event as DcaEvent, ScheduleIdsPerBlock,
+};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use std::borrow::Borrow;
+
+fn schedule_keeper_executed_dca() {
+	proceed_to_blocknumber(1, 500);
+
+	let schedule = ScheduleBuilder::new().with_keeper_execution().build();
+	assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+}
+
+#[test]
+fn keeper_executed_schedule_should_not_be_executed_in_on_initialize() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Act
+			schedule_keeper_executed_dca();
+			proceed_to_blocknumber(501, 510);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(0);
+			assert_eq!(DCA::keeper_execution_blocks(0), Some(502));
+			assert!(ScheduleIdsPerBlock::<Test>::get(502).is_empty());
+		});
+}
+
+#[test]
+fn execute_schedule_should_execute_trade_and_pay_full_bounty_when_executed_in_planned_block() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_keeper_executed_dca();
+			set_to_blocknumber(502);
+
+			//Act
+			assert_ok!(DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0));
+
+			//Assert
+			assert_number_of_executed_buy_trades!(1);
+			assert_balance!(BOB, HDX, KeeperBountyInNativeCurrency::get());
+			assert_eq!(DCA::retries_on_error(0), 0);
+			assert_eq!(DCA::keeper_execution_blocks(0), Some(502 + ONE_HUNDRED_BLOCKS));
+			System::assert_has_event(
+				DcaEvent::KeeperBountyPaid {
+					id: 0,
+					keeper: BOB,
+					asset_id: HDX,
+					amount: KeeperBountyInNativeCurrency::get(),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn execute_schedule_should_not_charge_transaction_fee() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_keeper_executed_dca();
+			set_to_blocknumber(502);
+			let treasury_balance = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+			//Act
+			assert_ok!(DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0));
+
+			//Assert
+			assert_balance!(TreasuryAccount::get(), HDX, treasury_balance);
+		});
+}
+
+#[test]
+fn execute_schedule_should_pay_decreased_bounty_when_executed_later_in_window() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_keeper_executed_dca();
+			set_to_blocknumber(504);
+
+			//Act
+			assert_ok!(DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0));
+
+			//Assert
+			let window_length = KeeperExecutionWindow::get() as Balance + 1;
+			let expected_bounty = KeeperBountyInNativeCurrency::get() * (window_length - 2) / window_length;
+			assert_number_of_executed_buy_trades!(1);
+			assert_balance!(BOB, HDX, expected_bounty);
+			assert_eq!(DCA::keeper_execution_blocks(0), Some(504 + ONE_HUNDRED_BLOCKS));
+		});
+}
+
+#[test]
+fn execute_schedule_should_skip_execution_when_window_is_missed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_keeper_executed_dca();
+			let block_after_window = 502 + KeeperExecutionWindow::get() as u64 + 1;
+			set_to_blocknumber(block_after_window);
+
+			//Act
+			assert_ok!(DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0));
+
+			//Assert
+			assert_number_of_executed_buy_trades!(0);
+			assert_balance!(BOB, HDX, 0);
+			assert_eq!(DCA::remaining_amounts(0), Some(1000 * ONE));
+			assert_eq!(
+				DCA::keeper_execution_blocks(0),
+				Some(block_after_window + ONE_HUNDRED_BLOCKS)
+			);
+			expect_events(vec![
+				DcaEvent::ExecutionWindowMissed { id: 0, who: ALICE }.into(),
+				DcaEvent::ExecutionPlanned {
+					id: 0,
+					who: ALICE,
+					block: block_after_window + ONE_HUNDRED_BLOCKS,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn execute_schedule_should_fail_when_planned_block_is_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_keeper_executed_dca();
+			set_to_blocknumber(501);
+
+			//Act and assert
+			assert_noop!(
+				DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0),
+				Error::<Test>::ExecutionNotDue
+			);
+		});
+}
+
+#[test]
+fn execute_schedule_should_fail_when_schedule_is_executed_automatically() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act and assert
+			assert_noop!(
+				DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0),
+				Error::<Test>::NotKeeperExecuted
+			);
+		});
+}
+
+#[test]
+fn execute_schedule_should_fail_when_schedule_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				DCA::execute_schedule(RuntimeOrigin::signed(BOB), 0),
+				Error::<Test>::ScheduleNotFound
+			);
+		});
+}
+
+#[test]
+fn terminate_should_remove_keeper_executed_schedule() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_keeper_executed_dca();
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), 0, None));
+
+			//Assert
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, 0);
+			assert_balance!(ALICE, HDX, 10000 * ONE);
+		});
+}
@@ -16,9 +16,9 @@
 // limitations under the License.
 
 use crate as dca;
-use crate::{Config, Error, RandomnessProvider, RelayChainBlockHashProvider};
+use crate::{Config, Error, RandomnessProvider, RelayChainBlockHashProvider, RemoteTransfer};
 use cumulus_primitives_core::relay_chain::Hash;
-use frame_support::traits::{Everything, Nothing};
+use frame_support::traits::{Everything, Nothing, Time};
 use frame_support::weights::constants::ExtrinsicBaseWeight;
 use frame_support::weights::WeightToFeeCoefficient;
 use frame_support::weights::{IdentityFee, Weight};
@@ -29,9 +29,10 @@ use frame_support::{assert_ok, parameter_types};
 use frame_system as system;
 use frame_system::{ensure_signed, EnsureRoot};
 use hydradx_traits::{registry::Inspect as InspectRegistry, AssetKind, NativePriceOracle, OraclePeriod, PriceOracle};
-use orml_traits::{parameter_type_with_key, GetByKey};
+use orml_traits::{parameter_type_with_key, GetByKey, MultiCurrency};
 use pallet_currencies::{BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
 use primitive_types::U128;
+use primitives::Moment;
 use sp_core::H256;
 use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, ConstU32};
 use sp_runtime::Permill;
@@ -62,6 +63,8 @@ pub const FORBIDDEN_ASSET: AssetId = 4;
 pub const DOT: AssetId = 5;
 pub const REGISTERED_ASSET: AssetId = 1000;
 pub const ONE_HUNDRED_BLOCKS: BlockNumber = 100;
+pub const ONE_HOUR: Moment = 3_600_000;
+pub const ONE_MINUTE: Moment = 60_000;
 
 //Since we always use the same parent hash in the tests, the generated radiuses are always the same
 pub const GENERATED_SEARCH_RADIUSES: [u64; 10] = [1, 3, 6, 10, 28, 34, 114, 207, 504, 947];
@@ -104,9 +107,13 @@ thread_local! {
 	pub static SELL_EXECUTIONS: RefCell<Vec<SellExecution>> = const { RefCell::new(vec![]) };
 	pub static SET_OMNIPOOL_ON: RefCell<bool> = const { RefCell::new(true) };
 	pub static MAX_PRICE_DIFFERENCE: RefCell<Permill> = RefCell::new(*ORIGINAL_MAX_PRICE_DIFFERENCE);
+	pub static SLIPPAGE_VOLATILITY_MULTIPLIER: RefCell<u32> = const { RefCell::new(0) };
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = const { RefCell::new((0u32,0u32, false)) };
 	pub static CALCULATED_AMOUNT_OUT_FOR_SELL: RefCell<Balance> = RefCell::new(*AMOUNT_OUT_FOR_OMNIPOOL_SELL);
 	pub static USE_PROD_RANDOMNESS: RefCell<bool> = const { RefCell::new(false) };
+	pub static TIMESTAMP: RefCell<Moment> = const { RefCell::new(0) };
+	pub static REMOTE_TRANSFERS: RefCell<Vec<RemoteTransferExecution>> = const { RefCell::new(vec![]) };
+	pub static REMOTE_TRANSFER_FAILS: RefCell<bool> = const { RefCell::new(false) };
 	pub static PARENT_HASH: RefCell<Option<Hash>> = RefCell::new(Some([
 			14, 87, 81, 192, 38, 229, 67, 178, 232, 171, 46, 176, 96, 153, 218, 161, 209, 229, 223, 71, 119, 143, 119,
 			135, 250, 171, 69, 205, 241, 47, 227, 168,
@@ -115,6 +122,14 @@ thread_local! {
 
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RemoteTransferExecution {
+	pub who: AccountId,
+	pub asset_id: AssetId,
+	pub amount: Balance,
+	pub destination: RemoteLocation,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct BuyExecution {
 	pub asset_in: AssetId,
@@ -147,11 +162,28 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type SpotPriceProvider = NoSpotPrice;
+	type Currency = Balances;
+	type RoutedOracleDeposit = RoutedOracleDeposit;
+	type MaxRoutedOracles = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const RoutedOracleDeposit: Balance = 1_000;
+}
+
+pub struct NoSpotPrice;
+impl hydradx_traits::price::PriceProvider<AssetId> for NoSpotPrice {
+	type Price = pallet_ema_oracle::Price;
+
+	fn get_price(_asset_a: AssetId, _asset_b: AssetId) -> Option<Self::Price> {
+		None
+	}
+}
+
 impl BlockNumberProvider for MockBlockNumberProvider {
 	type BlockNumber = BlockNumber;
 
@@ -260,10 +292,23 @@ impl pallet_omnipool::Config for Test {
 	type CollectionId = u32;
 	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type OmnipoolHooks = ();
+	type OnTradeExecuted = ();
 	type PriceBarrier = ();
 	type MinWithdrawalFee = ();
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = ();
+	type WithdrawalStreamBlocks = ();
+	type MaxQueuedWithdrawals = ();
+	type MaxQueuedWithdrawalsPerAccount = ();
+	type MaxHubAssetBuyPerBlock = ();
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
 }
 
 pub struct WithdrawFeePriceOracle;
@@ -652,7 +697,13 @@ parameter_types! {
 	pub MaxSchedulePerBlock: u32 = 20;
 	pub OmnipoolMaxAllowedPriceDifference: Permill = MAX_PRICE_DIFFERENCE.with(|v| *v.borrow());
 	pub MaxConfigurablePriceDifference: Permill = Permill::from_percent(20);
+	pub SlippageVolatilityMultiplier: u32 = SLIPPAGE_VOLATILITY_MULTIPLIER.with(|v| *v.borrow());
 	pub MinimalPeriod: u32 = 5;
+	pub MinimalTimePeriod: Moment = ONE_HOUR;
+	pub TimeBucketSize: Moment = ONE_MINUTE;
+	pub KeeperExecutionWindow: u32 = 4;
+	pub KeeperBountyInNativeCurrency: Balance = ONE;
+	pub FeeMultiplierForMinTradeLimit: Balance = 20;
 	pub BumpChance: Percent = Percent::from_percent(0);
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
@@ -673,6 +724,63 @@ impl RandomnessProvider for RandomnessProviderMock {
 	}
 }
 
+pub struct TimestampProviderMock;
+
+impl Time for TimestampProviderMock {
+	type Moment = Moment;
+
+	fn now() -> Self::Moment {
+		TIMESTAMP.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_timestamp(time: Moment) {
+	TIMESTAMP.with(|v| {
+		*v.borrow_mut() = time;
+	});
+}
+
+pub type RemoteLocation = u32;
+
+pub const REMOTE_TRANSFER_WEIGHT: u64 = 1_000_000;
+
+pub struct RemoteTransferMock;
+
+impl RemoteTransfer<AccountId, AssetId, RemoteLocation> for RemoteTransferMock {
+	fn transfer(who: &AccountId, asset_id: AssetId, amount: Balance, destination: &RemoteLocation) -> DispatchResult {
+		if REMOTE_TRANSFER_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("Remote transfer failed"));
+		}
+
+		Currencies::withdraw(asset_id, who, amount)?;
+
+		REMOTE_TRANSFERS.with(|v| {
+			v.borrow_mut().push(RemoteTransferExecution {
+				who: *who,
+				asset_id,
+				amount,
+				destination: *destination,
+			});
+		});
+
+		Ok(())
+	}
+
+	fn transfer_weight() -> Weight {
+		Weight::from_parts(REMOTE_TRANSFER_WEIGHT, 0)
+	}
+}
+
+pub fn set_remote_transfer_fails(fails: bool) {
+	REMOTE_TRANSFER_FAILS.with(|v| {
+		*v.borrow_mut() = fails;
+	});
+}
+
+pub fn remote_transfers() -> Vec<RemoteTransferExecution> {
+	REMOTE_TRANSFERS.with(|v| v.borrow().clone())
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -689,7 +797,17 @@ impl Config for Test {
 	type RouteProvider = DefaultRouteProvider;
 	type MaxPriceDifferenceBetweenBlocks = OmnipoolMaxAllowedPriceDifference;
 	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
+	type SlippageVolatilityMultiplier = SlippageVolatilityMultiplier;
 	type MinimalPeriod = MinimalPeriod;
+	type TimestampProvider = TimestampProviderMock;
+	type MinimalTimePeriod = MinimalTimePeriod;
+	type TimeBucketSize = TimeBucketSize;
+	type KeeperExecutionWindow = KeeperExecutionWindow;
+	type KeeperBountyInNativeCurrency = KeeperBountyInNativeCurrency;
+	type FeeMultiplierForMinTradeLimit = FeeMultiplierForMinTradeLimit;
+	type RemoteLocation = RemoteLocation;
+	type RemoteTransfer = RemoteTransferMock;
+	type HookWeightRecorder = ();
 	type BumpChance = BumpChance;
 	type NamedReserveId = NamedReserveId;
 	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
@@ -884,6 +1002,7 @@ pub struct ExtBuilder {
 	init_pool: Option<(FixedU128, FixedU128)>,
 	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
 	max_price_difference: Permill,
+	slippage_volatility_multiplier: u32,
 	min_trading_limit: Balance,
 }
 
@@ -898,6 +1017,12 @@ impl Default for ExtBuilder {
 		POSITIONS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		REMOTE_TRANSFERS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		REMOTE_TRANSFER_FAILS.with(|v| {
+			*v.borrow_mut() = false;
+		});
 
 		Self {
 			endowed_accounts: vec![(Omnipool::protocol_account(), DAI, 1000 * ONE)],
@@ -907,6 +1032,7 @@ impl Default for ExtBuilder {
 			register_stable_asset: true,
 			pool_tokens: vec![],
 			max_price_difference: Permill::from_percent(10),
+			slippage_volatility_multiplier: 0,
 			min_trading_limit: 1000,
 		}
 	}
@@ -923,6 +1049,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_slippage_volatility_multiplier(mut self, multiplier: u32) -> Self {
+		self.slippage_volatility_multiplier = multiplier;
+		self
+	}
+
 	pub fn with_min_trading_limit(mut self, limit: Balance) -> Self {
 		self.min_trading_limit = limit;
 		self
@@ -942,9 +1073,15 @@ impl ExtBuilder {
 			});
 		});
 
+		TIMESTAMP.with(|v| {
+			*v.borrow_mut() = 0;
+		});
 		MAX_PRICE_DIFFERENCE.with(|v| {
 			*v.borrow_mut() = self.max_price_difference;
 		});
+		SLIPPAGE_VOLATILITY_MULTIPLIER.with(|v| {
+			*v.borrow_mut() = self.slippage_volatility_multiplier;
+		});
 
 		MIN_TRADE_AMOUNT.with(|v| {
 			*v.borrow_mut() = self.min_trading_limit;
@@ -1,14 +1,20 @@
 use crate::tests::mock::*;
-use crate::{Balance, Order, Schedule, ScheduleId};
+use crate::{Balance, ExecutionMode, Order, Schedule, ScheduleId, TerminationMode};
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
+use primitives::Moment;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{BoundedVec, Permill};
 
+pub mod keeper;
 pub mod mock;
 pub mod on_initialize;
+pub mod remote;
 pub mod schedule;
+pub mod slippage;
 pub mod terminate;
+pub mod termination;
+pub mod time_period;
 
 #[macro_export]
 macro_rules! assert_balance {
@@ -25,6 +31,9 @@ struct ScheduleBuilder {
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
 	pub stability_threshold: Option<Option<Permill>>,
+	pub termination: Option<TerminationMode>,
+	pub time_period: Option<Option<Moment>>,
+	pub execution: Option<ExecutionMode>,
 }
 
 impl ScheduleBuilder {
@@ -36,6 +45,9 @@ impl ScheduleBuilder {
 			slippage: Some(None),
 			total_amount: Some(1000 * ONE),
 			max_retries: Some(None),
+			termination: Some(TerminationMode::Standard),
+			time_period: Some(None),
+			execution: Some(ExecutionMode::Automatic),
 			order: Some(Order::Buy {
 				asset_in: HDX,
 				asset_out: BTC,
@@ -85,6 +97,21 @@ impl ScheduleBuilder {
 		self
 	}
 
+	fn with_termination(mut self, termination: TerminationMode) -> ScheduleBuilder {
+		self.termination = Some(termination);
+		self
+	}
+
+	fn with_time_period(mut self, time_period: Moment) -> ScheduleBuilder {
+		self.time_period = Some(Some(time_period));
+		self
+	}
+
+	fn with_keeper_execution(mut self) -> ScheduleBuilder {
+		self.execution = Some(ExecutionMode::Keeper);
+		self
+	}
+
 	fn build(self) -> Schedule<AccountId, AssetId, BlockNumber> {
 		Schedule {
 			owner: self.owner.unwrap(),
@@ -94,6 +121,9 @@ impl ScheduleBuilder {
 			total_amount: self.total_amount.unwrap(),
 			max_retries: self.max_retries.unwrap(),
 			order: self.order.unwrap(),
+			termination: self.termination.unwrap(),
+			time_period: self.time_period.unwrap(),
+			execution: self.execution.unwrap(),
 		}
 	}
 }
@@ -124,5 +154,9 @@ macro_rules! assert_that_schedule_has_been_removed_from_storages {
 		assert!(DCA::owner_of($owner, $schedule_id).is_none());
 		assert!(DCA::remaining_amounts($schedule_id).is_none());
 		assert_eq!(DCA::retries_on_error($schedule_id), 0);
+		assert_eq!(DCA::accumulated_amounts_out($schedule_id), 0);
+		assert!(DCA::planned_execution_times($schedule_id).is_none());
+		assert!(DCA::keeper_execution_blocks($schedule_id).is_none());
+		assert!(DCA::remote_destinations($schedule_id).is_none());
 	};
 }
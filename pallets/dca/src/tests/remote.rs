@@ -0,0 +1,265 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::schedule::get_fee_for_sell_in_hdx;
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_scheduled_ids, assert_that_schedule_has_been_removed_from_storages, Event as DcaEvent,
+};
+use frame_support::assert_ok;
+use hydradx_traits::router::PoolType;
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError;
+
+const DESTINATION: RemoteLocation = 2_000;
+const TOTAL_AMOUNT: Balance = 5 * ONE;
+const AMOUNT_TO_SELL: Balance = ONE;
+
+fn schedule_remote_sell_dca() {
+	proceed_to_blocknumber(1, 500);
+
+	let schedule = ScheduleBuilder::new()
+		.with_total_amount(TOTAL_AMOUNT)
+		.with_order(Order::Sell {
+			asset_in: HDX,
+			asset_out: BTC,
+			amount_in: AMOUNT_TO_SELL,
+			min_amount_out: Balance::MIN,
+			route: create_bounded_vec(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: BTC,
+			}]),
+		})
+		.build();
+
+	assert_ok!(DCA::schedule_remote(
+		RuntimeOrigin::signed(ALICE),
+		schedule,
+		Option::None,
+		Box::new(DESTINATION)
+	));
+}
+
+#[test]
+fn schedule_remote_should_store_destination() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Act
+			schedule_remote_sell_dca();
+
+			//Assert
+			assert!(DCA::schedules(0).is_some());
+			assert_eq!(DCA::remote_destinations(0), Some(DESTINATION));
+			assert_eq!(
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE),
+				TOTAL_AMOUNT
+			);
+			System::assert_last_event(
+				DcaEvent::RemoteDestinationSet {
+					id: 0,
+					who: ALICE,
+					destination: DESTINATION,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn remote_schedule_should_transfer_amount_out_to_destination_when_trade_is_executed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_remote_sell_dca();
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let amount_out = *AMOUNT_OUT_FOR_OMNIPOOL_SELL;
+			assert_balance!(ALICE, BTC, 0);
+			assert_eq!(
+				remote_transfers(),
+				vec![RemoteTransferExecution {
+					who: ALICE,
+					asset_id: BTC,
+					amount: amount_out,
+					destination: DESTINATION,
+				}]
+			);
+			System::assert_has_event(
+				DcaEvent::RemoteTransferred {
+					id: 0,
+					who: ALICE,
+					asset_id: BTC,
+					amount: amount_out,
+				}
+				.into(),
+			);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
+
+#[test]
+fn remote_schedule_should_keep_amount_out_when_transfer_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_remote_sell_dca();
+			set_remote_transfer_fails(true);
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let amount_out = *AMOUNT_OUT_FOR_OMNIPOOL_SELL;
+			assert_balance!(ALICE, BTC, amount_out);
+			assert!(remote_transfers().is_empty());
+			System::assert_has_event(
+				DcaEvent::RemoteTransferFailed {
+					id: 0,
+					who: ALICE,
+					asset_id: BTC,
+					amount: amount_out,
+					error: DispatchError::Other("Remote transfer failed"),
+				}
+				.into(),
+			);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
+
+#[test]
+fn remote_schedule_should_charge_weight_of_transfers_in_transaction_fee() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_remote_sell_dca();
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let fee = get_fee_for_sell_in_hdx() + 2 * REMOTE_TRANSFER_WEIGHT as Balance;
+			assert_balance!(TreasuryAccount::get(), HDX, fee);
+			assert_eq!(DCA::remaining_amounts(0), Some(TOTAL_AMOUNT - AMOUNT_TO_SELL - fee));
+		});
+}
+
+#[test]
+fn terminate_should_transfer_leftover_budget_to_destination_when_schedule_is_remote() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_remote_sell_dca();
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), 0, Some(502)));
+
+			//Assert
+			assert_balance!(ALICE, HDX, 10000 * ONE - TOTAL_AMOUNT);
+			assert_eq!(
+				remote_transfers(),
+				vec![RemoteTransferExecution {
+					who: ALICE,
+					asset_id: HDX,
+					amount: TOTAL_AMOUNT,
+					destination: DESTINATION,
+				}]
+			);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, 0);
+		});
+}
+
+#[test]
+fn completed_remote_schedule_should_transfer_leftover_budget_to_destination() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_remote_sell_dca();
+			MIN_TRADE_AMOUNT.with(|v| {
+				*v.borrow_mut() = TOTAL_AMOUNT;
+			});
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let fee = get_fee_for_sell_in_hdx() + 2 * REMOTE_TRANSFER_WEIGHT as Balance;
+			let leftover = TOTAL_AMOUNT - AMOUNT_TO_SELL - fee;
+			assert_eq!(
+				remote_transfers(),
+				vec![
+					RemoteTransferExecution {
+						who: ALICE,
+						asset_id: BTC,
+						amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+						destination: DESTINATION,
+					},
+					RemoteTransferExecution {
+						who: ALICE,
+						asset_id: HDX,
+						amount: leftover,
+						destination: DESTINATION,
+					}
+				]
+			);
+			assert_balance!(ALICE, HDX, 10000 * ONE - TOTAL_AMOUNT);
+			assert_eq!(
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE),
+				0
+			);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, 0);
+		});
+}
+
+#[test]
+fn schedule_should_not_transfer_amount_out_when_schedule_is_not_remote() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(502);
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), 0, Some(602)));
+
+			//Assert
+			assert!(remote_transfers().is_empty());
+			assert!(DCA::remote_destinations(0).is_none());
+		});
+}
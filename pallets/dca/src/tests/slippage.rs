@@ -0,0 +1,139 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{assert_scheduled_ids, Order, Permill};
+use frame_support::assert_ok;
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+
+fn route() -> Vec<Trade<AssetId>> {
+	vec![Trade {
+		pool: PoolType::Omnipool,
+		asset_in: HDX,
+		asset_out: BTC,
+	}]
+}
+
+#[test]
+fn oracle_volatility_should_be_relative_difference_between_last_block_and_short_oracle_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(DCA::oracle_volatility(&route()), Ok(Permill::from_percent(10)));
+	});
+}
+
+#[test]
+fn default_slippage_tolerance_should_be_max_price_difference_when_volatility_is_low() {
+	ExtBuilder::default()
+		.with_max_price_difference(Permill::from_percent(15))
+		.with_slippage_volatility_multiplier(1)
+		.build()
+		.execute_with(|| {
+			assert_eq!(DCA::default_slippage_tolerance(&route()), Ok(Permill::from_percent(15)));
+		});
+}
+
+#[test]
+fn default_slippage_tolerance_should_scale_with_volatility() {
+	ExtBuilder::default()
+		.with_max_price_difference(Permill::from_percent(5))
+		.with_slippage_volatility_multiplier(1)
+		.build()
+		.execute_with(|| {
+			assert_eq!(DCA::default_slippage_tolerance(&route()), Ok(Permill::from_percent(10)));
+		});
+}
+
+#[test]
+fn default_slippage_tolerance_should_not_exceed_max_configurable_price_difference() {
+	ExtBuilder::default()
+		.with_slippage_volatility_multiplier(3)
+		.build()
+		.execute_with(|| {
+			assert_eq!(DCA::default_slippage_tolerance(&route()), Ok(Permill::from_percent(20)));
+		});
+}
+
+#[test]
+fn sell_should_be_executed_when_volatility_widens_default_slippage_tolerance() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 5000 * ONE)])
+		.with_slippage_volatility_multiplier(2)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			set_sell_amount_out(ONE * 3 / 4);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(50 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(route()),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			let schedule_id = 0;
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_scheduled_ids!(602, vec![schedule_id]);
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+		});
+}
+
+#[test]
+fn sell_should_be_retried_when_volatility_is_not_taken_into_account() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			set_sell_amount_out(ONE * 3 / 4);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(50 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(route()),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			let schedule_id = 0;
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_scheduled_ids!(512, vec![schedule_id]);
+			assert_eq!(DCA::retries_on_error(schedule_id), 1);
+		});
+}
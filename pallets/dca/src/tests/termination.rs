@@ -0,0 +1,270 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::on_initialize::proceed_to_blocknumber;
+use crate::tests::schedule::{get_fee_for_buy_in_hdx, get_fee_for_sell_in_hdx};
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_executed_buy_trades, assert_executed_sell_trades, assert_number_of_executed_buy_trades,
+	assert_number_of_executed_sell_trades, assert_that_schedule_has_been_removed_from_storages, Error,
+	Event as DcaEvent, Order, TerminationMode,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType::Omnipool;
+use orml_traits::MultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use std::borrow::Borrow;
+
+fn omnipool_route() -> BoundedVec<Trade<AssetId>, ConstU32<5>> {
+	create_bounded_vec(vec![Trade {
+		pool: Omnipool,
+		asset_in: HDX,
+		asset_out: BTC,
+	}])
+}
+
+fn assert_that_dca_is_completed(owner: AccountId, schedule_id: ScheduleId) {
+	assert_that_schedule_has_been_removed_from_storages!(owner, schedule_id);
+
+	expect_events(vec![DcaEvent::Completed {
+		id: schedule_id,
+		who: owner,
+	}
+	.into()]);
+}
+
+#[test]
+fn sell_schedule_should_sell_dust_in_last_trade_when_budget_exhausted_mode_is_used() {
+	let alice_init_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, alice_init_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let sell_dca_fee = get_fee_for_sell_in_hdx();
+
+			let amount_to_sell = *AMOUNT_OUT_FOR_OMNIPOOL_SELL;
+			let dust = 2 * sell_dca_fee;
+			let total_amount = 3 * (amount_to_sell + sell_dca_fee) + dust;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_termination(TerminationMode::BudgetExhausted)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: omnipool_route(),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 801);
+
+			//Assert
+			assert_executed_sell_trades!(vec![
+				SellExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				},
+				SellExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				},
+				SellExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell + dust,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+			]);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_balance!(ALICE, HDX, alice_init_hdx_balance - total_amount);
+			assert_that_dca_is_completed(ALICE, 0);
+		});
+}
+
+#[test]
+fn sell_schedule_should_leave_dust_when_standard_mode_is_used() {
+	let alice_init_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, alice_init_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let sell_dca_fee = get_fee_for_sell_in_hdx();
+
+			let amount_to_sell = *AMOUNT_OUT_FOR_OMNIPOOL_SELL;
+			let dust = 2 * sell_dca_fee;
+			let total_amount = 3 * (amount_to_sell + sell_dca_fee) + dust;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: omnipool_route(),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 801);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(3);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_balance!(ALICE, HDX, alice_init_hdx_balance - total_amount + dust);
+			assert_that_dca_is_completed(ALICE, 0);
+		});
+}
+
+#[test]
+fn buy_schedule_should_sell_remaining_budget_in_final_execution_when_budget_exhausted_mode_is_used() {
+	let alice_init_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, alice_init_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let buy_dca_fee = get_fee_for_buy_in_hdx();
+
+			let amount_to_buy = 10 * ONE;
+			let remainder = 5 * ONE;
+			let total_amount = 2 * (CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY + buy_dca_fee) + buy_dca_fee + remainder;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_termination(TerminationMode::BudgetExhausted)
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_amount_in: Balance::MAX,
+					route: omnipool_route(),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 801);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(2);
+			assert_executed_sell_trades!(vec![SellExecution {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_in: remainder,
+				min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+			}]);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_balance!(ALICE, HDX, alice_init_hdx_balance - total_amount);
+			assert_that_dca_is_completed(ALICE, 0);
+		});
+}
+
+#[test]
+fn buy_schedule_should_be_completed_when_target_amount_out_is_reached() {
+	let alice_init_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, alice_init_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let amount_to_buy = 10 * ONE;
+			let target_amount_out = 25 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(1000 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(100)))
+				.with_termination(TerminationMode::TargetAmountOut(target_amount_out))
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_amount_in: Balance::MAX,
+					route: omnipool_route(),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 1001);
+
+			//Assert
+			assert_executed_buy_trades!(vec![
+				BuyExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_sell_amount: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+				},
+				BuyExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_sell_amount: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+				},
+				BuyExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: 5 * ONE,
+					max_sell_amount: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+				}
+			]);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_that_dca_is_completed(ALICE, 0);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_target_amount_out_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let schedule = ScheduleBuilder::new()
+				.with_termination(TerminationMode::TargetAmountOut(0))
+				.build();
+
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidTargetAmountOut
+			);
+		});
+}
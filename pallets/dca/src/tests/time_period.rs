@@ -0,0 +1,180 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{
+	assert_number_of_executed_buy_trades, assert_that_schedule_has_been_removed_from_storages, Error,
+	Event as DcaEvent, ScheduleIdsPerBlock, ScheduleIdsPerTimeBucket,
+};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use std::borrow::Borrow;
+
+const START_TIME: Moment = 10 * ONE_MINUTE;
+
+fn schedule_time_based_dca() {
+	proceed_to_blocknumber(1, 500);
+	set_timestamp(START_TIME);
+
+	let schedule = ScheduleBuilder::new().with_time_period(ONE_HOUR).build();
+	assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+	set_to_blocknumber(502);
+}
+
+fn time_bucket(time: Moment) -> u64 {
+	time / ONE_MINUTE
+}
+
+#[test]
+fn time_based_schedule_should_be_planned_in_time_bucket_after_execution() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Act
+			schedule_time_based_dca();
+
+			//Assert
+			assert_number_of_executed_buy_trades!(1);
+
+			let next_execution_time = START_TIME + ONE_HOUR;
+			assert_eq!(
+				DCA::planned_execution_times(0),
+				Some((next_execution_time, time_bucket(next_execution_time)))
+			);
+			assert_eq!(
+				ScheduleIdsPerTimeBucket::<Test>::get(time_bucket(next_execution_time)).to_vec(),
+				vec![0]
+			);
+			assert!(ScheduleIdsPerBlock::<Test>::get(502 + ONE_HUNDRED_BLOCKS).is_empty());
+			expect_events(vec![DcaEvent::ExecutionTimePlanned {
+				id: 0,
+				who: ALICE,
+				time: next_execution_time,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn time_based_schedule_should_be_executed_when_time_bucket_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_time_based_dca();
+			let next_execution_time = START_TIME + ONE_HOUR;
+
+			//Act
+			set_timestamp(next_execution_time);
+			proceed_to_blocknumber(503, 509);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(2);
+			assert_eq!(
+				DCA::last_processed_time_bucket(),
+				Some(time_bucket(next_execution_time))
+			);
+			assert!(ScheduleIdsPerTimeBucket::<Test>::get(time_bucket(next_execution_time)).is_empty());
+		});
+}
+
+#[test]
+fn next_execution_time_should_be_calculated_from_planned_time_when_execution_is_delayed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_time_based_dca();
+			let planned_time = START_TIME + ONE_HOUR;
+
+			//Act
+			set_timestamp(planned_time + 5 * ONE_MINUTE);
+			proceed_to_blocknumber(503, 510);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(2);
+			let next_execution_time = planned_time + ONE_HOUR;
+			assert_eq!(
+				DCA::planned_execution_times(0),
+				Some((next_execution_time, time_bucket(next_execution_time)))
+			);
+		});
+}
+
+#[test]
+fn missed_executions_should_be_skipped_when_execution_is_delayed_by_more_than_period() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_time_based_dca();
+			let planned_time = START_TIME + ONE_HOUR;
+
+			//Act
+			set_timestamp(planned_time + 2 * ONE_HOUR + 5 * ONE_MINUTE);
+			proceed_to_blocknumber(503, 530);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(2);
+			let next_execution_time = planned_time + 3 * ONE_HOUR;
+			assert_eq!(
+				DCA::planned_execution_times(0),
+				Some((next_execution_time, time_bucket(next_execution_time)))
+			);
+		});
+}
+
+#[test]
+fn terminate_should_remove_schedule_from_time_bucket_when_no_block_is_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_time_based_dca();
+			let next_execution_time = START_TIME + ONE_HOUR;
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), 0, None));
+
+			//Assert
+			assert!(ScheduleIdsPerTimeBucket::<Test>::get(time_bucket(next_execution_time)).is_empty());
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, 0);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_time_period_is_shorter_than_minimal() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let schedule = ScheduleBuilder::new().with_time_period(ONE_HOUR - 1).build();
+
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::PeriodTooShort
+			);
+		});
+}
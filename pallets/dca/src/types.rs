@@ -1,5 +1,6 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
+use primitives::Moment;
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{BoundedVec, Permill};
@@ -32,6 +33,39 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
 	pub order: Order<AssetId>,
+	/// Determines when the schedule is completed.
+	pub termination: TerminationMode,
+	/// The time period (in milliseconds) between two schedule executions.
+	/// If specified, executions are planned by timestamp instead of by `period`.
+	pub time_period: Option<Moment>,
+	/// Determines who executes the schedule.
+	pub execution: ExecutionMode,
+}
+
+/// Determines how executions of a DCA schedule are triggered.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, Default, TypeInfo, MaxEncodedLen)]
+pub enum ExecutionMode {
+	/// The schedule is executed automatically during block initialization.
+	#[default]
+	Automatic,
+	/// The schedule is executed by any signed keeper within the execution window,
+	/// who receives a bounty taken from the schedule budget.
+	Keeper,
+}
+
+/// Condition under which a DCA schedule is completed.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, Default, TypeInfo, MaxEncodedLen)]
+pub enum TerminationMode {
+	/// The schedule is completed when the remaining budget is not enough for the next trade.
+	/// The leftover is unreserved.
+	#[default]
+	Standard,
+	/// The schedule keeps executing until the whole budget is spent.
+	/// The final execution sells all the remaining budget, including dust which would be too small for another trade.
+	BudgetExhausted,
+	/// Same as `BudgetExhausted`, but the schedule is also completed once the accumulated `amount_out`
+	/// reaches the specified target. Buy orders never buy more than the target.
+	TargetAmountOut(Balance),
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
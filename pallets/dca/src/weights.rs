@@ -64,7 +64,7 @@ impl WeightInfo for () {
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
@@ -75,19 +75,25 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RetriesOnError` (r:0 w:1)
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::AccumulatedAmountsOut` (r:1 w:1)
+	/// Proof: `DCA::AccumulatedAmountsOut` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+	/// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+	/// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn on_initialize_with_buy_trade() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `55128`
 		//  Estimated: `31902`
 		// Minimum execution time: 188_709_000 picoseconds.
 		Weight::from_parts(192_519_000, 31902)
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:0)
@@ -118,19 +124,25 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RetriesOnError` (r:0 w:1)
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::AccumulatedAmountsOut` (r:1 w:1)
+	/// Proof: `DCA::AccumulatedAmountsOut` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+	/// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+	/// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn on_initialize_with_buy_trade_with_insufficient_fee_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `60642`
 		//  Estimated: `31902`
 		// Minimum execution time: 304_459_000 picoseconds.
 		Weight::from_parts(311_059_000, 31902)
-			.saturating_add(RocksDbWeight::get().reads(35_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
@@ -141,19 +153,25 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RetriesOnError` (r:0 w:1)
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::AccumulatedAmountsOut` (r:1 w:1)
+	/// Proof: `DCA::AccumulatedAmountsOut` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+	/// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+	/// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn on_initialize_with_sell_trade() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `54916`
 		//  Estimated: `31902`
 		// Minimum execution time: 190_679_000 picoseconds.
 		Weight::from_parts(193_669_000, 31902)
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:0)
@@ -184,23 +202,35 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RetriesOnError` (r:0 w:1)
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::AccumulatedAmountsOut` (r:1 w:1)
+	/// Proof: `DCA::AccumulatedAmountsOut` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+	/// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+	/// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn on_initialize_with_sell_trade_with_insufficient_fee_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `61518`
 		//  Estimated: `31902`
 		// Minimum execution time: 302_059_000 picoseconds.
 		Weight::from_parts(308_529_000, 31902)
-			.saturating_add(RocksDbWeight::get().reads(35_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:1 w:0)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:1)
+	/// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn on_initialize_with_empty_block() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1075`
 		//  Estimated: `3566`
 		// Minimum execution time: 11_760_000 picoseconds.
-		Weight::from_parts(12_060_000, 3566).saturating_add(RocksDbWeight::get().reads(1_u64))
+		Weight::from_parts(12_060_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -219,7 +249,7 @@ impl WeightInfo for () {
 	/// Storage: `DCA::RetriesOnError` (r:0 w:1)
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:0 w:1)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleOwnership` (r:0 w:1)
 	/// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:0 w:1)
@@ -234,7 +264,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `DCA::Schedules` (r:1 w:1)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
 	/// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Reserves` (r:1 w:1)
@@ -247,6 +277,8 @@ impl WeightInfo for () {
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleOwnership` (r:0 w:1)
 	/// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::AccumulatedAmountsOut` (r:0 w:1)
+	/// Proof: `DCA::AccumulatedAmountsOut` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn terminate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2492`
@@ -254,6 +286,6 @@ impl WeightInfo for () {
 		// Minimum execution time: 58_440_000 picoseconds.
 		Weight::from_parts(60_230_000, 4714)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 }
@@ -29,7 +29,14 @@ pub mod weights;
 
 pub use crate::weights::WeightInfo;
 
-use frame_support::{dispatch::DispatchResult, ensure, traits::Contains, traits::Get};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{Contains, Get, OnKilledAccount},
+	weights::Weight,
+};
+use hydradx_traits::deposit::ExistentialDepositSponsor;
+use sp_runtime::traits::Zero;
 
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
@@ -74,6 +81,24 @@ pub mod pallet {
 	/// Account to send dust to.
 	pub type DustAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_deposit)]
+	/// Existential deposits sponsored by whitelisted sponsors - (sponsor, amount).
+	pub type SponsoredDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::CurrencyId,
+		(T::AccountId, T::Balance),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Total amount of existential deposits sponsored by sponsor which were not reclaimed yet.
+	pub type SponsoredTotal<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, T::CurrencyId, T::Balance, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
@@ -130,6 +155,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type TreasuryAccountId: Get<Self::AccountId>;
 
+		/// Accounts allowed to sponsor existential deposits of the accounts they pay out to.
+		type ExistentialDepositSponsors: Contains<Self::AccountId>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -173,6 +201,9 @@ pub mod pallet {
 
 		/// Reserve account is not set.
 		ReserveAccountNotSet,
+
+		/// Account is not allowed to sponsor existential deposits.
+		NotExistentialDepositSponsor,
 	}
 
 	#[pallet::event]
@@ -186,6 +217,22 @@ pub mod pallet {
 
 		/// Account removed from non-dustable list.
 		Removed { who: T::AccountId },
+
+		/// Existential deposit of account was sponsored.
+		DepositSponsored {
+			who: T::AccountId,
+			currency_id: T::CurrencyId,
+			sponsor: T::AccountId,
+			amount: T::Balance,
+		},
+
+		/// Sponsored existential deposit was returned to the sponsor when the account was reaped.
+		SponsoredDepositReclaimed {
+			who: T::AccountId,
+			currency_id: T::CurrencyId,
+			sponsor: T::AccountId,
+			amount: T::Balance,
+		},
 	}
 
 	#[pallet::call]
@@ -211,7 +258,13 @@ pub mod pallet {
 			// Error should never occur here
 			let dust_dest_account = Self::dust_dest_account().ok_or(Error::<T>::DustAccountNotSet)?;
 
-			Self::transfer_dust(&account, &dust_dest_account, currency_id, dust)?;
+			let reclaimed = Self::reclaim_sponsored_deposit(&account, currency_id, dust);
+			Self::transfer_dust(
+				&account,
+				&dust_dest_account,
+				currency_id,
+				dust.saturating_sub(reclaimed),
+			)?;
 
 			Self::deposit_event(Event::Dusted {
 				who: account,
@@ -280,6 +333,36 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Remove sponsored existential deposit of `who` and subtract it from the total of the sponsor.
+	fn take_sponsored_deposit(who: &T::AccountId, currency_id: T::CurrencyId) -> Option<(T::AccountId, T::Balance)> {
+		let (sponsor, deposit) = SponsoredDeposits::<T>::take(who, currency_id)?;
+		SponsoredTotal::<T>::mutate(&sponsor, currency_id, |total| *total = total.saturating_sub(deposit));
+
+		Some((sponsor, deposit))
+	}
+
+	/// Return sponsored existential deposit of reaped account to the sponsor, at most `available` amount.
+	/// Returns the returned amount.
+	fn reclaim_sponsored_deposit(who: &T::AccountId, currency_id: T::CurrencyId, available: T::Balance) -> T::Balance {
+		let Some((sponsor, deposit)) = Self::take_sponsored_deposit(who, currency_id) else {
+			return T::Balance::zero();
+		};
+
+		let amount = deposit.min(available);
+		if amount.is_zero() || T::MultiCurrency::transfer(currency_id, who, &sponsor, amount).is_err() {
+			return T::Balance::zero();
+		}
+
+		Self::deposit_event(Event::SponsoredDepositReclaimed {
+			who: who.clone(),
+			currency_id,
+			sponsor,
+			amount,
+		});
+
+		amount
+	}
+
 	/// Transfer dust amount to selected DustAccount ( usually treasury)
 	fn transfer_dust(
 		from: &T::AccountId,
@@ -299,11 +382,81 @@ pub struct DusterWhitelist<T>(PhantomData<T>);
 impl<T: Config> OnDust<T::AccountId, T::CurrencyId, T::Balance> for Pallet<T> {
 	fn on_dust(who: &T::AccountId, currency_id: T::CurrencyId, amount: T::Balance) {
 		if let Some(dust_dest_account) = Self::dust_dest_account() {
-			let _ = Self::transfer_dust(who, &dust_dest_account, currency_id, amount);
+			let reclaimed = Self::reclaim_sponsored_deposit(who, currency_id, amount);
+			let _ = Self::transfer_dust(who, &dust_dest_account, currency_id, amount.saturating_sub(reclaimed));
 		}
 	}
 }
 
+/// Native dust is removed by the balances pallet without notifying the duster, so the sponsored native deposit is
+/// reclaimed from the dust account when the account is killed.
+impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
+	fn on_killed_account(who: &T::AccountId) {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let Some((sponsor, deposit)) = Self::take_sponsored_deposit(who, native_currency_id) else {
+			return;
+		};
+		let Some(dust_dest_account) = Self::dust_dest_account() else {
+			return;
+		};
+
+		if T::MultiCurrency::transfer(native_currency_id, &dust_dest_account, &sponsor, deposit).is_ok() {
+			Self::deposit_event(Event::SponsoredDepositReclaimed {
+				who: who.clone(),
+				currency_id: native_currency_id,
+				sponsor,
+				amount: deposit,
+			});
+		}
+	}
+}
+
+impl<T: Config> ExistentialDepositSponsor<T::AccountId, T::CurrencyId, T::Balance> for Pallet<T> {
+	fn sponsored_transfer(
+		currency_id: T::CurrencyId,
+		sponsor: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		ensure!(
+			T::ExistentialDepositSponsors::contains(sponsor),
+			Error::<T>::NotExistentialDepositSponsor
+		);
+
+		// Account with sponsored deposit has its balance above the existential deposit.
+		let to_sponsor = if SponsoredDeposits::<T>::contains_key(to, currency_id) {
+			T::Balance::zero()
+		} else {
+			let balance = T::MultiCurrency::total_balance(currency_id, to).saturating_add(amount);
+			T::MinCurrencyDeposits::get(&currency_id).saturating_sub(balance)
+		};
+
+		T::MultiCurrency::transfer(currency_id, sponsor, to, amount.saturating_add(to_sponsor))?;
+
+		if !to_sponsor.is_zero() {
+			SponsoredDeposits::<T>::insert(to, currency_id, (sponsor.clone(), to_sponsor));
+			SponsoredTotal::<T>::mutate(sponsor, currency_id, |total| *total = total.saturating_add(to_sponsor));
+
+			Self::deposit_event(Event::DepositSponsored {
+				who: to.clone(),
+				currency_id,
+				sponsor: sponsor.clone(),
+				amount: to_sponsor,
+			});
+		}
+
+		Ok(to_sponsor)
+	}
+
+	fn sponsored_total(currency_id: T::CurrencyId, sponsor: &T::AccountId) -> T::Balance {
+		SponsoredTotal::<T>::get(sponsor, currency_id)
+	}
+
+	fn sponsored_transfer_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
+
 impl<T: Config> Contains<T::AccountId> for DusterWhitelist<T> {
 	fn contains(t: &T::AccountId) -> bool {
 		AccountBlacklist::<T>::contains_key(t)
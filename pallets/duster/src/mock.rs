@@ -1,7 +1,7 @@
 use crate as duster;
 
 use frame_support::parameter_types;
-use frame_support::traits::{Everything, Nothing, OnKilledAccount};
+use frame_support::traits::{Everything, IsInVec, Nothing, OnKilledAccount};
 
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
@@ -33,10 +33,12 @@ pub static ref ALICE: AccountId = 100;
 pub static ref BOB: AccountId = 200;
 pub static ref DUSTER: AccountId = 300;
 pub static ref TREASURY: AccountId = 400;
+pub static ref SPONSOR: AccountId = 500;
 }
 
 parameter_types! {
 	pub TreasuryAccount: AccountId = *TREASURY;
+	pub Sponsors: Vec<AccountId> = vec![*SPONSOR];
 }
 
 frame_support::construct_runtime!(
@@ -95,7 +97,7 @@ impl system::Config for Test {
 	type PalletInfo = PalletInfo;
 	type AccountData = pallet_balances::AccountData<u128>;
 	type OnNewAccount = ();
-	type OnKilledAccount = RecordKilled;
+	type OnKilledAccount = (RecordKilled, Duster);
 	type SystemWeightInfo = ();
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
@@ -134,6 +136,7 @@ impl Config for Test {
 	type NativeCurrencyId = NativeCurrencyId;
 	type BlacklistUpdateOrigin = EnsureRoot<AccountId>;
 	type TreasuryAccountId = TreasuryAccount;
+	type ExistentialDepositSponsors = IsInVec<Sponsors>;
 	type WeightInfo = ();
 }
 
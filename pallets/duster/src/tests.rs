@@ -1,10 +1,11 @@
 use super::*;
 use crate::mock::{
 	AssetId, Currencies, Duster, ExtBuilder, RuntimeEvent as TestEvent, RuntimeOrigin, System, Test, Tokens, ALICE,
-	BOB, DUSTER, KILLED, TREASURY,
+	BOB, DUSTER, KILLED, SPONSOR, TREASURY,
 };
 
 use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::deposit::ExistentialDepositSponsor;
 
 use sp_runtime::traits::BadOrigin;
 
@@ -317,3 +318,115 @@ fn remove_nondustable_account_works() {
 			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 0),);
 		});
 }
+
+#[test]
+fn sponsored_transfer_should_top_up_balance_to_existential_deposit() {
+	ExtBuilder::default()
+		.with_balance(*SPONSOR, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_eq!(Duster::sponsored_transfer(1, &*SPONSOR, &*ALICE, 10), Ok(99_990));
+
+			assert_eq!(Tokens::free_balance(1, &*ALICE), 100_000);
+			assert_eq!(Tokens::free_balance(1, &*SPONSOR), 900_000);
+			assert_eq!(Duster::sponsored_deposit(*ALICE, 1), Some((*SPONSOR, 99_990)));
+			assert_eq!(Duster::sponsored_total(1, &*SPONSOR), 99_990);
+			expect_events(vec![Event::DepositSponsored {
+				who: *ALICE,
+				currency_id: 1,
+				sponsor: *SPONSOR,
+				amount: 99_990,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn sponsored_transfer_should_not_sponsor_when_balance_reaches_existential_deposit() {
+	ExtBuilder::default()
+		.with_balance(*SPONSOR, 1, 1_000_000)
+		.with_balance(*ALICE, 1, 100_000)
+		.build()
+		.execute_with(|| {
+			assert_eq!(Duster::sponsored_transfer(1, &*SPONSOR, &*ALICE, 10), Ok(0));
+
+			assert_eq!(Tokens::free_balance(1, &*ALICE), 100_010);
+			assert_eq!(Duster::sponsored_deposit(*ALICE, 1), None);
+		});
+}
+
+#[test]
+fn sponsored_transfer_should_fail_when_sponsor_is_not_whitelisted() {
+	ExtBuilder::default()
+		.with_balance(*BOB, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Duster::sponsored_transfer(1, &*BOB, &*ALICE, 10),
+				Error::<Test>::NotExistentialDepositSponsor
+			);
+		});
+}
+
+#[test]
+fn dust_account_should_return_sponsored_deposit_to_sponsor() {
+	ExtBuilder::default()
+		.with_balance(*SPONSOR, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Duster::sponsored_transfer(1, &*SPONSOR, &*ALICE, 10));
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(*ALICE), *BOB, 1, 50));
+
+			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 1));
+
+			assert_eq!(Tokens::free_balance(1, &*ALICE), 0);
+			assert_eq!(Tokens::free_balance(1, &*SPONSOR), 999_950);
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 0);
+			assert_eq!(Duster::sponsored_deposit(*ALICE, 1), None);
+			assert_eq!(Duster::sponsored_total(1, &*SPONSOR), 0);
+			expect_events(vec![Event::SponsoredDepositReclaimed {
+				who: *ALICE,
+				currency_id: 1,
+				sponsor: *SPONSOR,
+				amount: 99_950,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn dust_account_should_transfer_dust_above_sponsored_deposit_to_dust_account() {
+	ExtBuilder::default()
+		.with_balance(*SPONSOR, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Duster::sponsored_transfer(1, &*SPONSOR, &*ALICE, 60_000));
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(*ALICE), *BOB, 1, 10_000));
+
+			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 1));
+
+			assert_eq!(Tokens::free_balance(1, &*SPONSOR), 1_000_000 - 60_000);
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 50_000);
+		});
+}
+
+#[test]
+fn killed_account_should_return_sponsored_native_deposit_to_sponsor() {
+	ExtBuilder::default()
+		.with_native_balance(*SPONSOR, 100_000)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_eq!(Duster::sponsored_transfer(0, &*SPONSOR, &*ALICE, 10), Ok(990));
+
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(*ALICE), *BOB, 0, 1_000));
+
+			assert_eq!(KILLED.with(|r| r.borrow().clone()), vec![*ALICE]);
+			assert_eq!(Currencies::free_balance(0, &*SPONSOR), 100_000 - 10);
+			assert_eq!(Currencies::free_balance(0, &*TREASURY), 1_000_000 - 990);
+			assert_eq!(Duster::sponsored_deposit(*ALICE, 0), None);
+		});
+}
@@ -22,8 +22,12 @@ use super::*;
 pub const HDX: AssetId = 1_000;
 pub const DOT: AssetId = 2_000;
 
-use frame_benchmarking::benchmarks;
-use frame_support::{assert_ok, dispatch::RawOrigin, traits::Hooks};
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{
+	assert_ok,
+	dispatch::RawOrigin,
+	traits::{Currency, Hooks},
+};
 
 #[cfg(test)]
 use pretty_assertions::assert_eq;
@@ -38,6 +42,18 @@ fn fill_whitelist_storage<T: Config>(n: u32) {
 		assert_ok!(EmaOracle::<T>::add_oracle(RawOrigin::Root.into(), SOURCE, (HDX, i)));
 	}
 }
+
+fn fill_routed_oracles<T: Config>(n: u32) {
+	for i in 0..n {
+		assert_ok!(EmaOracle::<T>::add_routed_oracle(RawOrigin::Root.into(), (HDX, i)));
+	}
+}
+
+fn funded_depositor<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = account("caller", 0, 1);
+	T::Currency::make_free_balance_be(&caller, T::RoutedOracleDeposit::get().saturating_mul(10));
+	caller
+}
 benchmarks! {
 	add_oracle {
 		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
@@ -379,5 +395,56 @@ benchmarks! {
 		}));
 	}
 
+	add_routed_oracle {
+		fill_routed_oracles::<T>(T::MaxRoutedOracles::get() - 1);
+		let caller = funded_depositor::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), (HDX, DOT))
+	verify {
+		assert_eq!(
+			RoutedOracles::<T>::get().get(&(HDX, DOT)),
+			Some(&Some((caller, T::RoutedOracleDeposit::get())))
+		);
+	}
+
+	remove_routed_oracle {
+		fill_routed_oracles::<T>(T::MaxRoutedOracles::get() - 1);
+		let caller = funded_depositor::<T>();
+		assert_ok!(EmaOracle::<T>::add_routed_oracle(RawOrigin::Signed(caller.clone()).into(), (HDX, DOT)));
+
+		let block_num: BlockNumberFor<T> = 5u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		EmaOracle::<T>::on_initialize(block_num);
+		EmaOracle::<T>::on_finalize(block_num);
+
+		assert!(Oracles::<T>::get((ROUTER_SOURCE, (HDX, DOT), OraclePeriod::LastBlock)).is_some());
+	}: _(RawOrigin::Signed(caller), (HDX, DOT))
+	verify {
+		assert!(!RoutedOracles::<T>::get().contains_key(&(HDX, DOT)));
+		assert!(Oracles::<T>::get((ROUTER_SOURCE, (HDX, DOT), OraclePeriod::LastBlock)).is_none());
+	}
+
+	on_finalize_routed_oracles {
+		let r in 1 .. T::MaxRoutedOracles::get();
+
+		fill_routed_oracles::<T>(r);
+
+		let initial_data_block: BlockNumberFor<T> = 5u32.into();
+		let block_num = initial_data_block.saturating_add(1_000_000u32.into());
+
+		frame_system::Pallet::<T>::set_block_number(initial_data_block);
+		EmaOracle::<T>::on_initialize(initial_data_block);
+		EmaOracle::<T>::on_finalize(initial_data_block);
+
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		EmaOracle::<T>::on_initialize(block_num);
+	}: { EmaOracle::<T>::on_finalize(block_num); }
+	verify {
+		assert!(Accumulator::<T>::get().is_empty());
+		for i in 0 .. r {
+			let (entry, _) = Oracles::<T>::get((ROUTER_SOURCE, ordered_pair(HDX, i), OraclePeriod::LastBlock)).unwrap();
+			assert_eq!(entry.updated_at, block_num);
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
 }
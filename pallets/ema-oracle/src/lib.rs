@@ -63,13 +63,22 @@
 //! and the price and liquidity to be constant) to the last block. Note: The most recent oracle
 //! values are always from the last block. This avoids e.g. sandwiching risks. If you want current
 //! prices you should use a spot price or similar.
+//!
+//! ### Routed oracles
+//!
+//! Asset pairs which are not traded in any single pool (and thus never emitted by a source) can be
+//! tracked as routed oracles. Routed oracle is registered by `add_routed_oracle` either by
+//! `AuthorityOrigin` or by any user who reserves `RoutedOracleDeposit`. At the end of each block
+//! the spot price of every registered pair is read from `SpotPriceProvider` (e.g. the router) and
+//! integrated into the oracles of `ROUTER_SOURCE` the same way as trades are.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
-use frame_support::traits::Contains;
+use frame_support::traits::{Contains, ReservableCurrency};
 use frame_system::pallet_prelude::BlockNumberFor;
+use hydradx_traits::price::PriceProvider;
 use hydradx_traits::{
 	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
 	OnLiquidityChangedHandler, OnTradeHandler, Volume,
@@ -95,6 +104,9 @@ pub const MAX_PERIODS: u32 = OraclePeriod::all_periods().len() as u32;
 
 const LOG_TARGET: &str = "runtime::ema-oracle";
 
+/// Source of the routed oracles.
+pub const ROUTER_SOURCE: Source = *b"hdxroute";
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -114,6 +126,7 @@ impl BenchmarkHelper<AssetId> for () {
 pub mod pallet {
 	use super::*;
 	use frame_support::{BoundedBTreeMap, BoundedBTreeSet};
+	use frame_system::ensure_signed;
 	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 
 	#[pallet::pallet]
@@ -142,6 +155,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
 
+		/// Spot price provider of asset pairs tracked by routed oracles.
+		type SpotPriceProvider: PriceProvider<AssetId, Price = Price>;
+
+		/// Currency used to reserve deposit of routed oracles registered by users.
+		type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Deposit reserved from a user registering a routed oracle.
+		#[pallet::constant]
+		type RoutedOracleDeposit: Get<Balance>;
+
+		/// Maximum number of routed oracles.
+		#[pallet::constant]
+		type MaxRoutedOracles: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
@@ -151,6 +178,16 @@ pub mod pallet {
 		TooManyUniqueEntries,
 		OnTradeValueZero,
 		OracleNotFound,
+		/// Oracle of the same asset is not supported.
+		SameAsset,
+		/// Spot price of the asset pair is not available.
+		PriceNotAvailable,
+		/// Routed oracle of the asset pair is already registered.
+		RoutedOracleAlreadyRegistered,
+		/// Maximum number of routed oracles has been reached.
+		TooManyRoutedOracles,
+		/// Routed oracle can be removed only by its depositor or the authority.
+		NotDepositor,
 	}
 
 	#[pallet::event]
@@ -160,6 +197,14 @@ pub mod pallet {
 		AddedToWhitelist { source: Source, assets: (AssetId, AssetId) },
 		/// Oracle was removed from the whitelist.
 		RemovedFromWhitelist { source: Source, assets: (AssetId, AssetId) },
+		/// Routed oracle was registered.
+		RoutedOracleAdded {
+			assets: (AssetId, AssetId),
+			depositor: Option<T::AccountId>,
+			deposit: Balance,
+		},
+		/// Routed oracle was removed.
+		RoutedOracleRemoved { assets: (AssetId, AssetId) },
 	}
 
 	/// Accumulator for oracle data in current block that will be recorded at the end of the block.
@@ -192,6 +237,16 @@ pub mod pallet {
 	pub type WhitelistedAssets<T: Config> =
 		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxUniqueEntries>, ValueQuery>;
 
+	/// Asset pairs tracked by routed oracles with the depositor and the reserved deposit.
+	/// Depositor is `None` if the oracle was registered by the authority.
+	#[pallet::storage]
+	#[pallet::getter(fn routed_oracles)]
+	pub type RoutedOracles<T: Config> = StorageValue<
+		_,
+		BoundedBTreeMap<(AssetId, AssetId), Option<(T::AccountId, Balance)>, T::MaxRoutedOracles>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -228,10 +283,15 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let routed_oracles = RoutedOracles::<T>::get().len() as u32;
 			T::WeightInfo::on_finalize_no_entry()
+				.saturating_add(T::WeightInfo::on_finalize_routed_oracles(routed_oracles))
+				.saturating_add(T::DbWeight::get().reads(1))
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
+			// add spot prices of routed oracles to the data accumulated during the block
+			Self::accumulate_routed_oracles();
 			// update oracles based on data accumulated during the block
 			Self::update_oracles_from_accumulator();
 		}
@@ -289,6 +349,115 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Register routed oracle of an asset pair.
+		///
+		/// Spot price of the pair is read from `SpotPriceProvider` at the end of each block and
+		/// tracked by oracles of `ROUTER_SOURCE`, even if the pair is not traded in any pool.
+		///
+		/// Can be called by `AuthorityOrigin` or by a signed origin, in which case
+		/// `RoutedOracleDeposit` is reserved from the caller until the oracle is removed.
+		///
+		/// Parameters:
+		/// - `origin`: `AuthorityOrigin` or signed origin
+		/// - `assets`: asset pair to track. Spot price of the pair must be available.
+		///
+		/// Emits `RoutedOracleAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_routed_oracle())]
+		pub fn add_routed_oracle(origin: OriginFor<T>, assets: (AssetId, AssetId)) -> DispatchResult {
+			let depositor = match T::AuthorityOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			ensure!(assets.0 != assets.1, Error::<T>::SameAsset);
+			let assets = ordered_pair(assets.0, assets.1);
+
+			ensure!(
+				T::SpotPriceProvider::get_price(assets.0, assets.1).is_some(),
+				Error::<T>::PriceNotAvailable
+			);
+
+			let deposit = if depositor.is_some() {
+				T::RoutedOracleDeposit::get()
+			} else {
+				Balance::zero()
+			};
+
+			RoutedOracles::<T>::try_mutate(|oracles| -> DispatchResult {
+				ensure!(
+					!oracles.contains_key(&assets),
+					Error::<T>::RoutedOracleAlreadyRegistered
+				);
+
+				if let Some(who) = &depositor {
+					T::Currency::reserve(who, deposit)?;
+				}
+
+				oracles
+					.try_insert(assets, depositor.clone().map(|who| (who, deposit)))
+					.map_err(|_| Error::<T>::TooManyRoutedOracles)?;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RoutedOracleAdded {
+				assets,
+				depositor,
+				deposit,
+			});
+
+			Ok(())
+		}
+
+		/// Remove routed oracle of an asset pair and unreserve the deposit.
+		///
+		/// Can be called by `AuthorityOrigin` or by the account which registered the oracle.
+		///
+		/// Parameters:
+		/// - `origin`: `AuthorityOrigin` or signed origin of the depositor
+		/// - `assets`: asset pair of the routed oracle
+		///
+		/// Emits `RoutedOracleRemoved` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_routed_oracle())]
+		pub fn remove_routed_oracle(origin: OriginFor<T>, assets: (AssetId, AssetId)) -> DispatchResult {
+			let who = match T::AuthorityOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let assets = ordered_pair(assets.0, assets.1);
+
+			RoutedOracles::<T>::try_mutate(|oracles| -> DispatchResult {
+				let deposit = oracles.remove(&assets).ok_or(Error::<T>::OracleNotFound)?;
+
+				if let Some(who) = &who {
+					ensure!(
+						matches!(&deposit, Some((depositor, _)) if depositor == who),
+						Error::<T>::NotDepositor
+					);
+				}
+
+				if let Some((depositor, amount)) = deposit {
+					T::Currency::unreserve(&depositor, amount);
+				}
+
+				Ok(())
+			})?;
+
+			Accumulator::<T>::mutate(|accumulator| {
+				accumulator.remove(&(ROUTER_SOURCE, assets));
+			});
+			for period in T::SupportedPeriods::get().into_iter() {
+				Oracles::<T>::remove((ROUTER_SOURCE, assets, period));
+			}
+
+			Self::deposit_event(Event::RoutedOracleRemoved { assets });
+
+			Ok(())
+		}
 	}
 }
 
@@ -305,6 +474,15 @@ impl<T: Config> Pallet<T> {
 			return Ok(());
 		}
 
+		Self::accumulate_entry(src, assets, oracle_entry)
+	}
+
+	/// Insert or update data in the accumulator regardless of the whitelist.
+	fn accumulate_entry(
+		src: Source,
+		assets: (AssetId, AssetId),
+		oracle_entry: OracleEntry<BlockNumberFor<T>>,
+	) -> Result<(), ()> {
 		Accumulator::<T>::mutate(|accumulator| {
 			if let Some(entry) = accumulator.get_mut(&(src, assets)) {
 				entry.accumulate_volume_and_update_from(&oracle_entry);
@@ -360,6 +538,31 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Add spot prices of routed oracles to the accumulator.
+	fn accumulate_routed_oracles() {
+		let updated_at = T::BlockNumberProvider::current_block_number();
+
+		for &assets in RoutedOracles::<T>::get().keys() {
+			let Some(price) = T::SpotPriceProvider::get_price(assets.0, assets.1) else {
+				log::debug!(target: LOG_TARGET, "Spot price of routed oracle {assets:?} is not available.");
+				continue;
+			};
+
+			let entry = OracleEntry {
+				price,
+				// spot price does not come with any trade volume or pool liquidity
+				volume: Volume::default(),
+				liquidity: Liquidity::default(),
+				updated_at,
+			};
+
+			// routed oracles are registered explicitly, so they bypass the whitelist
+			if Self::accumulate_entry(ROUTER_SOURCE, assets, entry).is_err() {
+				log::warn!(target: LOG_TARGET, "Too many unique entries. Routed oracle {assets:?} was not updated.");
+			}
+		}
+	}
+
 	/// Update oracles based on data accumulated during the block.
 	fn update_oracles_from_accumulator() {
 		for ((src, assets), oracle_entry) in Accumulator::<T>::take().into_iter() {
@@ -25,9 +25,10 @@ use frame_support::sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
-use frame_support::traits::{Contains, Everything};
+use frame_support::traits::{ConstU128, Contains, Everything};
 use frame_support::BoundedVec;
 use frame_system::EnsureRoot;
+use hydradx_traits::price::PriceProvider;
 use hydradx_traits::OraclePeriod::{self, *};
 use hydradx_traits::Source;
 use hydradx_traits::{AssetPairAccountIdFor, Liquidity, Volume};
@@ -46,6 +47,11 @@ pub const DOT: AssetId = 2_000;
 pub const ACA: AssetId = 3_000;
 // ensure this asset id is not used in the benchmarks, otherwise the benchmarking tests fail
 pub const INSUFFICIENT_ASSET: AssetId = 123_456;
+// spot price of pairs with this asset is not available
+pub const NO_SPOT_PRICE_ASSET: AssetId = 654_321;
+
+pub const SPOT_PRICE: Price = Price::new(3_000, 1_000);
+pub const ROUTED_ORACLE_DEPOSIT: Balance = 1_000;
 
 pub const ORACLE_ENTRY_1: OracleEntry<BlockNumber> = OracleEntry {
 	price: Price::new(2_000, 1_000),
@@ -74,6 +80,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	 {
 		 System: frame_system,
+		 Balances: pallet_balances,
 		 EmaOracle: ema_oracle,
 	 }
 
@@ -101,7 +108,7 @@ impl frame_system::Config for Test {
 	type DbWeight = ();
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -115,6 +122,22 @@ impl frame_system::Config for Test {
 	type PostTransactions = ();
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
 pub struct AssetPairAccountIdTest();
 
 impl AssetPairAccountIdFor<AssetId, u64> for AssetPairAccountIdTest {
@@ -139,6 +162,19 @@ impl Contains<(Source, AssetId, AssetId)> for OracleWhitelist {
 	}
 }
 
+pub struct SpotPriceProviderMock;
+impl PriceProvider<AssetId> for SpotPriceProviderMock {
+	type Price = Price;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Option<Price> {
+		if asset_a == NO_SPOT_PRICE_ASSET || asset_b == NO_SPOT_PRICE_ASSET {
+			None
+		} else {
+			Some(SPOT_PRICE)
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
@@ -146,6 +182,10 @@ impl Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = OracleWhitelist;
 	type MaxUniqueEntries = ConstU32<45>;
+	type SpotPriceProvider = SpotPriceProviderMock;
+	type Currency = Balances;
+	type RoutedOracleDeposit = ConstU128<ROUTED_ORACLE_DEPOSIT>;
+	type MaxRoutedOracles = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -156,6 +196,7 @@ pub type InitialDataEntry = (Source, (AssetId, AssetId), Price, Liquidity<Balanc
 #[derive(Default)]
 pub struct ExtBuilder {
 	pub initial_data: Vec<InitialDataEntry>,
+	pub endowed_accounts: Vec<(AccountId, Balance)>,
 }
 
 impl ExtBuilder {
@@ -164,8 +205,18 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
 		crate::GenesisConfig::<Test> {
 			initial_data: self.initial_data,
 			..Default::default()
@@ -0,0 +1,261 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use mock::{
+	expect_events, AccountId, Balances, EmaOracle, RuntimeOrigin, Test, DOT, HDX, NO_SPOT_PRICE_ASSET,
+	ROUTED_ORACLE_DEPOSIT, SPOT_PRICE,
+};
+
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const ALICE: AccountId = 1;
+const BOB: AccountId = 2;
+const INITIAL_BALANCE: Balance = 1_000_000;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)])
+		.build()
+}
+
+fn get_routed_oracle_entry(a: AssetId, b: AssetId, period: OraclePeriod) -> Option<OracleEntry<BlockNumber>> {
+	Oracles::<Test>::get((ROUTER_SOURCE, ordered_pair(a, b), period)).map(|(e, _)| e)
+}
+
+#[test]
+fn add_routed_oracle_should_register_pair_without_deposit_when_called_by_authority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::root(), (DOT, HDX)));
+
+		assert_eq!(EmaOracle::routed_oracles().get(&(HDX, DOT)), Some(&None));
+		expect_events(vec![Event::RoutedOracleAdded {
+			assets: (HDX, DOT),
+			depositor: None,
+			deposit: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_routed_oracle_should_reserve_deposit_when_called_by_user() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)));
+
+		assert_eq!(
+			EmaOracle::routed_oracles().get(&(HDX, DOT)),
+			Some(&Some((ALICE, ROUTED_ORACLE_DEPOSIT)))
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), ROUTED_ORACLE_DEPOSIT);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - ROUTED_ORACLE_DEPOSIT);
+		expect_events(vec![Event::RoutedOracleAdded {
+			assets: (HDX, DOT),
+			depositor: Some(ALICE),
+			deposit: ROUTED_ORACLE_DEPOSIT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_routed_oracle_should_fail_when_pair_is_already_registered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)));
+
+		assert_noop!(
+			EmaOracle::add_routed_oracle(RuntimeOrigin::signed(BOB), (DOT, HDX)),
+			Error::<Test>::RoutedOracleAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn add_routed_oracle_should_fail_when_spot_price_is_not_available() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, NO_SPOT_PRICE_ASSET)),
+			Error::<Test>::PriceNotAvailable
+		);
+	});
+}
+
+#[test]
+fn add_routed_oracle_should_fail_when_assets_are_same() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_routed_oracle(RuntimeOrigin::root(), (HDX, HDX)),
+			Error::<Test>::SameAsset
+		);
+	});
+}
+
+#[test]
+fn add_routed_oracle_should_fail_when_max_routed_oracles_is_reached() {
+	new_test_ext().execute_with(|| {
+		let max_routed_oracles = <<Test as Config>::MaxRoutedOracles as Get<u32>>::get();
+		for i in 0..max_routed_oracles {
+			assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::root(), (HDX, i)));
+		}
+
+		assert_noop!(
+			EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)),
+			Error::<Test>::TooManyRoutedOracles
+		);
+	});
+}
+
+#[test]
+fn add_routed_oracle_should_fail_when_user_cannot_pay_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ROUTED_ORACLE_DEPOSIT - 1)])
+		.build()
+		.execute_with(|| {
+			assert!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)).is_err());
+			assert!(EmaOracle::routed_oracles().is_empty());
+		});
+}
+
+#[test]
+fn remove_routed_oracle_should_unreserve_deposit_when_called_by_depositor() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)));
+
+		assert_ok!(EmaOracle::remove_routed_oracle(
+			RuntimeOrigin::signed(ALICE),
+			(DOT, HDX)
+		));
+
+		assert!(EmaOracle::routed_oracles().is_empty());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		expect_events(vec![Event::RoutedOracleRemoved { assets: (HDX, DOT) }.into()]);
+	});
+}
+
+#[test]
+fn remove_routed_oracle_should_unreserve_deposit_of_depositor_when_called_by_authority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)));
+
+		assert_ok!(EmaOracle::remove_routed_oracle(RuntimeOrigin::root(), (HDX, DOT)));
+
+		assert!(EmaOracle::routed_oracles().is_empty());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn remove_routed_oracle_should_fail_when_called_by_other_user() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)));
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::root(), (HDX, 1)));
+
+		assert_noop!(
+			EmaOracle::remove_routed_oracle(RuntimeOrigin::signed(BOB), (HDX, DOT)),
+			Error::<Test>::NotDepositor
+		);
+		assert_noop!(
+			EmaOracle::remove_routed_oracle(RuntimeOrigin::signed(BOB), (HDX, 1)),
+			Error::<Test>::NotDepositor
+		);
+	});
+}
+
+#[test]
+fn remove_routed_oracle_should_fail_when_pair_is_not_registered() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_routed_oracle(RuntimeOrigin::root(), (HDX, DOT)),
+			Error::<Test>::OracleNotFound
+		);
+	});
+}
+
+#[test]
+fn routed_oracle_calls_should_fail_when_called_by_unsigned_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_routed_oracle(RuntimeOrigin::none(), (HDX, DOT)),
+			BadOrigin
+		);
+		assert_noop!(
+			EmaOracle::remove_routed_oracle(RuntimeOrigin::none(), (HDX, DOT)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn on_finalize_should_update_routed_oracle_from_spot_price() {
+	new_test_ext().execute_with(|| {
+		// pairs with insufficient asset are not whitelisted, but routed oracles bypass the whitelist
+		assert_ok!(EmaOracle::add_routed_oracle(
+			RuntimeOrigin::root(),
+			(HDX, INSUFFICIENT_ASSET)
+		));
+
+		System::set_block_number(5);
+		EmaOracle::on_initialize(5);
+		EmaOracle::on_finalize(5);
+
+		let expected = OracleEntry {
+			price: SPOT_PRICE,
+			volume: Volume::default(),
+			liquidity: Liquidity::default(),
+			updated_at: 5,
+		};
+		for period in supported_periods() {
+			assert_eq!(
+				get_routed_oracle_entry(HDX, INSUFFICIENT_ASSET, period),
+				Some(expected.clone())
+			);
+		}
+		assert_eq!(
+			Oracles::<Test>::get((SOURCE, ordered_pair(HDX, INSUFFICIENT_ASSET), LastBlock)),
+			None
+		);
+	});
+}
+
+#[test]
+fn remove_routed_oracle_should_remove_oracles() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_routed_oracle(RuntimeOrigin::signed(ALICE), (HDX, DOT)));
+
+		System::set_block_number(5);
+		EmaOracle::on_initialize(5);
+		EmaOracle::on_finalize(5);
+		assert!(get_routed_oracle_entry(HDX, DOT, LastBlock).is_some());
+
+		assert_ok!(EmaOracle::remove_routed_oracle(
+			RuntimeOrigin::signed(ALICE),
+			(HDX, DOT)
+		));
+
+		for period in supported_periods() {
+			assert_eq!(get_routed_oracle_entry(HDX, DOT, period), None);
+		}
+
+		System::set_block_number(6);
+		EmaOracle::on_initialize(6);
+		EmaOracle::on_finalize(6);
+		assert_eq!(get_routed_oracle_entry(HDX, DOT, LastBlock), None);
+	});
+}
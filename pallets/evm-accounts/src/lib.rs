@@ -45,6 +45,12 @@
 //! This pallet is also used to control which contracts are allowed to manage balances and tokens.
 //! `ApprovedContract` storage is used by the currencies precompile to determine whenever contract is allowed to transfer or not.
 //!
+//! ### ERC-20 allowances
+//! The currencies precompile exposes every registered asset as an ERC-20 token. Allowances granted through
+//! `approve` are kept in the `Allowances` storage, keyed by the token address, the owner and the spender.
+//! Allowances can also be granted by EIP-2612 `permit` signatures. Nonces of the permits are kept in the
+//! `PermitNonces` storage, keyed by the token address and the owner.
+//!
 //! ### Dispatchable Functions
 //!
 //! * `bind_evm_address` - Binds a Substrate address to EVM address.
@@ -124,6 +130,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type ApprovedContract<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, ()>;
 
+	/// ERC-20 allowances granted by an owner to a spender for a given token address.
+	#[pallet::storage]
+	pub(super) type Allowances<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, EvmAddress>,
+			NMapKey<Blake2_128Concat, EvmAddress>,
+			NMapKey<Blake2_128Concat, EvmAddress>,
+		),
+		Balance,
+		ValueQuery,
+	>;
+
+	/// EIP-2612 permit nonces of an owner for a given token address.
+	#[pallet::storage]
+	pub(super) type PermitNonces<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, EvmAddress, Blake2_128Concat, EvmAddress, U256, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -150,6 +174,8 @@ pub mod pallet {
 		BoundAddressCannotBeUsed,
 		/// Address not whitelisted
 		AddressNotWhitelisted,
+		/// Spender's allowance is lower than the requested amount
+		InsufficientAllowance,
 	}
 
 	#[pallet::hooks]
@@ -316,6 +342,49 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Returns the amount of `token` the `spender` is allowed to transfer on behalf of the `owner`.
+	pub fn allowance(token: EvmAddress, owner: EvmAddress, spender: EvmAddress) -> Balance {
+		Allowances::<T>::get((token, owner, spender))
+	}
+
+	/// Sets the allowance of the `spender` over the `owner`'s `token`. Zero amount removes the entry.
+	pub fn set_allowance(token: EvmAddress, owner: EvmAddress, spender: EvmAddress, amount: Balance) {
+		if amount == 0 {
+			Allowances::<T>::remove((token, owner, spender));
+		} else {
+			Allowances::<T>::insert((token, owner, spender), amount);
+		}
+	}
+
+	/// Decreases the allowance of the `spender` by `amount`.
+	/// Unlimited allowance (`Balance::MAX`) is never decreased.
+	pub fn spend_allowance(
+		token: EvmAddress,
+		owner: EvmAddress,
+		spender: EvmAddress,
+		amount: Balance,
+	) -> DispatchResult {
+		let allowance = Self::allowance(token, owner, spender);
+		if allowance == Balance::MAX {
+			return Ok(());
+		}
+
+		let remaining = allowance.checked_sub(amount).ok_or(Error::<T>::InsufficientAllowance)?;
+		Self::set_allowance(token, owner, spender, remaining);
+
+		Ok(())
+	}
+
+	/// Returns the nonce of the next EIP-2612 permit of the `owner` for the `token`.
+	pub fn permit_nonce(token: EvmAddress, owner: EvmAddress) -> U256 {
+		PermitNonces::<T>::get(token, owner)
+	}
+
+	/// Increases the permit nonce of the `owner` for the `token`, invalidating the signed permit.
+	pub fn increment_permit_nonce(token: EvmAddress, owner: EvmAddress) {
+		PermitNonces::<T>::mutate(token, owner, |nonce| *nonce = nonce.saturating_add(U256::one()));
+	}
+
 	fn _is_evm_account(account_id: &[u8; 32]) -> bool {
 		&account_id[0..4] == b"ETH\0" && account_id[24..32] == [0u8; 8]
 	}
@@ -199,3 +199,58 @@ fn renounce_contract_deployer_should_remove_address_from_the_storage() {
 		assert_ok!(EVMAccounts::renounce_contract_deployer(RuntimeOrigin::signed(ALICE)));
 	});
 }
+
+#[test]
+fn spend_allowance_should_decrease_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let token = H160::from(hex!["0000000000000000000000000000000100000000"]);
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let spender = H160::from(hex!["222222ff7Be76052e023Ec1a306fCca8F9659D80"]);
+		EVMAccounts::set_allowance(token, owner, spender, 100);
+
+		// Act
+		assert_ok!(EVMAccounts::spend_allowance(token, owner, spender, 40));
+
+		// Assert
+		assert_eq!(EVMAccounts::allowance(token, owner, spender), 60);
+		assert_noop!(
+			EVMAccounts::spend_allowance(token, owner, spender, 61),
+			Error::<Test>::InsufficientAllowance
+		);
+	});
+}
+
+#[test]
+fn spend_allowance_should_not_decrease_unlimited_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let token = H160::from(hex!["0000000000000000000000000000000100000000"]);
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let spender = H160::from(hex!["222222ff7Be76052e023Ec1a306fCca8F9659D80"]);
+		EVMAccounts::set_allowance(token, owner, spender, Balance::MAX);
+
+		// Act
+		assert_ok!(EVMAccounts::spend_allowance(token, owner, spender, 1_000));
+
+		// Assert
+		assert_eq!(EVMAccounts::allowance(token, owner, spender), Balance::MAX);
+	});
+}
+
+#[test]
+fn increment_permit_nonce_should_increase_nonce_of_owner_for_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let token = H160::from(hex!["0000000000000000000000000000000100000000"]);
+		let other_token = H160::from(hex!["0000000000000000000000000000000100000001"]);
+		let owner = EVMAccounts::evm_address(&ALICE);
+
+		// Act
+		EVMAccounts::increment_permit_nonce(token, owner);
+
+		// Assert
+		assert_eq!(EVMAccounts::permit_nonce(token, owner), U256::one());
+		assert_eq!(EVMAccounts::permit_nonce(other_token, owner), U256::zero());
+	});
+}
@@ -110,6 +110,7 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+					None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -170,6 +171,7 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+					None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -229,6 +231,7 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+					None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -288,6 +291,7 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+					None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -25,19 +25,22 @@ pub use crate::types::{Amount, AssetId, AssetPair, Balance};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
 	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	DispatchError, RuntimeDebug, TransactionOutcome,
 };
 use frame_support::{
 	dispatch,
 	dispatch::DispatchResult,
 	ensure,
+	storage::with_transaction,
 	traits::{EnsureOrigin, Get, LockIdentifier},
 	transactional,
 };
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::types::LBPWeight;
-use hydradx_traits::{AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
+use hydradx_traits::router::PoolType;
+use hydradx_traits::{AMMCreatePool, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 
 use scale_info::TypeInfo;
@@ -88,6 +91,9 @@ pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
+/// Lock Identifier for the shares received by the pool migration
+pub const MIGRATION_LOCK_ID: LockIdentifier = *b"lbpmigrt";
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
@@ -149,6 +155,14 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 	}
 }
 
+/// Migration of the remaining pool liquidity to an AMM pool executed when the sale ends.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PoolMigration<BlockNumber> {
+	/// period for which the shares of the new pool are locked in the pool owner's account, zero means no lock
+	pub lock_period: BlockNumber,
+}
+
 pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	fn calculate_weight(
 		weight_curve: WeightCurveType,
@@ -224,10 +238,41 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Handler called after each trade. Part of the fee used by the handler is not counted to collected fees.
+		type OnTradeExecuted: OnTradeExecuted<Self::AccountId, AssetId, Balance>;
+
+		/// AMM pool the remaining liquidity is migrated to when the sale ends.
+		type MigrationPool: AMMCreatePool<Self::AccountId, AssetId, Balance>;
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let now = T::BlockNumberProvider::current_block_number();
+
+			let mut reads: u64 = 0;
+			let mut ended = Vec::new();
+			for (pool_id, migration) in <PoolMigrations<T>>::iter() {
+				reads.saturating_inc();
+				if let Some(pool_data) = <PoolData<T>>::get(&pool_id) {
+					reads.saturating_inc();
+					if matches!(pool_data.end, Some(end) if end < now) {
+						ended.push((pool_id, pool_data, migration));
+					}
+				}
+			}
+
+			let migrated = ended.len() as u64;
+			for (pool_id, pool_data, migration) in ended {
+				Self::migrate_pool(pool_id, pool_data, migration, now);
+			}
+
+			T::DbWeight::get()
+				.reads(reads)
+				.saturating_add(<T as Config>::WeightInfo::migrate_pool().saturating_mul(migrated))
+		}
+
 		fn integrity_test() {
 			// The exponentiation used in the math can overflow for values smaller than 3
 			assert!(T::MaxInRatio::get() >= 3, "LBP: MaxInRatio is set to invalid value.");
@@ -306,6 +351,12 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Account has no shares locked by the pool migration
+		SharesNotLocked,
+
+		/// Lock period of the shares has not ended yet
+		SharesStillLocked,
 	}
 
 	#[pallet::event]
@@ -362,6 +413,21 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Remaining liquidity of the pool was migrated to a new AMM pool owned by the pool owner.
+		PoolMigrated {
+			pool: PoolId<T>,
+			owner: T::AccountId,
+			share_token: AssetId,
+			shares: Balance,
+			locked_until: Option<BlockNumberFor<T>>,
+		},
+
+		/// Migration of the pool failed. The pool is kept and its liquidity can be removed by the owner.
+		PoolMigrationFailed { pool: PoolId<T>, error: DispatchError },
+
+		/// Shares locked by the pool migration were unlocked.
+		SharesUnlocked { who: T::AccountId, share_token: AssetId },
 	}
 
 	/// Details of a pool.
@@ -376,6 +442,18 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Migrations of the remaining liquidity executed when the sale of the pool ends.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_migration)]
+	pub type PoolMigrations<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, PoolMigration<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Shares received by the pool migration and the block they are locked until.
+	#[pallet::storage]
+	#[pallet::getter(fn locked_shares)]
+	pub type LockedShares<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -411,6 +489,8 @@ pub mod pallet {
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
 		/// reached, fee will be increased to 20% and taken from the pool
+		/// - `migration`: Optional migration of the remaining liquidity to a new AMM pool owned by the pool owner
+		/// when the sale ends. Shares of the new pool can be locked for the given period.
 		///
 		/// Emits `PoolCreated` event when successful.
 		///
@@ -433,6 +513,7 @@ pub mod pallet {
 			fee: (u32, u32),
 			fee_collector: T::AccountId,
 			repay_target: Balance,
+			migration: Option<PoolMigration<BlockNumberFor<T>>>,
 		) -> DispatchResult {
 			T::CreatePoolOrigin::ensure_origin(origin)?;
 
@@ -483,6 +564,9 @@ pub mod pallet {
 
 			<PoolData<T>>::insert(&pool_id, &pool_data);
 			<FeeCollectorWithAsset<T>>::insert(fee_collector, asset_a, true);
+			if let Some(migration) = migration {
+				<PoolMigrations<T>>::insert(&pool_id, migration);
+			}
 
 			Self::deposit_event(Event::PoolCreated {
 				pool: pool_id.clone(),
@@ -679,28 +763,7 @@ pub mod pallet {
 
 			ensure!(!Self::is_pool_running(&pool_data), Error::<T>::SaleNotEnded);
 
-			let (asset_a, asset_b) = pool_data.assets;
-
-			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
-			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
-
-			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount_a)?;
-			T::MultiCurrency::transfer(asset_b, &pool_id, &who, amount_b)?;
-
-			if Self::collected_fees(&pool_data) > 0 {
-				T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
-			}
-
-			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
-			<PoolData<T>>::remove(&pool_id);
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: pool_id,
-				asset_a,
-				asset_b,
-				amount_a,
-				amount_b,
-			});
+			Self::do_remove_liquidity(pool_id, pool_data)?;
 
 			Ok(())
 		}
@@ -720,7 +783,9 @@ pub mod pallet {
 		///
 		/// Emits `SellExecuted` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::LBP))
+		)]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -750,7 +815,9 @@ pub mod pallet {
 		///
 		/// Emits `BuyExecuted` when successful.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::LBP))
+		)]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -764,10 +831,123 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Unlock shares received by the pool migration after the lock period has ended.
+		///
+		/// Parameters:
+		/// - `origin`: The account the shares are locked in.
+		/// - `share_token`: The share token of the pool the liquidity was migrated to.
+		///
+		/// Emits `SharesUnlocked` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_shares())]
+		pub fn unlock_shares(origin: OriginFor<T>, share_token: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let locked_until = <LockedShares<T>>::get(&who, share_token).ok_or(Error::<T>::SharesNotLocked)?;
+
+			ensure!(
+				locked_until <= T::BlockNumberProvider::current_block_number(),
+				Error::<T>::SharesStillLocked
+			);
+
+			T::MultiCurrency::remove_lock(MIGRATION_LOCK_ID, share_token, &who)?;
+			<LockedShares<T>>::remove(&who, share_token);
+
+			Self::deposit_event(Event::SharesUnlocked { who, share_token });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Transfer all the liquidity from the pool to the pool owner and remove the pool.
+	///
+	/// Returns amounts of the pool assets transferred.
+	fn do_remove_liquidity(
+		pool_id: PoolId<T>,
+		pool_data: Pool<T::AccountId, BlockNumberFor<T>>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let (asset_a, asset_b) = pool_data.assets;
+
+		let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
+		let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
+
+		T::MultiCurrency::transfer(asset_a, &pool_id, &pool_data.owner, amount_a)?;
+		T::MultiCurrency::transfer(asset_b, &pool_id, &pool_data.owner, amount_b)?;
+
+		if Self::collected_fees(&pool_data) > 0 {
+			T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
+		}
+
+		<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
+		<PoolData<T>>::remove(&pool_id);
+		<PoolMigrations<T>>::remove(&pool_id);
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: pool_id,
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+		});
+
+		Ok((amount_a, amount_b))
+	}
+
+	/// Migrate remaining liquidity of the pool to a new AMM pool owned by the pool owner.
+	///
+	/// The pool is kept untouched if the migration fails. Migration is not retried.
+	fn migrate_pool(
+		pool_id: PoolId<T>,
+		pool_data: Pool<T::AccountId, BlockNumberFor<T>>,
+		migration: PoolMigration<BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) {
+		let result = with_transaction(|| {
+			let owner = pool_data.owner.clone();
+			let (asset_a, asset_b) = pool_data.assets;
+
+			let migrate = || -> Result<(AssetId, Balance, Option<BlockNumberFor<T>>), DispatchError> {
+				let (amount_a, amount_b) = Self::do_remove_liquidity(pool_id.clone(), pool_data)?;
+
+				let (share_token, shares) =
+					T::MigrationPool::create_pool(owner.clone(), asset_a, amount_a, asset_b, amount_b)?;
+
+				let locked_until = if migration.lock_period.is_zero() {
+					None
+				} else {
+					let locked_until = now.saturating_add(migration.lock_period);
+					T::MultiCurrency::set_lock(MIGRATION_LOCK_ID, share_token, &owner, shares)?;
+					<LockedShares<T>>::insert(&owner, share_token, locked_until);
+					Some(locked_until)
+				};
+
+				Ok((share_token, shares, locked_until))
+			};
+
+			match migrate() {
+				Ok(r) => TransactionOutcome::Commit(Ok((owner, r))),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		});
+
+		match result {
+			Ok((owner, (share_token, shares, locked_until))) => Self::deposit_event(Event::PoolMigrated {
+				pool: pool_id,
+				owner,
+				share_token,
+				shares,
+				locked_until,
+			}),
+			Err(error) => {
+				<PoolMigrations<T>>::remove(&pool_id);
+				Self::deposit_event(Event::PoolMigrationFailed { pool: pool_id, error });
+			}
+		}
+	}
+
 	fn calculate_weights(
 		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
 		at: BlockNumberFor<T>,
@@ -881,6 +1061,9 @@ impl<T: Config> Pallet<T> {
 		let pool_account = Self::get_pair_id(transfer.assets);
 		let pool = <PoolData<T>>::try_get(&pool_account).map_err(|_| Error::<T>::PoolNotFound)?;
 
+		let reserve_in = T::MultiCurrency::free_balance(transfer.assets.asset_in, &pool_account);
+		let reserve_out = T::MultiCurrency::free_balance(transfer.assets.asset_out, &pool_account);
+
 		// Transfer assets between pool and user
 		T::MultiCurrency::transfer(
 			transfer.assets.asset_in,
@@ -905,8 +1088,23 @@ impl<T: Config> Pallet<T> {
 
 		T::MultiCurrency::transfer(fee_asset, fee_payer, &pool.fee_collector, fee_amount)?;
 
+		let used = T::OnTradeExecuted::on_trade_executed(&TradeInfo {
+			pool: PoolType::LBP,
+			trader: transfer.origin.clone(),
+			fee_account: pool.fee_collector.clone(),
+			asset_in: transfer.assets.asset_in,
+			asset_out: transfer.assets.asset_out,
+			amount_in: transfer.amount,
+			amount_out: transfer.amount_b,
+			reserve_in,
+			reserve_out,
+			fee_asset,
+			fee_amount,
+			protocol_fee: Balance::zero(),
+		})?;
+
 		// Resets lock for total of collected fees
-		let collected_fee_total = Self::collected_fees(&pool) + fee_amount;
+		let collected_fee_total = Self::collected_fees(&pool) + fee_amount.saturating_sub(used);
 		T::MultiCurrency::set_lock(COLLECTOR_LOCK_ID, fee_asset, &pool.fee_collector, collected_fee_total)?;
 
 		Ok(())
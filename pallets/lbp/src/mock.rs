@@ -15,6 +15,7 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub type Amount = i128;
@@ -43,6 +44,9 @@ pub const KUSD_BSX_POOL_ID: AccountId = 2_003_000;
 
 pub const DEFAULT_FEE: (u32, u32) = (2, 1_000);
 
+pub const MIGRATION_POOL_ID: AccountId = 1_000_000;
+pub const MIGRATION_SHARE_TOKEN: AssetId = 5_000;
+
 pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
 	owner: ALICE,
 	start: SALE_START,
@@ -175,6 +179,32 @@ impl LockedBalance<AssetId, AccountId, Balance> for MultiLockedBalance {
 	}
 }
 
+thread_local! {
+	pub static MIGRATION_POOL_CREATION_FAILS: RefCell<bool> = const { RefCell::new(false) };
+}
+
+pub struct MigrationPoolMock;
+
+impl AMMCreatePool<AccountId, AssetId, Balance> for MigrationPoolMock {
+	fn create_pool(
+		who: AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError> {
+		if MIGRATION_POOL_CREATION_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("Pool cannot be created"));
+		}
+
+		Currency::transfer(asset_a, &who, &MIGRATION_POOL_ID, amount_a)?;
+		Currency::transfer(asset_b, &who, &MIGRATION_POOL_ID, amount_b)?;
+		Currency::deposit(MIGRATION_SHARE_TOKEN, &who, amount_a)?;
+
+		Ok((MIGRATION_SHARE_TOKEN, amount_a))
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currency;
@@ -188,6 +218,8 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type OnTradeExecuted = ();
+	type MigrationPool = MigrationPoolMock;
 }
 
 pub struct ExtBuilder {
@@ -196,6 +228,8 @@ pub struct ExtBuilder {
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
+		MIGRATION_POOL_CREATION_FAILS.with(|v| *v.borrow_mut() = false);
+
 		Self {
 			endowed_accounts: vec![
 				(ALICE, HDX, INITIAL_BALANCE),
@@ -217,6 +251,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_failing_migration_pool(self) -> Self {
+		MIGRATION_POOL_CREATION_FAILS.with(|v| *v.borrow_mut() = true);
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
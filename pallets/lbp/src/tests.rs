@@ -55,6 +55,7 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -122,6 +123,7 @@ pub fn predefined_test_ext_with_repay_target() -> sp_io::TestExternalities {
 			DEFAULT_FEE,
 			CHARLIE,
 			initial_liquidity,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -165,6 +167,7 @@ pub fn start_50_50_lbp_without_fee_and_repay_target(
 		(0, 1),
 		CHARLIE,
 		0,
+		None,
 	));
 
 	let pool_id = LBPPallet::get_pair_id(AssetPair { asset_in, asset_out });
@@ -374,6 +377,7 @@ fn create_pool_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
@@ -429,6 +433,7 @@ fn create_pool_from_basic_origin_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			BadOrigin
 		);
@@ -451,6 +456,7 @@ fn create_same_pool_should_not_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_noop!(
@@ -467,6 +473,7 @@ fn create_same_pool_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::PoolAlreadyExists
 		);
@@ -499,6 +506,7 @@ fn create_pool_with_same_assets_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::CannotCreatePoolWithSameAssets
 		);
@@ -521,6 +529,7 @@ fn create_pool_with_non_existing_fee_collector_with_asset_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::create_pool(
@@ -536,6 +545,7 @@ fn create_pool_with_non_existing_fee_collector_with_asset_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		),);
 	});
 }
@@ -556,6 +566,7 @@ fn create_pool_with_existing_fee_collector_with_asset_should_not_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_noop!(
@@ -572,6 +583,7 @@ fn create_pool_with_existing_fee_collector_with_asset_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::FeeCollectorWithAssetAlreadyUsed
 		);
@@ -595,6 +607,7 @@ fn create_pool_with_insufficient_liquidity_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::InsufficientLiquidity
 		);
@@ -613,6 +626,7 @@ fn create_pool_with_insufficient_liquidity_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::InsufficientLiquidity
 		);
@@ -631,6 +645,7 @@ fn create_pool_with_insufficient_liquidity_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::InsufficientLiquidity
 		);
@@ -654,6 +669,7 @@ fn create_pool_with_insufficient_balance_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::InsufficientAssetBalance
 		);
@@ -1080,6 +1096,7 @@ fn update_pool_with_existing_fee_collector_should_not_work() {
 			DEFAULT_FEE,
 			BOB,
 			0,
+			None,
 		));
 
 		assert_noop!(
@@ -1116,6 +1133,7 @@ fn update_pool_interval_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		set_block_number(15);
@@ -1537,6 +1555,7 @@ fn remove_liquidity_from_not_started_pool_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		let user_balance_a_before = Currency::free_balance(HDX, &ALICE);
@@ -1914,6 +1933,7 @@ fn zero_weight_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::InvalidWeight
 		);
@@ -2308,6 +2328,7 @@ fn buy_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		let pool_data1 = LBPPallet::pool_data(pool_id2).unwrap();
@@ -2535,6 +2556,7 @@ fn sell_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 		let pool_data1 = LBPPallet::pool_data(pool_id2).unwrap();
 
@@ -2832,6 +2854,7 @@ fn zero_fee_should_work() {
 			(0, 100),
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -2871,6 +2894,7 @@ fn invalid_fee_should_not_work() {
 				(10, 0),
 				CHARLIE,
 				0,
+				None,
 			),
 			Error::<Test>::FeeAmountInvalid
 		);
@@ -2971,6 +2995,7 @@ fn amm_trait_should_work() {
 			(400, 1_000),
 			CHARLIE,
 			0,
+			None,
 		));
 
 		let pool_id = LBPPallet::get_pair_id(AssetPair {
@@ -3000,6 +3025,7 @@ fn get_spot_price_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3154,6 +3180,7 @@ fn simulate_lbp_event_should_work() {
 			fee,
 			fee_collector,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3442,6 +3469,7 @@ fn validate_trade_should_not_work() {
 			(10, 1),
 			CHARLIE,
 			0,
+			None,
 		));
 		let pool_id2 = LBPPallet::get_pair_id(AssetPair {
 			asset_in: KUSD,
@@ -3516,6 +3544,7 @@ fn calculate_fees_should_work() {
 			(10, 1),
 			CHARLIE,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3566,6 +3595,7 @@ fn can_create_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
 		));
 		// pool is not initialized
 		assert!(!DisallowWhenLBPPoolRunning::<Test>::can_create(
@@ -3783,6 +3813,7 @@ fn simulate_lbp_event_with_repayment() {
 			fee,
 			fee_collector,
 			0,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3863,6 +3894,238 @@ fn simulate_lbp_event_with_repayment() {
 		assert_eq!(Currency::free_balance(sold_asset, &fee_collector), 0);
 	});
 }
+mod pool_migration {
+	use super::*;
+	use crate::mock::{MIGRATION_POOL_ID, MIGRATION_SHARE_TOKEN};
+	use frame_support::traits::Hooks;
+
+	fn create_pool_with_migration(lock_period: u64) {
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+			Some(PoolMigration { lock_period }),
+		));
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+	}
+
+	fn migrate_at(n: u64) {
+		set_block_number(n);
+		LBPPallet::on_initialize(n);
+	}
+
+	#[test]
+	fn create_pool_should_store_migration_when_provided() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(100);
+
+			assert_eq!(
+				LBPPallet::pool_migration(KUSD_BSX_POOL_ID),
+				Some(PoolMigration { lock_period: 100 })
+			);
+		});
+	}
+
+	#[test]
+	fn on_initialize_should_migrate_pool_when_sale_ended() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(0);
+			let kusd_before = Currency::free_balance(KUSD, &ALICE);
+			let bsx_before = Currency::free_balance(BSX, &ALICE);
+
+			migrate_at(SALE_END.unwrap() + 1);
+
+			assert!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).is_none());
+			assert!(LBPPallet::pool_migration(KUSD_BSX_POOL_ID).is_none());
+
+			assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 0);
+			assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 0);
+			assert_eq!(Currency::free_balance(KUSD, &MIGRATION_POOL_ID), 1_000_000_000);
+			assert_eq!(Currency::free_balance(BSX, &MIGRATION_POOL_ID), 2_000_000_000);
+			assert_eq!(Currency::free_balance(KUSD, &ALICE), kusd_before);
+			assert_eq!(Currency::free_balance(BSX, &ALICE), bsx_before);
+			assert_eq!(Currency::free_balance(MIGRATION_SHARE_TOKEN, &ALICE), 1_000_000_000);
+			assert_eq!(LBPPallet::locked_shares(ALICE, MIGRATION_SHARE_TOKEN), None);
+
+			expect_events(vec![
+				Event::LiquidityRemoved {
+					who: KUSD_BSX_POOL_ID,
+					asset_a: KUSD,
+					asset_b: BSX,
+					amount_a: 1_000_000_000,
+					amount_b: 2_000_000_000,
+				}
+				.into(),
+				Event::PoolMigrated {
+					pool: KUSD_BSX_POOL_ID,
+					owner: ALICE,
+					share_token: MIGRATION_SHARE_TOKEN,
+					shares: 1_000_000_000,
+					locked_until: None,
+				}
+				.into(),
+			]);
+		});
+	}
+
+	#[test]
+	fn on_initialize_should_not_migrate_pool_when_sale_is_running() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(0);
+
+			migrate_at(SALE_END.unwrap());
+
+			assert!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).is_some());
+			assert!(LBPPallet::pool_migration(KUSD_BSX_POOL_ID).is_some());
+			assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+		});
+	}
+
+	#[test]
+	fn on_initialize_should_not_migrate_pool_when_migration_is_not_set() {
+		predefined_test_ext().execute_with(|| {
+			migrate_at(SALE_END.unwrap() + 1);
+
+			assert!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).is_some());
+			assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+		});
+	}
+
+	#[test]
+	fn on_initialize_should_lock_shares_when_lock_period_is_set() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(100);
+
+			migrate_at(SALE_END.unwrap() + 1);
+
+			let locked_until = SALE_END.unwrap() + 101;
+			assert_eq!(
+				LBPPallet::locked_shares(ALICE, MIGRATION_SHARE_TOKEN),
+				Some(locked_until)
+			);
+			assert_noop!(
+				Currency::transfer(MIGRATION_SHARE_TOKEN, &ALICE, &BOB, 1),
+				orml_tokens::Error::<Test>::LiquidityRestrictions
+			);
+
+			expect_events(vec![Event::PoolMigrated {
+				pool: KUSD_BSX_POOL_ID,
+				owner: ALICE,
+				share_token: MIGRATION_SHARE_TOKEN,
+				shares: 1_000_000_000,
+				locked_until: Some(locked_until),
+			}
+			.into()]);
+		});
+	}
+
+	#[test]
+	fn on_initialize_should_keep_pool_when_migration_fails() {
+		ExtBuilder::default()
+			.with_failing_migration_pool()
+			.build()
+			.execute_with(|| {
+				set_block_number(1);
+				create_pool_with_migration(0);
+
+				migrate_at(SALE_END.unwrap() + 1);
+
+				assert!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).is_some());
+				assert!(LBPPallet::pool_migration(KUSD_BSX_POOL_ID).is_none());
+				assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+				assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 2_000_000_000);
+
+				expect_events(vec![Event::PoolMigrationFailed {
+					pool: KUSD_BSX_POOL_ID,
+					error: DispatchError::Other("Pool cannot be created"),
+				}
+				.into()]);
+
+				assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+			});
+	}
+
+	#[test]
+	fn remove_liquidity_should_remove_migration() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(0);
+			run_to_sale_end();
+
+			assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+			assert!(LBPPallet::pool_migration(KUSD_BSX_POOL_ID).is_none());
+		});
+	}
+
+	#[test]
+	fn unlock_shares_should_work_when_lock_period_ended() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(100);
+			migrate_at(SALE_END.unwrap() + 1);
+
+			set_block_number(SALE_END.unwrap() + 101);
+			assert_ok!(LBPPallet::unlock_shares(Origin::signed(ALICE), MIGRATION_SHARE_TOKEN));
+
+			assert_eq!(LBPPallet::locked_shares(ALICE, MIGRATION_SHARE_TOKEN), None);
+			assert_ok!(Currency::transfer(MIGRATION_SHARE_TOKEN, &ALICE, &BOB, 1_000_000_000));
+
+			expect_events(vec![Event::SharesUnlocked {
+				who: ALICE,
+				share_token: MIGRATION_SHARE_TOKEN,
+			}
+			.into()]);
+		});
+	}
+
+	#[test]
+	fn unlock_shares_should_fail_when_lock_period_is_running() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(100);
+			migrate_at(SALE_END.unwrap() + 1);
+
+			set_block_number(SALE_END.unwrap() + 100);
+			assert_noop!(
+				LBPPallet::unlock_shares(Origin::signed(ALICE), MIGRATION_SHARE_TOKEN),
+				Error::<Test>::SharesStillLocked
+			);
+		});
+	}
+
+	#[test]
+	fn unlock_shares_should_fail_when_shares_are_not_locked() {
+		new_test_ext().execute_with(|| {
+			create_pool_with_migration(100);
+			migrate_at(SALE_END.unwrap() + 1);
+
+			assert_noop!(
+				LBPPallet::unlock_shares(Origin::signed(BOB), MIGRATION_SHARE_TOKEN),
+				Error::<Test>::SharesNotLocked
+			);
+		});
+	}
+}
+
 mod spot_price_calculation {
 	use super::*;
 	use crate::mock::RuntimeOrigin;
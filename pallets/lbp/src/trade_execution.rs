@@ -4,7 +4,7 @@ use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::BlockNumberProvider;
 use sp_runtime::DispatchError::Corruption;
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
 
@@ -195,4 +195,29 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 
 		Ok(spot_price_with_fee)
 	}
+
+	fn calculate_fee(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<Permill, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::LBP {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let pool_id = Self::get_pair_id(AssetPair { asset_in, asset_out });
+		let pool_data =
+			<PoolData<T>>::try_get(&pool_id).map_err(|_| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+		let (numerator, denominator) = if Self::is_repay_fee_applied(&pool_data) {
+			Self::repay_fee()
+		} else {
+			pool_data.fee
+		};
+		if denominator == 0 {
+			return Ok(Permill::zero());
+		}
+
+		Ok(Permill::from_rational(numerator, denominator))
+	}
 }
@@ -60,6 +60,8 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_buy() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn migrate_pool() -> Weight;
+	fn unlock_shares() -> Weight;
 }
 
 /// Weights for pallet_lbp using the hydraDX node and recommended hardware.
@@ -300,4 +302,38 @@ impl WeightInfo for () {
 		Weight::from_parts(25_559_000, 6156)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 	}
+	/// Storage: `LBP::PoolMigrations` (r:1 w:1)
+	/// Proof: `LBP::PoolMigrations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:2 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ShareToken` (r:1 w:1)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::LockedShares` (r:0 w:1)
+	/// Proof: `LBP::LockedShares` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn migrate_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6357`
+		//  Estimated: `19071`
+		// Minimum execution time: 631_859_000 picoseconds.
+		Weight::from_parts(651_402_000, 19071)
+			.saturating_add(RocksDbWeight::get().reads(51_u64))
+			.saturating_add(RocksDbWeight::get().writes(32_u64))
+	}
+	/// Storage: `LBP::LockedShares` (r:1 w:1)
+	/// Proof: `LBP::LockedShares` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	fn unlock_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1575`
+		//  Estimated: `4726`
+		// Minimum execution time: 42_406_000 picoseconds.
+		Weight::from_parts(43_718_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -371,6 +371,9 @@ impl pallet_asset_registry::Config for Test {
 	type SequentialIdStartAt = SequentialIdOffset;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type SufficiencyChangeHook = ();
+	type MaxHoldersForParameterChange = frame_support::traits::ConstU32<0>;
+	type MaxAliases = frame_support::traits::ConstU32<0>;
 	type WeightInfo = ();
 }
 
@@ -393,10 +396,23 @@ impl pallet_omnipool::Config for Test {
 	type CollectionId = u32;
 	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type OmnipoolHooks = ();
+	type OnTradeExecuted = ();
 	type PriceBarrier = ();
 	type MinWithdrawalFee = ();
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = ();
+	type WithdrawalStreamBlocks = ();
+	type MaxQueuedWithdrawals = ();
+	type MaxQueuedWithdrawalsPerAccount = ();
+	type MaxHubAssetBuyPerBlock = ();
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
 }
 
 pub struct DummyNFT;
@@ -60,7 +60,9 @@
 //! shares are returned back to user.
 //! * `YieldFarm` -  can be in the 3 states: [`Active`, `Stopped`, `Terminated`]
 //!     * `Active` - liquidity mining is running, users are able to deposit, claim and withdraw LP
-//!     shares. `YieldFarm` is rewarded from `GlobalFarm` in this state.
+//!     shares. `YieldFarm` is rewarded from `GlobalFarm` in this state. Owner can set a value cap
+//!     limiting total valued shares eligible for rewards. Deposits over the cap are accepted but
+//!     their excess value earns no rewards.
 //!     * `Stopped` - liquidity mining is stopped. Users can claim and withdraw LP shares from the
 //!     farm. Users CAN'T deposit new LP shares to stopped farm. Stopped farm is not rewarded from the
 //!     `GlobalFarm`.
@@ -73,7 +75,8 @@
 //!     Note: Terminated farm CAN'T be resumed.
 //! * `GlobalFarm` - can be in the 2 states: [`Active`, `Terminated`]
 //!     * `Active` - liquidity mining program is running, new yield farms can be added to the
-//!     global farm.
+//!     global farm. Owner can top up rewards and extend planned yielding periods of the farm
+//!     without affecting its yield farms and deposits.
 //!     * `Terminated` - liquidity mining program is ended. Yield farms can't be added to the global
 //!     farm. Global farm MUST be empty(all yield farms in the global farm must be destroyed)
 //!     before it can be destroyed. Destroying global farm transfer undistributed rewards to farm's
@@ -112,7 +115,7 @@ use frame_support::{
 };
 
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::ArithmeticError;
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, ArithmeticError, Rounding};
 
 use hydra_dx_math::liquidity_mining as math;
 use hydradx_traits::{liquidity_mining::PriceAdjustment, pools::DustRemovalAccountWhitelist, registry::Inspect};
@@ -121,7 +124,7 @@ use scale_info::TypeInfo;
 use sp_arithmetic::{
 	fixed_point::FixedU128,
 	traits::{CheckedAdd, CheckedDiv, CheckedSub},
-	Perquintill,
+	FixedPointNumber, Perquintill,
 };
 use sp_std::{
 	convert::{From, Into, TryInto},
@@ -153,6 +156,11 @@ pub mod pallet {
 				"`T::MaxFarmEntriesPerDeposit` must be greater or equal to 1"
 			);
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), DispatchError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -318,6 +326,12 @@ pub mod pallet {
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
+
+		/// Top-up amount and extension can't be both 0.
+		InvalidTopUp,
+
+		/// Yield farm's value cap can't be 0.
+		InvalidValueCap,
 	}
 
 	//NOTE: these errors should never happen.
@@ -421,6 +435,13 @@ pub mod pallet {
 	pub type ActiveYieldFarm<T: Config<I>, I: 'static = ()> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AmmPoolId, Blake2_128Concat, GlobalFarmId, YieldFarmId>;
 
+	/// Max. value of valued shares eligible for rewards in the yield farm. Value is denominated
+	/// in global farm's `reward_currency`. No limit is applied if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn yield_farm_value_cap)]
+	pub type YieldFarmValueCap<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, YieldFarmId, Balance, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -441,6 +462,15 @@ pub mod pallet {
 
 		/// Global farm has no more rewards to distribute in the moment.
 		AllRewardsDistributed { global_farm_id: GlobalFarmId },
+
+		/// Valued shares of the deposit exceed yield farm's value cap. Only eligible part of the
+		/// valued shares is rewarded.
+		DepositValueCapped {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			valued_shares: Balance,
+			eligible_valued_shares: Balance,
+		},
 	}
 
 	#[pallet::call]
@@ -620,6 +650,73 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Add rewards to the existing global farm and extend its planned yielding periods.
+	///
+	/// Only farm's owner can perform this action.
+	///
+	/// Number of periods left to distribute is derived from undistributed rewards and current
+	/// max reward per period. Max reward per period is recalculated so that undistributed rewards
+	/// together with `amount` are distributed in periods left plus `extension`.
+	///
+	/// Returns: `(new max reward per period)`
+	///
+	/// Parameters:
+	/// - `who`: farm's owner.
+	/// - `global_farm_id`: global farm id.
+	/// - `amount`: amount of `reward_currency` transferred from `who` to the farm account.
+	/// - `extension`: number of periods to add to farm's planned yielding periods.
+	#[require_transactional]
+	fn top_up_global_farm(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Balance,
+		extension: PeriodOf<T>,
+	) -> Result<Balance, DispatchError> {
+		ensure!(!amount.is_zero() || !extension.is_zero(), Error::<T, I>::InvalidTopUp);
+
+		<GlobalFarm<T, I>>::try_mutate(global_farm_id, |maybe_global_farm| {
+			let global_farm = maybe_global_farm.as_mut().ok_or(Error::<T, I>::GlobalFarmNotFound)?;
+
+			ensure!(global_farm.state.is_active(), Error::<T, I>::GlobalFarmNotFound);
+
+			ensure!(who == global_farm.owner, Error::<T, I>::Forbidden);
+
+			T::MultiCurrency::ensure_can_withdraw(global_farm.reward_currency, &who, amount)
+				.map_err(|_| Error::<T, I>::InsufficientRewardCurrencyBalance)?;
+
+			let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+			Self::sync_global_farm(global_farm, current_period)?;
+
+			let global_farm_account = Self::farm_account_id(global_farm.id)?;
+			let left_to_distribute = T::MultiCurrency::free_balance(global_farm.reward_currency, &global_farm_account);
+			let periods_left = left_to_distribute
+				.checked_div(global_farm.max_reward_per_period)
+				.unwrap_or_default();
+
+			let extension_periods = TryInto::<u128>::try_into(extension).map_err(|_| ArithmeticError::Overflow)?;
+			let total_rewards = left_to_distribute
+				.checked_add(amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			let new_max_reward_period = total_rewards
+				.checked_div(periods_left.saturating_add(extension_periods))
+				.ok_or(Error::<T, I>::InvalidPlannedYieldingPeriods)?;
+			ensure!(
+				!new_max_reward_period.is_zero(),
+				Error::<T, I>::InvalidPlannedYieldingPeriods
+			);
+
+			global_farm.planned_yielding_periods = global_farm
+				.planned_yielding_periods
+				.checked_add(&extension)
+				.ok_or(ArithmeticError::Overflow)?;
+			global_farm.max_reward_per_period = new_max_reward_period;
+
+			T::MultiCurrency::transfer(global_farm.reward_currency, &who, &global_farm_account, amount)?;
+
+			Ok(new_max_reward_period)
+		})
+	}
+
 	/// Terminate existing liquidity mining program. Undistributed rewards are transferred to
 	/// owner(`who`).
 	///
@@ -815,6 +912,67 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Update yield farm's value cap.
+	///
+	/// Only farm owner can perform this action.
+	///
+	/// Deposits are accepted also when the cap is reached, but only valued shares fitting under
+	/// the cap at the time of the deposit are rewarded. Existing farm entries are not affected
+	/// by the change of the cap.
+	///
+	/// Returns: `(YieldFarmId)`
+	///
+	/// Parameters:
+	/// - `who`: farm's owner.
+	/// - `global_farm_id`: global farm id in which yield farm will be updated.
+	/// - `amm_pool_id`: id of the AMM pool identifying yield farm in global farm.
+	/// - `cap`: max. value of valued shares in `reward_currency`. `None` removes the limit.
+	fn update_yield_farm_value_cap(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		amm_pool_id: T::AmmPoolId,
+		cap: Option<Balance>,
+	) -> Result<YieldFarmId, DispatchError> {
+		ensure!(cap != Some(Zero::zero()), Error::<T, I>::InvalidValueCap);
+
+		let yield_farm_id =
+			Self::active_yield_farm(amm_pool_id, global_farm_id).ok_or(Error::<T, I>::YieldFarmNotFound)?;
+
+		let global_farm = Self::global_farm(global_farm_id)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::GlobalFarmNotFound.into())?;
+
+		ensure!(who == global_farm.owner, Error::<T, I>::Forbidden);
+
+		<YieldFarmValueCap<T, I>>::set(yield_farm_id, cap);
+
+		Ok(yield_farm_id)
+	}
+
+	/// Returns part of `valued_shares` eligible for rewards in the yield farm with respect to
+	/// yield farm's value cap.
+	fn eligible_valued_shares(
+		global_farm: &GlobalFarmData<T, I>,
+		yield_farm: &YieldFarmData<T, I>,
+		valued_shares: Balance,
+	) -> Result<Balance, DispatchError> {
+		let Some(cap) = Self::yield_farm_value_cap(yield_farm.id) else {
+			return Ok(valued_shares);
+		};
+
+		//NOTE: `cap` is in `reward_currency`, `price_adjustment` converts valued shares to it.
+		let max_valued_shares = multiply_by_rational_with_rounding(
+			cap,
+			FixedU128::accuracy(),
+			global_farm.price_adjustment.into_inner(),
+			Rounding::Down,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(max_valued_shares
+			.saturating_sub(yield_farm.total_valued_shares)
+			.min(valued_shares))
+	}
+
 	/// Stop liquidity mining for specific yield farm.
 	///
 	/// This function claims rewards from `GlobalFarm` for the last time and stops yield farm
@@ -1048,6 +1206,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					if yield_farm.can_be_removed() {
 						global_farm.decrease_total_yield_farm_count()?;
 
+						<YieldFarmValueCap<T, I>>::remove(yield_farm.id);
 						*maybe_yield_farm = None;
 					}
 
@@ -1387,6 +1546,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							if yield_farm.can_be_removed() {
 								global_farm.decrease_total_yield_farm_count()?;
 
+								<YieldFarmValueCap<T, I>>::remove(yield_farm.id);
 								*maybe_yield_farm = None;
 							}
 
@@ -1470,6 +1630,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Error::<T, I>::IncorrectValuedShares
 					);
 
+					let eligible_valued_shares = Self::eligible_valued_shares(global_farm, yield_farm, valued_shares)?;
+					if eligible_valued_shares < valued_shares {
+						Self::deposit_event(Event::DepositValueCapped {
+							global_farm_id: global_farm.id,
+							yield_farm_id: yield_farm.id,
+							valued_shares,
+							eligible_valued_shares,
+						});
+					}
+					let valued_shares = eligible_valued_shares;
+
 					let deposit_stake_in_global_farm =
 						math::calculate_global_farm_shares(valued_shares, yield_farm.multiplier)
 							.map_err(|_| ArithmeticError::Overflow)?;
@@ -1797,6 +1968,74 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		defensive!(e);
 		e
 	}
+
+	/// Check consistency of farms with deposits entered into them.
+	///
+	/// - yield farm's `total_shares`, `total_valued_shares` and `entries_count` equal the sums of
+	/// the deposits' entries in the farm
+	/// - global farm's yield farm counters equal the number of its yield farms in the storage
+	/// - active yield farms are registered in `ActiveYieldFarm`
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), DispatchError> {
+		use sp_std::collections::btree_map::BTreeMap;
+
+		// yield farm id -> (shares, valued shares, entries count)
+		let mut entries: BTreeMap<YieldFarmId, (Balance, Balance, u64)> = BTreeMap::new();
+		for (_, deposit) in Deposit::<T, I>::iter() {
+			for entry in deposit.yield_farm_entries.iter() {
+				let sums = entries.entry(entry.yield_farm_id).or_default();
+				sums.0 = sums.0.saturating_add(deposit.shares);
+				sums.1 = sums.1.saturating_add(entry.valued_shares);
+				sums.2 = sums.2.saturating_add(1);
+			}
+		}
+
+		// global farm id -> (live yield farms, total yield farms)
+		let mut yield_farms: BTreeMap<GlobalFarmId, (u32, u32)> = BTreeMap::new();
+		for ((amm_pool_id, global_farm_id, yield_farm_id), yield_farm) in YieldFarm::<T, I>::iter() {
+			let (shares, valued_shares, entries_count) = entries.remove(&yield_farm_id).unwrap_or_default();
+			ensure!(
+				yield_farm.total_shares == shares,
+				"Yield farm's total shares don't equal the sum of deposited shares"
+			);
+			ensure!(
+				yield_farm.total_valued_shares == valued_shares,
+				"Yield farm's total valued shares don't equal the sum of entries' valued shares"
+			);
+			ensure!(
+				yield_farm.entries_count == entries_count,
+				"Yield farm's entries count doesn't equal the number of entries"
+			);
+
+			let counts = yield_farms.entry(global_farm_id).or_default();
+			if !yield_farm.state.is_terminated() {
+				counts.0 = counts.0.saturating_add(1);
+			}
+			counts.1 = counts.1.saturating_add(1);
+
+			ensure!(
+				!yield_farm.state.is_active()
+					|| Self::active_yield_farm(amm_pool_id, global_farm_id) == Some(yield_farm_id),
+				"Active yield farm is not registered as active"
+			);
+		}
+		ensure!(entries.is_empty(), "Deposit is entered into non-existent yield farm");
+
+		for (global_farm_id, global_farm) in GlobalFarm::<T, I>::iter() {
+			let (live, total) = yield_farms.remove(&global_farm_id).unwrap_or_default();
+			ensure!(
+				global_farm.live_yield_farms_count == live,
+				"Global farm's live yield farms count doesn't equal the number of live yield farms"
+			);
+			ensure!(
+				global_farm.total_yield_farms_count == total,
+				"Global farm's total yield farms count doesn't equal the number of yield farms"
+			);
+		}
+		ensure!(yield_farms.is_empty(), "Yield farm of non-existent global farm");
+
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::AccountId, T::AssetId, BlockNumberFor<T>>
@@ -1876,6 +2115,15 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 		Self::update_global_farm(global_farm_id, planned_yielding_periods, yield_per_period, min_deposit)
 	}
 
+	fn top_up_global_farm(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Self::Balance,
+		extension: Self::Period,
+	) -> Result<Self::Balance, Self::Error> {
+		Self::top_up_global_farm(who, global_farm_id, amount, extension)
+	}
+
 	fn terminate_global_farm(
 		who: T::AccountId,
 		global_farm_id: u32,
@@ -1903,6 +2151,15 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 		Self::update_yield_farm_multiplier(who, global_farm_id, amm_pool_id, multiplier)
 	}
 
+	fn update_yield_farm_value_cap(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		amm_pool_id: Self::AmmPoolId,
+		cap: Option<Self::Balance>,
+	) -> Result<YieldFarmId, Self::Error> {
+		Self::update_yield_farm_value_cap(who, global_farm_id, amm_pool_id, cap)
+	}
+
 	fn stop_yield_farm(
 		who: T::AccountId,
 		global_farm_id: GlobalFarmId,
@@ -260,10 +260,13 @@ pub mod stop_yield_farm;
 pub mod terminate_global_farm;
 pub mod terminate_yield_farm;
 pub mod test_ext;
+pub mod top_up_global_farm;
 
 pub mod lm_with_oracle;
 #[allow(clippy::module_inception)]
 pub mod tests;
+pub mod try_state;
 pub mod update_global_farm;
 pub mod update_yield_farm;
 pub mod withdraw_lp_shares;
+pub mod yield_farm_value_cap;
@@ -0,0 +1,173 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+#[test]
+fn top_up_global_farm_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			let global_farm_0 = LiquidityMining::global_farm(GC_FARM).unwrap();
+			let global_farm_account = LiquidityMining::farm_account_id(GC_FARM).unwrap();
+			let gc_balance_0 = Tokens::free_balance(BSX, &GC);
+
+			//Act
+			assert_eq!(
+				LiquidityMining::top_up_global_farm(GC, GC_FARM, 30_000_000_000 * ONE, 250).unwrap(),
+				80_000_000 * ONE
+			);
+
+			//Assert
+			assert_eq!(
+				LiquidityMining::global_farm(GC_FARM).unwrap(),
+				GlobalFarmData {
+					planned_yielding_periods: 750,
+					max_reward_per_period: 80_000_000 * ONE,
+					..global_farm_0
+				},
+			);
+			assert_eq!(Tokens::free_balance(BSX, &global_farm_account), 60_000_000_000 * ONE);
+			assert_eq!(Tokens::free_balance(BSX, &GC), gc_balance_0 - 30_000_000_000 * ONE);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_only_extend_periods_when_amount_is_zero() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			let global_farm_0 = LiquidityMining::global_farm(GC_FARM).unwrap();
+
+			//Act
+			assert_ok!(LiquidityMining::top_up_global_farm(GC, GC_FARM, 0, 100));
+
+			//Assert
+			assert_eq!(
+				LiquidityMining::global_farm(GC_FARM).unwrap(),
+				GlobalFarmData {
+					planned_yielding_periods: 600,
+					max_reward_per_period: 50_000_000 * ONE,
+					..global_farm_0
+				},
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_sync_farm_before_recalculation() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			let global_farm_0 = LiquidityMining::global_farm(GC_FARM).unwrap();
+			let global_farm_account = LiquidityMining::farm_account_id(GC_FARM).unwrap();
+
+			set_block_number(100_000);
+
+			//Act
+			assert_ok!(LiquidityMining::top_up_global_farm(
+				GC,
+				GC_FARM,
+				10_000_000_000 * ONE,
+				506
+			));
+
+			//Assert
+			//NOTE: undistributed rewards are enough for 494 periods at the time of top-up.
+			let left_to_distribute = Tokens::free_balance(BSX, &global_farm_account);
+			assert_eq!(
+				LiquidityMining::global_farm(GC_FARM).unwrap(),
+				GlobalFarmData {
+					updated_at: 1_000,
+					accumulated_rpz: FixedU128::from_inner(491_000_000_000_000_000_000_u128),
+					pending_rewards: 343_195_125_u128 * ONE,
+					planned_yielding_periods: 1_006,
+					max_reward_per_period: left_to_distribute / 1_000,
+					..global_farm_0
+				},
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_amount_and_extension_are_zero() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::top_up_global_farm(GC, GC_FARM, 0, 0),
+				Error::<Test, Instance1>::InvalidTopUp
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_who_is_not_owner() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::top_up_global_farm(ALICE, GC_FARM, 1_000 * ONE, 100),
+				Error::<Test, Instance1>::Forbidden
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_farm_does_not_exist() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::top_up_global_farm(GC, GlobalFarmId::MAX, 1_000 * ONE, 100),
+				Error::<Test, Instance1>::GlobalFarmNotFound
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_owner_has_not_enough_balance() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			let gc_balance = Tokens::free_balance(BSX, &GC);
+
+			assert_noop!(
+				LiquidityMining::top_up_global_farm(GC, GC_FARM, gc_balance + 1, 100),
+				Error::<Test, Instance1>::InsufficientRewardCurrencyBalance
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
@@ -0,0 +1,88 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use test_ext::*;
+
+#[test]
+fn do_try_state_should_pass_when_farms_match_deposits() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		assert_ok!(LiquidityMining::do_try_state());
+	});
+}
+
+#[test]
+fn do_try_state_should_pass_when_yield_farm_is_terminated_and_deposits_are_withdrawn() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::stop_yield_farm(GC, GC_FARM, BSX_TKN1_AMM));
+			assert_ok!(LiquidityMining::terminate_yield_farm(
+				GC,
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::withdraw_lp_shares(
+				PREDEFINED_DEPOSIT_IDS[0],
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				0
+			));
+
+			//Assert
+			assert_ok!(LiquidityMining::do_try_state());
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn do_try_state_should_fail_when_yield_farm_valued_shares_dont_match_entries() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		//Arrange
+		YieldFarm::<Test, Instance1>::mutate((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID), |maybe_yield_farm| {
+			let yield_farm = maybe_yield_farm.as_mut().unwrap();
+			yield_farm.total_valued_shares = yield_farm.total_valued_shares.saturating_add(1);
+		});
+
+		//Act & assert
+		assert_noop!(
+			LiquidityMining::do_try_state(),
+			DispatchError::Other("Yield farm's total valued shares don't equal the sum of entries' valued shares")
+		);
+	});
+}
+
+#[test]
+fn do_try_state_should_fail_when_global_farm_yield_farms_count_is_wrong() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		//Arrange
+		GlobalFarm::<Test, Instance1>::mutate(GC_FARM, |maybe_global_farm| {
+			let global_farm = maybe_global_farm.as_mut().unwrap();
+			global_farm.live_yield_farms_count = global_farm.live_yield_farms_count.saturating_add(1);
+		});
+
+		//Act & assert
+		assert_noop!(
+			LiquidityMining::do_try_state(),
+			DispatchError::Other("Global farm's live yield farms count doesn't equal the number of live yield farms")
+		);
+	});
+}
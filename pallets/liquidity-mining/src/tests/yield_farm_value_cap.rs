@@ -0,0 +1,315 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use mock::{RuntimeEvent, System};
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+#[test]
+fn update_yield_farm_value_cap_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Act
+			assert_eq!(
+				LiquidityMining::update_yield_farm_value_cap(GC, GC_FARM, BSX_TKN1_AMM, Some(1_000 * ONE)).unwrap(),
+				GC_BSX_TKN1_YIELD_FARM_ID
+			);
+
+			//Assert
+			assert_eq!(
+				LiquidityMining::yield_farm_value_cap(GC_BSX_TKN1_YIELD_FARM_ID),
+				Some(1_000 * ONE)
+			);
+
+			//Act - remove cap
+			assert_ok!(LiquidityMining::update_yield_farm_value_cap(
+				GC,
+				GC_FARM,
+				BSX_TKN1_AMM,
+				None
+			));
+
+			//Assert
+			assert_eq!(LiquidityMining::yield_farm_value_cap(GC_BSX_TKN1_YIELD_FARM_ID), None);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn update_yield_farm_value_cap_should_fail_when_origin_is_not_farm_owner() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::update_yield_farm_value_cap(ALICE, GC_FARM, BSX_TKN1_AMM, Some(1_000 * ONE)),
+				Error::<Test, Instance1>::Forbidden
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn update_yield_farm_value_cap_should_fail_when_cap_is_zero() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::update_yield_farm_value_cap(GC, GC_FARM, BSX_TKN1_AMM, Some(0)),
+				Error::<Test, Instance1>::InvalidValueCap
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn update_yield_farm_value_cap_should_fail_when_yield_farm_is_not_active() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::stop_yield_farm(GC, GC_FARM, BSX_TKN1_AMM));
+
+			//Act & assert
+			assert_noop!(
+				LiquidityMining::update_yield_farm_value_cap(GC, GC_FARM, BSX_TKN1_AMM, Some(1_000 * ONE)),
+				Error::<Test, Instance1>::YieldFarmNotFound
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_lp_shares_should_not_cap_valued_shares_when_deposit_fits_under_cap() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			set_block_number(1_800);
+			assert_ok!(LiquidityMining::update_yield_farm_value_cap(
+				GC,
+				GC_FARM,
+				BSX_TKN1_AMM,
+				Some(3_000 * ONE)
+			));
+
+			//Act
+			let deposit_id = LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				50 * ONE,
+				|_, _, _| Ok(2_500 * ONE),
+			)
+			.unwrap();
+
+			//Assert
+			let yield_farm = LiquidityMining::yield_farm((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID)).unwrap();
+			assert_eq!(yield_farm.total_shares, 50 * ONE);
+			assert_eq!(yield_farm.total_valued_shares, 2_500 * ONE);
+			assert_eq!(
+				LiquidityMining::deposit(deposit_id).unwrap().yield_farm_entries[0].valued_shares,
+				2_500 * ONE
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_lp_shares_should_cap_valued_shares_when_deposit_exceeds_cap() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			set_block_number(1_800);
+			assert_ok!(LiquidityMining::update_yield_farm_value_cap(
+				GC,
+				GC_FARM,
+				BSX_TKN1_AMM,
+				Some(3_000 * ONE)
+			));
+			assert_ok!(LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				50 * ONE,
+				|_, _, _| Ok(2_500 * ONE),
+			));
+			let global_farm_0 = LiquidityMining::global_farm(GC_FARM).unwrap();
+
+			//Act
+			let deposit_id = LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				80 * ONE,
+				|_, _, _| Ok(4_160 * ONE),
+			)
+			.unwrap();
+
+			//Assert
+			let yield_farm = LiquidityMining::yield_farm((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID)).unwrap();
+			assert_eq!(yield_farm.total_shares, 130 * ONE);
+			assert_eq!(yield_farm.total_valued_shares, 3_000 * ONE);
+			assert_eq!(
+				LiquidityMining::deposit(deposit_id).unwrap().yield_farm_entries[0].valued_shares,
+				500 * ONE
+			);
+			assert_eq!(
+				LiquidityMining::global_farm(GC_FARM).unwrap().total_shares_z,
+				global_farm_0.total_shares_z + 500 * ONE * 5
+			);
+			assert!(System::events().iter().any(|record| record.event
+				== RuntimeEvent::LiquidityMining(Event::DepositValueCapped {
+					global_farm_id: GC_FARM,
+					yield_farm_id: GC_BSX_TKN1_YIELD_FARM_ID,
+					valued_shares: 4_160 * ONE,
+					eligible_valued_shares: 500 * ONE,
+				})));
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_lp_shares_should_accept_deposit_with_zero_valued_shares_when_cap_is_reached() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			set_block_number(1_800);
+			assert_ok!(LiquidityMining::update_yield_farm_value_cap(
+				GC,
+				GC_FARM,
+				BSX_TKN1_AMM,
+				Some(2_500 * ONE)
+			));
+			assert_ok!(LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				50 * ONE,
+				|_, _, _| Ok(2_500 * ONE),
+			));
+
+			//Act
+			let deposit_id = LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				80 * ONE,
+				|_, _, _| Ok(4_160 * ONE),
+			)
+			.unwrap();
+
+			//Assert
+			let yield_farm = LiquidityMining::yield_farm((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID)).unwrap();
+			assert_eq!(yield_farm.total_shares, 130 * ONE);
+			assert_eq!(yield_farm.total_valued_shares, 2_500 * ONE);
+			assert_eq!(yield_farm.entries_count, 2);
+			assert_eq!(
+				LiquidityMining::deposit(deposit_id).unwrap().yield_farm_entries[0].valued_shares,
+				0
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_lp_shares_should_use_freed_cap_space_when_deposit_was_withdrawn() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			set_block_number(1_800);
+			assert_ok!(LiquidityMining::update_yield_farm_value_cap(
+				GC,
+				GC_FARM,
+				BSX_TKN1_AMM,
+				Some(2_500 * ONE)
+			));
+			let deposit_id = LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				50 * ONE,
+				|_, _, _| Ok(2_500 * ONE),
+			)
+			.unwrap();
+			assert_ok!(LiquidityMining::withdraw_lp_shares(
+				deposit_id,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				0
+			));
+
+			//Act
+			let deposit_id = LiquidityMining::deposit_lp_shares(
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM,
+				80 * ONE,
+				|_, _, _| Ok(4_160 * ONE),
+			)
+			.unwrap();
+
+			//Assert
+			let yield_farm = LiquidityMining::yield_farm((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID)).unwrap();
+			assert_eq!(yield_farm.total_valued_shares, 2_500 * ONE);
+			assert_eq!(
+				LiquidityMining::deposit(deposit_id).unwrap().yield_farm_entries[0].valued_shares,
+				2_500 * ONE
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn terminate_yield_farm_should_remove_value_cap() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::update_yield_farm_value_cap(
+				GC,
+				GC_FARM,
+				BSX_TKN1_AMM,
+				Some(1_000 * ONE)
+			));
+			assert_ok!(LiquidityMining::stop_yield_farm(GC, GC_FARM, BSX_TKN1_AMM));
+
+			//Act
+			assert_ok!(LiquidityMining::terminate_yield_farm(
+				GC,
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM
+			));
+
+			//Assert
+			assert_eq!(LiquidityMining::yield_farm_value_cap(GC_BSX_TKN1_YIELD_FARM_ID), None);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
@@ -315,6 +315,14 @@ impl<T: Config<I>, I: 'static> DepositData<T, I> {
 		}
 	}
 
+	pub fn get_shares(&self) -> Balance {
+		self.shares
+	}
+
+	pub fn get_amm_pool_id(&self) -> T::AmmPoolId {
+		self.amm_pool_id.clone()
+	}
+
 	/// This function add new yield farm entry into the deposit.
 	/// This function returns error if deposit reached max entries in the deposit or
 	/// `entry.yield_farm_id` is not unique.
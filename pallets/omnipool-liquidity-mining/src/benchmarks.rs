@@ -332,6 +332,38 @@ benchmarks! {
 	}: _(RawOrigin::Root, global_farm_id, planned_yielding_periods, yield_per_period, min_deposit)
 
 
+	top_up_global_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_omnipool::<T>()?;
+
+		initialize_global_farm::<T>(owner.clone())?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, BTC.into())?;
+
+		let lp = create_funded_account::<T>("lp_1", 1, 10 * BTC_ONE, BTC.into());
+		let position_id = omnipool_add_liquidity::<T>(lp.clone(), BTC.into(), 10 * BTC_ONE)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, position_id)?;
+
+		fund::<T>(owner.clone(), REWARD_CURRENCY.into(), G_FARM_TOTAL_REWARDS)?;
+		let extension = BlockNumberFor::<T>::from(1_000_u32);
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, G_FARM_TOTAL_REWARDS, extension)
+
+	update_yield_farm_value_cap {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+
+		initialize_omnipool::<T>()?;
+
+		initialize_global_farm::<T>(owner.clone())?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, BTC.into())?;
+	}: _(RawOrigin::Signed(owner), global_farm_id, BTC.into(), Some(1_000_000 * ONE))
+
 	terminate_global_farm {
 		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
 		let global_farm_id = 1;
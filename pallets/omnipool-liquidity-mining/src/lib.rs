@@ -31,6 +31,12 @@
 //! * **LP:**  liquidity provider
 //! * **Position:** omnipool's LP position
 //! * **Deposit:** omnipool's position(LP shares) locked in the liquidity mining
+//!
+//! ### Deposits
+//!
+//! Omnipool position is not transferred to the pallet's account when it is deposited. It is frozen in the omnipool
+//! instead and stays owned by the LP, so its liquidity can't be removed while it's deposited. Position is thawed
+//! when the deposit is destroyed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -85,7 +91,10 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::genesis_config]
@@ -165,8 +174,7 @@ pub mod pallet {
 
 	#[pallet::storage]
 	/// Map of omnipool position's ids to LM's deposit ids.
-	pub(super) type OmniPositionId<T: Config> =
-		StorageMap<_, Blake2_128Concat, DepositId, T::PositionItemId, OptionQuery>;
+	pub type OmniPositionId<T: Config> = StorageMap<_, Blake2_128Concat, DepositId, T::PositionItemId, OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
@@ -287,6 +295,24 @@ pub mod pallet {
 
 		/// All LP shares were unlocked and NFT representing deposit was destroyed.
 		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// Rewards were added to the global farm and its planned yielding periods were extended.
+		GlobalFarmToppedUp {
+			id: GlobalFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			extension: PeriodOf<T>,
+			max_reward_per_period: Balance,
+		},
+
+		/// Value cap of the yield farm was updated.
+		YieldFarmValueCapUpdated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			cap: Option<Balance>,
+		},
 	}
 
 	#[pallet::error]
@@ -664,7 +690,7 @@ pub mod pallet {
 
 		/// Deposit omnipool position(LP shares) to a liquidity mining.
 		///
-		/// This function transfers omnipool position from `origin` to pallet's account and mint NFT for
+		/// This function freezes omnipool position of `origin` and mint NFT for
 		/// `origin` account. Minted NFT represents deposit in the liquidity mining. User can
 		/// deposit omnipool position as a whole(all the LP shares in the position).
 		///
@@ -720,8 +746,8 @@ pub mod pallet {
 			let position_id = OmniPositionId::<T>::get(deposit_id)
 				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
 
-			//NOTE: pallet should be owner of the omnipool position at this point.
-			let lp_position = OmnipoolPallet::<T>::load_position(position_id, Self::account_id())?;
+			let lp_position =
+				OmnipoolPallet::<T>::positions(position_id).ok_or(pallet_omnipool::Error::<T>::PositionNotFound)?;
 			ensure!(
 				OmnipoolPallet::<T>::exists(lp_position.asset_id),
 				Error::<T>::AssetNotFound
@@ -783,7 +809,7 @@ pub mod pallet {
 		}
 
 		/// This function claim rewards and withdraw LP shares from yield farm. Omnipool position
-		/// is transferred to origin only if this is last withdraw in the deposit and deposit is
+		/// is thawed only if this is last withdraw in the deposit and deposit is
 		/// destroyed. This function claim rewards only if yield farm is not terminated and user
 		/// didn't already claim rewards in current period.
 		///
@@ -814,7 +840,8 @@ pub mod pallet {
 			//NOTE: not tested - this should never fail.
 			let position_id = OmniPositionId::<T>::get(deposit_id)
 				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
-			let lp_position = OmnipoolPallet::<T>::load_position(position_id, Self::account_id())?;
+			let lp_position =
+				OmnipoolPallet::<T>::positions(position_id).ok_or(pallet_omnipool::Error::<T>::PositionNotFound)?;
 
 			//NOTE: not tested - this should never fail.
 			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
@@ -850,7 +877,7 @@ pub mod pallet {
 			});
 
 			if is_destroyed {
-				Self::unlock_lp_postion(deposit_id, &owner)?;
+				Self::unlock_lp_postion(deposit_id)?;
 				<T as pallet::Config>::NFTHandler::burn(
 					&<T as pallet::Config>::NFTCollectionId::get(),
 					&deposit_id,
@@ -1007,24 +1034,98 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add rewards to the existing global farm and extend its planned yielding periods.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Max reward per period is recalculated so that undistributed rewards together with
+		/// `amount` are distributed in periods left plus `extension`. Yield farms and deposits
+		/// in the global farm are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to top up.
+		/// - `amount`: amount of `reward_currency` transferred from the owner to the farm.
+		/// - `extension`: number of periods to add to farm's planned yielding periods.
+		///
+		/// Emits `GlobalFarmToppedUp` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_global_farm())]
+		pub fn top_up_global_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			amount: Balance,
+			extension: PeriodOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let max_reward_per_period =
+				T::LiquidityMiningHandler::top_up_global_farm(who.clone(), global_farm_id, amount, extension)?;
+
+			Self::deposit_event(Event::GlobalFarmToppedUp {
+				id: global_farm_id,
+				who,
+				amount,
+				extension,
+				max_reward_per_period,
+			});
+
+			Ok(())
+		}
+
+		/// Update yield farm's value cap.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Deposits exceeding the cap are accepted but only valued shares fitting under the cap
+		/// at the time of deposit are rewarded. Existing deposits are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm will be updated.
+		/// - `asset_id`: id of the asset identifying yield farm in the global farm.
+		/// - `cap`: max. value of rewarded valued shares in `reward_currency`, `None` removes the cap.
+		///
+		/// Emits `YieldFarmValueCapUpdated` event when successful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_yield_farm_value_cap())]
+		pub fn update_yield_farm_value_cap(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			asset_id: T::AssetId,
+			cap: Option<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(OmnipoolPallet::<T>::exists(asset_id), Error::<T>::AssetNotFound);
+
+			let yield_farm_id =
+				T::LiquidityMiningHandler::update_yield_farm_value_cap(who.clone(), global_farm_id, asset_id, cap)?;
+
+			Self::deposit_event(Event::YieldFarmValueCapUpdated {
+				global_farm_id,
+				yield_farm_id,
+				asset_id,
+				who,
+				cap,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// Account ID of the pot holding all the locked omnipool's positions(NFTs). This account
-	/// is also owner of the NFT collection used to mint liqudity mining's NFTs.
+	/// Account ID of the pallet. This account is owner of the NFT collection used to mint liqudity mining's NFTs.
 	pub fn account_id() -> T::AccountId {
 		<T as pallet::Config>::PalletId::get().into_account_truncating()
 	}
 
-	/// This function transfers omnipool's position NFT to liquidity mining's account. This
-	/// function also saves mapping of the deposit's id to omnipool position's id.
+	/// This function freezes omnipool's position so its liquidity can't be removed while it's
+	/// deposited. This function also saves mapping of the deposit's id to omnipool position's id.
 	fn lock_lp_position(position_id: T::PositionItemId, deposit_id: DepositId) -> Result<(), DispatchError> {
-		<T as pallet::Config>::NFTHandler::transfer(
-			&<T as pallet_omnipool::Config>::NFTCollectionId::get(),
-			&position_id,
-			&Self::account_id(),
-		)?;
+		OmnipoolPallet::<T>::freeze_position(position_id)?;
 
 		//Mapping of the `deposit_id` to `position_id` so we know which position to unlock when deposit
 		//is destroyed.
@@ -1033,20 +1134,16 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// This function transfers omnipool's NFT associated with `deposit_id` to `who` and removes
+	/// This function thaws omnipool's position associated with `deposit_id` and removes
 	/// deposit's id to omnipool position's id for storage.
-	fn unlock_lp_postion(deposit_id: DepositId, who: &T::AccountId) -> Result<(), DispatchError> {
+	fn unlock_lp_postion(deposit_id: DepositId) -> Result<(), DispatchError> {
 		OmniPositionId::<T>::try_mutate_exists(deposit_id, |maybe_position_id| -> DispatchResult {
 			//NOTE: not tested, this should never fail
 			let lp_position_id = maybe_position_id
 				.as_mut()
 				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
 
-			<T as pallet::Config>::NFTHandler::transfer(
-				&<T as pallet_omnipool::Config>::NFTCollectionId::get(),
-				lp_position_id,
-				who,
-			)?;
+			OmnipoolPallet::<T>::thaw_position(*lp_position_id)?;
 
 			//NOTE: storage clean up
 			*maybe_position_id = None;
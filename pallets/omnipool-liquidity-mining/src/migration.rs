@@ -75,3 +75,73 @@ pub fn migrate_to_v1<T: Config, P: GetStorageVersion + PalletInfoAccess>() -> fr
 		weight
 	}
 }
+
+pub mod versioned {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+
+	pub type V1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		v2::VersionUncheckedMigrateToV2<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// Omnipool positions are frozen instead of being transferred to the pallet's account when they are
+// deposited. This migration transfers deposited positions back to the owners of the deposits and freezes them.
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use sp_std::marker::PhantomData;
+
+	pub struct VersionUncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			for (deposit_id, position_id) in OmniPositionId::<T>::iter() {
+				count.saturating_inc();
+
+				let Some(owner) = <T as pallet::Config>::NFTHandler::owner(
+					&<T as pallet::Config>::NFTCollectionId::get(),
+					&deposit_id,
+				) else {
+					log::error!(
+						target: "runtime::omnipool-liquidity-mining",
+						"Owner of deposit {:?} not found",
+						deposit_id,
+					);
+					continue;
+				};
+
+				let result = <T as pallet::Config>::NFTHandler::transfer(
+					&<T as pallet_omnipool::Config>::NFTCollectionId::get(),
+					&position_id,
+					&owner,
+				)
+				.and_then(|_| OmnipoolPallet::<T>::freeze_position(position_id));
+
+				if let Err(e) = result {
+					log::error!(
+						target: "runtime::omnipool-liquidity-mining",
+						"Failed to migrate position {:?} of deposit {:?}: {:?}",
+						position_id,
+						deposit_id,
+						e,
+					);
+				}
+			}
+
+			log::info!(
+				target: "runtime::omnipool-liquidity-mining",
+				"Migrated {:?} deposited positions",
+				count,
+			);
+
+			T::DbWeight::get().reads_writes(count.saturating_mul(5), count.saturating_mul(5))
+		}
+	}
+}
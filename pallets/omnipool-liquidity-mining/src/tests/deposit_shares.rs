@@ -94,10 +94,10 @@ fn deposit_shares_should_work() {
 
 			assert_eq!(deposit, expected_deposit);
 
-			//NFT check: lm account should be owner of the omnipool position.
-			let lm_account = OmnipoolMining::account_id();
+			//NFT check: omnipool position should stay owned by the user and be frozen.
 			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
-			assert_eq!(owner, lm_account);
+			assert_eq!(owner, LP1);
+			assert!(Omnipool::is_position_frozen(omnipool_position_id));
 
 			//NFT check: lm deposit should be minted for user.
 			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
@@ -340,3 +340,104 @@ fn deposit_shares_should_fail_with_asset_not_found_when_omnipool_deosnt_exists()
 			);
 		});
 }
+
+#[test]
+fn deposit_shares_should_fail_when_omnipool_position_is_already_deposited() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let omnipool_position_id = 2;
+
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				global_farm_id,
+				yield_farm_id,
+				omnipool_position_id
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::deposit_shares(
+					RuntimeOrigin::signed(LP1),
+					global_farm_id,
+					yield_farm_id,
+					omnipool_position_id
+				),
+				pallet_omnipool::Error::<Test>::PositionFrozen
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_omnipool_position_is_deposited() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let omnipool_position_id = 2;
+
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				global_farm_id,
+				yield_farm_id,
+				omnipool_position_id
+			));
+
+			//Act & assert
+			assert_noop!(
+				Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), omnipool_position_id, ONE),
+				pallet_omnipool::Error::<Test>::PositionFrozen
+			);
+		});
+}
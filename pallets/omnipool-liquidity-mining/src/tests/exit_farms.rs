@@ -144,9 +144,10 @@ fn exit_farm_should_work_for_multiple_farm_entries() {
 			//Storage check
 			std::assert_eq!(crate::OmniPositionId::<Test>::get(deposit_id), None);
 
-			//Omnipool's NFT should return to the owner
+			//Omnipool's position should be thawed
 			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
 			std::assert_eq!(owner, LP1);
+			std::assert!(!Omnipool::is_position_frozen(omnipool_position_id));
 
 			//Deposit's NFT should be burned.
 			let owner: Option<AccountId> = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id);
@@ -116,10 +116,10 @@ fn join_farms_should_work_with_single_yield_farm() {
 
 			assert_eq!(deposit, expected_deposit);
 
-			//NFT check: lm account should be owner of the omnipool position.
-			let lm_account = OmnipoolMining::account_id();
+			//NFT check: omnipool position should stay owned by the user and be frozen.
 			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
-			assert_eq!(owner, lm_account);
+			assert_eq!(owner, LP1);
+			assert!(Omnipool::is_position_frozen(omnipool_position_id));
 
 			//NFT check: lm deposit should be minted for user.
 			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
@@ -281,10 +281,10 @@ fn join_farms_should_work_with_multiple_yield_farm() {
 
 			assert_eq!(deposit, expected_deposit);
 
-			//NFT check: lm account should be owner of the omnipool position.
-			let lm_account = OmnipoolMining::account_id();
+			//NFT check: omnipool position should stay owned by the user and be frozen.
 			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
-			assert_eq!(owner, lm_account);
+			assert_eq!(owner, LP1);
+			assert!(Omnipool::is_position_frozen(omnipool_position_id));
 
 			//NFT check: lm deposit should be minted for user.
 			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
@@ -262,11 +262,28 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type SpotPriceProvider = NoSpotPrice;
+	type Currency = Balances;
+	type RoutedOracleDeposit = RoutedOracleDeposit;
+	type MaxRoutedOracles = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const RoutedOracleDeposit: Balance = 1_000;
+}
+
+pub struct NoSpotPrice;
+impl hydradx_traits::price::PriceProvider<AssetId> for NoSpotPrice {
+	type Price = pallet_ema_oracle::Price;
+
+	fn get_price(_asset_a: AssetId, _asset_b: AssetId) -> Option<Self::Price> {
+		None
+	}
+}
+
 parameter_types! {
 	pub const HDXAssetId: AssetId = HDX;
 	pub const LRNAAssetId: AssetId = LRNA;
@@ -301,10 +318,23 @@ impl pallet_omnipool::Config for Test {
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u128;
 	type OmnipoolHooks = ();
+	type OnTradeExecuted = ();
 	type PriceBarrier = ();
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = ();
+	type WithdrawalStreamBlocks = ();
+	type MaxQueuedWithdrawals = ();
+	type MaxQueuedWithdrawalsPerAccount = ();
+	type MaxHubAssetBuyPerBlock = ();
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
 }
 
 pub struct ExtBuilder {
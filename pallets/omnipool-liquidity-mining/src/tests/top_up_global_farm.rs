@@ -0,0 +1,226 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn top_up_global_farm_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let farm_account = WarehouseLM::farm_account_id(global_farm_id).unwrap();
+
+			//Act
+			assert_ok!(OmnipoolMining::top_up_global_farm(
+				RuntimeOrigin::signed(GC),
+				global_farm_id,
+				10_000_000 * ONE,
+				372_000,
+			));
+
+			//Assert
+			assert_eq!(
+				WarehouseLM::global_farm(global_farm_id)
+					.unwrap()
+					.planned_yielding_periods,
+				3_000_000
+			);
+			assert_eq!(Tokens::free_balance(HDX, &farm_account), 90_000_000 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &GC), 10_000_000 * ONE);
+
+			assert_last_event!(crate::Event::GlobalFarmToppedUp {
+				id: global_farm_id,
+				who: GC,
+				amount: 10_000_000 * ONE,
+				extension: 372_000,
+				max_reward_per_period: 30 * ONE,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_keep_planned_periods_when_extension_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+
+			//Act
+			assert_ok!(OmnipoolMining::top_up_global_farm(
+				RuntimeOrigin::signed(GC),
+				global_farm_id,
+				10_000_000 * ONE,
+				0,
+			));
+
+			//Assert
+			assert_eq!(
+				WarehouseLM::global_farm(global_farm_id)
+					.unwrap()
+					.planned_yielding_periods,
+				2_628_000
+			);
+			assert_last_event!(crate::Event::GlobalFarmToppedUp {
+				id: global_farm_id,
+				who: GC,
+				amount: 10_000_000 * ONE,
+				extension: 0,
+				max_reward_per_period: 90_000_000 * ONE / 2_628_000,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_origin_is_not_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OmnipoolMining::top_up_global_farm(RuntimeOrigin::signed(LP1), 1, 10_000_000 * ONE, 372_000),
+				pallet_liquidity_mining::Error::<Test, pallet_liquidity_mining::Instance1>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_amount_and_extension_are_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OmnipoolMining::top_up_global_farm(RuntimeOrigin::signed(GC), 1, 0, 0),
+				pallet_liquidity_mining::Error::<Test, pallet_liquidity_mining::Instance1>::InvalidTopUp
+			);
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_origin_is_none() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OmnipoolMining::top_up_global_farm(RuntimeOrigin::none(), 1, 10_000_000 * ONE, 372_000),
+				BadOrigin
+			);
+		});
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn update_yield_farm_value_cap_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+
+			//Act
+			assert_ok!(OmnipoolMining::update_yield_farm_value_cap(
+				RuntimeOrigin::signed(GC),
+				global_farm_id,
+				KSM,
+				Some(1_000_000 * ONE),
+			));
+
+			//Assert
+			assert_eq!(WarehouseLM::yield_farm_value_cap(yield_farm_id), Some(1_000_000 * ONE));
+			assert_last_event!(crate::Event::YieldFarmValueCapUpdated {
+				global_farm_id,
+				yield_farm_id,
+				asset_id: KSM,
+				who: GC,
+				cap: Some(1_000_000 * ONE),
+			}
+			.into());
+		});
+}
+
+#[test]
+fn update_yield_farm_value_cap_should_fail_when_origin_is_not_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OmnipoolMining::update_yield_farm_value_cap(RuntimeOrigin::signed(LP1), 1, KSM, Some(1_000 * ONE)),
+				pallet_liquidity_mining::Error::<Test, pallet_liquidity_mining::Instance1>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn update_yield_farm_value_cap_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OmnipoolMining::update_yield_farm_value_cap(RuntimeOrigin::signed(GC), 1, DOT, Some(1_000 * ONE)),
+				Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn update_yield_farm_value_cap_should_fail_when_origin_is_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			OmnipoolMining::update_yield_farm_value_cap(RuntimeOrigin::none(), 1, KSM, Some(1_000 * ONE)),
+			BadOrigin
+		);
+	});
+}
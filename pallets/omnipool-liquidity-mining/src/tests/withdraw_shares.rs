@@ -97,9 +97,10 @@ fn withdraw_shares_should_unlock_omnipool_position_when_last_entry_in_deposit()
 			//Storage check
 			assert_eq!(crate::OmniPositionId::<Test>::get(deposit_id), None);
 
-			//Omnipool's NFT should return to the owner
+			//Omnipool's position should be thawed
 			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
 			assert_eq!(owner, LP1);
+			assert!(!Omnipool::is_position_frozen(omnipool_position_id));
 
 			//Deposit's NFT should be burned.
 			let owner: Option<AccountId> = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id);
@@ -197,10 +198,10 @@ fn withdraw_shares_should_not_unlock_omnipool_position_when_deposit_is_not_burne
 				omnipool_position_id
 			);
 
-			//Omnipool's NFT should not changed as deposit was not destroyed.
-			let lm_account = OmnipoolMining::account_id();
+			//Omnipool's position should stay frozen as deposit was not destroyed.
 			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
-			assert_eq!(owner, lm_account);
+			assert_eq!(owner, LP1);
+			assert!(Omnipool::is_position_frozen(omnipool_position_id));
 
 			//Deposit's NFT should not change as deposit was not destroyed.
 			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
@@ -66,6 +66,8 @@ pub trait WeightInfo {
 	fn add_liquidity_and_join_farms(c: u32) -> Weight;
 
 	fn exit_farms(c: u32) -> Weight;
+	fn top_up_global_farm() -> Weight;
+	fn update_yield_farm_value_cap() -> Weight;
 }
 
 /// Weights for pallet_omnipool_liquidity_mining using the hydraDX node and recommended hardware.
@@ -118,7 +120,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:1)
@@ -143,7 +145,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:0)
 	/// Proof: `OmnipoolWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
@@ -187,7 +189,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
@@ -231,7 +233,7 @@ impl WeightInfo for () {
 	/// Storage: `Omnipool::Positions` (r:1 w:0)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
@@ -272,7 +274,7 @@ impl WeightInfo for () {
 	/// Storage: `Omnipool::Positions` (r:1 w:0)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
@@ -339,7 +341,7 @@ impl WeightInfo for () {
 	/// Storage: `Omnipool::Positions` (r:1 w:0)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:5 w:5)
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:5 w:5)
@@ -378,7 +380,7 @@ impl WeightInfo for () {
 	/// Storage: `Tokens::Accounts` (r:3 w:3)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:3 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -486,4 +488,36 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((12_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 5242).saturating_mul(c.into()))
 	}
+	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn top_up_global_farm() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1075`
+		//  Estimated: `8799`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Omnipool::Assets` (r:1 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:0)
+	/// Proof: `OmnipoolWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:0)
+	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmValueCap` (r:0 w:1)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmValueCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn update_yield_farm_value_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1213`
+		//  Estimated: `3670`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3670)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
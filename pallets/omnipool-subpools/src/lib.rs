@@ -0,0 +1,357 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool subpools pallet
+//!
+//! Moves correlated Omnipool assets into a stableswap subpool whose share token is listed in the Omnipool.
+//!
+//! ## Overview
+//!
+//! `create_subpool` takes a set of Omnipool assets and creates a stableswap pool of them. Whole Omnipool reserve of
+//! each asset is provided as liquidity to the new pool and the minted share tokens are listed in the Omnipool
+//! instead of the assets. Hub asset reserves of the assets are moved to the share token, so the Omnipool hub asset
+//! liquidity and prices of other assets are not affected.
+//!
+//! Omnipool LP shares of the share token are split between the moved assets by their hub asset reserves. LP shares
+//! of a moved asset are converted to LP shares of the share token in the same proportion, including the shares
+//! owned by the protocol.
+//!
+//! LP positions of the moved assets are converted lazily by `migrate_position`, which can be called by anyone.
+//! Position keeps its id and owner, only its asset, amount, shares and price are converted. Queued withdrawal of the
+//! position is converted as well.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_subpool` - Moves Omnipool assets into a new stableswap subpool listed in the Omnipool.
+//! * `migrate_position` - Converts Omnipool LP position of a moved asset to a position of the subpool share token.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::{ensure, transactional};
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::OriginFor;
+use pallet_omnipool::types::{AssetReserveState, Balance, Position, Tradability};
+use pallet_stableswap::types::AssetAmount;
+use scale_info::TypeInfo;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding, RuntimeDebug};
+use sp_std::num::NonZeroU16;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+
+pub type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+
+/// Omnipool state of an asset at the time it was moved to a subpool.
+///
+/// It is used to convert LP positions of the asset to positions of the subpool share token.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MigratedAsset<AssetId> {
+	/// Subpool the asset was moved to. It is also the share token of the subpool.
+	pub pool_id: AssetId,
+	/// Omnipool reserve of the asset.
+	pub reserve: Balance,
+	/// Omnipool LP shares of the asset.
+	pub shares: Balance,
+	/// Omnipool LP shares of the share token the asset shares are converted to.
+	pub subpool_shares: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use pallet_stableswap::MAX_ASSETS_IN_POOL;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool::Config
+		+ pallet_stableswap::Config<AssetId = <Self as pallet_omnipool::Config>::AssetId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin able to create subpools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Assets moved from the Omnipool to a subpool.
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_asset)]
+	pub type MigratedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, MigratedAsset<AssetIdOf<T>>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Subpool was created and its share token was listed in the Omnipool.
+		SubpoolCreated {
+			pool_id: AssetIdOf<T>,
+			assets: Vec<AssetIdOf<T>>,
+			shares: Balance,
+		},
+		/// Asset was moved from the Omnipool to a subpool.
+		AssetMigrated {
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+			reserve: Balance,
+			hub_reserve: Balance,
+			shares: Balance,
+			subpool_shares: Balance,
+		},
+		/// LP position of a moved asset was converted to a position of the subpool share token.
+		PositionMigrated {
+			position_id: T::PositionItemId,
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+			shares: Balance,
+			subpool_shares: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Amplification of the subpool is zero.
+		InvalidAmplification,
+		/// Native asset can't be moved to a subpool.
+		NotAllowed,
+		/// Asset was already moved to a subpool.
+		AssetAlreadyMigrated,
+		/// Asset of the position was not moved to a subpool.
+		AssetNotMigrated,
+		/// Position does not exist.
+		PositionNotFound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Move Omnipool assets into a new stableswap subpool and list its share token in the Omnipool.
+		///
+		/// Whole Omnipool reserve of each asset is provided as liquidity to the subpool. Hub asset reserves,
+		/// LP shares and weight caps of the assets are added up and moved to the share token.
+		///
+		/// LP positions of the moved assets must be converted by `migrate_position`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `AuthorityOrigin`.
+		/// - `share_asset`: Preregistered share token of the subpool.
+		/// - `assets`: Omnipool assets moved to the subpool.
+		/// - `amplification`: Subpool amplification.
+		/// - `fee`: Subpool fee.
+		///
+		/// Emits `AssetMigrated` event for each asset and `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool(assets.len() as u32))]
+		#[transactional]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			assets: BoundedVec<AssetIdOf<T>, ConstU32<MAX_ASSETS_IN_POOL>>,
+			amplification: u16,
+			fee: Permill,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			let amplification = NonZeroU16::new(amplification).ok_or(Error::<T>::InvalidAmplification)?;
+
+			ensure!(
+				!assets.contains(&<T as pallet_omnipool::Config>::HdxAssetId::get()),
+				Error::<T>::NotAllowed
+			);
+
+			let mut states = Vec::with_capacity(assets.len());
+			let mut total_hub_reserve = Balance::zero();
+			for asset_id in assets.iter() {
+				ensure!(
+					!MigratedAssets::<T>::contains_key(asset_id),
+					Error::<T>::AssetAlreadyMigrated
+				);
+				let state = OmnipoolPallet::<T>::load_asset_state(*asset_id)?;
+				total_hub_reserve = total_hub_reserve
+					.checked_add(state.hub_reserve)
+					.ok_or(ArithmeticError::Overflow)?;
+				states.push((*asset_id, state));
+			}
+
+			let pool_id = StableswapPallet::<T>::do_create_pool(share_asset, &assets, amplification, fee)?;
+
+			let liquidity: Vec<AssetAmount<AssetIdOf<T>>> = states
+				.iter()
+				.map(|(asset_id, state)| AssetAmount::new(*asset_id, state.reserve))
+				.collect();
+			let shares =
+				StableswapPallet::<T>::do_add_liquidity(&OmnipoolPallet::<T>::protocol_account(), pool_id, &liquidity)?;
+
+			let mut remaining_shares = shares;
+			let mut protocol_shares = Balance::zero();
+			let mut cap = 0u128;
+			let last_idx = states.len().saturating_sub(1);
+			for (idx, (asset_id, state)) in states.into_iter().enumerate() {
+				// Share tokens are split by hub reserves, the last asset takes what is left after rounding.
+				let subpool_shares = if idx == last_idx {
+					remaining_shares
+				} else {
+					mul_div(shares, state.hub_reserve, total_hub_reserve)?
+				};
+				remaining_shares = remaining_shares.saturating_sub(subpool_shares);
+
+				protocol_shares = protocol_shares
+					.checked_add(mul_div(state.protocol_shares, subpool_shares, state.shares)?)
+					.ok_or(ArithmeticError::Overflow)?;
+				cap = cap.saturating_add(state.cap);
+
+				OmnipoolPallet::<T>::remove_asset(asset_id)?;
+				MigratedAssets::<T>::insert(
+					asset_id,
+					MigratedAsset {
+						pool_id,
+						reserve: state.reserve,
+						shares: state.shares,
+						subpool_shares,
+					},
+				);
+
+				Self::deposit_event(Event::AssetMigrated {
+					asset_id,
+					pool_id,
+					reserve: state.reserve,
+					hub_reserve: state.hub_reserve,
+					shares: state.shares,
+					subpool_shares,
+				});
+			}
+
+			OmnipoolPallet::<T>::add_asset(
+				pool_id,
+				AssetReserveState {
+					reserve: shares,
+					hub_reserve: total_hub_reserve,
+					shares,
+					protocol_shares,
+					cap: cap.min(FixedU128::one().into_inner()),
+					tradable: Tradability::default(),
+				}
+				.into(),
+			)?;
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id,
+				assets: assets.into_inner(),
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Convert Omnipool LP position of an asset moved to a subpool to a position of the subpool share token.
+		///
+		/// Position keeps its id and owner. Shares and amount of the position are converted in the same proportion
+		/// as the asset reserve and shares were. Queued withdrawal of the position is converted as well.
+		///
+		/// Unclaimed fee earnings of the position in the migrated asset are paid to the owner and the position
+		/// accrues fees of the share token from its current fee growth.
+		///
+		/// Can be called by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin.
+		/// - `position_id`: Position of an asset moved to a subpool.
+		///
+		/// Emits `PositionMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_position())]
+		#[transactional]
+		pub fn migrate_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let position = OmnipoolPallet::<T>::positions(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			let migrated = Self::migrated_asset(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let subpool_shares = mul_div(position.shares, migrated.subpool_shares, migrated.shares)?;
+			let new_position = Position {
+				asset_id: migrated.pool_id,
+				amount: mul_div(position.amount, migrated.subpool_shares, migrated.reserve)?,
+				shares: subpool_shares,
+				price: (
+					position.price.0,
+					multiply_by_rational_with_rounding(
+						position.price.1,
+						migrated.subpool_shares,
+						migrated.reserve,
+						Rounding::Up,
+					)
+					.ok_or(ArithmeticError::Overflow)?,
+				),
+			};
+
+			// Fees earned in the migrated asset are paid out before the position starts earning in the share token.
+			OmnipoolPallet::<T>::settle_fee_earnings(position_id)?;
+			OmnipoolPallet::<T>::set_position(position_id, &new_position)?;
+			OmnipoolPallet::<T>::checkpoint_fee_growth(position_id, migrated.pool_id);
+
+			pallet_omnipool::WithdrawalQueue::<T>::try_mutate(position_id, |maybe_withdrawal| -> DispatchResult {
+				if let Some(withdrawal) = maybe_withdrawal.as_mut() {
+					withdrawal.remaining_shares =
+						mul_div(withdrawal.remaining_shares, migrated.subpool_shares, migrated.shares)?;
+					withdrawal.shares_per_block = multiply_by_rational_with_rounding(
+						withdrawal.shares_per_block,
+						migrated.subpool_shares,
+						migrated.shares,
+						Rounding::Up,
+					)
+					.ok_or(ArithmeticError::Overflow)?;
+					withdrawal.remaining_limit =
+						mul_div(withdrawal.remaining_limit, migrated.subpool_shares, migrated.reserve)?;
+				}
+				Ok(())
+			})?;
+			OmnipoolPallet::<T>::move_queued_withdrawal(position_id, migrated.pool_id);
+
+			Self::deposit_event(Event::PositionMigrated {
+				position_id,
+				asset_id: position.asset_id,
+				pool_id: migrated.pool_id,
+				shares: position.shares,
+				subpool_shares,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+/// `a * b / c` rounded down.
+fn mul_div(a: Balance, b: Balance, c: Balance) -> Result<Balance, DispatchError> {
+	multiply_by_rational_with_rounding(a, b, c, Rounding::Down).ok_or_else(|| ArithmeticError::Overflow.into())
+}
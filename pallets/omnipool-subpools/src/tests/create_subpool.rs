@@ -0,0 +1,185 @@
+use super::*;
+use frame_support::assert_noop;
+use pallet_omnipool::types::AssetReserveState;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn create_subpool_should_work_when_assets_are_in_omnipool() {
+	subpool_ext().build().execute_with(|| {
+		// Act
+		create_usd_subpool();
+
+		// Assert
+		let shares = subpool_shares();
+		let usdt_shares = shares / 3;
+		let usdc_shares = shares - usdt_shares;
+
+		assert_balance!(Omnipool::protocol_account(), USDT, 0);
+		assert_balance!(Omnipool::protocol_account(), USDC, 0);
+		assert_balance!(pool_account(SHARE), USDT, 1000 * ONE);
+		assert_balance!(pool_account(SHARE), USDC, 1000 * ONE);
+		assert_balance!(Omnipool::protocol_account(), LRNA, NATIVE_AMOUNT + 2000 * ONE);
+
+		assert!(Omnipool::assets(USDT).is_none());
+		assert!(Omnipool::assets(USDC).is_none());
+		assert_eq!(
+			Omnipool::load_asset_state(SHARE).unwrap(),
+			AssetReserveState {
+				reserve: shares,
+				hub_reserve: 1500 * ONE,
+				shares,
+				protocol_shares: 0,
+				cap: FixedU128::one().into_inner(),
+				tradable: Tradability::default(),
+			}
+		);
+
+		assert_eq!(
+			OmnipoolSubpools::migrated_asset(USDT),
+			Some(MigratedAsset {
+				pool_id: SHARE,
+				reserve: 1000 * ONE,
+				shares: 1000 * ONE,
+				subpool_shares: usdt_shares,
+			})
+		);
+		assert_eq!(
+			OmnipoolSubpools::migrated_asset(USDC),
+			Some(MigratedAsset {
+				pool_id: SHARE,
+				reserve: 1000 * ONE,
+				shares: 1000 * ONE,
+				subpool_shares: usdc_shares,
+			})
+		);
+
+		expect_events(vec![
+			Event::AssetMigrated {
+				asset_id: USDT,
+				pool_id: SHARE,
+				reserve: 1000 * ONE,
+				hub_reserve: 500 * ONE,
+				shares: 1000 * ONE,
+				subpool_shares: usdt_shares,
+			}
+			.into(),
+			Event::AssetMigrated {
+				asset_id: USDC,
+				pool_id: SHARE,
+				reserve: 1000 * ONE,
+				hub_reserve: 1000 * ONE,
+				shares: 1000 * ONE,
+				subpool_shares: usdc_shares,
+			}
+			.into(),
+			Event::SubpoolCreated {
+				pool_id: SHARE,
+				assets: vec![USDT, USDC],
+				shares,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn create_subpool_should_convert_protocol_shares() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), 2));
+
+		// Act
+		create_usd_subpool();
+
+		// Assert
+		let usdt_shares = subpool_shares() / 3;
+		assert_eq!(Omnipool::load_asset_state(SHARE).unwrap().protocol_shares, usdt_shares);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_origin_is_not_authority() {
+	subpool_ext().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::signed(LP1),
+				SHARE,
+				vec![USDT, USDC].try_into().unwrap(),
+				100,
+				Permill::zero(),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_amplification_is_zero() {
+	subpool_ext().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE,
+				vec![USDT, USDC].try_into().unwrap(),
+				0,
+				Permill::zero(),
+			),
+			Error::<Test>::InvalidAmplification
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_native_asset_is_included() {
+	subpool_ext().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE,
+				vec![HDX, USDT].try_into().unwrap(),
+				100,
+				Permill::zero(),
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default()
+		.with_token(USDT, FixedU128::from_float(0.5), LP1, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE,
+					vec![USDT, USDC].try_into().unwrap(),
+					100,
+					Permill::zero(),
+				),
+				pallet_omnipool::Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_was_already_migrated() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		create_usd_subpool();
+
+		// Act & Assert
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE + 1,
+				vec![USDT, DAI].try_into().unwrap(),
+				100,
+				Permill::zero(),
+			),
+			Error::<Test>::AssetAlreadyMigrated
+		);
+	});
+}
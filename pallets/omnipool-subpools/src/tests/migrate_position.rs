@@ -0,0 +1,219 @@
+use super::*;
+use frame_support::assert_noop;
+use pallet_omnipool::types::{Position, QueuedWithdrawal};
+use pretty_assertions::assert_eq;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+
+const USDT_POSITION: u32 = 2;
+const USDC_POSITION: u32 = 3;
+
+#[test]
+fn migrate_position_should_convert_position_to_share_token() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		create_usd_subpool();
+		let usdt_shares = subpool_shares() / 3;
+
+		// Act
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP2),
+			USDT_POSITION
+		));
+
+		// Assert
+		let price_denominator =
+			multiply_by_rational_with_rounding(FixedU128::DIV, usdt_shares, 1000 * ONE, Rounding::Up).unwrap();
+		assert_eq!(
+			Omnipool::positions(USDT_POSITION),
+			Some(Position {
+				asset_id: SHARE,
+				amount: usdt_shares,
+				shares: usdt_shares,
+				price: (FixedU128::from_float(0.5).into_inner(), price_denominator),
+			})
+		);
+		expect_events(vec![Event::PositionMigrated {
+			position_id: USDT_POSITION,
+			asset_id: USDT,
+			pool_id: SHARE,
+			shares: 1000 * ONE,
+			subpool_shares: usdt_shares,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrated_positions_should_own_all_shares_of_share_token() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		create_usd_subpool();
+
+		// Act
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION
+		));
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDC_POSITION
+		));
+
+		// Assert
+		let usdt_position = Omnipool::positions(USDT_POSITION).unwrap();
+		let usdc_position = Omnipool::positions(USDC_POSITION).unwrap();
+		assert_eq!(
+			usdt_position.shares + usdc_position.shares,
+			Omnipool::load_asset_state(SHARE).unwrap().shares
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_position_was_migrated() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		create_usd_subpool();
+		let usdt_shares = subpool_shares() / 3;
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION
+		));
+
+		// Act
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION,
+			usdt_shares
+		));
+
+		// Assert
+		assert_balance_approx!(LP1, SHARE, usdt_shares, 10);
+		assert!(Omnipool::positions(USDT_POSITION).is_none());
+	});
+}
+
+#[test]
+fn migrate_position_should_reset_position_fee_growth() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		pallet_omnipool::PositionFeeGrowth::<Test>::insert(USDT_POSITION, FixedU128::from(2));
+		create_usd_subpool();
+
+		// Act
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION
+		));
+
+		// Assert
+		assert_eq!(Omnipool::position_fee_growth(USDT_POSITION), FixedU128::zero());
+	});
+}
+
+#[test]
+fn migrate_position_should_pay_fee_earnings_of_migrated_asset() {
+	subpool_ext()
+		.with_asset_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), HDX, USDT, 50 * ONE, 0));
+			let earnings = Omnipool::position_fee_earnings(USDT_POSITION).unwrap();
+			assert!(earnings > 0);
+			create_usd_subpool();
+			let balance = Tokens::free_balance(USDT, &LP1);
+
+			// Act
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP2),
+				USDT_POSITION
+			));
+
+			// Assert
+			assert_balance!(LP1, USDT, balance + earnings);
+		});
+}
+
+#[test]
+fn migrate_position_should_not_earn_share_token_fees_accrued_before_migration() {
+	subpool_ext()
+		.with_asset_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_usd_subpool();
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), HDX, SHARE, 50 * ONE, 0));
+			let share_fee_growth = Omnipool::asset_fee_growth(SHARE);
+			assert!(!share_fee_growth.is_zero());
+
+			// Act
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP1),
+				USDT_POSITION
+			));
+
+			// Assert
+			assert_eq!(Omnipool::position_fee_earnings(USDT_POSITION), Ok(0));
+			assert_eq!(Omnipool::position_fee_growth(USDT_POSITION), share_fee_growth);
+		});
+}
+
+#[test]
+fn migrate_position_should_convert_queued_withdrawal() {
+	subpool_ext().build().execute_with(|| {
+		// Arrange
+		pallet_omnipool::WithdrawalQueue::<Test>::insert(
+			USDT_POSITION,
+			QueuedWithdrawal {
+				owner: LP1,
+				asset_id: USDT,
+				remaining_shares: 400 * ONE,
+				shares_per_block: 100 * ONE,
+				remaining_limit: 300 * ONE,
+			},
+		);
+		create_usd_subpool();
+		let usdt_shares = subpool_shares() / 3;
+
+		// Act
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION
+		));
+
+		// Assert
+		assert_eq!(
+			Omnipool::queued_withdrawal(USDT_POSITION),
+			Some(QueuedWithdrawal {
+				owner: LP1,
+				asset_id: SHARE,
+				remaining_shares: usdt_shares * 2 / 5,
+				shares_per_block: (usdt_shares + 9) / 10,
+				remaining_limit: usdt_shares * 3 / 10,
+			})
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_asset_was_not_migrated() {
+	subpool_ext().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP1), USDT_POSITION),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_position_does_not_exist() {
+	subpool_ext().build().execute_with(|| {
+		create_usd_subpool();
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP1), 100),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
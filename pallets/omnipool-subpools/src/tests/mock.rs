@@ -0,0 +1,438 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_omnipool_subpools;
+use crate::*;
+
+use core::ops::RangeInclusive;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::{ConstU128, ConstU32, ConstU64, Everything};
+use frame_support::weights::Weight;
+use frame_support::{assert_ok, construct_runtime, parameter_types};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{registry::Inspect as InspectRegistry, AccountIdFor, AssetKind};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_omnipool::traits::ExternalPriceProvider;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::BuildStorage;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const USDT: AssetId = 3;
+pub const USDC: AssetId = 4;
+pub const SHARE: AssetId = 100;
+
+pub const LP1: AccountId = 1;
+pub const LP2: AccountId = 2;
+pub const LP3: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
+
+thread_local! {
+	pub static NFTS: RefCell<HashMap<u32, AccountId>> = RefCell::new(HashMap::default());
+	pub static ASSET_FEE: RefCell<Permill> = RefCell::new(Permill::zero());
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Omnipool: pallet_omnipool,
+		Stableswap: pallet_stableswap,
+		OmnipoolSubpools: pallet_omnipool_subpools,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const PositionCollectionId: u32 = 1000;
+	pub const MinTradingLimit: Balance = 1000;
+	pub const MinPoolLiquidity: Balance = 1000;
+	pub const ZeroFee: Permill = Permill::zero();
+	pub const WithdrawalQueueThreshold: Option<Permill> = None;
+	pub const HubAssetBuyPerBlock: Permill = Permill::from_percent(100);
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub const MaxAmplificationChangePerBlock: Option<Permill> = None;
+	pub const MaxRebalancePortion: Permill = Permill::from_percent(10);
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type PositionItemId = u32;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry;
+	type MinimumTradingLimit = MinTradingLimit;
+	type MinimumPoolLiquidity = MinPoolLiquidity;
+	type UpdateTradabilityOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = ConstU128<1>;
+	type MaxOutRatio = ConstU128<1>;
+	type CollectionId = u32;
+	type OmnipoolHooks = ();
+	type OnTradeExecuted = ();
+	type PriceBarrier = ();
+	type MinWithdrawalFee = ZeroFee;
+	type ExternalPriceOracle = SpotPriceOracle;
+	type PriceBandOracle = SpotPriceOracle;
+	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = WithdrawalQueueThreshold;
+	type WithdrawalStreamBlocks = ConstU32<4>;
+	type MaxQueuedWithdrawals = ConstU32<2>;
+	type MaxQueuedWithdrawalsPerAccount = ConstU32<2>;
+	type MaxHubAssetBuyPerBlock = HubAssetBuyPerBlock;
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type UpdateTradabilityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MaxAmplificationChangePerBlock = MaxAmplificationChangePerBlock;
+	type ParameterChangeDelay = ();
+	type GuardianOrigin = EnsureRoot<AccountId>;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxRebalancePortion = MaxRebalancePortion;
+	type RebalanceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type OnTradeExecuted = ();
+	type PegOracle = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		NFTS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		ASSET_FEE.with(|v| {
+			*v.borrow_mut() = Permill::zero();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+				(LP1, USDT, 5000 * ONE),
+				(LP2, USDC, 5000 * ONE),
+				(LP3, HDX, 1000 * ONE),
+			],
+			pool_tokens: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_token(mut self, asset_id: AssetId, price: FixedU128, owner: AccountId, amount: Balance) -> Self {
+		self.pool_tokens.push((asset_id, price, owner, amount));
+		self
+	}
+
+	pub fn with_asset_fee(self, fee: Permill) -> Self {
+		ASSET_FEE.with(|v| {
+			*v.borrow_mut() = fee;
+		});
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::add_token(
+				RuntimeOrigin::root(),
+				HDX,
+				FixedU128::from(1),
+				Permill::from_percent(100),
+				Omnipool::protocol_account(),
+			));
+			assert_ok!(Omnipool::add_token(
+				RuntimeOrigin::root(),
+				DAI,
+				FixedU128::from_float(0.5),
+				Permill::from_percent(100),
+				Omnipool::protocol_account(),
+			));
+
+			for (asset_id, price, owner, amount) in self.pool_tokens {
+				assert_ok!(Tokens::transfer(
+					RuntimeOrigin::signed(owner),
+					Omnipool::protocol_account(),
+					asset_id,
+					amount
+				));
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					asset_id,
+					price,
+					Permill::from_percent(50),
+					owner
+				));
+			}
+		});
+
+		r
+	}
+}
+
+pub struct DummyNFT;
+
+impl Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		NFTS.with(|v| v.borrow().get(instance).copied())
+	}
+}
+
+impl Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		NFTS.with(|v| v.borrow_mut().insert(*instance, *who));
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		NFTS.with(|v| v.borrow_mut().remove(instance));
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		true
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		[HDX, LRNA, DAI, USDT, USDC, SHARE].contains(&asset_id)
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		Self::exists(asset_id).then_some(12)
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(asset_id: Self::AssetId) -> Option<u128> {
+		Some(ExistentialDeposits::get(&asset_id))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_stableswap::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(_asset_id: AssetId, _decimals: u8) -> DispatchResult {
+		Ok(())
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<AssetId> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &AssetId, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as AccountId
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = identifier.map(|ident| ident.to_vec()).unwrap_or_default();
+		buf.extend_from_slice(&(asset).to_le_bytes());
+		buf
+	}
+}
+
+pub(crate) fn pool_account(pool_id: AssetId) -> AccountId {
+	AccountIdConstructor::from_assets(&pool_id, None)
+}
+
+pub struct Whitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
+	fn remove_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
+pub struct SpotPriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for SpotPriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(_asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		let state = Omnipool::load_asset_state(asset_b)?;
+		Ok(EmaPrice::new(state.hub_reserve, state.reserve))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(ASSET_FEE.with(|v| *v.borrow()), Permill::zero())
+	}
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,49 @@
+use crate::*;
+use frame_support::assert_ok;
+
+mod create_subpool;
+mod migrate_position;
+pub(crate) mod mock;
+
+use mock::*;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
+
+#[macro_export]
+macro_rules! assert_balance_approx {
+	( $x:expr, $y:expr, $z:expr, $l:expr) => {{
+		let b = Tokens::free_balance($y, &$x);
+
+		let diff = if $z >= b { $z - b } else { b - $z };
+		if diff > $l {
+			panic!("\nBalance not equal\n left: {}\nright: {}\n", b, $z);
+		};
+	}};
+}
+
+/// USDT and USDC listed in the Omnipool at different prices, owned by LP1 (position 2) and LP2 (position 3).
+pub(crate) fn subpool_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_token(USDT, FixedU128::from_float(0.5), LP1, 1000 * ONE)
+		.with_token(USDC, FixedU128::from(1), LP2, 1000 * ONE)
+}
+
+pub(crate) fn create_usd_subpool() {
+	assert_ok!(OmnipoolSubpools::create_subpool(
+		RuntimeOrigin::root(),
+		SHARE,
+		vec![USDT, USDC].try_into().unwrap(),
+		100,
+		Permill::zero(),
+	));
+}
+
+/// Share tokens minted by the subpool for the Omnipool.
+pub(crate) fn subpool_shares() -> Balance {
+	Tokens::free_balance(SHARE, &Omnipool::protocol_account())
+}
@@ -0,0 +1,119 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_omnipool_subpools`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: `migrate_position` weight is a hand-written estimate and was not produced by the benchmark
+//! CLI, see the comment on the function.
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --pallet=pallet-omnipool-subpools
+// --extrinsic=*
+// --template=scripts/pallet-weight-template.hbs
+// --output=./weights/pallet_omnipool_subpools.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_omnipool_subpools.
+pub trait WeightInfo {
+	fn create_subpool(n: u32, ) -> Weight;
+	fn migrate_position() -> Weight;
+}
+
+/// Weights for pallet_omnipool_subpools using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:5 w:5)
+	/// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:6 w:1)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::Pools` (r:1 w:1)
+	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:6 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:0 w:1)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::PoolPegs` (r:1 w:0)
+	/// Proof: `Stableswap::PoolPegs` (`max_values`: None, `max_size`: Some(401), added: 2876, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:11 w:11)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:0 w:5)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 5]`.
+	fn create_subpool(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2468 + n * (398 ±0)`
+		//  Estimated: `6156 + n * (5166 ±0)`
+		// Minimum execution time: 318_562_000 picoseconds.
+		Weight::from_parts(214_803_000, 6156)
+			// Standard Error: 187_224
+			.saturating_add(Weight::from_parts(71_256_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5166).saturating_mul(n.into()))
+	}
+	/// Storage: `Omnipool::Positions` (r:1 w:1)
+	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:1 w:0)
+	/// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::WithdrawalQueue` (r:1 w:1)
+	/// Proof: `Omnipool::WithdrawalQueue` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::QueuedWithdrawalsPerAsset` (r:2 w:2)
+	/// Storage: `Omnipool::QueuedWithdrawalsPerAccount` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:2 w:0)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::PositionFeeGrowth` (r:1 w:1)
+	/// Proof: `Omnipool::PositionFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// NOTE: Hand-written estimate, not produced by the benchmark CLI. Storage accesses above are
+	/// counted from the code, execution time and proof size are pessimistic guesses.
+	/// Must be regenerated with the `migrate_position` benchmark.
+	fn migrate_position() -> Weight {
+		Weight::from_parts(100_000_000, 12_000)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+}
@@ -0,0 +1,61 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SacrificedLiquidity<Balance> {
+	/// Shares which became protocol owned.
+	pub shares: Balance,
+	/// Amount of asset the shares were worth at the time of sacrifice.
+	pub amount: Balance,
+	/// Amount of hub asset the shares were worth at the time of sacrifice.
+	pub hub_amount: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct AssetSolvency<Balance> {
+	/// Reserve tracked in asset state.
+	pub reserve: Balance,
+	/// Balance of the asset in the pool account.
+	pub balance: Balance,
+	/// Quantity of LP shares for the asset.
+	pub shares: Balance,
+	/// Quantity of LP shares owned by protocol.
+	pub protocol_shares: Balance,
+	/// Sum of shares of all positions of the asset.
+	pub position_shares: Balance,
+	/// Balance covers the reserve and shares are consistent with positions.
+	pub solvent: bool,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct SolvencyReport<AssetId, Balance> {
+	/// Sum of hub reserves of all assets.
+	pub hub_reserve: Balance,
+	/// Balance of the hub asset in the pool account.
+	pub hub_balance: Balance,
+	/// Solvency of each asset in the pool.
+	pub assets: Vec<(AssetId, AssetSolvency<Balance>)>,
+	/// Hub asset and all assets are solvent.
+	pub solvent: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait OmnipoolApi<AssetId, Balance, PositionId> where
+		AssetId: Codec,
+		Balance: Codec,
+		PositionId: Codec,
+	{
+		/// Liquidity of an asset donated to the protocol by sacrificed positions.
+		fn sacrificed_liquidity(asset_id: AssetId) -> SacrificedLiquidity<Balance>;
+		/// Hub asset value of all sacrificed positions.
+		fn total_sacrificed_hub_amount() -> Balance;
+		/// Liquidity which would be donated to the protocol if the position was sacrificed now.
+		fn sacrifice_value(position_id: PositionId) -> Option<SacrificedLiquidity<Balance>>;
+		/// Tracked reserves and share issuance compared to balances of the pool account and shares of positions.
+		fn solvency_report() -> SolvencyReport<AssetId, Balance>;
+	}
+}
@@ -43,15 +43,95 @@
 //! Imbalance is always negative, internally represented by a special type `SimpleImbalance` which uses unsigned integer and boolean flag.
 //! This was done initially because of the intention that in future imbalance can also become positive.
 //!
+//! ### Buying hub asset
+//! Hub asset can be bought from the pool only when its tradable state contains `BUY`. The imbalance mechanism only
+//! partially protects LRNA against supply manipulation, so hub asset buys are further protected:
+//! - spot price of the asset sold for LRNA must be within `PriceBarrier` bounds of the oracle price,
+//! - amount of LRNA bought in a block is limited to `MaxHubAssetBuyPerBlock` of total hub asset reserve.
+//!
+//! ### Redeeming hub asset
+//! LRNA holders can redeem LRNA for any asset in the pool by `redeem_hub_asset`. Redemption is executed at spot price
+//! without slippage and does not change the imbalance, unlike selling LRNA which worsens it. Redeemed LRNA is added
+//! to the hub reserve of the asset and `HubAssetRedemptionFee` of the asset amount is accrued to LPs.
+//! Amount of LRNA redeemed in a block is limited to `MaxHubAssetRedemptionPerBlock` of total hub asset reserve.
+//!
+//! ### Hub asset buyback
+//! Protocol fees collected in the buyback account (`buyback_account`) can be used to buy LRNA back from the pool.
+//! When `AuthorityOrigin` sets the buyback parameters by `set_hub_asset_buyback`, a portion of the buyback account
+//! balance of each configured asset is sold for LRNA in `on_idle` once per buyback period. Bought LRNA is burned or
+//! sent to a sink account. Amount of LRNA bought in one buyback is capped and the buyback is subject to the same
+//! protections as other hub asset buys, except that it doesn't require hub asset to be tradable with `BUY`.
+//!
+//! ### Price protected trades
+//! `sell_with_price_protection` executes a sell only if the amount received is not lower than the sold amount valued
+//! at `ExternalPriceOracle` price by more than the given deviation. Trades at a price manipulated within the block
+//! are rejected. `min_amount_out_at_oracle_price` exposes the same check to routers and DCA.
+//!
+//! ### Commit-reveal trades
+//! Large trades can be protected from sandwiching by committing to the trade first. `commit_trade` stores a hash of
+//! the trade parameters, and the trade is executed by `reveal_sell` in a later block. Parameters of the trade are not
+//! known to anyone before the reveal, so the trade can't be front-run in the block it is committed in.
+//! Commitments which are not revealed within `TradeCommitmentExpiry` blocks expire.
+//!
 //! ### Omnipool Hooks
 //!
 //! Omnipool pallet supports multiple hooks which are triggerred on certain operations:
 //! - on_liquidity_changed - called when liquidity is added or removed from the pool
 //! - on_trade - called when trade is executed
-//! - on_trade_fee - called after successful trade with fee amount that can be taken out of the pool if needed.
 //!
 //! This is currently used to update on-chain oracle and in the circuit breaker.
 //!
+//! After each trade, `OnTradeExecuted` handler is called with the trade details and the asset fee amount that can be
+//! taken out of the pool if needed. It is shared with other pools, so fee skims are configured in one place.
+//!
+//! ### Withdrawal Queue
+//!
+//! Removal of liquidity which exceeds `WithdrawalQueueThreshold` of asset's shares is not executed at once.
+//! It is queued instead and streamed out of the pool in equal parts over `WithdrawalStreamBlocks` blocks in `on_initialize`.
+//! This protects the pool from being drained in a single block. Owner of the position can cancel queued withdrawal at any time.
+//! Number of queued withdrawals is limited per asset by `MaxQueuedWithdrawals` and per account by
+//! `MaxQueuedWithdrawalsPerAccount`. If a part of queued withdrawal fails, the rest of the withdrawal is cancelled.
+//! Safe withdrawal, when trading of the asset is disabled, is never queued.
+//!
+//! ### Fee accounting
+//!
+//! Part of the asset fee which is not taken out of the pool by `OnTradeExecuted` handler belongs to LPs. It is not left
+//! in the asset reserve but moved to the fee account (`fee_account`), so the fees do not change the price and
+//! are tracked precisely. Hub asset redemption fee is accrued the same way.
+//! Cumulative fee growth per share is tracked for each asset and snapshotted when a position is created.
+//! Fee earnings of a position are difference of these two multiplied by position's shares - see `position_fee_earnings`.
+//! They are paid to the owner by `claim_fees`, which snapshots the fee growth again, and when liquidity is removed
+//! or the position is sacrificed. Fees accrued by protocol's shares stay in the fee account until the asset is removed.
+//!
+//! ### Reserve accounting
+//!
+//! Reserve of each asset is tracked in asset state and is not read from balance of the pool account.
+//! Tokens transferred directly to the pool account do not change the price. Anyone can add such tokens to the reserve
+//! by `sync_reserve`, in which case they are added as liquidity owned by the protocol.
+//!
+//! `solvency_report` compares the tracked reserves with balances of the pool account and share issuance of each
+//! asset with shares of its positions. It is exposed by the runtime API for monitoring and checked in `try_state`.
+//!
+//! ### Frozen positions
+//!
+//! Other pallets can freeze a position by `freeze_position` instead of taking it over, e.g. liquidity mining while
+//! the position is deposited. Liquidity of a frozen position can't be removed and the position can't be sacrificed,
+//! but it stays owned by the LP.
+//!
+//! ### Funded listings
+//!
+//! Initial liquidity of a token can be provided by a funder instead of being transferred to the pool account
+//! beforehand. The funder approves the amount by `approve_token_funding` and `add_token_with_funder` transfers it
+//! to the pool when the token is added. Shares of the initial position can be vested, in which case they unlock
+//! linearly over the vesting period and only unlocked shares can be removed from the pool. The lister can't pull
+//! the seed liquidity out right after the listing, but the position can still be sacrificed.
+//!
+//! ### Price bands
+//!
+//! `AuthorityOrigin` can set a price band of an asset by `set_price_band`. Initial price of an asset with a price band
+//! and its spot price when liquidity is added must be within the band around `PriceBandOracle` price. Listings at
+//! a wrong price, which would be instantly arbitraged against LPs, are rejected.
+//!
 //! ## Terminology
 //!
 //! * **LP:**  liquidity provider
@@ -81,42 +161,68 @@
 //! * `refund_refused_asset` - Refunds the initial liquidity amount sent to pool account prior to add_token if the token has been refused to be added.
 //! * `sacrifice_position` - Destroys a position and position's shares become protocol's shares.
 //! * `withdraw_protocol_liquidity` - Withdraws protocol's liquidity from the pool. Used to withdraw liquidity from sacrificed position.
+//! * `cancel_withdrawal` - Cancels queued liquidity removal of a position.
+//! * `sync_reserve` - Adds tokens transferred directly to the pool account to asset reserve as protocol's liquidity.
+//! * `add_liquidity_from` - Trades any asset into selected asset by the router and adds it as liquidity to the pool.
+//! * `set_protocol_fee_split` - Sets destinations the protocol fee is split between.
+//! * `redeem_hub_asset` - Redeems hub asset for selected asset at spot price, reduced by redemption fee.
+//! * `sell_with_price_protection` - Sells asset, rejected if execution price is too far below oracle price.
+//! * `commit_trade` - Commits to a sell revealed in a later block.
+//! * `reveal_sell` - Executes sell committed to by `commit_trade`.
+//! * `approve_token_funding` - Approves amount of asset to be used as initial liquidity by `add_token_with_funder`.
+//! * `add_token_with_funder` - Adds token to the pool with initial liquidity of a funder, optionally vesting the initial shares.
+//! * `set_price_band` - Sets max deviation of asset's price from oracle price allowed when adding the asset or its liquidity.
+//! * `set_hub_asset_buyback` - Sets parameters of the periodic hub asset buyback executed in `on_idle`.
+//! * `claim_fees` - Pays trading fees earned by a position to its owner.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::require_transactional;
+use frame_support::storage::{with_transaction, TransactionOutcome};
 use frame_support::PalletId;
 use frame_support::{ensure, transactional};
-use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One};
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, Hash, One};
+use sp_runtime::traits::{CheckedAdd, CheckedSub, Saturating, Zero};
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::ops::{Add, Sub};
 use sp_std::prelude::*;
 
 use crate::traits::ShouldAllow;
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::DefensiveOption;
 use frame_system::ensure_signed;
-use frame_system::pallet_prelude::OriginFor;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use hydra_dx_math::ema::EmaPrice;
-use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, I129};
+use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, HubTradeStateChange, I129};
+use hydradx_traits::correlation::CorrelationId;
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
 use hydradx_traits::registry::Inspect as RegistryInspect;
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouterT, Trade};
+use hydradx_traits::AddSingleAssetLiquidity;
 use orml_traits::{GetByKey, MultiCurrency};
 #[cfg(feature = "try-runtime")]
 use primitive_types::U256;
 use scale_info::TypeInfo;
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding};
 
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod provider;
 pub mod router_execution;
 pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use crate::types::{
+	AssetReserveState, AssetSolvency, AssetState, Balance, HubAssetBuyback, Position, Price, ProtocolFeeDestination,
+	ProtocolFeeDestinations, QueuedWithdrawal, SacrificedLiquidity, SharesVesting, SimpleImbalance, SolvencyReport,
+	Tradability,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -131,14 +237,16 @@ pub mod pallet {
 	use crate::types::{Position, Price, Tradability};
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
-	use frame_support::traits::DefensiveOption;
 	use frame_system::pallet_prelude::*;
 	use hydra_dx_math::ema::EmaPrice;
 	use hydra_dx_math::omnipool::types::{BalanceUpdate, I129};
 	use orml_traits::GetByKey;
 	use sp_runtime::ArithmeticError;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -227,17 +335,69 @@ pub mod pallet {
 			Error = DispatchError,
 		>;
 
+		/// Handler called after each trade with the asset fee which can be taken out of the pool.
+		type OnTradeExecuted: OnTradeExecuted<Self::AccountId, Self::AssetId, Balance>;
+
 		/// Safety mechanism when adding and removing liquidity. Determines how much price can change between spot price and oracle price.
 		type PriceBarrier: ShouldAllow<Self::AccountId, Self::AssetId, EmaPrice>;
 
 		/// Oracle price provider. Provides price for given asset. Used in remove liquidity to support calculation of dynamic withdrawal fee.
 		type ExternalPriceOracle: ExternalPriceProvider<Self::AssetId, EmaPrice, Error = DispatchError>;
+
+		/// Oracle price provider used to validate prices of assets with a price band. Provides price of given asset
+		/// denominated in hub asset.
+		type PriceBandOracle: ExternalPriceProvider<Self::AssetId, EmaPrice, Error = DispatchError>;
+
+		/// Max fraction of asset's shares which can be removed from Omnipool at once.
+		/// Larger removals are queued and streamed out over `WithdrawalStreamBlocks` blocks. `None` disables the queue.
+		type WithdrawalQueueThreshold: Get<Option<Permill>>;
+
+		/// Number of blocks over which queued withdrawal is streamed out.
+		#[pallet::constant]
+		type WithdrawalStreamBlocks: Get<u32>;
+
+		/// Max number of withdrawals of an asset queued at the same time.
+		#[pallet::constant]
+		type MaxQueuedWithdrawals: Get<u32>;
+
+		/// Max number of withdrawals of an account queued at the same time.
+		#[pallet::constant]
+		type MaxQueuedWithdrawalsPerAccount: Get<u32>;
+
+		/// Max amount of hub asset which can be bought from Omnipool in a single block,
+		/// expressed as a fraction of total hub asset reserve.
+		#[pallet::constant]
+		type MaxHubAssetBuyPerBlock: Get<Permill>;
+
+		/// Fee charged on hub asset redemption. Taken from the redeemed asset and left in the pool.
+		#[pallet::constant]
+		type HubAssetRedemptionFee: Get<Permill>;
+
+		/// Max amount of hub asset which can be redeemed in a single block,
+		/// expressed as a fraction of total hub asset reserve.
+		#[pallet::constant]
+		type MaxHubAssetRedemptionPerBlock: Get<Permill>;
+
+		/// Router used to trade asset into the asset of the pool in `add_liquidity_from`.
+		type Router: RouteProvider<Self::AssetId>
+			+ RouterT<Self::RuntimeOrigin, Self::AssetId, Balance, Trade<Self::AssetId>, AmountInAndOut<Balance>>;
+
+		/// Weight information for the router trades.
+		type RouterWeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
+
+		/// Number of blocks after the commitment block in which a committed trade can be revealed.
+		#[pallet::constant]
+		type TradeCommitmentExpiry: Get<BlockNumberFor<Self>>;
+
+		/// Max number of trade commitments made in a single block.
+		#[pallet::constant]
+		type MaxTradeCommitmentsPerBlock: Get<u32>;
 	}
 
 	#[pallet::storage]
 	/// State of an asset in the omnipool
 	#[pallet::getter(fn assets)]
-	pub(super) type Assets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, AssetState<Balance>>;
+	pub type Assets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, AssetState<Balance>>;
 
 	#[pallet::storage]
 	/// Imbalance of hub asset
@@ -255,6 +415,14 @@ pub mod pallet {
 	pub(super) type HubAssetTradability<T: Config> =
 		StorageValue<_, Tradability, ValueQuery, DefaultHubAssetTradability>;
 
+	#[pallet::storage]
+	/// Amount of hub asset bought from Omnipool and the block it was bought in.
+	pub(super) type HubAssetBoughtInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, Balance), ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount of hub asset redeemed by `redeem_hub_asset` and the block it was redeemed in.
+	pub(super) type HubAssetRedeemedInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, Balance), ValueQuery>;
+
 	#[pallet::storage]
 	/// LP positions. Maps NFT instance id to corresponding position
 	#[pallet::getter(fn positions)]
@@ -266,6 +434,92 @@ pub mod pallet {
 	/// Position ids sequencer
 	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn queued_withdrawal)]
+	/// Large liquidity removals which are streamed out of Omnipool in `on_initialize`.
+	pub type WithdrawalQueue<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, T::PositionItemId, QueuedWithdrawal<T::AccountId, T::AssetId, Balance>>;
+
+	#[pallet::storage]
+	/// Number of queued withdrawals of an asset.
+	pub(super) type QueuedWithdrawalsPerAsset<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Number of queued withdrawals of an account.
+	pub(super) type QueuedWithdrawalsPerAccount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_fee_growth)]
+	/// Cumulative asset fee accrued to the fee account per share of an asset.
+	pub(super) type AssetFeeGrowth<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FixedU128, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn position_fee_growth)]
+	/// Asset fee growth of position's asset at the time the position was created or its fees were last claimed.
+	pub type PositionFeeGrowth<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, FixedU128, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sacrificed_liquidity)]
+	/// Total liquidity of an asset donated to the protocol by sacrificed positions.
+	pub type SacrificedLiquidityPerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, SacrificedLiquidity<Balance>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_split)]
+	/// Destinations of the protocol fee with their portions of the fee.
+	/// Part of the protocol fee which is not distributed goes to HDX subpool.
+	pub type ProtocolFeeSplit<T: Config> = StorageValue<_, ProtocolFeeDestinations<T::AccountId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trade_commitments)]
+	/// Unrevealed trade commitments of an account and the blocks they were committed in.
+	pub type TradeCommitments<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, T::Hash, BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	/// Trade commitments expiring at the beginning of a block.
+	pub(super) type TradeCommitmentExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, T::Hash), T::MaxTradeCommitmentsPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Positions frozen by `freeze_position`. Liquidity of a frozen position can't be removed and the position
+	/// can't be sacrificed.
+	pub type FrozenPositions<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn token_funding_approval)]
+	/// Amount of an asset a funder approved to be used as initial liquidity by `add_token_with_funder`.
+	pub type TokenFundingApprovals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AssetId, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn position_vesting)]
+	/// Vesting of initial shares of positions created by `add_token_with_funder`.
+	pub type PositionVesting<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PositionItemId, SharesVesting<BlockNumberFor<T>>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn price_band)]
+	/// Max deviation of asset's price from `PriceBandOracle` price allowed when the asset is added
+	/// or liquidity is added.
+	pub type PriceBands<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Permill, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn hub_asset_buyback)]
+	/// Parameters of the periodic hub asset buyback executed in `on_idle`. Buyback is disabled if not set.
+	pub type HubAssetBuybackConfig<T: Config> =
+		StorageValue<_, HubAssetBuyback<T::AccountId, T::AssetId, BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Block the last hub asset buyback was executed in.
+	pub(super) type LastHubAssetBuyback<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -288,6 +542,15 @@ pub mod pallet {
 			amount: Balance,
 			position_id: T::PositionItemId,
 		},
+		/// Liquidity of an asset was added to Omnipool from another asset traded by the router.
+		LiquidityAddedFrom {
+			who: T::AccountId,
+			asset_in: T::AssetId,
+			amount_in: Balance,
+			asset_id: T::AssetId,
+			amount: Balance,
+			position_id: T::PositionItemId,
+		},
 		/// Liquidity of an asset was removed from Omnipool.
 		LiquidityRemoved {
 			who: T::AccountId,
@@ -295,6 +558,7 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			shares_removed: Balance,
 			fee: FixedU128,
+			fee_earnings: Balance,
 		},
 		/// PRotocol Liquidity was removed from Omnipool.
 		ProtocolLiquidityRemoved {
@@ -315,6 +579,7 @@ pub mod pallet {
 			hub_amount_out: Balance,
 			asset_fee_amount: Balance,
 			protocol_fee_amount: Balance,
+			correlation_id: CorrelationId,
 		},
 		/// Buy trade executed.
 		BuyExecuted {
@@ -327,6 +592,7 @@ pub mod pallet {
 			hub_amount_out: Balance,
 			asset_fee_amount: Balance,
 			protocol_fee_amount: Balance,
+			correlation_id: CorrelationId,
 		},
 		/// LP Position was created and NFT instance minted.
 		PositionCreated {
@@ -342,6 +608,15 @@ pub mod pallet {
 			position_id: T::PositionItemId,
 			owner: T::AccountId,
 		},
+		/// LP Position was sacrificed and its shares became protocol owned.
+		PositionSacrificed {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_id: T::AssetId,
+			shares: Balance,
+			amount: Balance,
+			hub_amount: Balance,
+		},
 		/// LP Position was updated.
 		PositionUpdated {
 			position_id: T::PositionItemId,
@@ -363,6 +638,104 @@ pub mod pallet {
 
 		/// Asset's weight cap has been updated.
 		AssetWeightCapUpdated { asset_id: T::AssetId, cap: Permill },
+
+		/// Liquidity removal was queued and is streamed out over several blocks.
+		WithdrawalQueued {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			shares: Balance,
+			shares_per_block: Balance,
+		},
+
+		/// Queued liquidity removal was cancelled.
+		WithdrawalCancelled {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			remaining_shares: Balance,
+		},
+
+		/// Part of queued liquidity removal failed. The rest of the removal is cancelled.
+		QueuedWithdrawalFailed {
+			position_id: T::PositionItemId,
+			error: DispatchError,
+		},
+
+		/// Tokens transferred directly to the pool account were added to asset reserve as protocol shares.
+		ReserveSynced {
+			asset_id: T::AssetId,
+			amount: Balance,
+			shares: Balance,
+		},
+
+		/// Protocol fee split has been updated.
+		ProtocolFeeSplitUpdated {
+			destinations: ProtocolFeeDestinations<T::AccountId>,
+		},
+
+		/// Portion of protocol fee has been distributed to the destination.
+		ProtocolFeeDistributed {
+			destination: ProtocolFeeDestination<T::AccountId>,
+			amount: Balance,
+		},
+
+		/// Hub asset was redeemed for an asset at spot price.
+		HubAssetRedeemed {
+			who: T::AccountId,
+			asset_out: T::AssetId,
+			amount: Balance,
+			amount_out: Balance,
+			redemption_fee: Balance,
+		},
+		/// Trade was committed to. It can be revealed until `expires_at` block.
+		TradeCommitted {
+			who: T::AccountId,
+			commitment: T::Hash,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Committed trade was revealed and executed.
+		TradeRevealed { who: T::AccountId, commitment: T::Hash },
+		/// Trade commitment expired without being revealed.
+		TradeCommitmentExpired { who: T::AccountId, commitment: T::Hash },
+		/// Funder approved amount of an asset to be used as initial liquidity of the asset.
+		TokenFundingApproved {
+			funder: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
+		/// Shares of a position are vested and unlock linearly from `start` over `period` blocks.
+		PositionVestingSet {
+			position_id: T::PositionItemId,
+			shares: Balance,
+			start: BlockNumberFor<T>,
+			period: BlockNumberFor<T>,
+		},
+
+		/// Price band of an asset has been set or removed.
+		PriceBandSet {
+			asset_id: T::AssetId,
+			band: Option<Permill>,
+		},
+		/// Parameters of the hub asset buyback were set. `None` disables the buyback.
+		HubAssetBuybackSet {
+			config: Option<HubAssetBuyback<T::AccountId, T::AssetId, BlockNumberFor<T>>>,
+		},
+		/// Hub asset was bought from Omnipool with protocol fees of the buyback account.
+		HubAssetBoughtBack {
+			asset_id: T::AssetId,
+			amount_in: Balance,
+			hub_amount: Balance,
+			destination: ProtocolFeeDestination<T::AccountId>,
+		},
+		/// Buyback of hub asset with an asset failed. Balance of the asset stays in the buyback account.
+		HubAssetBuybackFailed { asset_id: T::AssetId, error: DispatchError },
+		/// Trading fees earned by a position were paid to the owner from the fee account.
+		FeesClaimed {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -432,6 +805,48 @@ pub mod pallet {
 		ExistentialDepositNotAvailable,
 		/// Slippage protection
 		SlippageLimit,
+		/// Liquidity removal of the position is already queued.
+		WithdrawalAlreadyQueued,
+		/// Max number of queued withdrawals of the asset has been reached.
+		WithdrawalQueueFull,
+		/// Liquidity removal of the position is not queued.
+		WithdrawalNotQueued,
+		/// Pool account balance of the asset does not exceed tracked reserve.
+		NothingToSync,
+		/// Max amount of hub asset which can be bought in a block has been exceeded.
+		MaxHubAssetBuyPerBlockExceeded,
+		/// Sum of protocol fee portions exceeds 100%.
+		InvalidProtocolFeeSplit,
+		/// Max amount of hub asset which can be redeemed in a block has been exceeded.
+		MaxHubAssetRedemptionPerBlockExceeded,
+		/// Execution price of a trade is lower than oracle price by more than allowed deviation.
+		TradePriceDeviationTooHigh,
+		/// Account has already committed to the same trade.
+		TradeCommitmentAlreadyExists,
+		/// Trade commitment does not exist or has expired.
+		TradeCommitmentNotFound,
+		/// Committed trade can't be revealed in the block it was committed in.
+		TradeCommitmentNotMature,
+		/// Max number of trade commitments in a block has been reached.
+		MaxTradeCommitmentsPerBlockExceeded,
+		/// Position is frozen.
+		PositionFrozen,
+		/// Position is not frozen.
+		PositionNotFrozen,
+		/// Funder has not approved funding of the asset.
+		TokenFundingNotApproved,
+		/// Vesting period must be greater than zero.
+		InvalidVestingPeriod,
+		/// Shares of the position are still vesting.
+		SharesVesting,
+		/// Price of the asset is outside of the price band around the oracle price.
+		PriceOutsideOracleBand,
+		/// Hub asset buyback parameters are invalid.
+		InvalidHubAssetBuyback,
+		/// Position has no fee earnings to claim.
+		NothingToClaim,
+		/// Max number of queued withdrawals of the account has been reached.
+		TooManyQueuedWithdrawals,
 	}
 
 	#[pallet::call]
@@ -450,10 +865,15 @@ pub mod pallet {
 		/// - `position_owner`: account id for which share are distributed in form on NFT
 		/// - `weight_cap`: asset weight cap
 		///
+		/// If the asset has a price band, `initial_price` must be within the band around `PriceBandOracle` price.
+		///
 		/// Emits `TokenAdded` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_token().saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[pallet::weight(<T as Config>::WeightInfo::add_token()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::PriceBandOracle::get_price_weight())
+		)]
 		#[transactional]
 		pub fn add_token(
 			origin: OriginFor<T>,
@@ -464,97 +884,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin.clone())?;
 
-			ensure!(!Assets::<T>::contains_key(asset), Error::<T>::AssetAlreadyAdded);
-
-			ensure!(T::AssetRegistry::exists(asset), Error::<T>::AssetNotRegistered);
-
-			ensure!(initial_price > FixedU128::zero(), Error::<T>::InvalidInitialAssetPrice);
-
-			// ensure collection is created, we can simply ignore the error if it was already created.
-			let _ = T::NFTHandler::create_collection(
-				&T::NFTCollectionId::get(),
-				&Self::protocol_account(),
-				&Self::protocol_account(),
-			);
-
-			let amount = T::Currency::free_balance(asset, &Self::protocol_account());
-
-			let ed = T::AssetRegistry::existential_deposit(asset).ok_or(Error::<T>::ExistentialDepositNotAvailable)?;
-			let minimum_pool_liquidity = ed.saturating_mul(20);
-
-			ensure!(ed > 0 && amount >= minimum_pool_liquidity, Error::<T>::MissingBalance);
-
-			let hub_reserve = initial_price.checked_mul_int(amount).ok_or(ArithmeticError::Overflow)?;
-
-			// Initial state of asset
-			let state = AssetState::<Balance> {
-				hub_reserve,
-				shares: amount,
-				protocol_shares: Balance::zero(),
-				cap: FixedU128::from(weight_cap).into_inner(),
-				tradable: Tradability::default(),
-			};
-
-			let lp_position = Position::<Balance, T::AssetId> {
-				asset_id: asset,
-				amount,
-				shares: amount,
-				price: (initial_price.into_inner(), FixedU128::DIV),
-			};
-
-			let instance_id = Self::create_and_mint_position_instance(&position_owner)?;
-
-			<Positions<T>>::insert(instance_id, lp_position);
-
-			Self::deposit_event(Event::PositionCreated {
-				position_id: instance_id,
-				owner: position_owner,
-				asset,
-				amount,
-				shares: amount,
-				price: initial_price,
-			});
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-
-			let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
-				hub_reserve,
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
-
-			Self::update_imbalance(BalanceUpdate::Decrease(delta_imbalance))?;
-
-			let delta_hub_reserve = BalanceUpdate::Increase(hub_reserve);
-			Self::update_hub_asset_liquidity(&delta_hub_reserve)?;
-
-			let reserve = T::Currency::free_balance(asset, &Self::protocol_account());
-
-			let reserve_state: AssetReserveState<_> = (state.clone(), reserve).into();
-			let changes = AssetStateChange {
-				delta_hub_reserve,
-				delta_reserve: BalanceUpdate::Increase(reserve),
-				delta_shares: BalanceUpdate::Increase(amount),
-				delta_protocol_shares: BalanceUpdate::Increase(Balance::zero()),
-			};
-			T::OmnipoolHooks::on_liquidity_changed(
-				origin,
-				AssetInfo::new(asset, &AssetReserveState::default(), &reserve_state, &changes, false),
-			)?;
-
-			<Assets<T>>::insert(asset, state);
-
-			Self::deposit_event(Event::TokenAdded {
-				asset_id: asset,
-				initial_amount: amount,
-				initial_price,
-			});
+			Self::do_add_token(origin, asset, initial_price, weight_cap, position_owner)?;
 
 			Ok(())
 		}
@@ -571,7 +901,8 @@ pub mod pallet {
 		/// Asset weight cap must be respected, otherwise `AssetWeightExceeded` error is returned.
 		/// Asset weight is ratio between new HubAsset reserve and total reserve of Hub asset in Omnipool.
 		///
-		/// Add liquidity fails if price difference between spot price and oracle price is higher than allowed by `PriceBarrier`.
+		/// Add liquidity fails if price difference between spot price and oracle price is higher than allowed by `PriceBarrier`,
+		/// or if the asset has a price band and spot price is outside of the band around `PriceBandOracle` price.
 		///
 		/// Parameters:
 		/// - `asset`: The identifier of the new asset added to the pool. Must be already in the pool
@@ -582,7 +913,8 @@ pub mod pallet {
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
 			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()
-			.saturating_add(T::ExternalPriceOracle::get_price_weight()))
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+			.saturating_add(T::PriceBandOracle::get_price_weight()))
 		)]
 		#[transactional]
 		pub fn add_liquidity(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
@@ -603,7 +935,8 @@ pub mod pallet {
 		/// Asset weight cap must be respected, otherwise `AssetWeightExceeded` error is returned.
 		/// Asset weight is ratio between new HubAsset reserve and total reserve of Hub asset in Omnipool.
 		///
-		/// Add liquidity fails if price difference between spot price and oracle price is higher than allowed by `PriceBarrier`.
+		/// Add liquidity fails if price difference between spot price and oracle price is higher than allowed by `PriceBarrier`,
+		/// or if the asset has a price band and spot price is outside of the band around `PriceBandOracle` price.
 		///
 		/// Parameters:
 		/// - `asset`: The identifier of the new asset added to the pool. Must be already in the pool
@@ -615,7 +948,8 @@ pub mod pallet {
 		#[pallet::call_index(13)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
 		.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()
-		.saturating_add(T::ExternalPriceOracle::get_price_weight()))
+		.saturating_add(T::ExternalPriceOracle::get_price_weight())
+		.saturating_add(T::PriceBandOracle::get_price_weight()))
 		)]
 		#[transactional]
 		pub fn add_liquidity_with_limit(
@@ -642,6 +976,10 @@ pub mod pallet {
 		/// Dynamic withdrawal fee is applied if withdrawal is not safe. It is calculated using spot price and external price oracle.
 		/// Withdrawal is considered safe when trading is disabled.
 		///
+		/// If `amount` exceeds `WithdrawalQueueThreshold` of asset's shares, removal is queued and streamed out
+		/// over `WithdrawalStreamBlocks` blocks. `WithdrawalQueued` event is emitted instead in such case.
+		/// Safe withdrawal is never queued.
+		///
 		/// Parameters:
 		/// - `position_id`: The identifier of position which liquidity is removed from.
 		/// - `amount`: Amount of shares removed from omnipool
@@ -674,6 +1012,10 @@ pub mod pallet {
 		/// Dynamic withdrawal fee is applied if withdrawal is not safe. It is calculated using spot price and external price oracle.
 		/// Withdrawal is considered safe when trading is disabled.
 		///
+		/// If `amount` exceeds `WithdrawalQueueThreshold` of asset's shares, removal is queued and streamed out
+		/// over `WithdrawalStreamBlocks` blocks. `WithdrawalQueued` event is emitted instead in such case.
+		/// Safe withdrawal is never queued.
+		///
 		/// Parameters:
 		/// - `position_id`: The identifier of position which liquidity is removed from.
 		/// - `amount`: Amount of shares removed from omnipool
@@ -692,194 +1034,95 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
-			ensure!(amount > Balance::zero(), Error::<T>::InvalidSharesAmount);
-
 			ensure!(
-				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
-				Error::<T>::Forbidden
+				!WithdrawalQueue::<T>::contains_key(position_id),
+				Error::<T>::WithdrawalAlreadyQueued
 			);
 
-			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			if let Some(threshold) = T::WithdrawalQueueThreshold::get() {
+				let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+				let asset_state = Self::load_asset_state(position.asset_id)?;
 
-			ensure!(position.shares >= amount, Error::<T>::InsufficientShares);
+				// Safe withdrawal - trading is disabled, so there is nothing to protect the pool from.
+				if !asset_state.tradable.is_safe_withdrawal() && amount > threshold.mul_floor(asset_state.shares) {
+					return Self::queue_withdrawal(who, position_id, position, asset_state, amount, min_limit);
+				}
+			}
 
-			let asset_id = position.asset_id;
+			Self::do_remove_liquidity(origin, who, position_id, amount, min_limit)
+		}
 
-			let asset_state = Self::load_asset_state(asset_id)?;
+		/// Sacrifice LP position in favor of pool.
+		///
+		/// A position is destroyed and liquidity owned by LP becomes pool owned liquidity.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Value of the position at current spot price is added to the sacrificed liquidity of the asset.
+		///
+		/// Emits `PositionSacrificed` and `PositionDestroyed`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::sacrifice_position())]
+		#[transactional]
+		pub fn sacrifice_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
 
 			ensure!(
-				asset_state.tradable.contains(Tradability::REMOVE_LIQUIDITY),
-				Error::<T>::NotAllowed
+				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+				Error::<T>::Forbidden
 			);
 
-			let safe_withdrawal = asset_state.tradable.is_safe_withdrawal();
-			// Skip price check if safe withdrawal - trading disabled.
-			if !safe_withdrawal {
-				T::PriceBarrier::ensure_price(
-					&who,
-					T::HubAssetId::get(),
-					asset_id,
-					EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
-				)
-				.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
-			}
-			let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+			ensure!(!Self::is_position_frozen(position_id), Error::<T>::PositionFrozen);
 
-			if ext_asset_price.is_zero() {
-				return Err(Error::<T>::InvalidOraclePrice.into());
-			}
-			let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
-				asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-				FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
-					.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
-				T::MinWithdrawalFee::get(),
-			);
+			// Fees earned so far are paid to the owner, sacrificed shares accrue fees for the protocol from now on.
+			let fee_earnings = Self::calculate_fee_earnings(position_id, position.asset_id, position.shares)?;
+			Self::pay_fee_earnings(position.asset_id, &who, fee_earnings)?;
 
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+			let sacrificed = Self::sacrifice_value(position_id)?;
 
-			//
-			// calculate state changes of remove liquidity
-			//
-			let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
-				&(&asset_state).into(),
-				amount,
-				&(&position).into(),
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
-				withdrawal_fee,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+			Assets::<T>::try_mutate(position.asset_id, |maybe_asset| -> DispatchResult {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
 
-			ensure!(
-				*state_changes.asset.delta_reserve >= min_limit,
-				Error::<T>::SlippageLimit
-			);
+				asset_state.protocol_shares = asset_state
+					.protocol_shares
+					.checked_add(position.shares)
+					.ok_or(ArithmeticError::Overflow)?;
 
-			let new_asset_state = asset_state
-				.delta_update(&state_changes.asset)
-				.ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
 
-			// Update position state
-			let updated_position = position
-				.delta_update(
-					&state_changes.delta_position_reserve,
-					&state_changes.delta_position_shares,
-				)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			T::Currency::transfer(
-				asset_id,
-				&Self::protocol_account(),
-				&who,
-				*state_changes.asset.delta_reserve,
-			)?;
-
-			Self::update_imbalance(state_changes.delta_imbalance)?;
-
-			// burn only difference between delta hub and lp hub amount.
-			Self::update_hub_asset_liquidity(
-				&state_changes
-					.asset
-					.delta_hub_reserve
-					.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
-					.ok_or(ArithmeticError::Overflow)?,
-			)?;
-
-			// LP receives some hub asset
-			Self::process_hub_amount(state_changes.lp_hub_amount, &who)?;
-
-			if updated_position.shares == Balance::zero() {
-				// All liquidity removed, remove position and burn NFT instance
-
-				<Positions<T>>::remove(position_id);
-				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
-
-				Self::deposit_event(Event::PositionDestroyed {
-					position_id,
-					owner: who.clone(),
-				});
-			} else {
-				Self::deposit_event(Event::PositionUpdated {
-					position_id,
-					owner: who.clone(),
-					asset: asset_id,
-					amount: updated_position.amount,
-					shares: updated_position.shares,
-					price: updated_position
-						.price_from_rational()
-						.ok_or(ArithmeticError::DivisionByZero)?,
-				});
-
-				<Positions<T>>::insert(position_id, updated_position);
-			}
-
-			// Callback hook info
-			let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
-				asset_id,
-				&asset_state,
-				&new_asset_state,
-				&state_changes.asset,
-				safe_withdrawal,
-			);
-
-			Self::set_asset_state(asset_id, new_asset_state);
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who,
-				position_id,
-				asset_id,
-				shares_removed: amount,
-				fee: withdrawal_fee,
-			});
-
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
-
-			#[cfg(feature = "try-runtime")]
-			Self::ensure_liquidity_invariant((asset_id, asset_state, new_asset_state));
-
-			Ok(())
-		}
-
-		/// Sacrifice LP position in favor of pool.
-		///
-		/// A position is destroyed and liquidity owned by LP becomes pool owned liquidity.
-		///
-		/// Only owner of position can perform this action.
-		///
-		/// Emits `PositionDestroyed`.
-		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::sacrifice_position())]
-		#[transactional]
-		pub fn sacrifice_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
-
-			ensure!(
-				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
-				Error::<T>::Forbidden
-			);
-
-			Assets::<T>::try_mutate(position.asset_id, |maybe_asset| -> DispatchResult {
-				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
-
-				asset_state.protocol_shares = asset_state
-					.protocol_shares
-					.checked_add(position.shares)
-					.ok_or(ArithmeticError::Overflow)?;
-
-				Ok(())
-			})?;
+			SacrificedLiquidityPerAsset::<T>::try_mutate(position.asset_id, |total| -> DispatchResult {
+				total.shares = total
+					.shares
+					.checked_add(sacrificed.shares)
+					.ok_or(ArithmeticError::Overflow)?;
+				total.amount = total
+					.amount
+					.checked_add(sacrificed.amount)
+					.ok_or(ArithmeticError::Overflow)?;
+				total.hub_amount = total
+					.hub_amount
+					.checked_add(sacrificed.hub_amount)
+					.ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
 
 			// Destroy position and burn NFT
 			<Positions<T>>::remove(position_id);
+			<PositionFeeGrowth<T>>::remove(position_id);
+			<PositionVesting<T>>::remove(position_id);
 			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
 
+			Self::deposit_event(Event::PositionSacrificed {
+				position_id,
+				owner: who.clone(),
+				asset_id: position.asset_id,
+				shares: sacrificed.shares,
+				amount: sacrificed.amount,
+				hub_amount: sacrificed.hub_amount,
+			});
 			Self::deposit_event(Event::PositionDestroyed {
 				position_id,
 				owner: who,
@@ -908,6 +1151,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
 			.saturating_add(T::OmnipoolHooks::on_trade_weight())
 			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::Omnipool))
 		)]
 		#[transactional]
 		pub fn sell(
@@ -938,7 +1182,8 @@ pub mod pallet {
 			}
 
 			if asset_out == T::HubAssetId::get() {
-				return Self::sell_asset_for_hub_asset(&who, asset_in, amount, min_buy_amount);
+				Self::ensure_hub_asset_tradable_for_buy()?;
+				return Self::sell_asset_for_hub_asset(origin, &who, asset_in, amount, min_buy_amount);
 			}
 
 			let asset_in_state = Self::load_asset_state(asset_in)?;
@@ -1067,9 +1312,22 @@ pub mod pallet {
 
 			T::OmnipoolHooks::on_trade(origin.clone(), info_in, info_out)?;
 
-			Self::update_hdx_subpool_hub_asset(origin, state_changes.hdx_hub_amount)?;
+			Self::distribute_protocol_fee(origin, state_changes.hdx_hub_amount)?;
 
-			Self::process_trade_fee(&who, asset_out, state_changes.fee.asset_fee)?;
+			Self::process_trade_fee(TradeInfo {
+				pool: PoolType::Omnipool,
+				trader: who.clone(),
+				fee_account: Self::protocol_account(),
+				asset_in,
+				asset_out,
+				amount_in: *state_changes.asset_in.delta_reserve,
+				amount_out: *state_changes.asset_out.delta_reserve,
+				reserve_in: asset_in_state.reserve,
+				reserve_out: asset_out_state.reserve,
+				fee_asset: asset_out,
+				fee_amount: state_changes.fee.asset_fee,
+				protocol_fee: state_changes.fee.protocol_fee,
+			})?;
 
 			debug_assert!(*state_changes.asset_in.delta_hub_reserve >= *state_changes.asset_out.delta_hub_reserve);
 			debug_assert_eq!(
@@ -1087,6 +1345,7 @@ pub mod pallet {
 				hub_amount_out: *state_changes.asset_out.delta_hub_reserve,
 				asset_fee_amount: state_changes.fee.asset_fee,
 				protocol_fee_amount: state_changes.fee.protocol_fee,
+				correlation_id: CorrelationId::current::<T>(),
 			});
 
 			#[cfg(feature = "try-runtime")]
@@ -1118,6 +1377,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
 			.saturating_add(T::OmnipoolHooks::on_trade_weight())
 			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::Omnipool))
 		)]
 		#[transactional]
 		pub fn buy(
@@ -1138,7 +1398,8 @@ pub mod pallet {
 
 			// Special handling when one of the asset is Hub Asset
 			if asset_out == T::HubAssetId::get() {
-				return Self::buy_hub_asset(&who, asset_in, amount, max_sell_amount);
+				Self::ensure_hub_asset_tradable_for_buy()?;
+				return Self::buy_hub_asset(origin, &who, asset_in, amount, max_sell_amount);
 			}
 
 			if asset_in == T::HubAssetId::get() {
@@ -1271,9 +1532,22 @@ pub mod pallet {
 
 			T::OmnipoolHooks::on_trade(origin.clone(), info_in, info_out)?;
 
-			Self::update_hdx_subpool_hub_asset(origin, state_changes.hdx_hub_amount)?;
+			Self::distribute_protocol_fee(origin, state_changes.hdx_hub_amount)?;
 
-			Self::process_trade_fee(&who, asset_out, state_changes.fee.asset_fee)?;
+			Self::process_trade_fee(TradeInfo {
+				pool: PoolType::Omnipool,
+				trader: who.clone(),
+				fee_account: Self::protocol_account(),
+				asset_in,
+				asset_out,
+				amount_in: *state_changes.asset_in.delta_reserve,
+				amount_out: *state_changes.asset_out.delta_reserve,
+				reserve_in: asset_in_state.reserve,
+				reserve_out: asset_out_state.reserve,
+				fee_asset: asset_out,
+				fee_amount: state_changes.fee.asset_fee,
+				protocol_fee: state_changes.fee.protocol_fee,
+			})?;
 
 			debug_assert!(*state_changes.asset_in.delta_hub_reserve >= *state_changes.asset_out.delta_hub_reserve);
 			debug_assert_eq!(
@@ -1291,6 +1565,7 @@ pub mod pallet {
 				hub_amount_out: *state_changes.asset_out.delta_hub_reserve,
 				asset_fee_amount: state_changes.fee.asset_fee,
 				protocol_fee_amount: state_changes.fee.protocol_fee,
+				correlation_id: CorrelationId::current::<T>(),
 			});
 
 			#[cfg(feature = "try-runtime")]
@@ -1322,7 +1597,7 @@ pub mod pallet {
 
 			if asset_id == T::HubAssetId::get() {
 				// Atm omnipool does not allow adding/removing liquidity of hub asset.
-				// Although BUY is not supported yet, we can allow the new state to be set to SELL/BUY.
+				// BUY allows hub asset to be bought, limited by `MaxHubAssetBuyPerBlock`.
 				ensure!(
 					!state.contains(Tradability::ADD_LIQUIDITY) && !state.contains(Tradability::REMOVE_LIQUIDITY),
 					Error::<T>::InvalidHubAssetTradableState
@@ -1549,7 +1824,14 @@ pub mod pallet {
 
 			T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), asset_state.hub_reserve)?;
 			T::Currency::transfer(asset_id, &Self::protocol_account(), &beneficiary, asset_state.reserve)?;
+			// No LP shares are left and fees of protocol's shares stay in the reserve, so only rounding
+			// remainders of the asset fee can be left in the fee account.
+			let accrued_fees = T::Currency::free_balance(asset_id, &Self::fee_account());
+			if !accrued_fees.is_zero() {
+				T::Currency::transfer(asset_id, &Self::fee_account(), &beneficiary, accrued_fees)?;
+			}
 			<Assets<T>>::remove(asset_id);
+			<AssetFeeGrowth<T>>::remove(asset_id);
 			Self::deposit_event(Event::TokenRemoved {
 				asset_id,
 				amount: asset_state.reserve,
@@ -1557,154 +1839,1320 @@ pub mod pallet {
 			});
 			Ok(())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn integrity_test() {
-			assert_ne!(
-				T::MinimumPoolLiquidity::get(),
-				Balance::zero(),
-				"Minimum pool liquidity is 0."
-			);
-			assert_ne!(
-				T::MinimumTradingLimit::get(),
-				Balance::zero(),
-				"Minimum trading limit is 0."
-			);
-			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
-			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
-		}
+		/// Cancel queued liquidity removal of a position.
+		///
+		/// Shares which have not been removed yet stay in the position.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which liquidity removal is cancelled.
+		///
+		/// Emits `WithdrawalCancelled` event when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_withdrawal())]
+		#[transactional]
+		pub fn cancel_withdrawal(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		#[cfg(feature = "try-runtime")]
-		fn try_state(_n: BlockNumberFor<T>) -> Result<(), DispatchError> {
-			use sp_std::collections::btree_map::BTreeMap;
-			let asset_hub_amount: Balance = Assets::<T>::iter_values().map(|v| v.hub_reserve).sum();
-			let account_balance = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-			assert_eq!(
-				account_balance, asset_hub_amount,
-				"LRNA amount in assets != amount in account"
-			);
+			let withdrawal = WithdrawalQueue::<T>::get(position_id).ok_or(Error::<T>::WithdrawalNotQueued)?;
+
+			ensure!(withdrawal.owner == who, Error::<T>::Forbidden);
+
+			Self::dequeue_withdrawal(position_id, &withdrawal);
+
+			Self::deposit_event(Event::WithdrawalCancelled {
+				who,
+				position_id,
+				remaining_shares: withdrawal.remaining_shares,
+			});
 
-			let mut shares: BTreeMap<T::AssetId, u128> = BTreeMap::new();
-			for position in Positions::<T>::iter_values() {
-				if let Some(current) = shares.get(&position.asset_id) {
-					shares.insert(position.asset_id, position.shares + current);
-				} else {
-					shares.insert(position.asset_id, position.shares);
-				}
-			}
-			for (asset_id, total) in shares.into_iter() {
-				let state = Assets::<T>::get(asset_id).unwrap();
-				assert_eq!(
-					state.shares - state.protocol_shares,
-					total,
-					"Asset {:?} shares in positions is not equal to shares in asset state",
-					asset_id
-				);
-			}
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Protocol account address
-	pub fn protocol_account() -> T::AccountId {
-		PalletId(*b"omnipool").into_account_truncating()
-	}
+		/// Add tokens transferred directly to the pool account to the asset reserve.
+		///
+		/// Asset reserves are tracked in asset state, so tokens transferred to the pool account outside of
+		/// omnipool operations do not affect the price. This adds such balance to the reserve as liquidity
+		/// owned by the protocol, so the price is not changed.
+		///
+		/// Asset weight cap is not checked.
+		///
+		/// Can be called by anyone.
+		///
+		/// Parameters:
+		/// - `asset_id`: The identifier of the asset which reserve is synced.
+		///
+		/// Emits `ReserveSynced` event when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::sync_reserve().saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn sync_reserve(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			ensure_signed(origin.clone())?;
 
-	/// Retrieve state of asset from the pool and its pool balance
-	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
-		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
-		let reserve = T::Currency::free_balance(asset_id, &Self::protocol_account());
-		Ok((state, reserve).into())
-	}
+			let asset_state = Self::load_asset_state(asset_id)?;
 
-	/// Set new state of asset.
-	/// This converts the new state into correct state type ( by removing the reserve)
-	fn set_asset_state(asset_id: T::AssetId, new_state: AssetReserveState<Balance>) {
-		<Assets<T>>::insert(asset_id, Into::<AssetState<Balance>>::into(new_state));
-	}
+			let amount =
+				T::Currency::free_balance(asset_id, &Self::protocol_account()).saturating_sub(asset_state.reserve);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToSync);
 
-	/// Generate an nft instance id and mint NFT into the class and instance.
-	#[require_transactional]
-	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
-		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
-			let next_position_id = *current_value;
+			let current_imbalance = <HubAssetImbalance<T>>::get();
+			let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
 
-			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+			let state_changes = hydra_dx_math::omnipool::calculate_add_liquidity_state_changes(
+				&(&asset_state).into(),
+				amount,
+				I129 {
+					value: current_imbalance.value,
+					negative: current_imbalance.negative,
+				},
+				current_hub_asset_liquidity,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
 
-			*current_value = current_value
-				.checked_add(&T::PositionItemId::one())
-				.ok_or(ArithmeticError::Overflow)?;
+			let shares = *state_changes.asset.delta_shares;
+			let changes = AssetStateChange {
+				delta_protocol_shares: BalanceUpdate::Increase(shares),
+				..state_changes.asset
+			};
 
-			Ok(next_position_id)
-		})
-	}
+			let new_asset_state = asset_state.delta_update(&changes).ok_or(ArithmeticError::Overflow)?;
 
-	/// Update Hub asset side of HDX subpool and add given amount to hub_asset_reserve
-	fn update_hdx_subpool_hub_asset(origin: T::RuntimeOrigin, hub_asset_amount: Balance) -> DispatchResult {
-		if hub_asset_amount > Balance::zero() {
-			let hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+			let info: AssetInfo<T::AssetId, Balance> =
+				AssetInfo::new(asset_id, &asset_state, &new_asset_state, &changes, false);
 
-			let mut native_subpool = Assets::<T>::get(T::HdxAssetId::get()).ok_or(Error::<T>::AssetNotFound)?;
-			native_subpool.hub_reserve = native_subpool
-				.hub_reserve
-				.checked_add(hub_asset_amount)
-				.ok_or(ArithmeticError::Overflow)?;
-			<Assets<T>>::insert(T::HdxAssetId::get(), native_subpool);
+			Self::update_imbalance(state_changes.delta_imbalance)?;
 
-			let updated_hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+			Self::update_hub_asset_liquidity(&changes.delta_hub_reserve)?;
 
-			let delta_changes = AssetStateChange {
-				delta_hub_reserve: BalanceUpdate::Increase(hub_asset_amount),
-				..Default::default()
-			};
+			Self::set_asset_state(asset_id, new_asset_state);
 
-			let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
-				T::HdxAssetId::get(),
-				&hdx_state,
-				&updated_hdx_state,
-				&delta_changes,
-				false,
-			);
+			Self::deposit_event(Event::ReserveSynced {
+				asset_id,
+				amount,
+				shares,
+			});
 
 			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
-		}
-		Ok(())
-	}
 
-	/// Mint or burn hub asset amount
-	#[require_transactional]
-	fn update_hub_asset_liquidity(delta_amount: &BalanceUpdate<Balance>) -> DispatchResult {
-		match delta_amount {
-			BalanceUpdate::Increase(amount) => {
-				T::Currency::deposit(T::HubAssetId::get(), &Self::protocol_account(), *amount)
-			}
-			BalanceUpdate::Decrease(amount) => {
-				T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), *amount)
-			}
+			Ok(())
 		}
-	}
 
-	/// Update imbalance with given delta_imbalance - increase or decrease.
-	/// It cannot result in imbalance being > 0.
-	fn update_imbalance(delta_imbalance: BalanceUpdate<Balance>) -> DispatchResult {
-		<HubAssetImbalance<T>>::try_mutate(|current_imbalance| -> DispatchResult {
-			*current_imbalance = match delta_imbalance {
-				BalanceUpdate::Decrease(amount) => (*current_imbalance).sub(amount).ok_or(ArithmeticError::Overflow)?,
-				BalanceUpdate::Increase(amount) => (*current_imbalance).add(amount).ok_or(ArithmeticError::Overflow)?,
-			};
+		/// Add liquidity to Omnipool from any asset.
+		///
+		/// `amount` of `asset_in` is sold for `target_asset` by the router using the on-chain route
+		/// of the asset pair. Whole amount received is then added as liquidity of `target_asset`
+		/// and the origin receives shares in form of NFT, same as in `add_liquidity`.
+		///
+		/// Both operations are executed atomically. Trade itself is not limited, `min_shares_limit` protects
+		/// the whole operation.
+		///
+		/// Parameters:
+		/// - `asset_in`: The identifier of the asset sold for `target_asset`.
+		/// - `amount`: Amount of `asset_in` to sell.
+		/// - `target_asset`: The identifier of the asset which liquidity is added. Must be already in the pool.
+		/// - `min_shares_limit`: The min amount of delta share asset the user should receive in the position
+		///
+		/// Emits `LiquidityAddedFrom` event when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+			.saturating_add(T::PriceBandOracle::get_price_weight())
+			.saturating_add(T::RouterWeightInfo::get_route_weight())
+			.saturating_add(T::RouterWeightInfo::sell_weight(&T::Router::get_route(AssetPair::new(*asset_in, *target_asset))))
+		)]
+		#[transactional]
+		pub fn add_liquidity_from(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			amount: Balance,
+			target_asset: T::AssetId,
+			min_shares_limit: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
 
-			ensure!(current_imbalance.negative, Error::<T>::PositiveImbalance);
+			ensure!(asset_in != target_asset, Error::<T>::SameAssetTradeNotAllowed);
 
-			Ok(())
-		})
-	}
+			let route = T::Router::get_route(AssetPair::new(asset_in, target_asset));
 
-	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
-	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
+			let initial_balance = T::Currency::free_balance(target_asset, &who);
+			T::Router::sell(origin.clone(), asset_in, target_asset, amount, Balance::zero(), route)?;
+			let received = T::Currency::free_balance(target_asset, &who).saturating_sub(initial_balance);
+
+			let position_id = Self::do_add_liquidity_with_limit(origin, target_asset, received, min_shares_limit)?;
+
+			Self::deposit_event(Event::LiquidityAddedFrom {
+				who,
+				asset_in,
+				amount_in: amount,
+				asset_id: target_asset,
+				amount: received,
+				position_id,
+			});
+
+			Ok(())
+		}
+
+		/// Update destinations the protocol fee is split between.
+		///
+		/// Each destination receives its portion of protocol fee of every trade. Part of the fee which is not
+		/// distributed goes to HDX subpool, so empty `destinations` sends whole protocol fee to HDX subpool.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: `AuthorityOrigin`
+		/// - `destinations`: destinations and their portions of the protocol fee. Sum of portions must not exceed 100%.
+		///
+		/// Emits `ProtocolFeeSplitUpdated` event when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_protocol_fee_split())]
+		#[transactional]
+		pub fn set_protocol_fee_split(
+			origin: OriginFor<T>,
+			destinations: ProtocolFeeDestinations<T::AccountId>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let total: u64 = destinations
+				.iter()
+				.map(|(_, portion)| portion.deconstruct() as u64)
+				.sum();
+			ensure!(
+				total <= Permill::one().deconstruct() as u64,
+				Error::<T>::InvalidProtocolFeeSplit
+			);
+
+			ProtocolFeeSplit::<T>::put(destinations.clone());
+
+			Self::deposit_event(Event::ProtocolFeeSplitUpdated { destinations });
+
+			Ok(())
+		}
+
+		/// Redeem hub asset for `asset_out` at spot price.
+		///
+		/// Unlike selling hub asset, redemption has no slippage and does not change the imbalance.
+		/// `amount` of hub asset is added to the hub reserve of `asset_out` and the caller receives
+		/// `asset_out` worth `amount` at spot price, reduced by `HubAssetRedemptionFee`. The fee stays in the pool
+		/// and belongs to LPs.
+		///
+		/// Amount of hub asset redeemed in a block is limited to `MaxHubAssetRedemptionPerBlock` of total hub asset reserve.
+		///
+		/// Asset's tradable state must contain BUY flag, otherwise `NotAllowed` error is returned.
+		///
+		/// Parameters:
+		/// - `origin`: account redeeming hub asset
+		/// - `amount`: Amount of hub asset to redeem
+		/// - `asset_out`: ID of asset received
+		/// - `min_out`: Minimum amount of `asset_out` required to receive
+		///
+		/// Emits `HubAssetRedeemed` event when successful.
+		///
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_hub_asset()
+			.saturating_add(T::OmnipoolHooks::on_trade_weight())
+		)]
+		#[transactional]
+		pub fn redeem_hub_asset(
+			origin: OriginFor<T>,
+			amount: Balance,
+			asset_out: T::AssetId,
+			min_out: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_out != T::HubAssetId::get(), Error::<T>::SameAssetTradeNotAllowed);
+			ensure!(
+				amount >= T::MinimumTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::ensure_can_withdraw(T::HubAssetId::get(), &who, amount).is_ok(),
+				Error::<T>::InsufficientBalance
+			);
+
+			let asset_state = Self::load_asset_state(asset_out)?;
+
+			ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
+			ensure!(
+				amount
+					<= asset_state
+						.hub_reserve
+						.checked_div(T::MaxInRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+				Error::<T>::MaxInRatioExceeded
+			);
+
+			let spot_amount_out = multiply_by_rational_with_rounding(
+				amount,
+				asset_state.reserve,
+				asset_state.hub_reserve,
+				Rounding::Down,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			let redemption_fee = T::HubAssetRedemptionFee::get().mul_ceil(spot_amount_out);
+			let amount_out = spot_amount_out.saturating_sub(redemption_fee);
+
+			ensure!(!amount_out.is_zero(), Error::<T>::ZeroAmountOut);
+			ensure!(amount_out >= min_out, Error::<T>::BuyLimitNotReached);
+			ensure!(
+				amount_out
+					<= asset_state
+						.reserve
+						.checked_div(T::MaxOutRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+				Error::<T>::MaxOutRatioExceeded
+			);
+
+			Self::ensure_hub_asset_redemption_allowed(amount)?;
+
+			let changes = AssetStateChange {
+				delta_hub_reserve: BalanceUpdate::Increase(amount),
+				delta_reserve: BalanceUpdate::Decrease(amount_out),
+				..Default::default()
+			};
+			let new_asset_state = asset_state.delta_update(&changes).ok_or(ArithmeticError::Overflow)?;
+
+			T::Currency::transfer(T::HubAssetId::get(), &who, &Self::protocol_account(), amount)?;
+			T::Currency::transfer(asset_out, &Self::protocol_account(), &who, amount_out)?;
+
+			let info: AssetInfo<T::AssetId, Balance> =
+				AssetInfo::new(asset_out, &asset_state, &new_asset_state, &changes, false);
+
+			Self::set_asset_state(asset_out, new_asset_state);
+
+			Self::accrue_fee(asset_out, redemption_fee)?;
+
+			Self::deposit_event(Event::HubAssetRedeemed {
+				who,
+				asset_out,
+				amount,
+				amount_out,
+				redemption_fee,
+			});
+
+			T::OmnipoolHooks::on_hub_asset_trade(origin, info)?;
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` protected by oracle price.
+		///
+		/// Same as `sell`, but the trade is rejected if the amount received is lower than `amount` valued at
+		/// `ExternalPriceOracle` price by more than `max_price_deviation`. The oracle price is an EMA of the Omnipool
+		/// prices, so trades executed at a price manipulated within the block are rejected.
+		///
+		/// Trade fees are part of the execution price, so `max_price_deviation` has to cover them.
+		///
+		/// Parameters:
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount`: Amount of asset sold
+		/// - `min_buy_amount`: Minimum amount required to receive
+		/// - `max_price_deviation`: Max allowed deviation of execution price from oracle price
+		///
+		/// Emits `SellExecuted` event when successful.
+		///
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::OmnipoolHooks::on_trade_weight())
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::Omnipool))
+			.saturating_add(T::ExternalPriceOracle::get_price_weight().saturating_mul(2))
+		)]
+		#[transactional]
+		pub fn sell_with_price_protection(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount: Balance,
+			min_buy_amount: Balance,
+			max_price_deviation: Permill,
+		) -> DispatchResult {
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+
+			let min_oracle_amount =
+				Self::min_amount_out_at_oracle_price(asset_in, asset_out, amount, max_price_deviation)?;
+
+			if min_oracle_amount <= min_buy_amount {
+				return Self::sell(origin, asset_in, asset_out, amount, min_buy_amount);
+			}
+
+			Self::sell(origin, asset_in, asset_out, amount, min_oracle_amount).map_err(|e| {
+				if e == Error::<T>::BuyLimitNotReached.into() {
+					Error::<T>::TradePriceDeviationTooHigh.into()
+				} else {
+					e
+				}
+			})
+		}
+
+		/// Commit to a trade revealed in a later block.
+		///
+		/// `commitment` is a hash of the trade parameters and a secret salt, see `trade_commitment`.
+		/// The trade can be revealed by `reveal_sell` in the next `TradeCommitmentExpiry` blocks.
+		///
+		/// Parameters:
+		/// - `origin`: account executing the trade
+		/// - `commitment`: hash of the trade parameters
+		///
+		/// Emits `TradeCommitted` event when successful.
+		///
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::commit_trade())]
+		#[transactional]
+		pub fn commit_trade(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!TradeCommitments::<T>::contains_key(&who, commitment),
+				Error::<T>::TradeCommitmentAlreadyExists
+			);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let expires_at = current_block.saturating_add(T::TradeCommitmentExpiry::get());
+
+			// commitment is removed at the beginning of the block following the last block it can be revealed in
+			TradeCommitmentExpiries::<T>::try_mutate(expires_at.saturating_add(One::one()), |commitments| {
+				commitments.try_push((who.clone(), commitment))
+			})
+			.map_err(|_| Error::<T>::MaxTradeCommitmentsPerBlockExceeded)?;
+			TradeCommitments::<T>::insert(&who, commitment, current_block);
+
+			Self::deposit_event(Event::TradeCommitted {
+				who,
+				commitment,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Reveal and execute a sell committed to by `commit_trade`.
+		///
+		/// Parameters and salt have to match the commitment. The sell can't be revealed in the block it was
+		/// committed in. Executed as `sell`.
+		///
+		/// Parameters:
+		/// - `origin`: account which committed to the trade
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount`: Amount of asset sold
+		/// - `min_buy_amount`: Minimum amount required to receive
+		/// - `salt`: Secret salt of the commitment
+		///
+		/// Emits `TradeRevealed` and `SellExecuted` events when successful.
+		///
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::OmnipoolHooks::on_trade_weight())
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::Omnipool))
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		#[transactional]
+		pub fn reveal_sell(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount: Balance,
+			min_buy_amount: Balance,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let commitment = Self::trade_commitment(&who, asset_in, asset_out, amount, min_buy_amount, salt);
+			let committed_at =
+				TradeCommitments::<T>::take(&who, commitment).ok_or(Error::<T>::TradeCommitmentNotFound)?;
+			ensure!(
+				committed_at < frame_system::Pallet::<T>::block_number(),
+				Error::<T>::TradeCommitmentNotMature
+			);
+
+			Self::deposit_event(Event::TradeRevealed { who, commitment });
+
+			Self::sell(origin, asset_in, asset_out, amount, min_buy_amount)
+		}
+
+		/// Approve amount of asset to be used as initial liquidity of the asset by `add_token_with_funder`.
+		///
+		/// Approval replaces previous approval of the asset. Zero amount removes the approval.
+		///
+		/// Parameters:
+		/// - `origin`: funder of the initial liquidity
+		/// - `asset`: asset to be added to Omnipool
+		/// - `amount`: amount of asset transferred to the pool when the asset is added
+		///
+		/// Emits `TokenFundingApproved` event when successful.
+		///
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_token_funding())]
+		#[transactional]
+		pub fn approve_token_funding(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if amount.is_zero() {
+				TokenFundingApprovals::<T>::remove(&who, asset);
+			} else {
+				TokenFundingApprovals::<T>::insert(&who, asset, amount);
+			}
+
+			Self::deposit_event(Event::TokenFundingApproved {
+				funder: who,
+				asset_id: asset,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Add new token to omnipool with initial liquidity provided by `funder`.
+		///
+		/// Amount approved by `funder` in `approve_token_funding` is transferred to pool's account and the token
+		/// is added as by `add_token`. The approval is consumed.
+		///
+		/// If `vesting_period` is set, shares of the initial position unlock linearly over `vesting_period` blocks
+		/// and only unlocked shares can be removed from the pool.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the new asset added to the pool. Must be registered in Asset registry
+		/// - `initial_price`: Initial price
+		/// - `weight_cap`: asset weight cap
+		/// - `position_owner`: account id for which share are distributed in form on NFT
+		/// - `funder`: account which approved funding of the initial liquidity
+		/// - `vesting_period`: number of blocks over which the initial shares unlock, `None` for no vesting
+		///
+		/// Emits `TokenAdded` event when successful and `PositionVestingSet` event if the shares are vested.
+		///
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_token_with_funder()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::PriceBandOracle::get_price_weight())
+		)]
+		#[transactional]
+		pub fn add_token_with_funder(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			initial_price: Price,
+			weight_cap: Permill,
+			position_owner: T::AccountId,
+			funder: T::AccountId,
+			vesting_period: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin.clone())?;
+
+			ensure!(
+				!vesting_period.is_some_and(|period| period.is_zero()),
+				Error::<T>::InvalidVestingPeriod
+			);
+
+			let amount = TokenFundingApprovals::<T>::take(&funder, asset);
+			ensure!(!amount.is_zero(), Error::<T>::TokenFundingNotApproved);
+
+			T::Currency::transfer(asset, &funder, &Self::protocol_account(), amount)?;
+
+			let position_id = Self::do_add_token(origin, asset, initial_price, weight_cap, position_owner)?;
+
+			if let Some(period) = vesting_period {
+				let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+				let vesting = SharesVesting {
+					shares: position.shares,
+					start: frame_system::Pallet::<T>::block_number(),
+					period,
+				};
+
+				PositionVesting::<T>::insert(position_id, &vesting);
+
+				Self::deposit_event(Event::PositionVestingSet {
+					position_id,
+					shares: vesting.shares,
+					start: vesting.start,
+					period: vesting.period,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Set price band of an asset.
+		///
+		/// Asset with a price band can be added to Omnipool and its liquidity can be added only if the price is
+		/// within `band` around `PriceBandOracle` price. This prevents listings and liquidity adds at a wrong price
+		/// which would be arbitraged against LPs.
+		///
+		/// Parameters:
+		/// - `origin`: Must be AuthorityOrigin
+		/// - `asset_id`: asset id
+		/// - `band`: max deviation of the price from the oracle price, `None` removes the band
+		///
+		/// Emits `PriceBandSet` event when successful.
+		///
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_price_band())]
+		#[transactional]
+		pub fn set_price_band(origin: OriginFor<T>, asset_id: T::AssetId, band: Option<Permill>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			PriceBands::<T>::set(asset_id, band);
+
+			Self::deposit_event(Event::PriceBandSet { asset_id, band });
+
+			Ok(())
+		}
+
+		/// Set parameters of the periodic hub asset buyback.
+		///
+		/// Every `period` blocks, `portion` of the buyback account balance of each of `assets` is sold for hub asset
+		/// in `on_idle`, in total for at most `max_hub_amount` of hub asset. Bought hub asset is burned or sent to
+		/// an account according to `destination`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be AuthorityOrigin
+		/// - `config`: buyback parameters, `None` disables the buyback
+		///
+		/// Emits `HubAssetBuybackSet` event when successful.
+		///
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_hub_asset_buyback())]
+		#[transactional]
+		pub fn set_hub_asset_buyback(
+			origin: OriginFor<T>,
+			config: Option<HubAssetBuyback<T::AccountId, T::AssetId, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if let Some(config) = &config {
+				ensure!(
+					!config.assets.is_empty()
+						&& !config.assets.contains(&T::HubAssetId::get())
+						&& !config.portion.is_zero()
+						&& !config.max_hub_amount.is_zero()
+						&& !config.period.is_zero(),
+					Error::<T>::InvalidHubAssetBuyback
+				);
+			}
+
+			HubAssetBuybackConfig::<T>::set(config.clone());
+
+			Self::deposit_event(Event::HubAssetBuybackSet { config });
+
+			Ok(())
+		}
+
+		/// Claim trading fees earned by a position.
+		///
+		/// Fee earnings of the position are transferred from the fee account to the owner and the position
+		/// keeps accruing fees from the current fee growth of its asset. Liquidity of the position is not changed.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which fees are claimed.
+		///
+		/// Emits `FeesClaimed` event when successful.
+		///
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_fees())]
+		#[transactional]
+		pub fn claim_fees(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+			ensure!(
+				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+				Error::<T>::Forbidden
+			);
+
+			let amount = Self::calculate_fee_earnings(position_id, position.asset_id, position.shares)?;
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			Self::pay_fee_earnings(position.asset_id, &who, amount)?;
+			Self::snapshot_fee_growth(position_id, position.asset_id);
+
+			Self::deposit_event(Event::FeesClaimed {
+				who,
+				position_id,
+				asset_id: position.asset_id,
+				amount,
+			});
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut weight = Self::expire_trade_commitments(n);
+
+			let queued: Vec<_> = WithdrawalQueue::<T>::iter().collect();
+			weight.saturating_accrue(T::DbWeight::get().reads(queued.len().saturating_add(1) as u64));
+
+			for (position_id, withdrawal) in queued {
+				Self::process_queued_withdrawal(position_id, withdrawal);
+				weight.saturating_accrue(
+					<T as Config>::WeightInfo::remove_liquidity()
+						.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+						.saturating_add(T::DbWeight::get().reads_writes(2, 3)),
+				);
+			}
+
+			weight
+		}
+
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::execute_hub_asset_buyback(n, remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert_ne!(
+				T::MinimumPoolLiquidity::get(),
+				Balance::zero(),
+				"Minimum pool liquidity is 0."
+			);
+			assert_ne!(
+				T::MinimumTradingLimit::get(),
+				Balance::zero(),
+				"Minimum trading limit is 0."
+			);
+			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
+			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), DispatchError> {
+			let report = Self::solvency_report();
+			assert_eq!(
+				report.hub_balance, report.hub_reserve,
+				"LRNA amount in assets != amount in account"
+			);
+
+			for (asset_id, asset) in report.assets.into_iter() {
+				assert_eq!(
+					asset.shares.saturating_sub(asset.protocol_shares),
+					asset.position_shares,
+					"Asset {:?} shares in positions is not equal to shares in asset state",
+					asset_id
+				);
+				assert!(
+					asset.balance >= asset.reserve,
+					"Asset {:?} reserve is greater than balance of the pool account",
+					asset_id
+				);
+			}
+
+			for (position_id, position) in Positions::<T>::iter() {
+				assert!(
+					T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).is_some(),
+					"Position {:?} of asset {:?} has no NFT instance",
+					position_id,
+					position.asset_id
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Add new token to omnipool with initial liquidity of pool's account balance of the token.
+	///
+	/// Returns id of the position created for `position_owner`.
+	fn do_add_token(
+		origin: OriginFor<T>,
+		asset: T::AssetId,
+		initial_price: Price,
+		weight_cap: Permill,
+		position_owner: T::AccountId,
+	) -> Result<T::PositionItemId, DispatchError> {
+		ensure!(!Assets::<T>::contains_key(asset), Error::<T>::AssetAlreadyAdded);
+
+		ensure!(T::AssetRegistry::exists(asset), Error::<T>::AssetNotRegistered);
+
+		ensure!(initial_price > FixedU128::zero(), Error::<T>::InvalidInitialAssetPrice);
+
+		Self::ensure_price_within_band(asset, EmaPrice::new(initial_price.into_inner(), FixedU128::DIV))?;
+
+		// ensure collection is created, we can simply ignore the error if it was already created.
+		let _ = T::NFTHandler::create_collection(
+			&T::NFTCollectionId::get(),
+			&Self::protocol_account(),
+			&Self::protocol_account(),
+		);
+
+		let amount = T::Currency::free_balance(asset, &Self::protocol_account());
+
+		let ed = T::AssetRegistry::existential_deposit(asset).ok_or(Error::<T>::ExistentialDepositNotAvailable)?;
+		let minimum_pool_liquidity = ed.saturating_mul(20);
+
+		ensure!(ed > 0 && amount >= minimum_pool_liquidity, Error::<T>::MissingBalance);
+
+		let hub_reserve = initial_price.checked_mul_int(amount).ok_or(ArithmeticError::Overflow)?;
+
+		// Initial state of asset
+		let state = AssetState::<Balance> {
+			reserve: amount,
+			hub_reserve,
+			shares: amount,
+			protocol_shares: Balance::zero(),
+			cap: FixedU128::from(weight_cap).into_inner(),
+			tradable: Tradability::default(),
+		};
+
+		let lp_position = Position::<Balance, T::AssetId> {
+			asset_id: asset,
+			amount,
+			shares: amount,
+			price: (initial_price.into_inner(), FixedU128::DIV),
+		};
+
+		let instance_id = Self::create_and_mint_position_instance(&position_owner)?;
+
+		<Positions<T>>::insert(instance_id, lp_position);
+		Self::snapshot_fee_growth(instance_id, asset);
+
+		Self::deposit_event(Event::PositionCreated {
+			position_id: instance_id,
+			owner: position_owner,
+			asset,
+			amount,
+			shares: amount,
+			price: initial_price,
+		});
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+			hub_reserve,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Self::update_imbalance(BalanceUpdate::Decrease(delta_imbalance))?;
+
+		let delta_hub_reserve = BalanceUpdate::Increase(hub_reserve);
+		Self::update_hub_asset_liquidity(&delta_hub_reserve)?;
+
+		let reserve_state: AssetReserveState<_> = state.clone().into();
+		let changes = AssetStateChange {
+			delta_hub_reserve,
+			delta_reserve: BalanceUpdate::Increase(amount),
+			delta_shares: BalanceUpdate::Increase(amount),
+			delta_protocol_shares: BalanceUpdate::Increase(Balance::zero()),
+		};
+		T::OmnipoolHooks::on_liquidity_changed(
+			origin,
+			AssetInfo::new(asset, &AssetReserveState::default(), &reserve_state, &changes, false),
+		)?;
+
+		<Assets<T>>::insert(asset, state);
+
+		Self::deposit_event(Event::TokenAdded {
+			asset_id: asset,
+			initial_amount: amount,
+			initial_price,
+		});
+
+		Ok(instance_id)
+	}
+
+	/// Queue liquidity removal which exceeds `WithdrawalQueueThreshold`.
+	///
+	/// Shares are removed in equal parts over `WithdrawalStreamBlocks` blocks in `on_initialize`.
+	fn queue_withdrawal(
+		who: T::AccountId,
+		position_id: T::PositionItemId,
+		position: Position<Balance, T::AssetId>,
+		asset_state: AssetReserveState<Balance>,
+		amount: Balance,
+		min_limit: Balance,
+	) -> DispatchResult {
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+			Error::<T>::Forbidden
+		);
+
+		ensure!(!Self::is_position_frozen(position_id), Error::<T>::PositionFrozen);
+
+		ensure!(position.shares >= amount, Error::<T>::InsufficientShares);
+
+		Self::ensure_shares_unlocked(position_id, &position, amount)?;
+
+		ensure!(
+			asset_state.tradable.contains(Tradability::REMOVE_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+
+		ensure!(
+			QueuedWithdrawalsPerAsset::<T>::get(position.asset_id) < T::MaxQueuedWithdrawals::get(),
+			Error::<T>::WithdrawalQueueFull
+		);
+		ensure!(
+			QueuedWithdrawalsPerAccount::<T>::get(&who) < T::MaxQueuedWithdrawalsPerAccount::get(),
+			Error::<T>::TooManyQueuedWithdrawals
+		);
+
+		let shares_per_block = amount.div_ceil(T::WithdrawalStreamBlocks::get().max(1).into());
+
+		QueuedWithdrawalsPerAsset::<T>::mutate(position.asset_id, |count| count.saturating_inc());
+		QueuedWithdrawalsPerAccount::<T>::mutate(&who, |count| count.saturating_inc());
+		WithdrawalQueue::<T>::insert(
+			position_id,
+			QueuedWithdrawal {
+				owner: who.clone(),
+				asset_id: position.asset_id,
+				remaining_shares: amount,
+				shares_per_block,
+				remaining_limit: min_limit,
+			},
+		);
+
+		Self::deposit_event(Event::WithdrawalQueued {
+			who,
+			position_id,
+			asset_id: position.asset_id,
+			shares: amount,
+			shares_per_block,
+		});
+
+		Ok(())
+	}
+
+	/// Remove queued withdrawal of a position from the queue.
+	fn dequeue_withdrawal(
+		position_id: T::PositionItemId,
+		withdrawal: &QueuedWithdrawal<T::AccountId, T::AssetId, Balance>,
+	) {
+		WithdrawalQueue::<T>::remove(position_id);
+		QueuedWithdrawalsPerAsset::<T>::mutate_exists(withdrawal.asset_id, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		});
+		QueuedWithdrawalsPerAccount::<T>::mutate_exists(&withdrawal.owner, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	/// Change asset of queued withdrawal of a position when the position is moved to another asset.
+	///
+	/// Does nothing if liquidity removal of the position is not queued.
+	pub fn move_queued_withdrawal(position_id: T::PositionItemId, asset_id: T::AssetId) {
+		let Some(withdrawal) = WithdrawalQueue::<T>::get(position_id) else {
+			return;
+		};
+		Self::dequeue_withdrawal(position_id, &withdrawal);
+		QueuedWithdrawalsPerAsset::<T>::mutate(asset_id, |count| count.saturating_inc());
+		QueuedWithdrawalsPerAccount::<T>::mutate(&withdrawal.owner, |count| count.saturating_inc());
+		WithdrawalQueue::<T>::insert(position_id, QueuedWithdrawal { asset_id, ..withdrawal });
+	}
+
+	/// Remove next part of queued liquidity removal.
+	///
+	/// If removal fails, the rest of the withdrawal is cancelled, so failing withdrawals don't occupy the queue.
+	/// Withdrawal is dropped if the position no longer exists or has changed its owner.
+	fn process_queued_withdrawal(
+		position_id: T::PositionItemId,
+		withdrawal: QueuedWithdrawal<T::AccountId, T::AssetId, Balance>,
+	) {
+		let owner = T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id);
+		if owner.as_ref() != Some(&withdrawal.owner) || !Positions::<T>::contains_key(position_id) {
+			Self::dequeue_withdrawal(position_id, &withdrawal);
+			Self::deposit_event(Event::WithdrawalCancelled {
+				who: withdrawal.owner,
+				position_id,
+				remaining_shares: withdrawal.remaining_shares,
+			});
+			return;
+		}
+
+		let shares = withdrawal.shares_per_block.min(withdrawal.remaining_shares);
+		let min_limit = if shares == withdrawal.remaining_shares {
+			withdrawal.remaining_limit
+		} else {
+			multiply_by_rational_with_rounding(
+				withdrawal.remaining_limit,
+				shares,
+				withdrawal.remaining_shares,
+				Rounding::Down,
+			)
+			.unwrap_or(withdrawal.remaining_limit)
+		};
+
+		let result = with_transaction::<(), DispatchError, _>(|| {
+			let result = Self::do_remove_liquidity(
+				frame_system::RawOrigin::Signed(withdrawal.owner.clone()).into(),
+				withdrawal.owner.clone(),
+				position_id,
+				shares,
+				min_limit,
+			);
+			if result.is_ok() {
+				TransactionOutcome::Commit(result)
+			} else {
+				TransactionOutcome::Rollback(result)
+			}
+		});
+
+		match result {
+			Ok(()) => {
+				let remaining_shares = withdrawal.remaining_shares.saturating_sub(shares);
+				if remaining_shares.is_zero() {
+					Self::dequeue_withdrawal(position_id, &withdrawal);
+				} else {
+					WithdrawalQueue::<T>::insert(
+						position_id,
+						QueuedWithdrawal {
+							remaining_shares,
+							remaining_limit: withdrawal.remaining_limit.saturating_sub(min_limit),
+							..withdrawal
+						},
+					);
+				}
+			}
+			Err(error) => {
+				Self::dequeue_withdrawal(position_id, &withdrawal);
+				Self::deposit_event(Event::QueuedWithdrawalFailed { position_id, error });
+				Self::deposit_event(Event::WithdrawalCancelled {
+					who: withdrawal.owner,
+					position_id,
+					remaining_shares: withdrawal.remaining_shares,
+				});
+			}
+		}
+	}
+
+	/// Remove liquidity of a position and transfer the withdrawn asset to the owner.
+	#[require_transactional]
+	fn do_remove_liquidity(
+		origin: OriginFor<T>,
+		who: T::AccountId,
+		position_id: T::PositionItemId,
+		amount: Balance,
+		min_limit: Balance,
+	) -> DispatchResult {
+		ensure!(amount > Balance::zero(), Error::<T>::InvalidSharesAmount);
+
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+			Error::<T>::Forbidden
+		);
+
+		ensure!(!Self::is_position_frozen(position_id), Error::<T>::PositionFrozen);
+
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+		ensure!(position.shares >= amount, Error::<T>::InsufficientShares);
+
+		Self::ensure_shares_unlocked(position_id, &position, amount)?;
+
+		let asset_id = position.asset_id;
+
+		// Fee earnings of removed shares are paid out. Remaining shares keep accruing from the same snapshot.
+		let fee_earnings = Self::calculate_fee_earnings(position_id, asset_id, amount)?;
+
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		ensure!(
+			asset_state.tradable.contains(Tradability::REMOVE_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+
+		let safe_withdrawal = asset_state.tradable.is_safe_withdrawal();
+		// Skip price check if safe withdrawal - trading disabled.
+		if !safe_withdrawal {
+			T::PriceBarrier::ensure_price(
+				&who,
+				T::HubAssetId::get(),
+				asset_id,
+				EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+			)
+			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
+		}
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+		let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		//
+		// calculate state changes of remove liquidity
+		//
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			amount,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+			withdrawal_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset.delta_reserve >= min_limit,
+			Error::<T>::SlippageLimit
+		);
+
+		let new_asset_state = asset_state
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// Update position state
+		let updated_position = position
+			.delta_update(
+				&state_changes.delta_position_reserve,
+				&state_changes.delta_position_shares,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(
+			asset_id,
+			&Self::protocol_account(),
+			&who,
+			*state_changes.asset.delta_reserve,
+		)?;
+		Self::pay_fee_earnings(asset_id, &who, fee_earnings)?;
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		// burn only difference between delta hub and lp hub amount.
+		Self::update_hub_asset_liquidity(
+			&state_changes
+				.asset
+				.delta_hub_reserve
+				.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
+				.ok_or(ArithmeticError::Overflow)?,
+		)?;
+
+		// LP receives some hub asset
+		Self::process_hub_amount(state_changes.lp_hub_amount, &who)?;
+
+		if updated_position.shares == Balance::zero() {
+			// All liquidity removed, remove position and burn NFT instance
+
+			<Positions<T>>::remove(position_id);
+			<PositionFeeGrowth<T>>::remove(position_id);
+			<PositionVesting<T>>::remove(position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id,
+				owner: who.clone(),
+			});
+		} else {
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price: updated_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			<Positions<T>>::insert(position_id, updated_position);
+		}
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
+			asset_id,
+			&asset_state,
+			&new_asset_state,
+			&state_changes.asset,
+			safe_withdrawal,
+		);
+
+		Self::set_asset_state(asset_id, new_asset_state);
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who,
+			position_id,
+			asset_id,
+			shares_removed: amount,
+			fee: withdrawal_fee,
+			fee_earnings,
+		});
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		#[cfg(feature = "try-runtime")]
+		Self::ensure_liquidity_invariant((asset_id, asset_state, new_asset_state));
+
+		Ok(())
+	}
+
+	/// Protocol account address
+	pub fn protocol_account() -> T::AccountId {
+		PalletId(*b"omnipool").into_account_truncating()
+	}
+
+	/// Account which holds asset fees accrued to LPs until they are paid out.
+	pub fn fee_account() -> T::AccountId {
+		PalletId(*b"omnifees").into_account_truncating()
+	}
+
+	/// Account holding protocol fees used to buy back hub asset.
+	pub fn buyback_account() -> T::AccountId {
+		PalletId(*b"lrnabuyb").into_account_truncating()
+	}
+
+	/// Retrieve state of asset from the pool including its tracked reserve
+	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
+		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+		Ok(state.into())
+	}
+
+	/// Set new state of asset.
+	/// This converts the new state into storage state type
+	fn set_asset_state(asset_id: T::AssetId, new_state: AssetReserveState<Balance>) {
+		<Assets<T>>::insert(asset_id, Into::<AssetState<Balance>>::into(new_state));
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+
+	/// Distribute protocol fee in hub asset between destinations of the protocol fee split.
+	/// Remaining amount is added to HDX subpool.
+	///
+	/// If the transfer to a destination account fails (e.g. amount is below existential deposit),
+	/// the portion stays in HDX subpool.
+	fn distribute_protocol_fee(origin: T::RuntimeOrigin, protocol_fee: Balance) -> DispatchResult {
+		if protocol_fee.is_zero() {
+			return Ok(());
+		}
+
+		let mut remaining = protocol_fee;
+
+		for (destination, portion) in ProtocolFeeSplit::<T>::get() {
+			let amount = portion.mul_floor(protocol_fee).min(remaining);
+			if amount.is_zero() {
+				continue;
+			}
+
+			let result = with_transaction::<(), DispatchError, _>(|| {
+				let result = match &destination {
+					ProtocolFeeDestination::Account(account) => {
+						T::Currency::transfer(T::HubAssetId::get(), &Self::protocol_account(), account, amount)
+					}
+					ProtocolFeeDestination::Burn => {
+						T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), amount)
+					}
+				};
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			});
+
+			if result.is_ok() {
+				remaining = remaining.saturating_sub(amount);
+				Self::deposit_event(Event::ProtocolFeeDistributed { destination, amount });
+			}
+		}
+
+		Self::update_hdx_subpool_hub_asset(origin, remaining)
+	}
+
+	/// Update Hub asset side of HDX subpool and add given amount to hub_asset_reserve
+	fn update_hdx_subpool_hub_asset(origin: T::RuntimeOrigin, hub_asset_amount: Balance) -> DispatchResult {
+		if hub_asset_amount > Balance::zero() {
+			let hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+
+			let mut native_subpool = Assets::<T>::get(T::HdxAssetId::get()).ok_or(Error::<T>::AssetNotFound)?;
+			native_subpool.hub_reserve = native_subpool
+				.hub_reserve
+				.checked_add(hub_asset_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			<Assets<T>>::insert(T::HdxAssetId::get(), native_subpool);
+
+			let updated_hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+
+			let delta_changes = AssetStateChange {
+				delta_hub_reserve: BalanceUpdate::Increase(hub_asset_amount),
+				..Default::default()
+			};
+
+			let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
+				T::HdxAssetId::get(),
+				&hdx_state,
+				&updated_hdx_state,
+				&delta_changes,
+				false,
+			);
+
+			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+		}
+		Ok(())
+	}
+
+	/// Mint or burn hub asset amount
+	#[require_transactional]
+	fn update_hub_asset_liquidity(delta_amount: &BalanceUpdate<Balance>) -> DispatchResult {
+		match delta_amount {
+			BalanceUpdate::Increase(amount) => {
+				T::Currency::deposit(T::HubAssetId::get(), &Self::protocol_account(), *amount)
+			}
+			BalanceUpdate::Decrease(amount) => {
+				T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), *amount)
+			}
+		}
+	}
+
+	/// Update imbalance with given delta_imbalance - increase or decrease.
+	/// It cannot result in imbalance being > 0.
+	fn update_imbalance(delta_imbalance: BalanceUpdate<Balance>) -> DispatchResult {
+		<HubAssetImbalance<T>>::try_mutate(|current_imbalance| -> DispatchResult {
+			*current_imbalance = match delta_imbalance {
+				BalanceUpdate::Decrease(amount) => (*current_imbalance).sub(amount).ok_or(ArithmeticError::Overflow)?,
+				BalanceUpdate::Increase(amount) => (*current_imbalance).add(amount).ok_or(ArithmeticError::Overflow)?,
+			};
+
+			ensure!(current_imbalance.negative, Error::<T>::PositiveImbalance);
+
+			Ok(())
+		})
+	}
+
+	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
+	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
 		asset_in.tradable.contains(Tradability::SELL) && asset_out.tradable.contains(Tradability::BUY)
 	}
 
@@ -1796,7 +3244,20 @@ impl<T: Config> Pallet<T> {
 
 		Self::set_asset_state(asset_out, new_asset_out_state);
 
-		Self::process_trade_fee(who, asset_out, state_changes.fee.asset_fee)?;
+		Self::process_trade_fee(TradeInfo {
+			pool: PoolType::Omnipool,
+			trader: who.clone(),
+			fee_account: Self::protocol_account(),
+			asset_in: T::HubAssetId::get(),
+			asset_out,
+			amount_in: *state_changes.asset.delta_hub_reserve,
+			amount_out: *state_changes.asset.delta_reserve,
+			reserve_in: asset_state.hub_reserve,
+			reserve_out: asset_state.reserve,
+			fee_asset: asset_out,
+			fee_amount: state_changes.fee.asset_fee,
+			protocol_fee: state_changes.fee.protocol_fee,
+		})?;
 
 		Self::deposit_event(Event::SellExecuted {
 			who: who.clone(),
@@ -1808,6 +3269,7 @@ impl<T: Config> Pallet<T> {
 			hub_amount_out: 0,
 			asset_fee_amount: state_changes.fee.asset_fee,
 			protocol_fee_amount: state_changes.fee.protocol_fee,
+			correlation_id: CorrelationId::current::<T>(),
 		});
 
 		T::OmnipoolHooks::on_hub_asset_trade(origin, info)?;
@@ -1860,99 +3322,552 @@ impl<T: Config> Pallet<T> {
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
-		ensure!(
-			*state_changes.asset.delta_hub_reserve <= limit,
-			Error::<T>::SellLimitExceeded
-		);
+		ensure!(
+			*state_changes.asset.delta_hub_reserve <= limit,
+			Error::<T>::SellLimitExceeded
+		);
+
+		ensure!(
+			*state_changes.asset.delta_hub_reserve
+				<= asset_state
+					.hub_reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let new_asset_out_state = asset_state
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(
+			T::HubAssetId::get(),
+			who,
+			&Self::protocol_account(),
+			*state_changes.asset.delta_hub_reserve,
+		)?;
+		T::Currency::transfer(
+			asset_out,
+			&Self::protocol_account(),
+			who,
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
+			asset_out,
+			&asset_state,
+			&new_asset_out_state,
+			&state_changes.asset,
+			false,
+		);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::set_asset_state(asset_out, new_asset_out_state);
+
+		Self::process_trade_fee(TradeInfo {
+			pool: PoolType::Omnipool,
+			trader: who.clone(),
+			fee_account: Self::protocol_account(),
+			asset_in: T::HubAssetId::get(),
+			asset_out,
+			amount_in: *state_changes.asset.delta_hub_reserve,
+			amount_out: *state_changes.asset.delta_reserve,
+			reserve_in: asset_state.hub_reserve,
+			reserve_out: asset_state.reserve,
+			fee_asset: asset_out,
+			fee_amount: state_changes.fee.asset_fee,
+			protocol_fee: state_changes.fee.protocol_fee,
+		})?;
+
+		Self::deposit_event(Event::BuyExecuted {
+			who: who.clone(),
+			asset_in: T::HubAssetId::get(),
+			asset_out,
+			amount_in: *state_changes.asset.delta_hub_reserve,
+			amount_out: *state_changes.asset.delta_reserve,
+			hub_amount_in: 0,
+			hub_amount_out: 0,
+			asset_fee_amount: state_changes.fee.asset_fee,
+			protocol_fee_amount: state_changes.fee.protocol_fee,
+			correlation_id: CorrelationId::current::<T>(),
+		});
+
+		T::OmnipoolHooks::on_hub_asset_trade(origin, info)?;
+
+		Ok(())
+	}
+
+	/// Ensure that hub asset can be bought from Omnipool by traders.
+	fn ensure_hub_asset_tradable_for_buy() -> DispatchResult {
+		ensure!(
+			HubAssetTradability::<T>::get().contains(Tradability::BUY),
+			Error::<T>::NotAllowed
+		);
+		Ok(())
+	}
+
+	/// Swap asset for exact amount of Hub Asset
+	/// Special handling of buy trade where asset out is Hub Asset.
+	fn buy_hub_asset(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		amount: Balance,
+		limit: Balance,
+	) -> DispatchResult {
+		let asset_state = Self::load_asset_state(asset_in)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::SELL), Error::<T>::NotAllowed);
+		ensure!(
+			amount
+				<= asset_state
+					.hub_reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let (_, protocol_fee) = T::Fee::get(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			protocol_fee,
+			current_imbalance.value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset.delta_reserve <= limit,
+			Error::<T>::SellLimitExceeded
+		);
+
+		ensure!(
+			*state_changes.asset.delta_reserve
+				<= asset_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		ensure!(
+			T::Currency::ensure_can_withdraw(asset_in, who, *state_changes.asset.delta_reserve).is_ok(),
+			Error::<T>::InsufficientBalance
+		);
+
+		Self::execute_hub_asset_buy(origin, who, asset_in, asset_state, state_changes, false)
+	}
+
+	/// Swap asset for Hub Asset
+	/// Special handling of sell trade where asset out is Hub Asset.
+	fn sell_asset_for_hub_asset(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		amount: Balance,
+		limit: Balance,
+	) -> DispatchResult {
+		let asset_state = Self::load_asset_state(asset_in)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::SELL), Error::<T>::NotAllowed);
+		ensure!(
+			amount
+				<= asset_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let (_, protocol_fee) = T::Fee::get(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_for_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			protocol_fee,
+			current_imbalance.value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let hub_amount_out = (*state_changes.asset.delta_hub_reserve)
+			.checked_sub(state_changes.fee.protocol_fee)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		ensure!(hub_amount_out > Balance::zero(), Error::<T>::ZeroAmountOut);
+		ensure!(hub_amount_out >= limit, Error::<T>::BuyLimitNotReached);
 
 		ensure!(
 			*state_changes.asset.delta_hub_reserve
 				<= asset_state
 					.hub_reserve
-					.checked_div(T::MaxInRatio::get())
-					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-			Error::<T>::MaxInRatioExceeded
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
 		);
 
-		let new_asset_out_state = asset_state
+		Self::execute_hub_asset_buy(origin, who, asset_in, asset_state, state_changes, true)
+	}
+
+	/// Apply state changes of a trade where asset out is Hub Asset.
+	///
+	/// Spot price of the asset must be within `PriceBarrier` bounds of the oracle price and hub asset received
+	/// by the trader is limited per block by `MaxHubAssetBuyPerBlock`.
+	/// Part of the protocol fee covering the imbalance is burned, the rest goes to HDX subpool.
+	fn execute_hub_asset_buy(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		asset_state: AssetReserveState<Balance>,
+		state_changes: HubTradeStateChange<Balance>,
+		is_sell: bool,
+	) -> DispatchResult {
+		let hub_amount_out = (*state_changes.asset.delta_hub_reserve)
+			.checked_sub(state_changes.fee.protocol_fee)
+			.ok_or(ArithmeticError::Underflow)?;
+		let hdx_hub_amount = state_changes
+			.fee
+			.protocol_fee
+			.checked_sub(*state_changes.delta_imbalance)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		T::PriceBarrier::ensure_price(
+			who,
+			T::HubAssetId::get(),
+			asset_in,
+			EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+		)
+		.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
+
+		Self::ensure_hub_asset_buy_allowed(hub_amount_out)?;
+
+		let new_asset_in_state = asset_state
 			.delta_update(&state_changes.asset)
 			.ok_or(ArithmeticError::Overflow)?;
 
 		T::Currency::transfer(
-			T::HubAssetId::get(),
+			asset_in,
 			who,
 			&Self::protocol_account(),
-			*state_changes.asset.delta_hub_reserve,
-		)?;
-		T::Currency::transfer(
-			asset_out,
-			&Self::protocol_account(),
-			who,
 			*state_changes.asset.delta_reserve,
 		)?;
+		T::Currency::transfer(T::HubAssetId::get(), &Self::protocol_account(), who, hub_amount_out)?;
 
-		let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
-			asset_out,
-			&asset_state,
-			&new_asset_out_state,
-			&state_changes.asset,
-			false,
-		);
+		if *state_changes.delta_imbalance > Balance::zero() {
+			T::Currency::withdraw(
+				T::HubAssetId::get(),
+				&Self::protocol_account(),
+				*state_changes.delta_imbalance,
+			)?;
+		}
+
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_in, &asset_state, &new_asset_in_state, &state_changes.asset, false);
 
 		Self::update_imbalance(state_changes.delta_imbalance)?;
 
-		Self::set_asset_state(asset_out, new_asset_out_state);
+		Self::set_asset_state(asset_in, new_asset_in_state);
 
-		Self::process_trade_fee(who, asset_out, state_changes.fee.asset_fee)?;
+		Self::distribute_protocol_fee(origin.clone(), hdx_hub_amount)?;
 
-		Self::deposit_event(Event::BuyExecuted {
-			who: who.clone(),
-			asset_in: T::HubAssetId::get(),
-			asset_out,
-			amount_in: *state_changes.asset.delta_hub_reserve,
-			amount_out: *state_changes.asset.delta_reserve,
-			hub_amount_in: 0,
-			hub_amount_out: 0,
-			asset_fee_amount: state_changes.fee.asset_fee,
-			protocol_fee_amount: state_changes.fee.protocol_fee,
-		});
+		if is_sell {
+			Self::deposit_event(Event::SellExecuted {
+				who: who.clone(),
+				asset_in,
+				asset_out: T::HubAssetId::get(),
+				amount_in: *state_changes.asset.delta_reserve,
+				amount_out: hub_amount_out,
+				hub_amount_in: 0,
+				hub_amount_out: 0,
+				asset_fee_amount: 0,
+				protocol_fee_amount: state_changes.fee.protocol_fee,
+				correlation_id: CorrelationId::current::<T>(),
+			});
+		} else {
+			Self::deposit_event(Event::BuyExecuted {
+				who: who.clone(),
+				asset_in,
+				asset_out: T::HubAssetId::get(),
+				amount_in: *state_changes.asset.delta_reserve,
+				amount_out: hub_amount_out,
+				hub_amount_in: 0,
+				hub_amount_out: 0,
+				asset_fee_amount: 0,
+				protocol_fee_amount: state_changes.fee.protocol_fee,
+				correlation_id: CorrelationId::current::<T>(),
+			});
+		}
 
 		T::OmnipoolHooks::on_hub_asset_trade(origin, info)?;
 
 		Ok(())
 	}
 
-	/// Buy hub asset from the pool
-	/// Special handling of buy trade where asset out is Hub Asset.
-	fn buy_hub_asset(_who: &T::AccountId, _asset_in: T::AssetId, _amount: Balance, _limit: Balance) -> DispatchResult {
-		ensure!(
-			HubAssetTradability::<T>::get().contains(Tradability::BUY),
-			Error::<T>::NotAllowed
+	/// Sell portion of the buyback account balance of buyback assets for hub asset.
+	///
+	/// Executed once per buyback period if `remaining_weight` allows. Assets are processed in the configured
+	/// order until `max_hub_amount` of hub asset is bought or the weight runs out. Failed buyback of an asset
+	/// doesn't stop the buyback of other assets.
+	fn execute_hub_asset_buyback(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let mut weight = T::DbWeight::get().reads(2);
+		if weight.any_gt(remaining_weight) {
+			return Weight::zero();
+		}
+
+		let Some(config) = HubAssetBuybackConfig::<T>::get() else {
+			return weight;
+		};
+		if n < LastHubAssetBuyback::<T>::get().saturating_add(config.period) {
+			return weight;
+		}
+
+		let asset_weight = <T as Config>::WeightInfo::sell()
+			.saturating_add(T::OmnipoolHooks::on_trade_weight())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+
+		// Buyback is postponed to the next block if not even one asset can be processed.
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+		if weight.saturating_add(asset_weight).any_gt(remaining_weight) {
+			return weight;
+		}
+
+		LastHubAssetBuyback::<T>::put(n);
+		let account = Self::buyback_account();
+		let mut hub_amount_left = config.max_hub_amount;
+
+		for asset_id in config.assets {
+			if hub_amount_left.is_zero() || weight.saturating_add(asset_weight).any_gt(remaining_weight) {
+				break;
+			}
+			weight.saturating_accrue(asset_weight);
+
+			let amount = config.portion.mul_floor(T::Currency::free_balance(asset_id, &account));
+			if amount < T::MinimumTradingLimit::get() {
+				continue;
+			}
+
+			let result = with_transaction::<(Balance, Balance), DispatchError, _>(|| {
+				let result = Self::buy_back_hub_asset(&account, asset_id, amount, hub_amount_left, &config.destination);
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			});
+
+			match result {
+				Ok((amount_in, hub_amount)) => {
+					hub_amount_left = hub_amount_left.saturating_sub(hub_amount);
+					Self::deposit_event(Event::HubAssetBoughtBack {
+						asset_id,
+						amount_in,
+						hub_amount,
+						destination: config.destination.clone(),
+					});
+				}
+				Err(error) => Self::deposit_event(Event::HubAssetBuybackFailed { asset_id, error }),
+			}
+		}
+
+		weight
+	}
+
+	/// Sell `amount` of asset from the buyback account for hub asset and send the hub asset to `destination`.
+	///
+	/// If the sell would return more than `max_hub_amount`, exactly `max_hub_amount` of hub asset is bought instead.
+	/// Returns amount of asset sold and amount of hub asset bought.
+	fn buy_back_hub_asset(
+		account: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: Balance,
+		max_hub_amount: Balance,
+		destination: &ProtocolFeeDestination<T::AccountId>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(account.clone()).into();
+		let hub_asset = T::HubAssetId::get();
+
+		let asset_state = Self::load_asset_state(asset_id)?;
+		let amount = amount.min(
+			asset_state
+				.reserve
+				.checked_div(T::MaxInRatio::get())
+				.ok_or(ArithmeticError::DivisionByZero)?,
 		);
 
-		// Note: Currently not allowed at all, neither math is done for this case
-		// this is already ready when hub asset will be allowed to be bought from the pool
+		let (_, protocol_fee) = T::Fee::get(&asset_id);
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_for_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			protocol_fee,
+			<HubAssetImbalance<T>>::get().value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let hub_amount_out = (*state_changes.asset.delta_hub_reserve)
+			.checked_sub(state_changes.fee.protocol_fee)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		let asset_balance = T::Currency::free_balance(asset_id, account);
+		let hub_balance = T::Currency::free_balance(hub_asset, account);
+
+		if hub_amount_out > max_hub_amount {
+			Self::buy_hub_asset(origin, account, asset_id, max_hub_amount, amount)?;
+		} else {
+			Self::sell_asset_for_hub_asset(origin, account, asset_id, amount, Balance::zero())?;
+		}
+
+		let amount_in = asset_balance.saturating_sub(T::Currency::free_balance(asset_id, account));
+		let hub_amount = T::Currency::free_balance(hub_asset, account).saturating_sub(hub_balance);
+
+		match destination {
+			ProtocolFeeDestination::Account(dest) => T::Currency::transfer(hub_asset, account, dest, hub_amount)?,
+			ProtocolFeeDestination::Burn => T::Currency::withdraw(hub_asset, account, hub_amount)?,
+		}
 
-		Err(Error::<T>::NotAllowed.into())
+		Ok((amount_in, hub_amount))
 	}
 
-	/// Swap asset for Hub Asset
-	/// Special handling of sell trade where asset out is Hub Asset.
-	fn sell_asset_for_hub_asset(
-		_who: &T::AccountId,
-		_asset_in: T::AssetId,
-		_amount: Balance,
-		_limit: Balance,
-	) -> DispatchResult {
+	/// Ensure that buying `amount` of hub asset does not exceed `MaxHubAssetBuyPerBlock` of total hub asset reserve
+	/// and record the bought amount.
+	fn ensure_hub_asset_buy_allowed(amount: Balance) -> DispatchResult {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let (block, bought) = HubAssetBoughtInBlock::<T>::get();
+		let bought = if block == current_block {
+			bought
+		} else {
+			Balance::zero()
+		};
+
+		let total_bought = bought.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+		let max_allowed = T::MaxHubAssetBuyPerBlock::get().mul_floor(Self::get_hub_asset_balance_of_protocol_account());
+
+		ensure!(total_bought <= max_allowed, Error::<T>::MaxHubAssetBuyPerBlockExceeded);
+
+		HubAssetBoughtInBlock::<T>::put((current_block, total_bought));
+
+		Ok(())
+	}
+
+	/// Ensure that redeeming `amount` of hub asset does not exceed `MaxHubAssetRedemptionPerBlock` of total hub asset
+	/// reserve and record the redeemed amount.
+	fn ensure_hub_asset_redemption_allowed(amount: Balance) -> DispatchResult {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let (block, redeemed) = HubAssetRedeemedInBlock::<T>::get();
+		let redeemed = if block == current_block {
+			redeemed
+		} else {
+			Balance::zero()
+		};
+
+		let total_redeemed = redeemed.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+		let max_allowed =
+			T::MaxHubAssetRedemptionPerBlock::get().mul_floor(Self::get_hub_asset_balance_of_protocol_account());
+
 		ensure!(
-			HubAssetTradability::<T>::get().contains(Tradability::BUY),
-			Error::<T>::NotAllowed
+			total_redeemed <= max_allowed,
+			Error::<T>::MaxHubAssetRedemptionPerBlockExceeded
 		);
 
-		// Note: Currently not allowed at all, neither math is done for this case
-		// this is already ready when hub asset will be allowed to be bought from the pool
+		HubAssetRedeemedInBlock::<T>::put((current_block, total_redeemed));
+
+		Ok(())
+	}
+
+	/// Minimum amount of `asset_out` received for `amount` of `asset_in` at `ExternalPriceOracle` price,
+	/// with at most `max_price_deviation` lower execution price.
+	pub fn min_amount_out_at_oracle_price(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		max_price_deviation: Permill,
+	) -> Result<Balance, DispatchError> {
+		let price_in = Self::oracle_hub_asset_price(asset_in)?;
+		let price_out = Self::oracle_hub_asset_price(asset_out)?;
+
+		let hub_amount = multiply_by_rational_with_rounding(amount, price_in.n, price_in.d, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+		let amount_out = multiply_by_rational_with_rounding(hub_amount, price_out.d, price_out.n, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(amount_out.saturating_sub(max_price_deviation.mul_ceil(amount_out)))
+	}
+
+	/// Commitment to a sell executed by `reveal_sell`.
+	pub fn trade_commitment(
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		min_buy_amount: Balance,
+		salt: [u8; 32],
+	) -> T::Hash {
+		T::Hashing::hash_of(&(who, asset_in, asset_out, amount, min_buy_amount, salt))
+	}
+
+	/// Remove unrevealed trade commitments expiring at the beginning of block `n`.
+	fn expire_trade_commitments(n: BlockNumberFor<T>) -> Weight {
+		let expiring = TradeCommitmentExpiries::<T>::take(n);
+		let committed_at = n
+			.saturating_sub(T::TradeCommitmentExpiry::get())
+			.saturating_sub(One::one());
+		let mut writes = 1u64;
+
+		for (who, commitment) in expiring.iter() {
+			// revealed commitments are already removed, the same commitment can be made again in a later block
+			if TradeCommitments::<T>::get(who, commitment) == Some(committed_at) {
+				TradeCommitments::<T>::remove(who, commitment);
+				writes.saturating_inc();
+				Self::deposit_event(Event::TradeCommitmentExpired {
+					who: who.clone(),
+					commitment: *commitment,
+				});
+			}
+		}
+
+		T::DbWeight::get().reads_writes((expiring.len() as u64).saturating_add(1), writes)
+	}
+
+	/// `ExternalPriceOracle` price of `asset_id` denominated in hub asset.
+	fn oracle_hub_asset_price(asset_id: T::AssetId) -> Result<EmaPrice, DispatchError> {
+		if asset_id == T::HubAssetId::get() {
+			return Ok(EmaPrice::one());
+		}
+
+		let price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+		ensure!(!price.is_zero(), Error::<T>::InvalidOraclePrice);
+
+		Ok(price)
+	}
+
+	/// Ensure `price` of `asset_id` denominated in hub asset is within the price band of the asset around
+	/// `PriceBandOracle` price. Assets without a price band are not checked.
+	fn ensure_price_within_band(asset_id: T::AssetId, price: EmaPrice) -> DispatchResult {
+		let Some(band) = PriceBands::<T>::get(asset_id) else {
+			return Ok(());
+		};
+
+		let oracle_price = T::PriceBandOracle::get_price(T::HubAssetId::get(), asset_id)?;
+		let oracle_price =
+			FixedU128::checked_from_rational(oracle_price.n, oracle_price.d).ok_or(Error::<T>::InvalidOraclePrice)?;
+		ensure!(!oracle_price.is_zero(), Error::<T>::InvalidOraclePrice);
+		let price = FixedU128::checked_from_rational(price.n, price.d).ok_or(ArithmeticError::Overflow)?;
+
+		let diff = if price >= oracle_price {
+			price.saturating_sub(oracle_price)
+		} else {
+			oracle_price.saturating_sub(price)
+		};
+
+		ensure!(
+			diff <= FixedU128::from(band).saturating_mul(oracle_price),
+			Error::<T>::PriceOutsideOracleBand
+		);
 
-		Err(Error::<T>::NotAllowed.into())
+		Ok(())
 	}
 
 	/// Get hub asset balance of protocol account
@@ -1961,6 +3876,9 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Remove asset from list of Omnipool assets.
+	///
+	/// Fee growth of the asset is kept, so fees accrued to the fee account for positions of the asset can still be
+	/// paid out by `settle_fee_earnings`.
 	/// No events emitted.
 	pub fn remove_asset(asset_id: T::AssetId) -> DispatchResult {
 		<Assets<T>>::remove(asset_id);
@@ -2007,6 +3925,56 @@ impl<T: Config> Pallet<T> {
 		Positions::<T>::get(position_id).ok_or_else(|| Error::<T>::PositionNotFound.into())
 	}
 
+	/// Freeze position so its liquidity can't be removed and it can't be sacrificed. Position stays owned by
+	/// its owner, so it can be used by other pallets (e.g. liquidity mining) without being transferred.
+	///
+	/// Fails if position is already frozen or if its liquidity removal is queued.
+	/// No events emitted.
+	pub fn freeze_position(position_id: T::PositionItemId) -> DispatchResult {
+		ensure!(Positions::<T>::contains_key(position_id), Error::<T>::PositionNotFound);
+		ensure!(!Self::is_position_frozen(position_id), Error::<T>::PositionFrozen);
+		ensure!(
+			!WithdrawalQueue::<T>::contains_key(position_id),
+			Error::<T>::WithdrawalAlreadyQueued
+		);
+
+		FrozenPositions::<T>::insert(position_id, ());
+		Ok(())
+	}
+
+	/// Thaw position frozen by `freeze_position`.
+	/// No events emitted.
+	pub fn thaw_position(position_id: T::PositionItemId) -> DispatchResult {
+		ensure!(Self::is_position_frozen(position_id), Error::<T>::PositionNotFrozen);
+
+		FrozenPositions::<T>::remove(position_id);
+		Ok(())
+	}
+
+	pub fn is_position_frozen(position_id: T::PositionItemId) -> bool {
+		FrozenPositions::<T>::contains_key(position_id)
+	}
+
+	/// Shares of a position which are still vesting in the current block.
+	pub fn locked_shares(position_id: T::PositionItemId) -> Balance {
+		PositionVesting::<T>::get(position_id)
+			.map(|vesting| vesting.locked_at(frame_system::Pallet::<T>::block_number()))
+			.unwrap_or_default()
+	}
+
+	/// Ensure that removing `amount` of shares leaves at least the locked shares in the position.
+	fn ensure_shares_unlocked(
+		position_id: T::PositionItemId,
+		position: &Position<Balance, T::AssetId>,
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(
+			position.shares.saturating_sub(amount) >= Self::locked_shares(position_id),
+			Error::<T>::SharesVesting
+		);
+		Ok(())
+	}
+
 	pub fn is_hub_asset_allowed(operation: Tradability) -> bool {
 		HubAssetTradability::<T>::get().contains(operation)
 	}
@@ -2016,19 +3984,208 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::contains_key(asset)
 	}
 
-	/// Calls `on_trade_fee` hook and ensures that no more than the fee amount is transferred.
-	fn process_trade_fee(trader: &T::AccountId, asset: T::AssetId, amount: Balance) -> DispatchResult {
-		let account = Self::protocol_account();
+	/// Calls `OnTradeExecuted` handler and ensures that no more than the fee amount is transferred.
+	fn process_trade_fee(trade: TradeInfo<T::AccountId, T::AssetId, Balance>) -> DispatchResult {
+		let account = trade.fee_account.clone();
+		let asset = trade.fee_asset;
+		let amount = trade.fee_amount;
 		let original_asset_reserve = T::Currency::free_balance(asset, &account);
 
 		// Let's give little bit less to process. Subtracting one due to potential rounding errors
 		let allowed_amount = amount.saturating_sub(Balance::one());
-		let used = T::OmnipoolHooks::on_trade_fee(account.clone(), trader.clone(), asset, allowed_amount)?;
+		let used = T::OnTradeExecuted::on_trade_executed(&TradeInfo {
+			fee_amount: allowed_amount,
+			..trade
+		})?;
 		let asset_reserve = T::Currency::free_balance(asset, &account);
 		let diff = original_asset_reserve.saturating_sub(asset_reserve);
 		ensure!(diff <= allowed_amount, Error::<T>::FeeOverdraft);
 		ensure!(diff == used, Error::<T>::FeeOverdraft);
-		Ok(())
+
+		if !used.is_zero() {
+			<Assets<T>>::try_mutate(asset, |maybe_asset| -> DispatchResult {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+				asset_state.reserve = asset_state
+					.reserve
+					.checked_sub(used)
+					.ok_or(ArithmeticError::Underflow)?;
+				Ok(())
+			})?;
+		}
+
+		// What is left of the fee belongs to LPs. One unit stays in the pool to cover rounding of the trade.
+		Self::accrue_fee(asset, allowed_amount.saturating_sub(used))
+	}
+
+	/// Move asset fee which belongs to LPs from the asset reserve to the fee account and increase cumulative
+	/// fee growth of the asset.
+	///
+	/// Part of the fee attributable to protocol's shares stays in the reserve, so everything accrued to the fee
+	/// account is owed to positions.
+	fn accrue_fee(asset: T::AssetId, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let (lp_amount, lp_shares) =
+			<Assets<T>>::try_mutate(asset, |maybe_asset| -> Result<(Balance, Balance), DispatchError> {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+				let lp_shares = asset_state.shares.saturating_sub(asset_state.protocol_shares);
+				if lp_shares.is_zero() {
+					return Ok((Balance::zero(), Balance::zero()));
+				}
+				let lp_amount =
+					multiply_by_rational_with_rounding(amount, lp_shares, asset_state.shares, Rounding::Down)
+						.ok_or(ArithmeticError::Overflow)?;
+				asset_state.reserve = asset_state
+					.reserve
+					.checked_sub(lp_amount)
+					.ok_or(ArithmeticError::Underflow)?;
+				Ok((lp_amount, lp_shares))
+			})?;
+		if lp_amount.is_zero() {
+			return Ok(());
+		}
+		T::Currency::transfer(asset, &Self::protocol_account(), &Self::fee_account(), lp_amount)?;
+
+		let growth = FixedU128::checked_from_rational(lp_amount, lp_shares).ok_or(ArithmeticError::Overflow)?;
+		<AssetFeeGrowth<T>>::try_mutate(asset, |current| -> DispatchResult {
+			*current = current.checked_add(&growth).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})
+	}
+
+	/// Transfer fee earnings of a position from the fee account to `who`.
+	fn pay_fee_earnings(asset: T::AssetId, who: &T::AccountId, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		T::Currency::transfer(asset, &Self::fee_account(), who, amount)
+	}
+
+	/// Store current fee growth of an asset as the starting point of position's fee earnings.
+	fn snapshot_fee_growth(position_id: T::PositionItemId, asset: T::AssetId) {
+		let growth = <AssetFeeGrowth<T>>::get(asset);
+		if !growth.is_zero() {
+			<PositionFeeGrowth<T>>::insert(position_id, growth);
+		}
+	}
+
+	/// Calculate asset fee earned by given shares of a position since the position was created or its fees were
+	/// last claimed.
+	fn calculate_fee_earnings(
+		position_id: T::PositionItemId,
+		asset: T::AssetId,
+		shares: Balance,
+	) -> Result<Balance, DispatchError> {
+		<AssetFeeGrowth<T>>::get(asset)
+			.saturating_sub(<PositionFeeGrowth<T>>::get(position_id))
+			.checked_mul_int(shares)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Pay unclaimed fee earnings of a position to its owner and reset its fee growth snapshot.
+	///
+	/// Works also for positions of an asset removed by `remove_asset`. Returns the amount paid.
+	pub fn settle_fee_earnings(position_id: T::PositionItemId) -> Result<Balance, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let owner =
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+		let amount = Self::calculate_fee_earnings(position_id, position.asset_id, position.shares)?;
+		Self::pay_fee_earnings(position.asset_id, &owner, amount)?;
+		<PositionFeeGrowth<T>>::remove(position_id);
+
+		Ok(amount)
+	}
+
+	/// Start fee earnings of a position from current fee growth of `asset`.
+	///
+	/// Must be called when asset of an existing position is changed, after its earnings are settled.
+	pub fn checkpoint_fee_growth(position_id: T::PositionItemId, asset: T::AssetId) {
+		<PositionFeeGrowth<T>>::remove(position_id);
+		Self::snapshot_fee_growth(position_id, asset);
+	}
+
+	/// Unclaimed trading fee earnings of a position, in position's asset.
+	///
+	/// This is the part of the asset fee accrued to the fee account which is attributable to position's shares
+	/// since the position was created or its fees were last claimed. It is paid by `claim_fees` or when liquidity
+	/// is removed, and is not included in the value of the position.
+	pub fn position_fee_earnings(position_id: T::PositionItemId) -> Result<Balance, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		Self::calculate_fee_earnings(position_id, position.asset_id, position.shares)
+	}
+
+	/// Current value of a position.
+	///
+	/// Returns amounts of position's asset and hub asset which would be received if all position's shares were
+	/// removed at current spot price. Withdrawal fee is not taken into account.
+	pub fn position_value(position_id: T::PositionItemId) -> Result<(Balance, Balance), DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			position.shares,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account()),
+			FixedU128::zero(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((*state_changes.asset.delta_reserve, state_changes.lp_hub_amount))
+	}
+
+	/// Liquidity which would be given up to the protocol if the position was sacrificed now.
+	///
+	/// Read-only simulation of `sacrifice_position`.
+	pub fn sacrifice_value(position_id: T::PositionItemId) -> Result<SacrificedLiquidity<Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let (amount, hub_amount) = Self::position_value(position_id)?;
+		Ok(SacrificedLiquidity {
+			shares: position.shares,
+			amount,
+			hub_amount,
+		})
+	}
+
+	/// Compares tracked reserves of all assets with balances of the pool account
+	/// and share issuance of all assets with shares of their positions.
+	pub fn solvency_report() -> SolvencyReport<T::AssetId, Balance> {
+		let mut position_shares: BTreeMap<T::AssetId, Balance> = BTreeMap::new();
+		for position in Positions::<T>::iter_values() {
+			position_shares
+				.entry(position.asset_id)
+				.or_default()
+				.saturating_accrue(position.shares);
+		}
+
+		let account = Self::protocol_account();
+		let mut hub_reserve = Balance::zero();
+		let assets = Assets::<T>::iter()
+			.map(|(asset_id, state)| {
+				hub_reserve.saturating_accrue(state.hub_reserve);
+				let solvency = AssetSolvency {
+					reserve: state.reserve,
+					balance: T::Currency::free_balance(asset_id, &account),
+					shares: state.shares,
+					protocol_shares: state.protocol_shares,
+					position_shares: position_shares.get(&asset_id).copied().unwrap_or_default(),
+				};
+				(asset_id, solvency)
+			})
+			.collect();
+
+		SolvencyReport {
+			hub_reserve,
+			hub_balance: T::Currency::free_balance(T::HubAssetId::get(), &account),
+			assets,
+		}
 	}
 
 	pub fn process_hub_amount(amount: Balance, dest: &T::AccountId) -> DispatchResult {
@@ -2079,6 +4236,8 @@ impl<T: Config> Pallet<T> {
 		)
 		.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
 
+		Self::ensure_price_within_band(asset, EmaPrice::new(asset_state.hub_reserve, asset_state.reserve))?;
+
 		let current_imbalance = <HubAssetImbalance<T>>::get();
 		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
 
@@ -2130,6 +4289,7 @@ impl<T: Config> Pallet<T> {
 		let instance_id = Self::create_and_mint_position_instance(&who)?;
 
 		<Positions<T>>::insert(instance_id, lp_position);
+		Self::snapshot_fee_growth(instance_id, asset);
 
 		Self::deposit_event(Event::PositionCreated {
 			position_id: instance_id,
@@ -2250,3 +4410,30 @@ impl<T: Config> Pallet<T> {
 		);
 	}
 }
+
+impl<T: Config> AddSingleAssetLiquidity<T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	fn can_add_liquidity(asset: T::AssetId) -> bool {
+		<Assets<T>>::get(asset).is_some_and(|state| state.tradable.contains(Tradability::ADD_LIQUIDITY))
+	}
+
+	fn add_liquidity(who: T::AccountId, asset: T::AssetId, amount: Balance) -> Result<Balance, DispatchError> {
+		let position_id = Self::do_add_liquidity_with_limit(
+			frame_system::RawOrigin::Signed(who).into(),
+			asset,
+			amount,
+			Balance::MIN,
+		)?;
+		let position = <Positions<T>>::get(position_id).defensive_ok_or(Error::<T>::PositionNotFound)?;
+
+		// Value of the position in hub asset at the price the position was created at.
+		multiply_by_rational_with_rounding(position.amount, position.price.0, position.price.1, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	fn add_liquidity_weight() -> frame_support::weights::Weight {
+		<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+			.saturating_add(T::PriceBandOracle::get_price_weight())
+	}
+}
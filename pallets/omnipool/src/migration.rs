@@ -0,0 +1,158 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+pub mod versioned {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+
+	pub type V0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		v1::VersionUncheckedMigrateToV1<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	pub type V1ToV2<T, F> = VersionedMigration<
+		1,
+		2,
+		v2::VersionUncheckedMigrateToV2<T, F>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// This migration adds tracked reserve to asset state. Reserve of each asset is initialized
+// to the current balance of the pool account.
+pub mod v1 {
+	use super::*;
+	use codec::Decode;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use frame_support::weights::Weight;
+	use sp_std::marker::PhantomData;
+
+	#[derive(Decode)]
+	pub struct OldAssetState<Balance> {
+		pub hub_reserve: Balance,
+		pub shares: Balance,
+		pub protocol_shares: Balance,
+		pub cap: u128,
+		pub tradable: Tradability,
+	}
+
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			let account = Pallet::<T>::protocol_account();
+			Assets::<T>::translate::<OldAssetState<Balance>, _>(|asset_id, old| {
+				count.saturating_inc();
+				Some(AssetState {
+					reserve: T::Currency::free_balance(asset_id, &account),
+					hub_reserve: old.hub_reserve,
+					shares: old.shares,
+					protocol_shares: old.protocol_shares,
+					cap: old.cap,
+					tradable: old.tradable,
+				})
+			});
+			T::DbWeight::get().reads_writes(count.saturating_mul(2), count)
+		}
+	}
+}
+
+// Asset fees which belong to LPs are moved to the fee account instead of staying in the reserves.
+// Fees accrued before the upgrade are already part of the reserves and are paid out as value of the positions,
+// so fee growth of assets and positions is reset. Fee account is funded with existential deposit of native asset
+// from account `F`, so native fees lower than existential deposit can be transferred to it. `F` must not be
+// the Omnipool account, so that reserves and prices are not changed.
+//
+// Fee growth entries are cleared in a single block. `try-runtime` checks that clearing all of them fits
+// into the maximum block weight.
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use frame_support::weights::Weight;
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+	use sp_std::marker::PhantomData;
+
+	const TARGET: &str = "runtime::omnipool::migration::v2";
+
+	pub struct VersionUncheckedMigrateToV2<T, F>(PhantomData<(T, F)>);
+
+	impl<T: Config, F: Get<T::AccountId>> VersionUncheckedMigrateToV2<T, F> {
+		fn migration_weight(assets: u64, positions: u64) -> Weight {
+			let entries = assets.saturating_add(positions);
+			T::DbWeight::get().reads_writes(entries.saturating_add(4), entries.saturating_add(2))
+		}
+	}
+
+	impl<T: Config, F: Get<T::AccountId>> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T, F> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let assets = AssetFeeGrowth::<T>::iter().count() as u64;
+			let positions = PositionFeeGrowth::<T>::iter().count() as u64;
+			log::info!(
+				target: TARGET,
+				"pre-upgrade state contains '{}' asset and '{}' position fee growth entries.",
+				assets,
+				positions
+			);
+
+			let weight = Self::migration_weight(assets, positions);
+			let max_weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+			ensure!(
+				weight.all_lte(max_weight),
+				TryRuntimeError::Other("clearing fee growth does not fit into a block")
+			);
+
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let assets = AssetFeeGrowth::<T>::clear(u32::MAX, None).unique as u64;
+			let positions = PositionFeeGrowth::<T>::clear(u32::MAX, None).unique as u64;
+
+			let native = T::HdxAssetId::get();
+			let fee_account = Pallet::<T>::fee_account();
+			let missing =
+				T::Currency::minimum_balance(native).saturating_sub(T::Currency::free_balance(native, &fee_account));
+			if !missing.is_zero() {
+				if let Err(e) = T::Currency::transfer(native, &F::get(), &fee_account, missing) {
+					log::error!(target: TARGET, "failed to fund fee account: {:?}", e);
+				}
+			}
+
+			Self::migration_weight(assets, positions)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				AssetFeeGrowth::<T>::iter().next().is_none(),
+				TryRuntimeError::Other("AssetFeeGrowth storage is not empty")
+			);
+			ensure!(
+				PositionFeeGrowth::<T>::iter().next().is_none(),
+				TryRuntimeError::Other("PositionFeeGrowth storage is not empty")
+			);
+			Ok(())
+		}
+	}
+}
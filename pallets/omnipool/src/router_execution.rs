@@ -5,9 +5,9 @@ use hydra_dx_math::omnipool::types::I129;
 
 use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use orml_traits::{GetByKey, MultiCurrency};
-use sp_runtime::traits::Get;
+use sp_runtime::traits::{Get, Saturating};
 use sp_runtime::DispatchError::Corruption;
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
 
 // dev note: The code is calculate sell and buy is copied from the corresponding functions.
 // This is not ideal and should be refactored to avoid code duplication.
@@ -207,4 +207,26 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 
 		Ok(spot_price)
 	}
+
+	fn calculate_fee(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<Permill, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Omnipool {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		if asset_out == T::HubAssetId::get() {
+			return Err(ExecutorError::Error(Error::<T>::NotAllowed.into()));
+		}
+
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+		if asset_in == T::HubAssetId::get() {
+			return Ok(asset_fee);
+		}
+
+		let (_, protocol_fee) = T::Fee::get(&asset_in);
+		Ok(asset_fee.saturating_add(protocol_fee))
+	}
 }
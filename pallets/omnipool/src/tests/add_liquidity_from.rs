@@ -0,0 +1,109 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+fn with_two_tokens() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+}
+
+#[test]
+fn add_liquidity_from_should_add_whole_amount_received_from_trade() {
+	with_two_tokens().build().execute_with(|| {
+		let position_id = last_position_id();
+
+		// ACT
+		assert_ok!(Omnipool::add_liquidity_from(
+			RuntimeOrigin::signed(LP1),
+			100,
+			50 * ONE,
+			200,
+			Balance::zero()
+		));
+
+		// ASSERT
+		let position = Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(position.asset_id, 200);
+		assert!(position.amount > Balance::zero());
+
+		assert_balance!(LP1, 100, 950 * ONE);
+		assert_balance!(LP1, 200, 0);
+		assert_balance!(Omnipool::protocol_account(), 100, 2050 * ONE);
+		assert_balance!(Omnipool::protocol_account(), 200, 2000 * ONE);
+
+		assert_eq!(POSITIONS.with(|v| v.borrow().get(&position_id).copied()), Some(LP1));
+
+		System::assert_last_event(
+			Event::LiquidityAddedFrom {
+				who: LP1,
+				asset_in: 100,
+				amount_in: 50 * ONE,
+				asset_id: 200,
+				amount: position.amount,
+				position_id,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_from_should_fail_when_min_shares_limit_is_not_reached() {
+	with_two_tokens().build().execute_with(|| {
+		// ACT & ASSERT
+		assert_noop!(
+			Omnipool::add_liquidity_from(RuntimeOrigin::signed(LP1), 100, 50 * ONE, 200, 50 * ONE),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_from_should_fail_when_trade_fails() {
+	with_two_tokens().build().execute_with(|| {
+		// ACT & ASSERT
+		assert_noop!(
+			Omnipool::add_liquidity_from(RuntimeOrigin::signed(LP1), 100, 2000 * ONE, 200, Balance::zero()),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_from_should_fail_when_liquidity_of_target_asset_cannot_be_added() {
+	with_two_tokens().build().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			200,
+			Tradability::SELL | Tradability::BUY
+		));
+
+		// ACT & ASSERT
+		assert_noop!(
+			Omnipool::add_liquidity_from(RuntimeOrigin::signed(LP1), 100, 50 * ONE, 200, Balance::zero()),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_from_should_fail_when_assets_are_same() {
+	with_two_tokens().build().execute_with(|| {
+		// ACT & ASSERT
+		assert_noop!(
+			Omnipool::add_liquidity_from(RuntimeOrigin::signed(LP1), 100, 50 * ONE, 100, Balance::zero()),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
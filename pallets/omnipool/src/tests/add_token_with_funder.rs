@@ -0,0 +1,241 @@
+use super::*;
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+
+const TOKEN: AssetId = REGISTERED_ASSET;
+const FUNDER: u64 = LP3;
+
+fn pool_with_funder() -> ExtBuilder {
+	ExtBuilder::default()
+		.add_endowed_accounts((FUNDER, TOKEN, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+}
+
+fn add_funded_token(vesting_period: Option<u64>) -> <Test as Config>::PositionItemId {
+	let position_id = <NextPositionId<Test>>::get();
+	assert_ok!(Omnipool::approve_token_funding(
+		RuntimeOrigin::signed(FUNDER),
+		TOKEN,
+		2000 * ONE
+	));
+	assert_ok!(Omnipool::add_token_with_funder(
+		RuntimeOrigin::root(),
+		TOKEN,
+		FixedU128::from_float(0.65),
+		Permill::from_percent(100),
+		LP1,
+		FUNDER,
+		vesting_period,
+	));
+	position_id
+}
+
+#[test]
+fn approve_token_funding_should_store_approval() {
+	pool_with_funder().build().execute_with(|| {
+		assert_ok!(Omnipool::approve_token_funding(
+			RuntimeOrigin::signed(FUNDER),
+			TOKEN,
+			2000 * ONE
+		));
+
+		assert_eq!(Omnipool::token_funding_approval(FUNDER, TOKEN), 2000 * ONE);
+		expect_events(vec![Event::TokenFundingApproved {
+			funder: FUNDER,
+			asset_id: TOKEN,
+			amount: 2000 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn approve_token_funding_should_remove_approval_when_amount_is_zero() {
+	pool_with_funder().build().execute_with(|| {
+		assert_ok!(Omnipool::approve_token_funding(
+			RuntimeOrigin::signed(FUNDER),
+			TOKEN,
+			2000 * ONE
+		));
+
+		assert_ok!(Omnipool::approve_token_funding(RuntimeOrigin::signed(FUNDER), TOKEN, 0));
+
+		assert!(!TokenFundingApprovals::<Test>::contains_key(FUNDER, TOKEN));
+	});
+}
+
+#[test]
+fn add_token_with_funder_should_transfer_approved_amount_from_funder() {
+	pool_with_funder().build().execute_with(|| {
+		let position_id = add_funded_token(None);
+
+		assert_balance!(FUNDER, TOKEN, 3000 * ONE);
+		assert_balance!(Omnipool::protocol_account(), TOKEN, 2000 * ONE);
+		assert_eq!(Omnipool::token_funding_approval(FUNDER, TOKEN), 0);
+		assert_asset_state!(
+			TOKEN,
+			AssetReserveState {
+				reserve: 2000 * ONE,
+				hub_reserve: 1300 * ONE,
+				shares: 2000 * ONE,
+				protocol_shares: Balance::zero(),
+				cap: DEFAULT_WEIGHT_CAP,
+				tradable: Tradability::default(),
+			}
+		);
+		assert_eq!(get_mock_minted_position(position_id), Some(LP1));
+		assert!(Omnipool::position_vesting(position_id).is_none());
+	});
+}
+
+#[test]
+fn add_token_with_funder_should_fail_when_funding_is_not_approved() {
+	pool_with_funder().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_token_with_funder(
+				RuntimeOrigin::root(),
+				TOKEN,
+				FixedU128::from_float(0.65),
+				Permill::from_percent(100),
+				LP1,
+				FUNDER,
+				None,
+			),
+			Error::<Test>::TokenFundingNotApproved
+		);
+	});
+}
+
+#[test]
+fn add_token_with_funder_should_fail_when_vesting_period_is_zero() {
+	pool_with_funder().build().execute_with(|| {
+		assert_ok!(Omnipool::approve_token_funding(
+			RuntimeOrigin::signed(FUNDER),
+			TOKEN,
+			2000 * ONE
+		));
+
+		assert_noop!(
+			Omnipool::add_token_with_funder(
+				RuntimeOrigin::root(),
+				TOKEN,
+				FixedU128::from_float(0.65),
+				Permill::from_percent(100),
+				LP1,
+				FUNDER,
+				Some(0),
+			),
+			Error::<Test>::InvalidVestingPeriod
+		);
+	});
+}
+
+#[test]
+fn add_token_with_funder_should_fail_when_origin_is_not_authority() {
+	pool_with_funder().build().execute_with(|| {
+		assert_ok!(Omnipool::approve_token_funding(
+			RuntimeOrigin::signed(FUNDER),
+			TOKEN,
+			2000 * ONE
+		));
+
+		assert_noop!(
+			Omnipool::add_token_with_funder(
+				RuntimeOrigin::signed(FUNDER),
+				TOKEN,
+				FixedU128::from_float(0.65),
+				Permill::from_percent(100),
+				FUNDER,
+				FUNDER,
+				None,
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_token_with_funder_should_vest_initial_shares() {
+	pool_with_funder().build().execute_with(|| {
+		let position_id = add_funded_token(Some(100));
+
+		assert_eq!(
+			Omnipool::position_vesting(position_id),
+			Some(SharesVesting {
+				shares: 2000 * ONE,
+				start: 1,
+				period: 100,
+			})
+		);
+		assert_eq!(Omnipool::locked_shares(position_id), 2000 * ONE);
+		expect_events(vec![Event::PositionVestingSet {
+			position_id,
+			shares: 2000 * ONE,
+			start: 1,
+			period: 100,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_shares_are_vesting() {
+	pool_with_funder().build().execute_with(|| {
+		let position_id = add_funded_token(Some(100));
+
+		assert_noop!(
+			Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), position_id, ONE),
+			Error::<Test>::SharesVesting
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_only_unlocked_shares_when_shares_are_vesting() {
+	pool_with_funder().build().execute_with(|| {
+		let position_id = add_funded_token(Some(100));
+
+		System::set_block_number(51);
+
+		assert_eq!(Omnipool::locked_shares(position_id), 1000 * ONE);
+		assert_noop!(
+			Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), position_id, 1000 * ONE + 1),
+			Error::<Test>::SharesVesting
+		);
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			1000 * ONE
+		));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_all_shares_when_vesting_is_over() {
+	pool_with_funder().build().execute_with(|| {
+		let position_id = add_funded_token(Some(100));
+
+		System::set_block_number(101);
+
+		assert_eq!(Omnipool::locked_shares(position_id), 0);
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			2000 * ONE
+		));
+		assert!(Positions::<Test>::get(position_id).is_none());
+		assert!(Omnipool::position_vesting(position_id).is_none());
+	});
+}
+
+#[test]
+fn sacrifice_position_should_work_when_shares_are_vesting() {
+	pool_with_funder().build().execute_with(|| {
+		let position_id = add_funded_token(Some(100));
+
+		assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id));
+
+		assert!(Positions::<Test>::get(position_id).is_none());
+		assert!(Omnipool::position_vesting(position_id).is_none());
+	});
+}
@@ -434,6 +434,7 @@ fn buy_should_emit_event_with_correct_asset_fee_amount() {
 				hub_amount_out: 57142857142858,
 				asset_fee_amount: 5_555_555_555_556,
 				protocol_fee_amount: 0,
+				correlation_id: correlation_id(),
 			}
 			.into()]);
 		});
@@ -479,6 +480,7 @@ fn buy_should_emit_event_with_correct_protocol_fee_amount() {
 				hub_amount_out: 51282051282052,
 				asset_fee_amount: 0,
 				protocol_fee_amount: 5698005698005,
+				correlation_id: correlation_id(),
 			}
 			.into()]);
 		});
@@ -523,6 +525,7 @@ fn sell_should_get_same_amount() {
 				hub_amount_out: 57142857142858,
 				asset_fee_amount: 5555555555556,
 				protocol_fee_amount: 0,
+				correlation_id: correlation_id(),
 			}
 			.into()]);
 		});
@@ -698,8 +701,9 @@ fn buy_should_work_when_trading_native_asset() {
 			);
 			assert_eq!(
 				Tokens::free_balance(200, &Omnipool::protocol_account()),
-				1950000000000000
+				1944444444444445
 			);
+			assert_eq!(Tokens::free_balance(200, &Omnipool::fee_account()), 5555555555555);
 
 			let hub_reserves: Vec<Balance> = Assets::<Test>::iter().map(|v| v.1.hub_reserve).collect();
 
@@ -715,7 +719,7 @@ fn buy_should_work_when_trading_native_asset() {
 			assert_asset_state!(
 				200,
 				AssetReserveState {
-					reserve: 1950000000000000,
+					reserve: 1944444444444445,
 					hub_reserve: 1337142857142858,
 					shares: 2000 * ONE,
 					protocol_shares: Balance::zero(),
@@ -0,0 +1,172 @@
+use super::*;
+use frame_support::assert_noop;
+use frame_support::traits::Hooks;
+
+const SALT: [u8; 32] = [7u8; 32];
+
+fn commit_reveal_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn next_block() {
+	let n = System::block_number() + 1;
+	System::set_block_number(n);
+	Omnipool::on_initialize(n);
+}
+
+fn commitment(who: AccountId, amount: Balance) -> <Test as frame_system::Config>::Hash {
+	Omnipool::trade_commitment(&who, 100, 200, amount, 0, SALT)
+}
+
+#[test]
+fn commit_trade_should_store_commitment() {
+	commit_reveal_ext().execute_with(|| {
+		let commitment = commitment(LP1, 50 * ONE);
+
+		assert_ok!(Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment));
+
+		assert_eq!(Omnipool::trade_commitments(LP1, commitment), Some(1));
+		expect_events(vec![Event::TradeCommitted {
+			who: LP1,
+			commitment,
+			expires_at: 1 + TradeCommitmentExpiry::get(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn commit_trade_should_fail_when_commitment_exists() {
+	commit_reveal_ext().execute_with(|| {
+		let commitment = commitment(LP1, 50 * ONE);
+		assert_ok!(Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment));
+
+		assert_noop!(
+			Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment),
+			Error::<Test>::TradeCommitmentAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn commit_trade_should_fail_when_max_commitments_per_block_is_reached() {
+	commit_reveal_ext().execute_with(|| {
+		assert_ok!(Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment(LP1, ONE)));
+		assert_ok!(Omnipool::commit_trade(
+			RuntimeOrigin::signed(LP1),
+			commitment(LP1, 2 * ONE)
+		));
+
+		assert_noop!(
+			Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment(LP1, 3 * ONE)),
+			Error::<Test>::MaxTradeCommitmentsPerBlockExceeded
+		);
+	});
+}
+
+#[test]
+fn reveal_sell_should_execute_sell_when_revealed_in_later_block() {
+	commit_reveal_ext().execute_with(|| {
+		let commitment = commitment(LP1, 50 * ONE);
+		assert_ok!(Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment));
+		next_block();
+
+		assert_ok!(Omnipool::reveal_sell(
+			RuntimeOrigin::signed(LP1),
+			100,
+			200,
+			50 * ONE,
+			0,
+			SALT
+		));
+
+		assert_eq!(Tokens::free_balance(100, &LP1), 950 * ONE);
+		assert!(Tokens::free_balance(200, &LP1) > 0);
+		assert_eq!(Omnipool::trade_commitments(LP1, commitment), None);
+		expect_events(vec![Event::TradeRevealed { who: LP1, commitment }.into()]);
+	});
+}
+
+#[test]
+fn reveal_sell_should_fail_when_revealed_in_commitment_block() {
+	commit_reveal_ext().execute_with(|| {
+		assert_ok!(Omnipool::commit_trade(
+			RuntimeOrigin::signed(LP1),
+			commitment(LP1, 50 * ONE)
+		));
+
+		assert_noop!(
+			Omnipool::reveal_sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0, SALT),
+			Error::<Test>::TradeCommitmentNotMature
+		);
+	});
+}
+
+#[test]
+fn reveal_sell_should_fail_when_parameters_do_not_match_commitment() {
+	commit_reveal_ext().execute_with(|| {
+		assert_ok!(Omnipool::commit_trade(
+			RuntimeOrigin::signed(LP1),
+			commitment(LP1, 50 * ONE)
+		));
+		next_block();
+
+		assert_noop!(
+			Omnipool::reveal_sell(RuntimeOrigin::signed(LP1), 100, 200, 60 * ONE, 0, SALT),
+			Error::<Test>::TradeCommitmentNotFound
+		);
+	});
+}
+
+#[test]
+fn reveal_sell_should_fail_when_revealed_by_other_account() {
+	commit_reveal_ext().execute_with(|| {
+		assert_ok!(Omnipool::commit_trade(
+			RuntimeOrigin::signed(LP1),
+			commitment(LP1, 50 * ONE)
+		));
+		next_block();
+
+		assert_noop!(
+			Omnipool::reveal_sell(RuntimeOrigin::signed(LP2), 100, 200, 50 * ONE, 0, SALT),
+			Error::<Test>::TradeCommitmentNotFound
+		);
+	});
+}
+
+#[test]
+fn unrevealed_commitment_should_expire() {
+	commit_reveal_ext().execute_with(|| {
+		let commitment = commitment(LP1, 50 * ONE);
+		assert_ok!(Omnipool::commit_trade(RuntimeOrigin::signed(LP1), commitment));
+
+		for _ in 0..TradeCommitmentExpiry::get() {
+			next_block();
+		}
+		assert_eq!(Omnipool::trade_commitments(LP1, commitment), Some(1));
+
+		next_block();
+
+		assert_eq!(Omnipool::trade_commitments(LP1, commitment), None);
+		expect_events(vec![Event::TradeCommitmentExpired { who: LP1, commitment }.into()]);
+		assert_noop!(
+			Omnipool::reveal_sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0, SALT),
+			Error::<Test>::TradeCommitmentNotFound
+		);
+	});
+}
@@ -0,0 +1,290 @@
+use super::*;
+use frame_support::assert_noop;
+
+const TOKEN: AssetId = 1_000;
+
+fn pool_with_asset_fee() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, TOKEN, 2000 * ONE),
+			(LP1, TOKEN, 5000 * ONE),
+			(LP3, HDX, 1000 * ONE),
+		])
+		.with_asset_fee(Permill::from_percent(1))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(TOKEN, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+}
+
+fn sell_hdx_for_token(amount: Balance) -> Balance {
+	assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), HDX, TOKEN, amount, 0));
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Omnipool(Event::SellExecuted { asset_fee_amount, .. }) => Some(asset_fee_amount),
+			_ => None,
+		})
+		.unwrap()
+}
+
+#[test]
+fn sell_should_increase_fee_growth_of_asset_out() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let shares = Assets::<Test>::get(TOKEN).unwrap().shares;
+
+		let fee = sell_hdx_for_token(50 * ONE);
+
+		assert!(fee > 0);
+		assert_eq!(
+			Omnipool::asset_fee_growth(TOKEN),
+			FixedU128::from_rational(fee - 1, shares)
+		);
+		assert_eq!(Omnipool::asset_fee_growth(HDX), FixedU128::zero());
+	});
+}
+
+#[test]
+fn fee_growth_should_not_include_fee_taken_by_hooks() {
+	pool_with_asset_fee()
+		.with_on_trade_withdrawal(Permill::from_percent(50))
+		.build()
+		.execute_with(|| {
+			let shares = Assets::<Test>::get(TOKEN).unwrap().shares;
+
+			let fee = sell_hdx_for_token(50 * ONE);
+
+			let taken = Permill::from_percent(50).mul_floor(fee - 1);
+			assert_eq!(
+				Omnipool::asset_fee_growth(TOKEN),
+				FixedU128::from_rational(fee - 1 - taken, shares)
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_snapshot_current_fee_growth() {
+	pool_with_asset_fee().build().execute_with(|| {
+		sell_hdx_for_token(50 * ONE);
+		let growth = Omnipool::asset_fee_growth(TOKEN);
+
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+
+		assert_eq!(Omnipool::position_fee_growth(position_id), growth);
+		assert_eq!(Omnipool::position_fee_earnings(position_id), Ok(0));
+	});
+}
+
+#[test]
+fn position_fee_earnings_should_include_only_fees_accrued_after_position_was_created() {
+	pool_with_asset_fee().build().execute_with(|| {
+		sell_hdx_for_token(50 * ONE);
+
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+		let growth_before = Omnipool::asset_fee_growth(TOKEN);
+
+		sell_hdx_for_token(50 * ONE);
+
+		let expected = Omnipool::asset_fee_growth(TOKEN)
+			.saturating_sub(growth_before)
+			.checked_mul_int(shares)
+			.unwrap();
+		assert!(expected > 0);
+		assert_eq!(Omnipool::position_fee_earnings(position_id), Ok(expected));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_report_fee_earnings_of_removed_shares() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+		sell_hdx_for_token(50 * ONE);
+
+		let growth = Omnipool::asset_fee_growth(TOKEN);
+		let removed = shares / 2;
+		let expected = growth.checked_mul_int(removed).unwrap();
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			removed
+		));
+
+		assert!(System::events().into_iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Omnipool(Event::LiquidityRemoved { position_id: id, fee_earnings, .. })
+				if id == position_id && fee_earnings == expected
+		)));
+		assert_eq!(
+			Omnipool::position_fee_earnings(position_id),
+			Ok(growth.checked_mul_int(shares - removed).unwrap())
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_fee_growth_snapshot_when_position_is_destroyed() {
+	pool_with_asset_fee().build().execute_with(|| {
+		sell_hdx_for_token(50 * ONE);
+
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+		assert!(PositionFeeGrowth::<Test>::contains_key(position_id));
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			shares
+		));
+
+		assert!(!PositionFeeGrowth::<Test>::contains_key(position_id));
+		assert_eq!(
+			Omnipool::position_fee_earnings(position_id),
+			Err(Error::<Test>::PositionNotFound.into())
+		);
+	});
+}
+
+#[test]
+fn sell_should_move_fee_from_reserve_to_fee_account() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let reserve = Assets::<Test>::get(TOKEN).unwrap().reserve;
+
+		let fee = sell_hdx_for_token(50 * ONE);
+
+		let amount_out = Tokens::free_balance(TOKEN, &LP3);
+		let expected_reserve = reserve - amount_out - (fee - 1);
+		assert_balance!(Omnipool::fee_account(), TOKEN, fee - 1);
+		assert_balance!(Omnipool::protocol_account(), TOKEN, expected_reserve);
+		assert_eq!(Assets::<Test>::get(TOKEN).unwrap().reserve, expected_reserve);
+	});
+}
+
+#[test]
+fn claim_fees_should_pay_fee_earnings_to_owner() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+
+		sell_hdx_for_token(50 * ONE);
+
+		let earnings = Omnipool::position_fee_earnings(position_id).unwrap();
+		assert!(earnings > 0);
+		let balance = Tokens::free_balance(TOKEN, &LP1);
+		let accrued = Tokens::free_balance(TOKEN, &Omnipool::fee_account());
+
+		assert_ok!(Omnipool::claim_fees(RuntimeOrigin::signed(LP1), position_id));
+
+		assert_balance!(LP1, TOKEN, balance + earnings);
+		assert_balance!(Omnipool::fee_account(), TOKEN, accrued - earnings);
+		assert_eq!(Omnipool::position_fee_earnings(position_id), Ok(0));
+		assert_eq!(
+			Omnipool::position_fee_growth(position_id),
+			Omnipool::asset_fee_growth(TOKEN)
+		);
+		expect_events(vec![Event::FeesClaimed {
+			who: LP1,
+			position_id,
+			asset_id: TOKEN,
+			amount: earnings,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn claim_fees_should_fail_when_position_has_no_fee_earnings() {
+	pool_with_asset_fee().build().execute_with(|| {
+		sell_hdx_for_token(50 * ONE);
+
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+
+		assert_noop!(
+			Omnipool::claim_fees(RuntimeOrigin::signed(LP1), position_id),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn claim_fees_should_fail_when_signed_account_is_not_owner() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+
+		sell_hdx_for_token(50 * ONE);
+
+		assert_noop!(
+			Omnipool::claim_fees(RuntimeOrigin::signed(LP2), position_id),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_pay_fee_earnings_from_fee_account() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+		sell_hdx_for_token(50 * ONE);
+
+		let earnings = Omnipool::position_fee_earnings(position_id).unwrap();
+		let accrued = Tokens::free_balance(TOKEN, &Omnipool::fee_account());
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			shares
+		));
+
+		assert_balance!(Omnipool::fee_account(), TOKEN, accrued - earnings);
+	});
+}
+
+#[test]
+fn sacrifice_position_should_pay_fee_earnings_to_owner() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 100 * ONE));
+
+		sell_hdx_for_token(50 * ONE);
+
+		let earnings = Omnipool::position_fee_earnings(position_id).unwrap();
+		let balance = Tokens::free_balance(TOKEN, &LP1);
+
+		assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id));
+
+		assert_balance!(LP1, TOKEN, balance + earnings);
+	});
+}
+
+#[test]
+fn sell_should_keep_fee_of_protocol_shares_in_reserve() {
+	pool_with_asset_fee().build().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+		assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id));
+		let state = Assets::<Test>::get(TOKEN).unwrap();
+		let lp_shares = state.shares - state.protocol_shares;
+
+		let fee = sell_hdx_for_token(50 * ONE);
+
+		let lp_fee = multiply_by_rational_with_rounding(fee - 1, lp_shares, state.shares, Rounding::Down).unwrap();
+		assert_balance!(Omnipool::fee_account(), TOKEN, lp_fee);
+		assert_eq!(
+			Omnipool::asset_fee_growth(TOKEN),
+			FixedU128::from_rational(lp_fee, lp_shares)
+		);
+	});
+}
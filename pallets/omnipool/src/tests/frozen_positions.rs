@@ -0,0 +1,146 @@
+use super::*;
+use frame_support::assert_noop;
+
+const TOKEN: AssetId = 1_000;
+
+fn pool_with_lp_position() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, TOKEN, 2000 * ONE),
+			(LP1, TOKEN, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(TOKEN, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 400 * ONE));
+	});
+	ext
+}
+
+#[test]
+fn freeze_position_should_work_when_position_exists() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::freeze_position(position_id));
+
+		assert!(Omnipool::is_position_frozen(position_id));
+		assert_eq!(POSITIONS.with(|v| v.borrow().get(&position_id).copied()), Some(LP1));
+	});
+}
+
+#[test]
+fn freeze_position_should_fail_when_position_does_not_exist() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+
+		assert_noop!(Omnipool::freeze_position(position_id), Error::<Test>::PositionNotFound);
+	});
+}
+
+#[test]
+fn freeze_position_should_fail_when_position_is_already_frozen() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+		assert_ok!(Omnipool::freeze_position(position_id));
+
+		assert_noop!(Omnipool::freeze_position(position_id), Error::<Test>::PositionFrozen);
+	});
+}
+
+#[test]
+fn freeze_position_should_fail_when_withdrawal_is_queued() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, TOKEN, 2000 * ONE),
+			(LP1, TOKEN, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(TOKEN, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_withdrawal_queue_threshold(Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 400 * ONE));
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				400 * ONE
+			));
+
+			assert_noop!(
+				Omnipool::freeze_position(position_id),
+				Error::<Test>::WithdrawalAlreadyQueued
+			);
+		});
+}
+
+#[test]
+fn thaw_position_should_work_when_position_is_frozen() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+		assert_ok!(Omnipool::freeze_position(position_id));
+
+		assert_ok!(Omnipool::thaw_position(position_id));
+
+		assert!(!Omnipool::is_position_frozen(position_id));
+	});
+}
+
+#[test]
+fn thaw_position_should_fail_when_position_is_not_frozen() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_noop!(Omnipool::thaw_position(position_id), Error::<Test>::PositionNotFrozen);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_position_is_frozen() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+		assert_ok!(Omnipool::freeze_position(position_id));
+
+		assert_noop!(
+			Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), position_id, 200 * ONE),
+			Error::<Test>::PositionFrozen
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_position_was_thawed() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+		assert_ok!(Omnipool::freeze_position(position_id));
+		assert_ok!(Omnipool::thaw_position(position_id));
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		assert_balance!(LP1, TOKEN, 5000 * ONE);
+		assert!(Positions::<Test>::get(position_id).is_none());
+	});
+}
+
+#[test]
+fn sacrifice_position_should_fail_when_position_is_frozen() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+		assert_ok!(Omnipool::freeze_position(position_id));
+
+		assert_noop!(
+			Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id),
+			Error::<Test>::PositionFrozen
+		);
+	});
+}
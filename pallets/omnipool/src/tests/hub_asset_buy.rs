@@ -0,0 +1,205 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+fn enable_hub_asset_buy() {
+	assert_ok!(Omnipool::set_asset_tradable_state(
+		RuntimeOrigin::root(),
+		LRNA,
+		Tradability::SELL | Tradability::BUY
+	));
+}
+
+fn hub_asset_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+}
+
+#[test]
+fn sell_for_hub_asset_should_work_when_hub_asset_buy_is_allowed() {
+	hub_asset_ext().build().execute_with(|| {
+		// Arrange
+		enable_hub_asset_buy();
+
+		// Act
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0));
+
+		// Assert
+		assert_balance!(LP1, 100, 900 * ONE);
+		assert_balance!(LP1, LRNA, 61904761904761);
+		assert_hub_asset!();
+		assert_asset_state!(
+			100,
+			AssetReserveState {
+				reserve: 2100 * ONE,
+				hub_reserve: 1300 * ONE - 61904761904761,
+				shares: 2000 * ONE,
+				protocol_shares: Balance::zero(),
+				cap: DEFAULT_WEIGHT_CAP,
+				tradable: Tradability::default(),
+			}
+		);
+		expect_events(vec![Event::SellExecuted {
+			who: LP1,
+			asset_in: 100,
+			asset_out: LRNA,
+			amount_in: 100 * ONE,
+			amount_out: 61904761904761,
+			hub_amount_in: 0,
+			hub_amount_out: 0,
+			asset_fee_amount: 0,
+			protocol_fee_amount: 0,
+			correlation_id: correlation_id(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_for_hub_asset_should_transfer_protocol_fee_to_hdx_subpool() {
+	hub_asset_ext()
+		.with_protocol_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			enable_hub_asset_buy();
+
+			// Act
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0));
+
+			// Assert
+			assert_balance!(LP1, LRNA, 61285714285714);
+			assert_eq!(Omnipool::assets(HDX).unwrap().hub_reserve, NATIVE_AMOUNT + 619047619047);
+			assert_hub_asset!();
+		});
+}
+
+#[test]
+fn buy_hub_asset_should_work_when_hub_asset_buy_is_allowed() {
+	hub_asset_ext().build().execute_with(|| {
+		// Arrange
+		enable_hub_asset_buy();
+
+		// Act
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP1),
+			LRNA,
+			100,
+			50 * ONE,
+			100 * ONE
+		));
+
+		// Assert
+		assert_balance!(LP1, LRNA, 50 * ONE);
+		assert_balance!(LP1, 100, 1000 * ONE - 80 * ONE - 1);
+		assert_hub_asset!();
+		assert_asset_state!(
+			100,
+			AssetReserveState {
+				reserve: 2080 * ONE + 1,
+				hub_reserve: 1250 * ONE,
+				shares: 2000 * ONE,
+				protocol_shares: Balance::zero(),
+				cap: DEFAULT_WEIGHT_CAP,
+				tradable: Tradability::default(),
+			}
+		);
+	});
+}
+
+#[test]
+fn buy_hub_asset_should_fail_when_limit_is_exceeded() {
+	hub_asset_ext().build().execute_with(|| {
+		enable_hub_asset_buy();
+
+		assert_noop!(
+			Omnipool::buy(RuntimeOrigin::signed(LP1), LRNA, 100, 50 * ONE, 80 * ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn hub_asset_buy_should_fail_when_max_amount_per_block_is_exceeded() {
+	hub_asset_ext()
+		.with_max_hub_asset_buy_per_block(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			enable_hub_asset_buy();
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0),
+				Error::<Test>::MaxHubAssetBuyPerBlockExceeded
+			);
+			assert_noop!(
+				Omnipool::buy(RuntimeOrigin::signed(LP1), LRNA, 100, 60 * ONE, 1000 * ONE),
+				Error::<Test>::MaxHubAssetBuyPerBlockExceeded
+			);
+		});
+}
+
+#[test]
+fn hub_asset_buy_should_work_again_when_next_block_starts() {
+	hub_asset_ext()
+		.with_max_hub_asset_buy_per_block(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			enable_hub_asset_buy();
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0));
+
+			// Act
+			System::set_block_number(System::block_number() + 1);
+
+			// Assert
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0));
+			assert_balance!(LP1, LRNA, 118181818181817);
+			assert_hub_asset!();
+		});
+}
+
+#[test]
+fn hub_asset_buy_should_fail_when_max_amount_per_block_is_zero() {
+	hub_asset_ext()
+		.with_max_hub_asset_buy_per_block(Permill::zero())
+		.build()
+		.execute_with(|| {
+			enable_hub_asset_buy();
+
+			assert_noop!(
+				Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0),
+				Error::<Test>::MaxHubAssetBuyPerBlockExceeded
+			);
+		});
+}
+
+#[test]
+fn hub_asset_buy_should_fail_when_price_differs_from_oracle_price() {
+	hub_asset_ext()
+		.with_max_allowed_price_difference(Permill::from_percent(1))
+		.with_external_price_adjustment((3, 100, false))
+		.build()
+		.execute_with(|| {
+			enable_hub_asset_buy();
+
+			assert_noop!(
+				Omnipool::sell(RuntimeOrigin::signed(LP1), 100, LRNA, 100 * ONE, 0),
+				Error::<Test>::PriceDifferenceTooHigh
+			);
+			assert_noop!(
+				Omnipool::buy(RuntimeOrigin::signed(LP1), LRNA, 100, 50 * ONE, 100 * ONE),
+				Error::<Test>::PriceDifferenceTooHigh
+			);
+		});
+}
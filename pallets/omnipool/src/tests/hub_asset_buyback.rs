@@ -0,0 +1,256 @@
+use super::*;
+use crate::types::{HubAssetBuyback, ProtocolFeeDestination};
+use frame_support::assert_noop;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const SINK: AccountId = 1_000;
+
+fn buyback(
+	max_hub_amount: Balance,
+	destination: ProtocolFeeDestination<AccountId>,
+) -> HubAssetBuyback<AccountId, AssetId, u64> {
+	HubAssetBuyback {
+		assets: vec![100, 200].try_into().unwrap(),
+		portion: Permill::from_percent(10),
+		max_hub_amount,
+		period: 10,
+		destination,
+	}
+}
+
+fn buyback_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(Omnipool::buyback_account(), 100, 100 * ONE),
+			(Omnipool::buyback_account(), 200, 100 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+}
+
+fn bought_back() -> Vec<(AssetId, Balance, Balance)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Omnipool(Event::HubAssetBoughtBack {
+				asset_id,
+				amount_in,
+				hub_amount,
+				..
+			}) => Some((asset_id, amount_in, hub_amount)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn set_hub_asset_buyback_should_work_when_called_by_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = buyback(10 * ONE, ProtocolFeeDestination::Burn);
+
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(config.clone())
+		));
+
+		assert_eq!(Omnipool::hub_asset_buyback(), Some(config.clone()));
+		expect_events(vec![Event::HubAssetBuybackSet { config: Some(config) }.into()]);
+	});
+}
+
+#[test]
+fn set_hub_asset_buyback_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_hub_asset_buyback(
+				RuntimeOrigin::signed(LP1),
+				Some(buyback(10 * ONE, ProtocolFeeDestination::Burn))
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_hub_asset_buyback_should_fail_when_config_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let valid = buyback(10 * ONE, ProtocolFeeDestination::Burn);
+		for config in [
+			HubAssetBuyback {
+				assets: vec![].try_into().unwrap(),
+				..valid.clone()
+			},
+			HubAssetBuyback {
+				assets: vec![100, LRNA].try_into().unwrap(),
+				..valid.clone()
+			},
+			HubAssetBuyback {
+				portion: Permill::zero(),
+				..valid.clone()
+			},
+			HubAssetBuyback {
+				max_hub_amount: 0,
+				..valid.clone()
+			},
+			HubAssetBuyback {
+				period: 0,
+				..valid.clone()
+			},
+		] {
+			assert_noop!(
+				Omnipool::set_hub_asset_buyback(RuntimeOrigin::root(), Some(config)),
+				Error::<Test>::InvalidHubAssetBuyback
+			);
+		}
+	});
+}
+
+#[test]
+fn on_idle_should_buy_back_and_burn_hub_asset_when_period_is_reached() {
+	buyback_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(buyback(1_000 * ONE, ProtocolFeeDestination::Burn))
+		));
+
+		// Act
+		Omnipool::on_idle(10, Weight::MAX);
+
+		// Assert
+		let bought = bought_back();
+		assert_eq!(bought.len(), 2);
+		assert_eq!((bought[0].0, bought[0].1), (100, 10 * ONE));
+		assert_eq!((bought[1].0, bought[1].1), (200, 10 * ONE));
+		assert!(bought.iter().all(|(_, _, hub_amount)| *hub_amount > 0));
+		assert_balance!(Omnipool::buyback_account(), 100, 90 * ONE);
+		assert_balance!(Omnipool::buyback_account(), 200, 90 * ONE);
+		assert_balance!(Omnipool::buyback_account(), LRNA, 0);
+		assert_hub_asset!();
+	});
+}
+
+#[test]
+fn on_idle_should_send_bought_hub_asset_to_destination_account() {
+	buyback_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(buyback(1_000 * ONE, ProtocolFeeDestination::Account(SINK)))
+		));
+
+		// Act
+		Omnipool::on_idle(10, Weight::MAX);
+
+		// Assert
+		let total: Balance = bought_back().iter().map(|(_, _, hub_amount)| hub_amount).sum();
+		assert!(total > 0);
+		assert_balance!(SINK, LRNA, total);
+		assert_balance!(Omnipool::buyback_account(), LRNA, 0);
+	});
+}
+
+#[test]
+fn on_idle_should_limit_bought_hub_asset_when_max_hub_amount_is_reached() {
+	buyback_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(buyback(ONE, ProtocolFeeDestination::Burn))
+		));
+
+		// Act
+		Omnipool::on_idle(10, Weight::MAX);
+
+		// Assert
+		let bought = bought_back();
+		assert_eq!(bought.len(), 1);
+		assert_eq!(bought[0].0, 100);
+		assert!(bought[0].1 < 10 * ONE);
+		assert_eq!(bought[0].2, ONE);
+		assert_balance!(Omnipool::buyback_account(), 200, 100 * ONE);
+	});
+}
+
+#[test]
+fn on_idle_should_not_buy_back_when_period_is_not_reached() {
+	buyback_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(buyback(1_000 * ONE, ProtocolFeeDestination::Burn))
+		));
+		Omnipool::on_idle(10, Weight::MAX);
+		System::reset_events();
+
+		// Act
+		Omnipool::on_idle(19, Weight::MAX);
+
+		// Assert
+		assert!(bought_back().is_empty());
+		assert_balance!(Omnipool::buyback_account(), 100, 90 * ONE);
+
+		Omnipool::on_idle(20, Weight::MAX);
+		assert_eq!(bought_back().len(), 2);
+		assert_balance!(Omnipool::buyback_account(), 100, 81 * ONE);
+	});
+}
+
+#[test]
+fn on_idle_should_not_buy_back_when_weight_is_not_sufficient() {
+	buyback_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(buyback(1_000 * ONE, ProtocolFeeDestination::Burn))
+		));
+
+		// Act
+		let weight = Omnipool::on_idle(10, Weight::zero());
+
+		// Assert
+		assert_eq!(weight, Weight::zero());
+		assert!(bought_back().is_empty());
+		assert_balance!(Omnipool::buyback_account(), 100, 100 * ONE);
+	});
+}
+
+#[test]
+fn on_idle_should_continue_with_next_asset_when_buyback_of_asset_fails() {
+	buyback_ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			100,
+			Tradability::BUY | Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY
+		));
+		assert_ok!(Omnipool::set_hub_asset_buyback(
+			RuntimeOrigin::root(),
+			Some(buyback(1_000 * ONE, ProtocolFeeDestination::Burn))
+		));
+
+		// Act
+		Omnipool::on_idle(10, Weight::MAX);
+
+		// Assert
+		expect_events(vec![Event::HubAssetBuybackFailed {
+			asset_id: 100,
+			error: Error::<Test>::NotAllowed.into(),
+		}
+		.into()]);
+		assert_balance!(Omnipool::buyback_account(), 100, 100 * ONE);
+		let bought = bought_back();
+		assert_eq!(bought.len(), 1);
+		assert_eq!(bought[0].0, 200);
+	});
+}
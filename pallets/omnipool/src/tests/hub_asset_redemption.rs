@@ -0,0 +1,188 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+fn redemption_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP1, LRNA, 200 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+}
+
+#[test]
+fn redeem_hub_asset_should_work_at_spot_price() {
+	redemption_ext().build().execute_with(|| {
+		// Act
+		assert_ok!(Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0));
+
+		// Assert
+		assert_balance!(LP1, LRNA, 135 * ONE);
+		assert_balance!(LP1, 100, 100 * ONE);
+		assert_hub_asset!();
+		assert_asset_state!(
+			100,
+			AssetReserveState {
+				reserve: 1900 * ONE,
+				hub_reserve: 1365 * ONE,
+				shares: 2000 * ONE,
+				protocol_shares: Balance::zero(),
+				cap: DEFAULT_WEIGHT_CAP,
+				tradable: Tradability::default(),
+			}
+		);
+		expect_events(vec![Event::HubAssetRedeemed {
+			who: LP1,
+			asset_out: 100,
+			amount: 65 * ONE,
+			amount_out: 100 * ONE,
+			redemption_fee: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn redeem_hub_asset_should_not_change_imbalance() {
+	redemption_ext().build().execute_with(|| {
+		let imbalance = Omnipool::current_imbalance();
+
+		assert_ok!(Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0));
+
+		assert_eq!(Omnipool::current_imbalance(), imbalance);
+	});
+}
+
+#[test]
+fn redeem_hub_asset_should_move_redemption_fee_to_fee_account() {
+	redemption_ext()
+		.with_hub_asset_redemption_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0));
+
+			// Assert
+			assert_balance!(LP1, 100, 99 * ONE);
+			assert_hub_asset!();
+			assert_asset_state!(
+				100,
+				AssetReserveState {
+					reserve: 1900 * ONE,
+					hub_reserve: 1365 * ONE,
+					shares: 2000 * ONE,
+					protocol_shares: Balance::zero(),
+					cap: DEFAULT_WEIGHT_CAP,
+					tradable: Tradability::default(),
+				}
+			);
+			assert_balance!(Omnipool::fee_account(), 100, ONE);
+			assert_eq!(Omnipool::asset_fee_growth(100), FixedU128::from_rational(1, 2000));
+			expect_events(vec![Event::HubAssetRedeemed {
+				who: LP1,
+				asset_out: 100,
+				amount: 65 * ONE,
+				amount_out: 99 * ONE,
+				redemption_fee: ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn redeem_hub_asset_should_fail_when_min_out_is_not_reached() {
+	redemption_ext()
+		.with_hub_asset_redemption_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 100 * ONE),
+				Error::<Test>::BuyLimitNotReached
+			);
+		});
+}
+
+#[test]
+fn redeem_hub_asset_should_fail_when_asset_cannot_be_bought() {
+	redemption_ext().build().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			100,
+			Tradability::SELL
+		));
+
+		assert_noop!(
+			Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn redeem_hub_asset_should_fail_when_asset_out_is_hub_asset() {
+	redemption_ext().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, LRNA, 0),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn redeem_hub_asset_should_fail_when_balance_is_insufficient() {
+	redemption_ext().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 201 * ONE, 100, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn redeem_hub_asset_should_fail_when_max_out_ratio_is_exceeded() {
+	redemption_ext().with_max_out_ratio(20).build().execute_with(|| {
+		assert_noop!(
+			Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE + 1, 100, 0),
+			Error::<Test>::MaxOutRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn redeem_hub_asset_should_fail_when_max_amount_per_block_is_exceeded() {
+	redemption_ext()
+		.with_max_hub_asset_redemption_per_block(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0),
+				Error::<Test>::MaxHubAssetRedemptionPerBlockExceeded
+			);
+		});
+}
+
+#[test]
+fn redeem_hub_asset_should_reset_limit_in_next_block() {
+	redemption_ext()
+		.with_max_hub_asset_redemption_per_block(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0));
+
+			// Act
+			System::set_block_number(System::block_number() + 1);
+
+			// Assert
+			assert_ok!(Omnipool::redeem_hub_asset(RuntimeOrigin::signed(LP1), 65 * ONE, 100, 0));
+		});
+}
@@ -24,6 +24,7 @@ use std::collections::HashMap;
 use crate as pallet_omnipool;
 
 use crate::traits::ExternalPriceProvider;
+use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::traits::{ConstU128, Everything};
 use frame_support::weights::Weight;
 use frame_support::{
@@ -77,6 +78,11 @@ thread_local! {
 	pub static WITHDRAWAL_FEE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = const { RefCell::new((0u32,0u32, false)) };
 	pub static ON_TRADE_WITHDRAWAL: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
+	pub static WITHDRAWAL_QUEUE_THRESHOLD: RefCell<Option<Permill>> = const { RefCell::new(None) };
+	pub static MAX_HUB_ASSET_BUY_PER_BLOCK: RefCell<Permill> = const { RefCell::new(Permill::from_percent(100)) };
+	pub static HUB_ASSET_REDEMPTION_FEE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
+	pub static MAX_HUB_ASSET_REDEMPTION_PER_BLOCK: RefCell<Permill> = const { RefCell::new(Permill::from_percent(100)) };
+	pub static PRICE_BAND_ORACLE_PRICES: RefCell<HashMap<AssetId, EmaPrice>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -178,6 +184,15 @@ parameter_types! {
 	pub MaxPriceDiff: Permill = MAX_PRICE_DIFF.with(|v| *v.borrow());
 	pub FourPercentDiff: Permill = Permill::from_percent(4);
 	pub MinWithdrawFee: Permill = WITHDRAWAL_FEE.with(|v| *v.borrow());
+	pub WithdrawalQueueThreshold: Option<Permill> = WITHDRAWAL_QUEUE_THRESHOLD.with(|v| *v.borrow());
+	pub const WithdrawalStreamBlocks: u32 = 4;
+	pub const MaxQueuedWithdrawals: u32 = 2;
+	pub const MaxQueuedWithdrawalsPerAccount: u32 = 1;
+	pub MaxHubAssetBuyPerBlock: Permill = MAX_HUB_ASSET_BUY_PER_BLOCK.with(|v| *v.borrow());
+	pub HubAssetRedemptionFee: Permill = HUB_ASSET_REDEMPTION_FEE.with(|v| *v.borrow());
+	pub MaxHubAssetRedemptionPerBlock: Permill = MAX_HUB_ASSET_REDEMPTION_PER_BLOCK.with(|v| *v.borrow());
+	pub const TradeCommitmentExpiry: u64 = 5;
+	pub const MaxTradeCommitmentsPerBlock: u32 = 2;
 }
 
 impl Config for Test {
@@ -199,13 +214,103 @@ impl Config for Test {
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
 	type OmnipoolHooks = MockHooks;
+	type OnTradeExecuted = MockTradeExecutedHandler;
 	type PriceBarrier = (
 		EnsurePriceWithin<AccountId, AssetId, MockOracle, FourPercentDiff, ()>,
 		EnsurePriceWithin<AccountId, AssetId, MockOracle, MaxPriceDiff, ()>,
 	);
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = MockPriceBandOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = WithdrawalQueueThreshold;
+	type WithdrawalStreamBlocks = WithdrawalStreamBlocks;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type MaxQueuedWithdrawalsPerAccount = MaxQueuedWithdrawalsPerAccount;
+	type MaxHubAssetBuyPerBlock = MaxHubAssetBuyPerBlock;
+	type HubAssetRedemptionFee = HubAssetRedemptionFee;
+	type MaxHubAssetRedemptionPerBlock = MaxHubAssetRedemptionPerBlock;
+	type Router = OmnipoolRouter;
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = TradeCommitmentExpiry;
+	type MaxTradeCommitmentsPerBlock = MaxTradeCommitmentsPerBlock;
+}
+
+/// Router executing each trade of the route in Omnipool.
+pub struct OmnipoolRouter;
+
+impl RouteProvider<AssetId> for OmnipoolRouter {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for OmnipoolRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin.clone())?;
+		let mut amount = amount_in;
+		for (idx, trade) in route.iter().enumerate() {
+			let initial_balance = Tokens::free_balance(trade.asset_out, &who);
+			let limit = if idx == route.len() - 1 { min_amount_out } else { 0 };
+			Omnipool::sell(origin.clone(), trade.asset_in, trade.asset_out, amount, limit)?;
+			amount = Tokens::free_balance(trade.asset_out, &who) - initial_balance;
+		}
+		Ok(())
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
 }
 
 pub struct ExtBuilder {
@@ -233,6 +338,9 @@ impl Default for ExtBuilder {
 		POSITIONS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		PRICE_BAND_ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		ASSET_WEIGHT_CAP.with(|v| {
 			*v.borrow_mut() = Permill::from_percent(100);
 		});
@@ -266,6 +374,18 @@ impl Default for ExtBuilder {
 		WITHDRAWAL_ADJUSTMENT.with(|v| {
 			*v.borrow_mut() = (0, 0, false);
 		});
+		WITHDRAWAL_QUEUE_THRESHOLD.with(|v| {
+			*v.borrow_mut() = None;
+		});
+		MAX_HUB_ASSET_BUY_PER_BLOCK.with(|v| {
+			*v.borrow_mut() = Permill::from_percent(100);
+		});
+		HUB_ASSET_REDEMPTION_FEE.with(|v| {
+			*v.borrow_mut() = Permill::from_percent(0);
+		});
+		MAX_HUB_ASSET_REDEMPTION_PER_BLOCK.with(|v| {
+			*v.borrow_mut() = Permill::from_percent(100);
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -349,6 +469,12 @@ impl ExtBuilder {
 		});
 		self
 	}
+	pub fn with_price_band_oracle_price(self, asset_id: AssetId, price: EmaPrice) -> Self {
+		PRICE_BAND_ORACLE_PRICES.with(|v| {
+			v.borrow_mut().insert(asset_id, price);
+		});
+		self
+	}
 	pub fn with_min_withdrawal_fee(self, fee: Permill) -> Self {
 		WITHDRAWAL_FEE.with(|v| *v.borrow_mut() = fee);
 		self
@@ -359,6 +485,26 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_withdrawal_queue_threshold(self, threshold: Permill) -> Self {
+		WITHDRAWAL_QUEUE_THRESHOLD.with(|v| *v.borrow_mut() = Some(threshold));
+		self
+	}
+
+	pub fn with_max_hub_asset_buy_per_block(self, limit: Permill) -> Self {
+		MAX_HUB_ASSET_BUY_PER_BLOCK.with(|v| *v.borrow_mut() = limit);
+		self
+	}
+
+	pub fn with_hub_asset_redemption_fee(self, fee: Permill) -> Self {
+		HUB_ASSET_REDEMPTION_FEE.with(|v| *v.borrow_mut() = fee);
+		self
+	}
+
+	pub fn with_max_hub_asset_redemption_per_block(self, limit: Permill) -> Self {
+		MAX_HUB_ASSET_REDEMPTION_PER_BLOCK.with(|v| *v.borrow_mut() = limit);
+		self
+	}
+
 	pub fn with_on_trade_withdrawal(self, p: Permill) -> Self {
 		ON_TRADE_WITHDRAWAL.with(|v| *v.borrow_mut() = p);
 		self
@@ -630,6 +776,23 @@ impl ExternalPriceProvider<AssetId, EmaPrice> for WithdrawFeePriceOracle {
 	}
 }
 
+pub struct MockPriceBandOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for MockPriceBandOracle {
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		PRICE_BAND_ORACLE_PRICES
+			.with(|v| v.borrow().get(&asset_b).copied())
+			.ok_or_else(|| Error::<Test>::InvalidOraclePrice.into())
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 // Helper methods to work with Ema Price
 pub(super) fn round_to_rational((n, d): (U256, U256), rounding: Rounding) -> EmaPrice {
 	let shift = n.bits().max(d.bits()).saturating_sub(128);
@@ -684,6 +847,10 @@ pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
 	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
 }
 
+pub(crate) fn correlation_id() -> CorrelationId {
+	CorrelationId::current::<Test>()
+}
+
 pub struct MockHooks;
 
 impl OmnipoolHooks<RuntimeOrigin, AccountId, AssetId, Balance> for MockHooks {
@@ -715,16 +882,19 @@ impl OmnipoolHooks<RuntimeOrigin, AccountId, AssetId, Balance> for MockHooks {
 	fn on_trade_weight() -> Weight {
 		Weight::zero()
 	}
+}
 
-	fn on_trade_fee(
-		fee_account: AccountId,
-		_trader: AccountId,
-		asset: AssetId,
-		amount: Balance,
-	) -> Result<Balance, Self::Error> {
+pub struct MockTradeExecutedHandler;
+
+impl OnTradeExecuted<AccountId, AssetId, Balance> for MockTradeExecutedHandler {
+	fn on_trade_executed(trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError> {
 		let percentage = ON_TRADE_WITHDRAWAL.with(|v| *v.borrow());
-		let to_take = percentage.mul_floor(amount);
-		Tokens::withdraw(asset, &fee_account, to_take)?;
+		let to_take = percentage.mul_floor(trade.fee_amount);
+		Tokens::withdraw(trade.fee_asset, &trade.fee_account, to_take)?;
 		Ok(to_take)
 	}
+
+	fn on_trade_executed_weight(_pool: PoolType<AssetId>) -> Weight {
+		Weight::zero()
+	}
 }
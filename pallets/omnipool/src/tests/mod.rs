@@ -4,24 +4,38 @@ use frame_support::assert_ok;
 use sp_runtime::FixedU128;
 
 mod add_liquidity;
+mod add_liquidity_from;
 mod add_token;
+mod add_token_with_funder;
 mod buy;
+mod commit_reveal;
+mod fee_growth;
+mod frozen_positions;
+mod hub_asset_buy;
+mod hub_asset_buyback;
+mod hub_asset_redemption;
 mod invariants;
 mod remove_liquidity;
 mod sell;
+mod sell_with_price_protection;
 
 mod add_liquidity_with_limit;
 mod barrier;
 mod imbalance;
 pub(crate) mod mock;
 mod positions;
+mod price_band;
+mod protocol_fee;
 mod refund;
 mod remove_liquidity_with_limit;
 mod remove_token;
+mod solvency;
 mod spot_price;
+mod sync_reserve;
 mod tradability;
 mod types;
 mod verification;
+mod withdrawal_queue;
 
 use crate::Balance;
 use mock::*;
@@ -122,3 +122,188 @@ fn sacrifice_position_should_emit_event_when_succesful() {
 			);
 		});
 }
+
+#[test]
+fn position_value_should_return_position_amount_when_price_did_not_change() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_eq!(Omnipool::position_value(position_id), Ok((400 * ONE, 0)));
+		});
+}
+
+#[test]
+fn position_value_should_include_hub_asset_when_price_increased() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP3, DAI, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, asset_id, 100 * ONE, 0));
+
+			// Act
+			let (amount, hub_amount) = Omnipool::position_value(position_id).unwrap();
+
+			// Assert
+			assert!(amount < 400 * ONE);
+			assert!(hub_amount > 0);
+		});
+}
+
+#[test]
+fn position_value_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				Omnipool::position_value(1_000),
+				Err(Error::<Test>::PositionNotFound.into())
+			);
+		});
+}
+
+#[test]
+fn sacrifice_value_should_return_position_shares_and_value() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_eq!(
+				Omnipool::sacrifice_value(position_id),
+				Ok(SacrificedLiquidity {
+					shares: 400 * ONE,
+					amount: 400 * ONE,
+					hub_amount: 0,
+				})
+			);
+		});
+}
+
+#[test]
+fn sacrifice_position_should_record_sacrificed_liquidity_of_asset() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let expected = Omnipool::sacrifice_value(position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id));
+
+			// Assert
+			assert_eq!(Omnipool::sacrificed_liquidity(asset_id), expected);
+			assert_eq!(Omnipool::sacrificed_liquidity(DAI), SacrificedLiquidity::default());
+		});
+}
+
+#[test]
+fn sacrifice_position_should_accumulate_sacrificed_liquidity_when_multiple_positions_are_sacrificed() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP3, DAI, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 200 * ONE));
+
+			let first = Omnipool::sacrifice_value(first_position_id).unwrap();
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP1),
+				first_position_id
+			));
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, asset_id, 100 * ONE, 0));
+			let second = Omnipool::sacrifice_value(second_position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP1),
+				second_position_id
+			));
+
+			// Assert
+			assert!(second.hub_amount > 0);
+			assert_eq!(
+				Omnipool::sacrificed_liquidity(asset_id),
+				SacrificedLiquidity {
+					shares: first.shares + second.shares,
+					amount: first.amount + second.amount,
+					hub_amount: first.hub_amount + second.hub_amount,
+				}
+			);
+		});
+}
+
+#[test]
+fn sacrifice_position_should_emit_position_sacrificed_event() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act
+			assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id));
+
+			// Assert
+			frame_system::Pallet::<Test>::assert_has_event(
+				crate::Event::PositionSacrificed {
+					position_id,
+					owner: LP1,
+					asset_id,
+					shares: 400 * ONE,
+					amount: 400 * ONE,
+					hub_amount: 0,
+				}
+				.into(),
+			);
+		});
+}
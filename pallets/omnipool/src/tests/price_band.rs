@@ -0,0 +1,202 @@
+use super::*;
+use frame_support::assert_noop;
+use hydra_dx_math::ema::EmaPrice;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_price_band_should_work_when_called_by_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Omnipool::set_price_band(
+			RuntimeOrigin::root(),
+			1_000,
+			Some(Permill::from_percent(10))
+		));
+
+		assert_eq!(Omnipool::price_band(1_000), Some(Permill::from_percent(10)));
+		expect_events(vec![Event::PriceBandSet {
+			asset_id: 1_000,
+			band: Some(Permill::from_percent(10)),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_price_band_should_remove_band_when_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Omnipool::set_price_band(
+			RuntimeOrigin::root(),
+			1_000,
+			Some(Permill::from_percent(10))
+		));
+
+		assert_ok!(Omnipool::set_price_band(RuntimeOrigin::root(), 1_000, None));
+
+		assert_eq!(Omnipool::price_band(1_000), None);
+		expect_events(vec![Event::PriceBandSet {
+			asset_id: 1_000,
+			band: None,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_price_band_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_price_band(RuntimeOrigin::signed(LP1), 1_000, Some(Permill::from_percent(10))),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_token_should_work_when_initial_price_is_within_band() {
+	ExtBuilder::default()
+		.with_registered_asset(1000)
+		.add_endowed_accounts((Omnipool::protocol_account(), 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_price_band_oracle_price(1_000, EmaPrice::new(60, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_price_band(
+				RuntimeOrigin::root(),
+				1_000,
+				Some(Permill::from_percent(10))
+			));
+
+			assert_ok!(Omnipool::add_token(
+				RuntimeOrigin::root(),
+				1_000,
+				FixedU128::from_float(0.65),
+				Permill::from_percent(100),
+				LP1
+			));
+
+			assert!(Assets::<Test>::contains_key(1_000));
+		});
+}
+
+#[test]
+fn add_token_should_fail_when_initial_price_is_outside_band() {
+	ExtBuilder::default()
+		.with_registered_asset(1000)
+		.add_endowed_accounts((Omnipool::protocol_account(), 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_price_band_oracle_price(1_000, EmaPrice::new(60, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_price_band(
+				RuntimeOrigin::root(),
+				1_000,
+				Some(Permill::from_percent(10))
+			));
+
+			assert_noop!(
+				Omnipool::add_token(
+					RuntimeOrigin::root(),
+					1_000,
+					FixedU128::from_float(0.8),
+					Permill::from_percent(100),
+					LP1
+				),
+				Error::<Test>::PriceOutsideOracleBand
+			);
+			assert_noop!(
+				Omnipool::add_token(
+					RuntimeOrigin::root(),
+					1_000,
+					FixedU128::from_float(0.5),
+					Permill::from_percent(100),
+					LP1
+				),
+				Error::<Test>::PriceOutsideOracleBand
+			);
+		});
+}
+
+#[test]
+fn add_token_should_fail_when_band_is_set_and_oracle_price_is_not_available() {
+	ExtBuilder::default()
+		.with_registered_asset(1000)
+		.add_endowed_accounts((Omnipool::protocol_account(), 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_price_band(
+				RuntimeOrigin::root(),
+				1_000,
+				Some(Permill::from_percent(10))
+			));
+
+			assert_noop!(
+				Omnipool::add_token(
+					RuntimeOrigin::root(),
+					1_000,
+					FixedU128::from_float(0.65),
+					Permill::from_percent(100),
+					LP1
+				),
+				Error::<Test>::InvalidOraclePrice
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_spot_price_is_outside_band() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_price_band_oracle_price(1_000, EmaPrice::new(50, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_price_band(
+				RuntimeOrigin::root(),
+				1_000,
+				Some(Permill::from_percent(10))
+			));
+
+			assert_noop!(
+				Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE),
+				Error::<Test>::PriceOutsideOracleBand
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_work_when_spot_price_is_within_band() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_price_band_oracle_price(1_000, EmaPrice::new(65, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_price_band(
+				RuntimeOrigin::root(),
+				1_000,
+				Some(Permill::from_percent(1))
+			));
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			assert_balance!(LP1, 1_000, 4600 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_should_not_check_band_when_asset_has_no_band() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+		});
+}
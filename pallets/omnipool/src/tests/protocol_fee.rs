@@ -0,0 +1,149 @@
+use super::*;
+use crate::types::{ProtocolFeeDestination, ProtocolFeeDestinations};
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+
+const TREASURY: AccountId = 1_000;
+
+fn split(destinations: Vec<(ProtocolFeeDestination<AccountId>, Permill)>) -> ProtocolFeeDestinations<AccountId> {
+	destinations.try_into().unwrap()
+}
+
+fn pool_with_protocol_fee() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_protocol_fee(Permill::from_percent(10))
+		.with_initial_pool(FixedU128::from(1), FixedU128::from(1))
+		.with_token(100, FixedU128::from(1), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from(1), LP3, 2000 * ONE)
+}
+
+#[test]
+fn set_protocol_fee_split_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let destinations = split(vec![
+			(ProtocolFeeDestination::Account(TREASURY), Permill::from_percent(50)),
+			(ProtocolFeeDestination::Burn, Permill::from_percent(50)),
+		]);
+
+		assert_ok!(Omnipool::set_protocol_fee_split(
+			RuntimeOrigin::root(),
+			destinations.clone()
+		));
+
+		assert_eq!(Omnipool::protocol_fee_split(), destinations);
+		expect_events(vec![Event::ProtocolFeeSplitUpdated { destinations }.into()]);
+	});
+}
+
+#[test]
+fn set_protocol_fee_split_should_fail_when_portions_exceed_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_protocol_fee_split(
+				RuntimeOrigin::root(),
+				split(vec![
+					(ProtocolFeeDestination::Account(TREASURY), Permill::from_percent(60)),
+					(ProtocolFeeDestination::Burn, Permill::from_percent(41)),
+				])
+			),
+			Error::<Test>::InvalidProtocolFeeSplit
+		);
+	});
+}
+
+#[test]
+fn set_protocol_fee_split_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_protocol_fee_split(
+				RuntimeOrigin::signed(LP1),
+				split(vec![(ProtocolFeeDestination::Burn, Permill::from_percent(10))])
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn trade_should_distribute_protocol_fee_between_destinations() {
+	pool_with_protocol_fee().build().execute_with(|| {
+		assert_ok!(Omnipool::set_protocol_fee_split(
+			RuntimeOrigin::root(),
+			split(vec![
+				(ProtocolFeeDestination::Account(TREASURY), Permill::from_percent(50)),
+				(ProtocolFeeDestination::Burn, Permill::from_percent(20)),
+			])
+		));
+		let hdx_hub_reserve = Omnipool::load_asset_state(HDX).unwrap().hub_reserve;
+
+		assert_ok!(Omnipool::buy(RuntimeOrigin::signed(LP1), 200, 100, 50 * ONE, 100 * ONE));
+
+		// protocol fee is 5698005698005
+		assert_balance!(TREASURY, LRNA, 2849002849002);
+		assert_eq!(
+			Omnipool::load_asset_state(HDX).unwrap().hub_reserve,
+			hdx_hub_reserve + 1709401709402
+		);
+		assert_hub_asset!();
+		expect_events(vec![
+			Event::ProtocolFeeDistributed {
+				destination: ProtocolFeeDestination::Account(TREASURY),
+				amount: 2849002849002,
+			}
+			.into(),
+			Event::ProtocolFeeDistributed {
+				destination: ProtocolFeeDestination::Burn,
+				amount: 1139601139601,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn trade_should_add_protocol_fee_to_hdx_subpool_when_split_is_not_set() {
+	pool_with_protocol_fee().build().execute_with(|| {
+		let hdx_hub_reserve = Omnipool::load_asset_state(HDX).unwrap().hub_reserve;
+
+		assert_ok!(Omnipool::buy(RuntimeOrigin::signed(LP1), 200, 100, 50 * ONE, 100 * ONE));
+
+		assert_eq!(
+			Omnipool::load_asset_state(HDX).unwrap().hub_reserve,
+			hdx_hub_reserve + 5698005698005
+		);
+		assert_hub_asset!();
+	});
+}
+
+#[test]
+fn trade_should_keep_portion_in_hdx_subpool_when_transfer_to_destination_fails() {
+	pool_with_protocol_fee().build().execute_with(|| {
+		// portion of the fee is below existential deposit of hub asset
+		assert_ok!(Omnipool::set_protocol_fee_split(
+			RuntimeOrigin::root(),
+			split(vec![(
+				ProtocolFeeDestination::Account(TREASURY),
+				Permill::from_parts(1)
+			)])
+		));
+		let hdx_hub_reserve = Omnipool::load_asset_state(HDX).unwrap().hub_reserve;
+
+		assert_ok!(Omnipool::buy(RuntimeOrigin::signed(LP1), 200, 100, 50 * ONE, 100 * ONE));
+
+		assert_balance!(TREASURY, LRNA, 0);
+		assert_eq!(
+			Omnipool::load_asset_state(HDX).unwrap().hub_reserve,
+			hdx_hub_reserve + 5698005698005
+		);
+		assert_hub_asset!();
+	});
+}
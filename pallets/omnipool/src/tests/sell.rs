@@ -414,7 +414,11 @@ fn simple_sell_with_fee_works() {
 			assert_eq!(Tokens::free_balance(200, &LP1), expected_10_percent_fee);
 			assert_eq!(
 				Tokens::free_balance(200, &Omnipool::protocol_account()),
-				2000000000000000 - expected_10_percent_fee,
+				2000000000000000 - expected_zero_fee + 1,
+			);
+			assert_eq!(
+				Tokens::free_balance(200, &Omnipool::fee_account()),
+				expected_zero_fee - expected_10_percent_fee - 1,
 			);
 		});
 }
@@ -520,8 +524,9 @@ fn sell_should_work_when_trading_native_asset() {
 			);
 			assert_eq!(
 				Tokens::free_balance(200, &Omnipool::protocol_account()),
-				1946528035647977
+				1940586706275530
 			);
+			assert_eq!(Tokens::free_balance(200, &Omnipool::fee_account()), 5941329372447);
 
 			let hub_reserves: Vec<Balance> = Assets::<Test>::iter().map(|v| v.1.hub_reserve).collect();
 
@@ -537,7 +542,7 @@ fn sell_should_work_when_trading_native_asset() {
 			assert_asset_state!(
 				200,
 				AssetReserveState {
-					reserve: 1946528035647977,
+					reserve: 1940586706275530,
 					hub_reserve: 1339800995024876,
 					shares: 2000 * ONE,
 					protocol_shares: Balance::zero(),
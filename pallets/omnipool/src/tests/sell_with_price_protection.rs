@@ -0,0 +1,144 @@
+use super::*;
+use frame_support::assert_noop;
+use sp_runtime::Permill;
+
+fn protected_sell_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+			(LP1, LRNA, 100 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+}
+
+#[test]
+fn sell_with_price_protection_should_work_when_price_is_within_allowed_deviation() {
+	protected_sell_ext().build().execute_with(|| {
+		assert_ok!(Omnipool::sell_with_price_protection(
+			RuntimeOrigin::signed(LP1),
+			100,
+			200,
+			50 * ONE,
+			0,
+			Permill::from_percent(5),
+		));
+
+		assert_eq!(Tokens::free_balance(100, &LP1), 950 * ONE);
+		let amount_out = Tokens::free_balance(200, &LP1);
+		assert!(amount_out >= 47_500_000_000_000 && amount_out < 50 * ONE);
+	});
+}
+
+#[test]
+fn sell_with_price_protection_should_fail_when_price_deviation_is_too_high() {
+	protected_sell_ext().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::sell_with_price_protection(
+				RuntimeOrigin::signed(LP1),
+				100,
+				200,
+				50 * ONE,
+				0,
+				Permill::from_percent(1),
+			),
+			Error::<Test>::TradePriceDeviationTooHigh
+		);
+	});
+}
+
+#[test]
+fn sell_with_price_protection_should_fail_when_min_buy_amount_is_not_reached() {
+	protected_sell_ext().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::sell_with_price_protection(
+				RuntimeOrigin::signed(LP1),
+				100,
+				200,
+				50 * ONE,
+				50 * ONE,
+				Permill::from_percent(5),
+			),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn sell_with_price_protection_should_work_when_hub_asset_is_sold() {
+	protected_sell_ext().build().execute_with(|| {
+		assert_ok!(Omnipool::sell_with_price_protection(
+			RuntimeOrigin::signed(LP1),
+			LRNA,
+			200,
+			10 * ONE,
+			0,
+			Permill::from_percent(5),
+		));
+
+		assert_eq!(Tokens::free_balance(LRNA, &LP1), 90 * ONE);
+		assert!(Tokens::free_balance(200, &LP1) > 0);
+	});
+}
+
+#[test]
+fn sell_with_price_protection_should_fail_when_spot_price_deviates_from_oracle_price() {
+	protected_sell_ext()
+		.with_withdrawal_adjustment((1, 10, true))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::sell_with_price_protection(
+					RuntimeOrigin::signed(LP1),
+					LRNA,
+					200,
+					10 * ONE,
+					0,
+					Permill::from_percent(5),
+				),
+				Error::<Test>::TradePriceDeviationTooHigh
+			);
+		});
+}
+
+#[test]
+fn sell_with_price_protection_should_fail_when_assets_are_same() {
+	protected_sell_ext().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::sell_with_price_protection(
+				RuntimeOrigin::signed(LP1),
+				100,
+				100,
+				50 * ONE,
+				0,
+				Permill::from_percent(5),
+			),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn min_amount_out_at_oracle_price_should_value_amount_at_oracle_price() {
+	protected_sell_ext().build().execute_with(|| {
+		assert_eq!(
+			Omnipool::min_amount_out_at_oracle_price(100, 200, 50 * ONE, Permill::zero()),
+			Ok(50 * ONE)
+		);
+		assert_eq!(
+			Omnipool::min_amount_out_at_oracle_price(100, 200, 50 * ONE, Permill::from_percent(10)),
+			Ok(45 * ONE)
+		);
+		assert_eq!(
+			Omnipool::min_amount_out_at_oracle_price(LRNA, 100, 13 * ONE, Permill::zero()),
+			Ok(20 * ONE)
+		);
+	});
+}
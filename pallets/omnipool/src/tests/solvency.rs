@@ -0,0 +1,97 @@
+use super::*;
+use orml_traits::MultiCurrency;
+
+const TOKEN: AssetId = 1_000;
+
+fn pool_with_token() -> ExtBuilder {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, TOKEN, 5000 * ONE))
+		.add_endowed_accounts((LP2, TOKEN, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(TOKEN, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+}
+
+fn token_solvency(report: &SolvencyReport<AssetId, Balance>) -> AssetSolvency<Balance> {
+	report
+		.assets
+		.iter()
+		.find(|(asset_id, _)| *asset_id == TOKEN)
+		.map(|(_, asset)| asset.clone())
+		.unwrap()
+}
+
+#[test]
+fn solvency_report_should_be_solvent_when_liquidity_is_added() {
+	pool_with_token().build().execute_with(|| {
+		// ACT
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 400 * ONE));
+
+		// ASSERT
+		let report = Omnipool::solvency_report();
+		assert!(report.is_solvent());
+		assert_eq!(report.assets.len(), 3);
+		assert_eq!(report.hub_reserve, report.hub_balance);
+		assert_eq!(
+			token_solvency(&report),
+			AssetSolvency {
+				reserve: 2400 * ONE,
+				balance: 2400 * ONE,
+				shares: 2400 * ONE,
+				protocol_shares: 0,
+				position_shares: 2400 * ONE,
+			}
+		);
+	});
+}
+
+#[test]
+fn solvency_report_should_be_solvent_when_balance_exceeds_reserve() {
+	pool_with_token().build().execute_with(|| {
+		// ACT
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(LP1),
+			Omnipool::protocol_account(),
+			TOKEN,
+			1000 * ONE
+		));
+
+		// ASSERT
+		let report = Omnipool::solvency_report();
+		assert!(report.is_solvent());
+		let token = token_solvency(&report);
+		assert_eq!(token.reserve, 2000 * ONE);
+		assert_eq!(token.balance, 3000 * ONE);
+	});
+}
+
+#[test]
+fn solvency_report_should_not_be_solvent_when_balance_is_below_reserve() {
+	pool_with_token().build().execute_with(|| {
+		// ACT
+		assert_ok!(Tokens::withdraw(TOKEN, &Omnipool::protocol_account(), ONE));
+
+		// ASSERT
+		let report = Omnipool::solvency_report();
+		assert!(!report.is_solvent());
+		assert!(!token_solvency(&report).is_solvent());
+		assert!(report
+			.assets
+			.iter()
+			.filter(|(asset_id, _)| *asset_id != TOKEN)
+			.all(|(_, asset)| asset.is_solvent()));
+	});
+}
+
+#[test]
+fn solvency_report_should_not_be_solvent_when_hub_balance_is_below_hub_reserve() {
+	pool_with_token().build().execute_with(|| {
+		// ACT
+		assert_ok!(Tokens::withdraw(LRNA, &Omnipool::protocol_account(), ONE));
+
+		// ASSERT
+		let report = Omnipool::solvency_report();
+		assert!(!report.is_solvent());
+		assert!(report.hub_balance < report.hub_reserve);
+		assert!(report.assets.iter().all(|(_, asset)| asset.is_solvent()));
+	});
+}
@@ -0,0 +1,107 @@
+use super::*;
+use frame_support::assert_noop;
+
+const TOKEN: AssetId = 1_000;
+
+fn pool_with_token() -> ExtBuilder {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, TOKEN, 5000 * ONE))
+		.add_endowed_accounts((LP2, TOKEN, 5000 * ONE))
+		.add_endowed_accounts((LP3, HDX, 1000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(TOKEN, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+}
+
+#[test]
+fn donation_should_not_change_asset_state() {
+	pool_with_token().build().execute_with(|| {
+		let state_before = Omnipool::load_asset_state(TOKEN).unwrap();
+
+		// ACT
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(LP1),
+			Omnipool::protocol_account(),
+			TOKEN,
+			1000 * ONE
+		));
+
+		// ASSERT
+		assert_eq!(Omnipool::load_asset_state(TOKEN).unwrap(), state_before);
+		assert_balance!(Omnipool::protocol_account(), TOKEN, 3000 * ONE);
+	});
+}
+
+#[test]
+fn sell_should_not_be_affected_by_donation() {
+	let sell = |donate: bool| {
+		pool_with_token().build().execute_with(|| {
+			if donate {
+				assert_ok!(Tokens::transfer(
+					RuntimeOrigin::signed(LP1),
+					Omnipool::protocol_account(),
+					TOKEN,
+					1000 * ONE
+				));
+			}
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), HDX, TOKEN, 50 * ONE, 0));
+			Tokens::free_balance(TOKEN, &LP3)
+		})
+	};
+
+	assert_eq!(sell(true), sell(false));
+}
+
+#[test]
+fn sync_reserve_should_add_donation_to_reserve_as_protocol_shares() {
+	pool_with_token().build().execute_with(|| {
+		let donation = 1000 * ONE;
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(LP1),
+			Omnipool::protocol_account(),
+			TOKEN,
+			donation
+		));
+
+		// ACT
+		assert_ok!(Omnipool::sync_reserve(RuntimeOrigin::signed(LP3), TOKEN));
+
+		// ASSERT
+		assert_asset_state!(
+			TOKEN,
+			AssetReserveState {
+				reserve: 3000 * ONE,
+				hub_reserve: 1950 * ONE,
+				shares: 3000 * ONE,
+				protocol_shares: donation,
+				cap: DEFAULT_WEIGHT_CAP,
+				tradable: Tradability::default(),
+			}
+		);
+		expect_events(vec![Event::ReserveSynced {
+			asset_id: TOKEN,
+			amount: donation,
+			shares: donation,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sync_reserve_should_fail_when_there_is_no_donation() {
+	pool_with_token().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::sync_reserve(RuntimeOrigin::signed(LP3), TOKEN),
+			Error::<Test>::NothingToSync
+		);
+	});
+}
+
+#[test]
+fn sync_reserve_should_fail_when_asset_is_not_in_pool() {
+	pool_with_token().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::sync_reserve(RuntimeOrigin::signed(LP3), 2_000),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
@@ -0,0 +1,307 @@
+use super::*;
+use crate::types::{QueuedWithdrawal, Tradability};
+use frame_support::assert_noop;
+use frame_support::traits::Hooks;
+
+const TOKEN: AssetId = 1_000;
+
+fn pool_with_lp_position() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, TOKEN, 2400 * ONE),
+			(LP1, TOKEN, 5000 * ONE),
+			(LP3, TOKEN, 400 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(TOKEN, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_withdrawal_queue_threshold(Permill::from_percent(10))
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 400 * ONE));
+	});
+	ext
+}
+
+fn run_queue(blocks: u64) {
+	for _ in 0..blocks {
+		let n = System::block_number() + 1;
+		System::set_block_number(n);
+		Omnipool::on_initialize(n);
+	}
+}
+
+#[test]
+fn remove_liquidity_should_queue_withdrawal_when_amount_exceeds_threshold() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		assert_balance!(LP1, TOKEN, 4600 * ONE);
+		assert_eq!(Positions::<Test>::get(position_id).unwrap().shares, 400 * ONE);
+		assert_eq!(
+			WithdrawalQueue::<Test>::get(position_id).unwrap(),
+			QueuedWithdrawal {
+				owner: LP1,
+				asset_id: TOKEN,
+				remaining_shares: 400 * ONE,
+				shares_per_block: 100 * ONE,
+				remaining_limit: 0,
+			}
+		);
+		expect_events(vec![Event::WithdrawalQueued {
+			who: LP1,
+			position_id,
+			asset_id: TOKEN,
+			shares: 400 * ONE,
+			shares_per_block: 100 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_queue_withdrawal_when_amount_is_below_threshold() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			200 * ONE
+		));
+
+		assert_balance!(LP1, TOKEN, 4800 * ONE);
+		assert!(WithdrawalQueue::<Test>::get(position_id).is_none());
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_queue_withdrawal_when_withdrawal_is_safe() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			TOKEN,
+			Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY
+		));
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		assert!(WithdrawalQueue::<Test>::get(position_id).is_none());
+		assert!(Positions::<Test>::get(position_id).is_none());
+	});
+}
+
+#[test]
+fn queued_withdrawal_should_be_streamed_out_over_blocks() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		run_queue(1);
+
+		assert_balance!(LP1, TOKEN, 4700 * ONE);
+		assert_eq!(Positions::<Test>::get(position_id).unwrap().shares, 300 * ONE);
+		assert_eq!(
+			WithdrawalQueue::<Test>::get(position_id).unwrap().remaining_shares,
+			300 * ONE
+		);
+
+		run_queue(3);
+
+		assert_balance!(LP1, TOKEN, 5000 * ONE);
+		assert!(Positions::<Test>::get(position_id).is_none());
+		assert!(WithdrawalQueue::<Test>::get(position_id).is_none());
+		assert_eq!(WithdrawalQueue::<Test>::count(), 0);
+	});
+}
+
+#[test]
+fn queued_withdrawal_should_be_cancelled_when_removal_fails() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity_with_limit(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE,
+			500 * ONE,
+		));
+
+		run_queue(1);
+
+		assert_balance!(LP1, TOKEN, 4600 * ONE);
+		assert_eq!(Positions::<Test>::get(position_id).unwrap().shares, 400 * ONE);
+		assert!(WithdrawalQueue::<Test>::get(position_id).is_none());
+		assert_eq!(QueuedWithdrawalsPerAsset::<Test>::get(TOKEN), 0);
+		assert_eq!(QueuedWithdrawalsPerAccount::<Test>::get(LP1), 0);
+		expect_events(vec![
+			Event::QueuedWithdrawalFailed {
+				position_id,
+				error: Error::<Test>::SlippageLimit.into(),
+			}
+			.into(),
+			Event::WithdrawalCancelled {
+				who: LP1,
+				position_id,
+				remaining_shares: 400 * ONE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_withdrawal_is_already_queued() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		assert_noop!(
+			Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), position_id, 10 * ONE),
+			Error::<Test>::WithdrawalAlreadyQueued
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_withdrawal_queue_of_asset_is_full() {
+	pool_with_lp_position().execute_with(|| {
+		let first = <NextPositionId<Test>>::get() - 1;
+		let second = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP3), TOKEN, 400 * ONE));
+		let third = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), TOKEN, 400 * ONE));
+
+		assert_ok!(Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), first, 400 * ONE));
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP3),
+			second,
+			400 * ONE
+		));
+
+		assert_noop!(
+			Omnipool::remove_liquidity(RuntimeOrigin::signed(LP2), third, 400 * ONE),
+			Error::<Test>::WithdrawalQueueFull
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_account_has_too_many_queued_withdrawals() {
+	pool_with_lp_position().execute_with(|| {
+		let first = <NextPositionId<Test>>::get() - 1;
+		let second = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 400 * ONE));
+
+		assert_ok!(Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), first, 400 * ONE));
+
+		assert_noop!(
+			Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), second, 400 * ONE),
+			Error::<Test>::TooManyQueuedWithdrawals
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_queue_withdrawal_when_previous_withdrawal_of_account_completed() {
+	pool_with_lp_position().execute_with(|| {
+		let first = <NextPositionId<Test>>::get() - 1;
+		let second = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), TOKEN, 400 * ONE));
+		assert_ok!(Omnipool::remove_liquidity(RuntimeOrigin::signed(LP1), first, 400 * ONE));
+
+		run_queue(4);
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			second,
+			400 * ONE
+		));
+		assert!(WithdrawalQueue::<Test>::get(second).is_some());
+		assert_eq!(QueuedWithdrawalsPerAsset::<Test>::get(TOKEN), 1);
+		assert_eq!(QueuedWithdrawalsPerAccount::<Test>::get(LP1), 1);
+	});
+}
+
+#[test]
+fn cancel_withdrawal_should_keep_remaining_shares_in_position() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		run_queue(1);
+
+		assert_ok!(Omnipool::cancel_withdrawal(RuntimeOrigin::signed(LP1), position_id));
+
+		assert!(WithdrawalQueue::<Test>::get(position_id).is_none());
+		assert_eq!(Positions::<Test>::get(position_id).unwrap().shares, 300 * ONE);
+		expect_events(vec![Event::WithdrawalCancelled {
+			who: LP1,
+			position_id,
+			remaining_shares: 300 * ONE,
+		}
+		.into()]);
+
+		run_queue(1);
+
+		assert_balance!(LP1, TOKEN, 4700 * ONE);
+	});
+}
+
+#[test]
+fn cancel_withdrawal_should_fail_when_signed_by_non_owner() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			400 * ONE
+		));
+
+		assert_noop!(
+			Omnipool::cancel_withdrawal(RuntimeOrigin::signed(LP2), position_id),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn cancel_withdrawal_should_fail_when_withdrawal_is_not_queued() {
+	pool_with_lp_position().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get() - 1;
+
+		assert_noop!(
+			Omnipool::cancel_withdrawal(RuntimeOrigin::signed(LP1), position_id),
+			Error::<Test>::WithdrawalNotQueued
+		);
+	});
+}
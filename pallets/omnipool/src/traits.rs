@@ -57,14 +57,6 @@ where
 
 	fn on_liquidity_changed_weight() -> Weight;
 	fn on_trade_weight() -> Weight;
-
-	/// Returns used amount
-	fn on_trade_fee(
-		fee_account: AccountId,
-		trader: AccountId,
-		asset: AssetId,
-		amount: Balance,
-	) -> Result<Balance, Self::Error>;
 }
 
 // Default implementation for no-op hooks.
@@ -97,15 +89,6 @@ where
 	fn on_trade_weight() -> Weight {
 		Weight::zero()
 	}
-
-	fn on_trade_fee(
-		_fee_account: AccountId,
-		_trader: AccountId,
-		_asset: AssetId,
-		_amount: Balance,
-	) -> Result<Balance, Self::Error> {
-		Ok(Balance::zero())
-	}
 }
 
 pub trait ExternalPriceProvider<AssetId, Price> {
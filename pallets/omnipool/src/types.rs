@@ -4,6 +4,7 @@ use super::*;
 use codec::MaxEncodedLen;
 use frame_support::pallet_prelude::*;
 use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, AssetStateChange, BalanceUpdate};
+use sp_runtime::traits::SaturatedConversion;
 use sp_runtime::{FixedPointNumber, FixedU128};
 use sp_std::ops::{Add, Sub};
 
@@ -44,6 +45,9 @@ impl Tradability {
 
 #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct AssetState<Balance> {
+	/// Quantity of asset in omnipool, as tracked by the pool.
+	/// Tokens transferred directly to the pool account are not included until synced by `sync_reserve`.
+	pub(super) reserve: Balance,
 	/// Quantity of Hub Asset matching this asset
 	pub(super) hub_reserve: Balance,
 	/// Quantity of LP shares for this asset
@@ -64,6 +68,7 @@ where
 {
 	fn from(s: AssetReserveState<Balance>) -> Self {
 		Self {
+			reserve: s.reserve,
 			hub_reserve: s.hub_reserve,
 			shares: s.shares,
 			protocol_shares: s.protocol_shares,
@@ -79,6 +84,7 @@ where
 {
 	fn from((state, cap, tradable): (MathReserveState<Balance>, Permill, Tradability)) -> Self {
 		Self {
+			reserve: state.reserve,
 			hub_reserve: state.hub_reserve,
 			shares: state.shares,
 			protocol_shares: state.protocol_shares,
@@ -137,6 +143,137 @@ where
 	}
 }
 
+/// Large liquidity removal which is streamed out of Omnipool over several blocks.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct QueuedWithdrawal<AccountId, AssetId, Balance> {
+	/// Owner of the position
+	pub owner: AccountId,
+	/// Asset of the position
+	pub asset_id: AssetId,
+	/// Quantity of shares which are still to be removed
+	pub remaining_shares: Balance,
+	/// Quantity of shares removed in each block
+	pub shares_per_block: Balance,
+	/// Min amount of asset which is still to be received by the owner
+	pub remaining_limit: Balance,
+}
+
+/// Linear vesting of initial shares of a position.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SharesVesting<BlockNumber> {
+	/// Quantity of shares locked at the start of the vesting
+	pub shares: Balance,
+	/// Block the vesting started in
+	pub start: BlockNumber,
+	/// Number of blocks over which the shares unlock
+	pub period: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> SharesVesting<BlockNumber> {
+	/// Quantity of shares which are still locked in block `now`.
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		let elapsed = now.saturating_sub(self.start);
+		if elapsed >= self.period {
+			return Balance::zero();
+		}
+		let remaining = self.period.saturating_sub(elapsed);
+		multiply_by_rational_with_rounding(
+			self.shares,
+			remaining.saturated_into(),
+			self.period.saturated_into(),
+			Rounding::Up,
+		)
+		.unwrap_or(self.shares)
+	}
+}
+
+/// Liquidity given up to the protocol by sacrificing positions.
+/// Amounts are valued at the spot price at the time of each sacrifice.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SacrificedLiquidity<Balance> {
+	/// Shares which became protocol owned
+	pub shares: Balance,
+	/// Amount of asset the shares were worth
+	pub amount: Balance,
+	/// Amount of hub asset the shares were worth
+	pub hub_amount: Balance,
+}
+
+/// Reserve and share issuance of an asset compared to the balance of the pool account and shares of positions.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AssetSolvency<Balance> {
+	/// Reserve tracked in asset state
+	pub reserve: Balance,
+	/// Balance of the asset in the pool account
+	pub balance: Balance,
+	/// Quantity of LP shares for the asset
+	pub shares: Balance,
+	/// Quantity of LP shares owned by protocol
+	pub protocol_shares: Balance,
+	/// Sum of shares of all positions of the asset
+	pub position_shares: Balance,
+}
+
+impl AssetSolvency<Balance> {
+	/// Asset is solvent if the pool account holds at least the tracked reserve
+	/// and all shares except protocol's shares are owned by positions.
+	pub fn is_solvent(&self) -> bool {
+		self.balance >= self.reserve && self.shares == self.protocol_shares.saturating_add(self.position_shares)
+	}
+}
+
+/// Solvency of the hub asset and of all assets in the pool.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SolvencyReport<AssetId, Balance> {
+	/// Sum of hub reserves of all assets
+	pub hub_reserve: Balance,
+	/// Balance of the hub asset in the pool account
+	pub hub_balance: Balance,
+	/// Solvency of each asset in the pool
+	pub assets: Vec<(AssetId, AssetSolvency<Balance>)>,
+}
+
+impl<AssetId> SolvencyReport<AssetId, Balance> {
+	/// Pool is solvent if the pool account holds at least the tracked hub reserve and all assets are solvent.
+	pub fn is_solvent(&self) -> bool {
+		self.hub_balance >= self.hub_reserve && self.assets.iter().all(|(_, asset)| asset.is_solvent())
+	}
+}
+
+/// Max number of destinations the protocol fee can be split between.
+pub const MAX_PROTOCOL_FEE_DESTINATIONS: u32 = 8;
+
+/// Destination of a portion of the protocol fee.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ProtocolFeeDestination<AccountId> {
+	/// Hub asset is transferred to the account.
+	Account(AccountId),
+	/// Hub asset is burned.
+	Burn,
+}
+
+/// Protocol fee destinations with their portions of the fee.
+pub type ProtocolFeeDestinations<AccountId> =
+	BoundedVec<(ProtocolFeeDestination<AccountId>, Permill), ConstU32<MAX_PROTOCOL_FEE_DESTINATIONS>>;
+
+/// Max number of assets sold for hub asset by the hub asset buyback.
+pub const MAX_BUYBACK_ASSETS: u32 = 8;
+
+/// Parameters of the periodic hub asset buyback.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct HubAssetBuyback<AccountId, AssetId, BlockNumber> {
+	/// Assets of the buyback account which are sold for hub asset.
+	pub assets: BoundedVec<AssetId, ConstU32<MAX_BUYBACK_ASSETS>>,
+	/// Portion of the buyback account balance of each asset sold in one buyback.
+	pub portion: Permill,
+	/// Max amount of hub asset bought in one buyback.
+	pub max_hub_amount: Balance,
+	/// Number of blocks between two buybacks.
+	pub period: BlockNumber,
+	/// Destination of the bought hub asset.
+	pub destination: ProtocolFeeDestination<AccountId>,
+}
+
 /// Simple type to represent imbalance which can be positive or negative.
 // Note: Simple prefix is used not to confuse with Imbalance trait from frame_support.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -258,13 +395,13 @@ where
 	}
 }
 
-impl<Balance> From<(&AssetState<Balance>, Balance)> for AssetReserveState<Balance>
+impl<Balance> From<&AssetState<Balance>> for AssetReserveState<Balance>
 where
 	Balance: Copy,
 {
-	fn from((s, reserve): (&AssetState<Balance>, Balance)) -> Self {
+	fn from(s: &AssetState<Balance>) -> Self {
 		Self {
-			reserve,
+			reserve: s.reserve,
 			hub_reserve: s.hub_reserve,
 			shares: s.shares,
 			protocol_shares: s.protocol_shares,
@@ -274,13 +411,13 @@ where
 	}
 }
 
-impl<Balance> From<(AssetState<Balance>, Balance)> for AssetReserveState<Balance>
+impl<Balance> From<AssetState<Balance>> for AssetReserveState<Balance>
 where
 	Balance: Copy,
 {
-	fn from((s, reserve): (AssetState<Balance>, Balance)) -> Self {
+	fn from(s: AssetState<Balance>) -> Self {
 		Self {
-			reserve,
+			reserve: s.reserve,
 			hub_reserve: s.hub_reserve,
 			shares: s.shares,
 			protocol_shares: s.protocol_shares,
@@ -64,12 +64,22 @@ pub trait WeightInfo {
 	fn withdraw_protocol_liquidity() -> Weight;
 	fn remove_token() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn cancel_withdrawal() -> Weight;
+	fn sync_reserve() -> Weight;
+	fn set_protocol_fee_split() -> Weight;
+	fn redeem_hub_asset() -> Weight;
+	fn commit_trade() -> Weight;
+	fn approve_token_funding() -> Weight;
+	fn add_token_with_funder() -> Weight;
+	fn set_price_band() -> Weight;
+	fn set_hub_asset_buyback() -> Weight;
+	fn claim_fees() -> Weight;
 }
 
 /// Weights for pallet_omnipool using the hydraDX node and recommended hardware.
 impl WeightInfo for () {
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Class` (r:1 w:1)
@@ -94,19 +104,21 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Positions` (r:0 w:1)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:0)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn add_token() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3128`
 		//  Estimated: `7406`
 		// Minimum execution time: 129_737_000 picoseconds.
 		Weight::from_parts(131_001_000, 7406)
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Tokens::Accounts` (r:3 w:3)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -145,21 +157,25 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Positions` (r:0 w:1)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:0)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::PositionFeeGrowth` (r:0 w:1)
+	/// Proof: `Omnipool::PositionFeeGrowth` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4552`
 		//  Estimated: `8739`
 		// Minimum execution time: 218_146_000 picoseconds.
 		Weight::from_parts(219_509_000, 8739)
-			.saturating_add(RocksDbWeight::get().reads(24_u64))
-			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:1)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Positions` (r:1 w:1)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:4 w:4)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
@@ -198,19 +214,23 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:0)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::PositionFeeGrowth` (r:1 w:1)
+	/// Proof: `Omnipool::PositionFeeGrowth` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7463`
 		//  Estimated: `11322`
 		// Minimum execution time: 288_313_000 picoseconds.
 		Weight::from_parts(290_432_000, 11322)
-			.saturating_add(RocksDbWeight::get().reads(27_u64))
-			.saturating_add(RocksDbWeight::get().writes(16_u64))
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
 	}
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:3 w:3)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
 	/// Proof: `Omnipool::HubAssetImbalance` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
 	/// Storage: `DynamicFees::AssetFee` (r:2 w:0)
@@ -246,7 +266,7 @@ impl WeightInfo for () {
 	/// Storage: `Referrals::Referrer` (r:1 w:0)
 	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::AssetRewards` (r:1 w:0)
-	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::TotalShares` (r:1 w:1)
 	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::ReferrerShares` (r:1 w:1)
@@ -257,17 +277,21 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::CounterForPendingConversions` (r:1 w:1)
 	/// Proof: `Referrals::CounterForPendingConversions` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:1)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LpRewardPerShare` (r:1 w:1)
+	/// Proof: `Referrals::LpRewardPerShare` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7823`
 		//  Estimated: `13905`
 		// Minimum execution time: 333_717_000 picoseconds.
 		Weight::from_parts(335_901_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(42_u64))
-			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:3 w:3)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -305,7 +329,7 @@ impl WeightInfo for () {
 	/// Storage: `Referrals::Referrer` (r:1 w:0)
 	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::AssetRewards` (r:1 w:0)
-	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::TotalShares` (r:1 w:1)
 	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::ReferrerShares` (r:1 w:1)
@@ -316,17 +340,21 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::CounterForPendingConversions` (r:1 w:1)
 	/// Proof: `Referrals::CounterForPendingConversions` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:1)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LpRewardPerShare` (r:1 w:1)
+	/// Proof: `Referrals::LpRewardPerShare` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7823`
 		//  Estimated: `13905`
 		// Minimum execution time: 335_910_000 picoseconds.
 		Weight::from_parts(337_763_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(42_u64))
-			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	fn set_asset_tradable_state() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1305`
@@ -337,7 +365,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:2 w:2)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Router::SkipEd` (r:1 w:0)
@@ -368,24 +396,32 @@ impl WeightInfo for () {
 	/// Storage: `Uniques::Asset` (r:1 w:1)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Class` (r:1 w:1)
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Account` (r:0 w:1)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::PositionFeeGrowth` (r:0 w:1)
+	/// Proof: `Omnipool::PositionFeeGrowth` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:0)
+	/// Proof: `Omnipool::HubAssetImbalance` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::SacrificedLiquidityPerAsset` (r:1 w:1)
+	/// Proof: `Omnipool::SacrificedLiquidityPerAsset` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn sacrifice_position() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2910`
+		//  Measured:  `3125`
 		//  Estimated: `3655`
-		// Minimum execution time: 67_565_000 picoseconds.
-		Weight::from_parts(68_526_000, 3655)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		// Minimum execution time: 81_209_000 picoseconds.
+		Weight::from_parts(82_347_000, 3655)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	fn set_asset_weight_cap() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1305`
@@ -396,7 +432,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:3 w:3)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -429,7 +465,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:4 w:3)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -460,7 +496,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:3 w:3)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -496,7 +532,7 @@ impl WeightInfo for () {
 	/// Storage: `Referrals::LinkedAccounts` (r:1 w:0)
 	/// Proof: `Referrals::LinkedAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::AssetRewards` (r:1 w:0)
-	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::TotalShares` (r:1 w:1)
 	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::TraderShares` (r:1 w:1)
@@ -523,7 +559,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 7749).saturating_mul(e.into()))
 	}
 	/// Storage: `Omnipool::Assets` (r:3 w:3)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -559,7 +595,7 @@ impl WeightInfo for () {
 	/// Storage: `Referrals::LinkedAccounts` (r:1 w:0)
 	/// Proof: `Referrals::LinkedAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::AssetRewards` (r:1 w:0)
-	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::TotalShares` (r:1 w:1)
 	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::TraderShares` (r:1 w:1)
@@ -584,7 +620,7 @@ impl WeightInfo for () {
 	/// Storage: `DynamicFees::AssetFee` (r:2 w:0)
 	/// Proof: `DynamicFees::AssetFee` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:2 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:2 w:0)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	fn calculate_spot_price_with_fee() -> Weight {
@@ -595,4 +631,172 @@ impl WeightInfo for () {
 		Weight::from_parts(44_633_000, 6156)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 	}
+	/// Storage: `Omnipool::WithdrawalQueue` (r:1 w:1)
+	/// Proof: `Omnipool::WithdrawalQueue` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::CounterForWithdrawalQueue` (r:1 w:1)
+	/// Proof: `Omnipool::CounterForWithdrawalQueue` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn cancel_withdrawal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1188`
+		//  Estimated: `3565`
+		// Minimum execution time: 19_818_000 picoseconds.
+		Weight::from_parts(20_431_000, 3565)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Omnipool::Assets` (r:1 w:1)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
+	/// Proof: `Omnipool::HubAssetImbalance` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:3 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn sync_reserve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2063`
+		//  Estimated: `6190`
+		// Minimum execution time: 59_402_000 picoseconds.
+		Weight::from_parts(61_240_000, 6190)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Omnipool::ProtocolFeeSplit` (r:0 w:1)
+	/// Proof: `Omnipool::ProtocolFeeSplit` (`max_values`: Some(1), `max_size`: Some(297), added: 792, mode: `MaxEncodedLen`)
+	fn set_protocol_fee_split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_284_000 picoseconds.
+		Weight::from_parts(12_731_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Omnipool::HubAssetRedeemedInBlock` (r:1 w:1)
+	/// Proof: `Omnipool::HubAssetRedeemedInBlock` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:1 w:1)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:1)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn redeem_hub_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3247`
+		//  Estimated: `11322`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(120_119_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Omnipool::TradeCommitments` (r:1 w:1)
+	/// Proof: `Omnipool::TradeCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::TradeCommitmentExpiries` (r:1 w:1)
+	/// Proof: `Omnipool::TradeCommitmentExpiries` (`max_values`: None, `max_size`: Some(6414), added: 8889, mode: `MaxEncodedLen`)
+	fn commit_trade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6502`
+		//  Estimated: `9879`
+		// Minimum execution time: 21_562_000 picoseconds.
+		Weight::from_parts(22_113_000, 9879)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Omnipool::TokenFundingApprovals` (r:0 w:1)
+	/// Proof: `Omnipool::TokenFundingApprovals` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn approve_token_funding() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_127_000 picoseconds.
+		Weight::from_parts(14_602_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Omnipool::TokenFundingApprovals` (r:1 w:1)
+	/// Proof: `Omnipool::TokenFundingApprovals` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:1 w:1)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
+	/// Proof: `Omnipool::HubAssetImbalance` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::NextPositionId` (r:1 w:1)
+	/// Proof: `Omnipool::NextPositionId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::CollectionMaxSupply` (r:1 w:0)
+	/// Proof: `Uniques::CollectionMaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:0)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Positions` (r:0 w:1)
+	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::PositionVesting` (r:0 w:1)
+	/// Proof: `Omnipool::PositionVesting` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn add_token_with_funder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3276`
+		//  Estimated: `7406`
+		// Minimum execution time: 151_284_000 picoseconds.
+		Weight::from_parts(153_017_000, 7406)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Omnipool::PriceBands` (r:0 w:1)
+	/// Proof: `Omnipool::PriceBands` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_price_band() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_462_000 picoseconds.
+		Weight::from_parts(11_815_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Omnipool::HubAssetBuybackConfig` (r:0 w:1)
+	/// Proof: `Omnipool::HubAssetBuybackConfig` (`max_values`: Some(1), `max_size`: Some(93), added: 588, mode: `MaxEncodedLen`)
+	fn set_hub_asset_buyback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_104_000 picoseconds.
+		Weight::from_parts(12_531_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Omnipool::Positions` (r:1 w:0)
+	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::AssetFeeGrowth` (r:1 w:0)
+	/// Proof: `Omnipool::AssetFeeGrowth` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::PositionFeeGrowth` (r:1 w:1)
+	/// Proof: `Omnipool::PositionFeeGrowth` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2214`
+		//  Estimated: `6196`
+		// Minimum execution time: 78_412_000 picoseconds.
+		Weight::from_parts(79_330_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
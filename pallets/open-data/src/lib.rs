@@ -0,0 +1,202 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Open data pallet
+//!
+//! Per-era digests of the protocol state.
+//!
+//! ## Overview
+//!
+//! Once per era, anyone can take a snapshot of the protocol state. The snapshot consists of three components
+//! provided by `SnapshotProvider` - pool states, fee totals and oracle checkpoints. Each component is
+//! SCALE-encoded by the provider and hashed with blake2_256. Digest of the era is blake2_256 hash of
+//! SCALE-encoded tuple `(era, pool_states, fee_totals, oracle_checkpoints)`.
+//!
+//! Digests are stored on-chain and emitted in an event, so full off-chain reports can be pinned (e.g. to IPFS)
+//! and anyone can verify them against the digest of given era.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `snapshot` - Computes and stores the digest of the current era.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::ensure;
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::{CheckedDiv, SaturatedConversion};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+pub use weights::WeightInfo;
+
+pub type EraIndex = u32;
+
+/// Provides SCALE-encoded components of a snapshot.
+pub trait SnapshotProvider {
+	/// State of the pools.
+	fn pool_states() -> Vec<u8>;
+
+	/// Fee totals.
+	fn fee_totals() -> Vec<u8>;
+
+	/// Oracle checkpoints.
+	fn oracle_checkpoints() -> Vec<u8>;
+
+	/// Weight of collecting all the components.
+	fn weight() -> Weight;
+}
+
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct EraDigest<BlockNumber> {
+	/// Hash of pool states.
+	pub pool_states: H256,
+	/// Hash of fee totals.
+	pub fee_totals: H256,
+	/// Hash of oracle checkpoints.
+	pub oracle_checkpoints: H256,
+	/// Digest of the era.
+	pub digest: H256,
+	/// Block number at which the snapshot was taken.
+	pub taken_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Provider of the snapshot components.
+		type SnapshotProvider: SnapshotProvider;
+
+		/// Length of an era in blocks.
+		#[pallet::constant]
+		type EraLength: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Digests of eras.
+	#[pallet::storage]
+	#[pallet::getter(fn era_digest)]
+	pub(super) type Digests<T: Config> = StorageMap<_, Twox64Concat, EraIndex, EraDigest<BlockNumberFor<T>>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Snapshot of an era was taken.
+		SnapshotTaken {
+			era: EraIndex,
+			digest: H256,
+			pool_states: H256,
+			fee_totals: H256,
+			oracle_checkpoints: H256,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Snapshot of the current era has already been taken.
+		SnapshotAlreadyTaken,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Take a snapshot of the current era.
+		///
+		/// Computes digest of the current era from components provided by `SnapshotProvider` and stores it.
+		/// Only one snapshot can be taken per era.
+		///
+		/// Parameters:
+		/// - `origin`: Any signed origin.
+		///
+		/// Emits `SnapshotTaken` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::snapshot().saturating_add(T::SnapshotProvider::weight()))]
+		pub fn snapshot(origin: OriginFor<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let era = Self::current_era();
+			ensure!(!Digests::<T>::contains_key(era), Error::<T>::SnapshotAlreadyTaken);
+
+			let pool_states = H256::from(blake2_256(&T::SnapshotProvider::pool_states()));
+			let fee_totals = H256::from(blake2_256(&T::SnapshotProvider::fee_totals()));
+			let oracle_checkpoints = H256::from(blake2_256(&T::SnapshotProvider::oracle_checkpoints()));
+			let digest = Self::compute_digest(era, pool_states, fee_totals, oracle_checkpoints);
+
+			Digests::<T>::insert(
+				era,
+				EraDigest {
+					pool_states,
+					fee_totals,
+					oracle_checkpoints,
+					digest,
+					taken_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::SnapshotTaken {
+				era,
+				digest,
+				pool_states,
+				fee_totals,
+				oracle_checkpoints,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns index of the current era.
+	pub fn current_era() -> EraIndex {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::EraLength::get())
+			.unwrap_or_default()
+			.saturated_into()
+	}
+
+	/// Computes digest of an era from hashes of its components.
+	pub fn compute_digest(era: EraIndex, pool_states: H256, fee_totals: H256, oracle_checkpoints: H256) -> H256 {
+		H256::from(blake2_256(&(era, pool_states, fee_totals, oracle_checkpoints).encode()))
+	}
+}
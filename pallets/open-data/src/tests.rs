@@ -0,0 +1,157 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod snapshot;
+
+use crate as pallet_open_data;
+use crate::*;
+
+use std::cell::RefCell;
+
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::Everything,
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+thread_local! {
+	pub static POOL_STATES: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+	pub static FEE_TOTALS: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+	pub static ORACLE_CHECKPOINTS: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		OpenData: pallet_open_data,
+	}
+);
+
+parameter_types! {
+	pub const EraLength: u64 = 10;
+}
+
+pub struct MockSnapshotProvider;
+
+impl SnapshotProvider for MockSnapshotProvider {
+	fn pool_states() -> Vec<u8> {
+		POOL_STATES.with(|v| v.borrow().clone())
+	}
+
+	fn fee_totals() -> Vec<u8> {
+		FEE_TOTALS.with(|v| v.borrow().clone())
+	}
+
+	fn oracle_checkpoints() -> Vec<u8> {
+		ORACLE_CHECKPOINTS.with(|v| v.borrow().clone())
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SnapshotProvider = MockSnapshotProvider;
+	type EraLength = EraLength;
+	type WeightInfo = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+pub struct ExtBuilder {
+	pool_states: Vec<u8>,
+	fee_totals: Vec<u8>,
+	oracle_checkpoints: Vec<u8>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			pool_states: vec![1, 2, 3],
+			fee_totals: vec![4, 5, 6],
+			oracle_checkpoints: vec![7, 8, 9],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_pool_states(mut self, data: Vec<u8>) -> Self {
+		self.pool_states = data;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		POOL_STATES.with(|v| *v.borrow_mut() = self.pool_states);
+		FEE_TOTALS.with(|v| *v.borrow_mut() = self.fee_totals);
+		ORACLE_CHECKPOINTS.with(|v| *v.borrow_mut() = self.oracle_checkpoints);
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
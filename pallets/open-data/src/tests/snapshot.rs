@@ -0,0 +1,99 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn snapshot_should_store_digest_of_current_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(25);
+
+		// Act
+		assert_ok!(OpenData::snapshot(RuntimeOrigin::signed(ALICE)));
+
+		// Assert
+		let pool_states = H256::from(blake2_256(&[1, 2, 3]));
+		let fee_totals = H256::from(blake2_256(&[4, 5, 6]));
+		let oracle_checkpoints = H256::from(blake2_256(&[7, 8, 9]));
+		let digest = H256::from(blake2_256(
+			&(2u32, pool_states, fee_totals, oracle_checkpoints).encode(),
+		));
+
+		assert_eq!(
+			OpenData::era_digest(2),
+			Some(EraDigest {
+				pool_states,
+				fee_totals,
+				oracle_checkpoints,
+				digest,
+				taken_at: 25,
+			})
+		);
+		expect_events(vec![Event::SnapshotTaken {
+			era: 2,
+			digest,
+			pool_states,
+			fee_totals,
+			oracle_checkpoints,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn snapshot_should_fail_when_already_taken_in_current_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OpenData::snapshot(RuntimeOrigin::signed(ALICE)));
+
+		// Act & Assert
+		System::set_block_number(9);
+		assert_noop!(
+			OpenData::snapshot(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::SnapshotAlreadyTaken
+		);
+	});
+}
+
+#[test]
+fn snapshot_should_be_allowed_again_in_next_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OpenData::snapshot(RuntimeOrigin::signed(ALICE)));
+
+		// Act
+		System::set_block_number(10);
+		assert_ok!(OpenData::snapshot(RuntimeOrigin::signed(BOB)));
+
+		// Assert
+		assert!(OpenData::era_digest(0).is_some());
+		assert!(OpenData::era_digest(1).is_some());
+	});
+}
+
+#[test]
+fn snapshot_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(OpenData::snapshot(RuntimeOrigin::root()), BadOrigin);
+	});
+}
+
+#[test]
+fn digest_should_change_when_pool_states_change() {
+	let first = ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OpenData::snapshot(RuntimeOrigin::signed(ALICE)));
+		OpenData::era_digest(0).unwrap()
+	});
+
+	let second = ExtBuilder::default()
+		.with_pool_states(vec![1, 2, 4])
+		.build()
+		.execute_with(|| {
+			assert_ok!(OpenData::snapshot(RuntimeOrigin::signed(ALICE)));
+			OpenData::era_digest(0).unwrap()
+		});
+
+	assert_ne!(first.pool_states, second.pool_states);
+	assert_eq!(first.fee_totals, second.fee_totals);
+	assert_ne!(first.digest, second.digest);
+}
@@ -44,6 +44,13 @@ benchmarks! {
 		});
 
   }:  _(RawOrigin::None, 0u32, 2 * ONE, route)
+
+	set_pool_type_min_profit {
+		let min_profit = Some(Perbill::from_percent(1));
+	}: _(RawOrigin::Root, ArbitragePoolType::Stableswap, min_profit)
+	verify {
+		assert_eq!(PoolTypeMinProfit::<T>::get(ArbitragePoolType::Stableswap), min_profit);
+	}
 }
 
 #[cfg(test)]
@@ -17,7 +17,7 @@
 //!
 //! ## Description
 //! The pallet provides implementation of the offchain worker for closing existing arbitrage opportunities between OTC
-//! orders and the Omnipool.
+//! orders and AMM pools.
 //! Two main parts of this pallet are methods to find the correct amount in order to close an existing arbitrage opportunity
 //! and an extrinsic. The extrinsic is mainly called by the offchain worker as unsigned extrinsic, but can be also called
 //! by any user using signed origin. In the former case, the block producer doesn't pay the fee.
@@ -27,9 +27,16 @@
 //! aligns the OTC and the Omnipool prices. Executing this trade needs to be profitable, but we are not trying to maximize
 //! the profit. If the pallet couldn't find the amount that closes the arb, the amount that reduces the size of the arb is used.
 //! In the case of not partially fillable OTC orders, the pallet tries to maximize the profit.
+//! OTC orders priced by the oracle are not settled.
+//!
+//! The AMM leg of the arbitrage is executed by the router. Besides the route provided by the router, routes returned by
+//! `ArbitrageRoutes` (e.g. direct routes through stableswap or XYK pools) are tried, starting with the route with the
+//! lowest price. Minimum profit can be configured per pool type by `set_pool_type_min_profit`. The highest minimum profit
+//! of pool types in a route is required, `MinProfitPercentage` is used for pool types without configuration.
 //!
 //! ## Dispatachable functions
 //! * `settle_otc_order` -  Executes a trade between an OTC order and some route.
+//! * `set_pool_type_min_profit` -  Sets minimum profit of arbitrage closed against a pool type.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -49,7 +56,7 @@ use frame_system::{
 	pallet_prelude::{BlockNumberFor, OriginFor},
 };
 use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouteSpotPriceProvider, RouterT, Trade,
+	AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouteSpotPriceProvider, RouterT, Trade,
 };
 use pallet_otc::weights::WeightInfo as OtcWeightInfo;
 pub use pallet_otc::OrderId;
@@ -104,6 +111,45 @@ pub const FILL_SEARCH_ITERATIONS: u32 = 40;
 pub type AssetIdOf<T> = <T as pallet_otc::Config>::AssetId;
 type SortedOtcsStorageType = OrderId;
 
+/// Type of the pool the arbitrage is closed against.
+#[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum ArbitragePoolType {
+	Omnipool,
+	Stableswap,
+	XYK,
+	LBP,
+}
+
+impl<AssetId> From<&PoolType<AssetId>> for ArbitragePoolType {
+	fn from(pool: &PoolType<AssetId>) -> Self {
+		match pool {
+			PoolType::Omnipool => Self::Omnipool,
+			PoolType::Stableswap(_) => Self::Stableswap,
+			PoolType::XYK => Self::XYK,
+			PoolType::LBP => Self::LBP,
+		}
+	}
+}
+
+/// Provides routes which are tried in addition to the route provided by the router.
+pub trait ArbitrageRoutes<AssetId> {
+	/// Return routes from `asset_pair.asset_in` to `asset_pair.asset_out`.
+	fn routes(asset_pair: AssetPair<AssetId>) -> Vec<Vec<Trade<AssetId>>>;
+
+	/// Weight of `routes`.
+	fn routes_weight() -> Weight;
+}
+
+impl<AssetId> ArbitrageRoutes<AssetId> for () {
+	fn routes(_asset_pair: AssetPair<AssetId>) -> Vec<Vec<Trade<AssetId>>> {
+		vec![]
+	}
+
+	fn routes_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -144,6 +190,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxIterations: Get<u32>;
 
+		/// Additional routes used to close arbitrage opportunities, e.g. direct routes through stableswap or XYK pools.
+		type ArbitrageRoutes: ArbitrageRoutes<AssetIdOf<Self>>;
+
+		/// Origin able to set minimum profit per pool type.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Router weight information.
 		type RouterWeightInfo: AmmTradeWeights<Trade<AssetIdOf<Self>>>;
 
@@ -151,6 +203,11 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::storage]
+	#[pallet::getter(fn pool_type_min_profit)]
+	/// Minimum profit of arbitrage closed against a pool type. `MinProfitPercentage` is used if not set.
+	pub type PoolTypeMinProfit<T: Config> = StorageMap<_, Blake2_128Concat, ArbitragePoolType, Perbill, OptionQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn offchain_worker(block_number: BlockNumberFor<T>) {
@@ -197,6 +254,11 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// A trade has been executed
 		Executed { asset_id: AssetIdOf<T>, profit: Balance },
+		/// Minimum profit of a pool type has been set
+		PoolTypeMinProfitSet {
+			pool_type: ArbitragePoolType,
+			min_profit: Option<Perbill>,
+		},
 	}
 
 	#[pallet::error]
@@ -215,6 +277,8 @@ pub mod pallet {
 		TradeAmountTooLow,
 		/// Price for a route is not available
 		PriceNotAvailable,
+		/// OTC order is priced by the oracle and cannot be settled
+		OraclePricedOrder,
 	}
 
 	#[pallet::call]
@@ -243,6 +307,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::settle_otc_order()
 			.saturating_add(<T as Config>::RouterWeightInfo::sell_weight(route))
 		.saturating_add(<T as Config>::RouterWeightInfo::get_route_weight())
+		.saturating_add(T::ArbitrageRoutes::routes_weight())
 		.saturating_add(<T as Config>::RouterWeightInfo::calculate_spot_price_with_fee_weight(route))
 		.saturating_add(<T as pallet_otc::Config>::WeightInfo::fill_order().max(<T as pallet_otc::Config>::WeightInfo::partial_fill_order()))
 		)]
@@ -256,6 +321,32 @@ pub mod pallet {
 			// If set to `false`, an arb needs to be fully closed.
 			Self::settle_otc(otc_id, amount, route, true)
 		}
+
+		/// Set minimum profit of arbitrage closed against a pool type.
+		///
+		/// If a route contains more pool types, the highest minimum profit is required.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin.
+		/// - `pool_type`: Type of the pool.
+		/// - `min_profit`: Minimum profit in terms of percentage. `MinProfitPercentage` is used if `None`.
+		///
+		/// Emits `PoolTypeMinProfitSet` event when successful.
+		///
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_type_min_profit())]
+		pub fn set_pool_type_min_profit(
+			origin: OriginFor<T>,
+			pool_type: ArbitragePoolType,
+			min_profit: Option<Perbill>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			PoolTypeMinProfit::<T>::set(pool_type, min_profit);
+
+			Self::deposit_event(Event::PoolTypeMinProfitSet { pool_type, min_profit });
+			Ok(())
+		}
 	}
 }
 
@@ -265,20 +356,60 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Ensure that the profit is more than some minimum amount.
-	fn ensure_min_profit(otc_amount_in: Balance, profit: Balance) -> DispatchResult {
+	fn ensure_min_profit(otc_amount_in: Balance, profit: Balance, route: &[Trade<AssetIdOf<T>>]) -> DispatchResult {
 		// In the benchmark we calculate the overhead of extrinsic and we doesn't make any trade.
 		// We disable this check because otherwise it would fail.
 		if cfg!(feature = "runtime-benchmarks") {
 			return Ok(());
 		}
 
-		let min_expected_profit = T::MinProfitPercentage::get().mul_floor(otc_amount_in);
+		let min_expected_profit = Self::min_profit_percentage(route).mul_floor(otc_amount_in);
 		// if the next condition is not met, tell the binary search algorithm to find higher values
 		// by throwing the error.
 		ensure!(profit >= min_expected_profit, Error::<T>::TradeAmountTooLow);
 		Ok(())
 	}
 
+	/// Minimum profit of an arbitrage closed against `route`.
+	/// The highest minimum profit of pool types in the route is used.
+	pub fn min_profit_percentage(route: &[Trade<AssetIdOf<T>>]) -> Perbill {
+		route
+			.iter()
+			.map(|trade| {
+				Self::pool_type_min_profit(ArbitragePoolType::from(&trade.pool))
+					.unwrap_or_else(T::MinProfitPercentage::get)
+			})
+			.max()
+			.unwrap_or_else(T::MinProfitPercentage::get)
+	}
+
+	/// Routes the arbitrage can be closed against. The route provided by the router is the first one.
+	pub fn routes(asset_pair: AssetPair<AssetIdOf<T>>) -> Vec<Vec<Trade<AssetIdOf<T>>>> {
+		let mut routes = vec![T::Router::get_route(asset_pair)];
+		for route in T::ArbitrageRoutes::routes(asset_pair) {
+			if !route.is_empty() && !routes.contains(&route) {
+				routes.push(route);
+			}
+		}
+		routes
+	}
+
+	/// Routes from asset_out to asset_in of `otc` with available spot price, sorted by the price.
+	/// The route with the lowest price, so the largest arbitrage opportunity, is the first one.
+	fn routes_with_price(otc: &Order<T::AccountId, T::AssetId>) -> Vec<(Vec<Trade<AssetIdOf<T>>>, FixedU128)> {
+		let mut routes: Vec<(Vec<Trade<AssetIdOf<T>>>, FixedU128)> = Self::routes(AssetPair {
+			// To get the correct price, we need to switch the assets, otherwise
+			// the price is inverted and not directly comparable to the OTC price.
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		})
+		.into_iter()
+		.filter_map(|route| T::Router::spot_price_with_fee(&route).map(|price| (route, price)))
+		.collect();
+		routes.sort_by(|a, b| a.1.cmp(&b.1));
+		routes
+	}
+
 	/// Because asset_in in a OTC order becomes asset_out in a router trade, we name
 	/// this asset just asset_a to make it less confusing.
 	///
@@ -308,6 +439,8 @@ impl<T: Config> Pallet<T> {
 		let pallet_acc = Self::account_id();
 
 		let otc = <pallet_otc::Orders<T>>::get(otc_id).ok_or(Error::<T>::OrderNotFound)?;
+		// the price of the order is not static, so the arbitrage can't be calculated from its amounts
+		ensure!(otc.oracle_spread.is_none(), Error::<T>::OraclePricedOrder);
 		let (asset_a, asset_b) = (otc.asset_in, otc.asset_out);
 
 		if !otc.partially_fillable {
@@ -316,11 +449,11 @@ impl<T: Config> Pallet<T> {
 		}
 
 		ensure!(
-			route
-				== T::Router::get_route(AssetPair {
-					asset_in: asset_b,
-					asset_out: asset_a,
-				}),
+			Self::routes(AssetPair {
+				asset_in: asset_b,
+				asset_out: asset_a,
+			})
+			.contains(&route),
 			Error::<T>::InvalidRoute
 		);
 
@@ -409,7 +542,7 @@ impl<T: Config> Pallet<T> {
 			.and_then(|value| value.checked_sub(amount))
 			.ok_or(ArithmeticError::Overflow)?;
 
-		Self::ensure_min_profit(otc.amount_in, profit)?;
+		Self::ensure_min_profit(otc.amount_in, profit, &route)?;
 
 		<T as Config>::Currency::transfer(
 			asset_a,
@@ -472,17 +605,21 @@ impl<T: Config> Pallet<T> {
 			if let Ok(_guard) = lock.try_lock() {
 				let sorted_otcs = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA);
 
+				let pallet_acc = Self::account_id();
 				let mut list = vec![];
 				for (otc_id, otc) in <pallet_otc::Orders<T>>::iter() {
+					// RFQ orders can be settled only if the pallet account is a counterparty
+					if !otc.can_be_filled_by(&pallet_acc) {
+						continue;
+					}
+					// oracle-priced orders track the market, there is no arbitrage to close
+					if otc.oracle_spread.is_some() {
+						continue;
+					}
+
 					let otc_price = Self::otc_price(&otc).ok();
 
-					let route = T::Router::get_route(AssetPair {
-						// To get the correct price, we need to switch the assets, otherwise
-						// the price is inverted and not directly comparable to the OTC price.
-						asset_in: otc.asset_out,
-						asset_out: otc.asset_in,
-					});
-					let router_price_before = T::Router::spot_price_with_fee(&route.clone());
+					let router_price_before = Self::routes_with_price(&otc).first().map(|(_, price)| *price);
 
 					if let (Some(otc_price), Some(router_price)) = (otc_price, router_price_before) {
 						// otc's with no arb opportunity are at the end of the list and are not sorted
@@ -529,24 +666,32 @@ impl<T: Config> Pallet<T> {
 				"test OTC id {:?} ", otc_id);
 
 			let otc = <pallet_otc::Orders<T>>::get(otc_id).unwrap();
-			let route = T::Router::get_route(AssetPair {
-				asset_in: otc.asset_out,
-				asset_out: otc.asset_in,
-			});
-			let maybe_amount = Self::try_find_trade_amount(*otc_id, &otc, &route);
-			if let Some(sell_amt) = maybe_amount {
-				log::debug!(
-				target: "offchain_worker::settle_otcs",
-						"Sending TX for OTC id: {:?} amount: {:?}",
-						otc_id,
-						sell_amt
-					);
-				let call = Call::settle_otc_order {
-					otc_id: *otc_id,
-					amount: sell_amt,
-					route,
-				};
-				let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+			let Ok(otc_price) = Self::otc_price(&otc) else {
+				continue;
+			};
+
+			// try the routes with arbitrage opportunity, starting with the largest one
+			for (route, router_price) in Self::routes_with_price(&otc) {
+				if router_price > otc_price {
+					break;
+				}
+				let maybe_amount = Self::try_find_trade_amount(*otc_id, &otc, &route);
+				if let Some(sell_amt) = maybe_amount {
+					log::debug!(
+					target: "offchain_worker::settle_otcs",
+							"Sending TX for OTC id: {:?} amount: {:?} route: {:?}",
+							otc_id,
+							sell_amt,
+							route
+						);
+					let call = Call::settle_otc_order {
+						otc_id: *otc_id,
+						amount: sell_amt,
+						route,
+					};
+					let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+					break;
+				}
 			}
 		}
 	}
@@ -29,6 +29,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSigned};
 use hydra_dx_math::{ema::EmaPrice, ratio::Ratio};
 use hydradx_traits::{
+	price::PriceProvider,
 	router::{PoolType, RefundEdCalculator},
 	OraclePeriod, PriceOracle,
 };
@@ -41,6 +42,7 @@ use sp_core::offchain::{
 };
 use sp_core::H256;
 use sp_std::sync::Arc;
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -83,6 +85,7 @@ parameter_types! {
 	pub PricePrecision: FixedU128 = FixedU128::from_rational(1, 1_000_000);
 	pub MinProfitPercentage: Perbill = Perbill::from_rational(1u32, 100_000_u32); // 0.001%
 	pub OtcFee: Permill = Permill::from_percent(1u32);
+	pub OtcMakerRebate: Permill = Permill::zero();
 }
 
 parameter_type_with_key! {
@@ -100,10 +103,43 @@ impl pallet_otc_settlements::Config for Test {
 	type PricePrecision = PricePrecision;
 	type MinTradingLimit = MinTradingLimit;
 	type MaxIterations = ConstU32<40>;
+	type ArbitrageRoutes = MockArbitrageRoutes;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 	type RouterWeightInfo = ();
 }
 
+thread_local! {
+	pub static ARBITRAGE_ROUTES: RefCell<Vec<Vec<Trade<AssetId>>>> = const { RefCell::new(vec![]) };
+}
+
+pub struct MockArbitrageRoutes;
+
+impl MockArbitrageRoutes {
+	pub fn set(routes: Vec<Vec<Trade<AssetId>>>) {
+		ARBITRAGE_ROUTES.with(|v| *v.borrow_mut() = routes);
+	}
+}
+
+impl ArbitrageRoutes<AssetId> for MockArbitrageRoutes {
+	fn routes(asset_pair: AssetPair<AssetId>) -> Vec<Vec<Trade<AssetId>>> {
+		ARBITRAGE_ROUTES.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|route| {
+					route.first().map(|t| t.asset_in) == Some(asset_pair.asset_in)
+						&& route.last().map(|t| t.asset_out) == Some(asset_pair.asset_out)
+				})
+				.cloned()
+				.collect()
+		})
+	}
+
+	fn routes_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 impl pallet_otc::Config for Test {
 	type AssetId = AssetId;
 	type AssetRegistry = AssetRegistry;
@@ -113,6 +149,8 @@ impl pallet_otc::Config for Test {
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
 	type Fee = OtcFee;
 	type FeeReceiver = TreasuryAccount;
+	type PriceProvider = PriceProviderMock;
+	type MakerRebate = OtcMakerRebate;
 	type WeightInfo = ();
 }
 
@@ -143,6 +181,14 @@ impl PriceOracle<AssetId> for PriceProviderMock {
 	}
 }
 
+impl PriceProvider<AssetId> for PriceProviderMock {
+	type Price = Ratio;
+
+	fn get_price(_asset_a: AssetId, _asset_b: AssetId) -> Option<Ratio> {
+		Some(Ratio::new(88, 100))
+	}
+}
+
 impl pallet_route_executor::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -306,6 +352,9 @@ impl pallet_asset_registry::Config for Test {
 	type SequentialIdStartAt = SequentialIdOffset;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type SufficiencyChangeHook = ();
+	type MaxHoldersForParameterChange = frame_support::traits::ConstU32<0>;
+	type MaxAliases = frame_support::traits::ConstU32<0>;
 	type WeightInfo = ();
 }
 
@@ -342,10 +391,23 @@ impl pallet_omnipool::Config for Test {
 	type CollectionId = u32;
 	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type OmnipoolHooks = ();
+	type OnTradeExecuted = ();
 	type PriceBarrier = ();
 	type MinWithdrawalFee = ();
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = ();
+	type WithdrawalStreamBlocks = ();
+	type MaxQueuedWithdrawals = ();
+	type MaxQueuedWithdrawalsPerAccount = ();
+	type MaxHubAssetBuyPerBlock = ();
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
 }
 
 pub struct DummyNFT;
@@ -21,9 +21,10 @@
 
 use super::*;
 pub use crate::mock::*;
-use frame_support::{assert_ok, assert_storage_noop};
+use frame_support::{assert_noop, assert_ok, assert_storage_noop};
 use hydradx_traits::Inspect;
 use orml_traits::MultiCurrency;
+use sp_runtime::Permill;
 
 pub fn expect_events(e: Vec<RuntimeEvent>) {
 	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
@@ -407,6 +408,43 @@ fn existing_arb_opportunity_should_trigger_trade_when_otc_is_not_partially_filla
 	});
 }
 
+#[test]
+fn trade_should_not_be_triggered_when_pallet_account_is_not_counterparty_of_otc() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_rfq_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			300_000 * ONE,
+			false,
+			vec![BOB].try_into().unwrap(),
+		));
+
+		// get otc price
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let otc_price = calculate_otc_price(&otc);
+
+		// get trade price
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let router_price = Router::spot_price_with_fee(&route).unwrap();
+
+		// verify that there's an arb opportunity
+		assert!(otc_price > router_price);
+
+		assert_storage_noop!(<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(
+			System::block_number()
+		));
+
+		assert!(<pallet_otc::Orders<Test>>::contains_key(otc_id));
+	});
+}
+
 #[test]
 fn existing_arb_opportunity_of_insufficient_asset_should_trigger_trade() {
 	let (mut ext, _) = ExtBuilder::default().build();
@@ -766,6 +804,256 @@ fn test_offchain_worker_signed_transaction_submission() {
 	})
 }
 
+#[test]
+fn oracle_priced_otc_should_not_be_settled() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_oracle_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			Permill::zero(),
+			true,
+		));
+
+		let otc_id = 0;
+		let route = Router::get_route(AssetPair {
+			asset_in: DAI,
+			asset_out: HDX,
+		});
+
+		assert_storage_noop!(<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(
+			System::block_number()
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(RuntimeOrigin::signed(ALICE), otc_id, 2_413_749_694_825_193, route),
+			Error::<Test>::OraclePricedOrder
+		);
+	})
+}
+
+#[test]
+fn settle_otc_should_work_when_route_is_provided_by_arbitrage_routes() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: KSM,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: KSM,
+				asset_out: HDX,
+			},
+		];
+		MockArbitrageRoutes::set(vec![route.clone()]);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			2_413_749_694_825_193,
+			route,
+		));
+
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::OtcSettlements(Event::Executed { asset_id: HDX, .. })
+		)));
+	})
+}
+
+#[test]
+fn settle_otc_should_fail_when_route_is_not_provided() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: KSM,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: KSM,
+				asset_out: HDX,
+			},
+		];
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(RuntimeOrigin::signed(ALICE), 0, 2_413_749_694_825_193, route),
+			Error::<Test>::InvalidRoute
+		);
+	})
+}
+
+#[test]
+fn routes_should_contain_router_route_first_and_no_duplicates() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let asset_pair = AssetPair {
+			asset_in: DAI,
+			asset_out: HDX,
+		};
+		let router_route = Router::get_route(asset_pair);
+		let route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: KSM,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: KSM,
+				asset_out: HDX,
+			},
+		];
+		MockArbitrageRoutes::set(vec![router_route.clone(), route.clone(), route.clone()]);
+
+		assert_eq!(OtcSettlements::routes(asset_pair), vec![router_route, route]);
+	})
+}
+
+#[test]
+fn set_pool_type_min_profit_should_work_when_called_by_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OtcSettlements::set_pool_type_min_profit(
+			RuntimeOrigin::root(),
+			ArbitragePoolType::Stableswap,
+			Some(Perbill::from_percent(1)),
+		));
+
+		assert_eq!(
+			OtcSettlements::pool_type_min_profit(ArbitragePoolType::Stableswap),
+			Some(Perbill::from_percent(1))
+		);
+		expect_events(vec![Event::PoolTypeMinProfitSet {
+			pool_type: ArbitragePoolType::Stableswap,
+			min_profit: Some(Perbill::from_percent(1)),
+		}
+		.into()]);
+
+		assert_ok!(OtcSettlements::set_pool_type_min_profit(
+			RuntimeOrigin::root(),
+			ArbitragePoolType::Stableswap,
+			None,
+		));
+		assert_eq!(
+			OtcSettlements::pool_type_min_profit(ArbitragePoolType::Stableswap),
+			None
+		);
+	})
+}
+
+#[test]
+fn set_pool_type_min_profit_should_fail_when_called_by_non_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_noop!(
+			OtcSettlements::set_pool_type_min_profit(
+				RuntimeOrigin::signed(ALICE),
+				ArbitragePoolType::Stableswap,
+				Some(Perbill::from_percent(1)),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn min_profit_percentage_should_be_highest_of_pool_types_in_route() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let route = vec![
+			Trade {
+				pool: PoolType::Stableswap(100),
+				asset_in: DAI,
+				asset_out: DOT,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: DOT,
+				asset_out: HDX,
+			},
+		];
+
+		assert_eq!(
+			OtcSettlements::min_profit_percentage(&route),
+			MinProfitPercentage::get()
+		);
+
+		assert_ok!(OtcSettlements::set_pool_type_min_profit(
+			RuntimeOrigin::root(),
+			ArbitragePoolType::Stableswap,
+			Some(Perbill::from_percent(1)),
+		));
+		assert_eq!(OtcSettlements::min_profit_percentage(&route), Perbill::from_percent(1));
+
+		assert_ok!(OtcSettlements::set_pool_type_min_profit(
+			RuntimeOrigin::root(),
+			ArbitragePoolType::XYK,
+			Some(Perbill::from_percent(2)),
+		));
+		assert_eq!(OtcSettlements::min_profit_percentage(&route), Perbill::from_percent(2));
+	})
+}
+
+#[test]
+fn trade_should_not_be_triggered_when_profit_is_lower_than_pool_type_min_profit() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+		assert_ok!(OtcSettlements::set_pool_type_min_profit(
+			RuntimeOrigin::root(),
+			ArbitragePoolType::Omnipool,
+			Some(Perbill::from_percent(1)),
+		));
+
+		let route = Router::get_route(AssetPair {
+			asset_in: DAI,
+			asset_out: HDX,
+		});
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(RuntimeOrigin::signed(ALICE), 0, 2_413_749_694_825_193, route),
+			Error::<Test>::TradeAmountTooLow
+		);
+		assert_storage_noop!(<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(
+			System::block_number()
+		));
+	})
+}
+
 fn place_orders() {
 	assert_ok!(OTC::place_order(
 		RuntimeOrigin::signed(ALICE),
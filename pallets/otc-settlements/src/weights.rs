@@ -51,6 +51,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_otc.
 pub trait WeightInfo {
 	fn settle_otc_order() -> Weight;
+	fn set_pool_type_min_profit() -> Weight;
 }
 
 /// Weights for pallet_otc using the hydraDX node and recommended hardware.
@@ -70,4 +71,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `OtcSettlements::PoolTypeMinProfit` (r:0 w:1)
+	/// Proof: `OtcSettlements::PoolTypeMinProfit` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_pool_type_min_profit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_120_000 picoseconds.
+		Weight::from_parts(9_431_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
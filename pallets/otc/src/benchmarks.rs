@@ -79,6 +79,71 @@ benchmarks! {
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
 	}
+
+	place_rfq_order {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		let counterparties: Counterparties<T::AccountId> = (0..MAX_COUNTERPARTIES)
+			.map(|i| account("counterparty", i, i))
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| "BoundedConvertionFailed")?;
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, false, counterparties.clone())
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
+		assert_eq!(crate::Pallet::<T>::orders(0).unwrap().counterparties, Some(counterparties));
+	}
+
+	place_oracle_order {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, Permill::from_percent(1), true)
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
+		assert_eq!(crate::Pallet::<T>::orders(0).unwrap().oracle_spread, Some(Permill::from_percent(1)));
+	}
+
+	partial_fill_oracle_order {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
+
+		assert_ok!(
+			crate::Pallet::<T>::place_oracle_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, Permill::from_percent(1), true)
+		);
+  }:  partial_fill_order(RawOrigin::Signed(filler.clone()), 0u32, 10 * ONE)
+	verify {
+		assert!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner) < 100 * ONE);
+	}
+
+	fill_oracle_order {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
+
+		assert_ok!(
+			crate::Pallet::<T>::place_oracle_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, Permill::from_percent(1), true)
+		);
+  }:  fill_order(RawOrigin::Signed(filler.clone()), 0u32)
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
+	}
+
+	claim_maker_rebate {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		T::Currency::reserve_named(&REBATE_RESERVE_ID, dot.into(), &owner, ONE)?;
+		MakerRebates::<T>::insert(&owner, T::AssetId::from(dot), ONE);
+  }:  _(RawOrigin::Signed(owner.clone()), dot.into())
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&REBATE_RESERVE_ID, dot.into(), &owner), 0);
+		assert_eq!(crate::Pallet::<T>::maker_rebates(&owner, T::AssetId::from(dot)), 0);
+	}
 }
 
 fn seed_registry<T: Config>() -> Result<(u32, u32), DispatchError>
@@ -20,6 +20,19 @@
 // whether the order is partially fillable. Fee is applied to all trades and is deducted from the `amount_out`.
 // Because of the fee, the order price is static and calculated as `(amount_out - fee) / amount_in`.
 //
+// Request-for-quote (RFQ) orders placed by `place_rfq_order` can be filled only by designated counterparties.
+// This allows to settle OTC deals negotiated off-chain trustlessly on-chain. Combined with a not partially fillable
+// order, the counterparty has to fill the whole order at once.
+//
+// Oracle-priced orders placed by `place_oracle_order` are not filled at a static price. At fill time, the price of
+// asset_out is taken from the oracle and increased by the spread of the order, so the order tracks the market without
+// being cancelled and placed again. `amount_in` of such order is the minimum amount the order is filled for - the
+// order is never filled at a lower price than the price it was placed with.
+//
+// Order owners can receive a rebate of the fee when their orders are filled. `MakerRebate` share of the fee is not
+// transferred to the fee receiver but stays reserved on the owner's account, tracked per account and asset, until it
+// is claimed by `claim_maker_rebate`. This way the protocol fee funds liquidity of the on-chain order book.
+//
 // ## Notes
 // The pallet implements a minimum order size as an alternative to storage fees. The amounts of an open order cannot
 // be lower than the existential deposit for the respective asset, multiplied by `ExistentialDepositMultiplier`.
@@ -28,21 +41,26 @@
 //
 // ## Dispatachable functions
 // * `place_order` -  create a new OTC order.
+// * `place_rfq_order` - create a new OTC order which can be filled only by designated counterparties.
+// * `place_oracle_order` - create a new OTC order priced by the oracle.
 // * `partial_fill_order` - fill an OTC order (partially).
 // * `fill_order` - fill an OTC order (completely).
 // * `cancel_order` - cancel an open OTC order.
+// * `claim_maker_rebate` - claim fee rebates accrued by filled orders.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::MaxEncodedLen;
 use frame_support::{pallet_prelude::*, require_transactional};
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use hydradx_traits::Inspect;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::{price::PriceProvider, Inspect};
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
 use sp_runtime::traits::{One, Zero};
 use sp_runtime::Permill;
 
+pub mod migration;
 #[cfg(test)]
 mod tests;
 
@@ -62,6 +80,14 @@ pub type NamedReserveIdentifier = [u8; 8];
 
 pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"otcorder";
 
+/// Named reserve of accrued maker rebates.
+pub const REBATE_RESERVE_ID: NamedReserveIdentifier = *b"otcrebat";
+
+/// Max number of counterparties allowed to fill an RFQ order.
+pub const MAX_COUNTERPARTIES: u32 = 16;
+
+pub type Counterparties<AccountId> = BoundedVec<AccountId, ConstU32<MAX_COUNTERPARTIES>>;
+
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Order<AccountId, AssetId> {
 	pub owner: AccountId,
@@ -70,6 +96,20 @@ pub struct Order<AccountId, AssetId> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 	pub partially_fillable: bool,
+	/// Accounts allowed to fill the order. Anyone can fill the order if not set.
+	pub counterparties: Option<Counterparties<AccountId>>,
+	/// Premium over the oracle price of asset_out. If set, the order is priced by the oracle at fill time and
+	/// `amount_in` is the minimum amount the order can be filled for.
+	pub oracle_spread: Option<Permill>,
+}
+
+impl<AccountId: PartialEq, AssetId> Order<AccountId, AssetId> {
+	/// Returns true if `who` is allowed to fill the order.
+	pub fn can_be_filled_by(&self, who: &AccountId) -> bool {
+		self.counterparties
+			.as_ref()
+			.map_or(true, |counterparties| counterparties.contains(who))
+	}
 }
 
 #[frame_support::pallet]
@@ -77,7 +117,10 @@ pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -114,6 +157,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type FeeReceiver: Get<Self::AccountId>;
 
+		/// Oracle price provider used to price oracle-priced orders.
+		type PriceProvider: PriceProvider<Self::AssetId, Price = EmaPrice>;
+
+		/// Share of the fee rebated to the order owner when the order is filled.
+		#[pallet::constant]
+		type MakerRebate: Get<Permill>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 	}
@@ -148,6 +198,26 @@ pub mod pallet {
 			amount_out: Balance,
 			partially_fillable: bool,
 		},
+		/// Filling of an Order has been restricted to the counterparties
+		CounterpartiesSet {
+			order_id: OrderId,
+			counterparties: Counterparties<T::AccountId>,
+		},
+		/// An Order is priced by the oracle with the spread
+		OraclePricingSet { order_id: OrderId, spread: Permill },
+		/// Part of the fee of a filled Order has been rebated to the order owner
+		MakerRebateAccrued {
+			order_id: OrderId,
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
+		/// Accrued maker rebate has been claimed
+		MakerRebateClaimed {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -170,6 +240,16 @@ pub mod pallet {
 		Forbidden,
 		/// Reserved amount not sufficient.
 		InsufficientReservedAmount,
+		/// RFQ order must have at least one counterparty
+		NoCounterparties,
+		/// The caller is not a counterparty of the order
+		NotCounterparty,
+		/// Asset is quarantined and cannot be traded
+		AssetQuarantined,
+		/// Oracle price of the asset pair is not available
+		OraclePriceNotAvailable,
+		/// There is no maker rebate to claim
+		NothingToClaim,
 	}
 
 	/// ID sequencer for Orders
@@ -181,6 +261,12 @@ pub mod pallet {
 	#[pallet::getter(fn orders)]
 	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
 
+	/// Maker rebates accrued by order owners and not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn maker_rebates)]
+	pub type MakerRebates<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AssetId, Balance, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new OTC order
@@ -194,6 +280,7 @@ pub mod pallet {
 		///
 		/// Validations:
 		/// - asset_in must be registered
+		/// - asset_in and asset_out must not be quarantined
 		/// - amount_in must be higher than the existential deposit of asset_in multiplied by
 		///   ExistentialDepositMultiplier
 		/// - amount_out must be higher than the existential deposit of asset_out multiplied by
@@ -212,44 +299,18 @@ pub mod pallet {
 			partially_fillable: bool,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
-			let order = Order {
+
+			Self::do_place_order(Order {
 				owner,
 				asset_in,
 				asset_out,
 				amount_in,
 				amount_out,
 				partially_fillable,
-			};
-
-			ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
-
-			let fee = Self::calculate_fee(order.amount_out);
-
-			Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
-			// the fee is applied to amount_out
-			Self::ensure_min_order_amount(
-				order.asset_out,
-				order.amount_out.checked_sub(fee).ok_or(Error::<T>::MathError)?,
-			)?;
-
-			<NextOrderId<T>>::try_mutate(|next_id| -> DispatchResult {
-				let order_id = *next_id;
-
-				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
-				<Orders<T>>::insert(order_id, &order);
-
-				Self::deposit_event(Event::Placed {
-					order_id,
-					asset_in: order.asset_in,
-					asset_out: order.asset_out,
-					amount_in: order.amount_in,
-					amount_out,
-					partially_fillable: order.partially_fillable,
-				});
-
-				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
-				Ok(())
-			})
+				counterparties: None,
+				oracle_spread: None,
+			})?;
+			Ok(())
 		}
 
 		/// Fill an OTC order (partially)
@@ -258,8 +319,12 @@ pub mod pallet {
 		/// - `order_id`: ID of the order
 		/// - `amount_in`: Amount with which the order is being filled
 		///
+		/// Oracle-priced orders are filled at the current price of the order. The minimum amount_in of the order
+		/// is reduced in proportion to the amount_out which was sold.
+		///
 		/// Validations:
 		/// - order must be partially_fillable
+		/// - oracle price must be available if the order is priced by the oracle
 		/// - after the partial_fill, the remaining order.amount_in must be higher than the existential deposit
 		///   of asset_in multiplied by ExistentialDepositMultiplier
 		/// - after the partial_fill, the remaining order.amount_out must be higher than the existential deposit
@@ -268,7 +333,9 @@ pub mod pallet {
 		/// Events:
 		/// `PartiallyFilled` event when successful.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::partial_fill_order())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::partial_fill_order().max(<T as Config>::WeightInfo::partial_fill_oracle_order())
+		)]
 		pub fn partial_fill_order(origin: OriginFor<T>, order_id: OrderId, amount_in: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			<Orders<T>>::try_mutate(order_id, |maybe_order| -> DispatchResult {
@@ -276,25 +343,32 @@ pub mod pallet {
 
 				ensure!(order.partially_fillable, Error::<T>::OrderNotPartiallyFillable);
 
-				let amount_out_calculation = U256::from(order.amount_out)
-					.checked_mul(U256::from(amount_in))
-					.and_then(|v| v.checked_div(U256::from(order.amount_in)))
-					.ok_or(Error::<T>::MathError)?;
-				let amount_out = Balance::try_from(amount_out_calculation).map_err(|_| Error::<T>::MathError)?;
-
-				order.amount_in = order.amount_in.checked_sub(amount_in).ok_or(Error::<T>::MathError)?;
+				let order_amount_in = Self::current_amount_in(order)?;
+				let amount_out = Self::mul_div(order.amount_out, amount_in, order_amount_in)?;
+				let remaining_amount_in = order_amount_in.checked_sub(amount_in).ok_or(Error::<T>::MathError)?;
+
+				order.amount_in = if order.oracle_spread.is_some() {
+					// keep the minimum price of the remaining order
+					let sold_min_amount_in = Self::mul_div(order.amount_in, amount_out, order.amount_out)?;
+					order
+						.amount_in
+						.checked_sub(sold_min_amount_in)
+						.ok_or(Error::<T>::MathError)?
+				} else {
+					remaining_amount_in
+				};
 				order.amount_out = order.amount_out.checked_sub(amount_out).ok_or(Error::<T>::MathError)?;
 
 				let fee = Self::calculate_fee(amount_out);
 
-				Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
+				Self::ensure_min_order_amount(order.asset_in, remaining_amount_in)?;
 				// the fee is applied to amount_out
 				Self::ensure_min_order_amount(
 					order.asset_out,
 					order.amount_out.checked_sub(fee).ok_or(Error::<T>::MathError)?,
 				)?;
 
-				Self::execute_order(order, &who, amount_in, amount_out, fee)?;
+				Self::execute_order(order_id, order, &who, amount_in, amount_out, fee)?;
 
 				Self::deposit_event(Event::PartiallyFilled {
 					order_id,
@@ -309,26 +383,32 @@ pub mod pallet {
 
 		/// Fill an OTC order (completely)
 		///  
+		/// Oracle-priced orders are filled at the current price of the order.
+		///
 		/// Parameters:
 		/// - `order_id`: ID of the order
 		///
+		/// Validations:
+		/// - oracle price must be available if the order is priced by the oracle
+		///
 		/// Events:
 		/// `Filled` event when successful.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::fill_order())]
+		#[pallet::weight(<T as Config>::WeightInfo::fill_order().max(<T as Config>::WeightInfo::fill_oracle_order()))]
 		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
 
+			let amount_in = Self::current_amount_in(&order)?;
 			let fee = Self::calculate_fee(order.amount_out);
 
-			Self::execute_order(&order, &who, order.amount_in, order.amount_out, fee)?;
+			Self::execute_order(order_id, &order, &who, amount_in, order.amount_out, fee)?;
 			<Orders<T>>::remove(order_id);
 
 			Self::deposit_event(Event::Filled {
 				order_id,
 				who,
-				amount_in: order.amount_in,
+				amount_in,
 				amount_out: order.amount_out,
 				fee,
 			});
@@ -364,10 +444,168 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		/// Create a new RFQ order which can be filled only by designated counterparties
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being bought
+		/// - `asset_out`: Asset which is being sold
+		/// - `amount_in`: Amount that the order is seeking to buy
+		/// - `amount_out`: Amount that the order is selling
+		/// - `partially_fillable`: Flag indicating whether counterparties can fill the order partially
+		/// - `counterparties`: Accounts allowed to fill the order
+		///
+		/// Validations:
+		/// - same as `place_order`
+		/// - at least one counterparty must be provided
+		///
+		/// Events:
+		/// - `Placed` and `CounterpartiesSet` events when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_rfq_order())]
+		pub fn place_rfq_order(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			partially_fillable: bool,
+			counterparties: Counterparties<T::AccountId>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(!counterparties.is_empty(), Error::<T>::NoCounterparties);
+
+			let order_id = Self::do_place_order(Order {
+				owner,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				partially_fillable,
+				counterparties: Some(counterparties.clone()),
+				oracle_spread: None,
+			})?;
+
+			Self::deposit_event(Event::CounterpartiesSet {
+				order_id,
+				counterparties,
+			});
+			Ok(())
+		}
+
+		/// Create a new OTC order priced by the oracle
+		///
+		/// At fill time, the order is priced by the oracle price of asset_out increased by `spread`, but never
+		/// lower than `min_amount_in` for `amount_out`.
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being bought
+		/// - `asset_out`: Asset which is being sold
+		/// - `min_amount_in`: Minimum amount that the order is seeking to buy
+		/// - `amount_out`: Amount that the order is selling
+		/// - `spread`: Premium over the oracle price of asset_out
+		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		///
+		/// Validations:
+		/// - same as `place_order`
+		///
+		/// Events:
+		/// - `Placed` and `OraclePricingSet` events when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_oracle_order())]
+		pub fn place_oracle_order(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			min_amount_in: Balance,
+			amount_out: Balance,
+			spread: Permill,
+			partially_fillable: bool,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let order_id = Self::do_place_order(Order {
+				owner,
+				asset_in,
+				asset_out,
+				amount_in: min_amount_in,
+				amount_out,
+				partially_fillable,
+				counterparties: None,
+				oracle_spread: Some(spread),
+			})?;
+
+			Self::deposit_event(Event::OraclePricingSet { order_id, spread });
+			Ok(())
+		}
+
+		/// Claim maker rebate accrued by filled orders
+		///
+		/// The rebate is accrued in asset_out of the filled orders and kept reserved until it is claimed.
+		///
+		/// Parameters:
+		/// - `asset_id`: Asset of the rebate
+		///
+		/// Validations:
+		/// - there must be a rebate accrued in the asset
+		///
+		/// Events:
+		/// - `MakerRebateClaimed` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_maker_rebate())]
+		pub fn claim_maker_rebate(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount = <MakerRebates<T>>::take(&who, asset_id);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			let remaining_to_unreserve = T::Currency::unreserve_named(&REBATE_RESERVE_ID, asset_id, &who, amount);
+			ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+
+			Self::deposit_event(Event::MakerRebateClaimed { who, asset_id, amount });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_place_order(order: Order<T::AccountId, T::AssetId>) -> Result<OrderId, DispatchError> {
+		ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
+		ensure!(
+			!T::AssetRegistry::is_quarantined(order.asset_in) && !T::AssetRegistry::is_quarantined(order.asset_out),
+			Error::<T>::AssetQuarantined
+		);
+
+		let fee = Self::calculate_fee(order.amount_out);
+
+		Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
+		// the fee is applied to amount_out
+		Self::ensure_min_order_amount(
+			order.asset_out,
+			order.amount_out.checked_sub(fee).ok_or(Error::<T>::MathError)?,
+		)?;
+
+		<NextOrderId<T>>::try_mutate(|next_id| -> Result<OrderId, DispatchError> {
+			let order_id = *next_id;
+
+			T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
+			<Orders<T>>::insert(order_id, &order);
+
+			Self::deposit_event(Event::Placed {
+				order_id,
+				asset_in: order.asset_in,
+				asset_out: order.asset_out,
+				amount_in: order.amount_in,
+				amount_out: order.amount_out,
+				partially_fillable: order.partially_fillable,
+			});
+
+			*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
+			Ok(order_id)
+		})
+	}
+
 	fn ensure_min_order_amount(asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let min_amount = T::ExistentialDeposits::get(&asset)
 			.checked_mul(T::ExistentialDepositMultiplier::get().into())
@@ -380,12 +618,15 @@ impl<T: Config> Pallet<T> {
 
 	#[require_transactional]
 	fn execute_order(
+		order_id: OrderId,
 		order: &Order<T::AccountId, T::AssetId>,
 		who: &T::AccountId,
 		amount_in: Balance,
 		amount_out: Balance,
 		fee: Balance,
 	) -> DispatchResult {
+		ensure!(order.can_be_filled_by(who), Error::<T>::NotCounterparty);
+
 		T::Currency::transfer(order.asset_in, who, &order.owner, amount_in)?;
 		let remaining_to_unreserve =
 			// returns any amount that was unable to be unreserved
@@ -394,13 +635,71 @@ impl<T: Config> Pallet<T> {
 
 		let amount_out_without_fee = amount_out.checked_sub(fee).ok_or(Error::<T>::MathError)?;
 
+		let rebate = T::MakerRebate::get().mul_floor(fee);
+		let protocol_fee = fee.checked_sub(rebate).ok_or(Error::<T>::MathError)?;
+
 		T::Currency::transfer(order.asset_out, &order.owner, who, amount_out_without_fee)?;
-		T::Currency::transfer(order.asset_out, &order.owner, &T::FeeReceiver::get(), fee)?;
+		T::Currency::transfer(order.asset_out, &order.owner, &T::FeeReceiver::get(), protocol_fee)?;
+
+		Self::accrue_maker_rebate(order_id, &order.owner, order.asset_out, rebate)
+	}
+
+	/// Keeps the rebate reserved on the order owner's account until it is claimed.
+	fn accrue_maker_rebate(
+		order_id: OrderId,
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
 
+		T::Currency::reserve_named(&REBATE_RESERVE_ID, asset_id, who, amount)?;
+		<MakerRebates<T>>::try_mutate(who, asset_id, |accrued| -> DispatchResult {
+			*accrued = accrued.checked_add(amount).ok_or(Error::<T>::MathError)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::MakerRebateAccrued {
+			order_id,
+			who: who.clone(),
+			asset_id,
+			amount,
+		});
 		Ok(())
 	}
 
 	pub fn calculate_fee(amount: Balance) -> Balance {
 		T::Fee::get().mul_ceil(amount)
 	}
+
+	/// Returns amount of asset_in the whole order is filled for.
+	///
+	/// It is `amount_in` of the order, or the oracle price of `amount_out` increased by the spread if it is higher
+	/// and the order is priced by the oracle.
+	pub fn current_amount_in(order: &Order<T::AccountId, T::AssetId>) -> Result<Balance, DispatchError> {
+		let Some(spread) = order.oracle_spread else {
+			return Ok(order.amount_in);
+		};
+
+		// price of asset_out denominated in asset_in
+		let price = T::PriceProvider::get_price(order.asset_in, order.asset_out)
+			.filter(|price| !price.d.is_zero())
+			.ok_or(Error::<T>::OraclePriceNotAvailable)?;
+		let oracle_amount_in = Self::mul_div(order.amount_out, price.n, price.d)?;
+		let oracle_amount_in = oracle_amount_in
+			.checked_add(spread.mul_ceil(oracle_amount_in))
+			.ok_or(Error::<T>::MathError)?;
+
+		Ok(oracle_amount_in.max(order.amount_in))
+	}
+
+	fn mul_div(a: Balance, b: Balance, c: Balance) -> Result<Balance, DispatchError> {
+		let result = U256::from(a)
+			.checked_mul(U256::from(b))
+			.and_then(|v| v.checked_div(U256::from(c)))
+			.ok_or(Error::<T>::MathError)?;
+		Balance::try_from(result).map_err(|_| Error::<T>::MathError.into())
+	}
 }
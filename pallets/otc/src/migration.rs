@@ -0,0 +1,118 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+pub mod versioned {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+
+	pub type V0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		v1::VersionUncheckedMigrateToV1<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	pub type V1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		v2::VersionUncheckedMigrateToV2<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// This migration adds counterparties to orders. Existing orders can be filled by anyone.
+pub mod v1 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use sp_runtime::traits::Saturating;
+	use sp_std::marker::PhantomData;
+
+	#[derive(Decode)]
+	pub struct OldOrder<AccountId, AssetId> {
+		pub owner: AccountId,
+		pub asset_in: AssetId,
+		pub asset_out: AssetId,
+		pub amount_in: Balance,
+		pub amount_out: Balance,
+		pub partially_fillable: bool,
+	}
+
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			Orders::<T>::translate_values::<OldOrder<T::AccountId, T::AssetId>, _>(|old| {
+				count.saturating_inc();
+				Some(Order {
+					owner: old.owner,
+					asset_in: old.asset_in,
+					asset_out: old.asset_out,
+					amount_in: old.amount_in,
+					amount_out: old.amount_out,
+					partially_fillable: old.partially_fillable,
+					counterparties: None,
+					oracle_spread: None,
+				})
+			});
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
+
+// This migration adds oracle pricing to orders. Existing orders keep their static price.
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	use sp_runtime::traits::Saturating;
+	use sp_std::marker::PhantomData;
+
+	#[derive(Decode)]
+	pub struct OldOrder<AccountId, AssetId> {
+		pub owner: AccountId,
+		pub asset_in: AssetId,
+		pub asset_out: AssetId,
+		pub amount_in: Balance,
+		pub amount_out: Balance,
+		pub partially_fillable: bool,
+		pub counterparties: Option<Counterparties<AccountId>>,
+	}
+
+	pub struct VersionUncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			Orders::<T>::translate_values::<OldOrder<T::AccountId, T::AssetId>, _>(|old| {
+				count.saturating_inc();
+				Some(Order {
+					owner: old.owner,
+					asset_in: old.asset_in,
+					asset_out: old.asset_out,
+					amount_in: old.amount_in,
+					amount_out: old.amount_out,
+					partially_fillable: old.partially_fillable,
+					counterparties: old.counterparties,
+					oracle_spread: None,
+				})
+			});
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
@@ -0,0 +1,175 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+#[test]
+fn fill_order_should_accrue_maker_rebate_when_rebate_is_set() {
+	ExtBuilder::default()
+		.with_maker_rebate(Permill::from_percent(50))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true
+			));
+			let alice_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+
+			// Act
+			assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+			// Assert
+			let fee = OTC::calculate_fee(100 * ONE);
+			let rebate = fee / 2;
+			assert_eq!(OTC::maker_rebates(ALICE, HDX), rebate);
+			assert_eq!(
+				Tokens::reserved_balance_named(&otc::REBATE_RESERVE_ID, HDX, &ALICE),
+				rebate
+			);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_hdx_balance_before);
+			assert_eq!(
+				Tokens::free_balance(HDX, &TreasuryAccount::get()),
+				TREASURY_INITIAL_BALANCE + fee - rebate
+			);
+			expect_events(vec![
+				Event::MakerRebateAccrued {
+					order_id: 0,
+					who: ALICE,
+					asset_id: HDX,
+					amount: rebate,
+				}
+				.into(),
+				Event::Filled {
+					order_id: 0,
+					who: BOB,
+					amount_in: 20 * ONE,
+					amount_out: 100 * ONE,
+					fee,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn partial_fill_order_should_accumulate_maker_rebate() {
+	ExtBuilder::default()
+		.with_maker_rebate(Permill::from_percent(50))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true
+			));
+
+			// Act
+			assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE));
+			assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+			// Assert
+			let rebate = OTC::calculate_fee(25 * ONE) / 2 + OTC::calculate_fee(75 * ONE) / 2;
+			assert_eq!(OTC::maker_rebates(ALICE, HDX), rebate);
+			assert_eq!(
+				Tokens::reserved_balance_named(&otc::REBATE_RESERVE_ID, HDX, &ALICE),
+				rebate
+			);
+		});
+}
+
+#[test]
+fn fill_order_should_not_accrue_maker_rebate_when_rebate_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true
+		));
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(OTC::maker_rebates(ALICE, HDX), 0);
+		assert_eq!(Tokens::reserved_balance_named(&otc::REBATE_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(
+			Tokens::free_balance(HDX, &TreasuryAccount::get()),
+			TREASURY_INITIAL_BALANCE + OTC::calculate_fee(100 * ONE)
+		);
+	});
+}
+
+#[test]
+fn claim_maker_rebate_should_unreserve_accrued_rebate() {
+	ExtBuilder::default()
+		.with_maker_rebate(Permill::from_percent(50))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true
+			));
+			assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+			let rebate = OTC::maker_rebates(ALICE, HDX);
+			let alice_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+
+			// Act
+			assert_ok!(OTC::claim_maker_rebate(RuntimeOrigin::signed(ALICE), HDX));
+
+			// Assert
+			assert_eq!(OTC::maker_rebates(ALICE, HDX), 0);
+			assert_eq!(Tokens::reserved_balance_named(&otc::REBATE_RESERVE_ID, HDX, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_hdx_balance_before + rebate);
+			expect_events(vec![Event::MakerRebateClaimed {
+				who: ALICE,
+				asset_id: HDX,
+				amount: rebate,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_maker_rebate_should_fail_when_nothing_is_accrued() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OTC::claim_maker_rebate(RuntimeOrigin::signed(ALICE), HDX),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
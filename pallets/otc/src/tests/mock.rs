@@ -17,11 +17,12 @@ use crate as otc;
 use crate::Config;
 use frame_support::{
 	parameter_types,
-	traits::{Everything, Nothing},
+	traits::{Everything, Get, Nothing},
 	PalletId,
 };
 use frame_system as system;
-use hydradx_traits::{registry::Inspect, AssetKind};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::{price::PriceProvider, registry::Inspect, AssetKind};
 use orml_tokens::AccountData;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
@@ -48,6 +49,7 @@ pub const ONE: Balance = 1_000_000_000_000;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 
 pub const TREASURY_INITIAL_BALANCE: Balance = 1_000_000 * ONE;
 
@@ -64,6 +66,9 @@ thread_local! {
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
 	pub static EXISTENTIAL_DEPOSIT: RefCell<HashMap<AssetId, u128>>= RefCell::new(HashMap::default());
 	pub static PRECISIONS: RefCell<HashMap<AssetId, u32>>= RefCell::new(HashMap::default());
+	pub static QUARANTINED_ASSETS: RefCell<Vec<AssetId>> = RefCell::new(Vec::default());
+	pub static ORACLE_PRICE: RefCell<Option<EmaPrice>> = const { RefCell::new(Some(EmaPrice::new(1, 1))) };
+	pub static MAKER_REBATE: RefCell<Permill> = const { RefCell::new(Permill::zero()) };
 }
 
 parameter_types! {
@@ -89,9 +94,34 @@ impl otc::Config for Test {
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
 	type Fee = OtcFee;
 	type FeeReceiver = TreasuryAccount;
+	type PriceProvider = OraclePriceProvider;
+	type MakerRebate = MakerRebate;
 	type WeightInfo = ();
 }
 
+pub struct MakerRebate;
+
+impl Get<Permill> for MakerRebate {
+	fn get() -> Permill {
+		MAKER_REBATE.with(|v| *v.borrow())
+	}
+}
+
+/// Returns the same price for all asset pairs.
+pub struct OraclePriceProvider;
+
+impl PriceProvider<AssetId> for OraclePriceProvider {
+	type Price = EmaPrice;
+
+	fn get_price(_asset_a: AssetId, _asset_b: AssetId) -> Option<Self::Price> {
+		ORACLE_PRICE.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_oracle_price(price: Option<EmaPrice>) {
+	ORACLE_PRICE.with(|v| *v.borrow_mut() = price);
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 63;
@@ -182,6 +212,10 @@ impl<T: Config> Inspect for DummyRegistry<T> {
 	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
 		unimplemented!()
 	}
+
+	fn is_quarantined(asset_id: Self::AssetId) -> bool {
+		QUARANTINED_ASSETS.with(|v| v.borrow().contains(&asset_id))
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -251,6 +285,11 @@ impl Default for ExtBuilder {
 		EXISTENTIAL_DEPOSIT.with(|v| {
 			v.borrow_mut().clear();
 		});
+		QUARANTINED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		set_oracle_price(Some(EmaPrice::new(1, 1)));
+		MAKER_REBATE.with(|v| *v.borrow_mut() = Permill::zero());
 
 		Self {
 			endowed_accounts: vec![
@@ -276,6 +315,12 @@ impl ExtBuilder {
 
 		self
 	}
+
+	pub fn with_maker_rebate(self, rebate: Permill) -> Self {
+		MAKER_REBATE.with(|v| *v.borrow_mut() = rebate);
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
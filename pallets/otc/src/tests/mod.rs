@@ -18,5 +18,8 @@ pub mod mock;
 pub mod cancel_order;
 pub mod fill_order;
 pub mod invariants;
+pub mod maker_rebate;
 pub mod partial_fill_order;
+pub mod place_oracle_order;
 pub mod place_order;
+pub mod place_rfq_order;
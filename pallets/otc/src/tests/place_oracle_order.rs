@@ -0,0 +1,198 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::ema::EmaPrice;
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+fn place_oracle_order(spread: Permill) {
+	assert_ok!(OTC::place_oracle_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		spread,
+		true
+	));
+}
+
+#[test]
+fn place_oracle_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		place_oracle_order(Permill::from_percent(1));
+
+		// Assert
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.amount_in, 20 * ONE);
+		assert_eq!(order.oracle_spread, Some(Permill::from_percent(1)));
+
+		expect_events(vec![
+			Event::Placed {
+				order_id: 0,
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: 20 * ONE,
+				amount_out: 100 * ONE,
+				partially_fillable: true,
+			}
+			.into(),
+			Event::OraclePricingSet {
+				order_id: 0,
+				spread: Permill::from_percent(1),
+			}
+			.into(),
+		]);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_use_oracle_price_with_spread_when_order_is_priced_by_oracle() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_oracle_order(Permill::from_percent(1));
+		set_oracle_price(Some(EmaPrice::new(1, 2)));
+
+		let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+		let bob_dai_balance_before = Tokens::free_balance(DAI, &BOB);
+		let bob_hdx_balance_before = Tokens::free_balance(HDX, &BOB);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		// 100 HDX for 0.5 DAI each, increased by 1%
+		let amount_in = 50 * ONE + ONE / 2;
+		let fee = OTC::calculate_fee(100 * ONE);
+
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_dai_balance_before + amount_in);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), bob_dai_balance_before - amount_in);
+		assert_eq!(
+			Tokens::free_balance(HDX, &BOB),
+			bob_hdx_balance_before + 100 * ONE - fee
+		);
+
+		expect_events(vec![Event::Filled {
+			order_id: 0,
+			who: BOB,
+			amount_in,
+			amount_out: 100 * ONE,
+			fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn fill_order_should_use_min_amount_in_when_oracle_price_is_lower() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_oracle_order(Permill::from_percent(1));
+		set_oracle_price(Some(EmaPrice::new(1, 10)));
+
+		let bob_dai_balance_before = Tokens::free_balance(DAI, &BOB);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), bob_dai_balance_before - 20 * ONE);
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_oracle_price_is_not_available() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_oracle_order(Permill::from_percent(1));
+		set_oracle_price(None);
+
+		// Act
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OraclePriceNotAvailable
+		);
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE),
+			Error::<Test>::OraclePriceNotAvailable
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_use_oracle_price_when_order_is_priced_by_oracle() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_oracle_order(Permill::zero());
+		set_oracle_price(Some(EmaPrice::new(1, 2)));
+
+		let bob_hdx_balance_before = Tokens::free_balance(HDX, &BOB);
+
+		// Act
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 25 * ONE));
+
+		// Assert
+		let fee = OTC::calculate_fee(50 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), bob_hdx_balance_before + 50 * ONE - fee);
+
+		// minimum price of the remaining order is kept
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.amount_in, 10 * ONE);
+		assert_eq!(order.amount_out, 50 * ONE);
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			50 * ONE
+		);
+
+		expect_events(vec![Event::PartiallyFilled {
+			order_id: 0,
+			who: BOB,
+			amount_in: 25 * ONE,
+			amount_out: 50 * ONE,
+			fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_reprice_remaining_order_when_oracle_price_changes() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_oracle_order(Permill::zero());
+		set_oracle_price(Some(EmaPrice::new(1, 2)));
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 25 * ONE));
+
+		set_oracle_price(Some(EmaPrice::new(1, 1)));
+		let bob_dai_balance_before = Tokens::free_balance(DAI, &BOB);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), bob_dai_balance_before - 50 * ONE);
+	});
+}
@@ -125,6 +125,24 @@ fn place_order_should_throw_error_when_asset_in_is_not_registered() {
 	});
 }
 
+#[test]
+fn place_order_should_throw_error_when_asset_is_quarantined() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		QUARANTINED_ASSETS.with(|v| v.borrow_mut().push(DAI));
+
+		// Act
+		assert_noop!(
+			OTC::place_order(RuntimeOrigin::signed(ALICE), DAI, HDX, 20 * ONE, 100 * ONE, true),
+			Error::<Test>::AssetQuarantined
+		);
+		assert_noop!(
+			OTC::place_order(RuntimeOrigin::signed(ALICE), HDX, DAI, 20 * ONE, 100 * ONE, true),
+			Error::<Test>::AssetQuarantined
+		);
+	});
+}
+
 #[test]
 fn place_order_should_throw_error_when_amount_in_is_too_low() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -0,0 +1,179 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Counterparties, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+fn counterparties(accounts: Vec<AccountId>) -> Counterparties<AccountId> {
+	accounts.try_into().unwrap()
+}
+
+#[test]
+fn place_rfq_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(OTC::place_rfq_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			false,
+			counterparties(vec![BOB])
+		));
+
+		// Assert
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.owner, ALICE);
+		assert_eq!(order.partially_fillable, false);
+		assert_eq!(order.counterparties, Some(counterparties(vec![BOB])));
+
+		expect_events(vec![
+			Event::Placed {
+				order_id: 0,
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: 20 * ONE,
+				amount_out: 100 * ONE,
+				partially_fillable: false,
+			}
+			.into(),
+			Event::CounterpartiesSet {
+				order_id: 0,
+				counterparties: counterparties(vec![BOB]),
+			}
+			.into(),
+		]);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+	});
+}
+
+#[test]
+fn place_rfq_order_should_throw_error_when_no_counterparties_are_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OTC::place_rfq_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				false,
+				counterparties(vec![])
+			),
+			Error::<Test>::NoCounterparties
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_work_when_filled_by_counterparty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_rfq_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			false,
+			counterparties(vec![CHARLIE, BOB])
+		));
+		let bob_dai_balance_before = Tokens::free_balance(DAI, &BOB);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(Tokens::free_balance(DAI, &BOB), bob_dai_balance_before - 20 * ONE);
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_caller_is_not_counterparty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_rfq_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			false,
+			counterparties(vec![CHARLIE])
+		));
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NotCounterparty
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_work_when_filled_by_counterparty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_rfq_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			counterparties(vec![BOB])
+		));
+
+		// Act
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE));
+
+		// Assert
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.amount_in, 10 * ONE);
+		assert_eq!(order.amount_out, 50 * ONE);
+		assert_eq!(order.counterparties, Some(counterparties(vec![BOB])));
+	});
+}
+
+#[test]
+fn partial_fill_order_should_throw_error_when_caller_is_not_counterparty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_rfq_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			counterparties(vec![CHARLIE])
+		));
+
+		// Act & Assert
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE),
+			Error::<Test>::NotCounterparty
+		);
+	});
+}
@@ -0,0 +1,342 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Price monitor pallet
+//!
+//! Offchain worker reporting divergence of spot prices from oracle prices.
+//!
+//! ## Overview
+//!
+//! Governance sets a divergence threshold for each monitored asset. In every block, the offchain worker of a
+//! validator compares the spot price of each monitored asset with its oracle price, both denominated in
+//! `HubAssetId`. Divergence is the difference of the two prices relative to the oracle price.
+//!
+//! When the divergence crosses the threshold, the worker submits an unsigned `report_divergence` transaction and
+//! the report is stored until the divergence falls back below the threshold, which is reported the same way.
+//!
+//! The transaction carries only the asset id. The divergence is computed again on-chain, both when the transaction
+//! is validated and when it is dispatched, so a report is accepted only if it changes the state of the asset.
+//!
+//! Active reports are provided by the `PriceDivergenceProvider` implementation of the pallet, which can be used by
+//! other pallets, e.g. the circuit breaker or dynamic fees, to react to the divergence.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_threshold` - Sets or removes the divergence threshold of an asset.
+//! * `report_divergence` - Reports that the divergence of an asset crossed its threshold or fell back below it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::{DispatchError, Get};
+use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
+use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::pools::SpotPriceProvider;
+use hydradx_traits::price::PriceDivergenceProvider;
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod, Source};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+use sp_runtime::{FixedPointNumber, FixedU128, Permill, RuntimeDebug};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::price-monitor";
+
+// value taken from https://github.com/substrate-developer-hub/recipes/blob/master/pallets/ocw-demo/src/lib.rs
+pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
+
+/// Divergence of the spot price of an asset from its oracle price which crossed the threshold.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct DivergenceReport<BlockNumber> {
+	/// Spot price of the asset.
+	pub spot_price: FixedU128,
+	/// Oracle price of the asset.
+	pub oracle_price: FixedU128,
+	/// Difference of the spot price and the oracle price relative to the oracle price.
+	pub divergence: Permill,
+	/// Block in which the divergence was reported.
+	pub reported_at: BlockNumber,
+}
+
+/// Change of the state of a monitored asset.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum DivergenceChange<BlockNumber> {
+	/// Divergence crossed the threshold.
+	Reported(DivergenceReport<BlockNumber>),
+	/// Divergence of a reported asset fell back below the threshold.
+	Resolved,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Asset id type.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Asset the prices are denominated in.
+		#[pallet::constant]
+		type HubAssetId: Get<Self::AssetId>;
+
+		/// Provider of the spot prices.
+		type SpotPriceProvider: SpotPriceProvider<Self::AssetId, Price = FixedU128>;
+
+		/// Provider of the oracle prices.
+		type Oracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
+		/// Source of the oracle prices.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Period of the oracle prices.
+		#[pallet::constant]
+		type OraclePeriod: Get<OraclePeriod>;
+
+		/// Origin able to set divergence thresholds.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Divergence thresholds of the monitored assets.
+	#[pallet::storage]
+	#[pallet::getter(fn threshold)]
+	pub type Thresholds<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, Permill, OptionQuery>;
+
+	/// Reports of the assets whose divergence crossed the threshold.
+	#[pallet::storage]
+	#[pallet::getter(fn divergence_report)]
+	pub type DivergenceReports<T: Config> =
+		StorageMap<_, Twox64Concat, T::AssetId, DivergenceReport<BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Divergence threshold of an asset was set or removed.
+		ThresholdSet {
+			asset_id: T::AssetId,
+			threshold: Option<Permill>,
+		},
+		/// Divergence of an asset crossed the threshold.
+		DivergenceReported {
+			asset_id: T::AssetId,
+			spot_price: FixedU128,
+			oracle_price: FixedU128,
+			divergence: Permill,
+		},
+		/// Divergence of an asset fell back below the threshold.
+		DivergenceResolved { asset_id: T::AssetId },
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Asset has no divergence threshold set.
+		AssetNotMonitored,
+		/// Spot or oracle price of the asset is not available.
+		PriceNotAvailable,
+		/// Divergence of the asset is on the same side of the threshold as reported.
+		NothingToReport,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(_block_number: BlockNumberFor<T>) {
+			// limit the cases when the offchain worker run
+			if sp_io::offchain::is_validator() {
+				Self::check_prices();
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match source {
+				TransactionSource::External => {
+					// receiving unsigned transaction from network - disallow
+					return InvalidTransaction::Call.into();
+				}
+				TransactionSource::Local => {}   // produced by off-chain worker
+				TransactionSource::InBlock => {} // some other node included it in a block
+			};
+
+			let Call::report_divergence { asset_id } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			match Self::divergence_change(*asset_id) {
+				Ok(Some(_)) => ValidTransaction::with_tag_prefix("price-monitor")
+					.priority(UNSIGNED_TXS_PRIORITY)
+					.and_provides(asset_id)
+					.longevity(3)
+					.propagate(false)
+					.build(),
+				_ => InvalidTransaction::Stale.into(),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set or remove the divergence threshold of an asset.
+		///
+		/// Asset is monitored while it has a threshold. Removing the threshold removes the report of the asset.
+		///
+		/// Parameters:
+		/// - `origin`: `AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `threshold`: divergence threshold, `None` to stop monitoring the asset
+		///
+		/// Emits `ThresholdSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_threshold())]
+		pub fn set_threshold(origin: OriginFor<T>, asset_id: T::AssetId, threshold: Option<Permill>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if let Some(threshold) = threshold {
+				Thresholds::<T>::insert(asset_id, threshold);
+			} else {
+				Thresholds::<T>::remove(asset_id);
+				if DivergenceReports::<T>::take(asset_id).is_some() {
+					Self::deposit_event(Event::DivergenceResolved { asset_id });
+				}
+			}
+
+			Self::deposit_event(Event::ThresholdSet { asset_id, threshold });
+
+			Ok(())
+		}
+
+		/// Report that the divergence of an asset crossed its threshold or fell back below it.
+		///
+		/// Submitted by the offchain worker as an unsigned transaction. The divergence is computed on-chain,
+		/// the call fails if the state of the asset would not change.
+		///
+		/// Parameters:
+		/// - `origin`: unsigned origin
+		/// - `asset_id`: monitored asset
+		///
+		/// Emits `DivergenceReported` or `DivergenceResolved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::report_divergence())]
+		pub fn report_divergence(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			ensure_none(origin)?;
+
+			match Self::divergence_change(asset_id)?.ok_or(Error::<T>::NothingToReport)? {
+				DivergenceChange::Reported(report) => {
+					Self::deposit_event(Event::DivergenceReported {
+						asset_id,
+						spot_price: report.spot_price,
+						oracle_price: report.oracle_price,
+						divergence: report.divergence,
+					});
+					DivergenceReports::<T>::insert(asset_id, report);
+				}
+				DivergenceChange::Resolved => {
+					DivergenceReports::<T>::remove(asset_id);
+					Self::deposit_event(Event::DivergenceResolved { asset_id });
+				}
+			}
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the change of the state of a monitored asset, `None` if the divergence is on the same side of
+	/// the threshold as reported.
+	pub fn divergence_change(
+		asset_id: T::AssetId,
+	) -> Result<Option<DivergenceChange<BlockNumberFor<T>>>, DispatchError> {
+		let threshold = Self::threshold(asset_id).ok_or(Error::<T>::AssetNotMonitored)?;
+		let hub_asset_id = T::HubAssetId::get();
+
+		let spot_price =
+			T::SpotPriceProvider::spot_price(hub_asset_id, asset_id).ok_or(Error::<T>::PriceNotAvailable)?;
+		let (oracle_price, _) =
+			T::Oracle::get_price(hub_asset_id, asset_id, T::OraclePeriod::get(), T::OracleSource::get())
+				.map_err(|_| Error::<T>::PriceNotAvailable)?;
+		let oracle_price =
+			FixedU128::checked_from_rational(oracle_price.n, oracle_price.d).ok_or(Error::<T>::PriceNotAvailable)?;
+
+		let divergence = Self::divergence(spot_price, oracle_price);
+		let is_reported = DivergenceReports::<T>::contains_key(asset_id);
+
+		Ok(match (divergence > threshold, is_reported) {
+			(true, false) => Some(DivergenceChange::Reported(DivergenceReport {
+				spot_price,
+				oracle_price,
+				divergence,
+				reported_at: frame_system::Pallet::<T>::block_number(),
+			})),
+			(false, true) => Some(DivergenceChange::Resolved),
+			_ => None,
+		})
+	}
+
+	/// Difference of the spot price and the oracle price relative to the oracle price.
+	fn divergence(spot_price: FixedU128, oracle_price: FixedU128) -> Permill {
+		let difference = if spot_price >= oracle_price {
+			spot_price.saturating_sub(oracle_price)
+		} else {
+			oracle_price.saturating_sub(spot_price)
+		};
+		Permill::from_rational(difference.into_inner(), oracle_price.into_inner())
+	}
+
+	/// Submits reports of all monitored assets whose state changed.
+	fn check_prices() {
+		for asset_id in Thresholds::<T>::iter_keys() {
+			match Self::divergence_change(asset_id) {
+				Ok(Some(_)) => {
+					let call = Call::report_divergence { asset_id };
+					if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+						log::warn!(target: LOG_TARGET, "Submitting divergence report of {asset_id:?} failed.");
+					}
+				}
+				Ok(None) => {}
+				Err(e) => log::debug!(target: LOG_TARGET, "Checking price of {asset_id:?} failed: {e:?}"),
+			}
+		}
+	}
+}
+
+impl<T: Config> PriceDivergenceProvider<T::AssetId> for Pallet<T> {
+	fn divergence(asset_id: T::AssetId) -> Option<Permill> {
+		DivergenceReports::<T>::get(asset_id).map(|report| report.divergence)
+	}
+}
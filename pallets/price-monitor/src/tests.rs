@@ -0,0 +1,214 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod offchain;
+mod report;
+mod threshold;
+
+use crate as pallet_price_monitor;
+use crate::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::{Everything, Hooks},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::offchain::{
+	testing::PoolState, testing::TestOffchainExt, testing::TestTransactionPoolExt, OffchainDbExt, OffchainWorkerExt,
+	TransactionPoolExt,
+};
+use sp_runtime::BuildStorage;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u32;
+
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 3;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		PriceMonitor: pallet_price_monitor,
+	}
+);
+
+parameter_types! {
+	pub const HubAssetId: AssetId = LRNA;
+	pub const OmnipoolSource: Source = *b"omnipool";
+	pub const ShortPeriod: OraclePeriod = OraclePeriod::Short;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type HubAssetId = HubAssetId;
+	type SpotPriceProvider = MockSpotPrice;
+	type Oracle = MockOracle;
+	type OracleSource = OmnipoolSource;
+	type OraclePeriod = ShortPeriod;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+pub(crate) type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+thread_local! {
+	pub static SPOT_PRICES: RefCell<HashMap<AssetId, FixedU128>> = RefCell::new(HashMap::default());
+	pub static ORACLE_PRICES: RefCell<HashMap<AssetId, EmaPrice>> = RefCell::new(HashMap::default());
+}
+
+/// Sets spot and oracle price of an asset, denominated in the hub asset.
+pub fn set_prices(asset_id: AssetId, spot_price: FixedU128, oracle_price: FixedU128) {
+	SPOT_PRICES.with(|v| v.borrow_mut().insert(asset_id, spot_price));
+	ORACLE_PRICES.with(|v| {
+		v.borrow_mut()
+			.insert(asset_id, EmaPrice::new(oracle_price.into_inner(), FixedU128::DIV))
+	});
+}
+
+pub struct MockSpotPrice;
+
+impl SpotPriceProvider<AssetId> for MockSpotPrice {
+	type Price = FixedU128;
+
+	fn pair_exists(_asset_a: AssetId, asset_b: AssetId) -> bool {
+		SPOT_PRICES.with(|v| v.borrow().contains_key(&asset_b))
+	}
+
+	fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		assert_eq!(asset_a, LRNA);
+		SPOT_PRICES.with(|v| v.borrow().get(&asset_b).copied())
+	}
+}
+
+pub struct MockOracle;
+
+impl AggregatedPriceOracle<AssetId, u64, EmaPrice> for MockOracle {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+	) -> Result<(EmaPrice, u64), Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		assert_eq!(period, OraclePeriod::Short);
+		assert_eq!(source, OmnipoolSource::get());
+		ORACLE_PRICES
+			.with(|v| v.borrow().get(&asset_b).copied())
+			.map(|price| (price, 0))
+			.ok_or(())
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	thresholds: Vec<(AssetId, Permill)>,
+}
+
+impl ExtBuilder {
+	pub fn with_threshold(mut self, asset_id: AssetId, threshold: Permill) -> Self {
+		self.thresholds.push((asset_id, threshold));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		self.build_with_pool().0
+	}
+
+	pub fn build_with_pool(self) -> (sp_io::TestExternalities, Arc<parking_lot::RwLock<PoolState>>) {
+		SPOT_PRICES.with(|v| v.borrow_mut().clear());
+		ORACLE_PRICES.with(|v| v.borrow_mut().clear());
+
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for (asset_id, threshold) in self.thresholds {
+				Thresholds::<Test>::insert(asset_id, threshold);
+			}
+		});
+
+		let (offchain, _offchain_state) = TestOffchainExt::with_offchain_db(ext.offchain_db());
+		ext.register_extension(OffchainDbExt::new(offchain.clone()));
+		ext.register_extension(OffchainWorkerExt::new(offchain));
+		let (pool, pool_state) = TestTransactionPoolExt::new();
+		ext.register_extension(TransactionPoolExt::new(pool));
+
+		(ext, pool_state)
+	}
+}
+
+pub fn price(n: u128, d: u128) -> FixedU128 {
+	FixedU128::from_rational(n, d)
+}
+
+pub fn run_offchain_worker() {
+	PriceMonitor::offchain_worker(System::block_number());
+}
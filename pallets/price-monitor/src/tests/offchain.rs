@@ -0,0 +1,72 @@
+use super::*;
+use codec::Decode;
+use frame_support::assert_ok;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn offchain_worker_should_submit_report_when_divergence_crosses_threshold() {
+	let (mut ext, pool_state) = ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.with_threshold(DAI, Permill::from_percent(10))
+		.build_with_pool();
+	ext.execute_with(|| {
+		// Arrange
+		set_prices(DOT, price(12, 10), price(1, 1));
+		set_prices(DAI, price(1, 1), price(1, 1));
+
+		// Act
+		run_offchain_worker();
+
+		// Assert
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		assert_eq!(
+			tx.call,
+			RuntimeCall::PriceMonitor(crate::Call::report_divergence { asset_id: DOT })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_should_submit_resolution_when_reported_divergence_recovers() {
+	let (mut ext, pool_state) = ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build_with_pool();
+	ext.execute_with(|| {
+		// Arrange
+		set_prices(DOT, price(12, 10), price(1, 1));
+		assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+		set_prices(DOT, price(1, 1), price(1, 1));
+
+		// Act
+		run_offchain_worker();
+
+		// Assert
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::PriceMonitor(crate::Call::report_divergence { asset_id: DOT })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_should_not_submit_report_when_prices_are_healthy() {
+	let (mut ext, pool_state) = ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.with_threshold(DAI, Permill::from_percent(10))
+		.build_with_pool();
+	ext.execute_with(|| {
+		// Arrange
+		set_prices(DOT, price(105, 100), price(1, 1));
+
+		// Act
+		run_offchain_worker();
+
+		// Assert
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
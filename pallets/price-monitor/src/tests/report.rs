@@ -0,0 +1,226 @@
+use super::*;
+use frame_support::pallet_prelude::{
+	InvalidTransaction, TransactionSource, TransactionValidityError, ValidateUnsigned,
+};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn report_divergence_should_store_report_when_divergence_crosses_threshold() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_prices(DOT, price(12, 10), price(1, 1));
+
+			// Act
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+
+			// Assert
+			assert_eq!(
+				PriceMonitor::divergence_report(DOT),
+				Some(DivergenceReport {
+					spot_price: price(12, 10),
+					oracle_price: price(1, 1),
+					divergence: Permill::from_percent(20),
+					reported_at: 1,
+				})
+			);
+			System::assert_last_event(
+				Event::DivergenceReported {
+					asset_id: DOT,
+					spot_price: price(12, 10),
+					oracle_price: price(1, 1),
+					divergence: Permill::from_percent(20),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn report_divergence_should_report_divergence_when_spot_price_is_below_oracle_price() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_prices(DOT, price(5, 10), price(1, 1));
+
+			// Act
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+
+			// Assert
+			assert_eq!(
+				PriceMonitor::divergence_report(DOT).map(|report| report.divergence),
+				Some(Permill::from_percent(50))
+			);
+		});
+}
+
+#[test]
+fn report_divergence_should_fail_when_divergence_is_below_threshold() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			set_prices(DOT, price(105, 100), price(1, 1));
+
+			assert_noop!(
+				PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT),
+				Error::<Test>::NothingToReport
+			);
+		});
+}
+
+#[test]
+fn report_divergence_should_fail_when_divergence_is_already_reported() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			set_prices(DOT, price(12, 10), price(1, 1));
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+
+			assert_noop!(
+				PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT),
+				Error::<Test>::NothingToReport
+			);
+		});
+}
+
+#[test]
+fn report_divergence_should_resolve_report_when_divergence_falls_below_threshold() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_prices(DOT, price(12, 10), price(1, 1));
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+			set_prices(DOT, price(105, 100), price(1, 1));
+
+			// Act
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+
+			// Assert
+			assert_eq!(PriceMonitor::divergence_report(DOT), None);
+			System::assert_last_event(Event::DivergenceResolved { asset_id: DOT }.into());
+		});
+}
+
+#[test]
+fn report_divergence_should_fail_when_asset_is_not_monitored() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_prices(DOT, price(12, 10), price(1, 1));
+
+		assert_noop!(
+			PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT),
+			Error::<Test>::AssetNotMonitored
+		);
+	});
+}
+
+#[test]
+fn report_divergence_should_fail_when_price_is_not_available() {
+	ExtBuilder::default()
+		.with_threshold(DAI, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				PriceMonitor::report_divergence(RuntimeOrigin::none(), DAI),
+				Error::<Test>::PriceNotAvailable
+			);
+		});
+}
+
+#[test]
+fn report_divergence_should_fail_when_origin_is_signed() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			set_prices(DOT, price(12, 10), price(1, 1));
+
+			assert_noop!(
+				PriceMonitor::report_divergence(RuntimeOrigin::signed(1), DOT),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn validate_unsigned_should_accept_report_when_divergence_changed() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			set_prices(DOT, price(12, 10), price(1, 1));
+
+			assert_ok!(PriceMonitor::validate_unsigned(
+				TransactionSource::Local,
+				&Call::report_divergence { asset_id: DOT }
+			));
+		});
+}
+
+#[test]
+fn validate_unsigned_should_reject_report_when_nothing_to_report() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			set_prices(DOT, price(105, 100), price(1, 1));
+
+			assert_eq!(
+				PriceMonitor::validate_unsigned(TransactionSource::Local, &Call::report_divergence { asset_id: DOT }),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+			);
+		});
+}
+
+#[test]
+fn validate_unsigned_should_reject_report_from_network() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			set_prices(DOT, price(12, 10), price(1, 1));
+
+			assert_eq!(
+				PriceMonitor::validate_unsigned(
+					TransactionSource::External,
+					&Call::report_divergence { asset_id: DOT }
+				),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+			);
+		});
+}
+
+#[test]
+fn divergence_provider_should_return_divergence_of_reported_asset() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.with_threshold(DAI, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_prices(DOT, price(12, 10), price(1, 1));
+			set_prices(DAI, price(1, 1), price(1, 1));
+
+			// Act
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+
+			// Assert
+			assert_eq!(
+				<PriceMonitor as PriceDivergenceProvider<AssetId>>::divergence(DOT),
+				Some(Permill::from_percent(20))
+			);
+			assert_eq!(
+				<PriceMonitor as PriceDivergenceProvider<AssetId>>::divergence(DAI),
+				None
+			);
+		});
+}
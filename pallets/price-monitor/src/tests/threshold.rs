@@ -0,0 +1,76 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_threshold_should_store_threshold_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(PriceMonitor::set_threshold(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(Permill::from_percent(10))
+		));
+
+		// Assert
+		assert_eq!(PriceMonitor::threshold(DOT), Some(Permill::from_percent(10)));
+		System::assert_last_event(
+			Event::ThresholdSet {
+				asset_id: DOT,
+				threshold: Some(Permill::from_percent(10)),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_threshold_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PriceMonitor::set_threshold(RuntimeOrigin::signed(1), DOT, Some(Permill::from_percent(10))),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_threshold_should_remove_threshold_when_threshold_is_none() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(PriceMonitor::set_threshold(RuntimeOrigin::root(), DOT, None));
+
+			// Assert
+			assert_eq!(PriceMonitor::threshold(DOT), None);
+			System::assert_last_event(
+				Event::ThresholdSet {
+					asset_id: DOT,
+					threshold: None,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn set_threshold_should_resolve_report_when_threshold_is_removed() {
+	ExtBuilder::default()
+		.with_threshold(DOT, Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_prices(DOT, price(12, 10), price(1, 1));
+			assert_ok!(PriceMonitor::report_divergence(RuntimeOrigin::none(), DOT));
+
+			// Act
+			assert_ok!(PriceMonitor::set_threshold(RuntimeOrigin::root(), DOT, None));
+
+			// Assert
+			assert_eq!(PriceMonitor::divergence_report(DOT), None);
+			System::assert_has_event(Event::DivergenceResolved { asset_id: DOT }.into());
+		});
+}
@@ -0,0 +1,89 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_price_monitor`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-11-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --pallet=pallet-price-monitor
+// --extrinsic=*
+// --template=scripts/pallet-weight-template.hbs
+// --output=./weights/pallet_price_monitor.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_price_monitor.
+pub trait WeightInfo {
+	fn set_threshold() -> Weight;
+	fn report_divergence() -> Weight;
+}
+
+/// Weights for pallet_price_monitor using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `PriceMonitor::DivergenceReports` (r:1 w:1)
+	/// Proof: `PriceMonitor::DivergenceReports` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PriceMonitor::Thresholds` (r:0 w:1)
+	/// Proof: `PriceMonitor::Thresholds` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn set_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `376`
+		//  Estimated: `3517`
+		// Minimum execution time: 17_853_000 picoseconds.
+		Weight::from_parts(18_402_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PriceMonitor::Thresholds` (r:1 w:0)
+	/// Proof: `PriceMonitor::Thresholds` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:1 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `PriceMonitor::DivergenceReports` (r:1 w:1)
+	/// Proof: `PriceMonitor::DivergenceReports` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn report_divergence() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1462`
+		//  Estimated: `6294`
+		// Minimum execution time: 48_716_000 picoseconds.
+		Weight::from_parts(49_538_000, 6294)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -83,14 +83,13 @@ benchmarks! {
 		// The worst case is when referrer account is updated to the top tier in one call
 		// So we need to have enough RewardAsset in the pot. And give all the shares to the caller.
 		let top_tier_volume = T::LevelVolumeAndRewardPercentages::get(&Level::Tier4).0;
-		T::Currency::mint_into(T::RewardAsset::get(), &Pallet::<T>::pot_account_id(), 2 * top_tier_volume + T::SeedNativeAmount::get())?;
+		T::Currency::mint_into(T::RewardAsset::get(), &Pallet::<T>::pot_account_id(), 2 * top_tier_volume)?;
 		ReferrerShares::<T>::insert(caller.clone(), 1_000_000_000_000);
 		TraderShares::<T>::insert(caller.clone(), 1_000_000_000_000);
 		TotalShares::<T>::put(2_000_000_000_000);
+		Pallet::<T>::distribute_rewards(2 * top_tier_volume)?;
 	}: _(RawOrigin::Signed(caller.clone()))
 	verify {
-		let count = PendingConversions::<T>::count();
-		assert_eq!(count , 0);
 		let balance = T::Currency::balance(T::RewardAsset::get(), &caller);
 		assert!(balance > caller_balance);
 		let (level, total) = Referrer::<T>::get(&caller).expect("correct entry");
@@ -101,16 +100,103 @@ benchmarks! {
 	set_reward_percentage{
 		let referrer_percentage = Permill::from_percent(40);
 		let trader_percentage = Permill::from_percent(30);
-		let external_percentage = Permill::from_percent(30);
-	}: _(RawOrigin::Root, T::RewardAsset::get(), Level::Tier2, FeeDistribution{referrer: referrer_percentage, trader: trader_percentage, external: external_percentage})
+		let external_percentage = Permill::from_percent(20);
+		let lp_percentage = Permill::from_percent(10);
+	}: _(RawOrigin::Root, T::RewardAsset::get(), Level::Tier2, FeeDistribution{referrer: referrer_percentage, trader: trader_percentage, external: external_percentage, lp: lp_percentage})
 	verify {
 		let entry = Pallet::<T>::asset_rewards(T::RewardAsset::get(), Level::Tier2);
 		assert_eq!(entry, Some(FeeDistribution{
 			referrer: referrer_percentage,
 			trader: trader_percentage,
 			external: external_percentage,
+			lp: lp_percentage,
 		}));
 	}
+
+	claim_lp_rewards{
+		let (caller, asset_id, position_id) = T::BenchmarkHelper::prepare_liquidity_position();
+		Pallet::<T>::claim_lp_rewards(RawOrigin::Signed(caller.clone()).into(), position_id)?;
+		let caller_balance = T::Currency::balance(T::RewardAsset::get(), &caller);
+
+		// Worst case is when the caller is also referrer account and reaches the top tier.
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code)?;
+
+		let top_tier_volume = T::LevelVolumeAndRewardPercentages::get(&Level::Tier4).0;
+		T::Currency::mint_into(T::RewardAsset::get(), &Pallet::<T>::pot_account_id(), 2 * top_tier_volume)?;
+		let lp_account = Pallet::<T>::lp_account_id(asset_id.clone());
+		ReferrerShares::<T>::insert(caller.clone(), 1_000_000_000_000);
+		TraderShares::<T>::insert(lp_account.clone(), 1_000_000_000_000);
+		TotalShares::<T>::put(2_000_000_000_000);
+		Pallet::<T>::distribute_rewards(2 * top_tier_volume)?;
+		LpRewardPerShare::<T>::insert(asset_id, FixedU128::from(1_000));
+	}: _(RawOrigin::Signed(caller.clone()), position_id)
+	verify {
+		assert_eq!(LpRewardCheckpoints::<T>::get(position_id), Some(FixedU128::from(1_000)));
+		assert_eq!(TraderShares::<T>::get(lp_account), 0);
+		let balance = T::Currency::balance(T::RewardAsset::get(), &caller);
+		assert!(balance > caller_balance);
+	}
+
+	set_era_budget{
+		let budget = 1_000_000_000_000_000u128;
+		EraBudgetUsed::<T>::mutate(|usage| usage.exhausted = true);
+	}: _(RawOrigin::Root, Some(budget))
+	verify {
+		assert_eq!(Pallet::<T>::era_budget(), Some(budget));
+		assert!(!Pallet::<T>::era_budget_usage().exhausted);
+	}
+
+	set_trader_rebate_cap{
+		let cap = 1_000_000_000_000_000u128;
+	}: _(RawOrigin::Root, Some(cap))
+	verify {
+		assert_eq!(Pallet::<T>::trader_rebate_cap(), Some(cap));
+	}
+
+	set_reward_vesting{
+		let vesting = RewardVesting { cliff: 100, period: 10, period_count: 10 };
+	}: _(RawOrigin::Root, Some(vesting))
+	verify {
+		assert_eq!(Pallet::<T>::reward_vesting(), Some(vesting));
+	}
+
+	set_external_account{
+		let external: T::AccountId = account("external", 0, 0);
+	}: _(RawOrigin::Root, Level::Tier4, Some(external.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::level_external_account(Level::Tier4), Some(external));
+	}
+
+	set_opt_out{
+		let caller: T::AccountId = account("caller", 0, 1);
+	}: _(RawOrigin::Signed(caller.clone()), true)
+	verify {
+		assert!(Pallet::<T>::is_opted_out(&caller));
+	}
+
+	top_up_seed{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let amount = 1_000_000_000_000_000u128;
+		T::Currency::mint_into(T::RewardAsset::get(), &caller, 2 * amount)?;
+	}: _(RawOrigin::Signed(caller), amount)
+	verify {
+		assert_eq!(Pallet::<T>::reward_pot().seed, amount);
+	}
+
+	withdraw_seed{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let dest: T::AccountId = account("dest", 0, 1);
+		let amount = 1_000_000_000_000_000u128;
+		T::Currency::mint_into(T::RewardAsset::get(), &caller, 3 * amount)?;
+		Pallet::<T>::top_up_seed(RawOrigin::Signed(caller).into(), 2 * amount)?;
+	}: _(RawOrigin::Root, amount, dest.clone())
+	verify {
+		assert_eq!(Pallet::<T>::reward_pot().seed, amount);
+		assert_eq!(T::Currency::balance(T::RewardAsset::get(), &dest), amount);
+	}
 }
 
 #[cfg(test)]
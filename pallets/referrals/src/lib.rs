@@ -27,7 +27,50 @@
 //! Pallet also provides support for volume-based tiering. Referrer can reached higher Level based on the total amount generated by users of the referrer code.
 //! The higher level, the better reward.
 //!
-//! Rewards are accumulated in the pallet's account and if it is not RewardAsset, it is converted to RewardAsset prior to claim.
+//! Rewards are accumulated in the pallet's account and if it is not RewardAsset, it is converted to RewardAsset
+//! in `on_idle` or by calling `convert`. RewardAsset received by the pallet's account is distributed to all shares
+//! by increasing the reward per share. Claim converts assets pending conversion first and pays rewards of all shares
+//! of the claimer. Claimed shares are burned.
+//!
+//! Total amount of shares accrued per era can be limited by governance. Once the era budget is reached,
+//! trade fees are not processed until the next era.
+//!
+//! Trader shares accrued by a single account in a rebate period can be capped by governance. Once the cap is reached,
+//! the trader part of the fee is not taken until the next period, which blunts farming of self-rebates by wash trading.
+//!
+//! Part of the fee can also be distributed to liquidity providers of the traded asset. These shares accrue
+//! to a distribution account of the asset and LPs claim them proportionally to the shares of their positions.
+//! A position has to be registered first by calling `claim_lp_rewards`, only rewards accrued after
+//! the registration can be claimed.
+//!
+//! Governance can configure claimed rewards to be paid as a vesting schedule instead of an instant transfer.
+//! Rewards are then locked for the cliff period and released linearly afterwards.
+//!
+//! Referrer receives a non-transferable NFT badge for each level reached. Badges are minted in a frozen
+//! collection which is created when the first badge is minted.
+//!
+//! Rewards distributed and claimed in an era are recorded together with the reward per share at the start and
+//! the end of the era. Records of the last `EraRewardHistoryLength` eras are kept and can be queried by
+//! `era_rewards` to reconcile `Claimed` events against the pot.
+//!
+//! Rewards can be funded from outside of the trading activity, e.g. by a treasury spend approved by governance,
+//! via `fund_rewards`. Funded amount is treated as a trade fee paid in RewardAsset - shares worth the amount are
+//! minted to a designated distribution account and the amount is distributed to all shares. Funding the pot by
+//! a plain transfer is not accounted for.
+//!
+//! RewardAsset held by the pot is accounted in `RewardPot`:
+//!
+//! * seed - principal of the pot, it is not claimable. Existential deposits of claiming accounts are sponsored
+//!   from it. Seed can be topped up by anyone and withdrawn by governance.
+//! * distributed - amount distributed to shares which has not been claimed yet. Claims are limited by it.
+//! * undistributed - amount received while there were no shares, it is distributed with the next inflow.
+//!
+//! Transaction fees paid in non-native currency are processed the same way as trade fees.
+//! `TransactionFeePortion` of the fee is taken from the fee receiver by `process_transaction_fee`,
+//! so referrers earn on any fee-paying activity of their traders, not only on trades.
+//!
+//! Traders can opt out of the referral program by `set_opt_out`. No fee is taken from trades of opted out
+//! traders, so neither they nor their referrers accrue shares from them.
 //!
 //! ### Terminology
 //!
@@ -35,6 +78,7 @@
 //! * **Referrer:**  user that registered a code
 //! * **Trader:**  user that does a trade
 //! * **Reward Asset:**  id of an asset which rewards are paid in. Usually native asset.
+//! * **LP:**  liquidity provider of the traded asset
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -49,29 +93,36 @@ mod tests;
 pub mod traits;
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::{DispatchResult, Get};
-use frame_support::traits::fungibles::Mutate;
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
+use frame_support::traits::fungibles::{Inspect, Mutate};
+use frame_support::traits::tokens::nonfungibles::{Create as CreateNft, Inspect as InspectNft, Mutate as MutateNft};
 use frame_support::traits::tokens::Preservation;
 use frame_support::{defensive, ensure, transactional};
 use frame_system::{
 	ensure_signed,
 	pallet_prelude::{BlockNumberFor, OriginFor},
 };
+use hydradx_traits::correlation::CorrelationId;
+use hydradx_traits::deposit::ExistentialDepositSponsor;
 use hydradx_traits::price::PriceProvider;
+use hydradx_traits::vesting::{RewardVesting, VestedTransfer};
+use hydradx_traits::OnHookWeightUsed;
 use orml_traits::GetByKey;
 use scale_info::TypeInfo;
 use sp_core::bounded::BoundedVec;
 use sp_core::U256;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedDiv, One, SaturatedConversion, Saturating};
 use sp_runtime::Rounding;
 use sp_runtime::{
 	traits::{CheckedAdd, Zero},
-	ArithmeticError, DispatchError, Permill, RuntimeDebug,
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, RuntimeDebug,
 };
+use sp_std::vec::Vec;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use crate::traits::BenchmarkHelper;
+use crate::traits::{Convert, Freeze, LiquidityPositions};
 
 pub use pallet::*;
 
@@ -79,6 +130,7 @@ pub use weights::WeightInfo;
 
 pub type Balance = u128;
 pub type ReferralCode<S> = BoundedVec<u8, S>;
+pub type EraIndex = u32;
 
 /// Referrer level.
 /// Indicates current level of the referrer to determine which reward percentages are used.
@@ -131,6 +183,68 @@ pub struct FeeDistribution {
 	pub trader: Permill,
 	/// Percentage of the fee that goes to specific account given by `ExternalAccount` config parameter as reward.r
 	pub external: Permill,
+	/// Percentage of the fee that goes to liquidity providers of the traded asset.
+	pub lp: Permill,
+}
+
+/// Shares accrued by the referral program in an era.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct EraBudgetUsage {
+	/// Era the usage is tracked for.
+	pub era: EraIndex,
+	/// Total shares accrued in the era.
+	pub accrued: Balance,
+	/// Indicates that the era budget has been reached and no more shares are accrued in the era.
+	pub exhausted: bool,
+}
+
+/// Trader shares accrued by an account in a rebate period.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct TraderRebateUsage {
+	/// Rebate period the usage is tracked for.
+	pub period: u32,
+	/// Trader shares accrued in the period.
+	pub accrued: Balance,
+}
+
+/// Accounting of RewardAsset held by the reward pot.
+///
+/// Pot balance together with the existential deposits sponsored by the pot covers the sum of all parts.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PotAccounting {
+	/// Principal of the pot which is not claimable as rewards.
+	pub seed: Balance,
+	/// Amount distributed to shares which has not been claimed yet.
+	pub distributed: Balance,
+	/// Amount received while there were no shares to distribute it to.
+	pub undistributed: Balance,
+}
+
+impl PotAccounting {
+	/// Sum of all parts of the pot.
+	pub fn total(&self) -> Balance {
+		self.seed
+			.saturating_add(self.distributed)
+			.saturating_add(self.undistributed)
+	}
+}
+
+/// Reward accounting of an era.
+///
+/// `reward_per_share` moves from `start_reward_per_share` only by RewardAsset `distributed` in the era, so claims
+/// of the era can be verified against the pot without replaying its blocks.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct EraRewards {
+	/// Era the rewards are tracked for.
+	pub era: EraIndex,
+	/// Reward per share at the start of the era.
+	pub start_reward_per_share: FixedU128,
+	/// Reward per share at the end of the era, or the current reward per share if the era is ongoing.
+	pub reward_per_share: FixedU128,
+	/// Amount of RewardAsset distributed to shares in the era.
+	pub distributed: Balance,
+	/// Amount of RewardAsset claimed in the era.
+	pub claimed: Balance,
 }
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
@@ -148,7 +262,8 @@ impl<AssetId> AssetAmount<AssetId> {
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use crate::traits::Convert;
+	use crate::traits::{Convert, Freeze, LiquidityPositions};
+	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
 	use frame_support::sp_runtime::ArithmeticError;
 	use frame_support::traits::fungibles::{Inspect, Mutate};
@@ -156,7 +271,10 @@ pub mod pallet {
 	use hydra_dx_math::ema::EmaPrice;
 	use sp_runtime::traits::Zero;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -204,17 +322,64 @@ pub mod pallet {
 		type LevelVolumeAndRewardPercentages: GetByKey<Level, (Balance, FeeDistribution)>;
 
 		/// External account that receives some percentage of the fee. Usually something like staking.
+		/// Used for levels without an external account set by `set_external_account`.
 		type ExternalAccount: Get<Option<Self::AccountId>>;
 
-		/// Seed amount that was sent to the reward pot.
+		/// Length of an era (in blocks) used to track the referral program budget.
+		#[pallet::constant]
+		type EraLength: Get<BlockNumberFor<Self>>;
+
+		/// Number of past eras whose reward accounting is kept in `EraRewardHistory`.
+		#[pallet::constant]
+		type EraRewardHistoryLength: Get<u32>;
+
+		/// Length of a period (in blocks) the trader rebate cap is applied to.
+		#[pallet::constant]
+		type TraderRebatePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Portion of a transaction fee paid in non-native currency which is processed as a trade fee.
+		#[pallet::constant]
+		type TransactionFeePortion: Get<Permill>;
+
+		/// Identifier of a liquidity position.
+		type PositionId: Parameter + Copy + MaxEncodedLen;
+
+		/// Provider of liquidity positions eligible for LP rewards.
+		type LiquidityPositions: LiquidityPositions<Self::AccountId, Self::AssetId, Self::PositionId>;
+
+		/// Support for paying rewards as a vesting schedule. Requires RewardAsset to be the native asset.
+		type VestedTransfer: VestedTransfer<Self::AccountId, Balance>;
+
+		/// Sponsors existential deposit of accounts claiming rewards below the existential deposit.
+		/// Deposits are sponsored from the seed of the reward pot.
+		type ExistentialDepositSponsor: ExistentialDepositSponsor<Self::AccountId, Self::AssetId, Balance>;
+
+		/// Collection id type.
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// NFT collection of level badges.
 		#[pallet::constant]
-		type SeedNativeAmount: Get<u128>;
+		type BadgeCollectionId: Get<Self::CollectionId>;
+
+		/// Level badge identifier type.
+		type BadgeItemId: Member + Parameter + Default + Copy + HasCompact + AtLeast32BitUnsigned + MaxEncodedLen;
+
+		/// Provides ability to freeze a collection.
+		type Collections: Freeze<Self::AccountId, Self::CollectionId>;
+
+		/// Non fungible handling - create collection, mint badges.
+		type NFTHandler: MutateNft<Self::AccountId>
+			+ CreateNft<Self::AccountId>
+			+ InspectNft<Self::AccountId, ItemId = Self::BadgeItemId, CollectionId = Self::CollectionId>;
+
+		/// Handler of weight used by conversions in `on_idle`.
+		type HookWeightRecorder: OnHookWeightUsed;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
 		#[cfg(feature = "runtime-benchmarks")]
-		type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Balance>;
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Balance, Self::AccountId, Self::PositionId>;
 	}
 
 	/// Referral codes
@@ -252,6 +417,23 @@ pub mod pallet {
 	#[pallet::getter(fn total_shares)]
 	pub(super) type TotalShares<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Cumulative RewardAsset amount distributed per share.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_per_share)]
+	pub(super) type RewardPerShare<T: Config> = StorageValue<_, FixedU128, ValueQuery>;
+
+	/// Reward per share at which referrer shares of an account were accrued.
+	#[pallet::storage]
+	#[pallet::getter(fn referrer_reward_checkpoint)]
+	pub(super) type ReferrerRewardCheckpoints<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FixedU128, ValueQuery>;
+
+	/// Reward per share at which trader shares of an account were accrued.
+	#[pallet::storage]
+	#[pallet::getter(fn trader_reward_checkpoint)]
+	pub(super) type TraderRewardCheckpoints<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FixedU128, ValueQuery>;
+
 	/// Referer level and total accumulated rewards over time.
 	/// Maps referrer account to (Level, Balance). Level indicates current rewards and Balance is used to unlock next level.
 	/// Dev note: we use OptionQuery here because this helps to easily determine that an account if referrer account.
@@ -272,6 +454,81 @@ pub mod pallet {
 	#[pallet::getter(fn pending_conversions)]
 	pub(super) type PendingConversions<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AssetId, ()>;
 
+	/// Maximum amount of shares that can be accrued by all accounts in one era.
+	/// Shares are denominated in RewardAsset. No limit is applied if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn era_budget)]
+	pub(super) type EraBudget<T: Config> = StorageValue<_, Balance, OptionQuery>;
+
+	/// Shares accrued in the current era.
+	#[pallet::storage]
+	#[pallet::getter(fn era_budget_usage)]
+	pub(super) type EraBudgetUsed<T: Config> = StorageValue<_, EraBudgetUsage, ValueQuery>;
+
+	/// Maximum amount of trader shares that can be accrued by one account in one rebate period.
+	/// Shares are denominated in RewardAsset. No limit is applied if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn trader_rebate_cap)]
+	pub(super) type TraderRebateCap<T: Config> = StorageValue<_, Balance, OptionQuery>;
+
+	/// Trader shares accrued by an account in the current rebate period.
+	#[pallet::storage]
+	#[pallet::getter(fn trader_rebate_usage)]
+	pub(super) type TraderRebateUsed<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TraderRebateUsage, ValueQuery>;
+
+	/// Cumulative LP reward shares per LP share of an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn lp_reward_per_share)]
+	pub(super) type LpRewardPerShare<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FixedU128, ValueQuery>;
+
+	/// LP reward per share of position's asset at the time of the last claim of a registered position.
+	#[pallet::storage]
+	#[pallet::getter(fn lp_reward_checkpoint)]
+	pub(super) type LpRewardCheckpoints<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionId, FixedU128>;
+
+	/// Vesting schedule claimed rewards are paid with. Rewards are transferred instantly if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_vesting)]
+	pub(super) type RewardVestingSchedule<T: Config> = StorageValue<_, RewardVesting, OptionQuery>;
+
+	/// External account of a level. Overrides `ExternalAccount` for trades of the level.
+	#[pallet::storage]
+	#[pallet::getter(fn level_external_account)]
+	pub(super) type LevelExternalAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, Level, T::AccountId, OptionQuery>;
+
+	/// Level badges minted to referrers.
+	/// Maps referrer account and reached level to the badge item id.
+	#[pallet::storage]
+	#[pallet::getter(fn badge)]
+	pub(super) type Badges<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, Level, T::BadgeItemId, OptionQuery>;
+
+	/// Item id of the next level badge.
+	#[pallet::storage]
+	#[pallet::getter(fn next_badge_id)]
+	pub(super) type NextBadgeId<T: Config> = StorageValue<_, T::BadgeItemId, ValueQuery>;
+
+	/// Reward accounting of the last era with any distribution or claim.
+	#[pallet::storage]
+	#[pallet::getter(fn current_era_rewards)]
+	pub(super) type CurrentEraRewards<T: Config> = StorageValue<_, EraRewards, OptionQuery>;
+
+	/// Reward accounting of past eras.
+	/// Ring buffer of `EraRewardHistoryLength` slots, era is stored in the slot `era % EraRewardHistoryLength`.
+	#[pallet::storage]
+	pub(super) type EraRewardHistory<T: Config> = StorageMap<_, Twox64Concat, u32, EraRewards, OptionQuery>;
+
+	/// Traders who opted out of the referral program. No fee is taken from their trades.
+	#[pallet::storage]
+	pub(super) type OptedOutTraders<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Accounting of RewardAsset held by the reward pot.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_pot)]
+	pub(super) type RewardPot<T: Config> = StorageValue<_, PotAccounting, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -305,6 +562,54 @@ pub mod pallet {
 		},
 		/// Referrer reached new level.
 		LevelUp { who: T::AccountId, level: Level },
+		/// Era budget has been updated.
+		EraBudgetUpdated { budget: Option<Balance> },
+		/// Era budget has been reached. No more shares are accrued until the next era.
+		EraBudgetExhausted {
+			era: EraIndex,
+			accrued: Balance,
+			correlation_id: CorrelationId,
+		},
+		/// Trader rebate cap has been updated.
+		TraderRebateCapUpdated { cap: Option<Balance> },
+		/// Liquidity position has been registered for LP rewards.
+		LpPositionRegistered {
+			who: T::AccountId,
+			position_id: T::PositionId,
+			asset_id: T::AssetId,
+		},
+		/// LP reward shares of a position have been claimed.
+		LpRewardsClaimed {
+			who: T::AccountId,
+			position_id: T::PositionId,
+			asset_id: T::AssetId,
+			shares: Balance,
+		},
+		/// Vesting schedule of claimed rewards has been updated.
+		RewardVestingUpdated { vesting: Option<RewardVesting> },
+		/// External account of a level has been updated.
+		ExternalAccountUpdated {
+			level: Level,
+			account: Option<T::AccountId>,
+		},
+		/// Badge of a reached level has been minted to the referrer.
+		BadgeMinted {
+			who: T::AccountId,
+			level: Level,
+			item_id: T::BadgeItemId,
+		},
+		/// Trader opted out of or back into the referral program.
+		OptOutUpdated { who: T::AccountId, opted_out: bool },
+		/// Rewards have been funded and shares minted to the distribution account.
+		RewardsFunded {
+			source: T::AccountId,
+			distribution: T::AccountId,
+			amount: Balance,
+		},
+		/// Seed of the reward pot has been topped up.
+		SeedToppedUp { who: T::AccountId, amount: Balance },
+		/// Part of the seed of the reward pot has been withdrawn.
+		SeedWithdrawn { dest: T::AccountId, amount: Balance },
 	}
 
 	#[pallet::error]
@@ -338,6 +643,12 @@ pub mod pallet {
 		ConversionMinTradingAmountNotReached,
 		/// Zero amount received from conversion.
 		ConversionZeroAmountReceived,
+		/// Liquidity position does not exist or is not owned by the signer.
+		PositionNotFound,
+		/// Vesting schedule must have non-zero period and period count.
+		InvalidRewardVesting,
+		/// Seed in the pot is not sufficient. Seed used to sponsor existential deposits can't be withdrawn.
+		InsufficientSeed,
 	}
 
 	#[pallet::call]
@@ -425,6 +736,8 @@ pub mod pallet {
 
 		/// Convert accrued asset amount to reward currency.
 		///
+		/// Received RewardAsset amount is distributed to all shares.
+		///
 		/// Parameters:
 		/// - `asset_id`: Id of an asset to convert to RewardAsset.
 		///
@@ -445,6 +758,7 @@ pub mod pallet {
 			)?;
 
 			PendingConversions::<T>::remove(asset_id.clone());
+			Self::distribute_rewards(total_reward_asset)?;
 
 			Self::deposit_event(Event::Converted {
 				from: AssetAmount::new(asset_id, asset_balance),
@@ -456,106 +770,30 @@ pub mod pallet {
 
 		/// Claim accumulated rewards
 		///
-		/// IF there is any asset in the reward pot, all is converted to RewardCurrency first.
-		///
-		/// Reward amount is calculated based on the shares of the signer account.
+		/// Reward amount is calculated based on the shares of the signer account and reward per share distributed
+		/// since the shares were accrued. Assets pending conversion are converted first, so the claim includes
+		/// rewards of all fees accrued so far. Assets which fail to convert stay pending.
 		///
 		/// if the signer account is referrer account, total accumulated rewards is updated as well as referrer level if reached.
 		///
+		/// Weight of the vested or sponsored transfer is refunded when the rewards are not paid by it.
+		///
 		/// Emits `Claimed` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight( {
-			let c = PendingConversions::<T>::count() as u64;
-			let convert_weight = (<T as Config>::WeightInfo::convert()).saturating_mul(c);
 			let w  = <T as Config>::WeightInfo::claim_rewards();
-			let one_read = T::DbWeight::get().reads(1_u64);
-			w.saturating_add(convert_weight).saturating_add(one_read)
+			let convert_weight = <T as Config>::WeightInfo::convert()
+				.saturating_mul(PendingConversions::<T>::count() as u64)
+				.saturating_add(T::DbWeight::get().reads(1));
+			let vested_transfer_weight = T::VestedTransfer::vested_transfer_weight();
+			let sponsored_transfer_weight = T::ExistentialDepositSponsor::sponsored_transfer_weight();
+			w.saturating_add(convert_weight)
+				.saturating_add(vested_transfer_weight.max(sponsored_transfer_weight))
 		})]
-		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
+		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			for (asset_id, _) in PendingConversions::<T>::iter() {
-				let asset_balance = T::Currency::balance(asset_id.clone(), &Self::pot_account_id());
-				let r = T::Convert::convert(
-					Self::pot_account_id(),
-					asset_id.clone(),
-					T::RewardAsset::get(),
-					asset_balance,
-				);
-				if let Err(error) = r {
-					// We allow these errors to continue claiming as the current amount of asset that needed to be converted
-					// has very low impact on the rewards.
-					if error != Error::<T>::ConversionMinTradingAmountNotReached.into()
-						&& error != Error::<T>::ConversionZeroAmountReceived.into()
-					{
-						return Err(error);
-					}
-				}
-				PendingConversions::<T>::remove(asset_id);
-			}
-			let referrer_shares = ReferrerShares::<T>::take(&who);
-			let trader_shares = TraderShares::<T>::take(&who);
-			let total_shares = referrer_shares.saturating_add(trader_shares);
-			if total_shares == Balance::zero() {
-				return Ok(());
-			}
-
-			let reward_reserve = T::Currency::balance(T::RewardAsset::get(), &Self::pot_account_id());
-			let reward_reserve = reward_reserve.saturating_sub(T::SeedNativeAmount::get());
-			let share_issuance = TotalShares::<T>::get();
-
-			let convert_shares = |to_convert: Balance| -> Option<Balance> {
-				let shares_hp = U256::from(to_convert);
-				let reward_reserve_hp = U256::from(reward_reserve);
-				let share_issuance_hp = U256::from(share_issuance);
-				let r = shares_hp
-					.checked_mul(reward_reserve_hp)?
-					.checked_div(share_issuance_hp)?;
-				Balance::try_from(r).ok()
-			};
-
-			let referrer_rewards = convert_shares(referrer_shares).ok_or(ArithmeticError::Overflow)?;
-			let trader_rewards = convert_shares(trader_shares).ok_or(ArithmeticError::Overflow)?;
-			let total_rewards = referrer_rewards
-				.checked_add(trader_rewards)
-				.ok_or(ArithmeticError::Overflow)?;
-			ensure!(total_rewards <= reward_reserve, Error::<T>::IncorrectRewardCalculation);
-
-			// Make sure that we can transfer all the rewards if all shares withdrawn.
-			let keep_pot_alive = match total_shares != share_issuance {
-				true => Preservation::Preserve,
-				false => Preservation::Expendable,
-			};
-
-			T::Currency::transfer(
-				T::RewardAsset::get(),
-				&Self::pot_account_id(),
-				&who,
-				total_rewards,
-				keep_pot_alive,
-			)?;
-			TotalShares::<T>::mutate(|v| {
-				*v = v.saturating_sub(total_shares);
-			});
-			Referrer::<T>::mutate(who.clone(), |v| {
-				if let Some((level, total)) = v {
-					*total = total.saturating_add(referrer_rewards);
-					let new_level = level.increase::<T>(*total);
-					if *level != new_level {
-						*level = new_level;
-						Self::deposit_event(Event::LevelUp {
-							who: who.clone(),
-							level: new_level,
-						});
-					}
-				}
-			});
-
-			Self::deposit_event(Event::Claimed {
-				who,
-				referrer_rewards,
-				trade_rewards: trader_rewards,
-			});
-			Ok(())
+			let transfer_weight = Self::do_claim_rewards(who)?;
+			Ok(Some(<T as Config>::WeightInfo::claim_rewards().saturating_add(transfer_weight)).into())
 		}
 
 		/// Set asset reward percentages
@@ -583,6 +821,8 @@ pub mod pallet {
 					.checked_add(&rewards.trader)
 					.ok_or(Error::<T>::IncorrectRewardPercentage)?
 					.checked_add(&rewards.external)
+					.ok_or(Error::<T>::IncorrectRewardPercentage)?
+					.checked_add(&rewards.lp)
 					.is_some(),
 				Error::<T>::IncorrectRewardPercentage
 			);
@@ -597,6 +837,245 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Set maximum amount of shares that can be accrued in one era.
+		///
+		/// Once the budget is reached, trade fees are not processed anymore until the next era.
+		///
+		/// Parameters:
+		/// - `budget`: maximum shares per era. `None` removes the limit.
+		///
+		/// Emits `EraBudgetUpdated` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_era_budget())]
+		pub fn set_era_budget(origin: OriginFor<T>, budget: Option<Balance>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			EraBudget::<T>::set(budget);
+			// New budget is re-evaluated on next trade.
+			EraBudgetUsed::<T>::mutate(|usage| {
+				usage.exhausted = false;
+			});
+
+			Self::deposit_event(Event::EraBudgetUpdated { budget });
+			Ok(())
+		}
+
+		/// Claim LP rewards of a liquidity position.
+		///
+		/// First call registers the position. Only rewards accrued after the registration can be claimed.
+		///
+		/// Reward shares of the position are calculated from the current shares of the position and moved
+		/// from the distribution account of position's asset to the signer. All rewards of the signer are claimed then.
+		///
+		/// Parameters:
+		/// - `position_id`: Id of a liquidity position owned by the signer.
+		///
+		/// Emits `LpPositionRegistered` event when position is registered.
+		/// Emits `LpRewardsClaimed` and `Claimed` events when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight( {
+			let w  = <T as Config>::WeightInfo::claim_lp_rewards();
+			let convert_weight = <T as Config>::WeightInfo::convert()
+				.saturating_mul(PendingConversions::<T>::count() as u64)
+				.saturating_add(T::DbWeight::get().reads(1));
+			let vested_transfer_weight = T::VestedTransfer::vested_transfer_weight();
+			let sponsored_transfer_weight = T::ExistentialDepositSponsor::sponsored_transfer_weight();
+			w.saturating_add(convert_weight)
+				.saturating_add(vested_transfer_weight.max(sponsored_transfer_weight))
+		})]
+		pub fn claim_lp_rewards(origin: OriginFor<T>, position_id: T::PositionId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let (asset_id, position_shares) =
+				T::LiquidityPositions::position_shares(&who, position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+			let reward_per_share = LpRewardPerShare::<T>::get(asset_id.clone());
+			let Some(checkpoint) = LpRewardCheckpoints::<T>::get(position_id) else {
+				LpRewardCheckpoints::<T>::insert(position_id, reward_per_share);
+				Self::deposit_event(Event::LpPositionRegistered {
+					who,
+					position_id,
+					asset_id,
+				});
+				return Ok(Some(<T as Config>::WeightInfo::claim_lp_rewards()).into());
+			};
+
+			let lp_account = Self::lp_account_id(asset_id.clone());
+			let shares = reward_per_share
+				.saturating_sub(checkpoint)
+				.checked_mul_int(position_shares)
+				.ok_or(ArithmeticError::Overflow)?
+				// rounding must not allow to claim more than what is left in the distribution account
+				.min(TraderShares::<T>::get(&lp_account));
+			LpRewardCheckpoints::<T>::insert(position_id, reward_per_share);
+
+			if !shares.is_zero() {
+				TraderShares::<T>::mutate(&lp_account, |v| {
+					*v = v.saturating_sub(shares);
+				});
+				// shares keep the checkpoint they were accrued at by the distribution account
+				Self::add_trader_shares(&who, shares, TraderRewardCheckpoints::<T>::get(&lp_account));
+				Self::deposit_event(Event::LpRewardsClaimed {
+					who: who.clone(),
+					position_id,
+					asset_id,
+					shares,
+				});
+			}
+
+			let transfer_weight = Self::do_claim_rewards(who)?;
+			Ok(Some(<T as Config>::WeightInfo::claim_lp_rewards().saturating_add(transfer_weight)).into())
+		}
+
+		/// Set vesting schedule claimed rewards are paid with.
+		///
+		/// Parameters:
+		/// - `vesting`: cliff, period and number of periods of the schedule. `None` pays rewards instantly.
+		///
+		/// Emits `RewardVestingUpdated` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_vesting())]
+		pub fn set_reward_vesting(origin: OriginFor<T>, vesting: Option<RewardVesting>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if let Some(v) = vesting {
+				ensure!(v.is_valid(), Error::<T>::InvalidRewardVesting);
+			}
+
+			RewardVestingSchedule::<T>::set(vesting);
+
+			Self::deposit_event(Event::RewardVestingUpdated { vesting });
+			Ok(())
+		}
+
+		/// Set external account which receives the external part of the fee of trades of given level.
+		///
+		/// Parameters:
+		/// - `level`: level
+		/// - `account`: external account of the level. `None` falls back to `ExternalAccount`.
+		///
+		/// Emits `ExternalAccountUpdated` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_external_account())]
+		pub fn set_external_account(
+			origin: OriginFor<T>,
+			level: Level,
+			account: Option<T::AccountId>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			LevelExternalAccounts::<T>::set(level, account.clone());
+
+			Self::deposit_event(Event::ExternalAccountUpdated { level, account });
+			Ok(())
+		}
+
+		/// Opt out of or back into the referral program.
+		///
+		/// No fee is taken from trades of an opted out trader, so neither the trader nor the linked
+		/// referrer accrue shares from them. Shares accrued before opting out can still be claimed.
+		///
+		/// Parameters:
+		/// - `opt_out`: `true` to opt out, `false` to opt back in.
+		///
+		/// Emits `OptOutUpdated` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_opt_out())]
+		pub fn set_opt_out(origin: OriginFor<T>, opt_out: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if opt_out {
+				OptedOutTraders::<T>::insert(&who, ());
+			} else {
+				OptedOutTraders::<T>::remove(&who);
+			}
+
+			Self::deposit_event(Event::OptOutUpdated {
+				who,
+				opted_out: opt_out,
+			});
+			Ok(())
+		}
+
+		/// Top up seed of the reward pot.
+		///
+		/// `amount` of RewardAsset is transferred from the signer to the pot. Seed is not claimable as rewards.
+		///
+		/// Parameters:
+		/// - `amount`: amount of RewardAsset to add to the seed.
+		///
+		/// Emits `SeedToppedUp` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_seed())]
+		pub fn top_up_seed(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::transfer(
+				T::RewardAsset::get(),
+				&who,
+				&Self::pot_account_id(),
+				amount,
+				Preservation::Preserve,
+			)?;
+			RewardPot::<T>::mutate(|pot| {
+				pot.seed = pot.seed.saturating_add(amount);
+			});
+
+			Self::deposit_event(Event::SeedToppedUp { who, amount });
+			Ok(())
+		}
+
+		/// Withdraw part of the seed of the reward pot.
+		///
+		/// Seed used to sponsor existential deposits of claiming accounts can't be withdrawn.
+		/// Rewards distributed to shares are not affected.
+		///
+		/// Parameters:
+		/// - `amount`: amount of RewardAsset to withdraw from the seed.
+		/// - `dest`: account which receives the withdrawn amount.
+		///
+		/// Emits `SeedWithdrawn` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_seed())]
+		pub fn withdraw_seed(origin: OriginFor<T>, amount: Balance, dest: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let reward_asset = T::RewardAsset::get();
+			let pot = Self::pot_account_id();
+			let sponsored = T::ExistentialDepositSponsor::sponsored_total(reward_asset.clone(), &pot);
+			RewardPot::<T>::try_mutate(|accounting| -> DispatchResult {
+				let available = accounting.seed.saturating_sub(sponsored);
+				ensure!(amount <= available, Error::<T>::InsufficientSeed);
+				accounting.seed = accounting.seed.saturating_sub(amount);
+				Ok(())
+			})?;
+			T::Currency::transfer(reward_asset, &pot, &dest, amount, Preservation::Preserve)?;
+
+			Self::deposit_event(Event::SeedWithdrawn { dest, amount });
+			Ok(())
+		}
+
+		/// Set maximum amount of trader shares that can be accrued by one account in one rebate period.
+		///
+		/// Once an account reaches the cap, the trader part of the fee is not taken from its trades
+		/// until the next rebate period.
+		///
+		/// Parameters:
+		/// - `cap`: maximum trader shares per account and period. `None` removes the limit.
+		///
+		/// Emits `TraderRebateCapUpdated` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_trader_rebate_cap())]
+		pub fn set_trader_rebate_cap(origin: OriginFor<T>, cap: Option<Balance>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			TraderRebateCap::<T>::set(cap);
+
+			Self::deposit_event(Event::TraderRebateCapUpdated { cap });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -607,20 +1086,38 @@ pub mod pallet {
 				return Weight::zero();
 			}
 			let one_read = T::DbWeight::get().reads(1u64);
-			let max_converts = remaining_weight.saturating_sub(one_read).ref_time() / convert_weight.ref_time();
+			let recorder_weight = T::HookWeightRecorder::on_hook_weight_used_weight();
+			let fixed_weight = one_read.saturating_add(recorder_weight);
+			if remaining_weight.any_lt(fixed_weight) {
+				return Weight::zero();
+			}
+			let max_converts = remaining_weight.saturating_sub(fixed_weight).ref_time() / convert_weight.ref_time();
 
+			let mut converts = 0u64;
 			for asset_id in PendingConversions::<T>::iter_keys().take(max_converts as usize) {
 				let asset_balance = T::Currency::balance(asset_id.clone(), &Self::pot_account_id());
 				// remove the asset_id from PendingConversions even when the conversion fails
-				let _ = T::Convert::convert(
+				if let Ok(received) = T::Convert::convert(
 					Self::pot_account_id(),
 					asset_id.clone(),
 					T::RewardAsset::get(),
 					asset_balance,
-				);
+				) {
+					let _ = Self::distribute_rewards(received);
+				}
 				PendingConversions::<T>::remove(asset_id);
+				converts.saturating_inc();
 			}
-			convert_weight.saturating_mul(max_converts).saturating_add(one_read)
+
+			let used_weight = convert_weight.saturating_mul(converts).saturating_add(one_read);
+			T::HookWeightRecorder::on_hook_weight_used(used_weight, remaining_weight);
+
+			used_weight.saturating_add(recorder_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), DispatchError> {
+			migration::invariants::check::<T>()
 		}
 	}
 }
@@ -630,11 +1127,474 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Account which accrues shares distributed to LPs of an asset.
+	pub fn lp_account_id(asset_id: T::AssetId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(asset_id)
+	}
+
+	/// Amount of RewardAsset `who` would receive when claiming rewards now.
+	///
+	/// Fees which are still pending conversion to RewardAsset are not included.
+	pub fn pending_rewards(who: &T::AccountId) -> Balance {
+		let reward_per_share = RewardPerShare::<T>::get();
+		let referrer_rewards = reward_per_share
+			.saturating_sub(ReferrerRewardCheckpoints::<T>::get(who))
+			.saturating_mul_int(ReferrerShares::<T>::get(who));
+		let trader_rewards = reward_per_share
+			.saturating_sub(TraderRewardCheckpoints::<T>::get(who))
+			.saturating_mul_int(TraderShares::<T>::get(who));
+
+		referrer_rewards
+			.saturating_add(trader_rewards)
+			.min(Self::reward_reserve())
+	}
+
+	/// Amount of RewardAsset in the pot available for rewards.
+	pub(crate) fn reward_reserve() -> Balance {
+		RewardPot::<T>::get().distributed
+	}
+
+	/// Converts all assets pending conversion and distributes received RewardAsset to all shares.
+	///
+	/// Assets which fail to convert stay pending. Returns weight of the conversions.
+	fn convert_pending_assets() -> Weight {
+		let convert_weight = <T as Config>::WeightInfo::convert();
+		let pot = Self::pot_account_id();
+		let mut converts = 0u64;
+		let pending = PendingConversions::<T>::iter_keys().collect::<Vec<_>>();
+		for asset_id in pending {
+			let asset_balance = T::Currency::balance(asset_id.clone(), &pot);
+			converts.saturating_inc();
+			let Ok(received) = T::Convert::convert(pot.clone(), asset_id.clone(), T::RewardAsset::get(), asset_balance)
+			else {
+				continue;
+			};
+			PendingConversions::<T>::remove(asset_id.clone());
+			if Self::distribute_rewards(received).is_ok() {
+				Self::deposit_event(Event::Converted {
+					from: AssetAmount::new(asset_id, asset_balance),
+					to: AssetAmount::new(T::RewardAsset::get(), received),
+				});
+			}
+		}
+		convert_weight
+			.saturating_mul(converts)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+
+	/// Converts assets pending conversion and transfers rewards of all shares of `who`.
+	///
+	/// Returns weight of the conversions and of the vested or sponsored transfer the rewards were paid by.
+	/// Transfer weight is zero if the rewards were paid by a plain transfer or there was nothing to claim.
+	fn do_claim_rewards(who: T::AccountId) -> Result<Weight, DispatchError> {
+		let convert_weight = Self::convert_pending_assets();
+		let referrer_shares = ReferrerShares::<T>::take(&who);
+		let trader_shares = TraderShares::<T>::take(&who);
+		let referrer_checkpoint = ReferrerRewardCheckpoints::<T>::take(&who);
+		let trader_checkpoint = TraderRewardCheckpoints::<T>::take(&who);
+		let total_shares = referrer_shares.saturating_add(trader_shares);
+		if total_shares == Balance::zero() {
+			return Ok(convert_weight);
+		}
+
+		let reward_asset = T::RewardAsset::get();
+		let pot = Self::pot_account_id();
+		let reward_reserve = Self::reward_reserve();
+		let share_issuance = TotalShares::<T>::get();
+		let reward_per_share = RewardPerShare::<T>::get();
+
+		let referrer_rewards = reward_per_share
+			.saturating_sub(referrer_checkpoint)
+			.checked_mul_int(referrer_shares)
+			.ok_or(ArithmeticError::Overflow)?;
+		let trader_rewards = reward_per_share
+			.saturating_sub(trader_checkpoint)
+			.checked_mul_int(trader_shares)
+			.ok_or(ArithmeticError::Overflow)?;
+		let total_rewards = referrer_rewards
+			.checked_add(trader_rewards)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(total_rewards <= reward_reserve, Error::<T>::IncorrectRewardCalculation);
+
+		// Make sure that we can transfer all the rewards if all shares withdrawn.
+		let keep_pot_alive = match total_shares != share_issuance {
+			true => Preservation::Preserve,
+			false => Preservation::Expendable,
+		};
+
+		let transfer_weight = if let Some(vesting) = RewardVestingSchedule::<T>::get() {
+			T::VestedTransfer::vested_transfer(&pot, &who, total_rewards, vesting)?;
+			T::VestedTransfer::vested_transfer_weight()
+		} else if Self::can_sponsor_deposit(&who, total_rewards) {
+			T::ExistentialDepositSponsor::sponsored_transfer(reward_asset, &pot, &who, total_rewards)?;
+			T::ExistentialDepositSponsor::sponsored_transfer_weight()
+		} else {
+			T::Currency::transfer(reward_asset, &pot, &who, total_rewards, keep_pot_alive)?;
+			Weight::zero()
+		};
+		RewardPot::<T>::mutate(|pot| {
+			pot.distributed = pot.distributed.saturating_sub(total_rewards);
+		});
+		TotalShares::<T>::mutate(|v| {
+			*v = v.saturating_sub(total_shares);
+		});
+		let level_up = Referrer::<T>::mutate(who.clone(), |v| {
+			if let Some((level, total)) = v {
+				*total = total.saturating_add(referrer_rewards);
+				let new_level = level.increase::<T>(*total);
+				if *level != new_level {
+					let previous_level = *level;
+					*level = new_level;
+					Self::deposit_event(Event::LevelUp {
+						who: who.clone(),
+						level: new_level,
+					});
+					return Some((previous_level, new_level));
+				}
+			}
+			None
+		});
+		if let Some((previous_level, new_level)) = level_up {
+			Self::mint_badges(&who, previous_level, new_level)?;
+		}
+		Self::update_era_rewards(|rewards| rewards.claimed = rewards.claimed.saturating_add(total_rewards));
+
+		Self::deposit_event(Event::Claimed {
+			who,
+			referrer_rewards,
+			trade_rewards: trader_rewards,
+		});
+		Ok(convert_weight.saturating_add(transfer_weight))
+	}
+
+	/// Whether the existential deposit of `who` receiving `amount` of rewards can be sponsored by the pot.
+	///
+	/// Sponsored deposits are paid from the seed, which must keep covering all outstanding deposits and
+	/// the existential deposit of the pot.
+	fn can_sponsor_deposit(who: &T::AccountId, amount: Balance) -> bool {
+		let reward_asset = T::RewardAsset::get();
+		let pot = Self::pot_account_id();
+		let ed = T::Currency::minimum_balance(reward_asset.clone());
+		let to_sponsor =
+			ed.saturating_sub(T::Currency::total_balance(reward_asset.clone(), who).saturating_add(amount));
+		if to_sponsor.is_zero() {
+			return false;
+		}
+
+		let sponsored_total = T::ExistentialDepositSponsor::sponsored_total(reward_asset.clone(), &pot);
+		sponsored_total.saturating_add(to_sponsor).saturating_add(ed) <= RewardPot::<T>::get().seed
+			&& amount.saturating_add(to_sponsor) <= T::Currency::balance(reward_asset, &pot)
+	}
+
+	/// Distributes `amount` of RewardAsset received by the pot to all shares.
+	///
+	/// If there are no shares, the amount is kept undistributed and distributed with the next amount.
+	pub(crate) fn distribute_rewards(amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let share_issuance = TotalShares::<T>::get();
+		if share_issuance.is_zero() {
+			RewardPot::<T>::mutate(|pot| {
+				pot.undistributed = pot.undistributed.saturating_add(amount);
+			});
+			return Ok(());
+		}
+		let amount = amount.saturating_add(RewardPot::<T>::get().undistributed);
+
+		// rounding down must not allow to claim more than what has been distributed
+		let per_share = multiply_by_rational_with_rounding(amount, FixedU128::DIV, share_issuance, Rounding::Down)
+			.map(FixedU128::from_inner)
+			.ok_or(ArithmeticError::Overflow)?;
+		let reward_per_share = RewardPerShare::<T>::get()
+			.checked_add(&per_share)
+			.ok_or(ArithmeticError::Overflow)?;
+		Self::update_era_rewards(|rewards| {
+			rewards.reward_per_share = reward_per_share;
+			rewards.distributed = rewards.distributed.saturating_add(amount);
+		});
+		RewardPerShare::<T>::put(reward_per_share);
+		RewardPot::<T>::mutate(|pot| {
+			pot.distributed = pot.distributed.saturating_add(amount);
+			pot.undistributed = Balance::zero();
+		});
+		Ok(())
+	}
+
+	/// Updates reward accounting of the current era.
+	///
+	/// Reward accounting of the previous era is moved to the history when the first update of a new era happens.
+	/// Reward per share doesn't change without an update, so the previous era ends at the current reward per share.
+	fn update_era_rewards(f: impl FnOnce(&mut EraRewards)) {
+		let era = Self::current_era();
+		let reward_per_share = RewardPerShare::<T>::get();
+		let mut rewards = match CurrentEraRewards::<T>::get() {
+			Some(rewards) if rewards.era == era => rewards,
+			previous => {
+				let history_length = T::EraRewardHistoryLength::get();
+				if let Some(previous) = previous.filter(|_| !history_length.is_zero()) {
+					EraRewardHistory::<T>::insert(previous.era % history_length, previous);
+				}
+				EraRewards {
+					era,
+					start_reward_per_share: reward_per_share,
+					reward_per_share,
+					..Default::default()
+				}
+			}
+		};
+		f(&mut rewards);
+		CurrentEraRewards::<T>::put(rewards);
+	}
+
+	/// Reward accounting of `era`.
+	///
+	/// Returns `None` if there was no distribution nor claim in the era or if the era is older than the history.
+	pub fn era_rewards(era: EraIndex) -> Option<EraRewards> {
+		if let Some(rewards) = CurrentEraRewards::<T>::get().filter(|rewards| rewards.era == era) {
+			return Some(rewards);
+		}
+		let history_length = T::EraRewardHistoryLength::get();
+		if history_length.is_zero() {
+			return None;
+		}
+		EraRewardHistory::<T>::get(era % history_length).filter(|rewards| rewards.era == era)
+	}
+
+	/// Adds referrer `shares` accrued at `checkpoint` reward per share to `who`.
+	fn add_referrer_shares(who: &T::AccountId, shares: Balance, checkpoint: FixedU128) {
+		if shares.is_zero() {
+			return;
+		}
+		ReferrerShares::<T>::mutate(who, |v| {
+			ReferrerRewardCheckpoints::<T>::mutate(who, |c| {
+				*c = Self::merge_checkpoints(*v, *c, shares, checkpoint);
+			});
+			*v = v.saturating_add(shares);
+		});
+	}
+
+	/// Adds trader `shares` accrued at `checkpoint` reward per share to `who`.
+	fn add_trader_shares(who: &T::AccountId, shares: Balance, checkpoint: FixedU128) {
+		if shares.is_zero() {
+			return;
+		}
+		TraderShares::<T>::mutate(who, |v| {
+			TraderRewardCheckpoints::<T>::mutate(who, |c| {
+				*c = Self::merge_checkpoints(*v, *c, shares, checkpoint);
+			});
+			*v = v.saturating_add(shares);
+		});
+	}
+
+	/// Checkpoint of `shares` at `checkpoint` merged with `added` shares at `added_checkpoint`.
+	///
+	/// Weighted average of both checkpoints, rounded up so the merged shares can't claim more than before.
+	fn merge_checkpoints(
+		shares: Balance,
+		checkpoint: FixedU128,
+		added: Balance,
+		added_checkpoint: FixedU128,
+	) -> FixedU128 {
+		let total = U256::from(shares).saturating_add(U256::from(added));
+		if total.is_zero() {
+			return added_checkpoint;
+		}
+		let weighted = U256::from(shares)
+			.saturating_mul(U256::from(checkpoint.into_inner()))
+			.saturating_add(U256::from(added).saturating_mul(U256::from(added_checkpoint.into_inner())));
+		let (merged, remainder) = weighted.div_mod(total);
+		let merged = if remainder.is_zero() {
+			merged
+		} else {
+			merged.saturating_add(U256::one())
+		};
+		FixedU128::from_inner(u128::try_from(merged).unwrap_or(u128::MAX))
+	}
+
+	/// Mints badges of all levels above `from` up to and including `to` which `who` does not own yet.
+	fn mint_badges(who: &T::AccountId, from: Level, to: Level) -> DispatchResult {
+		let collection_id = T::BadgeCollectionId::get();
+		if T::NFTHandler::collection_owner(&collection_id).is_none() {
+			let pot = Self::pot_account_id();
+			T::NFTHandler::create_collection(&collection_id, &pot, &pot)?;
+			T::Collections::freeze_collection(pot, collection_id)?;
+		}
+
+		let mut level = from;
+		while level != to && !level.is_max_level() {
+			level = level.next_level();
+			if Badges::<T>::contains_key(who, level) {
+				continue;
+			}
+
+			let item_id = NextBadgeId::<T>::mutate(|id| -> Result<T::BadgeItemId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(&One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+			T::NFTHandler::mint_into(&T::BadgeCollectionId::get(), &item_id, who)?;
+			Badges::<T>::insert(who, level, item_id);
+
+			Self::deposit_event(Event::BadgeMinted {
+				who: who.clone(),
+				level,
+				item_id,
+			});
+		}
+		Ok(())
+	}
+
+	/// Index of the current referral budget era.
+	pub fn current_era() -> EraIndex {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::EraLength::get())
+			.unwrap_or_default()
+			.saturated_into()
+	}
+
+	/// Accounts `shares` against the budget of the current era.
+	///
+	/// Returns false if accruing the shares would exceed the era budget.
+	fn use_era_budget(shares: Balance) -> bool {
+		let budget = EraBudget::<T>::get();
+		let era = Self::current_era();
+		EraBudgetUsed::<T>::mutate(|usage| {
+			if usage.era != era {
+				*usage = EraBudgetUsage {
+					era,
+					..Default::default()
+				};
+			}
+			if usage.exhausted {
+				return false;
+			}
+			let accrued = usage.accrued.saturating_add(shares);
+			match budget {
+				Some(budget) if accrued > budget => {
+					usage.exhausted = true;
+					Self::deposit_event(Event::EraBudgetExhausted {
+						era,
+						accrued: usage.accrued,
+						correlation_id: CorrelationId::current::<T>(),
+					});
+					false
+				}
+				Some(budget) => {
+					usage.accrued = accrued;
+					if accrued == budget {
+						usage.exhausted = true;
+						Self::deposit_event(Event::EraBudgetExhausted {
+							era,
+							accrued,
+							correlation_id: CorrelationId::current::<T>(),
+						});
+					}
+					true
+				}
+				None => {
+					usage.accrued = accrued;
+					true
+				}
+			}
+		})
+	}
+
+	/// Index of the current trader rebate period.
+	pub fn current_rebate_period() -> u32 {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::TraderRebatePeriod::get())
+			.unwrap_or_default()
+			.saturated_into()
+	}
+
+	/// Trader shares `who` can still accrue in the current rebate period. `None` if no cap is set.
+	fn remaining_trader_rebate(who: &T::AccountId) -> Option<Balance> {
+		let cap = TraderRebateCap::<T>::get()?;
+		let usage = TraderRebateUsed::<T>::get(who);
+		if usage.period != Self::current_rebate_period() {
+			return Some(cap);
+		}
+		Some(cap.saturating_sub(usage.accrued))
+	}
+
+	/// Accounts trader `shares` of `who` against the rebate cap of the current period.
+	fn use_trader_rebate(who: &T::AccountId, shares: Balance) {
+		if shares.is_zero() || TraderRebateCap::<T>::get().is_none() {
+			return;
+		}
+		let period = Self::current_rebate_period();
+		TraderRebateUsed::<T>::mutate(who, |usage| {
+			if usage.period != period {
+				*usage = TraderRebateUsage {
+					period,
+					..Default::default()
+				};
+			}
+			usage.accrued = usage.accrued.saturating_add(shares);
+		});
+	}
+
 	pub(crate) fn normalize_code(code: ReferralCode<T::CodeLength>) -> ReferralCode<T::CodeLength> {
 		let r = code.into_inner().iter().map(|v| v.to_ascii_uppercase()).collect();
 		ReferralCode::<T::CodeLength>::truncate_from(r)
 	}
 
+	/// Returns `true` if `who` opted out of the referral program.
+	pub fn is_opted_out(who: &T::AccountId) -> bool {
+		OptedOutTraders::<T>::contains_key(who)
+	}
+
+	/// Fund rewards with `amount` of RewardAsset from `source`.
+	///
+	/// Shares worth the `amount` are minted to the `distribution` account and the `amount` is distributed
+	/// to all shares, the same way as a trade fee paid in RewardAsset. Funded shares are not counted
+	/// against the era budget.
+	#[transactional]
+	pub fn fund_rewards(source: &T::AccountId, distribution: &T::AccountId, amount: Balance) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+		T::Currency::transfer(
+			T::RewardAsset::get(),
+			source,
+			&Self::pot_account_id(),
+			amount,
+			Preservation::Expendable,
+		)?;
+
+		TotalShares::<T>::mutate(|v| {
+			*v = v.saturating_add(amount);
+		});
+		Self::add_trader_shares(distribution, amount, RewardPerShare::<T>::get());
+		Self::distribute_rewards(amount)?;
+
+		Self::deposit_event(Event::RewardsFunded {
+			source: source.clone(),
+			distribution: distribution.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Process transaction fee paid in non-native currency.
+	/// `source`: account the fee has been deposited to
+	/// `payer`: account that paid the fee
+	///
+	/// `TransactionFeePortion` of the `fee` is processed as a trade fee of the `payer`.
+	/// Returns used amount on success.
+	pub fn process_transaction_fee(
+		source: T::AccountId,
+		payer: T::AccountId,
+		asset_id: T::AssetId,
+		fee: Balance,
+	) -> Result<Balance, DispatchError> {
+		let amount = T::TransactionFeePortion::get().mul_floor(fee);
+		if amount.is_zero() {
+			return Ok(Balance::zero());
+		}
+		Self::process_trade_fee(source, payer, asset_id, amount)
+	}
+
 	/// Process trader fee
 	/// `source`: account to take the fee from
 	/// `trader`: account that does the trade
@@ -647,6 +1607,10 @@ impl<T: Config> Pallet<T> {
 		asset_id: T::AssetId,
 		amount: Balance,
 	) -> Result<Balance, DispatchError> {
+		if Self::is_opted_out(&trader) {
+			return Ok(Balance::zero());
+		}
+
 		let Some(price) = T::PriceProvider::get_price(T::RewardAsset::get(), asset_id.clone()) else {
 			// no price, no fun.
 			return Ok(Balance::zero());
@@ -669,29 +1633,40 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or_else(|| T::LevelVolumeAndRewardPercentages::get(&level).1);
 
 		// Rewards
-		let external_account = T::ExternalAccount::get();
+		let external_account = Self::level_external_account(level).or_else(T::ExternalAccount::get);
 		let referrer_reward = if ref_account.is_some() {
 			rewards.referrer.mul_floor(amount)
 		} else {
 			0
 		};
-		let trader_reward = rewards.trader.mul_floor(amount);
+		let mut trader_reward = rewards.trader.mul_floor(amount);
+		if let Some(remaining) = Self::remaining_trader_rebate(&trader) {
+			// Take only the part of the trader reward which fits into the rebate cap.
+			let max_trader_reward =
+				multiply_by_rational_with_rounding(remaining, price.d, price.n, Rounding::Down).unwrap_or(Balance::MAX);
+			trader_reward = trader_reward.min(max_trader_reward);
+		}
 		let external_reward = if external_account.is_some() {
 			rewards.external.mul_floor(amount)
 		} else {
 			0
 		};
+		// LP rewards are distributed only if there is any liquidity of the asset provided.
+		let total_lp_shares = if !rewards.lp.is_zero() {
+			T::LiquidityPositions::total_shares(asset_id.clone())
+		} else {
+			0
+		};
+		let lp_reward = if !total_lp_shares.is_zero() {
+			rewards.lp.mul_floor(amount)
+		} else {
+			0
+		};
 		let total_taken = referrer_reward
 			.saturating_add(trader_reward)
-			.saturating_add(external_reward);
+			.saturating_add(external_reward)
+			.saturating_add(lp_reward);
 		ensure!(total_taken <= amount, Error::<T>::IncorrectRewardCalculation);
-		T::Currency::transfer(
-			asset_id.clone(),
-			&source,
-			&Self::pot_account_id(),
-			total_taken,
-			Preservation::Preserve,
-		)?;
 
 		let referrer_shares = if ref_account.is_some() {
 			multiply_by_rational_with_rounding(referrer_reward, price.n, price.d, Rounding::Down)
@@ -708,35 +1683,54 @@ impl<T: Config> Pallet<T> {
 			0
 		};
 
+		let lp_shares = multiply_by_rational_with_rounding(lp_reward, price.n, price.d, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let total_shares = referrer_shares
+			.saturating_add(trader_shares)
+			.saturating_add(external_shares)
+			.saturating_add(lp_shares);
+		if !Self::use_era_budget(total_shares) {
+			return Ok(Balance::zero());
+		}
+		Self::use_trader_rebate(&trader, trader_shares);
+
+		T::Currency::transfer(
+			asset_id.clone(),
+			&source,
+			&Self::pot_account_id(),
+			total_taken,
+			Preservation::Preserve,
+		)?;
+
 		TotalShares::<T>::mutate(|v| {
-			*v = v.saturating_add(
-				referrer_shares
-					.saturating_add(trader_shares)
-					.saturating_add(external_shares),
-			);
+			*v = v.saturating_add(total_shares);
 		});
 
+		// new shares are entitled only to rewards distributed from now on
+		let reward_per_share = RewardPerShare::<T>::get();
 		if let Some(acc) = ref_account {
-			ReferrerShares::<T>::mutate(acc, |v| {
-				*v = v.saturating_add(referrer_shares);
-			});
+			Self::add_referrer_shares(&acc, referrer_shares, reward_per_share);
 		}
-
-		// don't store zero values
-		if !trader_shares.is_zero() {
-			TraderShares::<T>::mutate(trader, |v| {
-				*v = v.saturating_add(trader_shares);
-			});
+		Self::add_trader_shares(&trader, trader_shares, reward_per_share);
+		if let Some(acc) = external_account {
+			Self::add_trader_shares(&acc, external_shares, reward_per_share);
 		}
 
-		if let Some(acc) = external_account {
-			TraderShares::<T>::mutate(acc, |v| {
-				*v = v.saturating_add(external_shares);
-			});
+		if !lp_shares.is_zero() {
+			let per_share =
+				FixedU128::checked_from_rational(lp_shares, total_lp_shares).ok_or(ArithmeticError::Overflow)?;
+			LpRewardPerShare::<T>::try_mutate(asset_id.clone(), |v| -> DispatchResult {
+				*v = v.checked_add(&per_share).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+			Self::add_trader_shares(&Self::lp_account_id(asset_id.clone()), lp_shares, reward_per_share);
 		}
 
 		if asset_id != T::RewardAsset::get() {
 			PendingConversions::<T>::insert(asset_id, ());
+		} else {
+			Self::distribute_rewards(total_taken)?;
 		}
 
 		Ok(total_taken)
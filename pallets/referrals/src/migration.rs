@@ -14,9 +14,17 @@
 // limitations under the License.
 
 use super::*;
-use frame_support::{traits::Get, weights::Weight};
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
 use hex_literal::hex;
 use sp_core::crypto::AccountId32;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
 
 pub fn preregister_parachain_codes<T: Config>() -> Weight
 where
@@ -112,3 +120,243 @@ where
 	}
 	weight
 }
+
+pub mod versioned {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+
+	/// All storage migrations of the pallet in order, with invariants checked before and after.
+	///
+	/// Each migration is executed only if the on-chain storage version matches its source version, so the pipeline
+	/// can stay in the runtime across upgrades. A new migration is added to the end of the tuple together with
+	/// the bump of the pallet storage version.
+	///
+	/// `SeedAmount` is the seed amount of the reward pot before the pot accounting was introduced.
+	pub type MigrateToLatest<T, SeedAmount> =
+		CheckInvariants<T, (V0ToV1<T>, V1ToV2<T, SeedAmount>, V2ToV3<T, SeedAmount>)>;
+
+	pub type V0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		v1::VersionUncheckedMigrateToV1<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	pub type V1ToV2<T, SeedAmount> = VersionedMigration<
+		1,
+		2,
+		v2::VersionUncheckedMigrateToV2<T, SeedAmount>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	pub type V2ToV3<T, SeedAmount> = VersionedMigration<
+		2,
+		3,
+		v3::VersionUncheckedMigrateToV3<T, SeedAmount>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Sum of rewards of all shares and number of accounts with shares.
+pub(crate) fn total_pending_rewards<T: Config>() -> (Balance, u64) {
+	let reward_per_share = RewardPerShare::<T>::get();
+	let mut count: u64 = 0;
+	let mut total = Balance::zero();
+	for (who, shares) in ReferrerShares::<T>::iter() {
+		count.saturating_inc();
+		total.saturating_accrue(
+			reward_per_share
+				.saturating_sub(ReferrerRewardCheckpoints::<T>::get(who))
+				.saturating_mul_int(shares),
+		);
+	}
+	for (who, shares) in TraderShares::<T>::iter() {
+		count.saturating_inc();
+		total.saturating_accrue(
+			reward_per_share
+				.saturating_sub(TraderRewardCheckpoints::<T>::get(who))
+				.saturating_mul_int(shares),
+		);
+	}
+	(total, count)
+}
+
+/// Runs migrations `M` and checks invariants of the pallet storage before and after them in try-runtime.
+pub struct CheckInvariants<T, M>(PhantomData<(T, M)>);
+
+impl<T: Config, M: OnRuntimeUpgrade> OnRuntimeUpgrade for CheckInvariants<T, M> {
+	fn on_runtime_upgrade() -> Weight {
+		M::on_runtime_upgrade()
+	}
+
+	// pre_upgrade and post_upgrade are not supported by tuples of migrations, so the checks wrap
+	// try_on_runtime_upgrade instead.
+	#[cfg(feature = "try-runtime")]
+	fn try_on_runtime_upgrade(checks: bool) -> Result<Weight, TryRuntimeError> {
+		if checks {
+			invariants::check::<T>()?;
+		}
+		let weight = M::try_on_runtime_upgrade(checks)?;
+		if checks {
+			invariants::check::<T>()?;
+		}
+		Ok(weight)
+	}
+}
+
+/// Invariants of the pallet storage.
+#[cfg(any(feature = "try-runtime", test))]
+pub mod invariants {
+	use super::*;
+
+	pub fn check<T: Config>() -> Result<(), DispatchError> {
+		total_shares_equal_account_shares::<T>()?;
+		// pot accounting is initialized by the migration to v3
+		if Pallet::<T>::on_chain_storage_version() < 3 {
+			return Ok(());
+		}
+		pot_covers_rewards::<T>()?;
+		pot_balance_covers_accounting::<T>()
+	}
+
+	/// Total share issuance equals the sum of referrer and trader shares of all accounts.
+	pub fn total_shares_equal_account_shares<T: Config>() -> Result<(), DispatchError> {
+		let referrer_shares =
+			ReferrerShares::<T>::iter_values().fold(Balance::zero(), |acc, shares| acc.saturating_add(shares));
+		let trader_shares =
+			TraderShares::<T>::iter_values().fold(Balance::zero(), |acc, shares| acc.saturating_add(shares));
+
+		ensure!(
+			TotalShares::<T>::get() == referrer_shares.saturating_add(trader_shares),
+			"Total shares don't equal the sum of referrer and trader shares"
+		);
+		Ok(())
+	}
+
+	/// RewardAsset distributed and not claimed yet covers rewards of all shares.
+	pub fn pot_covers_rewards<T: Config>() -> Result<(), DispatchError> {
+		ensure!(
+			total_pending_rewards::<T>().0 <= Pallet::<T>::reward_reserve(),
+			"Pot balance doesn't cover rewards of all shares"
+		);
+		Ok(())
+	}
+
+	/// RewardAsset in the pot, including existential deposits sponsored by the pot, covers all parts of the pot
+	/// accounting.
+	pub fn pot_balance_covers_accounting<T: Config>() -> Result<(), DispatchError> {
+		let reward_asset = T::RewardAsset::get();
+		let pot = Pallet::<T>::pot_account_id();
+		let balance = T::Currency::balance(reward_asset.clone(), &pot)
+			.saturating_add(T::ExistentialDepositSponsor::sponsored_total(reward_asset, &pot));
+		ensure!(
+			RewardPot::<T>::get().total() <= balance,
+			"Pot balance doesn't cover the pot accounting"
+		);
+		Ok(())
+	}
+}
+
+// This migration adds LP percentage to asset rewards. It is set to zero for existing entries.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldFeeDistribution {
+		pub referrer: Permill,
+		pub trader: Permill,
+		pub external: Permill,
+	}
+
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			AssetRewards::<T>::translate_values::<OldFeeDistribution, _>(|old| {
+				count.saturating_inc();
+				Some(FeeDistribution {
+					referrer: old.referrer,
+					trader: old.trader,
+					external: old.external,
+					lp: Permill::zero(),
+				})
+			});
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
+
+// This migration initializes reward per share. RewardAsset in the pot (except the seed amount) has been distributed
+// to all shares at claim so far. It is distributed to all existing shares now, checkpoints of the shares are zero.
+pub mod v2 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateToV2<T, SeedAmount>(PhantomData<(T, SeedAmount)>);
+
+	impl<T: Config, SeedAmount: Get<Balance>> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T, SeedAmount> {
+		fn on_runtime_upgrade() -> Weight {
+			let reward_reserve = T::Currency::balance(T::RewardAsset::get(), &Pallet::<T>::pot_account_id())
+				.saturating_sub(SeedAmount::get());
+			if Pallet::<T>::distribute_rewards(reward_reserve).is_err() {
+				defensive!("Failed to initialize reward per share");
+			}
+			T::DbWeight::get().reads_writes(3, 1)
+		}
+	}
+}
+
+// This migration initializes the pot accounting. Seed amount was given by a constant so far. Rewards of all shares
+// are distributed and not claimed yet, the rest of the pot except the seed is kept undistributed.
+//
+// Rewards are summed over all accounts with shares in a single block. `try-runtime` checks that it fits into
+// the maximum block weight.
+pub mod v3 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateToV3<T, SeedAmount>(PhantomData<(T, SeedAmount)>);
+
+	impl<T: Config, SeedAmount> VersionUncheckedMigrateToV3<T, SeedAmount> {
+		/// Each account with shares is read together with its reward checkpoint.
+		fn migration_weight(accounts: u64) -> Weight {
+			T::DbWeight::get().reads_writes(accounts.saturating_mul(2).saturating_add(3), 1)
+		}
+	}
+
+	impl<T: Config, SeedAmount: Get<Balance>> OnRuntimeUpgrade for VersionUncheckedMigrateToV3<T, SeedAmount> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let accounts = ReferrerShares::<T>::iter_keys()
+				.count()
+				.saturating_add(TraderShares::<T>::iter_keys().count()) as u64;
+			let weight = Self::migration_weight(accounts);
+			let max_weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+			ensure!(
+				weight.all_lte(max_weight),
+				TryRuntimeError::Other("summing rewards of all shares does not fit into a block")
+			);
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let reward_asset = T::RewardAsset::get();
+			let pot = Pallet::<T>::pot_account_id();
+			let balance = T::Currency::balance(reward_asset.clone(), &pot)
+				.saturating_add(T::ExistentialDepositSponsor::sponsored_total(reward_asset, &pot));
+
+			let seed = SeedAmount::get();
+			let (distributed, accounts) = total_pending_rewards::<T>();
+			let undistributed = balance.saturating_sub(seed).saturating_sub(distributed);
+			RewardPot::<T>::put(PotAccounting {
+				seed,
+				distributed,
+				undistributed,
+			});
+
+			Self::migration_weight(accounts)
+		}
+	}
+}
@@ -15,14 +15,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod badges;
+mod budget;
 mod claim;
 mod convert;
+mod era_rewards;
+mod external_account;
 mod flow;
+mod fund;
 mod link;
+mod lp_rewards;
+mod migration;
 mod mock_amm;
+mod opt_out;
+mod rebate_cap;
 mod register;
+mod seed;
 mod tiers;
 mod trade_fee;
+mod transaction_fee;
+mod vesting;
 
 use crate as pallet_referrals;
 use crate::*;
@@ -33,15 +45,18 @@ use std::collections::HashMap;
 use frame_support::{
 	assert_noop, assert_ok, construct_runtime, parameter_types,
 	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup, Zero},
-	traits::Everything,
+	traits::{tokens::nonfungibles, Everything},
+	weights::Weight,
 	PalletId,
 };
 use sp_core::H256;
 
 use crate::tests::mock_amm::{Hooks, TradeResult};
-use crate::traits::Convert;
+use crate::traits::{Convert, Freeze, LiquidityPositions};
 use frame_system::EnsureRoot;
 use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::deposit::ExistentialDepositSponsor;
+use hydradx_traits::vesting::{RewardVesting, VestedTransfer};
 use orml_traits::MultiCurrency;
 use orml_traits::{parameter_type_with_key, MultiCurrencyExtended};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
@@ -65,12 +80,21 @@ pub const TREASURY: AccountId = 400;
 
 pub(crate) const INITIAL_ALICE_BALANCE: Balance = 1_000 * ONE;
 
+pub(crate) const VESTED_TRANSFER_WEIGHT: Weight = Weight::from_parts(200_000_000, 0);
+pub(crate) const SPONSORED_TRANSFER_WEIGHT: Weight = Weight::from_parts(100_000_000, 0);
+
 thread_local! {
 	pub static CONVERSION_RATE: RefCell<HashMap<(AssetId,AssetId), EmaPrice>> = RefCell::new(HashMap::default());
 	pub static TIER_VOLUME: RefCell<HashMap<Level, Option<Balance>>> = RefCell::new(HashMap::default());
 	pub static TIER_REWARDS: RefCell<HashMap<Level, FeeDistribution>> = RefCell::new(HashMap::default());
 	pub static SEED_AMOUNT: RefCell<Balance> = RefCell::new(Balance::zero());
 	pub static EXTERNAL_ACCOUNT: RefCell<Option<AccountId>> = const { RefCell::new(None) };
+	pub static LP_POSITIONS: RefCell<HashMap<u32, (AccountId, AssetId, Balance)>> = RefCell::new(HashMap::default());
+	pub static VESTED_TRANSFERS: RefCell<Vec<(AccountId, Balance, RewardVesting)>> = const { RefCell::new(vec![]) };
+	pub static BADGE_COLLECTIONS: RefCell<HashMap<u128, (AccountId, bool)>> = RefCell::new(HashMap::default());
+	pub static BADGES: RefCell<HashMap<(u128, u32), AccountId>> = RefCell::new(HashMap::default());
+	pub static EXISTENTIAL_DEPOSIT: RefCell<Balance> = const { RefCell::new(0) };
+	pub static SPONSORED_DEPOSITS: RefCell<HashMap<(AssetId, AccountId), Balance>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -89,6 +113,11 @@ parameter_types! {
 	pub const MinCodeLength: u32 = 4;
 	pub const RegistrationFee: (AssetId,Balance, AccountId) = (HDX, 222 * 1_000_000_000_000, TREASURY) ;
 	pub const RewardAsset: AssetId = HDX;
+	pub const EraLength: u64 = 10;
+	pub const EraRewardHistoryLength: u32 = 3;
+	pub const TraderRebatePeriod: u64 = 10;
+	pub const TransactionFeePortion: Permill = Permill::from_percent(10);
+	pub const BadgeCollectionId: u128 = 3333;
 }
 
 pub struct LevelVolumeAndRewards;
@@ -141,7 +170,20 @@ impl Config for Test {
 	type MinCodeLength = MinCodeLength;
 	type LevelVolumeAndRewardPercentages = LevelVolumeAndRewards;
 	type ExternalAccount = ExtAccount;
-	type SeedNativeAmount = SeedAmount;
+	type EraLength = EraLength;
+	type EraRewardHistoryLength = EraRewardHistoryLength;
+	type TraderRebatePeriod = TraderRebatePeriod;
+	type TransactionFeePortion = TransactionFeePortion;
+	type PositionId = u32;
+	type LiquidityPositions = MockPositions;
+	type VestedTransfer = MockVesting;
+	type ExistentialDepositSponsor = MockDepositSponsor;
+	type CollectionId = u128;
+	type BadgeCollectionId = BadgeCollectionId;
+	type BadgeItemId = u32;
+	type Collections = DummyNFT;
+	type NFTHandler = DummyNFT;
+	type HookWeightRecorder = ();
 	type WeightInfo = ();
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -182,7 +224,7 @@ impl frame_system::Config for Test {
 
 parameter_type_with_key! {
 	pub ExistentialDeposits: |_asset_id: AssetId| -> Balance {
-		0
+		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
 	};
 }
 
@@ -233,6 +275,24 @@ impl Default for ExtBuilder {
 			let mut c = v.borrow_mut();
 			*c = None;
 		});
+		LP_POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		VESTED_TRANSFERS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		BADGE_COLLECTIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		BADGES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		EXISTENTIAL_DEPOSIT.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		SPONSORED_DEPOSITS.with(|v| {
+			v.borrow_mut().clear();
+		});
 
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, INITIAL_ALICE_BALANCE)],
@@ -306,6 +366,23 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_lp_positions(self, positions: Vec<(u32, AccountId, AssetId, Balance)>) -> Self {
+		LP_POSITIONS.with(|v| {
+			let mut m = v.borrow_mut();
+			for (position_id, owner, asset_id, shares) in positions {
+				m.insert(position_id, (owner, asset_id, shares));
+			}
+		});
+		self
+	}
+
+	pub fn with_existential_deposit(self, amount: Balance) -> Self {
+		EXISTENTIAL_DEPOSIT.with(|v| {
+			*v.borrow_mut() = amount;
+		});
+		self
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	pub fn with_default_volumes(self) -> Self {
 		let mut volumes = HashMap::new();
@@ -363,6 +440,10 @@ impl ExtBuilder {
 		r.execute_with(|| {
 			let seed_amount = SEED_AMOUNT.with(|v| *v.borrow());
 			Tokens::update_balance(HDX, &Referrals::pot_account_id(), seed_amount as i128).unwrap();
+			RewardPot::<Test>::mutate(|pot| pot.seed = seed_amount);
+			// endowed RewardAsset of the pot is distributed to the initial shares
+			let reward_reserve = Tokens::free_balance(HDX, &Referrals::pot_account_id()).saturating_sub(seed_amount);
+			Referrals::distribute_rewards(reward_reserve).unwrap();
 		});
 
 		r.execute_with(|| {
@@ -440,6 +521,133 @@ impl Hooks<AccountId, AssetId> for AmmTrader {
 	}
 }
 
+pub struct MockPositions;
+
+impl LiquidityPositions<AccountId, AssetId, u32> for MockPositions {
+	fn position_shares(who: &AccountId, position_id: u32) -> Option<(AssetId, Balance)> {
+		LP_POSITIONS.with(|v| {
+			v.borrow()
+				.get(&position_id)
+				.filter(|(owner, _, _)| owner == who)
+				.map(|(_, asset_id, shares)| (*asset_id, *shares))
+		})
+	}
+
+	fn total_shares(asset_id: AssetId) -> Balance {
+		LP_POSITIONS.with(|v| {
+			v.borrow()
+				.values()
+				.filter(|(_, asset, _)| *asset == asset_id)
+				.map(|(_, _, shares)| *shares)
+				.sum()
+		})
+	}
+}
+
+pub struct MockVesting;
+
+impl VestedTransfer<AccountId, Balance> for MockVesting {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		vesting: RewardVesting,
+	) -> Result<(), DispatchError> {
+		<Tokens as MultiCurrency<AccountId>>::transfer(HDX, from, to, amount)?;
+		VESTED_TRANSFERS.with(|v| v.borrow_mut().push((*to, amount, vesting)));
+		Ok(())
+	}
+
+	fn vested_transfer_weight() -> Weight {
+		VESTED_TRANSFER_WEIGHT
+	}
+}
+
+pub struct MockDepositSponsor;
+
+impl ExistentialDepositSponsor<AccountId, AssetId, Balance> for MockDepositSponsor {
+	fn sponsored_transfer(
+		asset: AssetId,
+		sponsor: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let balance = <Tokens as MultiCurrency<AccountId>>::total_balance(asset, to).saturating_add(amount);
+		let to_sponsor = ExistentialDeposits::get(&asset).saturating_sub(balance);
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset, sponsor, to, amount.saturating_add(to_sponsor))?;
+		SPONSORED_DEPOSITS.with(|v| v.borrow_mut().insert((asset, *to), to_sponsor));
+		Ok(to_sponsor)
+	}
+
+	fn sponsored_total(asset: AssetId, _sponsor: &AccountId) -> Balance {
+		SPONSORED_DEPOSITS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((a, _), _)| *a == asset)
+				.map(|(_, amount)| *amount)
+				.sum()
+		})
+	}
+
+	fn sponsored_transfer_weight() -> Weight {
+		SPONSORED_TRANSFER_WEIGHT
+	}
+}
+
+pub struct DummyNFT;
+
+impl nonfungibles::Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u128;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		BADGES.with(|v| v.borrow().get(&(*collection, *item)).copied())
+	}
+
+	fn collection_owner(collection: &Self::CollectionId) -> Option<AccountId> {
+		BADGE_COLLECTIONS.with(|v| v.borrow().get(collection).map(|(owner, _)| *owner))
+	}
+}
+
+impl nonfungibles::Create<AccountId> for DummyNFT {
+	fn create_collection(collection: &Self::CollectionId, who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		BADGE_COLLECTIONS.with(|v| v.borrow_mut().insert(*collection, (*who, false)));
+		Ok(())
+	}
+}
+
+impl nonfungibles::Mutate<AccountId> for DummyNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		BADGES.with(|v| v.borrow_mut().insert((*collection, *item), *who));
+		Ok(())
+	}
+}
+
+impl Freeze<AccountId, u128> for DummyNFT {
+	fn freeze_collection(owner: AccountId, collection: u128) -> DispatchResult {
+		BADGE_COLLECTIONS.with(|v| match v.borrow_mut().get_mut(&collection) {
+			Some((collection_owner, frozen)) if *collection_owner == owner => {
+				*frozen = true;
+				Ok(())
+			}
+			_ => Err(DispatchError::Other("not collection owner")),
+		})
+	}
+}
+
+pub(crate) fn is_badge_collection_frozen() -> bool {
+	BADGE_COLLECTIONS.with(|v| {
+		v.borrow()
+			.get(&BadgeCollectionId::get())
+			.map(|(_, frozen)| *frozen)
+			.unwrap_or(false)
+	})
+}
+
+pub(crate) fn badge_owner(item_id: u32) -> Option<AccountId> {
+	BADGES.with(|v| v.borrow().get(&(BadgeCollectionId::get(), item_id)).copied())
+}
+
 pub struct ConversionPrice;
 
 impl PriceProvider<AssetId> for ConversionPrice {
@@ -460,7 +668,7 @@ use crate::traits::BenchmarkHelper;
 pub struct Benchmarking;
 
 #[cfg(feature = "runtime-benchmarks")]
-impl BenchmarkHelper<AssetId, Balance> for Benchmarking {
+impl BenchmarkHelper<AssetId, Balance, AccountId, u32> for Benchmarking {
 	fn prepare_convertible_asset_and_amount() -> (AssetId, Balance) {
 		let price = EmaPrice::new(1_000_000_000_000, 1_000_000_000_000);
 		CONVERSION_RATE.with(|v| {
@@ -471,4 +679,11 @@ impl BenchmarkHelper<AssetId, Balance> for Benchmarking {
 
 		(1234, 1_000_000_000_000)
 	}
+
+	fn prepare_liquidity_position() -> (AccountId, AssetId, u32) {
+		LP_POSITIONS.with(|v| {
+			v.borrow_mut().insert(1, (BOB, DAI, 1_000_000_000_000_000));
+		});
+		(BOB, DAI, 1)
+	}
 }
@@ -0,0 +1,136 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn tier_volumes() -> HashMap<Level, Option<Balance>> {
+	let mut volumes = HashMap::new();
+	volumes.insert(Level::Tier0, Some(0));
+	volumes.insert(Level::Tier1, Some(10_000_000_000_000));
+	volumes.insert(Level::Tier2, Some(11_000_000_000_000));
+	volumes.insert(Level::Tier3, Some(12_000_000_000_000));
+	volumes.insert(Level::Tier4, Some(13_000_000_000_000));
+	volumes
+}
+
+fn register_alice_code() {
+	let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+}
+
+#[test]
+fn claim_rewards_should_not_mint_badge_when_level_is_not_increased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 5_000_000_000_000)])
+		.with_tier_volumes(tier_volumes())
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			register_alice_code();
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Assert
+			assert_eq!(Referrals::badge(ALICE, Level::Tier1), None);
+			assert_eq!(Referrals::next_badge_id(), 0);
+			assert_eq!(badge_owner(0), None);
+		});
+}
+
+#[test]
+fn claim_rewards_should_mint_badge_when_level_is_increased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 15_500_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 10_500_000_000_000)])
+		.with_tier_volumes(tier_volumes())
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			register_alice_code();
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Assert
+			assert_eq!(Referrals::badge(ALICE, Level::Tier1), Some(0));
+			assert_eq!(Referrals::badge(ALICE, Level::Tier2), None);
+			assert_eq!(badge_owner(0), Some(ALICE));
+			assert_eq!(Referrals::next_badge_id(), 1);
+			expect_events(vec![Event::BadgeMinted {
+				who: ALICE,
+				level: Level::Tier1,
+				item_id: 0,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_rewards_should_mint_badge_of_each_level_when_multiple_levels_are_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_tier_volumes(tier_volumes())
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			register_alice_code();
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Assert
+			for (item_id, level) in [Level::Tier1, Level::Tier2, Level::Tier3, Level::Tier4]
+				.into_iter()
+				.enumerate()
+			{
+				let item_id = item_id as u32;
+				assert_eq!(Referrals::badge(ALICE, level), Some(item_id));
+				assert_eq!(badge_owner(item_id), Some(ALICE));
+			}
+			assert_eq!(Referrals::next_badge_id(), 4);
+		});
+}
+
+#[test]
+fn claim_rewards_should_create_frozen_badge_collection_when_first_badge_is_minted() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_tier_volumes(tier_volumes())
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			register_alice_code();
+			assert!(!is_badge_collection_frozen());
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Assert
+			assert_eq!(
+				<DummyNFT as nonfungibles::Inspect<AccountId>>::collection_owner(&BadgeCollectionId::get()),
+				Some(Pallet::<Test>::pot_account_id())
+			);
+			assert!(is_badge_collection_frozen());
+		});
+}
+
+#[test]
+fn claim_rewards_should_mint_badges_with_unique_item_ids_when_claimed_by_different_referrers() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), HDX, 21_000_000_000_000),
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_referrer_shares(vec![(ALICE, 10_500_000_000_000), (BOB, 10_500_000_000_000)])
+		.with_tier_volumes(tier_volumes())
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			register_alice_code();
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BOBCODE".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), code));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Referrals::badge(ALICE, Level::Tier1), Some(0));
+			assert_eq!(Referrals::badge(BOB, Level::Tier1), Some(1));
+			assert_eq!(badge_owner(0), Some(ALICE));
+			assert_eq!(badge_owner(1), Some(BOB));
+		});
+}
@@ -0,0 +1,170 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn referrer_only_rewards() -> Vec<(AssetId, Level, FeeDistribution)> {
+	vec![(
+		DAI,
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(50),
+			trader: Permill::zero(),
+			external: Permill::zero(),
+			lp: Permill::zero(),
+		},
+	)]
+}
+
+fn link_bob_to_alice() {
+	let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone(),));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+}
+
+#[test]
+fn set_era_budget_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(1_000_000)));
+		// Assert
+		assert_eq!(Referrals::era_budget(), Some(1_000_000));
+		expect_events(vec![Event::EraBudgetUpdated {
+			budget: Some(1_000_000),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_era_budget_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_era_budget(RuntimeOrigin::signed(ALICE), Some(1_000_000)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_track_accrued_shares_when_no_budget_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(referrer_only_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(
+				Referrals::era_budget_usage(),
+				EraBudgetUsage {
+					era: 0,
+					accrued: 5_000_000_000,
+					exhausted: false,
+				}
+			);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_accrue_shares_when_era_budget_would_be_exceeded() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(referrer_only_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(12_000_000_000)));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			let pot_balance = Tokens::free_balance(DAI, &Referrals::pot_account_id());
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 10_000_000_000);
+			assert_eq!(TotalShares::<Test>::get(), 10_000_000_000);
+			assert_balance!(Referrals::pot_account_id(), DAI, pot_balance);
+			expect_events(vec![Event::EraBudgetExhausted {
+				era: 0,
+				accrued: 10_000_000_000,
+				correlation_id: CorrelationId::current::<Test>(),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_accrue_shares_for_remainder_of_era_when_budget_is_exhausted() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(referrer_only_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(12_000_000_000)));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Act
+			// smaller trade would fit into the remaining budget but era is already exhausted
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 100_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 10_000_000_000);
+			assert!(Referrals::era_budget_usage().exhausted);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_accrue_shares_again_when_new_era_starts() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(referrer_only_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(5_000_000_000)));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			assert!(Referrals::era_budget_usage().exhausted);
+			// Act
+			System::set_block_number(EraLength::get());
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 10_000_000_000);
+			assert_eq!(
+				Referrals::era_budget_usage(),
+				EraBudgetUsage {
+					era: 1,
+					accrued: 5_000_000_000,
+					exhausted: true,
+				}
+			);
+		});
+}
+
+#[test]
+fn set_era_budget_should_reset_exhausted_flag_when_budget_is_raised() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(referrer_only_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(5_000_000_000)));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Act
+			assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(10_000_000_000)));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 10_000_000_000);
+		});
+}
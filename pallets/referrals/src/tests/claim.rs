@@ -9,7 +9,7 @@ fn claim_rewards_should_work_when_amount_is_zero() {
 }
 
 #[test]
-fn claim_rewards_should_convert_all_assets() {
+fn claim_rewards_should_convert_pending_assets() {
 	ExtBuilder::default()
 		.with_endowed_accounts(vec![
 			(Pallet::<Test>::pot_account_id(), DAI, 3_000_000_000_000_000_000),
@@ -23,54 +23,73 @@ fn claim_rewards_should_convert_all_assets() {
 			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
 			// Assert
 			let acc = Pallet::<Test>::pot_account_id();
-			let reserve = Tokens::free_balance(HDX, &acc);
-			assert_eq!(reserve, 7_000_000_000_000);
-			let reserve = Tokens::free_balance(DOT, &acc);
-			assert_eq!(reserve, 0);
-			let reserve = Tokens::free_balance(DAI, &acc);
-			assert_eq!(reserve, 0);
+			assert_balance!(acc, HDX, 7_000_000_000_000);
+			assert_balance!(acc, DAI, 0);
+			assert_balance!(acc, DOT, 0);
+			assert_eq!(PendingConversions::<Test>::count(), 0);
 		});
 }
 
 #[test]
-fn claim_rewards_should_remove_assets_from_the_list_when_successful() {
+fn claim_rewards_should_pay_rewards_of_pending_assets() {
 	ExtBuilder::default()
 		.with_endowed_accounts(vec![
-			(Pallet::<Test>::pot_account_id(), DAI, 3_000_000_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000),
 			(Pallet::<Test>::pot_account_id(), DOT, 4_000_000_000_000),
 		])
-		.with_assets(vec![DAI, DOT])
-		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 5_000_000_000_000)])
+		.with_assets(vec![DOT])
 		.with_conversion_price((HDX, DOT), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000))
 		.build()
 		.execute_with(|| {
-			let count = PendingConversions::<Test>::count();
-			assert_eq!(count, 2);
 			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
 			// Assert
-			let count = PendingConversions::<Test>::count();
-			assert_eq!(count, 0);
+			assert_balance!(BOB, HDX, 7_000_000_000_000);
+			assert_balance!(Pallet::<Test>::pot_account_id(), DOT, 0);
+			assert_eq!(TotalShares::<Test>::get(), 5_000_000_000_000);
 		});
 }
 
 #[test]
-fn claim_rewards_should_remove_assets_from_the_list_when_not_successful() {
+fn claim_rewards_should_keep_asset_pending_when_conversion_fails() {
 	ExtBuilder::default()
 		.with_endowed_accounts(vec![
-			(Pallet::<Test>::pot_account_id(), DAI, 3_000_000_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000),
 			(Pallet::<Test>::pot_account_id(), DOT, 4_000_000_000_000),
 		])
-		.with_assets(vec![DAI, DOT])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 5_000_000_000_000)])
+		.with_assets(vec![DOT])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_balance!(BOB, HDX, 5_000_000_000_000);
+			assert_balance!(Pallet::<Test>::pot_account_id(), DOT, 4_000_000_000_000);
+			assert_eq!(PendingConversions::<Test>::get(DOT), Some(()));
+		});
+}
+
+#[test]
+fn claim_rewards_should_pay_rewards_converted_after_shares_were_accrued() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), DOT, 4_000_000_000_000),
+		])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 5_000_000_000_000)])
+		.with_assets(vec![DOT])
 		.with_conversion_price((HDX, DOT), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000))
 		.build()
 		.execute_with(|| {
-			let count = PendingConversions::<Test>::count();
-			assert_eq!(count, 2);
-			// conversion for DAI fails, but the asset should be removed from PendingConversions
+			// Arrange
+			assert_ok!(Referrals::convert(RuntimeOrigin::signed(CHARLIE), DOT));
+			// Act
 			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
 			// Assert
-			let count = PendingConversions::<Test>::count();
-			assert_eq!(count, 0);
+			assert_balance!(BOB, HDX, 7_000_000_000_000);
+			assert_balance!(ALICE, HDX, INITIAL_ALICE_BALANCE + 7_000_000_000_000);
+			assert_balance!(Pallet::<Test>::pot_account_id(), HDX, 0);
 		});
 }
 
@@ -307,3 +326,104 @@ fn claim_rewards_should_increase_referrer_level_directly_to_top_tier_when_limit_
 			assert_eq!(total, 15_000_000_000_000);
 		});
 }
+
+#[test]
+fn pending_rewards_should_return_portion_of_reward_reserve() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_trader_shares(vec![(BOB, 2_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 3_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Referrals::pending_rewards(&BOB), 5_000_000_000_000);
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			assert_balance!(BOB, HDX, 5_000_000_000_000);
+			assert_eq!(Referrals::pending_rewards(&BOB), 0);
+		});
+}
+
+#[test]
+fn claim_rewards_should_sponsor_existential_deposit_when_rewards_are_below_existential_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 1_000_000_000)])
+		.with_trader_shares(vec![(BOB, 1_000_000_000)])
+		.with_seed_amount(10 * ONE)
+		.with_existential_deposit(ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+
+			assert_balance!(BOB, HDX, ONE);
+			assert_balance!(Pallet::<Test>::pot_account_id(), HDX, 9_001_000_000_000);
+			assert_eq!(
+				MockDepositSponsor::sponsored_total(HDX, &Pallet::<Test>::pot_account_id()),
+				999_000_000_000
+			);
+			assert_eq!(Referrals::pending_rewards(&BOB), 0);
+		});
+}
+
+#[test]
+fn claim_rewards_should_fail_when_seed_amount_does_not_cover_existential_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 1_000_000_000)])
+		.with_trader_shares(vec![(BOB, 1_000_000_000)])
+		.with_seed_amount(ONE)
+		.with_existential_deposit(ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Referrals::claim_rewards(RuntimeOrigin::signed(BOB)),
+				orml_tokens::Error::<Test>::ExistentialDeposit
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_should_refund_transfer_weight_when_rewards_are_paid_by_plain_transfer() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Act
+			let post_info = Referrals::claim_rewards(RuntimeOrigin::signed(BOB)).unwrap();
+			// Assert
+			assert_eq!(
+				post_info.actual_weight,
+				Some(<Test as Config>::WeightInfo::claim_rewards())
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_should_charge_sponsored_transfer_weight_when_existential_deposit_is_sponsored() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 1_000_000_000)])
+		.with_trader_shares(vec![(BOB, 1_000_000_000)])
+		.with_seed_amount(10 * ONE)
+		.with_existential_deposit(ONE)
+		.build()
+		.execute_with(|| {
+			// Act
+			let post_info = Referrals::claim_rewards(RuntimeOrigin::signed(BOB)).unwrap();
+			// Assert
+			assert_eq!(
+				post_info.actual_weight,
+				Some(<Test as Config>::WeightInfo::claim_rewards().saturating_add(SPONSORED_TRANSFER_WEIGHT))
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_should_refund_transfer_weight_when_there_is_nothing_to_claim() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		let post_info = Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)).unwrap();
+		// Assert
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as Config>::WeightInfo::claim_rewards())
+		);
+	});
+}
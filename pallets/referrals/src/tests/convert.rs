@@ -66,6 +66,40 @@ fn convert_should_emit_event_when_successful() {
 		});
 }
 
+#[test]
+fn convert_should_distribute_converted_amount_to_all_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), DAI, 1_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_assets(vec![DAI])
+		.with_referrer_shares(vec![(ALICE, 3_000_000_000_000)])
+		.with_trader_shares(vec![(BOB, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::convert(RuntimeOrigin::signed(ALICE), DAI));
+			// Assert
+			assert_eq!(RewardPerShare::<Test>::get(), FixedU128::from_rational(1, 4));
+			assert_eq!(Referrals::pending_rewards(&ALICE), 750_000_000_000);
+			assert_eq!(Referrals::pending_rewards(&BOB), 250_000_000_000);
+		});
+}
+
+#[test]
+fn convert_should_not_distribute_rewards_when_there_are_no_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), DAI, 1_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_assets(vec![DAI])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::convert(RuntimeOrigin::signed(ALICE), DAI));
+			// Assert
+			assert_eq!(RewardPerShare::<Test>::get(), FixedU128::zero());
+		});
+}
+
 #[test]
 fn on_idle_should_convert_all_asset_amount_when_successful() {
 	ExtBuilder::default()
@@ -86,6 +120,23 @@ fn on_idle_should_convert_all_asset_amount_when_successful() {
 		});
 }
 
+#[test]
+fn on_idle_should_distribute_converted_amount_to_all_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), DAI, 1_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_assets(vec![DAI])
+		.with_referrer_shares(vec![(ALICE, 2_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Act
+			Referrals::on_idle(10, 1_000_000_000_000.into());
+			// Assert
+			assert_eq!(RewardPerShare::<Test>::get(), FixedU128::from_rational(1, 2));
+			assert_eq!(Referrals::pending_rewards(&ALICE), 1_000_000_000_000);
+		});
+}
+
 #[test]
 fn on_idle_should_remove_asset_from_pending_conversions_when_not_successful() {
 	ExtBuilder::default()
@@ -105,3 +156,40 @@ fn on_idle_should_remove_asset_from_pending_conversions_when_not_successful() {
 			assert!(entry.is_none())
 		});
 }
+
+#[test]
+fn on_idle_should_return_weight_of_performed_conversions() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), DAI, 1_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_assets(vec![DAI])
+		.build()
+		.execute_with(|| {
+			// Act
+			let weight = Referrals::on_idle(10, 1_000_000_000_000.into());
+			// Assert
+			assert_eq!(weight, <Test as Config>::WeightInfo::convert());
+		});
+}
+
+#[test]
+fn on_idle_should_not_use_more_than_remaining_weight() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), DAI, 1_000_000_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), DOT, 1_000_000_000_000),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_conversion_price((HDX, DOT), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000))
+		.with_assets(vec![DAI, DOT])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let remaining_weight = <Test as Config>::WeightInfo::convert();
+			// Act
+			let weight = Referrals::on_idle(10, remaining_weight);
+			// Assert
+			assert!(weight.all_lte(remaining_weight));
+			assert_eq!(PendingConversions::<Test>::count(), 1);
+		});
+}
@@ -0,0 +1,104 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn claim_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), DOT, 4_000_000_000_000),
+		])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 5_000_000_000_000)])
+		.with_assets(vec![DOT])
+		.with_conversion_price((HDX, DOT), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000))
+		.build()
+}
+
+#[test]
+fn era_rewards_should_track_distributed_and_claimed_rewards_of_current_era() {
+	claim_ext().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::convert(RuntimeOrigin::signed(CHARLIE), DOT));
+		assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+		// Assert
+		let expected = EraRewards {
+			era: 0,
+			start_reward_per_share: FixedU128::zero(),
+			reward_per_share: FixedU128::from_rational(14, 10),
+			distributed: 14_000_000_000_000,
+			claimed: 7_000_000_000_000,
+		};
+		assert_eq!(Referrals::reward_per_share(), FixedU128::from_rational(14, 10));
+		assert_eq!(Referrals::current_era_rewards(), Some(expected));
+		assert_eq!(Referrals::era_rewards(0), Some(expected));
+	});
+}
+
+#[test]
+fn era_rewards_should_keep_previous_era_in_history_when_new_era_starts() {
+	claim_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::convert(RuntimeOrigin::signed(CHARLIE), DOT));
+		assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+		System::set_block_number(EraLength::get());
+		// Act
+		assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+		// Assert
+		assert_eq!(
+			Referrals::era_rewards(0),
+			Some(EraRewards {
+				era: 0,
+				start_reward_per_share: FixedU128::zero(),
+				reward_per_share: FixedU128::from_rational(14, 10),
+				distributed: 14_000_000_000_000,
+				claimed: 7_000_000_000_000,
+			})
+		);
+		assert_eq!(
+			Referrals::era_rewards(1),
+			Some(EraRewards {
+				era: 1,
+				start_reward_per_share: FixedU128::from_rational(14, 10),
+				reward_per_share: FixedU128::from_rational(14, 10),
+				distributed: 0,
+				claimed: 7_000_000_000_000,
+			})
+		);
+	});
+}
+
+#[test]
+fn era_rewards_should_return_none_when_era_is_older_than_history() {
+	ExtBuilder::default()
+		.with_referrer_shares(vec![(BOB, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::distribute_rewards(1_000_000_000_000));
+			// Act
+			for era in 1..=EraRewardHistoryLength::get() + 1 {
+				System::set_block_number(era as u64 * EraLength::get());
+				assert_ok!(Referrals::distribute_rewards(1_000_000_000_000));
+			}
+			// Assert
+			assert_eq!(Referrals::era_rewards(0), None);
+			for era in 1..=EraRewardHistoryLength::get() + 1 {
+				assert_eq!(
+					Referrals::era_rewards(era),
+					Some(EraRewards {
+						era,
+						start_reward_per_share: FixedU128::from(era as u128),
+						reward_per_share: FixedU128::from(era as u128 + 1),
+						distributed: 1_000_000_000_000,
+						claimed: 0,
+					})
+				);
+			}
+		});
+}
+
+#[test]
+fn era_rewards_should_return_none_when_there_was_no_distribution_nor_claim_in_era() {
+	claim_ext().execute_with(|| {
+		assert_eq!(Referrals::era_rewards(1), None);
+	});
+}
@@ -0,0 +1,152 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const GLOBAL_EXTERNAL: AccountId = 12345;
+const TIER0_EXTERNAL: AccountId = 54321;
+
+fn rewards() -> HashMap<Level, FeeDistribution> {
+	let mut rewards = HashMap::new();
+	rewards.insert(
+		Level::None,
+		FeeDistribution {
+			referrer: Default::default(),
+			trader: Default::default(),
+			external: Permill::from_percent(50),
+			lp: Permill::zero(),
+		},
+	);
+	rewards.insert(
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(5),
+			trader: Permill::from_percent(5),
+			external: Permill::from_percent(40),
+			lp: Permill::zero(),
+		},
+	);
+	rewards
+}
+
+fn link_bob() {
+	let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+}
+
+#[test]
+fn set_external_account_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::set_external_account(
+			RuntimeOrigin::root(),
+			Level::Tier4,
+			Some(TIER0_EXTERNAL)
+		));
+		// Assert
+		assert_eq!(Referrals::level_external_account(Level::Tier4), Some(TIER0_EXTERNAL));
+		expect_events(vec![Event::ExternalAccountUpdated {
+			level: Level::Tier4,
+			account: Some(TIER0_EXTERNAL),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_external_account_should_remove_account_when_none_is_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::set_external_account(
+			RuntimeOrigin::root(),
+			Level::Tier4,
+			Some(TIER0_EXTERNAL)
+		));
+		// Act
+		assert_ok!(Referrals::set_external_account(
+			RuntimeOrigin::root(),
+			Level::Tier4,
+			None
+		));
+		// Assert
+		assert_eq!(Referrals::level_external_account(Level::Tier4), None);
+	});
+}
+
+#[test]
+fn set_external_account_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_external_account(RuntimeOrigin::signed(ALICE), Level::Tier4, Some(TIER0_EXTERNAL)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_increase_level_external_account_shares_when_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_global_tier_rewards(rewards())
+		.with_external_account(GLOBAL_EXTERNAL)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob();
+			assert_ok!(Referrals::set_external_account(
+				RuntimeOrigin::root(),
+				Level::Tier0,
+				Some(TIER0_EXTERNAL)
+			));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(TIER0_EXTERNAL), 4_000_000_000);
+			assert_eq!(TraderShares::<Test>::get(GLOBAL_EXTERNAL), 0);
+			assert_eq!(TotalShares::<Test>::get(), 5_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_use_global_external_account_when_level_account_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_global_tier_rewards(rewards())
+		.with_external_account(GLOBAL_EXTERNAL)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::set_external_account(
+				RuntimeOrigin::root(),
+				Level::Tier0,
+				Some(TIER0_EXTERNAL)
+			));
+			// Act - BOB has no code linked, so Level::None applies
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(GLOBAL_EXTERNAL), 5_000_000_000);
+			assert_eq!(TraderShares::<Test>::get(TIER0_EXTERNAL), 0);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_reward_level_external_account_when_global_account_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_global_tier_rewards(rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::set_external_account(
+				RuntimeOrigin::root(),
+				Level::None,
+				Some(TIER0_EXTERNAL)
+			));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(TIER0_EXTERNAL), 5_000_000_000);
+		});
+}
@@ -28,6 +28,7 @@ fn complete_referral_flow_should_work_as_expected() {
 					referrer: Permill::from_float(0.005),
 					trader: Permill::from_float(0.002),
 					external: Permill::from_float(0.002),
+					lp: Permill::zero(),
 				},
 			),
 			(
@@ -37,6 +38,7 @@ fn complete_referral_flow_should_work_as_expected() {
 					referrer: Permill::from_float(0.005),
 					trader: Permill::from_float(0.002),
 					external: Permill::from_float(0.002),
+					lp: Permill::zero(),
 				},
 			),
 			(
@@ -46,6 +48,7 @@ fn complete_referral_flow_should_work_as_expected() {
 					referrer: Permill::from_float(0.03),
 					trader: Permill::from_float(0.01),
 					external: Permill::from_float(0.002),
+					lp: Permill::zero(),
 				},
 			),
 			(
@@ -55,6 +58,7 @@ fn complete_referral_flow_should_work_as_expected() {
 					referrer: Permill::from_float(0.03),
 					trader: Permill::from_float(0.01),
 					external: Permill::from_float(0.002),
+					lp: Permill::zero(),
 				},
 			),
 			(
@@ -64,6 +68,7 @@ fn complete_referral_flow_should_work_as_expected() {
 					referrer: Permill::from_float(0.002),
 					trader: Permill::from_float(0.001),
 					external: Permill::from_float(0.002),
+					lp: Permill::zero(),
 				},
 			),
 			(
@@ -73,6 +78,7 @@ fn complete_referral_flow_should_work_as_expected() {
 					referrer: Permill::from_float(0.03),
 					trader: Permill::from_float(0.01),
 					external: Permill::from_float(0.002),
+					lp: Permill::zero(),
 				},
 			),
 		])
@@ -109,6 +115,13 @@ fn complete_referral_flow_should_work_as_expected() {
 			let total_shares = TotalShares::<Test>::get();
 			assert_eq!(total_shares, alice_shares + bob_shares + charlie_shares);
 
+			// CONVERSIONS
+			// HDX fee has been distributed to shares accrued before the trade of CHARLIE
+			assert_eq!(RewardPerShare::<Test>::get(), FixedU128::from_rational(3, 10));
+			assert_ok!(Referrals::convert(RuntimeOrigin::signed(ALICE), DAI));
+			assert_ok!(Referrals::convert(RuntimeOrigin::signed(ALICE), DOT));
+			assert_balance!(Pallet::<Test>::pot_account_id(), HDX, 170_000_000);
+
 			// CLAIMS
 			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(CHARLIE),));
 			// Assert charlie rewards
@@ -117,7 +130,7 @@ fn complete_referral_flow_should_work_as_expected() {
 			let total_shares = TotalShares::<Test>::get();
 			assert_eq!(total_shares, alice_shares + bob_shares);
 			let charlie_balance = Tokens::free_balance(HDX, &CHARLIE);
-			assert_eq!(charlie_balance, 20000000);
+			assert_eq!(charlie_balance, 16_470_588);
 
 			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB),));
 			// Assert BOB rewards
@@ -126,7 +139,7 @@ fn complete_referral_flow_should_work_as_expected() {
 			let total_shares = TotalShares::<Test>::get();
 			assert_eq!(total_shares, alice_shares);
 			let bob_balance = Tokens::free_balance(HDX, &BOB);
-			assert_eq!(bob_balance, 10_000_000_000_000);
+			assert_eq!(bob_balance, 10_000_003_705_882);
 
 			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE),));
 			// Assert ALICE rewards
@@ -135,9 +148,9 @@ fn complete_referral_flow_should_work_as_expected() {
 			let total_shares = TotalShares::<Test>::get();
 			assert_eq!(total_shares, 0);
 			let alice_balance = Tokens::free_balance(HDX, &ALICE);
-			assert_eq!(alice_balance, 778_000_120_000_000);
+			assert_eq!(alice_balance, 778_000_119_823_529);
 			let (level, total) = Referrer::<Test>::get(ALICE).unwrap();
 			assert_eq!(level, Level::Tier1);
-			assert_eq!(total, 120_000_000);
+			assert_eq!(total, 119_823_529);
 		});
 }
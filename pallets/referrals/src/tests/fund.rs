@@ -0,0 +1,103 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const DISTRIBUTION: AccountId = 500;
+
+#[test]
+fn fund_rewards_should_transfer_amount_to_pot() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 2_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::fund_rewards(&TREASURY, &DISTRIBUTION, 1_000 * ONE));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &TREASURY), 1_000 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &Referrals::pot_account_id()), 1_000 * ONE);
+			expect_events(vec![Event::RewardsFunded {
+				source: TREASURY,
+				distribution: DISTRIBUTION,
+				amount: 1_000 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn fund_rewards_should_mint_shares_to_distribution_account() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 2_000 * ONE)])
+		.with_trader_shares(vec![(BOB, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::fund_rewards(&TREASURY, &DISTRIBUTION, 1_000 * ONE));
+			// Assert
+			assert_eq!(Referrals::trader_shares(DISTRIBUTION), 1_000 * ONE);
+			assert_eq!(Referrals::total_shares(), 2_000 * ONE);
+		});
+}
+
+#[test]
+fn fund_rewards_should_distribute_amount_to_all_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 2_000 * ONE)])
+		.with_trader_shares(vec![(BOB, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::fund_rewards(&TREASURY, &DISTRIBUTION, 1_000 * ONE));
+			// Assert
+			assert_eq!(Referrals::pending_rewards(&BOB), 500 * ONE);
+			assert_eq!(Referrals::pending_rewards(&DISTRIBUTION), 500 * ONE);
+		});
+}
+
+#[test]
+fn claim_rewards_should_pay_funded_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 2_000 * ONE)])
+		.with_trader_shares(vec![(BOB, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::fund_rewards(&TREASURY, &DISTRIBUTION, 1_000 * ONE));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(DISTRIBUTION)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 500 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &DISTRIBUTION), 500 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &Referrals::pot_account_id()), 0);
+			assert_eq!(Referrals::total_shares(), 0);
+		});
+}
+
+#[test]
+fn fund_rewards_should_not_use_era_budget() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 2_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::set_era_budget(RuntimeOrigin::root(), Some(ONE)));
+			// Act
+			assert_ok!(Referrals::fund_rewards(&TREASURY, &DISTRIBUTION, 1_000 * ONE));
+			// Assert
+			assert_eq!(Referrals::era_budget_usage().accrued, 0);
+			assert!(!Referrals::era_budget_usage().exhausted);
+		});
+}
+
+#[test]
+fn fund_rewards_should_fail_when_amount_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 2_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Referrals::fund_rewards(&TREASURY, &DISTRIBUTION, 0),
+				Error::<Test>::ZeroAmount
+			);
+		});
+}
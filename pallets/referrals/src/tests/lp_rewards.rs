@@ -0,0 +1,174 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn lp_only_rewards() -> Vec<(AssetId, Level, FeeDistribution)> {
+	vec![(
+		DAI,
+		Level::None,
+		FeeDistribution {
+			referrer: Permill::zero(),
+			trader: Permill::zero(),
+			external: Permill::zero(),
+			lp: Permill::from_percent(50),
+		},
+	)]
+}
+
+fn lp_rewards_builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(lp_only_rewards())
+}
+
+#[test]
+fn process_trade_fee_should_accrue_lp_shares_to_distribution_account() {
+	lp_rewards_builder()
+		.with_lp_positions(vec![
+			(1, CHARLIE, DAI, 1_000_000_000_000),
+			(2, ALICE, DAI, 3_000_000_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			let lp_account = Pallet::<Test>::lp_account_id(DAI);
+			assert_eq!(TraderShares::<Test>::get(lp_account), 5_000_000_000);
+			assert_eq!(TotalShares::<Test>::get(), 5_000_000_000);
+			assert_eq!(
+				LpRewardPerShare::<Test>::get(DAI),
+				FixedU128::from_rational(5_000_000_000, 4_000_000_000_000)
+			);
+			assert_balance!(Pallet::<Test>::pot_account_id(), DAI, 5_000_000_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_take_lp_reward_when_there_is_no_liquidity() {
+	lp_rewards_builder().build().execute_with(|| {
+		// Act
+		assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+		// Assert
+		let lp_account = Pallet::<Test>::lp_account_id(DAI);
+		assert_eq!(TraderShares::<Test>::get(lp_account), 0);
+		assert_eq!(TotalShares::<Test>::get(), 0);
+		assert_eq!(LpRewardPerShare::<Test>::get(DAI), FixedU128::zero());
+		assert_balance!(Pallet::<Test>::pot_account_id(), DAI, 0);
+	});
+}
+
+#[test]
+fn claim_lp_rewards_should_register_position_when_called_first_time() {
+	lp_rewards_builder()
+		.with_lp_positions(vec![(1, CHARLIE, DAI, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Act
+			assert_ok!(Referrals::claim_lp_rewards(RuntimeOrigin::signed(CHARLIE), 1));
+			// Assert
+			assert_eq!(
+				LpRewardCheckpoints::<Test>::get(1),
+				Some(LpRewardPerShare::<Test>::get(DAI))
+			);
+			assert_eq!(TraderShares::<Test>::get(CHARLIE), 0);
+			assert_balance!(CHARLIE, HDX, 0);
+			expect_events(vec![Event::LpPositionRegistered {
+				who: CHARLIE,
+				position_id: 1,
+				asset_id: DAI,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_lp_rewards_should_transfer_rewards_proportionally_to_position_shares() {
+	lp_rewards_builder()
+		.with_lp_positions(vec![
+			(1, CHARLIE, DAI, 1_000_000_000_000),
+			(2, ALICE, DAI, 3_000_000_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::claim_lp_rewards(RuntimeOrigin::signed(CHARLIE), 1));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			assert_ok!(Referrals::convert(RuntimeOrigin::signed(ALICE), DAI));
+			// Act
+			assert_ok!(Referrals::claim_lp_rewards(RuntimeOrigin::signed(CHARLIE), 1));
+			// Assert
+			assert_balance!(CHARLIE, HDX, 1_250_000_000);
+			let lp_account = Pallet::<Test>::lp_account_id(DAI);
+			assert_eq!(TraderShares::<Test>::get(lp_account), 3_750_000_000);
+			assert_eq!(TotalShares::<Test>::get(), 3_750_000_000);
+			expect_events(vec![
+				Event::LpRewardsClaimed {
+					who: CHARLIE,
+					position_id: 1,
+					asset_id: DAI,
+					shares: 1_250_000_000,
+				}
+				.into(),
+				Event::Claimed {
+					who: CHARLIE,
+					referrer_rewards: 0,
+					trade_rewards: 1_250_000_000,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn claim_lp_rewards_should_not_include_rewards_accrued_before_registration() {
+	lp_rewards_builder()
+		.with_lp_positions(vec![(1, CHARLIE, DAI, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			assert_ok!(Referrals::claim_lp_rewards(RuntimeOrigin::signed(CHARLIE), 1));
+			// Act
+			assert_ok!(Referrals::claim_lp_rewards(RuntimeOrigin::signed(CHARLIE), 1));
+			// Assert
+			assert_balance!(CHARLIE, HDX, 0);
+			let lp_account = Pallet::<Test>::lp_account_id(DAI);
+			assert_eq!(TraderShares::<Test>::get(lp_account), 5_000_000_000);
+		});
+}
+
+#[test]
+fn claim_lp_rewards_should_fail_when_position_is_not_owned_by_signer() {
+	lp_rewards_builder()
+		.with_lp_positions(vec![(1, CHARLIE, DAI, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Referrals::claim_lp_rewards(RuntimeOrigin::signed(BOB), 1),
+				Error::<Test>::PositionNotFound
+			);
+		});
+}
+
+#[test]
+fn set_reward_percentage_should_fail_when_lp_percentage_exceeds_total() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_reward_percentage(
+				RuntimeOrigin::root(),
+				DAI,
+				Level::Tier0,
+				FeeDistribution {
+					referrer: Permill::from_percent(40),
+					trader: Permill::from_percent(30),
+					external: Permill::from_percent(20),
+					lp: Permill::from_percent(11),
+				}
+			),
+			Error::<Test>::IncorrectRewardPercentage
+		);
+	});
+}
@@ -0,0 +1,120 @@
+use crate::migration::{invariants, versioned::MigrateToLatest};
+use crate::tests::*;
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+use pretty_assertions::assert_eq;
+
+fn legacy_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_seed_amount(ONE)
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000)])
+		.with_trader_shares(vec![(ALICE, 5_000_000_000_000)])
+		.build();
+	ext.execute_with(|| {
+		// rewards received by the pot before reward per share was introduced
+		assert_ok!(Tokens::update_balance(
+			HDX,
+			&Referrals::pot_account_id(),
+			10 * ONE as i128
+		));
+		RewardPot::<Test>::kill();
+		StorageVersion::new(1).put::<Referrals>();
+	});
+	ext
+}
+
+#[test]
+fn migrate_to_latest_should_migrate_to_current_storage_version() {
+	legacy_ext().execute_with(|| {
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Referrals>(), 3);
+		assert_eq!(Referrals::reward_per_share(), FixedU128::from(1));
+		assert_eq!(invariants::check::<Test>(), Ok(()));
+	});
+}
+
+#[test]
+fn migrate_to_latest_should_initialize_pot_accounting() {
+	legacy_ext().execute_with(|| {
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		assert_eq!(
+			Referrals::reward_pot(),
+			PotAccounting {
+				seed: ONE,
+				distributed: 10 * ONE,
+				undistributed: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn migrate_to_latest_should_keep_rewards_without_shares_undistributed() {
+	legacy_ext().execute_with(|| {
+		StorageVersion::new(2).put::<Referrals>();
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		assert_eq!(
+			Referrals::reward_pot(),
+			PotAccounting {
+				seed: ONE,
+				distributed: 0,
+				undistributed: 10 * ONE,
+			}
+		);
+		assert_eq!(invariants::check::<Test>(), Ok(()));
+	});
+}
+
+#[test]
+fn migrate_to_latest_should_not_migrate_again_when_storage_is_migrated() {
+	legacy_ext().execute_with(|| {
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		assert_eq!(Referrals::reward_per_share(), FixedU128::from(1));
+		assert_eq!(invariants::check::<Test>(), Ok(()));
+	});
+}
+
+#[test]
+fn invariants_should_hold_when_rewards_are_claimed() {
+	legacy_ext().execute_with(|| {
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+
+		assert_eq!(invariants::check::<Test>(), Ok(()));
+	});
+}
+
+#[test]
+fn invariants_should_fail_when_total_shares_do_not_equal_account_shares() {
+	legacy_ext().execute_with(|| {
+		TotalShares::<Test>::mutate(|v| *v += 1);
+
+		assert!(invariants::total_shares_equal_account_shares::<Test>().is_err());
+	});
+}
+
+#[test]
+fn invariants_should_fail_when_pot_does_not_cover_rewards() {
+	legacy_ext().execute_with(|| {
+		RewardPerShare::<Test>::put(FixedU128::from(2));
+
+		assert!(invariants::pot_covers_rewards::<Test>().is_err());
+	});
+}
+
+#[test]
+fn invariants_should_fail_when_pot_balance_does_not_cover_accounting() {
+	legacy_ext().execute_with(|| {
+		MigrateToLatest::<Test, SeedAmount>::on_runtime_upgrade();
+
+		RewardPot::<Test>::mutate(|pot| pot.seed += 1);
+
+		assert!(invariants::pot_balance_covers_accounting::<Test>().is_err());
+	});
+}
@@ -0,0 +1,132 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const EXTERNAL: AccountId = 12345;
+
+fn rewards() -> HashMap<Level, FeeDistribution> {
+	let mut rewards = HashMap::new();
+	rewards.insert(
+		Level::None,
+		FeeDistribution {
+			referrer: Default::default(),
+			trader: Permill::from_percent(5),
+			external: Permill::from_percent(45),
+			lp: Permill::zero(),
+		},
+	);
+	rewards.insert(
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(5),
+			trader: Permill::from_percent(5),
+			external: Permill::from_percent(40),
+			lp: Permill::zero(),
+		},
+	);
+	rewards
+}
+
+fn link_bob() {
+	let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+}
+
+fn trade_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_global_tier_rewards(rewards())
+		.with_external_account(EXTERNAL)
+		.build()
+}
+
+#[test]
+fn set_opt_out_should_work_when_origin_is_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), true));
+		// Assert
+		assert!(Referrals::is_opted_out(&BOB));
+		expect_events(vec![Event::OptOutUpdated {
+			who: BOB,
+			opted_out: true,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_opt_out_should_opt_trader_back_in_when_false_is_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), true));
+		// Act
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), false));
+		// Assert
+		assert!(!Referrals::is_opted_out(&BOB));
+	});
+}
+
+#[test]
+fn set_opt_out_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_opt_out(RuntimeOrigin::root(), true),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_not_take_fee_when_trader_opted_out() {
+	trade_ext().execute_with(|| {
+		// Arrange
+		link_bob();
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), true));
+		let pot_balance = Tokens::free_balance(DAI, &Pallet::<Test>::pot_account_id());
+		// Act
+		assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(DAI, &Pallet::<Test>::pot_account_id()),
+			pot_balance
+		);
+		assert_eq!(TraderShares::<Test>::get(BOB), 0);
+		assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+		assert_eq!(TraderShares::<Test>::get(EXTERNAL), 0);
+		assert_eq!(TotalShares::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_take_fee_when_trader_opted_back_in() {
+	trade_ext().execute_with(|| {
+		// Arrange
+		link_bob();
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), true));
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), false));
+		// Act
+		assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+		// Assert
+		assert_eq!(TraderShares::<Test>::get(BOB), 500_000_000);
+		assert_eq!(ReferrerShares::<Test>::get(ALICE), 500_000_000);
+		assert_eq!(TotalShares::<Test>::get(), 5_000_000_000);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_keep_accrued_shares_when_trader_opted_out() {
+	trade_ext().execute_with(|| {
+		// Arrange
+		link_bob();
+		assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+		let bob_shares = TraderShares::<Test>::get(BOB);
+		assert!(bob_shares > 0);
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), true));
+		// Act
+		assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000));
+		// Assert
+		assert_eq!(TraderShares::<Test>::get(BOB), bob_shares);
+	});
+}
@@ -0,0 +1,143 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn trader_rewards() -> Vec<(AssetId, Level, FeeDistribution)> {
+	vec![(
+		DAI,
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(50),
+			trader: Permill::from_percent(20),
+			external: Permill::zero(),
+			lp: Permill::zero(),
+		},
+	)]
+}
+
+fn link_bob_to_alice() {
+	let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone(),));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+}
+
+#[test]
+fn set_trader_rebate_cap_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::set_trader_rebate_cap(RuntimeOrigin::root(), Some(1_000_000)));
+		// Assert
+		assert_eq!(Referrals::trader_rebate_cap(), Some(1_000_000));
+		expect_events(vec![Event::TraderRebateCapUpdated { cap: Some(1_000_000) }.into()]);
+	});
+}
+
+#[test]
+fn set_trader_rebate_cap_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_trader_rebate_cap(RuntimeOrigin::signed(ALICE), Some(1_000_000)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_not_track_trader_rebate_when_no_cap_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(trader_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(BOB), 2_000_000_000);
+			assert_eq!(Referrals::trader_rebate_usage(BOB), TraderRebateUsage::default());
+		});
+}
+
+#[test]
+fn process_trade_fee_should_limit_trader_shares_when_cap_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(trader_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_trader_rebate_cap(
+				RuntimeOrigin::root(),
+				Some(3_000_000_000)
+			));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(BOB), 3_000_000_000);
+			assert_eq!(
+				Referrals::trader_rebate_usage(BOB),
+				TraderRebateUsage {
+					period: 0,
+					accrued: 3_000_000_000,
+				}
+			);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_accrue_referrer_shares_when_trader_cap_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(trader_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_trader_rebate_cap(
+				RuntimeOrigin::root(),
+				Some(2_000_000_000)
+			));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(BOB), 2_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 10_000_000_000);
+			assert_eq!(Referrals::total_shares(), 12_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_accrue_trader_shares_again_when_new_period_starts() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(trader_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			link_bob_to_alice();
+			assert_ok!(Referrals::set_trader_rebate_cap(
+				RuntimeOrigin::root(),
+				Some(2_000_000_000)
+			));
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Act
+			System::set_block_number(TraderRebatePeriod::get());
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(TraderShares::<Test>::get(BOB), 4_000_000_000);
+			assert_eq!(
+				Referrals::trader_rebate_usage(BOB),
+				TraderRebateUsage {
+					period: 1,
+					accrued: 2_000_000_000,
+				}
+			);
+		});
+}
@@ -0,0 +1,184 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn top_up_seed_should_transfer_amount_to_pot_and_increase_seed() {
+	ExtBuilder::default().with_seed_amount(ONE).build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::top_up_seed(RuntimeOrigin::signed(ALICE), 10 * ONE));
+		// Assert
+		assert_balance!(ALICE, HDX, INITIAL_ALICE_BALANCE - 10 * ONE);
+		assert_balance!(Pallet::<Test>::pot_account_id(), HDX, 11 * ONE);
+		assert_eq!(
+			Referrals::reward_pot(),
+			PotAccounting {
+				seed: 11 * ONE,
+				distributed: 0,
+				undistributed: 0,
+			}
+		);
+		expect_events(vec![Event::SeedToppedUp {
+			who: ALICE,
+			amount: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn top_up_seed_should_fail_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::top_up_seed(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::ZeroAmount
+		);
+	});
+}
+
+#[test]
+fn withdraw_seed_should_transfer_amount_from_pot_and_decrease_seed() {
+	ExtBuilder::default()
+		.with_seed_amount(10 * ONE)
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::withdraw_seed(RuntimeOrigin::root(), 4 * ONE, CHARLIE));
+			// Assert
+			assert_balance!(CHARLIE, HDX, 4 * ONE);
+			assert_balance!(Pallet::<Test>::pot_account_id(), HDX, 6 * ONE);
+			assert_eq!(Referrals::reward_pot().seed, 6 * ONE);
+			expect_events(vec![Event::SeedWithdrawn {
+				dest: CHARLIE,
+				amount: 4 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn withdraw_seed_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default()
+		.with_seed_amount(10 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Referrals::withdraw_seed(RuntimeOrigin::signed(ALICE), ONE, ALICE),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn withdraw_seed_should_fail_when_amount_exceeds_seed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20 * ONE)])
+		.with_trader_shares(vec![(BOB, 20 * ONE)])
+		.with_seed_amount(10 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Referrals::withdraw_seed(RuntimeOrigin::root(), 10 * ONE + 1, CHARLIE),
+				Error::<Test>::InsufficientSeed
+			);
+		});
+}
+
+#[test]
+fn withdraw_seed_should_fail_when_seed_is_used_to_sponsor_existential_deposits() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 1_000_000_000)])
+		.with_trader_shares(vec![(BOB, 1_000_000_000)])
+		.with_seed_amount(10 * ONE)
+		.with_existential_deposit(ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Act & assert
+			assert_noop!(
+				Referrals::withdraw_seed(RuntimeOrigin::root(), 9_002_000_000_000, CHARLIE),
+				Error::<Test>::InsufficientSeed
+			);
+		});
+}
+
+#[test]
+fn withdraw_seed_should_not_affect_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20 * ONE)])
+		.with_trader_shares(vec![(BOB, 20 * ONE)])
+		.with_seed_amount(10 * ONE)
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::withdraw_seed(RuntimeOrigin::root(), 10 * ONE, CHARLIE));
+			// Assert
+			assert_eq!(Referrals::pending_rewards(&BOB), 20 * ONE);
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			assert_balance!(BOB, HDX, 20 * ONE);
+			assert_eq!(
+				Referrals::reward_pot(),
+				PotAccounting {
+					seed: 0,
+					distributed: 0,
+					undistributed: 0,
+				}
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_should_decrease_distributed_amount() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20 * ONE)])
+		.with_trader_shares(vec![(BOB, 5 * ONE), (ALICE, 15 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Referrals::reward_pot().distributed, 15 * ONE);
+		});
+}
+
+#[test]
+fn distribute_rewards_should_keep_amount_undistributed_when_there_are_no_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Referrals::reward_per_share(), FixedU128::zero());
+			assert_eq!(
+				Referrals::reward_pot(),
+				PotAccounting {
+					seed: 0,
+					distributed: 0,
+					undistributed: 20 * ONE,
+				}
+			);
+		});
+}
+
+#[test]
+fn distribute_rewards_should_distribute_undistributed_amount_with_next_amount() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), HDX, 20 * ONE),
+			(TREASURY, HDX, 20 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::fund_rewards(&TREASURY, &CHARLIE, 20 * ONE));
+			// Assert
+			assert_eq!(Referrals::pending_rewards(&CHARLIE), 40 * ONE);
+			assert_eq!(
+				Referrals::reward_pot(),
+				PotAccounting {
+					seed: 0,
+					distributed: 40 * ONE,
+					undistributed: 0,
+				}
+			);
+		});
+}
@@ -14,6 +14,7 @@ fn setting_asset_tier_should_fail_when_not_correct_origin() {
 					referrer: Permill::from_percent(1),
 					trader: Permill::from_percent(2),
 					external: Permill::from_percent(2),
+					lp: Permill::zero(),
 				}
 			),
 			BadOrigin
@@ -32,6 +33,7 @@ fn setting_asset_tier_should_correctly_update_storage() {
 				referrer: Permill::from_percent(1),
 				trader: Permill::from_percent(2),
 				external: Permill::from_percent(3),
+				lp: Permill::zero(),
 			}
 		));
 		let d = AssetRewards::<Test>::get(DAI, Level::Tier0);
@@ -41,6 +43,7 @@ fn setting_asset_tier_should_correctly_update_storage() {
 				referrer: Permill::from_percent(1),
 				trader: Permill::from_percent(2),
 				external: Permill::from_percent(3),
+				lp: Permill::zero(),
 			})
 		)
 	});
@@ -58,6 +61,7 @@ fn setting_asset_tier_should_fail_when_total_percentage_exceeds_hundred_percent(
 					referrer: Permill::from_percent(60),
 					trader: Permill::from_percent(40),
 					external: Permill::from_percent(10),
+					lp: Permill::zero(),
 				}
 			),
 			Error::<Test>::IncorrectRewardPercentage
@@ -76,6 +80,7 @@ fn setting_asset_tier_should_emit_event() {
 				referrer: Permill::from_percent(1),
 				trader: Permill::from_percent(2),
 				external: Permill::from_percent(3),
+				lp: Permill::zero(),
 			}
 		));
 		expect_events(vec![Event::AssetRewardsUpdated {
@@ -85,6 +90,7 @@ fn setting_asset_tier_should_emit_event() {
 				referrer: Permill::from_percent(1),
 				trader: Permill::from_percent(2),
 				external: Permill::from_percent(3),
+				lp: Permill::zero(),
 			},
 		}
 		.into()]);
@@ -13,6 +13,7 @@ fn process_trade_fee_should_increased_referrer_shares() {
 				referrer: Permill::from_percent(50),
 				trader: Permill::zero(),
 				external: Permill::zero(),
+				lp: Permill::zero(),
 			},
 		)])
 		.build()
@@ -41,6 +42,7 @@ fn process_trade_fee_should_increased_trader_shares() {
 				referrer: Permill::from_percent(50),
 				trader: Permill::from_percent(20),
 				external: Permill::zero(),
+				lp: Permill::zero(),
 			},
 		)])
 		.build()
@@ -69,6 +71,7 @@ fn process_trade_fee_should_increased_total_share_issuance() {
 				referrer: Permill::from_percent(50),
 				trader: Permill::from_percent(20),
 				external: Permill::zero(),
+				lp: Permill::zero(),
 			},
 		)])
 		.build()
@@ -97,6 +100,7 @@ fn process_trade_fee_should_fail_when_taken_amount_is_greater_than_fee_amount()
 				referrer: Permill::from_percent(50),
 				trader: Permill::from_percent(70),
 				external: Permill::zero(),
+				lp: Permill::zero(),
 			},
 		)])
 		.build()
@@ -147,6 +151,7 @@ fn process_trade_fee_should_add_asset_to_asset_list() {
 				referrer: Permill::from_percent(50),
 				trader: Permill::from_percent(20),
 				external: Permill::zero(),
+				lp: Permill::zero(),
 			},
 		)])
 		.build()
@@ -175,6 +180,7 @@ fn process_trade_fee_should_not_add_reward_asset_to_asset_list() {
 				referrer: Permill::from_percent(50),
 				trader: Permill::from_percent(20),
 				external: Permill::zero(),
+				lp: Permill::zero(),
 			},
 		)])
 		.build()
@@ -200,6 +206,7 @@ fn process_trade_fee_should_increase_external_account_shares_when_trader_has_no_
 			referrer: Default::default(),
 			trader: Default::default(),
 			external: Permill::from_percent(50),
+			lp: Permill::zero(),
 		},
 	);
 
@@ -229,6 +236,7 @@ fn process_trade_fee_should_not_store_zero_trader_reward_in_storage() {
 			referrer: Default::default(),
 			trader: Default::default(),
 			external: Permill::from_percent(50),
+			lp: Permill::zero(),
 		},
 	);
 
@@ -254,6 +262,7 @@ fn process_trade_fee_should_transfer_fee_to_pot_when_no_code_linked() {
 			referrer: Default::default(),
 			trader: Default::default(),
 			external: Permill::from_percent(50),
+			lp: Permill::zero(),
 		},
 	);
 
@@ -281,6 +290,7 @@ fn process_trade_fee_should_reward_all_parties_based_on_global_config_when_asset
 			referrer: Default::default(),
 			trader: Default::default(),
 			external: Permill::from_percent(50),
+			lp: Permill::zero(),
 		},
 	);
 	global_rewards.insert(
@@ -289,6 +299,7 @@ fn process_trade_fee_should_reward_all_parties_based_on_global_config_when_asset
 			referrer: Permill::from_percent(5),
 			trader: Permill::from_percent(5),
 			external: Permill::from_percent(40),
+			lp: Permill::zero(),
 		},
 	);
 	ExtBuilder::default()
@@ -330,6 +341,7 @@ fn process_trade_fee_should_use_configured_asset_instead_of_global_when_set() {
 			referrer: Default::default(),
 			trader: Default::default(),
 			external: Permill::from_percent(50),
+			lp: Permill::zero(),
 		},
 	);
 	global_rewards.insert(
@@ -338,6 +350,7 @@ fn process_trade_fee_should_use_configured_asset_instead_of_global_when_set() {
 			referrer: Permill::from_percent(5),
 			trader: Permill::from_percent(5),
 			external: Permill::from_percent(40),
+			lp: Permill::zero(),
 		},
 	);
 	ExtBuilder::default()
@@ -350,6 +363,7 @@ fn process_trade_fee_should_use_configured_asset_instead_of_global_when_set() {
 				referrer: Permill::from_percent(10),
 				trader: Permill::from_percent(5),
 				external: Permill::from_percent(30),
+				lp: Permill::zero(),
 			},
 		)])
 		.with_global_tier_rewards(global_rewards)
@@ -373,3 +387,73 @@ fn process_trade_fee_should_use_configured_asset_instead_of_global_when_set() {
 			assert_eq!(shares, 3_000_000_000 + 1_000_000_000 + 500_000_000);
 		});
 }
+
+#[test]
+fn process_trade_fee_should_distribute_reward_asset_fee_to_all_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 2_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			HDX,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::from_percent(20),
+				external: Permill::zero(),
+				lp: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone(),));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			// Act
+			assert_ok!(MockAmm::trade(
+				RuntimeOrigin::signed(BOB),
+				DAI,
+				HDX,
+				1_000_000_000_000_000_000,
+			));
+			// Assert
+			assert_eq!(RewardPerShare::<Test>::get(), FixedU128::one());
+			assert_eq!(Referrals::pending_rewards(&ALICE), 5_000_000_000);
+			assert_eq!(Referrals::pending_rewards(&BOB), 2_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_accrue_shares_at_current_reward_per_share() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, DAI, 2_000_000_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000),
+		])
+		.with_trader_shares(vec![(CHARLIE, 10_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::zero(),
+				external: Permill::zero(),
+				lp: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone(),));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 5_000_000_000);
+			assert_eq!(ReferrerRewardCheckpoints::<Test>::get(ALICE), FixedU128::one());
+			assert_eq!(Referrals::pending_rewards(&ALICE), 0);
+			assert_eq!(Referrals::pending_rewards(&CHARLIE), 10_000_000_000_000);
+		});
+}
@@ -0,0 +1,71 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const FEE: Balance = 100_000_000_000_000_000;
+
+fn transaction_fee_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::from_percent(20),
+				external: Permill::zero(),
+				lp: Permill::zero(),
+			},
+		)])
+		.build()
+}
+
+fn link_bob_to_alice() {
+	let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone(),));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+}
+
+#[test]
+fn process_transaction_fee_should_increase_referrer_and_trader_shares() {
+	transaction_fee_ext().execute_with(|| {
+		// Arrange
+		link_bob_to_alice();
+		// Act
+		assert_ok!(Referrals::process_transaction_fee(TREASURY, BOB, DAI, FEE));
+		// Assert
+		assert_eq!(ReferrerShares::<Test>::get(ALICE), 5_000_000_000);
+		assert_eq!(TraderShares::<Test>::get(BOB), 2_000_000_000);
+		assert_eq!(Referrals::total_shares(), 7_000_000_000);
+	});
+}
+
+#[test]
+fn process_transaction_fee_should_transfer_portion_of_fee_to_pot() {
+	transaction_fee_ext().execute_with(|| {
+		// Arrange
+		link_bob_to_alice();
+		// Act
+		let used = Referrals::process_transaction_fee(TREASURY, BOB, DAI, FEE).unwrap();
+		// Assert
+		assert_eq!(used, 7_000_000_000_000_000);
+		assert_balance!(Referrals::pot_account_id(), DAI, used);
+		assert_balance!(TREASURY, DAI, 1_000_000_000_000_000_000 - used);
+		assert!(PendingConversions::<Test>::contains_key(DAI));
+	});
+}
+
+#[test]
+fn process_transaction_fee_should_not_take_fee_when_payer_opted_out() {
+	transaction_fee_ext().execute_with(|| {
+		// Arrange
+		link_bob_to_alice();
+		assert_ok!(Referrals::set_opt_out(RuntimeOrigin::signed(BOB), true));
+		// Act
+		let used = Referrals::process_transaction_fee(TREASURY, BOB, DAI, FEE).unwrap();
+		// Assert
+		assert_eq!(used, 0);
+		assert_eq!(Referrals::total_shares(), 0);
+		assert_balance!(TREASURY, DAI, 1_000_000_000_000_000_000);
+	});
+}
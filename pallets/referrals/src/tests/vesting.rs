@@ -0,0 +1,115 @@
+use crate::tests::*;
+use hydradx_traits::vesting::RewardVesting;
+use pretty_assertions::assert_eq;
+
+const VESTING: RewardVesting = RewardVesting {
+	cliff: 100,
+	period: 10,
+	period_count: 5,
+};
+
+#[test]
+fn set_reward_vesting_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+		// Assert
+		assert_eq!(Referrals::reward_vesting(), Some(VESTING));
+		expect_events(vec![Event::RewardVestingUpdated { vesting: Some(VESTING) }.into()]);
+	});
+}
+
+#[test]
+fn set_reward_vesting_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_reward_vesting(RuntimeOrigin::signed(ALICE), Some(VESTING)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_reward_vesting_should_fail_when_schedule_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_reward_vesting(
+				RuntimeOrigin::root(),
+				Some(RewardVesting {
+					cliff: 100,
+					period: 0,
+					period_count: 5,
+				})
+			),
+			Error::<Test>::InvalidRewardVesting
+		);
+		assert_noop!(
+			Referrals::set_reward_vesting(
+				RuntimeOrigin::root(),
+				Some(RewardVesting {
+					cliff: 100,
+					period: 10,
+					period_count: 0,
+				})
+			),
+			Error::<Test>::InvalidRewardVesting
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_pay_rewards_as_vesting_schedule_when_vesting_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 5_000_000_000_000);
+			assert_eq!(
+				VESTED_TRANSFERS.with(|v| v.borrow().clone()),
+				vec![(BOB, 5_000_000_000_000, VESTING)]
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_should_transfer_rewards_instantly_when_vesting_is_removed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+			assert_ok!(Referrals::set_reward_vesting(RuntimeOrigin::root(), None));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 5_000_000_000_000);
+			assert!(VESTED_TRANSFERS.with(|v| v.borrow().is_empty()));
+		});
+}
+
+#[test]
+fn claim_rewards_should_charge_vested_transfer_weight_when_vesting_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+			// Act
+			let post_info = Referrals::claim_rewards(RuntimeOrigin::signed(BOB)).unwrap();
+			// Assert
+			assert_eq!(
+				post_info.actual_weight,
+				Some(<Test as Config>::WeightInfo::claim_rewards().saturating_add(VESTED_TRANSFER_WEIGHT))
+			);
+		});
+}
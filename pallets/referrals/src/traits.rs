@@ -1,3 +1,5 @@
+use frame_support::dispatch::DispatchResult;
+
 pub trait Convert<AccountId, AssetId, Balance> {
 	type Error;
 
@@ -6,8 +8,26 @@ pub trait Convert<AccountId, AssetId, Balance> {
 }
 
 #[cfg(feature = "runtime-benchmarks")]
-pub trait BenchmarkHelper<AssetId, Balance> {
+pub trait BenchmarkHelper<AssetId, Balance, AccountId, PositionId> {
 	// Should prepare everything that provides price for selected asset
 	// Amount returned is minted into pot account in benchmarks.
 	fn prepare_convertible_asset_and_amount() -> (AssetId, Balance);
+
+	// Should prepare liquidity position with non-zero shares.
+	// Returns owner, asset and id of the position.
+	fn prepare_liquidity_position() -> (AccountId, AssetId, PositionId);
+}
+
+/// Provides liquidity positions whose owners are eligible for LP rewards.
+pub trait LiquidityPositions<AccountId, AssetId, PositionId> {
+	/// Returns asset and quantity of shares of given position if the position is owned by `who`.
+	fn position_shares(who: &AccountId, position_id: PositionId) -> Option<(AssetId, u128)>;
+
+	/// Returns total quantity of LP shares of an asset.
+	fn total_shares(asset_id: AssetId) -> u128;
+}
+
+pub trait Freeze<AccountId, CollectionId> {
+	/// Freezes given collection so its items are not transferable.
+	fn freeze_collection(owner: AccountId, collection: CollectionId) -> DispatchResult;
 }
@@ -55,6 +55,14 @@ pub trait WeightInfo {
 	fn convert() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn set_reward_percentage() -> Weight;
+	fn set_era_budget() -> Weight;
+	fn claim_lp_rewards() -> Weight;
+	fn set_reward_vesting() -> Weight;
+	fn set_external_account() -> Weight;
+	fn set_opt_out() -> Weight;
+	fn top_up_seed() -> Weight;
+	fn withdraw_seed() -> Weight;
+	fn set_trader_rebate_cap() -> Weight;
 }
 
 /// Weights for pallet_referrals using the hydraDX node and recommended hardware.
@@ -92,7 +100,7 @@ impl WeightInfo for () {
 	/// Storage: `Tokens::Accounts` (r:2 w:2)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:2 w:2)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -129,14 +137,20 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::CounterForPendingConversions` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:0 w:1)
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CurrentEraRewards` (r:1 w:1)
+	/// Proof: `Referrals::CurrentEraRewards` (`max_values`: Some(1), `max_size`: Some(68), added: 563, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::EraRewardHistory` (r:0 w:1)
+	/// Proof: `Referrals::EraRewardHistory` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPot` (r:1 w:1)
+	/// Proof: `Referrals::RewardPot` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
 	fn convert() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2434`
 		//  Estimated: `8790`
 		// Minimum execution time: 267_679_000 picoseconds.
 		Weight::from_parts(269_271_000, 8790)
-			.saturating_add(RocksDbWeight::get().reads(28_u64))
-			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
 	}
 	/// Storage: `Referrals::PendingConversions` (r:1 w:0)
 	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
@@ -150,17 +164,39 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::Referrer` (r:1 w:1)
 	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardVestingSchedule` (r:1 w:0)
+	/// Proof: `Referrals::RewardVestingSchedule` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Badges` (r:4 w:4)
+	/// Proof: `Referrals::Badges` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::NextBadgeId` (r:1 w:1)
+	/// Proof: `Referrals::NextBadgeId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:4 w:4)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::CollectionMaxSupply` (r:4 w:0)
+	/// Proof: `Uniques::CollectionMaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::ClassAccount` (r:0 w:1)
+	/// Proof: `Uniques::ClassAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:4)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CurrentEraRewards` (r:1 w:1)
+	/// Proof: `Referrals::CurrentEraRewards` (`max_values`: Some(1), `max_size`: Some(68), added: 563, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::EraRewardHistory` (r:0 w:1)
+	/// Proof: `Referrals::EraRewardHistory` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPot` (r:1 w:1)
+	/// Proof: `Referrals::RewardPot` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `689`
 		//  Estimated: `6196`
-		// Minimum execution time: 78_198_000 picoseconds.
-		Weight::from_parts(78_998_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		// Minimum execution time: 171_532_000 picoseconds.
+		Weight::from_parts(173_104_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
 	}
 	/// Storage: `Referrals::AssetRewards` (r:1 w:1)
-	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	fn set_reward_percentage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
@@ -170,4 +206,122 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Referrals::EraBudgetUsed` (r:1 w:1)
+	/// Proof: `Referrals::EraBudgetUsed` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::EraBudget` (r:0 w:1)
+	/// Proof: `Referrals::EraBudget` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_era_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `1506`
+		// Minimum execution time: 10_543_000 picoseconds.
+		Weight::from_parts(10_870_000, 1506)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Omnipool::Positions` (r:1 w:0)
+	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LpRewardPerShare` (r:1 w:0)
+	/// Proof: `Referrals::LpRewardPerShare` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LpRewardCheckpoints` (r:1 w:1)
+	/// Proof: `Referrals::LpRewardCheckpoints` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::PendingConversions` (r:1 w:0)
+	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferrerShares` (r:1 w:1)
+	/// Proof: `Referrals::ReferrerShares` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::TraderShares` (r:2 w:2)
+	/// Proof: `Referrals::TraderShares` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::TotalShares` (r:1 w:1)
+	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:1 w:1)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardVestingSchedule` (r:1 w:0)
+	/// Proof: `Referrals::RewardVestingSchedule` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CurrentEraRewards` (r:1 w:1)
+	/// Proof: `Referrals::CurrentEraRewards` (`max_values`: Some(1), `max_size`: Some(68), added: 563, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::EraRewardHistory` (r:0 w:1)
+	/// Proof: `Referrals::EraRewardHistory` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPot` (r:1 w:1)
+	/// Proof: `Referrals::RewardPot` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	fn claim_lp_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6196`
+		// Minimum execution time: 106_073_000 picoseconds.
+		Weight::from_parts(109_354_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Referrals::RewardVestingSchedule` (r:0 w:1)
+	/// Proof: `Referrals::RewardVestingSchedule` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_reward_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `0`
+		// Minimum execution time: 9_129_000 picoseconds.
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::LevelExternalAccounts` (r:0 w:1)
+	/// Proof: `Referrals::LevelExternalAccounts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_external_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `0`
+		// Minimum execution time: 9_215_000 picoseconds.
+		Weight::from_parts(9_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::OptedOutTraders` (r:0 w:1)
+	/// Proof: `Referrals::OptedOutTraders` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_opt_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `0`
+		// Minimum execution time: 8_804_000 picoseconds.
+		Weight::from_parts(9_071_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPot` (r:1 w:1)
+	/// Proof: `Referrals::RewardPot` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	fn top_up_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `300`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_118_000 picoseconds.
+		Weight::from_parts(53_004_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::SponsoredTotal` (r:1 w:0)
+	/// Proof: `Duster::SponsoredTotal` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPot` (r:1 w:1)
+	/// Proof: `Referrals::RewardPot` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	fn withdraw_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `6196`
+		// Minimum execution time: 55_760_000 picoseconds.
+		Weight::from_parts(56_781_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Referrals::TraderRebateCap` (r:0 w:1)
+	/// Proof: `Referrals::TraderRebateCap` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_trader_rebate_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_212_000 picoseconds.
+		Weight::from_parts(6_430_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,41 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+
+// Weight of the dispatched call is added to the weight of `dispatch_intent` separately,
+// so only the conversion of the intent and the event are benchmarked.
+benchmarks! {
+	convert_intent{
+		let caller: T::AccountId = whitelisted_caller();
+		let intent = T::BenchmarkHelper::intent();
+	}: {
+		let call = T::IntentConverter::convert(caller.clone(), intent.clone().into_latest());
+		assert!(call.is_some());
+		Pallet::<T>::deposit_event(Event::IntentDispatched { who: caller, intent });
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Remote intents pallet
+//!
+//! Dispatch of typed intents sent by remote chains.
+//!
+//! ## Overview
+//!
+//! Remote chains calling the pallets of HydraDX by XCM `Transact` have to encode the calls by the pallet and call
+//! indices of the runtime. These change whenever the call enum of a pallet is reordered or a pallet is replaced,
+//! which silently breaks the integrations of sibling chains.
+//!
+//! Instead, remote chains can send a `VersionedRemoteIntent` to `dispatch_intent` - a SCALE-encoded description of
+//! what should be done (swap, add liquidity, schedule DCA) with an encoding which never changes. The intent is
+//! converted to the runtime call by `IntentConverter` and dispatched with the origin of the caller, so the call
+//! is executed on behalf of the account the XCM origin is converted to.
+//!
+//! The pallet itself is called by index, so its index in the runtime and the index of `dispatch_intent` must
+//! never change.
+//!
+//! Intents of older versions are converted to the latest version before they are passed to `IntentConverter`.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `dispatch_intent` - Converts the intent to the runtime call and dispatches it on behalf of the caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use codec::Decode;
+use frame_support::dispatch::{extract_actual_weight, DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo};
+use frame_support::pallet_prelude::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
+use sp_std::boxed::Box;
+
+pub use pallet::*;
+pub use types::*;
+
+pub use weights::WeightInfo;
+
+pub type RemoteIntentOf<T> = RemoteIntent<<T as Config>::AssetId, <T as Config>::Balance, BlockNumberFor<T>>;
+pub type VersionedRemoteIntentOf<T> =
+	VersionedRemoteIntent<<T as Config>::AssetId, <T as Config>::Balance, BlockNumberFor<T>>;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, Balance, BlockNumber> {
+	/// Intent supported by `IntentConverter`.
+	fn intent() -> VersionedRemoteIntent<AssetId, Balance, BlockNumber>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>, Balance: From<u128>, BlockNumber> BenchmarkHelper<AssetId, Balance, BlockNumber> for () {
+	fn intent() -> VersionedRemoteIntent<AssetId, Balance, BlockNumber> {
+		RemoteIntentV1::Swap {
+			asset_in: 0u32.into(),
+			asset_out: 1u32.into(),
+			amount_in: 1_000_000_000_000u128.into(),
+			min_amount_out: 0u128.into(),
+		}
+		.into()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type. Intents are converted to and dispatched as this type.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+
+		/// Asset id type.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Balance type.
+		type Balance: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Converts intents to runtime calls.
+		type IntentConverter: ConvertIntent<Self::AccountId, RemoteIntentOf<Self>, <Self as Config>::RuntimeCall>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::Balance, BlockNumberFor<Self>>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Intent was dispatched on behalf of `who`.
+		IntentDispatched {
+			who: T::AccountId,
+			intent: VersionedRemoteIntentOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Intent is not supported by the runtime.
+		UnsupportedIntent,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch intent on behalf of the caller.
+		///
+		/// The intent is converted to the runtime call and dispatched with the origin of the caller.
+		/// Fails if the intent is not supported or if the dispatched call fails.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin
+		/// - `intent`: versioned intent
+		///
+		/// Emits `IntentDispatched` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::convert_intent().saturating_add(Pallet::<T>::intent_weight(intent)))]
+		pub fn dispatch_intent(
+			origin: OriginFor<T>,
+			intent: Box<VersionedRemoteIntentOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			let call = T::IntentConverter::convert(who.clone(), intent.clone().into_latest())
+				.ok_or(Error::<T>::UnsupportedIntent)?;
+
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(origin);
+			let actual_weight = T::WeightInfo::convert_intent().saturating_add(extract_actual_weight(&result, &info));
+
+			result.map_err(|e| DispatchErrorWithPostInfo {
+				post_info: Some(actual_weight).into(),
+				error: e.error,
+			})?;
+
+			Self::deposit_event(Event::IntentDispatched { who, intent: *intent });
+
+			Ok(Some(actual_weight).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Weight of the call the intent is converted to. Zero if the intent is not supported.
+	fn intent_weight(intent: &VersionedRemoteIntentOf<T>) -> Weight {
+		// weight of the call doesn't depend on the caller, so the intent can be converted on behalf of any account
+		T::AccountId::decode(&mut TrailingZeroInput::zeroes())
+			.ok()
+			.and_then(|who| T::IntentConverter::convert(who, intent.clone().into_latest()))
+			.map(|call| call.get_dispatch_info().weight)
+			.unwrap_or_default()
+	}
+}
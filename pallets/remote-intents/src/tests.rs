@@ -0,0 +1,132 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod dispatch;
+mod encoding;
+
+use crate as pallet_remote_intents;
+use crate::*;
+
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::Everything,
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u32;
+pub(crate) type Balance = u128;
+
+pub const ALICE: AccountId = 1;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		RemoteIntents: pallet_remote_intents,
+	}
+);
+
+/// Swaps are converted to remarks of the encoded intent, DCA schedules to a call which requires root origin.
+/// Adding liquidity is not supported.
+pub struct MockIntentConverter;
+
+impl ConvertIntent<AccountId, RemoteIntentOf<Test>, RuntimeCall> for MockIntentConverter {
+	fn convert(_who: AccountId, intent: RemoteIntentOf<Test>) -> Option<RuntimeCall> {
+		match intent {
+			RemoteIntent::Swap { .. } => Some(RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: intent.encode(),
+			})),
+			RemoteIntent::AddLiquidity { .. } => None,
+			RemoteIntent::ScheduleDca { .. } => {
+				Some(RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 }))
+			}
+		}
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type IntentConverter = MockIntentConverter;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+#[derive(Default)]
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| System::set_block_number(1));
+		r
+	}
+}
+
+pub fn swap_intent() -> VersionedRemoteIntentOf<Test> {
+	RemoteIntent::Swap {
+		asset_in: 0,
+		asset_out: 2,
+		amount_in: 1_000,
+		min_amount_out: 500,
+	}
+	.into()
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
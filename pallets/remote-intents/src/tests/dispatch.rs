@@ -0,0 +1,94 @@
+use super::*;
+use frame_support::dispatch::GetDispatchInfo;
+use pretty_assertions::assert_eq;
+use sp_runtime::traits::Hash;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn dispatch_intent_should_dispatch_converted_call_on_behalf_of_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(RemoteIntents::dispatch_intent(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(swap_intent())
+		));
+
+		// Assert
+		expect_events(vec![
+			frame_system::Event::Remarked {
+				sender: ALICE,
+				hash: BlakeTwo256::hash(&swap_intent().into_latest().encode()),
+			}
+			.into(),
+			Event::IntentDispatched {
+				who: ALICE,
+				intent: swap_intent(),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn dispatch_intent_should_fail_when_intent_is_not_supported() {
+	ExtBuilder::default().build().execute_with(|| {
+		let intent = RemoteIntent::AddLiquidity {
+			asset: 2,
+			amount: 1_000,
+			min_shares: 0,
+		};
+
+		assert_noop!(
+			RemoteIntents::dispatch_intent(RuntimeOrigin::signed(ALICE), Box::new(intent.into())),
+			Error::<Test>::UnsupportedIntent
+		);
+	});
+}
+
+#[test]
+fn dispatch_intent_should_fail_when_converted_call_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let intent = RemoteIntent::ScheduleDca {
+			asset_in: 0,
+			asset_out: 2,
+			amount_in: 1_000,
+			min_amount_out: 0,
+			total_amount: 10_000,
+			period: 10,
+			max_retries: None,
+			slippage: None,
+		};
+
+		let result = RemoteIntents::dispatch_intent(RuntimeOrigin::signed(ALICE), Box::new(intent.into()));
+
+		assert_eq!(result.map_err(|e| e.error), Err(BadOrigin));
+		assert!(frame_system::Pallet::<Test>::events().is_empty());
+	});
+}
+
+#[test]
+fn dispatch_intent_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			RemoteIntents::dispatch_intent(RuntimeOrigin::root(), Box::new(swap_intent())),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn dispatch_intent_weight_should_include_weight_of_converted_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: swap_intent().into_latest().encode(),
+		});
+		let call = RuntimeCall::RemoteIntents(crate::Call::dispatch_intent {
+			intent: Box::new(swap_intent()),
+		});
+
+		assert_eq!(
+			call.get_dispatch_info().weight,
+			<() as WeightInfo>::convert_intent() + remark.get_dispatch_info().weight
+		);
+	});
+}
@@ -0,0 +1,77 @@
+use super::*;
+use codec::Decode;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+// Remote chains encode the intents on their side, so the encoding must never change.
+
+#[test]
+fn swap_intent_encoding_should_be_stable() {
+	let intent: VersionedRemoteIntentOf<Test> = RemoteIntent::Swap {
+		asset_in: 5,
+		asset_out: 10,
+		amount_in: 1_000,
+		min_amount_out: 900,
+	}
+	.into();
+
+	let mut expected = vec![1u8, 0u8];
+	expected.extend(5u32.encode());
+	expected.extend(10u32.encode());
+	expected.extend(1_000u128.encode());
+	expected.extend(900u128.encode());
+
+	assert_eq!(intent.encode(), expected);
+}
+
+#[test]
+fn add_liquidity_intent_encoding_should_be_stable() {
+	let intent: VersionedRemoteIntentOf<Test> = RemoteIntent::AddLiquidity {
+		asset: 5,
+		amount: 1_000,
+		min_shares: 900,
+	}
+	.into();
+
+	let mut expected = vec![1u8, 1u8];
+	expected.extend(5u32.encode());
+	expected.extend(1_000u128.encode());
+	expected.extend(900u128.encode());
+
+	assert_eq!(intent.encode(), expected);
+}
+
+#[test]
+fn schedule_dca_intent_encoding_should_be_stable() {
+	let intent: VersionedRemoteIntentOf<Test> = RemoteIntent::ScheduleDca {
+		asset_in: 5,
+		asset_out: 10,
+		amount_in: 1_000,
+		min_amount_out: 900,
+		total_amount: 10_000,
+		period: 20,
+		max_retries: Some(3),
+		slippage: Some(Permill::from_percent(1)),
+	}
+	.into();
+
+	let mut expected = vec![1u8, 2u8];
+	expected.extend(5u32.encode());
+	expected.extend(10u32.encode());
+	expected.extend(1_000u128.encode());
+	expected.extend(900u128.encode());
+	expected.extend(10_000u128.encode());
+	expected.extend(20u64.encode());
+	expected.extend(Some(3u8).encode());
+	expected.extend(Some(Permill::from_percent(1)).encode());
+
+	assert_eq!(intent.encode(), expected);
+}
+
+#[test]
+fn intent_should_fail_to_decode_when_version_is_unknown() {
+	let mut encoded = swap_intent().encode();
+	encoded[0] = 2;
+
+	assert!(VersionedRemoteIntentOf::<Test>::decode(&mut &encoded[..]).is_err());
+}
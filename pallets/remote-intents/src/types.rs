@@ -0,0 +1,88 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_runtime::Permill;
+
+// Encoding of the intents is the interface used by remote chains.
+// Variants must never be reordered or removed and their fields must never change. New intents are appended with
+// a new index, incompatible changes are added as a new version of `VersionedRemoteIntent`.
+
+/// Intent of a remote chain, version 1.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RemoteIntentV1<AssetId, Balance, BlockNumber> {
+	/// Sell `amount_in` of `asset_in` for at least `min_amount_out` of `asset_out` by the on-chain route.
+	#[codec(index = 0)]
+	Swap {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	},
+	/// Add `amount` of `asset` as liquidity to the omnipool for at least `min_shares` shares.
+	#[codec(index = 1)]
+	AddLiquidity {
+		asset: AssetId,
+		amount: Balance,
+		min_shares: Balance,
+	},
+	/// Schedule a DCA selling `amount_in` of `asset_in` for `asset_out` every `period` blocks, until
+	/// `total_amount` is spent.
+	#[codec(index = 2)]
+	ScheduleDca {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		total_amount: Balance,
+		period: BlockNumber,
+		max_retries: Option<u8>,
+		slippage: Option<Permill>,
+	},
+}
+
+/// Latest version of the intent. Intents of older versions are converted to it before dispatch.
+pub type RemoteIntent<AssetId, Balance, BlockNumber> = RemoteIntentV1<AssetId, Balance, BlockNumber>;
+
+/// Intent of a remote chain of any supported version.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum VersionedRemoteIntent<AssetId, Balance, BlockNumber> {
+	#[codec(index = 1)]
+	V1(RemoteIntentV1<AssetId, Balance, BlockNumber>),
+}
+
+impl<AssetId, Balance, BlockNumber> VersionedRemoteIntent<AssetId, Balance, BlockNumber> {
+	pub fn into_latest(self) -> RemoteIntent<AssetId, Balance, BlockNumber> {
+		match self {
+			VersionedRemoteIntent::V1(intent) => intent,
+		}
+	}
+}
+
+impl<AssetId, Balance, BlockNumber> From<RemoteIntentV1<AssetId, Balance, BlockNumber>>
+	for VersionedRemoteIntent<AssetId, Balance, BlockNumber>
+{
+	fn from(intent: RemoteIntentV1<AssetId, Balance, BlockNumber>) -> Self {
+		VersionedRemoteIntent::V1(intent)
+	}
+}
+
+/// Converts intent to the runtime call dispatched on behalf of `who`.
+pub trait ConvertIntent<AccountId, Intent, Call> {
+	/// Returns `None` if the intent is not supported.
+	fn convert(who: AccountId, intent: Intent) -> Option<Call>;
+}
@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Revenue<Balance> {
+	/// Trade fees collected by the pools.
+	pub asset_fees: Balance,
+	/// Protocol fees collected by the omnipool.
+	pub protocol_fees: Balance,
+	/// Part of the trade fees taken by referrals.
+	pub referral_fees: Balance,
+	/// Fees deposited to the treasury.
+	pub treasury_inflows: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait RevenueStatsApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Index of the current era.
+		fn current_era() -> u32;
+		/// Index of the oldest era which has not been pruned yet.
+		fn oldest_era() -> u32;
+		/// Revenue collected in an era per asset. Empty if nothing was collected or the era was already pruned.
+		fn era_revenue(era: u32) -> Vec<(AssetId, Revenue<Balance>)>;
+	}
+}
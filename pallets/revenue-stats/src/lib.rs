@@ -0,0 +1,196 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Revenue stats pallet
+//!
+//! Per-era totals of protocol revenue.
+//!
+//! ## Overview
+//!
+//! The pallet aggregates revenue of the protocol per era and asset, so it can be queried by the runtime API
+//! without indexing all the trades off-chain. Following amounts are tracked:
+//!
+//! * asset fees - trade fees collected by the pools,
+//! * protocol fees - protocol fees collected by the omnipool, in hub asset,
+//! * referral fees - part of the trade fees taken by referrals,
+//! * treasury inflows - fees deposited to the treasury.
+//!
+//! The amounts are recorded by the runtime - trade fees by the trade hook and treasury inflows by the fee deposit.
+//!
+//! Revenue is kept for `RetentionEras` eras, including the current one. Older eras are pruned in `on_idle`, so
+//! pruning never takes weight of the transactions.
+//!
+//! The pallet has no dispatchable functions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::{Get, Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{CheckedDiv, SaturatedConversion, Saturating, Zero};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+pub type EraIndex = u32;
+
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Revenue<Balance> {
+	/// Trade fees collected by the pools.
+	pub asset_fees: Balance,
+	/// Protocol fees collected by the omnipool.
+	pub protocol_fees: Balance,
+	/// Part of the trade fees taken by referrals.
+	pub referral_fees: Balance,
+	/// Fees deposited to the treasury.
+	pub treasury_inflows: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use sp_runtime::traits::AtLeast32BitUnsigned;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Asset id type.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Balance type.
+		type Balance: Member + Parameter + AtLeast32BitUnsigned + Copy + MaxEncodedLen + Default;
+
+		/// Length of an era in blocks.
+		#[pallet::constant]
+		type EraLength: Get<BlockNumberFor<Self>>;
+
+		/// Number of eras, including the current one, the revenue is kept for.
+		#[pallet::constant]
+		type RetentionEras: Get<u32>;
+	}
+
+	/// Revenue collected in an era per asset.
+	#[pallet::storage]
+	#[pallet::getter(fn revenue)]
+	pub type EraRevenue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::AssetId, Revenue<T::Balance>, ValueQuery>;
+
+	/// Oldest era which has not been pruned yet.
+	#[pallet::storage]
+	#[pallet::getter(fn oldest_era)]
+	pub type OldestEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_expired(remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert!(!T::EraLength::get().is_zero(), "Era length cannot be zero.");
+			assert!(!T::RetentionEras::get().is_zero(), "Retention period cannot be zero.");
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns index of the current era.
+	pub fn current_era() -> EraIndex {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::EraLength::get())
+			.unwrap_or_default()
+			.saturated_into()
+	}
+
+	/// Returns revenue collected in an era per asset.
+	pub fn era_revenue(era: EraIndex) -> Vec<(T::AssetId, Revenue<T::Balance>)> {
+		EraRevenue::<T>::iter_prefix(era).collect()
+	}
+
+	/// Records trade fee collected by a pool.
+	pub fn note_asset_fee(asset: T::AssetId, amount: T::Balance) {
+		Self::record(asset, amount, |revenue| &mut revenue.asset_fees);
+	}
+
+	/// Records protocol fee collected by the omnipool.
+	pub fn note_protocol_fee(asset: T::AssetId, amount: T::Balance) {
+		Self::record(asset, amount, |revenue| &mut revenue.protocol_fees);
+	}
+
+	/// Records part of a trade fee taken by referrals.
+	pub fn note_referral_fee(asset: T::AssetId, amount: T::Balance) {
+		Self::record(asset, amount, |revenue| &mut revenue.referral_fees);
+	}
+
+	/// Records fee deposited to the treasury.
+	pub fn note_treasury_inflow(asset: T::AssetId, amount: T::Balance) {
+		Self::record(asset, amount, |revenue| &mut revenue.treasury_inflows);
+	}
+
+	fn record(asset: T::AssetId, amount: T::Balance, field: impl FnOnce(&mut Revenue<T::Balance>) -> &mut T::Balance) {
+		if amount.is_zero() {
+			return;
+		}
+		EraRevenue::<T>::mutate(Self::current_era(), asset, |revenue| {
+			field(revenue).saturating_accrue(amount);
+		});
+	}
+
+	/// Returns `true` if the era is out of the retention period.
+	fn is_expired(era: EraIndex) -> bool {
+		Self::current_era().saturating_sub(era) >= T::RetentionEras::get()
+	}
+
+	/// Removes revenue of the oldest era if the era is out of the retention period.
+	///
+	/// Removes only as many entries as fit in `max_weight`, the rest of the era is removed in the following blocks.
+	fn prune_expired(max_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// block number and the oldest era
+		let mut weight = db_weight.reads(2);
+		let max_removals = max_weight
+			.saturating_sub(weight)
+			.saturating_sub(db_weight.writes(1))
+			.ref_time()
+			.checked_div(db_weight.writes(1).ref_time())
+			.unwrap_or(u64::MAX);
+		if max_weight.any_lt(weight) || max_removals.is_zero() {
+			return Weight::zero();
+		}
+
+		let oldest = OldestEra::<T>::get();
+		if !Self::is_expired(oldest) {
+			return weight;
+		}
+
+		let result = EraRevenue::<T>::clear_prefix(oldest, max_removals.saturated_into(), None);
+		weight.saturating_accrue(db_weight.writes(result.backend.into()));
+
+		if result.maybe_cursor.is_none() {
+			OldestEra::<T>::put(oldest.saturating_add(1));
+			weight.saturating_accrue(db_weight.writes(1));
+		}
+
+		weight
+	}
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod pruning;
+mod recording;
+
+use crate as pallet_revenue_stats;
+use crate::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::{Everything, Hooks},
+	weights::RuntimeDbWeight,
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u32;
+pub(crate) type Balance = u128;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		RevenueStats: pallet_revenue_stats,
+	}
+);
+
+parameter_types! {
+	pub const EraLength: u64 = 10;
+	pub const RetentionEras: u32 = 3;
+	pub const MockDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 10, write: 100 };
+}
+
+impl Config for Test {
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type EraLength = EraLength;
+	type RetentionEras = RetentionEras;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = MockDbWeight;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+#[derive(Default)]
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| System::set_block_number(1));
+		r
+	}
+}
+
+pub fn on_idle(remaining_weight: Weight) -> Weight {
+	RevenueStats::on_idle(System::block_number(), remaining_weight)
+}
+
+pub fn revenue(asset_fees: Balance, protocol_fees: Balance, referral_fees: Balance) -> Revenue<Balance> {
+	Revenue {
+		asset_fees,
+		protocol_fees,
+		referral_fees,
+		treasury_inflows: 0,
+	}
+}
@@ -0,0 +1,108 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn on_idle_should_not_prune_eras_in_retention_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		RevenueStats::note_asset_fee(DAI, 1_000);
+		System::set_block_number(29);
+
+		// Act
+		on_idle(Weight::MAX);
+
+		// Assert
+		assert_eq!(RevenueStats::oldest_era(), 0);
+		assert_eq!(RevenueStats::revenue(0, DAI), revenue(1_000, 0, 0));
+	});
+}
+
+#[test]
+fn on_idle_should_prune_oldest_era_when_it_is_out_of_retention_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		RevenueStats::note_asset_fee(DAI, 1_000);
+		RevenueStats::note_asset_fee(HDX, 1_000);
+		System::set_block_number(10);
+		RevenueStats::note_asset_fee(DAI, 2_000);
+		System::set_block_number(30);
+
+		// Act
+		on_idle(Weight::MAX);
+
+		// Assert
+		assert_eq!(RevenueStats::oldest_era(), 1);
+		assert_eq!(RevenueStats::era_revenue(0), vec![]);
+		assert_eq!(RevenueStats::revenue(1, DAI), revenue(2_000, 0, 0));
+	});
+}
+
+#[test]
+fn on_idle_should_prune_one_era_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		RevenueStats::note_asset_fee(DAI, 1_000);
+		System::set_block_number(10);
+		RevenueStats::note_asset_fee(DAI, 2_000);
+		System::set_block_number(50);
+
+		// Act
+		on_idle(Weight::MAX);
+
+		// Assert
+		assert_eq!(RevenueStats::oldest_era(), 1);
+		assert_eq!(RevenueStats::revenue(1, DAI), revenue(2_000, 0, 0));
+
+		on_idle(Weight::MAX);
+		assert_eq!(RevenueStats::oldest_era(), 2);
+		assert_eq!(RevenueStats::era_revenue(1), vec![]);
+	});
+}
+
+#[test]
+fn on_idle_should_continue_pruning_in_next_block_when_weight_is_not_sufficient() {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		RevenueStats::note_asset_fee(HDX, 1_000);
+		RevenueStats::note_asset_fee(LRNA, 1_000);
+		RevenueStats::note_asset_fee(DAI, 1_000);
+	});
+	// removal of entries is limited only in the backend
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		// Arrange
+		System::set_block_number(30);
+		// two reads, one removed entry and the oldest era update
+		let weight = Weight::from_parts(220, 0);
+
+		// Act
+		let used = on_idle(weight);
+
+		// Assert
+		assert_eq!(used, Weight::from_parts(120, 0));
+		assert_eq!(RevenueStats::oldest_era(), 0);
+		assert_eq!(RevenueStats::era_revenue(0).len(), 2);
+
+		on_idle(Weight::MAX);
+		assert_eq!(RevenueStats::oldest_era(), 1);
+		assert_eq!(RevenueStats::era_revenue(0), vec![]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_do_anything_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		RevenueStats::note_asset_fee(DAI, 1_000);
+		System::set_block_number(30);
+
+		// Act
+		let used = on_idle(Weight::from_parts(119, 0));
+
+		// Assert
+		assert_eq!(used, Weight::zero());
+		assert_eq!(RevenueStats::oldest_era(), 0);
+		assert_eq!(RevenueStats::revenue(0, DAI), revenue(1_000, 0, 0));
+	});
+}
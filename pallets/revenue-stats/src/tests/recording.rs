@@ -0,0 +1,83 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn note_functions_should_accumulate_revenue_of_current_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(25);
+
+		// Act
+		RevenueStats::note_asset_fee(DAI, 1_000);
+		RevenueStats::note_asset_fee(DAI, 500);
+		RevenueStats::note_protocol_fee(LRNA, 200);
+		RevenueStats::note_referral_fee(DAI, 300);
+		RevenueStats::note_treasury_inflow(HDX, 50);
+		RevenueStats::note_treasury_inflow(HDX, 70);
+
+		// Assert
+		assert_eq!(RevenueStats::current_era(), 2);
+		assert_eq!(RevenueStats::revenue(2, DAI), revenue(1_500, 0, 300));
+		assert_eq!(RevenueStats::revenue(2, LRNA), revenue(0, 200, 0));
+		assert_eq!(
+			RevenueStats::revenue(2, HDX),
+			Revenue {
+				treasury_inflows: 120,
+				..Default::default()
+			}
+		);
+	});
+}
+
+#[test]
+fn note_functions_should_record_revenue_to_era_of_current_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(19);
+		RevenueStats::note_asset_fee(DAI, 1_000);
+
+		// Act
+		System::set_block_number(20);
+		RevenueStats::note_asset_fee(DAI, 2_000);
+
+		// Assert
+		assert_eq!(RevenueStats::revenue(1, DAI), revenue(1_000, 0, 0));
+		assert_eq!(RevenueStats::revenue(2, DAI), revenue(2_000, 0, 0));
+	});
+}
+
+#[test]
+fn note_functions_should_not_store_anything_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		RevenueStats::note_asset_fee(DAI, 0);
+		RevenueStats::note_treasury_inflow(HDX, 0);
+
+		// Assert
+		assert!(!EraRevenue::<Test>::contains_key(0, DAI));
+		assert!(!EraRevenue::<Test>::contains_key(0, HDX));
+	});
+}
+
+#[test]
+fn era_revenue_should_return_revenue_of_all_assets_in_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		RevenueStats::note_asset_fee(DAI, 1_000);
+		RevenueStats::note_protocol_fee(LRNA, 200);
+		System::set_block_number(10);
+		RevenueStats::note_asset_fee(HDX, 3_000);
+
+		// Act
+		let mut era_revenue = RevenueStats::era_revenue(0);
+		era_revenue.sort_by_key(|(asset, _)| *asset);
+
+		// Assert
+		assert_eq!(
+			era_revenue,
+			vec![(LRNA, revenue(0, 200, 0)), (DAI, revenue(1_000, 0, 0))]
+		);
+		assert_eq!(RevenueStats::era_revenue(1), vec![(HDX, revenue(3_000, 0, 0))]);
+		assert_eq!(RevenueStats::era_revenue(2), vec![]);
+	});
+}
@@ -0,0 +1,40 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_runtime::{DispatchError, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum TradeDirection {
+	/// Route is simulated for a fixed amount in.
+	Sell,
+	/// Route is simulated for a fixed amount out.
+	Buy,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TradeSimulation<Trade, Balance> {
+	/// Trade of the route.
+	pub trade: Trade,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	/// Fee charged by the pool for the trade, including dynamic fees.
+	pub fee: Permill,
+	/// Spot price of asset out denominated in asset in, including the fee, before the trade.
+	pub spot_price_before: FixedU128,
+	/// Spot price of asset out denominated in asset in, including the fee, after the trade.
+	pub spot_price_after: FixedU128,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait RouterApi<Trade, Balance> where
+		Trade: Codec,
+		Balance: Codec,
+	{
+		/// Amounts, fees and spot prices of each trade of the route, as if the route was executed now.
+		/// `amount` is the amount in of a sell or the amount out of a buy.
+		fn simulate_route(route: Vec<Trade>, amount: Balance, direction: TradeDirection) -> Result<Vec<TradeSimulation<Trade, Balance>>, DispatchError>;
+	}
+}
@@ -32,6 +32,7 @@ use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
 
 use frame_system::pallet_prelude::OriginFor;
 use frame_system::{ensure_signed, Origin};
+use hydradx_traits::correlation::CorrelationId;
 use hydradx_traits::registry::Inspect as RegistryInspect;
 use hydradx_traits::router::{inverse_route, AssetPair, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider};
 pub use hydradx_traits::router::{
@@ -39,8 +40,10 @@ pub use hydradx_traits::router::{
 };
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use sp_core::U512;
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Saturating, TransactionOutcome};
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Zero};
+use sp_runtime::{
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Saturating, TransactionOutcome,
+};
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
@@ -49,12 +52,14 @@ pub mod weights;
 
 mod types;
 
+pub use types::{TradeInstruction, TradeSimulation};
 pub use weights::WeightInfo;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_TRADE_INSTRUCTIONS: u32 = 10;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -89,7 +94,8 @@ pub mod pallet {
 			+ CheckedAdd
 			+ CheckedDiv
 			+ Saturating
-			+ Zero;
+			+ Zero
+			+ Into<u128>;
 
 		/// Native Asset Id
 		#[pallet::constant]
@@ -139,9 +145,18 @@ pub mod pallet {
 			asset_out: T::AssetId,
 			amount_in: T::Balance,
 			amount_out: T::Balance,
+			correlation_id: CorrelationId,
 		},
 		///The route with trades has been successfully executed
 		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///Surplus of a trade beyond the user's limit has been sent to the beneficiary
+		SurplusCaptured {
+			who: T::AccountId,
+			beneficiary: T::AccountId,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+			correlation_id: CorrelationId,
+		},
 	}
 
 	#[pallet::error]
@@ -166,6 +181,14 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		/// No trade instructions were given
+		NoTradeInstructions,
+		/// The max number of trade instructions limit is reached
+		MaxTradeInstructionsExceeded,
+		/// Execution price of the route deviates from the spot price more than the allowed price impact
+		PriceImpactLimitReached,
+		/// Spot price of the route is not available
+		SpotPriceNotAvailable,
 	}
 
 	///Flag to indicate when to skip ED handling
@@ -209,7 +232,7 @@ pub mod pallet {
 			min_amount_out: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route).map(|_| ())
 		}
 
 		/// Executes a buy with a series of trades specified in the route.
@@ -236,65 +259,7 @@ pub mod pallet {
 			max_amount_in: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-
-			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
-
-			let route_length = route.len();
-			for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
-				Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out_at_most(
-					who.clone(),
-					trade.asset_in,
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			SkipEd::<T>::kill();
-
-			Self::ensure_that_user_spent_asset_in_at_least(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				first_trade.amount_in,
-			)?;
-
-			Self::deposit_event(Event::Executed {
-				asset_in,
-				asset_out,
-				amount_in: first_trade.amount_in,
-				amount_out,
-			});
-
-			Ok(())
+			Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route).map(|_| ())
 		}
 
 		/// Sets the on-chain route for a given asset pair.
@@ -443,7 +408,188 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 			let amount_in = T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
 
-			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route).map(|_| ())
+		}
+
+		/// Executes a sell with a series of trades specified in the route and sends the surplus to a beneficiary.
+		///
+		/// Surplus is the amount of `asset_out` received above `min_amount_out`. It is transferred from the
+		/// executor to the `beneficiary` after the trade. If the beneficiary is the executor, the surplus stays with them
+		/// and is only reported.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. Same as in `sell`.
+		/// - `beneficiary`: The account receiving the surplus
+		///
+		/// Emits `Executed` and `SurplusCaptured` when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::sell_weight(route).saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+		#[transactional]
+		pub fn sell_with_surplus_capture(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let amount_out = Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)?;
+
+			Self::capture_surplus(who, beneficiary, asset_out, amount_out.saturating_sub(min_amount_out))
+		}
+
+		/// Executes a buy with a series of trades specified in the route and sends the surplus to a beneficiary.
+		///
+		/// Surplus is the amount of `asset_in` saved below `max_amount_in`. It is transferred from the
+		/// executor to the `beneficiary` after the trade, so the executor spends exactly `max_amount_in`.
+		/// If the beneficiary is the executor, the surplus stays with them and is only reported.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The amount of `asset_out` to buy
+		/// - `max_amount_in`: The max amount of `asset_in` to spend on the buy.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. Same as in `buy`.
+		/// - `beneficiary`: The account receiving the surplus
+		///
+		/// Emits `Executed` and `SurplusCaptured` when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::buy_weight(route).saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+		#[transactional]
+		pub fn buy_with_surplus_capture(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let amount_in = Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)?;
+
+			Self::capture_surplus(who, beneficiary, asset_in, max_amount_in.saturating_sub(amount_in))
+		}
+
+		/// Executes several independent sells and buys atomically.
+		///
+		/// Each instruction is executed the same way as the `sell` or `buy` extrinsic with its own limit and route.
+		/// If any of the instructions fails, the whole batch is reverted.
+		///
+		/// - `origin`: The executor of the trades
+		/// - `instructions`: Sells and buys to execute, in the given order. At most `MAX_NUMBER_OF_TRADE_INSTRUCTIONS`.
+		///
+		/// Emits `Executed` for each instruction when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(Pallet::<T>::execute_trades_weight(instructions))]
+		#[transactional]
+		pub fn execute_trades(
+			origin: OriginFor<T>,
+			instructions: Vec<TradeInstruction<T::AssetId, T::Balance>>,
+		) -> DispatchResult {
+			ensure_signed(origin.clone())?;
+			ensure!(!instructions.is_empty(), Error::<T>::NoTradeInstructions);
+			ensure!(
+				(instructions.len() as u32) <= MAX_NUMBER_OF_TRADE_INSTRUCTIONS,
+				Error::<T>::MaxTradeInstructionsExceeded
+			);
+
+			for instruction in instructions {
+				match instruction {
+					TradeInstruction::Sell {
+						asset_in,
+						asset_out,
+						amount_in,
+						min_amount_out,
+						route,
+					} => {
+						Self::do_sell(origin.clone(), asset_in, asset_out, amount_in, min_amount_out, route)?;
+					}
+					TradeInstruction::Buy {
+						asset_in,
+						asset_out,
+						amount_out,
+						max_amount_in,
+						route,
+					} => {
+						Self::do_buy(origin.clone(), asset_in, asset_out, amount_out, max_amount_in, route)?;
+					}
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Executes a sell with a series of trades specified in the route and limits its price impact.
+		///
+		/// Price impact is the relative difference between the execution price of the whole route,
+		/// `amount_in / amount_out`, and the spot price of the route before the trade. The trade is rejected
+		/// if the price impact exceeds `max_price_impact`, independently of `min_amount_out`.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. Same as in `sell`.
+		/// - `max_price_impact`: The max allowed price impact of the trade
+		///
+		/// Emits `Executed` when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::sell_weight(route)
+			.saturating_add(T::WeightInfo::calculate_spot_price_with_fee_weight(route)))]
+		#[transactional]
+		pub fn sell_with_price_impact_limit(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+			max_price_impact: Permill,
+		) -> DispatchResult {
+			let spot_price = Self::route_spot_price(asset_in, asset_out, &route)?;
+			let amount_out = Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)?;
+
+			Self::ensure_price_impact(spot_price, amount_in, amount_out, max_price_impact)
+		}
+
+		/// Executes a buy with a series of trades specified in the route and limits its price impact.
+		///
+		/// Price impact is calculated the same way as in `sell_with_price_impact_limit`.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The amount of `asset_out` to buy
+		/// - `max_amount_in`: The max amount of `asset_in` to spend on the buy.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. Same as in `buy`.
+		/// - `max_price_impact`: The max allowed price impact of the trade
+		///
+		/// Emits `Executed` when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::buy_weight(route)
+			.saturating_add(T::WeightInfo::calculate_spot_price_with_fee_weight(route)))]
+		#[transactional]
+		pub fn buy_with_price_impact_limit(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+			max_price_impact: Permill,
+		) -> DispatchResult {
+			let spot_price = Self::route_spot_price(asset_in, asset_out, &route)?;
+			let amount_in = Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)?;
+
+			Self::ensure_price_impact(spot_price, amount_in, amount_out, max_price_impact)
 		}
 	}
 }
@@ -461,7 +607,7 @@ impl<T: Config> Pallet<T> {
 		amount_in: T::Balance,
 		min_amount_out: T::Balance,
 		route: Vec<Trade<T::AssetId>>,
-	) -> Result<(), DispatchError> {
+	) -> Result<T::Balance, DispatchError> {
 		let who = ensure_signed(origin.clone())?;
 
 		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
@@ -524,6 +670,139 @@ impl<T: Config> Pallet<T> {
 			asset_out,
 			amount_in,
 			amount_out: last_trade_amount.amount_out,
+			correlation_id: CorrelationId::current::<T>(),
+		});
+
+		Ok(last_trade_amount.amount_out)
+	}
+
+	fn do_buy(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+		let route_length = route.len();
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
+			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who.clone(),
+				trade.asset_in,
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		SkipEd::<T>::kill();
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who,
+			asset_in,
+			user_balance_of_asset_in_before_trade,
+			first_trade.amount_in,
+		)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in: first_trade.amount_in,
+			amount_out,
+			correlation_id: CorrelationId::current::<T>(),
+		});
+
+		Ok(first_trade.amount_in)
+	}
+
+	pub fn execute_trades_weight(instructions: &[TradeInstruction<T::AssetId, T::Balance>]) -> Weight {
+		instructions
+			.iter()
+			.fold(Weight::zero(), |weight, instruction| match instruction {
+				TradeInstruction::Sell { route, .. } => weight.saturating_add(T::WeightInfo::sell_weight(route)),
+				TradeInstruction::Buy { route, .. } => weight.saturating_add(T::WeightInfo::buy_weight(route)),
+			})
+	}
+
+	/// Returns spot price of the route which would be executed for the asset pair, including fees.
+	fn route_spot_price(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		route: &[Trade<T::AssetId>],
+	) -> Result<FixedU128, DispatchError> {
+		let route = Self::get_route_or_default(route.to_vec(), AssetPair::new(asset_in, asset_out))?;
+		<Self as RouteSpotPriceProvider<T::AssetId>>::spot_price_with_fee(&route)
+			.ok_or(Error::<T>::SpotPriceNotAvailable.into())
+	}
+
+	/// Ensures that execution price `amount_in / amount_out` does not exceed the spot price by more than `max_price_impact`.
+	fn ensure_price_impact(
+		spot_price: FixedU128,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+		max_price_impact: Permill,
+	) -> DispatchResult {
+		let (amount_in, amount_out): (u128, u128) = (amount_in.into(), amount_out.into());
+		let execution_price =
+			FixedU128::checked_from_rational(amount_in, amount_out).ok_or(Error::<T>::PriceImpactLimitReached)?;
+		let max_price = spot_price.saturating_mul(FixedU128::from(1).saturating_add(FixedU128::from(max_price_impact)));
+
+		ensure!(execution_price <= max_price, Error::<T>::PriceImpactLimitReached);
+
+		Ok(())
+	}
+
+	fn capture_surplus(
+		who: T::AccountId,
+		beneficiary: T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		if who != beneficiary {
+			T::Currency::transfer(asset_id, &who, &beneficiary, amount, Preservation::Expendable)?;
+		}
+
+		Self::deposit_event(Event::SurplusCaptured {
+			who,
+			beneficiary,
+			asset_id,
+			amount,
+			correlation_id: CorrelationId::current::<T>(),
 		});
 
 		Ok(())
@@ -743,6 +1022,10 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_out)
 	}
 
+	/// Amounts in and out of each trade of a sell route.
+	///
+	/// Amount out of each trade is calculated by the pool itself, with the trade fee deducted on whichever
+	/// side the pool charges it. It is used as the exact amount in of the next trade, so no estimation is involved.
 	fn calculate_sell_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
@@ -765,6 +1048,10 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	/// Amounts in and out of each trade of a buy route, in reverse order of the route.
+	///
+	/// Amount in of each trade is calculated by the pool itself, including the trade fee on whichever side
+	/// the pool charges it. It is used as the exact amount out of the previous trade, so no estimation is involved.
 	fn calculate_buy_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_out: T::Balance,
@@ -788,6 +1075,133 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	/// Simulates a sell of `amount_in` by `route` without changing the state.
+	///
+	/// Returns amounts, fee and spot prices before and after each trade of the route, in the order of the route.
+	pub fn simulate_sell(
+		route: Vec<Trade<T::AssetId>>,
+		amount_in: T::Balance,
+	) -> Result<Vec<TradeSimulation<T::AssetId, T::Balance>>, DispatchError> {
+		Self::ensure_simulated_route(&route)?;
+		let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
+
+		Self::simulate_trades(route, trade_amounts, false)
+	}
+
+	/// Simulates a buy of `amount_out` by `route` without changing the state.
+	///
+	/// Returns amounts, fee and spot prices before and after each trade of the route, in the order of the route.
+	pub fn simulate_buy(
+		route: Vec<Trade<T::AssetId>>,
+		amount_out: T::Balance,
+	) -> Result<Vec<TradeSimulation<T::AssetId, T::Balance>>, DispatchError> {
+		Self::ensure_simulated_route(&route)?;
+		let mut trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+		trade_amounts.reverse();
+
+		Self::simulate_trades(route, trade_amounts, true)
+	}
+
+	fn ensure_simulated_route(route: &[Trade<T::AssetId>]) -> Result<(), DispatchError> {
+		Self::ensure_route_size(route.len())?;
+		let asset_in = route.first().ok_or(Error::<T>::InvalidRoute)?.asset_in;
+		let asset_out = route.last().ok_or(Error::<T>::InvalidRoute)?.asset_out;
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		Self::ensure_route_arguments(&AssetPair::new(asset_in, asset_out), route)
+	}
+
+	/// Executes trades of the route by the router account with precalculated amounts and rolls them back.
+	///
+	/// Trades are executed so the spot price after a trade reflects the state changes of all previous trades.
+	fn simulate_trades(
+		route: Vec<Trade<T::AssetId>>,
+		trade_amounts: Vec<AmountInAndOut<T::Balance>>,
+		is_buy: bool,
+	) -> Result<Vec<TradeSimulation<T::AssetId, T::Balance>>, DispatchError> {
+		let first_trade = trade_amounts.first().ok_or(Error::<T>::RouteCalculationFailed)?;
+		let asset_in = route.first().ok_or(Error::<T>::InvalidRoute)?.asset_in;
+		let amount_in = first_trade.amount_in;
+
+		with_transaction::<Vec<TradeSimulation<T::AssetId, T::Balance>>, DispatchError, _>(|| {
+			let origin: OriginFor<T> = Origin::<T>::Signed(Self::router_account()).into();
+			//NOTE: Same as in `validate_sell` - router's account has to pay ED for insufficient assets in the route.
+			let _ = T::Currency::mint_into(
+				T::NativeAssetId::get(),
+				&Self::router_account(),
+				10_000_000_000_000_000_u128.into(),
+			);
+			let _ = T::Currency::mint_into(asset_in, &Self::router_account(), amount_in);
+
+			let result = route
+				.into_iter()
+				.zip(trade_amounts)
+				.map(|(trade, trade_amount)| Self::simulate_trade(origin.clone(), trade, trade_amount, is_buy))
+				.collect();
+
+			TransactionOutcome::Rollback(result)
+		})
+	}
+
+	fn simulate_trade(
+		origin: OriginFor<T>,
+		trade: Trade<T::AssetId>,
+		trade_amount: AmountInAndOut<T::Balance>,
+		is_buy: bool,
+	) -> Result<TradeSimulation<T::AssetId, T::Balance>, DispatchError> {
+		let fee = Self::map_executor_error(T::AMM::calculate_fee(trade.pool, trade.asset_in, trade.asset_out))?;
+		let spot_price_before = Self::map_executor_error(T::AMM::calculate_spot_price_with_fee(
+			trade.pool,
+			trade.asset_in,
+			trade.asset_out,
+		))?;
+
+		let execution_result = if is_buy {
+			T::AMM::execute_buy(
+				origin,
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			)
+		} else {
+			T::AMM::execute_sell(
+				origin,
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_in,
+				trade_amount.amount_out,
+			)
+		};
+		Self::map_executor_error(execution_result)?;
+
+		let spot_price_after = Self::map_executor_error(T::AMM::calculate_spot_price_with_fee(
+			trade.pool,
+			trade.asset_in,
+			trade.asset_out,
+		))?;
+
+		Ok(TradeSimulation {
+			pool: trade.pool,
+			asset_in: trade.asset_in,
+			asset_out: trade.asset_out,
+			amount_in: trade_amount.amount_in,
+			amount_out: trade_amount.amount_out,
+			fee,
+			spot_price_before,
+			spot_price_after,
+		})
+	}
+
+	fn map_executor_error<V>(result: Result<V, ExecutorError<DispatchError>>) -> Result<V, DispatchError> {
+		result.map_err(|error| match error {
+			ExecutorError::NotSupported => Error::<T>::PoolNotSupported.into(),
+			ExecutorError::Error(dispatch_error) => dispatch_error,
+		})
+	}
+
 	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
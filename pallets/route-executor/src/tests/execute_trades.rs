@@ -0,0 +1,124 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, TradeInstruction, MAX_NUMBER_OF_TRADE_INSTRUCTIONS};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+
+fn sell_instruction(amount_in: Balance, min_amount_out: Balance) -> TradeInstruction<AssetId, Balance> {
+	TradeInstruction::Sell {
+		asset_in: HDX,
+		asset_out: AUSD,
+		amount_in,
+		min_amount_out,
+		route: vec![HDX_AUSD_TRADE_IN_XYK],
+	}
+}
+
+fn buy_instruction(amount_out: Balance, max_amount_in: Balance) -> TradeInstruction<AssetId, Balance> {
+	TradeInstruction::Buy {
+		asset_in: HDX,
+		asset_out: AUSD,
+		amount_out,
+		max_amount_in,
+		route: vec![HDX_AUSD_TRADE_IN_XYK],
+	}
+}
+
+#[test]
+fn execute_trades_should_execute_all_instructions() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::execute_trades(
+			RuntimeOrigin::signed(ALICE),
+			vec![sell_instruction(10, 5), buy_instruction(10, 5)]
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+		assert_executed_buy_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+		expect_events(vec![
+			Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: 10,
+				amount_out: XYK_SELL_CALCULATION_RESULT,
+				correlation_id: correlation_id(),
+			}
+			.into(),
+			Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: XYK_BUY_CALCULATION_RESULT,
+				amount_out: 10,
+				correlation_id: correlation_id(),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn execute_trades_should_revert_all_instructions_when_one_fails_its_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::execute_trades(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					sell_instruction(10, 5),
+					buy_instruction(10, XYK_BUY_CALCULATION_RESULT - 1)
+				]
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn execute_trades_should_fail_when_no_instructions_are_given() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::execute_trades(RuntimeOrigin::signed(ALICE), vec![]),
+			Error::<Test>::NoTradeInstructions
+		);
+	});
+}
+
+#[test]
+fn execute_trades_should_fail_when_max_number_of_instructions_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let instructions = vec![sell_instruction(10, 5); MAX_NUMBER_OF_TRADE_INSTRUCTIONS as usize + 1];
+
+		assert_noop!(
+			Router::execute_trades(RuntimeOrigin::signed(ALICE), instructions),
+			Error::<Test>::MaxTradeInstructionsExceeded
+		);
+	});
+}
+
+#[test]
+fn execute_trades_weight_should_be_sum_of_instruction_weights() {
+	let sell_weight = Router::execute_trades_weight(&[sell_instruction(10, 5)]);
+	let buy_weight = Router::execute_trades_weight(&[buy_instruction(10, 5)]);
+
+	assert_eq!(
+		Router::execute_trades_weight(&[sell_instruction(10, 5), buy_instruction(10, 5)]),
+		sell_weight + buy_weight
+	);
+}
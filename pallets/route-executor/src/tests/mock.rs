@@ -24,16 +24,17 @@ use frame_support::{
 use frame_system::EnsureRoot;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::correlation::CorrelationId;
 use hydradx_traits::router::{ExecutorError, PoolType, RefundEdCalculator, TradeExecution};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{fungibles::FungibleCurrencies, BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
 use pretty_assertions::assert_eq;
 use sp_core::H256;
-use sp_runtime::FixedU128;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage, DispatchError,
 };
+use sp_runtime::{FixedU128, Permill};
 use std::cell::RefCell;
 use std::ops::Deref;
 
@@ -234,6 +235,7 @@ pub type AccountId = u64;
 
 pub const ALICE: AccountId = 1;
 pub const ASSET_PAIR_ACCOUNT: AccountId = 2;
+pub const BOB: AccountId = 3;
 
 pub const HDX: AssetId = 0;
 pub const AUSD: AssetId = 1001;
@@ -467,6 +469,18 @@ macro_rules! impl_fake_executor {
 			) -> Result<FixedU128, ExecutorError<Self::Error>> {
 				Ok(FixedU128::from_rational(1, 10))
 			}
+
+			fn calculate_fee(
+				pool_type: PoolType<AssetId>,
+				_asset_in: AssetId,
+				_asset_out: AssetId,
+			) -> Result<Permill, ExecutorError<Self::Error>> {
+				if !matches!(pool_type, $pool_type) {
+					return Err(ExecutorError::NotSupported);
+				}
+
+				Ok(Permill::from_percent(1))
+			}
 		}
 	};
 }
@@ -533,6 +547,10 @@ pub fn expect_events(e: Vec<RuntimeEvent>) {
 	test_utils::expect_events::<RuntimeEvent, Test>(e);
 }
 
+pub fn correlation_id() -> CorrelationId {
+	CorrelationId::current::<Test>()
+}
+
 pub fn expect_no_route_executed_event() {
 	let last_events = test_utils::last_events::<RuntimeEvent, Test>(20);
 
@@ -1,7 +1,11 @@
 pub mod buy;
+pub mod execute_trades;
 pub mod force_insert_route;
 pub mod mock;
+pub mod price_impact;
 pub mod sell;
 pub mod sell_all;
 pub mod set_route;
+pub mod simulate;
 pub mod spot_price;
+pub mod surplus_capture;
@@ -0,0 +1,163 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+// Spot price of the mocked pools is 0.1 asset_in per asset_out.
+
+#[test]
+fn sell_with_price_impact_limit_should_work_when_execution_price_is_below_spot_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::sell_with_price_impact_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			1,
+			XYK_SELL_CALCULATION_RESULT,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			Permill::zero()
+		));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), XYK_SELL_CALCULATION_RESULT);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: 1,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_with_price_impact_limit_should_work_when_price_impact_is_within_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act & Assert
+		// execution price 2 / 13 is ~54% above the spot price
+		assert_ok!(Router::sell_with_price_impact_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			2,
+			XYK_SELL_CALCULATION_RESULT,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			Permill::from_percent(60)
+		));
+	});
+}
+
+#[test]
+fn sell_with_price_impact_limit_should_fail_when_price_impact_exceeds_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act & Assert
+		assert_noop!(
+			Router::sell_with_price_impact_limit(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				2,
+				XYK_SELL_CALCULATION_RESULT,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				Permill::from_percent(50)
+			),
+			Error::<Test>::PriceImpactLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_with_price_impact_limit_should_still_check_min_amount_out() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act & Assert
+		assert_noop!(
+			Router::sell_with_price_impact_limit(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				1,
+				XYK_SELL_CALCULATION_RESULT + 1,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				Permill::from_percent(100)
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_with_price_impact_limit_should_work_when_execution_price_equals_spot_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::buy_with_price_impact_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			XYK_BUY_CALCULATION_RESULT,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			Permill::zero()
+		));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 10);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT,
+			amount_out: 10,
+			correlation_id: correlation_id(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_with_price_impact_limit_should_fail_when_price_impact_exceeds_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act & Assert
+		// execution price 1 / 5 is 100% above the spot price
+		assert_noop!(
+			Router::buy_with_price_impact_limit(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				5,
+				XYK_BUY_CALCULATION_RESULT,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				Permill::from_percent(99)
+			),
+			Error::<Test>::PriceImpactLimitReached
+		);
+		assert_ok!(Router::buy_with_price_impact_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			5,
+			XYK_BUY_CALCULATION_RESULT,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			Permill::from_percent(100)
+		));
+	});
+}
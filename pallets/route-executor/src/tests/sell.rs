@@ -18,12 +18,46 @@
 use crate::tests::mock::*;
 use crate::{Error, Event, Trade};
 use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::correlation::CorrelationId;
 use hydradx_traits::router::AssetPair;
 use hydradx_traits::router::PoolType;
 use pretty_assertions::assert_eq;
+use sp_core::storage::well_known_keys;
 use sp_runtime::DispatchError::BadOrigin;
 use sp_runtime::{DispatchError, TokenError};
 
+#[test]
+fn sell_should_emit_correlation_id_of_executed_extrinsic() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		System::set_block_number(3);
+		frame_support::storage::unhashed::put(well_known_keys::EXTRINSIC_INDEX, &2u32);
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			5,
+			vec![HDX_AUSD_TRADE_IN_XYK]
+		));
+
+		//Assert
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: 10,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: CorrelationId {
+				block_number: 3,
+				extrinsic_index: Some(2),
+			},
+		}
+		.into()]);
+	});
+}
+
 #[test]
 fn sell_should_work_when_route_has_single_trade() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -50,6 +84,7 @@ fn sell_should_work_when_route_has_single_trade() {
 			asset_out: AUSD,
 			amount_in: amount_to_sell,
 			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -79,6 +114,7 @@ fn sell_should_work_with_omnipool_when_no_specified_or_onchain_route_exist() {
 			asset_out: AUSD,
 			amount_in: amount_to_sell,
 			amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -185,6 +221,7 @@ fn sell_should_work_when_route_has_multiple_trades_with_same_pooltype() {
 			asset_out: KSM,
 			amount_in: amount_to_sell,
 			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -235,6 +272,7 @@ fn sell_should_work_when_route_has_multiple_trades_with_different_pool_type() {
 			asset_out: KSM,
 			amount_in: amount_to_sell,
 			amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -293,6 +331,7 @@ fn sell_should_work_with_onchain_route_when_no_routes_specified() {
 			asset_out: KSM,
 			amount_in: amount_to_sell,
 			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -355,6 +394,7 @@ fn sell_should_work_with_onchain_route_when_onchain_route_present_in_reverse_ord
 				asset_out: HDX,
 				amount_in: amount_to_sell,
 				amount_out: XYK_SELL_CALCULATION_RESULT,
+				correlation_id: correlation_id(),
 			}
 			.into()]);
 		});
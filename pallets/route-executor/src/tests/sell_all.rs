@@ -44,6 +44,7 @@ fn sell_should_work_when_route_has_single_trade() {
 			asset_out: AUSD,
 			amount_in: alice_balance,
 			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -67,6 +68,7 @@ fn sell_should_work_with_omnipool_when_no_specified_or_onchain_route_exist() {
 			asset_out: AUSD,
 			amount_in: alice_balance,
 			amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -135,6 +137,7 @@ fn sell_should_work_when_route_has_multiple_trades_with_same_pooltype() {
 			asset_out: KSM,
 			amount_in: alice_native_balance,
 			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -179,6 +182,7 @@ fn sell_should_work_when_route_has_multiple_trades_with_different_pool_type() {
 			asset_out: KSM,
 			amount_in: alice_native_balance,
 			amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -230,6 +234,7 @@ fn sell_should_work_with_onchain_route_when_no_routes_specified() {
 			asset_out: KSM,
 			amount_in: alice_native_balance,
 			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
 		}
 		.into()]);
 	});
@@ -286,6 +291,7 @@ fn sell_should_work_with_onchain_route_when_onchain_route_present_in_reverse_ord
 				asset_out: HDX,
 				amount_in: alice_nonnative_balance,
 				amount_out: XYK_SELL_CALCULATION_RESULT,
+				correlation_id: correlation_id(),
 			}
 			.into()]);
 		});
@@ -0,0 +1,197 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Trade, TradeSimulation};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedU128, Permill};
+
+fn hdx_ausd_movr_route() -> Vec<Trade<AssetId>> {
+	vec![
+		HDX_AUSD_TRADE_IN_XYK,
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: AUSD,
+			asset_out: MOVR,
+		},
+	]
+}
+
+fn simulated_trade(
+	pool: PoolType<AssetId>,
+	asset_in: AssetId,
+	asset_out: AssetId,
+	amount_in: Balance,
+	amount_out: Balance,
+) -> TradeSimulation<AssetId, Balance> {
+	TradeSimulation {
+		pool,
+		asset_in,
+		asset_out,
+		amount_in,
+		amount_out,
+		fee: Permill::from_percent(1),
+		spot_price_before: FixedU128::from_rational(1, 10),
+		spot_price_after: FixedU128::from_rational(1, 10),
+	}
+}
+
+#[test]
+fn simulate_sell_should_return_trade_when_route_has_single_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let simulation = Router::simulate_sell(vec![HDX_AUSD_TRADE_IN_XYK], 10);
+
+		//Assert
+		assert_ok!(
+			simulation,
+			vec![simulated_trade(
+				PoolType::XYK,
+				HDX,
+				AUSD,
+				10,
+				XYK_SELL_CALCULATION_RESULT
+			)]
+		);
+		assert_executed_sell_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn simulate_sell_should_return_all_trades_when_route_has_multiple_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let simulation = Router::simulate_sell(hdx_ausd_movr_route(), 10);
+
+		//Assert
+		assert_ok!(
+			simulation,
+			vec![
+				simulated_trade(PoolType::XYK, HDX, AUSD, 10, XYK_SELL_CALCULATION_RESULT),
+				simulated_trade(
+					PoolType::Omnipool,
+					AUSD,
+					MOVR,
+					XYK_SELL_CALCULATION_RESULT,
+					OMNIPOOL_SELL_CALCULATION_RESULT
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn simulate_buy_should_return_trades_in_order_of_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let simulation = Router::simulate_buy(hdx_ausd_movr_route(), 10);
+
+		//Assert
+		assert_ok!(
+			simulation,
+			vec![
+				simulated_trade(
+					PoolType::XYK,
+					HDX,
+					AUSD,
+					XYK_BUY_CALCULATION_RESULT,
+					OMNIPOOL_BUY_CALCULATION_RESULT
+				),
+				simulated_trade(PoolType::Omnipool, AUSD, MOVR, OMNIPOOL_BUY_CALCULATION_RESULT, 10),
+			]
+		);
+		assert_executed_buy_trades(vec![
+			(PoolType::XYK, OMNIPOOL_BUY_CALCULATION_RESULT, HDX, AUSD),
+			(PoolType::Omnipool, 10, AUSD, MOVR),
+		]);
+	});
+}
+
+#[test]
+fn simulate_sell_should_not_change_state() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::simulate_sell(hdx_ausd_movr_route(), 10));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(HDX, &Router::router_account()), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &Router::router_account()), 0);
+		assert_eq!(Currencies::free_balance(MOVR, &Router::router_account()), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &ASSET_PAIR_ACCOUNT), 1000);
+		assert_eq!(Currencies::free_balance(MOVR, &ASSET_PAIR_ACCOUNT), 1000);
+		expect_no_route_executed_event();
+	});
+}
+
+#[test]
+fn simulate_buy_should_not_change_state() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::simulate_buy(hdx_ausd_movr_route(), 10));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(HDX, &Router::router_account()), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &ASSET_PAIR_ACCOUNT), 1000);
+		assert_eq!(Currencies::free_balance(MOVR, &ASSET_PAIR_ACCOUNT), 1000);
+	});
+}
+
+#[test]
+fn simulate_sell_should_fail_when_route_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Router::simulate_sell(vec![], 10), Error::<Test>::InvalidRoute);
+	});
+}
+
+#[test]
+fn simulate_sell_should_fail_when_trades_are_not_connected() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![
+			HDX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: MOVR,
+				asset_out: KSM,
+			},
+		];
+
+		//Act and assert
+		assert_noop!(Router::simulate_sell(route, 10), Error::<Test>::InvalidRoute);
+	});
+}
+
+#[test]
+fn simulate_buy_should_fail_when_asset_in_and_out_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![
+			HDX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: AUSD,
+				asset_out: HDX,
+			},
+		];
+
+		//Act and assert
+		assert_noop!(Router::simulate_buy(route, 10), Error::<Test>::NotAllowed);
+	});
+}
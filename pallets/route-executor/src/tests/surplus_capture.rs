@@ -0,0 +1,205 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sell_with_surplus_capture_should_send_surplus_to_beneficiary() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let limit = 5;
+
+		//Act
+		assert_ok!(Router::sell_with_surplus_capture(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			BOB
+		));
+
+		//Assert
+		let surplus = XYK_SELL_CALCULATION_RESULT - limit;
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), limit);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), surplus);
+		expect_events(vec![
+			Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: amount_to_sell,
+				amount_out: XYK_SELL_CALCULATION_RESULT,
+				correlation_id: correlation_id(),
+			}
+			.into(),
+			Event::SurplusCaptured {
+				who: ALICE,
+				beneficiary: BOB,
+				asset_id: AUSD,
+				amount: surplus,
+				correlation_id: correlation_id(),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn sell_with_surplus_capture_should_keep_surplus_when_beneficiary_is_seller() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let limit = 5;
+
+		//Act
+		assert_ok!(Router::sell_with_surplus_capture(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			limit,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			ALICE
+		));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), XYK_SELL_CALCULATION_RESULT);
+		expect_events(vec![Event::SurplusCaptured {
+			who: ALICE,
+			beneficiary: ALICE,
+			asset_id: AUSD,
+			amount: XYK_SELL_CALCULATION_RESULT - limit,
+			correlation_id: correlation_id(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_with_surplus_capture_should_not_emit_event_when_there_is_no_surplus() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::sell_with_surplus_capture(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			XYK_SELL_CALCULATION_RESULT,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			BOB
+		));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: 10,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+			correlation_id: correlation_id(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_with_surplus_capture_should_fail_when_limit_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::sell_with_surplus_capture(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				XYK_SELL_CALCULATION_RESULT + 1,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				BOB
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_with_surplus_capture_should_send_surplus_to_beneficiary() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 5;
+		let bob_balance = Currencies::free_balance(HDX, &BOB);
+
+		//Act
+		assert_ok!(Router::buy_with_surplus_capture(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			BOB
+		));
+
+		//Assert
+		let surplus = limit - XYK_BUY_CALCULATION_RESULT;
+		assert_eq!(
+			Currencies::free_balance(HDX, &ALICE),
+			ALICE_INITIAL_NATIVE_BALANCE - limit
+		);
+		assert_eq!(Currencies::free_balance(HDX, &BOB), bob_balance + surplus);
+		expect_events(vec![
+			Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: XYK_BUY_CALCULATION_RESULT,
+				amount_out: amount_to_buy,
+				correlation_id: correlation_id(),
+			}
+			.into(),
+			Event::SurplusCaptured {
+				who: ALICE,
+				beneficiary: BOB,
+				asset_id: HDX,
+				amount: surplus,
+				correlation_id: correlation_id(),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn buy_with_surplus_capture_should_fail_when_limit_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::buy_with_surplus_capture(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				XYK_BUY_CALCULATION_RESULT - 1,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				BOB
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
@@ -1,5 +1,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::TypeInfo;
+use hydradx_traits::router::{PoolType, Trade};
+use sp_runtime::{FixedU128, Permill};
+use sp_std::vec::Vec;
 
 #[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum SkipEd {
@@ -7,3 +10,38 @@ pub enum SkipEd {
 	LockAndUnlock,
 	Unlock,
 }
+
+/// Single trade of a batch executed by `execute_trades`.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+pub enum TradeInstruction<AssetId, Balance> {
+	Sell {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		route: Vec<Trade<AssetId>>,
+	},
+	Buy {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		route: Vec<Trade<AssetId>>,
+	},
+}
+
+/// Trade of a route simulated by `simulate_sell` or `simulate_buy`.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+pub struct TradeSimulation<AssetId, Balance> {
+	pub pool: PoolType<AssetId>,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	/// Fee charged by the pool for the trade, including dynamic fees.
+	pub fee: Permill,
+	/// Spot price of `asset_out` denominated in `asset_in`, including the fee, before the trade.
+	pub spot_price_before: FixedU128,
+	/// Spot price of `asset_out` denominated in `asset_in`, including the fee, after the trade.
+	pub spot_price_after: FixedU128,
+}
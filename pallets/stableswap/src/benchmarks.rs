@@ -19,9 +19,10 @@
 
 use super::*;
 
-use crate::types::AssetAmount;
+use crate::types::{AssetAmount, DepositCap, ParameterChange, PegSource, PendingParameterChange, PoolParameter};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
+use frame_support::traits::ConstU32;
 use frame_support::traits::EnsureOrigin;
 use frame_support::BoundedVec;
 use frame_system::{Pallet as System, RawOrigin};
@@ -32,6 +33,23 @@ use sp_runtime::Permill;
 
 const ASSET_ID_OFFSET: u32 = 2_000;
 
+// Amplification change is applied directly, so that the setup does not depend on `ParameterChangeDelay`.
+fn change_amplification<T: Config>(
+	pool_id: T::AssetId,
+	final_amplification: u16,
+	start_block: BlockNumberFor<T>,
+	end_block: BlockNumberFor<T>,
+) -> DispatchResult {
+	crate::Pallet::<T>::do_update_pool_parameter(
+		pool_id,
+		ParameterChange::Amplification {
+			final_amplification: NonZeroU16::new(final_amplification).ok_or(Error::<T>::InvalidAmplification)?,
+			start_block,
+			end_block,
+		},
+	)
+}
+
 // Stable benchmarks
 // Worst case scenarios in any stableswap calculations are scenarios where "math" does max number of iterations.
 // Therefore, hydra-dx-math build with "runtime-benchmarks" features forces calculations of D and Y to perform all iterations.
@@ -64,6 +82,32 @@ benchmarks! {
 		assert!(<Pools<T>>::get::<T::AssetId>(pool_id.into()).is_some());
 	}
 
+	create_pool_with_deposit_caps {
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL{
+			let asset_id = idx + ASSET_ID_OFFSET;
+			T::BenchmarkHelper::register_asset(asset_id.into(), 12)?;
+			asset_ids.push(asset_id.into());
+		}
+		let pool_id = 1000u32;
+		T::BenchmarkHelper::register_asset(pool_id.into(), 18)?;
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let caps: BoundedVec<DepositCap<BlockNumberFor<T>>, ConstU32<MAX_DEPOSIT_CAP_STEPS>> = (1..=MAX_DEPOSIT_CAP_STEPS)
+			.map(|step| DepositCap {
+				block: (step * 100).into(),
+				cap: step as u128 * 1_000_000_000_000_000_000u128,
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id.into(), asset_ids, amplification, trade_fee, caps.clone())
+	verify {
+		assert!(<Pools<T>>::get::<T::AssetId>(pool_id.into()).is_some());
+		assert_eq!(crate::Pallet::<T>::pool_deposit_caps::<T::AssetId>(pool_id.into()), Some(caps));
+	}
+
 	add_liquidity{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -346,7 +390,7 @@ benchmarks! {
 		T::Currency::update_balance(asset_in, &seller, amount_sell as i128)?;
 		let buy_min_amount = 1_000u128;
 		// Worst case is when amplification is changing
-		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+		change_amplification::<T>(
 			pool_id,
 			1000,
 			100u32.into(),
@@ -400,7 +444,7 @@ benchmarks! {
 		let amount_buy = 10_000_000_000_000u128;
 		let sell_max_limit = 11_000_000_000_000u128;
 		// Worst case is when amplification is changing
-		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+		change_amplification::<T>(
 			pool_id,
 			1000,
 			100u32.into(),
@@ -413,6 +457,58 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_in, &buyer), 89_899_999_798_401);
 	}
 
+	rebalance{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000_000u128;
+
+		let mut initial: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			// Last asset is the scarce one, so selling it rebalances the pool
+			let liquidity = if idx == MAX_ASSETS_IN_POOL - 1 { initial_liquidity / 2 } else { initial_liquidity };
+			T::Currency::update_balance(asset_id, &caller, liquidity as i128)?;
+			initial.push(AssetAmount::new(asset_id, liquidity));
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let asset_in: T::AssetId = *asset_ids.last().unwrap();
+		let asset_out: T::AssetId = *asset_ids.first().unwrap();
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids,
+			amplification,
+			trade_fee,
+		)?;
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
+			pool_id,
+			BoundedVec::truncate_from(initial),
+		)?;
+
+		let rebalance_origin = T::RebalanceOrigin::try_successful_origin().unwrap();
+		let rebalancer = T::RebalanceOrigin::ensure_origin(rebalance_origin.clone()).unwrap();
+		let amount_sell  = 100_000_000_000_000u128;
+		T::Currency::update_balance(asset_in, &rebalancer, amount_sell as i128)?;
+		let buy_min_amount = 1_000u128;
+		// Worst case is when amplification is changing
+		change_amplification::<T>(
+			pool_id,
+			1000,
+			100u32.into(),
+			1000u32.into(),
+		)?;
+		System::<T>::set_block_number(500u32.into());
+	}: _<T::RuntimeOrigin>(rebalance_origin, pool_id, asset_in, asset_out, amount_sell, buy_min_amount)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_in, &rebalancer), 0u128);
+		assert!(T::Currency::free_balance(asset_out, &rebalancer) > amount_sell);
+	}
+
 	set_asset_tradable_state {
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -482,8 +578,12 @@ benchmarks! {
 		let new_fee = Permill::from_percent(50);
 	}: _<T::RuntimeOrigin>(successful_origin, pool_id, new_fee)
 	verify {
-		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
-		assert_eq!(pool.fee, new_fee);
+		if T::ParameterChangeDelay::get().is_zero() {
+			let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+			assert_eq!(pool.fee, new_fee);
+		} else {
+			assert!(crate::Pallet::<T>::pending_parameter_changes(pool_id, PoolParameter::Fee).is_some());
+		}
 	}
 
 	update_amplification{
@@ -515,7 +615,51 @@ benchmarks! {
 		)?;
 
 		// Worst case is when amplification is changing
-		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+		change_amplification::<T>(
+			pool_id,
+			1000,
+			100u32.into(),
+			1000u32.into(),
+		)?;
+
+		System::<T>::set_block_number(500u32.into());
+
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, 2000, 501u32.into(), 1000u32.into())
+	verify {
+		if T::ParameterChangeDelay::get().is_zero() {
+			let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+
+			assert_eq!(pool.initial_amplification, NonZeroU16::new(500).unwrap());
+			assert_eq!(pool.final_amplification, NonZeroU16::new(2000).unwrap());
+			assert_eq!(pool.initial_block, 501u32.into());
+			assert_eq!(pool.final_block, 1000u32.into());
+		} else {
+			assert!(crate::Pallet::<T>::pending_parameter_changes(pool_id, PoolParameter::Amplification).is_some());
+		}
+	}
+
+	stop_amplification_change{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000_000u128;
+
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, initial_liquidity as i128)?;
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		change_amplification::<T>(
 			pool_id,
 			1000,
 			100u32.into(),
@@ -524,14 +668,88 @@ benchmarks! {
 
 		System::<T>::set_block_number(500u32.into());
 
-	}: _<T::RuntimeOrigin>(successful_origin, pool_id, 5000, 501u32.into(), 1000u32.into())
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id)
 	verify {
 		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
 
 		assert_eq!(pool.initial_amplification, NonZeroU16::new(500).unwrap());
-		assert_eq!(pool.final_amplification, NonZeroU16::new(5000).unwrap());
-		assert_eq!(pool.initial_block, 501u32.into());
-		assert_eq!(pool.final_block, 1000u32.into());
+		assert_eq!(pool.final_amplification, NonZeroU16::new(500).unwrap());
+		assert_eq!(pool.initial_block, 500u32.into());
+		assert_eq!(pool.final_block, 500u32.into());
+	}
+
+	set_pool_pegs{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		let pegs: BoundedVec<PegSource<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>> =
+			BoundedVec::truncate_from(vec![PegSource::Value((105, 100)); MAX_ASSETS_IN_POOL as usize]);
+
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, pegs.clone())
+	verify {
+		assert_eq!(crate::Pallet::<T>::pool_pegs(pool_id), Some(pegs));
+	}
+
+	enact_parameter_change{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		// Worst case is amplification change
+		PendingParameterChanges::<T>::insert(pool_id, PoolParameter::Amplification, PendingParameterChange {
+			change: ParameterChange::Amplification {
+				final_amplification: NonZeroU16::new(200).unwrap(),
+				start_block: 10u32.into(),
+				end_block: 1000u32.into(),
+			},
+			enactment_block: 10u32.into(),
+		});
+		System::<T>::set_block_number(10u32.into());
+
+		let caller: T::AccountId = account("caller", 0, 1);
+	}: _(RawOrigin::Signed(caller), pool_id, PoolParameter::Amplification)
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+		assert_eq!(pool.final_amplification, NonZeroU16::new(200).unwrap());
+		assert!(crate::Pallet::<T>::pending_parameter_changes(pool_id, PoolParameter::Amplification).is_none());
+	}
+
+	veto_parameter_change{
+		let pool_id: T::AssetId = (1000u32).into();
+		PendingParameterChanges::<T>::insert(pool_id, PoolParameter::Fee, PendingParameterChange {
+			change: ParameterChange::Fee(Permill::from_percent(50)),
+			enactment_block: 10u32.into(),
+		});
+
+		let successful_origin = T::GuardianOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, PoolParameter::Fee)
+	verify {
+		assert!(crate::Pallet::<T>::pending_parameter_changes(pool_id, PoolParameter::Fee).is_none());
 	}
 
 	router_execution_sell{
@@ -578,7 +796,7 @@ benchmarks! {
 		T::Currency::update_balance(asset_in, &seller, amount_sell as i128)?;
 		let buy_min_amount = 1_000u128;
 		// Worst case is when amplification is changing
-		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+		change_amplification::<T>(
 			pool_id,
 			1000,
 			100u32.into(),
@@ -642,7 +860,7 @@ benchmarks! {
 		let amount_buy = 10_000_000_000_000u128;
 		let sell_max_limit = 11_000_000_000_000u128;
 		// Worst case is when amplification is changing
-		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+		change_amplification::<T>(
 			pool_id,
 			1000,
 			100u32.into(),
@@ -705,7 +923,7 @@ benchmarks! {
 		let amount_buy = 10_000_000_000_000u128;
 		let sell_max_limit = 11_000_000_000_000u128;
 		// Worst case is when amplification is changing
-		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+		change_amplification::<T>(
 			pool_id,
 			1000,
 			100u32.into(),
@@ -29,6 +29,32 @@
 //!
 //! This is currently used to update on-chain oracle.
 //!
+//! ### Rebalancing
+//!
+//! Accounts allowed by `RebalanceOrigin` (protocol modules such as a stability module or treasury rebalancer)
+//! can sell to a pool without paying the trade fee. A rebalance must move the pool towards balance - the asset sold
+//! must be the scarcer one and the trade must not overshoot the balanced state. The amount sold is limited to
+//! `MaxRebalancePortion` of the pool reserve of the asset sold.
+//!
+//! ### Pegged pools
+//!
+//! Assets of a pool can trade around configurable pegs instead of 1:1, e.g. a liquid staking token against its
+//! base asset. Peg of each pool asset is either a fixed value or a price provided by `PegOracle` and it is set by
+//! `AuthorityOrigin`. Reserves and amounts are converted to units of pool's peg base before the invariant is applied.
+//!
+//! ### Parameter changes
+//!
+//! Changes of pool fee and amplification are announced by `AuthorityOrigin` and can be enacted by anyone after
+//! `ParameterChangeDelay` blocks. During this window `GuardianOrigin` can veto the change, so LPs are protected from
+//! sudden parameter changes. Zero delay applies the changes immediately.
+//!
+//! ### Deposit caps
+//!
+//! A pool can be created with a schedule of deposit caps, so a new pool can be launched gradually. Each step of the
+//! schedule sets the maximum share issuance of the pool from given block. Liquidity cannot be added before the first
+//! step and adding liquidity which would mint shares above the current cap fails. Caps only increase and the cap of
+//! the last step stays in place once it is reached. `DepositCapIncreased` event is emitted when a step is reached.
+//!
 //! ### Terminology
 //!
 //! * **LP** - liquidity provider
@@ -53,7 +79,7 @@
 
 extern crate core;
 
-use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
 use frame_support::{ensure, require_transactional, transactional, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{registry::Inspect, AccountIdFor};
@@ -68,9 +94,17 @@ mod trade_execution;
 pub mod types;
 pub mod weights;
 
-use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
-use hydra_dx_math::stableswap::types::AssetReserve;
+use crate::types::{
+	AssetAmount, Balance, DepositCap, ParameterChange, PegSource, PendingParameterChange, PoolInfo, PoolParameter,
+	PoolState, StableswapHooks, Tradability,
+};
+use hydra_dx_math::ema::EmaPrice;
+use hydra_dx_math::stableswap::types::{AssetReserve, PegType};
+use hydra_dx_math::stableswap::Rounding;
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
+use hydradx_traits::oracle::AggregatedPriceOracle;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::router::PoolType;
 use orml_traits::MultiCurrency;
 use sp_std::collections::btree_map::BTreeMap;
 pub use weights::WeightInfo;
@@ -90,6 +124,8 @@ pub const POOL_IDENTIFIER: &[u8] = b"sts";
 
 pub const MAX_ASSETS_IN_POOL: u32 = 5;
 
+pub const MAX_DEPOSIT_CAP_STEPS: u32 = 10;
+
 const D_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_D_ITERATIONS;
 const Y_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_Y_ITERATIONS;
 
@@ -143,12 +179,18 @@ pub mod pallet {
 		/// Security origin which can set the asset tradable state
 		type UpdateTradabilityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Origin of protocol accounts allowed to rebalance pools without paying trade fee.
+		type RebalanceOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
 
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Handler called after each trade, e.g. to distribute part of the fee as referral rewards.
+		type OnTradeExecuted: OnTradeExecuted<Self::AccountId, Self::AssetId, Balance>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -157,10 +199,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinTradingLimit: Get<Balance>;
 
+		/// Maximum portion of a pool reserve of the asset sold that can be sold in a single rebalance.
+		#[pallet::constant]
+		type MaxRebalancePortion: Get<Permill>;
+
 		/// Amplification inclusive range. Pool's amp can be selected from the range only.
 		#[pallet::constant]
 		type AmplificationRange: Get<RangeInclusive<NonZeroU16>>;
 
+		/// Max change of amplification per block, relative to the lower of current and final amplification.
+		/// Amplification changes which are faster than this are rejected. `None` means no limit.
+		type MaxAmplificationChangePerBlock: Get<Option<Permill>>;
+
+		/// Number of blocks between announcement of a pool fee or amplification change and its enactment.
+		/// Zero delay applies the changes immediately.
+		#[pallet::constant]
+		type ParameterChangeDelay: Get<BlockNumberFor<Self>>;
+
+		/// Origin which can veto announced pool parameter changes.
+		type GuardianOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Oracle providing prices of assets of pegged pools.
+		type PegOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -179,6 +240,36 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Peg sources of pool assets, in the order of pool assets.
+	/// Assets of pools without pegs are traded 1:1.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_pegs)]
+	pub type PoolPegs<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BoundedVec<PegSource<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>>>;
+
+	/// Announced pool parameter changes waiting for enactment.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_parameter_changes)]
+	pub type PendingParameterChanges<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Twox64Concat,
+		PoolParameter,
+		PendingParameterChange<BlockNumberFor<T>>,
+	>;
+
+	/// Deposit caps schedules of pools, steps ordered by block.
+	/// Pools without a schedule are not capped.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_deposit_caps)]
+	pub type PoolDepositCaps<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<DepositCap<BlockNumberFor<T>>, ConstU32<MAX_DEPOSIT_CAP_STEPS>>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -242,8 +333,50 @@ pub mod pallet {
 			start_block: BlockNumberFor<T>,
 			end_block: BlockNumberFor<T>,
 		},
+		/// Scheduled amplification change of a pool has been stopped.
+		AmplificationChangeStopped {
+			pool_id: T::AssetId,
+			amplification: NonZeroU16,
+			block: BlockNumberFor<T>,
+		},
 		/// A pool has been destroyed.
 		PoolDestroyed { pool_id: T::AssetId },
+
+		/// Fee-free rebalance trade executed.
+		RebalanceExecuted {
+			who: T::AccountId,
+			pool_id: T::AssetId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+
+		/// Peg sources of a pool have been updated. Empty list means pegs were removed.
+		PoolPegsUpdated {
+			pool_id: T::AssetId,
+			pegs: Vec<PegSource<T::AssetId>>,
+		},
+
+		/// Change of a pool parameter has been announced and can be enacted from `enactment_block`.
+		ParameterChangeAnnounced {
+			pool_id: T::AssetId,
+			change: ParameterChange<BlockNumberFor<T>>,
+			enactment_block: BlockNumberFor<T>,
+		},
+		/// Announced change of a pool parameter has been vetoed.
+		ParameterChangeVetoed {
+			pool_id: T::AssetId,
+			parameter: PoolParameter,
+		},
+
+		/// Deposit caps schedule of a pool has been set.
+		DepositCapsSet {
+			pool_id: T::AssetId,
+			caps: Vec<DepositCap<BlockNumberFor<T>>>,
+		},
+		/// Deposit cap of a pool has stepped up.
+		DepositCapIncreased { pool_id: T::AssetId, cap: Balance },
 	}
 
 	#[pallet::error]
@@ -320,6 +453,36 @@ pub mod pallet {
 
 		/// Failed to retrieve asset decimals.
 		UnknownDecimals,
+
+		/// Rebalance amount exceeds maximum allowed portion of the pool reserve.
+		MaxRebalanceAmountExceeded,
+
+		/// Rebalance trade would not move the pool towards balance.
+		InvalidRebalance,
+
+		/// Amplification would change faster than allowed by `MaxAmplificationChangePerBlock`.
+		AmplificationChangeTooFast,
+
+		/// Amplification of the pool is not changing.
+		AmplificationNotChanging,
+
+		/// Number of pegs does not match number of pool assets or a peg is invalid.
+		IncorrectPegs,
+
+		/// Failed to retrieve peg of an asset from oracle.
+		PegOracleNotAvailable,
+
+		/// No change of the pool parameter has been announced.
+		ParameterChangeNotFound,
+
+		/// Announced parameter change cannot be enacted yet.
+		ParameterChangeNotDue,
+
+		/// Deposit caps schedule is empty or its blocks and caps are not increasing.
+		InvalidDepositCaps,
+
+		/// Share issuance of the pool would exceed its current deposit cap.
+		DepositCapExceeded,
 	}
 
 	#[pallet::call]
@@ -375,36 +538,44 @@ pub mod pallet {
 		///
 		/// if pool does not exist, `PoolNotFound` is returned.
 		///
+		/// If `ParameterChangeDelay` is not zero, the change is only announced and can be enacted
+		/// by `enact_parameter_change` after the delay. Previously announced fee change is replaced.
+		///
 		/// Parameters:
 		/// - `origin`: Must be T::AuthorityOrigin
 		/// - `pool_id`: pool to update
 		/// - `fee`: new pool fee
 		///
-		/// Emits `FeeUpdated` event if successful.
+		/// Emits `FeeUpdated` event if applied immediately, `ParameterChangeAnnounced` otherwise.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
 		#[transactional]
 		pub fn update_pool_fee(origin: OriginFor<T>, pool_id: T::AssetId, fee: Permill) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
 
-			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
-				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
 
-				pool.fee = fee;
-				Self::deposit_event(Event::FeeUpdated { pool_id, fee });
-				Ok(())
-			})
+			Self::announce_parameter_change(pool_id, ParameterChange::Fee(fee))
 		}
 
 		/// Update pool's amplification.
 		///
+		/// Amplification changes linearly from current amplification at `start_block`
+		/// to `final_amplification` at `end_block`. Pool's amplification is always calculated at the time of execution.
+		///
+		/// Change of amplification per block must not exceed `MaxAmplificationChangePerBlock`.
+		///
+		/// If `ParameterChangeDelay` is not zero, the change is only announced and can be enacted
+		/// by `enact_parameter_change` after the delay. Previously announced amplification change is replaced.
+		/// Announced change starts at `start_block` or at its enactment, whichever is later.
+		///
 		/// Parameters:
 		/// - `origin`: Must be T::AuthorityOrigin
 		/// - `pool_id`: pool to update
 		/// - `future_amplification`: new desired pool amplification
 		/// - `future_block`: future block number when the amplification is updated
 		///
-		/// Emits `AmplificationUpdated` event if successful.
+		/// Emits `AmplificationChanging` event if applied immediately, `ParameterChangeAnnounced` otherwise.
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as Config>::WeightInfo::update_amplification())]
 		#[transactional]
@@ -423,36 +594,22 @@ pub mod pallet {
 				Error::<T>::PastBlock
 			);
 
-			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
-				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
-
-				let current_amplification = Self::get_amplification(pool);
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
 
-				ensure!(
-					current_amplification != final_amplification as u128,
-					Error::<T>::SameAmplification
-				);
-
-				pool.initial_amplification =
-					NonZeroU16::new(current_amplification.saturated_into()).ok_or(Error::<T>::InvalidAmplification)?;
-				pool.final_amplification =
-					NonZeroU16::new(final_amplification).ok_or(Error::<T>::InvalidAmplification)?;
-				pool.initial_block = start_block;
-				pool.final_block = end_block;
+			let final_amplification = NonZeroU16::new(final_amplification).ok_or(Error::<T>::InvalidAmplification)?;
+			ensure!(
+				T::AmplificationRange::get().contains(&final_amplification),
+				Error::<T>::InvalidAmplification
+			);
 
-				ensure!(
-					T::AmplificationRange::get().contains(&pool.final_amplification),
-					Error::<T>::InvalidAmplification
-				);
-				Self::deposit_event(Event::AmplificationChanging {
-					pool_id,
-					current_amplification: pool.initial_amplification,
-					final_amplification: pool.final_amplification,
-					start_block: pool.initial_block,
-					end_block: pool.final_block,
-				});
-				Ok(())
-			})
+			Self::announce_parameter_change(
+				pool_id,
+				ParameterChange::Amplification {
+					final_amplification,
+					start_block,
+					end_block,
+				},
+			)
 		}
 
 		/// Add liquidity to selected pool.
@@ -473,6 +630,7 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+							.saturating_add(Pallet::<T>::get_pegs_weight())
 							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn add_liquidity(
@@ -510,6 +668,7 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_shares()
+							.saturating_add(Pallet::<T>::get_pegs_weight())
 							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn add_liquidity_shares(
@@ -549,6 +708,7 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
+							.saturating_add(Pallet::<T>::get_pegs_weight())
 							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
@@ -590,10 +750,12 @@ pub mod pallet {
 			);
 
 			let amplification = Self::get_amplification(&pool);
+			let pegs = Self::get_pegs(pool_id, &pool)?;
+			let pegged_reserves = Self::peg_reserves(&initial_reserves, &pegs)?;
 
 			//Calculate how much asset user will receive. Note that the fee is already subtracted from the amount.
 			let (amount, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
-				&initial_reserves,
+				&pegged_reserves,
 				share_amount,
 				asset_idx,
 				share_issuance,
@@ -601,6 +763,8 @@ pub mod pallet {
 				pool.fee,
 			)
 			.ok_or(ArithmeticError::Overflow)?;
+			let amount = Self::unpeg_amount(amount, pegs[asset_idx], Rounding::Down)?;
+			let fee = Self::unpeg_amount(fee, pegs[asset_idx], Rounding::Down)?;
 
 			ensure!(amount >= min_amount_out, Error::<T>::SlippageLimit);
 
@@ -639,6 +803,7 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as Config>::WeightInfo::withdraw_asset_amount()
+							.saturating_add(Pallet::<T>::get_pegs_weight())
 							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn withdraw_asset_amount(
@@ -665,12 +830,14 @@ pub mod pallet {
 				.ok_or(Error::<T>::UnknownDecimals)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let amplification = Self::get_amplification(&pool);
+			let pegs = Self::get_pegs(pool_id, &pool)?;
+			let pegged_reserves = Self::peg_reserves(&initial_reserves, &pegs)?;
 
 			// Calculate how much shares user needs to provide to receive `amount` of asset.
 			let shares = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
-				&initial_reserves,
+				&pegged_reserves,
 				asset_idx,
-				amount,
+				Self::peg_amount(amount, pegs[asset_idx], Rounding::Up)?,
 				amplification,
 				share_issuance,
 				pool.fee,
@@ -718,7 +885,9 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(Pallet::<T>::get_pegs_weight())
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::Stableswap(*pool_id))))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -757,9 +926,27 @@ pub mod pallet {
 			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
 
+			let reserve_in = T::Currency::free_balance(asset_in, &pool_account);
+			let reserve_out = T::Currency::free_balance(asset_out, &pool_account);
+
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
 			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
 
+			T::OnTradeExecuted::on_trade_executed(&TradeInfo {
+				pool: PoolType::Stableswap(pool_id),
+				trader: who.clone(),
+				fee_account: pool_account,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				reserve_in,
+				reserve_out,
+				fee_asset: asset_out,
+				fee_amount,
+				protocol_fee: Balance::zero(),
+			})?;
+
 			//All done and updated. Let's call on_trade hook.
 			Self::call_on_trade_hook(pool_id, asset_in, asset_out, &initial_reserves)?;
 
@@ -793,7 +980,9 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(Pallet::<T>::get_pegs_weight())
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::Stableswap(*pool_id))))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -835,9 +1024,27 @@ pub mod pallet {
 				Error::<T>::InsufficientBalance
 			);
 
+			let reserve_in = T::Currency::free_balance(asset_in, &pool_account);
+			let reserve_out = T::Currency::free_balance(asset_out, &pool_account);
+
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
 			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
 
+			T::OnTradeExecuted::on_trade_executed(&TradeInfo {
+				pool: PoolType::Stableswap(pool_id),
+				trader: who.clone(),
+				fee_account: pool_account,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				reserve_in,
+				reserve_out,
+				fee_asset: asset_in,
+				fee_amount,
+				protocol_fee: Balance::zero(),
+			})?;
+
 			//All done and updated. Let's call on_trade_hook.
 			Self::call_on_trade_hook(pool_id, asset_in, asset_out, &initial_reserves)?;
 
@@ -956,7 +1163,10 @@ pub mod pallet {
 			} else {
 				// Remove the pool.
 				Pools::<T>::remove(pool_id);
+				PoolPegs::<T>::remove(pool_id);
+				PoolDepositCaps::<T>::remove(pool_id);
 				let _ = AssetTradability::<T>::clear_prefix(pool_id, MAX_ASSETS_IN_POOL, None);
+				let _ = PendingParameterChanges::<T>::clear_prefix(pool_id, 2, None);
 				T::DustAccountHandler::remove_account(&Self::pool_account(pool_id))?;
 				Self::deposit_event(Event::PoolDestroyed { pool_id });
 			}
@@ -974,13 +1184,498 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Execute a fee-free swap of `asset_in` for `asset_out` to rebalance the pool.
+		///
+		/// Only accounts allowed by `RebalanceOrigin` can rebalance. Sold asset must be the scarcer one and
+		/// the trade must not overshoot the balanced state of the two assets.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::RebalanceOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: Amount of asset to be sold to the pool
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `RebalanceExecuted` event when successful.
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::rebalance()
+							.saturating_add(Pallet::<T>::get_pegs_weight())
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+		#[transactional]
+		pub fn rebalance(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = T::RebalanceOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+			ensure!(
+				Self::is_asset_allowed(pool_id, asset_in, Tradability::SELL)
+					&& Self::is_asset_allowed(pool_id, asset_out, Tradability::BUY),
+				Error::<T>::NotAllowed
+			);
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+			let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+
+			let pool_account = Self::pool_account(pool_id);
+			let initial_reserves = pool
+				.reserves_with_decimals::<T>(&pool_account)
+				.ok_or(Error::<T>::UnknownDecimals)?;
+
+			let reserve_in = initial_reserves[index_in];
+			let reserve_out = initial_reserves[index_out];
+			ensure!(
+				!reserve_in.is_zero() && !reserve_out.is_zero(),
+				Error::<T>::InsufficientLiquidity
+			);
+			ensure!(
+				amount_in <= T::MaxRebalancePortion::get().mul_floor(reserve_in.amount),
+				Error::<T>::MaxRebalanceAmountExceeded
+			);
+
+			let amplification = Self::get_amplification(&pool);
+			let pegs = Self::get_pegs(pool_id, &pool)?;
+			let pegged_reserves = Self::peg_reserves(&initial_reserves, &pegs)?;
+			let pegged_amount_in = Self::peg_amount(amount_in, pegs[index_in], Rounding::Down)?;
+			let (pegged_amount_out, _) =
+				hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+					&pegged_reserves,
+					index_in,
+					index_out,
+					pegged_amount_in,
+					amplification,
+					Permill::zero(),
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+			let amount_out = Self::unpeg_amount(pegged_amount_out, pegs[index_out], Rounding::Down)?;
+			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			// Sold asset must be the scarcer one, and it must not become the more abundant one after the trade.
+			// Reserves are compared in units of pool's peg base.
+			let reserve_in = pegged_reserves[index_in];
+			let reserve_out = pegged_reserves[index_out];
+			let final_in = AssetReserve::new(
+				reserve_in
+					.amount
+					.checked_add(pegged_amount_in)
+					.ok_or(ArithmeticError::Overflow)?,
+				reserve_in.decimals,
+			);
+			let final_out = AssetReserve::new(
+				reserve_out
+					.amount
+					.checked_sub(pegged_amount_out)
+					.ok_or(ArithmeticError::Underflow)?,
+				reserve_out.decimals,
+			);
+			ensure!(
+				Self::normalize_reserve(&reserve_in) < Self::normalize_reserve(&reserve_out)
+					&& Self::normalize_reserve(&final_in) <= Self::normalize_reserve(&final_out),
+				Error::<T>::InvalidRebalance
+			);
+
+			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
+
+			Self::call_on_trade_hook(pool_id, asset_in, asset_out, &initial_reserves)?;
+
+			Self::deposit_event(Event::RebalanceExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			#[cfg(feature = "try-runtime")]
+			Self::ensure_trade_invariant(pool_id, &initial_reserves, Permill::zero());
+
+			Ok(())
+		}
+
+		/// Stop scheduled amplification change of a pool.
+		///
+		/// Pool's amplification is fixed at the value calculated for the current block.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		///
+		/// Emits `AmplificationChangeStopped` event if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::stop_amplification_change())]
+		#[transactional]
+		pub fn stop_amplification_change(origin: OriginFor<T>, pool_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(
+					pool.final_block > current_block && pool.initial_amplification != pool.final_amplification,
+					Error::<T>::AmplificationNotChanging
+				);
+
+				let amplification = NonZeroU16::new(Self::get_amplification(pool).saturated_into())
+					.ok_or(Error::<T>::InvalidAmplification)?;
+
+				pool.initial_amplification = amplification;
+				pool.final_amplification = amplification;
+				pool.initial_block = current_block;
+				pool.final_block = current_block;
+
+				Self::deposit_event(Event::AmplificationChangeStopped {
+					pool_id,
+					amplification,
+					block: current_block,
+				});
+				Ok(())
+			})
+		}
+
+		/// Set peg sources of a pool.
+		///
+		/// Pool assets are traded around their pegs - amounts and reserves are converted to units of pool's
+		/// peg base before the invariant is applied. Peg sources must be provided in the order of pool assets.
+		/// Empty list removes pegs of the pool, so the assets are traded 1:1 again.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `pegs`: peg source of each pool asset
+		///
+		/// Emits `PoolPegsUpdated` event if successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_pegs().saturating_add(Pallet::<T>::get_pegs_weight()))]
+		#[transactional]
+		pub fn set_pool_pegs(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			pegs: BoundedVec<PegSource<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			if pegs.is_empty() {
+				PoolPegs::<T>::remove(pool_id);
+			} else {
+				ensure!(
+					pegs.len() == pool.assets.len() && pegs.iter().all(|peg| peg.is_valid()),
+					Error::<T>::IncorrectPegs
+				);
+				PoolPegs::<T>::insert(pool_id, pegs.clone());
+				// Ensure that all pegs can be retrieved.
+				Self::get_pegs(pool_id, &pool)?;
+			}
+
+			Self::deposit_event(Event::PoolPegsUpdated {
+				pool_id,
+				pegs: pegs.into_inner(),
+			});
+			Ok(())
+		}
+
+		/// Enact announced change of a pool parameter.
+		///
+		/// Can be called by anyone once `ParameterChangeDelay` has passed since the announcement.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin
+		/// - `pool_id`: pool to update
+		/// - `parameter`: parameter which change is enacted
+		///
+		/// Emits `FeeUpdated` or `AmplificationChanging` event if successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::enact_parameter_change())]
+		#[transactional]
+		pub fn enact_parameter_change(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			parameter: PoolParameter,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pending =
+				PendingParameterChanges::<T>::get(pool_id, parameter).ok_or(Error::<T>::ParameterChangeNotFound)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >= pending.enactment_block,
+				Error::<T>::ParameterChangeNotDue
+			);
+
+			PendingParameterChanges::<T>::remove(pool_id, parameter);
+
+			Self::do_update_pool_parameter(pool_id, pending.change)
+		}
+
+		/// Veto announced change of a pool parameter.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::GuardianOrigin
+		/// - `pool_id`: pool of the announced change
+		/// - `parameter`: parameter which change is vetoed
+		///
+		/// Emits `ParameterChangeVetoed` event if successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::veto_parameter_change())]
+		#[transactional]
+		pub fn veto_parameter_change(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			parameter: PoolParameter,
+		) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				PendingParameterChanges::<T>::take(pool_id, parameter).is_some(),
+				Error::<T>::ParameterChangeNotFound
+			);
+
+			Self::deposit_event(Event::ParameterChangeVetoed { pool_id, parameter });
+			Ok(())
+		}
+
+		/// Create a stable pool with given list of assets and a schedule of deposit caps.
+		///
+		/// Same as `create_pool`, but share issuance of the pool is limited by the cap of the last reached step
+		/// of the schedule. Liquidity cannot be added before the first step.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `assets`: List of Asset ids
+		/// - `amplification`: Pool amplification
+		/// - `fee`: fee to be applied on trade and liquidity operations
+		/// - `caps`: deposit caps schedule, both blocks and caps must be strictly increasing
+		///
+		/// Emits `PoolCreated` and `DepositCapsSet` events if successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_deposit_caps())]
+		#[transactional]
+		pub fn create_pool_with_deposit_caps(
+			origin: OriginFor<T>,
+			share_asset: T::AssetId,
+			assets: Vec<T::AssetId>,
+			amplification: u16,
+			fee: Permill,
+			caps: BoundedVec<DepositCap<BlockNumberFor<T>>, ConstU32<MAX_DEPOSIT_CAP_STEPS>>,
+		) -> DispatchResult {
+			ensure!(
+				!caps.is_empty()
+					&& caps
+						.windows(2)
+						.all(|steps| steps[0].block < steps[1].block && steps[0].cap < steps[1].cap),
+				Error::<T>::InvalidDepositCaps
+			);
+
+			Self::create_pool(origin, share_asset, assets, amplification, fee)?;
+			PoolDepositCaps::<T>::insert(share_asset, caps.clone());
+
+			Self::deposit_event(Event::DepositCapsSet {
+				pool_id: share_asset,
+				caps: caps.into_inner(),
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::notify_deposit_cap_increases()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), DispatchError> {
+			for (pool_id, pool) in Pools::<T>::iter() {
+				let pool_account = Self::pool_account(pool_id);
+				let share_issuance = T::Currency::total_issuance(pool_id);
+				if !share_issuance.is_zero() {
+					for asset_id in pool.assets.iter() {
+						assert!(
+							!T::Currency::free_balance(*asset_id, &pool_account).is_zero(),
+							"Pool {:?} has shares issued but no reserve of asset {:?}",
+							pool_id,
+							asset_id
+						);
+					}
+				}
+				if let Some(pegs) = PoolPegs::<T>::get(pool_id) {
+					assert_eq!(
+						pegs.len(),
+						pool.assets.len(),
+						"Pool {:?} peg sources do not match pool assets",
+						pool_id
+					);
+				}
+			}
+
+			for (pool_id, asset_id, _) in AssetTradability::<T>::iter() {
+				let pool = Pools::<T>::get(pool_id);
+				assert!(
+					pool.is_some_and(|pool| pool.find_asset(asset_id).is_some()),
+					"Tradability of asset {:?} is set for non-existent pool asset of pool {:?}",
+					asset_id,
+					pool_id
+				);
+			}
+			for pool_id in PoolPegs::<T>::iter_keys()
+				.chain(PoolDepositCaps::<T>::iter_keys())
+				.chain(PendingParameterChanges::<T>::iter_keys().map(|(pool_id, _)| pool_id))
+			{
+				assert!(
+					Pools::<T>::contains_key(pool_id),
+					"Pool {:?} storage left after pool was removed",
+					pool_id
+				);
+			}
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Apply the change immediately if `ParameterChangeDelay` is zero, otherwise store it as pending.
+	fn announce_parameter_change(pool_id: T::AssetId, change: ParameterChange<BlockNumberFor<T>>) -> DispatchResult {
+		let delay = T::ParameterChangeDelay::get();
+		if delay.is_zero() {
+			return Self::do_update_pool_parameter(pool_id, change);
+		}
+
+		let enactment_block = T::BlockNumberProvider::current_block_number().saturating_add(delay);
+		PendingParameterChanges::<T>::insert(
+			pool_id,
+			change.parameter(),
+			PendingParameterChange {
+				change: change.clone(),
+				enactment_block,
+			},
+		);
+
+		Self::deposit_event(Event::ParameterChangeAnnounced {
+			pool_id,
+			change,
+			enactment_block,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_update_pool_parameter(
+		pool_id: T::AssetId,
+		change: ParameterChange<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+			match change {
+				ParameterChange::Fee(fee) => {
+					pool.fee = fee;
+					Self::deposit_event(Event::FeeUpdated { pool_id, fee });
+				}
+				ParameterChange::Amplification {
+					final_amplification,
+					start_block,
+					end_block,
+				} => {
+					// Announced change may be enacted after its start block.
+					let start_block = start_block.max(T::BlockNumberProvider::current_block_number());
+					ensure!(end_block > start_block, Error::<T>::PastBlock);
+
+					let current_amplification = Self::get_amplification(pool);
+
+					ensure!(
+						current_amplification != final_amplification.get() as u128,
+						Error::<T>::SameAmplification
+					);
+
+					if let Some(max_change) = T::MaxAmplificationChangePerBlock::get() {
+						let duration: u128 = end_block.saturating_sub(start_block).saturated_into();
+						let max_diff = max_change
+							.mul_ceil(current_amplification.min(final_amplification.get() as u128))
+							.saturating_mul(duration);
+						ensure!(
+							current_amplification.abs_diff(final_amplification.get() as u128) <= max_diff,
+							Error::<T>::AmplificationChangeTooFast
+						);
+					}
+
+					pool.initial_amplification = NonZeroU16::new(current_amplification.saturated_into())
+						.ok_or(Error::<T>::InvalidAmplification)?;
+					pool.final_amplification = final_amplification;
+					pool.initial_block = start_block;
+					pool.final_block = end_block;
+
+					Self::deposit_event(Event::AmplificationChanging {
+						pool_id,
+						current_amplification: pool.initial_amplification,
+						final_amplification: pool.final_amplification,
+						start_block: pool.initial_block,
+						end_block: pool.final_block,
+					});
+				}
+			}
+			Ok(())
+		})
+	}
+
+	/// Returns current deposit cap of a pool, `None` if the pool is not capped.
+	///
+	/// Cap is zero before the first step of the schedule is reached.
+	pub fn current_deposit_cap(pool_id: T::AssetId) -> Option<Balance> {
+		let caps = PoolDepositCaps::<T>::get(pool_id)?;
+		let current_block = T::BlockNumberProvider::current_block_number();
+		Some(
+			caps.iter()
+				.rev()
+				.find(|step| step.block <= current_block)
+				.map(|step| step.cap)
+				.unwrap_or_default(),
+		)
+	}
+
+	fn ensure_deposit_cap(pool_id: T::AssetId, share_issuance: Balance) -> DispatchResult {
+		if let Some(cap) = Self::current_deposit_cap(pool_id) {
+			ensure!(share_issuance <= cap, Error::<T>::DepositCapExceeded);
+		}
+		Ok(())
+	}
+
+	/// Emits `DepositCapIncreased` for each pool which reaches a step of its deposit caps schedule in current block.
+	fn notify_deposit_cap_increases() -> Weight {
+		let current_block = T::BlockNumberProvider::current_block_number();
+		let mut reads = 0u64;
+		for (pool_id, caps) in PoolDepositCaps::<T>::iter() {
+			reads.saturating_inc();
+			if let Some(step) = caps.iter().find(|step| step.block == current_block) {
+				Self::deposit_event(Event::DepositCapIncreased { pool_id, cap: step.cap });
+			}
+		}
+		T::DbWeight::get().reads(reads.saturating_add(1))
+	}
+
 	/// Account address to be used to dry-run sell for determining spot price of stable assets
 	pub fn pallet_account() -> T::AccountId {
 		PalletId(*b"stblpool").into_account_truncating()
@@ -1011,15 +1706,22 @@ impl<T: Config> Pallet<T> {
 		);
 
 		let amplification = Self::get_amplification(&pool);
-		hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
-			&initial_reserves,
-			index_in,
-			index_out,
-			amount_in,
-			amplification,
-			pool.fee,
-		)
-		.ok_or_else(|| ArithmeticError::Overflow.into())
+		let pegs = Self::get_pegs(pool_id, &pool)?;
+		let (amount_out, fee) =
+			hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&Self::peg_reserves(&initial_reserves, &pegs)?,
+				index_in,
+				index_out,
+				Self::peg_amount(amount_in, pegs[index_in], Rounding::Down)?,
+				amplification,
+				pool.fee,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((
+			Self::unpeg_amount(amount_out, pegs[index_out], Rounding::Down)?,
+			Self::unpeg_amount(fee, pegs[index_out], Rounding::Down)?,
+		))
 	}
 
 	/// Calculates in amount given out amount.
@@ -1047,19 +1749,27 @@ impl<T: Config> Pallet<T> {
 		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
-		hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
-			&initial_reserves,
-			index_in,
-			index_out,
-			amount_out,
-			amplification,
-			pool.fee,
-		)
-		.ok_or_else(|| ArithmeticError::Overflow.into())
+		let pegs = Self::get_pegs(pool_id, &pool)?;
+		let (amount_in, fee) =
+			hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&Self::peg_reserves(&initial_reserves, &pegs)?,
+				index_in,
+				index_out,
+				Self::peg_amount(amount_out, pegs[index_out], Rounding::Up)?,
+				amplification,
+				pool.fee,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((
+			Self::unpeg_amount(amount_in, pegs[index_in], Rounding::Up)?,
+			Self::unpeg_amount(fee, pegs[index_in], Rounding::Down)?,
+		))
 	}
 
+	/// Create a pool without seeding it with liquidity. No events emitted.
 	#[require_transactional]
-	fn do_create_pool(
+	pub fn do_create_pool(
 		share_asset: T::AssetId,
 		assets: &[T::AssetId],
 		amplification: NonZeroU16,
@@ -1103,8 +1813,11 @@ impl<T: Config> Pallet<T> {
 		Ok(share_asset)
 	}
 
+	/// Add liquidity of given assets from `who` and mint pool shares to it. No events emitted.
+	///
+	/// Returns amount of shares minted.
 	#[require_transactional]
-	fn do_add_liquidity(
+	pub fn do_add_liquidity(
 		who: &T::AccountId,
 		pool_id: T::AssetId,
 		assets: &[AssetAmount<T::AssetId>],
@@ -1162,9 +1875,10 @@ impl<T: Config> Pallet<T> {
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
+		let pegs = Self::get_pegs(pool_id, &pool)?;
 		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
-			&initial_reserves,
-			&updated_reserves,
+			&Self::peg_reserves(&initial_reserves, &pegs)?,
+			&Self::peg_reserves(&updated_reserves, &pegs)?,
 			amplification,
 			share_issuance,
 			pool.fee,
@@ -1172,6 +1886,7 @@ impl<T: Config> Pallet<T> {
 		.ok_or(ArithmeticError::Overflow)?;
 
 		ensure!(!share_amount.is_zero(), Error::<T>::InvalidAssetAmount);
+		Self::ensure_deposit_cap(pool_id, share_issuance.saturating_add(share_amount))?;
 		let current_share_balance = T::Currency::free_balance(pool_id, who);
 
 		ensure!(
@@ -1220,8 +1935,9 @@ impl<T: Config> Pallet<T> {
 			ensure!(!reserve.amount.is_zero(), Error::<T>::InvalidInitialLiquidity);
 		}
 
+		let pegs = Self::get_pegs(pool_id, &pool)?;
 		let (amount_in, _) = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
-			&initial_reserves,
+			&Self::peg_reserves(&initial_reserves, &pegs)?,
 			shares,
 			asset_idx,
 			share_issuance,
@@ -1229,10 +1945,12 @@ impl<T: Config> Pallet<T> {
 			pool.fee,
 		)
 		.ok_or(ArithmeticError::Overflow)?;
+		let amount_in = Self::unpeg_amount(amount_in, pegs[asset_idx], Rounding::Up)?;
 
 		ensure!(amount_in <= max_asset_amount, Error::<T>::SlippageLimit);
 
 		ensure!(!amount_in.is_zero(), Error::<T>::InvalidAssetAmount);
+		Self::ensure_deposit_cap(pool_id, share_issuance.saturating_add(shares))?;
 		let current_share_balance = T::Currency::free_balance(pool_id, who);
 
 		ensure!(
@@ -1271,9 +1989,76 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[inline]
+	/// Scales reserve amount to 18 decimals, so reserves of assets with different decimals can be compared.
+	fn normalize_reserve(reserve: &AssetReserve) -> Balance {
+		const TARGET_DECIMALS: u8 = 18;
+		if reserve.decimals <= TARGET_DECIMALS {
+			reserve
+				.amount
+				.saturating_mul(10u128.saturating_pow((TARGET_DECIMALS - reserve.decimals) as u32))
+		} else {
+			reserve.amount / 10u128.saturating_pow((reserve.decimals - TARGET_DECIMALS) as u32)
+		}
+	}
+
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
 	}
+
+	/// Retrieve pegs of pool assets, in the order of pool assets.
+	/// Assets of pools without pegs are pegged 1:1.
+	pub(crate) fn get_pegs(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+	) -> Result<Vec<PegType>, DispatchError> {
+		let Some(sources) = PoolPegs::<T>::get(pool_id) else {
+			return Ok(vec![(1, 1); pool.assets.len()]);
+		};
+		ensure!(sources.len() == pool.assets.len(), Error::<T>::IncorrectPegs);
+
+		pool.assets
+			.iter()
+			.zip(sources.iter())
+			.map(|(asset_id, source)| match source {
+				PegSource::Value(peg) => Ok(*peg),
+				PegSource::Oracle((_, _, base_asset)) if base_asset == asset_id => Ok((1, 1)),
+				PegSource::Oracle((source, period, base_asset)) => {
+					let (price, _) = T::PegOracle::get_price(*base_asset, *asset_id, *period, *source)
+						.map_err(|_| Error::<T>::PegOracleNotAvailable)?;
+					ensure!(
+						!price.n.is_zero() && !price.d.is_zero(),
+						Error::<T>::PegOracleNotAvailable
+					);
+					Ok((price.n, price.d))
+				}
+			})
+			.collect()
+	}
+
+	/// Weight of retrieving pegs of a pool.
+	pub(crate) fn get_pegs_weight() -> Weight {
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(T::PegOracle::get_price_weight().saturating_mul(MAX_ASSETS_IN_POOL as u64))
+	}
+
+	#[inline]
+	pub(crate) fn peg_reserves(
+		reserves: &[AssetReserve],
+		pegs: &[PegType],
+	) -> Result<Vec<AssetReserve>, DispatchError> {
+		hydra_dx_math::stableswap::peg_reserves(reserves, pegs).ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	#[inline]
+	pub(crate) fn peg_amount(amount: Balance, peg: PegType, rounding: Rounding) -> Result<Balance, DispatchError> {
+		hydra_dx_math::stableswap::peg_amount(amount, peg, rounding).ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	#[inline]
+	pub(crate) fn unpeg_amount(amount: Balance, peg: PegType, rounding: Rounding) -> Result<Balance, DispatchError> {
+		hydra_dx_math::stableswap::unpeg_amount(amount, peg, rounding).ok_or_else(|| ArithmeticError::Overflow.into())
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -1327,9 +2112,10 @@ impl<T: Config> Pallet<T> {
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
+		let pegs = Self::get_pegs(pool_id, &pool)?;
 		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
-			&initial_reserves,
-			&updated_reserves,
+			&Self::peg_reserves(&initial_reserves, &pegs)?,
+			&Self::peg_reserves(&updated_reserves, &pegs)?,
 			amplification,
 			share_issuance,
 			pool.fee,
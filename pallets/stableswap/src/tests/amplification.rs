@@ -315,3 +315,197 @@ fn amplification_should_change_when_block_changes() {
 			}
 		});
 }
+
+#[test]
+fn update_amplification_should_fail_when_change_is_faster_than_allowed() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_max_amplification_change(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 1000, 10, 500),
+				Error::<Test>::AmplificationChangeTooFast
+			);
+
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1000,
+			));
+		});
+}
+
+#[test]
+fn update_amplification_should_fail_when_decrease_is_faster_than_allowed() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_max_amplification_change(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				1000,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 100, 10, 500),
+				Error::<Test>::AmplificationChangeTooFast
+			);
+		});
+}
+
+#[test]
+fn stop_amplification_change_should_fix_current_amplification() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1000,
+			));
+
+			System::set_block_number(505);
+
+			assert_ok!(Stableswap::stop_amplification_change(RuntimeOrigin::root(), pool_id));
+
+			assert_eq!(
+				<Pools<Test>>::get(pool_id).unwrap(),
+				PoolInfo {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(550).unwrap(),
+					final_amplification: NonZeroU16::new(550).unwrap(),
+					initial_block: 505,
+					final_block: 505,
+					fee: Permill::from_percent(10),
+				}
+			);
+			expect_events(vec![crate::Event::AmplificationChangeStopped {
+				pool_id,
+				amplification: NonZeroU16::new(550).unwrap(),
+				block: 505,
+			}
+			.into()]);
+
+			System::set_block_number(1000);
+			let pool = <Pools<Test>>::get(pool_id).unwrap();
+			assert_eq!(crate::Pallet::<Test>::get_amplification(&pool), 550);
+		});
+}
+
+#[test]
+fn stop_amplification_change_should_fail_when_amplification_is_not_changing() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::stop_amplification_change(RuntimeOrigin::root(), pool_id),
+				Error::<Test>::AmplificationNotChanging
+			);
+
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1000,
+			));
+
+			System::set_block_number(1000);
+
+			assert_noop!(
+				Stableswap::stop_amplification_change(RuntimeOrigin::root(), pool_id),
+				Error::<Test>::AmplificationNotChanging
+			);
+		});
+}
+
+#[test]
+fn stop_amplification_change_should_fail_when_origin_is_not_authority() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::stop_amplification_change(RuntimeOrigin::signed(ALICE), pool_id),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
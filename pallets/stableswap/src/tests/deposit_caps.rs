@@ -0,0 +1,210 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, DepositCap};
+use crate::{assert_balance, Error, Event, PoolDepositCaps, MAX_DEPOSIT_CAP_STEPS};
+use frame_support::traits::{ConstU32, Hooks};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{DispatchResult, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const POOL_ID: AssetId = 100;
+
+fn caps(steps: Vec<(u64, Balance)>) -> BoundedVec<DepositCap<u64>, ConstU32<MAX_DEPOSIT_CAP_STEPS>> {
+	steps
+		.into_iter()
+		.map(|(block, cap)| DepositCap { block, cap })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn deposit_caps_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 1_000 * ONE),
+			(BOB, ASSET_B, 1_000 * ONE),
+			(ALICE, ASSET_A, 1_000 * ONE),
+			(ALICE, ASSET_B, 1_000 * ONE),
+		])
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Stableswap::create_pool_with_deposit_caps(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			vec![ASSET_A, ASSET_B],
+			100,
+			Permill::from_percent(0),
+			caps(vec![(5, 300 * ONE * 1_000_000), (10, 1_000 * ONE * 1_000_000)]),
+		));
+	});
+	ext
+}
+
+fn add_initial_liquidity(who: AccountId, amount: Balance) -> DispatchResult {
+	Stableswap::add_liquidity(
+		RuntimeOrigin::signed(who),
+		POOL_ID,
+		BoundedVec::truncate_from(vec![
+			AssetAmount::new(ASSET_A, amount),
+			AssetAmount::new(ASSET_B, amount),
+		]),
+	)
+}
+
+#[test]
+fn create_pool_with_deposit_caps_should_store_schedule() {
+	deposit_caps_ext().execute_with(|| {
+		assert!(Stableswap::pools(POOL_ID).is_some());
+		assert_eq!(
+			PoolDepositCaps::<Test>::get(POOL_ID),
+			Some(caps(vec![(5, 300 * ONE * 1_000_000), (10, 1_000 * ONE * 1_000_000)]))
+		);
+		expect_events(vec![Event::DepositCapsSet {
+			pool_id: POOL_ID,
+			caps: vec![
+				DepositCap {
+					block: 5,
+					cap: 300 * ONE * 1_000_000,
+				},
+				DepositCap {
+					block: 10,
+					cap: 1_000 * ONE * 1_000_000,
+				},
+			],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_with_deposit_caps_should_fail_when_schedule_is_invalid() {
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.build()
+		.execute_with(|| {
+			for schedule in [
+				vec![],
+				vec![(5, 100 * ONE), (5, 200 * ONE)],
+				vec![(5, 100 * ONE), (10, 100 * ONE)],
+				vec![(10, 100 * ONE), (5, 200 * ONE)],
+			] {
+				assert_noop!(
+					Stableswap::create_pool_with_deposit_caps(
+						RuntimeOrigin::root(),
+						POOL_ID,
+						vec![ASSET_A, ASSET_B],
+						100,
+						Permill::from_percent(0),
+						caps(schedule),
+					),
+					Error::<Test>::InvalidDepositCaps
+				);
+			}
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_first_step_is_not_reached() {
+	deposit_caps_ext().execute_with(|| {
+		assert_eq!(Stableswap::current_deposit_cap(POOL_ID), Some(0));
+		assert_noop!(add_initial_liquidity(BOB, 100 * ONE), Error::<Test>::DepositCapExceeded);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_shares_exceed_current_cap() {
+	deposit_caps_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(add_initial_liquidity(BOB, 100 * ONE));
+		assert_balance!(BOB, POOL_ID, 200 * ONE * 1_000_000);
+
+		assert_noop!(
+			add_initial_liquidity(ALICE, 100 * ONE),
+			Error::<Test>::DepositCapExceeded
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_shares_should_fail_when_shares_exceed_current_cap() {
+	deposit_caps_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(add_initial_liquidity(BOB, 100 * ONE));
+
+		assert_noop!(
+			Stableswap::add_liquidity_shares(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				101 * ONE * 1_000_000,
+				ASSET_A,
+				500 * ONE,
+			),
+			Error::<Test>::DepositCapExceeded
+		);
+		assert_ok!(Stableswap::add_liquidity_shares(
+			RuntimeOrigin::signed(ALICE),
+			POOL_ID,
+			50 * ONE * 1_000_000,
+			ASSET_A,
+			500 * ONE,
+		));
+	});
+}
+
+#[test]
+fn deposit_cap_should_step_up_when_next_step_is_reached() {
+	deposit_caps_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(add_initial_liquidity(BOB, 100 * ONE));
+
+		System::set_block_number(10);
+		Stableswap::on_initialize(10);
+
+		assert_eq!(Stableswap::current_deposit_cap(POOL_ID), Some(1_000 * ONE * 1_000_000));
+		expect_events(vec![Event::DepositCapIncreased {
+			pool_id: POOL_ID,
+			cap: 1_000 * ONE * 1_000_000,
+		}
+		.into()]);
+		assert_ok!(add_initial_liquidity(ALICE, 100 * ONE));
+	});
+}
+
+#[test]
+fn deposit_cap_should_stay_at_last_step_when_schedule_is_finished() {
+	deposit_caps_ext().execute_with(|| {
+		System::set_block_number(100);
+		Stableswap::on_initialize(100);
+
+		assert_eq!(Stableswap::current_deposit_cap(POOL_ID), Some(1_000 * ONE * 1_000_000));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Stableswap(Event::DepositCapIncreased { .. })
+		)));
+	});
+}
+
+#[test]
+fn current_deposit_cap_should_be_none_when_pool_has_no_schedule() {
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				vec![ASSET_A, ASSET_B],
+				100,
+				Permill::from_percent(0),
+			));
+			assert_eq!(Stableswap::current_deposit_cap(POOL_ID), None);
+		});
+}
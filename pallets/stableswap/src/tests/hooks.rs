@@ -1,6 +1,10 @@
 use crate::tests::mock::*;
 use crate::types::{AssetAmount, PoolInfo, PoolState};
+use crate::{D_ITERATIONS, Y_ITERATIONS};
 use frame_support::{assert_ok, BoundedVec};
+use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::fee::TradeInfo;
+use hydradx_traits::router::PoolType;
 use sp_runtime::Permill;
 use std::num::NonZeroU16;
 
@@ -462,3 +466,106 @@ fn buy_should_provide_correct_values_in_the_hook() {
 			)
 		});
 }
+
+fn pool_with_fee() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, 1, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![1, 2].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![AssetAmount::new(1, 100 * ONE), AssetAmount::new(2, 100 * ONE)],
+			},
+		)
+}
+
+#[test]
+fn sell_should_pass_executed_trade_with_fee_in_asset_out_to_handler() {
+	pool_with_fee().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let (expected_out, expected_fee) =
+			hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&[AssetReserve::new(100 * ONE, 12), AssetReserve::new(100 * ONE, 12)],
+				0,
+				1,
+				30 * ONE,
+				100,
+				Permill::from_percent(1),
+			)
+			.unwrap();
+
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 30 * ONE, 0));
+
+		assert_eq!(
+			last_executed_trade(),
+			Some(TradeInfo {
+				pool: PoolType::Stableswap(pool_id),
+				trader: BOB,
+				fee_account: pool_account(pool_id),
+				asset_in: 1,
+				asset_out: 2,
+				amount_in: 30 * ONE,
+				amount_out: expected_out,
+				reserve_in: 100 * ONE,
+				reserve_out: 100 * ONE,
+				fee_asset: 2,
+				fee_amount: expected_fee,
+				protocol_fee: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn buy_should_pass_executed_trade_with_fee_in_asset_in_to_handler() {
+	pool_with_fee().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let (expected_in, expected_fee) =
+			hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&[AssetReserve::new(100 * ONE, 12), AssetReserve::new(100 * ONE, 12)],
+				0,
+				1,
+				30 * ONE,
+				100,
+				Permill::from_percent(1),
+			)
+			.unwrap();
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			2,
+			1,
+			30 * ONE,
+			100 * ONE
+		));
+
+		assert_eq!(
+			last_executed_trade(),
+			Some(TradeInfo {
+				pool: PoolType::Stableswap(pool_id),
+				trader: BOB,
+				fee_account: pool_account(pool_id),
+				asset_in: 1,
+				asset_out: 2,
+				amount_in: expected_in,
+				amount_out: 30 * ONE,
+				reserve_in: 100 * ONE,
+				reserve_out: 100 * ONE,
+				fee_asset: 1,
+				fee_amount: expected_fee,
+				protocol_fee: 0,
+			})
+		);
+	});
+}
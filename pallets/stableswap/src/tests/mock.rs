@@ -33,16 +33,16 @@ use frame_support::traits::{Contains, Everything};
 use frame_support::weights::Weight;
 use frame_support::{assert_ok, BoundedVec};
 use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime, ord_parameter_types, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
 pub use orml_traits::MultiCurrency;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage, DispatchError,
+	BuildStorage, DispatchError, Permill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -56,6 +56,8 @@ pub const DAI: AssetId = 1;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const REBALANCER: AccountId = 3;
+pub const GUARDIAN: AccountId = 4;
 
 pub const ONE: Balance = 1_000_000_000_000;
 
@@ -73,6 +75,10 @@ thread_local! {
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
+	pub static LAST_EXECUTED_TRADE: RefCell<Option<TradeInfo<AccountId, AssetId, Balance>>> = const { RefCell::new(None) };
+	pub static MAX_AMPLIFICATION_CHANGE: RefCell<Option<Permill>> = const { RefCell::new(None) };
+	pub static PARAMETER_CHANGE_DELAY: RefCell<u64> = const { RefCell::new(0) };
+	pub static PEG_ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -142,6 +148,14 @@ parameter_types! {
 	pub const MinimumLiquidity: Balance = 1_000_000;
 	pub const MinimumTradingLimit: Balance = 1000;
 	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub const MaxRebalancePortion: Permill = Permill::from_percent(10);
+	pub MaxAmplificationChangePerBlock: Option<Permill> = MAX_AMPLIFICATION_CHANGE.with(|v| *v.borrow());
+	pub ParameterChangeDelay: u64 = PARAMETER_CHANGE_DELAY.with(|v| *v.borrow());
+}
+
+ord_parameter_types! {
+	pub const Rebalancer: AccountId = REBALANCER;
+	pub const Guardian: AccountId = GUARDIAN;
 }
 
 pub struct Whitelist;
@@ -182,11 +196,18 @@ impl Config for Test {
 	type UpdateTradabilityOrigin = EnsureRoot<AccountId>;
 	type MinPoolLiquidity = MinimumLiquidity;
 	type AmplificationRange = AmplificationRange;
+	type MaxAmplificationChangePerBlock = MaxAmplificationChangePerBlock;
+	type ParameterChangeDelay = ParameterChangeDelay;
+	type GuardianOrigin = EnsureSignedBy<Guardian, AccountId>;
 	type MinTradingLimit = MinimumTradingLimit;
+	type MaxRebalancePortion = MaxRebalancePortion;
+	type RebalanceOrigin = EnsureSignedBy<Rebalancer, AccountId>;
 	type WeightInfo = ();
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type OnTradeExecuted = DummyTradeExecutedHandler;
+	type PegOracle = DummyPegOracle;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
@@ -216,6 +237,18 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		LAST_EXECUTED_TRADE.with(|v| {
+			*v.borrow_mut() = None;
+		});
+		MAX_AMPLIFICATION_CHANGE.with(|v| {
+			*v.borrow_mut() = None;
+		});
+		PARAMETER_CHANGE_DELAY.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		PEG_ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -230,6 +263,21 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_max_amplification_change(self, max_change: Permill) -> Self {
+		MAX_AMPLIFICATION_CHANGE.with(|v| *v.borrow_mut() = Some(max_change));
+		self
+	}
+
+	pub fn with_parameter_change_delay(self, delay: u64) -> Self {
+		PARAMETER_CHANGE_DELAY.with(|v| *v.borrow_mut() = delay);
+		self
+	}
+
+	pub fn with_peg_oracle_price(self, base_asset: AssetId, asset_id: AssetId, price: (Balance, Balance)) -> Self {
+		set_peg_oracle_price(base_asset, asset_id, price);
+		self
+	}
+
 	pub fn with_registered_asset(mut self, name: Vec<u8>, asset: AssetId, decimals: u8) -> Self {
 		self.registered_assets.push((name, asset, decimals));
 		self
@@ -318,7 +366,11 @@ impl ExtBuilder {
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
 use crate::types::{AssetAmount, PoolInfo, PoolState, StableswapHooks};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::router::PoolType;
 use hydradx_traits::{AccountIdFor, Inspect};
 use sp_runtime::traits::Zero;
 
@@ -443,6 +495,54 @@ pub(crate) fn last_trade_hook_state() -> Option<(AssetId, AssetId, AssetId, Pool
 	LAST_TRADE_HOOK.with(|v| v.borrow().clone())
 }
 
+pub struct DummyTradeExecutedHandler;
+
+impl OnTradeExecuted<AccountId, AssetId, Balance> for DummyTradeExecutedHandler {
+	fn on_trade_executed(trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError> {
+		LAST_EXECUTED_TRADE.with(|v| {
+			*v.borrow_mut() = Some(trade.clone());
+		});
+
+		Ok(Balance::zero())
+	}
+
+	fn on_trade_executed_weight(_pool: PoolType<AssetId>) -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn last_executed_trade() -> Option<TradeInfo<AccountId, AssetId, Balance>> {
+	LAST_EXECUTED_TRADE.with(|v| v.borrow().clone())
+}
+
+pub struct DummyPegOracle;
+
+impl AggregatedPriceOracle<AssetId, u64, EmaPrice> for DummyPegOracle {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, u64), Self::Error> {
+		PEG_ORACLE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.map(|(n, d)| (EmaPrice::new(n, d), System::block_number()))
+			.ok_or(())
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn set_peg_oracle_price(base_asset: AssetId, asset_id: AssetId, price: (Balance, Balance)) {
+	PEG_ORACLE_PRICES.with(|v| {
+		v.borrow_mut().insert((base_asset, asset_id), price);
+	});
+}
+
 pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
 	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
 }
@@ -6,10 +6,14 @@ mod add_liquidity;
 mod amplification;
 mod calculate_spot_price;
 mod creation;
+mod deposit_caps;
 mod hooks;
 mod invariants;
 pub(crate) mod mock;
+mod parameter_timelock;
+mod peg;
 mod price;
+mod rebalance;
 mod remove_liquidity;
 mod trades;
 mod update_pool;
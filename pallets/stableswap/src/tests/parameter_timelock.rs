@@ -0,0 +1,234 @@
+use crate::tests::mock::*;
+use crate::types::{ParameterChange, PendingParameterChange, PoolParameter};
+use crate::{Error, Event, Pools};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::Permill;
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const POOL_ID: AssetId = 100;
+const DELAY: u64 = 10;
+
+fn timelock_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_parameter_change_delay(DELAY)
+		.build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			vec![ASSET_A, ASSET_B],
+			100,
+			Permill::from_percent(1),
+		));
+	});
+	ext
+}
+
+#[test]
+fn update_pool_fee_should_announce_change_when_delay_is_set() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(10),
+		));
+
+		assert_eq!(Pools::<Test>::get(POOL_ID).unwrap().fee, Permill::from_percent(1));
+		assert_eq!(
+			Stableswap::pending_parameter_changes(POOL_ID, PoolParameter::Fee),
+			Some(PendingParameterChange {
+				change: ParameterChange::Fee(Permill::from_percent(10)),
+				enactment_block: 1 + DELAY,
+			})
+		);
+		expect_events(vec![Event::ParameterChangeAnnounced {
+			pool_id: POOL_ID,
+			change: ParameterChange::Fee(Permill::from_percent(10)),
+			enactment_block: 1 + DELAY,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn enact_parameter_change_should_update_fee_when_delay_has_passed() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(10),
+		));
+		System::set_block_number(1 + DELAY);
+
+		assert_ok!(Stableswap::enact_parameter_change(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			PoolParameter::Fee
+		));
+
+		assert_eq!(Pools::<Test>::get(POOL_ID).unwrap().fee, Permill::from_percent(10));
+		assert_eq!(Stableswap::pending_parameter_changes(POOL_ID, PoolParameter::Fee), None);
+		expect_events(vec![Event::FeeUpdated {
+			pool_id: POOL_ID,
+			fee: Permill::from_percent(10),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn enact_parameter_change_should_fail_when_delay_has_not_passed() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(10),
+		));
+		System::set_block_number(DELAY);
+
+		assert_noop!(
+			Stableswap::enact_parameter_change(RuntimeOrigin::signed(BOB), POOL_ID, PoolParameter::Fee),
+			Error::<Test>::ParameterChangeNotDue
+		);
+	});
+}
+
+#[test]
+fn enact_parameter_change_should_fail_when_change_is_not_announced() {
+	timelock_ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::enact_parameter_change(RuntimeOrigin::signed(BOB), POOL_ID, PoolParameter::Amplification),
+			Error::<Test>::ParameterChangeNotFound
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_replace_previously_announced_change() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(10),
+		));
+		System::set_block_number(5);
+
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(2),
+		));
+
+		assert_eq!(
+			Stableswap::pending_parameter_changes(POOL_ID, PoolParameter::Fee),
+			Some(PendingParameterChange {
+				change: ParameterChange::Fee(Permill::from_percent(2)),
+				enactment_block: 5 + DELAY,
+			})
+		);
+	});
+}
+
+#[test]
+fn enact_parameter_change_should_start_amplification_change_at_enactment_when_start_block_has_passed() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			1000,
+			5,
+			1000,
+		));
+		assert_eq!(
+			Pools::<Test>::get(POOL_ID).unwrap().final_amplification,
+			NonZeroU16::new(100).unwrap()
+		);
+		System::set_block_number(20);
+
+		assert_ok!(Stableswap::enact_parameter_change(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			PoolParameter::Amplification
+		));
+
+		let pool = Pools::<Test>::get(POOL_ID).unwrap();
+		assert_eq!(pool.initial_amplification, NonZeroU16::new(100).unwrap());
+		assert_eq!(pool.final_amplification, NonZeroU16::new(1000).unwrap());
+		assert_eq!(pool.initial_block, 20);
+		assert_eq!(pool.final_block, 1000);
+	});
+}
+
+#[test]
+fn update_amplification_should_fail_when_amplification_is_out_of_range() {
+	timelock_ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::update_amplification(RuntimeOrigin::root(), POOL_ID, 20_000, 5, 1000),
+			Error::<Test>::InvalidAmplification
+		);
+	});
+}
+
+#[test]
+fn veto_parameter_change_should_remove_announced_change() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(10),
+		));
+
+		assert_ok!(Stableswap::veto_parameter_change(
+			RuntimeOrigin::signed(GUARDIAN),
+			POOL_ID,
+			PoolParameter::Fee
+		));
+
+		assert_eq!(Stableswap::pending_parameter_changes(POOL_ID, PoolParameter::Fee), None);
+		expect_events(vec![Event::ParameterChangeVetoed {
+			pool_id: POOL_ID,
+			parameter: PoolParameter::Fee,
+		}
+		.into()]);
+
+		System::set_block_number(1 + DELAY);
+		assert_noop!(
+			Stableswap::enact_parameter_change(RuntimeOrigin::signed(BOB), POOL_ID, PoolParameter::Fee),
+			Error::<Test>::ParameterChangeNotFound
+		);
+		assert_eq!(Pools::<Test>::get(POOL_ID).unwrap().fee, Permill::from_percent(1));
+	});
+}
+
+#[test]
+fn veto_parameter_change_should_fail_when_origin_is_not_guardian() {
+	timelock_ext().execute_with(|| {
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(10),
+		));
+
+		assert_noop!(
+			Stableswap::veto_parameter_change(RuntimeOrigin::signed(BOB), POOL_ID, PoolParameter::Fee),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn veto_parameter_change_should_fail_when_change_is_not_announced() {
+	timelock_ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::veto_parameter_change(RuntimeOrigin::signed(GUARDIAN), POOL_ID, PoolParameter::Fee),
+			Error::<Test>::ParameterChangeNotFound
+		);
+	});
+}
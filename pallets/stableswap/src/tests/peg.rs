@@ -0,0 +1,368 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PegSource, PoolInfo};
+use crate::{assert_balance, Error, Event, PoolPegs, D_ITERATIONS, MAX_ASSETS_IN_POOL, Y_ITERATIONS};
+use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::oracle::OraclePeriod;
+use std::num::NonZeroU16;
+
+use frame_support::traits::ConstU32;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+const ORACLE_SOURCE: [u8; 8] = *b"omnipool";
+
+fn pool_with_liquidity(reserve_a: Balance, reserve_b: Balance) -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 100 * ONE),
+			(BOB, ASSET_B, 100 * ONE),
+			(ALICE, ASSET_A, reserve_a),
+			(ALICE, ASSET_B, reserve_b),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::zero(),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, reserve_a),
+					AssetAmount::new(ASSET_B, reserve_b),
+				],
+			},
+		)
+}
+
+fn pegs(pegs: Vec<PegSource<AssetId>>) -> BoundedVec<PegSource<AssetId>, ConstU32<MAX_ASSETS_IN_POOL>> {
+	BoundedVec::truncate_from(pegs)
+}
+
+#[test]
+fn set_pool_pegs_should_work_when_pegs_are_valid() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let pool_pegs = pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))]);
+
+		assert_ok!(Stableswap::set_pool_pegs(
+			RuntimeOrigin::root(),
+			pool_id,
+			pool_pegs.clone()
+		));
+
+		assert_eq!(PoolPegs::<Test>::get(pool_id), Some(pool_pegs.clone()));
+		expect_events(vec![Event::PoolPegsUpdated {
+			pool_id,
+			pegs: pool_pegs.into_inner(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_pool_pegs_should_remove_pegs_when_empty_list_is_provided() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_pegs(
+			RuntimeOrigin::root(),
+			pool_id,
+			pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+		));
+
+		assert_ok!(Stableswap::set_pool_pegs(RuntimeOrigin::root(), pool_id, pegs(vec![])));
+
+		assert!(PoolPegs::<Test>::get(pool_id).is_none());
+		expect_events(vec![Event::PoolPegsUpdated { pool_id, pegs: vec![] }.into()]);
+	});
+}
+
+#[test]
+fn set_pool_pegs_should_fail_when_origin_is_not_authority() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_pegs(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_pool_pegs_should_fail_when_number_of_pegs_does_not_match_pool_assets() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_pegs(RuntimeOrigin::root(), pool_id, pegs(vec![PegSource::Value((2, 1))])),
+			Error::<Test>::IncorrectPegs
+		);
+	});
+}
+
+#[test]
+fn set_pool_pegs_should_fail_when_peg_value_is_zero() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				pegs(vec![PegSource::Value((1, 1)), PegSource::Value((0, 1))])
+			),
+			Error::<Test>::IncorrectPegs
+		);
+	});
+}
+
+#[test]
+fn set_pool_pegs_should_fail_when_oracle_price_is_not_available() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				pegs(vec![
+					PegSource::Value((1, 1)),
+					PegSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A))
+				])
+			),
+			Error::<Test>::PegOracleNotAvailable
+		);
+	});
+}
+
+#[test]
+fn set_pool_pegs_should_fail_when_pool_does_not_exist() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		assert_noop!(
+			Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				1_000,
+				pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+			),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn sell_should_apply_pegs_when_pool_is_pegged() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_pegs(
+			RuntimeOrigin::root(),
+			pool_id,
+			pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+		));
+		let (pegged_out, _) = hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+			&[AssetReserve::new(200 * ONE, 12), AssetReserve::new(200 * ONE, 12)],
+			0,
+			1,
+			10 * ONE,
+			100,
+			Permill::zero(),
+		)
+		.unwrap();
+		let expected = pegged_out / 2;
+
+		// Act
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0,
+		));
+
+		// Assert
+		assert!(expected > 4_990_000_000_000 && expected < 5 * ONE);
+		assert_balance!(BOB, ASSET_A, 90 * ONE);
+		assert_balance!(BOB, ASSET_B, 100 * ONE + expected);
+	});
+}
+
+#[test]
+fn buy_should_apply_pegs_when_pool_is_pegged() {
+	pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_pegs(
+			RuntimeOrigin::root(),
+			pool_id,
+			pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+		));
+		let (pegged_in, _) = hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+			&[AssetReserve::new(200 * ONE, 12), AssetReserve::new(200 * ONE, 12)],
+			1,
+			0,
+			10 * ONE,
+			100,
+			Permill::zero(),
+		)
+		.unwrap();
+		let expected = pegged_in.div_ceil(2);
+
+		// Act
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			10 * ONE,
+		));
+
+		// Assert
+		assert!(expected > 5 * ONE && expected < 5_010_000_000_000);
+		assert_balance!(BOB, ASSET_A, 110 * ONE);
+		assert_balance!(BOB, ASSET_B, 100 * ONE - expected);
+	});
+}
+
+#[test]
+fn sell_should_use_oracle_peg_when_peg_source_is_oracle() {
+	pool_with_liquidity(200 * ONE, 100 * ONE)
+		.with_peg_oracle_price(ASSET_A, ASSET_B, (2, 1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let pool_id = get_pool_id_at(0);
+			assert_ok!(Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				pegs(vec![
+					PegSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A)),
+					PegSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A))
+				])
+			));
+			let (pegged_out, _) =
+				hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+					&[AssetReserve::new(200 * ONE, 12), AssetReserve::new(200 * ONE, 12)],
+					1,
+					0,
+					20 * ONE,
+					100,
+					Permill::zero(),
+				)
+				.unwrap();
+
+			// Act
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				ASSET_B,
+				ASSET_A,
+				10 * ONE,
+				0,
+			));
+
+			// Assert
+			assert_balance!(BOB, ASSET_B, 90 * ONE);
+			assert_balance!(BOB, ASSET_A, 100 * ONE + pegged_out);
+		});
+}
+
+#[test]
+fn sell_should_fail_when_oracle_peg_becomes_unavailable() {
+	pool_with_liquidity(200 * ONE, 100 * ONE)
+		.with_peg_oracle_price(ASSET_A, ASSET_B, (2, 1))
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			assert_ok!(Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				pegs(vec![
+					PegSource::Value((1, 1)),
+					PegSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A))
+				])
+			));
+			set_peg_oracle_price(ASSET_A, ASSET_B, (0, 1));
+
+			assert_noop!(
+				Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, ASSET_A, ASSET_B, 10 * ONE, 0),
+				Error::<Test>::PegOracleNotAvailable
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_value_assets_at_their_pegs() {
+	let shares_for = |asset_id: AssetId, amount: Balance| -> Balance {
+		let mut shares = 0;
+		pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			assert_ok!(Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+			));
+
+			assert_ok!(Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				BoundedVec::truncate_from(vec![AssetAmount::new(asset_id, amount)])
+			));
+			shares = Tokens::free_balance(pool_id, &BOB);
+		});
+		shares
+	};
+
+	let shares_for_a = shares_for(ASSET_A, 20 * ONE);
+	let shares_for_b = shares_for(ASSET_B, 10 * ONE);
+
+	assert_eq!(shares_for_a, shares_for_b);
+}
+
+#[test]
+fn remove_liquidity_one_asset_should_apply_pegs_when_pool_is_pegged() {
+	let received_for = |asset_id: AssetId| -> Balance {
+		let mut received = 0;
+		pool_with_liquidity(200 * ONE, 100 * ONE).build().execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			assert_ok!(Stableswap::set_pool_pegs(
+				RuntimeOrigin::root(),
+				pool_id,
+				pegs(vec![PegSource::Value((1, 1)), PegSource::Value((2, 1))])
+			));
+			let shares = Tokens::free_balance(pool_id, &ALICE) / 10;
+			let initial = Tokens::free_balance(asset_id, &ALICE);
+
+			assert_ok!(Stableswap::remove_liquidity_one_asset(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				asset_id,
+				shares,
+				0
+			));
+			received = Tokens::free_balance(asset_id, &ALICE) - initial;
+		});
+		received
+	};
+
+	let received_a = received_for(ASSET_A);
+	let received_b = received_for(ASSET_B);
+
+	assert!(received_b <= received_a / 2);
+	assert!(received_a / 2 - received_b <= 1);
+}
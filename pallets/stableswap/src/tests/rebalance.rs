@@ -0,0 +1,166 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo};
+use crate::{assert_balance, Error, Event, D_ITERATIONS, Y_ITERATIONS};
+use hydra_dx_math::stableswap::types::AssetReserve;
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+fn imbalanced_pool(reserve_a: Balance, reserve_b: Balance) -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(REBALANCER, ASSET_A, 100 * ONE),
+			(REBALANCER, ASSET_B, 100 * ONE),
+			(BOB, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_A, reserve_a),
+			(ALICE, ASSET_B, reserve_b),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, reserve_a),
+					AssetAmount::new(ASSET_B, reserve_b),
+				],
+			},
+		)
+}
+
+#[test]
+fn rebalance_should_work_without_fee_when_selling_scarce_asset() {
+	imbalanced_pool(100 * ONE, 150 * ONE).build().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+		let (expected, _) = hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+			&[AssetReserve::new(100 * ONE, 12), AssetReserve::new(150 * ONE, 12)],
+			0,
+			1,
+			5 * ONE,
+			100,
+			Permill::zero(),
+		)
+		.unwrap();
+
+		// Act
+		assert_ok!(Stableswap::rebalance(
+			RuntimeOrigin::signed(REBALANCER),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			5 * ONE,
+			5 * ONE,
+		));
+
+		// Assert
+		assert_balance!(REBALANCER, ASSET_A, 95 * ONE);
+		assert_balance!(REBALANCER, ASSET_B, 100 * ONE + expected);
+		assert_balance!(pool_account, ASSET_A, 105 * ONE);
+		assert_balance!(pool_account, ASSET_B, 150 * ONE - expected);
+		expect_events(vec![Event::RebalanceExecuted {
+			who: REBALANCER,
+			pool_id,
+			asset_in: ASSET_A,
+			asset_out: ASSET_B,
+			amount_in: 5 * ONE,
+			amount_out: expected,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn rebalance_should_fail_when_origin_is_not_rebalancer() {
+	imbalanced_pool(100 * ONE, 150 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::rebalance(RuntimeOrigin::signed(BOB), pool_id, ASSET_A, ASSET_B, 5 * ONE, 0),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rebalance_should_fail_when_amount_exceeds_max_portion_of_reserve() {
+	imbalanced_pool(100 * ONE, 150 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::rebalance(
+				RuntimeOrigin::signed(REBALANCER),
+				pool_id,
+				ASSET_A,
+				ASSET_B,
+				10 * ONE + 1,
+				0
+			),
+			Error::<Test>::MaxRebalanceAmountExceeded
+		);
+	});
+}
+
+#[test]
+fn rebalance_should_fail_when_selling_abundant_asset() {
+	imbalanced_pool(100 * ONE, 150 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::rebalance(RuntimeOrigin::signed(REBALANCER), pool_id, ASSET_B, ASSET_A, 5 * ONE, 0),
+			Error::<Test>::InvalidRebalance
+		);
+	});
+}
+
+#[test]
+fn rebalance_should_fail_when_trade_overshoots_balance() {
+	imbalanced_pool(100 * ONE, 110 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::rebalance(
+				RuntimeOrigin::signed(REBALANCER),
+				pool_id,
+				ASSET_A,
+				ASSET_B,
+				10 * ONE,
+				0
+			),
+			Error::<Test>::InvalidRebalance
+		);
+	});
+}
+
+#[test]
+fn rebalance_should_fail_when_min_buy_amount_is_not_reached() {
+	imbalanced_pool(100 * ONE, 150 * ONE).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::rebalance(
+				RuntimeOrigin::signed(REBALANCER),
+				pool_id,
+				ASSET_A,
+				ASSET_B,
+				5 * ONE,
+				6 * ONE
+			),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
@@ -2,10 +2,12 @@ use crate::types::AssetAmount;
 use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
 use frame_support::BoundedVec;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydra_dx_math::stableswap::Rounding;
 use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use orml_traits::MultiCurrency;
 use sp_core::Get;
-use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
+use sp_runtime::traits::{CheckedDiv, CheckedMul};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
 use sp_std::vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T>
@@ -36,13 +38,15 @@ where
 					let share_issuance = T::Currency::total_issuance(pool_id);
 
 					let amplification = Self::get_amplification(&pool);
+					let pegs = Self::get_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let balances = Self::peg_reserves(&balances, &pegs).map_err(ExecutorError::Error)?;
 					let (amount, _) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<
 						D_ITERATIONS,
 						Y_ITERATIONS,
 					>(&balances, amount_in, asset_idx, share_issuance, amplification, pool.fee)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
-					Ok(amount)
+					Self::unpeg_amount(amount, pegs[asset_idx], Rounding::Down).map_err(ExecutorError::Error)
 				} else if asset_out == pool_id {
 					let share_amount = Self::calculate_shares(
 						pool_id,
@@ -86,6 +90,8 @@ where
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
+					let pegs = Self::get_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let balances = Self::peg_reserves(&balances, &pegs).map_err(ExecutorError::Error)?;
 
 					let liqudity = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
 						&balances,
@@ -97,7 +103,7 @@ where
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
-					Ok(liqudity.0)
+					Self::unpeg_amount(liqudity.0, pegs[asset_idx], Rounding::Up).map_err(ExecutorError::Error)
 				} else if asset_in == pool_id {
 					let pool = Pools::<T>::get(pool_id)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
@@ -113,6 +119,10 @@ where
 
 					let pool = Pools::<T>::get(pool_id)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+					let pegs = Self::get_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+					let balances = Self::peg_reserves(&balances, &pegs).map_err(ExecutorError::Error)?;
+					let amount_out =
+						Self::peg_amount(amount_out, pegs[asset_idx], Rounding::Up).map_err(ExecutorError::Error)?;
 
 					let shares_amount = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
 						&balances,
@@ -218,6 +228,8 @@ where
 				let balances = pool
 					.reserves_with_decimals::<T>(&pool_account)
 					.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+				let pegs = Self::get_pegs(pool_id, &pool).map_err(ExecutorError::Error)?;
+				let balances = Self::peg_reserves(&balances, &pegs).map_err(ExecutorError::Error)?;
 
 				let assets_with_reserves = pool
 					.assets
@@ -241,9 +253,37 @@ where
 				)
 				.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
+				// Spot price is calculated in units of pool's peg base - convert it back to units of the assets.
+				let peg_of = |asset_id: T::AssetId| -> Option<FixedU128> {
+					match pool.find_asset(asset_id) {
+						Some(idx) => FixedU128::checked_from_rational(pegs[idx].0, pegs[idx].1),
+						None => Some(FixedU128::one()),
+					}
+				};
+				let spot_price = peg_of(asset_b)
+					.and_then(|peg_b| spot_price.checked_mul(&peg_b))
+					.zip(peg_of(asset_a))
+					.and_then(|(price, peg_a)| price.checked_div(&peg_a))
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+
 				Ok(spot_price)
 			}
 			_ => Err(ExecutorError::NotSupported),
 		}
 	}
+
+	fn calculate_fee(
+		pool_type: PoolType<T::AssetId>,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+	) -> Result<Permill, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Stableswap(pool_id) => {
+				let pool =
+					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+				Ok(pool.fee)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
 }
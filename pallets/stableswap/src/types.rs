@@ -13,7 +13,8 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
-use hydra_dx_math::stableswap::types::AssetReserve;
+use hydra_dx_math::stableswap::types::{AssetReserve, PegType};
+use hydradx_traits::oracle::{OraclePeriod, Source};
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
@@ -74,6 +75,73 @@ where
 	}
 }
 
+/// Source of a peg of pool asset.
+///
+/// Peg is the value of one unit of the asset expressed in units of pool's peg base.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PegSource<AssetId> {
+	/// Fixed peg value.
+	Value(PegType),
+	/// Oracle price of the asset denominated in given base asset.
+	Oracle((Source, OraclePeriod, AssetId)),
+}
+
+impl<AssetId> PegSource<AssetId> {
+	pub(crate) fn is_valid(&self) -> bool {
+		match self {
+			PegSource::Value((n, d)) => *n != 0 && *d != 0,
+			PegSource::Oracle(_) => true,
+		}
+	}
+}
+
+/// Pool parameter which changes only after `ParameterChangeDelay` from its announcement.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PoolParameter {
+	Fee,
+	Amplification,
+}
+
+/// Change of a pool parameter.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterChange<BlockNumber> {
+	/// New trade fee of the pool.
+	Fee(Permill),
+	/// Amplification changing linearly to `final_amplification` between `start_block` and `end_block`.
+	Amplification {
+		final_amplification: NonZeroU16,
+		start_block: BlockNumber,
+		end_block: BlockNumber,
+	},
+}
+
+impl<BlockNumber> ParameterChange<BlockNumber> {
+	pub fn parameter(&self) -> PoolParameter {
+		match self {
+			ParameterChange::Fee(_) => PoolParameter::Fee,
+			ParameterChange::Amplification { .. } => PoolParameter::Amplification,
+		}
+	}
+}
+
+/// Announced change of a pool parameter.
+/// `change`: the change
+/// `enactment_block`: block from which the change can be enacted
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingParameterChange<BlockNumber> {
+	pub change: ParameterChange<BlockNumber>,
+	pub enactment_block: BlockNumber,
+}
+
+/// Step of the deposit caps schedule of a pool.
+/// `block`: block from which the cap applies
+/// `cap`: maximum share issuance of the pool
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DepositCap<BlockNumber> {
+	pub block: BlockNumber,
+	pub cap: Balance,
+}
+
 #[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Default)]
 pub struct AssetAmount<AssetId> {
 	pub asset_id: AssetId,
@@ -17,7 +17,7 @@
 
 use super::*;
 
-use crate::types::{Conviction, Vote};
+use crate::types::{Conviction, SlashKind, Vote};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
 use frame_system::{Pallet as System, RawOrigin};
@@ -198,5 +198,106 @@ benchmarks! {
 		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1))
 	}
 
+	set_reward_vesting {
+		let vesting = RewardVesting { cliff: 100, period: 10, period_count: 10 };
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, Some(vesting))
+	verify {
+		assert_eq!(Pallet::<T>::reward_vesting(), Some(vesting));
+	}
+
+	set_reward_curve {
+		let curve = RewardCurve { a: FixedU128::from_rational(5, 100), b: 10_000 };
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, curve)
+	verify {
+		assert_eq!(Pallet::<T>::reward_curve(), curve);
+	}
+
+	set_position_transfers {
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, true)
+	verify {
+		assert!(Pallet::<T>::position_transfers_enabled());
+	}
+
+	transfer_position {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let dest: T::AccountId = account("dest", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &dest, (1_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+		PositionTransfersEnabled::<T>::put(true);
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), position_id, dest.clone())
+	verify {
+		assert_eq!(Pallet::<T>::get_user_position_id(&dest).unwrap(), Some(position_id));
+		assert_eq!(Pallet::<T>::get_user_position_id(&caller).unwrap(), None);
+	}
+
+	slash_position {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+
+		let successful_origin = T::SlashOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, caller, SlashKind::Stake, Perbill::from_percent(10))
+	verify {
+		assert!(Pallet::<T>::pending_slashes(position_id).is_some());
+	}
+
+	cancel_slash {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+		Pallet::<T>::schedule_slash(&caller, SlashKind::Stake, Perbill::from_percent(10))?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, position_id)
+	verify {
+		assert!(Pallet::<T>::pending_slashes(position_id).is_none());
+	}
+
+	execute_slash {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+		Pallet::<T>::schedule_slash(&caller_1, SlashKind::Stake, Perbill::from_percent(10))?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		System::<T>::set_block_number(System::<T>::block_number() + T::SlashAppealPeriod::get());
+	}: _(RawOrigin::Signed(caller_1.clone()), position_id)
+	verify {
+		assert_eq!(Pallet::<T>::positions(position_id).unwrap().stake, 45_000 * UNIT);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
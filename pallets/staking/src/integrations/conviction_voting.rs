@@ -116,7 +116,7 @@ where
 					// Add points only if referendum is finished
 					if let Some(is_ongoing) = ongoing {
 						if !is_ongoing {
-							position.action_points = position.action_points.saturating_add(points);
+							Pallet::<T>::add_action_points(position_id, position, points)?;
 						}
 					}
 					PositionVotes::<T>::mutate(position_id, |voting| {
@@ -17,20 +17,23 @@
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::traits::{ActionData, GetReferendumState, PayablePercentage, VestingDetails};
-use crate::types::{Action, Balance, Period, Point, Position, StakingData, Voting};
+use crate::traits::{ActionData, GetReferendumState, SlashStakingPosition, VestingDetails};
+use crate::types::{
+	Action, Balance, PendingSlash, Period, Point, Position, RewardCurve, SlashKind, StakingData, Voting,
+};
 use frame_support::ensure;
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	pallet_prelude::*,
-	traits::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate},
+	traits::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
 	traits::{DefensiveOption, LockIdentifier},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::staking as math;
+use hydradx_traits::vesting::{RewardVesting, VestedTransfer};
 use orml_traits::{GetByKey, MultiCurrency, MultiLockableCurrency};
 use sp_core::Get;
-use sp_runtime::traits::{AccountIdConversion, CheckedAdd, One};
+use sp_runtime::traits::{AccountIdConversion, CheckedAdd, One, Saturating};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Zero},
 	Perbill, Permill, SaturatedConversion,
@@ -69,7 +72,7 @@ pub mod pallet {
 	use sp_runtime::traits::AtLeast32BitUnsigned;
 
 	/// Current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -128,6 +131,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type UnclaimablePeriods: Get<Period>;
 
+		/// Number of periods without new action points after which all previously earned action
+		/// points are lost. Points decay linearly during this time. `0` disables the decay.
+		#[pallet::constant]
+		type ActionPointsDecayPeriods: Get<Period>;
+
 		/// Weight of the actual stake in slash points calculation. Bigger the value lower the calculated slash points.
 		#[pallet::constant]
 		type CurrentStakeWeight: Get<u8>;
@@ -140,10 +148,6 @@ pub mod pallet {
 		#[pallet::constant]
 		type NFTCollectionId: Get<Self::CollectionId>;
 
-		/// Function returning percentage of rewards to pay based on the number of points user
-		/// accumulated.
-		type PayablePercentage: PayablePercentage<Point>;
-
 		/// Block number provider.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
@@ -160,7 +164,8 @@ pub mod pallet {
 		type NFTHandler: Mutate<Self::AccountId>
 			+ Create<Self::AccountId>
 			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>
-			+ InspectEnumerable<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+			+ InspectEnumerable<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>
+			+ Transfer<Self::AccountId>;
 
 		/// Minimum amount of points to slash based on payable percentage.
 		type MinSlash: GetByKey<FixedU128, Point>;
@@ -176,10 +181,23 @@ pub mod pallet {
 		/// Provides information about amount of vested tokens.
 		type Vesting: VestingDetails<Self::AccountId, Balance>;
 
+		/// Support for paying rewards as a vesting schedule.
+		type VestedTransfer: VestedTransfer<Self::AccountId, Balance>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// Max mumber of locks per account.  It's used in on_vote_worst_case benchmarks.
 		type MaxLocks: Get<u32>;
 
+		/// Origin able to schedule slashes of staking positions.
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of blocks during which a scheduled slash can be cancelled by `AuthorityOrigin`.
+		#[pallet::constant]
+		type SlashAppealPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Account receiving slashed stake, e.g. treasury.
+		type SlashedFundsReceiver: Get<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -219,6 +237,42 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Period in which position received action points for the last time. Used to decay action
+	/// points of inactive positions.
+	#[pallet::getter(fn last_action_period)]
+	pub(super) type LastActionPeriod<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PositionItemId, Period, OptionQuery>;
+
+	#[pallet::storage]
+	/// Vesting schedule paid rewards are locked in. Rewards are transferred instantly if not set.
+	#[pallet::getter(fn reward_vesting)]
+	pub(super) type RewardVestingSchedule<T: Config> = StorageValue<_, RewardVesting, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultRewardCurve() -> RewardCurve {
+		RewardCurve {
+			a: FixedU128::from_rational(2, 100),
+			b: 2_000,
+		}
+	}
+
+	#[pallet::storage]
+	/// Parameters of the curve determining percentage of rewards paid based on position's points.
+	#[pallet::getter(fn reward_curve)]
+	pub(super) type RewardCurveParams<T: Config> = StorageValue<_, RewardCurve, ValueQuery, DefaultRewardCurve>;
+
+	#[pallet::storage]
+	/// Staking positions can be transferred to other accounts if set.
+	#[pallet::getter(fn position_transfers_enabled)]
+	pub(super) type PositionTransfersEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Slashes of staking positions waiting for the end of the appeal period.
+	#[pallet::getter(fn pending_slashes)]
+	pub(super) type PendingSlashes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PositionItemId, PendingSlash<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -266,6 +320,46 @@ pub mod pallet {
 			accumulated_rps: FixedU128,
 			total_stake: Balance,
 		},
+
+		/// Vesting schedule of paid rewards was updated.
+		RewardVestingUpdated { vesting: Option<RewardVesting> },
+
+		/// Reward curve parameters were updated.
+		RewardCurveUpdated { curve: RewardCurve },
+
+		/// Transfers of staking positions were enabled or disabled.
+		PositionTransfersUpdated { enabled: bool },
+
+		/// Staking position and its locked stake were transferred to another account.
+		PositionTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			position_id: T::PositionItemId,
+			locked: Balance,
+		},
+
+		/// Slash of staking position was scheduled.
+		SlashScheduled {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			kind: SlashKind,
+			percentage: Perbill,
+			executable_at: BlockNumberFor<T>,
+		},
+
+		/// Pending slash of staking position was cancelled.
+		SlashCancelled {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+		},
+
+		/// Staking position was slashed. `amount` is amount of slashed action points or stake.
+		PositionSlashed {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			kind: SlashKind,
+			amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -306,6 +400,27 @@ pub mod pallet {
 		/// Position contains processed votes. Removed these votes first before increasing stake or claiming.
 		ExistingProcessedVotes,
 
+		/// Vesting schedule must have non-zero period and period count.
+		InvalidRewardVesting,
+
+		/// Reward curve parameters are out of allowed bounds.
+		InvalidRewardCurve,
+
+		/// Transfers of staking positions are not enabled.
+		PositionTransfersDisabled,
+
+		/// Staking position has pending slash.
+		SlashPending,
+
+		/// Pending slash of staking position has not been found.
+		SlashNotFound,
+
+		/// Appeal period of the slash is not over yet.
+		SlashAppealPeriodNotOver,
+
+		/// Slash percentage must be non-zero.
+		InvalidSlashPercentage,
+
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
@@ -470,6 +585,7 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
 					let (rewards, unpaid_rewards, payable_percentage) = Self::calculate_rewards(
+						position_id,
 						position,
 						staking.accumulated_reward_per_stake,
 						current_period,
@@ -490,8 +606,8 @@ pub mod pallet {
 					position.accumulated_unpaid_rewards = unpaid_rewards;
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
-					let points =
-						Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
+					let points = Self::get_points(position_id, position, current_period, created_at)
+						.ok_or(Error::<T>::Arithmetic)?;
 					let slash_points = math::calculate_slashed_points(
 						points,
 						position.stake,
@@ -552,7 +668,7 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` event when successful.
 		///
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		#[pallet::weight(<T as Config>::WeightInfo::claim().saturating_add(T::VestedTransfer::vested_transfer_weight()))]
 		pub fn claim(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -582,6 +698,7 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
 					let (rewards_to_pay, accumulated_unpaid_rewards, payable_percentage) = Self::calculate_rewards(
+						position_id,
 						position,
 						staking.accumulated_reward_per_stake,
 						current_period,
@@ -590,8 +707,7 @@ pub mod pallet {
 					.ok_or(Error::<T>::Arithmetic)?;
 
 					if !rewards_to_pay.is_zero() {
-						let pot = Self::pot_account_id();
-						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards_to_pay)?;
+						Self::pay_rewards(&who, rewards_to_pay)?;
 					}
 
 					let rewards_to_unlock = position.accumulated_locked_rewards;
@@ -599,8 +715,8 @@ pub mod pallet {
 
 					position.accumulated_unpaid_rewards = accumulated_unpaid_rewards;
 
-					let points_to_slash =
-						Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
+					let points_to_slash = Self::get_points(position_id, position, current_period, created_at)
+						.ok_or(Error::<T>::Arithmetic)?;
 					position.accumulated_slash_points = position
 						.accumulated_slash_points
 						.checked_add(points_to_slash)
@@ -657,7 +773,7 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` and `Unstaked` events when successful.
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::unstake())]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake().saturating_add(T::VestedTransfer::vested_transfer_weight()))]
 		pub fn unstake(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -673,6 +789,11 @@ pub mod pallet {
 						.as_mut()
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
 
+					ensure!(
+						!PendingSlashes::<T>::contains_key(position_id),
+						Error::<T>::SlashPending
+					);
+
 					let voting = PositionVotes::<T>::get(position_id);
 
 					use frame_support::StorageDoubleMap;
@@ -687,6 +808,7 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
 					let (rewards_to_pay, return_to_pot, payable_percentage) = Self::calculate_rewards(
+						position_id,
 						position,
 						staking.accumulated_reward_per_stake,
 						current_period,
@@ -695,8 +817,7 @@ pub mod pallet {
 					.ok_or(Error::<T>::Arithmetic)?;
 
 					if !rewards_to_pay.is_zero() {
-						let pot = Self::pot_account_id();
-						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards_to_pay)?;
+						Self::pay_rewards(&who, rewards_to_pay)?;
 					}
 
 					staking.total_stake = staking
@@ -719,7 +840,7 @@ pub mod pallet {
 						position_id,
 						paid_rewards: rewards_to_pay,
 						unlocked_rewards: position.accumulated_locked_rewards,
-						slashed_points: Self::get_points(position, current_period, created_at)
+						slashed_points: Self::get_points(position_id, position, current_period, created_at)
 							.ok_or(Error::<T>::Arithmetic)?,
 						slashed_unpaid_rewards: return_to_pot,
 						payable_percentage,
@@ -732,12 +853,239 @@ pub mod pallet {
 					});
 
 					PositionVotes::<T>::remove(position_id);
+					LastActionPeriod::<T>::remove(position_id);
 					*maybe_position = None;
 
 					Ok(())
 				})
 			})
 		}
+
+		/// Set vesting schedule rewards paid by `claim` and `unstake` are locked in.
+		///
+		/// Parameters:
+		/// - `origin`: must be `AuthorityOrigin`
+		/// - `vesting`: cliff, period and number of periods of the schedule. `None` pays rewards instantly.
+		///
+		/// Emits `RewardVestingUpdated` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_vesting())]
+		pub fn set_reward_vesting(origin: OriginFor<T>, vesting: Option<RewardVesting>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if let Some(v) = vesting {
+				ensure!(v.is_valid(), Error::<T>::InvalidRewardVesting);
+			}
+
+			RewardVestingSchedule::<T>::set(vesting);
+
+			Self::deposit_event(Event::RewardVestingUpdated { vesting });
+
+			Ok(())
+		}
+
+		/// Set parameters of the curve determining percentage of rewards paid based on position's points.
+		///
+		/// Parameters:
+		/// - `origin`: must be `AuthorityOrigin`
+		/// - `curve`: `a` and `b` parameters of the sigmoid curve. `a` must be from range (0, 1] and
+		/// `b` from range [1, `RewardCurve::MAX_B`].
+		///
+		/// Emits `RewardCurveUpdated` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_curve())]
+		pub fn set_reward_curve(origin: OriginFor<T>, curve: RewardCurve) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(curve.is_valid(), Error::<T>::InvalidRewardCurve);
+
+			RewardCurveParams::<T>::put(curve);
+
+			Self::deposit_event(Event::RewardCurveUpdated { curve });
+
+			Ok(())
+		}
+
+		/// Enable or disable transfers of staking positions by `transfer_position`.
+		///
+		/// Parameters:
+		/// - `origin`: must be `AuthorityOrigin`
+		/// - `enabled`: positions are transferable if `true`
+		///
+		/// Emits `PositionTransfersUpdated` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_position_transfers())]
+		pub fn set_position_transfers(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			PositionTransfersEnabled::<T>::put(enabled);
+
+			Self::deposit_event(Event::PositionTransfersUpdated { enabled });
+
+			Ok(())
+		}
+
+		/// Transfer staking position to another account.
+		///
+		/// NFT representing the position is transferred together with the locked stake and locked
+		/// rewards. Points, including slashed points, are kept by the position so `dest` continues
+		/// where the previous owner left off.
+		///
+		/// Position with registered or processed votes can't be transferred. `dest` must not own
+		/// any staking position.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the staking position
+		/// - `position_id`: id of the staking position
+		/// - `dest`: account receiving the position
+		///
+		/// Emits `PositionTransferred` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_position())]
+		pub fn transfer_position(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+			ensure!(
+				PositionTransfersEnabled::<T>::get(),
+				Error::<T>::PositionTransfersDisabled
+			);
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+			ensure!(
+				Self::get_user_position_id(&dest)?.is_none(),
+				Error::<T>::PositionAlreadyExists
+			);
+			ensure!(
+				!PendingSlashes::<T>::contains_key(position_id),
+				Error::<T>::SlashPending
+			);
+
+			use frame_support::StorageDoubleMap;
+			ensure!(
+				PositionVotes::<T>::get(position_id).votes.is_empty() && !ProcessedVotes::<T>::contains_prefix(&who),
+				Error::<T>::ExistingVotes
+			);
+
+			let position = Positions::<T>::get(position_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+			let locked = position.get_total_locked()?;
+
+			T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who)?;
+			T::Currency::transfer(T::NativeAssetId::get(), &who, &dest, locked)?;
+			Self::ensure_stakeable_balance(&dest, locked, None)?;
+			T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &dest, locked)?;
+
+			let pallet_account = Self::pot_account_id();
+			T::Collections::thaw_collection(pallet_account.clone(), T::NFTCollectionId::get())?;
+			T::NFTHandler::transfer(&T::NFTCollectionId::get(), &position_id, &dest)?;
+			T::Collections::freeze_collection(pallet_account, T::NFTCollectionId::get())?;
+
+			Self::deposit_event(Event::PositionTransferred {
+				from: who,
+				to: dest,
+				position_id,
+				locked,
+			});
+
+			Ok(())
+		}
+
+		/// Schedule slash of staking position of `who` found to abuse action points farming.
+		///
+		/// Slash can be cancelled by `AuthorityOrigin` during `SlashAppealPeriod` and executed by
+		/// anyone after it. Position with pending slash can't be unstaked or transferred.
+		///
+		/// Parameters:
+		/// - `origin`: must be `SlashOrigin`
+		/// - `who`: owner of the staking position
+		/// - `kind`: slash action points or stake of the position
+		/// - `percentage`: percentage of action points or stake to slash
+		///
+		/// Emits `SlashScheduled` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::slash_position())]
+		pub fn slash_position(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			kind: SlashKind,
+			percentage: Perbill,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			Self::schedule_slash(&who, kind, percentage)
+		}
+
+		/// Cancel pending slash of staking position, e.g. when appeal of the slashed account is accepted.
+		///
+		/// Parameters:
+		/// - `origin`: must be `AuthorityOrigin`
+		/// - `position_id`: id of the slashed staking position
+		///
+		/// Emits `SlashCancelled` event when successful.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_slash())]
+		pub fn cancel_slash(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let slash = PendingSlashes::<T>::take(position_id).ok_or(Error::<T>::SlashNotFound)?;
+
+			Self::deposit_event(Event::SlashCancelled {
+				who: slash.who,
+				position_id,
+			});
+
+			Ok(())
+		}
+
+		/// Execute pending slash of staking position after its appeal period.
+		///
+		/// Slashed action points are removed from the position. Slashed stake is unlocked and
+		/// transferred to `SlashedFundsReceiver`, rewards accumulated by the slashed stake are kept by
+		/// the position.
+		///
+		/// Parameters:
+		/// - `origin`: any signed origin
+		/// - `position_id`: id of the slashed staking position
+		///
+		/// Emits `PositionSlashed` event when successful.
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_slash())]
+		pub fn execute_slash(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let slash = PendingSlashes::<T>::get(position_id).ok_or(Error::<T>::SlashNotFound)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >= slash.executable_at,
+				Error::<T>::SlashAppealPeriodNotOver
+			);
+
+			let amount = match slash.kind {
+				SlashKind::ActionPoints => Self::slash_action_points(&slash.who, position_id, slash.percentage)?,
+				SlashKind::Stake => Self::slash_stake(&slash.who, position_id, slash.percentage)?,
+			};
+
+			PendingSlashes::<T>::remove(position_id);
+
+			Self::deposit_event(Event::PositionSlashed {
+				who: slash.who,
+				position_id,
+				kind: slash.kind,
+				amount,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -750,6 +1098,15 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Transfers rewards from the `pot` to `who`, locked in the vesting schedule if one is set.
+	fn pay_rewards(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		let pot = Self::pot_account_id();
+		match RewardVestingSchedule::<T>::get() {
+			Some(vesting) => T::VestedTransfer::vested_transfer(&pot, who, amount, vesting),
+			None => T::Currency::transfer(T::NativeAssetId::get(), &pot, who, amount),
+		}
+	}
+
 	fn ensure_stakeable_balance(
 		who: &T::AccountId,
 		stake: Balance,
@@ -870,22 +1227,71 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// This function calculates total amount of points `position` accumulated until now.
-	/// Slash points are subtracted from returned value.
+	/// Action points are decayed based on the position's inactivity and slash points are
+	/// subtracted from returned value.
 	#[inline]
 	fn get_points(
+		position_id: T::PositionItemId,
 		position: &Position<BlockNumberFor<T>>,
 		current_period: Period,
 		position_created_at: Period,
 	) -> Option<Point> {
+		let action_points = Self::get_decayed_action_points(position_id, position, current_period)?;
+
+		// Decay can drop points below already slashed points.
 		math::calculate_points(
 			position_created_at,
 			current_period,
 			T::TimePointsPerPeriod::get(),
 			T::TimePointsWeight::get(),
-			position.action_points,
+			action_points,
 			T::ActionPointsWeight::get(),
-			position.accumulated_slash_points,
+			Point::zero(),
 		)
+		.map(|points| points.saturating_sub(position.accumulated_slash_points))
+	}
+
+	/// Returns action points of the position reduced by decay for the periods without any new
+	/// action. Positions without tracked activity are not decayed.
+	fn get_decayed_action_points(
+		position_id: T::PositionItemId,
+		position: &Position<BlockNumberFor<T>>,
+		current_period: Period,
+	) -> Option<Point> {
+		match LastActionPeriod::<T>::get(position_id) {
+			Some(last_action_period) => math::calculate_decayed_action_points(
+				position.action_points,
+				current_period.saturating_sub(last_action_period),
+				T::ActionPointsDecayPeriods::get(),
+			),
+			None => Some(position.action_points),
+		}
+	}
+
+	/// Adds `points` to position's action points.
+	///
+	/// Decay of already earned points is applied first and position's activity is moved to the
+	/// current period. Position without tracked activity starts to be tracked even if no points
+	/// are added.
+	pub(crate) fn add_action_points(
+		position_id: T::PositionItemId,
+		position: &mut Position<BlockNumberFor<T>>,
+		points: Point,
+	) -> DispatchResult {
+		let last_action_period = LastActionPeriod::<T>::get(position_id);
+		if points.is_zero() && (last_action_period.is_some() || position.action_points.is_zero()) {
+			return Ok(());
+		}
+
+		let current_period =
+			Self::get_current_period().defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+		position.action_points = Self::get_decayed_action_points(position_id, position, current_period)
+			.ok_or(Error::<T>::Arithmetic)?
+			.saturating_add(points);
+		LastActionPeriod::<T>::insert(position_id, current_period);
+
+		Ok(())
 	}
 
 	#[inline]
@@ -909,6 +1315,7 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Return `(claimable_rewards, accumulated_unpaid_rewards, payable_percentage)`
 	fn calculate_rewards(
+		position_id: T::PositionItemId,
 		position: &Position<BlockNumberFor<T>>,
 		accumulated_reward_per_stake: FixedU128,
 		current_period: Period,
@@ -922,8 +1329,9 @@ impl<T: Config> Pallet<T> {
 			return Some((Balance::zero(), unpaid_rewards, FixedU128::zero()));
 		}
 
-		let points = Self::get_points(position, current_period, position_created_at)?;
-		let payable_percentage = T::PayablePercentage::get(points)?;
+		let points = Self::get_points(position_id, position, current_period, position_created_at)?;
+		let curve = RewardCurveParams::<T>::get();
+		let payable_percentage = math::sigmoid(points, curve.a, curve.b)?;
 
 		let total_rewards = math::calculate_total_rewards(
 			new_rewards,
@@ -960,13 +1368,17 @@ impl<T: Config> Pallet<T> {
 		position_id: T::PositionItemId,
 		position: &mut Position<BlockNumberFor<T>>,
 	) -> DispatchResult {
+		let mut points = Point::zero();
 		PositionVotes::<T>::mutate(position_id, |voting| {
 			let max_position_vote = Conviction::max_multiplier().saturating_mul_int(position.stake);
 
 			voting.votes.retain(|(ref_idx, vote)| {
 				if T::ReferendumInfo::is_referendum_finished(*ref_idx) {
-					let points = Self::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
-					position.action_points = position.action_points.saturating_add(points);
+					points = points.saturating_add(Self::calculate_points_for_action(
+						Action::DemocracyVote,
+						vote,
+						max_position_vote,
+					));
 					// We need to keep the vote info to determine if the vote should be locked when removed.
 					ProcessedVotes::<T>::insert(who, *ref_idx, vote);
 					false
@@ -975,7 +1387,8 @@ impl<T: Config> Pallet<T> {
 				}
 			});
 		});
-		Ok(())
+
+		Self::add_action_points(position_id, position, points)
 	}
 
 	/// Returns amount of action points user receives for action.
@@ -992,12 +1405,122 @@ impl<T: Config> Pallet<T> {
 			.saturating_div(action_max_value)
 	}
 
+	/// Removes `percentage` of position's action points, including points from finished votes.
+	/// Returns amount of slashed points.
+	fn slash_action_points(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		percentage: Perbill,
+	) -> Result<Point, DispatchError> {
+		Positions::<T>::try_mutate(position_id, |maybe_position| {
+			let position = maybe_position
+				.as_mut()
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+			Self::process_votes(who, position_id, position)?;
+			// apply decay so slashed points are not decayed again
+			Self::add_action_points(position_id, position, Point::zero())?;
+
+			let slashed_points = percentage.mul_floor(position.action_points);
+			position.action_points = position.action_points.saturating_sub(slashed_points);
+
+			Ok(slashed_points)
+		})
+	}
+
+	/// Removes `percentage` of position's stake and transfers it to `SlashedFundsReceiver`.
+	/// Rewards accumulated until now are kept by the position as unpaid rewards.
+	/// Returns amount of slashed stake.
+	fn slash_stake(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		percentage: Perbill,
+	) -> Result<Balance, DispatchError> {
+		Staking::<T>::try_mutate(|staking| {
+			Self::update_rewards(staking)?;
+
+			Positions::<T>::try_mutate(position_id, |maybe_position| {
+				let position = maybe_position
+					.as_mut()
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+				let new_rewards = math::calculate_rewards(
+					staking.accumulated_reward_per_stake,
+					position.reward_per_stake,
+					position.stake,
+				)
+				.ok_or(Error::<T>::Arithmetic)?;
+				position.accumulated_unpaid_rewards = position
+					.accumulated_unpaid_rewards
+					.checked_add(new_rewards)
+					.ok_or(Error::<T>::Arithmetic)?;
+				position.reward_per_stake = staking.accumulated_reward_per_stake;
+
+				let slashed_stake = percentage.mul_floor(position.stake);
+				position.stake = position
+					.stake
+					.checked_sub(slashed_stake)
+					.ok_or(Error::<T>::Arithmetic)?;
+				staking.total_stake = staking
+					.total_stake
+					.checked_sub(slashed_stake)
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+				let native_asset = T::NativeAssetId::get();
+				T::Currency::set_lock(STAKING_LOCK_ID, native_asset, who, position.get_total_locked()?)?;
+
+				// slashing ignores other locks, e.g. conviction voting, which can overlay the stake
+				let not_slashed = T::Currency::slash(native_asset, who, slashed_stake);
+				let slashed_funds = slashed_stake.saturating_sub(not_slashed);
+				if !slashed_funds.is_zero() {
+					T::Currency::deposit(native_asset, &T::SlashedFundsReceiver::get(), slashed_funds)?;
+				}
+
+				Ok(slashed_stake)
+			})
+		})
+	}
+
 	#[inline]
 	fn is_initialized() -> bool {
 		Staking::<T>::exists()
 	}
 }
 
+impl<T: Config> SlashStakingPosition<T::AccountId> for Pallet<T> {
+	fn schedule_slash(who: &T::AccountId, kind: SlashKind, percentage: Perbill) -> DispatchResult {
+		ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+		ensure!(!percentage.is_zero(), Error::<T>::InvalidSlashPercentage);
+
+		let position_id = Self::get_user_position_id(who)?.ok_or(Error::<T>::PositionNotFound)?;
+		ensure!(
+			!PendingSlashes::<T>::contains_key(position_id),
+			Error::<T>::SlashPending
+		);
+
+		let executable_at = T::BlockNumberProvider::current_block_number().saturating_add(T::SlashAppealPeriod::get());
+		PendingSlashes::<T>::insert(
+			position_id,
+			PendingSlash {
+				who: who.clone(),
+				kind,
+				percentage,
+				executable_at,
+			},
+		);
+
+		Self::deposit_event(Event::SlashScheduled {
+			who: who.clone(),
+			position_id,
+			kind,
+			percentage,
+			executable_at,
+		});
+
+		Ok(())
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	pub fn get_position(position_id: T::PositionItemId) -> Option<Position<BlockNumberFor<T>>> {
 		Positions::<T>::get(position_id)
@@ -1007,17 +1530,3 @@ impl<T: Config> Pallet<T> {
 		PositionVotes::<T>::get(position_id)
 	}
 }
-
-pub struct SigmoidPercentage<T, B>(sp_std::marker::PhantomData<(T, B)>);
-
-impl<T, B: Get<u32>> PayablePercentage<Point> for SigmoidPercentage<T, B>
-where
-	T: Get<FixedU128>,
-{
-	fn get(p: Point) -> Option<FixedU128> {
-		let a: FixedU128 = T::get();
-		let b: u32 = B::get();
-
-		math::sigmoid(p, a, b)
-	}
-}
@@ -13,6 +13,14 @@ pub mod versioned {
 		crate::pallet::Pallet<T>,
 		<T as frame_system::Config>::DbWeight,
 	>;
+
+	pub type V2ToV3<T, A, B> = VersionedMigration<
+		2,
+		3,
+		v3::VersionUncheckedMigrateV2ToV3<T, A, B>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
 }
 
 // This migration clear existing staking votes.
@@ -75,3 +83,43 @@ pub mod v2 {
 		}
 	}
 }
+
+// This migration moves reward curve parameters from the runtime constants `A` and `B` to the storage.
+pub mod v3 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+
+	const TARGET: &str = "runtime::staking::migration::v3";
+
+	pub struct VersionUncheckedMigrateV2ToV3<T, A, B>(PhantomData<(T, A, B)>);
+
+	impl<T: Config, A: Get<FixedU128>, B: Get<u32>> OnRuntimeUpgrade for VersionUncheckedMigrateV2ToV3<T, A, B> {
+		fn on_runtime_upgrade() -> Weight {
+			log::info!(
+				target: TARGET,
+				"running storage migration from version 2 to version 3."
+			);
+
+			let curve = RewardCurve {
+				a: A::get(),
+				b: B::get(),
+			};
+			if !curve.is_valid() {
+				log::error!(target: TARGET, "reward curve {:?} is not valid, default curve is kept.", curve);
+				return T::DbWeight::get().reads(1);
+			}
+
+			RewardCurveParams::<T>::put(curve);
+
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			assert!(RewardCurveParams::<T>::exists(), "RewardCurveParams storage is empty");
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,183 @@
+use super::*;
+
+use crate::pallet::{LastActionPeriod, PositionVotes, ProcessedVotes};
+use crate::types::{Conviction, Vote};
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+//NOTE: Referendums with even indexes are finished.
+
+const BOB_POSITION_ID: PositionId = 1;
+const START_BLOCK: u64 = 1_452_987;
+// Period of the `START_BLOCK`
+const START_PERIOD: Period = 145;
+
+fn full_stake_vote() -> Vote {
+	Vote {
+		amount: 120_000 * ONE,
+		conviction: Conviction::Locked6x,
+	}
+}
+
+fn run_periods(n: u64) {
+	set_block_number(START_BLOCK + n * PeriodLength::get());
+}
+
+fn decay_ext(decay_periods: u64) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.start_at_block(START_BLOCK)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, START_BLOCK, 200_000 * ONE),
+			(BOB, 120_000 * ONE, START_BLOCK, 0),
+		])
+		.with_votings(vec![(BOB_POSITION_ID, vec![(2_u32, full_stake_vote())])])
+		.with_action_points_decay(decay_periods)
+		.build()
+}
+
+#[test]
+fn process_votes_should_track_last_action_period_when_points_are_added() {
+	decay_ext(10).execute_with(|| {
+		let mut position = Staking::positions(BOB_POSITION_ID).unwrap();
+
+		//Act
+		assert_ok!(Staking::process_votes(&BOB, BOB_POSITION_ID, &mut position));
+
+		//Assert
+		assert_eq!(position.action_points, 100);
+		assert_eq!(Staking::last_action_period(BOB_POSITION_ID), Some(START_PERIOD));
+	});
+}
+
+#[test]
+fn action_points_should_decay_linearly_when_position_is_inactive() {
+	decay_ext(10).execute_with(|| {
+		//Arrange
+		let mut position = Staking::positions(BOB_POSITION_ID).unwrap();
+		assert_ok!(Staking::process_votes(&BOB, BOB_POSITION_ID, &mut position));
+
+		//Act & Assert
+		run_periods(5);
+		assert_eq!(
+			Staking::get_decayed_action_points(BOB_POSITION_ID, &position, START_PERIOD + 5),
+			Some(50)
+		);
+
+		run_periods(10);
+		assert_eq!(
+			Staking::get_decayed_action_points(BOB_POSITION_ID, &position, START_PERIOD + 10),
+			Some(0)
+		);
+
+		//decay is evaluated lazily, stored points are untouched
+		assert_eq!(position.action_points, 100);
+	});
+}
+
+#[test]
+fn process_votes_should_apply_decay_before_adding_new_points() {
+	decay_ext(10).execute_with(|| {
+		//Arrange
+		let mut position = Staking::positions(BOB_POSITION_ID).unwrap();
+		assert_ok!(Staking::process_votes(&BOB, BOB_POSITION_ID, &mut position));
+
+		run_periods(5);
+		PositionVotes::<Test>::mutate(BOB_POSITION_ID, |voting| {
+			assert_ok!(voting.votes.try_push((4_u32, full_stake_vote())));
+		});
+
+		//Act
+		assert_ok!(Staking::process_votes(&BOB, BOB_POSITION_ID, &mut position));
+
+		//Assert
+		assert_eq!(position.action_points, 150);
+		assert_eq!(Staking::last_action_period(BOB_POSITION_ID), Some(START_PERIOD + 5));
+	});
+}
+
+#[test]
+fn action_points_should_not_decay_when_decay_is_disabled() {
+	decay_ext(0).execute_with(|| {
+		//Arrange
+		let mut position = Staking::positions(BOB_POSITION_ID).unwrap();
+		assert_ok!(Staking::process_votes(&BOB, BOB_POSITION_ID, &mut position));
+
+		//Act
+		run_periods(1_000);
+
+		//Assert
+		assert_eq!(
+			Staking::get_decayed_action_points(BOB_POSITION_ID, &position, START_PERIOD + 1_000),
+			Some(100)
+		);
+	});
+}
+
+#[test]
+fn process_votes_should_start_tracking_position_when_it_has_untracked_action_points() {
+	decay_ext(10).execute_with(|| {
+		//Arrange
+		PositionVotes::<Test>::remove(BOB_POSITION_ID);
+		let mut position = Staking::positions(BOB_POSITION_ID).unwrap();
+		position.action_points = 80;
+
+		run_periods(5);
+		assert_eq!(
+			Staking::get_decayed_action_points(BOB_POSITION_ID, &position, START_PERIOD + 5),
+			Some(80)
+		);
+
+		//Act
+		assert_ok!(Staking::process_votes(&BOB, BOB_POSITION_ID, &mut position));
+
+		//Assert
+		assert_eq!(position.action_points, 80);
+		assert_eq!(Staking::last_action_period(BOB_POSITION_ID), Some(START_PERIOD + 5));
+	});
+}
+
+#[test]
+fn claim_should_work_when_decayed_points_are_lower_than_slashed_points() {
+	decay_ext(10).execute_with(|| {
+		//Arrange
+		run_periods(11);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), BOB_POSITION_ID));
+		assert!(Staking::positions(BOB_POSITION_ID).unwrap().accumulated_slash_points > 0);
+		ProcessedVotes::<Test>::remove(BOB, 2_u32);
+
+		//Act
+		run_periods(21);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), BOB_POSITION_ID));
+
+		//Assert
+		assert!(has_event(
+			Event::<Test>::RewardsClaimed {
+				who: BOB,
+				position_id: BOB_POSITION_ID,
+				paid_rewards: 0,
+				unlocked_rewards: 0,
+				slashed_points: 0,
+				slashed_unpaid_rewards: 0,
+				payable_percentage: FixedU128::zero(),
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn unstake_should_remove_last_action_period_when_position_is_destroyed() {
+	decay_ext(10).execute_with(|| {
+		//Arrange
+		PositionVotes::<Test>::remove(BOB_POSITION_ID);
+		LastActionPeriod::<Test>::insert(BOB_POSITION_ID, START_PERIOD);
+
+		//Act
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), BOB_POSITION_ID));
+
+		//Assert
+		assert_eq!(Staking::last_action_period(BOB_POSITION_ID), None);
+	});
+}
@@ -25,12 +25,14 @@ use frame_support::{
 	weights::RuntimeDbWeight,
 };
 use frame_system::{EnsureRoot, RawOrigin};
+use hydradx_traits::vesting::{RewardVesting, VestedTransfer};
 use orml_traits::{parameter_type_with_key, LockIdentifier, MultiCurrencyExtended};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
 	BuildStorage,
 };
+use std::cell::RefCell;
 
 use crate as pallet_staking;
 
@@ -47,6 +49,7 @@ pub const BOB: AccountId = 1_001;
 pub const CHARLIE: AccountId = 1_002;
 pub const DAVE: AccountId = 1_003;
 pub const VESTED_100K: AccountId = 1_004;
+pub const TREASURY: AccountId = 1_005;
 
 pub const ONE: u128 = 1_000_000_000_000;
 
@@ -56,6 +59,10 @@ pub const NON_DUSTABLE_BALANCE: Balance = 1_000 * ONE;
 
 pub type PositionId = u128;
 
+thread_local! {
+	pub static VESTED_TRANSFERS: RefCell<Vec<(AccountId, Balance, RewardVesting)>> = const { RefCell::new(vec![]) };
+}
+
 construct_runtime!(
 	pub enum Test
 	{
@@ -198,8 +205,12 @@ parameter_types! {
 	pub const TimePointsPerPeriod: u8 = 2;
 	pub const CurrentStakeWeight: u8 = 2;
 	pub const UnclaimablePeriods: BlockNumber = 10;
+	pub static ActionPointsDecayPeriods: BlockNumber = 0;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(15,100);
+	pub const RewardCurveB: u32 = 40_000;
 	pub const MaxVotes: u32 = 10;
+	pub const SlashAppealPeriod: BlockNumber = 100;
+	pub const TreasuryAccount: AccountId = TREASURY;
 }
 
 impl pallet_staking::Config for Test {
@@ -215,6 +226,7 @@ impl pallet_staking::Config for Test {
 	type ActionPointsWeight = ActionPointsW;
 	type TimePointsPerPeriod = TimePointsPerPeriod;
 	type UnclaimablePeriods = UnclaimablePeriods;
+	type ActionPointsDecayPeriods = ActionPointsDecayPeriods;
 	type CurrentStakeWeight = CurrentStakeWeight;
 	type BlockNumberProvider = MockBlockNumberProvider;
 	type PositionItemId = PositionId;
@@ -222,14 +234,17 @@ impl pallet_staking::Config for Test {
 	type NFTCollectionId = ConstU128<1>;
 	type NFTHandler = Uniques;
 
-	type PayablePercentage = SigmoidPercentage<PointPercentage, ConstU32<40_000>>;
 	type MaxVotes = MaxVotes;
 	type MaxPointsPerAction = DummyMaxPointsPerAction;
 	type ReferendumInfo = DummyReferendumStatus;
 	type Vesting = DummyVesting;
+	type VestedTransfer = DummyVestedTransfer;
 	type Collections = FreezableUniques;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type MinSlash = DummyMinSlash;
+	type SlashOrigin = EnsureRoot<AccountId>;
+	type SlashAppealPeriod = SlashAppealPeriod;
+	type SlashedFundsReceiver = TreasuryAccount;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type MaxLocks = MaxLocks;
@@ -275,12 +290,30 @@ impl VestingDetails<AccountId, Balance> for DummyVesting {
 	}
 }
 
+pub struct DummyVestedTransfer;
+
+impl VestedTransfer<AccountId, Balance> for DummyVestedTransfer {
+	fn vested_transfer(from: &AccountId, to: &AccountId, amount: Balance, vesting: RewardVesting) -> DispatchResult {
+		<Tokens as MultiCurrency<AccountId>>::transfer(HDX, from, to, amount)?;
+		VESTED_TRANSFERS.with(|v| v.borrow_mut().push((*to, amount, vesting)));
+		Ok(())
+	}
+
+	fn vested_transfer_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct FreezableUniques;
 
 impl Freeze<AccountId, u128> for FreezableUniques {
 	fn freeze_collection(owner: AccountId, collection: u128) -> DispatchResult {
 		Uniques::freeze_collection(RuntimeOrigin::signed(owner), collection)
 	}
+
+	fn thaw_collection(owner: AccountId, collection: u128) -> DispatchResult {
+		Uniques::thaw_collection(RuntimeOrigin::signed(owner), collection)
+	}
 }
 
 pub fn set_block_number(n: u64) {
@@ -295,6 +328,7 @@ pub struct ExtBuilder {
 	stakes: Vec<(AccountId, Balance, BlockNumber, Balance)>,
 	init_staking: bool,
 	with_votings: Vec<(PositionId, Vec<(types::ReferendumIndex, Vote)>)>,
+	action_points_decay_periods: BlockNumber,
 }
 
 impl ExtBuilder {
@@ -323,12 +357,20 @@ impl ExtBuilder {
 		self.with_votings = votings;
 		self
 	}
+
+	pub fn with_action_points_decay(mut self, periods: BlockNumber) -> Self {
+		self.action_points_decay_periods = periods;
+		self
+	}
 }
 
 impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
+		VESTED_TRANSFERS.with(|v| v.borrow_mut().clear());
+		ActionPointsDecayPeriods::set(self.action_points_decay_periods);
+
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
 				.endowed_accounts
@@ -347,6 +389,11 @@ impl ExtBuilder {
 				set_block_number(self.initial_block_number);
 			}
 
+			pallet_staking::RewardCurveParams::<Test>::put(RewardCurve {
+				a: PointPercentage::get(),
+				b: RewardCurveB::get(),
+			});
+
 			if self.init_staking {
 				let pot = Staking::pot_account_id();
 				assert_ok!(Tokens::set_balance(
@@ -5,12 +5,17 @@ use crate::*;
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::BalanceLock;
 
+mod action_points_decay;
 mod claim;
 mod increase_stake;
 pub(crate) mod mock;
+mod reward_curve;
+mod reward_vesting;
+mod slashing;
 mod stake;
 #[allow(clippy::module_inception)]
 mod tests;
+mod transfer_position;
 mod unstake;
 
 /// Assert amount of locked tokens. `amount == 0` asserts no lock.
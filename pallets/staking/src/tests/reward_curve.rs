@@ -0,0 +1,144 @@
+use super::*;
+
+use crate::migration::v3::VersionUncheckedMigrateV2ToV3;
+use crate::types::RewardCurve;
+use frame_support::traits::OnRuntimeUpgrade;
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::traits::ConstU32;
+
+const CURVE: RewardCurve = RewardCurve {
+	a: FixedU128::from_inner(1_000_000_000_000_000_000),
+	b: 1,
+};
+
+fn payable_percentage() -> FixedU128 {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Staking(Event::<Test>::RewardsClaimed { payable_percentage, .. }) => Some(payable_percentage),
+			_ => None,
+		})
+		.expect("RewardsClaimed event expected")
+}
+
+fn claim_bob_rewards(curve: Option<RewardCurve>) -> FixedU128 {
+	let mut percentage = FixedU128::zero();
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			if let Some(curve) = curve {
+				assert_ok!(Staking::set_reward_curve(RuntimeOrigin::root(), curve));
+			}
+
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			percentage = payable_percentage();
+		});
+	percentage
+}
+
+#[test]
+fn set_reward_curve_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Staking::set_reward_curve(RuntimeOrigin::root(), CURVE));
+
+		//Assert
+		assert_eq!(Staking::reward_curve(), CURVE);
+		assert_last_event!(Event::<Test>::RewardCurveUpdated { curve: CURVE }.into());
+	});
+}
+
+#[test]
+fn set_reward_curve_should_not_work_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_reward_curve(RuntimeOrigin::signed(ALICE), CURVE),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_reward_curve_should_not_work_when_parameters_are_out_of_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		for curve in [
+			RewardCurve {
+				a: FixedU128::zero(),
+				..CURVE
+			},
+			RewardCurve {
+				a: FixedU128::from_rational(11, 10),
+				..CURVE
+			},
+			RewardCurve { b: 0, ..CURVE },
+			RewardCurve {
+				b: RewardCurve::MAX_B + 1,
+				..CURVE
+			},
+		] {
+			assert_noop!(
+				Staking::set_reward_curve(RuntimeOrigin::root(), curve),
+				Error::<Test>::InvalidRewardCurve
+			);
+		}
+	});
+}
+
+#[test]
+fn claim_should_pay_rewards_according_to_reward_curve_when_curve_is_updated() {
+	//Act
+	let default_percentage = claim_bob_rewards(None);
+	let updated_percentage = claim_bob_rewards(Some(CURVE));
+
+	//Assert
+	assert!(updated_percentage > default_percentage);
+}
+
+#[test]
+fn migration_should_set_reward_curve_from_constants() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		VersionUncheckedMigrateV2ToV3::<Test, PointPercentage, ConstU32<10_000>>::on_runtime_upgrade();
+
+		//Assert
+		assert_eq!(
+			Staking::reward_curve(),
+			RewardCurve {
+				a: PointPercentage::get(),
+				b: 10_000
+			}
+		);
+	});
+}
+
+#[test]
+fn migration_should_keep_reward_curve_when_constants_are_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let curve = Staking::reward_curve();
+
+		//Act
+		VersionUncheckedMigrateV2ToV3::<Test, PointPercentage, ConstU32<0>>::on_runtime_upgrade();
+
+		//Assert
+		assert_eq!(Staking::reward_curve(), curve);
+	});
+}
@@ -0,0 +1,164 @@
+use super::*;
+
+use hydradx_traits::vesting::RewardVesting;
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+const VESTING: RewardVesting = RewardVesting {
+	cliff: 100,
+	period: 10,
+	period_count: 5,
+};
+
+fn paid_rewards() -> Balance {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Staking(Event::<Test>::RewardsClaimed { paid_rewards, .. }) => Some(paid_rewards),
+			_ => None,
+		})
+		.expect("RewardsClaimed event expected")
+}
+
+#[test]
+fn set_reward_vesting_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Staking::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+
+		//Assert
+		assert_eq!(Staking::reward_vesting(), Some(VESTING));
+		assert_last_event!(Event::<Test>::RewardVestingUpdated { vesting: Some(VESTING) }.into());
+	});
+}
+
+#[test]
+fn set_reward_vesting_should_not_work_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_reward_vesting(RuntimeOrigin::signed(ALICE), Some(VESTING)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_reward_vesting_should_not_work_when_schedule_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_reward_vesting(
+				RuntimeOrigin::root(),
+				Some(RewardVesting {
+					cliff: 0,
+					period: 0,
+					period_count: 5,
+				})
+			),
+			Error::<Test>::InvalidRewardVesting
+		);
+	});
+}
+
+#[test]
+fn claim_should_pay_rewards_as_vesting_schedule_when_vesting_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			let paid_rewards = paid_rewards();
+			assert!(!paid_rewards.is_zero());
+			assert_eq!(
+				VESTED_TRANSFERS.with(|v| v.borrow().clone()),
+				vec![(BOB, paid_rewards, VESTING)]
+			);
+		});
+}
+
+#[test]
+fn unstake_should_pay_rewards_as_vesting_schedule_when_vesting_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_reward_vesting(RuntimeOrigin::root(), Some(VESTING)));
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			let paid_rewards = paid_rewards();
+			assert!(!paid_rewards.is_zero());
+			assert_eq!(
+				VESTED_TRANSFERS.with(|v| v.borrow().clone()),
+				vec![(BOB, paid_rewards, VESTING)]
+			);
+		});
+}
+
+#[test]
+fn claim_should_transfer_rewards_instantly_when_vesting_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert!(!paid_rewards().is_zero());
+			assert!(VESTED_TRANSFERS.with(|v| v.borrow().is_empty()));
+		});
+}
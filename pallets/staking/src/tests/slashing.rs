@@ -0,0 +1,323 @@
+use super::*;
+
+use crate::types::{PendingSlash, SlashKind};
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn slashing_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+}
+
+#[test]
+fn slash_position_should_schedule_slash_when_origin_is_slash_origin() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+
+		//Act
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+
+		//Assert
+		assert_eq!(
+			Staking::pending_slashes(alice_position_id),
+			Some(PendingSlash {
+				who: ALICE,
+				kind: SlashKind::Stake,
+				percentage: Perbill::from_percent(10),
+				executable_at: 1_452_987 + SlashAppealPeriod::get(),
+			})
+		);
+		assert_last_event!(Event::<Test>::SlashScheduled {
+			who: ALICE,
+			position_id: alice_position_id,
+			kind: SlashKind::Stake,
+			percentage: Perbill::from_percent(10),
+			executable_at: 1_452_987 + SlashAppealPeriod::get(),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn slash_position_should_not_work_when_origin_is_not_slash_origin() {
+	slashing_ext().execute_with(|| {
+		assert_noop!(
+			Staking::slash_position(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				SlashKind::Stake,
+				Perbill::from_percent(10)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn slash_position_should_not_work_when_slash_is_pending() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::ActionPoints,
+			Perbill::from_percent(50)
+		));
+
+		//Act & assert
+		assert_noop!(
+			Staking::slash_position(
+				RuntimeOrigin::root(),
+				ALICE,
+				SlashKind::Stake,
+				Perbill::from_percent(10)
+			),
+			Error::<Test>::SlashPending
+		);
+	});
+}
+
+#[test]
+fn slash_position_should_not_work_when_account_has_no_position() {
+	slashing_ext().execute_with(|| {
+		assert_noop!(
+			Staking::slash_position(RuntimeOrigin::root(), DAVE, SlashKind::Stake, Perbill::from_percent(10)),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
+
+#[test]
+fn slash_position_should_not_work_when_percentage_is_zero() {
+	slashing_ext().execute_with(|| {
+		assert_noop!(
+			Staking::slash_position(RuntimeOrigin::root(), ALICE, SlashKind::Stake, Perbill::zero()),
+			Error::<Test>::InvalidSlashPercentage
+		);
+	});
+}
+
+#[test]
+fn execute_slash_should_not_work_when_appeal_period_is_not_over() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+		set_block_number(1_452_987 + SlashAppealPeriod::get() - 1);
+
+		//Act & assert
+		assert_noop!(
+			Staking::execute_slash(RuntimeOrigin::signed(DAVE), alice_position_id),
+			Error::<Test>::SlashAppealPeriodNotOver
+		);
+	});
+}
+
+#[test]
+fn execute_slash_should_transfer_slashed_stake_to_treasury() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+		set_block_number(1_452_987 + SlashAppealPeriod::get());
+
+		//Act
+		assert_ok!(Staking::execute_slash(RuntimeOrigin::signed(DAVE), alice_position_id));
+
+		//Assert
+		assert_eq!(Staking::positions(alice_position_id).unwrap().stake, 90_000 * ONE);
+		assert_eq!(Staking::staking().total_stake, 210_000 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 140_000 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &TREASURY), 10_000 * ONE);
+		assert_hdx_lock!(ALICE, 90_000 * ONE, STAKING_LOCK);
+		assert_eq!(Staking::pending_slashes(alice_position_id), None);
+		assert_last_event!(Event::<Test>::PositionSlashed {
+			who: ALICE,
+			position_id: alice_position_id,
+			kind: SlashKind::Stake,
+			amount: 10_000 * ONE,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn execute_slash_should_keep_rewards_accumulated_by_slashed_stake() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+		set_pending_rewards(10_000 * ONE);
+		set_block_number(1_452_987 + SlashAppealPeriod::get());
+
+		//Act
+		assert_ok!(Staking::execute_slash(RuntimeOrigin::signed(DAVE), alice_position_id));
+
+		//Assert
+		let position = Staking::positions(alice_position_id).unwrap();
+		assert_eq!(position.accumulated_unpaid_rewards, 4_545_454_545_454_545);
+		assert_eq!(
+			position.reward_per_stake,
+			Staking::staking().accumulated_reward_per_stake
+		);
+	});
+}
+
+#[test]
+fn execute_slash_should_remove_action_points_when_action_points_are_slashed() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		Positions::<Test>::mutate(alice_position_id, |p| {
+			p.as_mut().unwrap().action_points = 1_000;
+		});
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::ActionPoints,
+			Perbill::from_percent(40)
+		));
+		set_block_number(1_452_987 + SlashAppealPeriod::get());
+
+		//Act
+		assert_ok!(Staking::execute_slash(RuntimeOrigin::signed(DAVE), alice_position_id));
+
+		//Assert
+		let position = Staking::positions(alice_position_id).unwrap();
+		assert_eq!(position.action_points, 600);
+		assert_eq!(position.stake, 100_000 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &TREASURY), 0);
+		assert_last_event!(Event::<Test>::PositionSlashed {
+			who: ALICE,
+			position_id: alice_position_id,
+			kind: SlashKind::ActionPoints,
+			amount: 400,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn cancel_slash_should_remove_pending_slash_when_origin_is_authority() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+
+		//Act
+		assert_ok!(Staking::cancel_slash(RuntimeOrigin::root(), alice_position_id));
+
+		//Assert
+		assert_eq!(Staking::pending_slashes(alice_position_id), None);
+		assert_last_event!(Event::<Test>::SlashCancelled {
+			who: ALICE,
+			position_id: alice_position_id,
+		}
+		.into());
+
+		set_block_number(1_452_987 + SlashAppealPeriod::get());
+		assert_noop!(
+			Staking::execute_slash(RuntimeOrigin::signed(DAVE), alice_position_id),
+			Error::<Test>::SlashNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_slash_should_not_work_when_origin_is_not_authority() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+
+		//Act & assert
+		assert_noop!(
+			Staking::cancel_slash(RuntimeOrigin::signed(ALICE), alice_position_id),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn unstake_should_not_work_when_slash_is_pending() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+
+		//Act & assert
+		assert_noop!(
+			Staking::unstake(RuntimeOrigin::signed(ALICE), alice_position_id),
+			Error::<Test>::SlashPending
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_slash_is_pending() {
+	slashing_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+		assert_ok!(Staking::slash_position(
+			RuntimeOrigin::root(),
+			ALICE,
+			SlashKind::Stake,
+			Perbill::from_percent(10)
+		));
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(ALICE), alice_position_id, DAVE),
+			Error::<Test>::SlashPending
+		);
+	});
+}
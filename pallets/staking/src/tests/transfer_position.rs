@@ -0,0 +1,220 @@
+use crate::types::{Conviction, Vote};
+
+use super::*;
+
+use frame_support::traits::nonfungibles::Transfer;
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn transfer_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(BOB, 50_000 * ONE, 1_600_000, 10_000 * ONE),
+		])
+		.build()
+}
+
+#[test]
+fn set_position_transfers_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+
+		//Assert
+		assert!(Staking::position_transfers_enabled());
+		assert_last_event!(Event::<Test>::PositionTransfersUpdated { enabled: true }.into());
+
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), false));
+		assert!(!Staking::position_transfers_enabled());
+	});
+}
+
+#[test]
+fn set_position_transfers_should_not_work_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_position_transfers(RuntimeOrigin::signed(ALICE), true),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_transfers_are_disabled() {
+	transfer_ext().execute_with(|| {
+		//Arrange
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(BOB), bob_position_id, DAVE),
+			Error::<Test>::PositionTransfersDisabled
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_origin_is_not_position_owner() {
+	transfer_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(DAVE), bob_position_id, DAVE),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_dest_has_position() {
+	transfer_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(BOB), bob_position_id, ALICE),
+			Error::<Test>::PositionAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_position_has_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (DAVE, HDX, 100_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE)])
+		.with_votings(vec![(
+			0,
+			vec![(
+				1_u32,
+				Vote {
+					amount: 10_000 * ONE,
+					conviction: Conviction::Locked4x,
+				},
+			)],
+		)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+			let alice_position_id = 0;
+
+			//Act & assert
+			assert_noop!(
+				Staking::transfer_position(RuntimeOrigin::signed(ALICE), alice_position_id, DAVE),
+				Error::<Test>::ExistingVotes
+			);
+		});
+}
+
+#[test]
+fn transfer_position_should_move_position_and_locked_stake_when_transfers_are_enabled() {
+	transfer_ext().execute_with(|| {
+		//Arrange
+		set_block_number(1_700_000);
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+		let position = Staking::positions(bob_position_id).unwrap();
+		let locked = position.get_total_locked().unwrap();
+		let bob_balance = Tokens::free_balance(HDX, &BOB);
+		let dave_balance = Tokens::free_balance(HDX, &DAVE);
+		let staking_data = Staking::staking();
+
+		assert!(!position.accumulated_slash_points.is_zero());
+		assert!(!position.accumulated_locked_rewards.is_zero());
+
+		//Act
+		assert_ok!(Staking::transfer_position(
+			RuntimeOrigin::signed(BOB),
+			bob_position_id,
+			DAVE
+		));
+
+		//Assert
+		assert_eq!(Staking::get_user_position_id(&BOB).unwrap(), None);
+		assert_eq!(Staking::get_user_position_id(&DAVE).unwrap(), Some(bob_position_id));
+		assert_eq!(Staking::positions(bob_position_id), Some(position));
+		assert_eq!(Staking::staking(), staking_data);
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), bob_balance - locked);
+		assert_eq!(Tokens::free_balance(HDX, &DAVE), dave_balance + locked);
+		assert_hdx_lock!(BOB, 0, STAKING_LOCK_ID);
+		assert_hdx_lock!(DAVE, locked, STAKING_LOCK_ID);
+		assert_unlocked_balance!(DAVE, HDX, dave_balance);
+
+		assert_last_event!(Event::<Test>::PositionTransferred {
+			from: BOB,
+			to: DAVE,
+			position_id: bob_position_id,
+			locked,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn transfer_position_should_keep_collection_frozen_when_position_was_transferred() {
+	transfer_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+		//Act
+		assert_ok!(Staking::transfer_position(
+			RuntimeOrigin::signed(BOB),
+			bob_position_id,
+			DAVE
+		));
+
+		//Assert
+		assert_noop!(
+			<Uniques as Transfer<AccountId>>::transfer(&1, &bob_position_id, &BOB),
+			pallet_uniques::Error::<Test>::Frozen
+		);
+	});
+}
+
+#[test]
+fn claim_should_work_when_position_was_transferred() {
+	transfer_ext().execute_with(|| {
+		//Arrange
+		set_block_number(1_700_000);
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+		assert_ok!(Staking::transfer_position(
+			RuntimeOrigin::signed(BOB),
+			bob_position_id,
+			DAVE
+		));
+		let dave_balance = Tokens::free_balance(HDX, &DAVE);
+
+		//Act
+		assert_noop!(
+			Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id),
+			Error::<Test>::Forbidden
+		);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(DAVE), bob_position_id));
+
+		//Assert
+		assert!(Tokens::free_balance(HDX, &DAVE) > dave_balance);
+	});
+}
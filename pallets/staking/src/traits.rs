@@ -1,11 +1,6 @@
-use crate::types::Balance;
+use crate::types::{Balance, SlashKind};
 use frame_support::dispatch::DispatchResult;
-use sp_runtime::FixedU128;
-
-pub trait PayablePercentage<Point> {
-	/// Returns percentage to pay based of amount of points.
-	fn get(points: Point) -> Option<FixedU128>;
-}
+use sp_runtime::{FixedU128, Perbill};
 
 pub trait GetReferendumState<Index> {
 	fn is_referendum_finished(index: Index) -> bool;
@@ -19,9 +14,20 @@ pub(crate) trait ActionData {
 pub trait Freeze<AccountId, CollectionId> {
 	/// Freezes given item so it is not transferable.
 	fn freeze_collection(owner: AccountId, collection: CollectionId) -> DispatchResult;
+
+	/// Thaws given collection so its items are transferable.
+	fn thaw_collection(owner: AccountId, collection: CollectionId) -> DispatchResult;
 }
 
 pub trait VestingDetails<AccountId, Balance> {
 	/// Returns vested amount for who.
 	fn locked(who: AccountId) -> Balance;
 }
+
+/// Hook used to slash staking positions of accounts abusing action points farming, e.g. by
+/// voting in referenda just to collect points.
+pub trait SlashStakingPosition<AccountId> {
+	/// Schedules slash of `percentage` of `kind` of the `who`'s staking position.
+	/// Slash can be cancelled during the appeal period and executed after it.
+	fn schedule_slash(who: &AccountId, kind: SlashKind, percentage: Perbill) -> DispatchResult;
+}
@@ -4,7 +4,10 @@ use scale_info::TypeInfo;
 use sp_core::bounded::BoundedVec;
 use sp_core::Get;
 use sp_runtime::RuntimeDebug;
-use sp_runtime::{traits::Zero, ArithmeticError, FixedU128};
+use sp_runtime::{
+	traits::{One, Zero},
+	ArithmeticError, FixedU128, Perbill,
+};
 
 pub type Balance = u128;
 pub type Point = u128;
@@ -12,6 +15,28 @@ pub type Period = u128;
 
 pub type ReferendumIndex = u32;
 
+/// Part of the staking position slashed for misbehaviour.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum SlashKind {
+	/// Action points of the position.
+	ActionPoints,
+	/// Staked amount of the position. Slashed stake is transferred to `SlashedFundsReceiver`.
+	Stake,
+}
+
+/// Slash of staking position waiting for the end of its appeal period.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PendingSlash<AccountId, BlockNumber> {
+	/// Owner of the position when the slash was scheduled.
+	pub who: AccountId,
+	/// Slashed part of the position.
+	pub kind: SlashKind,
+	/// Percentage of action points or stake to slash.
+	pub percentage: Perbill,
+	/// Block number from which the slash can be executed.
+	pub executable_at: BlockNumber,
+}
+
 pub enum Action {
 	DemocracyVote,
 }
@@ -59,6 +84,10 @@ impl<BlockNumber> Position<BlockNumber> {
 	pub fn get_action_points(&self) -> Point {
 		self.action_points
 	}
+
+	pub fn get_stake(&self) -> Balance {
+		self.stake
+	}
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
@@ -78,6 +107,25 @@ impl StakingData {
 	}
 }
 
+/// Parameters of the sigmoid curve determining percentage of rewards paid based on position's points.
+///
+/// f(x) = (ax)^4/(b + (ax)^4)
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RewardCurve {
+	pub a: FixedU128,
+	pub b: u32,
+}
+
+impl RewardCurve {
+	/// Max value of `b` parameter of the curve.
+	pub const MAX_B: u32 = 1_000_000;
+
+	/// `a` must be from range (0, 1] and `b` from range [1, `MAX_B`].
+	pub fn is_valid(&self) -> bool {
+		!self.a.is_zero() && self.a <= FixedU128::one() && self.b > 0 && self.b <= Self::MAX_B
+	}
+}
+
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
 pub enum Conviction {
 	#[default]
@@ -55,6 +55,13 @@ pub trait WeightInfo {
 	fn increase_stake() -> Weight;
 	fn claim() -> Weight;
 	fn unstake() -> Weight;
+	fn set_reward_vesting() -> Weight;
+	fn set_reward_curve() -> Weight;
+	fn set_position_transfers() -> Weight;
+	fn transfer_position() -> Weight;
+	fn slash_position() -> Weight;
+	fn cancel_slash() -> Weight;
+	fn execute_slash() -> Weight;
 }
 
 /// Weights for pallet_staking using the hydraDX node and recommended hardware.
@@ -123,14 +130,16 @@ impl WeightInfo for () {
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::LastActionPeriod` (r:1 w:1)
+	/// Proof: `Staking::LastActionPeriod` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn increase_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3379`
 		//  Estimated: `268590`
 		// Minimum execution time: 260_117_000 picoseconds.
 		Weight::from_parts(264_603_000, 268590)
-			.saturating_add(RocksDbWeight::get().reads(109_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(110_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Staking::Staking` (r:1 w:1)
 	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
@@ -150,14 +159,18 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::RewardVestingSchedule` (r:1 w:0)
+	/// Proof: `Staking::RewardVestingSchedule` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::LastActionPeriod` (r:1 w:1)
+	/// Proof: `Staking::LastActionPeriod` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3379`
 		//  Estimated: `268590`
 		// Minimum execution time: 253_815_000 picoseconds.
 		Weight::from_parts(257_035_000, 268590)
-			.saturating_add(RocksDbWeight::get().reads(109_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(111_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Staking::Staking` (r:1 w:1)
 	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
@@ -181,13 +194,129 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::RewardVestingSchedule` (r:1 w:0)
+	/// Proof: `Staking::RewardVestingSchedule` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::LastActionPeriod` (r:0 w:1)
+	/// Proof: `Staking::LastActionPeriod` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn unstake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1431`
 		//  Estimated: `6196`
 		// Minimum execution time: 134_541_000 picoseconds.
 		Weight::from_parts(135_440_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Staking::RewardVestingSchedule` (r:0 w:1)
+	/// Proof: `Staking::RewardVestingSchedule` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_reward_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `0`
+		// Minimum execution time: 9_129_000 picoseconds.
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::RewardCurveParams` (r:0 w:1)
+	/// Proof: `Staking::RewardCurveParams` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn set_reward_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `0`
+		// Minimum execution time: 9_043_000 picoseconds.
+		Weight::from_parts(9_301_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::PositionTransfersEnabled` (r:0 w:1)
+	/// Proof: `Staking::PositionTransfersEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_position_transfers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `0`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(9_187_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::Staking` (r:1 w:0)
+	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::PositionTransfersEnabled` (r:1 w:0)
+	/// Proof: `Staking::PositionTransfersEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:1 w:2)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::PositionVotes` (r:1 w:0)
+	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ProcessedVotes` (r:1 w:0)
+	/// Proof: `Staking::ProcessedVotes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Positions` (r:1 w:0)
+	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:0)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(469), added: 2944, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn transfer_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1472`
+		//  Estimated: `8538`
+		// Minimum execution time: 112_604_000 picoseconds.
+		Weight::from_parts(114_213_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Staking::Staking` (r:1 w:0)
+	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:2 w:0)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::PendingSlashes` (r:1 w:1)
+	/// Proof: `Staking::PendingSlashes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn slash_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `780`
+		//  Estimated: `6164`
+		// Minimum execution time: 27_412_000 picoseconds.
+		Weight::from_parts(28_063_000, 6164)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::PendingSlashes` (r:1 w:1)
+	/// Proof: `Staking::PendingSlashes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn cancel_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248`
+		//  Estimated: `3538`
+		// Minimum execution time: 14_870_000 picoseconds.
+		Weight::from_parts(15_311_000, 3538)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::PendingSlashes` (r:1 w:1)
+	/// Proof: `Staking::PendingSlashes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Staking` (r:1 w:1)
+	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn execute_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1205`
+		//  Estimated: `8799`
+		// Minimum execution time: 86_310_000 picoseconds.
+		Weight::from_parts(87_942_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 }
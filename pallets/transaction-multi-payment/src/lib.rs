@@ -32,15 +32,16 @@ mod tests;
 mod traits;
 
 pub use crate::traits::*;
+use codec::{Decode, Encode};
 use frame_support::storage::with_transaction;
 use frame_support::traits::{Contains, IsSubType};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	sp_runtime::{
-		traits::{DispatchInfoOf, One, PostDispatchInfoOf, Saturating, Zero},
-		transaction_validity::{InvalidTransaction, TransactionValidityError},
-		FixedPointNumber, FixedPointOperand, FixedU128,
+		traits::{DispatchInfoOf, IdentifyAccount, One, PostDispatchInfoOf, Saturating, SignedExtension, Verify, Zero},
+		transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+		FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 	},
 	traits::Get,
 	weights::Weight,
@@ -56,7 +57,9 @@ use hydradx_traits::{
 };
 use orml_traits::{GetByKey, Happened, MultiCurrency};
 use pallet_transaction_payment::OnChargeTransaction;
+use scale_info::TypeInfo;
 use sp_runtime::traits::TryConvert;
+use sp_runtime::DispatchError;
 use sp_std::{marker::PhantomData, prelude::*};
 
 pub type AssetIdOf<T> =
@@ -66,6 +69,9 @@ type BalanceOf<T> = <<T as Config>::Currencies as MultiCurrency<<T as frame_syst
 /// Spot price type
 pub type Price = FixedU128;
 
+/// Prefix of the signed permit payload.
+pub const PERMIT_PREFIX: &[u8] = b"hydration:permit";
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -73,14 +79,14 @@ pub use pallet::*;
 pub mod pallet {
 	use super::*;
 	use codec::DecodeLimit;
-	use frame_support::dispatch::PostDispatchInfo;
+	use frame_support::dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo};
 	use frame_support::pallet_prelude::*;
 	use frame_support::weights::WeightToFee;
 	use frame_system::ensure_none;
 	use frame_system::pallet_prelude::OriginFor;
 	use hydradx_traits::fee::SwappablePaymentAssetTrader;
 	use sp_core::{H160, H256, U256};
-	use sp_runtime::{ModuleError, TransactionOutcome};
+	use sp_runtime::{traits::Dispatchable, ModuleError, TransactionOutcome};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -160,6 +166,28 @@ pub mod pallet {
 		/// Try to retrieve fee currency from runtime call.
 		/// It is generic implementation to avoid tight coupling with other pallets such as utility.
 		type TryCallCurrency<'a>: TryConvert<&'a <Self as frame_system::Config>::RuntimeCall, AssetIdOf<Self>>;
+
+		/// The overarching call type. Calls of signed permits are dispatched as this type.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Signature of a signed permit.
+		type PermitSignature: Verify<Signer = Self::PermitSigner> + Parameter;
+
+		/// Signer of a signed permit.
+		type PermitSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Amount of native asset staked by an account. Used to select fee discount of the account.
+		type StakedAmount: GetByKey<Self::AccountId, BalanceOf<Self>>;
+
+		/// Maximum number of fee discount tiers.
+		#[pallet::constant]
+		type MaxFeeDiscounts: Get<u32>;
+
+		/// Handler of transaction fees paid in non-native currency, called after the fee is deposited.
+		type NonNativeFeeHandler: OnNonNativeFeeDeposited<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>>;
 	}
 
 	#[pallet::event]
@@ -189,6 +217,22 @@ pub mod pallet {
 			non_native_fee_amount: BalanceOf<T>,
 			destination_account_id: T::AccountId,
 		},
+
+		/// Call of a signed permit was dispatched on behalf of the signer.
+		/// Dispatch fee was paid by the signer to the relayer.
+		PermitDispatched {
+			who: T::AccountId,
+			relayer: T::AccountId,
+			nonce: T::Nonce,
+			fee_asset_id: AssetIdOf<T>,
+			fee_amount: BalanceOf<T>,
+			result: DispatchResult,
+		},
+
+		/// Fee discount tiers were set.
+		FeeDiscountsSet {
+			discounts: BoundedVec<(BalanceOf<T>, Permill), T::MaxFeeDiscounts>,
+		},
 	}
 
 	#[pallet::error]
@@ -228,6 +272,15 @@ pub mod pallet {
 
 		/// EVM permit call failed.
 		EvmPermitRunnerError,
+
+		/// Deadline of the signed permit has passed.
+		PermitExpired,
+
+		/// Signature of the signed permit is invalid.
+		InvalidPermitSignature,
+
+		/// Fee discount tiers must be sorted by minimum stake and minimum stakes must be unique.
+		InvalidFeeDiscounts,
 	}
 
 	/// Account currency map
@@ -245,11 +298,24 @@ pub mod pallet {
 	#[pallet::getter(fn currency_price)]
 	pub type AcceptedCurrencyPrice<T: Config> = StorageMap<_, Twox64Concat, AssetIdOf<T>, Price, OptionQuery>;
 
+	/// Fee currency of the transaction being dispatched, overriding the account currency.
+	/// Set by `dispatch_permit` and by the `SetFeeCurrency` signed extension for the duration of the transaction.
 	#[pallet::storage]
 	#[pallet::getter(fn tx_fee_currency_override)]
 	pub type TransactionCurrencyOverride<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, AssetIdOf<T>, OptionQuery>;
 
+	/// Nonce of the next signed permit of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	pub type PermitNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Nonce, ValueQuery>;
+
+	/// Fee discount tiers as pairs of minimum stake and discount, sorted by minimum stake.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_discounts)]
+	pub type FeeDiscounts<T: Config> =
+		StorageValue<_, BoundedVec<(BalanceOf<T>, Permill), T::MaxFeeDiscounts>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -454,6 +520,98 @@ pub mod pallet {
 
 			Ok(result)
 		}
+
+		/// Dispatch a call on behalf of `who`, authorized by a permit signed by `who`.
+		///
+		/// Allows a relayer to submit a call for an account which doesn't hold native currency.
+		/// The permit is a signature of the payload returned by `permit_payload`, which contains the call,
+		/// the deadline and the current permit nonce of `who`. The nonce is incremented with every dispatched
+		/// permit, so a permit can't be replayed.
+		///
+		/// Dispatch fee, calculated from the weight of the dispatch, is paid by `who` to the relayer
+		/// in the fee currency of `who`. The fee is paid and the nonce is incremented even if the call fails.
+		///
+		/// Parameters:
+		/// - `origin`: relayer
+		/// - `who`: signer of the permit, the call is dispatched with signed origin of `who`
+		/// - `call`: call to dispatch
+		/// - `deadline`: last block in which the permit can be dispatched
+		/// - `signature`: signature of the permit payload by `who`
+		///
+		/// Emits `PermitDispatched` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			let info = call.get_dispatch_info();
+			(<T as Config>::WeightInfo::dispatch_signed_permit().saturating_add(info.weight), info.class)
+		})]
+		pub fn dispatch_signed_permit(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			call: Box<<T as Config>::RuntimeCall>,
+			deadline: BlockNumberFor<T>,
+			signature: T::PermitSignature,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::PermitExpired
+			);
+
+			let nonce = PermitNonces::<T>::get(&who);
+			let payload = Self::permit_payload(&who, &call, nonce, deadline);
+			ensure!(signature.verify(&payload[..], &who), Error::<T>::InvalidPermitSignature);
+
+			let info = call.get_dispatch_info();
+			let permit_weight = <T as Config>::WeightInfo::dispatch_signed_permit();
+			let (fee_asset_id, fee_amount) =
+				Self::pay_permit_fee(&who, &relayer, permit_weight.saturating_add(info.weight))?;
+
+			PermitNonces::<T>::insert(&who, nonce.saturating_add(One::one()));
+
+			let result = (*call).dispatch(frame_system::RawOrigin::Signed(who.clone()).into());
+			let call_weight = extract_actual_weight(&result, &info);
+
+			Self::deposit_event(Event::PermitDispatched {
+				who,
+				relayer,
+				nonce,
+				fee_asset_id,
+				fee_amount,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+
+			Ok(Some(permit_weight.saturating_add(call_weight)).into())
+		}
+
+		/// Set fee discount tiers for staked accounts.
+		///
+		/// Each tier is a pair of minimum stake and discount. Account is given the discount of the highest tier
+		/// its stake reaches. The discount is applied to the transaction fee without the tip, when the fees are
+		/// charged by `DiscountedFees`. Empty list disables the discounts.
+		///
+		/// Tiers must be sorted by minimum stake and minimum stakes must be unique.
+		///
+		/// Emits `FeeDiscountsSet` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_discounts())]
+		pub fn set_fee_discounts(
+			origin: OriginFor<T>,
+			discounts: BoundedVec<(BalanceOf<T>, Permill), T::MaxFeeDiscounts>,
+		) -> DispatchResult {
+			T::AcceptedCurrencyOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				discounts.windows(2).all(|w| w[0].0 < w[1].0),
+				Error::<T>::InvalidFeeDiscounts
+			);
+
+			FeeDiscounts::<T>::put(discounts.clone());
+
+			Self::deposit_event(Event::FeeDiscountsSet { discounts });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -555,6 +713,61 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Payload of a signed permit to be signed by `who`.
+	pub fn permit_payload(
+		who: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		nonce: T::Nonce,
+		deadline: BlockNumberFor<T>,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(PERMIT_PREFIX, genesis_hash, who, call, nonce, deadline).encode()
+	}
+
+	/// Transfer fee for dispatch of `weight` from `who` to `relayer` in the fee currency of `who`.
+	fn pay_permit_fee(
+		who: &T::AccountId,
+		relayer: &T::AccountId,
+		weight: Weight,
+	) -> Result<(AssetIdOf<T>, BalanceOf<T>), DispatchError>
+	where
+		BalanceOf<T>: FixedPointOperand,
+	{
+		let fee = <<T as Config>::WeightToFee as frame_support::weights::WeightToFee>::weight_to_fee(&weight);
+		let currency = Self::account_currency(who);
+
+		let amount = if currency == T::NativeAssetId::get() {
+			fee
+		} else {
+			ensure!(
+				T::SwappablePaymentAssetSupport::is_transaction_fee_currency(currency),
+				Error::<T>::UnsupportedCurrency
+			);
+			let price = Self::get_currency_price(currency).ok_or(Error::<T>::FallbackPriceNotFound)?;
+			convert_fee_with_price(fee, price).ok_or(Error::<T>::Overflow)?
+		};
+
+		T::Currencies::transfer(currency, who, relayer, amount)?;
+
+		Ok((currency, amount))
+	}
+
+	/// Fee discount of `who`, given by the highest discount tier the stake of `who` reaches.
+	pub fn fee_discount(who: &T::AccountId) -> Permill {
+		let discounts = FeeDiscounts::<T>::get();
+		if discounts.is_empty() {
+			return Permill::zero();
+		}
+
+		let stake = T::StakedAmount::get(who);
+		discounts
+			.iter()
+			.rev()
+			.find(|(min_stake, _)| stake >= *min_stake)
+			.map(|(_, discount)| *discount)
+			.unwrap_or_default()
+	}
+
 	pub fn account_currency(who: &T::AccountId) -> AssetIdOf<T>
 	where
 		BalanceOf<T>: FixedPointOperand,
@@ -562,6 +775,16 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::get_currency(who).unwrap_or_else(T::NativeAssetId::get)
 	}
 
+	/// Currency in which the transaction fee of `who` is paid.
+	///
+	/// The currency override of the current transaction takes precedence over the account currency.
+	pub fn transaction_fee_currency(who: &T::AccountId) -> AssetIdOf<T>
+	where
+		BalanceOf<T>: FixedPointOperand,
+	{
+		TransactionCurrencyOverride::<T>::get(who).unwrap_or_else(|| Pallet::<T>::account_currency(who))
+	}
+
 	fn get_currency_price(currency: AssetIdOf<T>) -> Option<Price>
 	where
 		BalanceOf<T>: FixedPointOperand,
@@ -653,12 +876,12 @@ where
 			match calls.first() {
 				Some(first_call) => match first_call.is_sub_type() {
 					Some(Call::set_currency { currency }) => *currency,
-					_ => Pallet::<T>::account_currency(who),
+					_ => Pallet::<T>::transaction_fee_currency(who),
 				},
-				_ => Pallet::<T>::account_currency(who),
+				_ => Pallet::<T>::transaction_fee_currency(who),
 			}
 		} else {
-			Pallet::<T>::account_currency(who)
+			Pallet::<T>::transaction_fee_currency(who)
 		};
 
 		let (converted_fee, currency, price) = if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(currency)
@@ -728,12 +951,13 @@ where
 
 		if let Some(paid) = already_withdrawn {
 			// Calculate how much refund we should return
-			let (currency, refund, fee, tip) = match paid {
+			let (currency, refund, fee, tip, non_native_currency) = match paid {
 				PaymentInfo::Native(paid_fee) => (
 					T::NativeAssetId::get().into(),
 					paid_fee.saturating_sub(corrected_fee),
 					corrected_fee.saturating_sub(tip),
 					tip,
+					None,
 				),
 				PaymentInfo::NonNative(paid_fee, currency, price) => {
 					// calculate corrected_fee in the non-native currency
@@ -748,6 +972,7 @@ where
 						refund,
 						converted_corrected_fee.saturating_sub(converted_tip),
 						converted_tip,
+						Some(currency),
 					)
 				}
 			};
@@ -759,12 +984,67 @@ where
 			// deposit the fee
 			DF::deposit_fee(&fee_receiver, currency, fee + tip)
 				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+
+			if let Some(currency) = non_native_currency {
+				T::NonNativeFeeHandler::on_fee_deposited(who, &fee_receiver, currency, fee.into());
+			}
 		}
 
 		Ok(())
 	}
 }
 
+/// Applies fee discount of the payer to the fees charged by `OCT`.
+///
+/// The discount is determined once, when the fee is withdrawn, and the same discount is applied to the corrected fee.
+/// Tip is never discounted.
+pub struct DiscountedFees<T, OCT>(PhantomData<(T, OCT)>);
+
+impl<T, OCT> DiscountedFees<T, OCT>
+where
+	T: Config,
+	OCT: OnChargeTransaction<T>,
+{
+	fn discounted(fee: OCT::Balance, tip: OCT::Balance, discount: Permill) -> OCT::Balance {
+		fee.saturating_sub(discount.mul_floor(fee.saturating_sub(tip)))
+	}
+}
+
+impl<T, OCT> OnChargeTransaction<T> for DiscountedFees<T, OCT>
+where
+	T: Config,
+	OCT: OnChargeTransaction<T>,
+{
+	type Balance = OCT::Balance;
+	type LiquidityInfo = (OCT::LiquidityInfo, Permill);
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let discount = Pallet::<T>::fee_discount(who);
+		let fee = Self::discounted(fee, tip, discount);
+
+		OCT::withdraw_fee(who, call, info, fee, tip).map(|paid| (paid, discount))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(already_withdrawn, discount): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let corrected_fee = Self::discounted(corrected_fee, tip, discount);
+
+		OCT::correct_and_deposit_fee(who, dispatch_info, post_info, corrected_fee, tip, already_withdrawn)
+	}
+}
+
 /// We provide an oracle for the price of all currencies accepted as fee payment.
 /// In the else statement, first we try to get the price from cache, otherwise we calculate it
 /// The price calculation based on onchain-route is mainly used by EVM dry run as in the dry run we dont have storage filled with prices, so calculation is needed
@@ -867,3 +1147,88 @@ impl<T: Config> TryConvert<&<T as frame_system::Config>::RuntimeCall, AssetIdOf<
 		Err(call)
 	}
 }
+
+/// Signed extension which sets the currency in which the fee of the transaction is paid.
+///
+/// The currency overrides the account currency for this transaction only, the account currency stays unchanged.
+/// It must be the native currency or one of the accepted currencies, otherwise the transaction is invalid.
+///
+/// The override is set before the fee is withdrawn, so this extension must precede `ChargeTransactionPayment`
+/// in the signed extensions of the runtime. It is removed after the transaction is dispatched.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct SetFeeCurrency<T: Config + Send + Sync>(pub Option<AssetIdOf<T>>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for SetFeeCurrency<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "SetFeeCurrency({:?})", self.0)
+	}
+}
+
+impl<T: Config + Send + Sync> SetFeeCurrency<T> {
+	pub fn new(currency: Option<AssetIdOf<T>>) -> Self {
+		Self(currency)
+	}
+
+	/// Validate the currency and set it as the fee currency override of `who`.
+	fn set_override(&self, who: &T::AccountId) -> Result<Option<T::AccountId>, TransactionValidityError> {
+		let Some(currency) = self.0 else {
+			return Ok(None);
+		};
+
+		if currency != T::NativeAssetId::get() && !AcceptedCurrencies::<T>::contains_key(currency) {
+			return Err(InvalidTransaction::Payment.into());
+		}
+
+		TransactionCurrencyOverride::<T>::insert(who, currency);
+
+		Ok(Some(who.clone()))
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for SetFeeCurrency<T> {
+	const IDENTIFIER: &'static str = "SetFeeCurrency";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = Option<T::AccountId>;
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		// The override is needed by the fee validation of `ChargeTransactionPayment`.
+		// Changes made during transaction validation are not persisted.
+		self.set_override(who).map(|_| ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.set_override(who)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let Some(Some(who)) = pre {
+			TransactionCurrencyOverride::<T>::remove(who);
+		}
+		Ok(())
+	}
+}
@@ -73,6 +73,8 @@ const MAX_BLOCK_WEIGHT: Weight = Weight::from_parts(1024, 0);
 
 thread_local! {
 	static EXTRINSIC_BASE_WEIGHT: RefCell<Weight> = const { RefCell::new(Weight::zero()) };
+	static STAKES: RefCell<Vec<(AccountId, Balance)>> = const { RefCell::new(vec![]) };
+	static DEPOSITED_NON_NATIVE_FEES: RefCell<Vec<(AccountId, AccountId, AssetId, Balance)>> = const { RefCell::new(vec![]) };
 }
 
 pub struct ExtrinsicBaseWeight;
@@ -178,6 +180,51 @@ impl Config for Test {
 	type EvmPermit = PermitDispatchHandler;
 	type TryCallCurrency<'a> = NoCallCurrency<Test>;
 	type SwappablePaymentAssetSupport = MockedInsufficientAssetSupport;
+	type RuntimeCall = RuntimeCall;
+	type PermitSignature = MultiSignature;
+	type PermitSigner = <MultiSignature as Verify>::Signer;
+	type StakedAmount = StakedAmountMock;
+	type MaxFeeDiscounts = frame_support::traits::ConstU32<3>;
+	type NonNativeFeeHandler = NonNativeFeeHandlerMock;
+}
+
+pub struct NonNativeFeeHandlerMock;
+
+impl NonNativeFeeHandlerMock {
+	pub fn deposited_fees() -> Vec<(AccountId, AccountId, AssetId, Balance)> {
+		DEPOSITED_NON_NATIVE_FEES.with(|v| v.borrow().clone())
+	}
+}
+
+impl OnNonNativeFeeDeposited<AccountId, AssetId, Balance> for NonNativeFeeHandlerMock {
+	fn on_fee_deposited(who: &AccountId, fee_receiver: &AccountId, currency: AssetId, fee: Balance) {
+		DEPOSITED_NON_NATIVE_FEES.with(|v| v.borrow_mut().push((who.clone(), fee_receiver.clone(), currency, fee)));
+	}
+
+	fn on_fee_deposited_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct StakedAmountMock;
+
+impl StakedAmountMock {
+	pub fn set(who: AccountId, amount: Balance) {
+		STAKES.with(|v| v.borrow_mut().push((who, amount)));
+	}
+}
+
+impl GetByKey<AccountId, Balance> for StakedAmountMock {
+	fn get(who: &AccountId) -> Balance {
+		STAKES.with(|v| {
+			v.borrow()
+				.iter()
+				.rev()
+				.find(|(account, _)| account == who)
+				.map(|(_, amount)| *amount)
+				.unwrap_or_default()
+		})
+	}
 }
 
 pub struct MockedInsufficientAssetSupport;
@@ -437,6 +484,7 @@ impl ExtBuilder {
 	}
 	fn set_constants(&self) {
 		EXTRINSIC_BASE_WEIGHT.with(|v| *v.borrow_mut() = self.base_weight);
+		STAKES.with(|v| v.borrow_mut().clear());
 	}
 	pub fn build(self) -> sp_io::TestExternalities {
 		use frame_support::traits::OnInitialize;
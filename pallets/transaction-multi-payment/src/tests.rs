@@ -16,8 +16,13 @@
 // limitations under the License.
 
 pub use crate::{mock::*, Error};
-use crate::{AcceptedCurrencies, AcceptedCurrencyPrice, Event, PaymentInfo, Price};
+use crate::{
+	AcceptedCurrencies, AcceptedCurrencyPrice, DepositAll, DiscountedFees, Event, PaymentInfo, Price, SetFeeCurrency,
+	TransferFees,
+};
 
+use frame_support::traits::ConstU32;
+use frame_support::BoundedVec;
 use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
 	dispatch::{DispatchInfo, PostDispatchInfo},
@@ -28,10 +33,11 @@ use frame_support::{
 use hydradx_traits::evm::InspectEvmAccounts;
 use orml_traits::MultiCurrency;
 use pallet_balances::Call as BalancesCall;
-use pallet_transaction_payment::ChargeTransactionPayment;
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use sp_core::{H256, U256};
 use sp_runtime::traits::ValidateUnsigned;
-use sp_runtime::transaction_validity::TransactionSource;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+use sp_runtime::Permill;
 
 const CALL: &<Test as frame_system::Config>::RuntimeCall =
 	&RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: BOB, value: 69 });
@@ -1481,3 +1487,676 @@ fn dispatch_should_correctly_call_validate_and_dispatch() {
 			assert_eq!(PermitDispatchHandler::last_dispatch_call_data(), expected);
 		});
 }
+
+fn permit_signer() -> (sp_core::sr25519::Pair, AccountId) {
+	use sp_core::Pair;
+	use sp_runtime::traits::IdentifyAccount;
+
+	let pair = sp_core::sr25519::Pair::from_seed(&[7; 32]);
+	let account = sp_runtime::MultiSigner::from(pair.public()).into_account();
+	(pair, account)
+}
+
+fn sign_permit(
+	pair: &sp_core::sr25519::Pair,
+	who: &AccountId,
+	call: &RuntimeCall,
+	deadline: u64,
+) -> sp_runtime::MultiSignature {
+	use sp_core::Pair;
+
+	let payload = PaymentPallet::permit_payload(who, call, PaymentPallet::permit_nonce(who), deadline);
+	pair.sign(&payload).into()
+}
+
+fn permit_fee(call: &RuntimeCall) -> Balance {
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let weight = <() as crate::WeightInfo>::dispatch_signed_permit() + call.get_dispatch_info().weight;
+	weight.ref_time() as Balance
+}
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1, 2, 3] })
+}
+
+#[test]
+fn dispatch_signed_permit_should_dispatch_call_on_behalf_of_signer() {
+	let (pair, signer) = permit_signer();
+	ExtBuilder::default()
+		.account_native_balance(signer.clone(), INITIAL_BALANCE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let call = remark_call();
+			let signature = sign_permit(&pair, &signer, &call, 10);
+			let fee = permit_fee(&call);
+
+			// Act
+			assert_ok!(PaymentPallet::dispatch_signed_permit(
+				RuntimeOrigin::signed(BOB),
+				signer.clone(),
+				Box::new(call),
+				10,
+				signature,
+			));
+
+			// Assert
+			System::assert_has_event(
+				frame_system::Event::Remarked {
+					sender: signer.clone(),
+					hash: <sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash(&[1, 2, 3]),
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::PermitDispatched {
+					who: signer.clone(),
+					relayer: BOB,
+					nonce: 0,
+					fee_asset_id: HDX,
+					fee_amount: fee,
+					result: Ok(()),
+				}
+				.into(),
+			);
+			assert_eq!(PaymentPallet::permit_nonce(&signer), 1);
+			assert_eq!(Currencies::free_balance(HDX, &signer), INITIAL_BALANCE - fee);
+			assert_eq!(Currencies::free_balance(HDX, &BOB), fee);
+		});
+}
+
+#[test]
+fn dispatch_signed_permit_should_pay_fee_in_fee_currency_of_signer() {
+	let (pair, signer) = permit_signer();
+	ExtBuilder::default()
+		.account_native_balance(signer.clone(), INITIAL_BALANCE)
+		.account_tokens(signer.clone(), SUPPORTED_CURRENCY, INITIAL_BALANCE)
+		.with_currencies(vec![(signer.clone(), SUPPORTED_CURRENCY)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let call = remark_call();
+			let signature = sign_permit(&pair, &signer, &call, 10);
+			let fee = sp_runtime::FixedPointNumber::saturating_mul_int(Price::from_float(1.5), permit_fee(&call));
+
+			// Act
+			assert_ok!(PaymentPallet::dispatch_signed_permit(
+				RuntimeOrigin::signed(BOB),
+				signer.clone(),
+				Box::new(call),
+				10,
+				signature,
+			));
+
+			// Assert
+			assert_eq!(Currencies::free_balance(HDX, &signer), INITIAL_BALANCE);
+			assert_eq!(
+				Currencies::free_balance(SUPPORTED_CURRENCY, &signer),
+				INITIAL_BALANCE - fee
+			);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &BOB), fee);
+		});
+}
+
+#[test]
+fn dispatch_signed_permit_should_charge_fee_and_increase_nonce_when_call_fails() {
+	let (pair, signer) = permit_signer();
+	ExtBuilder::default()
+		.account_native_balance(signer.clone(), INITIAL_BALANCE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+				dest: CHARLIE,
+				value: 2 * INITIAL_BALANCE,
+			});
+			let signature = sign_permit(&pair, &signer, &call, 10);
+			let fee = permit_fee(&call);
+
+			// Act
+			assert_ok!(PaymentPallet::dispatch_signed_permit(
+				RuntimeOrigin::signed(BOB),
+				signer.clone(),
+				Box::new(call),
+				10,
+				signature,
+			));
+
+			// Assert
+			assert_eq!(PaymentPallet::permit_nonce(&signer), 1);
+			assert_eq!(Currencies::free_balance(HDX, &BOB), fee);
+			assert_eq!(Currencies::free_balance(HDX, &CHARLIE), 0);
+			assert!(matches!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(RuntimeEvent::PaymentPallet(Event::PermitDispatched {
+					result: Err(_),
+					..
+				}))
+			));
+		});
+}
+
+#[test]
+fn dispatch_signed_permit_should_fail_when_permit_is_replayed() {
+	let (pair, signer) = permit_signer();
+	ExtBuilder::default()
+		.account_native_balance(signer.clone(), INITIAL_BALANCE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let call = remark_call();
+			let signature = sign_permit(&pair, &signer, &call, 10);
+			assert_ok!(PaymentPallet::dispatch_signed_permit(
+				RuntimeOrigin::signed(BOB),
+				signer.clone(),
+				Box::new(call.clone()),
+				10,
+				signature.clone(),
+			));
+
+			// Act & Assert
+			assert_noop!(
+				PaymentPallet::dispatch_signed_permit(
+					RuntimeOrigin::signed(BOB),
+					signer,
+					Box::new(call),
+					10,
+					signature
+				),
+				Error::<Test>::InvalidPermitSignature
+			);
+		});
+}
+
+#[test]
+fn dispatch_signed_permit_should_fail_when_permit_is_not_signed_by_signer() {
+	let (_, signer) = permit_signer();
+	ExtBuilder::default()
+		.account_native_balance(signer.clone(), INITIAL_BALANCE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			use sp_core::Pair;
+			let other_pair = sp_core::sr25519::Pair::from_seed(&[8; 32]);
+			let call = remark_call();
+			let signature = sign_permit(&other_pair, &signer, &call, 10);
+
+			// Act & Assert
+			assert_noop!(
+				PaymentPallet::dispatch_signed_permit(
+					RuntimeOrigin::signed(BOB),
+					signer,
+					Box::new(call),
+					10,
+					signature
+				),
+				Error::<Test>::InvalidPermitSignature
+			);
+		});
+}
+
+#[test]
+fn dispatch_signed_permit_should_fail_when_deadline_has_passed() {
+	let (pair, signer) = permit_signer();
+	ExtBuilder::default()
+		.account_native_balance(signer.clone(), INITIAL_BALANCE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let call = remark_call();
+			let signature = sign_permit(&pair, &signer, &call, 10);
+			System::set_block_number(11);
+
+			// Act & Assert
+			assert_noop!(
+				PaymentPallet::dispatch_signed_permit(
+					RuntimeOrigin::signed(BOB),
+					signer,
+					Box::new(call),
+					10,
+					signature
+				),
+				Error::<Test>::PermitExpired
+			);
+		});
+}
+
+#[test]
+fn dispatch_signed_permit_should_fail_when_signer_cannot_pay_fee() {
+	let (pair, signer) = permit_signer();
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let call = remark_call();
+		let signature = sign_permit(&pair, &signer, &call, 10);
+
+		// Act & Assert
+		assert!(PaymentPallet::dispatch_signed_permit(
+			RuntimeOrigin::signed(BOB),
+			signer.clone(),
+			Box::new(call),
+			10,
+			signature
+		)
+		.is_err());
+		assert_eq!(PaymentPallet::permit_nonce(&signer), 0);
+	});
+}
+
+type DiscountedTransferFees = DiscountedFees<Test, TransferFees<Currencies, DepositAll<Test>, FeeReceiver>>;
+
+fn fee_discounts(discounts: Vec<(Balance, Permill)>) -> BoundedVec<(Balance, Permill), ConstU32<3>> {
+	discounts.try_into().unwrap()
+}
+
+fn set_fee_discounts() {
+	assert_ok!(PaymentPallet::set_fee_discounts(
+		RuntimeOrigin::root(),
+		fee_discounts(vec![
+			(1_000, Permill::from_percent(10)),
+			(10_000, Permill::from_percent(50)),
+		])
+	));
+}
+
+#[test]
+fn set_fee_discounts_should_store_discounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let discounts = fee_discounts(vec![
+			(1_000, Permill::from_percent(10)),
+			(10_000, Permill::from_percent(50)),
+		]);
+
+		// Act
+		assert_ok!(PaymentPallet::set_fee_discounts(
+			RuntimeOrigin::root(),
+			discounts.clone()
+		));
+
+		// Assert
+		assert_eq!(PaymentPallet::fee_discounts(), discounts);
+		expect_events(vec![Event::FeeDiscountsSet { discounts }.into()]);
+	});
+}
+
+#[test]
+fn set_fee_discounts_should_fail_when_discounts_are_not_sorted_by_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::set_fee_discounts(
+				RuntimeOrigin::root(),
+				fee_discounts(vec![
+					(10_000, Permill::from_percent(50)),
+					(1_000, Permill::from_percent(10)),
+				])
+			),
+			Error::<Test>::InvalidFeeDiscounts
+		);
+		assert_noop!(
+			PaymentPallet::set_fee_discounts(
+				RuntimeOrigin::root(),
+				fee_discounts(vec![
+					(1_000, Permill::from_percent(10)),
+					(1_000, Permill::from_percent(50)),
+				])
+			),
+			Error::<Test>::InvalidFeeDiscounts
+		);
+	});
+}
+
+#[test]
+fn set_fee_discounts_should_fail_when_origin_is_not_accepted_currency_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::set_fee_discounts(RuntimeOrigin::signed(ALICE), fee_discounts(vec![])),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fee_discount_should_be_discount_of_highest_reached_tier() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_fee_discounts();
+		StakedAmountMock::set(ALICE, 999);
+		StakedAmountMock::set(BOB, 5_000);
+		StakedAmountMock::set(CHARLIE, 10_000);
+
+		// Act & Assert
+		assert_eq!(PaymentPallet::fee_discount(&ALICE), Permill::zero());
+		assert_eq!(PaymentPallet::fee_discount(&BOB), Permill::from_percent(10));
+		assert_eq!(PaymentPallet::fee_discount(&CHARLIE), Permill::from_percent(50));
+		assert_eq!(PaymentPallet::fee_discount(&DAVE), Permill::zero());
+	});
+}
+
+#[test]
+fn discounted_fee_should_be_withdrawn_and_corrected_when_paid_in_native_currency() {
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_fee_discounts();
+			StakedAmountMock::set(CHARLIE, 10_000);
+			let dispatch_info = info_from_weight(Weight::from_parts(30, 0));
+
+			// Act
+			let paid = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				30,
+				0,
+			)
+			.unwrap();
+
+			// Assert
+			assert_eq!(paid, (Some(PaymentInfo::Native(15)), Permill::from_percent(50)));
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 15);
+
+			// Act
+			assert_ok!(
+				<DiscountedTransferFees as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+					&CHARLIE,
+					&dispatch_info,
+					&default_post_info(),
+					20,
+					0,
+					paid
+				)
+			);
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 10);
+			assert_eq!(Balances::free_balance(FEE_RECEIVER), 10);
+		});
+}
+
+#[test]
+fn discounted_fee_should_not_discount_tip() {
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_fee_discounts();
+			StakedAmountMock::set(CHARLIE, 10_000);
+			let dispatch_info = info_from_weight(Weight::from_parts(30, 0));
+
+			// Act
+			let paid = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				40,
+				10,
+			)
+			.unwrap();
+
+			// Assert
+			assert_eq!(paid.0, Some(PaymentInfo::Native(15 + 10)));
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 25);
+		});
+}
+
+#[test]
+fn discounted_fee_should_be_withdrawn_when_paid_in_non_native_currency() {
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY)])
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY, 10_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_fee_discounts();
+			StakedAmountMock::set(CHARLIE, 10_000);
+			let dispatch_info = info_from_weight(Weight::from_parts(30, 0));
+
+			// Act
+			let paid = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				30,
+				0,
+			)
+			.unwrap();
+
+			// Assert
+			assert_eq!(
+				paid.0,
+				Some(PaymentInfo::NonNative(22, SUPPORTED_CURRENCY, Price::from_float(1.5)))
+			);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10_000 - 22);
+		});
+}
+
+#[test]
+fn discounted_fee_should_be_corrected_with_discount_of_withdrawal_when_stake_changes() {
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_fee_discounts();
+			StakedAmountMock::set(CHARLIE, 10_000);
+			let dispatch_info = info_from_weight(Weight::from_parts(30, 0));
+			let paid = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				30,
+				0,
+			)
+			.unwrap();
+			StakedAmountMock::set(CHARLIE, 0);
+
+			// Act
+			assert_ok!(
+				<DiscountedTransferFees as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+					&CHARLIE,
+					&dispatch_info,
+					&default_post_info(),
+					30,
+					0,
+					paid
+				)
+			);
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 15);
+			assert_eq!(Balances::free_balance(FEE_RECEIVER), 15);
+		});
+}
+
+#[test]
+fn fee_should_not_be_discounted_when_account_has_no_stake() {
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			set_fee_discounts();
+			let dispatch_info = info_from_weight(Weight::from_parts(30, 0));
+
+			// Act
+			let paid = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				30,
+				0,
+			)
+			.unwrap();
+
+			// Assert
+			assert_eq!(paid, (Some(PaymentInfo::Native(30)), Permill::zero()));
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+		});
+}
+
+#[test]
+fn non_native_fee_handler_should_be_called_without_tip_when_fee_is_paid_in_non_native_currency() {
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY)])
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY, 10_000)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let len = 10;
+			let tip = 5;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+			let post_dispatch_info = post_info_from_weight(Weight::from_parts(10, 0));
+			let pre = ChargeTransactionPayment::<Test>::from(tip)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&post_dispatch_info,
+				len,
+				&Ok(())
+			));
+
+			// Assert
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &FeeReceiver::get()), 45);
+			assert_eq!(
+				NonNativeFeeHandlerMock::deposited_fees(),
+				vec![(CHARLIE, FEE_RECEIVER, SUPPORTED_CURRENCY, 38)]
+			);
+		});
+}
+
+#[test]
+fn non_native_fee_handler_should_not_be_called_when_fee_is_paid_in_native_currency() {
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let len = 10;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+			let pre = ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(FEE_RECEIVER), 30);
+			assert!(NonNativeFeeHandlerMock::deposited_fees().is_empty());
+		});
+}
+
+#[test]
+fn set_fee_currency_should_charge_fee_in_given_currency_when_currency_is_accepted() {
+	ExtBuilder::default()
+		.base_weight(5)
+		.with_currencies(vec![(ALICE, SUPPORTED_CURRENCY_WITH_PRICE)])
+		.build()
+		.execute_with(|| {
+			let len = 10;
+			let info = info_from_weight(Weight::from_parts(5, 0));
+
+			let override_pre =
+				SetFeeCurrency::<Test>::new(Some(SUPPORTED_CURRENCY)).pre_dispatch(&ALICE, CALL, &info, len);
+			assert_eq!(override_pre, Ok(Some(ALICE)));
+			assert_eq!(PaymentPallet::tx_fee_currency_override(ALICE), Some(SUPPORTED_CURRENCY));
+
+			let pre = ChargeTransactionPayment::<Test>::from(0).pre_dispatch(&ALICE, CALL, &info, len);
+			assert!(pre.is_ok());
+
+			assert_eq!(
+				Currencies::free_balance(SUPPORTED_CURRENCY, &ALICE),
+				999_999_999_999_970
+			);
+
+			assert_ok!(SetFeeCurrency::<Test>::post_dispatch(
+				Some(override_pre.unwrap()),
+				&info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre.unwrap()),
+				&info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &FEE_RECEIVER), 30);
+			assert_eq!(PaymentPallet::tx_fee_currency_override(ALICE), None);
+			assert_eq!(PaymentPallet::get_currency(ALICE), Some(SUPPORTED_CURRENCY_WITH_PRICE));
+		});
+}
+
+#[test]
+fn set_fee_currency_should_charge_fee_in_account_currency_when_currency_is_not_given() {
+	ExtBuilder::default().base_weight(5).build().execute_with(|| {
+		let len = 10;
+		let info = info_from_weight(Weight::from_parts(5, 0));
+
+		let override_pre = SetFeeCurrency::<Test>::new(None).pre_dispatch(&ALICE, CALL, &info, len);
+		assert_eq!(override_pre, Ok(None));
+
+		let pre = ChargeTransactionPayment::<Test>::from(0).pre_dispatch(&ALICE, CALL, &info, len);
+		assert!(pre.is_ok());
+
+		assert_eq!(Currencies::free_balance(HDX, &ALICE), 999_999_999_999_980);
+		assert_eq!(PaymentPallet::tx_fee_currency_override(ALICE), None);
+	});
+}
+
+#[test]
+fn set_fee_currency_should_be_invalid_when_currency_is_not_accepted() {
+	ExtBuilder::default().base_weight(5).build().execute_with(|| {
+		let len = 10;
+		let info = info_from_weight(Weight::from_parts(5, 0));
+
+		assert_eq!(
+			SetFeeCurrency::<Test>::new(Some(UNSUPPORTED_CURRENCY)).validate(&ALICE, CALL, &info, len),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_eq!(
+			SetFeeCurrency::<Test>::new(Some(UNSUPPORTED_CURRENCY)).pre_dispatch(&ALICE, CALL, &info, len),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_eq!(PaymentPallet::tx_fee_currency_override(ALICE), None);
+	});
+}
+
+#[test]
+fn set_fee_currency_should_allow_native_currency_when_account_currency_is_not_native() {
+	ExtBuilder::default()
+		.base_weight(5)
+		.with_currencies(vec![(ALICE, SUPPORTED_CURRENCY)])
+		.build()
+		.execute_with(|| {
+			let len = 10;
+			let info = info_from_weight(Weight::from_parts(5, 0));
+
+			assert_ok!(SetFeeCurrency::<Test>::new(Some(HDX)).pre_dispatch(&ALICE, CALL, &info, len));
+
+			let pre = ChargeTransactionPayment::<Test>::from(0).pre_dispatch(&ALICE, CALL, &info, len);
+			assert!(pre.is_ok());
+
+			assert_eq!(Currencies::free_balance(HDX, &ALICE), 999_999_999_999_980);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &ALICE), INITIAL_BALANCE);
+		});
+}
@@ -17,6 +17,24 @@ pub trait DepositFee<AccountId, AssetId, Balance> {
 	fn deposit_fee(who: &AccountId, currency: AssetId, amount: Balance) -> DispatchResult;
 }
 
+/// Handler of transaction fees paid in non-native currency.
+pub trait OnNonNativeFeeDeposited<AccountId, AssetId, Balance> {
+	/// Called after `fee` paid by `who` in `currency` has been deposited to `fee_receiver`.
+	/// The tip is not included in the `fee`.
+	fn on_fee_deposited(who: &AccountId, fee_receiver: &AccountId, currency: AssetId, fee: Balance);
+
+	/// Upper bound of the weight consumed by `on_fee_deposited`.
+	fn on_fee_deposited_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Balance> OnNonNativeFeeDeposited<AccountId, AssetId, Balance> for () {
+	fn on_fee_deposited(_who: &AccountId, _fee_receiver: &AccountId, _currency: AssetId, _fee: Balance) {}
+
+	fn on_fee_deposited_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub trait EVMPermit {
 	#![allow(clippy::too_many_arguments)]
 	fn validate_permit(
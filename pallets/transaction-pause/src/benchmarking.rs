@@ -20,24 +20,58 @@
 
 use super::*;
 
+use codec::Encode;
 use frame_benchmarking::benchmarks;
 use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use sp_runtime::traits::One;
 
 benchmarks! {
 
 	pause_transaction {
 		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
 	}: {
-		assert_ok!(crate::Pallet::<T>::pause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec()));
+		assert_ok!(crate::Pallet::<T>::pause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec(), None));
 	}
 
 	unpause_transaction {
 		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
-		crate::Pallet::<T>::pause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec())?;
+		crate::Pallet::<T>::pause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec(), None)?;
 		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
 	}:{
 		assert_ok!(crate::Pallet::<T>::unpause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec()));
 	}
+
+	pause_pallet {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let expires_at = frame_system::Pallet::<T>::block_number() + BlockNumberFor::<T>::one();
+	}: {
+		assert_ok!(crate::Pallet::<T>::pause_pallet(origin, b"Balances".to_vec(), Some(expires_at)));
+	}
+
+	unpause_pallet {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::pause_pallet(origin, b"Balances".to_vec(), None)?;
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+	}:{
+		assert_ok!(crate::Pallet::<T>::unpause_pallet(origin, b"Balances".to_vec()));
+	}
+
+	expire_pauses {
+		let n in 0 .. T::MaxExpiringPausesPerBlock::get();
+
+		let expires_at = frame_system::Pallet::<T>::block_number() + BlockNumberFor::<T>::one();
+		for i in 0..n {
+			let pallet_name = BoundedName::try_from(i.encode()).unwrap();
+			PausedPallets::<T>::insert(&pallet_name, Some(expires_at));
+			PauseExpirations::<T>::try_append(expires_at, (pallet_name, None::<BoundedName>)).unwrap();
+		}
+	}: {
+		crate::Pallet::<T>::on_initialize(expires_at);
+	}
+	verify {
+		assert_eq!(PausedPallets::<T>::iter().count(), 0);
+	}
 }
 
 #[cfg(test)]
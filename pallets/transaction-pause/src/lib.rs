@@ -42,7 +42,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -52,6 +52,9 @@ pub mod pallet {
 	pub const MAX_STR_LENGTH: u32 = 40;
 	pub type BoundedName = BoundedVec<u8, ConstU32<MAX_STR_LENGTH>>;
 
+	/// Paused pallet name and function name. `None` function name pauses the whole pallet.
+	pub type PauseEntry = (BoundedName, Option<BoundedName>);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -59,6 +62,10 @@ pub mod pallet {
 		/// The origin which may set the transaction pause filter.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Max number of pauses which can expire in one block.
+		#[pallet::constant]
+		type MaxExpiringPausesPerBlock: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -71,6 +78,10 @@ pub mod pallet {
 		InvalidCharacter,
 		/// pallet name or function name is too long
 		NameTooLong,
+		/// expiry block must be in the future
+		InvalidExpiry,
+		/// max number of pauses expiring in the block was reached
+		TooManyExpiringPauses,
 	}
 
 	#[pallet::event]
@@ -80,49 +91,113 @@ pub mod pallet {
 		TransactionPaused {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
+			expires_at: Option<BlockNumberFor<T>>,
 		},
 		/// Unpaused transaction
 		TransactionUnpaused {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
 		},
+		/// Paused all transactions of the pallet
+		PalletPaused {
+			pallet_name_bytes: Vec<u8>,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// Unpaused all transactions of the pallet
+		PalletUnpaused { pallet_name_bytes: Vec<u8> },
+		/// Pause lapsed at its expiry block. `function_name_bytes` is `None` for pause of the whole pallet.
+		PauseExpired {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+		},
 	}
 
 	/// The paused transaction map
 	///
-	/// map (PalletNameBytes, FunctionNameBytes) => Option<()>
+	/// map (PalletNameBytes, FunctionNameBytes) => Option<ExpiryBlock>
 	#[pallet::storage]
 	#[pallet::getter(fn paused_transactions)]
-	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (BoundedName, BoundedName), (), OptionQuery>;
+	pub type PausedTransactions<T: Config> =
+		StorageMap<_, Twox64Concat, (BoundedName, BoundedName), Option<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The paused pallet map
+	///
+	/// map PalletNameBytes => Option<ExpiryBlock>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_pallets)]
+	pub type PausedPallets<T: Config> =
+		StorageMap<_, Twox64Concat, BoundedName, Option<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Pauses expiring in the block.
+	///
+	/// Entries are not removed on unpause or re-pause, pause is lifted only if its expiry still matches.
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expirations)]
+	pub type PauseExpirations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<PauseEntry, T::MaxExpiringPausesPerBlock>,
+		ValueQuery,
+	>;
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let expiring = PauseExpirations::<T>::take(n);
+			if expiring.is_empty() {
+				return T::WeightInfo::expire_pauses(0);
+			}
+
+			let count = expiring.len() as u32;
+			for (pallet_name, function_name) in expiring {
+				Self::expire_pause(n, pallet_name, function_name);
+			}
+
+			T::WeightInfo::expire_pauses(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Pause a transaction.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `UpdateOrigin`.
+		/// - `pallet_name`: Name of the pallet.
+		/// - `function_name`: Name of the paused call.
+		/// - `expires_at`: Block in which the pause is lifted automatically. Pause doesn't expire if `None`.
+		///
+		/// Pausing already paused transaction updates its expiry.
+		///
+		/// Emits `TransactionPaused` event when successful.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::pause_transaction())]
-		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
+		pub fn pause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 
 			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
 			let function_name_b = BoundedName::try_from(function_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
 
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
-
-			PausedTransactions::<T>::mutate_exists((pallet_name_b, function_name_b), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused {
-						pallet_name_bytes: pallet_name,
-						function_name_bytes: function_name,
-					});
-				}
+			Self::ensure_pausable(&pallet_name)?;
+
+			let key = (pallet_name_b.clone(), function_name_b.clone());
+			if PausedTransactions::<T>::get(&key) == Some(expires_at) {
+				return Ok(());
+			}
+
+			Self::schedule_expiry(expires_at, (pallet_name_b, Some(function_name_b)))?;
+			PausedTransactions::<T>::insert(key, expires_at);
+
+			Self::deposit_event(Event::TransactionPaused {
+				pallet_name_bytes: pallet_name,
+				function_name_bytes: function_name,
+				expires_at,
 			});
 			Ok(())
 		}
@@ -147,6 +222,129 @@ pub mod pallet {
 			};
 			Ok(())
 		}
+
+		/// Pause all transactions of a pallet.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `UpdateOrigin`.
+		/// - `pallet_name`: Name of the pallet.
+		/// - `expires_at`: Block in which the pause is lifted automatically. Pause doesn't expire if `None`.
+		///
+		/// Pausing already paused pallet updates its expiry. Pauses of single transactions of the pallet
+		/// are kept and stay in force when the pallet is unpaused.
+		///
+		/// Emits `PalletPaused` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause_pallet())]
+		pub fn pause_pallet(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
+
+			Self::ensure_pausable(&pallet_name)?;
+
+			if PausedPallets::<T>::get(&pallet_name_b) == Some(expires_at) {
+				return Ok(());
+			}
+
+			Self::schedule_expiry(expires_at, (pallet_name_b.clone(), None))?;
+			PausedPallets::<T>::insert(pallet_name_b, expires_at);
+
+			Self::deposit_event(Event::PalletPaused {
+				pallet_name_bytes: pallet_name,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Unpause all transactions of a pallet paused by `pause_pallet`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `UpdateOrigin`.
+		/// - `pallet_name`: Name of the pallet.
+		///
+		/// Emits `PalletUnpaused` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unpause_pallet())]
+		pub fn unpause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
+
+			if PausedPallets::<T>::take(&pallet_name_b).is_some() {
+				Self::deposit_event(Event::PalletUnpaused {
+					pallet_name_bytes: pallet_name,
+				});
+			};
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	// not allowed to pause calls of this pallet to ensure safe
+	fn ensure_pausable(pallet_name: &[u8]) -> DispatchResult {
+		let pallet_name_string = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			pallet_name_string != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotPause
+		);
+		Ok(())
+	}
+
+	fn schedule_expiry(expires_at: Option<BlockNumberFor<T>>, entry: PauseEntry) -> DispatchResult {
+		let Some(expires_at) = expires_at else {
+			return Ok(());
+		};
+
+		ensure!(
+			expires_at > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::InvalidExpiry
+		);
+
+		PauseExpirations::<T>::try_mutate(expires_at, |expiring| -> DispatchResult {
+			if expiring.contains(&entry) {
+				return Ok(());
+			}
+			expiring
+				.try_push(entry)
+				.map_err(|_| Error::<T>::TooManyExpiringPauses)?;
+			Ok(())
+		})
+	}
+
+	fn expire_pause(n: BlockNumberFor<T>, pallet_name: BoundedName, function_name: Option<BoundedName>) {
+		let expired = match function_name.clone() {
+			Some(function_name) => {
+				PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name), |maybe_paused| {
+					if *maybe_paused == Some(Some(n)) {
+						*maybe_paused = None;
+						true
+					} else {
+						false
+					}
+				})
+			}
+			None => PausedPallets::<T>::mutate_exists(pallet_name.clone(), |maybe_paused| {
+				if *maybe_paused == Some(Some(n)) {
+					*maybe_paused = None;
+					true
+				} else {
+					false
+				}
+			}),
+		};
+
+		if expired {
+			Self::deposit_event(Event::PauseExpired {
+				pallet_name_bytes: pallet_name.into_inner(),
+				function_name_bytes: function_name.map(|f| f.into_inner()),
+			});
+		}
 	}
 }
 
@@ -168,6 +366,8 @@ where
 		}
 
 		// it's safe to call unwrap here thanks to the test above
-		PausedTransactions::<T>::contains_key((pallet_name_b.unwrap_or_default(), function_name_b.unwrap_or_default()))
+		let pallet_name_b = pallet_name_b.unwrap_or_default();
+		PausedPallets::<T>::contains_key(&pallet_name_b)
+			|| PausedTransactions::<T>::contains_key((pallet_name_b, function_name_b.unwrap_or_default()))
 	}
 }
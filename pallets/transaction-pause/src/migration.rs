@@ -35,6 +35,10 @@ pub mod v0 {
 pub mod v1 {
 	use super::*;
 
+	#[storage_alias]
+	pub type PausedTransactions<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, (BoundedName, BoundedName), (), OptionQuery>;
+
 	pub struct Migration<T>(PhantomData<T>);
 
 	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
@@ -63,7 +67,7 @@ pub mod v1 {
 
 				match (pallet_name_b, function_name_b) {
 					(Ok(pallet), Ok(function)) => {
-						crate::PausedTransactions::<T>::insert((pallet, function), ());
+						PausedTransactions::<T>::insert((pallet, function), ());
 						weight.saturating_accrue(T::DbWeight::get().writes(1));
 					}
 					_ => log::info!(
@@ -85,7 +89,7 @@ pub mod v1 {
 
 			let previous_state = <Vec<(Vec<u8>, Vec<u8>)> as codec::Decode>::decode(&mut state.as_slice()).unwrap();
 
-			let new_state = crate::PausedTransactions::<T>::iter_keys()
+			let new_state = PausedTransactions::<T>::iter_keys()
 				.map(|v| (v.0.into_inner(), v.1.into_inner()))
 				.collect::<Vec<(Vec<u8>, Vec<u8>)>>();
 
@@ -103,6 +107,68 @@ pub mod v1 {
 	}
 }
 
+/// Adds optional expiry block to the paused transactions.
+pub mod v2 {
+	use super::*;
+
+	const TARGET: &str = "runtime::transaction-pause::migration::v2";
+
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let keys = crate::PausedTransactions::<T>::iter_keys().collect::<Vec<_>>();
+
+			log::info!(target: TARGET, "Transaction pause migration: PRE checks successful!");
+
+			Ok(keys.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::info!(target: TARGET, "Migration to v2 already applied");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v2 for Transaction pause");
+
+			let mut count: u64 = 0;
+			crate::PausedTransactions::<T>::translate_values::<(), _>(|_| {
+				count = count.saturating_add(1);
+				// Pauses set before the upgrade don't expire.
+				Some(None)
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "Unexpected storage version.");
+
+			let previous_keys = <Vec<(BoundedName, BoundedName)> as codec::Decode>::decode(&mut state.as_slice())
+				.map_err(|_| "Failed to decode state")?;
+
+			for key in previous_keys.iter() {
+				assert_eq!(
+					crate::PausedTransactions::<T>::get(key),
+					Some(None),
+					"Paused transaction not migrated"
+				);
+			}
+
+			log::info!(target: TARGET, "Transaction pause migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -133,14 +199,14 @@ mod test {
 			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
 
 			assert_eq!(
-				crate::PausedTransactions::<T>::get((
+				v1::PausedTransactions::<T>::get((
 					BoundedName::try_from("first pallet".as_bytes().to_vec()).unwrap(),
 					BoundedName::try_from("first function".as_bytes().to_vec()).unwrap()
 				)),
 				Some(())
 			);
 			assert_eq!(
-				crate::PausedTransactions::<T>::get((
+				v1::PausedTransactions::<T>::get((
 					BoundedName::try_from("second pallet".as_bytes().to_vec()).unwrap(),
 					BoundedName::try_from("second function".as_bytes().to_vec()).unwrap()
 				)),
@@ -148,4 +214,24 @@ mod test {
 			);
 		});
 	}
+
+	#[test]
+	fn migration_to_v2_works() {
+		ExtBuilder.build().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			let key = (
+				BoundedName::try_from("first pallet".as_bytes().to_vec()).unwrap(),
+				BoundedName::try_from("first function".as_bytes().to_vec()).unwrap(),
+			);
+			v1::PausedTransactions::<T>::insert(key.clone(), ());
+
+			let state = v2::Migration::<T>::pre_upgrade().unwrap();
+			let _w = v2::Migration::<T>::on_runtime_upgrade();
+			v2::Migration::<T>::post_upgrade(state).unwrap();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+			assert_eq!(crate::PausedTransactions::<T>::get(key), Some(None));
+		});
+	}
 }
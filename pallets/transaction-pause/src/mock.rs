@@ -121,6 +121,7 @@ ord_parameter_types! {
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type MaxExpiringPausesPerBlock = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use mock::{RuntimeEvent as Event, *};
 use sp_runtime::traits::BadOrigin;
 
@@ -43,7 +43,12 @@ fn pause_transaction_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			TransactionPause::pause_transaction(RuntimeOrigin::signed(5), b"Balances".to_vec(), b"transfer".to_vec()),
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(5),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				None
+			),
 			BadOrigin
 		);
 
@@ -54,22 +59,25 @@ fn pause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused {
 			pallet_name_bytes: b"Balances".to_vec(),
 			function_name_bytes: b"transfer".to_vec(),
+			expires_at: None,
 		}));
 		assert_eq!(
 			TransactionPause::paused_transactions((balances_b_str, transfer_b_str)),
-			Some(())
+			Some(None)
 		);
 
 		assert_noop!(
 			TransactionPause::pause_transaction(
 				RuntimeOrigin::signed(1),
 				b"TransactionPause".to_vec(),
-				b"pause_transaction".to_vec()
+				b"pause_transaction".to_vec(),
+				None
 			),
 			Error::<Runtime>::CannotPause
 		);
@@ -77,21 +85,24 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				RuntimeOrigin::signed(1),
 				b"TransactionPause".to_vec(),
-				b"some_other_call".to_vec()
+				b"some_other_call".to_vec(),
+				None
 			),
 			Error::<Runtime>::CannotPause
 		);
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"OtherPallet".to_vec(),
-			b"pause_transaction".to_vec()
+			b"pause_transaction".to_vec(),
+			None
 		));
 
 		assert_noop!(
 			TransactionPause::pause_transaction(
 				RuntimeOrigin::signed(1),
 				vec![1u8; (MAX_STR_LENGTH + 1) as usize],
-				b"transfer".to_vec()
+				b"transfer".to_vec(),
+				None
 			),
 			Error::<Runtime>::NameTooLong
 		);
@@ -101,6 +112,7 @@ fn pause_transaction_work() {
 				RuntimeOrigin::signed(1),
 				b"Balances".to_vec(),
 				vec![1u8; (MAX_STR_LENGTH + 1) as usize],
+				None
 			),
 			Error::<Runtime>::NameTooLong
 		);
@@ -118,11 +130,12 @@ fn unpause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert_eq!(
 			TransactionPause::paused_transactions((balances_b_str.clone(), transfer_b_str.clone())),
-			Some(())
+			Some(None)
 		);
 
 		assert_noop!(
@@ -172,12 +185,14 @@ fn paused_transaction_filter_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer_allow_death".to_vec()
+			b"transfer_allow_death".to_vec(),
+			None
 		));
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Tokens".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
 		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
@@ -195,3 +210,199 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+#[test]
+fn pause_pallet_work() {
+	ExtBuilder.build().execute_with(|| {
+		let balances_b_str = BoundedName::try_from(b"Balances".to_vec()).unwrap();
+
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_pallet(RuntimeOrigin::signed(5), b"Balances".to_vec(), None),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_pallet(RuntimeOrigin::signed(1), b"TransactionPause".to_vec(), None),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_pallet(RuntimeOrigin::signed(1), vec![1u8; (MAX_STR_LENGTH + 1) as usize], None),
+			Error::<Runtime>::NameTooLong
+		);
+
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			None
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PalletPaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			expires_at: None,
+		}));
+		assert_eq!(TransactionPause::paused_pallets(balances_b_str), Some(None));
+	});
+}
+
+#[test]
+fn unpause_pallet_work() {
+	ExtBuilder.build().execute_with(|| {
+		let balances_b_str = BoundedName::try_from(b"Balances".to_vec()).unwrap();
+
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			None
+		));
+
+		assert_noop!(
+			TransactionPause::unpause_pallet(RuntimeOrigin::signed(5), b"Balances".to_vec()),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::unpause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PalletUnpaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+		}));
+		assert_eq!(TransactionPause::paused_pallets(balances_b_str), None);
+	});
+}
+
+#[test]
+fn paused_pallet_filter_work() {
+	ExtBuilder.build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			None
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		// single transaction pause stays in force when the pallet is unpaused
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec(),
+			None
+		));
+		assert_ok!(TransactionPause::unpause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec()
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_should_fail_when_expiry_is_not_in_future() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(1),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				Some(10)
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			TransactionPause::pause_pallet(RuntimeOrigin::signed(1), b"Balances".to_vec(), Some(9)),
+			Error::<Runtime>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn pause_should_be_lifted_when_it_expires() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			Some(5)
+		));
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			Some(5)
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		System::set_block_number(4);
+		TransactionPause::on_initialize(4);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		System::set_block_number(5);
+		TransactionPause::on_initialize(5);
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		System::assert_has_event(Event::TransactionPause(crate::Event::PauseExpired {
+			pallet_name_bytes: b"Tokens".to_vec(),
+			function_name_bytes: Some(b"transfer".to_vec()),
+		}));
+		System::assert_has_event(Event::TransactionPause(crate::Event::PauseExpired {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: None,
+		}));
+		assert_eq!(TransactionPause::pause_expirations(5).len(), 0);
+	});
+}
+
+#[test]
+fn pause_should_not_be_lifted_when_expiry_was_updated() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			Some(5)
+		));
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			None
+		));
+
+		System::set_block_number(5);
+		TransactionPause::on_initialize(5);
+
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_should_fail_when_too_many_pauses_expire_in_block() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			Some(5)
+		));
+		assert_ok!(TransactionPause::pause_pallet(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			Some(5)
+		));
+
+		assert_noop!(
+			TransactionPause::pause_pallet(RuntimeOrigin::signed(1), b"OtherPallet".to_vec(), Some(5)),
+			Error::<Runtime>::TooManyExpiringPauses
+		);
+	});
+}
@@ -49,30 +49,78 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause_transaction() -> Weight;
 	fn unpause_transaction() -> Weight;
+	fn pause_pallet() -> Weight;
+	fn unpause_pallet() -> Weight;
+	fn expire_pauses(n: u32, ) -> Weight;
 }
 
 /// Weights for module_transaction_pause using the Acala node and recommended hardware.
 impl WeightInfo for () {
 	/// Storage: `TransactionPause::PausedTransactions` (r:1 w:1)
-	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PauseExpirations` (r:1 w:1)
+	/// Proof: `TransactionPause::PauseExpirations` (`max_values`: None, `max_size`: Some(1673), added: 4148, mode: `MaxEncodedLen`)
 	fn pause_transaction() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3555`
-		// Minimum execution time: 10_654_000 picoseconds.
-		Weight::from_parts(11_047_000, 3555)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1774`
+		//  Estimated: `5138`
+		// Minimum execution time: 18_912_000 picoseconds.
+		Weight::from_parts(19_436_000, 5138)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `TransactionPause::PausedTransactions` (r:1 w:1)
-	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
 	fn unpause_transaction() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `160`
-		//  Estimated: `3555`
+		//  Estimated: `3560`
 		// Minimum execution time: 12_775_000 picoseconds.
-		Weight::from_parts(13_283_000, 3555)
+		Weight::from_parts(13_283_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TransactionPause::PausedPallets` (r:1 w:1)
+	/// Proof: `TransactionPause::PausedPallets` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PauseExpirations` (r:1 w:1)
+	/// Proof: `TransactionPause::PauseExpirations` (`max_values`: None, `max_size`: Some(1673), added: 4148, mode: `MaxEncodedLen`)
+	fn pause_pallet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1774`
+		//  Estimated: `5138`
+		// Minimum execution time: 18_104_000 picoseconds.
+		Weight::from_parts(18_630_000, 5138)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TransactionPause::PausedPallets` (r:1 w:1)
+	/// Proof: `TransactionPause::PausedPallets` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	fn unpause_pallet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `124`
+		//  Estimated: `3519`
+		// Minimum execution time: 12_206_000 picoseconds.
+		Weight::from_parts(12_631_000, 3519)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TransactionPause::PauseExpirations` (r:1 w:1)
+	/// Proof: `TransactionPause::PauseExpirations` (`max_values`: None, `max_size`: Some(1673), added: 4148, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PausedTransactions` (r:20 w:20)
+	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 20]`.
+	fn expire_pauses(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (170 ±0)`
+		//  Estimated: `5138 + n * (2570 ±0)`
+		// Minimum execution time: 4_615_000 picoseconds.
+		Weight::from_parts(5_127_000, 5138)
+			// Standard Error: 3_811
+			.saturating_add(Weight::from_parts(7_932_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2570).saturating_mul(n.into()))
 	}
 }
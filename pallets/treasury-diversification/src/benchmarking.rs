@@ -0,0 +1,108 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{EnsureOrigin, Hooks};
+use frame_support::BoundedVec;
+use orml_traits::MultiCurrencyExtended;
+use sp_std::vec;
+
+const ONE: Balance = 1_000_000_000_000;
+const TARGET_ASSET: u32 = 1_000;
+const SWEPT_ASSET: u32 = 2_000;
+
+fn set_max_sweep_assets<T: Config>() -> BoundedVec<T::AssetId, T::MaxSweepAssets>
+where
+	T::AssetId: From<u32>,
+{
+	(0..T::MaxSweepAssets::get())
+		.map(|i| T::AssetId::from(SWEPT_ASSET + i))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+benchmarks! {
+	where_clause {  where
+		T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+	}
+
+	set_target_allocation{
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		SweepAssets::<T>::put(set_max_sweep_assets::<T>());
+
+		let max_targets = T::MaxTargets::get();
+		let share = Permill::one().deconstruct() / max_targets;
+		let mut targets: Vec<(T::AssetId, Permill)> = (0..max_targets)
+			.map(|i| (T::AssetId::from(TARGET_ASSET + i), Permill::from_parts(share)))
+			.collect();
+		targets[0].1 = Permill::from_parts(Permill::one().deconstruct() - share * (max_targets - 1));
+		let targets: BoundedVec<(T::AssetId, Permill), T::MaxTargets> = targets.try_into().unwrap();
+	}: _<T::RuntimeOrigin>(origin, targets.clone())
+	verify {
+		assert_eq!(Pallet::<T>::target_allocation(), targets);
+	}
+
+	set_sweep_assets{
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let targets: BoundedVec<(T::AssetId, Permill), T::MaxTargets> =
+			vec![(T::AssetId::from(TARGET_ASSET), Permill::one())].try_into().unwrap();
+		TargetAllocation::<T>::put(targets);
+
+		let assets = set_max_sweep_assets::<T>();
+	}: _<T::RuntimeOrigin>(origin, assets.clone())
+	verify {
+		assert_eq!(Pallet::<T>::sweep_assets(), assets);
+	}
+
+	set_paused{
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, true)
+	verify {
+		assert!(Pallet::<T>::paused());
+	}
+
+	sweep{
+		let n in 0 .. T::MaxSweepAssets::get();
+
+		let targets: BoundedVec<(T::AssetId, Permill), T::MaxTargets> =
+			vec![(T::AssetId::from(TARGET_ASSET), Permill::one())].try_into().unwrap();
+		TargetAllocation::<T>::put(targets);
+
+		let assets = set_max_sweep_assets::<T>();
+		let treasury = T::TreasuryAccount::get();
+		for asset_id in assets.iter().take(n as usize) {
+			T::Currency::update_balance(*asset_id, &treasury, (1_000 * ONE) as i128)?;
+		}
+		SweepAssets::<T>::put(assets);
+
+		let block = T::SweepPeriod::get();
+	}: {
+		Pallet::<T>::on_initialize(block);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
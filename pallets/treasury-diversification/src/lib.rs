@@ -0,0 +1,353 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Treasury diversification pallet
+//!
+//! Automated diversification of the fee revenue collected by the treasury.
+//!
+//! ## Overview
+//!
+//! Governance sets a target allocation - a list of assets with their shares, which must add up to 100% - and
+//! a list of non-native assets to sweep from the treasury account.
+//!
+//! Every `SweepPeriod` blocks, free balance of each swept asset held by the treasury is split according to
+//! the target allocation and a DCA sell is scheduled by `DcaScheduler` for every part. The swept balance is
+//! reserved by the DCA schedules, so it is not swept again by the next sweep.
+//!
+//! A sell which can't be scheduled (e.g. the amount is below DCA minimal budget) is skipped and
+//! `SellSchedulingFailed` event is emitted. Balance left in the treasury is swept again in the next sweep.
+//!
+//! Sweeps can be paused and resumed by governance.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_target_allocation` - Sets assets and shares the treasury fee revenue is diversified into.
+//! * `set_sweep_assets` - Sets assets swept from the treasury account.
+//! * `set_paused` - Pauses or resumes the sweeps.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use frame_support::ensure;
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
+use frame_support::storage::with_transaction;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::Zero;
+use sp_runtime::{DispatchError, PerThing, Permill, TransactionOutcome};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+pub use weights::WeightInfo;
+
+pub type Balance = u128;
+
+/// Schedules DCA sells of the swept assets.
+pub trait DcaScheduler<AccountId, AssetId> {
+	/// Schedule a sell of `amount` of `asset_in` for `asset_out`. `amount` is taken from `who`.
+	fn schedule_sell(who: &AccountId, asset_in: AssetId, asset_out: AssetId, amount: Balance) -> DispatchResult;
+
+	/// Weight of scheduling one sell.
+	fn schedule_sell_weight() -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin able to set the target allocation, swept assets and pause the sweeps.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Asset id type.
+		type AssetId: Member + Parameter + Copy + MaybeSerializeDeserialize + MaxEncodedLen;
+
+		/// Multi currency mechanism.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Schedules DCA sells of the swept assets.
+		type DcaScheduler: DcaScheduler<Self::AccountId, Self::AssetId>;
+
+		/// Account the fee revenue is swept from.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Native asset id. Native asset can't be swept.
+		#[pallet::constant]
+		type NativeAssetId: Get<Self::AssetId>;
+
+		/// Number of blocks between two sweeps.
+		#[pallet::constant]
+		type SweepPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Max number of assets in the target allocation.
+		#[pallet::constant]
+		type MaxTargets: Get<u32>;
+
+		/// Max number of swept assets.
+		#[pallet::constant]
+		type MaxSweepAssets: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Assets and their shares the swept fee revenue is diversified into.
+	#[pallet::storage]
+	#[pallet::getter(fn target_allocation)]
+	pub(super) type TargetAllocation<T: Config> =
+		StorageValue<_, BoundedVec<(T::AssetId, Permill), T::MaxTargets>, ValueQuery>;
+
+	/// Assets swept from the treasury account.
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_assets)]
+	pub(super) type SweepAssets<T: Config> = StorageValue<_, BoundedVec<T::AssetId, T::MaxSweepAssets>, ValueQuery>;
+
+	/// Sweeps are not executed when `true`.
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub(super) type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Target allocation was set.
+		TargetAllocationSet {
+			targets: BoundedVec<(T::AssetId, Permill), T::MaxTargets>,
+		},
+		/// Swept assets were set.
+		SweepAssetsSet {
+			assets: BoundedVec<T::AssetId, T::MaxSweepAssets>,
+		},
+		/// Sweeps were paused or resumed.
+		PausedSet { paused: bool },
+		/// DCA sell of swept asset was scheduled.
+		SellScheduled {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount: Balance,
+		},
+		/// DCA sell of swept asset couldn't be scheduled.
+		SellSchedulingFailed {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount: Balance,
+			error: DispatchError,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Shares of the target allocation don't add up to 100%.
+		InvalidTargetShares,
+		/// Asset is listed more than once.
+		DuplicateAsset,
+		/// Native asset can't be swept.
+		NativeAssetSwept,
+		/// Asset can't be both swept and a target of the allocation.
+		TargetAssetSwept,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let period = T::SweepPeriod::get();
+			if period.is_zero() || !(n % period).is_zero() {
+				return Weight::zero();
+			}
+
+			if Self::paused() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let scheduled = Self::sweep();
+
+			T::WeightInfo::sweep(scheduled)
+				.saturating_add(T::DcaScheduler::schedule_sell_weight().saturating_mul(scheduled.into()))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set assets and shares the treasury fee revenue is diversified into.
+		///
+		/// Shares must add up to 100% unless `targets` is empty, which disables the sweeps.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `AuthorityOrigin`.
+		/// - `targets`: Target assets and their shares.
+		///
+		/// Emits `TargetAllocationSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_target_allocation())]
+		pub fn set_target_allocation(
+			origin: OriginFor<T>,
+			targets: BoundedVec<(T::AssetId, Permill), T::MaxTargets>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if !targets.is_empty() {
+				let total = targets
+					.iter()
+					.fold(0u32, |acc, (_, share)| acc.saturating_add(share.deconstruct()));
+				ensure!(total == Permill::one().deconstruct(), Error::<T>::InvalidTargetShares);
+			}
+
+			let target_assets: Vec<T::AssetId> = targets.iter().map(|(asset_id, _)| *asset_id).collect();
+			Self::ensure_unique(&target_assets)?;
+
+			let sweep_assets = Self::sweep_assets();
+			ensure!(
+				!target_assets.iter().any(|asset_id| sweep_assets.contains(asset_id)),
+				Error::<T>::TargetAssetSwept
+			);
+
+			TargetAllocation::<T>::put(&targets);
+
+			Self::deposit_event(Event::TargetAllocationSet { targets });
+
+			Ok(())
+		}
+
+		/// Set non-native assets swept from the treasury account.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `AuthorityOrigin`.
+		/// - `assets`: Swept assets.
+		///
+		/// Emits `SweepAssetsSet` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sweep_assets())]
+		pub fn set_sweep_assets(
+			origin: OriginFor<T>,
+			assets: BoundedVec<T::AssetId, T::MaxSweepAssets>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(!assets.contains(&T::NativeAssetId::get()), Error::<T>::NativeAssetSwept);
+			Self::ensure_unique(&assets)?;
+
+			let targets = Self::target_allocation();
+			ensure!(
+				!targets.iter().any(|(asset_id, _)| assets.contains(asset_id)),
+				Error::<T>::TargetAssetSwept
+			);
+
+			SweepAssets::<T>::put(&assets);
+
+			Self::deposit_event(Event::SweepAssetsSet { assets });
+
+			Ok(())
+		}
+
+		/// Pause or resume the sweeps.
+		///
+		/// Already scheduled DCA sells are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `AuthorityOrigin`.
+		/// - `paused`: `true` to pause, `false` to resume.
+		///
+		/// Emits `PausedSet` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_paused())]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Paused::<T>::put(paused);
+
+			Self::deposit_event(Event::PausedSet { paused });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Schedules DCA sells of the swept assets held by the treasury according to the target allocation.
+	///
+	/// Returns number of sells that were attempted to be scheduled.
+	fn sweep() -> u32 {
+		let targets = Self::target_allocation();
+		if targets.is_empty() {
+			return 0;
+		}
+
+		let treasury = T::TreasuryAccount::get();
+		let mut scheduled: u32 = 0;
+
+		for asset_in in Self::sweep_assets() {
+			let balance = T::Currency::free_balance(asset_in, &treasury);
+			if balance.is_zero() {
+				continue;
+			}
+
+			for (asset_out, share) in targets.iter() {
+				let amount = share.mul_floor(balance);
+				if amount.is_zero() {
+					continue;
+				}
+
+				scheduled = scheduled.saturating_add(1);
+
+				let result = with_transaction(|| {
+					match T::DcaScheduler::schedule_sell(&treasury, asset_in, *asset_out, amount) {
+						Ok(()) => TransactionOutcome::Commit(Ok(())),
+						Err(e) => TransactionOutcome::Rollback(Err(e)),
+					}
+				});
+
+				match result {
+					Ok(()) => Self::deposit_event(Event::SellScheduled {
+						asset_in,
+						asset_out: *asset_out,
+						amount,
+					}),
+					Err(error) => Self::deposit_event(Event::SellSchedulingFailed {
+						asset_in,
+						asset_out: *asset_out,
+						amount,
+						error,
+					}),
+				}
+			}
+		}
+
+		scheduled
+	}
+
+	fn ensure_unique(assets: &[T::AssetId]) -> DispatchResult {
+		for (idx, asset_id) in assets.iter().enumerate() {
+			ensure!(!assets[idx + 1..].contains(asset_id), Error::<T>::DuplicateAsset);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod settings;
+mod sweep;
+
+use crate as pallet_treasury_diversification;
+use crate::*;
+
+use std::cell::RefCell;
+
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::{Everything, Hooks},
+	BoundedVec,
+};
+use frame_system::EnsureRoot;
+use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u32;
+
+pub const ALICE: AccountId = 1;
+pub const TREASURY: AccountId = 100;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const USDT: AssetId = 2;
+pub const DAI: AssetId = 3;
+pub const WBTC: AssetId = 4;
+
+pub const ONE: Balance = 1_000_000_000_000;
+pub const MIN_BUDGET: Balance = 10 * ONE;
+pub const SWEEP_PERIOD: u64 = 10;
+
+thread_local! {
+	pub static SCHEDULED_SELLS: RefCell<Vec<(AccountId, AssetId, AssetId, Balance)>> = const { RefCell::new(Vec::new()) };
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		TreasuryDiversification: pallet_treasury_diversification,
+	}
+);
+
+parameter_types! {
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const NativeAssetId: AssetId = HDX;
+	pub const SweepPeriod: u64 = SWEEP_PERIOD;
+}
+
+pub struct MockDcaScheduler;
+
+impl DcaScheduler<AccountId, AssetId> for MockDcaScheduler {
+	fn schedule_sell(who: &AccountId, asset_in: AssetId, asset_out: AssetId, amount: Balance) -> DispatchResult {
+		ensure!(amount >= MIN_BUDGET, DispatchError::Other("BudgetTooLow"));
+
+		// DCA reserves the budget of the schedule
+		Tokens::reserve(asset_in, who, amount)?;
+		SCHEDULED_SELLS.with(|v| v.borrow_mut().push((*who, asset_in, asset_out, amount)));
+
+		Ok(())
+	}
+
+	fn schedule_sell_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type DcaScheduler = MockDcaScheduler;
+	type TreasuryAccount = TreasuryAccount;
+	type NativeAssetId = NativeAssetId;
+	type SweepPeriod = SweepPeriod;
+	type MaxTargets = ConstU32<5>;
+	type MaxSweepAssets = ConstU32<5>;
+	type WeightInfo = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_asset_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	targets: Vec<(AssetId, Permill)>,
+	sweep_assets: Vec<AssetId>,
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_targets(mut self, targets: Vec<(AssetId, Permill)>) -> Self {
+		self.targets = targets;
+		self
+	}
+
+	pub fn with_sweep_assets(mut self, assets: Vec<AssetId>) -> Self {
+		self.sweep_assets = assets;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		SCHEDULED_SELLS.with(|v| v.borrow_mut().clear());
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			pallet_treasury_diversification::TargetAllocation::<Test>::put(
+				BoundedVec::<_, ConstU32<5>>::try_from(self.targets).unwrap(),
+			);
+			pallet_treasury_diversification::SweepAssets::<Test>::put(
+				BoundedVec::<_, ConstU32<5>>::try_from(self.sweep_assets).unwrap(),
+			);
+		});
+
+		r
+	}
+}
+
+pub fn scheduled_sells() -> Vec<(AccountId, AssetId, AssetId, Balance)> {
+	SCHEDULED_SELLS.with(|v| v.borrow().clone())
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,205 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn targets(targets: Vec<(AssetId, Permill)>) -> BoundedVec<(AssetId, Permill), ConstU32<5>> {
+	targets.try_into().unwrap()
+}
+
+fn assets(assets: Vec<AssetId>) -> BoundedVec<AssetId, ConstU32<5>> {
+	assets.try_into().unwrap()
+}
+
+#[test]
+fn set_target_allocation_should_work_when_shares_add_up_to_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let t = targets(vec![
+			(DOT, Permill::from_percent(60)),
+			(USDT, Permill::from_percent(40)),
+		]);
+
+		// Act
+		assert_ok!(TreasuryDiversification::set_target_allocation(
+			RuntimeOrigin::root(),
+			t.clone()
+		));
+
+		// Assert
+		assert_eq!(TreasuryDiversification::target_allocation(), t);
+		expect_events(vec![Event::TargetAllocationSet { targets: t }.into()]);
+	});
+}
+
+#[test]
+fn set_target_allocation_should_work_when_targets_are_empty() {
+	ExtBuilder::default()
+		.with_targets(vec![(DOT, Permill::one())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TreasuryDiversification::set_target_allocation(
+				RuntimeOrigin::root(),
+				targets(vec![])
+			));
+
+			assert!(TreasuryDiversification::target_allocation().is_empty());
+		});
+}
+
+#[test]
+fn set_target_allocation_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_target_allocation(
+				RuntimeOrigin::signed(ALICE),
+				targets(vec![(DOT, Permill::one())])
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_target_allocation_should_fail_when_shares_dont_add_up_to_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_target_allocation(
+				RuntimeOrigin::root(),
+				targets(vec![
+					(DOT, Permill::from_percent(60)),
+					(USDT, Permill::from_percent(30))
+				])
+			),
+			Error::<Test>::InvalidTargetShares
+		);
+		assert_noop!(
+			TreasuryDiversification::set_target_allocation(
+				RuntimeOrigin::root(),
+				targets(vec![
+					(DOT, Permill::from_percent(60)),
+					(USDT, Permill::from_percent(50))
+				])
+			),
+			Error::<Test>::InvalidTargetShares
+		);
+	});
+}
+
+#[test]
+fn set_target_allocation_should_fail_when_asset_is_duplicated() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_target_allocation(
+				RuntimeOrigin::root(),
+				targets(vec![(DOT, Permill::from_percent(50)), (DOT, Permill::from_percent(50))])
+			),
+			Error::<Test>::DuplicateAsset
+		);
+	});
+}
+
+#[test]
+fn set_target_allocation_should_fail_when_target_asset_is_swept() {
+	ExtBuilder::default()
+		.with_sweep_assets(vec![USDT])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryDiversification::set_target_allocation(
+					RuntimeOrigin::root(),
+					targets(vec![
+						(DOT, Permill::from_percent(50)),
+						(USDT, Permill::from_percent(50))
+					])
+				),
+				Error::<Test>::TargetAssetSwept
+			);
+		});
+}
+
+#[test]
+fn set_sweep_assets_should_work_when_assets_are_valid() {
+	ExtBuilder::default()
+		.with_targets(vec![(DOT, Permill::one())])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(TreasuryDiversification::set_sweep_assets(
+				RuntimeOrigin::root(),
+				assets(vec![USDT, DAI])
+			));
+
+			// Assert
+			assert_eq!(TreasuryDiversification::sweep_assets(), assets(vec![USDT, DAI]));
+			expect_events(vec![Event::SweepAssetsSet {
+				assets: assets(vec![USDT, DAI]),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn set_sweep_assets_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_sweep_assets(RuntimeOrigin::signed(ALICE), assets(vec![USDT])),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_sweep_assets_should_fail_when_native_asset_is_swept() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_sweep_assets(RuntimeOrigin::root(), assets(vec![USDT, HDX])),
+			Error::<Test>::NativeAssetSwept
+		);
+	});
+}
+
+#[test]
+fn set_sweep_assets_should_fail_when_asset_is_duplicated() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_sweep_assets(RuntimeOrigin::root(), assets(vec![USDT, DAI, USDT])),
+			Error::<Test>::DuplicateAsset
+		);
+	});
+}
+
+#[test]
+fn set_sweep_assets_should_fail_when_swept_asset_is_target() {
+	ExtBuilder::default()
+		.with_targets(vec![(DOT, Permill::one())])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryDiversification::set_sweep_assets(RuntimeOrigin::root(), assets(vec![USDT, DOT])),
+				Error::<Test>::TargetAssetSwept
+			);
+		});
+}
+
+#[test]
+fn set_paused_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TreasuryDiversification::set_paused(RuntimeOrigin::root(), true));
+		assert!(TreasuryDiversification::paused());
+		expect_events(vec![Event::PausedSet { paused: true }.into()]);
+
+		assert_ok!(TreasuryDiversification::set_paused(RuntimeOrigin::root(), false));
+		assert!(!TreasuryDiversification::paused());
+		expect_events(vec![Event::PausedSet { paused: false }.into()]);
+	});
+}
+
+#[test]
+fn set_paused_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryDiversification::set_paused(RuntimeOrigin::signed(ALICE), true),
+			BadOrigin
+		);
+	});
+}
@@ -0,0 +1,161 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn diversification_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(TREASURY, USDT, 1_000 * ONE),
+			(TREASURY, DAI, 500 * ONE),
+			(TREASURY, HDX, 1_000 * ONE),
+		])
+		.with_targets(vec![
+			(DOT, Permill::from_percent(70)),
+			(WBTC, Permill::from_percent(30)),
+		])
+		.with_sweep_assets(vec![USDT, DAI])
+		.build()
+}
+
+#[test]
+fn on_initialize_should_schedule_sells_according_to_target_allocation() {
+	diversification_ext().execute_with(|| {
+		// Act
+		TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+
+		// Assert
+		assert_eq!(
+			scheduled_sells(),
+			vec![
+				(TREASURY, USDT, DOT, 700 * ONE),
+				(TREASURY, USDT, WBTC, 300 * ONE),
+				(TREASURY, DAI, DOT, 350 * ONE),
+				(TREASURY, DAI, WBTC, 150 * ONE),
+			]
+		);
+		expect_events(vec![
+			Event::SellScheduled {
+				asset_in: USDT,
+				asset_out: DOT,
+				amount: 700 * ONE,
+			}
+			.into(),
+			Event::SellScheduled {
+				asset_in: DAI,
+				asset_out: WBTC,
+				amount: 150 * ONE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn on_initialize_should_do_nothing_when_block_is_not_end_of_sweep_period() {
+	diversification_ext().execute_with(|| {
+		TreasuryDiversification::on_initialize(SWEEP_PERIOD + 1);
+
+		assert!(scheduled_sells().is_empty());
+	});
+}
+
+#[test]
+fn on_initialize_should_do_nothing_when_paused() {
+	diversification_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(TreasuryDiversification::set_paused(RuntimeOrigin::root(), true));
+
+		// Act
+		TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+
+		// Assert
+		assert!(scheduled_sells().is_empty());
+	});
+}
+
+#[test]
+fn on_initialize_should_resume_sweeps_when_unpaused() {
+	diversification_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(TreasuryDiversification::set_paused(RuntimeOrigin::root(), true));
+		TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+		assert_ok!(TreasuryDiversification::set_paused(RuntimeOrigin::root(), false));
+
+		// Act
+		TreasuryDiversification::on_initialize(2 * SWEEP_PERIOD);
+
+		// Assert
+		assert_eq!(scheduled_sells().len(), 4);
+	});
+}
+
+#[test]
+fn on_initialize_should_do_nothing_when_target_allocation_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, USDT, 1_000 * ONE)])
+		.with_sweep_assets(vec![USDT])
+		.build()
+		.execute_with(|| {
+			TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+
+			assert!(scheduled_sells().is_empty());
+		});
+}
+
+#[test]
+fn on_initialize_should_not_sweep_already_scheduled_balance() {
+	diversification_ext().execute_with(|| {
+		// Arrange
+		TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+
+		// Act
+		TreasuryDiversification::on_initialize(2 * SWEEP_PERIOD);
+
+		// Assert
+		assert_eq!(scheduled_sells().len(), 4);
+	});
+}
+
+#[test]
+fn on_initialize_should_sweep_new_fee_revenue_when_next_period_ends() {
+	diversification_ext().execute_with(|| {
+		// Arrange
+		TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+		assert_ok!(Tokens::deposit(USDT, &TREASURY, 100 * ONE));
+
+		// Act
+		TreasuryDiversification::on_initialize(2 * SWEEP_PERIOD);
+
+		// Assert
+		assert_eq!(
+			scheduled_sells()[4..].to_vec(),
+			vec![(TREASURY, USDT, DOT, 70 * ONE), (TREASURY, USDT, WBTC, 30 * ONE),]
+		);
+	});
+}
+
+#[test]
+fn on_initialize_should_skip_sell_when_scheduling_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, USDT, 20 * ONE)])
+		.with_targets(vec![
+			(DOT, Permill::from_percent(70)),
+			(WBTC, Permill::from_percent(30)),
+		])
+		.with_sweep_assets(vec![USDT])
+		.build()
+		.execute_with(|| {
+			// Act
+			TreasuryDiversification::on_initialize(SWEEP_PERIOD);
+
+			// Assert
+			assert_eq!(scheduled_sells(), vec![(TREASURY, USDT, DOT, 14 * ONE)]);
+			assert_eq!(Tokens::free_balance(USDT, &TREASURY), 6 * ONE);
+			expect_events(vec![Event::SellSchedulingFailed {
+				asset_in: USDT,
+				asset_out: WBTC,
+				amount: 6 * ONE,
+				error: DispatchError::Other("BudgetTooLow"),
+			}
+			.into()]);
+		});
+}
@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub type HookId = [u8; 8];
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct WeightUsage {
+	/// Number of recorded hook executions.
+	pub samples: u32,
+	/// Sum of `ref_time` used by the hook.
+	pub total_used: u64,
+	/// Max `ref_time` used by the hook in a single execution.
+	pub max_used: u64,
+	/// Number of executions per utilization of the available `ref_time`, in 10% buckets.
+	pub histogram: [u32; 10],
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait WeightTelemetryApi {
+		/// Weight usage collected for a hook. Default if the hook has not been recorded yet.
+		fn hook_weight_usage(hook: HookId) -> WeightUsage;
+		/// Weight usage collected for all recorded hooks.
+		fn hooks_weight_usage() -> Vec<(HookId, WeightUsage)>;
+	}
+}
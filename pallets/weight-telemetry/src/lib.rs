@@ -0,0 +1,134 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Weight telemetry pallet
+//!
+//! Distribution of weight used by block hooks.
+//!
+//! ## Overview
+//!
+//! Some pallets do their work in `on_initialize` or `on_idle` within a weight budget, e.g. referrals convert
+//! pending fees in `on_idle` and DCA executes schedules in `on_initialize`. The pallet collects how much of the
+//! available weight such hooks actually use, so the budgets can be tuned from data instead of guesses.
+//!
+//! Instrumented hooks report the used and the available weight through the `OnHookWeightUsed` handler, which is
+//! implemented by `RecordHookWeight` for a hook identifier. For each hook, the pallet keeps number of samples,
+//! total and max used `ref_time` and a histogram of utilization of the available weight in 10% buckets.
+//!
+//! The collected data are exposed by the runtime API.
+//!
+//! The pallet has no dispatchable functions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::{Get, Weight};
+use hydradx_traits::OnHookWeightUsed;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+/// Identifier of an instrumented hook.
+pub type HookId = [u8; 8];
+
+/// Number of buckets of the utilization histogram.
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct WeightUsage {
+	/// Number of recorded hook executions.
+	pub samples: u32,
+	/// Sum of `ref_time` used by the hook.
+	pub total_used: u64,
+	/// Max `ref_time` used by the hook in a single execution.
+	pub max_used: u64,
+	/// Number of executions per utilization of the available `ref_time`, in 10% buckets.
+	/// Last bucket includes executions which used the whole available weight.
+	pub histogram: [u32; HISTOGRAM_BUCKETS],
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	/// Weight usage collected per hook.
+	#[pallet::storage]
+	#[pallet::getter(fn hook_weight_usage)]
+	pub type HookWeightUsage<T: Config> = StorageMap<_, Twox64Concat, HookId, WeightUsage, ValueQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns weight usage of all recorded hooks.
+	pub fn hooks_weight_usage() -> Vec<(HookId, WeightUsage)> {
+		HookWeightUsage::<T>::iter().collect()
+	}
+
+	/// Records weight used by a hook and weight which was available to it.
+	pub fn note_weight_used(hook: HookId, used: Weight, available: Weight) {
+		let used = used.ref_time();
+		let bucket = Self::histogram_bucket(used, available.ref_time());
+		HookWeightUsage::<T>::mutate(hook, |usage| {
+			usage.samples.saturating_inc();
+			usage.total_used.saturating_accrue(used);
+			usage.max_used = usage.max_used.max(used);
+			usage.histogram[bucket].saturating_inc();
+		});
+	}
+
+	/// Weight of `note_weight_used`.
+	pub fn note_weight_used_weight() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	fn histogram_bucket(used: u64, available: u64) -> usize {
+		let last = HISTOGRAM_BUCKETS - 1;
+		if used >= available {
+			return last;
+		}
+		let bucket = (used as u128)
+			.saturating_mul(HISTOGRAM_BUCKETS as u128)
+			.checked_div(available as u128)
+			.unwrap_or_default();
+		(bucket as usize).min(last)
+	}
+}
+
+/// Records weight used by a hook identified by `Hook`.
+pub struct RecordHookWeight<T, Hook>(PhantomData<(T, Hook)>);
+
+impl<T: Config, Hook: Get<HookId>> OnHookWeightUsed for RecordHookWeight<T, Hook> {
+	fn on_hook_weight_used(used: Weight, available: Weight) -> Weight {
+		Pallet::<T>::note_weight_used(Hook::get(), used, available);
+		Pallet::<T>::note_weight_used_weight()
+	}
+
+	fn on_hook_weight_used_weight() -> Weight {
+		Pallet::<T>::note_weight_used_weight()
+	}
+}
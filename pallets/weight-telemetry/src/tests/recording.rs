@@ -0,0 +1,83 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn note_weight_used_should_accumulate_usage_of_hook() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		WeightTelemetry::note_weight_used(REFERRALS, Weight::from_parts(100, 0), Weight::from_parts(1_000, 0));
+		WeightTelemetry::note_weight_used(REFERRALS, Weight::from_parts(450, 0), Weight::from_parts(1_000, 0));
+		WeightTelemetry::note_weight_used(REFERRALS, Weight::from_parts(150, 0), Weight::from_parts(1_000, 0));
+
+		// Assert
+		assert_eq!(
+			WeightTelemetry::hook_weight_usage(REFERRALS),
+			WeightUsage {
+				samples: 3,
+				total_used: 700,
+				max_used: 450,
+				histogram: histogram(&[(1, 2), (4, 1)]),
+			}
+		);
+		assert_eq!(WeightTelemetry::hook_weight_usage(DCA), WeightUsage::default());
+	});
+}
+
+#[test]
+fn note_weight_used_should_record_to_last_bucket_when_whole_weight_is_used() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		WeightTelemetry::note_weight_used(DCA, Weight::from_parts(1_000, 0), Weight::from_parts(1_000, 0));
+		WeightTelemetry::note_weight_used(DCA, Weight::from_parts(1_500, 0), Weight::from_parts(1_000, 0));
+		WeightTelemetry::note_weight_used(DCA, Weight::from_parts(999, 0), Weight::from_parts(1_000, 0));
+		WeightTelemetry::note_weight_used(DCA, Weight::zero(), Weight::zero());
+
+		// Assert
+		assert_eq!(WeightTelemetry::hook_weight_usage(DCA).histogram, histogram(&[(9, 4)]));
+	});
+}
+
+#[test]
+fn note_weight_used_should_record_to_first_bucket_when_nothing_is_used() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		WeightTelemetry::note_weight_used(DCA, Weight::zero(), Weight::from_parts(1_000, 0));
+
+		// Assert
+		assert_eq!(
+			WeightTelemetry::hook_weight_usage(DCA),
+			WeightUsage {
+				samples: 1,
+				total_used: 0,
+				max_used: 0,
+				histogram: histogram(&[(0, 1)]),
+			}
+		);
+	});
+}
+
+#[test]
+fn record_hook_weight_should_record_usage_of_configured_hook() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		let weight = RecordHookWeight::<Test, ReferralsHook>::on_hook_weight_used(
+			Weight::from_parts(300, 0),
+			Weight::from_parts(1_000, 0),
+		);
+
+		// Assert
+		assert_eq!(weight, Weight::from_parts(110, 0));
+		assert_eq!(
+			WeightTelemetry::hooks_weight_usage(),
+			vec![(
+				REFERRALS,
+				WeightUsage {
+					samples: 1,
+					total_used: 300,
+					max_used: 300,
+					histogram: histogram(&[(3, 1)]),
+				}
+			)]
+		);
+	});
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+
+fn trap_assets<T: Config>(origin: &Location) -> VersionedAssets {
+	let asset = T::BenchmarkHelper::trapped_asset();
+	Pallet::<T>::drop_assets(
+		origin,
+		AssetsInHolding::from(asset.clone()),
+		&XcmContext::with_message_id([0; 32]),
+	);
+	VersionedAssets::from(Assets::from(asset))
+}
+
+benchmarks! {
+	claim_trapped_assets{
+		let caller: T::AccountId = whitelisted_caller();
+		let trap_origin = T::AccountIdToLocation::convert(caller.clone());
+		let assets = trap_assets::<T>(&trap_origin);
+		let hash = Pallet::<T>::trap_hash(&trap_origin, &assets);
+	}: _(RawOrigin::Signed(caller), Box::new(VersionedLocation::from(trap_origin)), Box::new(assets))
+	verify {
+		assert!(Pallet::<T>::trapped_assets(hash).is_none());
+	}
+
+	force_claim_trapped_assets{
+		let origin = T::ForceOrigin::try_successful_origin().unwrap();
+		let trap_origin = Location::new(1, [Parachain(2_000)]);
+		let assets = trap_assets::<T>(&trap_origin);
+		let hash = Pallet::<T>::trap_hash(&trap_origin, &assets);
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+	}: _<T::RuntimeOrigin>(origin, Box::new(VersionedLocation::from(trap_origin)), Box::new(assets), beneficiary)
+	verify {
+		assert!(Pallet::<T>::trapped_assets(hash).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,286 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # XCM asset trap pallet
+//!
+//! Recovery of assets trapped during failed XCM executions.
+//!
+//! ## Overview
+//!
+//! When an XCM execution fails (e.g. because of insufficient fees), assets left in the holding register are
+//! dropped by the XCM executor. The pallet implements `DropAssets` and records the hash of the dropped assets
+//! and the origin of the message. `AssetsTrapped` event is emitted with the hash, the origin and the assets.
+//!
+//! Trapped assets can be claimed back by the account the trap origin is converted to by `LocationToAccountId`.
+//! The claimant provides the trap origin and the exact trapped assets as emitted in the `AssetsTrapped` event.
+//! These serve as the claim proof - they must hash to an existing trap. Claimed assets are deposited to the
+//! claimant by `AssetTransactor`.
+//!
+//! Trap origins which can't be converted to a local account (e.g. other chains) can't claim the assets by an
+//! extrinsic. `ForceOrigin` can claim any trapped assets on behalf of the trap origin to any beneficiary.
+//!
+//! The pallet also implements `ClaimAssets`, so the trapped assets can be claimed by the `ClaimAsset` XCM
+//! instruction sent from the trap origin.
+//!
+//! If the same assets are trapped more than once with the same origin, the trap is counted and can be claimed
+//! the same number of times.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `claim_trapped_assets` - Claims assets trapped with the origin of the caller.
+//! * `force_claim_trapped_assets` - Claims trapped assets of any origin to a beneficiary.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Convert, Hash};
+use sp_std::boxed::Box;
+use xcm::{v4::prelude::*, VersionedAssets, VersionedLocation};
+use xcm_executor::traits::{ClaimAssets, ConvertLocation, DropAssets, TransactAsset};
+use xcm_executor::AssetsInHolding;
+
+pub use pallet::*;
+
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Asset which can be deposited to an account by `AssetTransactor`.
+	fn trapped_asset() -> Asset;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn trapped_asset() -> Asset {
+		(Location::here(), 1_000_000_000_000u128).into()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Deposits claimed assets to the beneficiary.
+		type AssetTransactor: TransactAsset;
+
+		/// Converts trap origin to the account allowed to claim the trapped assets.
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+
+		/// Converts beneficiary account to location.
+		type AccountIdToLocation: Convert<Self::AccountId, Location>;
+
+		/// Origin able to claim trapped assets of any origin.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// Number of times the assets were trapped with the same origin, by hash of the trap origin and the assets.
+	#[pallet::storage]
+	#[pallet::getter(fn trapped_assets)]
+	pub(super) type TrappedAssets<T: Config> = StorageMap<_, Identity, H256, u32, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Assets were trapped during a failed XCM execution.
+		AssetsTrapped {
+			hash: H256,
+			origin: Location,
+			assets: VersionedAssets,
+		},
+		/// Trapped assets were claimed.
+		AssetsClaimed {
+			hash: H256,
+			origin: Location,
+			assets: VersionedAssets,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Location or assets can't be converted to the latest XCM version.
+		BadVersion,
+		/// No assets were trapped with given origin and assets.
+		TrapNotFound,
+		/// Caller is not the account of the trap origin.
+		NotTrapOrigin,
+		/// Claimed asset can't be deposited to the beneficiary.
+		AssetDepositFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Claim assets trapped with origin of the caller.
+		///
+		/// `trap_origin` and `assets` must match the `AssetsTrapped` event of the trap and `trap_origin`
+		/// must be converted to the caller's account by `LocationToAccountId`.
+		///
+		/// Claimed assets are deposited to the caller.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin
+		/// - `trap_origin`: origin of the failed XCM execution
+		/// - `assets`: trapped assets
+		///
+		/// Emits `AssetsClaimed` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::claim_trapped_assets().saturating_mul(Pallet::<T>::assets_count(assets)))]
+		pub fn claim_trapped_assets(
+			origin: OriginFor<T>,
+			trap_origin: Box<VersionedLocation>,
+			assets: Box<VersionedAssets>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let trap_origin = Location::try_from(*trap_origin).map_err(|_| Error::<T>::BadVersion)?;
+
+			ensure!(
+				T::LocationToAccountId::convert_location(&trap_origin) == Some(who.clone()),
+				Error::<T>::NotTrapOrigin
+			);
+
+			Self::do_claim(trap_origin, *assets, T::AccountIdToLocation::convert(who))
+		}
+
+		/// Claim trapped assets of any origin to the beneficiary.
+		///
+		/// Can be called only by `ForceOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: `ForceOrigin`
+		/// - `trap_origin`: origin of the failed XCM execution
+		/// - `assets`: trapped assets
+		/// - `beneficiary`: account the claimed assets are deposited to
+		///
+		/// Emits `AssetsClaimed` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::claim_trapped_assets().saturating_mul(Pallet::<T>::assets_count(assets)))]
+		pub fn force_claim_trapped_assets(
+			origin: OriginFor<T>,
+			trap_origin: Box<VersionedLocation>,
+			assets: Box<VersionedAssets>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let trap_origin = Location::try_from(*trap_origin).map_err(|_| Error::<T>::BadVersion)?;
+
+			Self::do_claim(trap_origin, *assets, T::AccountIdToLocation::convert(beneficiary))
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn trap_hash(origin: &Location, assets: &VersionedAssets) -> H256 {
+		BlakeTwo256::hash_of(&(origin, assets))
+	}
+
+	/// Number of assets used to calculate the claim weight. At least one.
+	fn assets_count(assets: &VersionedAssets) -> u64 {
+		Assets::try_from(assets.clone())
+			.map(|assets| assets.len() as u64)
+			.unwrap_or_default()
+			.max(1)
+	}
+
+	fn do_claim(trap_origin: Location, assets: VersionedAssets, beneficiary: Location) -> DispatchResult {
+		let assets = Assets::try_from(assets).map_err(|_| Error::<T>::BadVersion)?;
+		Self::take_trap(&trap_origin, &VersionedAssets::from(assets.clone())).ok_or(Error::<T>::TrapNotFound)?;
+
+		for asset in assets.inner() {
+			T::AssetTransactor::deposit_asset(asset, &beneficiary, None).map_err(|_| Error::<T>::AssetDepositFailed)?;
+		}
+
+		Ok(())
+	}
+
+	/// Remove one trap of `assets` with `origin`. Returns hash of the trap if it exists.
+	///
+	/// `assets` must be in the latest version.
+	fn take_trap(origin: &Location, assets: &VersionedAssets) -> Option<H256> {
+		let hash = Self::trap_hash(origin, assets);
+		let count = TrappedAssets::<T>::get(hash)?;
+
+		if count > 1 {
+			TrappedAssets::<T>::insert(hash, count - 1);
+		} else {
+			TrappedAssets::<T>::remove(hash);
+		}
+
+		Self::deposit_event(Event::AssetsClaimed {
+			hash,
+			origin: origin.clone(),
+			assets: assets.clone(),
+		});
+
+		Some(hash)
+	}
+}
+
+impl<T: Config> DropAssets for Pallet<T> {
+	fn drop_assets(origin: &Location, assets: AssetsInHolding, _context: &XcmContext) -> Weight {
+		if assets.is_empty() {
+			return Weight::zero();
+		}
+
+		let assets = VersionedAssets::from(Assets::from(assets));
+		let hash = Self::trap_hash(origin, &assets);
+
+		TrappedAssets::<T>::mutate(hash, |count| {
+			*count = Some(count.unwrap_or_default().saturating_add(1));
+		});
+
+		Self::deposit_event(Event::AssetsTrapped {
+			hash,
+			origin: origin.clone(),
+			assets,
+		});
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
+impl<T: Config> ClaimAssets for Pallet<T> {
+	fn claim_assets(origin: &Location, ticket: &Location, what: &Assets, _context: &XcmContext) -> bool {
+		// Assets are always trapped in the latest version, so only the default ticket is supported.
+		if ticket != &Location::here() {
+			return false;
+		}
+
+		Self::take_trap(origin, &VersionedAssets::from(what.clone())).is_some()
+	}
+}
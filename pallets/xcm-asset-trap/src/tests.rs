@@ -0,0 +1,196 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod claim;
+mod trap;
+
+use crate as pallet_xcm_asset_trap;
+use crate::*;
+
+use std::cell::RefCell;
+
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::Everything,
+};
+use frame_system::EnsureRoot;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = sp_runtime::AccountId32;
+
+pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
+
+thread_local! {
+	pub static DEPOSITS: RefCell<Vec<(Asset, Location)>> = const { RefCell::new(Vec::new()) };
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		XcmAssetTrap: pallet_xcm_asset_trap,
+	}
+);
+
+pub struct MockAssetTransactor;
+
+impl TransactAsset for MockAssetTransactor {
+	fn deposit_asset(what: &Asset, who: &Location, _context: Option<&XcmContext>) -> XcmResult {
+		DEPOSITS.with(|v| v.borrow_mut().push((what.clone(), who.clone())));
+		Ok(())
+	}
+}
+
+pub struct LocationToAccountId;
+
+impl ConvertLocation<AccountId> for LocationToAccountId {
+	fn convert_location(location: &Location) -> Option<AccountId> {
+		match location.unpack() {
+			(0, [AccountId32 { network: None, id }]) => Some((*id).into()),
+			_ => None,
+		}
+	}
+}
+
+pub struct AccountIdToLocation;
+
+impl Convert<AccountId, Location> for AccountIdToLocation {
+	fn convert(account: AccountId) -> Location {
+		Location::new(
+			0,
+			[AccountId32 {
+				network: None,
+				id: account.into(),
+			}],
+		)
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTransactor = MockAssetTransactor;
+	type LocationToAccountId = LocationToAccountId;
+	type AccountIdToLocation = AccountIdToLocation;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	traps: Vec<(Location, Asset)>,
+}
+
+impl ExtBuilder {
+	pub fn with_trapped_assets(mut self, traps: Vec<(Location, Asset)>) -> Self {
+		self.traps = traps;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		DEPOSITS.with(|v| v.borrow_mut().clear());
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			for (origin, asset) in self.traps {
+				trap(&origin, asset);
+			}
+		});
+
+		r
+	}
+}
+
+pub fn account_location(account: AccountId) -> Location {
+	AccountIdToLocation::convert(account)
+}
+
+pub fn sibling_location() -> Location {
+	Location::new(1, [Parachain(2_000)])
+}
+
+pub fn hdx(amount: u128) -> Asset {
+	(Location::here(), amount).into()
+}
+
+pub fn dot(amount: u128) -> Asset {
+	(Location::parent(), amount).into()
+}
+
+pub fn versioned(assets: Vec<Asset>) -> VersionedAssets {
+	VersionedAssets::from(Assets::from(assets))
+}
+
+pub fn trap(origin: &Location, asset: Asset) {
+	XcmAssetTrap::drop_assets(
+		origin,
+		AssetsInHolding::from(asset),
+		&XcmContext::with_message_id([0; 32]),
+	);
+}
+
+pub fn trap_hash(origin: &Location, assets: &VersionedAssets) -> H256 {
+	XcmAssetTrap::trap_hash(origin, assets)
+}
+
+pub fn deposits() -> Vec<(Asset, Location)> {
+	DEPOSITS.with(|v| v.borrow().clone())
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
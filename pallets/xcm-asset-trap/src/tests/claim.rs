@@ -0,0 +1,197 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn claim(who: AccountId, trap_origin: Location, assets: Vec<Asset>) -> DispatchResult {
+	XcmAssetTrap::claim_trapped_assets(
+		RuntimeOrigin::signed(who),
+		Box::new(VersionedLocation::from(trap_origin)),
+		Box::new(versioned(assets)),
+	)
+}
+
+#[test]
+fn claim_trapped_assets_should_deposit_assets_to_trap_origin_account() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(account_location(ALICE), hdx(1_000))])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let hash = trap_hash(&account_location(ALICE), &versioned(vec![hdx(1_000)]));
+
+			// Act
+			assert_ok!(claim(ALICE, account_location(ALICE), vec![hdx(1_000)]));
+
+			// Assert
+			assert_eq!(deposits(), vec![(hdx(1_000), account_location(ALICE))]);
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), None);
+			expect_events(vec![Event::AssetsClaimed {
+				hash,
+				origin: account_location(ALICE),
+				assets: versioned(vec![hdx(1_000)]),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_trapped_assets_should_decrease_count_when_assets_were_trapped_multiple_times() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![
+			(account_location(ALICE), hdx(1_000)),
+			(account_location(ALICE), hdx(1_000)),
+		])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(claim(ALICE, account_location(ALICE), vec![hdx(1_000)]));
+
+			// Assert
+			let hash = trap_hash(&account_location(ALICE), &versioned(vec![hdx(1_000)]));
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), Some(1));
+			assert_ok!(claim(ALICE, account_location(ALICE), vec![hdx(1_000)]));
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), None);
+			assert_eq!(deposits().len(), 2);
+		});
+}
+
+#[test]
+fn claim_trapped_assets_should_fail_when_caller_is_not_trap_origin() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(account_location(ALICE), hdx(1_000))])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				claim(BOB, account_location(ALICE), vec![hdx(1_000)]),
+				Error::<Test>::NotTrapOrigin
+			);
+		});
+}
+
+#[test]
+fn claim_trapped_assets_should_fail_when_trap_origin_is_not_local_account() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				claim(ALICE, sibling_location(), vec![dot(1_000)]),
+				Error::<Test>::NotTrapOrigin
+			);
+		});
+}
+
+#[test]
+fn claim_trapped_assets_should_fail_when_assets_do_not_match_trap() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(account_location(ALICE), hdx(1_000))])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				claim(ALICE, account_location(ALICE), vec![hdx(1_001)]),
+				Error::<Test>::TrapNotFound
+			);
+			assert_noop!(
+				claim(ALICE, account_location(ALICE), vec![dot(1_000)]),
+				Error::<Test>::TrapNotFound
+			);
+		});
+}
+
+#[test]
+fn claim_trapped_assets_should_fail_when_trap_was_already_claimed() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(account_location(ALICE), hdx(1_000))])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(claim(ALICE, account_location(ALICE), vec![hdx(1_000)]));
+
+			// Act and assert
+			assert_noop!(
+				claim(ALICE, account_location(ALICE), vec![hdx(1_000)]),
+				Error::<Test>::TrapNotFound
+			);
+		});
+}
+
+#[test]
+fn force_claim_trapped_assets_should_deposit_assets_to_beneficiary() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(XcmAssetTrap::force_claim_trapped_assets(
+				RuntimeOrigin::root(),
+				Box::new(VersionedLocation::from(sibling_location())),
+				Box::new(versioned(vec![dot(1_000)])),
+				BOB,
+			));
+
+			// Assert
+			assert_eq!(deposits(), vec![(dot(1_000), account_location(BOB))]);
+			let hash = trap_hash(&sibling_location(), &versioned(vec![dot(1_000)]));
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), None);
+		});
+}
+
+#[test]
+fn force_claim_trapped_assets_should_fail_when_origin_is_not_force_origin() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				XcmAssetTrap::force_claim_trapped_assets(
+					RuntimeOrigin::signed(ALICE),
+					Box::new(VersionedLocation::from(sibling_location())),
+					Box::new(versioned(vec![dot(1_000)])),
+					ALICE,
+				),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn claim_assets_should_claim_trap_when_claimed_by_xcm() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			// Act
+			let claimed = XcmAssetTrap::claim_assets(
+				&sibling_location(),
+				&Location::here(),
+				&Assets::from(dot(1_000)),
+				&XcmContext::with_message_id([0; 32]),
+			);
+
+			// Assert
+			assert!(claimed);
+			let hash = trap_hash(&sibling_location(), &versioned(vec![dot(1_000)]));
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), None);
+		});
+}
+
+#[test]
+fn claim_assets_should_not_claim_trap_when_claimed_by_different_origin() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			// Act
+			let claimed = XcmAssetTrap::claim_assets(
+				&Location::parent(),
+				&Location::here(),
+				&Assets::from(dot(1_000)),
+				&XcmContext::with_message_id([0; 32]),
+			);
+
+			// Assert
+			assert!(!claimed);
+			let hash = trap_hash(&sibling_location(), &versioned(vec![dot(1_000)]));
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), Some(1));
+		});
+}
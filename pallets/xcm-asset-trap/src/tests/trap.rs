@@ -0,0 +1,73 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn drop_assets_should_record_trapped_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let origin = sibling_location();
+		let assets = versioned(vec![dot(1_000)]);
+		let hash = trap_hash(&origin, &assets);
+
+		// Act
+		trap(&origin, dot(1_000));
+
+		// Assert
+		assert_eq!(XcmAssetTrap::trapped_assets(hash), Some(1));
+		expect_events(vec![Event::AssetsTrapped { hash, origin, assets }.into()]);
+	});
+}
+
+#[test]
+fn drop_assets_should_increase_count_when_same_assets_are_trapped_again() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			// Act
+			trap(&sibling_location(), dot(1_000));
+
+			// Assert
+			let hash = trap_hash(&sibling_location(), &versioned(vec![dot(1_000)]));
+			assert_eq!(XcmAssetTrap::trapped_assets(hash), Some(2));
+		});
+}
+
+#[test]
+fn drop_assets_should_record_separate_traps_when_origins_differ() {
+	ExtBuilder::default()
+		.with_trapped_assets(vec![(sibling_location(), dot(1_000))])
+		.build()
+		.execute_with(|| {
+			// Act
+			trap(&account_location(ALICE), dot(1_000));
+
+			// Assert
+			let assets = versioned(vec![dot(1_000)]);
+			assert_eq!(
+				XcmAssetTrap::trapped_assets(trap_hash(&sibling_location(), &assets)),
+				Some(1)
+			);
+			assert_eq!(
+				XcmAssetTrap::trapped_assets(trap_hash(&account_location(ALICE), &assets)),
+				Some(1)
+			);
+		});
+}
+
+#[test]
+fn drop_assets_should_not_record_trap_when_holding_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		let weight = XcmAssetTrap::drop_assets(
+			&sibling_location(),
+			AssetsInHolding::new(),
+			&XcmContext::with_message_id([0; 32]),
+		);
+
+		// Assert
+		assert_eq!(weight, Weight::zero());
+		assert_eq!(TrappedAssets::<Test>::iter().count(), 0);
+		assert!(System::events().is_empty());
+	});
+}
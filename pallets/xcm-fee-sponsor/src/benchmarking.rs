@@ -0,0 +1,109 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+
+const PARA_ID: u32 = 2_000;
+
+fn sponsored_origin() -> VersionedLocation {
+	VersionedLocation::from(Location::new(1, [Parachain(PARA_ID)]))
+}
+
+fn period_cap<T: Config>() -> BalanceOf<T> {
+	BalanceOf::<T>::from(1_000_000u32)
+}
+
+/// Registers sponsorships of the sibling parachain up to one below the maximum.
+fn fill_sponsorships<T: Config>() {
+	let sponsor: T::AccountId = account("sponsor", 0, 0);
+	for _ in 1..T::MaxSponsorshipsPerParachain::get() {
+		Pallet::<T>::register_sponsorship(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			Box::new(sponsored_origin()),
+			period_cap::<T>(),
+			BlockNumberFor::<T>::from(100u32),
+			BlockNumberFor::<T>::from(1_000u32),
+		)
+		.unwrap();
+	}
+}
+
+fn register<T: Config>(sponsor: T::AccountId) -> SponsorshipId {
+	Pallet::<T>::register_sponsorship(
+		RawOrigin::Signed(sponsor).into(),
+		Box::new(sponsored_origin()),
+		period_cap::<T>(),
+		BlockNumberFor::<T>::from(100u32),
+		BlockNumberFor::<T>::from(1_000u32),
+	)
+	.unwrap();
+	Pallet::<T>::next_sponsorship_id() - 1
+}
+
+benchmarks! {
+	where_clause {
+		where BalanceOf<T>: From<u32>,
+	}
+
+	register_sponsorship{
+		fill_sponsorships::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let id = Pallet::<T>::next_sponsorship_id();
+	}: _(RawOrigin::Signed(caller), Box::new(sponsored_origin()), period_cap::<T>(), BlockNumberFor::<T>::from(100u32), BlockNumberFor::<T>::from(1_000u32))
+	verify {
+		assert!(Pallet::<T>::sponsorships(id).is_some());
+	}
+
+	update_sponsorship{
+		let caller: T::AccountId = whitelisted_caller();
+		let id = register::<T>(caller.clone());
+	}: _(RawOrigin::Signed(caller), id, period_cap::<T>() + One::one(), BlockNumberFor::<T>::from(2_000u32))
+	verify {
+		assert_eq!(Pallet::<T>::sponsorships(id).map(|s| s.period_cap), Some(period_cap::<T>() + One::one()));
+	}
+
+	cancel_sponsorship{
+		fill_sponsorships::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let id = register::<T>(caller.clone());
+	}: _(RawOrigin::Signed(caller), id)
+	verify {
+		assert!(Pallet::<T>::sponsorships(id).is_none());
+	}
+
+	remove_expired_sponsorship{
+		fill_sponsorships::<T>();
+		let id = register::<T>(account("sponsor", 1, 0));
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2_000u32));
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), id)
+	verify {
+		assert!(Pallet::<T>::sponsorships(id).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,489 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # XCM fee sponsor pallet
+//!
+//! Sponsored execution fees of XCM messages from sibling parachains.
+//!
+//! ## Overview
+//!
+//! A sponsor registers a sponsorship for an origin pattern of a sibling parachain. The pattern is a location
+//! starting with the sibling parachain, e.g. `(1, [Parachain(2000)])` sponsors every origin of the parachain and
+//! `(1, [Parachain(2000), AccountId32 { .. }])` sponsors a single account of the parachain. This allows e.g.
+//! onboarding campaigns where new users of other chains don't need to hold fee assets on this chain.
+//!
+//! Messages from an origin matching an active sponsorship are allowed by `AllowSponsoredExecutionFrom` barrier
+//! without paying for the execution. The execution fee of the message weight is converted by `WeightToFee` and
+//! transferred from the sponsor to `FeeReceiver` instead. If more sponsorships match the origin, the first
+//! registered one which can pay the fee is charged.
+//!
+//! Each sponsorship has a spending cap per period. Sponsor pays at most `period_cap` per `period` blocks, messages
+//! which would exceed the cap are not sponsored. The spent amount is reset when a new period starts.
+//!
+//! Sponsorship expires automatically after the sponsored duration. Expired sponsorships are never charged, they are
+//! removed when a message of the sibling parachain is processed or by `remove_expired_sponsorship` which can be
+//! called by anyone.
+//!
+//! ### Dispatchable Functions
+//!
+//! * `register_sponsorship` - Registers a sponsorship paid by the caller.
+//! * `update_sponsorship` - Updates the spending cap and the duration of a sponsorship.
+//! * `cancel_sponsorship` - Cancels a sponsorship.
+//! * `remove_expired_sponsorship` - Removes an expired sponsorship.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::{DispatchResult, Get, RuntimeDebug, Weight};
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_support::traits::tokens::Preservation;
+use frame_support::traits::ProcessMessageError;
+use frame_support::weights::WeightToFee;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{CheckedAdd, Saturating, Zero};
+use sp_std::boxed::Box;
+use sp_std::marker::PhantomData;
+use xcm::{v4::prelude::*, VersionedLocation};
+use xcm_executor::traits::{Properties, ShouldExecute};
+
+pub use pallet::*;
+
+pub use weights::WeightInfo;
+
+pub type SponsorshipId = u32;
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Sponsorship of execution fees of XCM messages from an origin pattern of a sibling parachain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Sponsorship<AccountId, Balance, BlockNumber> {
+	/// Account paying the execution fees.
+	pub sponsor: AccountId,
+	/// Sibling parachain of the sponsored origins.
+	pub para_id: u32,
+	/// Sponsored origin pattern. Origins starting with the pattern are sponsored.
+	///
+	/// Stored in the current XCM version, which bounds the number of junctions.
+	pub origin: Location,
+	/// Maximum amount of fees paid by the sponsor per period.
+	pub period_cap: Balance,
+	/// Length of the spending period in blocks.
+	pub period: BlockNumber,
+	/// Block the sponsorship expires at.
+	pub expires_at: BlockNumber,
+	/// Index of the period `spent` is tracked for.
+	pub current_period: BlockNumber,
+	/// Fees paid by the sponsor in the current period.
+	pub spent: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Native currency the execution fees are paid in.
+		type Currency: Mutate<Self::AccountId>;
+
+		/// Converts weight of a sponsored message to the execution fee.
+		type WeightToFee: WeightToFee<Balance = BalanceOf<Self>>;
+
+		/// Account receiving the execution fees paid by sponsors.
+		#[pallet::constant]
+		type FeeReceiver: Get<Self::AccountId>;
+
+		/// Maximum number of sponsorships of one sibling parachain.
+		#[pallet::constant]
+		type MaxSponsorshipsPerParachain: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Id of the next registered sponsorship.
+	#[pallet::storage]
+	#[pallet::getter(fn next_sponsorship_id)]
+	pub(super) type NextSponsorshipId<T: Config> = StorageValue<_, SponsorshipId, ValueQuery>;
+
+	/// Registered sponsorships.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub(super) type Sponsorships<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		SponsorshipId,
+		Sponsorship<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Ids of sponsorships by sibling parachain, in order of registration.
+	#[pallet::storage]
+	#[pallet::getter(fn parachain_sponsorships)]
+	pub(super) type ParachainSponsorships<T: Config> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<SponsorshipId, T::MaxSponsorshipsPerParachain>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Sponsorship was registered.
+		SponsorshipRegistered {
+			id: SponsorshipId,
+			sponsor: T::AccountId,
+			origin: Location,
+			period_cap: BalanceOf<T>,
+			period: BlockNumberFor<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Spending cap or expiration of a sponsorship was updated.
+		SponsorshipUpdated {
+			id: SponsorshipId,
+			period_cap: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Sponsorship was cancelled by the sponsor.
+		SponsorshipCancelled { id: SponsorshipId },
+		/// Expired sponsorship was removed.
+		SponsorshipExpired { id: SponsorshipId },
+		/// Execution fee of a message was paid by the sponsor.
+		ExecutionSponsored {
+			id: SponsorshipId,
+			sponsor: T::AccountId,
+			origin: Location,
+			fee: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Location can't be converted to the latest XCM version.
+		BadVersion,
+		/// Sponsored origin is not a sibling parachain or its location.
+		NotSiblingParachain,
+		/// Spending cap must be greater than zero.
+		InvalidPeriodCap,
+		/// Period must be greater than zero.
+		InvalidPeriod,
+		/// Duration must be greater than zero.
+		InvalidDuration,
+		/// Maximum number of sponsorships of the sibling parachain was reached.
+		TooManySponsorships,
+		/// Sponsorship does not exist.
+		SponsorshipNotFound,
+		/// Caller is not the sponsor of the sponsorship.
+		NotSponsor,
+		/// Sponsorship has not expired yet.
+		SponsorshipNotExpired,
+		/// Sponsorship id overflow.
+		SponsorshipIdOverflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a sponsorship of execution fees of XCM messages from origins matching `sponsored_origin`.
+		///
+		/// `sponsored_origin` must be a location of a sibling parachain or a location within it. Fees are paid by
+		/// the caller in the native currency, at most `period_cap` per `period` blocks. Sponsorship expires after
+		/// `duration` blocks.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin of the sponsor
+		/// - `sponsored_origin`: origin pattern of sponsored messages
+		/// - `period_cap`: maximum amount of fees paid per period
+		/// - `period`: length of the spending period in blocks
+		/// - `duration`: number of blocks the sponsorship is active
+		///
+		/// Emits `SponsorshipRegistered` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_sponsorship())]
+		pub fn register_sponsorship(
+			origin: OriginFor<T>,
+			sponsored_origin: Box<VersionedLocation>,
+			period_cap: BalanceOf<T>,
+			period: BlockNumberFor<T>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let sponsored_origin = Location::try_from(*sponsored_origin).map_err(|_| Error::<T>::BadVersion)?;
+			let para_id = Self::sibling_para_id(&sponsored_origin).ok_or(Error::<T>::NotSiblingParachain)?;
+
+			ensure!(!period_cap.is_zero(), Error::<T>::InvalidPeriodCap);
+			ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+			ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
+
+			let id = NextSponsorshipId::<T>::try_mutate(|next_id| -> Result<SponsorshipId, DispatchError> {
+				let id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(Error::<T>::SponsorshipIdOverflow)?;
+				Ok(id)
+			})?;
+
+			ParachainSponsorships::<T>::try_mutate(para_id, |ids| ids.try_push(id))
+				.map_err(|_| Error::<T>::TooManySponsorships)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(duration);
+
+			Sponsorships::<T>::insert(
+				id,
+				Sponsorship {
+					sponsor: who.clone(),
+					para_id,
+					origin: sponsored_origin.clone(),
+					period_cap,
+					period,
+					expires_at,
+					current_period: now / period,
+					spent: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::SponsorshipRegistered {
+				id,
+				sponsor: who,
+				origin: sponsored_origin,
+				period_cap,
+				period,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Update the spending cap and the duration of a sponsorship.
+		///
+		/// The sponsorship expires after `duration` blocks from the current block. Fees already paid in the
+		/// current period count towards the new cap.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin of the sponsor
+		/// - `id`: id of the sponsorship
+		/// - `period_cap`: maximum amount of fees paid per period
+		/// - `duration`: number of blocks the sponsorship is active from now
+		///
+		/// Emits `SponsorshipUpdated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::update_sponsorship())]
+		pub fn update_sponsorship(
+			origin: OriginFor<T>,
+			id: SponsorshipId,
+			period_cap: BalanceOf<T>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!period_cap.is_zero(), Error::<T>::InvalidPeriodCap);
+			ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
+
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+
+			Sponsorships::<T>::try_mutate(id, |maybe_sponsorship| -> DispatchResult {
+				let sponsorship = maybe_sponsorship.as_mut().ok_or(Error::<T>::SponsorshipNotFound)?;
+				ensure!(sponsorship.sponsor == who, Error::<T>::NotSponsor);
+
+				sponsorship.period_cap = period_cap;
+				sponsorship.expires_at = expires_at;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SponsorshipUpdated {
+				id,
+				period_cap,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a sponsorship.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin of the sponsor
+		/// - `id`: id of the sponsorship
+		///
+		/// Emits `SponsorshipCancelled` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cancel_sponsorship())]
+		pub fn cancel_sponsorship(origin: OriginFor<T>, id: SponsorshipId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let sponsorship = Sponsorships::<T>::get(id).ok_or(Error::<T>::SponsorshipNotFound)?;
+			ensure!(sponsorship.sponsor == who, Error::<T>::NotSponsor);
+
+			Self::remove_sponsorship(id, sponsorship.para_id);
+
+			Self::deposit_event(Event::SponsorshipCancelled { id });
+
+			Ok(())
+		}
+
+		/// Remove an expired sponsorship.
+		///
+		/// Can be called by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin
+		/// - `id`: id of the sponsorship
+		///
+		/// Emits `SponsorshipExpired` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::remove_expired_sponsorship())]
+		pub fn remove_expired_sponsorship(origin: OriginFor<T>, id: SponsorshipId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let sponsorship = Sponsorships::<T>::get(id).ok_or(Error::<T>::SponsorshipNotFound)?;
+			ensure!(
+				Self::is_expired(&sponsorship, frame_system::Pallet::<T>::block_number()),
+				Error::<T>::SponsorshipNotExpired
+			);
+
+			Self::remove_sponsorship(id, sponsorship.para_id);
+
+			Self::deposit_event(Event::SponsorshipExpired { id });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Id of the sibling parachain of `location`.
+	fn sibling_para_id(location: &Location) -> Option<u32> {
+		match location.unpack() {
+			(1, [Parachain(para_id), ..]) => Some(*para_id),
+			_ => None,
+		}
+	}
+
+	fn is_expired(
+		sponsorship: &Sponsorship<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) -> bool {
+		sponsorship.expires_at <= now
+	}
+
+	fn remove_sponsorship(id: SponsorshipId, para_id: u32) {
+		Sponsorships::<T>::remove(id);
+		ParachainSponsorships::<T>::mutate_exists(para_id, |maybe_ids| {
+			if let Some(ids) = maybe_ids {
+				ids.retain(|sponsorship_id| *sponsorship_id != id);
+				if ids.is_empty() {
+					*maybe_ids = None;
+				}
+			}
+		});
+	}
+
+	/// Charge the execution fee of `weight` to the first active sponsorship matching `origin`.
+	///
+	/// Expired sponsorships of the sibling parachain of `origin` are removed.
+	///
+	/// Returns id of the charged sponsorship, `None` if the execution is not sponsored.
+	pub fn sponsor_execution(origin: &Location, weight: Weight) -> Option<SponsorshipId> {
+		let para_id = Self::sibling_para_id(origin)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let fee = T::WeightToFee::weight_to_fee(&weight);
+
+		for id in ParachainSponsorships::<T>::get(para_id) {
+			let Some(mut sponsorship) = Sponsorships::<T>::get(id) else {
+				continue;
+			};
+
+			if Self::is_expired(&sponsorship, now) {
+				Self::remove_sponsorship(id, para_id);
+				Self::deposit_event(Event::SponsorshipExpired { id });
+				continue;
+			}
+
+			if !origin.starts_with(&sponsorship.origin) {
+				continue;
+			}
+
+			let period = now / sponsorship.period;
+			if sponsorship.current_period != period {
+				sponsorship.current_period = period;
+				sponsorship.spent = Zero::zero();
+			}
+
+			let Some(spent) = sponsorship.spent.checked_add(&fee) else {
+				continue;
+			};
+			if spent > sponsorship.period_cap {
+				continue;
+			}
+
+			if T::Currency::transfer(
+				&sponsorship.sponsor,
+				&T::FeeReceiver::get(),
+				fee,
+				Preservation::Preserve,
+			)
+			.is_err()
+			{
+				continue;
+			}
+
+			sponsorship.spent = spent;
+			let sponsor = sponsorship.sponsor.clone();
+			Sponsorships::<T>::insert(id, sponsorship);
+
+			Self::deposit_event(Event::ExecutionSponsored {
+				id,
+				sponsor,
+				origin: origin.clone(),
+				fee,
+			});
+
+			return Some(id);
+		}
+
+		None
+	}
+}
+
+/// Barrier allowing execution of messages from origins with an active sponsorship.
+///
+/// The execution fee of `max_weight` is charged to the sponsor. Messages which are not sponsored are rejected, so
+/// the barrier should be evaluated after the barriers of paid execution.
+pub struct AllowSponsoredExecutionFrom<T>(PhantomData<T>);
+
+impl<T: Config> ShouldExecute for AllowSponsoredExecutionFrom<T> {
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		_instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		Pallet::<T>::sponsor_execution(origin, max_weight)
+			.map(|_| ())
+			.ok_or(ProcessMessageError::Unsupported)
+	}
+}
@@ -0,0 +1,194 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod barrier;
+mod cancel;
+mod register;
+mod update;
+
+use crate as pallet_xcm_fee_sponsor;
+use crate::*;
+
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::Everything,
+	weights::IdentityFee,
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = sp_runtime::AccountId32;
+pub(crate) type Balance = u128;
+
+pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
+pub const TREASURY: AccountId = AccountId::new([9; 32]);
+
+pub const PARA_ID: u32 = 2_000;
+pub const OTHER_PARA_ID: u32 = 3_000;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		XcmFeeSponsor: pallet_xcm_fee_sponsor,
+	}
+);
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1_000;
+	pub const FeeReceiver: AccountId = TREASURY;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type WeightToFee = IdentityFee<Balance>;
+	type FeeReceiver = FeeReceiver;
+	type MaxSponsorshipsPerParachain = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub fn sibling_location() -> Location {
+	Location::new(1, [Parachain(PARA_ID)])
+}
+
+pub fn sibling_account_location(account: AccountId) -> Location {
+	Location::new(
+		1,
+		[
+			Parachain(PARA_ID),
+			AccountId32 {
+				network: None,
+				id: account.into(),
+			},
+		],
+	)
+}
+
+pub fn register(
+	sponsor: AccountId,
+	origin: Location,
+	period_cap: Balance,
+	period: u64,
+	duration: u64,
+) -> DispatchResult {
+	XcmFeeSponsor::register_sponsorship(
+		RuntimeOrigin::signed(sponsor),
+		Box::new(VersionedLocation::from(origin)),
+		period_cap,
+		period,
+		duration,
+	)
+}
+
+pub fn execute(origin: &Location, weight: u64) -> Result<(), ProcessMessageError> {
+	AllowSponsoredExecutionFrom::<Test>::should_execute::<()>(
+		origin,
+		&mut [],
+		Weight::from_parts(weight, 0),
+		&mut Properties {
+			weight_credit: Weight::zero(),
+			message_id: None,
+		},
+	)
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,153 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sponsored_execution_should_charge_sponsor_when_origin_matches() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+
+			// Act
+			assert_ok!(execute(&sibling_account_location(BOB), 1_000_000));
+
+			// Assert
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * ONE - 1_000_000);
+			assert_eq!(Balances::free_balance(TREASURY), 1_000_000);
+			assert_eq!(XcmFeeSponsor::sponsorships(0).map(|s| s.spent), Some(1_000_000));
+			expect_events(vec![Event::ExecutionSponsored {
+				id: 0,
+				sponsor: ALICE,
+				origin: sibling_account_location(BOB),
+				fee: 1_000_000,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn sponsored_execution_should_fail_when_origin_does_not_match() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(register(ALICE, sibling_account_location(BOB), 100 * ONE, 10, 100));
+
+			assert_eq!(
+				execute(&sibling_account_location(ALICE), 1_000_000),
+				Err(ProcessMessageError::Unsupported)
+			);
+			assert_eq!(
+				execute(&sibling_location(), 1_000_000),
+				Err(ProcessMessageError::Unsupported)
+			);
+			assert_eq!(
+				execute(&Location::new(1, [Parachain(OTHER_PARA_ID)]), 1_000_000),
+				Err(ProcessMessageError::Unsupported)
+			);
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * ONE);
+		});
+}
+
+#[test]
+fn sponsored_execution_should_fail_when_period_cap_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(register(ALICE, sibling_location(), 1_500_000, 10, 100));
+			assert_ok!(execute(&sibling_location(), 1_000_000));
+
+			// Act & Assert
+			assert_eq!(
+				execute(&sibling_location(), 1_000_000),
+				Err(ProcessMessageError::Unsupported)
+			);
+			assert_ok!(execute(&sibling_location(), 500_000));
+			assert_eq!(XcmFeeSponsor::sponsorships(0).map(|s| s.spent), Some(1_500_000));
+		});
+}
+
+#[test]
+fn sponsored_execution_should_reset_spent_amount_when_new_period_starts() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(register(ALICE, sibling_location(), 1_000_000, 10, 100));
+			assert_ok!(execute(&sibling_location(), 1_000_000));
+
+			// Act
+			System::set_block_number(10);
+			assert_ok!(execute(&sibling_location(), 1_000_000));
+
+			// Assert
+			let sponsorship = XcmFeeSponsor::sponsorships(0).unwrap();
+			assert_eq!(sponsorship.current_period, 1);
+			assert_eq!(sponsorship.spent, 1_000_000);
+			assert_eq!(Balances::free_balance(TREASURY), 2_000_000);
+		});
+}
+
+#[test]
+fn sponsored_execution_should_charge_next_sponsorship_when_first_cannot_pay() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * ONE), (BOB, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(register(ALICE, sibling_location(), 500_000, 10, 100));
+			assert_ok!(register(BOB, sibling_location(), 100 * ONE, 10, 100));
+
+			// Act
+			assert_ok!(execute(&sibling_location(), 1_000_000));
+
+			// Assert
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * ONE);
+			assert_eq!(Balances::free_balance(BOB), 1_000 * ONE - 1_000_000);
+		});
+}
+
+#[test]
+fn sponsored_execution_should_fail_when_sponsor_has_insufficient_balance() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000_500)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+
+			assert_eq!(
+				execute(&sibling_location(), 1_000_000),
+				Err(ProcessMessageError::Unsupported)
+			);
+			assert_eq!(Balances::free_balance(ALICE), 1_000_500);
+		});
+}
+
+#[test]
+fn sponsored_execution_should_remove_sponsorship_when_expired() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+			System::set_block_number(101);
+
+			// Act
+			assert_eq!(
+				execute(&sibling_location(), 1_000_000),
+				Err(ProcessMessageError::Unsupported)
+			);
+
+			// Assert
+			assert_eq!(XcmFeeSponsor::sponsorships(0), None);
+			assert!(!ParachainSponsorships::<Test>::contains_key(PARA_ID));
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * ONE);
+			expect_events(vec![Event::SponsorshipExpired { id: 0 }.into()]);
+		});
+}
@@ -0,0 +1,61 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn cancel_sponsorship_should_remove_sponsorship() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+		assert_ok!(register(BOB, sibling_location(), 100 * ONE, 10, 100));
+
+		// Act
+		assert_ok!(XcmFeeSponsor::cancel_sponsorship(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(XcmFeeSponsor::sponsorships(0), None);
+		assert_eq!(XcmFeeSponsor::parachain_sponsorships(PARA_ID).to_vec(), vec![1]);
+		expect_events(vec![Event::SponsorshipCancelled { id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn cancel_sponsorship_should_fail_when_caller_is_not_sponsor() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+
+		assert_noop!(
+			XcmFeeSponsor::cancel_sponsorship(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NotSponsor
+		);
+	});
+}
+
+#[test]
+fn remove_expired_sponsorship_should_remove_sponsorship_when_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+		System::set_block_number(101);
+
+		// Act
+		assert_ok!(XcmFeeSponsor::remove_expired_sponsorship(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(XcmFeeSponsor::sponsorships(0), None);
+		assert!(!ParachainSponsorships::<Test>::contains_key(PARA_ID));
+		expect_events(vec![Event::SponsorshipExpired { id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn remove_expired_sponsorship_should_fail_when_not_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+		System::set_block_number(100);
+
+		assert_noop!(
+			XcmFeeSponsor::remove_expired_sponsorship(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::SponsorshipNotExpired
+		);
+	});
+}
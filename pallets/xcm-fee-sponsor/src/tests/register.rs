@@ -0,0 +1,109 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn register_sponsorship_should_store_sponsorship() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+
+		// Assert
+		assert_eq!(
+			XcmFeeSponsor::sponsorships(0),
+			Some(Sponsorship {
+				sponsor: ALICE,
+				para_id: PARA_ID,
+				origin: sibling_location(),
+				period_cap: 100 * ONE,
+				period: 10,
+				expires_at: 101,
+				current_period: 0,
+				spent: 0,
+			})
+		);
+		assert_eq!(XcmFeeSponsor::parachain_sponsorships(PARA_ID).to_vec(), vec![0]);
+		assert_eq!(XcmFeeSponsor::next_sponsorship_id(), 1);
+		expect_events(vec![Event::SponsorshipRegistered {
+			id: 0,
+			sponsor: ALICE,
+			origin: sibling_location(),
+			period_cap: 100 * ONE,
+			period: 10,
+			expires_at: 101,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn register_sponsorship_should_work_when_origin_is_account_of_sibling_parachain() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(register(ALICE, sibling_account_location(BOB), 100 * ONE, 10, 100));
+
+		// Assert
+		assert_eq!(
+			XcmFeeSponsor::sponsorships(0).map(|s| s.origin),
+			Some(sibling_account_location(BOB))
+		);
+		assert_eq!(XcmFeeSponsor::parachain_sponsorships(PARA_ID).to_vec(), vec![0]);
+	});
+}
+
+#[test]
+fn register_sponsorship_should_fail_when_origin_is_not_sibling_parachain() {
+	ExtBuilder::default().build().execute_with(|| {
+		for origin in [
+			Location::parent(),
+			Location::here(),
+			Location::new(0, [Parachain(PARA_ID)]),
+			Location::new(2, [GlobalConsensus(Kusama), Parachain(PARA_ID)]),
+		] {
+			assert_noop!(
+				register(ALICE, origin, 100 * ONE, 10, 100),
+				Error::<Test>::NotSiblingParachain
+			);
+		}
+	});
+}
+
+#[test]
+fn register_sponsorship_should_fail_when_parameters_are_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			register(ALICE, sibling_location(), 0, 10, 100),
+			Error::<Test>::InvalidPeriodCap
+		);
+		assert_noop!(
+			register(ALICE, sibling_location(), 100 * ONE, 0, 100),
+			Error::<Test>::InvalidPeriod
+		);
+		assert_noop!(
+			register(ALICE, sibling_location(), 100 * ONE, 10, 0),
+			Error::<Test>::InvalidDuration
+		);
+	});
+}
+
+#[test]
+fn register_sponsorship_should_fail_when_parachain_has_max_sponsorships() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		for _ in 0..3 {
+			assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+		}
+
+		// Act & Assert
+		assert_noop!(
+			register(BOB, sibling_location(), 100 * ONE, 10, 100),
+			Error::<Test>::TooManySponsorships
+		);
+		assert_ok!(register(
+			BOB,
+			Location::new(1, [Parachain(OTHER_PARA_ID)]),
+			100 * ONE,
+			10,
+			100
+		));
+	});
+}
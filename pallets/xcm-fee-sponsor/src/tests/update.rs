@@ -0,0 +1,52 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn update_sponsorship_should_update_cap_and_expiration() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+		System::set_block_number(50);
+
+		// Act
+		assert_ok!(XcmFeeSponsor::update_sponsorship(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			200 * ONE,
+			100
+		));
+
+		// Assert
+		let sponsorship = XcmFeeSponsor::sponsorships(0).unwrap();
+		assert_eq!(sponsorship.period_cap, 200 * ONE);
+		assert_eq!(sponsorship.expires_at, 150);
+		expect_events(vec![Event::SponsorshipUpdated {
+			id: 0,
+			period_cap: 200 * ONE,
+			expires_at: 150,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_sponsorship_should_fail_when_caller_is_not_sponsor() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register(ALICE, sibling_location(), 100 * ONE, 10, 100));
+
+		assert_noop!(
+			XcmFeeSponsor::update_sponsorship(RuntimeOrigin::signed(BOB), 0, 200 * ONE, 100),
+			Error::<Test>::NotSponsor
+		);
+	});
+}
+
+#[test]
+fn update_sponsorship_should_fail_when_sponsorship_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmFeeSponsor::update_sponsorship(RuntimeOrigin::signed(ALICE), 0, 200 * ONE, 100),
+			Error::<Test>::SponsorshipNotFound
+		);
+	});
+}
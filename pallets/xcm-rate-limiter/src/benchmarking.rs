@@ -0,0 +1,82 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+
+const GROUP_ID: GroupId = 1;
+const LIMIT: u128 = 1_000_000_000_000_000;
+const PARA_ID: u32 = 2000;
+
+/// Deferred message of maximal length.
+fn deferred_message<T: Config>() -> DeferredMessageOf<T> {
+	let len = <T::XcmpQueue as EnqueueMessage<ParaId>>::MaxMessageLen::get();
+	DeferredMessage {
+		sent_at: 1,
+		deferred_to: 1,
+		message: MessageOf::<T>::truncate_from(sp_std::vec![0; len as usize]),
+	}
+}
+
+benchmarks! {
+	set_group_rate_limit {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		GroupAccumulatedAmounts::<T>::insert(GROUP_ID, AccumulatedAmount { amount: LIMIT, last_updated: 1 });
+	}: _<T::RuntimeOrigin>(origin, GROUP_ID, None)
+	verify {
+		assert!(Pallet::<T>::group_rate_limit(GROUP_ID).is_none());
+		assert_eq!(Pallet::<T>::group_accumulated_amount(GROUP_ID), AccumulatedAmount::default());
+	}
+
+	set_asset_group {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let asset_id = T::AssetId::from(2u32);
+	}: _<T::RuntimeOrigin>(origin, asset_id, Some(GROUP_ID))
+	verify {
+		assert_eq!(Pallet::<T>::asset_group(asset_id), Some(GROUP_ID));
+	}
+
+	release_deferred {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let para_id = ParaId::from(PARA_ID);
+		DeferredMessages::<T>::insert(para_id, 0, deferred_message::<T>());
+	}: _<T::RuntimeOrigin>(origin, para_id, 0)
+	verify {
+		assert!(Pallet::<T>::deferred_message(para_id, 0).is_none());
+	}
+
+	discard_deferred {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let para_id = ParaId::from(PARA_ID);
+		DeferredMessages::<T>::insert(para_id, 0, deferred_message::<T>());
+	}: _<T::RuntimeOrigin>(origin, para_id, 0)
+	verify {
+		assert!(Pallet::<T>::deferred_message(para_id, 0).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -27,8 +27,22 @@
 //! The `RateLimitFor` associated type is supposed to be provided by the `AssetRegistry`, but could work with any other
 //! implementation.
 //!
-//! This pallet does not provide any extrinsics of its own, but it is meant to provide the implementation of
-//! `XcmDeferFilter` for the `XcmpQueue`.
+//! The pallet is meant to provide the implementation of `XcmDeferFilter` for the `XcmpQueue`. It also holds the
+//! messages deferred by the filter, so it is configured as the queue the `XcmpQueue` enqueues incoming messages into
+//! and forwards them to the actual message queue given by `XcmpQueue` of this pallet.
+//!
+//! ### Deferred messages
+//!
+//! Messages that are not deferred are passed to the message queue right away. Deferred messages are stored in
+//! `DeferredMessages` by sender and index and are passed to the message queue in `on_idle` once the relay chain
+//! block they are deferred to is reached. `on_idle` scans the stored messages from where it stopped in the previous
+//! block, so releasing doesn't start over when the remaining weight is not enough to scan all of them.
+//!
+//! `UpdateOrigin` can review deferred messages and release them before the deferral period ends via
+//! `release_deferred`, or remove them without processing via `discard_deferred`. Deferred messages of a sender are
+//! listed by `deferred_messages`.
+//!
+//! Amounts of deferred messages stay accumulated when the messages are released early or discarded.
 //!
 //! ### Implementation
 //!
@@ -47,6 +61,16 @@
 //! The accumulated amounts decay linearly at the rate limit. For example: With rate limit 1000 tokens per 10 blocks,
 //! the accumulated amount will be reduced by 100 tokens per block.
 //!
+//! ### Limit groups
+//!
+//! Assets can be assigned to a limit group, e.g. all stablecoins or all bridged variants of ETH. Incoming amounts of
+//! all members of a group accumulate in a single amount, which is limited by the rate limit of the group. Splitting
+//! a transfer across near-fungible assets therefore doesn't evade the limit. The group limit applies on top of the
+//! limits of the individual assets, and the XCM is deferred by the larger of the durations.
+//!
+//! Amounts of the members are added up as they are, so a group should only contain assets with the same decimals.
+//! Groups and their limits are configured by `UpdateOrigin` via `set_group_rate_limit` and `set_asset_group`.
+//!
 //! The filter works with XCM v3 and so assumes that other versions can be converted to it.
 //!
 //! The filter processes only the first instruction of the XCM message, because that is how assets will arrive on chain.
@@ -55,23 +79,27 @@
 //! ### Limitations
 //!
 //! - The filter assumes that it is fine to ignore (neither track nor limit) tokens that don't have a defined local id
-//!   or don't have a configured rate limit.
+//!   or have neither a configured rate limit nor a limit group with a rate limit.
 //! - It counts accumulated amounts via `MultiLocation`s of incoming messages without reanchoring or canonicalizing.
 //! - It only tracks and limits incoming tokens, not outgoing.
 //! - Only tracks and limits `ReserveAssetDeposited` and `ReceiveTeleportedAsset`, meaning that core asset tokens
 //!   "returning" from other chains are not tracked or limited.
+//! - The number of deferred messages is not capped, it is bounded by the capacity of the XCMP channels and
+//!   `MaxDeferDuration`. A sender can't get its messages processed sooner by filling the storage.
+//! - Messages that can't be decoded are not deferred, the message queue fails to process them anyway.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeLimit, Encode};
 use cumulus_pallet_xcmp_queue::XcmDeferFilter;
 use frame_support::pallet_prelude::Weight;
-use frame_support::traits::Get;
+use frame_support::traits::{EnqueueMessage, Get, QueueFootprint};
+use frame_support::{BoundedSlice, BoundedVec};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::rate_limiter::{calculate_deferred_duration, calculate_new_accumulated_amount};
 
 use orml_traits::GetByKey;
-use polkadot_parachain::primitives::RelayChainBlockNumber;
+use polkadot_parachain::primitives::{Id as ParaId, RelayChainBlockNumber};
 use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
 use sp_runtime::traits::AtLeast32BitUnsigned;
@@ -83,12 +111,22 @@ use sp_std::vec::Vec;
 use xcm::lts::prelude::*;
 use xcm::VersionedXcm;
 use xcm::VersionedXcm::V3;
+use xcm::MAX_XCM_DECODE_DEPTH;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub mod weights;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Identifier of a limit group.
+pub type GroupId = u32;
 
 #[derive(Clone, Default, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
 pub struct AccumulatedAmount {
@@ -96,18 +134,40 @@ pub struct AccumulatedAmount {
 	pub last_updated: RelayChainBlockNumber,
 }
 
+/// Index of a deferred message of a sender.
+pub type DeferredIndex = u32;
+
+/// Message deferred by the rate limiter.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct DeferredMessage<Message> {
+	/// Relay chain block in which the message was received.
+	pub sent_at: RelayChainBlockNumber,
+	/// Relay chain block from which the message is passed to the message queue.
+	pub deferred_to: RelayChainBlockNumber,
+	/// Encoded `VersionedXcm` as received from the sender.
+	pub message: Message,
+}
+
+pub type MessageOf<T> = BoundedVec<u8, <<T as Config>::XcmpQueue as EnqueueMessage<ParaId>>::MaxMessageLen>;
+pub type DeferredMessageOf<T> = DeferredMessage<MessageOf<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
 
+	use frame_system::pallet_prelude::OriginFor;
 	use polkadot_parachain::primitives::RelayChainBlockNumber;
 	use sp_runtime::traits::BlockNumberProvider;
 	use xcm::lts::MultiLocation;
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::release_due_messages(remaining_weight)
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -141,6 +201,15 @@ pub mod pallet {
 
 		/// Xcm rate limit getter for each asset
 		type RateLimitFor: GetByKey<Self::AssetId, Option<u128>>;
+
+		/// Origin able to configure limit groups and to release or discard deferred messages.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Queue that receives messages which are not deferred and deferred messages once they are released.
+		type XcmpQueue: EnqueueMessage<ParaId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -152,18 +221,248 @@ pub mod pallet {
 	pub type AccumulatedAmounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, MultiLocation, AccumulatedAmount, ValueQuery>;
 
+	#[pallet::storage]
+	/// Limit group of an asset
+	#[pallet::getter(fn asset_group)]
+	pub type AssetGroups<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, GroupId>;
+
+	#[pallet::storage]
+	/// Rate limit shared by all assets of a limit group
+	#[pallet::getter(fn group_rate_limit)]
+	pub type GroupRateLimits<T: Config> = StorageMap<_, Blake2_128Concat, GroupId, u128>;
+
+	#[pallet::storage]
+	/// Accumulated amounts for each limit group
+	#[pallet::getter(fn group_accumulated_amount)]
+	pub type GroupAccumulatedAmounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, GroupId, AccumulatedAmount, ValueQuery>;
+
+	#[pallet::storage]
+	/// Messages deferred by the rate limiter by sender and index
+	#[pallet::getter(fn deferred_message)]
+	pub type DeferredMessages<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ParaId, Twox64Concat, DeferredIndex, DeferredMessageOf<T>>;
+
+	#[pallet::storage]
+	/// Index of the next deferred message of a sender
+	pub type NextDeferredIndex<T: Config> = StorageMap<_, Blake2_128Concat, ParaId, DeferredIndex, ValueQuery>;
+
+	#[pallet::storage]
+	/// Last deferred message scanned by `on_idle`, scanning continues after it in the next block
+	pub type ReleaseCursor<T: Config> = StorageValue<_, (ParaId, DeferredIndex), OptionQuery>;
+
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Rate limit of a limit group was set or removed.
+		GroupRateLimitSet { group_id: GroupId, limit: Option<u128> },
+		/// Asset was assigned to a limit group or removed from its group.
+		AssetGroupSet {
+			asset_id: T::AssetId,
+			group_id: Option<GroupId>,
+		},
+		/// Incoming message was deferred.
+		XcmDeferred {
+			para_id: ParaId,
+			index: DeferredIndex,
+			deferred_to: RelayChainBlockNumber,
+		},
+		/// Deferred message was passed to the message queue.
+		DeferredXcmReleased { para_id: ParaId, index: DeferredIndex },
+		/// Deferred message was removed without being processed.
+		DeferredXcmDiscarded { para_id: ParaId, index: DeferredIndex },
+	}
 
 	#[pallet::error]
 	#[cfg_attr(test, derive(PartialEq, Eq))]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// Deferred message with given sender and index doesn't exist.
+		DeferredXcmNotFound,
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Set rate limit shared by all assets of a limit group.
+		///
+		/// `None` removes the limit and the accumulated amount of the group. Assets stay assigned to the group, but
+		/// only their own limits apply until the group limit is set again.
+		///
+		/// Can only be called by `UpdateOrigin`.
+		///
+		/// Emits `GroupRateLimitSet` event.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_group_rate_limit())]
+		pub fn set_group_rate_limit(origin: OriginFor<T>, group_id: GroupId, limit: Option<u128>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			match limit {
+				Some(limit) => GroupRateLimits::<T>::insert(group_id, limit),
+				None => {
+					GroupRateLimits::<T>::remove(group_id);
+					GroupAccumulatedAmounts::<T>::remove(group_id);
+				}
+			}
+
+			Self::deposit_event(Event::GroupRateLimitSet { group_id, limit });
+
+			Ok(())
+		}
+
+		/// Assign asset to a limit group.
+		///
+		/// Incoming amounts of the asset are accumulated in the amount of the group from now on. Asset can be a member
+		/// of one group only, assigning it to another group moves it. `None` removes the asset from its group.
+		///
+		/// Can only be called by `UpdateOrigin`.
+		///
+		/// Emits `AssetGroupSet` event.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_group())]
+		pub fn set_asset_group(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			group_id: Option<GroupId>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			AssetGroups::<T>::set(asset_id, group_id);
+
+			Self::deposit_event(Event::AssetGroupSet { asset_id, group_id });
+
+			Ok(())
+		}
+
+		/// Pass deferred message to the message queue before its deferral period ends.
+		///
+		/// The message is processed by the message queue without being checked by the rate limiter again.
+		///
+		/// Can only be called by `UpdateOrigin`.
+		///
+		/// Emits `DeferredXcmReleased` event.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_deferred())]
+		pub fn release_deferred(origin: OriginFor<T>, para_id: ParaId, index: DeferredIndex) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let deferred = DeferredMessages::<T>::take(para_id, index).ok_or(Error::<T>::DeferredXcmNotFound)?;
+			Self::release(para_id, index, deferred);
+
+			Ok(())
+		}
+
+		/// Remove deferred message without processing it.
+		///
+		/// Assets of the message are not received, it is up to the sender to return them to their owner.
+		///
+		/// Can only be called by `UpdateOrigin`.
+		///
+		/// Emits `DeferredXcmDiscarded` event.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::discard_deferred())]
+		pub fn discard_deferred(origin: OriginFor<T>, para_id: ParaId, index: DeferredIndex) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			DeferredMessages::<T>::take(para_id, index).ok_or(Error::<T>::DeferredXcmNotFound)?;
+
+			Self::deposit_event(Event::DeferredXcmDiscarded { para_id, index });
+
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Deferred messages of a sender with their indices.
+	pub fn deferred_messages(para_id: ParaId) -> Vec<(DeferredIndex, DeferredMessageOf<T>)> {
+		DeferredMessages::<T>::iter_prefix(para_id).collect()
+	}
+
+	fn release(para_id: ParaId, index: DeferredIndex, deferred: DeferredMessageOf<T>) {
+		T::XcmpQueue::enqueue_message(deferred.message.as_bounded_slice(), para_id);
+		Self::deposit_event(Event::DeferredXcmReleased { para_id, index });
+	}
+
+	/// Passes messages whose deferral period has ended to the message queue, as long as the weight allows.
+	///
+	/// Continues scanning after the message scanned last in the previous call and starts over once all messages have
+	/// been scanned.
+	fn release_due_messages(remaining_weight: Weight) -> Weight {
+		// read of the relay chain block number and read and write of the cursor
+		let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+		let scan_weight = T::DbWeight::get().reads(1);
+		let release_weight = <T as Config>::WeightInfo::release_deferred();
+		if remaining_weight.any_lt(used_weight.saturating_add(scan_weight).saturating_add(release_weight)) {
+			return Weight::zero();
+		}
+
+		let now = T::RelayBlockNumberProvider::current_block_number();
+		let mut cursor = ReleaseCursor::<T>::get();
+		let mut messages = match cursor {
+			Some((para_id, index)) => {
+				DeferredMessages::<T>::iter_from(DeferredMessages::<T>::hashed_key_for(para_id, index))
+			}
+			None => DeferredMessages::<T>::iter(),
+		};
+
+		while !remaining_weight.any_lt(used_weight.saturating_add(scan_weight).saturating_add(release_weight)) {
+			let Some((para_id, index, deferred)) = messages.next() else {
+				cursor = None;
+				break;
+			};
+			used_weight.saturating_accrue(scan_weight);
+			cursor = Some((para_id, index));
+
+			if deferred.deferred_to <= now {
+				DeferredMessages::<T>::remove(para_id, index);
+				Self::release(para_id, index, deferred);
+				used_weight.saturating_accrue(release_weight);
+			}
+		}
+
+		ReleaseCursor::<T>::set(cursor);
+
+		used_weight
+	}
+
+	/// Stores message to be passed to the message queue at `deferred_to`.
+	fn defer(
+		para_id: ParaId,
+		sent_at: RelayChainBlockNumber,
+		deferred_to: RelayChainBlockNumber,
+		message: MessageOf<T>,
+	) {
+		let index = NextDeferredIndex::<T>::mutate(para_id, |next| {
+			let index = *next;
+			*next = next.wrapping_add(1);
+			index
+		});
+		DeferredMessages::<T>::insert(
+			para_id,
+			index,
+			DeferredMessage {
+				sent_at,
+				deferred_to,
+				message,
+			},
+		);
+
+		Self::deposit_event(Event::XcmDeferred {
+			para_id,
+			index,
+			deferred_to,
+		});
+	}
+
+	/// Returns relay chain block the message is deferred to, `None` if it should be processed right away.
+	fn deferred_to(para_id: ParaId, sent_at: RelayChainBlockNumber, message: &[u8]) -> Option<RelayChainBlockNumber> {
+		let versioned_xcm =
+			VersionedXcm::<T::RuntimeCall>::decode_all_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &message[..])
+				.ok()?;
+		let (_, deferred_by) = Self::deferred_by(para_id, sent_at, &versioned_xcm);
+
+		deferred_by.map(|deferred_by| sent_at.saturating_add(deferred_by))
+	}
+
 	fn get_locations_and_amounts(instruction: &Instruction<T::RuntimeCall>) -> Vec<(MultiLocation, u128)> {
 		use Instruction::*;
 		match instruction {
@@ -174,6 +473,62 @@ impl<T: Config> Pallet<T> {
 			_ => Vec::new(),
 		}
 	}
+
+	/// Adds incoming amount to the decayed accumulated amount and returns the new accumulated amount with the
+	/// duration to defer by.
+	fn accumulate(
+		accumulated: AccumulatedAmount,
+		limit_per_duration: u128,
+		amount: u128,
+		current_time: RelayChainBlockNumber,
+	) -> (AccumulatedAmount, RelayChainBlockNumber) {
+		let defer_duration: u32 = T::DeferDuration::get();
+		let time_difference = current_time.saturating_sub(accumulated.last_updated);
+
+		let new_accumulated_amount = calculate_new_accumulated_amount(
+			defer_duration,
+			limit_per_duration,
+			amount,
+			accumulated.amount,
+			time_difference.saturated_into(),
+		);
+
+		let deferred_by = calculate_deferred_duration(defer_duration, limit_per_duration, new_accumulated_amount);
+
+		(
+			AccumulatedAmount {
+				amount: new_accumulated_amount,
+				last_updated: current_time,
+			},
+			deferred_by,
+		)
+	}
+
+	/// Tracks incoming amount of an asset in the limit group of the asset, if the group has a rate limit.
+	///
+	/// Returns the duration to defer by and the weight used.
+	fn track_group(
+		asset_id: T::AssetId,
+		amount: u128,
+		current_time: RelayChainBlockNumber,
+	) -> (RelayChainBlockNumber, Weight) {
+		let Some(group_id) = AssetGroups::<T>::get(asset_id) else {
+			return (0, T::DbWeight::get().reads(1));
+		};
+		let Some(limit_per_duration) = GroupRateLimits::<T>::get(group_id) else {
+			return (0, T::DbWeight::get().reads(2));
+		};
+
+		let (accumulated, deferred_by) = Self::accumulate(
+			GroupAccumulatedAmounts::<T>::get(group_id),
+			limit_per_duration,
+			amount,
+			current_time,
+		);
+		GroupAccumulatedAmounts::<T>::insert(group_id, accumulated);
+
+		(deferred_by, T::DbWeight::get().reads_writes(3, 1))
+	}
 }
 
 fn get_loc_and_amount(m: &MultiAsset) -> Option<(MultiLocation, u128)> {
@@ -212,39 +567,30 @@ impl<T: Config> XcmDeferFilter<T::RuntimeCall> for Pallet<T> {
 				total_weight.saturating_accrue(T::DbWeight::get().reads(1));
 				continue;
 			};
-			// ... or that don't have a rate limit configured.
+			let current_time = T::RelayBlockNumberProvider::current_block_number();
+
+			// Amounts of assets in a limit group are limited by the group as well.
+			let (group_deferred_by, group_weight) = Pallet::<T>::track_group(asset_id, amount, current_time);
+			total_deferred_by = group_deferred_by.max(total_deferred_by);
+			total_weight.saturating_accrue(group_weight);
+
+			// Assets without a rate limit of their own are limited only by their group.
 			let Some(limit_per_duration) = T::RateLimitFor::get(&asset_id) else {
 				total_weight.saturating_accrue(T::DbWeight::get().reads(2));
 				continue;
 			};
-			let defer_duration: u32 = T::DeferDuration::get();
 
-			let current_time = T::RelayBlockNumberProvider::current_block_number();
 			// let's assume one read for `RateLimitFor` as well as a read and write for `AccumulatedAmounts` updates.
 			let weight = if current_time == accumulated_liquidity.last_updated {
 				T::DbWeight::get().reads(1)
 			} else {
 				T::DbWeight::get().reads_writes(2, 1)
 			};
-			let time_difference = current_time.saturating_sub(accumulated_liquidity.last_updated);
-
-			let new_accumulated_amount = calculate_new_accumulated_amount(
-				defer_duration,
-				limit_per_duration,
-				amount,
-				accumulated_liquidity.amount,
-				time_difference.saturated_into(),
-			);
-
-			let deferred_by = calculate_deferred_duration(defer_duration, limit_per_duration, new_accumulated_amount);
-
-			AccumulatedAmounts::<T>::insert(
-				location,
-				AccumulatedAmount {
-					amount: new_accumulated_amount,
-					last_updated: current_time,
-				},
-			);
+
+			let (accumulated, deferred_by) =
+				Pallet::<T>::accumulate(accumulated_liquidity, limit_per_duration, amount, current_time);
+
+			AccumulatedAmounts::<T>::insert(location, accumulated);
 
 			total_deferred_by = deferred_by.max(total_deferred_by);
 			total_weight.saturating_accrue(weight);
@@ -257,3 +603,41 @@ impl<T: Config> XcmDeferFilter<T::RuntimeCall> for Pallet<T> {
 		}
 	}
 }
+
+/// Defers incoming messages that exceed the rate limits and passes the rest to `T::XcmpQueue`.
+impl<T: Config> EnqueueMessage<ParaId> for Pallet<T> {
+	type MaxMessageLen = <T::XcmpQueue as EnqueueMessage<ParaId>>::MaxMessageLen;
+
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: ParaId) {
+		Self::enqueue_messages(sp_std::iter::once(message), origin);
+	}
+
+	fn enqueue_messages<'a>(messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>, origin: ParaId) {
+		let sent_at = T::RelayBlockNumberProvider::current_block_number();
+
+		let mut not_deferred = Vec::new();
+		for message in messages {
+			match Self::deferred_to(origin, sent_at, &message[..]) {
+				Some(deferred_to) => Self::defer(
+					origin,
+					sent_at,
+					deferred_to,
+					BoundedVec::truncate_from(message.to_vec()),
+				),
+				None => not_deferred.push(message),
+			}
+		}
+
+		if !not_deferred.is_empty() {
+			T::XcmpQueue::enqueue_messages(not_deferred.into_iter(), origin);
+		}
+	}
+
+	fn sweep_queue(origin: ParaId) {
+		T::XcmpQueue::sweep_queue(origin);
+	}
+
+	fn footprint(origin: ParaId) -> QueueFootprint {
+		T::XcmpQueue::footprint(origin)
+	}
+}
@@ -0,0 +1,323 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::defer_xcm::create_versioned_reserve_asset_deposited;
+use crate::tests::mock::*;
+use crate::*;
+use codec::Encode;
+use frame_support::traits::{EnqueueMessage, Hooks};
+use frame_support::{assert_noop, BoundedSlice};
+use sp_runtime::DispatchError::BadOrigin;
+
+pub use pretty_assertions::assert_eq;
+
+const PARA_ID: u32 = 999;
+
+fn message(amount: u128) -> Vec<u8> {
+	create_versioned_reserve_asset_deposited(MultiLocation::here(), amount).encode()
+}
+
+fn enqueue(message: &[u8]) {
+	XcmRateLimiter::enqueue_message(BoundedSlice::try_from(message).unwrap(), PARA_ID.into());
+}
+
+#[test]
+fn enqueue_message_should_pass_message_within_limit_to_queue() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		enqueue(&message(500 * ONE));
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![(PARA_ID.into(), message(500 * ONE))]);
+		assert!(XcmRateLimiter::deferred_messages(PARA_ID.into()).is_empty());
+	});
+}
+
+#[test]
+fn enqueue_message_should_store_message_exceeding_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		enqueue(&message(2000 * ONE));
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![]);
+		assert_eq!(
+			XcmRateLimiter::deferred_message(ParaId::from(PARA_ID), 0),
+			Some(DeferredMessage {
+				sent_at: 1,
+				deferred_to: 11,
+				message: BoundedVec::truncate_from(message(2000 * ONE)),
+			})
+		);
+		System::assert_last_event(
+			Event::<Test>::XcmDeferred {
+				para_id: PARA_ID.into(),
+				index: 0,
+				deferred_to: 11,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn enqueue_messages_should_pass_only_messages_which_are_not_deferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let without_limit =
+			create_versioned_reserve_asset_deposited(MultiLocation::new(1, GeneralIndex(43)), 2000 * ONE).encode();
+		let messages = [message(500 * ONE), message(2000 * ONE), without_limit.clone()];
+
+		//Act
+		XcmRateLimiter::enqueue_messages(
+			messages
+				.iter()
+				.map(|message| BoundedSlice::try_from(&message[..]).unwrap()),
+			PARA_ID.into(),
+		);
+
+		//Assert
+		assert_eq!(
+			enqueued_messages(),
+			vec![(PARA_ID.into(), message(500 * ONE)), (PARA_ID.into(), without_limit)]
+		);
+		let deferred = XcmRateLimiter::deferred_messages(PARA_ID.into());
+		assert_eq!(deferred.len(), 1);
+		assert_eq!(deferred[0].1.message.to_vec(), message(2000 * ONE));
+	});
+}
+
+#[test]
+fn enqueue_message_should_pass_message_which_cant_be_decoded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		enqueue(&[1, 2, 3]);
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![(PARA_ID.into(), vec![1, 2, 3])]);
+		assert!(XcmRateLimiter::deferred_messages(PARA_ID.into()).is_empty());
+	});
+}
+
+#[test]
+fn deferred_messages_should_list_messages_of_sender_only() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+		enqueue(&message(2000 * ONE));
+		XcmRateLimiter::enqueue_message(
+			BoundedSlice::try_from(&message(2000 * ONE)[..]).unwrap(),
+			(PARA_ID + 1).into(),
+		);
+
+		//Act
+		let deferred = XcmRateLimiter::deferred_messages(PARA_ID.into());
+
+		//Assert
+		let mut indices: Vec<_> = deferred.iter().map(|(index, _)| *index).collect();
+		indices.sort();
+		assert_eq!(indices, vec![0, 1]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_release_message_before_deferral_ends() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+		System::set_block_number(10);
+
+		//Act
+		XcmRateLimiter::on_idle(10, Weight::MAX);
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![]);
+		assert!(XcmRateLimiter::deferred_message(ParaId::from(PARA_ID), 0).is_some());
+	});
+}
+
+#[test]
+fn on_idle_should_release_message_when_deferral_ends() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+		System::set_block_number(11);
+
+		//Act
+		XcmRateLimiter::on_idle(11, Weight::MAX);
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![(PARA_ID.into(), message(2000 * ONE))]);
+		assert!(XcmRateLimiter::deferred_message(ParaId::from(PARA_ID), 0).is_none());
+		System::assert_last_event(
+			Event::<Test>::DeferredXcmReleased {
+				para_id: PARA_ID.into(),
+				index: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_release_messages_over_several_blocks_when_weight_is_limited() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+		enqueue(&message(2000 * ONE));
+		System::set_block_number(100);
+		let release_weight = <Test as Config>::WeightInfo::release_deferred();
+
+		//Act
+		let used_weight = XcmRateLimiter::on_idle(100, release_weight);
+
+		//Assert
+		assert_eq!(used_weight, release_weight);
+		assert_eq!(enqueued_messages().len(), 1);
+		assert_eq!(XcmRateLimiter::deferred_messages(PARA_ID.into()).len(), 1);
+		assert!(ReleaseCursor::<Test>::get().is_some());
+
+		//Act
+		// the remaining message is released in the next block, or in the one after if scanning reaches the end of
+		// the messages and starts over
+		XcmRateLimiter::on_idle(101, release_weight);
+		XcmRateLimiter::on_idle(102, release_weight);
+
+		//Assert
+		assert!(XcmRateLimiter::deferred_messages(PARA_ID.into()).is_empty());
+		assert_eq!(enqueued_messages().len(), 2);
+	});
+}
+
+#[test]
+fn on_idle_should_not_release_messages_when_weight_is_not_enough() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+		System::set_block_number(100);
+		let release_weight = <Test as Config>::WeightInfo::release_deferred();
+
+		//Act
+		let used_weight = XcmRateLimiter::on_idle(100, release_weight.saturating_sub(Weight::from_parts(1, 0)));
+
+		//Assert
+		assert_eq!(used_weight, Weight::zero());
+		assert_eq!(enqueued_messages(), vec![]);
+	});
+}
+
+#[test]
+fn release_deferred_should_pass_message_to_queue_before_deferral_ends() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+
+		//Act
+		assert_ok!(XcmRateLimiter::release_deferred(
+			RuntimeOrigin::root(),
+			PARA_ID.into(),
+			0
+		));
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![(PARA_ID.into(), message(2000 * ONE))]);
+		assert!(XcmRateLimiter::deferred_message(ParaId::from(PARA_ID), 0).is_none());
+		System::assert_last_event(
+			Event::<Test>::DeferredXcmReleased {
+				para_id: PARA_ID.into(),
+				index: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn release_deferred_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+
+		//Act & Assert
+		assert_noop!(
+			XcmRateLimiter::release_deferred(RuntimeOrigin::signed(1), PARA_ID.into(), 0),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn release_deferred_should_fail_when_message_doesnt_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act & Assert
+		assert_noop!(
+			XcmRateLimiter::release_deferred(RuntimeOrigin::root(), PARA_ID.into(), 0),
+			Error::<Test>::DeferredXcmNotFound
+		);
+	});
+}
+
+#[test]
+fn discard_deferred_should_remove_message_without_passing_it_to_queue() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+
+		//Act
+		assert_ok!(XcmRateLimiter::discard_deferred(
+			RuntimeOrigin::root(),
+			PARA_ID.into(),
+			0
+		));
+
+		//Assert
+		assert_eq!(enqueued_messages(), vec![]);
+		assert!(XcmRateLimiter::deferred_message(ParaId::from(PARA_ID), 0).is_none());
+		System::assert_last_event(
+			Event::<Test>::DeferredXcmDiscarded {
+				para_id: PARA_ID.into(),
+				index: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn discard_deferred_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		enqueue(&message(2000 * ONE));
+
+		//Act & Assert
+		assert_noop!(
+			XcmRateLimiter::discard_deferred(RuntimeOrigin::signed(1), PARA_ID.into(), 0),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn discard_deferred_should_fail_when_message_doesnt_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act & Assert
+		assert_noop!(
+			XcmRateLimiter::discard_deferred(RuntimeOrigin::root(), PARA_ID.into(), 0),
+			Error::<Test>::DeferredXcmNotFound
+		);
+	});
+}
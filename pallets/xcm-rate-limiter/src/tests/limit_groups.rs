@@ -0,0 +1,299 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::defer_xcm::create_versioned_reserve_asset_deposited;
+use crate::tests::mock::*;
+use crate::*;
+use cumulus_pallet_xcmp_queue::XcmDeferFilter;
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+
+pub use pretty_assertions::assert_eq;
+
+const STABLECOINS: GroupId = 1;
+const ASSET_WITHOUT_LIMIT: AssetId = 43;
+
+fn location(asset_id: AssetId) -> MultiLocation {
+	MultiLocation::new(1, GeneralIndex(asset_id.into()))
+}
+
+fn with_stablecoins_group(limit: u128) {
+	assert_ok!(XcmRateLimiter::set_group_rate_limit(
+		RuntimeOrigin::root(),
+		STABLECOINS,
+		Some(limit)
+	));
+	assert_ok!(XcmRateLimiter::set_asset_group(
+		RuntimeOrigin::root(),
+		DAI,
+		Some(STABLECOINS)
+	));
+	assert_ok!(XcmRateLimiter::set_asset_group(
+		RuntimeOrigin::root(),
+		ACA,
+		Some(STABLECOINS)
+	));
+}
+
+#[test]
+fn set_group_rate_limit_should_store_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(XcmRateLimiter::set_group_rate_limit(
+			RuntimeOrigin::root(),
+			STABLECOINS,
+			Some(1000 * ONE)
+		));
+
+		//Assert
+		assert_eq!(XcmRateLimiter::group_rate_limit(STABLECOINS), Some(1000 * ONE));
+		System::assert_last_event(
+			Event::<Test>::GroupRateLimitSet {
+				group_id: STABLECOINS,
+				limit: Some(1000 * ONE),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_group_rate_limit_should_remove_limit_and_accumulated_amount_when_limit_is_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		with_stablecoins_group(1000 * ONE);
+		let versioned_xcm = create_versioned_reserve_asset_deposited(location(DAI), 500 * ONE);
+		XcmRateLimiter::deferred_by(999.into(), 10, &versioned_xcm);
+
+		//Act
+		assert_ok!(XcmRateLimiter::set_group_rate_limit(
+			RuntimeOrigin::root(),
+			STABLECOINS,
+			None
+		));
+
+		//Assert
+		assert_eq!(XcmRateLimiter::group_rate_limit(STABLECOINS), None);
+		assert_eq!(
+			XcmRateLimiter::group_accumulated_amount(STABLECOINS),
+			AccumulatedAmount::default()
+		);
+	});
+}
+
+#[test]
+fn set_group_rate_limit_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmRateLimiter::set_group_rate_limit(RuntimeOrigin::signed(WHITELISTED_ACCCOUNT), STABLECOINS, Some(ONE)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_group_should_assign_asset_to_group() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(XcmRateLimiter::set_asset_group(
+			RuntimeOrigin::root(),
+			DAI,
+			Some(STABLECOINS)
+		));
+
+		//Assert
+		assert_eq!(XcmRateLimiter::asset_group(DAI), Some(STABLECOINS));
+		System::assert_last_event(
+			Event::<Test>::AssetGroupSet {
+				asset_id: DAI,
+				group_id: Some(STABLECOINS),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_asset_group_should_remove_asset_from_group_when_group_is_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		with_stablecoins_group(1000 * ONE);
+
+		//Act
+		assert_ok!(XcmRateLimiter::set_asset_group(RuntimeOrigin::root(), DAI, None));
+
+		//Assert
+		assert_eq!(XcmRateLimiter::asset_group(DAI), None);
+	});
+}
+
+#[test]
+fn set_asset_group_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmRateLimiter::set_asset_group(RuntimeOrigin::signed(WHITELISTED_ACCCOUNT), DAI, Some(STABLECOINS)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn deferred_by_should_defer_xcm_when_group_limit_is_exceeded_by_amounts_split_across_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		with_stablecoins_group(1000 * ONE);
+		let para_id = 999.into();
+
+		//Act
+		let first_deferred_by = XcmRateLimiter::deferred_by(
+			para_id,
+			10,
+			&create_versioned_reserve_asset_deposited(location(DAI), 600 * ONE),
+		)
+		.1;
+		let second_deferred_by = XcmRateLimiter::deferred_by(
+			para_id,
+			10,
+			&create_versioned_reserve_asset_deposited(location(ACA), 800 * ONE),
+		)
+		.1;
+
+		//Assert
+		assert_eq!(first_deferred_by, None);
+		// limits of the assets are not exceeded, 1400 accumulated in the group exceed its limit by 400
+		assert_eq!(second_deferred_by, Some(4));
+		assert_eq!(XcmRateLimiter::accumulated_amount(location(DAI)).amount, 600 * ONE);
+		assert_eq!(XcmRateLimiter::accumulated_amount(location(ACA)).amount, 800 * ONE);
+		assert_eq!(
+			XcmRateLimiter::group_accumulated_amount(STABLECOINS),
+			AccumulatedAmount {
+				amount: 1400 * ONE,
+				last_updated: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn deferred_by_should_defer_by_max_of_asset_and_group_durations() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		with_stablecoins_group(5000 * ONE);
+
+		//Act
+		let deferred_by = XcmRateLimiter::deferred_by(
+			999.into(),
+			10,
+			&create_versioned_reserve_asset_deposited(location(DAI), 3000 * ONE),
+		)
+		.1;
+
+		//Assert
+		assert_eq!(deferred_by, Some(20));
+		assert_eq!(XcmRateLimiter::group_accumulated_amount(STABLECOINS).amount, 3000 * ONE);
+	});
+}
+
+#[test]
+fn deferred_by_should_limit_asset_without_own_limit_by_group_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		with_stablecoins_group(1000 * ONE);
+		assert_ok!(XcmRateLimiter::set_asset_group(
+			RuntimeOrigin::root(),
+			ASSET_WITHOUT_LIMIT,
+			Some(STABLECOINS)
+		));
+
+		//Act
+		let deferred_by = XcmRateLimiter::deferred_by(
+			999.into(),
+			10,
+			&create_versioned_reserve_asset_deposited(location(ASSET_WITHOUT_LIMIT), 2000 * ONE),
+		)
+		.1;
+
+		//Assert
+		assert_eq!(deferred_by, Some(10));
+		assert_eq!(
+			XcmRateLimiter::accumulated_amount(location(ASSET_WITHOUT_LIMIT)),
+			AccumulatedAmount::default()
+		);
+		assert_eq!(XcmRateLimiter::group_accumulated_amount(STABLECOINS).amount, 2000 * ONE);
+	});
+}
+
+#[test]
+fn deferred_by_should_not_track_group_when_group_has_no_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(XcmRateLimiter::set_asset_group(
+			RuntimeOrigin::root(),
+			DAI,
+			Some(STABLECOINS)
+		));
+
+		//Act
+		let deferred_by = XcmRateLimiter::deferred_by(
+			999.into(),
+			10,
+			&create_versioned_reserve_asset_deposited(location(DAI), 600 * ONE),
+		)
+		.1;
+
+		//Assert
+		assert_eq!(deferred_by, None);
+		assert_eq!(
+			XcmRateLimiter::group_accumulated_amount(STABLECOINS),
+			AccumulatedAmount::default()
+		);
+	});
+}
+
+#[test]
+fn deferred_by_should_decay_group_accumulated_amount_when_time_passes() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		with_stablecoins_group(1000 * ONE);
+		let para_id = 999.into();
+		XcmRateLimiter::deferred_by(
+			para_id,
+			10,
+			&create_versioned_reserve_asset_deposited(location(DAI), 800 * ONE),
+		);
+
+		System::set_block_number(6);
+
+		//Act
+		let deferred_by = XcmRateLimiter::deferred_by(
+			para_id,
+			10,
+			&create_versioned_reserve_asset_deposited(location(ACA), 800 * ONE),
+		)
+		.1;
+
+		//Assert
+		assert_eq!(deferred_by, Some(1));
+		assert_eq!(
+			XcmRateLimiter::group_accumulated_amount(STABLECOINS),
+			AccumulatedAmount {
+				amount: 1100 * ONE,
+				last_updated: 6,
+			}
+		);
+	});
+}
@@ -17,13 +17,14 @@
 
 pub use crate as pallet_xcm_rate_limiter;
 
-use frame_support::traits::Contains;
 pub use frame_support::traits::Everything;
+use frame_support::traits::{Contains, EnqueueMessage, QueueFootprint};
+use frame_support::BoundedSlice;
 pub use frame_support::{assert_ok, parameter_types};
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
 use orml_traits::GetByKey;
-use polkadot_parachain::primitives::RelayChainBlockNumber;
+use polkadot_parachain::primitives::{Id as ParaId, RelayChainBlockNumber};
 use sp_core::H256;
 use sp_runtime::traits::BlockNumberProvider;
 use sp_runtime::traits::Convert;
@@ -72,6 +73,7 @@ thread_local! {
 	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<(u32, u32)> = RefCell::new((2_000, 10_000)); // 20%
 	pub static MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = RefCell::new(Some((4_000, 10_000))); // 40%
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = RefCell::new(Some((2_000, 10_000))); // 20%
+	pub static ENQUEUED_MESSAGES: RefCell<Vec<(ParaId, Vec<u8>)>> = RefCell::new(Vec::default());
 }
 
 frame_support::construct_runtime!(
@@ -163,6 +165,33 @@ impl pallet_xcm_rate_limiter::Config for Test {
 	type RelayBlockNumberProvider = TreatSystemAsRelayBlockNumberProvider;
 	type RateLimitFor = XcmRateLimitFor;
 	type CurrencyIdConvert = ConvertIdMock;
+	type UpdateOrigin = EnsureRoot<Self::AccountId>;
+	type XcmpQueue = MessageQueueMock;
+	type WeightInfo = ();
+}
+
+/// Records enqueued messages in `ENQUEUED_MESSAGES`.
+pub struct MessageQueueMock;
+impl EnqueueMessage<ParaId> for MessageQueueMock {
+	type MaxMessageLen = ConstU32<1024>;
+
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: ParaId) {
+		ENQUEUED_MESSAGES.with(|v| v.borrow_mut().push((origin, message.to_vec())));
+	}
+
+	fn enqueue_messages<'a>(messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>, origin: ParaId) {
+		messages.for_each(|message| Self::enqueue_message(message, origin));
+	}
+
+	fn sweep_queue(_origin: ParaId) {}
+
+	fn footprint(_origin: ParaId) -> QueueFootprint {
+		QueueFootprint::default()
+	}
+}
+
+pub fn enqueued_messages() -> Vec<(ParaId, Vec<u8>)> {
+	ENQUEUED_MESSAGES.with(|v| v.borrow().clone())
 }
 
 pub struct CircuitBreakerWhitelist;
@@ -255,10 +284,23 @@ impl pallet_omnipool::Config for Test {
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
 	type OmnipoolHooks = ();
+	type OnTradeExecuted = ();
 	type PriceBarrier = ();
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type PriceBandOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type WithdrawalQueueThreshold = ();
+	type WithdrawalStreamBlocks = ();
+	type MaxQueuedWithdrawals = ();
+	type MaxQueuedWithdrawalsPerAccount = ();
+	type MaxHubAssetBuyPerBlock = ();
+	type HubAssetRedemptionFee = ();
+	type MaxHubAssetRedemptionPerBlock = ();
+	type Router = ();
+	type RouterWeightInfo = ();
+	type TradeCommitmentExpiry = ();
+	type MaxTradeCommitmentsPerBlock = ();
 }
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
 use frame_support::weights::Weight;
@@ -383,6 +425,9 @@ impl Default for ExtBuilder {
 		POSITIONS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ENQUEUED_MESSAGES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		ASSET_WEIGHT_CAP.with(|v| {
 			*v.borrow_mut() = Permill::from_percent(100);
 		});
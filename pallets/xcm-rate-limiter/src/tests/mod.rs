@@ -1,2 +1,4 @@
 pub(crate) mod defer_xcm;
+pub(crate) mod deferred_messages;
+pub(crate) mod limit_groups;
 pub(crate) mod mock;
@@ -0,0 +1,107 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_xcm_rate_limiter`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-11-05, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: `release_deferred` and `discard_deferred` weights are hand-written estimates and were not produced by the
+//! benchmark CLI, see the comments on the functions.
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --pallet=pallet-xcm-rate-limiter
+// --extrinsic=*
+// --template=scripts/pallet-weight-template.hbs
+// --output=./weights/pallet_xcm_rate_limiter.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_rate_limiter.
+pub trait WeightInfo {
+	fn set_group_rate_limit() -> Weight;
+	fn set_asset_group() -> Weight;
+	fn release_deferred() -> Weight;
+	fn discard_deferred() -> Weight;
+}
+
+/// Weights for pallet_xcm_rate_limiter using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `XcmRateLimiter::GroupAccumulatedAmounts` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::GroupAccumulatedAmounts` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::GroupRateLimits` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::GroupRateLimits` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_group_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(9_736_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmRateLimiter::AssetGroups` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::AssetGroups` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_asset_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_921_000 picoseconds.
+		Weight::from_parts(9_205_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmRateLimiter::DeferredMessages` (r:1 w:1)
+	/// Storage: `MessageQueue::BookStateFor` (r:1 w:1)
+	/// Storage: `MessageQueue::ServiceHead` (r:1 w:1)
+	/// Storage: `MessageQueue::Pages` (r:0 w:1)
+	/// NOTE: Hand-written estimate, not produced by the benchmark CLI. Storage accesses are counted from the code,
+	/// execution time and proof size are pessimistic guesses for a message of maximal length.
+	/// Must be regenerated with the `release_deferred` benchmark.
+	fn release_deferred() -> Weight {
+		Weight::from_parts(50_000_000, 140_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `XcmRateLimiter::DeferredMessages` (r:1 w:1)
+	/// NOTE: Hand-written estimate, not produced by the benchmark CLI. Storage accesses are counted from the code,
+	/// execution time and proof size are pessimistic guesses for a message of maximal length.
+	/// Must be regenerated with the `discard_deferred` benchmark.
+	fn discard_deferred() -> Weight {
+		Weight::from_parts(25_000_000, 140_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -316,6 +316,24 @@ pub mod pallet {
 
 		/// NFT representing deposit has been destroyed
 		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// Rewards were added to the global farm and its planned yielding periods were extended.
+		GlobalFarmToppedUp {
+			id: GlobalFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			extension: PeriodOf<T>,
+			max_reward_per_period: Balance,
+		},
+
+		/// Value cap of the yield farm was updated.
+		YieldFarmValueCapUpdated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			asset_pair: AssetPair,
+			cap: Option<Balance>,
+		},
 	}
 
 	#[pallet::call]
@@ -1009,6 +1027,84 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add rewards to the existing global farm and extend its planned yielding periods.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Max reward per period is recalculated so that undistributed rewards together with
+		/// `amount` are distributed in periods left plus `extension`. Yield farms and deposits
+		/// in the global farm are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to top up.
+		/// - `amount`: amount of `reward_currency` transferred from the owner to the farm.
+		/// - `extension`: number of periods to add to farm's planned yielding periods.
+		///
+		/// Emits `GlobalFarmToppedUp` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_global_farm())]
+		pub fn top_up_global_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			amount: Balance,
+			extension: PeriodOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let max_reward_per_period =
+				T::LiquidityMiningHandler::top_up_global_farm(who.clone(), global_farm_id, amount, extension)?;
+
+			Self::deposit_event(Event::GlobalFarmToppedUp {
+				id: global_farm_id,
+				who,
+				amount,
+				extension,
+				max_reward_per_period,
+			});
+
+			Ok(())
+		}
+
+		/// Update yield farm value cap.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Deposits exceeding the cap are accepted but only valued shares fitting under the cap
+		/// at the time of deposit are rewarded. Existing deposits are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm will be updated.
+		/// - `asset_pair`: asset pair identifying yield farm in global farm.
+		/// - `cap`: max. value of rewarded valued shares in `reward_currency`, `None` removes the cap.
+		///
+		/// Emits `YieldFarmValueCapUpdated` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_yield_farm_value_cap())]
+		pub fn update_yield_farm_value_cap(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			asset_pair: AssetPair,
+			cap: Option<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
+
+			let yield_farm_id =
+				T::LiquidityMiningHandler::update_yield_farm_value_cap(who.clone(), global_farm_id, amm_pool_id, cap)?;
+
+			Self::deposit_event(Event::YieldFarmValueCapUpdated {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				asset_pair,
+				cap,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -584,6 +584,26 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 		})
 	}
 
+	fn top_up_global_farm(
+		_who: AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Self::Balance,
+		extension: Self::Period,
+	) -> Result<Self::Balance, Self::Error> {
+		GLOBAL_FARMS.with(|v| {
+			let mut p = v.borrow_mut();
+
+			let global_farm = p.get_mut(&global_farm_id).unwrap();
+
+			global_farm.total_rewards += amount;
+			global_farm.planned_yielding_periods += extension;
+			global_farm._max_reward_per_period =
+				global_farm.total_rewards / global_farm.planned_yielding_periods as u128;
+
+			Ok(global_farm._max_reward_per_period)
+		})
+	}
+
 	fn terminate_global_farm(
 		who: AccountId,
 		global_farm_id: u32,
@@ -655,6 +675,21 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 		})
 	}
 
+	fn update_yield_farm_value_cap(
+		_who: AccountId,
+		_global_farm_id: u32,
+		amm_pool_id: Self::AmmPoolId,
+		_cap: Option<Self::Balance>,
+	) -> Result<u32, Self::Error> {
+		YIELD_FARMS.with(|v| {
+			let p = v.borrow();
+
+			let (id, _) = p.iter().find(|(_, farm)| farm.amm_pool_id == amm_pool_id).unwrap();
+
+			Ok(*id)
+		})
+	}
+
 	fn stop_yield_farm(
 		_who: AccountId,
 		_global_farm_id: u32,
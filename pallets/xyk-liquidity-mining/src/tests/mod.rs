@@ -58,6 +58,8 @@ pub mod resume_yield_farm;
 pub mod stop_yield_farm;
 pub mod terminate_global_farm;
 pub mod terminate_yield_farm;
+pub mod top_up_global_farm;
 pub mod update_global_farm;
 pub mod update_yield_farm;
+pub mod update_yield_farm_value_cap;
 pub mod withdraw_shares;
@@ -65,6 +65,8 @@ pub trait WeightInfo {
 	fn join_farms(c: u32) -> Weight;	
 	fn add_liquidity_and_join_farms(c: u32) -> Weight;
 	fn exit_farms(c: u32) -> Weight;
+	fn top_up_global_farm() -> Weight;
+	fn update_yield_farm_value_cap() -> Weight;
 }
 
 /// Weights for `pallet_xyk_liquidity_mining` using the HydraDX node and recommended hardware.
@@ -605,4 +607,36 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
+	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn top_up_global_farm() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1075`
+		//  Estimated: `8799`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `XYK::ShareToken` (r:1 w:0)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::ActiveYieldFarm` (r:1 w:0)
+	/// Proof: `XYKWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:0)
+	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmValueCap` (r:0 w:1)
+	/// Proof: `XYKWarehouseLM::YieldFarmValueCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn update_yield_farm_value_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1305`
+		//  Estimated: `3670`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3670)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
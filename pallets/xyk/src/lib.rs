@@ -29,7 +29,7 @@
 #![allow(clippy::upper_case_acronyms)]
 
 use frame_support::sp_runtime::{traits::Zero, DispatchError};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional, weights::Weight};
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{
@@ -40,7 +40,13 @@ use sp_std::{vec, vec::Vec};
 
 use crate::types::{Amount, AssetId, AssetPair, Balance};
 use hydra_dx_math::ratio::Ratio;
-use hydradx_traits::AMMAddLiquidity;
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::registry::{AssetKind, Create};
+use hydradx_traits::router::{
+	AmmTradeWeights, AmountInAndOut, AssetPair as RouterAssetPair, PoolType, RouteProvider, RouterT, Trade,
+};
+use hydradx_traits::{AMMAddLiquidity, AMMCreatePool, AddSingleAssetLiquidity};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 
 #[cfg(test)]
@@ -63,17 +69,50 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::OriginFor;
-	use hydradx_traits::{
-		pools::DustRemovalAccountWhitelist,
-		registry::{AssetKind, Create},
-		Source,
-	};
+	use hydradx_traits::Source;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), DispatchError> {
+			for (pool_account, (asset_a, asset_b)) in PoolAssets::<T>::iter() {
+				assert!(
+					ShareToken::<T>::contains_key(&pool_account),
+					"Pool {:?} has no share token",
+					pool_account
+				);
+				let share_token = ShareToken::<T>::get(&pool_account);
+				let total_liquidity = TotalLiquidity::<T>::get(&pool_account);
+				assert_eq!(
+					T::Currency::total_issuance(share_token),
+					total_liquidity,
+					"Pool {:?} share issuance is not equal to total liquidity",
+					pool_account
+				);
+				if !total_liquidity.is_zero() {
+					for asset in [asset_a, asset_b] {
+						assert!(
+							!T::Currency::free_balance(asset, &pool_account).is_zero(),
+							"Pool {:?} has liquidity but no reserve of asset {:?}",
+							pool_account,
+							asset
+						);
+					}
+				}
+			}
+			for pool_account in ShareToken::<T>::iter_keys().chain(TotalLiquidity::<T>::iter_keys()) {
+				assert!(
+					PoolAssets::<T>::contains_key(&pool_account),
+					"Pool {:?} storage left after pool was destroyed",
+					pool_account
+				);
+			}
+			Ok(())
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -132,6 +171,19 @@ pub mod pallet {
 
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Handler called after each trade, e.g. to distribute part of the fee as referral rewards.
+		type OnTradeExecuted: OnTradeExecuted<Self::AccountId, AssetId, Balance>;
+
+		/// Router used to sell assets which can't be added to Omnipool when migrating liquidity.
+		type Router: RouteProvider<AssetId>
+			+ RouterT<Self::RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>>;
+
+		/// Router weight information.
+		type RouterWeightInfo: AmmTradeWeights<Trade<AssetId>>;
+
+		/// Omnipool liquidity is migrated to.
+		type OmnipoolLiquidity: AddSingleAssetLiquidity<Self::AccountId, AssetId, Balance>;
 	}
 
 	#[pallet::error]
@@ -208,6 +260,15 @@ pub mod pallet {
 
 		/// Pool cannot be created due to outside factors.
 		CannotCreatePool,
+
+		/// Amount of shares received is less than given minimum.
+		InsufficientShares,
+
+		/// Neither asset of the pool can be added to Omnipool.
+		AssetNotInOmnipool,
+
+		/// Value of created Omnipool positions is less than given minimum.
+		InsufficientPositionValue,
 	}
 
 	#[pallet::event]
@@ -230,6 +291,15 @@ pub mod pallet {
 			shares: Balance,
 		},
 
+		/// Liquidity was migrated from the pool to Omnipool.
+		LiquidityMigrated {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			shares: Balance,
+			position_value: Balance,
+		},
+
 		/// Pool was created.
 		PoolCreated {
 			who: T::AccountId,
@@ -312,73 +382,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				T::CanCreatePool::can_create(asset_a, asset_b),
-				Error::<T>::CannotCreatePool
-			);
-
-			ensure!(
-				amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
-
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
-
-			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
-
-			ensure!(
-				T::Currency::free_balance(asset_a, &who) >= amount_a,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			ensure!(
-				T::Currency::free_balance(asset_b, &who) >= amount_b,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			let pair_account = Self::get_pair_id(asset_pair);
-
-			let token_name = asset_pair.name();
-
-			let share_token = T::AssetRegistry::get_or_register_insufficient_asset(
-				token_name.try_into().map_err(|_| Error::<T>::CannotCreatePool)?,
-				AssetKind::XYK,
-				None,
-				None,
-				None,
-				None,
-				None,
-			)?;
-
-			let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
-
-			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
-
-			<ShareToken<T>>::insert(&pair_account, share_token);
-			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
-
-			Self::deposit_event(Event::PoolCreated {
-				who: who.clone(),
-				asset_a,
-				asset_b,
-				initial_shares_amount: shares_added,
-				share_token,
-				pool: pair_account.clone(),
-			});
-
-			T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
-			T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
-
-			T::Currency::deposit(share_token, &who, shares_added)?;
-
-			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+			Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b)?;
 
 			Ok(())
 		}
@@ -427,112 +431,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!liquidity_amount.is_zero(), Error::<T>::ZeroLiquidity);
-
-			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
-
-			let pair_account = Self::get_pair_id(asset_pair);
-
-			let share_token = Self::share_token(&pair_account);
-
-			let total_shares = Self::total_liquidity(&pair_account);
-
-			let account_shares = T::Currency::free_balance(share_token, &who);
-
-			ensure!(total_shares >= liquidity_amount, Error::<T>::InsufficientLiquidity);
-
-			ensure!(account_shares >= liquidity_amount, Error::<T>::InsufficientAssetBalance);
-
-			// Account's liquidity left should be either 0 or at least MinPoolLiquidity
-			ensure!(
-				(account_shares.saturating_sub(liquidity_amount)) >= T::MinPoolLiquidity::get()
-					|| (account_shares == liquidity_amount),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
-			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
-
-			let liquidity_out = hydra_dx_math::xyk::calculate_liquidity_out(
-				asset_a_reserve,
-				asset_b_reserve,
-				liquidity_amount,
-				total_shares,
-			)
-			.map_err(|_| Error::<T>::RemoveAssetAmountInvalid)?;
-
-			let (remove_amount_a, remove_amount_b) = liquidity_out;
-
-			ensure!(
-				T::Currency::free_balance(asset_a, &pair_account) >= remove_amount_a,
-				Error::<T>::InsufficientPoolAssetBalance
-			);
-			ensure!(
-				T::Currency::free_balance(asset_b, &pair_account) >= remove_amount_b,
-				Error::<T>::InsufficientPoolAssetBalance
-			);
-
-			let liquidity_left = total_shares
-				.checked_sub(liquidity_amount)
-				.ok_or(Error::<T>::InvalidLiquidityAmount)?;
-
-			T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
-			T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
-
-			T::Currency::withdraw(share_token, &who, liquidity_amount)?;
-
-			<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
-
-			let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
-			let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
-			T::AMMHandler::on_liquidity_changed(
-				T::OracleSource::get(),
-				asset_a,
-				asset_b,
-				remove_amount_a,
-				remove_amount_b,
-				liquidity_a,
-				liquidity_b,
-				Ratio::new(liquidity_a, liquidity_b),
-			)
-			.map_err(|(_w, e)| e)?;
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: who.clone(),
-				asset_a,
-				asset_b,
-				shares: liquidity_amount,
-			});
-
-			if liquidity_left == 0 {
-				<ShareToken<T>>::remove(&pair_account);
-				<PoolAssets<T>>::remove(&pair_account);
-				<TotalLiquidity<T>>::remove(&pair_account);
-
-				// Ignore the failure, this cant stop liquidity removal
-				let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
-
-				if r.is_err() {
-					log::trace!(
-					target: "xyk::remova_liquidity", "XYK: Failed to remove account {:?} from dust-removal whitelist. Reason {:?}",
-						pair_account,
-					r
-					);
-				}
-
-				Self::deposit_event(Event::PoolDestroyed {
-					who,
-					asset_a,
-					asset_b,
-					share_token,
-					pool: pair_account,
-				});
-			}
+			Self::do_remove_liquidity(who, asset_a, asset_b, liquidity_amount)?;
 
 			Ok(())
 		}
@@ -545,7 +444,7 @@ pub mod pallet {
 		///
 		/// Emits `SellExecuted` when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight() + <T as Config>::OnTradeExecuted::on_trade_executed_weight(PoolType::XYK))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -569,7 +468,7 @@ pub mod pallet {
 		///
 		/// Emits `BuyExecuted` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight() + <T as Config>::OnTradeExecuted::on_trade_executed_weight(PoolType::XYK))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -584,10 +483,180 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add liquidity to previously created asset pair pool providing only one asset of the pair.
+		///
+		/// Part of `amount_a` is sold for `asset_b` first. The sold part is calculated so that the rest of `amount_a`
+		/// and the amount of `asset_b` received are in the ratio of pool reserves after the trade, accounting for
+		/// the trade fee and price impact. Both are then added to the pool. Dust left after rounding stays in the account.
+		///
+		/// `min_shares` - minimum amount of shares to be received.
+		///
+		/// Emits `SellExecuted` and `LiquidityAdded` events when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::add_liquidity_single_asset()
+				.saturating_add(T::AMMHandler::on_trade_weight())
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+				.saturating_add(T::OnTradeExecuted::on_trade_executed_weight(PoolType::XYK))
+		)]
+		#[transactional]
+		pub fn add_liquidity_single_asset(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			min_shares: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let shares_added = Self::do_add_liquidity_single_asset(who, asset_a, asset_b, amount_a)?;
+
+			ensure!(shares_added >= min_shares, Error::<T>::InsufficientShares);
+
+			Ok(())
+		}
+
+		/// Migrate all liquidity of the origin from the pool to Omnipool.
+		///
+		/// All shares of the origin are removed from the pool. If both assets of the pool can be added to Omnipool,
+		/// both are added as separate positions. Otherwise the asset which can't be added is sold for the other one
+		/// by the router using the on-chain route of the asset pair, and the whole amount is added as a single position.
+		///
+		/// All operations are executed atomically. Trade itself is not limited, `min_position_value` protects
+		/// the whole migration.
+		///
+		/// Parameters:
+		/// - `pool`: account of the pool to migrate liquidity from.
+		/// - `min_position_value`: minimum total value of created Omnipool positions, denominated in hub asset.
+		///
+		/// Emits `LiquidityRemoved` and `LiquidityMigrated` events when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::migrate_liquidity_weight(pool))]
+		#[transactional]
+		pub fn migrate_xyk_liquidity(
+			origin: OriginFor<T>,
+			pool: T::AccountId,
+			min_position_value: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let (asset_a, asset_b) = Self::pool_assets(&pool).ok_or(Error::<T>::TokenPoolNotFound)?;
+
+			let can_add_a = T::OmnipoolLiquidity::can_add_liquidity(asset_a);
+			let can_add_b = T::OmnipoolLiquidity::can_add_liquidity(asset_b);
+			ensure!(can_add_a || can_add_b, Error::<T>::AssetNotInOmnipool);
+
+			let shares = T::Currency::free_balance(Self::share_token(&pool), &who);
+			let (amount_a, amount_b) = Self::do_remove_liquidity(who.clone(), asset_a, asset_b, shares)?;
+
+			let position_value = match (can_add_a, can_add_b) {
+				(true, true) => T::OmnipoolLiquidity::add_liquidity(who.clone(), asset_a, amount_a)?
+					.checked_add(T::OmnipoolLiquidity::add_liquidity(who.clone(), asset_b, amount_b)?)
+					.ok_or(Error::<T>::Overflow)?,
+				(true, false) => {
+					Self::sell_and_add_to_omnipool(origin, &who, (asset_b, amount_b), (asset_a, amount_a))?
+				}
+				(false, _) => Self::sell_and_add_to_omnipool(origin, &who, (asset_a, amount_a), (asset_b, amount_b))?,
+			};
+
+			ensure!(
+				position_value >= min_position_value,
+				Error::<T>::InsufficientPositionValue
+			);
+
+			Self::deposit_event(Event::LiquidityMigrated {
+				who,
+				asset_a,
+				asset_b,
+				shares,
+				position_value,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_create_pool(
+		who: T::AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError> {
+		ensure!(
+			T::CanCreatePool::can_create(asset_a, asset_b),
+			Error::<T>::CannotCreatePool
+		);
+
+		ensure!(
+			amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+
+		let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &who) >= amount_a,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		ensure!(
+			T::Currency::free_balance(asset_b, &who) >= amount_b,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let token_name = asset_pair.name();
+
+		let share_token = T::AssetRegistry::get_or_register_insufficient_asset(
+			token_name.try_into().map_err(|_| Error::<T>::CannotCreatePool)?,
+			AssetKind::XYK,
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+
+		let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
+
+		T::NonDustableWhitelistHandler::add_account(&pair_account)?;
+
+		<ShareToken<T>>::insert(&pair_account, share_token);
+		<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+
+		Self::deposit_event(Event::PoolCreated {
+			who: who.clone(),
+			asset_a,
+			asset_b,
+			initial_shares_amount: shares_added,
+			share_token,
+			pool: pair_account.clone(),
+		});
+
+		T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
+		T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
+
+		T::Currency::deposit(share_token, &who, shares_added)?;
+
+		<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+
+		Ok((share_token, shares_added))
+	}
+
 	fn do_add_liquidity(
 		who: T::AccountId,
 		asset_a: AssetId,
@@ -684,6 +753,203 @@ impl<T: Config> Pallet<T> {
 		Ok(shares_added)
 	}
 
+	fn do_remove_liquidity(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		liquidity_amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!liquidity_amount.is_zero(), Error::<T>::ZeroLiquidity);
+
+		ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let share_token = Self::share_token(&pair_account);
+
+		let total_shares = Self::total_liquidity(&pair_account);
+
+		let account_shares = T::Currency::free_balance(share_token, &who);
+
+		ensure!(total_shares >= liquidity_amount, Error::<T>::InsufficientLiquidity);
+
+		ensure!(account_shares >= liquidity_amount, Error::<T>::InsufficientAssetBalance);
+
+		// Account's liquidity left should be either 0 or at least MinPoolLiquidity
+		ensure!(
+			(account_shares.saturating_sub(liquidity_amount)) >= T::MinPoolLiquidity::get()
+				|| (account_shares == liquidity_amount),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+		let liquidity_out = hydra_dx_math::xyk::calculate_liquidity_out(
+			asset_a_reserve,
+			asset_b_reserve,
+			liquidity_amount,
+			total_shares,
+		)
+		.map_err(|_| Error::<T>::RemoveAssetAmountInvalid)?;
+
+		let (remove_amount_a, remove_amount_b) = liquidity_out;
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &pair_account) >= remove_amount_a,
+			Error::<T>::InsufficientPoolAssetBalance
+		);
+		ensure!(
+			T::Currency::free_balance(asset_b, &pair_account) >= remove_amount_b,
+			Error::<T>::InsufficientPoolAssetBalance
+		);
+
+		let liquidity_left = total_shares
+			.checked_sub(liquidity_amount)
+			.ok_or(Error::<T>::InvalidLiquidityAmount)?;
+
+		T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
+		T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
+
+		T::Currency::withdraw(share_token, &who, liquidity_amount)?;
+
+		<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
+
+		let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
+		let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			asset_a,
+			asset_b,
+			remove_amount_a,
+			remove_amount_b,
+			liquidity_a,
+			liquidity_b,
+			Ratio::new(liquidity_a, liquidity_b),
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: who.clone(),
+			asset_a,
+			asset_b,
+			shares: liquidity_amount,
+		});
+
+		if liquidity_left == 0 {
+			<ShareToken<T>>::remove(&pair_account);
+			<PoolAssets<T>>::remove(&pair_account);
+			<TotalLiquidity<T>>::remove(&pair_account);
+
+			// Ignore the failure, this cant stop liquidity removal
+			let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
+
+			if r.is_err() {
+				log::trace!(
+				target: "xyk::remova_liquidity", "XYK: Failed to remove account {:?} from dust-removal whitelist. Reason {:?}",
+					pair_account,
+				r
+				);
+			}
+
+			Self::deposit_event(Event::PoolDestroyed {
+				who,
+				asset_a,
+				asset_b,
+				share_token,
+				pool: pair_account,
+			});
+		}
+
+		Ok((remove_amount_a, remove_amount_b))
+	}
+
+	fn do_add_liquidity_single_asset(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+	) -> Result<Balance, DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &who) >= amount_a,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+
+		let sell_amount =
+			hydra_dx_math::xyk::calculate_single_asset_sell_amount(asset_a_reserve, amount_a, T::GetExchangeFee::get())
+				.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
+
+		let transfer = Self::validate_sell(&who, asset_pair, sell_amount, Balance::zero(), false)?;
+		Self::execute_sell(&transfer)?;
+
+		let amount_b = transfer.amount_b;
+		let amount_a_left = amount_a
+			.checked_sub(sell_amount)
+			.ok_or(Error::<T>::AddAssetAmountInvalid)?;
+
+		// Liquidity in is rounded up in favor of the pool, so use at most as much of asset a
+		// as can be matched by the received amount of asset b.
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+		let amount_a_matched =
+			hydra_dx_math::xyk::calculate_spot_price(asset_b_reserve, asset_a_reserve, amount_b.saturating_sub(1))
+				.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
+
+		Self::do_add_liquidity(who, asset_a, asset_b, amount_a_left.min(amount_a_matched), amount_b)
+	}
+
+	/// Sell `amount_in` of `asset_in` for `asset_out` by the on-chain route and add `amount_out` together with
+	/// the amount received to Omnipool. Returns value of the created position.
+	fn sell_and_add_to_omnipool(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		(asset_in, amount_in): (AssetId, Balance),
+		(asset_out, amount_out): (AssetId, Balance),
+	) -> Result<Balance, DispatchError> {
+		let route = T::Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+
+		let initial_balance = T::Currency::free_balance(asset_out, who);
+		T::Router::sell(origin, asset_in, asset_out, amount_in, Balance::zero(), route)?;
+		let received = T::Currency::free_balance(asset_out, who).saturating_sub(initial_balance);
+
+		let amount = amount_out.checked_add(received).ok_or(Error::<T>::Overflow)?;
+
+		T::OmnipoolLiquidity::add_liquidity(who.clone(), asset_out, amount)
+	}
+
+	fn migrate_liquidity_weight(pool: &T::AccountId) -> Weight {
+		// Either asset of the pool can be sold, so take the heavier route.
+		let trade_weight = Self::pool_assets(pool)
+			.map(|(asset_a, asset_b)| {
+				let sell_a = T::Router::get_route(RouterAssetPair::new(asset_a, asset_b));
+				let sell_b = T::Router::get_route(RouterAssetPair::new(asset_b, asset_a));
+				T::RouterWeightInfo::sell_weight(&sell_a).max(T::RouterWeightInfo::sell_weight(&sell_b))
+			})
+			.unwrap_or_default();
+
+		<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+			.saturating_add(T::RouterWeightInfo::get_route_weight())
+			.saturating_add(trade_weight)
+			.saturating_add(T::OmnipoolLiquidity::add_liquidity_weight().saturating_mul(2))
+	}
+
 	/// Return balance of each asset in selected liquidity pool.
 	pub fn get_pool_balances(pool_address: T::AccountId) -> Option<Vec<(AssetId, Balance)>> {
 		let mut balances = Vec::new();
@@ -874,6 +1140,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			T::Currency::withdraw(native_asset, &transfer.origin, transfer.discount_amount)?;
 		}
 
+		let reserve_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
+		let reserve_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
+
 		T::Currency::transfer(
 			transfer.assets.asset_in,
 			&transfer.origin,
@@ -887,6 +1156,21 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b,
 		)?;
 
+		T::OnTradeExecuted::on_trade_executed(&TradeInfo {
+			pool: PoolType::XYK,
+			trader: transfer.origin.clone(),
+			fee_account: pair_account.clone(),
+			asset_in: transfer.assets.asset_in,
+			asset_out: transfer.assets.asset_out,
+			amount_in: transfer.amount,
+			amount_out: transfer.amount_b,
+			reserve_in,
+			reserve_out,
+			fee_asset: transfer.fee.0,
+			fee_amount: transfer.fee.1,
+			protocol_fee: Balance::zero(),
+		})?;
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -1039,6 +1323,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			T::Currency::withdraw(native_asset, &transfer.origin, transfer.discount_amount)?;
 		}
 
+		let reserve_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
+		let reserve_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
+
 		T::Currency::transfer(
 			transfer.assets.asset_out,
 			&pair_account,
@@ -1052,6 +1339,21 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b + transfer.fee.1,
 		)?;
 
+		T::OnTradeExecuted::on_trade_executed(&TradeInfo {
+			pool: PoolType::XYK,
+			trader: transfer.origin.clone(),
+			fee_account: pair_account.clone(),
+			asset_in: transfer.assets.asset_in,
+			asset_out: transfer.assets.asset_out,
+			amount_in: transfer.amount_b + transfer.fee.1,
+			amount_out: transfer.amount,
+			reserve_in,
+			reserve_out,
+			fee_asset: transfer.fee.0,
+			fee_amount: transfer.fee.1,
+			protocol_fee: Balance::zero(),
+		})?;
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -1145,3 +1447,15 @@ impl<T: Config> AMMAddLiquidity<T::AccountId, AssetId, Balance> for Pallet<T> {
 		Self::do_add_liquidity(who, asset_a, asset_b, amount_a, amount_b_max_limit)
 	}
 }
+
+impl<T: Config> AMMCreatePool<T::AccountId, AssetId, Balance> for Pallet<T> {
+	fn create_pool(
+		who: T::AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError> {
+		Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b)
+	}
+}
@@ -1,6 +1,7 @@
 pub use super::mock::*;
 use crate::{Error, Event};
 use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
 use hydradx_traits::AMM as AmmPool;
 use orml_traits::MultiCurrency;
 
@@ -496,3 +497,76 @@ fn discount_buy_fees_should_work() {
 		.into()]);
 	});
 }
+
+#[test]
+fn sell_should_pass_executed_trade_to_handler() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			200_000_000_000,
+			DOT,
+			600_000_000_000_000,
+		));
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		});
+
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(BOB),
+			ACA,
+			DOT,
+			456_444_678,
+			1_000_000_000_000,
+			false,
+		));
+
+		let trade = last_executed_trade().unwrap();
+		assert_eq!(trade.pool, PoolType::XYK);
+		assert_eq!(trade.trader, BOB);
+		assert_eq!(trade.fee_account, pair_account);
+		assert_eq!((trade.asset_in, trade.asset_out), (ACA, DOT));
+		assert_eq!(trade.amount_in, 456_444_678);
+		assert_eq!(
+			(trade.reserve_in, trade.reserve_out),
+			(200_000_000_000, 600_000_000_000_000)
+		);
+		assert_eq!((trade.fee_asset, trade.fee_amount), (DOT, 2_732_432_046));
+	});
+}
+
+#[test]
+fn buy_should_pass_executed_trade_to_handler() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			200_000_000,
+			DOT,
+			640_000_000_000,
+		));
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: ACA,
+			asset_out: DOT,
+		});
+
+		assert_ok!(XYK::buy(
+			RuntimeOrigin::signed(BOB),
+			ACA,
+			DOT,
+			6_666_666,
+			1_000_000_000_000,
+			false,
+		));
+
+		let trade = last_executed_trade().unwrap();
+		assert_eq!(trade.pool, PoolType::XYK);
+		assert_eq!(trade.trader, BOB);
+		assert_eq!(trade.fee_account, pair_account);
+		assert_eq!((trade.asset_in, trade.asset_out), (DOT, ACA));
+		assert_eq!(trade.amount_out, 6_666_666);
+		assert_eq!((trade.reserve_in, trade.reserve_out), (640_000_000_000, 200_000_000));
+		assert_eq!((trade.fee_asset, trade.fee_amount), (DOT, 44_137_926));
+	});
+}
@@ -687,3 +687,113 @@ fn share_ratio_calculations_are_correct() {
 			}
 		});
 }
+
+#[test]
+fn add_liquidity_single_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		let asset_a = DOT;
+		let asset_b = HDX;
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			asset_a,
+			100 * ONE,
+			asset_b,
+			200 * ONE
+		));
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		assert_ok!(XYK::add_liquidity_single_asset(
+			RuntimeOrigin::signed(BOB),
+			asset_a,
+			asset_b,
+			10 * ONE,
+			9_700_000_000_000
+		));
+
+		assert_eq!(Currency::free_balance(share_token, &BOB), 9_751_521_405_790);
+		assert_eq!(XYK::total_liquidity(pair_account), 200 * ONE + 9_751_521_405_790);
+
+		// dust left after rounding stays in the account
+		assert_eq!(
+			Currency::free_balance(asset_a, &BOB),
+			1_000_000_000_000_000 - 10 * ONE + 3
+		);
+		assert_eq!(Currency::free_balance(asset_b, &BOB), 1_000_000_000_000_000 + 2);
+
+		assert_eq!(Currency::free_balance(asset_a, &pair_account), 110 * ONE - 3);
+		assert_eq!(Currency::free_balance(asset_b, &pair_account), 200 * ONE - 2);
+
+		expect_events(vec![
+			Event::SellExecuted {
+				who: BOB,
+				asset_in: asset_a,
+				asset_out: asset_b,
+				amount: 4_886_009_181_491,
+				sale_price: 9_298_165_124_559,
+				fee_asset: asset_b,
+				fee_amount: 18_633_597_444,
+				pool: pair_account,
+			}
+			.into(),
+			Event::LiquidityAdded {
+				who: BOB,
+				asset_a,
+				asset_b,
+				amount_a: 5_113_990_818_506,
+				amount_b: 9_298_165_124_557,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_fail_when_shares_are_less_than_min_shares() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			100 * ONE,
+			HDX,
+			200 * ONE
+		));
+
+		assert_noop!(
+			XYK::add_liquidity_single_asset(RuntimeOrigin::signed(BOB), DOT, HDX, 10 * ONE, 10 * ONE),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity_single_asset(RuntimeOrigin::signed(BOB), DOT, HDX, 10 * ONE, 0),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_single_asset_should_fail_when_account_has_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			100 * ONE,
+			HDX,
+			200 * ONE
+		));
+
+		assert_noop!(
+			XYK::add_liquidity_single_asset(RuntimeOrigin::signed(CHARLIE), ACA, HDX, 10 * ONE, 0),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
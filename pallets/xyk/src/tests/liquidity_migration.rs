@@ -0,0 +1,147 @@
+pub use super::mock::*;
+use crate::types::AssetPair;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::{AMMPosition, AMM as AmmPool};
+use orml_traits::MultiCurrency;
+
+fn migration_ext(omnipool_assets: Vec<AssetId>) -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().with_omnipool_assets(omnipool_assets).build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			100_000_000,
+			HDX,
+			65_400_000
+		));
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			10_000_000,
+			1_000_000_000_000
+		));
+	});
+	ext
+}
+
+fn dot_hdx_pool() -> AccountId {
+	XYK::get_pair_id(AssetPair {
+		asset_in: DOT,
+		asset_out: HDX,
+	})
+}
+
+#[test]
+fn migrate_xyk_liquidity_should_add_both_assets_when_both_are_in_omnipool() {
+	migration_ext(vec![DOT, HDX]).execute_with(|| {
+		let pool = dot_hdx_pool();
+		let share_token = XYK::share_token(pool);
+		let shares = Currency::free_balance(share_token, &BOB);
+		let (amount_dot, amount_hdx) = XYK::get_liquidity_behind_shares(DOT, HDX, shares).unwrap();
+
+		assert_ok!(XYK::migrate_xyk_liquidity(RuntimeOrigin::signed(BOB), pool, 0));
+
+		assert_eq!(Currency::free_balance(share_token, &BOB), 0);
+		assert_eq!(Currency::free_balance(DOT, &OMNIPOOL_ACCOUNT), amount_dot);
+		assert_eq!(Currency::free_balance(HDX, &OMNIPOOL_ACCOUNT), amount_hdx);
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				who: BOB,
+				asset_a: DOT,
+				asset_b: HDX,
+				shares,
+			}
+			.into(),
+			Event::LiquidityMigrated {
+				who: BOB,
+				asset_a: DOT,
+				asset_b: HDX,
+				shares,
+				position_value: amount_dot + amount_hdx,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn migrate_xyk_liquidity_should_sell_asset_not_in_omnipool_when_only_one_asset_is_in_omnipool() {
+	migration_ext(vec![DOT]).execute_with(|| {
+		let pool = dot_hdx_pool();
+		let share_token = XYK::share_token(pool);
+		let shares = Currency::free_balance(share_token, &BOB);
+		let (amount_dot, _) = XYK::get_liquidity_behind_shares(DOT, HDX, shares).unwrap();
+		let bob_hdx_balance = Currency::free_balance(HDX, &BOB);
+
+		assert_ok!(XYK::migrate_xyk_liquidity(RuntimeOrigin::signed(BOB), pool, 0));
+
+		let position_value = Currency::free_balance(DOT, &OMNIPOOL_ACCOUNT);
+		assert!(position_value > amount_dot);
+		assert_eq!(Currency::free_balance(HDX, &OMNIPOOL_ACCOUNT), 0);
+		assert_eq!(Currency::free_balance(HDX, &BOB), bob_hdx_balance);
+		assert_eq!(Currency::free_balance(share_token, &BOB), 0);
+
+		expect_events(vec![Event::LiquidityMigrated {
+			who: BOB,
+			asset_a: DOT,
+			asset_b: HDX,
+			shares,
+			position_value,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_xyk_liquidity_should_fail_when_no_asset_is_in_omnipool() {
+	migration_ext(vec![]).execute_with(|| {
+		assert_noop!(
+			XYK::migrate_xyk_liquidity(RuntimeOrigin::signed(BOB), dot_hdx_pool(), 0),
+			Error::<Test>::AssetNotInOmnipool
+		);
+	});
+}
+
+#[test]
+fn migrate_xyk_liquidity_should_fail_when_pool_does_not_exist() {
+	migration_ext(vec![DOT, ACA]).execute_with(|| {
+		let pool = XYK::get_pair_id(AssetPair {
+			asset_in: DOT,
+			asset_out: ACA,
+		});
+
+		assert_noop!(
+			XYK::migrate_xyk_liquidity(RuntimeOrigin::signed(BOB), pool, 0),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn migrate_xyk_liquidity_should_fail_when_origin_has_no_shares() {
+	migration_ext(vec![DOT, HDX]).execute_with(|| {
+		assert_noop!(
+			XYK::migrate_xyk_liquidity(RuntimeOrigin::signed(CHARLIE), dot_hdx_pool(), 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn migrate_xyk_liquidity_should_fail_when_position_value_is_below_limit() {
+	migration_ext(vec![DOT, HDX]).execute_with(|| {
+		let pool = dot_hdx_pool();
+		let shares = Currency::free_balance(XYK::share_token(pool), &BOB);
+		let (amount_dot, amount_hdx) = XYK::get_liquidity_behind_shares(DOT, HDX, shares).unwrap();
+
+		assert_noop!(
+			XYK::migrate_xyk_liquidity(RuntimeOrigin::signed(BOB), pool, amount_dot + amount_hdx + 1),
+			Error::<Test>::InsufficientPositionValue
+		);
+	});
+}
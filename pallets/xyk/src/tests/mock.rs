@@ -17,7 +17,9 @@
 
 use crate as xyk;
 use crate::*;
+use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::parameter_types;
+use frame_support::weights::Weight;
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
@@ -41,6 +43,7 @@ pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CHARLIE: AccountId = 3;
 pub const TREASURY: AccountId = 4;
+pub const OMNIPOOL_ACCOUNT: AccountId = 5;
 
 pub const HDX: AssetId = 1000;
 pub const DOT: AssetId = 2000;
@@ -69,6 +72,8 @@ thread_local! {
 		static EXCHANGE_FEE: RefCell<(u32, u32)> = const { RefCell::new((2, 1_000)) };
 		static DISCOUNTED_FEE: RefCell<(u32, u32)> = const { RefCell::new((7, 10_000)) };
 		static MAX_OUT_RATIO: RefCell<u128> = const { RefCell::new(3) };
+		static LAST_EXECUTED_TRADE: RefCell<Option<TradeInfo<AccountId, AssetId, Balance>>> = const { RefCell::new(None) };
+		static OMNIPOOL_ASSETS: RefCell<Vec<AssetId>> = const { RefCell::new(Vec::new()) };
 }
 
 struct ExchangeFee;
@@ -117,6 +122,9 @@ impl pallet_asset_registry::Config for Test {
 	type SequentialIdStartAt = SequentialIdOffset;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type SufficiencyChangeHook = ();
+	type MaxHoldersForParameterChange = frame_support::traits::ConstU32<0>;
+	type MaxAliases = frame_support::traits::ConstU32<0>;
 	type WeightInfo = ();
 }
 
@@ -220,6 +228,114 @@ impl xyk::Config for Test {
 	type DiscountedFee = DiscountedFeeRate;
 	type NonDustableWhitelistHandler = Whitelist;
 	type OracleSource = OracleSourceIdentifier;
+	type OnTradeExecuted = DummyTradeExecutedHandler;
+	type Router = XykRouter;
+	type RouterWeightInfo = ();
+	type OmnipoolLiquidity = MockOmnipool;
+}
+
+/// Router which sells in the XYK pool of the asset pair, regardless of the route.
+pub struct XykRouter;
+
+impl RouteProvider<AssetId> for XykRouter {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for XykRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		XYK::sell(origin, asset_in, asset_out, amount_in, min_amount_out, false)
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: RouterAssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: RouterAssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+/// Omnipool where every asset is priced 1:1 to the hub asset, so value of a position equals its amount.
+pub struct MockOmnipool;
+
+impl AddSingleAssetLiquidity<AccountId, AssetId, Balance> for MockOmnipool {
+	fn can_add_liquidity(asset: AssetId) -> bool {
+		OMNIPOOL_ASSETS.with(|v| v.borrow().contains(&asset))
+	}
+
+	fn add_liquidity(who: AccountId, asset: AssetId, amount: Balance) -> Result<Balance, DispatchError> {
+		<Currency as MultiCurrency<AccountId>>::transfer(asset, &who, &OMNIPOOL_ACCOUNT, amount)?;
+		Ok(amount)
+	}
+
+	fn add_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyTradeExecutedHandler;
+
+impl OnTradeExecuted<AccountId, AssetId, Balance> for DummyTradeExecutedHandler {
+	fn on_trade_executed(trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError> {
+		LAST_EXECUTED_TRADE.with(|v| *v.borrow_mut() = Some(trade.clone()));
+		Ok(0)
+	}
+
+	fn on_trade_executed_weight(_pool: PoolType<AssetId>) -> Weight {
+		Weight::zero()
+	}
+}
+
+pub fn last_executed_trade() -> Option<TradeInfo<AccountId, AssetId, Balance>> {
+	LAST_EXECUTED_TRADE.with(|v| v.borrow().clone())
 }
 
 pub struct ExtBuilder {
@@ -266,7 +382,14 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_omnipool_assets(self, assets: Vec<AssetId>) -> Self {
+		OMNIPOOL_ASSETS.with(|v| *v.borrow_mut() = assets);
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
+		LAST_EXECUTED_TRADE.with(|v| *v.borrow_mut() = None);
+
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
 		orml_tokens::GenesisConfig::<Test> {
@@ -3,6 +3,7 @@ mod creation;
 mod fees;
 mod invariants;
 mod liquidity;
+mod liquidity_migration;
 pub(crate) mod mock;
 mod spot_price;
 mod trades;
@@ -6,7 +6,7 @@ use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::DispatchError::Corruption;
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -170,4 +170,25 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 
 		Ok(spot_price_with_fee)
 	}
+
+	fn calculate_fee(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<Permill, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::XYK {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		if !Self::exists(AssetPair { asset_in, asset_out }) {
+			return Err(ExecutorError::Error(Error::<T>::TokenPoolNotFound.into()));
+		}
+
+		let (numerator, denominator) = T::GetExchangeFee::get();
+		if denominator == 0 {
+			return Ok(Permill::zero());
+		}
+
+		Ok(Permill::from_rational(numerator, denominator))
+	}
 }
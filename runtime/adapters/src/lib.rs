@@ -22,7 +22,7 @@ use cumulus_primitives_core::relay_chain::Hash;
 use frame_support::{
 	sp_runtime::{
 		traits::{AtLeast32BitUnsigned, Convert, Get, MaybeSerializeDeserialize, Saturating, Zero},
-		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 		SaturatedConversion,
 	},
 	traits::{Contains, LockIdentifier, OriginTrait},
@@ -34,6 +34,7 @@ use hydra_dx_math::{
 	omnipool::types::BalanceUpdate,
 	support::rational::{round_to_rational, round_u512_to_rational, Rounding},
 };
+use hydradx_traits::fee::{OnTradeExecuted, TradeInfo};
 use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, Trade};
 use hydradx_traits::{
 	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, LockedBalance, NativePriceOracle,
@@ -45,7 +46,7 @@ use pallet_circuit_breaker::WeightInfo;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
 use pallet_stableswap::types::{PoolState, StableswapHooks};
-use pallet_transaction_multi_payment::DepositFee;
+use pallet_transaction_multi_payment::{DepositFee, OnNonNativeFeeDeposited};
 use polkadot_xcm::v4::prelude::*;
 use primitive_types::{U128, U512};
 use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
@@ -256,6 +257,57 @@ impl<
 	}
 }
 
+/// Deposits fees by `DF` and records them as treasury inflows in the revenue stats.
+pub struct RevenueStatsFeeDeposit<Runtime, DF>(PhantomData<(Runtime, DF)>);
+
+impl<Runtime, DF> DepositFee<AccountId, AssetId, Balance> for RevenueStatsFeeDeposit<Runtime, DF>
+where
+	Runtime: pallet_revenue_stats::Config,
+	<Runtime as pallet_revenue_stats::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_revenue_stats::Config>::Balance: From<Balance>,
+	DF: DepositFee<AccountId, AssetId, Balance>,
+{
+	fn deposit_fee(who: &AccountId, currency: AssetId, amount: Balance) -> DispatchResult {
+		DF::deposit_fee(who, currency, amount)?;
+		pallet_revenue_stats::Pallet::<Runtime>::note_treasury_inflow(currency.into(), amount.into());
+		Ok(())
+	}
+}
+
+/// Processes part of transaction fees paid in non-native currency by referrals.
+///
+/// The fee has already been deposited to the fee receiver, referral part is taken from there.
+/// Failure to process the fee is ignored, fee payment must not fail because of referrals.
+pub struct ReferralsTransactionFee<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> OnNonNativeFeeDeposited<AccountId, AssetId, Balance> for ReferralsTransactionFee<Runtime>
+where
+	Runtime: pallet_referrals::Config + pallet_revenue_stats::Config,
+	<Runtime as frame_system::Config>::AccountId: From<AccountId>,
+	<Runtime as pallet_referrals::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_revenue_stats::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_revenue_stats::Config>::Balance: From<Balance>,
+{
+	fn on_fee_deposited(who: &AccountId, fee_receiver: &AccountId, currency: AssetId, fee: Balance) {
+		let used = pallet_referrals::Pallet::<Runtime>::process_transaction_fee(
+			fee_receiver.clone().into(),
+			who.clone().into(),
+			currency.into(),
+			fee,
+		)
+		.unwrap_or_default();
+		pallet_revenue_stats::Pallet::<Runtime>::note_referral_fee(currency.into(), used.into());
+	}
+
+	fn on_fee_deposited_weight() -> Weight {
+		// Price, referral account, referrer, asset rewards, era budget, assets, shares and balances.
+		let referrals = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(10, 7);
+		// Era revenue of the fee asset.
+		let revenue_stats = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+		referrals.saturating_add(revenue_stats)
+	}
+}
+
 // Relay chain Block number provider.
 // Reason why the implementation is different for benchmarks is that it is not possible
 // to set or change the block number in a benchmark using parachain system pallet.
@@ -333,14 +385,7 @@ impl<Origin, NativeAsset, Lrna, Runtime> OmnipoolHooks<Origin, AccountId, AssetI
 where
 	Lrna: Get<AssetId>,
 	NativeAsset: Get<AssetId>,
-	Runtime: pallet_ema_oracle::Config
-		+ pallet_circuit_breaker::Config
-		+ frame_system::Config<RuntimeOrigin = Origin>
-		+ pallet_staking::Config
-		+ pallet_referrals::Config,
-	<Runtime as frame_system::Config>::AccountId: From<AccountId>,
-	<Runtime as pallet_staking::Config>::AssetId: From<AssetId>,
-	<Runtime as pallet_referrals::Config>::AssetId: From<AssetId>,
+	Runtime: pallet_ema_oracle::Config + pallet_circuit_breaker::Config + frame_system::Config<RuntimeOrigin = Origin>,
 {
 	type Error = DispatchError;
 
@@ -410,18 +455,6 @@ where
 		)
 		.map_err(|(_, e)| e)?;
 
-		let amount_in = *asset_in.delta_changes.delta_reserve;
-		let amount_out = *asset_out.delta_changes.delta_reserve;
-
-		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
-			asset_in.asset_id.into(),
-			asset_in.before.reserve.into(),
-			amount_in.into(),
-			asset_out.asset_id.into(),
-			asset_out.before.reserve.into(),
-			amount_out.into(),
-		)?;
-
 		Ok(Self::on_trade_weight())
 	}
 
@@ -438,17 +471,6 @@ where
 		)
 		.map_err(|(_, e)| e)?;
 
-		let amount_out = *asset.delta_changes.delta_reserve;
-
-		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
-			Lrna::get().into(),
-			Balance::zero().into(),
-			Balance::zero().into(),
-			asset.asset_id.into(),
-			asset.before.reserve.into(),
-			amount_out.into(),
-		)?;
-
 		Ok(Self::on_trade_weight())
 	}
 
@@ -461,38 +483,7 @@ where
 	}
 
 	fn on_trade_weight() -> Weight {
-		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2);
-		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit();
-		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
-	}
-
-	fn on_trade_fee(
-		fee_account: AccountId,
-		trader: AccountId,
-		asset: AssetId,
-		amount: Balance,
-	) -> Result<Balance, Self::Error> {
-		if asset == Lrna::get() {
-			return Ok(Balance::zero());
-		}
-		let referrals_used = if asset == NativeAsset::get() {
-			Balance::zero()
-		} else {
-			pallet_referrals::Pallet::<Runtime>::process_trade_fee(
-				fee_account.clone().into(),
-				trader.into(),
-				asset.into(),
-				amount,
-			)?
-		};
-
-		let staking_used = pallet_staking::Pallet::<Runtime>::process_trade_fee(
-			fee_account.into(),
-			asset.into(),
-			amount.saturating_sub(referrals_used),
-		)?;
-		Ok(staking_used.saturating_add(referrals_used))
+		OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2)
 	}
 }
 
@@ -518,6 +509,35 @@ where
 	}
 }
 
+/// Passes price of routed ema oracle to the omnipool.
+///
+/// Routed oracles track spot price of a pair even if it is not traded in Omnipool, so the price is available
+/// before an asset is added to Omnipool.
+pub struct RoutedOraclePriceAdapter<Period, Runtime>(PhantomData<(Period, Runtime)>);
+
+impl<Period, Runtime> ExternalPriceProvider<AssetId, Price> for RoutedOraclePriceAdapter<Period, Runtime>
+where
+	Period: Get<OraclePeriod>,
+	Runtime: pallet_ema_oracle::Config + pallet_omnipool::Config,
+{
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<Price, Self::Error> {
+		let (price, _) = pallet_ema_oracle::Pallet::<Runtime>::get_price(
+			asset_a,
+			asset_b,
+			Period::get(),
+			pallet_ema_oracle::ROUTER_SOURCE,
+		)
+		.map_err(|_| pallet_omnipool::Error::<Runtime>::InvalidOraclePrice)?;
+		Ok(price)
+	}
+
+	fn get_price_weight() -> Weight {
+		pallet_ema_oracle::Pallet::<Runtime>::get_price_weight()
+	}
+}
+
 pub struct OraclePriceProvider<AssetId, AggregatedPriceGetter, Lrna>(
 	PhantomData<(AssetId, AggregatedPriceGetter, Lrna)>,
 );
@@ -820,6 +840,60 @@ impl<
 	}
 }
 
+/// `UnknownAsset` implementation which registers unknown foreign assets in the asset registry.
+///
+/// The asset is registered as quarantined - it is not sufficient and it cannot be traded until it is promoted by
+/// the governance. Its location is stored in the registry, so following transfers of the asset are handled as known.
+///
+/// Deposits of assets which cannot be registered, or which fail after the asset is registered, are passed to
+/// `Fallback`. Withdrawals are always handled by `Fallback`.
+pub struct QuarantineUnknownAssets<Runtime, MultiCurrency, AccountIdConvert, Fallback>(
+	PhantomData<(Runtime, MultiCurrency, AccountIdConvert, Fallback)>,
+);
+
+impl<Runtime, MultiCurrency, AccountIdConvert, Fallback>
+	QuarantineUnknownAssets<Runtime, MultiCurrency, AccountIdConvert, Fallback>
+where
+	Runtime: pallet_asset_registry::Config<AssetId = AssetId>,
+	Runtime::AssetNativeLocation: TryFrom<Location>,
+	MultiCurrency: orml_traits::MultiCurrency<AccountId, CurrencyId = AssetId, Balance = Balance>,
+	AccountIdConvert: ConvertLocation<AccountId>,
+{
+	fn register_and_deposit(asset: &Asset, to: &Location) -> DispatchResult {
+		let Fungibility::Fungible(amount) = asset.fun else {
+			return Err(DispatchError::Other("NonFungible"));
+		};
+		// assets of this chain are never unknown
+		ensure!(asset.id.0.parents > 0, DispatchError::Other("LocalAsset"));
+		let who = AccountIdConvert::convert_location(to).ok_or(DispatchError::Other("AccountIdConversionFailed"))?;
+		let location = Runtime::AssetNativeLocation::try_from(asset.id.0.clone())
+			.map_err(|_| DispatchError::Other("LocationConversionFailed"))?;
+
+		frame_support::storage::with_storage_layer(|| {
+			let asset_id = pallet_asset_registry::Pallet::<Runtime>::register_quarantined_asset(location)?;
+			MultiCurrency::deposit(asset_id, &who, amount)
+		})
+	}
+}
+
+impl<Runtime, MultiCurrency, AccountIdConvert, Fallback> UnknownAssetT
+	for QuarantineUnknownAssets<Runtime, MultiCurrency, AccountIdConvert, Fallback>
+where
+	Runtime: pallet_asset_registry::Config<AssetId = AssetId>,
+	Runtime::AssetNativeLocation: TryFrom<Location>,
+	MultiCurrency: orml_traits::MultiCurrency<AccountId, CurrencyId = AssetId, Balance = Balance>,
+	AccountIdConvert: ConvertLocation<AccountId>,
+	Fallback: UnknownAssetT,
+{
+	fn deposit(asset: &Asset, to: &Location) -> DispatchResult {
+		Self::register_and_deposit(asset, to).or_else(|_| Fallback::deposit(asset, to))
+	}
+
+	fn withdraw(asset: &Asset, from: &Location) -> DispatchResult {
+		Fallback::withdraw(asset, from)
+	}
+}
+
 // Dynamic fees volume adapter
 pub struct OracleVolume(Balance, Balance);
 
@@ -883,6 +957,20 @@ impl<Runtime, Origin: OriginTrait<AccountId = AccountId>> pallet_staking::traits
 	for FreezableNFT<Runtime, Origin>
 where
 	Runtime: frame_system::Config<RuntimeOrigin = Origin> + pallet_uniques::Config<CollectionId = CollectionId>,
+{
+	fn freeze_collection(owner: AccountId, collection: CollectionId) -> DispatchResult {
+		pallet_uniques::Pallet::<Runtime>::freeze_collection(Runtime::RuntimeOrigin::signed(owner), collection)
+	}
+
+	fn thaw_collection(owner: AccountId, collection: CollectionId) -> DispatchResult {
+		pallet_uniques::Pallet::<Runtime>::thaw_collection(Runtime::RuntimeOrigin::signed(owner), collection)
+	}
+}
+
+impl<Runtime, Origin: OriginTrait<AccountId = AccountId>> pallet_referrals::traits::Freeze<AccountId, CollectionId>
+	for FreezableNFT<Runtime, Origin>
+where
+	Runtime: frame_system::Config<RuntimeOrigin = Origin> + pallet_uniques::Config<CollectionId = CollectionId>,
 {
 	fn freeze_collection(owner: AccountId, collection: CollectionId) -> DispatchResult {
 		pallet_uniques::Pallet::<Runtime>::freeze_collection(Runtime::RuntimeOrigin::signed(owner), collection)
@@ -1016,6 +1104,124 @@ where
 	}
 }
 
+/// Handles trades executed by all pools.
+///
+/// `ReferralsPortion` is the part of the fee used for referral rewards, configured separately for each pool type.
+/// Fees in native asset are not used for referrals.
+///
+/// Omnipool trades are also checked by the circuit breaker and what is left of the fee goes to the staking pot.
+/// Trade volume limits of the circuit breaker are tracked per asset against the Omnipool reserve, so trades of other
+/// pools are not included. Fees of other pools stay with their LPs and are not shared with staking.
+///
+/// Fees and referral skims of all trades are recorded in the revenue stats.
+pub struct TradeExecutedHandler<Runtime, Lrna, NativeAsset, ReferralsPortion>(
+	PhantomData<(Runtime, Lrna, NativeAsset, ReferralsPortion)>,
+);
+
+impl<Runtime, Lrna, NativeAsset, ReferralsPortion> OnTradeExecuted<AccountId, AssetId, Balance>
+	for TradeExecutedHandler<Runtime, Lrna, NativeAsset, ReferralsPortion>
+where
+	Runtime: frame_system::Config
+		+ pallet_circuit_breaker::Config
+		+ pallet_staking::Config
+		+ pallet_referrals::Config
+		+ pallet_revenue_stats::Config,
+	<Runtime as frame_system::Config>::AccountId: From<AccountId>,
+	<Runtime as pallet_staking::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_referrals::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_revenue_stats::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_revenue_stats::Config>::Balance: From<Balance>,
+	Lrna: Get<AssetId>,
+	NativeAsset: Get<AssetId>,
+	ReferralsPortion: GetByKey<PoolType<AssetId>, Permill>,
+{
+	fn on_trade_executed(trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError> {
+		if trade.pool == PoolType::Omnipool {
+			let (reserve_in, amount_in) = if trade.asset_in == Lrna::get() {
+				(Balance::zero(), Balance::zero())
+			} else {
+				(trade.reserve_in, trade.amount_in)
+			};
+			pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+				trade.asset_in.into(),
+				reserve_in.into(),
+				amount_in.into(),
+				trade.asset_out.into(),
+				trade.reserve_out.into(),
+				trade.amount_out.into(),
+			)?;
+			pallet_circuit_breaker::Pallet::<Runtime>::ensure_account_trade_volume_limit(
+				&trade.trader.clone().into(),
+				trade.asset_in.into(),
+				reserve_in.into(),
+				amount_in.into(),
+				trade.asset_out.into(),
+				trade.reserve_out.into(),
+				trade.amount_out.into(),
+			)?;
+		}
+
+		pallet_revenue_stats::Pallet::<Runtime>::note_asset_fee(trade.fee_asset.into(), trade.fee_amount.into());
+		pallet_revenue_stats::Pallet::<Runtime>::note_protocol_fee(Lrna::get().into(), trade.protocol_fee.into());
+
+		if trade.fee_asset == Lrna::get() {
+			return Ok(Balance::zero());
+		}
+
+		let referrals_amount = ReferralsPortion::get(&trade.pool).mul_floor(trade.fee_amount);
+		let referrals_used = if trade.fee_asset == NativeAsset::get() || referrals_amount.is_zero() {
+			Balance::zero()
+		} else {
+			pallet_referrals::Pallet::<Runtime>::process_trade_fee(
+				trade.fee_account.clone().into(),
+				trade.trader.clone().into(),
+				trade.fee_asset.into(),
+				referrals_amount,
+			)?
+		};
+		pallet_revenue_stats::Pallet::<Runtime>::note_referral_fee(trade.fee_asset.into(), referrals_used.into());
+
+		if trade.pool != PoolType::Omnipool {
+			return Ok(referrals_used);
+		}
+
+		let staking_used = pallet_staking::Pallet::<Runtime>::process_trade_fee(
+			trade.fee_account.clone().into(),
+			trade.fee_asset.into(),
+			trade.fee_amount.saturating_sub(referrals_used),
+		)?;
+		Ok(staking_used.saturating_add(referrals_used))
+	}
+
+	fn on_trade_executed_weight(pool: PoolType<AssetId>) -> Weight {
+		// Revenue of the fee asset, protocol fee and referral skim.
+		let revenue_stats = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 3);
+		let referrals = if ReferralsPortion::get(&pool).is_zero() {
+			Weight::zero()
+		} else {
+			// Price, referral account, referrer, asset rewards, era budget, assets, shares and balances.
+			<Runtime as frame_system::Config>::DbWeight::get().reads_writes(10, 7)
+		};
+		if pool != PoolType::Omnipool {
+			return referrals.saturating_add(revenue_stats);
+		}
+		let circuit_breaker = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit()
+			.saturating_add(
+				<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_account_trade_volume_limit(),
+			)
+			.saturating_add(
+				<Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry()
+					.saturating_mul(2),
+			);
+		// Staking pot balance and its transfer.
+		let staking = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
+		referrals
+			.saturating_add(revenue_stats)
+			.saturating_add(circuit_breaker)
+			.saturating_add(staking)
+	}
+}
+
 /// Price provider that returns a price of an asset that can be used to pay tx fee.
 /// If an asset cannot be used as fee payment asset, None is returned.
 pub struct AssetFeeOraclePriceProvider<A, AC, RP, Oracle, FallbackPrice, Period>(
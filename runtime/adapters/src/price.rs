@@ -3,15 +3,16 @@ use frame_support::weights::Weight;
 use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::fee::SwappablePaymentAssetTrader;
 use hydradx_traits::price::PriceProvider;
-use hydradx_traits::router::{AssetPair, RouteProvider};
+use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, RouteSpotPriceProvider, Trade};
 use hydradx_traits::{
-	AccountFeeCurrency, AccountFeeCurrencyBalanceInCurrency, AggregatedPriceOracle, OraclePeriod, PriceOracle,
+	AccountFeeCurrency, AccountFeeCurrencyBalanceInCurrency, AggregatedPriceOracle, NativePriceOracle, OraclePeriod,
+	PriceOracle,
 };
 use primitives::{AccountId, AssetId, Balance};
 use sp_core::Get;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_runtime::traits::Convert;
-use sp_runtime::Rounding;
+use sp_runtime::traits::{Convert, One};
+use sp_runtime::{FixedPointNumber, FixedU128, Rounding};
 use sp_std::marker::PhantomData;
 
 pub struct OraclePriceProviderUsingRoute<RP, OP, P>(PhantomData<(RP, OP, P)>);
@@ -30,6 +31,57 @@ where
 	}
 }
 
+/// Spot price of an asset pair calculated along the route provided by the router.
+pub struct RouterSpotPriceProvider<RP>(PhantomData<RP>);
+
+impl<AssetId, RP> PriceProvider<AssetId> for RouterSpotPriceProvider<RP>
+where
+	RP: RouteProvider<AssetId> + RouteSpotPriceProvider<AssetId>,
+{
+	type Price = EmaPrice;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		let route = RP::get_route(AssetPair::new(asset_a, asset_b));
+		let price = RP::spot_price_with_fee(&route)?;
+		Some(EmaPrice::new(price.into_inner(), FixedU128::DIV))
+	}
+}
+
+/// Native price of an asset derived from the Omnipool oracle.
+///
+/// The price is the amount of `asset` per one unit of native asset, taken from the oracle of the
+/// `asset -> LRNA -> native` Omnipool trade over given `Period`. Assets without Omnipool oracle data
+/// are priced by `Fallback`.
+pub struct OmnipoolNativePriceOracle<OP, NativeAsset, Period, Fallback>(
+	PhantomData<(OP, NativeAsset, Period, Fallback)>,
+);
+
+impl<AssetId, OP, NativeAsset, Period, Fallback> NativePriceOracle<AssetId, FixedU128>
+	for OmnipoolNativePriceOracle<OP, NativeAsset, Period, Fallback>
+where
+	AssetId: Copy + PartialEq,
+	OP: PriceOracle<AssetId, Price = EmaPrice>,
+	NativeAsset: Get<AssetId>,
+	Period: Get<OraclePeriod>,
+	Fallback: NativePriceOracle<AssetId, FixedU128>,
+{
+	fn price(currency: AssetId) -> Option<FixedU128> {
+		let native_asset = NativeAsset::get();
+		if currency == native_asset {
+			return Some(FixedU128::one());
+		}
+
+		let route = [Trade {
+			pool: PoolType::Omnipool,
+			asset_in: currency,
+			asset_out: native_asset,
+		}];
+		OP::price(&route, Period::get())
+			.and_then(|price| FixedU128::checked_from_rational(price.n, price.d))
+			.or_else(|| Fallback::price(currency))
+	}
+}
+
 pub struct FeeAssetBalanceInCurrency<T, C, AC, I>(sp_std::marker::PhantomData<(T, C, AC, I)>);
 
 impl<T, C, AC, I> AccountFeeCurrencyBalanceInCurrency<AssetId, T::AccountId> for FeeAssetBalanceInCurrency<T, C, AC, I>
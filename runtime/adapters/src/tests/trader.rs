@@ -445,3 +445,70 @@ macro_rules! assert_that_fee_is_deposited {
 		});
 	};
 }
+
+/// Mock oracle which returns price only for Omnipool trades of the test asset to the core asset.
+struct MockOmnipoolOracle;
+impl PriceOracle<AssetId> for MockOmnipoolOracle {
+	type Price = EmaPrice;
+
+	fn price(route: &[Trade<AssetId>], _period: OraclePeriod) -> Option<EmaPrice> {
+		match route {
+			[Trade {
+				pool: PoolType::Omnipool,
+				asset_in: TEST_ASSET_ID,
+				asset_out: CORE_ASSET_ID,
+			}] => Some(EmaPrice::new(2, 1)),
+			_ => None,
+		}
+	}
+}
+
+frame_support::parameter_types! {
+	pub const CoreAssetId: AssetId = CORE_ASSET_ID;
+	pub const TraderOraclePeriod: OraclePeriod = OraclePeriod::Short;
+}
+
+type OmnipoolPrice = price::OmnipoolNativePriceOracle<MockOmnipoolOracle, CoreAssetId, TraderOraclePeriod, MockOracle>;
+
+#[test]
+fn omnipool_price_should_be_used_when_oracle_is_available() {
+	assert_eq!(OmnipoolPrice::price(CORE_ASSET_ID), Some(Price::one()));
+	assert_eq!(
+		OmnipoolPrice::price(TEST_ASSET_ID),
+		Some(Price::saturating_from_integer(2))
+	);
+	assert_eq!(
+		OmnipoolPrice::price(CHEAP_ASSET_ID),
+		Some(Price::saturating_from_integer(4))
+	);
+	assert_eq!(OmnipoolPrice::price(1234), None);
+}
+
+#[test]
+fn can_buy_weight_and_get_refund_with_omnipool_price() {
+	ExpectRevenue::reset();
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, OmnipoolPrice, MockConvert, ExpectRevenue>;
+
+	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let payment: Asset = (test_id.clone(), Fungible(3_000_000)).into();
+		let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment.into(), &ctx));
+		let unused = res.expect("buy_weight should succeed because payment > 2 * weight");
+		assert_eq!(unused, Asset::from((test_id.clone(), Fungible(1_000_000))).into());
+
+		let refund = trader.refund_weight(Weight::from_parts(500_000, 0), &ctx);
+		assert_eq!(refund, Some((test_id.clone(), Fungible(1_000_000)).into()));
+
+		ExpectRevenue::register_expected_asset((test_id, Fungible(1_000_000)).into());
+	}
+	ExpectRevenue::expect_revenue();
+}
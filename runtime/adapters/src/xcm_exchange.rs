@@ -9,12 +9,14 @@ use xcm_executor::AssetsInHolding;
 
 /// Implements `AssetExchange` to support the `ExchangeAsset` XCM instruction.
 ///
-/// Uses pallet-route-executor to execute trades.
+/// Uses pallet-route-executor to execute trades. No route is passed to the router, so the trade follows the
+/// on-chain route stored for the asset pair, which defaults to a direct Omnipool trade when none is set.
+/// This allows remote chains to swap through Hydration with plain XCM programs, without `Transact`.
 ///
 /// Will map exchange instructions with `maximal = true` to sell (selling all of `give` asset) and `false` to buy
 /// (buying exactly `want` amount of asset).
 ///
-/// NOTE: Currenty limited to one asset each for `give` and `want`.
+/// NOTE: Currently limited to one asset each for `give` and `want`.
 pub struct XcmAssetExchanger<Runtime, TempAccount, CurrencyIdConvert, Currency>(
 	PhantomData<(Runtime, TempAccount, CurrencyIdConvert, Currency)>,
 );
@@ -44,7 +46,7 @@ where
 			return Err(give);
 		};
 
-		//We assume only one asset wanted as translating into buy and sell is ambigous for multiple want assets
+		//We assume only one asset wanted as translating into buy and sell is ambiguous for multiple want assets
 		if want.len() != 1 {
 			log::warn!(target: "xcm::exchange-asset", "Only one want asset is supported.");
 			return Err(give);
@@ -86,7 +88,7 @@ where
 				let amount_received = Currency::free_balance(asset_out, &account);
 				debug_assert!(
 					amount_received >= min_buy_amount.into(),
-					"Sell should return more than mininum buy amount."
+					"Sell should return more than minimum buy amount."
 				);
 				Currency::withdraw(asset_out, &account, amount_received)?; // burn the received tokens
 				let holding: Asset = (wanted.id.clone(), amount_received.into()).into();
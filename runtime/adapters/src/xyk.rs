@@ -10,6 +10,9 @@ where
 	R: Inspect<AssetId = AssetId>,
 {
 	fn can_create(asset_a: AssetId, asset_b: AssetId) -> bool {
+		if R::is_quarantined(asset_a) || R::is_quarantined(asset_b) {
+			return false;
+		}
 		let Some(asset_a_type) = R::asset_type(asset_a) else {
 			return false;
 		};
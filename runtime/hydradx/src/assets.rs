@@ -17,13 +17,13 @@
 
 use super::*;
 use crate::evm::Erc20Currency;
-use crate::origins::{GeneralAdmin, OmnipoolAdmin};
+use crate::origins::{GeneralAdmin, OmnipoolAdmin, Treasurer};
 use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
 	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
 	OracleAssetVolumeProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider, RelayChainBlockNumberProvider,
-	StableswapHooksAdapter, VestingInfo,
+	RoutedOraclePriceAdapter, StableswapHooksAdapter, TradeExecutedHandler, VestingInfo,
 };
 
 pub use hydradx_traits::{
@@ -32,6 +32,7 @@ pub use hydradx_traits::{
 	router::{inverse_route, PoolType, Trade},
 	AccountIdFor, AssetKind, AssetPairAccountIdFor, Liquidity, NativePriceOracle, OnTradeHandler, OraclePeriod, Source,
 };
+use orml_vesting::WeightInfo as VestingWeights;
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_omnipool::{
 	traits::{EnsurePriceWithin, OmnipoolHooks},
@@ -43,9 +44,13 @@ use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKille
 use primitives::constants::{
 	chain::{OMNIPOOL_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
-	time::DAYS,
+	time::{DAYS, HOURS},
+};
+use primitives::Moment;
+use sp_runtime::{
+	traits::{BlockNumberProvider, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Percent,
 };
-use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Percent};
 
 use crate::evm::precompiles::erc20_mapping::SetCodeForErc20Precompile;
 use core::ops::RangeInclusive;
@@ -55,24 +60,26 @@ use frame_support::{
 	sp_runtime::traits::{One, PhantomData},
 	sp_runtime::{FixedU128, Perbill, Permill},
 	traits::{
-		AsEnsureOriginWithArg, ConstU32, Contains, Currency, Defensive, EitherOf, EnsureOrigin, Imbalance,
-		LockIdentifier, NeverEnsureOrigin, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstU32, Contains, Currency, Defensive, EitherOf, EnsureOrigin, ExistenceRequirement,
+		Imbalance, LockIdentifier, NeverEnsureOrigin, OnUnbalanced, SortedMembers,
 	},
 	BoundedVec, PalletId,
 };
-use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy, RawOrigin};
+use hydradx_traits::fee::OnTradeExecuted;
+use hydradx_traits::registry::SufficiencyChangeHook;
+use hydradx_traits::vesting::{RewardVesting, VestedTransfer};
 use hydradx_traits::AMM;
 use orml_traits::{
 	currency::{MultiCurrency, MultiLockableCurrency, MutationHooks, OnDeposit, OnTransfer},
 	GetByKey, Happened,
 };
+use pallet_balances::WeightInfo as BalancesWeights;
+use pallet_dca::WeightInfo as DcaWeights;
 use pallet_dynamic_fees::types::FeeParams;
 use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeights, MAX_NUMBER_OF_TRADES};
-use pallet_staking::{
-	types::{Action, Point},
-	SigmoidPercentage,
-};
+use pallet_staking::types::{Action, Point};
 use pallet_xyk::weights::WeightInfo as XykWeights;
 use sp_std::num::NonZeroU16;
 parameter_types! {
@@ -223,24 +230,7 @@ impl SufficiencyCheck {
 				ed_in_fee_asset
 			};
 
-			//NOTE: we are locking little bit less than charging.
-			let to_lock = pallet_balances::Locks::<Runtime>::get(TreasuryAccount::get())
-				.iter()
-				.find(|x| x.id == SUFFICIENCY_LOCK)
-				.map(|p| p.amount)
-				.unwrap_or_default()
-				.saturating_add(<Runtime as pallet_balances::Config>::ExistentialDeposit::get());
-
-			<Currencies as MultiLockableCurrency<AccountId>>::set_lock(
-				SUFFICIENCY_LOCK,
-				NativeAssetId::get(),
-				&TreasuryAccount::get(),
-				to_lock,
-			)?;
-
-			frame_system::Pallet::<Runtime>::inc_sufficients(to);
-
-			pallet_asset_registry::ExistentialDepositCounter::<Runtime>::mutate(|v| *v = v.saturating_add(1));
+			Self::lock_existential_deposit(to)?;
 
 			pallet_asset_registry::Pallet::<Runtime>::deposit_event(
 				pallet_asset_registry::Event::<Runtime>::ExistentialDepositPaid {
@@ -253,6 +243,30 @@ impl SufficiencyCheck {
 
 		Ok(())
 	}
+
+	/// Lock ED paid for `to` in the treasury and count it as paid.
+	fn lock_existential_deposit(to: &AccountId) -> DispatchResult {
+		//NOTE: we are locking little bit less than charging.
+		let to_lock = pallet_balances::Locks::<Runtime>::get(TreasuryAccount::get())
+			.iter()
+			.find(|x| x.id == SUFFICIENCY_LOCK)
+			.map(|p| p.amount)
+			.unwrap_or_default()
+			.saturating_add(<Runtime as pallet_balances::Config>::ExistentialDeposit::get());
+
+		<Currencies as MultiLockableCurrency<AccountId>>::set_lock(
+			SUFFICIENCY_LOCK,
+			NativeAssetId::get(),
+			&TreasuryAccount::get(),
+			to_lock,
+		)?;
+
+		frame_system::Pallet::<Runtime>::inc_sufficients(to);
+
+		pallet_asset_registry::ExistentialDepositCounter::<Runtime>::mutate(|v| *v = v.saturating_add(1));
+
+		Ok(())
+	}
 }
 
 impl OnTransfer<AccountId, AssetId, Balance> for SufficiencyCheck {
@@ -288,6 +302,13 @@ impl Happened<(AccountId, AssetId)> for OnKilledTokenAccount {
 			return;
 		}
 
+		Self::release_existential_deposit(who);
+	}
+}
+
+impl OnKilledTokenAccount {
+	/// Refund ED paid by `who` from the treasury and release corresponding part of the treasury lock.
+	fn release_existential_deposit(who: &AccountId) {
 		let (ed_to_refund, locked_ed) = RefundAndLockedEdCalculator::calculate();
 		let paid_counts = pallet_asset_registry::ExistentialDepositCounter::<Runtime>::get();
 
@@ -321,6 +342,30 @@ impl Happened<(AccountId, AssetId)> for OnKilledTokenAccount {
 		pallet_asset_registry::ExistentialDepositCounter::<Runtime>::set(paid_counts.saturating_sub(1));
 	}
 }
+
+/// Settles ED of holders of the asset which changed sufficiency.
+///
+/// Holders of an insufficient asset paid ED when they received it. When the asset becomes sufficient, paid ED is
+/// refunded as if the holder's account of the asset was killed. When the asset becomes insufficient, holders haven't
+/// paid ED for it so it's sponsored by the treasury - it's locked and released on account kill as if it was paid.
+pub struct SufficiencyChange;
+impl SufficiencyChangeHook<AccountId, AssetId> for SufficiencyChange {
+	fn on_sufficiency_changed(_asset_id: AssetId, who: &AccountId, is_sufficient: bool) {
+		if is_sufficient {
+			if !frame_system::Pallet::<Runtime>::account(who).sufficients.is_zero() {
+				OnKilledTokenAccount::release_existential_deposit(who);
+			}
+		} else {
+			let _ = SufficiencyCheck::lock_existential_deposit(who).defensive();
+		}
+	}
+
+	fn on_sufficiency_changed_weight() -> Weight {
+		// locks, balances and accounts of the treasury and holder + ED counter
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(7, 6)
+	}
+}
+
 pub struct RefundAndLockedEdCalculator;
 
 impl RefundAndLockedEdCalculator {
@@ -412,6 +457,48 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 }
 
+/// Pays rewards of incentive programs locked in orml-vesting schedules.
+/// Rewards are moved to the vesting pallet account which makes the vested transfer.
+/// Remainder of the amount not divisible by the number of periods is transferred instantly,
+/// as well as the whole amount if it is below `MinVestedTransfer`.
+pub struct VestedRewards;
+impl VestedTransfer<AccountId, Balance> for VestedRewards {
+	fn vested_transfer(from: &AccountId, to: &AccountId, amount: Balance, vesting: RewardVesting) -> DispatchResult {
+		let per_period = amount
+			.checked_div(vesting.period_count.into())
+			.ok_or(ArithmeticError::DivisionByZero)?;
+		let vested = per_period.saturating_mul(vesting.period_count.into());
+		if vested < MinVestedTransfer::get() {
+			return <Balances as Currency<AccountId>>::transfer(from, to, amount, ExistenceRequirement::AllowDeath);
+		}
+
+		let remainder = amount.saturating_sub(vested);
+		if !remainder.is_zero() {
+			<Balances as Currency<AccountId>>::transfer(from, to, remainder, ExistenceRequirement::AllowDeath)?;
+		}
+
+		let vesting_account: AccountId = VestingPalletId::get().into_account_truncating();
+		<Balances as Currency<AccountId>>::transfer(from, &vesting_account, vested, ExistenceRequirement::AllowDeath)?;
+
+		let start = RelayChainBlockNumberProvider::<Runtime>::current_block_number().saturating_add(vesting.cliff);
+		Vesting::vested_transfer(
+			RawOrigin::Root.into(),
+			to.clone().into(),
+			orml_vesting::VestingSchedule {
+				start,
+				period: vesting.period,
+				period_count: vesting.period_count,
+				per_period,
+			},
+		)
+	}
+
+	fn vested_transfer_weight() -> Weight {
+		weights::orml_vesting::HydraWeight::<Runtime>::vested_transfer()
+			.saturating_add(weights::pallet_balances::HydraWeight::<Runtime>::transfer_allow_death().saturating_mul(2))
+	}
+}
+
 parameter_types! {
 	pub ClaimMessagePrefix: &'static [u8] = b"I hereby claim all my HDX tokens to wallet:";
 }
@@ -431,6 +518,8 @@ parameter_types! {
 	pub const MinRegistryStrLimit: u32 = 3;
 	pub const SequentialIdOffset: u32 = 1_000_000;
 	pub const RegExternalWeightMultiplier: u64 = 10;
+	pub const MaxHoldersForParameterChange: u32 = 100;
+	pub const MaxAssetAliases: u32 = 4;
 }
 
 impl pallet_asset_registry::Config for Runtime {
@@ -445,6 +534,9 @@ impl pallet_asset_registry::Config for Runtime {
 	type SequentialIdStartAt = SequentialIdOffset;
 	type RegExternalWeightMultiplier = RegExternalWeightMultiplier;
 	type RegisterAssetHook = SetCodeForErc20Precompile;
+	type SufficiencyChangeHook = SufficiencyChange;
+	type MaxHoldersForParameterChange = MaxHoldersForParameterChange;
+	type MaxAliases = MaxAssetAliases;
 	type WeightInfo = weights::pallet_asset_registry::HydraWeight<Runtime>;
 }
 
@@ -490,8 +582,18 @@ parameter_types! {
 	pub const OmnipoolCollectionId: CollectionId = 1337u128;
 	pub const EmaOracleSpotPriceLastBlock: OraclePeriod = OraclePeriod::LastBlock;
 	pub const EmaOracleSpotPriceShort: OraclePeriod = OraclePeriod::Short;
+	pub const OmnipoolPriceBandOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 	pub const OmnipoolMaxAllowedPriceDifference: Permill = Permill::from_percent(1);
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
+	pub const OmnipoolWithdrawalQueueThreshold: Option<Permill> = Some(Permill::from_percent(5));
+	pub const OmnipoolWithdrawalStreamBlocks: u32 = 25;
+	pub const OmnipoolMaxQueuedWithdrawals: u32 = 10;
+	pub const OmnipoolMaxQueuedWithdrawalsPerAccount: u32 = 3;
+	pub const OmnipoolMaxHubAssetBuyPerBlock: Permill = Permill::from_percent(1);
+	pub const OmnipoolHubAssetRedemptionFee: Permill = Permill::from_percent(1);
+	pub const OmnipoolMaxHubAssetRedemptionPerBlock: Permill = Permill::from_percent(1);
+	pub const OmnipoolTradeCommitmentExpiry: BlockNumber = 10;
+	pub const OmnipoolMaxTradeCommitmentsPerBlock: u32 = 100;
 }
 
 impl pallet_omnipool::Config for Runtime {
@@ -515,6 +617,7 @@ impl pallet_omnipool::Config for Runtime {
 	type NFTHandler = Uniques;
 	type WeightInfo = weights::pallet_omnipool::HydraWeight<Runtime>;
 	type OmnipoolHooks = OmnipoolHookAdapter<Self::RuntimeOrigin, NativeAssetId, LRNA, Runtime>;
+	type OnTradeExecuted = OnTradeExecutedHandler;
 	type PriceBarrier = (
 		EnsurePriceWithin<
 			AccountId,
@@ -532,7 +635,19 @@ impl pallet_omnipool::Config for Runtime {
 		>,
 	);
 	type ExternalPriceOracle = EmaOraclePriceAdapter<EmaOracleSpotPriceShort, Runtime>;
+	type PriceBandOracle = RoutedOraclePriceAdapter<OmnipoolPriceBandOraclePeriod, Runtime>;
 	type Fee = pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime>;
+	type WithdrawalQueueThreshold = OmnipoolWithdrawalQueueThreshold;
+	type WithdrawalStreamBlocks = OmnipoolWithdrawalStreamBlocks;
+	type MaxQueuedWithdrawals = OmnipoolMaxQueuedWithdrawals;
+	type MaxQueuedWithdrawalsPerAccount = OmnipoolMaxQueuedWithdrawalsPerAccount;
+	type MaxHubAssetBuyPerBlock = OmnipoolMaxHubAssetBuyPerBlock;
+	type HubAssetRedemptionFee = OmnipoolHubAssetRedemptionFee;
+	type MaxHubAssetRedemptionPerBlock = OmnipoolMaxHubAssetRedemptionPerBlock;
+	type Router = Router;
+	type RouterWeightInfo = RouterWeightInfo;
+	type TradeCommitmentExpiry = OmnipoolTradeCommitmentExpiry;
+	type MaxTradeCommitmentsPerBlock = OmnipoolMaxTradeCommitmentsPerBlock;
 }
 
 pub struct CircuitBreakerWhitelist;
@@ -543,11 +658,6 @@ impl Contains<AccountId> for CircuitBreakerWhitelist {
 	}
 }
 
-parameter_types! {
-	pub const DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000);	// 50%
-	pub const DefaultMaxLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000));	// 5%
-}
-
 impl pallet_circuit_breaker::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -555,9 +665,12 @@ impl pallet_circuit_breaker::Config for Runtime {
 	type UpdateLimitsOrigin =
 		EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, OmnipoolAdmin>>;
 	type WhitelistedAccounts = CircuitBreakerWhitelist;
-	type DefaultMaxNetTradeVolumeLimitPerBlock = DefaultMaxNetTradeVolumeLimitPerBlock;
-	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
-	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
+	type DefaultMaxNetTradeVolumeLimitPerBlock = dynamic_params::circuit_breaker::DefaultMaxNetTradeVolumeLimitPerBlock;
+	type DefaultMaxAddLiquidityLimitPerBlock = dynamic_params::circuit_breaker::DefaultMaxAddLiquidityLimitPerBlock;
+	type DefaultMaxRemoveLiquidityLimitPerBlock =
+		dynamic_params::circuit_breaker::DefaultMaxRemoveLiquidityLimitPerBlock;
+	type DefaultMaxAccountTradeVolumeLimitPerBlock =
+		dynamic_params::circuit_breaker::DefaultMaxAccountTradeVolumeLimitPerBlock;
 	type OmnipoolHubAsset = LRNA;
 	type WeightInfo = weights::pallet_circuit_breaker::HydraWeight<Runtime>;
 }
@@ -579,6 +692,10 @@ where
 	}
 }
 
+parameter_types! {
+	pub const RoutedOracleDeposit: Balance = 1_000 * UNITS;
+}
+
 impl pallet_ema_oracle::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
@@ -591,6 +708,14 @@ impl pallet_ema_oracle::Config for Runtime {
 	/// With every asset trading against LRNA we will only have as many pairs as there will be assets, so
 	/// 40 seems a decent upper bound for the foreseeable future.
 	type MaxUniqueEntries = ConstU32<40>;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type SpotPriceProvider = hydradx_adapters::price::RouterSpotPriceProvider<Router>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type SpotPriceProvider = ReferralsDummyPriceProvider;
+	type Currency = Balances;
+	type RoutedOracleDeposit = RoutedOracleDeposit;
+	/// Routed oracles are updated every block, so keep their number low.
+	type MaxRoutedOracles = ConstU32<10>;
 	type WeightInfo = weights::pallet_ema_oracle::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	/// Should take care of the overhead introduced by `OracleWhitelist`.
@@ -609,6 +734,14 @@ parameter_types! {
 	pub const DustingReward: u128 = 0;
 }
 
+pub struct ExistentialDepositSponsors;
+
+impl Contains<AccountId> for ExistentialDepositSponsors {
+	fn contains(a: &AccountId) -> bool {
+		<PalletId as AccountIdConversion<AccountId>>::into_account_truncating(&ReferralsPalletId::get()) == *a
+	}
+}
+
 impl pallet_duster::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -620,6 +753,7 @@ impl pallet_duster::Config for Runtime {
 	type NativeCurrencyId = NativeAssetId;
 	type BlacklistUpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type TreasuryAccountId = TreasuryAccount;
+	type ExistentialDepositSponsors = ExistentialDepositSponsors;
 	type WeightInfo = weights::pallet_duster::HydraWeight<Runtime>;
 }
 
@@ -806,11 +940,16 @@ parameter_types! {
 	pub MaxSchedulesPerBlock: u32 = 20;
 	pub MaxPriceDifference: Permill = Permill::from_rational(15u32, 1000u32);
 	pub MaxConfigurablePriceDifference: Permill = Permill::from_percent(5);
+	pub SlippageVolatilityMultiplier: u32 = 2;
 	pub MinimalPeriod: u32 = 5;
+	pub MinimalTimePeriod: Moment = 60 * 60 * 1000;
+	pub DcaTimeBucketSize: Moment = 60 * 1000;
 	pub BumpChance: Percent = Percent::from_percent(17);
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
 	pub DCAOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	pub KeeperExecutionWindow: u32 = 10;
+	pub KeeperBountyInNativeCurrency: Balance = 5 * UNITS;
 
 }
 
@@ -844,7 +983,11 @@ impl pallet_dca::Config for Runtime {
 	type RouteProvider = Router;
 	type MaxPriceDifferenceBetweenBlocks = MaxPriceDifference;
 	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
+	type SlippageVolatilityMultiplier = SlippageVolatilityMultiplier;
 	type MinimalPeriod = MinimalPeriod;
+	type TimestampProvider = Timestamp;
+	type MinimalTimePeriod = MinimalTimePeriod;
+	type TimeBucketSize = DcaTimeBucketSize;
 	type BumpChance = BumpChance;
 	type MaxSchedulePerBlock = MaxSchedulesPerBlock;
 	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
@@ -855,6 +998,12 @@ impl pallet_dca::Config for Runtime {
 	type NamedReserveId = NamedReserveId;
 	type WeightToFee = WeightToFee;
 	type AmmTradeWeights = RouterWeightInfo;
+	type KeeperExecutionWindow = KeeperExecutionWindow;
+	type KeeperBountyInNativeCurrency = KeeperBountyInNativeCurrency;
+	type FeeMultiplierForMinTradeLimit = dynamic_params::dca::FeeMultiplierForMinTradeLimit;
+	type RemoteLocation = AssetLocation;
+	type RemoteTransfer = DcaRemoteTransfer;
+	type HookWeightRecorder = pallet_weight_telemetry::RecordHookWeight<Runtime, DcaExecutionsHook>;
 	type WeightInfo = weights::pallet_dca::HydraWeight<Runtime>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type NativePriceOracle = AssetFeeOraclePriceProvider<
@@ -983,6 +1132,7 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
 			};
 			weight.saturating_accrue(amm_weight);
+			weight.saturating_accrue(OnTradeExecutedHandler::on_trade_executed_weight(trade.pool));
 		}
 
 		//We add the overweight for skipping ED handling if route has multiple trades and we have any insufficient asset
@@ -1031,6 +1181,7 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
 			};
 			weight.saturating_accrue(amm_weight);
+			weight.saturating_accrue(OnTradeExecutedHandler::on_trade_executed_weight(trade.pool));
 		}
 
 		//We add the overweight for skipping ED handling if we have any insufficient asset
@@ -1224,7 +1375,7 @@ parameter_types! {
 	pub const ExistentialDepositMultiplier: u8 = 5;
 	pub const PricePrecision: FixedU128 = FixedU128::from_rational(1, 100);
 	pub MinProfitPercentage: Perbill = Perbill::from_rational(1u32, 100_000_u32); // 0.001%
-	pub OtcFee: Permill = Permill::from_rational(1u32, 1_000_u32); // 0.1%
+	pub const OtcOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 impl pallet_otc::Config for Runtime {
@@ -1234,8 +1385,14 @@ impl pallet_otc::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposits = AssetRegistry;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
-	type Fee = OtcFee;
+	type Fee = dynamic_params::otc::Fee;
 	type FeeReceiver = TreasuryAccount;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type PriceProvider =
+		OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA>, OtcOraclePeriod>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type PriceProvider = ReferralsDummyPriceProvider;
+	type MakerRebate = dynamic_params::otc::MakerRebate;
 	type WeightInfo = weights::pallet_otc::HydraWeight<Runtime>;
 }
 
@@ -1251,10 +1408,52 @@ impl pallet_otc_settlements::Config for Runtime {
 	type PricePrecision = PricePrecision;
 	type MinTradingLimit = MinTradingLimit;
 	type MaxIterations = ConstU32<40>;
+	type ArbitrageRoutes = OtcArbitrageRoutes;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
 	type WeightInfo = weights::pallet_otc_settlements::HydraWeight<Runtime>;
 	type RouterWeightInfo = RouterWeightInfo;
 }
 
+parameter_types! {
+	pub const OtcArbitrageMaxStableswapPools: u32 = 20;
+}
+
+/// Direct routes through XYK and stableswap pools used by OTC settlements to close arbitrage opportunities.
+pub struct OtcArbitrageRoutes;
+
+impl pallet_otc_settlements::ArbitrageRoutes<AssetId> for OtcArbitrageRoutes {
+	fn routes(asset_pair: hydradx_traits::router::AssetPair<AssetId>) -> Vec<Vec<Trade<AssetId>>> {
+		let trade = |pool| {
+			vec![Trade {
+				pool,
+				asset_in: asset_pair.asset_in,
+				asset_out: asset_pair.asset_out,
+			}]
+		};
+
+		let mut routes: Vec<Vec<Trade<AssetId>>> = pallet_stableswap::Pools::<Runtime>::iter()
+			.take(OtcArbitrageMaxStableswapPools::get() as usize)
+			.filter(|(_, pool)| {
+				pool.assets.contains(&asset_pair.asset_in) && pool.assets.contains(&asset_pair.asset_out)
+			})
+			.map(|(pool_id, _)| trade(PoolType::Stableswap(pool_id)))
+			.collect();
+
+		if XYK::exists(pallet_xyk::types::AssetPair::new(
+			asset_pair.asset_in,
+			asset_pair.asset_out,
+		)) {
+			routes.push(trade(PoolType::XYK));
+		}
+
+		routes
+	}
+
+	fn routes_weight() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(OtcArbitrageMaxStableswapPools::get() as u64 + 1)
+	}
+}
+
 // Dynamic fees
 parameter_types! {
 	pub AssetFeeParams: FeeParams<Permill> = FeeParams{
@@ -1287,6 +1486,18 @@ impl pallet_dynamic_fees::Config for Runtime {
 // Stableswap
 parameter_types! {
 	pub StableswapAmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub const StableswapMaxRebalancePortion: Permill = Permill::from_percent(5);
+	pub const StableswapMaxAmplificationChangePerBlock: Option<Permill> = Some(Permill::from_percent(1));
+	pub const StableswapParameterChangeDelay: BlockNumber = 2 * DAYS;
+	pub const StableswapReferralsFeePortion: Permill = Permill::from_percent(50);
+}
+
+/// Protocol accounts allowed to rebalance stableswap pools without paying trade fee.
+pub struct StableswapRebalancers;
+impl SortedMembers<AccountId> for StableswapRebalancers {
+	fn sorted_members() -> Vec<AccountId> {
+		vec![TreasuryAccount::get()]
+	}
 }
 
 pub struct StableswapAccountIdConstructor<T: frame_system::Config>(PhantomData<T>);
@@ -1322,7 +1533,7 @@ use hydradx_traits::price::PriceProvider;
 #[cfg(feature = "runtime-benchmarks")]
 use hydradx_traits::registry::Create;
 use hydradx_traits::router::RefundEdCalculator;
-use pallet_referrals::traits::Convert;
+use pallet_referrals::traits::{Convert, LiquidityPositions};
 use pallet_referrals::{FeeDistribution, Level};
 #[cfg(feature = "runtime-benchmarks")]
 use pallet_stableswap::BenchmarkHelper;
@@ -1395,9 +1606,16 @@ impl pallet_stableswap::Config for Runtime {
 	type UpdateTradabilityOrigin = EitherOf<EnsureRoot<Self::AccountId>, TechCommitteeSuperMajority>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type OnTradeExecuted = OnTradeExecutedHandler;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
+	type MaxAmplificationChangePerBlock = StableswapMaxAmplificationChangePerBlock;
+	type ParameterChangeDelay = StableswapParameterChangeDelay;
+	type GuardianOrigin = EitherOf<EnsureRoot<Self::AccountId>, TechCommitteeSuperMajority>;
+	type MaxRebalancePortion = StableswapMaxRebalancePortion;
+	type RebalanceOrigin = EnsureSignedBy<StableswapRebalancers, AccountId>;
+	type PegOracle = EmaOracle;
 	type WeightInfo = weights::pallet_stableswap::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = RegisterAsset<Runtime>;
@@ -1407,6 +1625,7 @@ impl pallet_stableswap::Config for Runtime {
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const BondsOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 pub struct AssetTypeWhitelist;
@@ -1428,6 +1647,20 @@ impl pallet_bonds::Config for Runtime {
 	type AssetTypeWhitelist = AssetTypeWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type SaleOrigin = EitherOf<EnsureRoot<AccountId>, Treasurer>;
+	type TreasuryAccount = TreasuryAccount;
+	type AuthorityOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Router = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Router = pallet_route_executor::DummyRouter<Runtime>;
+	type RouteProvider = Router;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type OraclePriceProvider = OraclePriceProvider<AssetId, EmaOracle, LRNA>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type OraclePriceProvider = DummyOraclePriceProvider;
+	type OraclePeriod = BondsOraclePeriod;
+	type AmmTradeWeights = RouterWeightInfo;
 	type WeightInfo = weights::pallet_bonds::HydraWeight<Runtime>;
 }
 
@@ -1441,7 +1674,10 @@ parameter_types! {
 	pub const TimePointsPerPeriod: u8 = 1;
 	pub const CurrentStakeWeight: u8 = 2;
 	pub const UnclaimablePeriods: BlockNumber = 1;
+	pub const ActionPointsDecayPeriods: BlockNumber = 90;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(2,100);
+	pub const RewardCurveB: u32 = 2_000;
+	pub const StakingSlashAppealPeriod: BlockNumber = 7 * DAYS;
 }
 
 pub struct PointsPerAction;
@@ -1479,8 +1715,8 @@ impl pallet_staking::Config for Runtime {
 	type ActionPointsWeight = ActionPointsW;
 	type TimePointsPerPeriod = TimePointsPerPeriod;
 	type UnclaimablePeriods = UnclaimablePeriods;
+	type ActionPointsDecayPeriods = ActionPointsDecayPeriods;
 	type CurrentStakeWeight = CurrentStakeWeight;
-	type PayablePercentage = SigmoidPercentage<PointPercentage, ConstU32<2_000>>;
 	type BlockNumberProvider = System;
 	type PositionItemId = u128;
 	type CollectionId = u128;
@@ -1494,8 +1730,12 @@ impl pallet_staking::Config for Runtime {
 	>;
 	type MaxPointsPerAction = PointsPerAction;
 	type Vesting = VestingInfo<Runtime>;
+	type VestedTransfer = VestedRewards;
 	type WeightInfo = weights::pallet_staking::HydraWeight<Runtime>;
 	type MinSlash = StakingMinSlash;
+	type SlashOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+	type SlashAppealPeriod = StakingSlashAppealPeriod;
+	type SlashedFundsReceiver = TreasuryAccount;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type MaxLocks = MaxLocks;
@@ -1534,14 +1774,34 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type OnTradeExecuted = OnTradeExecutedHandler;
+	type MigrationPool = XYK;
 }
 
 parameter_types! {
 	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
 	pub const DiscountedFee: (u32, u32) = (7, 10_000);
 	pub const XYKOracleSourceIdentifier: Source = XYK_SOURCE;
+	pub const XYKReferralsFeePortion: Permill = Permill::from_percent(50);
 }
 
+/// Part of the trade fee used for referral rewards, per pool type.
+pub struct ReferralsFeePortion;
+
+impl GetByKey<PoolType<AssetId>, Permill> for ReferralsFeePortion {
+	fn get(pool: &PoolType<AssetId>) -> Permill {
+		match pool {
+			PoolType::Omnipool => Permill::from_percent(100),
+			PoolType::Stableswap(_) => StableswapReferralsFeePortion::get(),
+			PoolType::XYK => XYKReferralsFeePortion::get(),
+			PoolType::LBP => Permill::zero(),
+		}
+	}
+}
+
+/// Trade handler shared by all pools - referrals, staking and circuit breaker.
+pub type OnTradeExecutedHandler = TradeExecutedHandler<Runtime, LRNA, NativeAssetId, ReferralsFeePortion>;
+
 impl pallet_xyk::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetRegistry = AssetRegistry;
@@ -1559,6 +1819,10 @@ impl pallet_xyk::Config for Runtime {
 	type DiscountedFee = DiscountedFee;
 	type NonDustableWhitelistHandler = Duster;
 	type OracleSource = XYKOracleSourceIdentifier;
+	type OnTradeExecuted = OnTradeExecutedHandler;
+	type Router = Router;
+	type RouterWeightInfo = RouterWeightInfo;
+	type OmnipoolLiquidity = Omnipool;
 }
 
 parameter_types! {
@@ -1567,8 +1831,12 @@ parameter_types! {
 	pub const MaxCodeLength: u32 = 10;
 	pub const MinCodeLength: u32 = 4;
 	pub const ReferralsOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
-	pub const ReferralsSeedAmount: Balance = 10_000_000_000_000;
 	pub ReferralsExternalRewardAccount: Option<AccountId> = Some(StakingPalletId::get().into_account_truncating());
+	pub const ReferralsEraLength: BlockNumber = DAYS;
+	pub const ReferralsEraRewardHistoryLength: u32 = 90;
+	pub const ReferralsTraderRebatePeriod: BlockNumber = DAYS;
+	pub const ReferralsTransactionFeePortion: Permill = Permill::from_percent(5);
+	pub const ReferralsBadgeCollectionId: CollectionId = 4444_u128;
 }
 
 impl pallet_referrals::Config for Runtime {
@@ -1589,12 +1857,197 @@ impl pallet_referrals::Config for Runtime {
 	type MinCodeLength = MinCodeLength;
 	type LevelVolumeAndRewardPercentages = ReferralsLevelVolumeAndRewards;
 	type ExternalAccount = ReferralsExternalRewardAccount;
-	type SeedNativeAmount = ReferralsSeedAmount;
+	type EraLength = ReferralsEraLength;
+	type EraRewardHistoryLength = ReferralsEraRewardHistoryLength;
+	type TraderRebatePeriod = ReferralsTraderRebatePeriod;
+	type TransactionFeePortion = ReferralsTransactionFeePortion;
+	type PositionId = ItemId;
+	type LiquidityPositions = OmnipoolLiquidityPositions;
+	type VestedTransfer = VestedRewards;
+	type ExistentialDepositSponsor = Duster;
+	type CollectionId = CollectionId;
+	type BadgeCollectionId = ReferralsBadgeCollectionId;
+	type BadgeItemId = ItemId;
+	type Collections = FreezableNFT<Runtime, Self::RuntimeOrigin>;
+	type NFTHandler = Uniques;
+	type HookWeightRecorder = pallet_weight_telemetry::RecordHookWeight<Runtime, ReferralsConversionsHook>;
 	type WeightInfo = weights::pallet_referrals::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ReferralsBenchmarkHelper;
 }
 
+// Open data
+parameter_types! {
+	pub const OpenDataEraLength: BlockNumber = DAYS;
+	/// Upper bound of storage reads needed to collect snapshot components.
+	pub const OpenDataSnapshotReads: u64 = 500;
+}
+
+/// Collects snapshot components from omnipool, stableswap, dynamic fees, referrals and oracles.
+/// Collections are encoded in storage iteration order.
+pub struct OpenDataSnapshotProvider;
+
+impl pallet_open_data::SnapshotProvider for OpenDataSnapshotProvider {
+	fn pool_states() -> Vec<u8> {
+		let omnipool_account = Omnipool::protocol_account();
+		let omnipool: Vec<(AssetId, pallet_omnipool::types::AssetState<Balance>, Balance)> =
+			pallet_omnipool::Assets::<Runtime>::iter()
+				.map(|(asset_id, state)| {
+					let reserve = Currencies::free_balance(asset_id, &omnipool_account);
+					(asset_id, state, reserve)
+				})
+				.collect();
+
+		let stableswap: Vec<(AssetId, Vec<(AssetId, Balance)>)> = pallet_stableswap::Pools::<Runtime>::iter()
+			.map(|(pool_id, pool)| {
+				let pool_account = StableswapAccountIdConstructor::<Runtime>::from_assets(
+					&pool_id,
+					Some(pallet_stableswap::POOL_IDENTIFIER),
+				);
+				let reserves = pool
+					.assets
+					.iter()
+					.map(|asset_id| (*asset_id, Currencies::free_balance(*asset_id, &pool_account)))
+					.collect();
+				(pool_id, reserves)
+			})
+			.collect();
+
+		(omnipool, stableswap).encode()
+	}
+
+	fn fee_totals() -> Vec<u8> {
+		let asset_fees: Vec<_> = pallet_omnipool::Assets::<Runtime>::iter_keys()
+			.map(|asset_id| (asset_id, pallet_dynamic_fees::AssetFee::<Runtime>::get(asset_id)))
+			.collect();
+		let referrals_pot = Currencies::free_balance(NativeAssetId::get(), &Referrals::pot_account_id());
+
+		(asset_fees, Referrals::total_shares(), referrals_pot).encode()
+	}
+
+	fn oracle_checkpoints() -> Vec<u8> {
+		let checkpoints: Vec<_> = pallet_omnipool::Assets::<Runtime>::iter_keys()
+			.map(|asset_id| {
+				let pair = pallet_ema_oracle::ordered_pair(asset_id, LRNA::get());
+				let entry = pallet_ema_oracle::Oracles::<Runtime>::get((OMNIPOOL_SOURCE, pair, OraclePeriod::Day));
+				(pair, entry)
+			})
+			.collect();
+
+		checkpoints.encode()
+	}
+
+	fn weight() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(OpenDataSnapshotReads::get())
+	}
+}
+
+impl pallet_open_data::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SnapshotProvider = OpenDataSnapshotProvider;
+	type EraLength = OpenDataEraLength;
+	type WeightInfo = weights::pallet_open_data::HydraWeight<Runtime>;
+}
+
+// Revenue stats
+parameter_types! {
+	pub const RevenueStatsEraLength: BlockNumber = DAYS;
+	pub const RevenueStatsRetentionEras: u32 = 90;
+}
+
+impl pallet_revenue_stats::Config for Runtime {
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type EraLength = RevenueStatsEraLength;
+	type RetentionEras = RevenueStatsRetentionEras;
+}
+
+// Price monitor
+parameter_types! {
+	pub const PriceMonitorOracleSource: Source = OMNIPOOL_SOURCE;
+}
+
+impl pallet_price_monitor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type HubAssetId = LRNA;
+	type SpotPriceProvider = Omnipool;
+	type Oracle = EmaOracle;
+	type OracleSource = PriceMonitorOracleSource;
+	type OraclePeriod = EmaOracleSpotPriceShort;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, OmnipoolAdmin>>;
+	type WeightInfo = weights::pallet_price_monitor::HydraWeight<Runtime>;
+}
+
+// Weight telemetry
+parameter_types! {
+	pub const ReferralsConversionsHook: pallet_weight_telemetry::HookId = *b"refconvs";
+	pub const DcaExecutionsHook: pallet_weight_telemetry::HookId = *b"dcaexecs";
+}
+
+impl pallet_weight_telemetry::Config for Runtime {}
+
+// Treasury diversification
+parameter_types! {
+	pub const DiversificationSweepPeriod: BlockNumber = DAYS;
+	/// Swept amount is sold in this many DCA trades.
+	pub const DiversificationTradesPerSweep: Balance = 24;
+	pub const DiversificationTradePeriod: BlockNumber = HOURS;
+	pub const DiversificationSlippage: Permill = Permill::from_percent(3);
+}
+
+/// Sells swept treasury assets by DCA schedules owned by the treasury.
+pub struct DiversificationDcaScheduler;
+
+impl pallet_treasury_diversification::DcaScheduler<AccountId, AssetId> for DiversificationDcaScheduler {
+	fn schedule_sell(who: &AccountId, asset_in: AssetId, asset_out: AssetId, amount: Balance) -> DispatchResult {
+		let schedule = pallet_dca::types::Schedule {
+			owner: who.clone(),
+			period: DiversificationTradePeriod::get(),
+			total_amount: amount,
+			max_retries: None,
+			stability_threshold: None,
+			slippage: Some(DiversificationSlippage::get()),
+			order: pallet_dca::types::Order::Sell {
+				asset_in,
+				asset_out,
+				amount_in: amount.saturating_div(DiversificationTradesPerSweep::get()),
+				min_amount_out: 0,
+				route: BoundedVec::default(),
+			},
+			termination: pallet_dca::types::TerminationMode::default(),
+			time_period: None,
+			execution: pallet_dca::types::ExecutionMode::default(),
+		};
+
+		DCA::schedule(RuntimeOrigin::signed(who.clone()), schedule, None)
+	}
+
+	fn schedule_sell_weight() -> Weight {
+		<Runtime as pallet_dca::Config>::WeightInfo::schedule()
+	}
+}
+
+impl pallet_treasury_diversification::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, Treasurer>;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type DcaScheduler = DiversificationDcaScheduler;
+	type TreasuryAccount = TreasuryAccount;
+	type NativeAssetId = NativeAssetId;
+	type SweepPeriod = DiversificationSweepPeriod;
+	type MaxTargets = ConstU32<10>;
+	type MaxSweepAssets = ConstU32<20>;
+	type WeightInfo = weights::pallet_treasury_diversification::HydraWeight<Runtime>;
+}
+
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, OmnipoolAdmin>;
+	type WeightInfo = weights::pallet_omnipool_subpools::HydraWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const LiquidationGasLimit: u64 = 4_000_000;
 }
@@ -1678,6 +2131,23 @@ where
 	}
 }
 
+/// Omnipool positions are eligible for LP rewards of the referrals program.
+pub struct OmnipoolLiquidityPositions;
+
+impl LiquidityPositions<AccountId, AssetId, ItemId> for OmnipoolLiquidityPositions {
+	fn position_shares(who: &AccountId, position_id: ItemId) -> Option<(AssetId, Balance)> {
+		Omnipool::load_position(position_id, who.clone())
+			.ok()
+			.map(|position| (position.asset_id, position.shares))
+	}
+
+	fn total_shares(asset_id: AssetId) -> Balance {
+		Omnipool::load_asset_state(asset_id)
+			.map(|state| state.shares)
+			.unwrap_or_default()
+	}
+}
+
 pub struct ReferralsLevelVolumeAndRewards;
 
 impl GetByKey<Level, (Balance, FeeDistribution)> for ReferralsLevelVolumeAndRewards {
@@ -1694,31 +2164,37 @@ impl GetByKey<Level, (Balance, FeeDistribution)> for ReferralsLevelVolumeAndRewa
 				referrer: Permill::zero(),
 				trader: Permill::zero(),
 				external: Permill::from_percent(50),
+				lp: Permill::zero(),
 			},
 			Level::Tier0 => FeeDistribution {
 				referrer: Permill::from_percent(5),
 				trader: Permill::from_percent(10),
 				external: Permill::from_percent(35),
+				lp: Permill::zero(),
 			},
 			Level::Tier1 => FeeDistribution {
 				referrer: Permill::from_percent(10),
 				trader: Permill::from_percent(11),
 				external: Permill::from_percent(29),
+				lp: Permill::zero(),
 			},
 			Level::Tier2 => FeeDistribution {
 				referrer: Permill::from_percent(15),
 				trader: Permill::from_percent(12),
 				external: Permill::from_percent(23),
+				lp: Permill::zero(),
 			},
 			Level::Tier3 => FeeDistribution {
 				referrer: Permill::from_percent(20),
 				trader: Permill::from_percent(13),
 				external: Permill::from_percent(17),
+				lp: Permill::zero(),
 			},
 			Level::Tier4 => FeeDistribution {
 				referrer: Permill::from_percent(25),
 				trader: Permill::from_percent(15),
 				external: Permill::from_percent(10),
+				lp: Permill::zero(),
 			},
 		};
 		(volume, rewards)
@@ -1734,7 +2210,7 @@ use primitives::constants::chain::CORE_ASSET_ID;
 pub struct ReferralsBenchmarkHelper;
 
 #[cfg(feature = "runtime-benchmarks")]
-impl RefBenchmarkHelper<AssetId, Balance> for ReferralsBenchmarkHelper {
+impl RefBenchmarkHelper<AssetId, Balance, AccountId, ItemId> for ReferralsBenchmarkHelper {
 	fn prepare_convertible_asset_and_amount() -> (AssetId, Balance) {
 		let asset_id: u32 = 1234u32;
 		let asset_name: BoundedVec<u8, RegistryStrLimit> = asset_id.to_le_bytes().to_vec().try_into().unwrap();
@@ -1792,6 +2268,13 @@ impl RefBenchmarkHelper<AssetId, Balance> for ReferralsBenchmarkHelper {
 		.unwrap();
 		(1234, 1_000_000_000_000_000_000)
 	}
+
+	fn prepare_liquidity_position() -> (AccountId, AssetId, ItemId) {
+		let (asset_id, _) = Self::prepare_convertible_asset_and_amount();
+		// position of initial liquidity is created by add_token
+		let position_id = Omnipool::next_position_id() - 1;
+		(TreasuryAccount::get(), asset_id, position_id)
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -33,7 +33,7 @@ use frame_system::RawOrigin;
 use hydradx_traits::router::PoolType;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
-use pallet_dca::types::{Order, Schedule, ScheduleId};
+use pallet_dca::types::{ExecutionMode, Order, Schedule, ScheduleId, TerminationMode};
 use pallet_dca::{ScheduleIdsPerBlock, Schedules};
 use pallet_route_executor::Trade;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
@@ -79,6 +79,9 @@ fn schedule_fake(
 				asset_out,
 			}]),
 		},
+		termination: TerminationMode::Standard,
+		time_period: None,
+		execution: ExecutionMode::Automatic,
 	};
 	schedule1
 }
@@ -111,6 +114,9 @@ fn schedule_buy_fake(
 				asset_out,
 			}]),
 		},
+		termination: TerminationMode::Standard,
+		time_period: None,
+		execution: ExecutionMode::Automatic,
 	};
 	schedule1
 }
@@ -139,6 +145,9 @@ fn schedule_sell_fake(
 				asset_out,
 			}]),
 		},
+		termination: TerminationMode::Standard,
+		time_period: None,
+		execution: ExecutionMode::Automatic,
 	};
 	schedule1
 }
@@ -453,6 +462,9 @@ runtime_benchmarks! {
 				max_amount_in: Balance::MAX,
 				route: create_bounded_vec(route),
 			},
+			termination: TerminationMode::Standard,
+			time_period: None,
+			execution: ExecutionMode::Automatic,
 		};
 
 		let execution_block = 105u32;
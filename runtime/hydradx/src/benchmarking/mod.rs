@@ -6,6 +6,8 @@ pub mod duster;
 pub mod dynamic_evm_fee;
 pub mod multi_payment;
 pub mod omnipool;
+pub mod omnipool_subpools;
+pub mod price_monitor;
 pub mod route_executor;
 pub mod tokens;
 pub mod vesting;
@@ -18,8 +18,8 @@
 
 use super::*;
 use crate::{
-	AccountId, AssetId, Balance, Currencies, EmaOracle, InsufficientEDinHDX, Runtime, RuntimeCall, System,
-	TreasuryAccount,
+	AccountId, AssetId, Balance, Currencies, EmaOracle, InsufficientEDinHDX, MaxFeeDiscounts, Runtime, RuntimeCall,
+	System, TreasuryAccount,
 };
 use frame_benchmarking::account;
 use frame_benchmarking::BenchmarkError;
@@ -36,10 +36,11 @@ use orml_traits::MultiCurrencyExtended;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
 use pallet_transaction_payment::OnChargeTransaction;
 use primitives::{BlockNumber, Price};
+use sp_core::crypto::KeyTypeId;
 use sp_core::Get;
-use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::traits::{IdentifyAccount, SaturatedConversion};
 use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
-use sp_runtime::FixedU128;
+use sp_runtime::{BoundedVec, FixedU128, MultiSigner, Permill};
 
 type MultiPaymentPallet<T> = pallet_transaction_multi_payment::Pallet<T>;
 type XykPallet<T> = pallet_xyk::Pallet<T>;
@@ -53,6 +54,8 @@ const SEED: u32 = 1;
 
 const UNITS: Balance = 1_000_000_000_000;
 
+const PERMIT_KEY_TYPE: KeyTypeId = KeyTypeId(*b"perm");
+
 pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
 	assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
 		currency_id,
@@ -176,6 +179,38 @@ runtime_benchmarks! {
 		assert_eq!(MultiPaymentPallet::<Runtime>::get_currency(caller_evm_acc), Some(<Runtime as pallet_transaction_multi_payment::Config>::EvmAssetId::get()));
 	}
 
+	dispatch_signed_permit {
+		let relayer: AccountId = account("relayer", 0, SEED);
+		let signer = sp_io::crypto::sr25519_generate(PERMIT_KEY_TYPE, None);
+		let who: AccountId = MultiSigner::from(signer).into_account();
+
+		let fee_asset = register_asset(b"FEE".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		MultiPaymentPallet::<Runtime>::add_currency(RawOrigin::Root.into(), fee_asset, Price::from(1))?;
+		update_balance(0, &who, 1_000 * UNITS);
+		update_balance(fee_asset, &who, 1_000 * UNITS);
+		MultiPaymentPallet::<Runtime>::set_currency(RawOrigin::Signed(who.clone()).into(), fee_asset)?;
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let deadline = System::block_number();
+		let payload = MultiPaymentPallet::<Runtime>::permit_payload(&who, &call, 0, deadline);
+		let signature = sp_io::crypto::sr25519_sign(PERMIT_KEY_TYPE, &signer, &payload)
+			.ok_or(BenchmarkError::Stop("Failed to sign permit"))?;
+	}: { MultiPaymentPallet::<Runtime>::dispatch_signed_permit(RawOrigin::Signed(relayer).into(), who.clone(), Box::new(call), deadline, signature.into())? }
+	verify {
+		assert_eq!(MultiPaymentPallet::<Runtime>::permit_nonce(&who), 1);
+	}
+
+	set_fee_discounts {
+		let discounts: BoundedVec<(Balance, Permill), MaxFeeDiscounts> = (0..MaxFeeDiscounts::get())
+			.map(|i| ((i as Balance + 1) * 1_000 * UNITS, Permill::from_percent(i + 1)))
+			.collect::<sp_std::vec::Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: { MultiPaymentPallet::<Runtime>::set_fee_discounts(RawOrigin::Root.into(), discounts.clone())? }
+	verify {
+		assert_eq!(MultiPaymentPallet::<Runtime>::fee_discounts(), discounts);
+	}
+
 	//Used for calculating multi payment overhead for BaseExtrinsicWeight
 	withdraw_fee {
 		let fee_asset = setup_insufficient_asset_with_dot()?;
@@ -295,7 +330,11 @@ mod tests {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
-		sp_io::TestExternalities::new(t)
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.register_extension(sp_keystore::KeystoreExt::new(
+			sp_keystore::testing::MemoryKeystore::new(),
+		));
+		ext
 	}
 
 	impl_benchmark_test_suite!(new_test_ext(),);
@@ -11,13 +11,16 @@ use frame_support::{
 		traits::{One, SaturatedConversion, Zero},
 		FixedU128, Permill,
 	},
-	traits::{OnFinalize, OnInitialize},
+	traits::{Get, OnFinalize, OnInitialize},
 };
 use frame_system::RawOrigin;
 use hydradx_traits::router::{PoolType, TradeExecution};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use pallet_omnipool::types::Tradability;
+use pallet_omnipool::types::{
+	HubAssetBuyback, ProtocolFeeDestination, ProtocolFeeDestinations, Tradability, MAX_BUYBACK_ASSETS,
+	MAX_PROTOCOL_FEE_DESTINATIONS,
+};
 use pallet_referrals::ReferralCode;
 
 pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
@@ -47,6 +50,19 @@ fn run_to_block(to: u32) {
 const HDX: AssetId = 0;
 const DAI: AssetId = 2;
 
+fn max_protocol_fee_split() -> ProtocolFeeDestinations<AccountId> {
+	(0..MAX_PROTOCOL_FEE_DESTINATIONS)
+		.map(|i| {
+			(
+				ProtocolFeeDestination::Account(account("destination", i, 1)),
+				Permill::from_percent(10),
+			)
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
 pub fn init() -> DispatchResult {
 	let stable_amount: Balance = 1_000_000_000_000_000u128;
 	let native_amount: Balance = 1_000_000_000_000_000u128;
@@ -222,6 +238,9 @@ runtime_benchmarks! {
 		let code = ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"MYCODE".to_vec());
 		Referrals::register_code(RawOrigin::Signed(owner).into(), code.clone())?;
 		Referrals::link_code(RawOrigin::Signed(seller.clone()).into(), code)?;
+
+		// Split protocol fee between max number of destinations for the weight too
+		Omnipool::set_protocol_fee_split(RawOrigin::Root.into(), max_protocol_fee_split())?;
 	}: { Omnipool::sell(RawOrigin::Signed(seller.clone()).into(), token_id, DAI, amount_sell, buy_min_amount)? }
 	verify {
 		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(DAI, &seller) >= buy_min_amount);
@@ -269,6 +288,9 @@ runtime_benchmarks! {
 		let code = ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"MYCODE".to_vec());
 		Referrals::register_code(RawOrigin::Signed(owner).into(), code.clone())?;
 		Referrals::link_code(RawOrigin::Signed(seller.clone()).into(), code)?;
+
+		// Split protocol fee between max number of destinations for the weight too
+		Omnipool::set_protocol_fee_split(RawOrigin::Root.into(), max_protocol_fee_split())?;
 	}: { Omnipool::buy(RawOrigin::Signed(seller.clone()).into(), DAI, token_id, amount_buy, sell_max_limit)? }
 	verify {
 		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(DAI, &seller) >= Balance::zero());
@@ -558,6 +580,183 @@ runtime_benchmarks! {
 		assert!(<Omnipool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Omnipool, token_id, DAI).is_ok());
 	}
 
+	cancel_withdrawal {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		// Register new asset in asset registry
+		let token_id = register_asset(b"FCK".to_vec(), 1_u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		let position_id = Omnipool::next_position_id();
+
+		// Add the token to the pool - owner receives position with all the shares
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner.clone())?;
+
+		// Removing all the shares exceeds the threshold, so the withdrawal is queued
+		Omnipool::remove_liquidity(RawOrigin::Signed(owner.clone()).into(), position_id, token_amount)?;
+		assert!(Omnipool::queued_withdrawal(position_id).is_some());
+	}: { Omnipool::cancel_withdrawal(RawOrigin::Signed(owner).into(), position_id)? }
+	verify {
+		assert!(Omnipool::queued_withdrawal(position_id).is_none());
+	}
+
+	set_protocol_fee_split {
+		init()?;
+		let destinations = max_protocol_fee_split();
+	}: { Omnipool::set_protocol_fee_split(RawOrigin::Root.into(), destinations.clone())? }
+	verify {
+		assert_eq!(Omnipool::protocol_fee_split(), destinations);
+	}
+
+	sync_reserve {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		// Register new asset in asset registry
+		let token_id = register_asset(b"FCK".to_vec(), 1_u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner)?;
+
+		// Transfer tokens directly to the pool account
+		update_balance(token_id, &acc, token_amount);
+
+		let caller: AccountId = account("caller", 0, 1);
+	}: { Omnipool::sync_reserve(RawOrigin::Signed(caller).into(), token_id)? }
+	verify {
+		assert_eq!(Omnipool::load_asset_state(token_id)?.reserve, 2 * token_amount);
+	}
+
+	redeem_hub_asset {
+		init()?;
+		let lrna = <Runtime as pallet_omnipool::Config>::HubAssetId::get();
+		let amount = 1_000_000_000_000_u128;
+
+		let redeemer: AccountId = account("redeemer", 2, 1);
+		update_balance(lrna, &redeemer, amount);
+	}: { Omnipool::redeem_hub_asset(RawOrigin::Signed(redeemer.clone()).into(), amount, DAI, Balance::zero())? }
+	verify {
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(DAI, &redeemer) > Balance::zero());
+	}
+
+	commit_trade {
+		// commitments of the block are decoded and encoded on every commit
+		let max_commitments = <Runtime as pallet_omnipool::Config>::MaxTradeCommitmentsPerBlock::get();
+		for i in 1..max_commitments {
+			let committer: AccountId = account("committer", i, 1);
+			let commitment =
+				Omnipool::trade_commitment(&committer, DAI, HDX, 1_000_000_000_000_u128, Balance::zero(), [1u8; 32]);
+			Omnipool::commit_trade(RawOrigin::Signed(committer).into(), commitment)?;
+		}
+
+		let caller: AccountId = account("caller", 2, 1);
+		let commitment =
+			Omnipool::trade_commitment(&caller, DAI, HDX, 1_000_000_000_000_u128, Balance::zero(), [1u8; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), commitment)
+	verify {
+		assert!(Omnipool::trade_commitments(caller, commitment).is_some());
+	}
+
+	approve_token_funding {
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let funder: AccountId = account("funder", 0, 1);
+	}: _(RawOrigin::Signed(funder.clone()), token_id, 200_000_000_000_000u128)
+	verify {
+		assert_eq!(Omnipool::token_funding_approval(funder, token_id), 200_000_000_000_000u128);
+	}
+
+	add_token_with_funder {
+		init()?;
+
+		// Register new asset in asset registry
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let owner: AccountId = account("owner", 0, 1);
+		let funder: AccountId = account("funder", 1, 1);
+
+		let token_price: FixedU128= FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000u128;
+
+		update_balance(token_id, &funder, token_amount);
+		Omnipool::approve_token_funding(RawOrigin::Signed(funder.clone()).into(), token_id, token_amount)?;
+
+		let current_position_id = Omnipool::next_position_id();
+
+	}: _(RawOrigin::Root, token_id, token_price, Permill::from_percent(100), owner, funder, Some(100u32))
+	verify {
+		assert!(Omnipool::positions(current_position_id).is_some());
+		assert!(Omnipool::position_vesting(current_position_id).is_some());
+	}
+
+	set_price_band {
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	}: _(RawOrigin::Root, token_id, Some(Permill::from_percent(10)))
+	verify {
+		assert_eq!(Omnipool::price_band(token_id), Some(Permill::from_percent(10)));
+	}
+
+	set_hub_asset_buyback {
+		let config = HubAssetBuyback {
+			assets: (0..MAX_BUYBACK_ASSETS).map(|i| i + 100).collect::<Vec<AssetId>>().try_into().unwrap(),
+			portion: Permill::from_percent(10),
+			max_hub_amount: 1_000_000_000_000u128,
+			period: 100u32,
+			destination: ProtocolFeeDestination::Account(account("sink", 0, 1)),
+		};
+	}: _(RawOrigin::Root, Some(config.clone()))
+	verify {
+		assert_eq!(Omnipool::hub_asset_buyback(), Some(config));
+	}
+
+	claim_fees {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner)?;
+
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		// Trade to accrue fees of the token
+		let buyer: AccountId = account("buyer", 2, 1);
+		update_balance(DAI, &buyer, 500_000_000_000_000_u128);
+		Omnipool::buy(RawOrigin::Signed(buyer).into(), token_id, DAI, 30_000_000_000_000_u128, 100_000_000_000_000_u128)?;
+
+		let balance_before = <Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &lp_provider);
+	}: _(RawOrigin::Signed(lp_provider.clone()), current_position_id)
+	verify {
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &lp_provider) > balance_before);
+	}
+
 }
 
 #[cfg(test)]
@@ -0,0 +1,143 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{AccountId, AssetId, Balance, Omnipool, OmnipoolSubpools, Runtime};
+use primitives::ItemId;
+
+use super::omnipool::{init, update_balance};
+
+use frame_benchmarking::account;
+use frame_support::{
+	assert_ok,
+	sp_runtime::{FixedU128, Permill},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use pallet_stableswap::{BenchmarkHelper, MAX_ASSETS_IN_POOL};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+type StableswapBenchmarkHelper = <Runtime as pallet_stableswap::Config>::BenchmarkHelper;
+
+const SHARE_ASSET: AssetId = 2000;
+const ASSET_AMOUNT: Balance = 200_000_000_000_000_000u128;
+
+/// Registers `n` assets and lists them in the Omnipool. Returns the assets and the position of the first one.
+fn omnipool_assets(n: u32, owner: AccountId) -> Result<(Vec<AssetId>, ItemId), DispatchError> {
+	init()?;
+
+	StableswapBenchmarkHelper::register_asset(SHARE_ASSET, 18)?;
+
+	let position_id = Omnipool::next_position_id();
+	let mut assets = Vec::new();
+	for idx in 0..n {
+		let asset_id = 1000 + idx;
+		StableswapBenchmarkHelper::register_asset(asset_id, 18)?;
+		update_balance(asset_id, &Omnipool::protocol_account(), ASSET_AMOUNT);
+		Omnipool::add_token(
+			RawOrigin::Root.into(),
+			asset_id,
+			FixedU128::from_rational(1, 2),
+			Permill::from_percent(100),
+			owner.clone(),
+		)?;
+		assets.push(asset_id);
+	}
+
+	Ok((assets, position_id))
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_omnipool_subpools}
+
+	create_subpool {
+		let n in 2..MAX_ASSETS_IN_POOL;
+
+		let owner: AccountId = account("owner", 0, 1);
+		let (assets, _) = omnipool_assets(n, owner)?;
+		let assets: BoundedVec<AssetId, _> = assets.try_into().unwrap();
+	}: _(RawOrigin::Root, SHARE_ASSET, assets.clone(), 100, Permill::from_percent(1))
+	verify {
+		assert!(Omnipool::assets(SHARE_ASSET).is_some());
+		for asset_id in assets {
+			assert!(OmnipoolSubpools::migrated_asset(asset_id).is_some());
+		}
+	}
+
+	migrate_position {
+		let owner: AccountId = account("owner", 0, 1);
+		let (assets, position_id) = omnipool_assets(2, owner.clone())?;
+
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RawOrigin::Root.into(),
+			SHARE_ASSET,
+			assets.try_into().unwrap(),
+			100,
+			Permill::from_percent(1),
+		));
+	}: _(RawOrigin::Signed(owner), position_id)
+	verify {
+		assert_eq!(Omnipool::positions(position_id).unwrap().asset_id, SHARE_ASSET);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<crate::Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![
+				(
+					Some(1),
+					Some(b"LRNA".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+				(
+					Some(2),
+					Some(b"DAI".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+			],
+			native_asset_name: b"HDX".to_vec().try_into().unwrap(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+			native_decimals: 12,
+			native_symbol: b"HDX".to_vec().try_into().unwrap(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
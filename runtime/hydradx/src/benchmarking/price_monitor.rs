@@ -0,0 +1,129 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::benchmarking::omnipool::update_balance;
+use crate::{AccountId, Omnipool, PriceMonitor};
+use frame_benchmarking::account;
+use orml_benchmarking::runtime_benchmarks;
+use pallet_price_monitor::{DivergenceReport, DivergenceReports};
+use sp_runtime::Permill;
+
+/// Adds a token to the omnipool and moves its spot price away from the oracle price.
+fn init_diverged_token() -> Result<AssetId, BenchmarkError> {
+	crate::benchmarking::omnipool::init()?;
+
+	let token_id =
+		register_asset(b"PMN".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	let owner: AccountId = account("owner", 0, 1);
+	update_balance(token_id, &Omnipool::protocol_account(), 200_000_000_000_000_u128);
+	Omnipool::add_token(
+		RawOrigin::Root.into(),
+		token_id,
+		FixedU128::from((1, 5)),
+		Permill::from_percent(100),
+		owner,
+	)?;
+
+	let seller: AccountId = account("seller", 0, 1);
+	update_balance(0, &seller, 1_000_000_000_000_000_u128);
+	Omnipool::sell(
+		RawOrigin::Signed(seller.clone()).into(),
+		0,
+		token_id,
+		10_000_000_000_000,
+		0,
+	)?;
+	set_period(10);
+
+	// trade in the current block is not in the oracle yet
+	Omnipool::sell(RawOrigin::Signed(seller).into(), 0, token_id, 10_000_000_000_000, 0)?;
+
+	Ok(token_id)
+}
+
+runtime_benchmarks! {
+	{ Runtime, pallet_price_monitor }
+
+	set_threshold {
+		let token_id = init_diverged_token()?;
+		PriceMonitor::set_threshold(RawOrigin::Root.into(), token_id, Some(Permill::from_percent(1)))?;
+		PriceMonitor::report_divergence(RawOrigin::None.into(), token_id)?;
+	}: _(RawOrigin::Root, token_id, None)
+	verify {
+		assert!(PriceMonitor::threshold(token_id).is_none());
+		assert!(PriceMonitor::divergence_report(token_id).is_none());
+	}
+
+	report_divergence {
+		let token_id = init_diverged_token()?;
+		PriceMonitor::set_threshold(RawOrigin::Root.into(), token_id, Some(Permill::from_percent(1)))?;
+	}: _(RawOrigin::None, token_id)
+	verify {
+		assert!(matches!(
+			DivergenceReports::<Runtime>::get(token_id),
+			Some(DivergenceReport { reported_at: 10, .. })
+		));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<crate::Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![
+				(
+					Some(1),
+					Some(b"LRNA".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+				(
+					Some(2),
+					Some(b"DAI".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+			],
+			native_asset_name: b"HDX".to_vec().try_into().unwrap(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+			native_decimals: 12,
+			native_symbol: b"HDX".to_vec().try_into().unwrap(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
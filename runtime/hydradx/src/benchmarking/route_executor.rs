@@ -81,6 +81,7 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 		fee,
 		fee_collector,
 		repay_target,
+		None,
 	)?;
 	ensure!(
 		pallet_lbp::PoolData::<Runtime>::contains_key(&pool_id),
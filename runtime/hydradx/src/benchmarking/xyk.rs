@@ -99,6 +99,31 @@ runtime_benchmarks! {
 		assert_eq!(frame_system::Pallet::<Runtime>::account(caller).sufficients, 2);
 	}
 
+	add_liquidity_single_asset {
+		let asset_a = register_external_asset(b"TKNA".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_b = register_external_asset(b"TKNB".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let fee_asset = register_asset(b"FEE".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let caller = funded_account::<Runtime>("caller", 0, &[asset_a, fee_asset]);
+		let maker = funded_account::<Runtime>("maker", 1, &[asset_a, asset_b, fee_asset]);
+
+		init_fee_asset(fee_asset)?;
+		MultiTransactionPayment::set_currency(RawOrigin::Signed(maker.clone()).into(), fee_asset)?;
+		MultiTransactionPayment::set_currency(RawOrigin::Signed(caller.clone()).into(), fee_asset)?;
+
+		let amount : Balance = 250_000_000_000_000;
+		let min_shares : Balance = 1;
+
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+
+		<Currencies as MultiCurrency<AccountId>>::transfer(asset_a, &caller, &maker, INITIAL_BALANCE - amount)?;
+
+		let share_token = XYK::share_token(XYK::pair_account_from_assets(asset_a, asset_b));
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, min_shares)
+	verify {
+		assert!(Currencies::free_balance(share_token, &caller) > 0);
+	}
+
 	remove_liquidity {
 		let asset_a = register_external_asset(b"TKNA".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
 		let asset_b = register_external_asset(b"TKNB".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
@@ -143,6 +143,45 @@ runtime_benchmarks! {
 	}: _(RawOrigin::Signed(farm_owner), gfarm_id, FixedU128::from_inner(234_456_677_000_000_000_u128))
 	//NOTE: not verified because update prop is not public
 
+	top_up_global_farm {
+		let pair = AssetPair {
+			asset_in: register_external_asset(b"TKN1".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?,
+			asset_out: register_external_asset(b"TKN2".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?
+		};
+
+		let farm_owner = funded_account("caller", 0, &[HDX, pair.asset_in, pair.asset_out]);
+		let xyk_caller = funded_account("xyk_caller", 1, &[HDX, pair.asset_in, pair.asset_out]);
+		let liq_provider = funded_account("liq_provider", 2, &[HDX, pair.asset_in, pair.asset_out]);
+
+		create_xyk_pool(xyk_caller, pair.asset_in, pair.asset_out);
+		xyk_add_liquidity(liq_provider.clone(), pair, 1_000 * ONE, 100_000 * ONE)?;
+
+		let gfarm_id = 1;
+		let yfarm_id = 2;
+		create_gfarm(farm_owner.clone(), pair.asset_in, pair.asset_out, 9_000_000 * ONE)?;
+		create_yfarm(farm_owner.clone(), gfarm_id, pair, FixedU128::one())?;
+
+		run_to_block(200);
+		XYKLiquidityMining::deposit_shares(RawOrigin::Signed(liq_provider).into(), gfarm_id, yfarm_id, pair, 10 * ONE)?;
+		run_to_block(300);
+	}: _(RawOrigin::Signed(farm_owner), gfarm_id, 500_000 * ONE, BlockNumber::from(100_000_u32))
+
+	update_yield_farm_value_cap {
+		let pair = AssetPair {
+			asset_in: register_external_asset(b"TKN1".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?,
+			asset_out: register_external_asset(b"TKN2".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?
+		};
+
+		let farm_owner = funded_account("caller", 0, &[HDX, pair.asset_in, pair.asset_out]);
+		let xyk_caller = funded_account("xyk_caller", 1, &[HDX, pair.asset_in, pair.asset_out]);
+
+		create_xyk_pool(xyk_caller, pair.asset_in, pair.asset_out);
+
+		let gfarm_id = 1;
+		create_gfarm(farm_owner.clone(), pair.asset_in, pair.asset_out, 9_000_000 * ONE)?;
+		create_yfarm(farm_owner.clone(), gfarm_id, pair, FixedU128::one())?;
+	}: _(RawOrigin::Signed(farm_owner), gfarm_id, pair, Some(1_000_000 * ONE))
+
 	terminate_global_farm {
 		let pair = AssetPair {
 			asset_in: register_external_asset(b"TKN1".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?,
@@ -34,6 +34,9 @@ pub enum Function {
 	Transfer = "transfer(address,uint256)",
 	Approve = "approve(address,uint256)",
 	TransferFrom = "transferFrom(address,address,uint256)",
+	Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+	Nonces = "nonces(address)",
+	DomainSeparator = "DOMAIN_SEPARATOR()",
 }
 type BalanceOf<T> = <<T as pallet_evm::Config>::Currency as frame_support::traits::Currency<
 	<T as frame_system::Config>::AccountId,
@@ -182,6 +182,7 @@ where
 			RawOrigin::Root.into(),
 			b"MultiTransactionPayment".to_vec(),
 			b"dispatch_permit".to_vec(),
+			None,
 		);
 	}
 }
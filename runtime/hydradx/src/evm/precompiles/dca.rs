@@ -0,0 +1,214 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::evm::{
+	precompiles::{
+		erc20_mapping::HydraErc20Mapping,
+		handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+		revert,
+		substrate::RuntimeHelper,
+		succeed, Address, Output,
+	},
+	ExtendedAddressMapping,
+};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::IsType;
+use frame_system::pallet_prelude::BlockNumberFor;
+use hydradx_traits::evm::Erc20Mapping;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_dca::types::{ExecutionMode, Order, Schedule, TerminationMode};
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileResult};
+use primitive_types::H160;
+use primitives::{AssetId, Balance};
+use sp_runtime::traits::Dispatchable;
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	ScheduleSell = "scheduleSell(address,address,uint256,uint256,uint256,uint32)",
+	ScheduleBuy = "scheduleBuy(address,address,uint256,uint256,uint256,uint32)",
+	Terminate = "terminate(uint32,uint32)",
+	NextScheduleId = "nextScheduleId()",
+	RemainingAmount = "remainingAmount(uint32)",
+}
+
+/// Precompile exposing DCA schedule management to EVM contracts.
+///
+/// Assets are referenced by their ERC-20 precompile address (see `HydraErc20Mapping`),
+/// and the schedule owner is always the substrate account derived from the EVM caller.
+pub struct DcaPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for DcaPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_dca::Config<AssetId = AssetId>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_dca::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32> + IsType<sp_runtime::AccountId32>,
+	BlockNumberFor<Runtime>: From<u32>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Function::ScheduleSell | Function::ScheduleBuy | Function::Terminate => FunctionModifier::NonPayable,
+			_ => FunctionModifier::View,
+		})?;
+
+		match selector {
+			Function::ScheduleSell => Self::schedule(handle, true),
+			Function::ScheduleBuy => Self::schedule(handle, false),
+			Function::Terminate => Self::terminate(handle),
+			Function::NextScheduleId => Self::next_schedule_id(handle),
+			Function::RemainingAmount => Self::remaining_amount(handle),
+		}
+	}
+}
+
+impl<Runtime> DcaPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_dca::Config<AssetId = AssetId>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_dca::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32> + IsType<sp_runtime::AccountId32>,
+	BlockNumberFor<Runtime>: From<u32>,
+{
+	fn schedule(handle: &mut impl PrecompileHandle, is_sell: bool) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(6)?;
+
+		let asset_in = Self::decode_asset(input.read::<Address>()?.into())?;
+		let asset_out = Self::decode_asset(input.read::<Address>()?.into())?;
+		let amount = input.read::<Balance>()?;
+		let limit = input.read::<Balance>()?;
+		let total_amount = input.read::<Balance>()?;
+		let period = input.read::<u32>()?;
+
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		let order = if is_sell {
+			Order::Sell {
+				asset_in,
+				asset_out,
+				amount_in: amount,
+				min_amount_out: limit,
+				route: Default::default(),
+			}
+		} else {
+			Order::Buy {
+				asset_in,
+				asset_out,
+				amount_out: amount,
+				max_amount_in: limit,
+				route: Default::default(),
+			}
+		};
+
+		let schedule = Schedule {
+			owner: who.clone(),
+			period: period.into(),
+			total_amount,
+			max_retries: None,
+			stability_threshold: None,
+			slippage: None,
+			order,
+			termination: TerminationMode::Standard,
+			time_period: None,
+			execution: ExecutionMode::Automatic,
+		};
+
+		log::debug!(target: "evm", "dca: schedule by: {:?}, schedule: {:?}", who, schedule);
+
+		let schedule_id = pallet_dca::Pallet::<Runtime>::next_schedule_id();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_dca::Call::<Runtime>::schedule {
+				schedule,
+				start_execution_block: None,
+			},
+		)?;
+
+		Ok(succeed(EvmDataWriter::new().write(schedule_id).build()))
+	}
+
+	fn terminate(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let schedule_id = input.read::<u32>()?;
+		let next_execution_block = input.read::<u32>()?;
+
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		log::debug!(target: "evm", "dca: terminate by: {:?}, schedule id: {:?}", who, schedule_id);
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_dca::Call::<Runtime>::terminate {
+				schedule_id,
+				next_execution_block: Some(next_execution_block.into()),
+			},
+		)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn next_schedule_id(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		let schedule_id = pallet_dca::Pallet::<Runtime>::next_schedule_id();
+
+		Ok(succeed(Output::encode_uint::<u32>(schedule_id)))
+	}
+
+	fn remaining_amount(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let schedule_id = input.read::<u32>()?;
+
+		let remaining = pallet_dca::Pallet::<Runtime>::remaining_amounts(schedule_id).unwrap_or_default();
+
+		Ok(succeed(Output::encode_uint::<u128>(remaining)))
+	}
+
+	fn decode_asset(address: H160) -> Result<AssetId, pallet_evm::PrecompileFailure> {
+		HydraErc20Mapping::decode_evm_address(address).ok_or_else(|| revert("invalid asset address"))
+	}
+}
@@ -0,0 +1,134 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::evm::precompiles::{
+	erc20_mapping::HydraErc20Mapping,
+	handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+	revert,
+	substrate::RuntimeHelper,
+	succeed, Address,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use hydradx_traits::{evm::Erc20Mapping, AggregatedEntry, AggregatedOracle, OraclePeriod, Source};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_ema_oracle::{ordered_pair, WeightInfo};
+use pallet_evm::{GasWeightMapping, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
+use primitive_types::{H160, H256};
+use primitives::{AssetId, Balance};
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+
+type EmaOracle<Runtime> = pallet_ema_oracle::Pallet<Runtime>;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	GetPrice = "getPrice(bytes8,address,address,uint8)",
+}
+
+/// Read-only precompile exposing prices of the EMA oracle to EVM contracts.
+///
+/// `getPrice` returns the price of `assetA` denominated in `assetB` as a `(numerator, denominator)` pair,
+/// the block in which the oracle was last updated and the liquidity of both assets. The values are up to date
+/// until the parent block, like the values returned to the pallets.
+///
+/// Assets are referenced by their ERC-20 precompile address (see `HydraErc20Mapping`), the period is the index
+/// of the period in `OraclePeriod` and the source is the oracle source, e.g. `omnipool` or `hdxroute`.
+pub struct EmaOraclePrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for EmaOraclePrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_ema_oracle::Config,
+	BlockNumberFor<Runtime>: Into<u32>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(FunctionModifier::View)?;
+
+		match selector {
+			Function::GetPrice => Self::get_price(handle),
+		}
+	}
+}
+
+impl<Runtime> EmaOraclePrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_ema_oracle::Config,
+	BlockNumberFor<Runtime>: Into<u32>,
+{
+	fn get_price(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Updated entry and the stored entry of the period.
+		handle.record_cost(
+			<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+				<Runtime as pallet_ema_oracle::Config>::WeightInfo::get_entry(),
+			)
+			.saturating_add(RuntimeHelper::<Runtime>::db_read_gas_cost()),
+		)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let source = Self::decode_source(input.read::<H256>()?)?;
+		let asset_a = Self::decode_asset(input.read::<Address>()?.into())?;
+		let asset_b = Self::decode_asset(input.read::<Address>()?.into())?;
+		let period = Self::decode_period(input.read::<u8>()?)?;
+
+		let AggregatedEntry { price, liquidity, .. } =
+			<EmaOracle<Runtime> as AggregatedOracle<_, Balance, _, _>>::get_entry(asset_a, asset_b, period, source)
+				.map_err(|_| revert("oracle not available"))?;
+
+		let (entry, _) = EmaOracle::<Runtime>::oracle((source, ordered_pair(asset_a, asset_b), period))
+			.ok_or_else(|| revert("oracle not available"))?;
+		let updated_at: u32 = entry.updated_at.into();
+
+		Ok(succeed(
+			EvmDataWriter::new()
+				.write(price.n)
+				.write(price.d)
+				.write(updated_at)
+				.write(liquidity.a)
+				.write(liquidity.b)
+				.build(),
+		))
+	}
+
+	fn decode_source(source: H256) -> Result<Source, PrecompileFailure> {
+		let (source, padding) = source.as_bytes().split_at(8);
+		if padding.iter().any(|byte| *byte != 0) {
+			return Err(revert("invalid source"));
+		}
+		source.try_into().map_err(|_| revert("invalid source"))
+	}
+
+	fn decode_asset(address: H160) -> Result<AssetId, PrecompileFailure> {
+		HydraErc20Mapping::decode_evm_address(address).ok_or_else(|| revert("invalid asset address"))
+	}
+
+	fn decode_period(period: u8) -> Result<OraclePeriod, PrecompileFailure> {
+		OraclePeriod::all_periods()
+			.get(period as usize)
+			.copied()
+			.ok_or_else(|| revert("invalid period"))
+	}
+}
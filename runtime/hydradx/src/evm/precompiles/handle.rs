@@ -339,6 +339,60 @@ impl EvmData for bool {
 	}
 }
 
+impl<T: EvmData> EvmData for Vec<T> {
+	fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+		let mut inner_reader = reader.read_pointer()?;
+
+		// Read array size.
+		let array_size: usize = inner_reader
+			.read::<U256>()
+			.map_err(|_| revert("tried to parse array length out of bounds"))?
+			.try_into()
+			.map_err(|_| revert("array length is too large"))?;
+
+		// Items are read relative to the start of the array data (after the length).
+		let mut item_reader = EvmDataReader {
+			input: inner_reader
+				.input
+				.get(32..)
+				.ok_or_else(|| revert("tried to read array items out of bounds"))?,
+			cursor: 0,
+		};
+
+		let mut array = vec![];
+		for _ in 0..array_size {
+			array.push(item_reader.read()?);
+		}
+
+		Ok(array)
+	}
+
+	fn write(writer: &mut EvmDataWriter, value: Self) {
+		let mut inner_writer = EvmDataWriter::new().write(U256::from(value.len()));
+
+		for inner in value {
+			// Offsets in items are relative to the start of the item instead of the start of
+			// the array, and the offseted data must be appended after all items are written.
+			// This is corrected by the offset shift.
+			let shift = inner_writer.data.len();
+			let item_writer = EvmDataWriter::new().write(inner);
+
+			inner_writer = inner_writer.write_raw_bytes(&item_writer.data);
+			for mut offset_datum in item_writer.offset_data {
+				offset_datum.offset_shift += 32;
+				offset_datum.offset_position += shift;
+				inner_writer.offset_data.push(offset_datum);
+			}
+		}
+
+		writer.write_pointer(inner_writer.build());
+	}
+
+	fn has_static_size() -> bool {
+		false
+	}
+}
+
 impl<'a> EvmDataReader<'a> {
 	/// Create a new input parser.
 	pub fn new(input: &'a [u8]) -> Self {
@@ -21,7 +21,10 @@
 
 use core::marker::PhantomData;
 
-use crate::evm::precompiles::{erc20_mapping::is_asset_address, multicurrency::MultiCurrencyPrecompile};
+use crate::evm::precompiles::{
+	dca::DcaPrecompile, ema_oracle::EmaOraclePrecompile, erc20_mapping::is_asset_address,
+	multicurrency::MultiCurrencyPrecompile, router::RouterPrecompile,
+};
 use codec::Decode;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{
@@ -41,9 +44,12 @@ use primitive_types::{H160, U256};
 use sp_std::{borrow::ToOwned, vec::Vec};
 
 pub mod costs;
+pub mod dca;
+pub mod ema_oracle;
 pub mod erc20_mapping;
 pub mod handle;
 pub mod multicurrency;
+pub mod router;
 pub mod substrate;
 
 pub type EvmResult<T = ()> = Result<T, PrecompileFailure>;
@@ -93,6 +99,9 @@ pub const BN_MUL: H160 = H160(hex!("0000000000000000000000000000000000000007"));
 pub const BN_PAIRING: H160 = H160(hex!("0000000000000000000000000000000000000008"));
 pub const BLAKE2F: H160 = H160(hex!("0000000000000000000000000000000000000009"));
 pub const CALLPERMIT: H160 = H160(hex!("000000000000000000000000000000000000080a"));
+pub const DCA: H160 = H160(hex!("000000000000000000000000000000000000080b"));
+pub const EMA_ORACLE: H160 = H160(hex!("000000000000000000000000000000000000080c"));
+pub const ROUTER: H160 = H160(hex!("000000000000000000000000000000000000080d"));
 
 pub const ETH_PRECOMPILE_END: H160 = BLAKE2F;
 
@@ -106,6 +115,9 @@ where
 	R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + Decode,
 	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
 	MultiCurrencyPrecompile<R>: Precompile,
+	DcaPrecompile<R>: Precompile,
+	EmaOraclePrecompile<R>: Precompile,
+	RouterPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let context = handle.context();
@@ -141,6 +153,12 @@ where
 			Some(pallet_evm_precompile_call_permit::CallPermitPrecompile::<R>::execute(
 				handle,
 			))
+		} else if address == DCA {
+			Some(DcaPrecompile::<R>::execute(handle))
+		} else if address == EMA_ORACLE {
+			Some(EmaOraclePrecompile::<R>::execute(handle))
+		} else if address == ROUTER {
+			Some(RouterPrecompile::<R>::execute(handle))
 		} else if address == DISPATCH_ADDR {
 			Some(pallet_evm_precompile_dispatch::Dispatch::<R>::execute(handle))
 		} else if is_asset_address(address) {
@@ -159,7 +177,12 @@ where
 }
 
 pub fn is_precompile(address: H160) -> bool {
-	address == DISPATCH_ADDR || is_asset_address(address) || is_standard_precompile(address)
+	address == DISPATCH_ADDR
+		|| address == DCA
+		|| address == EMA_ORACLE
+		|| address == ROUTER
+		|| is_asset_address(address)
+		|| is_standard_precompile(address)
 }
 
 // This is a reimplementation of the upstream u64->H160 conversion
@@ -27,22 +27,37 @@ use crate::{
 			erc20_mapping::HydraErc20Mapping,
 			handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
 			substrate::RuntimeHelper,
-			succeed, Address, Output,
+			succeed, Address, EvmResult, Output,
 		},
 		ExtendedAddressMapping,
 	},
 	Currencies,
 };
 use codec::EncodeLike;
-use frame_support::traits::{IsType, OriginTrait};
+use ethabi::Token;
+use frame_support::traits::{Get, IsType, OriginTrait, Time};
+use hex_literal::hex;
 use hydradx_traits::evm::{Erc20Mapping, InspectEvmAccounts};
 use hydradx_traits::registry::Inspect as InspectRegistry;
 use orml_traits::{MultiCurrency as MultiCurrencyT, MultiCurrency};
 use pallet_evm::{AddressMapping, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
-use primitive_types::H160;
+use primitive_types::{H160, H256, U256};
 use primitives::{AssetId, Balance};
-use sp_runtime::traits::Dispatchable;
-use sp_std::marker::PhantomData;
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{Dispatchable, UniqueSaturatedInto};
+use sp_std::{marker::PhantomData, vec};
+
+/// Keccak256 of `Transfer(address,address,uint256)`.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// Keccak256 of `Approval(address,address,uint256)`.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+/// Version of the EIP-712 domain of the permits.
+pub const PERMIT_DOMAIN_VERSION: &[u8] = b"1";
+
+/// Gas cost of the signature recovery, same as the `ECRecover` precompile.
+pub const PERMIT_ECRECOVER_GAS_COST: u64 = 3_000;
 
 pub struct MultiCurrencyPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -74,7 +89,9 @@ where
 
 			handle.check_function_modifier(match selector {
 				Function::Transfer => FunctionModifier::NonPayable,
+				Function::Approve => FunctionModifier::NonPayable,
 				Function::TransferFrom => FunctionModifier::NonPayable,
+				Function::Permit => FunctionModifier::NonPayable,
 				_ => FunctionModifier::View,
 			})?;
 
@@ -86,8 +103,11 @@ where
 				Function::BalanceOf => Self::balance_of(asset_id, handle),
 				Function::Transfer => Self::transfer(asset_id, handle),
 				Function::Allowance => Self::allowance(handle),
-				Function::Approve => Self::not_supported(),
+				Function::Approve => Self::approve(handle),
 				Function::TransferFrom => Self::transfer_from(asset_id, handle),
+				Function::Permit => Self::permit(asset_id, handle),
+				Function::Nonces => Self::nonces(handle),
+				Function::DomainSeparator => Self::domain_separator(asset_id, handle),
 			};
 		}
 		Err(PrecompileFailure::Revert {
@@ -217,11 +237,12 @@ where
 		let mut input = handle.read_input()?;
 		input.expect_arguments(2)?;
 
-		let to: H160 = input.read::<Address>()?.into();
+		let to_address: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
 
-		let origin = ExtendedAddressMapping::into_account_id(handle.context().caller);
-		let to = ExtendedAddressMapping::into_account_id(to);
+		let from_address = handle.context().caller;
+		let origin = ExtendedAddressMapping::into_account_id(from_address);
+		let to = ExtendedAddressMapping::into_account_id(to_address);
 
 		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", origin, to, amount);
 
@@ -236,6 +257,8 @@ where
 			output: Into::<&str>::into(e).as_bytes().to_vec(),
 		})?;
 
+		Self::log_event(handle, SELECTOR_LOG_TRANSFER, from_address, to_address, amount)?;
+
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
@@ -246,7 +269,7 @@ where
 		let mut input = handle.read_input()?;
 		input.expect_arguments(2)?;
 
-		let _owner: H160 = input.read::<Address>()?.into();
+		let owner: H160 = input.read::<Address>()?.into();
 		let spender: H160 = input.read::<Address>()?.into();
 
 		let allowance =
@@ -255,13 +278,35 @@ where
 			) {
 				u128::MAX
 			} else {
-				0
+				pallet_evm_accounts::Pallet::<Runtime>::allowance(handle.code_address(), owner, spender)
 			};
 
 		let encoded = Output::encode_uint::<u128>(allowance);
 		Ok(succeed(encoded))
 	}
 
+	fn approve(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		// Amounts above `Balance::MAX`, e.g. `type(uint256).max`, are unlimited allowance.
+		let amount = Self::saturate_amount(input.read::<U256>()?);
+
+		let owner = handle.context().caller;
+
+		log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+		pallet_evm_accounts::Pallet::<Runtime>::set_allowance(handle.code_address(), owner, spender, amount);
+
+		Self::log_event(handle, SELECTOR_LOG_APPROVAL, owner, spender, amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
 	fn transfer_from(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 
@@ -270,38 +315,204 @@ where
 		input.expect_arguments(3)?;
 
 		let origin: H160 = handle.context().caller;
-		let from: H160 = input.read::<Address>()?.into();
-		let to: H160 = input.read::<Address>()?.into();
+		let from_address: H160 = input.read::<Address>()?.into();
+		let to_address: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
 
-		let from = ExtendedAddressMapping::into_account_id(from);
-		let to = ExtendedAddressMapping::into_account_id(to);
+		let from = ExtendedAddressMapping::into_account_id(from_address);
+		let to = ExtendedAddressMapping::into_account_id(to_address);
 
 		log::debug!(target: "evm", "multicurrency: transferFrom from: {:?}, to: {:?}, amount: {:?}", from, to, amount);
 
-		if <pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
-			origin,
-		) {
-			<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
-				asset_id,
-				&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from)),
-				&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
+		// Approved contracts manage balances without an explicit allowance.
+		let spends_allowance =
+			!<pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
+				origin,
+			);
+		if spends_allowance {
+			let token = handle.code_address();
+			if pallet_evm_accounts::Pallet::<Runtime>::allowance(token, from_address, origin) < amount {
+				return Err(revert("Insufficient allowance"));
+			}
+		}
+
+		<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
+			asset_id,
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from)),
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
+			amount,
+		)
+		.map_err(|e| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: Into::<&str>::into(e).as_bytes().to_vec(),
+		})?;
+
+		if spends_allowance {
+			handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+			// Allowance has been checked above, so this can't fail.
+			pallet_evm_accounts::Pallet::<Runtime>::spend_allowance(
+				handle.code_address(),
+				from_address,
+				origin,
 				amount,
 			)
-			.map_err(|e| PrecompileFailure::Revert {
-				exit_status: ExitRevert::Reverted,
-				output: Into::<&str>::into(e).as_bytes().to_vec(),
-			})?;
+			.map_err(|_| revert("Insufficient allowance"))?;
+		}
+
+		Self::log_event(handle, SELECTOR_LOG_TRANSFER, from_address, to_address, amount)?;
 
-			Ok(succeed(EvmDataWriter::new().write(true).build()))
-		} else {
-			Err(revert("Not approved contract"))
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	/// Sets the allowance from an EIP-2612 permit signed by the owner.
+	fn permit(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(
+			PERMIT_ECRECOVER_GAS_COST
+				.saturating_add(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))
+				.saturating_add(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(2)),
+		)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(7)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+		let value = input.read::<U256>()?;
+		let deadline = input.read::<U256>()?;
+		let v = input.read::<u8>()?;
+		let r = input.read::<H256>()?;
+		let s = input.read::<H256>()?;
+
+		// Blockchain time is in ms while Ethereum use second timestamps.
+		let timestamp: u128 = <Runtime as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+		if deadline < U256::from(timestamp / 1000) {
+			return Err(revert("Permit expired"));
+		}
+
+		let token = handle.code_address();
+		let nonce = pallet_evm_accounts::Pallet::<Runtime>::permit_nonce(token, owner);
+		let digest = Self::permit_digest(asset_id, token, owner, spender, value, nonce, deadline);
+
+		let mut signature = [0u8; 65];
+		signature[0..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v;
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+			.map_err(|_| revert("Invalid permit signature"))?;
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+		if signer.is_zero() || signer != owner {
+			return Err(revert("Invalid permit signature"));
 		}
+
+		// Signed value is kept for the digest, values above `Balance::MAX` are unlimited allowance.
+		let amount = Self::saturate_amount(value);
+
+		log::debug!(target: "evm", "multicurrency: permit owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+		pallet_evm_accounts::Pallet::<Runtime>::increment_permit_nonce(token, owner);
+		pallet_evm_accounts::Pallet::<Runtime>::set_allowance(token, owner, spender, amount);
+
+		Self::log_event(handle, SELECTOR_LOG_APPROVAL, owner, spender, amount)?;
+
+		Ok(succeed(EvmDataWriter::new().build()))
 	}
 
-	fn not_supported() -> PrecompileResult {
-		Err(PrecompileFailure::Error {
-			exit_status: pallet_evm::ExitError::Other("not supported".into()),
-		})
+	fn nonces(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+
+		let nonce = pallet_evm_accounts::Pallet::<Runtime>::permit_nonce(handle.code_address(), owner);
+
+		Ok(succeed(Output::encode_uint::<U256>(nonce)))
+	}
+
+	fn domain_separator(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		let domain_separator = Self::domain_separator_hash(asset_id, handle.code_address());
+
+		Ok(succeed(Output::encode_fixed_bytes(&domain_separator)))
+	}
+
+	/// EIP-712 domain separator of the token. The domain name is the asset name in the registry.
+	fn domain_separator_hash(asset_id: AssetId, token: H160) -> [u8; 32] {
+		let name = <pallet_asset_registry::Pallet<Runtime>>::asset_name(asset_id.into()).unwrap_or_default();
+		let chain_id: u64 = <Runtime as pallet_evm::Config>::ChainId::get();
+
+		keccak_256(&ethabi::encode(&[
+			Token::FixedBytes(
+				keccak_256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+					.to_vec(),
+			),
+			Token::FixedBytes(keccak_256(name.as_slice()).to_vec()),
+			Token::FixedBytes(keccak_256(PERMIT_DOMAIN_VERSION).to_vec()),
+			Token::Uint(U256::from(chain_id)),
+			Token::Address(token),
+		]))
+	}
+
+	/// EIP-712 digest of the EIP-2612 permit message.
+	pub fn permit_digest(
+		asset_id: AssetId,
+		token: H160,
+		owner: H160,
+		spender: H160,
+		value: U256,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let permit_hash = keccak_256(&ethabi::encode(&[
+			Token::FixedBytes(
+				keccak_256(b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+					.to_vec(),
+			),
+			Token::Address(owner),
+			Token::Address(spender),
+			Token::Uint(value),
+			Token::Uint(nonce),
+			Token::Uint(deadline),
+		]));
+
+		let mut message = b"\x19\x01".to_vec();
+		message.extend_from_slice(&Self::domain_separator_hash(asset_id, token));
+		message.extend_from_slice(&permit_hash);
+
+		keccak_256(&message)
+	}
+
+	/// Converts EVM amount to `Balance`, saturating at `Balance::MAX`.
+	fn saturate_amount(amount: U256) -> Balance {
+		Balance::try_from(amount).unwrap_or(Balance::MAX)
+	}
+
+	/// Emits `Transfer` or `Approval` log with the two indexed addresses and the amount as data.
+	fn log_event(
+		handle: &mut impl PrecompileHandle,
+		selector: [u8; 32],
+		from: H160,
+		to: H160,
+		amount: Balance,
+	) -> EvmResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let address = handle.code_address();
+		handle.log(
+			address,
+			vec![H256(selector), from.into(), to.into()],
+			EvmDataWriter::new().write(amount).build(),
+		)?;
+
+		Ok(())
 	}
 }
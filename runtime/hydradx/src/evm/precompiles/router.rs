@@ -0,0 +1,215 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::evm::{
+	precompiles::{
+		erc20_mapping::HydraErc20Mapping,
+		handle::{EvmData, EvmDataReader, EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+		revert,
+		substrate::RuntimeHelper,
+		succeed, Address, EvmResult, Output,
+	},
+	ExtendedAddressMapping,
+};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::{fungibles::Inspect, IsType};
+use hydradx_traits::evm::Erc20Mapping;
+use hydradx_traits::router::{PoolType, Trade};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileResult};
+use pallet_route_executor::MAX_NUMBER_OF_TRADES;
+use primitive_types::H160;
+use primitives::{AssetId, Balance};
+use sp_runtime::traits::Dispatchable;
+use sp_runtime::RuntimeDebug;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	Sell = "sell(address,address,uint256,uint256,(uint8,uint32,address,address)[])",
+	Buy = "buy(address,address,uint256,uint256,(uint8,uint32,address,address)[])",
+}
+
+/// Pool type codes of a hop.
+pub const POOL_XYK: u8 = 0;
+pub const POOL_LBP: u8 = 1;
+pub const POOL_STABLESWAP: u8 = 2;
+pub const POOL_OMNIPOOL: u8 = 3;
+
+/// One trade of a route, encoded as the Solidity tuple `(uint8 pool, uint32 poolId, address assetIn, address assetOut)`.
+///
+/// `poolId` is the share asset of the stableswap pool and is ignored for other pool types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hop {
+	pub pool: u8,
+	pub pool_id: u32,
+	pub asset_in: Address,
+	pub asset_out: Address,
+}
+
+impl EvmData for Hop {
+	fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+		Ok(Hop {
+			pool: reader.read()?,
+			pool_id: reader.read()?,
+			asset_in: reader.read()?,
+			asset_out: reader.read()?,
+		})
+	}
+
+	fn write(writer: &mut EvmDataWriter, value: Self) {
+		let mut data = EvmDataWriter::new()
+			.write(value.pool)
+			.write(value.pool_id)
+			.write(value.asset_in)
+			.write(value.asset_out)
+			.build();
+		writer.data.append(&mut data);
+	}
+
+	fn has_static_size() -> bool {
+		true
+	}
+}
+
+/// Precompile exposing router trades to EVM contracts.
+///
+/// Assets are referenced by their ERC-20 precompile address (see `HydraErc20Mapping`) and the trade
+/// is executed on behalf of the substrate account derived from the EVM caller. An empty route uses
+/// the route stored in the router for the asset pair.
+pub struct RouterPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for RouterPrecompile<Runtime>
+where
+	Runtime:
+		frame_system::Config + pallet_evm::Config + pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32> + IsType<sp_runtime::AccountId32>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+		match selector {
+			Function::Sell => Self::trade(handle, true),
+			Function::Buy => Self::trade(handle, false),
+		}
+	}
+}
+
+impl<Runtime> RouterPrecompile<Runtime>
+where
+	Runtime:
+		frame_system::Config + pallet_evm::Config + pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32> + IsType<sp_runtime::AccountId32>,
+{
+	/// Executes sell or buy and returns the amount received or spent by the caller.
+	fn trade(handle: &mut impl PrecompileHandle, is_sell: bool) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(5)?;
+
+		let asset_in = Self::decode_asset(input.read::<Address>()?.into())?;
+		let asset_out = Self::decode_asset(input.read::<Address>()?.into())?;
+		let amount = input.read::<Balance>()?;
+		let limit = input.read::<Balance>()?;
+		let hops = input.read::<Vec<Hop>>()?;
+
+		if hops.len() > MAX_NUMBER_OF_TRADES as usize {
+			return Err(revert("route is too long"));
+		}
+		let route = hops
+			.into_iter()
+			.map(Self::decode_hop)
+			.collect::<Result<Vec<Trade<AssetId>>, _>>()?;
+
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		log::debug!(target: "evm", "router: trade by: {:?}, is_sell: {:?}, route: {:?}", who, is_sell, route);
+
+		let (balance_asset, call) = if is_sell {
+			(
+				asset_out,
+				pallet_route_executor::Call::<Runtime>::sell {
+					asset_in,
+					asset_out,
+					amount_in: amount,
+					min_amount_out: limit,
+					route,
+				},
+			)
+		} else {
+			(
+				asset_in,
+				pallet_route_executor::Call::<Runtime>::buy {
+					asset_in,
+					asset_out,
+					amount_out: amount,
+					max_amount_in: limit,
+					route,
+				},
+			)
+		};
+
+		let initial_balance = <Runtime as pallet_route_executor::Config>::Currency::balance(balance_asset, &who);
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call)?;
+
+		let final_balance = <Runtime as pallet_route_executor::Config>::Currency::balance(balance_asset, &who);
+		let traded = if is_sell {
+			final_balance.saturating_sub(initial_balance)
+		} else {
+			initial_balance.saturating_sub(final_balance)
+		};
+
+		Ok(succeed(Output::encode_uint::<u128>(traded)))
+	}
+
+	fn decode_hop(hop: Hop) -> Result<Trade<AssetId>, pallet_evm::PrecompileFailure> {
+		let pool = match hop.pool {
+			POOL_XYK => PoolType::XYK,
+			POOL_LBP => PoolType::LBP,
+			POOL_STABLESWAP => PoolType::Stableswap(hop.pool_id),
+			POOL_OMNIPOOL => PoolType::Omnipool,
+			_ => return Err(revert("invalid pool type")),
+		};
+
+		Ok(Trade {
+			pool,
+			asset_in: Self::decode_asset(hop.asset_in.into())?,
+			asset_out: Self::decode_asset(hop.asset_out.into())?,
+		})
+	}
+
+	fn decode_asset(address: H160) -> Result<AssetId, pallet_evm::PrecompileFailure> {
+		HydraErc20Mapping::decode_evm_address(address).ok_or_else(|| revert("invalid asset address"))
+	}
+}
@@ -0,0 +1,222 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Programmatic construction of the runtime genesis - used to set up scenarios in integration tests.
+//!
+//! `GenesisBuilder` collects registered assets, funded accounts, collators and omnipool assets and builds
+//! the genesis storage out of them.
+//!
+//! Omnipool has no genesis config, so omnipool assets are added by `add_token` executed on top of the built
+//! genesis storage. The omnipool account is funded with the initial liquidity of each omnipool asset.
+
+use crate::{
+	opaque::SessionKeys, AccountId, AssetId, AuraId, Balance, NativeExistentialDeposit, Omnipool, Price,
+	RuntimeGenesisConfig, RuntimeOrigin, Treasury,
+};
+use primitives::constants::chain::CORE_ASSET_ID;
+use sp_core::Get;
+use sp_runtime::{BuildStorage, Permill, Storage};
+use sp_state_machine::BasicExternalities;
+
+/// Asset added to the omnipool at genesis.
+#[derive(Clone, Debug)]
+pub struct OmnipoolAsset {
+	pub asset_id: AssetId,
+	/// Initial liquidity of the asset. The omnipool account is funded with this amount.
+	pub amount: Balance,
+	pub price: Price,
+	pub weight_cap: Permill,
+	/// Owner of the position NFT minted for the initial liquidity.
+	pub position_owner: AccountId,
+}
+
+/// Builder of the runtime genesis.
+///
+/// Native asset balances are set in `pallet-balances`, balances of other assets in `orml-tokens`.
+#[derive(Clone, Default)]
+pub struct GenesisBuilder {
+	parachain_id: u32,
+	collators: Vec<(AccountId, AuraId)>,
+	candidacy_bond: Balance,
+	balances: Vec<(AccountId, AssetId, Balance)>,
+	//asset_id, name, existential deposit, is_sufficient
+	registered_assets: Vec<(Option<AssetId>, Option<Vec<u8>>, Balance, bool)>,
+	fee_currencies: Vec<(AssetId, Price)>,
+	omnipool_assets: Vec<OmnipoolAsset>,
+}
+
+impl GenesisBuilder {
+	pub fn new(parachain_id: u32) -> Self {
+		Self {
+			parachain_id,
+			..Default::default()
+		}
+	}
+
+	/// Set invulnerable collators and their session keys.
+	pub fn with_collators(mut self, collators: Vec<(AccountId, AuraId)>, candidacy_bond: Balance) -> Self {
+		self.collators = collators;
+		self.candidacy_bond = candidacy_bond;
+		self
+	}
+
+	/// Fund `who` with `amount` of `asset_id`.
+	pub fn with_balance(mut self, who: AccountId, asset_id: AssetId, amount: Balance) -> Self {
+		self.balances.push((who, asset_id, amount));
+		self
+	}
+
+	pub fn with_balances(mut self, balances: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.balances.extend(balances);
+		self
+	}
+
+	/// Register asset in the asset registry.
+	///
+	/// Asset registered without id gets the next available asset id.
+	pub fn with_registered_asset(
+		mut self,
+		asset_id: Option<AssetId>,
+		name: Option<&[u8]>,
+		existential_deposit: Balance,
+		is_sufficient: bool,
+	) -> Self {
+		self.registered_assets
+			.push((asset_id, name.map(|n| n.to_vec()), existential_deposit, is_sufficient));
+		self
+	}
+
+	/// Accept `asset_id` as a transaction fee currency.
+	pub fn with_fee_currency(mut self, asset_id: AssetId, price: Price) -> Self {
+		self.fee_currencies.push((asset_id, price));
+		self
+	}
+
+	/// Add asset to the omnipool. Asset has to be registered.
+	pub fn with_omnipool_asset(
+		mut self,
+		asset_id: AssetId,
+		amount: Balance,
+		price: Price,
+		weight_cap: Permill,
+		position_owner: AccountId,
+	) -> Self {
+		self.omnipool_assets.push(OmnipoolAsset {
+			asset_id,
+			amount,
+			price,
+			weight_cap,
+			position_owner,
+		});
+		self
+	}
+
+	/// Genesis config without omnipool assets.
+	pub fn build(&self) -> RuntimeGenesisConfig {
+		let omnipool_account = Omnipool::protocol_account();
+		let balances = self.balances.iter().cloned().chain(
+			self.omnipool_assets
+				.iter()
+				.map(|asset| (omnipool_account.clone(), asset.asset_id, asset.amount)),
+		);
+		let (native_balances, token_balances): (Vec<_>, Vec<_>) =
+			balances.partition(|(_, asset_id, _)| *asset_id == CORE_ASSET_ID);
+
+		RuntimeGenesisConfig {
+			balances: crate::BalancesConfig {
+				balances: native_balances
+					.into_iter()
+					.map(|(who, _, amount)| (who, amount))
+					.collect(),
+			},
+			collator_selection: crate::CollatorSelectionConfig {
+				invulnerables: self.collators.iter().map(|(acc, _)| acc.clone()).collect(),
+				candidacy_bond: self.candidacy_bond,
+				..Default::default()
+			},
+			session: crate::SessionConfig {
+				keys: self
+					.collators
+					.iter()
+					.cloned()
+					.map(|(acc, aura)| (acc.clone(), acc, SessionKeys { aura }))
+					.collect(),
+			},
+			asset_registry: crate::AssetRegistryConfig {
+				registered_assets: self
+					.registered_assets
+					.iter()
+					.cloned()
+					.map(|(asset_id, name, existential_deposit, is_sufficient)| {
+						(
+							asset_id,
+							name.map(|n| n.try_into().expect("Invalid asset name!")),
+							existential_deposit,
+							None,
+							None,
+							None,
+							is_sufficient,
+						)
+					})
+					.collect(),
+				native_existential_deposit: NativeExistentialDeposit::get(),
+				..Default::default()
+			},
+			parachain_info: crate::ParachainInfoConfig {
+				parachain_id: self.parachain_id.into(),
+				..Default::default()
+			},
+			tokens: crate::TokensConfig {
+				balances: token_balances,
+			},
+			polkadot_xcm: crate::PolkadotXcmConfig {
+				safe_xcm_version: Some(3),
+				..Default::default()
+			},
+			multi_transaction_payment: crate::MultiTransactionPaymentConfig {
+				currencies: self.fee_currencies.clone(),
+				account_currencies: vec![],
+			},
+			duster: crate::DusterConfig {
+				account_blacklist: vec![Treasury::account_id()],
+				reward_account: Some(Treasury::account_id()),
+				dust_account: Some(Treasury::account_id()),
+			},
+			..Default::default()
+		}
+	}
+
+	/// Genesis storage, including omnipool assets.
+	pub fn build_storage(&self) -> Result<Storage, String> {
+		let mut storage = self.build().build_storage()?;
+
+		BasicExternalities::execute_with_storage(&mut storage, || {
+			self.omnipool_assets.iter().try_for_each(|asset| {
+				Omnipool::add_token(
+					RuntimeOrigin::root(),
+					asset.asset_id,
+					asset.price,
+					asset.weight_cap,
+					asset.position_owner.clone(),
+				)
+				.map_err(|e| format!("Failed to add asset {} to omnipool: {:?}", asset.asset_id, e))
+			})
+		})?;
+
+		Ok(storage)
+	}
+}
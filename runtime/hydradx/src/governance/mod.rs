@@ -36,6 +36,7 @@ use crate::governance::{
 	origins::{GeneralAdmin, ReferendumCanceller, ReferendumKiller, Spender, Treasurer, WhitelistedCaller},
 	tracks::TracksInfo,
 };
+use codec::MaxEncodedLen;
 use frame_support::{
 	parameter_types,
 	sp_runtime::Permill,
@@ -45,9 +46,11 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use pallet_collective::EnsureProportionAtLeast;
 use primitives::constants::{currency::DOLLARS, time::DAYS};
+use scale_info::TypeInfo;
 use sp_arithmetic::Perbill;
 use sp_core::ConstU32;
 use sp_runtime::traits::IdentityLookup;
+use sp_runtime::RuntimeDebug;
 
 pub type TechCommitteeMajority = EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>;
 pub type TechCommitteeSuperMajority = EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>;
@@ -85,11 +88,39 @@ parameter_types! {
 	pub const TreasuryPayoutPeriod: u32 = 30 * DAYS;
 }
 
+/// Beneficiary of a treasury spend.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum TreasuryBeneficiary {
+	/// Spend is transferred to the account.
+	Account(AccountId),
+	/// Spend funds the referrals reward pot. Shares of the funded rewards are minted to the distribution account.
+	ReferralRewards(AccountId),
+}
+
+impl TreasuryBeneficiary {
+	fn pay(&self, amount: Balance) -> Result<(), sp_runtime::DispatchError> {
+		match self {
+			TreasuryBeneficiary::Account(who) => {
+				let _ = <Balances as frame_support::traits::fungible::Mutate<_>>::transfer(
+					&TreasuryAccount::get(),
+					who,
+					amount,
+					frame_support::traits::tokens::Preservation::Expendable,
+				)?;
+				Ok(())
+			}
+			TreasuryBeneficiary::ReferralRewards(distribution) => {
+				Referrals::fund_rewards(&TreasuryAccount::get(), distribution, amount)
+			}
+		}
+	}
+}
+
 pub struct PayFromTreasuryAccount;
 
 impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	type Balance = Balance;
-	type Beneficiary = AccountId;
+	type Beneficiary = TreasuryBeneficiary;
 	type AssetKind = ();
 	type Id = ();
 	type Error = sp_runtime::DispatchError;
@@ -100,13 +131,7 @@ impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 		_asset_kind: Self::AssetKind,
 		amount: Self::Balance,
 	) -> Result<Self::Id, Self::Error> {
-		let _ = <Balances as frame_support::traits::fungible::Mutate<_>>::transfer(
-			&TreasuryAccount::get(),
-			who,
-			amount,
-			frame_support::traits::tokens::Preservation::Expendable,
-		)?;
-		Ok(())
+		who.pay(amount)
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -117,13 +142,7 @@ impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	) -> Result<Self::Id, Self::Error> {
 		// In case of benchmarks, we adjust the value by multiplying it by 1_000_000_000_000, otherwise it fails with BelowMinimum limit error, because
 		// treasury benchmarks uses only 100 as the amount.
-		let _ = <Balances as frame_support::traits::fungible::Mutate<_>>::transfer(
-			&TreasuryAccount::get(),
-			who,
-			amount * 1_000_000_000_000,
-			frame_support::traits::tokens::Preservation::Expendable,
-		)?;
-		Ok(())
+		who.pay(amount * 1_000_000_000_000)
 	}
 
 	fn check_payment(_id: Self::Id) -> frame_support::traits::tokens::PaymentStatus {
@@ -142,6 +161,18 @@ impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	fn ensure_concluded(_: Self::Id) {}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<(), TreasuryBeneficiary> for TreasuryBenchmarkHelper {
+	fn create_asset_kind(_seed: u32) {}
+
+	fn create_beneficiary(seed: [u8; 32]) -> TreasuryBeneficiary {
+		TreasuryBeneficiary::Account(AccountId::from(seed))
+	}
+}
+
 impl pallet_treasury::Config for Runtime {
 	type Currency = Balances;
 	type ApproveOrigin = EitherOf<EnsureRoot<AccountId>, Treasurer>;
@@ -164,13 +195,13 @@ impl pallet_treasury::Config for Runtime {
 	type SpendOrigin =
 		frame_system::EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, crate::benches::BenchmarkMaxBalance>;
 	type AssetKind = (); // set to () to support only the native currency
-	type Beneficiary = AccountId;
-	type BeneficiaryLookup = IdentityLookup<AccountId>;
+	type Beneficiary = TreasuryBeneficiary;
+	type BeneficiaryLookup = IdentityLookup<TreasuryBeneficiary>;
 	type Paymaster = PayFromTreasuryAccount;
 	type BalanceConverter = UnityAssetBalanceConversion;
 	type PayoutPeriod = TreasuryPayoutPeriod;
 	#[cfg(feature = "runtime-benchmarks")]
-	type BenchmarkHelper = (); // default impl is enough because we support only the native currency
+	type BenchmarkHelper = TreasuryBenchmarkHelper;
 }
 
 parameter_types! {
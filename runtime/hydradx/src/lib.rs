@@ -34,16 +34,22 @@ pub mod weights;
 
 mod assets;
 pub mod evm;
+#[cfg(feature = "std")]
+pub mod genesis;
 pub mod governance;
+pub mod parameters;
+pub mod portfolio;
 mod system;
 pub mod types;
 pub mod xcm;
+pub mod xcm_introspection;
 
 pub use assets::*;
 pub use governance::origins::pallet_custom_origins;
 pub use governance::*;
 use pallet_asset_registry::AssetType;
 use pallet_currencies_rpc_runtime_api::AccountData;
+pub use parameters::*;
 pub use system::*;
 pub use xcm::*;
 
@@ -113,7 +119,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 275,
+	spec_version: 384,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -135,6 +141,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		VestingPalletId::get().into_account_truncating(),
 		ReferralsPalletId::get().into_account_truncating(),
 		BondsPalletId::get().into_account_truncating(),
+		Omnipool::fee_account(),
 	]
 }
 
@@ -168,6 +175,7 @@ construct_runtime!(
 		Referenda: pallet_referenda = 37,
 		Origins: pallet_custom_origins = 38,
 		Whitelist: pallet_whitelist = 39,
+		Parameters: pallet_parameters = 40,
 
 		// HydraDX related modules
 		AssetRegistry: pallet_asset_registry = 51,
@@ -208,6 +216,13 @@ construct_runtime!(
 		XYKLiquidityMining: pallet_xyk_liquidity_mining = 95,
 		XYKWarehouseLM: warehouse_liquidity_mining::<Instance2> = 96,
 
+		OpenData: pallet_open_data = 97,
+		TreasuryDiversification: pallet_treasury_diversification = 98,
+		OmnipoolSubpools: pallet_omnipool_subpools = 99,
+		RevenueStats: pallet_revenue_stats = 100,
+		PriceMonitor: pallet_price_monitor = 101,
+		WeightTelemetry: pallet_weight_telemetry = 102,
+
 		RelayChainInfo: pallet_relaychain_info = 201,
 		//NOTE: DCA pallet should be declared before ParachainSystem pallet,
 		//otherwise there is no data about relay chain parent hash
@@ -225,6 +240,10 @@ construct_runtime!(
 		XcmpQueue: cumulus_pallet_xcmp_queue exclude_parts { Call } = 111,
 		// 113 was used by DmpQueue which is now replaced by MessageQueue
 		MessageQueue: pallet_message_queue = 114,
+		XcmAssetTrap: pallet_xcm_asset_trap = 115,
+		RemoteIntents: pallet_remote_intents = 116,
+		XcmFeeSponsor: pallet_xcm_fee_sponsor = 117,
+		XcmRateLimiter: pallet_xcm_rate_limiter = 118,
 
 		// ORML XCM
 		OrmlXcm: orml_xcm = 135,
@@ -262,6 +281,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_transaction_multi_payment::SetFeeCurrency<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	pallet_claims::ValidateClaim<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
@@ -278,7 +298,19 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	migration::OnRuntimeUpgradeMigration,
+	(
+		migration::OnRuntimeUpgradeMigration,
+		pallet_referrals::migration::versioned::MigrateToLatest<Runtime, migration::ReferralsSeedNativeAmount>,
+		pallet_dca::migration::versioned::V0ToV1<Runtime>,
+		pallet_omnipool::migration::versioned::V0ToV1<Runtime>,
+		pallet_omnipool::migration::versioned::V1ToV2<Runtime, TreasuryAccount>,
+		pallet_otc::migration::versioned::V0ToV1<Runtime>,
+		pallet_otc::migration::versioned::V1ToV2<Runtime>,
+		pallet_transaction_pause::migration::v2::Migration<Runtime>,
+		pallet_staking::migration::versioned::V2ToV3<Runtime, PointPercentage, RewardCurveB>,
+		pallet_omnipool_liquidity_mining::migration::versioned::V1ToV2<Runtime>,
+		pallet_asset_registry::migration::versioned::V2ToV3<Runtime>,
+	),
 >;
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
@@ -306,6 +338,12 @@ mod benches {
 		[pallet_claims, Claims]
 		[pallet_staking, Staking]
 		[pallet_referrals, Referrals]
+		[pallet_open_data, OpenData]
+		[pallet_treasury_diversification, TreasuryDiversification]
+		[pallet_xcm_asset_trap, XcmAssetTrap]
+		[pallet_xcm_fee_sponsor, XcmFeeSponsor]
+		[pallet_xcm_rate_limiter, XcmRateLimiter]
+		[pallet_remote_intents, RemoteIntents]
 		[pallet_evm_accounts, EVMAccounts]
 		[pallet_otc, OTC]
 		[pallet_otc_settlements, OtcSettlements]
@@ -334,6 +372,7 @@ mod benches {
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_referenda, Referenda]
 		[pallet_whitelist, Whitelist]
+		[pallet_parameters, Parameters]
 	);
 }
 
@@ -943,6 +982,195 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, Balance, ItemId> for Runtime {
+		fn sacrificed_liquidity(asset_id: AssetId) -> pallet_omnipool_rpc_runtime_api::SacrificedLiquidity<Balance> {
+			let sacrificed = Omnipool::sacrificed_liquidity(asset_id);
+			pallet_omnipool_rpc_runtime_api::SacrificedLiquidity {
+				shares: sacrificed.shares,
+				amount: sacrificed.amount,
+				hub_amount: sacrificed.hub_amount,
+			}
+		}
+
+		fn total_sacrificed_hub_amount() -> Balance {
+			pallet_omnipool::SacrificedLiquidityPerAsset::<Runtime>::iter_values()
+				.fold(0, |acc: Balance, sacrificed| acc.saturating_add(sacrificed.hub_amount))
+		}
+
+		fn sacrifice_value(position_id: ItemId) -> Option<pallet_omnipool_rpc_runtime_api::SacrificedLiquidity<Balance>> {
+			let sacrificed = Omnipool::sacrifice_value(position_id).ok()?;
+			Some(pallet_omnipool_rpc_runtime_api::SacrificedLiquidity {
+				shares: sacrificed.shares,
+				amount: sacrificed.amount,
+				hub_amount: sacrificed.hub_amount,
+			})
+		}
+
+		fn solvency_report() -> pallet_omnipool_rpc_runtime_api::SolvencyReport<AssetId, Balance> {
+			let report = Omnipool::solvency_report();
+			pallet_omnipool_rpc_runtime_api::SolvencyReport {
+				hub_reserve: report.hub_reserve,
+				hub_balance: report.hub_balance,
+				solvent: report.is_solvent(),
+				assets: report
+					.assets
+					.into_iter()
+					.map(|(asset_id, asset)| {
+						(
+							asset_id,
+							pallet_omnipool_rpc_runtime_api::AssetSolvency {
+								solvent: asset.is_solvent(),
+								reserve: asset.reserve,
+								balance: asset.balance,
+								shares: asset.shares,
+								protocol_shares: asset.protocol_shares,
+								position_shares: asset.position_shares,
+							},
+						)
+					})
+					.collect(),
+			}
+		}
+	}
+
+	impl pallet_circuit_breaker_rpc_runtime_api::CircuitBreakerApi<Block, AssetId, AccountId, Balance> for Runtime {
+		fn remaining_limits(asset_id: AssetId) -> Option<pallet_circuit_breaker_rpc_runtime_api::RemainingLimits<Balance>> {
+			let reserve = Omnipool::load_asset_state(asset_id).ok()?.reserve;
+
+			Some(pallet_circuit_breaker_rpc_runtime_api::RemainingLimits {
+				trade_volume: CircuitBreaker::remaining_trade_volume(asset_id, reserve)
+					.ok()?
+					.map(|(amount_in, amount_out)| pallet_circuit_breaker_rpc_runtime_api::TradeVolume { amount_in, amount_out }),
+				add_liquidity: CircuitBreaker::remaining_add_liquidity(asset_id, reserve).ok()?,
+				remove_liquidity: CircuitBreaker::remaining_remove_liquidity(asset_id, reserve).ok()?,
+			})
+		}
+
+		fn remaining_account_trade_volume(asset_id: AssetId, who: AccountId) -> Option<pallet_circuit_breaker_rpc_runtime_api::TradeVolume<Balance>> {
+			let reserve = Omnipool::load_asset_state(asset_id).ok()?.reserve;
+
+			CircuitBreaker::remaining_account_trade_volume(&who, asset_id, reserve)
+				.ok()?
+				.map(|(amount_in, amount_out)| pallet_circuit_breaker_rpc_runtime_api::TradeVolume { amount_in, amount_out })
+		}
+	}
+
+	impl pallet_revenue_stats_rpc_runtime_api::RevenueStatsApi<Block, AssetId, Balance> for Runtime {
+		fn current_era() -> u32 {
+			RevenueStats::current_era()
+		}
+
+		fn oldest_era() -> u32 {
+			RevenueStats::oldest_era()
+		}
+
+		fn era_revenue(era: u32) -> Vec<(AssetId, pallet_revenue_stats_rpc_runtime_api::Revenue<Balance>)> {
+			RevenueStats::era_revenue(era)
+				.into_iter()
+				.map(|(asset_id, revenue)| {
+					(
+						asset_id,
+						pallet_revenue_stats_rpc_runtime_api::Revenue {
+							asset_fees: revenue.asset_fees,
+							protocol_fees: revenue.protocol_fees,
+							referral_fees: revenue.referral_fees,
+							treasury_inflows: revenue.treasury_inflows,
+						},
+					)
+				})
+				.collect()
+		}
+	}
+
+	impl pallet_asset_registry_rpc_runtime_api::AssetRegistryApi<Block, AssetId> for Runtime {
+		fn asset_by_ticker(ticker: Vec<u8>) -> Option<AssetId> {
+			AssetRegistry::asset_by_ticker(ticker)
+		}
+
+		fn asset_tickers(asset_id: AssetId) -> Option<pallet_asset_registry_rpc_runtime_api::AssetTickers> {
+			AssetRegistry::asset_tickers(asset_id)
+				.map(|(symbol, aliases)| pallet_asset_registry_rpc_runtime_api::AssetTickers { symbol, aliases })
+		}
+	}
+
+	impl pallet_weight_telemetry_rpc_runtime_api::WeightTelemetryApi<Block> for Runtime {
+		fn hook_weight_usage(hook: pallet_weight_telemetry::HookId) -> pallet_weight_telemetry_rpc_runtime_api::WeightUsage {
+			let usage = WeightTelemetry::hook_weight_usage(hook);
+			pallet_weight_telemetry_rpc_runtime_api::WeightUsage {
+				samples: usage.samples,
+				total_used: usage.total_used,
+				max_used: usage.max_used,
+				histogram: usage.histogram,
+			}
+		}
+
+		fn hooks_weight_usage() -> Vec<(pallet_weight_telemetry::HookId, pallet_weight_telemetry_rpc_runtime_api::WeightUsage)> {
+			WeightTelemetry::hooks_weight_usage()
+				.into_iter()
+				.map(|(hook, usage)| {
+					(
+						hook,
+						pallet_weight_telemetry_rpc_runtime_api::WeightUsage {
+							samples: usage.samples,
+							total_used: usage.total_used,
+							max_used: usage.max_used,
+							histogram: usage.histogram,
+						},
+					)
+				})
+				.collect()
+		}
+	}
+
+	impl pallet_route_executor_rpc_runtime_api::RouterApi<Block, hydradx_traits::router::Trade<AssetId>, Balance> for Runtime {
+		fn simulate_route(
+			route: Vec<hydradx_traits::router::Trade<AssetId>>,
+			amount: Balance,
+			direction: pallet_route_executor_rpc_runtime_api::TradeDirection,
+		) -> Result<Vec<pallet_route_executor_rpc_runtime_api::TradeSimulation<hydradx_traits::router::Trade<AssetId>, Balance>>, sp_runtime::DispatchError> {
+			let simulation = match direction {
+				pallet_route_executor_rpc_runtime_api::TradeDirection::Sell => Router::simulate_sell(route, amount)?,
+				pallet_route_executor_rpc_runtime_api::TradeDirection::Buy => Router::simulate_buy(route, amount)?,
+			};
+
+			Ok(simulation
+				.into_iter()
+				.map(|trade| pallet_route_executor_rpc_runtime_api::TradeSimulation {
+					trade: hydradx_traits::router::Trade {
+						pool: trade.pool,
+						asset_in: trade.asset_in,
+						asset_out: trade.asset_out,
+					},
+					amount_in: trade.amount_in,
+					amount_out: trade.amount_out,
+					fee: trade.fee,
+					spot_price_before: trade.spot_price_before,
+					spot_price_after: trade.spot_price_after,
+				})
+				.collect())
+		}
+	}
+
+	impl portfolio_runtime_api::PortfolioApi<Block, AccountId, AssetId, Balance, primitives::ItemId> for Runtime {
+		fn account_portfolio(who: AccountId) -> portfolio_runtime_api::AccountPortfolio<AssetId, Balance, primitives::ItemId> {
+			portfolio::account_portfolio(who)
+		}
+	}
+
+	#[api_version(2)]
+	impl xcm_introspection_runtime_api::XcmIntrospectionApi<Block, AssetId, Balance> for Runtime {
+		fn introspect_xcm(
+			origin: VersionedLocation,
+			message: VersionedXcm<()>,
+		) -> Result<xcm_introspection_runtime_api::XcmIntrospection<AssetId, Balance>, xcm_introspection_runtime_api::Error> {
+			xcm_introspection::introspect_xcm(origin, message)
+		}
+
+		fn deferred_xcms(para_id: u32) -> Vec<xcm_introspection_runtime_api::DeferredXcm> {
+			xcm_introspection::deferred_xcms(para_id)
+		}
+	}
+
 	impl xcm_fee_payment_runtime_api::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: polkadot_xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			if !matches!(xcm_version, 3 | 4) {
@@ -1030,6 +1258,8 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_xyk, benchmarking::xyk);
 			orml_list_benchmark!(list, extra, pallet_dynamic_evm_fee, benchmarking::dynamic_evm_fee);
 			orml_list_benchmark!(list, extra, pallet_xyk_liquidity_mining, benchmarking::xyk_liquidity_mining);
+			orml_list_benchmark!(list, extra, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
+			orml_list_benchmark!(list, extra, pallet_price_monitor, benchmarking::price_monitor);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -1167,6 +1397,8 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_xyk, benchmarking::xyk);
 			orml_add_benchmark!(params, batches, pallet_dynamic_evm_fee, benchmarking::dynamic_evm_fee);
 			orml_add_benchmark!(params, batches, pallet_xyk_liquidity_mining, benchmarking::xyk_liquidity_mining);
+			orml_add_benchmark!(params, batches, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
+			orml_add_benchmark!(params, batches, pallet_price_monitor, benchmarking::price_monitor);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
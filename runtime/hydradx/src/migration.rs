@@ -14,14 +14,23 @@
 // limitations under the License.
 
 use super::*;
+use codec::DecodeAll;
 use frame_support::{
 	dispatch::{GetDispatchInfo, RawOrigin},
-	traits::OnRuntimeUpgrade,
+	parameter_types,
+	traits::{Get, OnRuntimeUpgrade},
 	weights::Weight,
 };
+use sp_runtime::Saturating;
 pub struct OnRuntimeUpgradeMigration;
 use super::Runtime;
 
+parameter_types! {
+	/// Amount of native asset transferred to the referrals pot when the pot was seeded.
+	/// Read only by the referrals migration initializing the pot accounting.
+	pub const ReferralsSeedNativeAmount: Balance = 10_000_000_000_000;
+}
+
 pub fn bind_pallet_account() -> Weight {
 	match EVMAccounts::bind_evm_address(RawOrigin::Signed(Liquidation::account_id()).into()) {
 		Ok(_) => {
@@ -43,8 +52,47 @@ pub fn bind_pallet_account() -> Weight {
 	call.get_dispatch_info().weight
 }
 
+/// Converts beneficiaries of pending treasury spends from `AccountId` to `TreasuryBeneficiary::Account`.
+///
+/// Only spends which decode in the old format are converted, so the migration can run repeatedly.
+pub fn migrate_treasury_spend_beneficiaries() -> Weight {
+	type OldSpendStatus = pallet_treasury::SpendStatus<(), Balance, AccountId, BlockNumber, ()>;
+
+	let mut reads: u64 = 0;
+	let mut writes: u64 = 0;
+	for index in pallet_treasury::Spends::<Runtime>::iter_keys().collect::<Vec<_>>() {
+		reads.saturating_inc();
+		let key = pallet_treasury::Spends::<Runtime>::hashed_key_for(index);
+		let Some(raw) = frame_support::storage::unhashed::get_raw(&key) else {
+			continue;
+		};
+		let Ok(old) = OldSpendStatus::decode_all(&mut &raw[..]) else {
+			continue;
+		};
+		pallet_treasury::Spends::<Runtime>::insert(
+			index,
+			pallet_treasury::SpendStatus {
+				asset_kind: old.asset_kind,
+				amount: old.amount,
+				beneficiary: TreasuryBeneficiary::Account(old.beneficiary),
+				valid_from: old.valid_from,
+				expire_at: old.expire_at,
+				status: old.status,
+			},
+		);
+		writes.saturating_inc();
+	}
+
+	log::info!(
+		target: "runtime::pallet_treasury",
+		"Migrated {:?} treasury spend beneficiaries", writes
+	);
+
+	<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
+}
+
 impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 	fn on_runtime_upgrade() -> Weight {
-		bind_pallet_account()
+		bind_pallet_account().saturating_add(migrate_treasury_spend_beneficiaries())
 	}
 }
@@ -0,0 +1,113 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Economic constants adjustable on-chain by `pallet-parameters`.
+//!
+//! Each parameter is read by the pallets as a regular `Get` implementation. Until a parameter is set, its default
+//! value is used, so the defaults are the values the constants had before they became adjustable.
+//!
+//! Parameters are grouped by the pallet using them. The codec indices of the groups and of the parameters are part
+//! of the storage keys and must never change.
+
+use crate::origins::{GeneralAdmin, OmnipoolAdmin};
+use crate::{weights, AccountId, Balance, Runtime, RuntimeEvent, RuntimeOrigin, TechCommitteeSuperMajority};
+use frame_support::dynamic_params::{dynamic_pallet_params, dynamic_params};
+use frame_support::traits::{EitherOf, EnsureOrigin, EnsureOriginWithArg};
+use frame_system::EnsureRoot;
+use sp_runtime::Permill;
+
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod dca {
+		/// Multiple of the transaction fee a trade of a schedule has to reach.
+		#[codec(index = 0)]
+		pub static FeeMultiplierForMinTradeLimit: Balance = 20;
+	}
+
+	#[dynamic_pallet_params]
+	#[codec(index = 2)]
+	pub mod circuit_breaker {
+		#[codec(index = 0)]
+		pub static DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000); // 50%
+
+		#[codec(index = 1)]
+		pub static DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000)); // 5%
+
+		#[codec(index = 2)]
+		pub static DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000)); // 5%
+
+		#[codec(index = 3)]
+		pub static DefaultMaxAccountTradeVolumeLimitPerBlock: Option<(u32, u32)> = None;
+	}
+
+	#[dynamic_pallet_params]
+	#[codec(index = 3)]
+	pub mod otc {
+		#[codec(index = 0)]
+		pub static Fee: Permill = Permill::from_rational(1u32, 1_000u32); // 0.1%
+
+		/// Share of the fee rebated to order owners.
+		#[codec(index = 1)]
+		pub static MakerRebate: Permill = Permill::zero();
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Otc(dynamic_params::otc::Parameters::Fee(
+			dynamic_params::otc::Fee,
+			Some(Permill::from_percent(1)),
+		))
+	}
+}
+
+type CircuitBreakerParametersOrigin =
+	EitherOf<EnsureRoot<AccountId>, EitherOf<TechCommitteeSuperMajority, OmnipoolAdmin>>;
+type DcaParametersOrigin = EitherOf<EnsureRoot<AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+type GeneralParametersOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+
+/// Parameters are set by the same origins which are allowed to configure the pallet using them.
+pub struct DynamicParametersOrigin;
+
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParametersOrigin {
+	type Success = ();
+
+	fn try_origin(origin: RuntimeOrigin, key: &RuntimeParametersKey) -> Result<Self::Success, RuntimeOrigin> {
+		match key {
+			RuntimeParametersKey::CircuitBreaker(_) => CircuitBreakerParametersOrigin::try_origin(origin).map(|_| ()),
+			RuntimeParametersKey::Dca(_) => DcaParametersOrigin::try_origin(origin).map(|_| ()),
+			RuntimeParametersKey::Otc(_) => GeneralParametersOrigin::try_origin(origin).map(|_| ()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParametersOrigin;
+	type WeightInfo = weights::pallet_parameters::HydraWeight<Runtime>;
+}
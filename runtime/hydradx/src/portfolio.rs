@@ -0,0 +1,141 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the portfolio runtime api - all holdings of an account collected in one call.
+
+use crate::{
+	AccountId, AssetId, Balance, Block, Currencies, Omnipool, OmnipoolCollectionId, OmnipoolLMCollectionId, Referrals,
+	Runtime, StableswapAccountIdConstructor, Staking, Uniques, XYKLmCollectionId, XYKWarehouseLM, XYK,
+};
+use frame_support::traits::tokens::nonfungibles::InspectEnumerable;
+use hydradx_traits::AccountIdFor;
+use orml_traits::MultiCurrency;
+use pallet_currencies_rpc_runtime_api::CurrenciesApi;
+use portfolio_runtime_api::{
+	AccountPortfolio, OmnipoolDeposit, OmnipoolPosition, StableswapShares, StakingPosition, TokenBalance, XykDeposit,
+};
+use primitives::ItemId;
+use sp_std::vec::Vec;
+
+pub fn account_portfolio(who: AccountId) -> AccountPortfolio<AssetId, Balance, ItemId> {
+	let balances = <Runtime as CurrenciesApi<Block, AssetId, AccountId, Balance>>::accounts(who.clone())
+		.into_iter()
+		.map(|(asset_id, data)| {
+			(
+				asset_id,
+				TokenBalance {
+					free: data.free,
+					reserved: data.reserved,
+					frozen: data.frozen,
+				},
+			)
+		})
+		.collect();
+
+	// positions deposited in liquidity mining are frozen and reported with the deposits
+	let omnipool_positions = Uniques::owned_in_collection(&OmnipoolCollectionId::get(), &who)
+		.filter(|position_id| !Omnipool::is_position_frozen(*position_id))
+		.filter_map(omnipool_position)
+		.collect();
+
+	let omnipool_lm_deposits = Uniques::owned_in_collection(&OmnipoolLMCollectionId::get(), &who)
+		.filter_map(|deposit_id| {
+			let position_id = pallet_omnipool_liquidity_mining::OmniPositionId::<Runtime>::get(deposit_id)?;
+			Some(OmnipoolDeposit {
+				deposit_id,
+				position: omnipool_position(position_id)?,
+			})
+		})
+		.collect();
+
+	let xyk_lm_deposits = Uniques::owned_in_collection(&XYKLmCollectionId::get(), &who)
+		.filter_map(|deposit_id| {
+			let deposit = XYKWarehouseLM::deposit(deposit_id)?;
+			Some(XykDeposit {
+				deposit_id,
+				share_token: XYK::share_token(deposit.get_amm_pool_id()),
+				shares: deposit.get_shares(),
+			})
+		})
+		.collect();
+
+	let staking = Staking::get_user_position_id(&who)
+		.ok()
+		.flatten()
+		.and_then(|position_id| {
+			let position = Staking::get_position(position_id)?;
+			Some(StakingPosition {
+				position_id,
+				stake: position.get_stake(),
+				locked: position.get_total_locked().ok()?,
+			})
+		});
+
+	AccountPortfolio {
+		balances,
+		omnipool_positions,
+		stableswap_shares: stableswap_shares(&who),
+		omnipool_lm_deposits,
+		xyk_lm_deposits,
+		staking,
+		referral_rewards: Referrals::pending_rewards(&who),
+	}
+}
+
+fn omnipool_position(position_id: ItemId) -> Option<OmnipoolPosition<AssetId, Balance, ItemId>> {
+	let position = Omnipool::positions(position_id)?;
+	let (value, hub_value) = Omnipool::position_value(position_id).ok()?;
+	Some(OmnipoolPosition {
+		position_id,
+		asset_id: position.asset_id,
+		amount: position.amount,
+		shares: position.shares,
+		value,
+		hub_value,
+	})
+}
+
+fn stableswap_shares(who: &AccountId) -> Vec<StableswapShares<AssetId, Balance>> {
+	pallet_stableswap::Pools::<Runtime>::iter()
+		.filter_map(|(pool_id, pool)| {
+			let shares = Currencies::free_balance(pool_id, who);
+			if shares == 0 {
+				return None;
+			}
+			let share_issuance = Currencies::total_issuance(pool_id);
+			let pool_account = StableswapAccountIdConstructor::<Runtime>::from_assets(
+				&pool_id,
+				Some(pallet_stableswap::POOL_IDENTIFIER),
+			);
+			let assets = pool
+				.assets
+				.iter()
+				.map(|asset_id| {
+					let reserve = Currencies::free_balance(*asset_id, &pool_account);
+					let amount = hydra_dx_math::stableswap::calculate_liquidity_out(reserve, shares, share_issuance)
+						.unwrap_or(0);
+					(*asset_id, amount)
+				})
+				.collect();
+			Some(StableswapShares {
+				pool_id,
+				shares,
+				assets,
+			})
+		})
+		.collect()
+}
@@ -18,7 +18,7 @@
 use super::*;
 
 use crate::origins::GeneralAdmin;
-use pallet_transaction_multi_payment::{DepositAll, TransferFees, WeightInfo};
+use pallet_transaction_multi_payment::{DepositAll, DiscountedFees, OnNonNativeFeeDeposited, TransferFees, WeightInfo};
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use primitives::constants::{
 	chain::{CORE_ASSET_ID, MAXIMUM_BLOCK_WEIGHT},
@@ -46,7 +46,9 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureRoot;
-use hydradx_adapters::{OraclePriceProvider, RelayChainBlockNumberProvider};
+use hydradx_adapters::{
+	OraclePriceProvider, ReferralsTransactionFee, RelayChainBlockNumberProvider, RevenueStatsFeeDeposit,
+};
 use scale_info::TypeInfo;
 
 pub struct CallFilter;
@@ -152,13 +154,14 @@ parameter_types! {
 	pub const SS58Prefix: u16 = 63;
 }
 
-//We get the base and add the multi payment overhead until we have proper solution for calculating ExtrinsicBaseWeight by using the benchmark overhead command.
+//We get the base and add the multi payment and non-native fee handling overhead until we have proper solution for calculating ExtrinsicBaseWeight by using the benchmark overhead command.
 pub fn get_extrinsic_base_weight() -> Weight {
 	let mut base_weight = frame_support::weights::constants::ExtrinsicBaseWeight::get();
 	let multi_payment_overhead =
 		crate::weights::pallet_transaction_multi_payment::HydraWeight::<Runtime>::withdraw_fee();
 
 	base_weight.saturating_accrue(multi_payment_overhead);
+	base_weight.saturating_accrue(ReferralsTransactionFee::<Runtime>::on_fee_deposited_weight());
 
 	base_weight
 }
@@ -204,7 +207,7 @@ impl frame_system::Config for Runtime {
 	/// What to do if a new account is created.
 	type OnNewAccount = ();
 	/// What to do if an account is fully reaped from the system.
-	type OnKilledAccount = ();
+	type OnKilledAccount = Duster;
 	/// Weight information for the extrinsics of this pallet.
 	type SystemWeightInfo = weights::frame_system::HydraWeight<Runtime>;
 	type SS58Prefix = SS58Prefix;
@@ -421,6 +424,7 @@ pub enum ProxyType {
 	Transfer,
 	Liquidity,
 	LiquidityMining,
+	Trading,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -463,6 +467,31 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 						pallet_omnipool_liquidity_mining::Call::withdraw_shares { .. }
 					)
 			),
+			// Trading group doesn't include transfers, so trading can be delegated to bots safely.
+			// Trades with surplus capture are excluded as they send the surplus to an arbitrary beneficiary.
+			// Keeper schedules are excluded as the proxy could execute them and take the keeper bounty.
+			ProxyType::Trading => match c {
+				RuntimeCall::DCA(pallet_dca::Call::schedule { schedule, .. }) => {
+					schedule.execution != pallet_dca::types::ExecutionMode::Keeper
+				}
+				_ => matches!(
+					c,
+					RuntimeCall::Omnipool(pallet_omnipool::Call::sell { .. })
+						| RuntimeCall::Omnipool(pallet_omnipool::Call::buy { .. })
+						| RuntimeCall::Omnipool(pallet_omnipool::Call::sell_with_price_protection { .. })
+						| RuntimeCall::Omnipool(pallet_omnipool::Call::commit_trade { .. })
+						| RuntimeCall::Omnipool(pallet_omnipool::Call::reveal_sell { .. })
+						| RuntimeCall::Stableswap(pallet_stableswap::Call::sell { .. })
+						| RuntimeCall::Stableswap(pallet_stableswap::Call::buy { .. })
+						| RuntimeCall::Router(pallet_route_executor::Call::sell { .. })
+						| RuntimeCall::Router(pallet_route_executor::Call::buy { .. })
+						| RuntimeCall::Router(pallet_route_executor::Call::sell_all { .. })
+						| RuntimeCall::Router(pallet_route_executor::Call::execute_trades { .. })
+						| RuntimeCall::Router(pallet_route_executor::Call::sell_with_price_impact_limit { .. })
+						| RuntimeCall::Router(pallet_route_executor::Call::buy_with_price_impact_limit { .. })
+						| RuntimeCall::DCA(pallet_dca::Call::terminate { .. })
+				),
+			},
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -567,15 +596,35 @@ parameter_types! {
 	pub MaximumMultiplier: Multiplier = Multiplier::saturating_from_integer(320);
 }
 
+/// Deposits fees to the treasury and records them in the revenue stats.
+pub type TreasuryFeeDeposit = RevenueStatsFeeDeposit<Runtime, DepositAll<Runtime>>;
+
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount>;
+	type OnChargeTransaction = DiscountedFees<Runtime, TransferFees<Currencies, TreasuryFeeDeposit, TreasuryAccount>>;
 	type OperationalFeeMultiplier = ();
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
 }
 
+/// Amount staked in the staking position of an account.
+pub struct StakedAmount;
+impl orml_traits::GetByKey<AccountId, Balance> for StakedAmount {
+	fn get(who: &AccountId) -> Balance {
+		Staking::get_user_position_id(who)
+			.ok()
+			.flatten()
+			.and_then(Staking::get_position)
+			.map(|position| position.get_stake())
+			.unwrap_or_default()
+	}
+}
+
+parameter_types! {
+	pub const MaxFeeDiscounts: u32 = 10;
+}
+
 impl pallet_transaction_multi_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AcceptedCurrencyOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
@@ -591,6 +640,12 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type EvmPermit = evm::permit::EvmPermitHandler<Runtime>;
 	type TryCallCurrency<'a> = pallet_transaction_multi_payment::TryCallCurrency<Runtime>;
 	type SwappablePaymentAssetSupport = assets::XykPaymentAssetSupport;
+	type RuntimeCall = RuntimeCall;
+	type PermitSignature = Signature;
+	type PermitSigner = <Signature as sp_runtime::traits::Verify>::Signer;
+	type StakedAmount = StakedAmount;
+	type MaxFeeDiscounts = MaxFeeDiscounts;
+	type NonNativeFeeHandler = ReferralsTransactionFee<Runtime>;
 }
 
 impl pallet_relaychain_info::Config for Runtime {
@@ -632,6 +687,7 @@ impl pallet_collator_rewards::Config for Runtime {
 impl pallet_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+	type MaxExpiringPausesPerBlock = ConstU32<20>;
 	type WeightInfo = weights::pallet_transaction_pause::HydraWeight<Runtime>;
 }
 
@@ -363,3 +363,101 @@ mod xcm_fee_payment_api_tests {
 		});
 	}
 }
+
+#[cfg(test)]
+mod xcm_introspection_api_tests {
+	use super::*;
+	use frame_support::traits::ProcessMessageError;
+	use polkadot_xcm::v4::prelude::*;
+	use xcm_introspection_runtime_api::runtime_decl_for_xcm_introspection_api::XcmIntrospectionApiV2;
+	use xcm_introspection_runtime_api::{DeferredXcm, XcmIntrospection};
+
+	fn hdx(amount: u128) -> Asset {
+		Asset {
+			id: AssetId(Location::new(
+				1,
+				Junctions::X2(Arc::new([Parachain(100), GeneralIndex(0)])),
+			)),
+			fun: Fungible(amount),
+		}
+	}
+
+	fn sibling() -> VersionedLocation {
+		VersionedLocation::V4(Location::new(1, Junctions::X1(Arc::new([Parachain(2000)]))))
+	}
+
+	#[test]
+	fn introspect_xcm_should_report_fee_when_message_buys_execution() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let xcm_message = Xcm(vec![
+				WithdrawAsset(hdx(1_000_000_000_000).into()),
+				BuyExecution {
+					fees: hdx(400_000_000_000),
+					weight_limit: Unlimited,
+				},
+			]);
+
+			let weight = Weight::from_parts(200_000_000, 0);
+			assert_eq!(
+				Runtime::introspect_xcm(sibling(), VersionedXcm::from(xcm_message)),
+				Ok(XcmIntrospection {
+					barrier: Ok(()),
+					weight,
+					fee: Some((CORE_ASSET_ID, crate::WeightToFee::weight_to_fee(&weight))),
+					deferred_by: None,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn introspect_xcm_should_report_barrier_rejection_when_message_does_not_pay_for_execution() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let xcm_message = Xcm(vec![
+				WithdrawAsset(hdx(1_000_000_000_000).into()),
+				DepositAsset {
+					assets: All.into(),
+					beneficiary: Location::new(
+						0,
+						Junctions::X1(Arc::new([AccountId32 {
+							network: None,
+							id: [1; 32],
+						}])),
+					),
+				},
+			]);
+
+			let introspection = Runtime::introspect_xcm(sibling(), VersionedXcm::from(xcm_message)).unwrap();
+
+			assert_eq!(introspection.barrier, Err(ProcessMessageError::Unsupported));
+			assert_eq!(introspection.fee, None);
+		});
+	}
+
+	#[test]
+	fn deferred_xcms_should_list_messages_deferred_by_rate_limiter() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let xcm_message = VersionedXcm::from(Xcm::<()>(vec![ReserveAssetDeposited(hdx(1_000_000_000_000).into())]));
+			pallet_xcm_rate_limiter::DeferredMessages::<Runtime>::insert(
+				polkadot_parachain::primitives::Id::from(2000),
+				3,
+				pallet_xcm_rate_limiter::DeferredMessage {
+					sent_at: 10,
+					deferred_to: 20,
+					message: frame_support::BoundedVec::truncate_from(xcm_message.encode()),
+				},
+			);
+
+			assert_eq!(
+				Runtime::deferred_xcms(2000),
+				vec![DeferredXcm {
+					index: 3,
+					sent_at: 10,
+					deferred_to: 20,
+					message: xcm_message,
+				}]
+			);
+			assert_eq!(Runtime::deferred_xcms(2001), vec![]);
+		});
+	}
+}
@@ -72,14 +72,22 @@ impl<T: frame_system::Config> cumulus_pallet_xcmp_queue::WeightInfo for HydraWei
 	/// Proof: `XcmpQueue::InboundXcmpSuspended` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `MessageQueue::Pages` (r:0 w:1)
 	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(134193), added: 136668, mode: `MaxEncodedLen`)
+	/// NOTE: Includes a hand-written estimate for `XcmRateLimiter` which the messages pass before the message queue,
+	/// for a message with one asset that is deferred:
+	/// `ParachainSystem::ValidationData` (r:1 w:0), `XcmRateLimiter::AccumulatedAmounts` (r:1 w:1),
+	/// `AssetRegistry::LocationAssets` (r:1 w:0), `AssetRegistry::Assets` (r:1 w:0), `XcmRateLimiter::AssetGroups` (r:1 w:0),
+	/// `XcmRateLimiter::GroupRateLimits` (r:1 w:0), `XcmRateLimiter::GroupAccumulatedAmounts` (r:1 w:1),
+	/// `XcmRateLimiter::NextDeferredIndex` (r:1 w:1), `XcmRateLimiter::DeferredMessages` (r:0 w:1).
+	/// Must be regenerated with the benchmark CLI.
 	fn enqueue_xcmp_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `218`
 		//  Estimated: `3517`
 		// Minimum execution time: 16_526_000 picoseconds.
 		Weight::from_parts(16_912_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(40_000_000, 20_000))
+			.saturating_add(T::DbWeight::get().reads(4_u64 + 8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64 + 4_u64))
 	}
 	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
 	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -27,12 +27,17 @@ pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_omnipool;
 pub mod pallet_omnipool_liquidity_mining;
+pub mod pallet_omnipool_subpools;
+pub mod pallet_open_data;
 pub mod pallet_otc;
 pub mod pallet_otc_settlements;
+pub mod pallet_parameters;
 pub mod pallet_preimage;
+pub mod pallet_price_monitor;
 pub mod pallet_proxy;
 pub mod pallet_referenda;
 pub mod pallet_referrals;
+pub mod pallet_remote_intents;
 pub mod pallet_route_executor;
 pub mod pallet_scheduler;
 pub mod pallet_stableswap;
@@ -43,8 +48,12 @@ pub mod pallet_tips;
 pub mod pallet_transaction_multi_payment;
 pub mod pallet_transaction_pause;
 pub mod pallet_treasury;
+pub mod pallet_treasury_diversification;
 pub mod pallet_utility;
 pub mod pallet_whitelist;
 pub mod pallet_xcm;
+pub mod pallet_xcm_asset_trap;
+pub mod pallet_xcm_fee_sponsor;
+pub mod pallet_xcm_rate_limiter;
 pub mod pallet_xyk;
 pub mod pallet_xyk_liquidity_mining;
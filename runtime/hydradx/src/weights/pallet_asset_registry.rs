@@ -126,4 +126,85 @@ impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for HydraWeight<
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:100 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_sufficiency(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `420 + n * (142 ±0)`
+		//  Estimated: `3590 + n * (2583 ±0)`
+		// Minimum execution time: 19_415_000 picoseconds.
+		Weight::from_parts(17_528_940, 3590)
+			// Standard Error: 2_104
+			.saturating_add(Weight::from_parts(7_812_304, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2583).saturating_mul(n.into()))
+	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:100 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_existential_deposit(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `420 + n * (142 ±0)`
+		//  Estimated: `3590 + n * (2583 ±0)`
+		// Minimum execution time: 19_003_000 picoseconds.
+		Weight::from_parts(17_244_166, 3590)
+			// Standard Error: 2_104
+			.saturating_add(Weight::from_parts(7_812_304, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2583).saturating_mul(n.into()))
+	}
+	/// Storage: `AssetRegistry::QuarantinedAssets` (r:1 w:1)
+	/// Proof: `AssetRegistry::QuarantinedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn promote_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `3485`
+		// Minimum execution time: 14_903_000 picoseconds.
+		Weight::from_parts(15_224_000, 3485)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::TickerAssets` (r:1 w:1)
+	/// Proof: `AssetRegistry::TickerAssets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetAliases` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetAliases` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	fn add_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3658`
+		// Minimum execution time: 24_310_000 picoseconds.
+		Weight::from_parts(24_870_000, 3658)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetRegistry::AssetAliases` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetAliases` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::TickerAssets` (r:0 w:1)
+	/// Proof: `AssetRegistry::TickerAssets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn remove_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `3658`
+		// Minimum execution time: 22_641_000 picoseconds.
+		Weight::from_parts(23_102_000, 3658)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
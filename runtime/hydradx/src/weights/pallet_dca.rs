@@ -60,7 +60,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
     /// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
     /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
     /// Storage: `DCA::Schedules` (r:1 w:0)
-    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
     /// Storage: `AssetRegistry::Assets` (r:1 w:0)
     /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RemainingAmounts` (r:1 w:1)
@@ -71,19 +71,23 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RetriesOnError` (r:0 w:1)
     /// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+    /// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+    /// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     fn on_initialize_with_buy_trade() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `55175`
         //  Estimated: `31902`
         // Minimum execution time: 229_651_000 picoseconds.
         Weight::from_parts(235_813_000, 31902)
-            .saturating_add(T::DbWeight::get().reads(18_u64))
-            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().reads(20_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
     }
     /// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
     /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
     /// Storage: `DCA::Schedules` (r:1 w:0)
-    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
     /// Storage: `AssetRegistry::Assets` (r:2 w:0)
     /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
     /// Storage: `AssetRegistry::NextAssetId` (r:1 w:0)
@@ -114,19 +118,23 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
     /// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RetriesOnError` (r:0 w:1)
     /// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+    /// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+    /// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     fn on_initialize_with_buy_trade_with_insufficient_fee_asset() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `60689`
         //  Estimated: `31902`
         // Minimum execution time: 364_296_000 picoseconds.
         Weight::from_parts(370_868_000, 31902)
-            .saturating_add(T::DbWeight::get().reads(35_u64))
-            .saturating_add(T::DbWeight::get().writes(10_u64))
+            .saturating_add(T::DbWeight::get().reads(37_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
     }
     /// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
     /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
     /// Storage: `DCA::Schedules` (r:1 w:0)
-    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
     /// Storage: `AssetRegistry::Assets` (r:1 w:0)
     /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RemainingAmounts` (r:1 w:1)
@@ -137,19 +145,23 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RetriesOnError` (r:0 w:1)
     /// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+    /// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+    /// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     fn on_initialize_with_sell_trade() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `54963`
         //  Estimated: `31902`
         // Minimum execution time: 233_583_000 picoseconds.
         Weight::from_parts(236_857_000, 31902)
-            .saturating_add(T::DbWeight::get().reads(18_u64))
-            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().reads(20_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
     }
     /// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
     /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
     /// Storage: `DCA::Schedules` (r:1 w:0)
-    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
     /// Storage: `AssetRegistry::Assets` (r:2 w:0)
     /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
     /// Storage: `AssetRegistry::NextAssetId` (r:1 w:0)
@@ -180,24 +192,33 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
     /// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RetriesOnError` (r:0 w:1)
     /// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::PlannedExecutionTimes` (r:1 w:1)
+    /// Proof: `DCA::PlannedExecutionTimes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:0)
+    /// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     fn on_initialize_with_sell_trade_with_insufficient_fee_asset() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `61565`
         //  Estimated: `31902`
         // Minimum execution time: 365_645_000 picoseconds.
         Weight::from_parts(367_426_000, 31902)
-            .saturating_add(T::DbWeight::get().reads(35_u64))
-            .saturating_add(T::DbWeight::get().writes(10_u64))
+            .saturating_add(T::DbWeight::get().reads(37_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
     }
     /// Storage: `DCA::ScheduleIdsPerBlock` (r:1 w:0)
     /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::LastProcessedTimeBucket` (r:1 w:1)
+    /// Proof: `DCA::LastProcessedTimeBucket` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     fn on_initialize_with_empty_block() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `1113`
         //  Estimated: `3566`
         // Minimum execution time: 15_430_000 picoseconds.
         Weight::from_parts(15_771_000, 3566)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
     }
     /// Storage: `AssetRegistry::Assets` (r:1 w:0)
     /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -220,7 +241,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
     /// Storage: `DCA::RetriesOnError` (r:0 w:1)
     /// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
     /// Storage: `DCA::Schedules` (r:0 w:1)
-    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
     /// Storage: `DCA::ScheduleOwnership` (r:0 w:1)
     /// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RemainingAmounts` (r:0 w:1)
@@ -235,7 +256,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
             .saturating_add(T::DbWeight::get().writes(8_u64))
     }
     /// Storage: `DCA::Schedules` (r:1 w:1)
-    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
     /// Storage: `DCA::RemainingAmounts` (r:1 w:1)
     /// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Reserves` (r:1 w:1)
@@ -148,4 +148,63 @@ impl<T: frame_system::Config> pallet_ema_oracle::WeightInfo for HydraWeight<T> {
 		Weight::from_parts(17_680_000, 6294)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	/// Storage: `EmaOracle::RoutedOracles` (r:1 w:1)
+	/// Proof: `EmaOracle::RoutedOracles` (`max_values`: Some(1), `max_size`: Some(571), added: 1066, mode: `MaxEncodedLen`)
+	/// Storage: `Router::Routes` (r:1 w:0)
+	/// Proof: `Router::Routes` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:2 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn add_routed_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3094`
+		//  Estimated: `6110`
+		// Minimum execution time: 58_931_000 picoseconds.
+		Weight::from_parts(59_612_000, 6110)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EmaOracle::RoutedOracles` (r:1 w:1)
+	/// Proof: `EmaOracle::RoutedOracles` (`max_values`: Some(1), `max_size`: Some(571), added: 1066, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:0 w:3)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn remove_routed_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1287`
+		//  Estimated: `7406`
+		// Minimum execution time: 41_206_000 picoseconds.
+		Weight::from_parts(41_887_000, 7406)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EmaOracle::RoutedOracles` (r:1 w:0)
+	/// Proof: `EmaOracle::RoutedOracles` (`max_values`: Some(1), `max_size`: Some(571), added: 1066, mode: `MaxEncodedLen`)
+	/// Storage: `Router::Routes` (r:10 w:0)
+	/// Proof: `Router::Routes` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Storage: `Omnipool::Assets` (r:20 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:30 w:30)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 10]`.
+	fn on_finalize_routed_oracles(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1408 + r * (1212 ±0)`
+		//  Estimated: `7406 + r * (13076 ±0)`
+		// Minimum execution time: 72_516_000 picoseconds.
+		Weight::from_parts(21_307_412, 7406)
+			// Standard Error: 24_118
+			.saturating_add(Weight::from_parts(52_413_906, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 13076).saturating_mul(r.into()))
+	}
 }
\ No newline at end of file
@@ -99,7 +99,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:1)
@@ -124,7 +124,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:0)
 	/// Proof: `OmnipoolWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
@@ -168,7 +168,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
@@ -212,7 +212,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Storage: `Omnipool::Positions` (r:1 w:0)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
@@ -253,7 +253,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Storage: `Omnipool::Positions` (r:1 w:0)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:1 w:1)
@@ -336,7 +336,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Storage: `Omnipool::Positions` (r:1 w:0)
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::YieldFarm` (r:5 w:5)
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:5 w:5)
@@ -381,7 +381,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Storage: `Tokens::Accounts` (r:3 w:3)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
-	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:5 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::HubAssetImbalance` (r:1 w:1)
@@ -486,4 +486,36 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
 	}
+	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn top_up_global_farm() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1075`
+		//  Estimated: `8799`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Omnipool::Assets` (r:1 w:0)
+	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::ActiveYieldFarm` (r:1 w:0)
+	/// Proof: `OmnipoolWarehouseLM::ActiveYieldFarm` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:0)
+	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmValueCap` (r:0 w:1)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmValueCap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn update_yield_farm_value_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1213`
+		//  Estimated: `3670`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3670)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
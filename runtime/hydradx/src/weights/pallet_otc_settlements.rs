@@ -63,4 +63,14 @@ impl<T: frame_system::Config> pallet_otc_settlements::weights::WeightInfo for Hy
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `OtcSettlements::PoolTypeMinProfit` (r:0 w:1)
+	/// Proof: `OtcSettlements::PoolTypeMinProfit` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_pool_type_min_profit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_120_000 picoseconds.
+		Weight::from_parts(9_431_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_treasury_diversification`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --pallet=pallet-treasury-diversification
+// --extrinsic=*
+// --template=scripts/pallet-weight-template.hbs
+// --output=./weights/pallet_treasury_diversification.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_treasury_diversification`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_treasury_diversification` using the HydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_treasury_diversification::WeightInfo for HydraWeight<T> {
+	/// Storage: `TreasuryDiversification::SweepAssets` (r:1 w:0)
+	/// Proof: `TreasuryDiversification::SweepAssets` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryDiversification::TargetAllocation` (r:0 w:1)
+	/// Proof: `TreasuryDiversification::TargetAllocation` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	fn set_target_allocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1566`
+		// Minimum execution time: 14_921_000 picoseconds.
+		Weight::from_parts(15_318_000, 1566)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryDiversification::TargetAllocation` (r:1 w:0)
+	/// Proof: `TreasuryDiversification::TargetAllocation` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryDiversification::SweepAssets` (r:0 w:1)
+	/// Proof: `TreasuryDiversification::SweepAssets` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	fn set_sweep_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1566`
+		// Minimum execution time: 14_512_000 picoseconds.
+		Weight::from_parts(14_893_000, 1566)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryDiversification::Paused` (r:0 w:1)
+	/// Proof: `TreasuryDiversification::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_204_000 picoseconds.
+		Weight::from_parts(9_517_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryDiversification::Paused` (r:1 w:0)
+	/// Proof: `TreasuryDiversification::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryDiversification::TargetAllocation` (r:1 w:0)
+	/// Proof: `TreasuryDiversification::TargetAllocation` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryDiversification::SweepAssets` (r:1 w:0)
+	/// Proof: `TreasuryDiversification::SweepAssets` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:20 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 200]`.
+	fn sweep(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1346`
+		//  Estimated: `52650`
+		// Minimum execution time: 98_410_000 picoseconds.
+		Weight::from_parts(104_275_000, 52650)
+			// Standard Error: 2_418
+			.saturating_add(Weight::from_parts(1_356_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_xcm_fee_sponsor`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-11-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --pallet=pallet-xcm-fee-sponsor
+// --extrinsic=*
+// --template=scripts/pallet-weight-template.hbs
+// --output=./weights/pallet_xcm_fee_sponsor.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_fee_sponsor`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_xcm_fee_sponsor` using the HydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm_fee_sponsor::WeightInfo for HydraWeight<T> {
+	/// Storage: `XcmFeeSponsor::NextSponsorshipId` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::NextSponsorshipId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `XcmFeeSponsor::ParachainSponsorships` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::ParachainSponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmFeeSponsor::Sponsorships` (r:0 w:1)
+	/// Proof: `XcmFeeSponsor::Sponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `3805`
+		// Minimum execution time: 24_512_000 picoseconds.
+		Weight::from_parts(25_108_000, 3805)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `XcmFeeSponsor::Sponsorships` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::Sponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 17_340_000 picoseconds.
+		Weight::from_parts(17_896_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmFeeSponsor::Sponsorships` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::Sponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmFeeSponsor::ParachainSponsorships` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::ParachainSponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3943`
+		// Minimum execution time: 21_027_000 picoseconds.
+		Weight::from_parts(21_655_000, 3943)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmFeeSponsor::Sponsorships` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::Sponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmFeeSponsor::ParachainSponsorships` (r:1 w:1)
+	/// Proof: `XcmFeeSponsor::ParachainSponsorships` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3943`
+		// Minimum execution time: 21_384_000 picoseconds.
+		Weight::from_parts(22_001_000, 3943)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Weights for `pallet_xcm_rate_limiter`
+//!
+//! NOTE: This file was not produced by the benchmark CLI. `set_group_rate_limit` and `set_asset_group` are taken
+//! from the default weights of the pallet, `release_deferred` and `discard_deferred` are hand-written estimates.
+//! Must be regenerated with the benchmark CLI:
+//!
+//! target/release/hydradx benchmark pallet --chain=dev --steps=50 --repeat=20 --wasm-execution=compiled
+//! --pallet=pallet-xcm-rate-limiter --extrinsic=* --template=scripts/pallet-weight-template.hbs
+//! --output=./weights/pallet_xcm_rate_limiter.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_rate_limiter`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_xcm_rate_limiter` using the HydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm_rate_limiter::WeightInfo for HydraWeight<T> {
+	/// Storage: `XcmRateLimiter::GroupAccumulatedAmounts` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::GroupAccumulatedAmounts` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::GroupRateLimits` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::GroupRateLimits` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_group_rate_limit() -> Weight {
+		Weight::from_parts(9_736_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmRateLimiter::AssetGroups` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::AssetGroups` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_asset_group() -> Weight {
+		Weight::from_parts(9_205_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmRateLimiter::DeferredMessages` (r:1 w:1)
+	/// Storage: `MessageQueue::BookStateFor` (r:1 w:1)
+	/// Storage: `MessageQueue::ServiceHead` (r:1 w:1)
+	/// Storage: `MessageQueue::Pages` (r:0 w:1)
+	fn release_deferred() -> Weight {
+		Weight::from_parts(50_000_000, 140_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `XcmRateLimiter::DeferredMessages` (r:1 w:1)
+	fn discard_deferred() -> Weight {
+		Weight::from_parts(25_000_000, 140_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -4,8 +4,10 @@ use crate::origins::GeneralAdmin;
 use sp_std::marker::PhantomData;
 
 use codec::MaxEncodedLen;
-use hydradx_adapters::{MultiCurrencyTrader, ReroutingMultiCurrencyAdapter, ToFeeReceiver};
-use pallet_transaction_multi_payment::DepositAll;
+use hydradx_adapters::{
+	price::OmnipoolNativePriceOracle, MultiCurrencyTrader, OraclePriceProvider, QuarantineUnknownAssets,
+	RelayChainBlockNumberProvider, ReroutingMultiCurrencyAdapter, ToFeeReceiver,
+};
 use primitives::{AssetId, Price};
 
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
@@ -17,13 +19,15 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use hydradx_adapters::{xcm_exchange::XcmAssetExchanger, xcm_execute_filter::AllowTransferAndSwap};
-use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
+use hydradx_traits::OraclePeriod;
+use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key, GetByKey};
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiNativeAsset};
 use pallet_evm::AddressMapping;
 pub use pallet_xcm::GenesisConfig as XcmGenesisConfig;
 use pallet_xcm::XcmPassthrough;
+use pallet_xcm_fee_sponsor::AllowSponsoredExecutionFrom;
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
-use polkadot_parachain::primitives::Sibling;
+use polkadot_parachain::primitives::{RelayChainBlockNumber, Sibling};
 use polkadot_xcm::v3::MultiLocation;
 use polkadot_xcm::v4::{prelude::*, Asset, InteriorLocation, Weight as XcmWeight};
 use scale_info::TypeInfo;
@@ -70,6 +74,8 @@ pub type Barrier = (
 	WithComputedOrigin<
 		(
 			AllowTopLevelPaidExecutionFrom<Everything>,
+			// Execution of messages from sponsored origins is paid by the sponsor.
+			AllowSponsoredExecutionFrom<Runtime>,
 			// Subscriptions for version tracking are OK.
 			AllowSubscriptionsFrom<Everything>,
 		),
@@ -125,11 +131,21 @@ parameter_types! {
 	pub TempAccountForXcmAssetExchange: AccountId = [42; 32].into();
 	pub const MaxXcmDepth: u16 = 5;
 	pub const MaxNumberOfInstructions: u16 = 100;
+	pub const XcmFeeOraclePeriod: OraclePeriod = OraclePeriod::Short;
 
 	pub UniversalLocation: InteriorLocation = [GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into())].into();
 	pub AssetHubLocation: Location = (Parent, Parachain(ASSET_HUB_PARA_ID)).into();
 }
 
+/// Prices XCM execution fees using the Omnipool oracle, falling back to accepted currencies of the
+/// transaction payment pallet for assets not in the Omnipool.
+pub type XcmFeePriceOracle = OmnipoolNativePriceOracle<
+	OraclePriceProvider<AssetId, EmaOracle, LRNA>,
+	NativeAssetId,
+	XcmFeeOraclePeriod,
+	MultiTransactionPayment,
+>;
+
 /// Matches foreign assets from a given origin.
 /// Foreign assets are assets bridged from other consensus systems. i.e parents > 1.
 pub struct IsForeignNativeAssetFrom<Origin>(PhantomData<Origin>);
@@ -191,24 +207,25 @@ impl Config for XcmConfig {
 
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
-	// We calculate weight fees the same way as for regular extrinsics and use the prices and choice
-	// of accepted currencies of the transaction payment pallet. Fees go to the same fee receiver as
-	// configured in `MultiTransactionPayment`.
+	// We calculate weight fees the same way as for regular extrinsics. Fees can be paid in any Omnipool
+	// asset at its oracle price; other assets use prices of the transaction payment pallet. Fees go to
+	// the same fee receiver as configured in `MultiTransactionPayment`.
 	type Trader = MultiCurrencyTrader<
 		AssetId,
 		Balance,
 		Price,
 		WeightToFee,
-		MultiTransactionPayment,
+		XcmFeePriceOracle,
 		CurrencyIdConvert,
-		ToFeeReceiver<AccountId, AssetId, Balance, Price, CurrencyIdConvert, DepositAll<Runtime>, TreasuryAccount>,
+		ToFeeReceiver<AccountId, AssetId, Balance, Price, CurrencyIdConvert, TreasuryFeeDeposit, TreasuryAccount>,
 	>;
 
 	type ResponseHandler = PolkadotXcm;
-	type AssetTrap = PolkadotXcm;
+	type AssetTrap = XcmAssetTrap;
 	type AssetLocker = ();
 	type AssetExchanger = XcmAssetExchanger<Runtime, TempAccountForXcmAssetExchange, CurrencyIdConvert, Currencies>;
-	type AssetClaims = PolkadotXcm;
+	// assets trapped by pallet_xcm before XcmAssetTrap was introduced remain claimable
+	type AssetClaims = (XcmAssetTrap, PolkadotXcm);
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = ConstU32<64>;
@@ -233,6 +250,9 @@ parameter_types! {
 	pub const MaxInboundSuspended: u32 = 1_000;
 }
 
+/// Defers processing of incoming XCMP messages by the rate limits of their assets.
+pub type XcmpDeferFilter = XcmRateLimiter;
+
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ChannelInfo = ParachainSystem;
@@ -241,10 +261,36 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type ControllerOriginConverter = XcmOriginToCallOrigin;
 	type PriceForSiblingDelivery = polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery<ParaId>;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::HydraWeight<Runtime>;
-	type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
+	// incoming messages pass the rate limiter, which holds deferred messages and passes the rest to the message queue
+	type XcmpQueue = XcmRateLimiter;
 	type MaxInboundSuspended = MaxInboundSuspended;
 }
 
+parameter_types! {
+	pub const DeferDuration: RelayChainBlockNumber = 600 * 10; // 10 hours
+	pub const MaxDeferDuration: RelayChainBlockNumber = 600 * 24 * 10; // 10 days
+}
+
+pub struct XcmRateLimitsInRegistry;
+impl GetByKey<AssetId, Option<u128>> for XcmRateLimitsInRegistry {
+	fn get(asset_id: &AssetId) -> Option<u128> {
+		AssetRegistry::assets(asset_id).and_then(|details| details.xcm_rate_limit)
+	}
+}
+
+impl pallet_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type DeferDuration = DeferDuration;
+	type MaxDeferDuration = MaxDeferDuration;
+	type RelayBlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type RateLimitFor = XcmRateLimitsInRegistry;
+	type UpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+	type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
+	type WeightInfo = weights::pallet_xcm_rate_limiter::HydraWeight<Runtime>;
+}
+
 const ASSET_HUB_PARA_ID: u32 = 1000;
 
 parameter_type_with_key! {
@@ -311,6 +357,172 @@ impl pallet_xcm::Config for Runtime {
 	type RemoteLockConsumerIdentifier = ();
 }
 
+impl pallet_xcm_asset_trap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTransactor = LocalAssetTransactor;
+	type LocationToAccountId = LocationToAccountId;
+	type AccountIdToLocation = AccountIdToMultiLocation;
+	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+	type WeightInfo = weights::pallet_xcm_asset_trap::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = XcmAssetTrapBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct XcmAssetTrapBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_xcm_asset_trap::BenchmarkHelper for XcmAssetTrapBenchmarkHelper {
+	fn trapped_asset() -> Asset {
+		(
+			Location::new(0, [GeneralIndex(CORE_ASSET_ID.into())]),
+			1_000 * primitives::constants::currency::UNITS,
+		)
+			.into()
+	}
+}
+
+impl pallet_xcm_fee_sponsor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type WeightToFee = WeightToFee;
+	type FeeReceiver = TreasuryAccount;
+	type MaxSponsorshipsPerParachain = ConstU32<10>;
+	type WeightInfo = weights::pallet_xcm_fee_sponsor::HydraWeight<Runtime>;
+}
+
+impl pallet_remote_intents::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type IntentConverter = RemoteIntentConverter;
+	type WeightInfo = weights::pallet_remote_intents::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// Converts intents of remote chains to runtime calls.
+///
+/// Trades are executed by the on-chain route of the asset pair.
+pub struct RemoteIntentConverter;
+
+impl pallet_remote_intents::ConvertIntent<AccountId, pallet_remote_intents::RemoteIntentOf<Runtime>, RuntimeCall>
+	for RemoteIntentConverter
+{
+	fn convert(who: AccountId, intent: pallet_remote_intents::RemoteIntentOf<Runtime>) -> Option<RuntimeCall> {
+		use pallet_remote_intents::RemoteIntent;
+
+		let call = match intent {
+			RemoteIntent::Swap {
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+			} => RuntimeCall::Router(pallet_route_executor::Call::sell {
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route: Default::default(),
+			}),
+			RemoteIntent::AddLiquidity {
+				asset,
+				amount,
+				min_shares,
+			} => RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity_with_limit {
+				asset,
+				amount,
+				min_shares_limit: min_shares,
+			}),
+			RemoteIntent::ScheduleDca {
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				total_amount,
+				period,
+				max_retries,
+				slippage,
+			} => RuntimeCall::DCA(pallet_dca::Call::schedule {
+				schedule: pallet_dca::types::Schedule {
+					owner: who,
+					period,
+					total_amount,
+					max_retries,
+					stability_threshold: None,
+					slippage,
+					order: pallet_dca::types::Order::Sell {
+						asset_in,
+						asset_out,
+						amount_in,
+						min_amount_out,
+						route: Default::default(),
+					},
+					termination: Default::default(),
+					time_period: None,
+					execution: Default::default(),
+				},
+				start_execution_block: None,
+			}),
+		};
+
+		Some(call)
+	}
+}
+
+/// Transfers assets of remote DCA schedules to their destinations by `XTokens`.
+///
+/// The execution on the destination chain is paid from the transferred amount.
+pub struct DcaRemoteTransfer;
+
+impl pallet_dca::RemoteTransfer<AccountId, AssetId, AssetLocation> for DcaRemoteTransfer {
+	fn transfer(
+		who: &AccountId,
+		asset_id: AssetId,
+		amount: Balance,
+		destination: &AssetLocation,
+	) -> sp_runtime::DispatchResult {
+		let destination: Option<Location> = destination.clone().into();
+		let destination = destination.ok_or(sp_runtime::DispatchError::Other("Invalid remote destination"))?;
+
+		<XTokens as orml_traits::XcmTransfer<AccountId, Balance, AssetId>>::transfer(
+			who.clone(),
+			asset_id,
+			amount,
+			destination,
+			WeightLimit::Unlimited,
+		)
+		.map(|_| ())
+	}
+
+	fn transfer_weight() -> Weight {
+		use frame_support::dispatch::GetDispatchInfo;
+
+		// weight of the transfer doesn't depend on the destination account
+		orml_xtokens::Call::<Runtime>::transfer {
+			currency_id: CORE_ASSET_ID,
+			amount: 0,
+			dest: Box::new(
+				Location::new(
+					1,
+					[
+						Parachain(1000),
+						AccountId32 {
+							network: None,
+							id: [0u8; 32],
+						},
+					],
+				)
+				.into_versioned(),
+			),
+			dest_weight_limit: WeightLimit::Unlimited,
+		}
+		.get_dispatch_info()
+		.weight
+	}
+}
+
 parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(25) * BlockWeights::get().max_block;
 	pub const MessageQueueMaxStale: u32 = 8;
@@ -382,6 +594,13 @@ impl Convert<Location, Option<AssetId>> for CurrencyIdConvert {
 	}
 }
 
+impl Convert<MultiLocation, Option<AssetId>> for CurrencyIdConvert {
+	fn convert(location: MultiLocation) -> Option<AssetId> {
+		let location: Location = location.try_into().ok()?;
+		Self::convert(location)
+	}
+}
+
 impl Convert<Asset, Option<AssetId>> for CurrencyIdConvert {
 	fn convert(asset: Asset) -> Option<AssetId> {
 		Self::convert(asset.id.0)
@@ -458,10 +677,13 @@ impl Contains<(AssetId, AccountId)> for OmnipoolProtocolAccount {
 	}
 }
 
+/// Unknown foreign assets are registered as quarantined. Assets which cannot be registered are held by `UnknownTokens`.
+pub type QuarantinedUnknownTokens = QuarantineUnknownAssets<Runtime, Currencies, LocationToAccountId, UnknownTokens>;
+
 /// We use `orml::Currencies` for asset transacting. Transfers to active Omnipool accounts are rerouted to the treasury.
 pub type LocalAssetTransactor = ReroutingMultiCurrencyAdapter<
 	Currencies,
-	UnknownTokens,
+	QuarantinedUnknownTokens,
 	IsNativeConcrete<AssetId, CurrencyIdConvert>,
 	AccountId,
 	LocationToAccountId,
@@ -0,0 +1,114 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the XCM introspection runtime api - how the XCM config of the runtime would treat an incoming
+//! message, without executing it.
+
+use crate::{
+	AssetId, Balance, CurrencyIdConvert, Runtime, RuntimeCall, WeightToFee, XcmConfig, XcmFeePriceOracle,
+	XcmRateLimiter, XcmpDeferFilter,
+};
+use codec::DecodeLimit;
+use cumulus_pallet_xcmp_queue::XcmDeferFilter;
+use cumulus_primitives_core::ParaId;
+use frame_support::weights::{Weight, WeightToFee as _};
+use hydradx_adapters::RelayChainBlockNumberProvider;
+use hydradx_traits::NativePriceOracle;
+use polkadot_xcm::v4::prelude::*;
+use polkadot_xcm::{VersionedLocation, VersionedXcm, MAX_XCM_DECODE_DEPTH};
+use sp_runtime::traits::{BlockNumberProvider, Convert};
+use sp_runtime::FixedPointNumber;
+use sp_std::vec::Vec;
+use xcm_executor::traits::{Properties, ShouldExecute, WeightBounds};
+use xcm_introspection_runtime_api::{DeferredXcm, Error, RelayChainBlockNumber, XcmIntrospection};
+
+pub fn introspect_xcm(
+	origin: VersionedLocation,
+	message: VersionedXcm<()>,
+) -> Result<XcmIntrospection<AssetId, Balance>, Error> {
+	let origin: Location = origin.try_into().map_err(|_| Error::VersionedConversionFailed)?;
+	let message: Xcm<()> = message.try_into().map_err(|_| Error::VersionedConversionFailed)?;
+	let mut message: Xcm<RuntimeCall> = Xcm::from(message);
+
+	let weight =
+		<XcmConfig as xcm_executor::Config>::Weigher::weight(&mut message).map_err(|_| Error::WeightNotComputable)?;
+	let fee = execution_fee(&message, weight);
+	let deferred_by = deferred_by(&origin, &message);
+
+	// the message is checked with no weight credit, as when it's received from another chain
+	let mut properties = Properties {
+		weight_credit: Weight::zero(),
+		message_id: None,
+	};
+	let barrier = <XcmConfig as xcm_executor::Config>::Barrier::should_execute(
+		&origin,
+		message.inner_mut(),
+		weight,
+		&mut properties,
+	);
+
+	Ok(XcmIntrospection {
+		barrier,
+		weight,
+		fee,
+		deferred_by,
+	})
+}
+
+/// Fee for `weight` in the asset of the first `BuyExecution`, priced the same way as by the trader of the XCM executor.
+fn execution_fee(message: &Xcm<RuntimeCall>, weight: Weight) -> Option<(AssetId, Balance)> {
+	let fees = message.inner().iter().find_map(|instruction| match instruction {
+		BuyExecution { fees, .. } => Some(fees),
+		_ => None,
+	})?;
+
+	let asset_id = CurrencyIdConvert::convert(fees.id.0.clone())?;
+	let price = XcmFeePriceOracle::price(asset_id)?;
+	let fee = price.checked_mul_int(WeightToFee::weight_to_fee(&weight))?;
+
+	Some((asset_id, fee))
+}
+
+/// Deferral of the message by the XCMP queue. Only messages of sibling parachains are sent over XCMP.
+fn deferred_by(origin: &Location, message: &Xcm<RuntimeCall>) -> Option<RelayChainBlockNumber> {
+	let para_id = match origin.unpack() {
+		(1, [Parachain(id)]) => ParaId::from(*id),
+		_ => return None,
+	};
+	let sent_at = RelayChainBlockNumberProvider::<Runtime>::current_block_number();
+
+	<XcmpDeferFilter as XcmDeferFilter<RuntimeCall>>::deferred_by(para_id, sent_at, &VersionedXcm::V4(message.clone()))
+		.1
+}
+
+/// Messages of sibling parachain `para_id` held by the rate limiter until their deferral period ends.
+pub fn deferred_xcms(para_id: u32) -> Vec<DeferredXcm> {
+	XcmRateLimiter::deferred_messages(para_id.into())
+		.into_iter()
+		.filter_map(|(index, deferred)| {
+			let message =
+				VersionedXcm::<()>::decode_all_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &deferred.message[..])
+					.ok()?;
+			Some(DeferredXcm {
+				index,
+				sent_at: deferred.sent_at,
+				deferred_to: deferred.deferred_to,
+				message,
+			})
+		})
+		.collect()
+}
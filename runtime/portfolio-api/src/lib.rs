@@ -0,0 +1,83 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct TokenBalance<Balance> {
+	/// Transferable and frozen part of the balance.
+	pub free: Balance,
+	/// Balance reserved by other subsystems.
+	pub reserved: Balance,
+	/// Part of `free` balance which can't be withdrawn.
+	pub frozen: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OmnipoolPosition<AssetId, Balance, ItemId> {
+	pub position_id: ItemId,
+	pub asset_id: AssetId,
+	/// Amount of asset provided to the Omnipool.
+	pub amount: Balance,
+	pub shares: Balance,
+	/// Amount of asset received if whole position was removed at current spot price, without withdrawal fee.
+	pub value: Balance,
+	/// Amount of hub asset received if whole position was removed at current spot price.
+	pub hub_value: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StableswapShares<AssetId, Balance> {
+	pub pool_id: AssetId,
+	pub shares: Balance,
+	/// Pool reserves attributable to the shares.
+	pub assets: Vec<(AssetId, Balance)>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OmnipoolDeposit<AssetId, Balance, ItemId> {
+	pub deposit_id: ItemId,
+	/// Omnipool position locked in the deposit.
+	pub position: OmnipoolPosition<AssetId, Balance, ItemId>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct XykDeposit<AssetId, Balance, ItemId> {
+	pub deposit_id: ItemId,
+	/// Share token of the XYK pool.
+	pub share_token: AssetId,
+	pub shares: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StakingPosition<Balance, ItemId> {
+	pub position_id: ItemId,
+	pub stake: Balance,
+	/// Stake together with rewards locked by increasing the stake.
+	pub locked: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AccountPortfolio<AssetId, Balance, ItemId> {
+	pub balances: Vec<(AssetId, TokenBalance<Balance>)>,
+	pub omnipool_positions: Vec<OmnipoolPosition<AssetId, Balance, ItemId>>,
+	pub stableswap_shares: Vec<StableswapShares<AssetId, Balance>>,
+	pub omnipool_lm_deposits: Vec<OmnipoolDeposit<AssetId, Balance, ItemId>>,
+	pub xyk_lm_deposits: Vec<XykDeposit<AssetId, Balance, ItemId>>,
+	pub staking: Option<StakingPosition<Balance, ItemId>>,
+	/// Referral rewards claimable now, in the referrals reward asset.
+	pub referral_rewards: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait PortfolioApi<AccountId, AssetId, Balance, ItemId> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		ItemId: Codec,
+	{
+		fn account_portfolio(who: AccountId) -> AccountPortfolio<AssetId, Balance, ItemId>;
+	}
+}
@@ -0,0 +1,60 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use frame_support::{traits::ProcessMessageError, weights::Weight};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+use xcm::{VersionedLocation, VersionedXcm};
+
+/// Relay chain block number, used for the deferral of incoming messages.
+pub type RelayChainBlockNumber = u32;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct XcmIntrospection<AssetId, Balance> {
+	/// Result of the barrier of the XCM executor, `Ok` if the message would be allowed to execute.
+	pub barrier: Result<(), ProcessMessageError>,
+	/// Weight of the message as determined by the weigher of the XCM executor.
+	pub weight: Weight,
+	/// Estimated execution fee in the asset of the first `BuyExecution` instruction.
+	/// `None` if the message doesn't buy execution or the asset can't be used to pay for it.
+	pub fee: Option<(AssetId, Balance)>,
+	/// Number of relay chain blocks the message would be deferred by before it's processed.
+	pub deferred_by: Option<RelayChainBlockNumber>,
+}
+
+/// Incoming message deferred by the runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DeferredXcm {
+	/// Index of the message among the deferred messages of the sender.
+	pub index: u32,
+	/// Relay chain block in which the message was received.
+	pub sent_at: RelayChainBlockNumber,
+	/// Relay chain block from which the message is processed.
+	pub deferred_to: RelayChainBlockNumber,
+	/// The deferred message.
+	pub message: VersionedXcm<()>,
+}
+
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Error {
+	/// Origin or message can't be converted to the XCM version used by the runtime.
+	VersionedConversionFailed,
+	/// Weight of the message can't be computed, e.g. the message has too many instructions.
+	WeightNotComputable,
+}
+
+sp_api::decl_runtime_apis! {
+	#[api_version(2)]
+	pub trait XcmIntrospectionApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Report how the runtime would treat `message` sent by `origin`, without executing it.
+		fn introspect_xcm(origin: VersionedLocation, message: VersionedXcm<()>) -> Result<XcmIntrospection<AssetId, Balance>, Error>;
+
+		/// List messages of sibling parachain `para_id` which are deferred by the runtime.
+		#[api_version(2)]
+		fn deferred_xcms(para_id: u32) -> Vec<DeferredXcm>;
+	}
+}
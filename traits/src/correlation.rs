@@ -0,0 +1,25 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::sp_runtime::{traits::SaturatedConversion, RuntimeDebug};
+use scale_info::TypeInfo;
+
+/// Identifier shared by all events emitted while executing one extrinsic.
+///
+/// Derived from the block number and the index of the extrinsic in the block. Indexers can use it to group
+/// the cascade of events emitted for one user trade (router, pools, referrals) without relying on event ordering.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CorrelationId {
+	/// Block the events were emitted in.
+	pub block_number: u32,
+	/// Index of the extrinsic in the block. `None` if the events were emitted outside of an extrinsic, e.g. in a hook.
+	pub extrinsic_index: Option<u32>,
+}
+
+impl CorrelationId {
+	/// Correlation id of the extrinsic which is currently executed.
+	pub fn current<T: frame_system::Config>() -> Self {
+		Self {
+			block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
+			extrinsic_index: frame_system::Pallet::<T>::extrinsic_index(),
+		}
+	}
+}
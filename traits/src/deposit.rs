@@ -0,0 +1,24 @@
+use frame_support::sp_runtime::DispatchError;
+use frame_support::weights::Weight;
+
+/// Sponsorship of the existential deposit of accounts receiving small payouts.
+pub trait ExistentialDepositSponsor<AccountId, AssetId, Balance> {
+	/// Transfer `amount` of `asset` from `sponsor` to `to`.
+	///
+	/// If the balance of `to` would stay below the existential deposit, `sponsor` tops it up to the existential
+	/// deposit. The top-up is reclaimed by `sponsor` when `to` is reaped.
+	///
+	/// Returns the sponsored amount.
+	fn sponsored_transfer(
+		asset: AssetId,
+		sponsor: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Total amount of `asset` sponsored by `sponsor` which was not reclaimed yet.
+	fn sponsored_total(asset: AssetId, sponsor: &AccountId) -> Balance;
+
+	/// Weight of `sponsored_transfer`.
+	fn sponsored_transfer_weight() -> Weight;
+}
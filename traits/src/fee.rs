@@ -1,4 +1,6 @@
+use crate::router::PoolType;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
+use frame_support::weights::Weight;
 
 ///Checking if asset is an accepted transaction fee currency
 pub trait InspectTransactionFeeCurrency<AssetId> {
@@ -32,3 +34,48 @@ pub trait SwappablePaymentAssetTrader<AccountId, AssetId, Balance>: InspectTrans
 		dest: &AccountId,
 	) -> DispatchResult;
 }
+
+/// Trade executed by one of the pools.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeInfo<AccountId, AssetId, Balance> {
+	/// Pool which executed the trade.
+	pub pool: PoolType<AssetId>,
+	pub trader: AccountId,
+	/// Account which holds the collected fee.
+	pub fee_account: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	/// Pool reserve of `asset_in` before the trade.
+	pub reserve_in: Balance,
+	/// Pool reserve of `asset_out` before the trade.
+	pub reserve_out: Balance,
+	pub fee_asset: AssetId,
+	/// Fee amount which can be taken out of `fee_account` by the handler.
+	pub fee_amount: Balance,
+	/// Protocol fee of the trade, in hub asset. Zero if the pool doesn't charge a protocol fee.
+	pub protocol_fee: Balance,
+}
+
+/// Handler called by the pools after every executed trade.
+///
+/// Single place where the runtime hooks in fee skims and trade volume tracking, regardless of the pool type.
+pub trait OnTradeExecuted<AccountId, AssetId, Balance> {
+	/// Processes executed trade. Part of the fee can be transferred out of `fee_account`.
+	///
+	/// Returns amount of the fee used by the handler.
+	fn on_trade_executed(trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError>;
+
+	fn on_trade_executed_weight(pool: PoolType<AssetId>) -> Weight;
+}
+
+impl<AccountId, AssetId, Balance: Default> OnTradeExecuted<AccountId, AssetId, Balance> for () {
+	fn on_trade_executed(_trade: &TradeInfo<AccountId, AssetId, Balance>) -> Result<Balance, DispatchError> {
+		Ok(Balance::default())
+	}
+
+	fn on_trade_executed_weight(_pool: PoolType<AssetId>) -> Weight {
+		Weight::zero()
+	}
+}
@@ -18,6 +18,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::upper_case_acronyms)]
 
+pub mod correlation;
+pub mod deposit;
 pub mod evm;
 pub mod fee;
 pub mod liquidity_mining;
@@ -27,6 +29,7 @@ pub mod pools;
 pub mod price;
 pub mod registry;
 pub mod router;
+pub mod vesting;
 
 pub use oracle::*;
 pub use registry::*;
@@ -287,6 +290,47 @@ pub trait AMMAddLiquidity<AccountId, AssetId, Balance> {
 	) -> Result<Balance, DispatchError>;
 }
 
+/// Implementers of this trait are able to create their AMM pool.
+pub trait AMMCreatePool<AccountId, AssetId, Balance> {
+	/// Create a pool with initial liquidity provided by `who`.
+	///
+	/// Returns share token of the pool and amount of shares issued to `who`.
+	fn create_pool(
+		who: AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError>;
+}
+
+/// Implementers of this trait are able to add liquidity of a single asset to their pool, e.g. Omnipool.
+pub trait AddSingleAssetLiquidity<AccountId, AssetId, Balance> {
+	/// Returns `true` if liquidity of `asset` can be added to the pool.
+	fn can_add_liquidity(asset: AssetId) -> bool;
+
+	/// Add `amount` of `asset` provided by `who` as liquidity to the pool.
+	///
+	/// Returns value of the created position, denominated in the numeraire of the pool.
+	fn add_liquidity(who: AccountId, asset: AssetId, amount: Balance) -> Result<Balance, DispatchError>;
+
+	fn add_liquidity_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Balance> AddSingleAssetLiquidity<AccountId, AssetId, Balance> for () {
+	fn can_add_liquidity(_asset: AssetId) -> bool {
+		false
+	}
+
+	fn add_liquidity(_who: AccountId, _asset: AssetId, _amount: Balance) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("Adding liquidity not available"))
+	}
+
+	fn add_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 /// Provides account's fee payment asset
 pub trait AccountFeeCurrency<AccountId> {
 	type AssetId;
@@ -298,3 +342,24 @@ pub trait AccountFeeCurrencyBalanceInCurrency<AssetId, AccountId> {
 	type Output;
 	fn get_balance_in_currency(to_currency: AssetId, account: &AccountId) -> Self::Output;
 }
+
+/// Handler of weight used by a block hook, e.g. to collect weight telemetry.
+pub trait OnHookWeightUsed {
+	/// Called with weight used by the hook and weight which was available to it.
+	///
+	/// Returns weight consumed by the handler itself.
+	fn on_hook_weight_used(used: Weight, available: Weight) -> Weight;
+
+	/// Weight consumed by `on_hook_weight_used`, so hooks can reserve it before using the available weight.
+	fn on_hook_weight_used_weight() -> Weight;
+}
+
+impl OnHookWeightUsed for () {
+	fn on_hook_weight_used(_used: Weight, _available: Weight) -> Weight {
+		Weight::zero()
+	}
+
+	fn on_hook_weight_used_weight() -> Weight {
+		Weight::zero()
+	}
+}
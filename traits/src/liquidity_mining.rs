@@ -60,6 +60,16 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 		min_deposit: Self::Balance,
 	) -> Result<(), Self::Error>;
 
+	/// Add rewards to the existing global farm and extend its planned yielding periods.
+	///
+	/// Returns: `(new max reward per period)`
+	fn top_up_global_farm(
+		who: AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Self::Balance,
+		extension: Self::Period,
+	) -> Result<Self::Balance, Self::Error>;
+
 	/// Terminate existing global farm.
 	///
 	/// Returns: `(reward currency, undistributed rewards, destination account)`
@@ -90,6 +100,16 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 		multiplier: FixedU128,
 	) -> Result<YieldFarmId, Self::Error>;
 
+	/// Update value cap of the existing yield farm. `None` removes the cap.
+	///
+	/// Returns: `(YieldFarmId)`
+	fn update_yield_farm_value_cap(
+		who: AccountId,
+		global_farm_id: GlobalFarmId,
+		amm_pool_id: Self::AmmPoolId,
+		cap: Option<Self::Balance>,
+	) -> Result<YieldFarmId, Self::Error>;
+
 	/// Stop yield farming for amm pool in the global farm.
 	///
 	/// Returns: `(YieldFarmId)`
@@ -1,5 +1,20 @@
+use sp_arithmetic::Permill;
+
 pub trait PriceProvider<AssetId> {
 	type Price;
 
 	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price>;
 }
+
+/// Implementers of this trait provide the divergence of the spot price of an asset from its oracle price.
+///
+/// Should return `None` if the price of the asset is considered healthy.
+pub trait PriceDivergenceProvider<AssetId> {
+	fn divergence(asset_id: AssetId) -> Option<Permill>;
+}
+
+impl<AssetId> PriceDivergenceProvider<AssetId> for () {
+	fn divergence(_asset_id: AssetId) -> Option<Permill> {
+		None
+	}
+}
@@ -1,5 +1,6 @@
 use crate::evm::EvmAddress;
 use frame_support::dispatch::Parameter;
+use frame_support::weights::Weight;
 use sp_std::vec::Vec;
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
@@ -49,6 +50,11 @@ pub trait Inspect {
 	fn asset_symbol(id: Self::AssetId) -> Option<Vec<u8>>;
 
 	fn existential_deposit(id: Self::AssetId) -> Option<u128>;
+
+	/// Quarantined assets were registered automatically and can't be traded until they are promoted by governance.
+	fn is_quarantined(_id: Self::AssetId) -> bool {
+		false
+	}
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -187,3 +193,20 @@ pub trait RegisterAssetHook<AssetId> {
 impl<AssetId> RegisterAssetHook<AssetId> for () {
 	fn on_register_asset(_: AssetId) {}
 }
+
+/// Hook executed for every account holding an asset whose sufficiency was changed.
+pub trait SufficiencyChangeHook<AccountId, AssetId> {
+	/// Migrate references of `who` holding `asset_id` which is sufficient now if `is_sufficient` is true.
+	fn on_sufficiency_changed(asset_id: AssetId, who: &AccountId, is_sufficient: bool);
+
+	/// Weight of `on_sufficiency_changed` for a single account.
+	fn on_sufficiency_changed_weight() -> Weight;
+}
+
+impl<AccountId, AssetId> SufficiencyChangeHook<AccountId, AssetId> for () {
+	fn on_sufficiency_changed(_: AssetId, _: &AccountId, _: bool) {}
+
+	fn on_sufficiency_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -3,7 +3,7 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
-use sp_arithmetic::FixedU128;
+use sp_arithmetic::{FixedU128, Permill};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -60,6 +60,8 @@ pub trait RouteProvider<AssetId> {
 	}
 }
 
+impl<AssetId> RouteProvider<AssetId> for () {}
+
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
 	XYK,
@@ -142,6 +144,67 @@ pub trait RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> {
 	) -> DispatchResultWithPostInfo;
 }
 
+/// Router which does not support any trade.
+impl<Origin, AssetId, Balance, Trade, AmountInAndOut> RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> for () {
+	fn sell(
+		_origin: Origin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+		_route: Vec<Trade>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Router not available"))
+	}
+
+	fn sell_all(
+		_origin: Origin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Router not available"))
+	}
+
+	fn buy(
+		_origin: Origin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Router not available"))
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut>, DispatchError> {
+		Err(DispatchError::Other("Router not available"))
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut>, DispatchError> {
+		Err(DispatchError::Other("Router not available"))
+	}
+
+	fn set_route(_origin: Origin, _asset_pair: AssetPair<AssetId>, _route: Vec<Trade>) -> DispatchResultWithPostInfo {
+		Err(DispatchError::Other("Router not available").into())
+	}
+
+	fn force_insert_route(
+		_origin: Origin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade>,
+	) -> DispatchResultWithPostInfo {
+		Err(DispatchError::Other("Router not available").into())
+	}
+}
+
 /// All AMMs used in the router are required to implement this trait.
 pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 	type Error;
@@ -189,6 +252,15 @@ pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 		asset_a: AssetId,
 		asset_b: AssetId,
 	) -> Result<FixedU128, ExecutorError<Self::Error>>;
+
+	/// Fee charged by the pool for a trade of `asset_in` for `asset_out`, including dynamic fees.
+	fn calculate_fee(
+		_pool_type: PoolType<AssetId>,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+	) -> Result<Permill, ExecutorError<Self::Error>> {
+		Err(ExecutorError::NotSupported)
+	}
 }
 
 #[allow(clippy::redundant_clone)] //Needed as it complains about redundant clone, but clone is needed as Origin is moved and it is not copy type.
@@ -308,6 +380,23 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 		);
 		Err(value)
 	}
+
+	fn calculate_fee(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<Permill, ExecutorError<Self::Error>> {
+		for_tuples!(
+			#(
+				let value = match Tuple::calculate_fee(pool_type, asset_in, asset_out){
+					Ok(result) => return Ok(result),
+					Err(v) if v == ExecutorError::NotSupported => v,
+					Err(v) => return Err(v),
+				};
+			)*
+		);
+		Err(value)
+	}
 }
 
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
@@ -0,0 +1,32 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::sp_runtime::{DispatchResult, RuntimeDebug};
+use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+
+/// Vesting schedule rewards of an incentive program are paid with.
+///
+/// Rewards are locked for `cliff` blocks and then released linearly in `period_count` releases,
+/// one every `period` blocks.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RewardVesting {
+	/// Number of blocks before the first release.
+	pub cliff: u32,
+	/// Number of blocks between two releases.
+	pub period: u32,
+	/// Number of releases.
+	pub period_count: u32,
+}
+
+impl RewardVesting {
+	pub fn is_valid(&self) -> bool {
+		self.period > 0 && self.period_count > 0
+	}
+}
+
+/// Transfers native currency to an account locked in a vesting schedule.
+pub trait VestedTransfer<AccountId, Balance> {
+	fn vested_transfer(from: &AccountId, to: &AccountId, amount: Balance, vesting: RewardVesting) -> DispatchResult;
+
+	/// Weight of `vested_transfer`.
+	fn vested_transfer_weight() -> Weight;
+}